@@ -0,0 +1,545 @@
+//! # 成就
+//!
+//! [`Achievements`] 资源统一管理成就定义、解锁与进度，游戏代码只和它打交道：
+//!
+//! - `unlock("first_blood")` 直接解锁；`add_progress("collector", 1)` 累加进度，达到目标自动解锁；
+//! - 状态以 RON 持久化到本地文件，下次启动恢复；
+//! - 每次解锁 / 进度变化发送 [`AchievementUnlocked`] / [`AchievementProgressed`] 事件，供 UI 弹出提示；
+//! - 平台层（Steam、主机 SDK 等）实现 [`AchievementBackend`] 并通过
+//!   [`AchievementsPlugin::with_backend`] 接入，游戏代码无需改动。
+//!
+//! ```rust
+//! use anvilkit_app::achievements::{AchievementDef, Achievements};
+//!
+//! let mut achievements = Achievements::default();
+//! achievements.register(AchievementDef::new("first_blood", "First Blood", "Defeat an enemy"));
+//! achievements.register(AchievementDef::new("collector", "Collector", "Collect 10 gems").with_goal(10));
+//!
+//! assert!(achievements.unlock("first_blood"));
+//! assert!(!achievements.unlock("first_blood")); // 已解锁
+//!
+//! achievements.add_progress("collector", 4);
+//! assert_eq!(achievements.progress("collector"), Some((4, 10)));
+//! achievements.add_progress("collector", 20);
+//! assert!(achievements.is_unlocked("collector"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use bevy_ecs::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use anvilkit_core::error::{AnvilKitError, Result};
+
+use crate::ecs_app::{App, Plugin};
+
+/// 成就定义
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AchievementDef {
+    /// 唯一 ID（与平台后台配置的 API 名称一致）
+    pub id: String,
+    /// 显示名称
+    pub name: String,
+    /// 描述
+    pub description: String,
+    /// 进度目标；为 1 时即普通的一次性成就
+    pub goal: u32,
+    /// 解锁前是否隐藏名称与描述
+    pub hidden: bool,
+}
+
+impl AchievementDef {
+    /// 一次性成就
+    pub fn new(id: impl Into<String>, name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self { id: id.into(), name: name.into(), description: description.into(), goal: 1, hidden: false }
+    }
+
+    /// 设置进度目标
+    pub fn with_goal(mut self, goal: u32) -> Self {
+        self.goal = goal.max(1);
+        self
+    }
+
+    /// 解锁前隐藏
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+}
+
+/// 单个成就的持久化状态
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AchievementStatus {
+    /// 当前进度
+    pub progress: u32,
+    /// 解锁时间（Unix 秒），未解锁为 `None`
+    pub unlocked_at: Option<u64>,
+}
+
+/// 平台成就后端
+///
+/// 本地状态始终由 [`Achievements`] 维护并持久化，后端只负责把变化同步到平台。
+pub trait AchievementBackend: Send + Sync + 'static {
+    /// 后端名称（日志用）
+    fn name(&self) -> &str;
+
+    /// 成就已解锁
+    fn unlock(&mut self, id: &str);
+
+    /// 进度变化（平台支持进度显示时实现）
+    fn set_progress(&mut self, _id: &str, _current: u32, _goal: u32) {}
+
+    /// 成就被重置（调试用）
+    fn reset(&mut self, _id: &str) {}
+
+    /// 每帧调用一次，处理平台回调；返回平台侧已解锁、需要同步到本地的成就 ID
+    fn poll(&mut self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// 仅本地持久化的后端（默认）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalAchievementBackend;
+
+impl AchievementBackend for LocalAchievementBackend {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    fn unlock(&mut self, _id: &str) {}
+}
+
+/// 成就解锁事件
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AchievementUnlocked {
+    /// 成就 ID
+    pub id: String,
+    /// 显示名称
+    pub name: String,
+    /// 描述
+    pub description: String,
+}
+
+/// 成就进度变化事件（未解锁时）
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AchievementProgressed {
+    /// 成就 ID
+    pub id: String,
+    /// 当前进度
+    pub current: u32,
+    /// 目标
+    pub goal: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Notification {
+    Unlocked(String),
+    Progressed(String),
+    Reset(String),
+}
+
+/// 成就表 (Resource)
+///
+/// 变化在 [`achievements_system`] 中同步到后端、转为事件并写回文件。
+#[derive(Resource)]
+pub struct Achievements {
+    defs: Vec<AchievementDef>,
+    status: BTreeMap<String, AchievementStatus>,
+    pending: Vec<Notification>,
+    backend: Box<dyn AchievementBackend>,
+    path: Option<PathBuf>,
+}
+
+impl Default for Achievements {
+    fn default() -> Self {
+        Self {
+            defs: Vec::new(),
+            status: BTreeMap::new(),
+            pending: Vec::new(),
+            backend: Box::new(LocalAchievementBackend),
+            path: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for Achievements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Achievements")
+            .field("defs", &self.defs)
+            .field("status", &self.status)
+            .field("backend", &self.backend.name())
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+fn now_unix_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl Achievements {
+    /// 持久化到 `path` 的成就表
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), ..Default::default() }
+    }
+
+    /// 替换后端
+    pub fn set_backend(&mut self, backend: impl AchievementBackend) {
+        self.backend = Box::new(backend);
+    }
+
+    /// 当前后端名称
+    pub fn backend_name(&self) -> &str {
+        self.backend.name()
+    }
+
+    /// 持久化文件路径
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 注册成就；同 ID 的定义会被替换，已有状态保留
+    pub fn register(&mut self, def: AchievementDef) {
+        self.status.entry(def.id.clone()).or_default();
+        match self.defs.iter_mut().find(|d| d.id == def.id) {
+            Some(existing) => *existing = def,
+            None => self.defs.push(def),
+        }
+    }
+
+    /// 按注册顺序的成就定义
+    pub fn defs(&self) -> &[AchievementDef] {
+        &self.defs
+    }
+
+    /// 查找定义
+    pub fn def(&self, id: &str) -> Option<&AchievementDef> {
+        self.defs.iter().find(|d| d.id == id)
+    }
+
+    /// 查询状态
+    pub fn status(&self, id: &str) -> Option<&AchievementStatus> {
+        self.def(id).and(self.status.get(id))
+    }
+
+    /// 是否已解锁
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.status(id).is_some_and(|s| s.unlocked_at.is_some())
+    }
+
+    /// `(当前进度, 目标)`
+    pub fn progress(&self, id: &str) -> Option<(u32, u32)> {
+        let def = self.def(id)?;
+        Some((self.status.get(id).map_or(0, |s| s.progress), def.goal))
+    }
+
+    /// 已解锁数量与总数
+    pub fn unlocked_count(&self) -> (usize, usize) {
+        let unlocked = self.defs.iter().filter(|d| self.is_unlocked(&d.id)).count();
+        (unlocked, self.defs.len())
+    }
+
+    /// 解锁成就；未注册或已解锁时返回 `false`
+    pub fn unlock(&mut self, id: &str) -> bool {
+        let Some(goal) = self.def(id).map(|d| d.goal) else {
+            log::warn!("解锁未注册的成就: {}", id);
+            return false;
+        };
+        let status = self.status.entry(id.to_string()).or_default();
+        if status.unlocked_at.is_some() {
+            return false;
+        }
+        status.progress = goal;
+        status.unlocked_at = Some(now_unix_secs());
+        self.pending.push(Notification::Unlocked(id.to_string()));
+        true
+    }
+
+    /// 累加进度，达到目标时解锁；返回是否因此解锁
+    pub fn add_progress(&mut self, id: &str, amount: u32) -> bool {
+        let current = self.progress(id).map_or(0, |(current, _)| current);
+        self.set_progress(id, current.saturating_add(amount))
+    }
+
+    /// 设置进度（只增不减），达到目标时解锁；返回是否因此解锁
+    pub fn set_progress(&mut self, id: &str, value: u32) -> bool {
+        let Some(goal) = self.def(id).map(|d| d.goal) else {
+            log::warn!("更新未注册成就的进度: {}", id);
+            return false;
+        };
+        if self.is_unlocked(id) {
+            return false;
+        }
+        let status = self.status.entry(id.to_string()).or_default();
+        let value = value.min(goal);
+        if value <= status.progress {
+            return false;
+        }
+        status.progress = value;
+        if value >= goal {
+            return self.unlock(id);
+        }
+        self.pending.push(Notification::Progressed(id.to_string()));
+        false
+    }
+
+    /// 重置成就（调试用）
+    pub fn reset(&mut self, id: &str) {
+        if let Some(status) = self.status.get_mut(id) {
+            *status = AchievementStatus::default();
+            self.pending.push(Notification::Reset(id.to_string()));
+        }
+    }
+
+    /// 重置全部成就
+    pub fn reset_all(&mut self) {
+        let ids: Vec<String> = self.defs.iter().map(|d| d.id.clone()).collect();
+        for id in ids {
+            self.reset(&id);
+        }
+    }
+
+    /// 序列化状态为 RON
+    pub fn to_ron(&self) -> Result<String> {
+        ron::ser::to_string_pretty(&self.status, ron::ser::PrettyConfig::default())
+            .map_err(|e| AnvilKitError::serialization(format!("序列化成就失败: {}", e)))
+    }
+
+    /// 从 RON 恢复状态（不产生事件）
+    pub fn load_ron(&mut self, source: &str) -> Result<()> {
+        let loaded: BTreeMap<String, AchievementStatus> =
+            ron::from_str(source).map_err(|e| AnvilKitError::persistence(format!("解析成就失败: {}", e)))?;
+        self.status.extend(loaded);
+        Ok(())
+    }
+
+    /// 从本地文件加载；文件不存在时保持空状态
+    pub fn load(&mut self) -> Result<()> {
+        let Some(path) = self.path.clone() else { return Ok(()) };
+        if !path.exists() {
+            return Ok(());
+        }
+        let source = std::fs::read_to_string(&path).map_err(|e| {
+            AnvilKitError::persistence_with_path(format!("读取成就失败: {}", e), path.display().to_string())
+        })?;
+        self.load_ron(&source)
+    }
+
+    /// 写回本地文件（未配置路径时什么都不做）
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AnvilKitError::persistence_with_path(format!("创建成就目录失败: {}", e), parent.display().to_string())
+            })?;
+        }
+        std::fs::write(path, self.to_ron()?).map_err(|e| {
+            AnvilKitError::persistence_with_path(format!("写入成就失败: {}", e), path.display().to_string())
+        })
+    }
+}
+
+/// 同步后端、发送事件并持久化 (Last)
+///
+/// 后端报告的平台侧解锁会合并进本地状态。
+pub fn achievements_system(
+    mut achievements: ResMut<Achievements>,
+    mut unlocked: EventWriter<AchievementUnlocked>,
+    mut progressed: EventWriter<AchievementProgressed>,
+) {
+    for id in achievements.backend.poll() {
+        achievements.unlock(&id);
+    }
+    if achievements.pending.is_empty() {
+        return;
+    }
+
+    let achievements = &mut *achievements;
+    for notification in std::mem::take(&mut achievements.pending) {
+        match notification {
+            Notification::Unlocked(id) => {
+                let Some(def) = achievements.defs.iter().find(|d| d.id == id) else { continue };
+                achievements.backend.unlock(&id);
+                unlocked.send(AchievementUnlocked {
+                    id,
+                    name: def.name.clone(),
+                    description: def.description.clone(),
+                });
+            }
+            Notification::Progressed(id) => {
+                let Some(def) = achievements.defs.iter().find(|d| d.id == id) else { continue };
+                let current = achievements.status.get(&id).map_or(0, |s| s.progress);
+                achievements.backend.set_progress(&id, current, def.goal);
+                progressed.send(AchievementProgressed { id, current, goal: def.goal });
+            }
+            Notification::Reset(id) => achievements.backend.reset(&id),
+        }
+    }
+    if let Err(e) = achievements.save() {
+        log::warn!("{}", e);
+    }
+}
+
+/// 成就插件
+///
+/// ```rust,ignore
+/// app.add_plugins(
+///     AchievementsPlugin::new("saves/achievements.ron")
+///         .with(AchievementDef::new("first_blood", "First Blood", "Defeat an enemy"))
+///         .with_backend(SteamAchievements::new(client)),
+/// );
+/// ```
+pub struct AchievementsPlugin {
+    path: Option<PathBuf>,
+    defs: Vec<AchievementDef>,
+    backend: std::sync::Mutex<Option<Box<dyn AchievementBackend>>>,
+}
+
+impl Default for AchievementsPlugin {
+    fn default() -> Self {
+        Self { path: None, defs: Vec::new(), backend: std::sync::Mutex::new(None) }
+    }
+}
+
+impl AchievementsPlugin {
+    /// 持久化到 `path` 的成就插件
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), ..Default::default() }
+    }
+
+    /// 添加成就定义
+    pub fn with(mut self, def: AchievementDef) -> Self {
+        self.defs.push(def);
+        self
+    }
+
+    /// 使用平台后端（默认 [`LocalAchievementBackend`]）
+    pub fn with_backend(self, backend: impl AchievementBackend) -> Self {
+        *self.backend.lock().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(backend));
+        self
+    }
+}
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        let mut achievements = match &self.path {
+            Some(path) => Achievements::with_path(path),
+            None => Achievements::default(),
+        };
+        if let Err(e) = achievements.load() {
+            log::warn!("{}", e);
+        }
+        for def in &self.defs {
+            achievements.register(def.clone());
+        }
+        if let Some(backend) = self.backend.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            achievements.backend = backend;
+        }
+
+        app.insert_resource(achievements)
+            .add_event::<AchievementUnlocked>()
+            .add_event::<AchievementProgressed>()
+            .add_systems(bevy_app::Last, achievements_system);
+    }
+
+    fn name(&self) -> &str {
+        "AchievementsPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default, Clone)]
+    struct RecordingBackend {
+        log: Arc<Mutex<Vec<String>>>,
+        remote: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl AchievementBackend for RecordingBackend {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        fn unlock(&mut self, id: &str) {
+            self.log.lock().unwrap().push(format!("unlock {}", id));
+        }
+
+        fn set_progress(&mut self, id: &str, current: u32, goal: u32) {
+            self.log.lock().unwrap().push(format!("progress {} {}/{}", id, current, goal));
+        }
+
+        fn poll(&mut self) -> Vec<String> {
+            std::mem::take(&mut *self.remote.lock().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_progress_is_monotonic_and_unlocks() {
+        let mut achievements = Achievements::default();
+        achievements.register(AchievementDef::new("gems", "Gems", "").with_goal(3));
+        assert!(!achievements.set_progress("gems", 2));
+        assert!(!achievements.set_progress("gems", 1));
+        assert_eq!(achievements.progress("gems"), Some((2, 3)));
+        assert!(achievements.add_progress("gems", 1));
+        assert!(achievements.is_unlocked("gems"));
+        assert!(!achievements.add_progress("gems", 1));
+        assert!(!achievements.unlock("missing"));
+        assert_eq!(achievements.unlocked_count(), (1, 1));
+
+        achievements.reset("gems");
+        assert_eq!(achievements.progress("gems"), Some((0, 3)));
+    }
+
+    #[test]
+    fn test_events_backend_and_persistence() {
+        let path = std::env::temp_dir().join(format!("anvilkit_achievements_test_{}.ron", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let backend = RecordingBackend::default();
+
+        let mut app = App::new();
+        app.add_plugins(
+            AchievementsPlugin::new(&path)
+                .with(AchievementDef::new("first", "First", "desc"))
+                .with(AchievementDef::new("gems", "Gems", "").with_goal(5))
+                .with(AchievementDef::new("remote", "Remote", ""))
+                .with_backend(backend.clone()),
+        );
+        {
+            let mut achievements = app.world_mut().resource_mut::<Achievements>();
+            achievements.unlock("first");
+            achievements.add_progress("gems", 2);
+        }
+        backend.remote.lock().unwrap().push("remote".into());
+        app.update();
+
+        let unlocked: Vec<String> = app
+            .world_mut()
+            .resource_mut::<Events<AchievementUnlocked>>()
+            .drain()
+            .map(|e| e.id)
+            .collect();
+        assert_eq!(unlocked, vec!["first".to_string(), "remote".to_string()]);
+        let progressed: Vec<_> = app.world_mut().resource_mut::<Events<AchievementProgressed>>().drain().collect();
+        assert_eq!(progressed, vec![AchievementProgressed { id: "gems".into(), current: 2, goal: 5 }]);
+        assert_eq!(
+            *backend.log.lock().unwrap(),
+            vec!["unlock first".to_string(), "progress gems 2/5".to_string(), "unlock remote".to_string()]
+        );
+
+        let mut reloaded = Achievements::with_path(&path);
+        reloaded.load().unwrap();
+        reloaded.register(AchievementDef::new("first", "First", "desc"));
+        reloaded.register(AchievementDef::new("gems", "Gems", "").with_goal(5));
+        assert!(reloaded.is_unlocked("first"));
+        assert_eq!(reloaded.progress("gems"), Some((2, 5)));
+        let _ = std::fs::remove_file(&path);
+    }
+}
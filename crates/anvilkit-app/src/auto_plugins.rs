@@ -14,8 +14,13 @@ use crate::schedule::AnvilKitSchedule;
 
 /// 自动输入插件
 ///
-/// 在 Cleanup 阶段自动调用 `InputState::end_frame()`，
+/// 在 Cleanup 阶段自动调用 `InputState::end_frame()`（存在 `GamepadState` 时一并清除），
 /// 确保 just_pressed / just_released 状态在帧末正确清除。
+/// 同时注册 `KeyboardLayout`（由窗口键盘事件自动识别布局）与 `ComboTriggered` 事件，
+/// 并在动作映射更新后把本帧触发的动作写入 `InputBuffer`、匹配连招。
+/// 手柄震动：注册 `Haptics` 资源（默认空后端）与 `RumbleRequest` 事件，在 PostUpdate 推进震动效果。
+/// 本地多人：插入 `PlayerInputMap` 资源后，每帧检查手柄热插拔（发送 `PlayerDeviceChanged`）
+/// 并更新玩家实体上的 `PlayerActions`。
 ///
 /// # 示例
 ///
@@ -31,9 +36,18 @@ pub struct AutoInputPlugin;
 
 impl Plugin for AutoInputPlugin {
     fn build(&self, app: &mut App) {
-        use anvilkit_input::prelude::InputState;
+        use anvilkit_input::prelude::{InputState, KeyboardLayout};
         app.init_resource::<InputState>();
-        app.add_systems(AnvilKitSchedule::PreUpdate, action_map_update_system);
+        app.init_resource::<KeyboardLayout>();
+        app.add_event::<anvilkit_input::prelude::ComboTriggered>();
+        app.add_event::<anvilkit_input::prelude::PlayerDeviceChanged>();
+        app.add_systems(
+            AnvilKitSchedule::PreUpdate,
+            (action_map_update_system, player_input_system, input_buffer_system).chain(),
+        );
+        app.init_resource::<anvilkit_input::prelude::Haptics>();
+        app.add_event::<anvilkit_input::prelude::RumbleRequest>();
+        app.add_systems(AnvilKitSchedule::PostUpdate, haptics_system);
         app.add_systems(AnvilKitSchedule::Cleanup, input_end_frame_system);
     }
 
@@ -63,9 +77,116 @@ pub fn action_map_update_system(
     }
 }
 
-/// 帧末清除 just_pressed/just_released 状态
-fn input_end_frame_system(mut input: ResMut<anvilkit_input::prelude::InputState>) {
+/// Check gamepad hot-plug and update every `PlayerActions` from the `PlayerInputMap`.
+///
+/// Device assignment changes are sent as `PlayerDeviceChanged` events.
+/// No-op without a `PlayerInputMap`.
+pub fn player_input_system(
+    input: Res<anvilkit_input::prelude::InputState>,
+    gamepads: Option<Res<anvilkit_input::prelude::GamepadState>>,
+    players: Option<ResMut<anvilkit_input::prelude::PlayerInputMap>>,
+    mut actions: Query<&mut anvilkit_input::prelude::PlayerActions>,
+    mut changes: EventWriter<anvilkit_input::prelude::PlayerDeviceChanged>,
+) {
+    let Some(mut players) = players else { return };
+    if let Some(gamepads) = &gamepads {
+        players.update_devices(gamepads);
+    }
+    for change in players.take_events() {
+        changes.send(change);
+    }
+    for mut player in &mut actions {
+        players.evaluate(&mut player, &input, gamepads.as_deref());
+    }
+}
+
+/// Components read by [`input_buffer_system`] on entities with their own `InputBuffer`.
+type InputBufferQuery = (
+    Entity,
+    &'static mut anvilkit_input::prelude::InputBuffer,
+    Option<&'static anvilkit_input::prelude::ComboMatcher>,
+    Option<&'static anvilkit_input::prelude::PlayerActions>,
+);
+
+/// Record just-pressed actions into every `InputBuffer` and emit matched combos.
+///
+/// Updates the `InputBuffer` resource (matched against the `ComboMatcher` resource) and
+/// all entities with an `InputBuffer` component (matched against their own `ComboMatcher`
+/// component, falling back to the resource). Entities with `PlayerActions` record that
+/// player's actions; other buffers record from the `ActionMap` and are skipped without one.
+pub fn input_buffer_system(
+    dt: Option<Res<anvilkit_core::time::DeltaTime>>,
+    action_map: Option<Res<anvilkit_input::prelude::ActionMap>>,
+    buffer: Option<ResMut<anvilkit_input::prelude::InputBuffer>>,
+    matcher: Option<Res<anvilkit_input::prelude::ComboMatcher>>,
+    mut buffers: Query<InputBufferQuery>,
+    mut combos: EventWriter<anvilkit_input::prelude::ComboTriggered>,
+) {
+    use anvilkit_input::prelude::{ComboMatcher, ComboTriggered, InputBuffer, PlayerActions};
+
+    let dt = dt.map_or(0.0, |dt| dt.0);
+    let mut update = |buffer: &mut InputBuffer,
+                      matcher: Option<&ComboMatcher>,
+                      entity: Option<Entity>,
+                      player: Option<&PlayerActions>| {
+        match (player, action_map.as_deref()) {
+            (Some(player), _) => {
+                buffer.advance(dt);
+                buffer.record_player_just_pressed(player);
+            }
+            (None, Some(action_map)) => {
+                buffer.advance(dt);
+                buffer.record_just_pressed(action_map);
+            }
+            (None, None) => return,
+        }
+        if let Some(matcher) = matcher {
+            for combo in matcher.check(buffer) {
+                combos.send(ComboTriggered { entity, combo });
+            }
+        }
+    };
+
+    if let Some(mut buffer) = buffer {
+        update(&mut buffer, matcher.as_deref(), None, None);
+    }
+    for (entity, mut buffer, own, player) in &mut buffers {
+        update(&mut buffer, own.or(matcher.as_deref()), Some(entity), player);
+    }
+}
+
+/// Start requested rumble patterns and advance `Haptics`, pushing intensities to its backend.
+///
+/// Registered by [`AutoInputPlugin`] in `PostUpdate`, so `RumbleRequest`s sent
+/// during `Update` take effect in the same frame.
+pub fn haptics_system(
+    dt: Option<Res<anvilkit_core::time::DeltaTime>>,
+    haptics: Option<ResMut<anvilkit_input::prelude::Haptics>>,
+    mut requests: EventReader<anvilkit_input::prelude::RumbleRequest>,
+) {
+    let Some(mut haptics) = haptics else {
+        requests.clear();
+        return;
+    };
+    for request in requests.read() {
+        if request.queued {
+            haptics.queue(request.gamepad, request.pattern.clone());
+        } else {
+            haptics.play(request.gamepad, request.pattern.clone());
+        }
+    }
+    haptics.update(dt.map_or(0.0, |dt| dt.0));
+}
+
+/// 帧末清除 just_pressed/just_released 状态（键盘鼠标与手柄）
+fn input_end_frame_system(
+    mut input: ResMut<anvilkit_input::prelude::InputState>,
+    gamepads: Option<ResMut<anvilkit_input::prelude::GamepadState>>,
+) {
     input.end_frame();
+    if let Some(mut gamepads) = gamepads {
+        gamepads.end_frame();
+    }
 }
 
 /// 自动时间更新插件
@@ -188,6 +309,115 @@ mod tests {
         assert!(!input.is_key_just_pressed(anvilkit_input::prelude::KeyCode::Space));
     }
 
+    #[test]
+    fn test_input_buffer_combo_events() {
+        use anvilkit_input::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Triggered(Vec<ComboTriggered>);
+
+        fn collect(mut events: EventReader<ComboTriggered>, mut out: ResMut<Triggered>) {
+            out.0.extend(events.read().cloned());
+        }
+
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app.add_plugins(AutoInputPlugin);
+        let mut actions = ActionMap::new();
+        actions.add_binding("down", InputBinding::Key(KeyCode::S));
+        actions.add_binding("forward", InputBinding::Key(KeyCode::D));
+        actions.add_binding("punch", InputBinding::Key(KeyCode::J));
+        app.insert_resource(actions);
+        app.insert_resource(InputBuffer::new(0.5));
+        app.init_resource::<Triggered>();
+        app.add_systems(AnvilKitSchedule::Update, collect);
+        let fighter = app.world_mut().spawn((
+            InputBuffer::new(0.5),
+            ComboMatcher::new().with_combo(Combo::new("fireball", ["down", "forward", "punch"])),
+        )).id();
+
+        for key in [KeyCode::S, KeyCode::D, KeyCode::J] {
+            app.world_mut().resource_mut::<InputState>().press_key(key);
+            app.update();
+        }
+
+        let triggered = &app.world().resource::<Triggered>().0;
+        assert_eq!(triggered, &vec![ComboTriggered { entity: Some(fighter), combo: "fireball".into() }]);
+        // 全局缓冲没有匹配器，只记录输入
+        assert_eq!(app.world().resource::<InputBuffer>().entries().count(), 3);
+    }
+
+    #[test]
+    fn test_player_input_routing() {
+        use anvilkit_input::prelude::*;
+
+        #[derive(Resource, Default)]
+        struct Collected(Vec<PlayerDeviceChanged>, Vec<ComboTriggered>);
+
+        fn collect(
+            mut changes: EventReader<PlayerDeviceChanged>,
+            mut combos: EventReader<ComboTriggered>,
+            mut out: ResMut<Collected>,
+        ) {
+            out.0.extend(changes.read().copied());
+            out.1.extend(combos.read().cloned());
+        }
+
+        let mut bindings = PlayerBindings::new();
+        bindings.bind_key("punch", KeyboardZone::Left, InputBinding::Key(KeyCode::F));
+        bindings.bind_button("punch", GamepadButton::West);
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app.add_plugins(AutoInputPlugin);
+        app.insert_resource(PlayerInputMap::new(2, bindings));
+        app.init_resource::<GamepadState>();
+        app.init_resource::<Collected>();
+        app.add_systems(AnvilKitSchedule::Update, collect);
+        app.world_mut().resource_mut::<PlayerInputMap>().assign(0, InputDevice::Keyboard(KeyboardZone::Left));
+        let double = ComboMatcher::new().with_combo(Combo::new("double", ["punch", "punch"]));
+        let p1 = app.world_mut().spawn((PlayerActions::new(0), InputBuffer::new(1.0), double.clone())).id();
+        let p2 = app.world_mut().spawn((PlayerActions::new(1), InputBuffer::new(1.0), double)).id();
+
+        // 手柄热插拔：连接后加入槽位 1
+        app.world_mut().resource_mut::<GamepadState>().connect(4);
+        app.update();
+        for _ in 0..2 {
+            app.world_mut().resource_mut::<GamepadState>().press_button(4, GamepadButton::West);
+            app.update();
+            app.world_mut().resource_mut::<GamepadState>().release_button(4, GamepadButton::West);
+            app.update();
+        }
+
+        assert!(!app.world().get::<PlayerActions>(p1).unwrap().is_active("punch"));
+        assert!(app.world().get::<PlayerActions>(p2).unwrap().is_just_released("punch"));
+        let collected = app.world().resource::<Collected>();
+        assert_eq!(collected.0, vec![
+            PlayerDeviceChanged { slot: 0, change: DeviceChange::Assigned(InputDevice::Keyboard(KeyboardZone::Left)) },
+            PlayerDeviceChanged { slot: 1, change: DeviceChange::Assigned(InputDevice::Gamepad(4)) },
+        ]);
+        assert_eq!(collected.1, vec![ComboTriggered { entity: Some(p2), combo: "double".into() }]);
+    }
+
+    #[test]
+    fn test_rumble_requests_drive_haptics() {
+        use anvilkit_input::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app.add_plugins(AutoInputPlugin);
+        app.insert_resource(anvilkit_core::time::DeltaTime(0.1));
+        app.world_mut().send_event(RumbleRequest {
+            gamepad: 2,
+            pattern: RumblePattern::constant(Rumble::uniform(0.5), 0.15),
+            queued: false,
+        });
+
+        app.update();
+        assert_eq!(app.world().resource::<Haptics>().intensity(2), Rumble::uniform(0.5));
+        app.update();
+        assert!(!app.world().resource::<Haptics>().is_playing(2));
+    }
+
     #[test]
     fn test_auto_delta_time_plugin_registers_resource() {
         let mut app = App::new();
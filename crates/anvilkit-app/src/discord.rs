@@ -0,0 +1,391 @@
+//! # Discord 富状态
+//!
+//! [`DiscordPresencePlugin`] 把 [`DiscordActivity`] 资源同步到本机 Discord 客户端的 IPC 套接字：
+//!
+//! - 资源变化后在 `Last` 阶段把新状态发给后台线程，IPC 读写不会阻塞游戏帧；
+//! - Discord 未启动或连接断开时，后台线程按指数退避（1s → 60s）重连，
+//!   连上后自动补发最近一次的活动；
+//! - 收到 `AppExit` 或插件资源被移除时清除活动并关闭连接。
+//!
+//! ```rust,ignore
+//! app.add_plugins(DiscordPresencePlugin::new("123456789012345678"));
+//!
+//! fn enter_match(mut activity: ResMut<DiscordActivity>) {
+//!     *activity = DiscordActivity::new()
+//!         .with_details("Ranked match")
+//!         .with_state("In queue")
+//!         .with_party(1, 4)
+//!         .started_now();
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use bevy_app::AppExit;
+use bevy_ecs::prelude::*;
+use discord_rich_presence::{activity, DiscordIpc, DiscordIpcClient};
+
+use crate::ecs_app::{App, Plugin};
+
+/// 首次重连等待时间
+const RECONNECT_MIN: Duration = Duration::from_secs(1);
+/// 重连等待上限
+const RECONNECT_MAX: Duration = Duration::from_secs(60);
+
+/// 当前活动 (Resource)
+///
+/// 所有字段为空时清除 Discord 中的活动显示。
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiscordActivity {
+    /// 第二行文字（如 "In queue"）
+    pub state: Option<String>,
+    /// 第一行文字（如 "Ranked match"）
+    pub details: Option<String>,
+    /// 开始时间（Unix 秒），Discord 显示已进行时间
+    pub start_timestamp: Option<i64>,
+    /// 结束时间（Unix 秒），Discord 显示剩余时间
+    pub end_timestamp: Option<i64>,
+    /// 队伍人数 `(当前, 上限)`
+    pub party: Option<(u32, u32)>,
+    /// 大图资源键（Discord 开发者后台上传的 Art Asset）
+    pub large_image: Option<String>,
+    /// 大图悬停文字
+    pub large_text: Option<String>,
+    /// 小图资源键
+    pub small_image: Option<String>,
+    /// 小图悬停文字
+    pub small_text: Option<String>,
+}
+
+impl DiscordActivity {
+    /// 空活动
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置第二行文字
+    pub fn with_state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// 设置第一行文字
+    pub fn with_details(mut self, details: impl Into<String>) -> Self {
+        self.details = Some(details.into());
+        self
+    }
+
+    /// 设置开始时间（Unix 秒）
+    pub fn with_start(mut self, unix_secs: i64) -> Self {
+        self.start_timestamp = Some(unix_secs);
+        self
+    }
+
+    /// 以当前时间为开始时间
+    pub fn started_now(self) -> Self {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        self.with_start(now)
+    }
+
+    /// 设置结束时间（Unix 秒）
+    pub fn with_end(mut self, unix_secs: i64) -> Self {
+        self.end_timestamp = Some(unix_secs);
+        self
+    }
+
+    /// 设置队伍人数
+    pub fn with_party(mut self, current: u32, max: u32) -> Self {
+        self.party = Some((current, max));
+        self
+    }
+
+    /// 设置大图及悬停文字
+    pub fn with_large_image(mut self, key: impl Into<String>, text: impl Into<String>) -> Self {
+        self.large_image = Some(key.into());
+        self.large_text = Some(text.into());
+        self
+    }
+
+    /// 设置小图及悬停文字
+    pub fn with_small_image(mut self, key: impl Into<String>, text: impl Into<String>) -> Self {
+        self.small_image = Some(key.into());
+        self.small_text = Some(text.into());
+        self
+    }
+
+    /// 是否没有任何内容
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 转换为 IPC 载荷（借用自身字符串）
+    fn to_ipc(&self) -> activity::Activity<'_> {
+        let mut payload = activity::Activity::new();
+        if let Some(state) = &self.state {
+            payload = payload.state(state);
+        }
+        if let Some(details) = &self.details {
+            payload = payload.details(details);
+        }
+        if self.start_timestamp.is_some() || self.end_timestamp.is_some() {
+            let mut timestamps = activity::Timestamps::new();
+            if let Some(start) = self.start_timestamp {
+                timestamps = timestamps.start(start);
+            }
+            if let Some(end) = self.end_timestamp {
+                timestamps = timestamps.end(end);
+            }
+            payload = payload.timestamps(timestamps);
+        }
+        if let Some((current, max)) = self.party {
+            // Discord 要求当前人数不超过上限
+            let max = max.max(current).min(i32::MAX as u32) as i32;
+            let current = current.min(max as u32) as i32;
+            payload = payload.party(activity::Party::new().size([current, max]));
+        }
+        let mut assets = activity::Assets::new();
+        let mut has_assets = false;
+        if let Some(key) = &self.large_image {
+            assets = assets.large_image(key);
+            has_assets = true;
+        }
+        if let Some(text) = &self.large_text {
+            assets = assets.large_text(text);
+            has_assets = true;
+        }
+        if let Some(key) = &self.small_image {
+            assets = assets.small_image(key);
+            has_assets = true;
+        }
+        if let Some(text) = &self.small_text {
+            assets = assets.small_text(text);
+            has_assets = true;
+        }
+        if has_assets {
+            payload = payload.assets(assets);
+        }
+        payload
+    }
+}
+
+/// 指数退避：每次失败等待时间翻倍，直到上限；连接成功后复位
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectBackoff {
+    next: Duration,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self { next: RECONNECT_MIN }
+    }
+}
+
+impl ReconnectBackoff {
+    /// 本次失败后应等待的时间
+    pub fn fail(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(RECONNECT_MAX);
+        delay
+    }
+
+    /// 连接成功，复位等待时间
+    pub fn reset(&mut self) {
+        self.next = RECONNECT_MIN;
+    }
+}
+
+enum PresenceCommand {
+    Set(DiscordActivity),
+    Shutdown,
+}
+
+/// 后台 IPC 线程句柄 (Resource)
+///
+/// 资源被移除或 App 销毁时关闭连接并等待线程退出。
+#[derive(Resource)]
+pub struct DiscordPresence {
+    sender: Sender<PresenceCommand>,
+    connected: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DiscordPresence {
+    /// 启动后台线程
+    pub fn start(client_id: impl Into<String>) -> Self {
+        let client_id = client_id.into();
+        let (sender, receiver) = channel::<PresenceCommand>();
+        let connected = Arc::new(AtomicBool::new(false));
+        let flag = connected.clone();
+
+        let thread = std::thread::Builder::new()
+            .name("discord-presence".into())
+            .spawn(move || {
+                let mut client = match DiscordIpcClient::new(&client_id) {
+                    Ok(client) => client,
+                    Err(e) => {
+                        log::warn!("Discord 客户端创建失败: {}", e);
+                        return;
+                    }
+                };
+                let mut backoff = ReconnectBackoff::default();
+                // None：已同步，阻塞等待下一条命令
+                let mut wait = Some(Duration::ZERO);
+                let mut latest: Option<DiscordActivity> = None;
+                // 最新活动是否已成功发送
+                let mut synced = true;
+
+                loop {
+                    let command = match wait {
+                        Some(timeout) => receiver.recv_timeout(timeout),
+                        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                    };
+                    match command {
+                        Ok(PresenceCommand::Set(next)) => {
+                            latest = Some(next);
+                            synced = false;
+                        }
+                        Ok(PresenceCommand::Shutdown) | Err(RecvTimeoutError::Disconnected) => break,
+                        Err(RecvTimeoutError::Timeout) => {}
+                    }
+
+                    if !flag.load(Ordering::Relaxed) {
+                        match client.connect() {
+                            Ok(()) => {
+                                log::info!("已连接 Discord");
+                                flag.store(true, Ordering::Relaxed);
+                                backoff.reset();
+                                synced = false;
+                            }
+                            Err(e) => {
+                                let delay = backoff.fail();
+                                log::debug!("连接 Discord 失败，{:?} 后重试: {}", delay, e);
+                                wait = Some(delay);
+                                continue;
+                            }
+                        }
+                    }
+
+                    if !synced {
+                        let result = match &latest {
+                            Some(next) if !next.is_empty() => client.set_activity(next.to_ipc()),
+                            _ => client.clear_activity(),
+                        };
+                        if let Err(e) = result {
+                            log::warn!("Discord 连接已断开: {}", e);
+                            flag.store(false, Ordering::Relaxed);
+                            let _ = client.close();
+                            wait = Some(backoff.fail());
+                            continue;
+                        }
+                        synced = true;
+                    }
+                    wait = None;
+                }
+
+                if flag.swap(false, Ordering::Relaxed) {
+                    let _ = client.clear_activity();
+                    let _ = client.close();
+                }
+            })
+            .ok();
+        if thread.is_none() {
+            log::warn!("无法启动 Discord 富状态线程");
+        }
+
+        Self { sender, connected, thread }
+    }
+
+    /// 是否已连接到 Discord 客户端
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// 发送活动（通常由 [`discord_activity_system`] 调用）
+    pub fn update(&self, activity: &DiscordActivity) {
+        let _ = self.sender.send(PresenceCommand::Set(activity.clone()));
+    }
+}
+
+impl Drop for DiscordPresence {
+    fn drop(&mut self) {
+        let _ = self.sender.send(PresenceCommand::Shutdown);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// 活动变化时发送给后台线程 (Last)
+pub fn discord_activity_system(activity: Res<DiscordActivity>, presence: Option<Res<DiscordPresence>>) {
+    let Some(presence) = presence else { return };
+    if activity.is_changed() {
+        presence.update(&activity);
+    }
+}
+
+/// 收到 `AppExit` 后关闭连接 (Last)
+pub fn discord_shutdown_system(world: &mut World) {
+    let exiting = world.get_resource::<Events<AppExit>>().is_some_and(|events| !events.is_empty());
+    if exiting && world.remove_resource::<DiscordPresence>().is_some() {
+        log::info!("关闭 Discord 富状态");
+    }
+}
+
+/// Discord 富状态插件
+pub struct DiscordPresencePlugin {
+    /// Discord 开发者后台中应用的 Client ID
+    pub client_id: String,
+}
+
+impl DiscordPresencePlugin {
+    /// 指定 Client ID
+    pub fn new(client_id: impl Into<String>) -> Self {
+        Self { client_id: client_id.into() }
+    }
+}
+
+impl Plugin for DiscordPresencePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiscordActivity>()
+            .insert_resource(DiscordPresence::start(self.client_id.clone()))
+            .add_systems(bevy_app::Last, (discord_activity_system, discord_shutdown_system).chain());
+    }
+
+    fn name(&self) -> &str {
+        "DiscordPresencePlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_backoff() {
+        let mut backoff = ReconnectBackoff::default();
+        let delays: Vec<u64> = (0..8).map(|_| backoff.fail().as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 32, 60, 60]);
+        backoff.reset();
+        assert_eq!(backoff.fail(), RECONNECT_MIN);
+    }
+
+    #[test]
+    fn test_activity_builder() {
+        let activity = DiscordActivity::new()
+            .with_details("Ranked match")
+            .with_state("In queue")
+            .with_party(1, 4)
+            .with_start(1_700_000_000);
+        assert!(!activity.is_empty());
+        assert_eq!(activity.party, Some((1, 4)));
+        assert_eq!(activity.start_timestamp, Some(1_700_000_000));
+        assert!(DiscordActivity::new().is_empty());
+    }
+}
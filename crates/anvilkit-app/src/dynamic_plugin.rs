@@ -0,0 +1,430 @@
+//! # 动态插件
+//!
+//! 运行时从动态库加载插件（编辑器工具、调试面板等），无需重新编译游戏本体。
+//!
+//! 插件 crate 以 `crate-type = ["cdylib"]` 编译，通过 [`export_plugin!`](crate::export_plugin)
+//! 导出一个 C ABI 的 [`PluginDeclaration`]；宿主启用 `dynamic_plugins` feature 后
+//! 调用 [`DynamicPluginAppExt::load_dynamic_plugin`] 加载。
+//!
+//! ## 握手
+//!
+//! 加载时依次校验：
+//!
+//! 1. [`PLUGIN_ABI_VERSION`] — 声明结构体布局的版本
+//! 2. [`ENGINE_VERSION`] — 插件编译时链接的引擎版本
+//! 3. 构建指纹 — 由 `App`/`World` 的 `TypeId` 计算，编译器或 ECS 依赖版本不一致时不同
+//!
+//! 插件与宿主交换的是 Rust 类型（`App`、组件、系统），因此必须使用同一编译器和依赖版本构建，
+//! 构建指纹用于在调用插件代码之前发现这种不一致。
+//!
+//! ## 插件端示例
+//!
+//! ```rust
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_app::dynamic_plugin::PluginRegistrar;
+//!
+//! #[derive(Component)]
+//! struct Inspected;
+//!
+//! fn inspector_system(query: Query<&Inspected>) {
+//!     let _ = query.iter().count();
+//! }
+//!
+//! fn register(registrar: &mut PluginRegistrar) {
+//!     registrar
+//!         .register_component::<Inspected>()
+//!         .add_systems(AnvilKitSchedule::Update, inspector_system);
+//! }
+//!
+//! anvilkit_app::export_plugin!("inspector", register);
+//! ```
+//!
+//! 已加载的库不会被卸载：插件注册的系统与组件元数据指向库中的代码，
+//! 在 `App` 销毁前卸载会导致悬垂的函数指针。
+
+use std::any::{type_name, TypeId};
+use std::collections::hash_map::DefaultHasher;
+use std::ffi::{c_char, CStr};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ScheduleLabel;
+use anvilkit_core::error::{AnvilKitError, Result};
+
+use crate::ecs_app::App;
+use crate::ecs_plugin::Plugin;
+
+/// 插件声明结构体的 ABI 版本，布局变化时递增
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// 引擎版本（插件必须与宿主一致）
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[doc(hidden)]
+pub const ENGINE_VERSION_NUL: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// 动态库中导出的声明符号名
+pub const PLUGIN_DECLARATION_SYMBOL: &str = "ANVILKIT_PLUGIN_DECLARATION";
+
+/// 插件声明（C ABI）
+///
+/// 由 [`export_plugin!`](crate::export_plugin) 生成，不应手动构造。
+#[repr(C)]
+pub struct PluginDeclaration {
+    /// 声明布局版本，见 [`PLUGIN_ABI_VERSION`]
+    pub abi_version: u32,
+    /// 以 NUL 结尾的引擎版本字符串
+    pub engine_version: *const c_char,
+    /// 以 NUL 结尾的插件名称
+    pub name: *const c_char,
+    /// 插件侧计算的构建指纹，见 [`build_fingerprint`]
+    pub build_fingerprint: extern "C" fn() -> u64,
+    /// 注册入口；插件代码 panic 时返回 `false`
+    pub register: unsafe extern "C" fn(registrar: *mut PluginRegistrar<'_>) -> bool,
+}
+
+// SAFETY: 声明只包含指向静态字符串的指针和函数指针，均不可变
+unsafe impl Sync for PluginDeclaration {}
+
+/// 当前构建的指纹
+///
+/// 由 ECS 核心类型的 `TypeId` 与引擎版本计算；编译器、`bevy_ecs` 或引擎版本不同时结果不同。
+pub fn build_fingerprint() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    TypeId::of::<App>().hash(&mut hasher);
+    TypeId::of::<World>().hash(&mut hasher);
+    TypeId::of::<PluginRegistrar<'static>>().hash(&mut hasher);
+    ENGINE_VERSION.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 导出动态插件声明
+///
+/// `$register` 是签名为 `fn(&mut PluginRegistrar)` 的函数。
+#[macro_export]
+macro_rules! export_plugin {
+    ($name:literal, $register:path) => {
+        #[no_mangle]
+        pub static ANVILKIT_PLUGIN_DECLARATION: $crate::dynamic_plugin::PluginDeclaration =
+            $crate::dynamic_plugin::PluginDeclaration {
+                abi_version: $crate::dynamic_plugin::PLUGIN_ABI_VERSION,
+                engine_version: $crate::dynamic_plugin::ENGINE_VERSION_NUL.as_ptr() as *const ::std::ffi::c_char,
+                name: concat!($name, "\0").as_ptr() as *const ::std::ffi::c_char,
+                build_fingerprint: {
+                    extern "C" fn __anvilkit_fingerprint() -> u64 {
+                        $crate::dynamic_plugin::build_fingerprint()
+                    }
+                    __anvilkit_fingerprint
+                },
+                register: {
+                    unsafe extern "C" fn __anvilkit_register(
+                        registrar: *mut $crate::dynamic_plugin::PluginRegistrar<'_>,
+                    ) -> bool {
+                        // SAFETY: 宿主保证指针在调用期间有效且独占
+                        let registrar = unsafe { &mut *registrar };
+                        ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| $register(registrar))).is_ok()
+                    }
+                    __anvilkit_register
+                },
+            };
+    };
+}
+
+/// 已加载插件的注册信息
+#[derive(Debug, Clone)]
+pub struct DynamicPluginInfo {
+    /// 插件名称
+    pub name: String,
+    /// 动态库路径
+    pub path: PathBuf,
+    /// 注册的组件（类型名, ComponentId）
+    pub components: Vec<(String, ComponentId)>,
+    /// 注册的系统组数量
+    pub system_sets: usize,
+    /// 添加的子插件名称
+    pub plugins: Vec<String>,
+}
+
+/// 已加载动态插件的登记表
+#[derive(Resource, Debug, Default)]
+pub struct DynamicPlugins {
+    plugins: Vec<DynamicPluginInfo>,
+}
+
+impl DynamicPlugins {
+    /// 全部已加载插件
+    pub fn iter(&self) -> impl Iterator<Item = &DynamicPluginInfo> {
+        self.plugins.iter()
+    }
+
+    /// 按名称查找
+    pub fn get(&self, name: &str) -> Option<&DynamicPluginInfo> {
+        self.plugins.iter().find(|p| p.name == name)
+    }
+
+    /// 已加载插件数量
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// 是否没有加载任何插件
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+}
+
+/// 插件注册器
+///
+/// 传给插件的注册函数，插件通过它向宿主 `App` 注册组件、资源、系统和子插件，
+/// 注册内容同时记录到 [`DynamicPlugins`]。
+pub struct PluginRegistrar<'a> {
+    app: &'a mut App,
+    info: DynamicPluginInfo,
+}
+
+impl PluginRegistrar<'_> {
+    /// 注册组件类型
+    pub fn register_component<T: Component>(&mut self) -> &mut Self {
+        let id = self.app.world_mut().register_component::<T>();
+        self.info.components.push((type_name::<T>().to_string(), id));
+        self
+    }
+
+    /// 初始化资源
+    pub fn init_resource<R: Resource + FromWorld>(&mut self) -> &mut Self {
+        self.app.init_resource::<R>();
+        self
+    }
+
+    /// 添加系统
+    pub fn add_systems<M>(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        systems: impl IntoSystemConfigs<M>,
+    ) -> &mut Self {
+        self.app.add_systems(schedule, systems);
+        self.info.system_sets += 1;
+        self
+    }
+
+    /// 添加子插件
+    pub fn add_plugin(&mut self, plugin: impl Plugin) -> &mut Self {
+        self.info.plugins.push(plugin.name().to_string());
+        self.app.add_plugins(plugin);
+        self
+    }
+
+    /// 直接访问宿主 `App`（绕过登记，仅用于注册器未覆盖的操作）
+    pub fn app(&mut self) -> &mut App {
+        self.app
+    }
+}
+
+/// 读取声明中的 C 字符串
+///
+/// # Safety
+///
+/// `ptr` 必须为空或指向以 NUL 结尾的有效字符串。
+unsafe fn read_c_str(ptr: *const c_char) -> Option<String> {
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: 由调用者保证
+    Some(unsafe { CStr::from_ptr(ptr) }.to_string_lossy().into_owned())
+}
+
+/// 校验插件声明并执行注册
+///
+/// # Safety
+///
+/// `declaration` 中的指针必须有效（由 [`export_plugin!`](crate::export_plugin) 生成即满足），
+/// 且声明所在的代码在 `app` 销毁前保持加载。
+pub unsafe fn install_plugin(app: &mut App, declaration: &PluginDeclaration, path: &Path) -> Result<()> {
+    let context = format!("动态插件 '{}'", path.display());
+
+    if declaration.abi_version != PLUGIN_ABI_VERSION {
+        return Err(AnvilKitError::generic(format!(
+            "插件 ABI 版本 {} 与宿主 {} 不一致",
+            declaration.abi_version, PLUGIN_ABI_VERSION
+        ))
+        .with_context(context));
+    }
+
+    // SAFETY: ABI 版本一致，字段布局可信；指针有效性由调用者保证
+    let engine_version = unsafe { read_c_str(declaration.engine_version) }.unwrap_or_default();
+    if engine_version != ENGINE_VERSION {
+        return Err(AnvilKitError::generic(format!(
+            "插件基于引擎 {} 构建，宿主为 {}",
+            engine_version, ENGINE_VERSION
+        ))
+        .with_context(context));
+    }
+
+    if (declaration.build_fingerprint)() != build_fingerprint() {
+        return Err(AnvilKitError::generic("插件与宿主的编译器或依赖版本不一致，请使用相同工具链重新构建")
+            .with_context(context));
+    }
+
+    // SAFETY: 同上
+    let name = unsafe { read_c_str(declaration.name) }
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| AnvilKitError::generic("插件名称为空").with_context(context.clone()))?;
+
+    if app.world().get_resource::<DynamicPlugins>().is_some_and(|p| p.get(&name).is_some()) {
+        return Err(AnvilKitError::generic(format!("插件 '{}' 已加载", name)).with_context(context));
+    }
+
+    let mut registrar = PluginRegistrar {
+        app,
+        info: DynamicPluginInfo {
+            name,
+            path: path.to_path_buf(),
+            components: Vec::new(),
+            system_sets: 0,
+            plugins: Vec::new(),
+        },
+    };
+    // SAFETY: registrar 在调用期间有效且独占
+    let ok = unsafe { (declaration.register)(&mut registrar) };
+    let PluginRegistrar { app, info } = registrar;
+    if !ok {
+        return Err(AnvilKitError::generic(format!("插件 '{}' 注册时 panic", info.name)).with_context(context));
+    }
+
+    log::info!(
+        "已加载动态插件 '{}': {} 个组件, {} 组系统",
+        info.name, info.components.len(), info.system_sets
+    );
+    app.world_mut().get_resource_or_insert_with(DynamicPlugins::default).plugins.push(info);
+    Ok(())
+}
+
+/// 动态插件加载扩展
+#[cfg(feature = "dynamic_plugins")]
+pub trait DynamicPluginAppExt {
+    /// 从动态库加载插件
+    ///
+    /// # Safety
+    ///
+    /// 加载动态库会执行其中的任意代码，调用者必须信任该库，
+    /// 且该库由 [`export_plugin!`](crate::export_plugin) 导出声明。
+    unsafe fn load_dynamic_plugin(&mut self, path: impl AsRef<Path>) -> Result<&mut Self>;
+}
+
+#[cfg(feature = "dynamic_plugins")]
+impl DynamicPluginAppExt for App {
+    unsafe fn load_dynamic_plugin(&mut self, path: impl AsRef<Path>) -> Result<&mut Self> {
+        let path = path.as_ref();
+        let context = || format!("动态插件 '{}'", path.display());
+
+        // SAFETY: 由调用者保证库可信
+        let library = unsafe { libloading::Library::new(path) }
+            .map_err(|e| AnvilKitError::generic(format!("无法加载动态库: {}", e)).with_context(context()))?;
+
+        let declaration: &'static PluginDeclaration = {
+            // SAFETY: 符号由 export_plugin! 导出，类型为 PluginDeclaration
+            let symbol = unsafe { library.get::<*const PluginDeclaration>(PLUGIN_DECLARATION_SYMBOL.as_bytes()) }
+                .map_err(|e| AnvilKitError::generic(format!("缺少插件声明符号: {}", e)).with_context(context()))?;
+            // SAFETY: 库随后被泄漏，声明在进程生命周期内有效
+            unsafe { &**symbol }
+        };
+
+        // 插件代码可能已被注册到 App（即便注册失败），库必须常驻
+        std::mem::forget(library);
+        // SAFETY: 声明由 export_plugin! 生成，库已常驻
+        unsafe { install_plugin(self, declaration, path)? };
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs_plugin::AnvilKitEcsPlugin;
+    use crate::schedule::AnvilKitSchedule;
+
+    #[derive(Component)]
+    struct Probe;
+
+    #[derive(Resource, Default)]
+    struct Ticks(u32);
+
+    fn tick(mut ticks: ResMut<Ticks>) {
+        ticks.0 += 1;
+    }
+
+    fn register(registrar: &mut PluginRegistrar) {
+        registrar
+            .register_component::<Probe>()
+            .init_resource::<Ticks>()
+            .add_systems(AnvilKitSchedule::Update, tick);
+    }
+
+    crate::export_plugin!("test_plugin", register);
+
+    fn panicking(_: &mut PluginRegistrar) {
+        panic!("register failed");
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app
+    }
+
+    #[test]
+    fn test_install_registers_and_records() {
+        let mut app = test_app();
+        unsafe { install_plugin(&mut app, &ANVILKIT_PLUGIN_DECLARATION, Path::new("test_plugin.so")) }.unwrap();
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+        let plugins = app.world().resource::<DynamicPlugins>();
+        let info = plugins.get("test_plugin").unwrap();
+        assert_eq!(info.system_sets, 1);
+        assert_eq!(info.components.len(), 1);
+        assert!(info.components[0].0.ends_with("Probe"));
+
+        // 同名插件不能重复加载
+        assert!(unsafe { install_plugin(&mut app, &ANVILKIT_PLUGIN_DECLARATION, Path::new("again.so")) }.is_err());
+    }
+
+    #[test]
+    fn test_handshake_rejects_mismatch() {
+        extern "C" fn other_fingerprint() -> u64 {
+            build_fingerprint().wrapping_add(1)
+        }
+
+        let mut app = test_app();
+        let old_abi = PluginDeclaration { abi_version: PLUGIN_ABI_VERSION + 1, ..ANVILKIT_PLUGIN_DECLARATION };
+        let err = unsafe { install_plugin(&mut app, &old_abi, Path::new("p.so")) }.unwrap_err();
+        assert!(err.to_string().contains("ABI"));
+
+        let old_engine = PluginDeclaration {
+            engine_version: "0.0.0-old\0".as_ptr() as *const c_char,
+            ..ANVILKIT_PLUGIN_DECLARATION
+        };
+        assert!(unsafe { install_plugin(&mut app, &old_engine, Path::new("p.so")) }.is_err());
+
+        let other_build = PluginDeclaration { build_fingerprint: other_fingerprint, ..ANVILKIT_PLUGIN_DECLARATION };
+        assert!(unsafe { install_plugin(&mut app, &other_build, Path::new("p.so")) }.is_err());
+
+        assert!(app.world().get_resource::<DynamicPlugins>().is_none());
+    }
+
+    #[test]
+    fn test_register_panic_is_reported() {
+        unsafe extern "C" fn register_panicking(registrar: *mut PluginRegistrar<'_>) -> bool {
+            let registrar = unsafe { &mut *registrar };
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| panicking(registrar))).is_ok()
+        }
+
+        let mut app = test_app();
+        let declaration = PluginDeclaration { register: register_panicking, ..ANVILKIT_PLUGIN_DECLARATION };
+        let err = unsafe { install_plugin(&mut app, &declaration, Path::new("p.so")) }.unwrap_err();
+        assert!(err.to_string().contains("panic"));
+    }
+}
@@ -38,6 +38,7 @@ impl AnvilKitEcsPlugin {
         app.init_schedule(AnvilKitSchedule::Startup);
         app.init_schedule(AnvilKitSchedule::Main);
         app.init_schedule(AnvilKitSchedule::PreUpdate);
+        app.init_schedule(AnvilKitSchedule::StateTransition);
         app.init_schedule(AnvilKitSchedule::FixedUpdate);
         app.init_schedule(AnvilKitSchedule::Update);
         app.init_schedule(AnvilKitSchedule::PostUpdate);
@@ -46,7 +47,8 @@ impl AnvilKitEcsPlugin {
         {
             let mut order = app.world_mut().resource_mut::<MainScheduleOrder>();
             order.insert_after(bevy_app::PreUpdate, AnvilKitSchedule::PreUpdate);
-            order.insert_after(AnvilKitSchedule::PreUpdate, AnvilKitSchedule::FixedUpdate);
+            order.insert_after(AnvilKitSchedule::PreUpdate, AnvilKitSchedule::StateTransition);
+            order.insert_after(AnvilKitSchedule::StateTransition, AnvilKitSchedule::FixedUpdate);
             order.insert_after(bevy_app::Update, AnvilKitSchedule::Update);
             order.insert_after(bevy_app::PostUpdate, AnvilKitSchedule::PostUpdate);
             order.insert_after(bevy_app::Last, AnvilKitSchedule::Cleanup);
@@ -0,0 +1,340 @@
+//! # 实体池
+//!
+//! 为子弹、粒子等高频生成 / 销毁的实体提供复用：释放的实体不会被 despawn，
+//! 而是就地把 [`Pooled`] 标记为非活跃并设为 `Visibility::Hidden`，渲染与拾取因此跳过它。
+//! 池中实体始终带有 [`Pooled`] 与 `Visibility`，生成与释放只覆盖已有组件的值，
+//! 不会在 archetype 之间迁移。
+//!
+//! 下次生成时重新插入模板 Bundle 以重置组件值；活跃期间追加的组件会保留，
+//! 需要复位的组件可通过 [`EntityPool::with_reset`] 按类型注册重置钩子。
+//!
+//! 游戏系统查询时应通过 [`Pooled::is_active`] 跳过池中的非活跃实体。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_app::entity_pool::{EntityPool, Pooled};
+//!
+//! #[derive(Component, Clone)]
+//! struct Bullet { ttl: f32 }
+//!
+//! #[derive(Component)]
+//! struct Hits(u32);
+//!
+//! let mut world = World::new();
+//! let mut pool = EntityPool::new(Bullet { ttl: 2.0 }).with_reset::<Hits>(|hits| hits.0 = 0);
+//!
+//! let e = pool.spawn_in(&mut world);
+//! world.get_mut::<Bullet>(e).unwrap().ttl = 0.0;
+//! world.entity_mut(e).insert(Hits(3));
+//! pool.release_in(&mut world, e);
+//! assert!(!world.get::<Pooled>(e).unwrap().is_active());
+//!
+//! // 复用同一实体，模板组件与注册了钩子的组件均已重置
+//! assert_eq!(pool.spawn_in(&mut world), e);
+//! assert_eq!(world.get::<Bullet>(e).unwrap().ttl, 2.0);
+//! assert_eq!(world.get::<Hits>(e).unwrap().0, 0);
+//! assert_eq!(pool.stats().reused, 1);
+//! ```
+
+use std::any::TypeId;
+use std::fmt;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::EntityCommands;
+use anvilkit_core::collections::PoolStats;
+use anvilkit_render::component::Visibility;
+
+/// 池管理的实体状态
+///
+/// 由 [`EntityPool`] 在首次生成时插入，此后只切换活跃标记，不会被移除。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pooled {
+    active: bool,
+}
+
+impl Pooled {
+    const ACTIVE: Self = Self { active: true };
+    const INACTIVE: Self = Self { active: false };
+
+    /// 实体是否已被生成使用（`false` 表示位于池中）
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+/// 复用实体时对某类组件执行的重置
+type ResetHook = Box<dyn Fn(&mut EntityWorldMut) + Send + Sync>;
+
+/// 实体池资源
+///
+/// `B` 为生成 / 重置时插入的模板 Bundle，每种模板对应一个独立的池资源。
+#[derive(Resource)]
+pub struct EntityPool<B: Bundle + Clone> {
+    template: B,
+    free: Vec<Entity>,
+    max_size: Option<usize>,
+    stats: PoolStats,
+    /// 每种组件类型至多一个重置钩子；`Arc` 使 `Commands` 路径无需复制钩子表
+    resets: Arc<Vec<(TypeId, ResetHook)>>,
+}
+
+impl<B: Bundle + Clone> EntityPool<B> {
+    /// 以模板 Bundle 创建实体池
+    pub fn new(template: B) -> Self {
+        Self {
+            template,
+            free: Vec::new(),
+            max_size: None,
+            stats: PoolStats::default(),
+            resets: Arc::default(),
+        }
+    }
+
+    /// 限制池中非活跃实体数量，超出时释放的实体会被直接 despawn
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// 注册组件 `C` 的重置钩子，复用实体时在重新插入模板之后对其调用
+    ///
+    /// 用于活跃期间追加、模板之外的组件；实体没有该组件时跳过。
+    /// 同一类型重复注册时替换旧钩子。需在池投入使用前完成注册。
+    pub fn with_reset<C: Component>(mut self, reset: impl Fn(&mut C) + Send + Sync + 'static) -> Self {
+        let resets = Arc::get_mut(&mut self.resets).expect("重置钩子须在实体池投入使用前注册");
+        let hook: ResetHook = Box::new(move |entity| {
+            if let Some(mut component) = entity.get_mut::<C>() {
+                reset(&mut component);
+            }
+        });
+        match resets.iter_mut().find(|(id, _)| *id == TypeId::of::<C>()) {
+            Some(slot) => slot.1 = hook,
+            None => resets.push((TypeId::of::<C>(), hook)),
+        }
+        self
+    }
+
+    /// 模板 Bundle
+    pub fn template(&self) -> &B {
+        &self.template
+    }
+
+    /// 池中非活跃实体数量
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// 复用统计
+    pub fn stats(&self) -> PoolStats {
+        self.stats
+    }
+
+    /// 生成实体（优先复用池中实体），返回可继续插入组件的 `EntityCommands`
+    pub fn spawn<'a>(&mut self, commands: &'a mut Commands) -> EntityCommands<'a> {
+        while let Some(entity) = self.free.pop() {
+            // 池中实体可能已被外部 despawn
+            if commands.get_entity(entity).is_some() {
+                self.stats.reused += 1;
+                let mut ec = commands.entity(entity);
+                ec.insert((Pooled::ACTIVE, Visibility::default())).insert(self.template.clone());
+                if !self.resets.is_empty() {
+                    let resets = Arc::clone(&self.resets);
+                    ec.queue(move |mut entity: EntityWorldMut| run_resets(&resets, &mut entity));
+                }
+                return ec;
+            }
+        }
+        self.stats.created += 1;
+        let mut ec = commands.spawn(self.template.clone());
+        ec.insert_if_new((Pooled::ACTIVE, Visibility::default()));
+        ec
+    }
+
+    /// 将实体归还到池中
+    pub fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        let Some(mut ec) = commands.get_entity(entity) else { return };
+        if self.is_full() {
+            self.stats.dropped += 1;
+            ec.despawn();
+            return;
+        }
+        self.stats.released += 1;
+        ec.insert((Pooled::INACTIVE, Visibility::Hidden));
+        self.free.push(entity);
+    }
+
+    /// 直接在 World 上生成实体（独占系统 / 测试使用）
+    pub fn spawn_in(&mut self, world: &mut World) -> Entity {
+        while let Some(entity) = self.free.pop() {
+            if let Ok(mut e) = world.get_entity_mut(entity) {
+                self.stats.reused += 1;
+                e.insert((Pooled::ACTIVE, Visibility::default()));
+                e.insert(self.template.clone());
+                run_resets(&self.resets, &mut e);
+                return entity;
+            }
+        }
+        self.stats.created += 1;
+        world.spawn(self.template.clone()).insert_if_new((Pooled::ACTIVE, Visibility::default())).id()
+    }
+
+    /// 直接在 World 上归还实体
+    pub fn release_in(&mut self, world: &mut World, entity: Entity) {
+        let Ok(mut e) = world.get_entity_mut(entity) else { return };
+        if self.is_full() {
+            self.stats.dropped += 1;
+            e.despawn();
+            return;
+        }
+        self.stats.released += 1;
+        e.insert((Pooled::INACTIVE, Visibility::Hidden));
+        self.free.push(entity);
+    }
+
+    /// 预先生成 `count` 个非活跃实体
+    pub fn prewarm(&mut self, world: &mut World, count: usize) {
+        let target = self.max_size.map_or(count, |m| count.min(m));
+        while self.free.len() < target {
+            let mut e = world.spawn(self.template.clone());
+            e.insert((Pooled::INACTIVE, Visibility::Hidden));
+            self.stats.created += 1;
+            self.free.push(e.id());
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.max_size.is_some_and(|m| self.free.len() >= m)
+    }
+}
+
+impl<B: Bundle + Clone + fmt::Debug> fmt::Debug for EntityPool<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EntityPool")
+            .field("template", &self.template)
+            .field("free", &self.free)
+            .field("max_size", &self.max_size)
+            .field("stats", &self.stats)
+            .field("resets", &self.resets.len())
+            .finish()
+    }
+}
+
+fn run_resets(resets: &[(TypeId, ResetHook)], entity: &mut EntityWorldMut) {
+    for (_, reset) in resets {
+        reset(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Health(i32);
+
+    #[derive(Component)]
+    struct Extra;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Charge(u32);
+
+    #[test]
+    fn test_reuse_resets_components_in_place() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new(Health(10)).with_reset::<Charge>(|charge| charge.0 = 0);
+
+        let a = pool.spawn_in(&mut world);
+        assert!(world.get::<Pooled>(a).unwrap().is_active());
+        world.get_mut::<Health>(a).unwrap().0 = 1;
+        world.entity_mut(a).insert(Charge(7));
+        let archetype = world.entity(a).archetype().id();
+
+        pool.release_in(&mut world, a);
+        assert_eq!(world.get::<Visibility>(a), Some(&Visibility::Hidden));
+        assert_eq!(world.entity(a).archetype().id(), archetype);
+        let live = world.query::<&Pooled>().iter(&world).filter(|p| p.is_active()).count();
+        assert_eq!(live, 0);
+
+        let b = pool.spawn_in(&mut world);
+        assert_eq!(a, b);
+        assert_eq!(world.entity(b).archetype().id(), archetype);
+        assert_eq!(world.get::<Health>(b), Some(&Health(10)));
+        assert_eq!(world.get::<Charge>(b), Some(&Charge(0)));
+        assert!(world.get::<Pooled>(b).unwrap().is_active());
+        assert_eq!(world.get::<Visibility>(b), Some(&Visibility::Visible));
+        assert_eq!(pool.stats().reuse_rate(), 0.5);
+    }
+
+    #[test]
+    fn test_template_visibility_is_kept() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new((Health(1), Visibility::Inherited));
+        let e = pool.spawn_in(&mut world);
+        assert_eq!(world.get::<Visibility>(e), Some(&Visibility::Inherited));
+        pool.release_in(&mut world, e);
+        pool.spawn_in(&mut world);
+        assert_eq!(world.get::<Visibility>(e), Some(&Visibility::Inherited));
+    }
+
+    #[test]
+    fn test_commands_spawn_runs_reset_hooks() {
+        let mut world = World::new();
+        world.insert_resource(EntityPool::new(Health(3)).with_reset::<Charge>(|charge| charge.0 = 0));
+        let e = world.resource_scope(|world, mut pool: Mut<EntityPool<Health>>| {
+            let e = pool.spawn_in(world);
+            world.entity_mut(e).insert(Charge(9));
+            pool.release_in(world, e);
+            e
+        });
+
+        let spawn = |mut commands: Commands, mut pool: ResMut<EntityPool<Health>>| {
+            pool.spawn(&mut commands);
+        };
+        world.run_system_once(spawn).unwrap();
+        assert_eq!(world.get::<Charge>(e), Some(&Charge(0)));
+        assert!(world.get::<Pooled>(e).unwrap().is_active());
+    }
+
+    #[test]
+    fn test_commands_spawn_and_release() {
+        let mut world = World::new();
+        world.insert_resource(EntityPool::new(Health(5)).with_max_size(1));
+
+        let spawn = |mut commands: Commands, mut pool: ResMut<EntityPool<Health>>| {
+            pool.spawn(&mut commands).insert(Extra);
+            pool.spawn(&mut commands);
+        };
+        world.run_system_once(spawn).unwrap();
+        assert_eq!(world.query::<&Health>().iter(&world).count(), 2);
+
+        let release_all = |mut commands: Commands, mut pool: ResMut<EntityPool<Health>>, q: Query<Entity, With<Health>>| {
+            for e in &q {
+                pool.release(&mut commands, e);
+            }
+        };
+        world.run_system_once(release_all).unwrap();
+
+        // 上限为 1：一个入池，一个被销毁
+        let pool = world.resource::<EntityPool<Health>>();
+        assert_eq!(pool.available(), 1);
+        assert_eq!(pool.stats().dropped, 1);
+        assert_eq!(world.query::<&Health>().iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn test_stale_entities_skipped() {
+        let mut world = World::new();
+        let mut pool = EntityPool::new(Health(1));
+        pool.prewarm(&mut world, 2);
+        let stale = pool.free[1];
+        world.despawn(stale);
+
+        let e = pool.spawn_in(&mut world);
+        assert_ne!(e, stale);
+        assert_eq!(pool.stats().reused, 1);
+    }
+}
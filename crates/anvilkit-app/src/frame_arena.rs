@@ -0,0 +1,179 @@
+//! # 帧内存分配器
+//!
+//! [`FrameArena`] 资源为每帧的临时数据（剔除列表、绘制排序键、UI 布局中间结果等）
+//! 提供 bump 分配，[`FrameArenaPlugin`] 在 `Cleanup` 阶段统一重置。
+//!
+//! 分配结果的生命周期绑定在 `Res<FrameArena>` 的借用上，无法保存到组件或资源中，
+//! 编译期即保证引用不会越过帧边界：
+//!
+//! ```rust,compile_fail
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_app::frame_arena::FrameArena;
+//!
+//! #[derive(Resource)]
+//! struct Leaked(&'static mut [u32]);
+//!
+//! fn leak(arena: Res<FrameArena>, mut commands: Commands) {
+//!     let scratch = arena.alloc_slice_copy(&[1u32, 2, 3]);
+//!     commands.insert_resource(Leaked(scratch)); // 编译错误：借用无法逃逸出系统
+//! }
+//! ```
+//!
+//! 需要跨系统传递“帧内索引”等非引用数据时，可记录 [`FrameArena::frame`]，
+//! 使用前以 [`FrameArena::debug_assert_frame`] 检查仍处于同一帧。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_app::frame_arena::{FrameArena, FrameArenaPlugin};
+//!
+//! fn cull(arena: Res<FrameArena>) {
+//!     let visible = arena.alloc_slice_fill_with(4, |i| i as u32 * 2);
+//!     visible.sort_unstable_by(|a, b| b.cmp(a));
+//!     assert_eq!(visible[0], 6);
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(AnvilKitEcsPlugin)
+//!     .add_plugins(FrameArenaPlugin)
+//!     .add_systems(AnvilKitSchedule::Update, cull);
+//! app.update();
+//!
+//! let arena = app.world().resource::<FrameArena>();
+//! assert_eq!(arena.frame(), 1);
+//! assert_eq!(arena.allocated_bytes(), 0);
+//! assert_eq!(arena.peak_bytes(), 16);
+//! ```
+
+use std::ops::Deref;
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::collections::ArenaAllocator;
+
+use crate::ecs_app::App;
+use crate::ecs_plugin::Plugin;
+use crate::schedule::AnvilKitSchedule;
+
+/// 每帧重置的 bump 分配器资源
+///
+/// 通过 `Deref` 暴露 [`ArenaAllocator`] 的全部分配接口。
+#[derive(Resource, Debug, Default)]
+pub struct FrameArena {
+    arena: ArenaAllocator,
+    frame: u64,
+    peak_bytes: usize,
+}
+
+impl FrameArena {
+    /// 指定内存块大小创建
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        Self { arena: ArenaAllocator::with_chunk_size(chunk_size), ..Default::default() }
+    }
+
+    /// 已完成的帧数（每次重置加一）
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// 单帧分配量的历史峰值（字节），用于调整块大小
+    pub fn peak_bytes(&self) -> usize {
+        self.peak_bytes.max(self.arena.allocated_bytes())
+    }
+
+    /// 调试构建下断言 `frame` 与当前帧一致
+    ///
+    /// 用于检查记录在别处的帧内数据（例如指向本帧分配结果的索引）没有被跨帧使用。
+    #[track_caller]
+    pub fn debug_assert_frame(&self, frame: u64) {
+        debug_assert_eq!(
+            frame, self.frame,
+            "FrameArena: 第 {} 帧的数据在第 {} 帧被使用", frame, self.frame
+        );
+    }
+
+    /// 释放本帧的全部分配并进入下一帧
+    pub fn reset(&mut self) {
+        self.peak_bytes = self.peak_bytes();
+        self.arena.reset();
+        self.frame += 1;
+    }
+}
+
+impl Deref for FrameArena {
+    type Target = ArenaAllocator;
+
+    fn deref(&self) -> &ArenaAllocator {
+        &self.arena
+    }
+}
+
+/// 帧末重置 [`FrameArena`]
+pub fn frame_arena_reset_system(mut arena: ResMut<FrameArena>) {
+    arena.reset();
+}
+
+/// 帧内存分配器插件
+///
+/// 插入 [`FrameArena`] 资源，并在 `Cleanup` 阶段重置。
+pub struct FrameArenaPlugin;
+
+impl Plugin for FrameArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameArena>();
+        app.add_systems(AnvilKitSchedule::Cleanup, frame_arena_reset_system);
+    }
+
+    fn name(&self) -> &str {
+        "FrameArenaPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs_plugin::AnvilKitEcsPlugin;
+
+    #[derive(Resource, Default)]
+    struct Observed(Vec<(u64, usize)>);
+
+    fn scratch_system(arena: Res<FrameArena>, mut observed: ResMut<Observed>) {
+        let frame = arena.frame();
+        let values = arena.alloc_slice_fill_with(8, |i| i as u64 + frame);
+        observed.0.push((frame, arena.allocated_bytes()));
+        assert_eq!(values[7], 7 + frame);
+    }
+
+    #[test]
+    fn test_reset_each_frame() {
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin)
+            .add_plugins(FrameArenaPlugin)
+            .init_resource::<Observed>()
+            .add_systems(AnvilKitSchedule::Update, scratch_system);
+
+        for _ in 0..3 {
+            app.update();
+        }
+
+        let observed = &app.world().resource::<Observed>().0;
+        // 每帧开始时 arena 为空，分配量不随帧数累积
+        assert_eq!(observed.as_slice(), &[(0, 64), (1, 64), (2, 64)]);
+        let arena = app.world().resource::<FrameArena>();
+        assert_eq!(arena.frame(), 3);
+        assert_eq!(arena.allocated_bytes(), 0);
+        assert_eq!(arena.peak_bytes(), 64);
+        assert_eq!(arena.chunk_count(), 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "FrameArena")]
+    fn test_debug_assert_frame_detects_stale_data() {
+        let mut arena = FrameArena::default();
+        let frame = arena.frame();
+        arena.debug_assert_frame(frame);
+        arena.reset();
+        arena.debug_assert_frame(frame);
+    }
+}
@@ -40,6 +40,18 @@ pub mod ecs_plugin;
 pub mod schedule;
 pub mod auto_plugins;
 pub mod state;
+pub mod turn;
+pub mod entity_pool;
+pub mod frame_arena;
+pub mod dynamic_plugin;
+#[cfg(feature = "loading-screen")]
+pub mod loading;
+#[cfg(feature = "achievements")]
+pub mod achievements;
+#[cfg(feature = "steam")]
+pub mod steam;
+#[cfg(feature = "discord")]
+pub mod discord;
 
 mod window_size;
 pub mod screen;
@@ -212,7 +224,8 @@ macro_rules! game_ctx {
 
 impl<G: GameCallbacks> AnvilKitApp<G> {
     /// Run the game. This blocks until the window is closed.
-    pub fn run(config: GameConfig, app: App, game: G) {
+    pub fn run(config: GameConfig, mut app: App, game: G) {
+        RenderApp::finish_plugins(&mut app);
         let event_loop = EventLoop::new().expect("Failed to create event loop");
 
         let wconfig = config.to_window_config();
@@ -334,6 +347,7 @@ impl<G: GameCallbacks> ApplicationHandler for AnvilKitApp<G> {
         if !egui_wants {
             RenderApp::forward_input(&mut self.app, &event);
         }
+        RenderApp::forward_window_event(&mut self.app, &event);
 
         // Let RenderApp handle window management (resize surface, etc.)
         self.render_app.window_event(event_loop, window_id, event);
@@ -360,6 +374,7 @@ impl<G: GameCallbacks> ApplicationHandler for AnvilKitApp<G> {
 
         // 2. Frame tick: DeltaTime → ECS update → end_frame → request_redraw
         self.render_app.tick(&mut self.app);
+        self.render_app.apply_window_commands(&mut self.app);
 
         // Apply cursor mode from ECS resource (set by ScreenPlugin's cursor_sync_system)
         if let Some(cursor_mode) = self.app.world().get_resource::<screen::CursorMode>() {
@@ -403,9 +418,28 @@ pub mod prelude {
     pub use crate::egui_integration::EguiTextures;
     pub use crate::ecs_app::{App, Plugin, DeltaTime, AppExt};
     pub use crate::ecs_plugin::AnvilKitEcsPlugin;
-    pub use crate::schedule::{AnvilKitSchedule, AnvilKitSystemSet, ScheduleBuilder, common_conditions};
+    pub use crate::schedule::{AnvilKitSchedule, AnvilKitSystemSet, ScheduleBuilder, common_conditions, on_timer, on_fixed_interval};
     pub use crate::auto_plugins::{AutoInputPlugin, AutoDeltaTimePlugin};
-    pub use crate::state::{GameState, NextGameState, StateTransitionEvent, StateValue, in_state, state_transition_system};
+    pub use crate::state::{
+        GameState, NextGameState, StateTransitionEvent, StateValue, in_state, state_transition_system,
+        OnEnter, OnExit, OnTransition, StatePlugin, StateCommandsExt, apply_state_transition,
+    };
+    pub use crate::turn::{InitiativeQueue, TurnControl, TurnPlugin, TurnSchedule, TurnStarted, advance_turn};
+    pub use crate::entity_pool::{EntityPool, Pooled};
+    pub use crate::frame_arena::{FrameArena, FrameArenaPlugin};
+    pub use crate::dynamic_plugin::{DynamicPlugins, PluginRegistrar};
+    #[cfg(feature = "dynamic_plugins")]
+    pub use crate::dynamic_plugin::DynamicPluginAppExt;
+    #[cfg(feature = "loading-screen")]
+    pub use crate::loading::{AssetBundle, LoadingProgress, LoadingScreenPlugin};
+    #[cfg(feature = "achievements")]
+    pub use crate::achievements::{
+        AchievementBackend, AchievementDef, AchievementProgressed, AchievementUnlocked, Achievements, AchievementsPlugin,
+    };
+    #[cfg(feature = "steam")]
+    pub use crate::steam::{RichPresence, SteamClient, SteamPlugin, SteamUser};
+    #[cfg(feature = "discord")]
+    pub use crate::discord::{DiscordActivity, DiscordPresence, DiscordPresencePlugin};
     pub use bevy_ecs::prelude::*;
     pub use egui;
 }
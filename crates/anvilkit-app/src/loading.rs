@@ -0,0 +1,519 @@
+//! # 加载画面
+//!
+//! [`LoadingScreenPlugin`] 为状态声明所需的资产包（[`AssetBundle`]）。切换到这些状态时，
+//! 若资产包尚未全部加载，转换会被改道到加载状态：后台异步加载资产，屏幕显示进度条、
+//! 可选的提示文字与旋转指示，全部完成后自动进入原目标状态。
+//!
+//! 需要 `loading-screen` feature，并依赖 [`StatePlugin`](crate::state::StatePlugin) 管理状态。
+//! 资产通过非 Send 资源 [`AssetServer`] 加载；插件在缺少时以 `"assets"` 为根目录创建一个。
+//! 初始状态由 `StatePlugin` 直接设置，不会被改道。
+//!
+//! ```rust,no_run
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_app::loading::{AssetBundle, LoadingScreenPlugin};
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+//! enum AppState { #[default] Menu, Loading, Playing }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(AnvilKitEcsPlugin)
+//!     .add_plugins(StatePlugin::new(AppState::Menu))
+//!     .add_plugins(
+//!         LoadingScreenPlugin::new(AppState::Loading)
+//!             .with_bundle(AppState::Playing, AssetBundle::new(["models/level1.glb", "textures/atlas.png"]))
+//!             .with_tips(["Hold Shift to sprint", "Press F3 for diagnostics"]),
+//!     );
+//! ```
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use anvilkit_assets::asset_server::{AssetHandle, AssetServer, LoadState};
+use anvilkit_core::time::DeltaTime;
+use anvilkit_render::renderer::ui::{UiNode, UiText};
+use anvilkit_render::renderer::ui_anchor::{ui_anchor_system, Anchor, Margin, UiAnchor, UiAnchorPlugin};
+
+use crate::schedule::AnvilKitSchedule;
+use crate::state::{apply_state_transition, in_state, GameState, NextGameState, OnEnter, OnExit, StateValue};
+
+/// 资产包：一组相对于资产根目录的路径
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetBundle {
+    /// 资产路径
+    pub paths: Vec<PathBuf>,
+}
+
+impl AssetBundle {
+    /// 由路径列表创建
+    pub fn new<P: Into<PathBuf>>(paths: impl IntoIterator<Item = P>) -> Self {
+        Self { paths: paths.into_iter().map(Into::into).collect() }
+    }
+
+    /// Builder: 追加一个路径
+    pub fn with(mut self, path: impl Into<PathBuf>) -> Self {
+        self.paths.push(path.into());
+        self
+    }
+
+    /// 资产包是否已全部驻留（加载完成）
+    pub fn is_resident(&self, server: &mut AssetServer) -> bool {
+        self.paths.iter().all(|path| {
+            let handle = server.load::<Vec<u8>>(path);
+            server.load_state(&handle).is_loaded()
+        })
+    }
+}
+
+/// 加载画面外观
+#[derive(Resource, Debug, Clone)]
+pub struct LoadingScreenConfig {
+    /// 加载时轮换显示的提示
+    pub tips: Vec<String>,
+    /// 每条提示显示时长（秒）
+    pub tip_interval: f32,
+    /// 是否显示旋转指示
+    pub show_spinner: bool,
+    /// 加载画面最短显示时长（秒），避免一闪而过
+    pub min_duration: f32,
+    /// 进度条尺寸（逻辑像素）
+    pub bar_size: Vec2,
+    /// 背景色
+    pub background: [f32; 4],
+    /// 进度条底色
+    pub track_color: [f32; 4],
+    /// 进度条填充色
+    pub fill_color: [f32; 4],
+}
+
+impl Default for LoadingScreenConfig {
+    fn default() -> Self {
+        Self {
+            tips: Vec::new(),
+            tip_interval: 4.0,
+            show_spinner: true,
+            min_duration: 0.0,
+            bar_size: Vec2::new(480.0, 12.0),
+            background: [0.02, 0.02, 0.03, 1.0],
+            track_color: [0.15, 0.15, 0.18, 1.0],
+            fill_color: [0.3, 0.6, 1.0, 1.0],
+        }
+    }
+}
+
+/// 各状态所需的资产包 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct LoadingBundles<S: StateValue> {
+    /// 加载画面所在的状态
+    pub loading_state: S,
+    /// 目标状态 → 资产包
+    pub bundles: HashMap<S, AssetBundle>,
+}
+
+/// 当前加载进度 (Resource)
+#[derive(Resource, Debug)]
+pub struct LoadingProgress<S: StateValue> {
+    /// 加载完成后进入的状态
+    pub target: Option<S>,
+    /// 已加载数量
+    pub loaded: usize,
+    /// 加载失败数量（失败也视为完成，不阻塞转换）
+    pub failed: usize,
+    /// 资产总数
+    pub total: usize,
+    /// 已在加载状态停留的时长（秒）
+    pub elapsed: f32,
+    handles: Vec<AssetHandle<Vec<u8>>>,
+    pinned: Vec<Option<Arc<Vec<u8>>>>,
+}
+
+impl<S: StateValue> Default for LoadingProgress<S> {
+    fn default() -> Self {
+        Self { target: None, loaded: 0, failed: 0, total: 0, elapsed: 0.0, handles: Vec::new(), pinned: Vec::new() }
+    }
+}
+
+impl<S: StateValue> LoadingProgress<S> {
+    /// 进度 `0.0..=1.0`
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            (self.loaded + self.failed) as f32 / self.total as f32
+        }
+    }
+
+    /// 全部资产是否已结束加载
+    pub fn is_complete(&self) -> bool {
+        self.loaded + self.failed >= self.total
+    }
+}
+
+/// 已驻留的资产包 (Resource)
+///
+/// 持有加载完成的资产数据，防止 [`AssetServer`] 的自动卸载回收它们。
+/// 不再需要某个状态的资产时调用 [`release`](Self::release)。
+#[derive(Resource, Debug)]
+pub struct ResidentBundles<S: StateValue> {
+    pinned: HashMap<S, Vec<Arc<Vec<u8>>>>,
+}
+
+impl<S: StateValue> Default for ResidentBundles<S> {
+    fn default() -> Self {
+        Self { pinned: HashMap::new() }
+    }
+}
+
+impl<S: StateValue> ResidentBundles<S> {
+    /// 状态的资产包是否由本资源持有
+    pub fn contains(&self, state: S) -> bool {
+        self.pinned.contains_key(&state)
+    }
+
+    /// 释放状态的资产包，之后可被自动卸载
+    pub fn release(&mut self, state: S) {
+        self.pinned.remove(&state);
+    }
+}
+
+/// 改道系统（`StateTransition` 阶段，先于 [`apply_state_transition`]）
+///
+/// 目标状态的资产包未驻留时开始异步加载，并把转换改为进入加载状态。
+/// 离开加载状态的转换不会被改道。
+pub fn loading_redirect_system<S: StateValue>(
+    bundles: Res<LoadingBundles<S>>,
+    current: Res<GameState<S>>,
+    mut next: ResMut<NextGameState<S>>,
+    mut progress: ResMut<LoadingProgress<S>>,
+    server: Option<NonSendMut<AssetServer>>,
+) {
+    let Some(target) = next.0 else { return };
+    if current.0 == bundles.loading_state || target == bundles.loading_state || target == current.0 {
+        return;
+    }
+    let (Some(bundle), Some(mut server)) = (bundles.bundles.get(&target), server) else { return };
+    if bundle.is_resident(&mut server) {
+        return;
+    }
+
+    let handles: Vec<AssetHandle<Vec<u8>>> = bundle
+        .paths
+        .iter()
+        .map(|path| {
+            let handle = server.load::<Vec<u8>>(path);
+            if server.load_state(&handle).is_loaded() {
+                handle
+            } else {
+                server.load_async::<Vec<u8>>(path)
+            }
+        })
+        .collect();
+    log::debug!("资产包未就绪，进入加载状态: {:?} → {:?}", target, bundles.loading_state);
+
+    *progress = LoadingProgress {
+        target: Some(target),
+        total: handles.len(),
+        pinned: vec![None; handles.len()],
+        handles,
+        ..Default::default()
+    };
+    next.set(bundles.loading_state);
+}
+
+/// 加载进度系统 (Update，仅在加载状态运行)
+///
+/// 处理完成的加载结果并统计进度，全部完成且达到最短显示时长后切换到目标状态。
+/// 使用不触发自动卸载的 `process_completed_count`，并持有已加载的数据。
+pub fn loading_progress_system<S: StateValue>(
+    config: Res<LoadingScreenConfig>,
+    mut progress: ResMut<LoadingProgress<S>>,
+    mut resident: ResMut<ResidentBundles<S>>,
+    mut next: ResMut<NextGameState<S>>,
+    dt: Option<Res<DeltaTime>>,
+    server: Option<NonSendMut<AssetServer>>,
+) {
+    progress.elapsed += dt.map_or(1.0 / 60.0, |d| d.0);
+    if let Some(mut server) = server {
+        server.process_completed_count();
+        let (mut loaded, mut failed) = (0, 0);
+        let progress = &mut *progress;
+        for (handle, pinned) in progress.handles.iter().zip(progress.pinned.iter_mut()) {
+            match server.load_state(handle) {
+                LoadState::Loaded => {
+                    loaded += 1;
+                    if pinned.is_none() {
+                        *pinned = server.get_cached(handle.id());
+                    }
+                }
+                LoadState::Failed => failed += 1,
+                _ => {}
+            }
+        }
+        progress.loaded = loaded;
+        progress.failed = failed;
+    }
+
+    if progress.is_complete() && progress.elapsed >= config.min_duration {
+        if progress.failed > 0 {
+            log::warn!("{} 个资产加载失败", progress.failed);
+        }
+        if let Some(target) = progress.target.take() {
+            resident.pinned.insert(target, progress.pinned.drain(..).flatten().collect());
+            next.set(target);
+        }
+        progress.handles.clear();
+        progress.pinned.clear();
+    }
+}
+
+/// 加载画面 UI 元素
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+#[require(UiNode)]
+pub enum LoadingScreenUi {
+    /// 全屏背景
+    Background,
+    /// 进度条底
+    Track,
+    /// 进度条填充
+    Fill,
+    /// 百分比与旋转指示
+    Label,
+    /// 提示文字
+    Tip,
+}
+
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+fn spawn_loading_screen(mut commands: Commands, config: Res<LoadingScreenConfig>) {
+    let bottom = |height: f32, offset: f32| {
+        UiAnchor::new(Anchor::Bottom, Vec2::new(config.bar_size.x, height)).with_margin(Margin { bottom: offset, ..Margin::ZERO })
+    };
+    commands.spawn((
+        LoadingScreenUi::Background,
+        UiAnchor::new(Anchor::Center, Vec2::splat(1.0e5)),
+        UiNode { background_color: config.background, ..Default::default() },
+    ));
+    commands.spawn((
+        LoadingScreenUi::Track,
+        bottom(config.bar_size.y, 80.0),
+        UiNode { background_color: config.track_color, corner_radius: config.bar_size.y * 0.5, ..Default::default() },
+    ));
+    commands.spawn((
+        LoadingScreenUi::Fill,
+        UiNode { background_color: config.fill_color, corner_radius: config.bar_size.y * 0.5, ..Default::default() },
+    ));
+    commands.spawn((
+        LoadingScreenUi::Label,
+        bottom(24.0, 80.0 + config.bar_size.y + 8.0),
+        UiNode { text: Some(UiText::new("Loading 0%")), ..Default::default() },
+    ));
+    if !config.tips.is_empty() {
+        commands.spawn((
+            LoadingScreenUi::Tip,
+            bottom(24.0, 40.0),
+            UiNode { text: Some(UiText::new(config.tips[0].clone()).with_color([0.7, 0.7, 0.75, 1.0])), ..Default::default() },
+        ));
+    }
+}
+
+fn despawn_loading_screen(mut commands: Commands, query: Query<Entity, With<LoadingScreenUi>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// 加载画面 UI 更新系统（PostUpdate，锚点布局之后）
+///
+/// 填充条宽度跟随进度，标签显示百分比与旋转指示，提示按间隔轮换。
+pub fn loading_screen_ui_system<S: StateValue>(
+    config: Res<LoadingScreenConfig>,
+    progress: Res<LoadingProgress<S>>,
+    mut nodes: Query<(&LoadingScreenUi, &mut UiNode)>,
+) {
+    let Some(track) = nodes.iter().find(|(ui, _)| **ui == LoadingScreenUi::Track).map(|(_, node)| node.computed_rect) else {
+        return;
+    };
+    let fraction = progress.fraction().clamp(0.0, 1.0);
+    let spinner = if config.show_spinner {
+        let frame = (progress.elapsed * 8.0) as usize % SPINNER_FRAMES.len();
+        format!(" {}", SPINNER_FRAMES[frame])
+    } else {
+        String::new()
+    };
+    let tip_index = if config.tips.is_empty() {
+        0
+    } else {
+        (progress.elapsed / config.tip_interval.max(0.1)) as usize % config.tips.len()
+    };
+
+    for (ui, mut node) in nodes.iter_mut() {
+        match ui {
+            LoadingScreenUi::Fill => {
+                let rect = [track[0], track[1], track[2] * fraction, track[3]];
+                if node.computed_rect != rect {
+                    node.computed_rect = rect;
+                }
+            }
+            LoadingScreenUi::Label => {
+                let label = format!("Loading {:.0}%{}", fraction * 100.0, spinner);
+                if node.text.as_ref().map_or(true, |t| t.content != label) {
+                    node.text.get_or_insert_with(UiText::default).content = label;
+                }
+            }
+            LoadingScreenUi::Tip => {
+                let tip = &config.tips[tip_index];
+                if node.text.as_ref().map_or(true, |t| &t.content != tip) {
+                    node.text.get_or_insert_with(UiText::default).content = tip.clone();
+                }
+            }
+            LoadingScreenUi::Background | LoadingScreenUi::Track => {}
+        }
+    }
+}
+
+/// 加载画面插件
+pub struct LoadingScreenPlugin<S: StateValue> {
+    loading_state: S,
+    bundles: HashMap<S, AssetBundle>,
+    config: LoadingScreenConfig,
+}
+
+impl<S: StateValue> LoadingScreenPlugin<S> {
+    /// 以 `loading_state` 作为加载画面所在状态
+    pub fn new(loading_state: S) -> Self {
+        Self { loading_state, bundles: HashMap::new(), config: LoadingScreenConfig::default() }
+    }
+
+    /// Builder: 声明进入 `state` 前必须驻留的资产包
+    pub fn with_bundle(mut self, state: S, bundle: AssetBundle) -> Self {
+        self.bundles.insert(state, bundle);
+        self
+    }
+
+    /// Builder: 设置轮换提示
+    pub fn with_tips<T: Into<String>>(mut self, tips: impl IntoIterator<Item = T>) -> Self {
+        self.config.tips = tips.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Builder: 设置外观
+    pub fn with_config(mut self, config: LoadingScreenConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+impl<S: StateValue> Plugin for LoadingScreenPlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UiAnchorPlugin>() {
+            app.add_plugins(UiAnchorPlugin);
+        }
+        if !app.world().contains_non_send::<AssetServer>() {
+            app.insert_non_send_resource(AssetServer::new("assets"));
+        }
+        let loading = self.loading_state;
+        app.insert_resource(LoadingBundles { loading_state: loading, bundles: self.bundles.clone() })
+            .insert_resource(self.config.clone())
+            .init_resource::<LoadingProgress<S>>()
+            .init_resource::<ResidentBundles<S>>()
+            .add_systems(
+                AnvilKitSchedule::StateTransition,
+                loading_redirect_system::<S>.before(apply_state_transition::<S>),
+            )
+            .add_systems(bevy_app::Update, loading_progress_system::<S>.run_if(in_state(loading)))
+            .add_systems(
+                bevy_app::PostUpdate,
+                loading_screen_ui_system::<S>.after(ui_anchor_system).run_if(in_state(loading)),
+            )
+            .add_systems(OnEnter(loading), spawn_loading_screen)
+            .add_systems(OnExit(loading), despawn_loading_screen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs_plugin::AnvilKitEcsPlugin;
+    use crate::state::StatePlugin;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    enum TestState {
+        #[default]
+        Menu,
+        Loading,
+        Playing,
+        Credits,
+    }
+
+    fn test_app(root: &std::path::Path) -> App {
+        let mut app = App::new();
+        app.insert_non_send_resource(AssetServer::new(root));
+        app.add_plugins(AnvilKitEcsPlugin)
+            .add_plugins(StatePlugin::new(TestState::Menu))
+            .add_plugins(
+                LoadingScreenPlugin::new(TestState::Loading)
+                    .with_bundle(TestState::Playing, AssetBundle::new(["a.bin", "b.bin"]))
+                    .with_tips(["tip"]),
+            );
+        app
+    }
+
+    #[test]
+    fn test_progress_fraction() {
+        let mut progress = LoadingProgress::<TestState>::default();
+        assert!(progress.is_complete());
+        assert_eq!(progress.fraction(), 1.0);
+        progress.total = 4;
+        progress.loaded = 1;
+        progress.failed = 1;
+        assert_eq!(progress.fraction(), 0.5);
+        assert!(!progress.is_complete());
+    }
+
+    #[test]
+    fn test_redirects_through_loading_state() {
+        let root = std::env::temp_dir().join(format!("anvilkit_loading_{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("a.bin"), [1u8; 16]).unwrap();
+        std::fs::write(root.join("b.bin"), [2u8; 16]).unwrap();
+
+        let mut app = test_app(&root);
+        app.update();
+
+        // 没有资产包的状态直接进入
+        app.world_mut().resource_mut::<NextGameState<TestState>>().set(TestState::Credits);
+        app.update();
+        assert_eq!(app.world().resource::<GameState<TestState>>().0, TestState::Credits);
+
+        app.world_mut().resource_mut::<NextGameState<TestState>>().set(TestState::Playing);
+        app.update();
+        assert_eq!(app.world().resource::<GameState<TestState>>().0, TestState::Loading);
+        assert_eq!(app.world().resource::<LoadingProgress<TestState>>().total, 2);
+
+        let mut reached = false;
+        for _ in 0..200 {
+            app.update();
+            if app.world().resource::<GameState<TestState>>().0 == TestState::Playing {
+                reached = true;
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+        assert!(reached, "加载完成后应进入目标状态");
+        // 离开加载状态后 UI 被清理
+        assert_eq!(app.world_mut().query::<&LoadingScreenUi>().iter(app.world()).count(), 0);
+
+        // 资产已驻留：再次进入不经过加载状态
+        app.world_mut().resource_mut::<NextGameState<TestState>>().set(TestState::Menu);
+        app.update();
+        app.world_mut().resource_mut::<NextGameState<TestState>>().set(TestState::Playing);
+        app.update();
+        assert_eq!(app.world().resource::<GameState<TestState>>().0, TestState::Playing);
+        assert!(app.world().resource::<ResidentBundles<TestState>>().contains(TestState::Playing));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -54,6 +54,7 @@ pub use bevy_ecs::schedule::ScheduleLabel;
 /// 1. `Startup` - 应用启动时执行一次
 /// 2. `Main` - 主循环调度器（包含以下子阶段）
 ///    - `PreUpdate` - 更新前准备
+///    - `StateTransition` - 状态转换
 ///    - `Update` - 主要更新逻辑
 ///    - `PostUpdate` - 更新后处理
 ///    - `Cleanup` - 帧结束清理
@@ -92,6 +93,12 @@ pub enum AnvilKitSchedule {
     /// 
     /// 用于输入处理、时间更新、状态准备等。
     PreUpdate,
+
+    /// 状态转换阶段
+    ///
+    /// 在 PreUpdate 之后运行，应用 `NextGameState<S>` 请求并执行
+    /// `OnExit` / `OnTransition` / `OnEnter` 调度。
+    StateTransition,
     
     /// 固定步长更新
     ///
@@ -213,6 +220,75 @@ pub enum AnvilKitSystemSet {
 /// ```
 pub use bevy_ecs::schedule::common_conditions;
 
+/// 计时器运行条件
+///
+/// 返回一个运行条件：内部持有独立的重复 [`Timer`](anvilkit_core::time::Timer)，
+/// 每次评估时以 `Time::delta()` 推进，计时完成的那一帧返回 `true`。
+/// 每个 `on_timer(...)` 调用都拥有自己的计时器状态。
+///
+/// 计时从条件首次评估时开始，间隔会随帧时间累积误差（与 `Timer` 语义一致）。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_app::prelude::*;
+/// use anvilkit_app::schedule::on_timer;
+/// use std::time::Duration;
+///
+/// fn autosave() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(AnvilKitEcsPlugin);
+/// app.add_systems(AnvilKitSchedule::Update, autosave.run_if(on_timer(Duration::from_secs(30))));
+/// ```
+pub fn on_timer(
+    duration: std::time::Duration,
+) -> impl FnMut(bevy_ecs::system::Res<anvilkit_core::time::Time>) -> bool + Clone {
+    let mut timer = anvilkit_core::time::Timer::repeating(duration);
+    move |time: bevy_ecs::system::Res<anvilkit_core::time::Time>| {
+        timer.tick(time.delta());
+        timer.just_finished()
+    }
+}
+
+/// 固定间隔运行条件
+///
+/// 与 [`on_timer`] 不同，间隔对齐到 `Time::elapsed()`（应用启动时间），
+/// 不会因帧时间抖动而漂移：每当 elapsed 跨过 `interval` 的整数倍时返回 `true`。
+/// 单帧跨过多个间隔时只触发一次。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_app::prelude::*;
+/// use anvilkit_app::schedule::on_fixed_interval;
+/// use std::time::Duration;
+///
+/// fn network_heartbeat() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(AnvilKitEcsPlugin);
+/// app.add_systems(
+///     AnvilKitSchedule::Update,
+///     network_heartbeat.run_if(on_fixed_interval(Duration::from_millis(250))),
+/// );
+/// ```
+pub fn on_fixed_interval(
+    interval: std::time::Duration,
+) -> impl FnMut(bevy_ecs::system::Res<anvilkit_core::time::Time>) -> bool + Clone {
+    let interval_nanos = interval.as_nanos().max(1);
+    let mut last_index = 0u128;
+    move |time: bevy_ecs::system::Res<anvilkit_core::time::Time>| {
+        let index = time.elapsed().as_nanos() / interval_nanos;
+        if index > last_index {
+            last_index = index;
+            true
+        } else {
+            false
+        }
+    }
+}
+
 /// 调度构建器
 /// 
 /// 提供便捷的方法来构建和配置调度器。
@@ -337,6 +413,52 @@ mod tests {
         assert_eq!(resource.value, 10);
     }
 
+    /// 将 `Time` 推进到启动后 `elapsed_ms` 毫秒并运行调度（确定的时钟，不依赖真实时间）
+    fn run_at(schedule: &mut Schedule, world: &mut World, elapsed_ms: u64) {
+        let mut time = world.resource_mut::<anvilkit_core::time::Time>();
+        let now = time.startup_time() + std::time::Duration::from_millis(elapsed_ms);
+        time.update_with_instant(now);
+        schedule.run(world);
+    }
+
+    #[test]
+    fn test_on_timer_condition() {
+        let mut world = World::new();
+        world.init_resource::<TestResource>();
+        world.insert_resource(anvilkit_core::time::Time::new());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(test_system.run_if(on_timer(std::time::Duration::from_millis(20))));
+
+        // First update has zero delta
+        run_at(&mut schedule, &mut world, 0);
+        assert_eq!(world.resource::<TestResource>().value, 0);
+
+        run_at(&mut schedule, &mut world, 10);
+        assert_eq!(world.resource::<TestResource>().value, 0);
+
+        run_at(&mut schedule, &mut world, 30);
+        assert_eq!(world.resource::<TestResource>().value, 1);
+    }
+
+    #[test]
+    fn test_on_fixed_interval_condition() {
+        let mut world = World::new();
+        world.init_resource::<TestResource>();
+        world.insert_resource(anvilkit_core::time::Time::new());
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(test_system.run_if(on_fixed_interval(std::time::Duration::from_secs(3600))));
+
+        run_at(&mut schedule, &mut world, 5);
+        assert_eq!(world.resource::<TestResource>().value, 0);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(test_system.run_if(on_fixed_interval(std::time::Duration::from_millis(1))));
+        run_at(&mut schedule, &mut world, 10);
+        assert_eq!(world.resource::<TestResource>().value, 1);
+    }
+
     #[test]
     fn test_schedule_builder() {
         let mut schedule = ScheduleBuilder::new()
@@ -358,6 +480,7 @@ mod tests {
         let labels = vec![
             format!("{:?}", AnvilKitSchedule::Startup),
             format!("{:?}", AnvilKitSchedule::PreUpdate),
+            format!("{:?}", AnvilKitSchedule::StateTransition),
             format!("{:?}", AnvilKitSchedule::Update),
             format!("{:?}", AnvilKitSchedule::PostUpdate),
             format!("{:?}", AnvilKitSchedule::Cleanup),
@@ -2,9 +2,7 @@ use bevy_ecs::prelude::*;
 
 use crate::ecs_app::App;
 use crate::schedule::AnvilKitSchedule;
-use crate::state::{
-    GameState, NextGameState, StateTransitionEvent, StateValue, state_transition_system,
-};
+use crate::state::{GameState, StatePlugin, StateValue};
 
 use super::cursor::CursorMode;
 
@@ -16,9 +14,9 @@ pub struct ScreenPluginConfig<S: StateValue> {
 
 /// Plugin that wires up game-state management with automatic cursor control.
 ///
-/// Registers [`GameState<S>`], [`NextGameState<S>`], [`StateTransitionEvent<S>`],
-/// [`state_transition_system`], and a cursor-sync system that keeps [`CursorMode`]
-/// in sync with the current state.
+/// Adds a [`StatePlugin<S>`] (state resources, transition event, and
+/// `OnEnter` / `OnExit` schedules) plus a cursor-sync system that keeps
+/// [`CursorMode`] in sync with the current state.
 ///
 /// # Example
 ///
@@ -56,14 +54,11 @@ impl<S: StateValue> ScreenPlugin<S> {
 
     /// Register all resources, events, and systems on the App.
     pub fn build(self, app: &mut App) {
-        app.insert_resource(GameState(self.initial));
-        app.insert_resource(NextGameState::<S>::default());
+        app.add_plugins(StatePlugin::new(self.initial));
         app.insert_resource(CursorMode::default());
         app.insert_resource(ScreenPluginConfig::<S> {
             locked_states: self.locked_states,
         });
-        app.add_event::<StateTransitionEvent<S>>();
-        app.add_systems(AnvilKitSchedule::PreUpdate, state_transition_system::<S>);
         app.add_systems(AnvilKitSchedule::PostUpdate, cursor_sync_system::<S>);
     }
 }
@@ -1,24 +1,32 @@
 //! # 游戏状态机
 //!
-//! 提供简单的类型化状态管理，支持状态转换、条件系统执行。
+//! 提供简单的类型化状态管理，支持状态转换、条件系统执行，
+//! 以及 `OnEnter` / `OnExit` / `OnTransition` 调度。
 //!
 //! ## 使用示例
 //!
 //! ```rust
 //! use anvilkit_app::prelude::*;
-//! use anvilkit_app::state::{GameState, NextGameState, in_state};
-//! use anvilkit_app::schedule::AnvilKitSchedule;
+//! use anvilkit_app::state::{GameState, NextGameState, OnEnter, StatePlugin, in_state};
 //!
 //! #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 //! enum AppState { #[default] Menu, Playing, Paused }
 //!
+//! fn spawn_level() {}
+//!
 //! let mut app = App::new();
 //! app.add_plugins(AnvilKitEcsPlugin);
-//! app.insert_resource(GameState(AppState::Menu));
-//! app.insert_resource(NextGameState::<AppState>::default());
+//! app.add_plugins(StatePlugin::new(AppState::Menu));
+//! app.add_systems(OnEnter(AppState::Playing), spawn_level);
+//!
+//! app.world_mut().resource_mut::<NextGameState<AppState>>().set(AppState::Playing);
+//! app.update();
+//! assert_eq!(app.world().resource::<GameState<AppState>>().0, AppState::Playing);
 //! ```
 
 use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ScheduleLabel;
+use bevy_app::{App, Plugin};
 use std::fmt::Debug;
 use std::hash::Hash;
 
@@ -121,11 +129,37 @@ pub fn in_state<S: StateValue>(state: S) -> impl Fn(Option<Res<GameState<S>>>) -
     }
 }
 
+/// 进入状态时运行的调度
+///
+/// 在状态切换到 `S` 后运行一次。初始状态的 `OnEnter` 在 Startup 阶段运行。
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnEnter<S: StateValue>(pub S);
+
+/// 离开状态时运行的调度
+///
+/// 在状态从 `S` 切换出去时运行一次。
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnExit<S: StateValue>(pub S);
+
+/// 特定状态对之间转换时运行的调度
+///
+/// 在 `OnExit(from)` 之后、`OnEnter(to)` 之前运行。
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OnTransition<S: StateValue> {
+    /// 转换前的状态
+    pub from: S,
+    /// 转换后的状态
+    pub to: S,
+}
+
 /// 状态转换系统
 ///
 /// 检查 `NextGameState<S>`，如果有待处理的转换请求，
 /// 更新 `GameState<S>` 并清除请求。
 ///
+/// 仅发送 [`StateTransitionEvent`]，不运行 `OnEnter` / `OnExit` 调度；
+/// 需要这些调度时使用 [`StatePlugin`]（内部注册 [`apply_state_transition`]）。
+///
 /// 应注册在 `PreUpdate` 阶段，确保状态在 Update 系统之前已更新。
 pub fn state_transition_system<S: StateValue>(
     mut current: ResMut<GameState<S>>,
@@ -142,6 +176,112 @@ pub fn state_transition_system<S: StateValue>(
     }
 }
 
+/// 应用状态转换并运行转换调度（独占系统）
+///
+/// 执行顺序：更新 `GameState<S>` → `OnExit(from)` → `OnTransition { from, to }`
+/// → `OnEnter(to)` → 发送 [`StateTransitionEvent`]。未注册任何系统的调度会被跳过。
+///
+/// 由 [`StatePlugin`] 注册在 `AnvilKitSchedule::StateTransition` 阶段。
+pub fn apply_state_transition<S: StateValue>(world: &mut World) {
+    let Some(to) = world
+        .get_resource_mut::<NextGameState<S>>()
+        .and_then(|mut next| next.0.take())
+    else {
+        return;
+    };
+    let Some(mut current) = world.get_resource_mut::<GameState<S>>() else { return };
+    let from = current.0;
+    if from == to {
+        return;
+    }
+    current.0 = to;
+    log::debug!("状态转换: {:?} → {:?}", from, to);
+
+    let _ = world.try_run_schedule(OnExit(from));
+    let _ = world.try_run_schedule(OnTransition { from, to });
+    let _ = world.try_run_schedule(OnEnter(to));
+
+    if let Some(mut events) = world.get_resource_mut::<Events<StateTransitionEvent<S>>>() {
+        events.send(StateTransitionEvent { from, to });
+    }
+}
+
+/// 为初始状态运行 `OnEnter` 调度（Startup 阶段）
+fn enter_initial_state<S: StateValue>(world: &mut World) {
+    if let Some(state) = world.get_resource::<GameState<S>>().map(|s| s.0) {
+        let _ = world.try_run_schedule(OnEnter(state));
+    }
+}
+
+/// 状态机插件
+///
+/// 注册 [`GameState<S>`]、[`NextGameState<S>`]、[`StateTransitionEvent<S>`]，
+/// 并在 `AnvilKitSchedule::StateTransition` 阶段运行 [`apply_state_transition`]。
+/// 需要先添加 `AnvilKitEcsPlugin` 以注册调度顺序。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_app::prelude::*;
+/// use anvilkit_app::state::{OnExit, StatePlugin};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// enum MyState { #[default] Menu, Playing }
+///
+/// fn save_progress() {}
+///
+/// let mut app = App::new();
+/// app.add_plugins(AnvilKitEcsPlugin);
+/// app.add_plugins(StatePlugin::new(MyState::Menu));
+/// app.add_systems(OnExit(MyState::Playing), save_progress);
+/// ```
+pub struct StatePlugin<S: StateValue> {
+    initial: S,
+}
+
+impl<S: StateValue> StatePlugin<S> {
+    /// 创建以 `initial` 为初始状态的插件
+    pub fn new(initial: S) -> Self {
+        Self { initial }
+    }
+}
+
+impl<S: StateValue + Default> Default for StatePlugin<S> {
+    fn default() -> Self {
+        Self::new(S::default())
+    }
+}
+
+impl<S: StateValue> Plugin for StatePlugin<S> {
+    fn build(&self, app: &mut App) {
+        use crate::schedule::AnvilKitSchedule;
+
+        app.insert_resource(GameState(self.initial));
+        app.insert_resource(NextGameState::<S>::default());
+        app.add_event::<StateTransitionEvent<S>>();
+        app.add_systems(bevy_app::Startup, enter_initial_state::<S>);
+        app.add_systems(AnvilKitSchedule::StateTransition, apply_state_transition::<S>);
+    }
+}
+
+/// `Commands` 状态扩展
+///
+/// 通过命令队列请求状态转换，适用于无法直接访问 `NextGameState<S>` 的场景。
+pub trait StateCommandsExt {
+    /// 请求在下一次状态转换阶段切换到 `state`
+    fn set_state<S: StateValue>(&mut self, state: S);
+}
+
+impl StateCommandsExt for Commands<'_, '_> {
+    fn set_state<S: StateValue>(&mut self, state: S) {
+        self.queue(move |world: &mut World| {
+            if let Some(mut next) = world.get_resource_mut::<NextGameState<S>>() {
+                next.set(state);
+            }
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -212,4 +352,65 @@ mod tests {
         assert_eq!(transition_events[0].from, TestState::Menu);
         assert_eq!(transition_events[0].to, TestState::Playing);
     }
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<&'static str>);
+
+    fn state_app() -> App {
+        use crate::ecs_plugin::AnvilKitEcsPlugin;
+
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app.add_plugins(StatePlugin::new(TestState::Menu));
+        app.init_resource::<Log>();
+        app
+    }
+
+    #[test]
+    fn test_state_plugin_runs_enter_exit_schedules() {
+        let mut app = state_app();
+        app.add_systems(OnEnter(TestState::Menu), |mut log: ResMut<Log>| log.0.push("enter_menu"));
+        app.add_systems(OnExit(TestState::Menu), |mut log: ResMut<Log>| log.0.push("exit_menu"));
+        app.add_systems(
+            OnTransition { from: TestState::Menu, to: TestState::Playing },
+            |mut log: ResMut<Log>| log.0.push("menu_to_playing"),
+        );
+        app.add_systems(OnEnter(TestState::Playing), |mut log: ResMut<Log>| log.0.push("enter_playing"));
+
+        // Startup enters the initial state
+        app.update();
+        assert_eq!(app.world().resource::<Log>().0, vec!["enter_menu"]);
+
+        app.world_mut().resource_mut::<NextGameState<TestState>>().set(TestState::Playing);
+        app.update();
+        assert_eq!(app.world().resource::<GameState<TestState>>().0, TestState::Playing);
+        assert_eq!(
+            app.world().resource::<Log>().0,
+            vec!["enter_menu", "exit_menu", "menu_to_playing", "enter_playing"],
+        );
+    }
+
+    #[test]
+    fn test_same_state_transition_is_ignored() {
+        let mut app = state_app();
+        app.add_systems(OnExit(TestState::Menu), |mut log: ResMut<Log>| log.0.push("exit_menu"));
+        app.update();
+
+        app.world_mut().resource_mut::<NextGameState<TestState>>().set(TestState::Menu);
+        app.update();
+        assert!(app.world().resource::<Log>().0.is_empty());
+        assert_eq!(app.world().resource::<NextGameState<TestState>>().0, None);
+    }
+
+    #[test]
+    fn test_set_state_command() {
+        let mut app = state_app();
+        app.add_systems(
+            crate::schedule::AnvilKitSchedule::Update,
+            |mut commands: Commands| commands.set_state(TestState::Paused),
+        );
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<GameState<TestState>>().0, TestState::Paused);
+    }
 }
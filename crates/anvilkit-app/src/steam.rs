@@ -0,0 +1,270 @@
+//! # Steamworks 集成
+//!
+//! [`SteamPlugin`] 基于 steamworks-rs：
+//!
+//! - 构建时初始化 Steam API，`First` 阶段运行回调，收到 `AppExit` 后释放客户端（即关闭 Steam API）；
+//! - [`SteamUser`] 资源提供当前用户的 SteamID、昵称与头像像素；
+//! - 已添加 [`AchievementsPlugin`](crate::achievements::AchievementsPlugin) 时，
+//!   自动把成就后端替换为 [`SteamAchievementBackend`]；
+//! - [`RichPresence`] 资源变化时同步到好友列表中的状态显示。
+//!
+//! Steam 未运行或初始化失败时只输出警告，游戏照常运行（相关资源不存在）。
+//!
+//! ```rust,ignore
+//! app.add_plugins(AchievementsPlugin::new("saves/achievements.ron").with(/* ... */))
+//!     .add_plugins(SteamPlugin::new(480));
+//!
+//! fn enter_level(mut presence: ResMut<RichPresence>) {
+//!     presence.set("steam_display", "#InLevel");
+//!     presence.set("level", "Forest");
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use bevy_app::AppExit;
+use bevy_ecs::prelude::*;
+use steamworks::{Client, SingleClient};
+
+use crate::achievements::{AchievementBackend, Achievements};
+use crate::ecs_app::{App, Plugin};
+
+/// Steam 客户端 (Resource)
+///
+/// 克隆开销很小，可在系统中直接访问 steamworks-rs 的其他接口。
+#[derive(Resource, Clone)]
+pub struct SteamClient(pub Client);
+
+/// 回调泵（NonSend Resource，SingleClient 只能在主线程使用）
+pub struct SteamCallbacks(pub SingleClient);
+
+/// 头像像素（RGBA8）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteamAvatar {
+    /// 宽度
+    pub width: u32,
+    /// 高度
+    pub height: u32,
+    /// RGBA8 像素
+    pub rgba: Vec<u8>,
+}
+
+/// 当前 Steam 用户 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct SteamUser {
+    /// 64 位 SteamID
+    pub steam_id: u64,
+    /// 昵称
+    pub name: String,
+    /// 中等尺寸头像（64×64），尚未下载时为 `None`
+    pub avatar: Option<SteamAvatar>,
+}
+
+/// 富状态键值 (Resource)
+///
+/// 变化后由 [`rich_presence_system`] 整体同步；`steam_display` 键引用 Steamworks 后台
+/// 配置的本地化字符串（如 `"#InLevel"`），其余键可作为字符串中的替换参数。
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichPresence {
+    entries: BTreeMap<String, String>,
+}
+
+impl RichPresence {
+    /// 设置键值
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.entries.insert(key.into(), value.into());
+    }
+
+    /// 移除键
+    pub fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+    }
+
+    /// 清空全部
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// 查询
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// 按键名顺序遍历
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+/// 通过 Steam 用户统计接口同步成就
+pub struct SteamAchievementBackend {
+    client: Client,
+}
+
+impl SteamAchievementBackend {
+    /// 使用已初始化的客户端，并请求当前用户的统计数据
+    pub fn new(client: Client) -> Self {
+        client.user_stats().request_current_stats();
+        Self { client }
+    }
+}
+
+impl AchievementBackend for SteamAchievementBackend {
+    fn name(&self) -> &str {
+        "steam"
+    }
+
+    fn unlock(&mut self, id: &str) {
+        let stats = self.client.user_stats();
+        if stats.achievement(id).set().is_err() {
+            log::warn!("Steam 成就解锁失败: {}", id);
+            return;
+        }
+        if stats.store_stats().is_err() {
+            log::warn!("Steam 统计数据上传失败");
+        }
+    }
+
+    fn set_progress(&mut self, id: &str, current: u32, goal: u32) {
+        if self.client.user_stats().indicate_achievement_progress(id, current, goal).is_err() {
+            log::debug!("Steam 成就进度提示失败: {}", id);
+        }
+    }
+
+    fn reset(&mut self, id: &str) {
+        let stats = self.client.user_stats();
+        if stats.achievement(id).clear().is_ok() {
+            let _ = stats.store_stats();
+        }
+    }
+}
+
+/// 读取当前用户信息
+fn read_user(client: &Client) -> SteamUser {
+    let steam_id = client.user().steam_id();
+    let friend = client.friends().get_friend(steam_id);
+    let avatar = friend.medium_avatar().map(|rgba| SteamAvatar { width: 64, height: 64, rgba });
+    SteamUser { steam_id: steam_id.raw(), name: client.friends().name(), avatar }
+}
+
+/// 运行 Steam 回调，并在头像下载完成后补全 [`SteamUser::avatar`] (First)
+pub fn steam_callbacks_system(
+    callbacks: Option<NonSend<SteamCallbacks>>,
+    client: Option<Res<SteamClient>>,
+    user: Option<ResMut<SteamUser>>,
+) {
+    let Some(callbacks) = callbacks else { return };
+    callbacks.0.run_callbacks();
+
+    if let (Some(client), Some(mut user)) = (client, user) {
+        if user.avatar.is_none() {
+            if let Some(avatar) = read_user(&client.0).avatar {
+                user.avatar = Some(avatar);
+            }
+        }
+    }
+}
+
+/// 同步富状态 (Last)
+pub fn rich_presence_system(presence: Res<RichPresence>, client: Option<Res<SteamClient>>) {
+    let Some(client) = client else { return };
+    if !presence.is_changed() {
+        return;
+    }
+    let friends = client.0.friends();
+    friends.clear_rich_presence();
+    for (key, value) in presence.iter() {
+        if !friends.set_rich_presence(key, Some(value)) {
+            log::warn!("设置 Steam 富状态失败: {}={}", key, value);
+        }
+    }
+}
+
+/// 收到 `AppExit` 后释放 Steam 客户端 (Last)
+pub fn steam_shutdown_system(world: &mut World) {
+    let exiting = world.get_resource::<Events<AppExit>>().is_some_and(|events| !events.is_empty());
+    if !exiting || !world.contains_resource::<SteamClient>() {
+        return;
+    }
+    log::info!("关闭 Steam API");
+    world.remove_resource::<SteamClient>();
+    world.remove_resource::<SteamUser>();
+    world.remove_non_send_resource::<SteamCallbacks>();
+    if let Some(mut achievements) = world.get_resource_mut::<Achievements>() {
+        achievements.set_backend(crate::achievements::LocalAchievementBackend);
+    }
+}
+
+/// Steamworks 插件
+pub struct SteamPlugin {
+    /// Steam App ID；`None` 时读取 `steam_appid.txt` / 由 Steam 客户端启动时提供
+    pub app_id: Option<u32>,
+}
+
+impl SteamPlugin {
+    /// 指定 App ID
+    pub fn new(app_id: u32) -> Self {
+        Self { app_id: Some(app_id) }
+    }
+}
+
+impl Plugin for SteamPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RichPresence>()
+            .add_systems(bevy_app::First, steam_callbacks_system)
+            .add_systems(bevy_app::Last, (rich_presence_system, steam_shutdown_system).chain());
+
+        let init = match self.app_id {
+            Some(app_id) => Client::init_app(app_id),
+            None => Client::init(),
+        };
+        let (client, single) = match init {
+            Ok(pair) => pair,
+            Err(e) => {
+                log::warn!("Steam 初始化失败，Steam 功能不可用: {}", e);
+                return;
+            }
+        };
+        log::info!("Steam 已初始化 (app id {})", client.utils().app_id().0);
+
+        app.insert_resource(read_user(&client))
+            .insert_resource(SteamClient(client))
+            .insert_non_send_resource(SteamCallbacks(single));
+    }
+
+    /// 所有插件构建完成后接管成就后端
+    ///
+    /// 引擎的 runner（`RenderApp::run`、`AnvilKitApp::run`）在进入事件循环前调用
+    /// `finish`，因此与 `AchievementsPlugin` 的添加顺序无关；自行驱动 `App::update`
+    /// 时需先调用 `app.finish()`。
+    fn finish(&self, app: &mut App) {
+        let Some(client) = app.world().get_resource::<SteamClient>().map(|c| c.0.clone()) else { return };
+        if let Some(mut achievements) = app.world_mut().get_resource_mut::<Achievements>() {
+            achievements.set_backend(SteamAchievementBackend::new(client));
+        }
+    }
+
+    fn name(&self) -> &str {
+        "SteamPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rich_presence_entries() {
+        let mut presence = RichPresence::default();
+        presence.set("steam_display", "#InLevel");
+        presence.set("level", "Forest");
+        presence.set("level", "Cave");
+        assert_eq!(presence.get("level"), Some("Cave"));
+        assert_eq!(
+            presence.iter().collect::<Vec<_>>(),
+            vec![("level", "Cave"), ("steam_display", "#InLevel")]
+        );
+        presence.remove("level");
+        assert_eq!(presence.get("level"), None);
+    }
+}
@@ -0,0 +1,436 @@
+//! # 回合制调度
+//!
+//! 为回合制 / Roguelike 游戏提供独立于帧循环的回合推进：
+//!
+//! - [`TurnSchedule`]: 回合阶段调度标签（`RoundStart` / `TurnStart` / `TurnEnd`）
+//! - [`InitiativeQueue`]: 先攻队列资源，按先攻值降序轮转行动实体
+//! - [`advance_turn`] / [`TurnControl`]: 显式推进回合
+//!
+//! 回合阶段调度只在推进回合时运行，不会每帧执行。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_app::turn::{InitiativeQueue, TurnPlugin, TurnSchedule, advance_turn};
+//!
+//! fn begin_turn(queue: Res<InitiativeQueue>) {
+//!     println!("{:?} 的回合", queue.current());
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(AnvilKitEcsPlugin);
+//! app.add_plugins(TurnPlugin);
+//! app.add_systems(TurnSchedule::TurnStart, begin_turn);
+//!
+//! let hero = app.world_mut().spawn_empty().id();
+//! app.world_mut().resource_mut::<InitiativeQueue>().add(hero, 12);
+//!
+//! advance_turn(app.world_mut());
+//! assert_eq!(app.world().resource::<InitiativeQueue>().current(), Some(hero));
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ScheduleLabel;
+use bevy_app::{App, Plugin};
+use crate::schedule::AnvilKitSchedule;
+
+/// 回合阶段调度标签
+///
+/// 由 [`advance_turn`] 按顺序运行：`TurnEnd`（上一个行动者）→
+/// `RoundStart`（队列轮转一圈时）→ `TurnStart`（新行动者）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScheduleLabel)]
+pub enum TurnSchedule {
+    /// 新一轮开始（所有参与者都行动过一次后）
+    RoundStart,
+    /// 当前行动者回合开始
+    TurnStart,
+    /// 当前行动者回合结束
+    TurnEnd,
+}
+
+/// 先攻队列条目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitiativeEntry {
+    /// 参与者实体
+    pub entity: Entity,
+    /// 先攻值（越高越先行动）
+    pub initiative: i32,
+}
+
+/// 队列游标
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Cursor {
+    /// 尚未推进过
+    #[default]
+    NotStarted,
+    /// 当前行动者的索引
+    Acting(usize),
+    /// 当前行动者已被移除，下一次推进从该索引继续（越界则开始新一轮）
+    Resume(usize),
+}
+
+/// 先攻队列资源
+///
+/// 按先攻值降序排列参与者（同值按加入顺序），[`advance`](Self::advance)
+/// 依次轮转。首次推进前、以及当前行动者被移除后，没有当前行动者。
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InitiativeQueue {
+    entries: Vec<InitiativeEntry>,
+    cursor: Cursor,
+    round: u32,
+    turn: u64,
+}
+
+impl InitiativeQueue {
+    /// 创建空队列
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加入参与者（已存在则更新先攻值）
+    pub fn add(&mut self, entity: Entity, initiative: i32) {
+        let current_entity = self.current();
+        if let Some(idx) = self.index_of(entity) {
+            self.entries.remove(idx);
+            self.shift_resume(|next| if idx < next { next - 1 } else { next });
+        }
+        let pos = self.entries.iter().position(|e| e.initiative < initiative).unwrap_or(self.entries.len());
+        self.entries.insert(pos, InitiativeEntry { entity, initiative });
+        // 插入到续行位置之前的参与者本轮已错过；插入在续行位置上则下一个行动
+        self.shift_resume(|next| if pos < next { next + 1 } else { next });
+        if let Some(idx) = current_entity.and_then(|c| self.index_of(c)) {
+            self.cursor = Cursor::Acting(idx);
+        }
+    }
+
+    /// 移除参与者
+    ///
+    /// 若为当前行动者，此后 [`current`](Self::current) 返回 `None`，
+    /// 下一次推进轮到其后的参与者（本轮不会重新开始）。
+    pub fn remove(&mut self, entity: Entity) -> bool {
+        let Some(idx) = self.index_of(entity) else { return false };
+        self.entries.remove(idx);
+        self.cursor = match self.cursor {
+            Cursor::Acting(cur) if idx == cur => Cursor::Resume(cur),
+            Cursor::Acting(cur) if idx < cur => Cursor::Acting(cur - 1),
+            Cursor::Resume(next) if idx < next => Cursor::Resume(next - 1),
+            cursor => cursor,
+        };
+        true
+    }
+
+    /// 当前行动者
+    pub fn current(&self) -> Option<Entity> {
+        match self.cursor {
+            Cursor::Acting(i) => self.entries.get(i).map(|e| e.entity),
+            _ => None,
+        }
+    }
+
+    /// 推进到下一个行动者，返回 `(实体, 是否开始新一轮)`
+    pub fn advance(&mut self) -> Option<(Entity, bool)> {
+        if self.entries.is_empty() {
+            self.cursor = Cursor::NotStarted;
+            return None;
+        }
+        let (next, new_round) = match self.cursor {
+            Cursor::Acting(i) if i + 1 < self.entries.len() => (i + 1, false),
+            Cursor::Resume(next) if next < self.entries.len() => (next, false),
+            _ => (0, true),
+        };
+        if new_round {
+            self.round += 1;
+        }
+        self.turn += 1;
+        self.cursor = Cursor::Acting(next);
+        Some((self.entries[next].entity, new_round))
+    }
+
+    /// 当前轮数（从 1 开始，未开始时为 0）
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// 累计回合数
+    pub fn turn(&self) -> u64 {
+        self.turn
+    }
+
+    /// 参与者数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 按行动顺序遍历参与者
+    pub fn iter(&self) -> impl Iterator<Item = &InitiativeEntry> {
+        self.entries.iter()
+    }
+
+    fn index_of(&self, entity: Entity) -> Option<usize> {
+        self.entries.iter().position(|e| e.entity == entity)
+    }
+
+    fn shift_resume(&mut self, f: impl FnOnce(usize) -> usize) {
+        if let Cursor::Resume(next) = self.cursor {
+            self.cursor = Cursor::Resume(f(next));
+        }
+    }
+}
+
+/// 回合开始事件
+#[derive(Debug, Clone, Event)]
+pub struct TurnStarted {
+    /// 行动者
+    pub entity: Entity,
+    /// 累计回合数
+    pub turn: u64,
+    /// 当前轮数
+    pub round: u32,
+}
+
+/// 回合推进请求资源
+///
+/// 系统中调用 [`request_advance`](Self::request_advance)，
+/// 由 `turn_control_system` 在同一帧内执行 [`advance_turn`]。
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TurnControl {
+    pending: u32,
+}
+
+impl TurnControl {
+    /// 请求推进一个回合
+    pub fn request_advance(&mut self) {
+        self.pending += 1;
+    }
+
+    /// 待处理的推进请求数
+    pub fn pending(&self) -> u32 {
+        self.pending
+    }
+}
+
+/// 推进一个回合
+///
+/// 依次运行 `TurnEnd`（若已有行动者）、`RoundStart`（新一轮时）、`TurnStart`，
+/// 并发送 [`TurnStarted`]。返回新的行动者；队列为空时返回 `None`。
+pub fn advance_turn(world: &mut World) -> Option<Entity> {
+    let had_current = world
+        .get_resource::<InitiativeQueue>()
+        .is_some_and(|q| q.current().is_some());
+    if had_current {
+        let _ = world.try_run_schedule(TurnSchedule::TurnEnd);
+    }
+
+    let mut queue = world.get_resource_mut::<InitiativeQueue>()?;
+    let (entity, new_round) = queue.advance()?;
+    let (turn, round) = (queue.turn(), queue.round());
+
+    if new_round {
+        let _ = world.try_run_schedule(TurnSchedule::RoundStart);
+    }
+    let _ = world.try_run_schedule(TurnSchedule::TurnStart);
+
+    if let Some(mut events) = world.get_resource_mut::<Events<TurnStarted>>() {
+        events.send(TurnStarted { entity, turn, round });
+    }
+    Some(entity)
+}
+
+/// 处理 [`TurnControl`] 中的推进请求（独占系统）
+pub fn turn_control_system(world: &mut World) {
+    let pending = world
+        .get_resource_mut::<TurnControl>()
+        .map_or(0, |mut c| std::mem::take(&mut c.pending));
+    for _ in 0..pending {
+        if advance_turn(world).is_none() {
+            break;
+        }
+    }
+}
+
+/// 回合制插件
+///
+/// 注册 [`InitiativeQueue`]、[`TurnControl`]、[`TurnStarted`] 与回合阶段调度，
+/// 并在 `PostUpdate` 阶段运行 `turn_control_system`。
+pub struct TurnPlugin;
+
+impl Plugin for TurnPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InitiativeQueue>();
+        app.init_resource::<TurnControl>();
+        app.add_event::<TurnStarted>();
+        app.init_schedule(TurnSchedule::RoundStart);
+        app.init_schedule(TurnSchedule::TurnStart);
+        app.init_schedule(TurnSchedule::TurnEnd);
+        app.add_systems(AnvilKitSchedule::PostUpdate, turn_control_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs_plugin::AnvilKitEcsPlugin;
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<&'static str>);
+
+    fn entities(world: &mut World, n: usize) -> Vec<Entity> {
+        (0..n).map(|_| world.spawn_empty().id()).collect()
+    }
+
+    #[test]
+    fn test_queue_orders_by_initiative() {
+        let mut world = World::new();
+        let e = entities(&mut world, 3);
+        let mut q = InitiativeQueue::new();
+        q.add(e[0], 5);
+        q.add(e[1], 20);
+        q.add(e[2], 5);
+
+        let order: Vec<_> = q.iter().map(|x| x.entity).collect();
+        assert_eq!(order, vec![e[1], e[0], e[2]]);
+    }
+
+    #[test]
+    fn test_queue_advance_wraps_rounds() {
+        let mut world = World::new();
+        let e = entities(&mut world, 2);
+        let mut q = InitiativeQueue::new();
+        q.add(e[0], 10);
+        q.add(e[1], 1);
+
+        assert_eq!(q.current(), None);
+        assert_eq!(q.advance(), Some((e[0], true)));
+        assert_eq!(q.advance(), Some((e[1], false)));
+        assert_eq!(q.advance(), Some((e[0], true)));
+        assert_eq!(q.round(), 2);
+        assert_eq!(q.turn(), 3);
+    }
+
+    #[test]
+    fn test_remove_current_keeps_order() {
+        let mut world = World::new();
+        let e = entities(&mut world, 3);
+        let mut q = InitiativeQueue::new();
+        q.add(e[0], 3);
+        q.add(e[1], 2);
+        q.add(e[2], 1);
+        q.advance();
+        q.advance();
+        assert_eq!(q.current(), Some(e[1]));
+
+        assert!(q.remove(e[1]));
+        assert_eq!(q.current(), None);
+        assert_eq!(q.advance(), Some((e[2], false)));
+        assert!(!q.remove(e[1]));
+    }
+
+    #[test]
+    fn test_remove_current_at_front_stays_in_round() {
+        let mut world = World::new();
+        let e = entities(&mut world, 3);
+        let mut q = InitiativeQueue::new();
+        q.add(e[0], 3);
+        q.add(e[1], 2);
+        q.add(e[2], 1);
+        q.advance();
+        assert_eq!(q.current(), Some(e[0]));
+
+        assert!(q.remove(e[0]));
+        assert_eq!(q.current(), None);
+        assert_eq!(q.advance(), Some((e[1], false)));
+        assert_eq!(q.advance(), Some((e[2], false)));
+        assert_eq!(q.advance(), Some((e[1], true)));
+        assert_eq!(q.round(), 2);
+    }
+
+    #[test]
+    fn test_remove_current_at_back_starts_new_round() {
+        let mut world = World::new();
+        let e = entities(&mut world, 2);
+        let mut q = InitiativeQueue::new();
+        q.add(e[0], 2);
+        q.add(e[1], 1);
+        q.advance();
+        q.advance();
+
+        assert!(q.remove(e[1]));
+        assert_eq!(q.current(), None);
+        assert_eq!(q.advance(), Some((e[0], true)));
+    }
+
+    #[test]
+    fn test_add_after_removing_current_keeps_resume_point() {
+        let mut world = World::new();
+        let e = entities(&mut world, 4);
+        let mut q = InitiativeQueue::new();
+        q.add(e[0], 30);
+        q.add(e[1], 20);
+        q.add(e[2], 10);
+        q.advance();
+        q.advance();
+        q.remove(e[1]);
+
+        // 先攻落在续行位置的新参与者按顺序接着行动
+        q.add(e[3], 25);
+        assert_eq!(q.advance(), Some((e[3], false)));
+        assert_eq!(q.advance(), Some((e[2], false)));
+
+        // 先攻高于当前行动者的新参与者本轮已错过
+        q.remove(e[2]);
+        q.add(e[1], 40);
+        assert_eq!(q.advance(), Some((e[1], true)));
+    }
+
+    #[test]
+    fn test_advance_turn_runs_phase_schedules() {
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app.add_plugins(TurnPlugin);
+        app.init_resource::<Log>();
+        app.add_systems(TurnSchedule::RoundStart, |mut log: ResMut<Log>| log.0.push("round"));
+        app.add_systems(TurnSchedule::TurnStart, |mut log: ResMut<Log>| log.0.push("start"));
+        app.add_systems(TurnSchedule::TurnEnd, |mut log: ResMut<Log>| log.0.push("end"));
+
+        let e = entities(app.world_mut(), 2);
+        app.world_mut().resource_mut::<InitiativeQueue>().add(e[0], 2);
+        app.world_mut().resource_mut::<InitiativeQueue>().add(e[1], 1);
+
+        // Frames alone never advance turns
+        app.update();
+        assert!(app.world().resource::<Log>().0.is_empty());
+
+        assert_eq!(advance_turn(app.world_mut()), Some(e[0]));
+        app.world_mut().resource_mut::<TurnControl>().request_advance();
+        app.update();
+
+        assert_eq!(app.world().resource::<InitiativeQueue>().current(), Some(e[1]));
+        assert_eq!(app.world().resource::<Log>().0, vec!["round", "start", "end", "start"]);
+    }
+
+    #[test]
+    fn test_removed_actor_gets_no_turn_end() {
+        let mut app = App::new();
+        app.add_plugins(AnvilKitEcsPlugin);
+        app.add_plugins(TurnPlugin);
+        app.init_resource::<Log>();
+        app.add_systems(TurnSchedule::RoundStart, |mut log: ResMut<Log>| log.0.push("round"));
+        app.add_systems(TurnSchedule::TurnStart, |mut log: ResMut<Log>| log.0.push("start"));
+        app.add_systems(TurnSchedule::TurnEnd, |mut log: ResMut<Log>| log.0.push("end"));
+
+        let e = entities(app.world_mut(), 2);
+        app.world_mut().resource_mut::<InitiativeQueue>().add(e[0], 2);
+        app.world_mut().resource_mut::<InitiativeQueue>().add(e[1], 1);
+        advance_turn(app.world_mut());
+        app.world_mut().resource_mut::<InitiativeQueue>().remove(e[0]);
+
+        assert_eq!(advance_turn(app.world_mut()), Some(e[1]));
+        assert_eq!(app.world().resource::<Log>().0, vec!["round", "start", "start"]);
+    }
+}
@@ -9,7 +9,7 @@
 //! - [`SkinData`]: 蒙皮数据（骨骼权重和索引）
 //! - [`AnimationClip`]: 动画剪辑（关键帧序列）
 
-use glam::Mat4;
+use glam::{Mat4, Quat, Vec3};
 
 /// 单个关节（骨骼节点）
 ///
@@ -121,6 +121,8 @@ pub enum AnimationProperty {
     Rotation,
     /// Scale property.
     Scale,
+    /// 按名称读取的自定义 `f32` 属性（取关键帧值的第一个分量，忽略 `joint_index`）
+    Custom(&'static str),
 }
 
 /// 单个动画通道（一个关节的一个属性的关键帧序列）
@@ -220,7 +222,7 @@ impl AnimationChannel {
 pub struct Keyframe {
     /// 时间戳（秒）
     pub time: f32,
-    /// 值（Translation: xyz+0, Rotation: xyzw, Scale: xyz+0）
+    /// 值（Translation: xyz+0, Rotation: xyzw, Scale: xyz+0, Custom: x+000）
     pub value: [f32; 4],
 }
 
@@ -250,6 +252,100 @@ impl AnimationClip {
     pub fn duration(&self) -> f32 {
         self.channels.iter().map(|c| c.duration()).fold(0.0f32, f32::max)
     }
+
+    /// 在指定时间采样前 `joint_count` 个关节的局部姿态
+    pub fn sample_pose(&self, time: f32, joint_count: usize) -> Vec<JointPose> {
+        let mut pose = vec![JointPose::default(); joint_count];
+        for channel in &self.channels {
+            let Some(joint) = pose.get_mut(channel.joint_index) else { continue };
+            let value = channel.sample(time);
+            match channel.property {
+                AnimationProperty::Translation => {
+                    joint.translation = Some(Vec3::new(value[0], value[1], value[2]));
+                }
+                AnimationProperty::Rotation => {
+                    joint.rotation = Some(Quat::from_xyzw(value[0], value[1], value[2], value[3]).normalize());
+                }
+                AnimationProperty::Scale => {
+                    joint.scale = Some(Vec3::new(value[0], value[1], value[2]));
+                }
+                AnimationProperty::Custom(_) => {}
+            }
+        }
+        pose
+    }
+
+    /// 在指定时间采样所有自定义属性通道
+    pub fn sample_properties(&self, time: f32) -> Vec<(&'static str, f32)> {
+        self.channels
+            .iter()
+            .filter_map(|channel| match channel.property {
+                AnimationProperty::Custom(name) => Some((name, channel.sample(time)[0])),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// 单个关节的局部姿态；`None` 表示剪辑不驱动该属性
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct JointPose {
+    /// 平移
+    pub translation: Option<Vec3>,
+    /// 旋转
+    pub rotation: Option<Quat>,
+    /// 缩放
+    pub scale: Option<Vec3>,
+}
+
+impl JointPose {
+    /// 与另一姿态混合，`weight = 0` 为 `self`，`weight = 1` 为 `other`；
+    /// 只有一方驱动的属性直接取该方的值
+    pub fn blend(&self, other: &JointPose, weight: f32) -> JointPose {
+        JointPose {
+            translation: match (self.translation, other.translation) {
+                (Some(a), Some(b)) => Some(a.lerp(b, weight)),
+                (a, b) => a.or(b),
+            },
+            rotation: match (self.rotation, other.rotation) {
+                (Some(a), Some(b)) => Some(a.slerp(b, weight)),
+                (a, b) => a.or(b),
+            },
+            scale: match (self.scale, other.scale) {
+                (Some(a), Some(b)) => Some(a.lerp(b, weight)),
+                (a, b) => a.or(b),
+            },
+        }
+    }
+
+    /// 组合为 T × R × S 局部矩阵（未驱动的属性取单位值）
+    pub fn to_matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(
+            self.scale.unwrap_or(Vec3::ONE),
+            self.rotation.unwrap_or(Quat::IDENTITY),
+            self.translation.unwrap_or(Vec3::ZERO),
+        )
+    }
+}
+
+/// 交叉淡出中的旧剪辑
+#[derive(Debug, Clone)]
+pub struct AnimationFade {
+    /// 正在淡出的剪辑
+    pub clip: AnimationClip,
+    /// 旧剪辑的播放时间
+    pub time: f32,
+    /// 过渡总时长（秒）
+    pub duration: f32,
+    /// 已过渡时间（秒）
+    pub elapsed: f32,
+}
+
+impl AnimationFade {
+    /// 新剪辑的混合权重（0 → 1）
+    pub fn weight(&self) -> f32 {
+        if self.duration <= 0.0 { 1.0 } else { (self.elapsed / self.duration).clamp(0.0, 1.0) }
+    }
 }
 
 /// 动画播放器（运行时状态）
@@ -278,6 +374,8 @@ pub struct AnimationPlayer {
     pub looping: bool,
     /// 播放速度（1.0 = 正常）
     pub speed: f32,
+    /// 交叉淡出中的旧剪辑；淡出期间再次切换会直接丢弃更早的剪辑
+    pub fade: Option<AnimationFade>,
 }
 
 impl AnimationPlayer {
@@ -289,22 +387,105 @@ impl AnimationPlayer {
             playing: false,
             looping: true,
             speed: 1.0,
+            fade: None,
+        }
+    }
+
+    /// 从头播放剪辑
+    pub fn play(&mut self, clip: AnimationClip) {
+        self.clip = clip;
+        self.current_time = 0.0;
+        self.playing = true;
+        self.fade = None;
+    }
+
+    /// 在 `duration` 秒内从当前剪辑过渡到新剪辑，旧剪辑淡出期间继续播放
+    pub fn crossfade(&mut self, clip: AnimationClip, duration: f32) {
+        if duration <= 0.0 {
+            self.play(clip);
+            return;
         }
+        let previous = std::mem::replace(&mut self.clip, clip);
+        self.fade = Some(AnimationFade { clip: previous, time: self.current_time, duration, elapsed: 0.0 });
+        self.current_time = 0.0;
+        self.playing = true;
+    }
+
+    /// 暂停
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// 继续播放
+    pub fn resume(&mut self) {
+        self.playing = true;
+    }
+
+    /// 跳转到指定时间（钳制到剪辑范围内）
+    pub fn seek(&mut self, time: f32) {
+        self.current_time = time.clamp(0.0, self.clip.duration());
     }
 
     /// 推进时间
     pub fn advance(&mut self, dt: f32) {
         if !self.playing { return; }
-        self.current_time += dt * self.speed;
-        let duration = self.clip.duration();
-        if duration > 0.0 && self.current_time > duration {
-            if self.looping {
-                self.current_time %= duration;
-            } else {
-                self.current_time = duration;
-                self.playing = false;
+        let step = dt * self.speed;
+        let (time, finished) = Self::step_time(self.current_time, step, self.clip.duration(), self.looping);
+        self.current_time = time;
+        if finished {
+            self.playing = false;
+        }
+        if let Some(fade) = &mut self.fade {
+            fade.time = Self::step_time(fade.time, step, fade.clip.duration(), self.looping).0;
+            fade.elapsed += dt;
+            if fade.elapsed >= fade.duration {
+                self.fade = None;
+            }
+        }
+    }
+
+    /// 返回推进后的时间，以及非循环剪辑是否播放到了尽头
+    fn step_time(time: f32, step: f32, duration: f32, looping: bool) -> (f32, bool) {
+        let time = time + step;
+        if duration <= 0.0 {
+            return (time, false);
+        }
+        if looping {
+            (time.rem_euclid(duration), false)
+        } else if time >= duration {
+            (duration, true)
+        } else if time <= 0.0 {
+            (0.0, step < 0.0)
+        } else {
+            (time, false)
+        }
+    }
+
+    /// 当前（混合后的）前 `joint_count` 个关节的局部姿态
+    pub fn pose(&self, joint_count: usize) -> Vec<JointPose> {
+        let pose = self.clip.sample_pose(self.current_time, joint_count);
+        let Some(fade) = &self.fade else { return pose };
+        let weight = fade.weight();
+        fade.clip
+            .sample_pose(fade.time, joint_count)
+            .iter()
+            .zip(&pose)
+            .map(|(old, new)| old.blend(new, weight))
+            .collect()
+    }
+
+    /// 当前（混合后的）自定义属性值
+    pub fn properties(&self) -> Vec<(&'static str, f32)> {
+        let mut values = self.clip.sample_properties(self.current_time);
+        let Some(fade) = &self.fade else { return values };
+        let weight = fade.weight();
+        for (name, old) in fade.clip.sample_properties(fade.time) {
+            match values.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, value)) => *value = old + (*value - old) * weight,
+                None => values.push((name, old)),
             }
         }
+        values
     }
 }
 
@@ -318,40 +499,9 @@ impl AnimationPlayer {
 /// 顶点着色器中: skinned_pos = sum(weight[i] * bone_matrices[joint[i]] * pos)
 pub fn compute_bone_matrices(skeleton: &Skeleton, player: &AnimationPlayer) -> Vec<Mat4> {
     let n = skeleton.joint_count();
-    let mut local_transforms: Vec<Mat4> = vec![Mat4::IDENTITY; n];
-
-    // Accumulate T/R/S per joint separately, then compose as T × R × S (glTF standard)
-    let mut translations: Vec<Option<glam::Vec3>> = vec![None; n];
-    let mut rotations: Vec<Option<glam::Quat>> = vec![None; n];
-    let mut scales: Vec<Option<glam::Vec3>> = vec![None; n];
-
-    for channel in &player.clip.channels {
-        let idx = channel.joint_index;
-        if idx >= n { continue; }
-        let value = channel.sample(player.current_time);
-
-        match channel.property {
-            AnimationProperty::Translation => {
-                translations[idx] = Some(glam::Vec3::new(value[0], value[1], value[2]));
-            }
-            AnimationProperty::Rotation => {
-                rotations[idx] = Some(
-                    glam::Quat::from_xyzw(value[0], value[1], value[2], value[3]).normalize()
-                );
-            }
-            AnimationProperty::Scale => {
-                scales[idx] = Some(glam::Vec3::new(value[0], value[1], value[2]));
-            }
-        }
-    }
 
-    // Compose per-joint: T × R × S
-    for idx in 0..n {
-        let t = translations[idx].unwrap_or(glam::Vec3::ZERO);
-        let r = rotations[idx].unwrap_or(glam::Quat::IDENTITY);
-        let s = scales[idx].unwrap_or(glam::Vec3::ONE);
-        local_transforms[idx] = Mat4::from_scale_rotation_translation(s, r, t);
-    }
+    // Per-joint T/R/S (blended while crossfading), composed as T × R × S (glTF standard)
+    let local_transforms: Vec<Mat4> = player.pose(n).iter().map(JointPose::to_matrix).collect();
 
     // Propagate through hierarchy to get global transforms
     let mut global_transforms: Vec<Mat4> = vec![Mat4::IDENTITY; n];
@@ -377,7 +527,7 @@ pub fn compute_bone_matrices(skeleton: &Skeleton, player: &AnimationPlayer) -> V
 
 /// GPU 骨骼矩阵组件
 ///
-/// 每帧由 `animation_system` 从 Skeleton + AnimationPlayer 计算并填充。
+/// 每帧由渲染层的 `animation_player_system` 从 Skeleton + AnimationPlayer 计算并填充。
 /// 渲染系统将其上传到 storage buffer 供 skinned vertex shader 使用。
 ///
 /// 最多 128 个关节（与 skinned_pbr.wgsl 中 MAX_JOINTS 匹配）。
@@ -454,6 +604,78 @@ mod tests {
         assert!((player.current_time - 0.5).abs() < 0.001);
     }
 
+    fn slide_clip() -> AnimationClip {
+        AnimationClip {
+            name: "Slide".into(),
+            channels: vec![
+                AnimationChannel {
+                    joint_index: 0,
+                    property: AnimationProperty::Translation,
+                    interpolation: Interpolation::Linear,
+                    keyframes: vec![
+                        Keyframe { time: 0.0, value: [0.0; 4] },
+                        Keyframe { time: 1.0, value: [1.0, 0.0, 0.0, 0.0] },
+                    ],
+                },
+                AnimationChannel {
+                    joint_index: 0,
+                    property: AnimationProperty::Custom("glow"),
+                    interpolation: Interpolation::Linear,
+                    keyframes: vec![
+                        Keyframe { time: 0.0, value: [0.0; 4] },
+                        Keyframe { time: 1.0, value: [1.0, 0.0, 0.0, 0.0] },
+                    ],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_player_pause_seek_and_finish() {
+        let mut player = AnimationPlayer::new(slide_clip());
+        player.looping = false;
+        player.play(slide_clip());
+        player.advance(0.25);
+        assert_eq!(player.properties(), vec![("glow", 0.25)]);
+
+        player.pause();
+        player.advance(0.5);
+        assert_eq!(player.current_time, 0.25);
+
+        player.resume();
+        player.seek(0.9);
+        player.advance(0.5);
+        assert_eq!(player.current_time, 1.0);
+        assert!(!player.playing);
+        assert_eq!(player.pose(1)[0].translation, Some(Vec3::X));
+    }
+
+    #[test]
+    fn test_player_crossfade_blends_poses() {
+        let hold = AnimationClip {
+            name: "Hold".into(),
+            channels: vec![AnimationChannel {
+                joint_index: 0,
+                property: AnimationProperty::Translation,
+                interpolation: Interpolation::Step,
+                keyframes: vec![Keyframe { time: 0.0, value: [0.0, 2.0, 0.0, 0.0] }],
+            }],
+        };
+        let mut player = AnimationPlayer::new(slide_clip());
+        player.play(slide_clip());
+        player.speed = 0.0;
+        player.crossfade(hold, 1.0);
+        player.advance(0.5);
+        let pose = player.pose(1);
+        assert!((pose[0].translation.unwrap() - Vec3::new(0.0, 1.0, 0.0)).length() < 1e-6);
+        // 只有旧剪辑驱动的属性保持旧值
+        assert_eq!(player.properties(), vec![("glow", 0.0)]);
+
+        player.advance(0.5);
+        assert!(player.fade.is_none());
+        assert_eq!(player.pose(1)[0].translation, Some(Vec3::new(0.0, 2.0, 0.0)));
+    }
+
     #[test]
     fn test_compute_bone_matrices_identity() {
         let skeleton = Skeleton {
@@ -14,6 +14,8 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::sync::mpsc;
 
+use anvilkit_core::collections::Istr;
+
 use crate::asset_cache::{AssetCache, AssetCacheConfig};
 use crate::dependency::DependencyGraph;
 use crate::parsed_asset::ParsedAsset;
@@ -237,8 +239,8 @@ pub struct AsyncLoadResult {
 pub struct AssetServer {
     /// 资产根目录
     asset_root: PathBuf,
-    /// 路径 → AssetId 映射（去重）
-    path_to_id: HashMap<PathBuf, AssetId>,
+    /// 驻留路径 → AssetId 映射（去重，O(1) 比较与哈希）
+    path_to_id: HashMap<Istr, AssetId>,
     /// AssetId → 路径 反向映射（用于 reload）
     id_to_path: HashMap<AssetId, PathBuf>,
     /// AssetId → 加载状态
@@ -309,7 +311,8 @@ impl AssetServer {
     /// 如果同一路径已请求过，返回相同 ID 的新句柄。
     pub fn load<T>(&mut self, path: impl AsRef<Path>) -> AssetHandle<T> {
         let full_path = self.asset_root.join(path.as_ref());
-        let id = *self.path_to_id.entry(full_path.clone()).or_insert_with(AssetId::next);
+        let key = Istr::new(&full_path.to_string_lossy());
+        let id = *self.path_to_id.entry(key).or_insert_with(AssetId::next);
         self.id_to_path.entry(id).or_insert_with(|| full_path.clone());
 
         if !self.states.contains_key(&id) {
@@ -390,7 +393,8 @@ impl AssetServer {
         #[cfg(feature = "hot-reload")]
         if let Some(ref mut watcher) = self.watcher {
             for changed_path in watcher.poll_changes() {
-                if let Some(&id) = self.path_to_id.get(&changed_path) {
+                let key = Istr::get(&changed_path.to_string_lossy());
+                if let Some(&id) = key.and_then(|key| self.path_to_id.get(&key)) {
                     log::info!("热重载: {:?}", changed_path);
                     self.reload(id);
                 }
@@ -10,6 +10,8 @@ use anvilkit_core::error::{AnvilKitError, Result};
 use crate::mesh::MeshData;
 use crate::material::{TextureData, MaterialData};
 use crate::scene::SceneData;
+use crate::import::ImportOptions;
+use anvilkit_core::math::CoordinateSystem;
 
 /// 从 glTF/GLB 文件加载第一个网格的第一个图元
 ///
@@ -89,6 +91,14 @@ pub fn load_gltf_mesh(path: impl AsRef<Path>) -> Result<MeshData> {
         .map(|t| t.collect())
         .unwrap_or_else(|| vec![[1.0, 0.0, 0.0, 1.0]; positions.len()]);
 
+    // 读取可选属性：COLOR_0 与 TEXCOORD_1（缺失则为空）
+    let colors: Vec<[f32; 4]> = reader.read_colors(0)
+        .map(|c| c.into_rgba_f32().collect())
+        .unwrap_or_default();
+    let texcoords1: Vec<Vec2> = reader.read_tex_coords(1)
+        .map(|tc| tc.into_f32().map(Vec2::from).collect())
+        .unwrap_or_default();
+
     // 读取索引（必须）
     let indices: Vec<u32> = reader.read_indices()
         .ok_or_else(|| AnvilKitError::asset_with_path(
@@ -106,6 +116,8 @@ pub fn load_gltf_mesh(path: impl AsRef<Path>) -> Result<MeshData> {
         texcoords,
         tangents,
         indices,
+        colors,
+        texcoords1,
     })
 }
 
@@ -172,6 +184,14 @@ pub fn load_gltf_scene(path: impl AsRef<Path>) -> Result<SceneData> {
         .map(|t| t.collect())
         .unwrap_or_else(|| vec![[1.0, 0.0, 0.0, 1.0]; positions.len()]);
 
+    // 读取可选属性：COLOR_0 与 TEXCOORD_1（缺失则为空）
+    let colors: Vec<[f32; 4]> = reader.read_colors(0)
+        .map(|c| c.into_rgba_f32().collect())
+        .unwrap_or_default();
+    let texcoords1: Vec<Vec2> = reader.read_tex_coords(1)
+        .map(|tc| tc.into_f32().map(Vec2::from).collect())
+        .unwrap_or_default();
+
     let indices: Vec<u32> = reader.read_indices()
         .ok_or_else(|| AnvilKitError::asset_with_path(
             "网格缺少索引数据".to_string(),
@@ -180,7 +200,7 @@ pub fn load_gltf_scene(path: impl AsRef<Path>) -> Result<SceneData> {
         .into_u32()
         .collect();
 
-    let mesh = MeshData { positions, normals, texcoords, tangents, indices };
+    let mesh = MeshData { positions, normals, texcoords, tangents, indices, colors, texcoords1 };
 
     // 提取材质数据
     let material = extract_material(&primitive, &images);
@@ -215,45 +235,155 @@ pub fn load_gltf_scene_multi(path: impl AsRef<Path>) -> Result<crate::scene::Mul
             path.to_string_lossy().to_string(),
         ))?;
 
-    let mut submeshes = Vec::new();
-
-    for gltf_mesh in document.meshes() {
-        for primitive in gltf_mesh.primitives() {
-            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let submeshes: Vec<crate::scene::Submesh> = document.meshes()
+        .flat_map(|gltf_mesh| gltf_mesh.primitives())
+        .filter_map(|primitive| read_submesh(&primitive, &buffers, &images))
+        .collect();
 
-            let Some(positions) = reader.read_positions().map(|p| p.map(Vec3::from).collect::<Vec<_>>()) else {
-                continue;
-            };
+    info!("多子网格场景加载完成: {} 个子网格", submeshes.len());
+    Ok(crate::scene::MultiMeshScene { submeshes })
+}
 
-            let normals: Vec<Vec3> = reader.read_normals()
-                .map(|n| n.map(Vec3::from).collect())
-                .unwrap_or_else(|| {
-                    log::warn!("Mesh missing normals, using default up direction");
-                    vec![Vec3::Y; positions.len()]
-                });
+/// 按导入选项加载多子网格场景，返回前执行坐标转换
+///
+/// glTF 规范约定右手、Y 上、模型正面 +Z；默认选项转换到引擎坐标系。
+pub fn load_gltf_scene_multi_with(
+    path: impl AsRef<Path>,
+    options: &ImportOptions,
+) -> Result<crate::scene::MultiMeshScene> {
+    let mut scene = load_gltf_scene_multi(path)?;
+    if let Some((from, to)) = options.conversion(CoordinateSystem::GLTF) {
+        scene.convert_coordinates(from, to);
+    }
+    Ok(scene)
+}
 
-            let texcoords: Vec<Vec2> = reader.read_tex_coords(0)
-                .map(|tc| tc.into_f32().map(Vec2::from).collect())
-                .unwrap_or_else(|| vec![Vec2::ZERO; positions.len()]);
+/// 从 glTF/GLB 文件加载带节点层级的完整场景
+///
+/// 保留所有网格（含多个 primitive）、材质与节点局部 TRS 变换。
+/// 根节点取默认场景（无默认场景时取第一个场景，无场景时取所有未被引用的节点）。
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use anvilkit_assets::gltf_loader::load_gltf_hierarchy;
+///
+/// let scene = load_gltf_hierarchy("assets/model.glb").expect("加载失败");
+/// println!("节点: {}, 子网格: {}", scene.node_count(), scene.submesh_count());
+/// ```
+pub fn load_gltf_hierarchy(path: impl AsRef<Path>) -> Result<crate::scene::SceneHierarchy> {
+    let path = path.as_ref();
+    info!("加载 glTF 层级场景: {}", path.display());
 
-            let tangents: Vec<[f32; 4]> = reader.read_tangents()
-                .map(|t| t.collect())
-                .unwrap_or_else(|| vec![[1.0, 0.0, 0.0, 1.0]; positions.len()]);
+    let (document, buffers, images) = gltf::import(path)
+        .map_err(|e| AnvilKitError::asset_with_path(
+            format!("glTF 导入失败: {}", e),
+            path.to_string_lossy().to_string(),
+        ))?;
 
-            let Some(indices) = reader.read_indices().map(|i| i.into_u32().collect::<Vec<_>>()) else {
-                continue;
-            };
+    let meshes = document.meshes()
+        .map(|gltf_mesh| crate::scene::SceneMesh {
+            name: gltf_mesh.name().map(str::to_string),
+            primitives: gltf_mesh.primitives()
+                .filter_map(|primitive| read_submesh(&primitive, &buffers, &images))
+                .collect(),
+        })
+        .collect();
 
-            let mesh = MeshData { positions, normals, texcoords, tangents, indices };
-            let material = extract_material(&primitive, &images);
+    let nodes: Vec<crate::scene::SceneNode> = document.nodes()
+        .map(|node| {
+            let (translation, rotation, scale) = node.transform().decomposed();
+            crate::scene::SceneNode {
+                name: node.name().map(str::to_string),
+                translation: Vec3::from(translation),
+                rotation: glam::Quat::from_array(rotation),
+                scale: Vec3::from(scale),
+                mesh: node.mesh().map(|m| m.index()),
+                children: node.children().map(|c| c.index()).collect(),
+            }
+        })
+        .collect();
 
-            info!("子网格: {} 顶点, {} 索引", mesh.vertex_count(), mesh.index_count());
-            submeshes.push(crate::scene::Submesh { mesh, material });
+    let roots: Vec<usize> = match document.default_scene().or_else(|| document.scenes().next()) {
+        Some(scene) => scene.nodes().map(|n| n.index()).collect(),
+        None => {
+            let mut is_child = vec![false; nodes.len()];
+            for child in nodes.iter().flat_map(|n| n.children.iter()) {
+                if let Some(flag) = is_child.get_mut(*child) {
+                    *flag = true;
+                }
+            }
+            (0..nodes.len()).filter(|&i| !is_child[i]).collect()
         }
+    };
+
+    let scene = crate::scene::SceneHierarchy { meshes, nodes, roots };
+    info!("层级场景加载完成: {} 个节点, {} 个子网格", scene.node_count(), scene.submesh_count());
+    Ok(scene)
+}
+
+/// 按导入选项加载层级场景，返回前转换网格顶点与节点变换
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use anvilkit_assets::gltf_loader::load_gltf_hierarchy_with;
+/// use anvilkit_assets::import::ImportOptions;
+///
+/// let scene = load_gltf_hierarchy_with("assets/model.glb", &ImportOptions::default()).expect("加载失败");
+/// println!("节点: {}", scene.node_count());
+/// ```
+pub fn load_gltf_hierarchy_with(
+    path: impl AsRef<Path>,
+    options: &ImportOptions,
+) -> Result<crate::scene::SceneHierarchy> {
+    let mut scene = load_gltf_hierarchy(path)?;
+    if let Some((from, to)) = options.conversion(CoordinateSystem::GLTF) {
+        scene.convert_coordinates(from, to);
     }
+    Ok(scene)
+}
 
-    info!("多子网格场景加载完成: {} 个子网格", submeshes.len());
-    Ok(crate::scene::MultiMeshScene { submeshes })
+/// 读取单个 primitive 的几何与材质（缺少位置或索引时返回 `None`）
+fn read_submesh(
+    primitive: &gltf::Primitive<'_>,
+    buffers: &[gltf::buffer::Data],
+    images: &[gltf::image::Data],
+) -> Option<crate::scene::Submesh> {
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+
+    let positions: Vec<Vec3> = reader.read_positions()?.map(Vec3::from).collect();
+
+    let normals: Vec<Vec3> = reader.read_normals()
+        .map(|n| n.map(Vec3::from).collect())
+        .unwrap_or_else(|| {
+            log::warn!("Mesh missing normals, using default up direction");
+            vec![Vec3::Y; positions.len()]
+        });
+
+    let texcoords: Vec<Vec2> = reader.read_tex_coords(0)
+        .map(|tc| tc.into_f32().map(Vec2::from).collect())
+        .unwrap_or_else(|| vec![Vec2::ZERO; positions.len()]);
+
+    let tangents: Vec<[f32; 4]> = reader.read_tangents()
+        .map(|t| t.collect())
+        .unwrap_or_else(|| vec![[1.0, 0.0, 0.0, 1.0]; positions.len()]);
+
+    // 读取可选属性：COLOR_0 与 TEXCOORD_1（缺失则为空）
+    let colors: Vec<[f32; 4]> = reader.read_colors(0)
+        .map(|c| c.into_rgba_f32().collect())
+        .unwrap_or_default();
+    let texcoords1: Vec<Vec2> = reader.read_tex_coords(1)
+        .map(|tc| tc.into_f32().map(Vec2::from).collect())
+        .unwrap_or_default();
+
+    let indices: Vec<u32> = reader.read_indices()?.into_u32().collect();
+
+    let mesh = MeshData { positions, normals, texcoords, tangents, indices, colors, texcoords1 };
+    let material = extract_material(primitive, images);
+
+    info!("子网格: {} 顶点, {} 索引", mesh.vertex_count(), mesh.index_count());
+    Some(crate::scene::Submesh { mesh, material })
 }
 
 /// 从 glTF primitive 提取材质数据
@@ -0,0 +1,83 @@
+//! # 导入选项
+//!
+//! 各加载器读取的数据保持源文件的坐标约定。`*_with` 系列加载函数接受 [`ImportOptions`]，
+//! 在返回前把网格与节点转换到引擎坐标系（左手，Y 上，+Z 前，见
+//! [`anvilkit_core::math::coordinates`]），从 Blender / 3ds Max 等 Z 上工具导出的资产
+//! 无需在项目中手动旋转或镜像。
+//!
+//! ```rust
+//! use anvilkit_assets::import::ImportOptions;
+//! use anvilkit_core::math::CoordinateSystem;
+//!
+//! // glTF 按规范为右手 Y 上；Z 上的 OBJ 需要显式声明
+//! let gltf = ImportOptions::default();
+//! assert_eq!(gltf.source_for(CoordinateSystem::GLTF), CoordinateSystem::GLTF);
+//! let z_up = ImportOptions::z_up();
+//! assert_eq!(z_up.source_for(CoordinateSystem::GLTF), CoordinateSystem::BLENDER);
+//! ```
+
+use anvilkit_core::math::CoordinateSystem;
+
+/// 资产导入选项
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImportOptions {
+    /// 资产的坐标约定；`None` 使用格式默认约定（glTF / OBJ：右手 Y 上）
+    pub source: Option<CoordinateSystem>,
+    /// 转换目标；`None` 保持源数据不变
+    pub target: Option<CoordinateSystem>,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self { source: None, target: Some(CoordinateSystem::ANVILKIT) }
+    }
+}
+
+impl ImportOptions {
+    /// 不做任何坐标转换（与不带 `_with` 的加载函数一致）
+    pub fn raw() -> Self {
+        Self { source: None, target: None }
+    }
+
+    /// 资产来自 Z 上的右手系工具（Blender、3ds Max、`upAxis = "Z"` 的 USD）
+    pub fn z_up() -> Self {
+        Self::default().with_source(CoordinateSystem::BLENDER)
+    }
+
+    /// 指定资产坐标约定（链式调用）
+    pub fn with_source(mut self, source: CoordinateSystem) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// 指定转换目标（链式调用）
+    pub fn with_target(mut self, target: CoordinateSystem) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// 资产实际使用的坐标约定
+    pub fn source_for(&self, format_default: CoordinateSystem) -> CoordinateSystem {
+        self.source.unwrap_or(format_default)
+    }
+
+    /// 需要执行的转换 `(from, to)`；源与目标相同或未设置目标时为 `None`
+    pub fn conversion(&self, format_default: CoordinateSystem) -> Option<(CoordinateSystem, CoordinateSystem)> {
+        let from = self.source_for(format_default);
+        self.target.filter(|&to| to != from).map(|to| (from, to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_options_conversion() {
+        let gltf = CoordinateSystem::GLTF;
+        assert_eq!(ImportOptions::default().conversion(gltf), Some((gltf, CoordinateSystem::ANVILKIT)));
+        assert_eq!(ImportOptions::raw().conversion(gltf), None);
+        let unity = ImportOptions::default().with_source(CoordinateSystem::UNITY);
+        assert_eq!(unity.conversion(gltf), None);
+    }
+}
@@ -17,6 +17,14 @@ pub mod mesh;
 pub mod material;
 pub mod scene;
 pub mod gltf_loader;
+pub mod import;
+/// Wavefront OBJ + MTL 加载
+pub mod obj_loader;
+/// SVG 子集加载（矢量路径 + 填充 / 描边样式）
+pub mod svg_loader;
+/// USDA / USDZ 加载（`usd` 特性）
+#[cfg(feature = "usd")]
+pub mod usd_loader;
 pub mod asset_server;
 /// Content-addressed asset cache with LRU eviction.
 pub mod asset_cache;
@@ -25,7 +33,7 @@ pub mod animation;
 pub mod audio_asset;
 /// 统一的解析结果类型
 pub mod parsed_asset;
-/// Procedural mesh generation utilities (sphere, plane, box).
+/// Procedural mesh generation utilities (sphere, plane, box, cylinder, capsule, torus).
 pub mod procedural;
 /// 独立纹理加载（PNG/JPEG → RGBA8）
 pub mod texture;
@@ -38,11 +46,20 @@ pub mod dependency;
 pub mod prelude {
     pub use crate::mesh::{MeshData, InterleavedPbrVertex};
     pub use crate::material::{TextureData, MaterialData};
-    pub use crate::scene::{SceneData, Submesh, MultiMeshScene};
-    pub use crate::gltf_loader::{load_gltf_mesh, load_gltf_scene, load_gltf_scene_multi, load_gltf_animations};
+    pub use crate::scene::{SceneData, Submesh, MultiMeshScene, SceneHierarchy, SceneNode, SceneMesh};
+    pub use crate::gltf_loader::{load_gltf_mesh, load_gltf_scene, load_gltf_scene_multi, load_gltf_hierarchy, load_gltf_animations};
+    pub use crate::gltf_loader::{load_gltf_scene_multi_with, load_gltf_hierarchy_with};
+    pub use crate::obj_loader::{load_obj, load_obj_with};
+    pub use crate::svg_loader::{load_svg, parse_svg, SvgDocument};
+    pub use crate::import::ImportOptions;
+    #[cfg(feature = "usd")]
+    pub use crate::usd_loader::{load_usd, load_usd_with, UsdScene};
     pub use crate::asset_server::{AssetServer, AssetHandle, AssetStorage, AssetId, LoadState};
     pub use crate::asset_cache::{AssetCache, AssetCacheConfig};
-    pub use crate::procedural::{generate_sphere, generate_plane, generate_box};
+    pub use crate::procedural::{
+        generate_sphere, generate_plane, generate_box, generate_subdivided_plane,
+        generate_cylinder, generate_capsule, generate_torus,
+    };
     pub use crate::texture::{load_texture, load_texture_from_memory};
     pub use crate::dependency::DependencyGraph;
 }
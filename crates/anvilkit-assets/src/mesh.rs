@@ -3,11 +3,13 @@
 //! 定义从 glTF 文件提取的网格数据结构。
 
 use glam::{Vec2, Vec3};
+use anvilkit_core::math::CoordinateSystem;
 
 /// CPU 侧网格数据
 ///
 /// 包含从 glTF 文件提取的顶点属性和索引数据。
 /// 所有属性数组长度一致（`positions.len() == normals.len() == texcoords.len() == tangents.len()`）。
+/// 可选属性 `colors` / `texcoords1` 为空表示网格不带该属性，否则长度同样与 `positions` 一致。
 ///
 /// # 示例
 ///
@@ -21,11 +23,12 @@ use glam::{Vec2, Vec3};
 ///     texcoords: vec![Vec2::ZERO, Vec2::X, Vec2::Y],
 ///     tangents: vec![[1.0, 0.0, 0.0, 1.0]; 3],
 ///     indices: vec![0, 1, 2],
+///     ..Default::default()
 /// };
 /// assert_eq!(mesh.vertex_count(), 3);
 /// assert_eq!(mesh.index_count(), 3);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct MeshData {
     /// 顶点位置（物体空间）
     pub positions: Vec<Vec3>,
@@ -37,6 +40,10 @@ pub struct MeshData {
     pub tangents: Vec<[f32; 4]>,
     /// 三角形索引 (u32)
     pub indices: Vec<u32>,
+    /// 顶点颜色（线性 RGBA，可选，空 = 无）
+    pub colors: Vec<[f32; 4]>,
+    /// 第二套纹理坐标（UV 通道 1，常用于光照贴图；可选，空 = 无）
+    pub texcoords1: Vec<Vec2>,
 }
 
 impl MeshData {
@@ -54,6 +61,7 @@ impl MeshData {
     ///     texcoords: vec![Vec2::ZERO; 100],
     ///     tangents: vec![[1.0, 0.0, 0.0, 1.0]; 100],
     ///     indices: vec![0; 300],
+    ///     ..Default::default()
     /// };
     /// assert_eq!(mesh.vertex_count(), 100);
     /// ```
@@ -79,6 +87,16 @@ impl MeshData {
                 "tangents.len()={} != positions.len()={}", self.tangents.len(), n
             ));
         }
+        if !self.colors.is_empty() && self.colors.len() != n {
+            return Err(format!(
+                "colors.len()={} != positions.len()={}", self.colors.len(), n
+            ));
+        }
+        if !self.texcoords1.is_empty() && self.texcoords1.len() != n {
+            return Err(format!(
+                "texcoords1.len()={} != positions.len()={}", self.texcoords1.len(), n
+            ));
+        }
         Ok(())
     }
 
@@ -101,6 +119,7 @@ impl MeshData {
     ///     texcoords: vec![Vec2::ZERO; 3],
     ///     tangents: vec![[1.0, 0.0, 0.0, 1.0]; 3],
     ///     indices: vec![0, 1, 2, 2, 1, 0],
+    ///     ..Default::default()
     /// };
     /// assert_eq!(mesh.index_count(), 6);
     /// ```
@@ -108,6 +127,34 @@ impl MeshData {
         self.indices.len()
     }
 
+    /// 把顶点数据从 `from` 坐标约定转换到 `to`
+    ///
+    /// 位置、法线与切线按轴映射；跨手性时翻转切线的副切线符号并反转三角形环绕方向，
+    /// 保持正面朝外。
+    pub fn convert_coordinates(&mut self, from: CoordinateSystem, to: CoordinateSystem) {
+        if from == to {
+            return;
+        }
+        let m = from.conversion_to(to);
+        for p in &mut self.positions {
+            *p = m * *p;
+        }
+        for n in &mut self.normals {
+            *n = m * *n;
+        }
+        let flip = from.flips_winding(to);
+        for t in &mut self.tangents {
+            let v = m * Vec3::new(t[0], t[1], t[2]);
+            let w = if flip { -t[3] } else { t[3] };
+            *t = [v.x, v.y, v.z, w];
+        }
+        if flip {
+            for tri in self.indices.chunks_exact_mut(3) {
+                tri.swap(1, 2);
+            }
+        }
+    }
+
     /// 转换为交错 PBR 顶点格式
     ///
     /// 返回 `Vec<InterleavedPbrVertex>`，每个元素 48 字节，
@@ -126,6 +173,7 @@ impl MeshData {
     ///     texcoords: vec![Vec2::new(0.5, 0.5)],
     ///     tangents: vec![[1.0, 0.0, 0.0, 1.0]],
     ///     indices: vec![0],
+    ///     ..Default::default()
     /// };
     /// let verts = mesh.to_pbr_vertices();
     /// assert_eq!(verts.len(), 1);
@@ -146,6 +194,48 @@ impl MeshData {
             .collect()
     }
 
+    /// 是否带顶点颜色
+    pub fn has_colors(&self) -> bool {
+        !self.colors.is_empty()
+    }
+
+    /// 是否带第二套纹理坐标
+    pub fn has_texcoords1(&self) -> bool {
+        !self.texcoords1.is_empty()
+    }
+
+    /// 转换为交错的可选属性顶点（与 `anvilkit-render` 的 `AttributeVertex` 布局一致）
+    ///
+    /// 缺失的颜色默认为白色，缺失的 UV1 回退为 UV0。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_assets::mesh::MeshData;
+    /// use glam::{Vec2, Vec3};
+    ///
+    /// let mesh = MeshData {
+    ///     positions: vec![Vec3::ZERO],
+    ///     normals: vec![Vec3::Y],
+    ///     texcoords: vec![Vec2::new(0.25, 0.75)],
+    ///     tangents: vec![[1.0, 0.0, 0.0, 1.0]],
+    ///     indices: vec![0],
+    ///     colors: vec![[1.0, 0.0, 0.0, 1.0]],
+    ///     ..Default::default()
+    /// };
+    /// let attrs = mesh.to_attribute_vertices();
+    /// assert_eq!(attrs[0].color, [1.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(attrs[0].texcoord1, [0.25, 0.75]);
+    /// ```
+    pub fn to_attribute_vertices(&self) -> Vec<InterleavedAttributeVertex> {
+        (0..self.vertex_count())
+            .map(|i| InterleavedAttributeVertex {
+                color: self.colors.get(i).copied().unwrap_or([1.0; 4]),
+                texcoord1: self.texcoords1.get(i).or(self.texcoords.get(i)).copied().unwrap_or(Vec2::ZERO).into(),
+            })
+            .collect()
+    }
+
     /// 生成立方体网格数据
     ///
     /// 以原点为中心，边长为 `size` 的立方体。
@@ -200,6 +290,7 @@ impl MeshData {
             texcoords,
             tangents: vec![[1.0, 0.0, 0.0, 1.0]; 24],
             indices,
+            ..Default::default()
         }
     }
 
@@ -233,6 +324,7 @@ impl MeshData {
             ],
             tangents: vec![[1.0, 0.0, 0.0, 1.0]; 4],
             indices: vec![0, 1, 2, 0, 2, 3],
+            ..Default::default()
         }
     }
 
@@ -292,6 +384,7 @@ impl MeshData {
             texcoords,
             tangents: vec![[1.0, 0.0, 0.0, 1.0]; vert_count],
             indices,
+            ..Default::default()
         }
     }
 }
@@ -313,6 +406,18 @@ pub struct InterleavedPbrVertex {
     pub tangent: [f32; 4],
 }
 
+/// 交错可选属性顶点（24 字节：color + uv1）
+///
+/// 作为独立的第二个顶点流上传，基础 PBR 顶点布局保持不变。
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct InterleavedAttributeVertex {
+    /// 顶点颜色（线性 RGBA）
+    pub color: [f32; 4],
+    /// 第二套纹理坐标
+    pub texcoord1: [f32; 2],
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +430,7 @@ mod tests {
             texcoords: vec![Vec2::ZERO; 24],
             tangents: vec![[1.0, 0.0, 0.0, 1.0]; 24],
             indices: vec![0; 36],
+            ..Default::default()
         };
         assert_eq!(mesh.vertex_count(), 24);
         assert_eq!(mesh.index_count(), 36);
@@ -338,6 +444,7 @@ mod tests {
             texcoords: vec![],
             tangents: vec![],
             indices: vec![],
+            ..Default::default()
         };
         assert_eq!(mesh.vertex_count(), 0);
         assert_eq!(mesh.index_count(), 0);
@@ -351,6 +458,7 @@ mod tests {
             texcoords: vec![Vec2::new(0.5, 0.5), Vec2::new(1.0, 0.0)],
             tangents: vec![[1.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, -1.0]],
             indices: vec![0, 1],
+            ..Default::default()
         };
         let verts = mesh.to_pbr_vertices();
         assert_eq!(verts.len(), 2);
@@ -368,12 +476,49 @@ mod tests {
             texcoords: vec![Vec2::ZERO],
             tangents: vec![], // 缺失 tangents
             indices: vec![0],
+            ..Default::default()
         };
         let verts = mesh.to_pbr_vertices();
         assert_eq!(verts.len(), 1);
         assert_eq!(verts[0].tangent, [1.0, 0.0, 0.0, 1.0]); // 默认值
     }
 
+    #[test]
+    fn test_optional_attributes() {
+        let mut mesh = MeshData::generate_plane(1.0);
+        assert!(!mesh.has_colors() && !mesh.has_texcoords1());
+        assert_eq!(mesh.to_attribute_vertices()[2].color, [1.0; 4]);
+        assert_eq!(mesh.to_attribute_vertices()[2].texcoord1, [1.0, 0.0]);
+
+        mesh.colors = vec![[0.5; 4]; 3];
+        assert!(mesh.validate().is_err());
+        mesh.colors.push([0.5; 4]);
+        mesh.texcoords1 = vec![Vec2::splat(0.25); 4];
+        assert!(mesh.validate().is_ok());
+        let attrs = mesh.to_attribute_vertices();
+        assert_eq!(attrs[0].color, [0.5; 4]);
+        assert_eq!(attrs[3].texcoord1, [0.25, 0.25]);
+    }
+
+    #[test]
+    fn test_convert_coordinates_blender() {
+        let mut mesh = MeshData {
+            positions: vec![Vec3::ZERO, Vec3::X, Vec3::Z],
+            normals: vec![Vec3::NEG_Y; 3],
+            texcoords: vec![Vec2::ZERO; 3],
+            tangents: vec![[1.0, 0.0, 0.0, 1.0]; 3],
+            indices: vec![0, 1, 2],
+            ..Default::default()
+        };
+        mesh.convert_coordinates(CoordinateSystem::BLENDER, CoordinateSystem::ANVILKIT);
+        // Blender: Z 上、-Y 为正面 → 引擎: Y 上、+Z 为正面
+        assert!((mesh.positions[2] - Vec3::Y).length() < 1e-6);
+        assert!((mesh.normals[0] - Vec3::Z).length() < 1e-6);
+        assert!((mesh.positions[1] - Vec3::NEG_X).length() < 1e-6);
+        assert_eq!(mesh.tangents[0][3], -1.0);
+        assert_eq!(mesh.indices, vec![0, 2, 1]);
+    }
+
     #[test]
     fn test_generate_box() {
         let cube = MeshData::generate_box(2.0);
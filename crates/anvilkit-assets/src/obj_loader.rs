@@ -0,0 +1,377 @@
+//! # Wavefront OBJ 加载器
+//!
+//! 解析 OBJ + MTL 文件，产出与 glTF 加载器相同的 [`MultiMeshScene`]，
+//! 每个 `usemtl` 材质对应一个 [`Submesh`]。
+//!
+//! - 多边形面按三角扇拆分，支持负数（相对）索引
+//! - 相同 `v/vt/vn` 组合的顶点去重
+//! - 缺失法线时按面积加权生成平滑法线
+//! - MTL 的 `Kd` / `d` / `Tr` / `Ke` / `Ns` / `map_Kd` 以及 PBR 扩展 `Pr` / `Pm` 转换为 [`MaterialData`]
+//!
+//! ## 使用示例
+//!
+//! ```rust,no_run
+//! use anvilkit_assets::obj_loader::load_obj;
+//!
+//! let scene = load_obj("assets/model.obj").expect("加载失败");
+//! println!("子网格数: {}", scene.submesh_count());
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::{Vec2, Vec3};
+use log::{info, warn};
+
+use anvilkit_core::error::{AnvilKitError, Result};
+use crate::material::MaterialData;
+use crate::mesh::MeshData;
+use crate::scene::{MultiMeshScene, Submesh};
+use crate::texture::load_texture;
+use crate::import::ImportOptions;
+use anvilkit_core::math::CoordinateSystem;
+
+/// OBJ 材质的默认值：白色非金属（glTF 默认的金属度 1.0 不适合 OBJ）
+fn default_material() -> MaterialData {
+    MaterialData { metallic_factor: 0.0, roughness_factor: 0.5, ..Default::default() }
+}
+
+/// 从文件加载 OBJ 模型，自动加载 `mtllib` 引用的 MTL 文件（相对 OBJ 所在目录）
+///
+/// MTL 文件或其纹理缺失时仅输出警告并使用默认材质。
+pub fn load_obj(path: impl AsRef<Path>) -> Result<MultiMeshScene> {
+    let path = path.as_ref();
+    info!("加载 OBJ 文件: {}", path.display());
+
+    let source = std::fs::read_to_string(path).map_err(|e| AnvilKitError::asset_with_path(
+        format!("OBJ 读取失败: {}", e),
+        path.to_string_lossy().to_string(),
+    ))?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let mut materials = HashMap::new();
+    for line in source.lines() {
+        let Some(rest) = line.trim().strip_prefix("mtllib") else { continue };
+        for name in rest.split_whitespace() {
+            let mtl_path = base_dir.join(name);
+            match std::fs::read_to_string(&mtl_path) {
+                Ok(mtl) => materials.extend(parse_mtl(&mtl, Some(base_dir))),
+                Err(e) => warn!("MTL 读取失败 {}: {}", mtl_path.display(), e),
+            }
+        }
+    }
+
+    parse_obj(&source, &materials).map_err(|e| AnvilKitError::asset_with_path(
+        e.to_string(),
+        path.to_string_lossy().to_string(),
+    ))
+}
+
+/// 按导入选项加载 OBJ 模型
+///
+/// OBJ 没有坐标约定规范，默认按右手 Y 上（多数 DCC 的 OBJ 导出默认值）处理；
+/// Z 上导出的文件使用 [`ImportOptions::z_up`]。
+pub fn load_obj_with(path: impl AsRef<Path>, options: &ImportOptions) -> Result<MultiMeshScene> {
+    let mut scene = load_obj(path)?;
+    if let Some((from, to)) = options.conversion(CoordinateSystem::GLTF) {
+        scene.convert_coordinates(from, to);
+    }
+    Ok(scene)
+}
+
+/// 解析 MTL 文本，返回 `材质名 → MaterialData`
+///
+/// `texture_dir` 为 `None` 时忽略纹理贴图。OBJ 材质默认非金属（`metallic_factor = 0`），
+/// 粗糙度由高光指数 `Ns` 近似换算，`Pr` / `Pm` 存在时优先使用。
+pub fn parse_mtl(source: &str, texture_dir: Option<&Path>) -> HashMap<String, MaterialData> {
+    let mut materials = HashMap::new();
+    let mut current: Option<(String, MaterialData)> = None;
+
+    for line in source.lines() {
+        let line = line.trim();
+        let mut parts = line.split_whitespace();
+        let Some(keyword) = parts.next() else { continue };
+        let args: Vec<&str> = parts.collect();
+
+        if keyword == "newmtl" {
+            if let Some((name, mat)) = current.take() {
+                materials.insert(name, mat);
+            }
+            current = Some((args.join(" "), default_material()));
+            continue;
+        }
+        let Some((_, mat)) = current.as_mut() else { continue };
+        let floats: Vec<f32> = args.iter().filter_map(|a| a.parse().ok()).collect();
+
+        match keyword {
+            "Kd" if floats.len() >= 3 => {
+                mat.base_color_factor[..3].copy_from_slice(&floats[..3]);
+            }
+            "d" if !floats.is_empty() => mat.base_color_factor[3] = floats[0].clamp(0.0, 1.0),
+            "Tr" if !floats.is_empty() => mat.base_color_factor[3] = (1.0 - floats[0]).clamp(0.0, 1.0),
+            "Ke" if floats.len() >= 3 => mat.emissive_factor.copy_from_slice(&floats[..3]),
+            "Ns" if !floats.is_empty() => {
+                // Blinn-Phong 指数 → 粗糙度：α = sqrt(2 / (Ns + 2))
+                mat.roughness_factor = (2.0 / (floats[0].max(0.0) + 2.0)).sqrt().clamp(0.0, 1.0);
+            }
+            "Pr" if !floats.is_empty() => mat.roughness_factor = floats[0].clamp(0.0, 1.0),
+            "Pm" if !floats.is_empty() => mat.metallic_factor = floats[0].clamp(0.0, 1.0),
+            "map_Kd" => {
+                // 选项（如 -s 1 1 1）位于文件名之前，文件名取最后一个参数
+                let (Some(dir), Some(file)) = (texture_dir, args.last()) else { continue };
+                let texture_path = dir.join(file);
+                match load_texture(&texture_path) {
+                    Ok(texture) => mat.base_color_texture = Some(texture),
+                    Err(e) => warn!("{}", e),
+                }
+            }
+            _ => {}
+        }
+    }
+    if let Some((name, mat)) = current {
+        materials.insert(name, mat);
+    }
+    materials
+}
+
+/// 按材质累积的子网格
+#[derive(Default)]
+struct SubmeshBuilder {
+    mesh: MeshData,
+    /// `(v, vt, vn)` → 输出顶点索引
+    lookup: HashMap<(usize, Option<usize>, Option<usize>), u32>,
+    /// 每个输出顶点的位置索引（用于生成平滑法线）
+    position_index: Vec<usize>,
+    /// 每个输出顶点是否带有 OBJ 法线
+    has_normal: Vec<bool>,
+}
+
+impl SubmeshBuilder {
+    fn vertex(&mut self, key: (usize, Option<usize>, Option<usize>), positions: &[Vec3], texcoords: &[Vec2], normals: &[Vec3]) -> u32 {
+        if let Some(&index) = self.lookup.get(&key) {
+            return index;
+        }
+        let (v, vt, vn) = key;
+        let index = self.mesh.positions.len() as u32;
+        self.mesh.positions.push(positions[v]);
+        // OBJ 的 V 轴向上，纹理坐标系向下
+        self.mesh.texcoords.push(vt.map_or(Vec2::ZERO, |t| Vec2::new(texcoords[t].x, 1.0 - texcoords[t].y)));
+        self.mesh.normals.push(vn.map_or(Vec3::ZERO, |n| normals[n]));
+        self.mesh.tangents.push([1.0, 0.0, 0.0, 1.0]);
+        self.position_index.push(v);
+        self.has_normal.push(vn.is_some());
+        self.lookup.insert(key, index);
+        index
+    }
+
+    fn finish(mut self, position_count: usize) -> MeshData {
+        if self.has_normal.iter().all(|&h| h) {
+            return self.mesh;
+        }
+        // 按原始位置累积面法线（叉积长度即两倍面积，天然面积加权），
+        // 使 UV 接缝处拆分出的顶点仍得到一致的平滑法线
+        let mut accum = vec![Vec3::ZERO; position_count];
+        for tri in self.mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i as usize);
+            let p = &self.mesh.positions;
+            let face = (p[b] - p[a]).cross(p[c] - p[a]);
+            for i in [a, b, c] {
+                accum[self.position_index[i]] += face;
+            }
+        }
+        for (i, normal) in self.mesh.normals.iter_mut().enumerate() {
+            if !self.has_normal[i] {
+                *normal = accum[self.position_index[i]].try_normalize().unwrap_or(Vec3::Y);
+            }
+        }
+        self.mesh
+    }
+}
+
+/// 解析 OBJ 文本
+///
+/// `materials` 为 `usemtl` 名称到材质的映射，未找到的材质使用非金属白色默认材质。
+/// 没有任何面时返回错误。
+///
+/// # 示例
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use anvilkit_assets::obj_loader::{parse_mtl, parse_obj};
+///
+/// let mtl = parse_mtl("newmtl red\nKd 1 0 0\n", None);
+/// let obj = "v 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl red\nf 1 2 3\n";
+/// let scene = parse_obj(obj, &mtl).unwrap();
+/// assert_eq!(scene.submesh_count(), 1);
+/// assert_eq!(scene.submeshes[0].material.base_color_factor, [1.0, 0.0, 0.0, 1.0]);
+/// assert_eq!(scene.submeshes[0].mesh.normals[0], glam::Vec3::Z);
+/// ```
+pub fn parse_obj(source: &str, materials: &HashMap<String, MaterialData>) -> Result<MultiMeshScene> {
+    let mut positions: Vec<Vec3> = Vec::new();
+    let mut texcoords: Vec<Vec2> = Vec::new();
+    let mut normals: Vec<Vec3> = Vec::new();
+
+    // 保持材质首次出现的顺序
+    let mut order: Vec<String> = Vec::new();
+    let mut builders: HashMap<String, SubmeshBuilder> = HashMap::new();
+    let mut current = String::new();
+
+    for (line_no, line) in source.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut parts = line.split_whitespace();
+        let Some(keyword) = parts.next() else { continue };
+        let error = |msg: &str| AnvilKitError::asset(format!("OBJ 第 {} 行: {}", line_no + 1, msg));
+        let parse_floats = |parts: std::str::SplitWhitespace, n: usize| -> Result<Vec<f32>> {
+            let values: Vec<f32> = parts.take(n).map(str::parse::<f32>).collect::<std::result::Result<_, _>>()
+                .map_err(|_| error("无效的数值"))?;
+            Ok(values)
+        };
+
+        match keyword {
+            "v" => {
+                let f = parse_floats(parts, 3)?;
+                if f.len() < 3 {
+                    return Err(error("顶点位置需要 3 个分量"));
+                }
+                positions.push(Vec3::new(f[0], f[1], f[2]));
+            }
+            "vt" => {
+                let f = parse_floats(parts, 2)?;
+                texcoords.push(Vec2::new(f.first().copied().unwrap_or(0.0), f.get(1).copied().unwrap_or(0.0)));
+            }
+            "vn" => {
+                let f = parse_floats(parts, 3)?;
+                if f.len() < 3 {
+                    return Err(error("法线需要 3 个分量"));
+                }
+                normals.push(Vec3::new(f[0], f[1], f[2]).normalize_or_zero());
+            }
+            "usemtl" => current = parts.collect::<Vec<_>>().join(" "),
+            "f" => {
+                let resolve = |raw: &str, len: usize| -> Result<usize> {
+                    let i: i64 = raw.parse().map_err(|_| error("无效的面索引"))?;
+                    let resolved = if i < 0 { len as i64 + i } else { i - 1 };
+                    if resolved < 0 || resolved as usize >= len {
+                        return Err(error("面索引越界"));
+                    }
+                    Ok(resolved as usize)
+                };
+                let mut corners = Vec::new();
+                for corner in parts {
+                    let mut fields = corner.split('/');
+                    let v = resolve(fields.next().unwrap_or(""), positions.len())?;
+                    let vt = match fields.next() {
+                        Some(s) if !s.is_empty() => Some(resolve(s, texcoords.len())?),
+                        _ => None,
+                    };
+                    let vn = match fields.next() {
+                        Some(s) if !s.is_empty() => Some(resolve(s, normals.len())?),
+                        _ => None,
+                    };
+                    corners.push((v, vt, vn));
+                }
+                if corners.len() < 3 {
+                    return Err(error("面至少需要 3 个顶点"));
+                }
+
+                if !builders.contains_key(&current) {
+                    order.push(current.clone());
+                }
+                let builder = builders.entry(current.clone()).or_default();
+                let indices: Vec<u32> = corners.iter()
+                    .map(|&key| builder.vertex(key, &positions, &texcoords, &normals))
+                    .collect();
+                for i in 1..indices.len() - 1 {
+                    builder.mesh.indices.extend_from_slice(&[indices[0], indices[i], indices[i + 1]]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if order.is_empty() {
+        return Err(AnvilKitError::asset("OBJ 文件中没有面".to_string()));
+    }
+
+    let submeshes = order.into_iter()
+        .map(|name| {
+            let builder = builders.remove(&name).expect("order 与 builders 同步");
+            let material = materials.get(&name).cloned().unwrap_or_else(|| {
+                if !name.is_empty() {
+                    warn!("OBJ 引用了未定义的材质 '{}'，使用默认材质", name);
+                }
+                default_material()
+            });
+            Submesh { mesh: builder.finish(positions.len()), material }
+        })
+        .collect();
+
+    Ok(MultiMeshScene { submeshes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUAD: &str = "\
+# 两个三角形组成的四边形
+v -1 0 -1
+v  1 0 -1
+v  1 0  1
+v -1 0  1
+vt 0 0
+vt 1 0
+vt 1 1
+vt 0 1
+f 1/1 4/4 3/3 2/2
+";
+
+    #[test]
+    fn test_quad_triangulated_and_normals_generated() {
+        let scene = parse_obj(QUAD, &HashMap::new()).unwrap();
+        assert_eq!(scene.submesh_count(), 1);
+        let mesh = &scene.submeshes[0].mesh;
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert!(mesh.validate().is_ok());
+        for n in &mesh.normals {
+            assert!((*n - Vec3::Y).length() < 1e-5, "{:?}", n);
+        }
+        // V 轴翻转
+        assert_eq!(mesh.texcoords[0], Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_vertex_dedup_and_negative_indices() {
+        let obj = "v 0 0 0\nv 1 0 0\nv 0 1 0\nv 1 1 0\nvn 0 0 1\nf -4//1 -3//1 -2//1\nf 2//1 4//1 3//1\n";
+        let mesh = &parse_obj(obj, &HashMap::new()).unwrap().submeshes[0].mesh;
+        assert_eq!(mesh.vertex_count(), 4);
+        assert_eq!(mesh.index_count(), 6);
+        assert!(mesh.normals.iter().all(|n| *n == Vec3::Z));
+    }
+
+    #[test]
+    fn test_submesh_per_material() {
+        let mtl = parse_mtl("newmtl a\nKd 0.5 0.5 0.5\nd 0.25\nNs 0\nnewmtl b\nKe 1 1 0\nPm 1\nPr 0.1\n", None);
+        assert_eq!(mtl.len(), 2);
+        assert_eq!(mtl["a"].base_color_factor, [0.5, 0.5, 0.5, 0.25]);
+        assert!((mtl["a"].roughness_factor - 1.0).abs() < 1e-6);
+        assert_eq!(mtl["b"].emissive_factor, [1.0, 1.0, 0.0]);
+        assert_eq!(mtl["b"].metallic_factor, 1.0);
+
+        let obj = "v 0 0 0\nv 1 0 0\nv 0 1 0\nusemtl b\nf 1 2 3\nusemtl a\nf 1 3 2\nusemtl b\nf 3 2 1\n";
+        let scene = parse_obj(obj, &mtl).unwrap();
+        assert_eq!(scene.submesh_count(), 2);
+        assert_eq!(scene.submeshes[0].material.metallic_factor, 1.0);
+        assert_eq!(scene.submeshes[0].mesh.index_count(), 6);
+        assert_eq!(scene.submeshes[1].material.base_color_factor[3], 0.25);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse_obj("v 0 0 0\n", &HashMap::new()).is_err());
+        assert!(parse_obj("v 0 0 0\nf 1 2 3\n", &HashMap::new()).is_err());
+        assert!(parse_obj("v 0 0\n", &HashMap::new()).is_err());
+        assert!(parse_obj("v 0 0 0\nv 1 0 0\nf 1 2\n", &HashMap::new()).is_err());
+    }
+}
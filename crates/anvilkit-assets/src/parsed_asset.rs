@@ -5,6 +5,7 @@
 use crate::mesh::MeshData;
 use crate::material::TextureData;
 use crate::audio_asset::AudioAsset;
+use crate::svg_loader::SvgDocument;
 
 /// 解析后的资产数据
 ///
@@ -18,6 +19,8 @@ pub enum ParsedAsset {
     Texture(TextureData),
     /// 音频数据（原始字节）
     Audio(AudioAsset),
+    /// 矢量图形（来自 SVG）
+    Vector(SvgDocument),
     /// 原始字节（通用格式）
     Raw(Vec<u8>),
 }
@@ -46,6 +49,14 @@ impl ParsedAsset {
             _ => None,
         }
     }
+
+    /// 尝试获取矢量图形
+    pub fn as_vector(&self) -> Option<&SvgDocument> {
+        match self {
+            ParsedAsset::Vector(doc) => Some(doc),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -57,6 +68,7 @@ mod tests {
         let asset = ParsedAsset::Raw(vec![1, 2, 3]);
         assert!(asset.as_meshes().is_none());
         assert!(asset.as_texture().is_none());
+        assert!(asset.as_vector().is_none());
     }
 
     #[test]
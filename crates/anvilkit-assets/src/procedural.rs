@@ -7,6 +7,12 @@
 //! - [`generate_sphere`] — UV 球体
 //! - [`generate_plane`] — XZ 平面
 //! - [`generate_box`] — 立方体/长方体
+//! - [`generate_subdivided_plane`] — 细分 XZ 平面
+//! - [`generate_cylinder`] — 带端盖的圆柱
+//! - [`generate_capsule`] — 胶囊体
+//! - [`generate_torus`] — 圆环
+//!
+//! 新增的几何体均为逆时针（CCW）朝外绕序，与 glTF 约定一致。
 //!
 //! ## 使用示例
 //!
@@ -113,6 +119,7 @@ pub fn generate_sphere(radius: f32, sectors: u32, rings: u32) -> MeshData {
         texcoords,
         tangents,
         indices,
+        ..Default::default()
     }
 }
 
@@ -158,6 +165,7 @@ pub fn generate_plane(width: f32, depth: f32) -> MeshData {
         ],
         tangents: vec![[1.0, 0.0, 0.0, 1.0]; 4],
         indices: vec![0, 1, 2, 0, 2, 3],
+        ..Default::default()
     }
 }
 
@@ -286,7 +294,227 @@ pub fn generate_box(half_extents: [f32; 3]) -> MeshData {
         texcoords,
         tangents,
         indices,
+        ..Default::default()
+    }
+}
+
+/// 连接 `(rows + 1) × (cols + 1)` 的顶点网格（行优先），每个单元两个三角形
+///
+/// 行方向与列方向满足 `行 × 列 = 法线` 时为 CCW 朝外。
+fn push_grid_indices(indices: &mut Vec<u32>, base: u32, rows: u32, cols: u32) {
+    let stride = cols + 1;
+    for i in 0..rows {
+        for j in 0..cols {
+            let a = base + i * stride + j;
+            let b = a + 1;
+            let c = a + stride;
+            let d = c + 1;
+            indices.extend_from_slice(&[a, c, b, b, c, d]);
+        }
+    }
+}
+
+/// 绕 Y 轴角度 θ 处的水平方向（θ = 0 指向 +Z，随 θ 增大转向 +X）
+fn around_y(theta: f32) -> Vec3 {
+    Vec3::new(theta.sin(), 0.0, theta.cos())
+}
+
+/// 沿 θ 增大方向的切线
+fn around_y_tangent(theta: f32) -> [f32; 4] {
+    [theta.cos(), 0.0, -theta.sin(), 1.0]
+}
+
+/// 生成细分的 XZ 平面网格
+///
+/// 与 [`generate_plane`] 相同的尺寸约定，但沿 X / Z 方向各细分为若干段，
+/// 适用于地形、水面等需要逐顶点位移的场景。
+///
+/// # 参数
+///
+/// - `width`: X 轴方向宽度
+/// - `depth`: Z 轴方向深度
+/// - `x_segments` / `z_segments`: 各方向分段数（最小为 1）
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_assets::procedural::generate_subdivided_plane;
+///
+/// let mesh = generate_subdivided_plane(10.0, 10.0, 4, 2);
+/// assert_eq!(mesh.vertex_count(), 5 * 3);
+/// assert_eq!(mesh.index_count(), 4 * 2 * 6);
+/// ```
+pub fn generate_subdivided_plane(width: f32, depth: f32, x_segments: u32, z_segments: u32) -> MeshData {
+    let cols = x_segments.max(1);
+    let rows = z_segments.max(1);
+    let mut mesh = MeshData::default();
+
+    for i in 0..=rows {
+        let v = i as f32 / rows as f32;
+        for j in 0..=cols {
+            let u = j as f32 / cols as f32;
+            mesh.positions.push(Vec3::new((u - 0.5) * width, 0.0, (v - 0.5) * depth));
+            mesh.normals.push(Vec3::Y);
+            mesh.texcoords.push(Vec2::new(u, v));
+            mesh.tangents.push([1.0, 0.0, 0.0, 1.0]);
+        }
+    }
+    push_grid_indices(&mut mesh.indices, 0, rows, cols);
+    mesh
+}
+
+/// 生成圆柱网格（以原点为中心，轴沿 Y）
+///
+/// 侧面与上下端盖使用独立顶点，端盖法线为 ±Y。
+///
+/// # 参数
+///
+/// - `radius`: 半径
+/// - `height`: 总高度
+/// - `sectors`: 圆周分段数（最小为 3）
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_assets::procedural::generate_cylinder;
+///
+/// let mesh = generate_cylinder(0.5, 2.0, 16);
+/// // 侧面 2 × 17，端盖各 1 + 17
+/// assert_eq!(mesh.vertex_count(), 2 * 17 + 2 * 18);
+/// assert_eq!(mesh.index_count(), 16 * 6 + 2 * 16 * 3);
+/// ```
+pub fn generate_cylinder(radius: f32, height: f32, sectors: u32) -> MeshData {
+    let sectors = sectors.max(3);
+    let half = height * 0.5;
+    let mut mesh = MeshData::default();
+
+    // 侧面：第 0 行为顶部，第 1 行为底部
+    for (row, y) in [half, -half].into_iter().enumerate() {
+        for j in 0..=sectors {
+            let u = j as f32 / sectors as f32;
+            let theta = u * std::f32::consts::TAU;
+            let dir = around_y(theta);
+            mesh.positions.push(dir * radius + Vec3::Y * y);
+            mesh.normals.push(dir);
+            mesh.texcoords.push(Vec2::new(u, row as f32));
+            mesh.tangents.push(around_y_tangent(theta));
+        }
+    }
+    push_grid_indices(&mut mesh.indices, 0, 1, sectors);
+
+    // 端盖：中心点 + 一圈顶点
+    for (normal, y) in [(Vec3::Y, half), (Vec3::NEG_Y, -half)] {
+        let center = mesh.positions.len() as u32;
+        mesh.positions.push(Vec3::Y * y);
+        mesh.normals.push(normal);
+        mesh.texcoords.push(Vec2::splat(0.5));
+        mesh.tangents.push([1.0, 0.0, 0.0, 1.0]);
+        for j in 0..=sectors {
+            let dir = around_y(j as f32 / sectors as f32 * std::f32::consts::TAU);
+            mesh.positions.push(dir * radius + Vec3::Y * y);
+            mesh.normals.push(normal);
+            mesh.texcoords.push(Vec2::new(0.5 + 0.5 * dir.x, 0.5 - 0.5 * dir.z * normal.y));
+            mesh.tangents.push([1.0, 0.0, 0.0, 1.0]);
+        }
+        for j in 0..sectors {
+            let (a, b) = (center + 1 + j, center + 2 + j);
+            if normal.y > 0.0 {
+                mesh.indices.extend_from_slice(&[center, a, b]);
+            } else {
+                mesh.indices.extend_from_slice(&[center, b, a]);
+            }
+        }
+    }
+    mesh
+}
+
+/// 生成胶囊体网格（以原点为中心，轴沿 Y）
+///
+/// 由两个半球与中间的圆柱段组成，总高度为 `2 * (radius + half_height)`。
+///
+/// # 参数
+///
+/// - `radius`: 半球与圆柱半径
+/// - `half_height`: 圆柱段的一半高度（为 0 时退化为球体）
+/// - `sectors`: 圆周分段数（最小为 3）
+/// - `hemisphere_rings`: 每个半球的纬度分段数（最小为 1）
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_assets::procedural::generate_capsule;
+///
+/// let mesh = generate_capsule(0.5, 0.5, 16, 8);
+/// assert_eq!(mesh.vertex_count(), 17 * 2 * 9);
+/// assert_eq!(mesh.index_count(), 16 * (2 * 8 + 1) * 6);
+/// ```
+pub fn generate_capsule(radius: f32, half_height: f32, sectors: u32, hemisphere_rings: u32) -> MeshData {
+    let sectors = sectors.max(3);
+    let rings = hemisphere_rings.max(1);
+    let top = radius + half_height;
+    let total_height = 2.0 * top;
+    let mut mesh = MeshData::default();
+
+    // 上半球 φ ∈ [0, π/2]，下半球 φ ∈ [π/2, π]；两条赤道行之间即圆柱段
+    for (offset, phi_start) in [(half_height, 0.0), (-half_height, std::f32::consts::FRAC_PI_2)] {
+        for i in 0..=rings {
+            let phi = phi_start + std::f32::consts::FRAC_PI_2 * i as f32 / rings as f32;
+            for j in 0..=sectors {
+                let u = j as f32 / sectors as f32;
+                let theta = u * std::f32::consts::TAU;
+                let normal = around_y(theta) * phi.sin() + Vec3::Y * phi.cos();
+                let position = normal * radius + Vec3::Y * offset;
+                mesh.positions.push(position);
+                mesh.normals.push(normal);
+                mesh.texcoords.push(Vec2::new(u, (top - position.y) / total_height));
+                mesh.tangents.push(around_y_tangent(theta));
+            }
+        }
+    }
+    push_grid_indices(&mut mesh.indices, 0, 2 * rings + 1, sectors);
+    mesh
+}
+
+/// 生成圆环网格（位于 XZ 平面，以原点为中心）
+///
+/// # 参数
+///
+/// - `major_radius`: 圆环中心线半径
+/// - `minor_radius`: 管半径
+/// - `major_segments`: 绕 Y 轴的分段数（最小为 3）
+/// - `minor_segments`: 管截面分段数（最小为 3）
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_assets::procedural::generate_torus;
+///
+/// let mesh = generate_torus(1.0, 0.25, 32, 12);
+/// assert_eq!(mesh.vertex_count(), 33 * 13);
+/// assert_eq!(mesh.index_count(), 32 * 12 * 6);
+/// ```
+pub fn generate_torus(major_radius: f32, minor_radius: f32, major_segments: u32, minor_segments: u32) -> MeshData {
+    let cols = major_segments.max(3);
+    let rows = minor_segments.max(3);
+    let mut mesh = MeshData::default();
+
+    // 行沿管截面自外侧顶部向下绕行，列绕 Y 轴
+    for i in 0..=rows {
+        let v = i as f32 / rows as f32;
+        let psi = std::f32::consts::FRAC_PI_2 - v * std::f32::consts::TAU;
+        for j in 0..=cols {
+            let u = j as f32 / cols as f32;
+            let theta = u * std::f32::consts::TAU;
+            let dir = around_y(theta);
+            let normal = dir * psi.cos() + Vec3::Y * psi.sin();
+            mesh.positions.push(dir * major_radius + normal * minor_radius);
+            mesh.normals.push(normal);
+            mesh.texcoords.push(Vec2::new(u, v));
+            mesh.tangents.push(around_y_tangent(theta));
+        }
     }
+    push_grid_indices(&mut mesh.indices, 0, rows, cols);
+    mesh
 }
 
 #[cfg(test)]
@@ -375,4 +603,64 @@ mod tests {
             assert!(pos.z.abs() <= 2.0 + 1e-5);
         }
     }
+
+    /// 所有非退化三角形的几何法线与顶点法线同向（CCW 朝外）
+    fn assert_outward_ccw(mesh: &MeshData) {
+        assert!(mesh.validate().is_ok());
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i as usize);
+            let p = &mesh.positions;
+            let face = (p[b] - p[a]).cross(p[c] - p[a]);
+            if face.length() < 1e-6 {
+                continue;
+            }
+            let n = mesh.normals[a] + mesh.normals[b] + mesh.normals[c];
+            assert!(face.dot(n) > 0.0, "triangle {:?} faces inward", tri);
+        }
+        for n in &mesh.normals {
+            assert!((n.length() - 1.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_subdivided_plane() {
+        let mesh = generate_subdivided_plane(4.0, 2.0, 4, 2);
+        assert_eq!(mesh.vertex_count(), 15);
+        assert_outward_ccw(&mesh);
+        assert_eq!(mesh.positions[0], Vec3::new(-2.0, 0.0, -1.0));
+        assert_eq!(mesh.positions[14], Vec3::new(2.0, 0.0, 1.0));
+        // 0 段被钳制为 1 段
+        assert_eq!(generate_subdivided_plane(1.0, 1.0, 0, 0).index_count(), 6);
+    }
+
+    #[test]
+    fn test_cylinder() {
+        let mesh = generate_cylinder(0.5, 2.0, 12);
+        assert_outward_ccw(&mesh);
+        for p in &mesh.positions {
+            assert!(p.y.abs() <= 1.0 + 1e-5);
+            assert!(Vec2::new(p.x, p.z).length() <= 0.5 + 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_capsule() {
+        let mesh = generate_capsule(0.5, 1.0, 12, 6);
+        assert_outward_ccw(&mesh);
+        let max_y = mesh.positions.iter().map(|p| p.y).fold(f32::MIN, f32::max);
+        let min_y = mesh.positions.iter().map(|p| p.y).fold(f32::MAX, f32::min);
+        assert!((max_y - 1.5).abs() < 1e-5);
+        assert!((min_y + 1.5).abs() < 1e-5);
+        assert!(mesh.texcoords.iter().all(|uv| (0.0..=1.0).contains(&uv.y)));
+    }
+
+    #[test]
+    fn test_torus() {
+        let mesh = generate_torus(1.0, 0.25, 16, 8);
+        assert_outward_ccw(&mesh);
+        for p in &mesh.positions {
+            let ring = Vec2::new(p.x, p.z).length();
+            assert!(ring >= 0.75 - 1e-5 && ring <= 1.25 + 1e-5);
+        }
+    }
 }
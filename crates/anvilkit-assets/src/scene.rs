@@ -1,7 +1,9 @@
 //! # 场景数据
 //!
-//! 定义从 glTF 文件提取的完整场景数据（网格 + 材质）。
+//! 定义从 glTF 文件提取的完整场景数据（网格 + 材质 + 节点层级）。
 
+use glam::{Mat4, Quat, Vec3};
+use anvilkit_core::math::CoordinateSystem;
 use crate::mesh::MeshData;
 use crate::material::MaterialData;
 
@@ -24,6 +26,7 @@ use crate::material::MaterialData;
 ///         texcoords: vec![Vec2::ZERO],
 ///         tangents: vec![[1.0, 0.0, 0.0, 1.0]],
 ///         indices: vec![0],
+///         ..Default::default()
 ///     },
 ///     material: MaterialData::default(),
 /// };
@@ -74,4 +77,203 @@ impl MultiMeshScene {
     pub fn total_vertex_count(&self) -> usize {
         self.submeshes.iter().map(|s| s.mesh.vertex_count()).sum()
     }
+
+    /// 把所有子网格从 `from` 坐标约定转换到 `to`
+    pub fn convert_coordinates(&mut self, from: CoordinateSystem, to: CoordinateSystem) {
+        for submesh in &mut self.submeshes {
+            submesh.mesh.convert_coordinates(from, to);
+        }
+    }
+}
+
+/// 场景网格（glTF mesh，由一个或多个 primitive 组成）
+#[derive(Debug, Clone)]
+pub struct SceneMesh {
+    /// 网格名称
+    pub name: Option<String>,
+    /// 子网格（每个 primitive 一个）
+    pub primitives: Vec<Submesh>,
+}
+
+/// 场景节点（局部 TRS 变换 + 可选网格引用）
+#[derive(Debug, Clone)]
+pub struct SceneNode {
+    /// 节点名称
+    pub name: Option<String>,
+    /// 局部平移
+    pub translation: Vec3,
+    /// 局部旋转
+    pub rotation: Quat,
+    /// 局部缩放
+    pub scale: Vec3,
+    /// 引用的网格索引（`SceneHierarchy::meshes`）
+    pub mesh: Option<usize>,
+    /// 子节点索引（`SceneHierarchy::nodes`）
+    pub children: Vec<usize>,
+}
+
+impl Default for SceneNode {
+    fn default() -> Self {
+        Self {
+            name: None,
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+            mesh: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl SceneNode {
+    /// 局部变换矩阵
+    pub fn local_matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+/// 带节点层级的场景数据
+///
+/// 网格与节点按 glTF 中的索引存储，`roots` 为场景的根节点。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_assets::scene::{SceneHierarchy, SceneNode};
+/// use glam::Vec3;
+///
+/// let scene = SceneHierarchy {
+///     meshes: vec![],
+///     nodes: vec![
+///         SceneNode { translation: Vec3::X, children: vec![1], ..Default::default() },
+///         SceneNode { translation: Vec3::Y, ..Default::default() },
+///     ],
+///     roots: vec![0],
+/// };
+/// let world = scene.world_matrices();
+/// assert_eq!(world[1].transform_point3(Vec3::ZERO), Vec3::new(1.0, 1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SceneHierarchy {
+    /// 网格列表
+    pub meshes: Vec<SceneMesh>,
+    /// 节点列表
+    pub nodes: Vec<SceneNode>,
+    /// 根节点索引
+    pub roots: Vec<usize>,
+}
+
+impl SceneHierarchy {
+    /// 节点数量
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// 子网格总数
+    pub fn submesh_count(&self) -> usize {
+        self.meshes.iter().map(|m| m.primitives.len()).sum()
+    }
+
+    /// 按深度优先顺序遍历从根可达的节点，回调参数为 `(节点索引, 父节点索引)`
+    ///
+    /// 索引越界或成环的子节点引用会被跳过。
+    pub fn visit(&self, mut f: impl FnMut(usize, Option<usize>)) {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack: Vec<(usize, Option<usize>)> = self.roots.iter().rev().map(|&r| (r, None)).collect();
+        while let Some((index, parent)) = stack.pop() {
+            if index >= self.nodes.len() || visited[index] {
+                continue;
+            }
+            visited[index] = true;
+            f(index, parent);
+            stack.extend(self.nodes[index].children.iter().rev().map(|&c| (c, Some(index))));
+        }
+    }
+
+    /// 把网格顶点与节点 TRS 从 `from` 坐标约定转换到 `to`
+    ///
+    /// 每个局部变换按 `M · L · M⁻¹` 转换，层级相乘后世界矩阵同样满足该关系。
+    pub fn convert_coordinates(&mut self, from: CoordinateSystem, to: CoordinateSystem) {
+        if from == to {
+            return;
+        }
+        for mesh in &mut self.meshes {
+            for primitive in &mut mesh.primitives {
+                primitive.mesh.convert_coordinates(from, to);
+            }
+        }
+        for node in &mut self.nodes {
+            node.translation = from.convert_vec3(node.translation, to);
+            node.rotation = from.convert_rotation(node.rotation, to);
+            node.scale = from.convert_scale(node.scale, to);
+        }
+    }
+
+    /// 计算所有节点的世界矩阵（不可达节点为单位矩阵）
+    pub fn world_matrices(&self) -> Vec<Mat4> {
+        let mut world = vec![Mat4::IDENTITY; self.nodes.len()];
+        self.visit(|index, parent| {
+            let local = self.nodes[index].local_matrix();
+            world[index] = match parent {
+                Some(p) => world[p] * local,
+                None => local,
+            };
+        });
+        world
+    }
+}
+
+/// 将扁平的多子网格场景包装为单节点层级（用于 OBJ 等无节点信息的格式）
+impl From<MultiMeshScene> for SceneHierarchy {
+    fn from(scene: MultiMeshScene) -> Self {
+        Self {
+            meshes: vec![SceneMesh { name: None, primitives: scene.submeshes }],
+            nodes: vec![SceneNode { mesh: Some(0), ..Default::default() }],
+            roots: vec![0],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hierarchy_world_matrices() {
+        let scene = SceneHierarchy {
+            meshes: vec![],
+            nodes: vec![
+                SceneNode { scale: Vec3::splat(2.0), children: vec![1, 2], ..Default::default() },
+                SceneNode { translation: Vec3::X, children: vec![0], ..Default::default() },
+                SceneNode { translation: Vec3::Z, children: vec![99], ..Default::default() },
+            ],
+            roots: vec![0],
+        };
+        let world = scene.world_matrices();
+        assert_eq!(world[1].transform_point3(Vec3::ZERO), Vec3::new(2.0, 0.0, 0.0));
+        assert_eq!(world[2].transform_point3(Vec3::ZERO), Vec3::new(0.0, 0.0, 2.0));
+
+        // 环引用 (1 → 0) 与越界索引被跳过
+        let mut order = Vec::new();
+        scene.visit(|i, p| order.push((i, p)));
+        assert_eq!(order, vec![(0, None), (1, Some(0)), (2, Some(0))]);
+    }
+
+    #[test]
+    fn test_hierarchy_convert_coordinates() {
+        let mut scene = SceneHierarchy {
+            meshes: vec![],
+            nodes: vec![
+                SceneNode { rotation: Quat::from_rotation_z(0.5), children: vec![1], ..Default::default() },
+                SceneNode { translation: Vec3::new(0.0, -1.0, 2.0), ..Default::default() },
+            ],
+            roots: vec![0],
+        };
+        let (from, to) = (CoordinateSystem::BLENDER, CoordinateSystem::ANVILKIT);
+        let expected: Vec<Mat4> = scene.world_matrices().into_iter().map(|m| from.convert_matrix(m, to)).collect();
+        scene.convert_coordinates(from, to);
+        for (actual, expected) in scene.world_matrices().iter().zip(&expected) {
+            assert!(actual.abs_diff_eq(*expected, 1e-5));
+        }
+    }
 }
@@ -0,0 +1,969 @@
+//! # SVG 子集加载器
+//!
+//! 把 SVG 文档解析为矢量路径与填充 / 描边样式，供 2D 矢量渲染器细分为三角形。
+//! 坐标保持 SVG 约定（像素，y 向下），与精灵的屏幕坐标一致。
+//!
+//! 支持范围：
+//!
+//! - 元素：`svg`（`width` / `height` / `viewBox`）、`g`、`path`、`rect`（含 `rx` / `ry` 圆角）、
+//!   `circle`、`ellipse`、`line`、`polyline`、`polygon`
+//! - 路径命令：`M L H V C S Q T A Z` 及其相对形式，圆弧转换为三次贝塞尔
+//! - `transform`：`matrix` / `translate` / `scale` / `rotate` / `skewX` / `skewY`，沿 `g` 嵌套累积
+//! - 样式（属性或 `style="..."`，可继承）：`fill`、`fill-rule`、`fill-opacity`、`stroke`、
+//!   `stroke-width`、`stroke-opacity`、`stroke-linecap`、`stroke-linejoin`、`stroke-miterlimit`、
+//!   `opacity`、`display="none"`、`visibility="hidden"`
+//! - 颜色：`#rgb` / `#rrggbb` 等十六进制、`rgb()` / `rgba()`、常用颜色名、`none`
+//!
+//! 不支持：渐变与图案（按 `none` 处理）、`use` / `defs` 引用、文本、CSS 选择器、裁剪与遮罩。
+//!
+//! ```rust,no_run
+//! use anvilkit_assets::svg_loader::load_svg;
+//!
+//! let doc = load_svg("assets/icons/heart.svg").expect("加载失败");
+//! println!("{}x{}, 形状: {}", doc.width, doc.height, doc.shapes.len());
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::{Affine2, Vec2};
+use log::{info, warn};
+
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_core::math::Color;
+
+/// 路径命令（绝对坐标）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    /// 开始新的子路径
+    MoveTo(Vec2),
+    /// 直线
+    LineTo(Vec2),
+    /// 二次贝塞尔
+    QuadTo {
+        /// 控制点
+        ctrl: Vec2,
+        /// 终点
+        to: Vec2,
+    },
+    /// 三次贝塞尔
+    CubicTo {
+        /// 第一个控制点
+        ctrl1: Vec2,
+        /// 第二个控制点
+        ctrl2: Vec2,
+        /// 终点
+        to: Vec2,
+    },
+    /// 闭合当前子路径
+    Close,
+}
+
+impl PathCommand {
+    /// 对命令中的所有点应用仿射变换
+    pub fn transformed(self, t: &Affine2) -> Self {
+        let p = |v: Vec2| t.transform_point2(v);
+        match self {
+            PathCommand::MoveTo(to) => PathCommand::MoveTo(p(to)),
+            PathCommand::LineTo(to) => PathCommand::LineTo(p(to)),
+            PathCommand::QuadTo { ctrl, to } => PathCommand::QuadTo { ctrl: p(ctrl), to: p(to) },
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                PathCommand::CubicTo { ctrl1: p(ctrl1), ctrl2: p(ctrl2), to: p(to) }
+            }
+            PathCommand::Close => PathCommand::Close,
+        }
+    }
+}
+
+/// 填充规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillRule {
+    /// 非零环绕数
+    #[default]
+    NonZero,
+    /// 奇偶
+    EvenOdd,
+}
+
+/// 描边端点样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineCap {
+    /// 在端点处截断
+    #[default]
+    Butt,
+    /// 向外延伸半个线宽的方头
+    Square,
+    /// 半圆
+    Round,
+}
+
+/// 描边拐角样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineJoin {
+    /// 尖角，超过斜接限制时退化为斜切
+    #[default]
+    Miter,
+    /// 圆角
+    Round,
+    /// 斜切
+    Bevel,
+}
+
+/// 填充样式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgFill {
+    /// 颜色（已乘入 `fill-opacity` 与 `opacity`）
+    pub color: Color,
+    /// 填充规则
+    pub rule: FillRule,
+}
+
+/// 描边样式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgStroke {
+    /// 颜色（已乘入 `stroke-opacity` 与 `opacity`）
+    pub color: Color,
+    /// 线宽（已乘入变换的平均缩放）
+    pub width: f32,
+    /// 端点样式
+    pub cap: LineCap,
+    /// 拐角样式
+    pub join: LineJoin,
+    /// 斜接限制（斜接长度 / 线宽）
+    pub miter_limit: f32,
+}
+
+/// 一个带样式的形状，路径已变换到文档坐标
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgShape {
+    /// 路径命令
+    pub commands: Vec<PathCommand>,
+    /// 填充（`fill="none"` 时为 `None`）
+    pub fill: Option<SvgFill>,
+    /// 描边（`stroke` 缺省或为 `none` 时为 `None`）
+    pub stroke: Option<SvgStroke>,
+}
+
+/// SVG 文档
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SvgDocument {
+    /// 文档宽度（像素）
+    pub width: f32,
+    /// 文档高度（像素）
+    pub height: f32,
+    /// 按文档顺序（由下到上）排列的形状
+    pub shapes: Vec<SvgShape>,
+}
+
+/// 从文件加载 SVG
+pub fn load_svg(path: impl AsRef<Path>) -> Result<SvgDocument> {
+    let path = path.as_ref();
+    info!("加载 SVG 文件: {}", path.display());
+    let source = std::fs::read_to_string(path).map_err(|e| AnvilKitError::asset_with_path(
+        format!("SVG 读取失败: {}", e),
+        path.to_string_lossy().to_string(),
+    ))?;
+    parse_svg(&source).map_err(|e| AnvilKitError::asset_with_path(e.to_string(), path.to_string_lossy().to_string()))
+}
+
+/// 可继承的样式状态
+#[derive(Debug, Clone)]
+struct Style {
+    transform: Affine2,
+    fill: Option<Color>,
+    fill_rule: FillRule,
+    fill_opacity: f32,
+    stroke: Option<Color>,
+    stroke_width: f32,
+    stroke_opacity: f32,
+    cap: LineCap,
+    join: LineJoin,
+    miter_limit: f32,
+    opacity: f32,
+    hidden: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            transform: Affine2::IDENTITY,
+            fill: Some(Color::BLACK),
+            fill_rule: FillRule::NonZero,
+            fill_opacity: 1.0,
+            stroke: None,
+            stroke_width: 1.0,
+            stroke_opacity: 1.0,
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 4.0,
+            opacity: 1.0,
+            hidden: false,
+        }
+    }
+}
+
+impl Style {
+    /// 应用元素自身的属性（`style` 中的声明优先于表现属性）
+    fn apply(&mut self, attrs: &HashMap<String, String>) -> Result<()> {
+        let mut props: Vec<(&str, &str)> = attrs.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        if let Some(style) = attrs.get("style") {
+            props.extend(style.split(';').filter_map(|decl| {
+                let (k, v) = decl.split_once(':')?;
+                Some((k.trim(), v.trim()))
+            }));
+        }
+        for (key, value) in props {
+            match key {
+                "fill" => self.fill = parse_paint(value, self.fill),
+                "fill-rule" => self.fill_rule = if value == "evenodd" { FillRule::EvenOdd } else { FillRule::NonZero },
+                "fill-opacity" => self.fill_opacity = parse_number(value).unwrap_or(1.0).clamp(0.0, 1.0),
+                "stroke" => self.stroke = parse_paint(value, self.stroke),
+                "stroke-width" => self.stroke_width = parse_number(value).unwrap_or(1.0).max(0.0),
+                "stroke-opacity" => self.stroke_opacity = parse_number(value).unwrap_or(1.0).clamp(0.0, 1.0),
+                "stroke-linecap" => {
+                    self.cap = match value {
+                        "round" => LineCap::Round,
+                        "square" => LineCap::Square,
+                        _ => LineCap::Butt,
+                    }
+                }
+                "stroke-linejoin" => {
+                    self.join = match value {
+                        "round" => LineJoin::Round,
+                        "bevel" => LineJoin::Bevel,
+                        _ => LineJoin::Miter,
+                    }
+                }
+                "stroke-miterlimit" => self.miter_limit = parse_number(value).unwrap_or(4.0).max(1.0),
+                "opacity" => self.opacity *= parse_number(value).unwrap_or(1.0).clamp(0.0, 1.0),
+                "display" if value == "none" => self.hidden = true,
+                "visibility" => self.hidden = value == "hidden" || value == "collapse",
+                _ => {}
+            }
+        }
+        if let Some(transform) = attrs.get("transform") {
+            self.transform *= parse_transform(transform)?;
+        }
+        Ok(())
+    }
+
+    fn shape(&self, commands: Vec<PathCommand>) -> SvgShape {
+        let commands = commands.into_iter().map(|c| c.transformed(&self.transform)).collect();
+        let with_alpha = |color: Color, opacity: f32| color.with_alpha(color.a * opacity * self.opacity);
+        // 线宽按变换的平均缩放换算（非均匀缩放下为近似）
+        let scale = self.transform.matrix2.determinant().abs().sqrt();
+        SvgShape {
+            commands,
+            fill: self.fill.map(|color| SvgFill { color: with_alpha(color, self.fill_opacity), rule: self.fill_rule }),
+            stroke: self.stroke.filter(|_| self.stroke_width > 0.0).map(|color| SvgStroke {
+                color: with_alpha(color, self.stroke_opacity),
+                width: self.stroke_width * scale,
+                cap: self.cap,
+                join: self.join,
+                miter_limit: self.miter_limit,
+            }),
+        }
+    }
+}
+
+/// 其内容不直接绘制的容器元素
+const SKIPPED_CONTAINERS: &[&str] = &[
+    "defs", "clipPath", "mask", "pattern", "symbol", "marker", "linearGradient", "radialGradient",
+    "style", "script", "title", "desc", "metadata", "text",
+];
+
+/// 从 SVG 源文本解析
+pub fn parse_svg(source: &str) -> Result<SvgDocument> {
+    let mut doc = SvgDocument::default();
+    // (元素名, 进入该元素前的样式)
+    let mut stack: Vec<(String, Style)> = Vec::new();
+    let mut style = Style::default();
+    let mut skip_depth = 0usize;
+    let mut seen_root = false;
+
+    let mut rest = source;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        // 注释、CDATA、声明与处理指令
+        let skip_to = if rest.starts_with("<!--") {
+            Some("-->")
+        } else if rest.starts_with("<![CDATA[") {
+            Some("]]>")
+        } else if rest.starts_with("<?") {
+            Some("?>")
+        } else if rest.starts_with("<!") {
+            Some(">")
+        } else {
+            None
+        };
+        if let Some(end) = skip_to {
+            let Some(i) = rest.find(end) else { break };
+            rest = &rest[i + end.len()..];
+            continue;
+        }
+
+        let end = find_tag_end(rest).ok_or_else(|| AnvilKitError::asset("SVG 标签未闭合".to_string()))?;
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            match stack.pop() {
+                Some((open, previous)) if open == name => {
+                    style = previous;
+                    skip_depth = skip_depth.saturating_sub(1);
+                }
+                _ => return Err(AnvilKitError::asset(format!("SVG 结束标签 </{}> 不匹配", name))),
+            }
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+        let name = &tag[..name_end];
+        let attrs = parse_attributes(&tag[name_end..]);
+
+        let previous = style.clone();
+        if skip_depth > 0 || SKIPPED_CONTAINERS.contains(&name) {
+            if !self_closing {
+                skip_depth += 1;
+                stack.push((name.to_string(), previous));
+            }
+            continue;
+        }
+
+        if name == "svg" && !seen_root {
+            seen_root = true;
+            let (width, height, view_box) = root_geometry(&attrs);
+            doc.width = width;
+            doc.height = height;
+            if let Some([min_x, min_y, vw, vh]) = view_box.filter(|v| v[2] > 0.0 && v[3] > 0.0) {
+                style.transform = Affine2::from_scale(Vec2::new(width / vw, height / vh))
+                    * Affine2::from_translation(Vec2::new(-min_x, -min_y));
+            }
+        }
+        style.apply(&attrs)?;
+
+        if !style.hidden {
+            if let Some(commands) = element_path(name, &attrs)? {
+                if !commands.is_empty() && (style.fill.is_some() || style.stroke.is_some()) {
+                    doc.shapes.push(style.shape(commands));
+                }
+            }
+        }
+
+        if self_closing {
+            style = previous;
+        } else {
+            stack.push((name.to_string(), previous));
+        }
+    }
+
+    if !seen_root {
+        return Err(AnvilKitError::asset("缺少 <svg> 根元素".to_string()));
+    }
+    if !stack.is_empty() {
+        warn!("SVG 有 {} 个元素未闭合", stack.len());
+    }
+    Ok(doc)
+}
+
+/// 标签结束 `>` 的位置（跳过引号内的 `>`）
+fn find_tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_attributes(s: &str) -> HashMap<String, String> {
+    let mut attrs = HashMap::new();
+    let mut rest = s;
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_string();
+        let after = rest[eq + 1..].trim_start();
+        let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else { break };
+        let Some(close) = after[1..].find(quote) else { break };
+        attrs.insert(key, after[1..1 + close].to_string());
+        rest = &after[close + 2..];
+    }
+    attrs
+}
+
+/// 根元素的 `(width, height, viewBox)`；缺少尺寸时取 viewBox 的尺寸
+fn root_geometry(attrs: &HashMap<String, String>) -> (f32, f32, Option<[f32; 4]>) {
+    let view_box = attrs.get("viewBox").and_then(|v| {
+        let n = parse_number_list(v);
+        (n.len() == 4).then(|| [n[0], n[1], n[2], n[3]])
+    });
+    let length = |key: &str, fallback: f32| {
+        attrs.get(key).filter(|v| !v.ends_with('%')).and_then(|v| parse_number(v)).unwrap_or(fallback)
+    };
+    let width = length("width", view_box.map_or(0.0, |v| v[2]));
+    let height = length("height", view_box.map_or(0.0, |v| v[3]));
+    (width, height, view_box)
+}
+
+/// 基本形状元素转换为路径；非形状元素返回 `None`
+fn element_path(name: &str, attrs: &HashMap<String, String>) -> Result<Option<Vec<PathCommand>>> {
+    let num = |key: &str| attrs.get(key).and_then(|v| parse_number(v)).unwrap_or(0.0);
+    let commands = match name {
+        "path" => match attrs.get("d") {
+            Some(d) => parse_path_data(d)?,
+            None => Vec::new(),
+        },
+        "rect" => {
+            let (x, y, w, h) = (num("x"), num("y"), num("width"), num("height"));
+            if w <= 0.0 || h <= 0.0 {
+                return Ok(Some(Vec::new()));
+            }
+            // rx / ry 只给出一个时两者相等
+            let rx = attrs.get("rx").and_then(|v| parse_number(v));
+            let ry = attrs.get("ry").and_then(|v| parse_number(v));
+            let rx = rx.or(ry).unwrap_or(0.0).clamp(0.0, w / 2.0);
+            let ry = ry.or(Some(rx)).unwrap_or(0.0).clamp(0.0, h / 2.0);
+            rect_path(Vec2::new(x, y), Vec2::new(w, h), Vec2::new(rx, ry))
+        }
+        "circle" => {
+            let r = num("r");
+            if r <= 0.0 {
+                return Ok(Some(Vec::new()));
+            }
+            ellipse_path(Vec2::new(num("cx"), num("cy")), Vec2::splat(r))
+        }
+        "ellipse" => {
+            let (rx, ry) = (num("rx"), num("ry"));
+            if rx <= 0.0 || ry <= 0.0 {
+                return Ok(Some(Vec::new()));
+            }
+            ellipse_path(Vec2::new(num("cx"), num("cy")), Vec2::new(rx, ry))
+        }
+        "line" => vec![
+            PathCommand::MoveTo(Vec2::new(num("x1"), num("y1"))),
+            PathCommand::LineTo(Vec2::new(num("x2"), num("y2"))),
+        ],
+        "polyline" | "polygon" => {
+            let n = attrs.get("points").map(|p| parse_number_list(p)).unwrap_or_default();
+            let mut commands: Vec<PathCommand> = n
+                .chunks_exact(2)
+                .enumerate()
+                .map(|(i, p)| {
+                    let p = Vec2::new(p[0], p[1]);
+                    if i == 0 { PathCommand::MoveTo(p) } else { PathCommand::LineTo(p) }
+                })
+                .collect();
+            if name == "polygon" && !commands.is_empty() {
+                commands.push(PathCommand::Close);
+            }
+            commands
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(commands))
+}
+
+/// 三次贝塞尔近似四分之一圆弧的控制点系数
+const KAPPA: f32 = 0.552_284_8;
+
+/// 椭圆路径（四段三次贝塞尔）
+pub fn ellipse_path(center: Vec2, radii: Vec2) -> Vec<PathCommand> {
+    let k = radii * KAPPA;
+    let (c, r) = (center, radii);
+    vec![
+        PathCommand::MoveTo(Vec2::new(c.x + r.x, c.y)),
+        PathCommand::CubicTo { ctrl1: Vec2::new(c.x + r.x, c.y + k.y), ctrl2: Vec2::new(c.x + k.x, c.y + r.y), to: Vec2::new(c.x, c.y + r.y) },
+        PathCommand::CubicTo { ctrl1: Vec2::new(c.x - k.x, c.y + r.y), ctrl2: Vec2::new(c.x - r.x, c.y + k.y), to: Vec2::new(c.x - r.x, c.y) },
+        PathCommand::CubicTo { ctrl1: Vec2::new(c.x - r.x, c.y - k.y), ctrl2: Vec2::new(c.x - k.x, c.y - r.y), to: Vec2::new(c.x, c.y - r.y) },
+        PathCommand::CubicTo { ctrl1: Vec2::new(c.x + k.x, c.y - r.y), ctrl2: Vec2::new(c.x + r.x, c.y - k.y), to: Vec2::new(c.x + r.x, c.y) },
+        PathCommand::Close,
+    ]
+}
+
+/// 矩形路径，`radii` 非零时为圆角矩形
+pub fn rect_path(origin: Vec2, size: Vec2, radii: Vec2) -> Vec<PathCommand> {
+    let (x0, y0) = (origin.x, origin.y);
+    let (x1, y1) = (origin.x + size.x, origin.y + size.y);
+    if radii.x <= 0.0 || radii.y <= 0.0 {
+        return vec![
+            PathCommand::MoveTo(Vec2::new(x0, y0)),
+            PathCommand::LineTo(Vec2::new(x1, y0)),
+            PathCommand::LineTo(Vec2::new(x1, y1)),
+            PathCommand::LineTo(Vec2::new(x0, y1)),
+            PathCommand::Close,
+        ];
+    }
+    let (rx, ry) = (radii.x, radii.y);
+    let (kx, ky) = (rx * (1.0 - KAPPA), ry * (1.0 - KAPPA));
+    vec![
+        PathCommand::MoveTo(Vec2::new(x0 + rx, y0)),
+        PathCommand::LineTo(Vec2::new(x1 - rx, y0)),
+        PathCommand::CubicTo { ctrl1: Vec2::new(x1 - kx, y0), ctrl2: Vec2::new(x1, y0 + ky), to: Vec2::new(x1, y0 + ry) },
+        PathCommand::LineTo(Vec2::new(x1, y1 - ry)),
+        PathCommand::CubicTo { ctrl1: Vec2::new(x1, y1 - ky), ctrl2: Vec2::new(x1 - kx, y1), to: Vec2::new(x1 - rx, y1) },
+        PathCommand::LineTo(Vec2::new(x0 + rx, y1)),
+        PathCommand::CubicTo { ctrl1: Vec2::new(x0 + kx, y1), ctrl2: Vec2::new(x0, y1 - ky), to: Vec2::new(x0, y1 - ry) },
+        PathCommand::LineTo(Vec2::new(x0, y0 + ry)),
+        PathCommand::CubicTo { ctrl1: Vec2::new(x0, y0 + ky), ctrl2: Vec2::new(x0 + kx, y0), to: Vec2::new(x0 + rx, y0) },
+        PathCommand::Close,
+    ]
+}
+
+/// 解析颜色；`none`、渐变 / 图案引用返回 `None`，`currentColor` 与 `inherit` 保持继承值
+fn parse_paint(value: &str, inherited: Option<Color>) -> Option<Color> {
+    let value = value.trim();
+    match value {
+        "none" | "transparent" => None,
+        "inherit" | "currentColor" => inherited,
+        _ if value.starts_with("url(") => None,
+        _ => parse_color(value).or_else(|| {
+            warn!("SVG 无法解析的颜色: {}", value);
+            inherited
+        }),
+    }
+}
+
+/// 解析 CSS 颜色（十六进制、`rgb()` / `rgba()`、常用颜色名）
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if value.starts_with('#') {
+        return Color::hex(value);
+    }
+    if let Some(args) = value.strip_prefix("rgba(").or_else(|| value.strip_prefix("rgb(")) {
+        let args: Vec<&str> = args.trim_end_matches(')').split(',').map(str::trim).collect();
+        if args.len() < 3 {
+            return None;
+        }
+        let channel = |s: &str| match s.strip_suffix('%') {
+            Some(p) => p.parse::<f32>().ok().map(|v| v / 100.0),
+            None => s.parse::<f32>().ok().map(|v| v / 255.0),
+        };
+        let alpha = args.get(3).and_then(|a| a.parse::<f32>().ok()).unwrap_or(1.0);
+        return Some(Color::srgba(channel(args[0])?, channel(args[1])?, channel(args[2])?, alpha.clamp(0.0, 1.0)));
+    }
+    let hex = match value.to_ascii_lowercase().as_str() {
+        "black" => "000000",
+        "white" => "ffffff",
+        "red" => "ff0000",
+        "lime" => "00ff00",
+        "green" => "008000",
+        "blue" => "0000ff",
+        "yellow" => "ffff00",
+        "cyan" | "aqua" => "00ffff",
+        "magenta" | "fuchsia" => "ff00ff",
+        "gray" | "grey" => "808080",
+        "silver" => "c0c0c0",
+        "maroon" => "800000",
+        "olive" => "808000",
+        "navy" => "000080",
+        "purple" => "800080",
+        "teal" => "008080",
+        "orange" => "ffa500",
+        _ => return None,
+    };
+    Color::hex(hex)
+}
+
+/// 解析带单位的数值（忽略 `px` 等后缀）
+fn parse_number(value: &str) -> Option<f32> {
+    let mut scanner = Scanner::new(value.trim());
+    scanner.number()
+}
+
+fn parse_number_list(value: &str) -> Vec<f32> {
+    let mut scanner = Scanner::new(value);
+    std::iter::from_fn(|| scanner.number()).collect()
+}
+
+/// 解析 `transform` 属性
+pub fn parse_transform(value: &str) -> Result<Affine2> {
+    let mut result = Affine2::IDENTITY;
+    let mut rest = value.trim();
+    while !rest.is_empty() {
+        let open = rest.find('(').ok_or_else(|| AnvilKitError::asset(format!("无效的 transform: {}", value)))?;
+        let close = rest.find(')').ok_or_else(|| AnvilKitError::asset(format!("无效的 transform: {}", value)))?;
+        let name = rest[..open].trim_matches(|c: char| c.is_whitespace() || c == ',');
+        let args = parse_number_list(&rest[open + 1..close]);
+        let arg = |i: usize| args.get(i).copied();
+        let t = match (name, args.len()) {
+            ("matrix", 6) => Affine2::from_cols_array(&[args[0], args[1], args[2], args[3], args[4], args[5]]),
+            ("translate", 1 | 2) => Affine2::from_translation(Vec2::new(args[0], arg(1).unwrap_or(0.0))),
+            ("scale", 1 | 2) => Affine2::from_scale(Vec2::new(args[0], arg(1).unwrap_or(args[0]))),
+            ("rotate", 1) => Affine2::from_angle(args[0].to_radians()),
+            ("rotate", 3) => {
+                let pivot = Vec2::new(args[1], args[2]);
+                Affine2::from_translation(pivot) * Affine2::from_angle(args[0].to_radians()) * Affine2::from_translation(-pivot)
+            }
+            ("skewX", 1) => Affine2::from_cols_array(&[1.0, 0.0, args[0].to_radians().tan(), 1.0, 0.0, 0.0]),
+            ("skewY", 1) => Affine2::from_cols_array(&[1.0, args[0].to_radians().tan(), 0.0, 1.0, 0.0, 0.0]),
+            _ => return Err(AnvilKitError::asset(format!("不支持的 transform: {}", &rest[..=close]))),
+        };
+        result *= t;
+        rest = rest[close + 1..].trim_start();
+    }
+    Ok(result)
+}
+
+/// 路径数据 / 数字列表扫描器
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(s: &'a str) -> Self {
+        Self { bytes: s.as_bytes(), pos: 0 }
+    }
+
+    fn skip_separators(&mut self) {
+        while self.pos < self.bytes.len() && (self.bytes[self.pos].is_ascii_whitespace() || self.bytes[self.pos] == b',') {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_separators();
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn number(&mut self) -> Option<f32> {
+        self.skip_separators();
+        let start = self.pos;
+        let b = self.bytes;
+        let mut i = self.pos;
+        if i < b.len() && (b[i] == b'+' || b[i] == b'-') {
+            i += 1;
+        }
+        let digits_start = i;
+        while i < b.len() && b[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i < b.len() && b[i] == b'.' {
+            i += 1;
+            while i < b.len() && b[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+        if i == digits_start || (i == digits_start + 1 && b[digits_start] == b'.') {
+            return None;
+        }
+        if i < b.len() && (b[i] == b'e' || b[i] == b'E') {
+            let mut j = i + 1;
+            if j < b.len() && (b[j] == b'+' || b[j] == b'-') {
+                j += 1;
+            }
+            if j < b.len() && b[j].is_ascii_digit() {
+                while j < b.len() && b[j].is_ascii_digit() {
+                    j += 1;
+                }
+                i = j;
+            }
+        }
+        let value = std::str::from_utf8(&b[start..i]).ok()?.parse().ok()?;
+        self.pos = i;
+        // 跳过单位后缀
+        while self.pos < b.len() && b[self.pos].is_ascii_alphabetic() && !is_path_command(b[self.pos]) {
+            self.pos += 1;
+        }
+        Some(value)
+    }
+
+    /// 圆弧标志位：单个 `0` / `1`，后面可以不带分隔符
+    fn flag(&mut self) -> Option<bool> {
+        match self.peek()? {
+            b'0' => { self.pos += 1; Some(false) }
+            b'1' => { self.pos += 1; Some(true) }
+            _ => None,
+        }
+    }
+
+    fn point(&mut self) -> Option<Vec2> {
+        Some(Vec2::new(self.number()?, self.number()?))
+    }
+}
+
+fn is_path_command(c: u8) -> bool {
+    matches!(c.to_ascii_uppercase(), b'M' | b'L' | b'H' | b'V' | b'C' | b'S' | b'Q' | b'T' | b'A' | b'Z')
+}
+
+/// 解析路径数据（`d` 属性）为绝对坐标命令
+pub fn parse_path_data(d: &str) -> Result<Vec<PathCommand>> {
+    let err = |scanner: &Scanner| AnvilKitError::asset(format!("路径数据在第 {} 个字节处无效", scanner.pos));
+    let mut s = Scanner::new(d);
+    let mut out = Vec::new();
+    let mut current = Vec2::ZERO;
+    let mut subpath_start = Vec2::ZERO;
+    // 上一个三次 / 二次控制点，用于 S / T 的反射
+    let mut last_cubic: Option<Vec2> = None;
+    let mut last_quad: Option<Vec2> = None;
+    let mut command: Option<u8> = None;
+
+    while let Some(c) = s.peek() {
+        if is_path_command(c) {
+            s.pos += 1;
+            command = Some(c);
+        } else if command.is_none() {
+            return Err(err(&s));
+        }
+        let cmd = command.expect("checked above");
+        let relative = cmd.is_ascii_lowercase();
+        let base = if relative { current } else { Vec2::ZERO };
+        let mut cubic_ctrl = None;
+        let mut quad_ctrl = None;
+
+        match cmd.to_ascii_uppercase() {
+            b'M' => {
+                let p = s.point().ok_or_else(|| err(&s))? + base;
+                out.push(PathCommand::MoveTo(p));
+                current = p;
+                subpath_start = p;
+                // M 之后的隐式坐标对按 L 处理
+                command = Some(if relative { b'l' } else { b'L' });
+            }
+            b'L' => {
+                let p = s.point().ok_or_else(|| err(&s))? + base;
+                out.push(PathCommand::LineTo(p));
+                current = p;
+            }
+            b'H' => {
+                let x = s.number().ok_or_else(|| err(&s))? + base.x;
+                current = Vec2::new(x, current.y);
+                out.push(PathCommand::LineTo(current));
+            }
+            b'V' => {
+                let y = s.number().ok_or_else(|| err(&s))? + base.y;
+                current = Vec2::new(current.x, y);
+                out.push(PathCommand::LineTo(current));
+            }
+            b'C' => {
+                let ctrl1 = s.point().ok_or_else(|| err(&s))? + base;
+                let ctrl2 = s.point().ok_or_else(|| err(&s))? + base;
+                let to = s.point().ok_or_else(|| err(&s))? + base;
+                out.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+                cubic_ctrl = Some(ctrl2);
+                current = to;
+            }
+            b'S' => {
+                let ctrl1 = last_cubic.map_or(current, |c| 2.0 * current - c);
+                let ctrl2 = s.point().ok_or_else(|| err(&s))? + base;
+                let to = s.point().ok_or_else(|| err(&s))? + base;
+                out.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+                cubic_ctrl = Some(ctrl2);
+                current = to;
+            }
+            b'Q' => {
+                let ctrl = s.point().ok_or_else(|| err(&s))? + base;
+                let to = s.point().ok_or_else(|| err(&s))? + base;
+                out.push(PathCommand::QuadTo { ctrl, to });
+                quad_ctrl = Some(ctrl);
+                current = to;
+            }
+            b'T' => {
+                let ctrl = last_quad.map_or(current, |c| 2.0 * current - c);
+                let to = s.point().ok_or_else(|| err(&s))? + base;
+                out.push(PathCommand::QuadTo { ctrl, to });
+                quad_ctrl = Some(ctrl);
+                current = to;
+            }
+            b'A' => {
+                let rx = s.number().ok_or_else(|| err(&s))?;
+                let ry = s.number().ok_or_else(|| err(&s))?;
+                let rotation = s.number().ok_or_else(|| err(&s))?;
+                let large_arc = s.flag().ok_or_else(|| err(&s))?;
+                let sweep = s.flag().ok_or_else(|| err(&s))?;
+                let to = s.point().ok_or_else(|| err(&s))? + base;
+                arc_to_cubics(current, Vec2::new(rx, ry), rotation, large_arc, sweep, to, &mut out);
+                current = to;
+            }
+            b'Z' => {
+                out.push(PathCommand::Close);
+                current = subpath_start;
+                // Z 没有参数；下一个 token 必须是命令
+                command = None;
+            }
+            _ => unreachable!("is_path_command filters commands"),
+        }
+        last_cubic = cubic_ctrl;
+        last_quad = quad_ctrl;
+    }
+    Ok(out)
+}
+
+/// 端点参数化的椭圆弧转换为三次贝塞尔（SVG 规范附录 B.2.4 的中心参数化）
+fn arc_to_cubics(from: Vec2, radii: Vec2, rotation_deg: f32, large_arc: bool, sweep: bool, to: Vec2, out: &mut Vec<PathCommand>) {
+    let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+    if from == to {
+        return;
+    }
+    if rx == 0.0 || ry == 0.0 {
+        out.push(PathCommand::LineTo(to));
+        return;
+    }
+    let phi = rotation_deg.to_radians();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+    let half = (from - to) / 2.0;
+    let p = Vec2::new(cos_phi * half.x + sin_phi * half.y, -sin_phi * half.x + cos_phi * half.y);
+
+    // 半径不足以连接两端点时按比例放大
+    let lambda = (p.x * p.x) / (rx * rx) + (p.y * p.y) / (ry * ry);
+    if lambda > 1.0 {
+        let s = lambda.sqrt();
+        rx *= s;
+        ry *= s;
+    }
+    let num = rx * rx * ry * ry - rx * rx * p.y * p.y - ry * ry * p.x * p.x;
+    let den = rx * rx * p.y * p.y + ry * ry * p.x * p.x;
+    let mut coef = (num / den).max(0.0).sqrt();
+    if large_arc == sweep {
+        coef = -coef;
+    }
+    let center_prime = Vec2::new(coef * rx * p.y / ry, -coef * ry * p.x / rx);
+    let mid = (from + to) / 2.0;
+    let center = Vec2::new(
+        cos_phi * center_prime.x - sin_phi * center_prime.y + mid.x,
+        sin_phi * center_prime.x + cos_phi * center_prime.y + mid.y,
+    );
+
+    let angle = |u: Vec2, v: Vec2| {
+        let a = (u.dot(v) / (u.length() * v.length())).clamp(-1.0, 1.0).acos();
+        if u.x * v.y - u.y * v.x < 0.0 { -a } else { a }
+    };
+    let start_vec = Vec2::new((p.x - center_prime.x) / rx, (p.y - center_prime.y) / ry);
+    let end_vec = Vec2::new((-p.x - center_prime.x) / rx, (-p.y - center_prime.y) / ry);
+    let theta1 = angle(Vec2::X, start_vec);
+    let mut delta = angle(start_vec, end_vec);
+    if !sweep && delta > 0.0 {
+        delta -= std::f32::consts::TAU;
+    } else if sweep && delta < 0.0 {
+        delta += std::f32::consts::TAU;
+    }
+
+    // 每段不超过 90°
+    let segments = (delta.abs() / std::f32::consts::FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = delta / segments as f32;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let point = |theta: f32| {
+        let (s, c) = theta.sin_cos();
+        Vec2::new(
+            cos_phi * rx * c - sin_phi * ry * s + center.x,
+            sin_phi * rx * c + cos_phi * ry * s + center.y,
+        )
+    };
+    let derivative = |theta: f32| {
+        let (s, c) = theta.sin_cos();
+        Vec2::new(-cos_phi * rx * s - sin_phi * ry * c, -sin_phi * rx * s + cos_phi * ry * c)
+    };
+    let mut theta = theta1;
+    for i in 0..segments {
+        let next = theta + step;
+        let end = if i + 1 == segments { to } else { point(next) };
+        out.push(PathCommand::CubicTo {
+            ctrl1: point(theta) + k * derivative(theta),
+            ctrl2: point(next) - k * derivative(next),
+            to: end,
+        });
+        theta = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path_data_relative_and_implicit() {
+        let cmds = parse_path_data("m10,10 20 0 v20 h-20 z M0 0L5-5").unwrap();
+        assert_eq!(cmds, vec![
+            PathCommand::MoveTo(Vec2::new(10.0, 10.0)),
+            PathCommand::LineTo(Vec2::new(30.0, 10.0)),
+            PathCommand::LineTo(Vec2::new(30.0, 30.0)),
+            PathCommand::LineTo(Vec2::new(10.0, 30.0)),
+            PathCommand::Close,
+            PathCommand::MoveTo(Vec2::ZERO),
+            PathCommand::LineTo(Vec2::new(5.0, -5.0)),
+        ]);
+
+        // S 反射上一个控制点；紧凑的数字写法
+        let cmds = parse_path_data("M0 0C0 10 10 10 10 0S20-10 20 0").unwrap();
+        assert_eq!(cmds[2], PathCommand::CubicTo {
+            ctrl1: Vec2::new(10.0, -10.0),
+            ctrl2: Vec2::new(20.0, -10.0),
+            to: Vec2::new(20.0, 0.0),
+        });
+        assert!(parse_path_data("10 10").is_err());
+    }
+
+    #[test]
+    fn test_arc_ends_at_target_and_stays_on_circle() {
+        // 半圆：(0,0) → (20,0)，半径 10，顺时针（y 向下时经过 y > 0 一侧）
+        let cmds = parse_path_data("M0 0 A10 10 0 0 1 20 0").unwrap();
+        assert_eq!(cmds.len(), 3);
+        let PathCommand::CubicTo { to, ctrl1, .. } = cmds[1] else { panic!("expected cubic") };
+        assert!((to - Vec2::new(10.0, -10.0)).length() < 1e-3 || (to - Vec2::new(10.0, 10.0)).length() < 1e-3);
+        assert!(ctrl1.x.abs() < 1e-3);
+        let PathCommand::CubicTo { to, .. } = cmds[2] else { panic!("expected cubic") };
+        assert_eq!(to, Vec2::new(20.0, 0.0));
+        // 紧凑的标志位写法
+        assert_eq!(parse_path_data("M0 0a10 10 0 1020 0").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_parse_svg_shapes_styles_and_transforms() {
+        let source = r##"<?xml version="1.0"?>
+            <!-- icon -->
+            <svg xmlns="http://www.w3.org/2000/svg" width="200" height="100" viewBox="0 0 100 50">
+              <defs><linearGradient id="g"><stop offset="0"/></linearGradient></defs>
+              <g fill="#ff0000" transform="translate(10 0)">
+                <rect x="0" y="0" width="10" height="10"/>
+                <circle cx="5" cy="5" r="2" style="fill:none; stroke: blue; stroke-width: 2"/>
+              </g>
+              <path d="M0 0 L1 1" fill="none"/>
+              <polygon points="0,0 4,0 2,3" fill-rule="evenodd" opacity="0.5"/>
+              <rect width="5" height="5" display="none"/>
+            </svg>"##;
+        let doc = parse_svg(source).unwrap();
+        assert_eq!((doc.width, doc.height), (200.0, 100.0));
+        assert_eq!(doc.shapes.len(), 3);
+
+        // viewBox 缩放 2 倍，再平移 10
+        let rect = &doc.shapes[0];
+        assert_eq!(rect.commands[0], PathCommand::MoveTo(Vec2::new(20.0, 0.0)));
+        assert_eq!(rect.commands[2], PathCommand::LineTo(Vec2::new(40.0, 20.0)));
+        assert_eq!(rect.fill.unwrap().color, Color::RED);
+        assert!(rect.stroke.is_none());
+
+        let circle = &doc.shapes[1];
+        assert!(circle.fill.is_none());
+        let stroke = circle.stroke.unwrap();
+        assert_eq!(stroke.color, Color::BLUE);
+        assert!((stroke.width - 4.0).abs() < 1e-5);
+
+        let triangle = &doc.shapes[2];
+        let fill = triangle.fill.unwrap();
+        assert_eq!(fill.rule, FillRule::EvenOdd);
+        assert_eq!(fill.color, Color::BLACK.with_alpha(0.5));
+        assert_eq!(triangle.commands.last(), Some(&PathCommand::Close));
+    }
+
+    #[test]
+    fn test_parse_svg_errors() {
+        assert!(parse_svg("<g></g>").is_err());
+        assert!(parse_svg("<svg><g></svg>").is_err());
+        assert!(parse_svg(r#"<svg><path d="M0 0" transform="perspective(1)"/></svg>"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#f00"), Some(Color::RED));
+        assert_eq!(parse_color("rgb(0, 0, 255)"), Some(Color::BLUE));
+        assert_eq!(parse_color("rgba(0,0,0,0.25)"), Some(Color::BLACK.with_alpha(0.25)));
+        assert_eq!(parse_color("White"), Some(Color::WHITE));
+        assert_eq!(parse_color("chartreuse-ish"), None);
+    }
+}
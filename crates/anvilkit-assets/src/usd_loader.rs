@@ -0,0 +1,994 @@
+//! # USD 加载器（`usd` 特性）
+//!
+//! 读取 USDA 文本层与 USDZ 包，产出与 glTF 层级加载器相同的 [`SceneHierarchy`]，
+//! 供无法把整个资产库转换为 glTF 的工作室管线直接使用。
+//!
+//! 支持范围：
+//!
+//! - `Xform` / `Scope` / `Mesh` prim 层级，`xformOpOrder` 中的
+//!   translate / scale / rotateX|Y|Z / rotateXYZ 等欧拉组合 / orient / transform（含 `!invert!`）
+//! - `Mesh` 的 `points`、`faceVertexCounts`、`faceVertexIndices`、`normals`、`primvars:st`
+//!   （vertex / faceVarying / uniform / constant 插值，支持 `:indices`），多边形按三角扇拆分，
+//!   `orientation = "leftHanded"` 翻转环绕方向，缺失法线时生成面法线
+//! - `material:binding`（可从祖先继承）指向的 `UsdPreviewSurface`：diffuseColor、metallic、
+//!   roughness、opacity、emissiveColor，以及经 `UsdUVTexture` 连接的基础色 / 法线纹理；
+//!   未绑定材质时使用 `primvars:displayColor`
+//! - 层元数据 `upAxis` 与 `metersPerUnit`；属性只有 `timeSamples` 时取第一个采样
+//!
+//! 不支持：二进制 USDC（可用 `usdcat -o model.usda model.usdc` 转换）、组合弧
+//! （references / payloads / sublayers / variants）、`GeomSubset` 分面材质与压缩的 USDZ 条目。
+//!
+//! ```rust,no_run
+//! use anvilkit_assets::usd_loader::load_usd_with;
+//! use anvilkit_assets::import::ImportOptions;
+//!
+//! let scene = load_usd_with("assets/chair.usdz", &ImportOptions::default()).expect("加载失败");
+//! println!("节点: {}, 子网格: {}", scene.hierarchy.node_count(), scene.hierarchy.submesh_count());
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use glam::{Mat4, Quat, Vec2, Vec3};
+use log::{info, warn};
+
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_core::math::CoordinateSystem;
+use crate::import::ImportOptions;
+use crate::material::{MaterialData, TextureData};
+use crate::mesh::MeshData;
+use crate::scene::{SceneHierarchy, SceneMesh, SceneNode, Submesh};
+use crate::texture::{load_texture, load_texture_from_memory};
+
+/// USD 加载结果
+#[derive(Debug, Clone)]
+pub struct UsdScene {
+    /// 节点层级、网格与材质
+    pub hierarchy: SceneHierarchy,
+    /// 层的坐标约定（由 `upAxis` 决定，转换后为目标约定）
+    pub coordinate_system: CoordinateSystem,
+    /// 每单位对应的米数（USD 默认 0.01，即厘米）
+    pub meters_per_unit: f32,
+}
+
+/// 从 `.usda` 或 `.usdz` 文件加载，保持层的坐标约定
+pub fn load_usd(path: impl AsRef<Path>) -> Result<UsdScene> {
+    let path = path.as_ref();
+    info!("加载 USD 文件: {}", path.display());
+
+    let with_path = |e: AnvilKitError| AnvilKitError::asset_with_path(e.to_string(), path.to_string_lossy().to_string());
+    let bytes = std::fs::read(path).map_err(|e| AnvilKitError::asset_with_path(
+        format!("USD 读取失败: {}", e),
+        path.to_string_lossy().to_string(),
+    ))?;
+
+    let is_usdz = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("usdz"));
+    let result = if is_usdz {
+        read_usdz(&bytes)
+    } else {
+        let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+        layer_text(&bytes).and_then(|source| parse_usda(source, |asset| {
+            load_texture(base_dir.join(asset))
+                .map_err(|e| warn!("USD 纹理加载失败 {}: {}", asset, e))
+                .ok()
+        }))
+    };
+    let scene = result.map_err(with_path)?;
+
+    info!("USD 加载完成: {} 个节点, {} 个子网格", scene.hierarchy.node_count(), scene.hierarchy.submesh_count());
+    Ok(scene)
+}
+
+/// 按导入选项加载，返回前把层的坐标约定（`upAxis`）转换到目标约定
+pub fn load_usd_with(path: impl AsRef<Path>, options: &ImportOptions) -> Result<UsdScene> {
+    let mut scene = load_usd(path)?;
+    if let Some((from, to)) = options.conversion(scene.coordinate_system) {
+        scene.hierarchy.convert_coordinates(from, to);
+        scene.coordinate_system = to;
+    }
+    Ok(scene)
+}
+
+/// 从内存中的 USDZ 包加载
+///
+/// 第一个条目为根层，纹理从包内按相对路径解析。
+pub fn read_usdz(bytes: &[u8]) -> Result<UsdScene> {
+    let entries = usdz_entries(bytes)?;
+    let (_, root) = entries.first().ok_or_else(|| usd_error("USDZ 包为空"))?;
+    let source = layer_text(root)?;
+    let files: HashMap<&str, &[u8]> = entries.iter().map(|(name, data)| (name.as_str(), *data)).collect();
+    parse_usda(source, |asset| {
+        let data = files.get(asset.trim_start_matches("./"))?;
+        load_texture_from_memory(data)
+            .map_err(|e| warn!("USDZ 纹理解码失败 {}: {}", asset, e))
+            .ok()
+    })
+}
+
+/// 解析 USDA 文本层
+///
+/// `load_texture` 把 `UsdUVTexture` 的 `inputs:file` 资产路径解析为纹理，返回 `None` 时忽略该纹理。
+pub fn parse_usda(source: &str, mut load_texture: impl FnMut(&str) -> Option<TextureData>) -> Result<UsdScene> {
+    if !source.trim_start().starts_with("#usda") {
+        return Err(usd_error("缺少 `#usda` 文件头"));
+    }
+    let mut parser = Parser { tokens: tokenize(source)?, pos: 0 };
+    let (metadata, roots) = parser.parse_layer()?;
+
+    let coordinate_system = match metadata.get("upAxis").and_then(Value::as_str) {
+        Some("Z") => CoordinateSystem::USD_Z_UP,
+        _ => CoordinateSystem::USD_Y_UP,
+    };
+    let meters_per_unit = metadata.get("metersPerUnit").and_then(Value::as_f32).unwrap_or(0.01);
+
+    let mut prims = HashMap::new();
+    for root in &roots {
+        index_prims(root, &mut prims);
+    }
+    let mut builder = SceneBuilder {
+        prims,
+        load_texture: &mut load_texture,
+        materials: HashMap::new(),
+        hierarchy: SceneHierarchy::default(),
+    };
+    let root_nodes: Vec<usize> = roots
+        .iter()
+        .filter_map(|prim| builder.build_node(prim, None))
+        .collect();
+    let mut hierarchy = builder.hierarchy;
+    hierarchy.roots = root_nodes;
+
+    Ok(UsdScene { hierarchy, coordinate_system, meters_per_unit })
+}
+
+fn usd_error(message: impl Into<String>) -> AnvilKitError {
+    AnvilKitError::asset(format!("USD: {}", message.into()))
+}
+
+/// 校验并取出层的文本内容
+fn layer_text(bytes: &[u8]) -> Result<&str> {
+    if bytes.starts_with(b"PXR-USDC") {
+        return Err(usd_error("不支持二进制 USDC 层，请先用 `usdcat -o <file>.usda` 转换"));
+    }
+    std::str::from_utf8(bytes).map_err(|_| usd_error("层不是有效的 UTF-8 文本"))
+}
+
+/// 读取 USDZ（不压缩的 zip）中的所有条目
+fn usdz_entries(bytes: &[u8]) -> Result<Vec<(String, &[u8])>> {
+    const LOCAL_HEADER: u32 = 0x0403_4b50;
+    let u16_at = |o: usize| bytes.get(o..o + 2).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize);
+    let u32_at = |o: usize| bytes.get(o..o + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+    let truncated = || usd_error("USDZ 包被截断");
+
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while u32_at(offset) == Some(LOCAL_HEADER) {
+        let flags = u16_at(offset + 6).ok_or_else(truncated)?;
+        let method = u16_at(offset + 8).ok_or_else(truncated)?;
+        let size = u32_at(offset + 18).ok_or_else(truncated)? as usize;
+        let name_len = u16_at(offset + 26).ok_or_else(truncated)?;
+        let extra_len = u16_at(offset + 28).ok_or_else(truncated)?;
+        let name_start = offset + 30;
+        let name = bytes.get(name_start..name_start + name_len).ok_or_else(truncated)?;
+        let name = String::from_utf8_lossy(name).into_owned();
+        if method != 0 || flags & 0x08 != 0 {
+            return Err(usd_error(format!("USDZ 条目 {} 被压缩（规范要求不压缩存储）", name)));
+        }
+        let data_start = name_start + name_len + extra_len;
+        let data = bytes.get(data_start..data_start + size).ok_or_else(truncated)?;
+        entries.push((name, data));
+        offset = data_start + size;
+    }
+    Ok(entries)
+}
+
+// ---------------------------------------------------------------------------
+//  USDA 词法与语法
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Asset(String),
+    Path(String),
+    Punct(char),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let take_until = |i: &mut usize, end: char| -> Result<String> {
+        let start = *i;
+        while *i < chars.len() && chars[*i] != end {
+            *i += 1;
+        }
+        if *i >= chars.len() {
+            return Err(usd_error(format!("缺少闭合的 `{}`", end)));
+        }
+        let text: String = chars[start..*i].iter().collect();
+        *i += 1;
+        Ok(text)
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if c == '"' || c == '\'' {
+            let triple = chars.get(i + 1) == Some(&c) && chars.get(i + 2) == Some(&c);
+            i += if triple { 3 } else { 1 };
+            let mut text = String::new();
+            loop {
+                if i >= chars.len() {
+                    return Err(usd_error("字符串未闭合"));
+                }
+                if chars[i] == c && (!triple || (chars.get(i + 1) == Some(&c) && chars.get(i + 2) == Some(&c))) {
+                    i += if triple { 3 } else { 1 };
+                    break;
+                }
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    i += 1;
+                }
+                text.push(chars[i]);
+                i += 1;
+            }
+            tokens.push(Token::Str(text));
+        } else if c == '@' {
+            i += 1;
+            tokens.push(Token::Asset(take_until(&mut i, '@')?));
+        } else if c == '<' {
+            i += 1;
+            tokens.push(Token::Path(take_until(&mut i, '>')?));
+        } else if c.is_ascii_digit()
+            || (matches!(c, '-' | '+' | '.') && chars.get(i + 1).is_some_and(|n| n.is_ascii_digit() || *n == '.'))
+        {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || matches!(chars[i], '.' | '-' | '+')) {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let value = text.parse::<f64>().map_err(|_| usd_error(format!("无效的数值 `{}`", text)))?;
+            tokens.push(Token::Num(value));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | ':' | '.')) {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(Token::Punct(c));
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    None,
+    Num(f64),
+    Str(String),
+    Asset(String),
+    Path(String),
+    Tuple(Vec<Value>),
+    List(Vec<Value>),
+    Samples(Vec<(f64, Value)>),
+}
+
+impl Value {
+    fn as_f32(&self) -> Option<f32> {
+        match self {
+            Value::Num(n) => Some(*n as f32),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(items) | Value::Tuple(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_floats<const N: usize>(&self) -> Option<[f32; N]> {
+        let items = self.as_list()?;
+        if items.len() != N {
+            return None;
+        }
+        let mut out = [0.0; N];
+        for (slot, item) in out.iter_mut().zip(items) {
+            *slot = item.as_f32()?;
+        }
+        Some(out)
+    }
+
+    fn as_vec2(&self) -> Option<Vec2> {
+        self.as_floats::<2>().map(Vec2::from)
+    }
+
+    fn as_vec3(&self) -> Option<Vec3> {
+        self.as_floats::<3>().map(Vec3::from)
+    }
+
+    /// USD 四元数为 `(real, i, j, k)`
+    fn as_quat(&self) -> Option<Quat> {
+        let [w, x, y, z] = self.as_floats::<4>()?;
+        Some(Quat::from_xyzw(x, y, z, w).normalize())
+    }
+
+    /// USD 矩阵为行向量约定，按行读取即得到列向量约定下的矩阵
+    fn as_mat4(&self) -> Option<Mat4> {
+        let rows = self.as_list()?;
+        if rows.len() != 4 {
+            return None;
+        }
+        let mut cols = [[0.0; 4]; 4];
+        for (col, row) in cols.iter_mut().zip(rows) {
+            *col = row.as_floats::<4>()?;
+        }
+        Some(Mat4::from_cols_array_2d(&cols))
+    }
+
+    fn as_path(&self) -> Option<&str> {
+        match self {
+            Value::Path(p) => Some(p),
+            Value::List(items) => items.first().and_then(Value::as_path),
+            _ => None,
+        }
+    }
+
+    fn map_list<T>(&self, f: impl Fn(&Value) -> Option<T>) -> Option<Vec<T>> {
+        self.as_list()?.iter().map(f).collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Attribute {
+    value: Option<Value>,
+    metadata: HashMap<String, Value>,
+}
+
+#[derive(Debug, Default)]
+struct Prim {
+    specifier: String,
+    type_name: String,
+    name: String,
+    path: String,
+    attrs: HashMap<String, Attribute>,
+    children: Vec<Prim>,
+}
+
+impl Prim {
+    /// 属性值；只有 `timeSamples` 时取第一个采样
+    fn attr(&self, name: &str) -> Option<&Value> {
+        if let Some(value) = self.attrs.get(name).and_then(|a| a.value.as_ref()).filter(|v| **v != Value::None) {
+            return Some(value);
+        }
+        match self.attrs.get(&format!("{name}.timeSamples"))?.value.as_ref()? {
+            Value::Samples(samples) => samples.first().map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn attr_meta(&self, name: &str, key: &str) -> Option<&Value> {
+        self.attrs.get(name)?.metadata.get(key)
+    }
+
+    /// `<name>.connect` 连接到的属性路径
+    fn connection(&self, name: &str) -> Option<&str> {
+        self.attrs.get(&format!("{name}.connect"))?.value.as_ref()?.as_path()
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Result<Token> {
+        let token = self.tokens.get(self.pos).cloned().ok_or_else(|| usd_error("文件意外结束"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        match self.bump()? {
+            Token::Punct(p) if p == c => Ok(()),
+            other => Err(usd_error(format!("期望 `{}`，实际为 {:?}", c, other))),
+        }
+    }
+
+    /// 跳过到与已消费的 `open` 匹配的 `close`
+    fn skip_group(&mut self, open: char, close: char) -> Result<()> {
+        let mut depth = 1;
+        while depth > 0 {
+            match self.bump()? {
+                Token::Punct(c) if c == open => depth += 1,
+                Token::Punct(c) if c == close => depth -= 1,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_layer(&mut self) -> Result<(HashMap<String, Value>, Vec<Prim>)> {
+        let metadata = if self.eat('(') { self.parse_metadata()? } else { HashMap::new() };
+        let mut prims = Vec::new();
+        while let Some(token) = self.peek().cloned() {
+            self.pos += 1;
+            match token {
+                Token::Ident(word) if matches!(word.as_str(), "def" | "over" | "class") => {
+                    prims.push(self.parse_prim(word, "")?);
+                }
+                Token::Punct(';') => {}
+                other => return Err(usd_error(format!("层中出现意外的记号 {:?}", other))),
+            }
+        }
+        Ok((metadata, prims))
+    }
+
+    fn parse_prim(&mut self, specifier: String, parent_path: &str) -> Result<Prim> {
+        let type_name = match self.peek() {
+            Some(Token::Ident(t)) => {
+                let t = t.clone();
+                self.pos += 1;
+                t
+            }
+            _ => String::new(),
+        };
+        let name = match self.bump()? {
+            Token::Str(name) => name,
+            other => return Err(usd_error(format!("缺少 prim 名称，实际为 {:?}", other))),
+        };
+        let path = format!("{}/{}", parent_path, name);
+        if self.eat('(') {
+            self.parse_metadata()?;
+        }
+        self.expect('{')?;
+
+        let mut prim = Prim { specifier, type_name, name, path, ..Default::default() };
+        loop {
+            match self.bump()? {
+                Token::Punct('}') => break,
+                Token::Punct(';') => {}
+                Token::Ident(word) => match word.as_str() {
+                    "def" | "over" | "class" => {
+                        let child = self.parse_prim(word, &prim.path)?;
+                        prim.children.push(child);
+                    }
+                    "variantSet" => {
+                        while !self.eat('{') {
+                            self.bump()?;
+                        }
+                        self.skip_group('{', '}')?;
+                    }
+                    _ => self.parse_property(word, &mut prim)?,
+                },
+                other => return Err(usd_error(format!("prim {} 中出现意外的记号 {:?}", prim.path, other))),
+            }
+        }
+        Ok(prim)
+    }
+
+    /// `[custom] [uniform] <type>[[]] <name> [= value] [(metadata)]`，或 `rel <name> [= target]`
+    fn parse_property(&mut self, first: String, prim: &mut Prim) -> Result<()> {
+        let mut type_name = first;
+        while matches!(type_name.as_str(), "custom" | "uniform" | "varying" | "prepend" | "append" | "add" | "delete" | "reorder") {
+            match self.bump()? {
+                Token::Ident(word) => type_name = word,
+                other => return Err(usd_error(format!("prim {} 的属性声明不完整: {:?}", prim.path, other))),
+            }
+        }
+        if self.peek() == Some(&Token::Punct('[')) && self.tokens.get(self.pos + 1) == Some(&Token::Punct(']')) {
+            self.pos += 2;
+        }
+        let name = match self.peek() {
+            Some(Token::Ident(name)) => {
+                let name = name.clone();
+                self.pos += 1;
+                name
+            }
+            _ => type_name,
+        };
+        let value = if self.eat('=') { Some(self.parse_value()?) } else { None };
+        let metadata = if self.eat('(') { self.parse_metadata()? } else { HashMap::new() };
+        prim.attrs.insert(name, Attribute { value, metadata });
+        Ok(())
+    }
+
+    /// 已消费 `(`，读取 `key = value` 形式的元数据直到 `)`
+    fn parse_metadata(&mut self) -> Result<HashMap<String, Value>> {
+        let mut metadata = HashMap::new();
+        loop {
+            match self.bump()? {
+                Token::Punct(')') => break,
+                Token::Punct('(') => self.skip_group('(', ')')?,
+                Token::Punct('{') => self.skip_group('{', '}')?,
+                Token::Ident(word) => {
+                    // 列表操作或类型前缀：`prepend apiSchemas = [...]`
+                    let mut key = word;
+                    while let Some(Token::Ident(next)) = self.peek() {
+                        key = next.clone();
+                        self.pos += 1;
+                    }
+                    if self.eat('=') {
+                        let value = self.parse_value()?;
+                        metadata.insert(key, value);
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(metadata)
+    }
+
+    fn parse_value(&mut self) -> Result<Value> {
+        Ok(match self.bump()? {
+            Token::Num(n) => Value::Num(n),
+            Token::Str(s) => Value::Str(s),
+            Token::Asset(a) => Value::Asset(a),
+            Token::Path(p) => Value::Path(p),
+            Token::Ident(word) => match word.as_str() {
+                "None" => Value::None,
+                "inf" => Value::Num(f64::INFINITY),
+                "nan" => Value::Num(f64::NAN),
+                "true" => Value::Num(1.0),
+                "false" => Value::Num(0.0),
+                _ => Value::Str(word),
+            },
+            Token::Punct('(') => Value::Tuple(self.parse_sequence(')')?),
+            Token::Punct('[') => Value::List(self.parse_sequence(']')?),
+            Token::Punct('{') => self.parse_samples()?,
+            other => return Err(usd_error(format!("期望属性值，实际为 {:?}", other))),
+        })
+    }
+
+    fn parse_sequence(&mut self, close: char) -> Result<Vec<Value>> {
+        let mut items = Vec::new();
+        while !self.eat(close) {
+            items.push(self.parse_value()?);
+            self.eat(',');
+        }
+        Ok(items)
+    }
+
+    /// `{ time: value, ... }`；其他字典（如 customData）整体跳过
+    fn parse_samples(&mut self) -> Result<Value> {
+        let mut samples = Vec::new();
+        loop {
+            match (self.peek().cloned(), self.tokens.get(self.pos + 1)) {
+                (Some(Token::Punct('}')), _) => {
+                    self.pos += 1;
+                    break;
+                }
+                (Some(Token::Num(time)), Some(Token::Punct(':'))) => {
+                    self.pos += 2;
+                    let value = self.parse_value()?;
+                    samples.push((time, value));
+                    self.eat(',');
+                }
+                _ => {
+                    self.skip_group('{', '}')?;
+                    break;
+                }
+            }
+        }
+        Ok(Value::Samples(samples))
+    }
+}
+
+fn index_prims<'a>(prim: &'a Prim, prims: &mut HashMap<String, &'a Prim>) {
+    prims.insert(prim.path.clone(), prim);
+    for child in &prim.children {
+        index_prims(child, prims);
+    }
+}
+
+/// 属性路径 `/A/B.outputs:rgb` 所属的 prim 路径
+fn prim_path(property_path: &str) -> &str {
+    property_path.split('.').next().unwrap_or(property_path)
+}
+
+// ---------------------------------------------------------------------------
+//  Prim → SceneHierarchy
+// ---------------------------------------------------------------------------
+
+struct SceneBuilder<'a, F> {
+    prims: HashMap<String, &'a Prim>,
+    load_texture: F,
+    materials: HashMap<String, MaterialData>,
+    hierarchy: SceneHierarchy,
+}
+
+impl<'a, F: FnMut(&str) -> Option<TextureData>> SceneBuilder<'a, F> {
+    fn build_node(&mut self, prim: &'a Prim, inherited_material: Option<&'a str>) -> Option<usize> {
+        if prim.specifier != "def" || matches!(prim.type_name.as_str(), "Material" | "Shader" | "NodeGraph" | "GeomSubset") {
+            return None;
+        }
+        if prim.attr("visibility").and_then(Value::as_str) == Some("invisible")
+            || prim.attr("purpose").and_then(Value::as_str) == Some("guide")
+        {
+            return None;
+        }
+
+        let material = prim.attr("material:binding").and_then(Value::as_path).or(inherited_material);
+        let (scale, rotation, translation) = local_matrix(prim).to_scale_rotation_translation();
+        let mesh = if prim.type_name == "Mesh" { self.build_mesh(prim, material) } else { None };
+
+        let index = self.hierarchy.nodes.len();
+        self.hierarchy.nodes.push(SceneNode {
+            name: Some(prim.name.clone()),
+            translation,
+            rotation,
+            scale,
+            mesh,
+            children: Vec::new(),
+        });
+        let children = prim.children.iter().filter_map(|child| self.build_node(child, material)).collect();
+        self.hierarchy.nodes[index].children = children;
+        Some(index)
+    }
+
+    fn build_mesh(&mut self, prim: &Prim, material: Option<&str>) -> Option<usize> {
+        let Some(mesh) = read_mesh(prim) else {
+            warn!("USD Mesh {} 缺少 points / faceVertexCounts / faceVertexIndices，已跳过", prim.path);
+            return None;
+        };
+        let material = match material {
+            Some(path) => self.material(path),
+            None => {
+                let mut data = MaterialData { metallic_factor: 0.0, roughness_factor: 0.5, ..Default::default() };
+                if let Some(color) = prim.attr("primvars:displayColor").and_then(|v| v.as_vec3().or_else(|| v.as_list()?.first()?.as_vec3())) {
+                    data.base_color_factor = [color.x, color.y, color.z, 1.0];
+                }
+                data
+            }
+        };
+        self.hierarchy.meshes.push(SceneMesh { name: Some(prim.name.clone()), primitives: vec![Submesh { mesh, material }] });
+        Some(self.hierarchy.meshes.len() - 1)
+    }
+
+    fn material(&mut self, path: &str) -> MaterialData {
+        if let Some(material) = self.materials.get(path) {
+            return material.clone();
+        }
+        let material = self.read_material(path).unwrap_or_else(|| {
+            warn!("USD 材质 {} 没有可识别的 UsdPreviewSurface，使用默认材质", path);
+            MaterialData { metallic_factor: 0.0, roughness_factor: 0.5, ..Default::default() }
+        });
+        self.materials.insert(path.to_string(), material.clone());
+        material
+    }
+
+    fn read_material(&mut self, path: &str) -> Option<MaterialData> {
+        let material = *self.prims.get(path)?;
+        let surface = material
+            .connection("outputs:surface")
+            .and_then(|p| self.prims.get(prim_path(p)).copied())
+            .or_else(|| {
+                material.children.iter().find(|c| c.attr("info:id").and_then(Value::as_str) == Some("UsdPreviewSurface"))
+            })?;
+
+        // UsdPreviewSurface 默认值
+        let mut data = MaterialData {
+            base_color_factor: [0.18, 0.18, 0.18, 1.0],
+            metallic_factor: 0.0,
+            roughness_factor: 0.5,
+            ..Default::default()
+        };
+        data.base_color_texture = self.input_texture(surface, "inputs:diffuseColor");
+        if let Some(color) = surface.attr("inputs:diffuseColor").and_then(Value::as_vec3) {
+            data.base_color_factor = [color.x, color.y, color.z, 1.0];
+        } else if data.base_color_texture.is_some() {
+            data.base_color_factor = [1.0; 4];
+        }
+        if let Some(opacity) = surface.attr("inputs:opacity").and_then(Value::as_f32) {
+            data.base_color_factor[3] = opacity;
+        }
+        if let Some(metallic) = surface.attr("inputs:metallic").and_then(Value::as_f32) {
+            data.metallic_factor = metallic;
+        }
+        if let Some(roughness) = surface.attr("inputs:roughness").and_then(Value::as_f32) {
+            data.roughness_factor = roughness;
+        }
+        if let Some(emissive) = surface.attr("inputs:emissiveColor").and_then(Value::as_vec3) {
+            data.emissive_factor = emissive.into();
+        }
+        data.normal_texture = self.input_texture(surface, "inputs:normal");
+        Some(data)
+    }
+
+    /// 输入连接到的 `UsdUVTexture` 纹理
+    fn input_texture(&mut self, shader: &Prim, input: &str) -> Option<TextureData> {
+        let texture = self.prims.get(prim_path(shader.connection(input)?))?;
+        let file = match texture.attr("inputs:file")? {
+            Value::Asset(file) | Value::Str(file) => file.clone(),
+            _ => return None,
+        };
+        (self.load_texture)(&file)
+    }
+}
+
+/// 按 `xformOpOrder` 组合局部矩阵
+fn local_matrix(prim: &Prim) -> Mat4 {
+    let Some(order) = prim.attr("xformOpOrder").and_then(Value::as_list) else {
+        return Mat4::IDENTITY;
+    };
+    let mut matrix = Mat4::IDENTITY;
+    for op in order.iter().filter_map(Value::as_str) {
+        let (invert, name) = match op.strip_prefix("!invert!") {
+            Some(name) => (true, name),
+            None => (false, op),
+        };
+        let Some(value) = prim.attr(name) else { continue };
+        let kind = name.strip_prefix("xformOp:").unwrap_or(name).split(':').next().unwrap_or("");
+        let op_matrix = match kind {
+            "translate" => value.as_vec3().map(Mat4::from_translation),
+            "scale" => value.as_vec3().map(Mat4::from_scale),
+            "rotateX" => value.as_f32().map(|d| Mat4::from_rotation_x(d.to_radians())),
+            "rotateY" => value.as_f32().map(|d| Mat4::from_rotation_y(d.to_radians())),
+            "rotateZ" => value.as_f32().map(|d| Mat4::from_rotation_z(d.to_radians())),
+            "orient" => value.as_quat().map(Mat4::from_quat),
+            "transform" => value.as_mat4(),
+            k if k.len() == 9 && k.starts_with("rotate") => value.as_vec3().map(|deg| euler_matrix(&k[6..], deg)),
+            _ => None,
+        };
+        match op_matrix {
+            Some(m) => matrix *= if invert { m.inverse() } else { m },
+            None => warn!("USD prim {} 的变换操作 {} 无法解析，已忽略", prim.path, op),
+        }
+    }
+    matrix
+}
+
+/// `rotateXYZ` 等：按名称顺序依次绕各轴旋转（先 X 后 Y 再 Z 即 `Rz·Ry·Rx`）
+fn euler_matrix(axes: &str, degrees: Vec3) -> Mat4 {
+    let rotation = axes.chars().fold(Quat::IDENTITY, |q, axis| {
+        let step = match axis {
+            'X' => Quat::from_rotation_x(degrees.x.to_radians()),
+            'Y' => Quat::from_rotation_y(degrees.y.to_radians()),
+            _ => Quat::from_rotation_z(degrees.z.to_radians()),
+        };
+        step * q
+    });
+    Mat4::from_quat(rotation)
+}
+
+/// 按插值方式取 primvar 值（可选的 `indices` 先做一次间接）
+fn primvar<T: Copy>(values: &[T], indices: Option<&[i64]>, interpolation: &str, face: usize, point: usize, corner: usize) -> Option<T> {
+    let i = match interpolation {
+        "faceVarying" => corner,
+        "uniform" => face,
+        "constant" => 0,
+        _ => point,
+    };
+    let i = match indices {
+        Some(indices) => usize::try_from(*indices.get(i)?).ok()?,
+        None => i,
+    };
+    values.get(i).copied()
+}
+
+/// 读取 Mesh：每个面角一个顶点，多边形按三角扇拆分
+fn read_mesh(prim: &Prim) -> Option<MeshData> {
+    let as_int = |v: &Value| v.as_f32().map(|n| n as i64);
+    let points = prim.attr("points")?.map_list(Value::as_vec3)?;
+    let counts = prim.attr("faceVertexCounts")?.map_list(as_int)?;
+    let face_indices = prim.attr("faceVertexIndices")?.map_list(as_int)?;
+    let left_handed = prim.attr("orientation").and_then(Value::as_str) == Some("leftHanded");
+
+    let interpolation = |name: &str, default: &'static str| -> String {
+        prim.attr_meta(name, "interpolation").and_then(Value::as_str).unwrap_or(default).to_string()
+    };
+    let normal_name = if prim.attr("primvars:normals").is_some() { "primvars:normals" } else { "normals" };
+    let normals = prim.attr(normal_name).and_then(|v| v.map_list(Value::as_vec3));
+    let normal_indices = prim.attr(&format!("{normal_name}:indices")).and_then(|v| v.map_list(as_int));
+    let normal_interp = interpolation(normal_name, "vertex");
+
+    let uv_name = ["primvars:st", "primvars:st0", "primvars:UVMap", "primvars:uv"]
+        .into_iter()
+        .find(|name| prim.attr(name).is_some());
+    let uvs = uv_name.and_then(|name| prim.attr(name)?.map_list(Value::as_vec2));
+    let uv_indices = uv_name.and_then(|name| prim.attr(&format!("{name}:indices"))?.map_list(as_int));
+    let uv_interp = uv_name.map_or_else(|| "vertex".to_string(), |name| interpolation(name, "faceVarying"));
+
+    let mut mesh = MeshData::default();
+    let mut corner = 0;
+    for (face, &count) in counts.iter().enumerate() {
+        let count = count.max(0) as usize;
+        let start = corner;
+        corner += count;
+        if corner > face_indices.len() {
+            warn!("USD Mesh {} 的 faceVertexIndices 不足，截断", prim.path);
+            break;
+        }
+        if count < 3 {
+            continue;
+        }
+        let Some(corner_points) = face_indices[start..corner]
+            .iter()
+            .map(|&i| usize::try_from(i).ok().filter(|&i| i < points.len()))
+            .collect::<Option<Vec<usize>>>()
+        else {
+            warn!("USD Mesh {} 的面 {} 引用越界的顶点，已跳过", prim.path, face);
+            continue;
+        };
+
+        // Newell 法线，用于补全缺失的法线
+        let mut face_normal = Vec3::ZERO;
+        for k in 0..count {
+            let (a, b) = (points[corner_points[k]], points[corner_points[(k + 1) % count]]);
+            face_normal += Vec3::new((a.y - b.y) * (a.z + b.z), (a.z - b.z) * (a.x + b.x), (a.x - b.x) * (a.y + b.y));
+        }
+        let face_normal = (if left_handed { -face_normal } else { face_normal }).normalize_or_zero();
+
+        let base = mesh.positions.len() as u32;
+        for (k, &point) in corner_points.iter().enumerate() {
+            let c = start + k;
+            mesh.positions.push(points[point]);
+            let normal = normals
+                .as_deref()
+                .and_then(|n| primvar(n, normal_indices.as_deref(), &normal_interp, face, point, c))
+                .map_or(face_normal, Vec3::normalize_or_zero);
+            mesh.normals.push(normal);
+            let uv = uvs
+                .as_deref()
+                .and_then(|uv| primvar(uv, uv_indices.as_deref(), &uv_interp, face, point, c))
+                .unwrap_or(Vec2::ZERO);
+            // USD 纹理坐标原点在左下角
+            mesh.texcoords.push(Vec2::new(uv.x, 1.0 - uv.y));
+        }
+        for k in 1..count as u32 - 1 {
+            if left_handed {
+                mesh.indices.extend_from_slice(&[base, base + k + 1, base + k]);
+            } else {
+                mesh.indices.extend_from_slice(&[base, base + k, base + k + 1]);
+            }
+        }
+    }
+    mesh.tangents = vec![[1.0, 0.0, 0.0, 1.0]; mesh.positions.len()];
+    Some(mesh)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYER: &str = r#"#usda 1.0
+(
+    defaultPrim = "World"
+    doc = """Test layer"""
+    upAxis = "Z"
+    metersPerUnit = 1
+)
+
+def Xform "World"
+{
+    double3 xformOp:translate = (1, 2, 3)
+    float3 xformOp:rotateXYZ = (0, 0, 90)
+    uniform token[] xformOpOrder = ["xformOp:translate", "xformOp:rotateXYZ"]
+
+    def Mesh "Quad" (
+        prepend apiSchemas = ["MaterialBindingAPI"]
+    )
+    {
+        int[] faceVertexCounts = [4]
+        int[] faceVertexIndices = [0, 1, 2, 3]
+        point3f[] points = [(0, 0, 0), (1, 0, 0), (1, 1, 0), (0, 1, 0)]
+        texCoord2f[] primvars:st = [(0, 0), (1, 0), (1, 1), (0, 1)] (
+            interpolation = "vertex"
+        )
+        rel material:binding = </World/Looks/Red>
+    }
+
+    def Scope "Looks"
+    {
+        def Material "Red"
+        {
+            token outputs:surface.connect = </World/Looks/Red/Surface.outputs:surface>
+
+            def Shader "Surface"
+            {
+                uniform token info:id = "UsdPreviewSurface"
+                color3f inputs:diffuseColor = (1, 0, 0)
+                float inputs:roughness = 0.25
+                token outputs:surface
+            }
+        }
+    }
+}
+"#;
+
+    #[test]
+    fn test_parse_usda_hierarchy_mesh_material() {
+        let scene = parse_usda(LAYER, |_| None).unwrap();
+        assert_eq!(scene.coordinate_system, CoordinateSystem::USD_Z_UP);
+        assert_eq!(scene.meters_per_unit, 1.0);
+
+        let hierarchy = &scene.hierarchy;
+        assert_eq!(hierarchy.roots, vec![0]);
+        assert_eq!(hierarchy.node_count(), 3);
+        let world = &hierarchy.nodes[0];
+        assert_eq!(world.translation, Vec3::new(1.0, 2.0, 3.0));
+        assert!(world.rotation.dot(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)).abs() > 1.0 - 1e-5);
+        assert_eq!(world.children, vec![1, 2]);
+
+        let quad = &hierarchy.meshes[hierarchy.nodes[1].mesh.unwrap()].primitives[0];
+        assert_eq!(quad.mesh.vertex_count(), 4);
+        assert_eq!(quad.mesh.indices, vec![0, 1, 2, 0, 2, 3]);
+        assert_eq!(quad.mesh.normals[0], Vec3::Z);
+        assert_eq!(quad.mesh.texcoords[0], Vec2::new(0.0, 1.0));
+        assert_eq!(quad.material.base_color_factor, [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(quad.material.roughness_factor, 0.25);
+        assert_eq!(quad.material.metallic_factor, 0.0);
+    }
+
+    fn stored_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (name, data) in entries {
+            bytes.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+            bytes.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(&0u16.to_le_bytes());
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(data);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_read_usdz_left_handed_and_time_samples() {
+        let layer = r#"#usda 1.0
+def Mesh "Tri"
+{
+    uniform token orientation = "leftHanded"
+    int[] faceVertexCounts = [3]
+    int[] faceVertexIndices = [0, 1, 2]
+    point3f[] points.timeSamples = {
+        0: [(0, 0, 0), (1, 0, 0), (0, 1, 0)],
+        10: [(0, 0, 1), (1, 0, 1), (0, 1, 1)],
+    }
+}
+"#;
+        let scene = read_usdz(&stored_zip(&[("scene.usda", layer.as_bytes())])).unwrap();
+        assert_eq!(scene.coordinate_system, CoordinateSystem::USD_Y_UP);
+        assert_eq!(scene.meters_per_unit, 0.01);
+        let mesh = &scene.hierarchy.meshes[0].primitives[0].mesh;
+        assert_eq!(mesh.positions[1], Vec3::X);
+        assert_eq!(mesh.indices, vec![0, 2, 1]);
+        assert_eq!(mesh.normals[0], Vec3::NEG_Z);
+
+        assert!(read_usdz(&stored_zip(&[("scene.usdc", b"PXR-USDC....")])).is_err());
+    }
+}
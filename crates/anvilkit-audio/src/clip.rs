@@ -0,0 +1,119 @@
+//! # 音频片段
+//!
+//! 内存中的已编码音频数据（wav / ogg / mp3）。`AudioSource::from_asset_id` 创建的音频源
+//! 通过 [`AudioClips`] 资源解析数据，而不是从磁盘路径读取。
+//!
+//! ```rust
+//! use anvilkit_audio::clip::{AudioClip, AudioClips};
+//!
+//! let mut clips = AudioClips::default();
+//! clips.insert(7, AudioClip::new(vec![0u8; 16], "sounds/click.wav"));
+//! assert_eq!(clips.get(7).unwrap().len(), 16);
+//! ```
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+use bevy_ecs::prelude::*;
+
+/// 已编码的音频数据
+///
+/// 字节以 `Arc` 共享，同一片段同时播放多次不会复制数据。
+#[derive(Debug, Clone)]
+pub struct AudioClip {
+    bytes: Arc<[u8]>,
+    /// 来源路径（用于日志）
+    pub path: String,
+}
+
+impl AudioClip {
+    /// 由已编码字节创建片段
+    pub fn new(bytes: impl Into<Arc<[u8]>>, path: impl Into<String>) -> Self {
+        Self { bytes: bytes.into(), path: path.into() }
+    }
+
+    /// 从文件读取片段
+    pub fn load(path: &str) -> std::io::Result<Self> {
+        Ok(Self::new(std::fs::read(path)?, path))
+    }
+
+    /// 原始字节
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// 字节长度
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// 供 `rodio::Decoder` 使用的读取器（共享底层数据）
+    pub fn cursor(&self) -> Cursor<Arc<[u8]>> {
+        Cursor::new(self.bytes.clone())
+    }
+}
+
+/// 音频片段表 — 以资产 ID 索引
+///
+/// ID 与 `AudioSource::asset_id` 对应，通常取 `anvilkit_assets::asset_server::AssetId::0`。
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AudioClips {
+    clips: HashMap<u64, AudioClip>,
+}
+
+impl AudioClips {
+    /// 注册片段，返回被替换的旧片段
+    pub fn insert(&mut self, id: u64, clip: AudioClip) -> Option<AudioClip> {
+        self.clips.insert(id, clip)
+    }
+
+    /// 查询片段
+    pub fn get(&self, id: u64) -> Option<&AudioClip> {
+        self.clips.get(&id)
+    }
+
+    /// 移除片段
+    pub fn remove(&mut self, id: u64) -> Option<AudioClip> {
+        self.clips.remove(&id)
+    }
+
+    /// 已注册片段数量
+    pub fn len(&self) -> usize {
+        self.clips.len()
+    }
+
+    /// 是否没有片段
+    pub fn is_empty(&self) -> bool {
+        self.clips.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_clip_cursor_shares_bytes() {
+        let clip = AudioClip::new(vec![1u8, 2, 3], "a.wav");
+        let mut out = Vec::new();
+        clip.cursor().read_to_end(&mut out).unwrap();
+        assert_eq!(out, vec![1, 2, 3]);
+        assert_eq!(Arc::strong_count(&clip.bytes), 1);
+    }
+
+    #[test]
+    fn test_clips_registry() {
+        let mut clips = AudioClips::default();
+        assert!(clips.insert(1, AudioClip::new(vec![0u8; 4], "a.wav")).is_none());
+        assert!(clips.insert(1, AudioClip::new(vec![0u8; 8], "b.wav")).is_some());
+        assert_eq!(clips.get(1).unwrap().path, "b.wav");
+        assert!(clips.remove(1).is_some());
+        assert!(clips.is_empty());
+    }
+}
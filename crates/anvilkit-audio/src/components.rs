@@ -1,7 +1,7 @@
 //! # 音频组件
 //!
-//! 定义 ECS 音频组件类型，用于与 kira 音频引擎集成。
-//! 组件定义不依赖 kira，实际音频播放由 AudioPlugin 提供。
+//! 定义 ECS 音频组件类型，用于与 rodio 音频引擎集成。
+//! 组件定义不依赖 rodio，实际音频播放由 AudioPlugin 提供。
 //!
 //! ## 使用示例
 //!
@@ -77,8 +77,8 @@ pub struct AudioSource {
     pub path: String,
     /// Optional asset ID for AssetServer integration.
     ///
-    /// When set, the audio playback system should resolve audio data via the
-    /// `AssetServer` using this ID instead of loading from `path` directly.
+    /// When set, the audio playback system resolves audio data through the
+    /// `AudioClips` resource using this ID instead of loading from `path` directly.
     /// The value corresponds to `anvilkit_assets::asset_server::AssetId::0`.
     pub asset_id: Option<u64>,
     /// 音量 [0.0, 1.0+]
@@ -121,9 +121,9 @@ impl AudioSource {
     /// Creates an audio source backed by an asset ID from the `AssetServer`.
     ///
     /// The `id` value corresponds to `anvilkit_assets::asset_server::AssetId::0`.
-    /// When this source is processed by the audio playback system, it should
-    /// resolve the audio data through the `AssetServer` rather than loading
-    /// from a file path.
+    /// When this source is processed by the audio playback system, the audio
+    /// data is looked up in the `AudioClips` resource rather than loaded from
+    /// a file path.
     pub fn from_asset_id(id: u64) -> Self {
         Self {
             path: String::new(),
@@ -148,6 +148,33 @@ impl AudioSource {
     pub fn stop(&mut self) { self.state = PlaybackState::Stopped; }
 }
 
+/// 音频 Sink 状态组件
+///
+/// 由 `audio_playback_system` 在开始播放时插入，`audio_sink_status_system` 每帧
+/// 从引擎同步。游戏逻辑只读它即可得知播放是否结束，控制仍通过 [`AudioSource`] 的
+/// `play` / `pause` / `stop` 与 `volume`。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_audio::components::AudioSink;
+///
+/// let sink = AudioSink::default();
+/// assert!(!sink.is_finished);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default, Component, Describe)]
+/// Runtime playback status of an entity's audio sink.
+pub struct AudioSink {
+    /// 是否经过空间化（左右声道平移）
+    pub is_spatial: bool,
+    /// 是否暂停
+    pub is_paused: bool,
+    /// 非循环音频是否已播放完毕（或加载失败）
+    pub is_finished: bool,
+    /// 最近一次应用的音量（含总线与距离衰减）
+    pub effective_volume: f32,
+}
+
 /// 音频监听器组件
 ///
 /// 附加到相机或玩家实体上，表示 3D 音频的收听位置。
@@ -3,7 +3,7 @@
 //! 基于 rodio 的音频输出管理。
 
 use bevy_ecs::prelude::*;
-use rodio::{OutputStream, OutputStreamHandle, Sink};
+use rodio::{OutputStream, OutputStreamHandle, Sink, SpatialSink};
 use std::collections::HashMap;
 use log::{info, error};
 
@@ -16,6 +16,7 @@ struct AudioEngineInner {
     _stream: OutputStream,
     stream_handle: OutputStreamHandle,
     sinks: HashMap<Entity, Sink>,
+    spatial_sinks: HashMap<Entity, SpatialSink>,
 }
 
 // NOTE: No `unsafe impl Send/Sync` — OutputStream (CoreAudio on macOS) is !Send.
@@ -46,6 +47,7 @@ impl AudioEngine {
                         _stream: stream,
                         stream_handle: handle,
                         sinks: HashMap::new(),
+                        spatial_sinks: HashMap::new(),
                     },
                 })
             }
@@ -66,11 +68,41 @@ impl AudioEngine {
         Ok(self.inner.sinks.get(&entity).unwrap())
     }
 
+    /// 获取或创建实体的空间化 Sink
+    ///
+    /// 坐标位于监听器局部空间，由 `spatial_audio_system` 每帧更新。
+    pub fn get_or_create_spatial_sink(
+        &mut self,
+        entity: Entity,
+        emitter: [f32; 3],
+        left_ear: [f32; 3],
+        right_ear: [f32; 3],
+    ) -> Result<&SpatialSink, String> {
+        if !self.inner.spatial_sinks.contains_key(&entity) {
+            let sink = SpatialSink::try_new(&self.inner.stream_handle, emitter, left_ear, right_ear)
+                .map_err(|e| format!("创建空间音频 sink 失败: {}", e))?;
+            self.inner.spatial_sinks.insert(entity, sink);
+        }
+        Ok(self.inner.spatial_sinks.get(&entity).unwrap())
+    }
+
+    /// 更新空间化 Sink 的发声体与双耳位置
+    pub fn set_spatial_positions(&self, entity: Entity, emitter: [f32; 3], left_ear: [f32; 3], right_ear: [f32; 3]) {
+        if let Some(sink) = self.inner.spatial_sinks.get(&entity) {
+            sink.set_emitter_position(emitter);
+            sink.set_left_ear_position(left_ear);
+            sink.set_right_ear_position(right_ear);
+        }
+    }
+
     /// 暂停实体音频
     pub fn pause(&self, entity: Entity) {
         if let Some(sink) = self.inner.sinks.get(&entity) {
             sink.pause();
         }
+        if let Some(sink) = self.inner.spatial_sinks.get(&entity) {
+            sink.pause();
+        }
     }
 
     /// 恢复实体音频
@@ -78,6 +110,9 @@ impl AudioEngine {
         if let Some(sink) = self.inner.sinks.get(&entity) {
             sink.play();
         }
+        if let Some(sink) = self.inner.spatial_sinks.get(&entity) {
+            sink.play();
+        }
     }
 
     /// 停止并移除实体音频
@@ -85,6 +120,9 @@ impl AudioEngine {
         if let Some(sink) = self.inner.sinks.remove(&entity) {
             sink.stop();
         }
+        if let Some(sink) = self.inner.spatial_sinks.remove(&entity) {
+            sink.stop();
+        }
     }
 
     /// 设置实体音量
@@ -92,11 +130,27 @@ impl AudioEngine {
         if let Some(sink) = self.inner.sinks.get(&entity) {
             sink.set_volume(volume);
         }
+        if let Some(sink) = self.inner.spatial_sinks.get(&entity) {
+            sink.set_volume(volume);
+        }
+    }
+
+    /// 实体是否拥有活跃的 Sink
+    pub fn has_sink(&self, entity: Entity) -> bool {
+        self.inner.sinks.contains_key(&entity) || self.inner.spatial_sinks.contains_key(&entity)
+    }
+
+    /// 实体音频是否处于暂停
+    pub fn is_paused(&self, entity: Entity) -> bool {
+        self.inner.sinks.get(&entity).map(|s| s.is_paused())
+            .or_else(|| self.inner.spatial_sinks.get(&entity).map(|s| s.is_paused()))
+            .unwrap_or(false)
     }
 
     /// 清理已完成播放的 Sink
     pub fn cleanup_finished(&mut self) {
         self.inner.sinks.retain(|_, sink| !sink.empty());
+        self.inner.spatial_sinks.retain(|_, sink| !sink.empty());
     }
 }
 
@@ -128,6 +182,9 @@ mod tests {
             engine.pause(entity);
             engine.resume(entity);
             engine.set_volume(entity, 0.5);
+            engine.set_spatial_positions(entity, [0.0; 3], [-0.5, 0.0, 0.0], [0.5, 0.0, 0.0]);
+            assert!(!engine.has_sink(entity));
+            assert!(!engine.is_paused(entity));
         }
     }
 
@@ -15,6 +15,7 @@
 
 #![warn(missing_docs)]
 
+pub mod clip;
 pub mod engine;
 pub mod systems;
 pub mod components;
@@ -22,11 +23,13 @@ pub mod components;
 use bevy_ecs::prelude::*;
 use bevy_app::{App, Plugin};
 use engine::AudioEngine;
-use systems::{audio_playback_system, audio_cleanup_system, spatial_audio_system};
+use clip::AudioClips;
+use components::AudioBus;
+use systems::{audio_playback_system, audio_cleanup_system, audio_sink_status_system, spatial_audio_system};
 
 /// 音频插件
 ///
-/// 初始化 rodio 音频引擎并注册播放系统。
+/// 初始化 rodio 音频引擎并注册播放、状态同步与空间音频系统。
 pub struct AudioPlugin;
 
 impl Plugin for AudioPlugin {
@@ -34,10 +37,13 @@ impl Plugin for AudioPlugin {
         if let Some(engine) = AudioEngine::new() {
             app.insert_non_send_resource(engine);
         }
-        app.add_systems(bevy_app::PostUpdate, (
-            audio_playback_system,
-            audio_cleanup_system.after(audio_playback_system),
-            spatial_audio_system.after(audio_playback_system),
-        ));
+        app.init_resource::<AudioClips>()
+            .init_resource::<AudioBus>()
+            .add_systems(bevy_app::PostUpdate, (
+                audio_playback_system,
+                audio_cleanup_system.after(audio_playback_system),
+                audio_sink_status_system.after(audio_playback_system),
+                spatial_audio_system.after(audio_playback_system),
+            ));
     }
 }
@@ -3,15 +3,22 @@
 //! ECS 系统：监听 AudioSource 组件状态变化，驱动 rodio 播放。
 
 use bevy_ecs::prelude::*;
-use crate::components::{AudioSource, PlaybackState, AudioListener, AudioBus};
-use anvilkit_core::math::Transform;
+use crate::components::{AudioSource, AudioSink, PlaybackState, AudioListener, AudioBus};
+use crate::clip::AudioClips;
+use anvilkit_core::math::{GlobalTransform, Transform};
+use glam::{Quat, Vec3};
 use log::{debug, error};
-use std::io::BufReader;
+use std::io::{BufReader, Read, Seek};
 use std::fs::File;
 use rodio::Source;
 
 use crate::engine::AudioEngine;
 
+/// 监听器局部空间中的左耳位置（rodio `SpatialSink` 坐标）
+pub const LEFT_EAR: [f32; 3] = [-1.0, 0.0, 0.0];
+/// 监听器局部空间中的右耳位置
+pub const RIGHT_EAR: [f32; 3] = [1.0, 0.0, 0.0];
+
 /// 音频播放状态追踪组件
 #[derive(Component)]
 pub struct AudioPlaybackTracker {
@@ -27,12 +34,64 @@ impl Default for AudioPlaybackTracker {
     }
 }
 
+/// 线性距离衰减：距离 0 为 1.0，达到 `range` 时为 0.0
+pub fn distance_attenuation(distance: f32, range: f32) -> f32 {
+    if range <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - distance / range).max(0.0)
+}
+
+/// 将发声体位置转换到监听器局部空间的单位方向
+///
+/// 距离衰减由 [`distance_attenuation`] 单独计算，因此交给 `SpatialSink` 的发声体
+/// 总是位于单位球面上，rodio 只负责左右声道平移。发声体与监听器重合时返回原点（居中）。
+pub fn listener_local_emitter(listener_pos: Vec3, listener_rot: Quat, emitter_pos: Vec3) -> [f32; 3] {
+    let dir = (emitter_pos - listener_pos).normalize_or_zero();
+    (listener_rot.inverse() * dir).to_array()
+}
+
+/// 实体的世界位姿：优先 `GlobalTransform`，否则退回 `Transform`
+fn world_pose(global: Option<&GlobalTransform>, local: Option<&Transform>) -> Option<(Vec3, Quat)> {
+    global
+        .map(|g| (g.translation(), g.rotation()))
+        .or_else(|| local.map(|t| (t.translation, t.rotation)))
+}
+
+/// 解码并把音频追加到实体的 Sink（空间化音频使用 `SpatialSink`）
+fn start_playback<R>(engine: &mut AudioEngine, entity: Entity, source: &AudioSource, reader: R) -> Result<(), String>
+where
+    R: Read + Seek + Send + Sync + 'static,
+{
+    let decoder = rodio::Decoder::new(reader).map_err(|e| format!("解码音频失败: {}", e))?;
+    let stream: Box<dyn Source<Item = i16> + Send> = if source.looping {
+        Box::new(decoder.buffered().repeat_infinite())
+    } else {
+        Box::new(decoder)
+    };
+
+    if source.spatial {
+        let sink = engine.get_or_create_spatial_sink(entity, [0.0; 3], LEFT_EAR, RIGHT_EAR)?;
+        sink.set_volume(source.volume);
+        sink.set_speed(source.pitch);
+        sink.append(stream);
+    } else {
+        let sink = engine.get_or_create_sink(entity)?;
+        sink.set_volume(source.volume);
+        sink.set_speed(source.pitch);
+        sink.append(stream);
+    }
+    Ok(())
+}
+
 /// 音频播放系统
 ///
-/// 检测 AudioSource 状态变化并驱动 rodio 播放。
+/// 检测 AudioSource 状态变化并驱动 rodio 播放。设置了 `asset_id` 的音频源
+/// 从 [`AudioClips`] 读取数据，否则从 `path` 打开文件。
 pub fn audio_playback_system(
     mut commands: Commands,
     query: Query<(Entity, &AudioSource, Option<&AudioPlaybackTracker>)>,
+    clips: Option<Res<AudioClips>>,
     engine: Option<NonSendMut<AudioEngine>>,
 ) {
     let Some(mut engine) = engine else { return };
@@ -50,31 +109,24 @@ pub fn audio_playback_system(
                     engine.resume(entity);
                 } else {
                     // Start new playback
-                    match File::open(&source.path) {
-                        Ok(file) => {
-                            let reader = BufReader::new(file);
-                            match rodio::Decoder::new(reader) {
-                                Ok(decoder) => {
-                                    match engine.get_or_create_sink(entity) {
-                                        Ok(sink) => {
-                                            sink.set_volume(source.volume);
-                                            sink.set_speed(source.pitch);
-                                            if source.looping {
-                                                let buffered = decoder.buffered();
-                                                sink.append(buffered.repeat_infinite());
-                                            } else {
-                                                sink.append(decoder);
-                                            }
-                                            debug!("播放音频: {}", source.path);
-                                        }
-                                        Err(e) => error!("创建 sink 失败 {}: {}", source.path, e),
-                                    }
-                                }
-                                Err(e) => error!("解码音频失败 {}: {}", source.path, e),
-                            }
-                        }
-                        Err(e) => error!("打开音频文件失败 {}: {}", source.path, e),
+                    let result = match source.asset_id {
+                        Some(id) => match clips.as_deref().and_then(|c| c.get(id)) {
+                            Some(clip) => start_playback(&mut engine, entity, source, clip.cursor()),
+                            None => Err(format!("未注册的音频片段 {}", id)),
+                        },
+                        None => File::open(&source.path)
+                            .map_err(|e| format!("打开音频文件失败: {}", e))
+                            .and_then(|file| start_playback(&mut engine, entity, source, BufReader::new(file))),
+                    };
+                    match result {
+                        Ok(()) => debug!("播放音频: {}", source.path),
+                        Err(e) => error!("{} ({})", e, source.path),
                     }
+                    commands.entity(entity).insert(AudioSink {
+                        is_spatial: source.spatial,
+                        effective_volume: source.volume,
+                        ..Default::default()
+                    });
                 }
             }
             PlaybackState::Paused => {
@@ -95,6 +147,31 @@ pub fn audio_playback_system(
     engine.cleanup_finished();
 }
 
+/// Sink 状态同步系统
+///
+/// 把引擎中的暂停 / 结束状态写回 [`AudioSink`]。非循环音频播放完毕（或加载失败）后
+/// `AudioSource` 回到 `Stopped`，再次调用 `play()` 即可重播。
+pub fn audio_sink_status_system(
+    engine: Option<NonSend<AudioEngine>>,
+    mut query: Query<(Entity, &mut AudioSource, &mut AudioSink)>,
+) {
+    let Some(engine) = engine else { return };
+
+    for (entity, mut source, mut sink) in query.iter_mut() {
+        let active = engine.has_sink(entity);
+        let finished = !active && source.state == PlaybackState::Playing;
+        if finished {
+            source.state = PlaybackState::Stopped;
+        }
+        let status = AudioSink {
+            is_paused: engine.is_paused(entity),
+            is_finished: finished || (sink.is_finished && !active),
+            ..*sink
+        };
+        sink.set_if_neq(status);
+    }
+}
+
 /// 音频清理系统 — 移除已 despawn 实体的 Sink，防止泄漏。
 ///
 /// 通过 `RemovedComponents<AudioSource>` 检测实体移除事件。
@@ -110,9 +187,11 @@ pub fn audio_cleanup_system(
 }
 
 /// 空间音频系统 — 基于距离的音量衰减 + 立体声平移
+///
+/// 监听器与发声体的位置优先取 `GlobalTransform`（支持层级），没有时退回 `Transform`。
 pub fn spatial_audio_system(
-    query: Query<(Entity, &AudioSource, &Transform)>,
-    listener_query: Query<&Transform, With<AudioListener>>,
+    mut query: Query<(Entity, &AudioSource, Option<&GlobalTransform>, Option<&Transform>, Option<&mut AudioSink>)>,
+    listener_query: Query<(&AudioListener, Option<&GlobalTransform>, Option<&Transform>)>,
     engine: Option<NonSend<AudioEngine>>,
     bus: Option<Res<AudioBus>>,
 ) {
@@ -120,57 +199,69 @@ pub fn spatial_audio_system(
     let default_bus = AudioBus::default();
     let bus = bus.as_deref().unwrap_or(&default_bus);
 
-    let listener_transform = listener_query.iter().next().copied()
-        .unwrap_or(Transform::IDENTITY);
-    let listener_pos = listener_transform.translation;
-    // Listener's right vector for stereo panning
-    let listener_right = listener_transform.rotation * glam::Vec3::X;
+    let (listener_pos, listener_rot) = listener_query
+        .iter()
+        .filter(|(listener, ..)| listener.is_active)
+        .find_map(|(_, global, local)| world_pose(global, local))
+        .unwrap_or((Vec3::ZERO, Quat::IDENTITY));
 
-    for (entity, source, transform) in query.iter() {
+    for (entity, source, global, local, sink) in query.iter_mut() {
         if source.state != PlaybackState::Playing { continue; }
 
         let bus_vol = bus.effective_volume(source.bus);
-        let effective_vol = if source.spatial && source.spatial_range > 0.0 {
-            let distance = (transform.translation - listener_pos).length();
-            let attenuation = (1.0 - distance / source.spatial_range).max(0.0);
-            source.volume * attenuation * bus_vol
-        } else {
-            source.volume * bus_vol
-        };
+        let mut effective_vol = source.volume * bus_vol;
 
-        // Stereo panning: project source direction onto listener's right axis.
-        // pan in [-1, 1]: -1 = full left, 0 = center, +1 = full right
-        let _panning = if source.spatial {
-            let offset = transform.translation - listener_pos;
-            let len = offset.length();
-            if len > 1e-5 {
-                let dir = offset / len;
-                // Dot with right vector gives signed horizontal displacement
-                dir.dot(listener_right).clamp(-1.0, 1.0)
-            } else {
-                0.0 // source at listener position → center
+        if source.spatial {
+            if let Some((emitter_pos, _)) = world_pose(global, local) {
+                let distance = (emitter_pos - listener_pos).length();
+                effective_vol *= distance_attenuation(distance, source.spatial_range);
+                let emitter = listener_local_emitter(listener_pos, listener_rot, emitter_pos);
+                engine.set_spatial_positions(entity, emitter, LEFT_EAR, RIGHT_EAR);
             }
-        } else {
-            0.0
-        };
+        }
 
-        // Derive per-channel volumes from the panning value.
-        // Equal-power-ish linear pan law:
-        //   left  = (1 - pan) * 0.5 * volume
-        //   right = (1 + pan) * 0.5 * volume
-        let _left_vol  = (1.0 - _panning) * 0.5 * effective_vol;
-        let _right_vol = (1.0 + _panning) * 0.5 * effective_vol;
-
-        // NOTE: rodio 0.19 `Sink` does not expose a stereo panning API.
-        // When rodio gains `set_stereo_volume` or equivalent, replace the
-        // single `set_volume` call below with per-channel volumes.
-        // For now, apply the distance-attenuated mono volume.
         engine.set_volume(entity, effective_vol);
+        if let Some(mut sink) = sink {
+            if sink.effective_volume != effective_vol {
+                sink.effective_volume = effective_vol;
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listener_local_emitter() {
+        // 监听器绕 Y 轴转 90°：右手方向变为 -Z
+        let rot = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        assert!((rot * Vec3::X - Vec3::NEG_Z).length() < 1e-5);
+
+        let local = Vec3::from(listener_local_emitter(Vec3::ZERO, rot, Vec3::new(0.0, 0.0, -5.0)));
+        assert!((local - Vec3::X).length() < 1e-5);
+
+        // 与监听器重合时居中
+        assert_eq!(listener_local_emitter(Vec3::ONE, Quat::IDENTITY, Vec3::ONE), [0.0; 3]);
+    }
+
+    #[test]
+    fn test_distance_attenuation_helper() {
+        assert_eq!(distance_attenuation(5.0, 20.0), 0.75);
+        assert_eq!(distance_attenuation(30.0, 20.0), 0.0);
+        assert_eq!(distance_attenuation(30.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_world_pose_prefers_global() {
+        let local = Transform::from_xyz(1.0, 0.0, 0.0);
+        let global = GlobalTransform(glam::Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0)));
+        assert_eq!(world_pose(Some(&global), Some(&local)).unwrap().0, Vec3::new(5.0, 0.0, 0.0));
+        assert_eq!(world_pose(None, Some(&local)).unwrap().0, Vec3::X);
+        assert!(world_pose(None, None).is_none());
+    }
+
     /// 距离衰减计算单元测试（与 spatial_audio_system 中的逻辑一致）
     #[test]
     fn test_distance_attenuation() {
@@ -196,8 +287,6 @@ mod tests {
     /// 立体声 panning 计算单元测试
     #[test]
     fn test_stereo_panning_calculation() {
-        use glam::Vec3;
-
         let listener_right = Vec3::X; // facing +Z, right is +X
 
         // Source to the right
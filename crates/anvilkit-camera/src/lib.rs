@@ -11,6 +11,7 @@
 //! ├── input_curve      — Dead zone + response curve
 //! ├── systems          — Core ECS systems (input, mode, effects)
 //! ├── plugin           — CameraPlugin registration
+//! ├── photo_mode       — PhotoModePlugin (paused free camera + capture)
 //! ├── orbit/           — Orbit subsystem
 //! │   ├── OrbitState   — Distance, target, limits
 //! │   ├── rig          — Entity follow with offset + damping
@@ -32,6 +33,8 @@ pub mod input_curve;
 pub mod plugin;
 /// Camera controller ECS systems.
 pub mod systems;
+/// Photo mode: paused free camera with roll/FOV and screenshot capture.
+pub mod photo_mode;
 
 /// Orbit subsystem: orbit state, entity rig, spring arm collision.
 pub mod orbit;
@@ -52,6 +55,7 @@ pub mod prelude {
     pub use crate::constraints::look_at::LookAtTarget;
     pub use crate::constraints::rail::{CameraRail, RailInterpolation};
     pub use crate::plugin::CameraPlugin;
+    pub use crate::photo_mode::{PhotoMode, PhotoModePlugin, not_in_photo_mode, ui_visible};
     pub use crate::systems::{
         camera_input_system,
         camera_mode_system,
@@ -0,0 +1,414 @@
+//! Photo mode — pause the game and frame screenshots with a free camera.
+//!
+//! [`PhotoModePlugin`] adds a [`PhotoMode`] resource. While active:
+//!
+//! - the simulation is paused: the `Physics` and `GameLogic` system sets of the
+//!   `FixedUpdate` and `Update` schedules are gated on [`not_in_photo_mode`], and
+//!   [`DeltaTime`] is zeroed for systems outside those sets. The real frame delta
+//!   is kept in [`PhotoMode::real_delta`] for the photo camera;
+//! - the regular camera controller systems are suspended (see [`not_in_photo_mode`])
+//!   and the active camera is driven as a free camera with roll and FOV control;
+//! - [`PhotoMode::post_process`] (DOF, color grading, ...) replaces the scene's
+//!   `PostProcessSettings`, and the original settings are restored on exit;
+//! - [`PhotoMode::hide_ui`] is exposed to UI systems through [`ui_visible`].
+//!
+//! With the `capture` feature, [`PhotoMode::request_capture`] (or the capture key)
+//! saves a supersampled screenshot: the scene is rendered at
+//! [`PhotoMode::capture_scale`] times the window resolution for one frame and
+//! downsampled to the window resolution.
+//!
+//! Default controls: `F10` toggle, WASD/Space/Shift move, mouse look, Q/E roll,
+//! scroll FOV, `H` hide UI, `F12` capture.
+
+use bevy_app::{App, First, Plugin, PostUpdate, PreUpdate};
+use bevy_ecs::prelude::*;
+use glam::{Quat, Vec3};
+use anvilkit_app::schedule::{AnvilKitSchedule, AnvilKitSystemSet};
+use anvilkit_core::math::Transform;
+use anvilkit_core::time::DeltaTime;
+use anvilkit_input::prelude::{InputState, KeyCode};
+use anvilkit_render::plugin::{CameraComponent, Projection};
+use anvilkit_render::renderer::post_process::PostProcessSettings;
+
+/// Photo mode state and configuration.
+#[derive(Resource, Debug, Clone)]
+pub struct PhotoMode {
+    /// Whether photo mode is active.
+    pub active: bool,
+    /// Hide gameplay UI while active (see [`ui_visible`]).
+    pub hide_ui: bool,
+    /// Camera roll in radians.
+    pub roll: f32,
+    /// Vertical FOV in degrees.
+    pub fov: f32,
+    /// Allowed FOV range in degrees.
+    pub fov_limits: (f32, f32),
+    /// Free camera speed in units per second.
+    pub move_speed: f32,
+    /// Mouse look sensitivity (radians per pixel).
+    pub look_sensitivity: f32,
+    /// Roll speed in radians per second.
+    pub roll_speed: f32,
+    /// Post-processing used while active (`None` keeps the scene's settings).
+    pub post_process: Option<PostProcessSettings>,
+    /// Screenshot supersampling factor relative to the window (capped by the renderer's
+    /// `MAX_RENDER_SCALE`).
+    pub capture_scale: u32,
+    /// Key that toggles photo mode.
+    pub toggle_key: KeyCode,
+    /// Key that captures a screenshot while active.
+    pub capture_key: KeyCode,
+    /// Unscaled frame delta in seconds (gameplay sees zero while active).
+    pub real_delta: f32,
+    yaw: f32,
+    pitch: f32,
+    saved: Option<SavedCamera>,
+    pending_capture: Option<std::path::PathBuf>,
+    capture_counter: u32,
+}
+
+/// Camera and render state restored when photo mode exits.
+#[derive(Debug, Clone)]
+struct SavedCamera {
+    entity: Entity,
+    transform: Transform,
+    fov: f32,
+    projection: Projection,
+    post_process: Option<PostProcessSettings>,
+}
+
+impl Default for PhotoMode {
+    fn default() -> Self {
+        Self {
+            active: false,
+            hide_ui: true,
+            roll: 0.0,
+            fov: 60.0,
+            fov_limits: (10.0, 120.0),
+            move_speed: 5.0,
+            look_sensitivity: 0.003,
+            roll_speed: 1.0,
+            post_process: None,
+            capture_scale: 2,
+            toggle_key: KeyCode::F10,
+            capture_key: KeyCode::F12,
+            real_delta: 0.0,
+            yaw: 0.0,
+            pitch: 0.0,
+            saved: None,
+            pending_capture: None,
+            capture_counter: 0,
+        }
+    }
+}
+
+impl PhotoMode {
+    /// Enter photo mode on the next update.
+    pub fn enter(&mut self) {
+        self.active = true;
+    }
+
+    /// Leave photo mode on the next update, restoring the camera.
+    pub fn exit(&mut self) {
+        self.active = false;
+    }
+
+    /// Toggle photo mode.
+    pub fn toggle(&mut self) {
+        self.active = !self.active;
+    }
+
+    /// `true` while UI should be hidden.
+    pub fn ui_hidden(&self) -> bool {
+        self.active && self.hide_ui
+    }
+
+    /// Request a screenshot written to `path` (requires the `capture` feature).
+    pub fn request_capture(&mut self, path: impl Into<std::path::PathBuf>) {
+        self.pending_capture = Some(path.into());
+    }
+
+    /// Camera orientation from the photo yaw, pitch and roll.
+    pub fn rotation(&self) -> Quat {
+        Quat::from_rotation_y(self.yaw) * Quat::from_rotation_x(self.pitch) * Quat::from_rotation_z(self.roll)
+    }
+
+    fn next_capture_path(&mut self) -> std::path::PathBuf {
+        self.capture_counter += 1;
+        std::path::PathBuf::from(format!("photo_{:04}.png", self.capture_counter))
+    }
+}
+
+/// Run condition: `true` unless photo mode is active.
+pub fn not_in_photo_mode(photo: Option<Res<PhotoMode>>) -> bool {
+    photo.is_none_or(|p| !p.active)
+}
+
+/// Run condition for UI systems: `false` while photo mode hides the UI.
+pub fn ui_visible(photo: Option<Res<PhotoMode>>) -> bool {
+    photo.is_none_or(|p| !p.ui_hidden())
+}
+
+/// Records the real frame delta and zeroes gameplay time while active (First).
+pub fn photo_mode_time_system(mut photo: ResMut<PhotoMode>, mut dt: ResMut<DeltaTime>) {
+    photo.real_delta = dt.0;
+    if photo.active {
+        dt.0 = 0.0;
+    }
+}
+
+/// Handles the toggle, hide-UI and capture keys (PreUpdate).
+pub fn photo_mode_input_system(input: Option<Res<InputState>>, mut photo: ResMut<PhotoMode>) {
+    let Some(input) = input else { return };
+    if input.is_key_just_pressed(photo.toggle_key) {
+        photo.toggle();
+    }
+    if !photo.active {
+        return;
+    }
+    if input.is_key_just_pressed(KeyCode::H) {
+        photo.hide_ui = !photo.hide_ui;
+    }
+    if input.is_key_just_pressed(photo.capture_key) {
+        let path = photo.next_capture_path();
+        photo.request_capture(path);
+    }
+}
+
+/// Enters/exits photo mode and drives the free camera (PostUpdate).
+pub fn photo_mode_camera_system(
+    input: Option<Res<InputState>>,
+    mut photo: ResMut<PhotoMode>,
+    mut post_process: Option<ResMut<PostProcessSettings>>,
+    mut cameras: Query<(Entity, &mut Transform, &mut CameraComponent)>,
+) {
+    // --- Enter ---
+    if photo.active && photo.saved.is_none() {
+        let Some((entity, transform, camera)) = cameras.iter().find(|(_, _, c)| c.is_active) else {
+            photo.active = false;
+            return;
+        };
+        let (yaw, pitch, roll) = transform.rotation.to_euler(glam::EulerRot::YXZ);
+        photo.yaw = yaw;
+        photo.pitch = pitch;
+        photo.roll = roll;
+        photo.fov = match camera.projection {
+            Projection::Perspective { fov } => fov,
+            Projection::Orthographic { .. } => camera.fov,
+        };
+        let saved_post = post_process.as_deref().cloned();
+        if let (Some(overrides), Some(pp)) = (photo.post_process.clone(), post_process.as_deref_mut()) {
+            *pp = overrides;
+        }
+        photo.saved = Some(SavedCamera {
+            entity,
+            transform: *transform,
+            fov: camera.fov,
+            projection: camera.projection.clone(),
+            post_process: saved_post,
+        });
+    }
+
+    // --- Exit ---
+    if !photo.active {
+        if let Some(saved) = photo.saved.take() {
+            if let Ok((_, mut transform, mut camera)) = cameras.get_mut(saved.entity) {
+                *transform = saved.transform;
+                camera.fov = saved.fov;
+                camera.projection = saved.projection;
+            }
+            if let (Some(original), Some(pp)) = (saved.post_process, post_process.as_deref_mut()) {
+                *pp = original;
+            }
+        }
+        return;
+    }
+
+    // --- Free camera ---
+    let Some(entity) = photo.saved.as_ref().map(|s| s.entity) else { return };
+    let Ok((_, mut transform, mut camera)) = cameras.get_mut(entity) else {
+        photo.active = false;
+        photo.saved = None;
+        return;
+    };
+
+    let dt = photo.real_delta;
+    if let Some(input) = input {
+        let mouse = input.mouse_delta();
+        photo.yaw += mouse.x * photo.look_sensitivity;
+        photo.pitch = (photo.pitch + mouse.y * photo.look_sensitivity).clamp(-1.55, 1.55);
+
+        if input.is_key_pressed(KeyCode::Q) { photo.roll -= photo.roll_speed * dt; }
+        if input.is_key_pressed(KeyCode::E) { photo.roll += photo.roll_speed * dt; }
+
+        let scroll = input.scroll_delta();
+        if scroll.abs() > 0.01 {
+            photo.fov = (photo.fov - scroll * 2.0).clamp(photo.fov_limits.0, photo.fov_limits.1);
+        }
+
+        let rotation = photo.rotation();
+        let forward = rotation * Vec3::Z;
+        let right = rotation * Vec3::X;
+        let mut dir = Vec3::ZERO;
+        if input.is_key_pressed(KeyCode::W) { dir += forward; }
+        if input.is_key_pressed(KeyCode::S) { dir -= forward; }
+        if input.is_key_pressed(KeyCode::A) { dir -= right; }
+        if input.is_key_pressed(KeyCode::D) { dir += right; }
+        if input.is_key_pressed(KeyCode::Space) { dir.y += 1.0; }
+        if input.is_key_pressed(KeyCode::LShift) { dir.y -= 1.0; }
+        transform.translation += dir.normalize_or_zero() * photo.move_speed * dt;
+    }
+
+    transform.rotation = photo.rotation();
+    camera.fov = photo.fov;
+    if let Projection::Perspective { ref mut fov } = camera.projection {
+        *fov = photo.fov;
+    }
+}
+
+/// Forwards capture requests to the renderer's `CaptureState` (PostUpdate).
+///
+/// Updates the existing resource so recordings and streams in flight keep running;
+/// a request made while another screenshot is pending waits for the next frame.
+#[cfg(feature = "capture")]
+pub fn photo_mode_capture_system(
+    mut commands: Commands,
+    mut photo: ResMut<PhotoMode>,
+    state: Option<ResMut<anvilkit_render::renderer::capture::CaptureState>>,
+) {
+    use anvilkit_render::renderer::capture::CaptureState;
+
+    let Some(path) = photo.pending_capture.take() else { return };
+    let scale = photo.capture_scale.max(1);
+    match state {
+        Some(state) if state.screenshot_path.is_some() => photo.pending_capture = Some(path),
+        Some(mut state) => {
+            state.screenshot_path = Some(path);
+            state.scale = scale;
+        }
+        None => {
+            let mut state = CaptureState::screenshot(path);
+            state.scale = scale;
+            commands.insert_resource(state);
+        }
+    }
+}
+
+/// Photo mode plugin — registers [`PhotoMode`] and its systems.
+///
+/// Add after [`CameraPlugin`](crate::plugin::CameraPlugin); camera controller
+/// systems already skip frames where photo mode is active. Gameplay systems
+/// placed in [`AnvilKitSystemSet::Physics`] or [`AnvilKitSystemSet::GameLogic`]
+/// are paused while photo mode is active.
+pub struct PhotoModePlugin;
+
+impl Plugin for PhotoModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhotoMode>();
+        if !app.world().contains_resource::<DeltaTime>() {
+            app.insert_resource(DeltaTime::default());
+        }
+        for schedule in [AnvilKitSchedule::FixedUpdate, AnvilKitSchedule::Update] {
+            app.configure_sets(
+                schedule,
+                (AnvilKitSystemSet::Physics, AnvilKitSystemSet::GameLogic).run_if(not_in_photo_mode),
+            );
+        }
+        app.add_systems(First, photo_mode_time_system);
+        app.add_systems(PreUpdate, photo_mode_input_system);
+        app.add_systems(PostUpdate, photo_mode_camera_system);
+        #[cfg(feature = "capture")]
+        app.add_systems(PostUpdate, photo_mode_capture_system);
+    }
+
+    fn name(&self) -> &str {
+        "PhotoModePlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_render::renderer::fxaa::FxaaSettings;
+
+    fn setup() -> (World, Schedule, Entity) {
+        let mut world = World::new();
+        world.init_resource::<PhotoMode>();
+        world.insert_resource(DeltaTime(0.5));
+        world.insert_resource(PostProcessSettings::default());
+        let camera = world
+            .spawn((Transform::from_xyz(1.0, 2.0, 3.0), CameraComponent::default()))
+            .id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((photo_mode_time_system, photo_mode_camera_system).chain());
+        (world, schedule, camera)
+    }
+
+    #[test]
+    fn active_photo_mode_pauses_gameplay_time() {
+        let (mut world, mut schedule, _) = setup();
+        world.resource_mut::<PhotoMode>().enter();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<DeltaTime>().0, 0.0);
+        assert_eq!(world.resource::<PhotoMode>().real_delta, 0.5);
+        assert!(not_in_photo_mode(None));
+    }
+
+    #[test]
+    fn exit_restores_camera_and_post_process() {
+        let (mut world, mut schedule, camera) = setup();
+        {
+            let mut photo = world.resource_mut::<PhotoMode>();
+            photo.post_process = Some(PostProcessSettings { fxaa: Some(FxaaSettings::default()), ..Default::default() });
+            photo.enter();
+        }
+        schedule.run(&mut world);
+        assert!(world.resource::<PostProcessSettings>().fxaa.is_some());
+
+        world.resource_mut::<PhotoMode>().roll = 0.5;
+        world.resource_mut::<PhotoMode>().fov = 30.0;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<CameraComponent>(camera).unwrap().fov, 30.0);
+
+        world.resource_mut::<PhotoMode>().exit();
+        schedule.run(&mut world);
+        let transform = world.get::<Transform>(camera).unwrap();
+        assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(transform.rotation, Quat::IDENTITY);
+        assert_eq!(world.get::<CameraComponent>(camera).unwrap().fov, 60.0);
+        assert!(world.resource::<PostProcessSettings>().fxaa.is_none());
+    }
+
+    #[test]
+    fn active_photo_mode_gates_simulation_sets() {
+        #[derive(Resource, Default)]
+        struct Steps(u32);
+
+        let mut app = App::new();
+        app.add_plugins(PhotoModePlugin);
+        app.init_resource::<Steps>();
+        app.add_systems(
+            AnvilKitSchedule::Update,
+            (|mut steps: ResMut<Steps>| steps.0 += 1).in_set(AnvilKitSystemSet::GameLogic),
+        );
+
+        app.world_mut().run_schedule(AnvilKitSchedule::Update);
+        app.world_mut().resource_mut::<PhotoMode>().enter();
+        app.world_mut().run_schedule(AnvilKitSchedule::Update);
+        assert_eq!(app.world().resource::<Steps>().0, 1);
+
+        app.world_mut().resource_mut::<PhotoMode>().exit();
+        app.world_mut().run_schedule(AnvilKitSchedule::Update);
+        assert_eq!(app.world().resource::<Steps>().0, 2);
+    }
+
+    #[test]
+    fn ui_hidden_only_while_active() {
+        let mut photo = PhotoMode::default();
+        assert!(!photo.ui_hidden());
+        photo.enter();
+        assert!(photo.ui_hidden());
+        photo.hide_ui = false;
+        assert!(!photo.ui_hidden());
+    }
+}
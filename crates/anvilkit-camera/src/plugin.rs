@@ -13,6 +13,8 @@ use crate::orbit::spring_arm::camera_spring_arm_system;
 use crate::constraints::rail::camera_rail_system;
 use crate::constraints::look_at::camera_look_at_system;
 use crate::effects::transition::camera_transition_system;
+use crate::photo_mode::not_in_photo_mode;
+use bevy_ecs::schedule::IntoSystemConfigs;
 
 /// Camera plugin — registers the camera system pipeline.
 ///
@@ -36,14 +38,15 @@ impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         // Register camera systems in order within PostUpdate.
         // Pipeline: rig → input → rail → mode → spring_arm → look_at → effects → transition
-        app.add_systems(PostUpdate, camera_rig_system);
-        app.add_systems(PostUpdate, camera_input_system);
-        app.add_systems(PostUpdate, camera_rail_system);
-        app.add_systems(PostUpdate, camera_mode_system);
-        app.add_systems(PostUpdate, camera_spring_arm_system);
-        app.add_systems(PostUpdate, camera_look_at_system);
-        app.add_systems(PostUpdate, camera_effects_apply_system);
-        app.add_systems(PostUpdate, camera_transition_system);
+        // All are suspended while photo mode drives the camera.
+        app.add_systems(PostUpdate, camera_rig_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_input_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_rail_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_mode_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_spring_arm_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_look_at_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_effects_apply_system.run_if(not_in_photo_mode));
+        app.add_systems(PostUpdate, camera_transition_system.run_if(not_in_photo_mode));
     }
 
     fn name(&self) -> &str {
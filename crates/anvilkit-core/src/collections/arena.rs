@@ -0,0 +1,302 @@
+//! 线性（bump）内存分配器
+//!
+//! [`ArenaAllocator`] 从预分配的大块内存中顺序切分，单次分配只是一次指针对齐与递增，
+//! 适合每帧大量生成、帧末统一丢弃的临时数据（剔除列表、排序键、布局中间结果等）。
+//!
+//! - 分配只需 `&self`，多个分配结果可同时存活
+//! - [`reset`](ArenaAllocator::reset) 需要 `&mut self`，借用检查保证此时没有存活的引用
+//! - 被分配的值不会运行析构函数，因此只接受 `T: Copy`
+//! - 重置时若本轮用到了多个内存块，会合并为一个等容量的大块，稳定后不再追加分配
+
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::ptr::NonNull;
+use std::sync::Mutex;
+
+/// 默认内存块大小（64 KiB）
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+/// 内存块起始地址对齐
+const CHUNK_ALIGN: usize = 16;
+
+struct Chunk {
+    ptr: NonNull<u8>,
+    capacity: usize,
+}
+
+impl Chunk {
+    fn new(capacity: usize) -> Self {
+        let layout = Layout::from_size_align(capacity, CHUNK_ALIGN).expect("Arena 内存块大小溢出");
+        // SAFETY: capacity 至少为 1（见 bump），layout 非零大小
+        let ptr = unsafe { alloc(layout) };
+        let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
+        Self { ptr, capacity }
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        // SAFETY: 与 Chunk::new 中使用的 layout 相同
+        unsafe { dealloc(self.ptr.as_ptr(), Layout::from_size_align_unchecked(self.capacity, CHUNK_ALIGN)) }
+    }
+}
+
+#[derive(Default)]
+struct ArenaState {
+    chunks: Vec<Chunk>,
+    /// 当前切分的内存块
+    current: usize,
+    /// 当前内存块中已使用的字节数
+    offset: usize,
+    allocated: usize,
+    allocations: usize,
+}
+
+impl ArenaState {
+    fn bump(&mut self, layout: Layout, chunk_size: usize) -> NonNull<u8> {
+        loop {
+            if let Some(chunk) = self.chunks.get(self.current) {
+                let base = chunk.ptr.as_ptr() as usize;
+                let start = (base + self.offset + layout.align() - 1) & !(layout.align() - 1);
+                let end = start - base + layout.size();
+                if end <= chunk.capacity {
+                    self.offset = end;
+                    self.allocated += layout.size();
+                    self.allocations += 1;
+                    // SAFETY: start - base + size <= capacity，结果仍在该内存块内
+                    return unsafe { NonNull::new_unchecked(chunk.ptr.as_ptr().add(start - base)) };
+                }
+                if self.current + 1 < self.chunks.len() {
+                    self.current += 1;
+                    self.offset = 0;
+                    continue;
+                }
+            }
+            let capacity = chunk_size.max(layout.size() + layout.align()).max(1);
+            self.chunks.push(Chunk::new(capacity));
+            self.current = self.chunks.len() - 1;
+            self.offset = 0;
+        }
+    }
+}
+
+/// 线性内存分配器
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::collections::ArenaAllocator;
+///
+/// let mut arena = ArenaAllocator::new();
+/// let keys = arena.alloc_slice_copy(&[3u64, 1, 2]);
+/// keys.sort_unstable();
+/// let name = arena.alloc_str("scratch");
+/// assert_eq!(keys, &[1, 2, 3]);
+/// assert_eq!(name, "scratch");
+///
+/// // 帧末统一释放（保留已申请的内存供下一帧复用）
+/// arena.reset();
+/// assert_eq!(arena.allocated_bytes(), 0);
+/// ```
+pub struct ArenaAllocator {
+    state: Mutex<ArenaState>,
+    chunk_size: usize,
+}
+
+// SAFETY: 内存块由 ArenaAllocator 独占持有，切分状态受 Mutex 保护，
+// 每次分配返回互不重叠的区域，且只接受 `T: Copy`（无析构、无内部共享状态）。
+unsafe impl Send for ArenaAllocator {}
+unsafe impl Sync for ArenaAllocator {}
+
+impl ArenaAllocator {
+    /// 使用默认内存块大小（64 KiB）创建，首次分配时才申请内存
+    pub fn new() -> Self {
+        Self::with_chunk_size(DEFAULT_CHUNK_SIZE)
+    }
+
+    /// 指定内存块大小创建（超过块大小的单次分配会申请独立的大块）
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        Self { state: Mutex::new(ArenaState::default()), chunk_size: chunk_size.max(1) }
+    }
+
+    fn bump(&self, layout: Layout) -> NonNull<u8> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).bump(layout, self.chunk_size)
+    }
+
+    /// 分配单个值
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T: Copy>(&self, value: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        if layout.size() == 0 {
+            // SAFETY: 零大小类型的悬垂对齐指针是合法引用
+            return unsafe { &mut *NonNull::<T>::dangling().as_ptr() };
+        }
+        let ptr = self.bump(layout).cast::<T>();
+        // SAFETY: ptr 指向为 T 对齐且未被其他分配使用的内存，生命周期受 &self 约束，
+        // reset 需要 &mut self，因此引用不会越过重置
+        unsafe {
+            ptr.as_ptr().write(value);
+            &mut *ptr.as_ptr()
+        }
+    }
+
+    /// 分配长度为 `len` 的切片，元素由 `f(index)` 生成
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_with<T: Copy>(&self, len: usize, mut f: impl FnMut(usize) -> T) -> &mut [T] {
+        let layout = Layout::array::<T>(len).expect("Arena 切片大小溢出");
+        if layout.size() == 0 {
+            // SAFETY: 零字节切片可使用悬垂对齐指针；零大小类型的值无需写入
+            return unsafe { std::slice::from_raw_parts_mut(NonNull::<T>::dangling().as_ptr(), len) };
+        }
+        let ptr = self.bump(layout).cast::<T>();
+        // SAFETY: 同 alloc；先逐个写入再构造切片，T: Copy 保证 f panic 时无需清理
+        unsafe {
+            for i in 0..len {
+                ptr.as_ptr().add(i).write(f(i));
+            }
+            std::slice::from_raw_parts_mut(ptr.as_ptr(), len)
+        }
+    }
+
+    /// 复制切片到 arena
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        self.alloc_slice_fill_with(src.len(), |i| src[i])
+    }
+
+    /// 复制字符串到 arena
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str(&self, s: &str) -> &mut str {
+        let bytes = self.alloc_slice_copy(s.as_bytes());
+        // SAFETY: 字节来自合法的 UTF-8 字符串
+        unsafe { std::str::from_utf8_unchecked_mut(bytes) }
+    }
+
+    /// 释放所有分配（保留内存供复用）
+    ///
+    /// 若本轮使用了多个内存块，合并为一个总容量相同的块。
+    /// 调试构建下已用内存会被填充为 `0xDD`。
+    pub fn reset(&mut self) {
+        let state = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+        // 调试构建下用 0xDD 覆盖已用内存，使越过重置的非法访问（unsafe 代码）立即暴露
+        #[cfg(debug_assertions)]
+        for (index, chunk) in state.chunks.iter().enumerate().take(state.current + 1) {
+            let used = if index == state.current { state.offset } else { chunk.capacity };
+            // SAFETY: used <= capacity，且 &mut self 保证没有存活的分配引用
+            unsafe { std::ptr::write_bytes(chunk.ptr.as_ptr(), 0xDD, used) }
+        }
+        if state.chunks.len() > 1 {
+            let total: usize = state.chunks.iter().map(|c| c.capacity).sum();
+            state.chunks.clear();
+            state.chunks.push(Chunk::new(total));
+        }
+        state.current = 0;
+        state.offset = 0;
+        state.allocated = 0;
+        state.allocations = 0;
+    }
+
+    /// 自上次重置以来分配的字节数（不含对齐填充）
+    pub fn allocated_bytes(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).allocated
+    }
+
+    /// 自上次重置以来的分配次数（零大小分配不计）
+    pub fn allocation_count(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).allocations
+    }
+
+    /// 已申请的总内存（字节）
+    pub fn capacity(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).chunks.iter().map(|c| c.capacity).sum()
+    }
+
+    /// 已申请的内存块数量
+    pub fn chunk_count(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).chunks.len()
+    }
+}
+
+impl Default for ArenaAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for ArenaAllocator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ArenaAllocator")
+            .field("allocated_bytes", &self.allocated_bytes())
+            .field("capacity", &self.capacity())
+            .field("chunk_count", &self.chunk_count())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alloc_alignment_and_independence() {
+        let arena = ArenaAllocator::with_chunk_size(64);
+        let a = arena.alloc(1u8);
+        let b = arena.alloc(2u64);
+        let c = arena.alloc([3u32; 4]);
+        assert_eq!(b as *mut u64 as usize % std::mem::align_of::<u64>(), 0);
+        *a += 10;
+        *b += 10;
+        c[0] = 7;
+        assert_eq!((*a, *b, c[0], c[1]), (11, 12, 7, 3));
+        assert_eq!(arena.allocated_bytes(), 1 + 8 + 16);
+        assert_eq!(arena.allocation_count(), 3);
+    }
+
+    #[test]
+    fn test_spill_and_reset_coalesces() {
+        let mut arena = ArenaAllocator::with_chunk_size(32);
+        for i in 0..10u64 {
+            assert_eq!(*arena.alloc(i), i);
+        }
+        // 超过块大小的分配使用独立大块
+        let big = arena.alloc_slice_fill_with(100, |i| i as u32);
+        assert_eq!(big[99], 99);
+        assert!(arena.chunk_count() > 1);
+
+        let capacity = arena.capacity();
+        arena.reset();
+        assert_eq!(arena.chunk_count(), 1);
+        assert_eq!(arena.capacity(), capacity);
+        assert_eq!(arena.allocated_bytes(), 0);
+
+        // 合并后同样的负载不再追加内存块
+        for i in 0..10u64 {
+            arena.alloc(i);
+        }
+        arena.alloc_slice_fill_with(100, |i| i as u32);
+        assert_eq!(arena.chunk_count(), 1);
+    }
+
+    #[test]
+    fn test_zero_sized_and_str() {
+        let arena = ArenaAllocator::new();
+        arena.alloc(());
+        assert!(arena.alloc_slice_copy::<u32>(&[]).is_empty());
+        assert_eq!(arena.alloc_slice_fill_with(5, |_| ()).len(), 5);
+        assert_eq!(arena.chunk_count(), 0);
+        assert_eq!(arena.alloc_str("你好"), "你好");
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let arena = ArenaAllocator::with_chunk_size(128);
+        std::thread::scope(|s| {
+            for t in 0..4u32 {
+                let arena = &arena;
+                s.spawn(move || {
+                    let values = arena.alloc_slice_fill_with(64, |i| t * 1000 + i as u32);
+                    assert!(values.iter().enumerate().all(|(i, v)| *v == t * 1000 + i as u32));
+                });
+            }
+        });
+        assert_eq!(arena.allocation_count(), 4);
+    }
+}
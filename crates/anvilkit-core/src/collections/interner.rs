@@ -0,0 +1,289 @@
+//! 全局字符串驻留
+//!
+//! [`Istr`] 是驻留字符串的句柄：内容相同的存活句柄共享同一份分配，
+//! 比较与哈希只看指针，均为 O(1)，适合名称、标签、资产路径等在热路径中频繁比较的字符串。
+//!
+//! 驻留表为进程级全局表，只持有弱引用：最后一个句柄释放后字符串随之释放，
+//! 失效表项在后续驻留时按摊还方式批量清理，运行时生成的名称不会让驻留表无界增长。
+//! 克隆句柄只增加引用计数。
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, OnceLock, RwLock, Weak};
+
+/// 驻留表清理阈值的下限
+const MIN_PURGE_AT: usize = 64;
+
+#[derive(Default)]
+struct Interner {
+    /// 内容哈希 -> 同哈希的驻留字符串
+    buckets: HashMap<u64, Vec<Weak<str>>>,
+    /// 表项数量（含已失效的）
+    len: usize,
+    /// 表项达到该数量时清理失效表项
+    purge_at: usize,
+}
+
+impl Interner {
+    fn find(&self, hash: u64, s: &str) -> Option<Arc<str>> {
+        self.buckets.get(&hash)?.iter().filter_map(Weak::upgrade).find(|live| **live == *s)
+    }
+
+    fn intern(&mut self, hash: u64, s: &str) -> Arc<str> {
+        if let Some(live) = self.find(hash, s) {
+            return live;
+        }
+        if self.len >= self.purge_at {
+            self.purge();
+        }
+        let live: Arc<str> = Arc::from(s);
+        self.buckets.entry(hash).or_default().push(Arc::downgrade(&live));
+        self.len += 1;
+        live
+    }
+
+    /// 清理失效表项，并把阈值放大到存活数量的两倍
+    fn purge(&mut self) {
+        self.buckets.retain(|_, bucket| {
+            bucket.retain(|weak| weak.strong_count() > 0);
+            !bucket.is_empty()
+        });
+        self.len = self.buckets.values().map(Vec::len).sum();
+        self.purge_at = (self.len * 2).max(MIN_PURGE_AT);
+    }
+}
+
+fn interner() -> &'static RwLock<Interner> {
+    static INTERNER: OnceLock<RwLock<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(|| RwLock::new(Interner { purge_at: MIN_PURGE_AT, ..Default::default() }))
+}
+
+fn content_hash(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// 驻留字符串
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::collections::Istr;
+///
+/// let a = Istr::new("player");
+/// let b = Istr::from("player");
+/// assert_eq!(a, b);
+/// assert_eq!(a.as_str(), "player");
+/// assert_eq!(a, "player");
+/// ```
+#[derive(Clone, Default)]
+pub struct Istr(Option<Arc<str>>);
+
+impl Istr {
+    /// 空字符串（不占用驻留表）
+    pub const EMPTY: Istr = Istr(None);
+
+    /// 驻留字符串（已存在时只做一次读锁查找）
+    pub fn new(s: &str) -> Self {
+        if s.is_empty() {
+            return Self::EMPTY;
+        }
+        let hash = content_hash(s);
+        if let Some(live) = interner().read().unwrap_or_else(|e| e.into_inner()).find(hash, s) {
+            return Istr(Some(live));
+        }
+        Istr(Some(interner().write().unwrap_or_else(|e| e.into_inner()).intern(hash, s)))
+    }
+
+    /// 查找已驻留且仍存活的字符串，不存在时返回 `None` 且不插入
+    pub fn get(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            return Some(Self::EMPTY);
+        }
+        interner()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .find(content_hash(s), s)
+            .map(|live| Istr(Some(live)))
+    }
+
+    /// 驻留的字符串内容
+    pub fn as_str(&self) -> &str {
+        self.0.as_deref().unwrap_or("")
+    }
+
+    /// 是否为空字符串
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// 全局驻留表中仍存活的字符串数量（不含空字符串）
+    pub fn interned_count() -> usize {
+        let interner = interner().read().unwrap_or_else(|e| e.into_inner());
+        interner.buckets.values().flatten().filter(|weak| weak.strong_count() > 0).count()
+    }
+
+    fn ptr(&self) -> *const u8 {
+        self.0.as_ref().map_or(std::ptr::null(), |live| Arc::as_ptr(live) as *const u8)
+    }
+}
+
+impl PartialEq for Istr {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr() == other.ptr()
+    }
+}
+
+impl Eq for Istr {}
+
+impl Hash for Istr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr().hash(state);
+    }
+}
+
+impl PartialOrd for Istr {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// 按内容排序（与进程内的分配地址无关）
+impl Ord for Istr {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self == other {
+            return std::cmp::Ordering::Equal;
+        }
+        self.as_str().cmp(other.as_str())
+    }
+}
+impl fmt::Debug for Istr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for Istr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for Istr {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl From<String> for Istr {
+    fn from(s: String) -> Self {
+        Self::new(&s)
+    }
+}
+
+impl From<&String> for Istr {
+    fn from(s: &String) -> Self {
+        Self::new(s)
+    }
+}
+
+impl AsRef<str> for Istr {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for Istr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Istr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Istr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Istr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(Istr::new(&s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_dedup() {
+        let a = Istr::new("interner_test_a");
+        let b = Istr::new(&String::from("interner_test_a"));
+        let c = Istr::new("interner_test_c");
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert!(a < c);
+        assert_eq!(a.as_str(), "interner_test_a");
+        assert_eq!(c, "interner_test_c");
+        assert_eq!(format!("{}", a), "interner_test_a");
+        assert_eq!(format!("{:?}", a), "\"interner_test_a\"");
+    }
+
+    #[test]
+    fn test_get_does_not_insert() {
+        assert_eq!(Istr::get("interner_test_missing"), None);
+        assert_eq!(Istr::get("interner_test_missing"), None);
+        let live = Istr::new("interner_test_missing");
+        assert_eq!(Istr::get("interner_test_missing"), Some(live));
+    }
+
+    #[test]
+    fn test_released_when_last_handle_drops() {
+        let a = Istr::new("interner_test_released");
+        let b = a.clone();
+        drop(a);
+        assert!(Istr::get("interner_test_released").is_some());
+        drop(b);
+        assert_eq!(Istr::get("interner_test_released"), None);
+    }
+
+    #[test]
+    fn test_dead_entries_are_purged() {
+        for i in 0..10_000 {
+            Istr::new(&format!("interner_test_transient_{i}"));
+        }
+        let len = interner().read().unwrap().len;
+        assert!(len < 10_000, "失效表项未被清理：{len}");
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Istr::default(), Istr::new(""));
+        assert!(Istr::default().is_empty());
+        assert_eq!(Istr::EMPTY.as_str(), "");
+        assert_eq!(Istr::get(""), Some(Istr::EMPTY));
+        assert!(!Istr::new("x").is_empty());
+    }
+
+    #[test]
+    fn test_threads_agree() {
+        let keep = Istr::new("interner_test_shared");
+        let handles: Vec<_> = (0..4)
+            .map(|_| std::thread::spawn(|| Istr::new("interner_test_shared")))
+            .collect();
+        let ids: Vec<Istr> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        assert!(ids.iter().all(|id| *id == keep));
+    }
+}
@@ -0,0 +1,20 @@
+//! # 通用容器
+//!
+//! 游戏运行时常用的数据结构。
+//!
+//! ## 模块组织
+//!
+//! - [`pool`]: 对象池，复用昂贵对象并统计复用率
+//! - [`slot_map`]: 分代索引容器，检测过期句柄
+//! - [`arena`]: 线性（bump）分配器，承载每帧临时数据
+//! - [`interner`]: 全局字符串驻留，O(1) 比较的 [`Istr`]
+
+pub mod arena;
+pub mod interner;
+pub mod pool;
+pub mod slot_map;
+
+pub use arena::ArenaAllocator;
+pub use interner::Istr;
+pub use pool::{Pool, PoolStats};
+pub use slot_map::{Key, SlotKey, SlotMap};
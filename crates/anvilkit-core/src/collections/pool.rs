@@ -0,0 +1,200 @@
+//! 对象池
+//!
+//! [`Pool<T>`] 缓存已释放的对象供下次获取时复用，避免频繁分配
+//! （如子弹、粒子的缓冲区）。[`PoolStats`] 记录创建 / 复用次数，用于评估池大小。
+
+/// 对象池统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// 新创建的对象数
+    pub created: u64,
+    /// 从池中复用的对象数
+    pub reused: u64,
+    /// 归还到池中的对象数
+    pub released: u64,
+    /// 因池已满而丢弃的对象数
+    pub dropped: u64,
+}
+
+impl PoolStats {
+    /// 总获取次数
+    pub fn acquired(&self) -> u64 {
+        self.created + self.reused
+    }
+
+    /// 复用率（复用次数 / 总获取次数），未获取过时为 0
+    pub fn reuse_rate(&self) -> f32 {
+        match self.acquired() {
+            0 => 0.0,
+            n => self.reused as f32 / n as f32,
+        }
+    }
+}
+
+/// 对象工厂函数
+type Factory<T> = Box<dyn Fn() -> T + Send + Sync>;
+/// 归还时的重置函数
+type Reset<T> = Box<dyn Fn(&mut T) + Send + Sync>;
+
+/// 通用对象池
+///
+/// 获取时优先复用空闲对象，否则调用工厂函数创建；归还时先执行重置函数。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::collections::Pool;
+///
+/// let mut pool = Pool::new(|| Vec::<u8>::with_capacity(1024)).with_reset(Vec::clear);
+///
+/// let mut buf = pool.acquire();
+/// buf.push(1);
+/// pool.release(buf);
+///
+/// let buf = pool.acquire();
+/// assert!(buf.is_empty());
+/// assert!(buf.capacity() >= 1024);
+/// assert_eq!(pool.stats().reused, 1);
+/// ```
+pub struct Pool<T> {
+    free: Vec<T>,
+    factory: Factory<T>,
+    reset: Option<Reset<T>>,
+    max_size: Option<usize>,
+    stats: PoolStats,
+}
+
+impl<T> Pool<T> {
+    /// 以工厂函数创建空池
+    pub fn new(factory: impl Fn() -> T + Send + Sync + 'static) -> Self {
+        Self {
+            free: Vec::new(),
+            factory: Box::new(factory),
+            reset: None,
+            max_size: None,
+            stats: PoolStats::default(),
+        }
+    }
+
+    /// 设置归还时的重置函数
+    pub fn with_reset(mut self, reset: impl Fn(&mut T) + Send + Sync + 'static) -> Self {
+        self.reset = Some(Box::new(reset));
+        self
+    }
+
+    /// 限制空闲对象数量上限，超出的归还对象直接丢弃
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = Some(max_size);
+        self.free.truncate(max_size);
+        self
+    }
+
+    /// 预先创建 `count` 个空闲对象（计入 `created`）
+    pub fn prewarm(&mut self, count: usize) {
+        let target = self.max_size.map_or(count, |m| count.min(m));
+        while self.free.len() < target {
+            self.free.push((self.factory)());
+            self.stats.created += 1;
+        }
+    }
+
+    /// 获取对象：优先复用，否则新建
+    pub fn acquire(&mut self) -> T {
+        match self.free.pop() {
+            Some(item) => {
+                self.stats.reused += 1;
+                item
+            }
+            None => {
+                self.stats.created += 1;
+                (self.factory)()
+            }
+        }
+    }
+
+    /// 归还对象
+    pub fn release(&mut self, mut item: T) {
+        if self.max_size.is_some_and(|m| self.free.len() >= m) {
+            self.stats.dropped += 1;
+            return;
+        }
+        if let Some(reset) = &self.reset {
+            reset(&mut item);
+        }
+        self.stats.released += 1;
+        self.free.push(item);
+    }
+
+    /// 空闲对象数量
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// 丢弃所有空闲对象
+    pub fn clear(&mut self) {
+        self.free.clear();
+    }
+
+    /// 统计信息
+    pub fn stats(&self) -> PoolStats {
+        self.stats
+    }
+}
+
+impl<T: Default + 'static> Default for Pool<T> {
+    fn default() -> Self {
+        Self::new(T::default)
+    }
+}
+
+impl<T> std::fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pool")
+            .field("available", &self.free.len())
+            .field("max_size", &self.max_size)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_release_reuses() {
+        let mut pool: Pool<Vec<u32>> = Pool::default().with_reset(Vec::clear);
+        let mut a = pool.acquire();
+        a.extend([1, 2, 3]);
+        pool.release(a);
+        assert_eq!(pool.available(), 1);
+
+        let b = pool.acquire();
+        assert!(b.is_empty());
+        let _c = pool.acquire();
+
+        let stats = pool.stats();
+        assert_eq!((stats.created, stats.reused, stats.released), (2, 1, 1));
+        assert!((stats.reuse_rate() - 1.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_max_size_and_prewarm() {
+        let mut pool = Pool::new(|| 0u8).with_max_size(2);
+        pool.prewarm(5);
+        assert_eq!(pool.available(), 2);
+
+        let (a, b) = (pool.acquire(), pool.acquire());
+        let c = pool.acquire();
+        pool.release(a);
+        pool.release(b);
+        pool.release(c);
+        assert_eq!(pool.available(), 2);
+        assert_eq!(pool.stats().dropped, 1);
+    }
+
+    #[test]
+    fn test_empty_stats() {
+        assert_eq!(PoolStats::default().reuse_rate(), 0.0);
+    }
+}
@@ -0,0 +1,326 @@
+//! 分代索引 SlotMap
+//!
+//! [`SlotMap<K, V>`] 以 `(索引, 代数)` 作为键存储值：槽位被移除后代数递增，
+//! 之后复用该槽位时旧键不再有效，从而检测出过期句柄而不是静默指向新值。
+//! 插入、查询与删除均为 O(1)。
+
+use std::marker::PhantomData;
+
+/// 分代键
+///
+/// 可通过 [`to_bits`](Self::to_bits) / [`from_bits`](Self::from_bits) 与 `u64` 互转，
+/// 便于嵌入已有的整数句柄。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SlotKey {
+    index: u32,
+    generation: u32,
+}
+
+impl SlotKey {
+    /// 槽位索引
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// 代数
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// 打包为 `u64`（高 32 位为代数，低 32 位为索引）
+    pub fn to_bits(&self) -> u64 {
+        ((self.generation as u64) << 32) | self.index as u64
+    }
+
+    /// 从 [`to_bits`](Self::to_bits) 的结果还原
+    pub fn from_bits(bits: u64) -> Self {
+        Self { index: bits as u32, generation: (bits >> 32) as u32 }
+    }
+}
+
+/// 可用作 [`SlotMap`] 键的类型
+///
+/// 为自定义句柄实现此 trait 可获得类型安全的键（不同 SlotMap 的键不可混用）。
+pub trait Key: Copy {
+    /// 由分代键构造
+    fn from_slot_key(key: SlotKey) -> Self;
+    /// 取出分代键
+    fn slot_key(&self) -> SlotKey;
+}
+
+impl Key for SlotKey {
+    fn from_slot_key(key: SlotKey) -> Self {
+        key
+    }
+
+    fn slot_key(&self) -> SlotKey {
+        *self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot<V> {
+    /// 偶数表示空闲，奇数表示占用
+    generation: u32,
+    value: Option<V>,
+}
+
+/// 分代索引容器
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::collections::SlotMap;
+///
+/// let mut map = SlotMap::new();
+/// let a = map.insert("a");
+/// assert_eq!(map.get(a), Some(&"a"));
+///
+/// map.remove(a);
+/// let b = map.insert("b");
+/// // 槽位被复用，但旧键已失效
+/// assert_eq!(a.index(), b.index());
+/// assert_eq!(map.get(a), None);
+/// assert_eq!(map.get(b), Some(&"b"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlotMap<K: Key, V> {
+    slots: Vec<Slot<V>>,
+    free: Vec<u32>,
+    len: usize,
+    _key: PhantomData<fn() -> K>,
+}
+
+impl<V> SlotMap<SlotKey, V> {
+    /// 创建使用 [`SlotKey`] 作为键的空容器
+    pub fn new() -> Self {
+        Self::with_key()
+    }
+}
+
+impl<K: Key, V> SlotMap<K, V> {
+    /// 创建使用自定义键类型的空容器
+    pub fn with_key() -> Self {
+        Self { slots: Vec::new(), free: Vec::new(), len: 0, _key: PhantomData }
+    }
+
+    /// 预分配容量
+    pub fn with_capacity_and_key(capacity: usize) -> Self {
+        Self { slots: Vec::with_capacity(capacity), ..Self::with_key() }
+    }
+
+    /// 元素数量
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 插入值并返回键
+    pub fn insert(&mut self, value: V) -> K {
+        self.insert_with_key(|_| value)
+    }
+
+    /// 插入由键构造的值（值需要保存自身键时使用）
+    pub fn insert_with_key(&mut self, f: impl FnOnce(K) -> V) -> K {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                let index = u32::try_from(self.slots.len()).expect("SlotMap 槽位数量超出 u32 范围");
+                self.slots.push(Slot { generation: 0, value: None });
+                index
+            }
+        };
+        let slot = &mut self.slots[index as usize];
+        slot.generation = slot.generation.wrapping_add(1);
+        let key = K::from_slot_key(SlotKey { index, generation: slot.generation });
+        slot.value = Some(f(key));
+        self.len += 1;
+        key
+    }
+
+    fn slot(&self, key: K) -> Option<&Slot<V>> {
+        let k = key.slot_key();
+        self.slots.get(k.index as usize).filter(|s| s.generation == k.generation)
+    }
+
+    /// 键是否有效
+    pub fn contains_key(&self, key: K) -> bool {
+        self.slot(key).is_some_and(|s| s.value.is_some())
+    }
+
+    /// 获取值（过期键返回 `None`）
+    pub fn get(&self, key: K) -> Option<&V> {
+        self.slot(key)?.value.as_ref()
+    }
+
+    /// 获取可变值（过期键返回 `None`）
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        let k = key.slot_key();
+        self.slots
+            .get_mut(k.index as usize)
+            .filter(|s| s.generation == k.generation)?
+            .value
+            .as_mut()
+    }
+
+    /// 移除并返回值，槽位代数递增使所有旧键失效
+    pub fn remove(&mut self, key: K) -> Option<V> {
+        let k = key.slot_key();
+        let slot = self.slots.get_mut(k.index as usize).filter(|s| s.generation == k.generation)?;
+        let value = slot.value.take()?;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.free.push(k.index);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// 仅保留满足条件的元素
+    pub fn retain(&mut self, mut f: impl FnMut(K, &mut V) -> bool) {
+        for index in 0..self.slots.len() {
+            let slot = &mut self.slots[index];
+            let key = K::from_slot_key(SlotKey { index: index as u32, generation: slot.generation });
+            let keep = match slot.value.as_mut() {
+                Some(value) => f(key, value),
+                None => continue,
+            };
+            if !keep {
+                self.remove(key);
+            }
+        }
+    }
+
+    /// 移除所有元素（所有旧键失效）
+    pub fn clear(&mut self) {
+        self.retain(|_, _| false);
+    }
+
+    /// 遍历 `(键, 值)`
+    pub fn iter(&self) -> impl Iterator<Item = (K, &V)> + '_ {
+        self.slots.iter().enumerate().filter_map(|(index, s)| {
+            let key = K::from_slot_key(SlotKey { index: index as u32, generation: s.generation });
+            s.value.as_ref().map(|v| (key, v))
+        })
+    }
+
+    /// 可变遍历 `(键, 值)`
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (K, &mut V)> + '_ {
+        self.slots.iter_mut().enumerate().filter_map(|(index, s)| {
+            let key = K::from_slot_key(SlotKey { index: index as u32, generation: s.generation });
+            s.value.as_mut().map(|v| (key, v))
+        })
+    }
+
+    /// 遍历所有键
+    pub fn keys(&self) -> impl Iterator<Item = K> + '_ {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// 遍历所有值
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// 可变遍历所有值
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.iter_mut().map(|(_, v)| v)
+    }
+}
+
+impl<K: Key, V> Default for SlotMap<K, V> {
+    fn default() -> Self {
+        Self::with_key()
+    }
+}
+
+impl<K: Key, V> std::ops::Index<K> for SlotMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: K) -> &V {
+        self.get(key).expect("SlotMap: 无效或已过期的键")
+    }
+}
+
+impl<K: Key, V> std::ops::IndexMut<K> for SlotMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut V {
+        self.get_mut(key).expect("SlotMap: 无效或已过期的键")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stale_keys_detected() {
+        let mut map = SlotMap::new();
+        let a = map.insert(1);
+        let b = map.insert(2);
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.remove(a), Some(1));
+        assert_eq!(map.remove(a), None);
+        assert!(!map.contains_key(a));
+
+        let c = map.insert(3);
+        assert_eq!(c.index(), a.index());
+        assert_ne!(c, a);
+        assert_eq!(map.get(a), None);
+        assert_eq!(map.get_mut(a), None);
+        assert_eq!(map[c], 3);
+        assert_eq!(map[b], 2);
+    }
+
+    #[test]
+    fn test_bits_roundtrip() {
+        let mut map = SlotMap::new();
+        let a = map.insert(());
+        map.remove(a);
+        let b = map.insert(());
+        assert_eq!(SlotKey::from_bits(b.to_bits()), b);
+        assert_eq!(b.generation(), 3);
+    }
+
+    #[test]
+    fn test_iter_retain_clear() {
+        let mut map = SlotMap::new();
+        let keys: Vec<_> = (0..6).map(|i| map.insert(i)).collect();
+        map.retain(|_, v| *v % 2 == 0);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![0, 2, 4]);
+        for v in map.values_mut() {
+            *v *= 10;
+        }
+        assert_eq!(map.get(keys[4]), Some(&40));
+        assert!(map.get(keys[1]).is_none());
+
+        map.clear();
+        assert!(map.is_empty());
+        assert!(keys.iter().all(|&k| !map.contains_key(k)));
+        assert_eq!(map.iter().count(), 0);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct NodeId(SlotKey);
+
+    impl Key for NodeId {
+        fn from_slot_key(key: SlotKey) -> Self {
+            NodeId(key)
+        }
+
+        fn slot_key(&self) -> SlotKey {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_custom_key_and_insert_with_key() {
+        let mut map: SlotMap<NodeId, (NodeId, &str)> = SlotMap::with_key();
+        let id = map.insert_with_key(|k| (k, "root"));
+        assert_eq!(map[id].0, id);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![id]);
+    }
+}
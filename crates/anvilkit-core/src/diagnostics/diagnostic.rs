@@ -0,0 +1,223 @@
+//! 指标与指标表
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+/// 单个命名指标：最新值与滚动历史
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    name: String,
+    unit: String,
+    history: VecDeque<f64>,
+    max_history: usize,
+    sum: f64,
+    /// 为 `false` 时忽略新的测量值
+    pub enabled: bool,
+}
+
+impl Diagnostic {
+    /// 创建指标，保留最近 `max_history` 个测量值
+    pub fn new(name: impl Into<String>, unit: impl Into<String>, max_history: usize) -> Self {
+        let max_history = max_history.max(1);
+        Self {
+            name: name.into(),
+            unit: unit.into(),
+            history: VecDeque::with_capacity(max_history),
+            max_history,
+            sum: 0.0,
+            enabled: true,
+        }
+    }
+
+    /// 指标名称
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 单位（如 `"ms"`）
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    /// 历史容量
+    pub fn max_history(&self) -> usize {
+        self.max_history
+    }
+
+    /// 写入一个测量值，超出容量时丢弃最旧的
+    pub fn add(&mut self, value: f64) {
+        if !self.enabled || !value.is_finite() {
+            return;
+        }
+        if self.history.len() == self.max_history {
+            if let Some(old) = self.history.pop_front() {
+                self.sum -= old;
+            }
+        }
+        self.history.push_back(value);
+        self.sum += value;
+    }
+
+    /// 最新测量值
+    pub fn value(&self) -> Option<f64> {
+        self.history.back().copied()
+    }
+
+    /// 历史平均值
+    pub fn average(&self) -> Option<f64> {
+        (!self.history.is_empty()).then(|| self.sum / self.history.len() as f64)
+    }
+
+    /// 历史最小值
+    pub fn min(&self) -> Option<f64> {
+        self.history.iter().copied().reduce(f64::min)
+    }
+
+    /// 历史最大值
+    pub fn max(&self) -> Option<f64> {
+        self.history.iter().copied().reduce(f64::max)
+    }
+
+    /// 历史测量值（旧 → 新）
+    pub fn history(&self) -> &VecDeque<f64> {
+        &self.history
+    }
+
+    /// 清空历史
+    pub fn clear(&mut self) {
+        self.history.clear();
+        self.sum = 0.0;
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.value(), self.average()) {
+            (Some(value), Some(avg)) => {
+                write!(f, "{}: {:.2}{} (avg {:.2})", self.name, value, self.unit, avg)
+            }
+            _ => write!(f, "{}: -", self.name),
+        }
+    }
+}
+
+/// 指标表 (Resource)
+///
+/// 按名称排序存储，便于稳定地遍历与输出日志。
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::system::Resource))]
+#[derive(Debug, Clone)]
+pub struct Diagnostics {
+    diagnostics: BTreeMap<String, Diagnostic>,
+    /// [`register`](Self::register) 使用的默认历史容量
+    pub max_history: usize,
+}
+
+impl Default for Diagnostics {
+    fn default() -> Self {
+        Self::with_max_history(120)
+    }
+}
+
+impl Diagnostics {
+    /// 以指定的默认历史容量创建
+    pub fn with_max_history(max_history: usize) -> Self {
+        Self { diagnostics: BTreeMap::new(), max_history }
+    }
+
+    /// 注册指标（已存在时保持原样），返回该指标
+    pub fn register(&mut self, name: &str, unit: &str) -> &mut Diagnostic {
+        let max_history = self.max_history;
+        self.diagnostics
+            .entry(name.to_string())
+            .or_insert_with(|| Diagnostic::new(name, unit, max_history))
+    }
+
+    /// 写入测量值；指标未注册时返回 `false`
+    pub fn add_measurement(&mut self, name: &str, value: f64) -> bool {
+        match self.diagnostics.get_mut(name) {
+            Some(diagnostic) => {
+                diagnostic.add(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 按名称查询
+    pub fn get(&self, name: &str) -> Option<&Diagnostic> {
+        self.diagnostics.get(name)
+    }
+
+    /// 按名称可变查询
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Diagnostic> {
+        self.diagnostics.get_mut(name)
+    }
+
+    /// 指标最新值的快捷查询
+    pub fn value(&self, name: &str) -> Option<f64> {
+        self.get(name).and_then(Diagnostic::value)
+    }
+
+    /// 是否已注册
+    pub fn contains(&self, name: &str) -> bool {
+        self.diagnostics.contains_key(name)
+    }
+
+    /// 按名称顺序遍历全部指标
+    pub fn iter(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.values()
+    }
+
+    /// 已注册指标数
+    pub fn len(&self) -> usize {
+        self.diagnostics.len()
+    }
+
+    /// 是否没有任何指标
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+
+    /// 每个指标一行的摘要，适合写入日志
+    pub fn summary(&self) -> String {
+        self.iter().map(|d| d.to_string()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_history() {
+        let mut diagnostic = Diagnostic::new("frame_time", "ms", 3);
+        for value in [1.0, 2.0, 3.0, 10.0] {
+            diagnostic.add(value);
+        }
+        assert_eq!(diagnostic.history().len(), 3);
+        assert_eq!(diagnostic.value(), Some(10.0));
+        assert_eq!(diagnostic.average(), Some(5.0));
+        assert_eq!(diagnostic.min(), Some(2.0));
+        assert_eq!(diagnostic.max(), Some(10.0));
+
+        diagnostic.add(f64::NAN);
+        diagnostic.enabled = false;
+        diagnostic.add(100.0);
+        assert_eq!(diagnostic.value(), Some(10.0));
+    }
+
+    #[test]
+    fn test_register_and_summary() {
+        let mut diagnostics = Diagnostics::with_max_history(4);
+        assert!(!diagnostics.add_measurement("fps", 60.0));
+
+        diagnostics.register("fps", "").add(60.0);
+        diagnostics.register("entity_count", "");
+        // 重复注册不会重置历史
+        diagnostics.register("fps", "");
+        assert_eq!(diagnostics.value("fps"), Some(60.0));
+        assert_eq!(diagnostics.get("fps").unwrap().max_history(), 4);
+
+        assert_eq!(diagnostics.summary(), "entity_count: -\nfps: 60.00 (avg 60.00)");
+    }
+}
@@ -0,0 +1,49 @@
+//! # 诊断指标
+//!
+//! 引擎各模块以名称注册指标（帧时间、实体数、绘制调用、GPU 内存……），
+//! 每帧写入一个测量值并保留滚动历史，供调试叠加层、日志与外部分析工具查询。
+//!
+//! ## 模块组织
+//!
+//! - [`diagnostic`]: [`Diagnostic`] 单个指标及其历史，[`Diagnostics`] 指标表
+//! - [`source`]: 按帧从 ECS World 采样的指标来源（需要 `bevy_ecs` feature）
+//!
+//! ```rust
+//! use anvilkit_core::diagnostics::{Diagnostics, FRAME_TIME};
+//!
+//! let mut diagnostics = Diagnostics::default();
+//! diagnostics.register(FRAME_TIME, "ms");
+//! diagnostics.add_measurement(FRAME_TIME, 16.0);
+//! diagnostics.add_measurement(FRAME_TIME, 18.0);
+//!
+//! let frame_time = diagnostics.get(FRAME_TIME).unwrap();
+//! assert_eq!(frame_time.value(), Some(18.0));
+//! assert_eq!(frame_time.average(), Some(17.0));
+//! ```
+
+pub mod diagnostic;
+#[cfg(feature = "bevy_ecs")]
+pub mod source;
+
+pub use diagnostic::{Diagnostic, Diagnostics};
+#[cfg(feature = "bevy_ecs")]
+pub use source::{sample_diagnostics, DiagnosticSourceFn, DiagnosticSources};
+
+/// 帧时间（毫秒）
+pub const FRAME_TIME: &str = "frame_time";
+/// 帧率
+pub const FPS: &str = "fps";
+/// 实体数量
+pub const ENTITY_COUNT: &str = "entity_count";
+/// 每帧绘制调用数
+pub const DRAW_CALLS: &str = "draw_calls";
+/// 每帧三角形数
+pub const TRIANGLES: &str = "triangles";
+/// GPU 内存估计（MiB）
+pub const GPU_MEMORY: &str = "gpu_memory";
+/// 整帧 GPU 耗时（毫秒，需要启用 GPU 计时）
+pub const GPU_FRAME_TIME: &str = "gpu_frame_time";
+/// 单个渲染 pass GPU 耗时指标名的前缀，完整名称形如 `gpu/scene`
+pub const GPU_PASS_PREFIX: &str = "gpu/";
+/// 累计卡顿（长帧）次数
+pub const HITCHES: &str = "hitches";
@@ -0,0 +1,88 @@
+//! 从 ECS World 采样的指标来源
+
+use bevy_ecs::prelude::*;
+
+use super::Diagnostics;
+
+/// 指标采样函数：返回 `None` 表示本帧没有测量值
+pub type DiagnosticSourceFn = dyn Fn(&World) -> Option<f64> + Send + Sync;
+
+/// 已注册的指标来源 (Resource)
+///
+/// 每个来源对应一个 [`Diagnostics`] 中的指标，由 [`sample_diagnostics`] 每帧采样一次。
+#[derive(Resource, Default)]
+pub struct DiagnosticSources {
+    sources: Vec<(String, Box<DiagnosticSourceFn>)>,
+}
+
+impl DiagnosticSources {
+    /// 添加来源；同名来源会被替换
+    pub fn add(&mut self, name: &str, source: impl Fn(&World) -> Option<f64> + Send + Sync + 'static) {
+        self.sources.retain(|(existing, _)| existing != name);
+        self.sources.push((name.to_string(), Box::new(source)));
+    }
+
+    /// 移除来源
+    pub fn remove(&mut self, name: &str) {
+        self.sources.retain(|(existing, _)| existing != name);
+    }
+
+    /// 来源数量
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// 是否没有来源
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+/// 采样全部来源并写入 [`Diagnostics`]（独占系统）
+pub fn sample_diagnostics(world: &mut World) {
+    if !world.contains_resource::<Diagnostics>() {
+        return;
+    }
+    world.resource_scope(|world, sources: Mut<DiagnosticSources>| {
+        let values: Vec<(&str, f64)> = sources
+            .sources
+            .iter()
+            .filter_map(|(name, source)| source(world).map(|value| (name.as_str(), value)))
+            .collect();
+        let mut diagnostics = world.resource_mut::<Diagnostics>();
+        for (name, value) in values {
+            diagnostics.add_measurement(name, value);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource)]
+    struct Counter(u32);
+
+    #[test]
+    fn test_sample_sources() {
+        let mut world = World::new();
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.register("counter", "");
+        world.insert_resource(diagnostics);
+        world.insert_resource(Counter(3));
+
+        let mut sources = DiagnosticSources::default();
+        sources.add("counter", |world| world.get_resource::<Counter>().map(|c| c.0 as f64));
+        sources.add("missing", |_| Some(1.0));
+        world.insert_resource(sources);
+
+        sample_diagnostics(&mut world);
+        world.resource_mut::<Counter>().0 = 5;
+        sample_diagnostics(&mut world);
+
+        let diagnostics = world.resource::<Diagnostics>();
+        assert_eq!(diagnostics.get("counter").unwrap().history().len(), 2);
+        assert_eq!(diagnostics.value("counter"), Some(5.0));
+        assert!(!diagnostics.contains("missing"));
+    }
+}
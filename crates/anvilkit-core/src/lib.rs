@@ -6,6 +6,11 @@
 //! - **数学系统**: 变换、几何图形、插值和数学常量
 //! - **时间管理**: 帧时间跟踪、计时器和时间工具
 //! - **错误处理**: 统一的错误类型和结果处理
+//! - **通用容器**: 对象池等运行时数据结构
+//! - **诊断指标**: 命名指标的滚动历史，供调试叠加层与分析工具使用
+//! - **随机数**: 可设种子、可复现的 PCG32 生成器与按名称派生的随机流
+//! - **程序化噪声**: Perlin / Simplex 噪声、可平铺变体与分形布朗运动
+//! - **空间索引**: 空间哈希网格、四叉树与 BVH，用于宽相位区域 / 射线查询
 //! 
 //! ## 快速开始
 //! 
@@ -28,6 +33,8 @@
 //! 
 //! - `serde`: 启用序列化支持
 //! - `debug`: 启用调试功能和额外的验证
+//! - `wgpu`: 启用 `Color` → `wgpu::Color` 转换
+//! - `simd`: 批量几何测试（如 `Aabb::intersects_many`）使用 SIMD 路径
 
 #![warn(missing_docs)]
 
@@ -35,12 +42,25 @@ pub mod math;
 pub mod time;
 pub mod error;
 pub mod persistence;
+pub mod collections;
+pub mod diagnostics;
+pub mod rng;
+pub mod noise;
+pub mod spatial;
 
 /// 预导入模块，包含最常用的类型和函数
 pub mod prelude {
     // 数学类型
-    pub use crate::math::{Transform, GlobalTransform};
-    pub use crate::math::{Aabb, Frustum};
+    pub use crate::math::{Transform, GlobalTransform, Transform2D};
+    pub use crate::math::{Aabb, Bounds2D, Frustum};
+    pub use crate::math::{Color, EaseFunction, Lerp};
+
+    // 随机数
+    pub use crate::rng::{GlobalRng, Rng};
+    pub use crate::noise::{Fbm, Noise, NoiseKind};
+
+    // 空间索引
+    pub use crate::spatial::{Bvh, Quadtree, SpatialHash2D, SpatialIndex2D};
 
     // 时间类型
     pub use crate::time::{Time, Timer};
@@ -1,6 +1,8 @@
 //! 轴对齐包围盒 (Axis-Aligned Bounding Box)
 
-use glam::Vec3;
+use glam::{Mat4, Vec3};
+#[cfg(feature = "simd")]
+use glam::Vec3A;
 use anvilkit_describe::Describe;
 
 /// 轴对齐包围盒 (Axis-Aligned Bounding Box)
@@ -68,6 +70,36 @@ impl Aabb {
             && self.min.z <= other.max.z && self.max.z >= other.min.z
     }
 
+    /// 批量相交测试
+    ///
+    /// 清空 `out` 后写入 `others.len()` 个结果，`out[i]` 表示是否与 `others[i]` 相交。
+    /// 启用 `simd` feature 时使用 glam 的 `Vec3A`（SSE2 / NEON）单指令比较三轴，
+    /// 否则使用与 [`intersects`](Self::intersects) 相同的标量比较。
+    pub fn intersects_many(&self, others: &[Aabb], out: &mut Vec<bool>) {
+        out.clear();
+        out.reserve(others.len());
+
+        #[cfg(feature = "simd")]
+        {
+            let min = Vec3A::from(self.min);
+            let max = Vec3A::from(self.max);
+            out.extend(others.iter().map(|o| {
+                (min.cmple(Vec3A::from(o.max)) & max.cmpge(Vec3A::from(o.min))).all()
+            }));
+        }
+
+        #[cfg(not(feature = "simd"))]
+        out.extend(others.iter().map(|o| self.intersects(o)));
+    }
+
+    /// 批量相交测试，仅收集相交的索引（用于宽相位碰撞 / 剔除）
+    pub fn intersecting_indices(&self, others: &[Aabb], out: &mut Vec<usize>) {
+        let mut mask = Vec::new();
+        self.intersects_many(others, &mut mask);
+        out.clear();
+        out.extend(mask.iter().enumerate().filter_map(|(i, &hit)| hit.then_some(i)));
+    }
+
     /// 将 AABB 按偏移量平移
     pub fn translated(&self, offset: Vec3) -> Aabb {
         Aabb {
@@ -75,6 +107,29 @@ impl Aabb {
             max: self.max + offset,
         }
     }
+
+    /// 合并两个 AABB，返回同时包含两者的最小 AABB
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// 将局部空间 AABB 变换到矩阵所在空间（通常为 `GlobalTransform` 的世界矩阵）
+    ///
+    /// 结果为包含变换后 8 个角点的最小 AABB，旋转时会适当膨胀。
+    pub fn transformed(&self, matrix: &Mat4) -> Aabb {
+        let center = matrix.transform_point3(self.center());
+        let half = self.half_extents();
+        let extent = matrix.x_axis.truncate().abs() * half.x
+            + matrix.y_axis.truncate().abs() * half.y
+            + matrix.z_axis.truncate().abs() * half.z;
+        Aabb {
+            min: center - extent,
+            max: center + extent,
+        }
+    }
 }
 
 impl Default for Aabb {
@@ -117,6 +172,30 @@ mod tests {
         assert!(!a.intersects(&c));
     }
 
+    #[test]
+    fn test_intersects_many_matches_scalar() {
+        let a = Aabb::from_min_max(Vec3::ZERO, Vec3::ONE);
+        let others: Vec<Aabb> = (0..64)
+            .map(|i| {
+                let o = Vec3::new((i % 4) as f32 - 1.5, (i / 4 % 4) as f32 - 1.5, (i / 16) as f32 - 1.5);
+                Aabb::from_min_max(o, o + Vec3::splat(0.75))
+            })
+            .collect();
+
+        let mut mask = vec![true; 3];
+        a.intersects_many(&others, &mut mask);
+        assert_eq!(mask.len(), others.len());
+        for (hit, other) in mask.iter().zip(&others) {
+            assert_eq!(*hit, a.intersects(other));
+        }
+
+        let mut indices = Vec::new();
+        a.intersecting_indices(&others, &mut indices);
+        assert_eq!(indices.len(), mask.iter().filter(|&&h| h).count());
+        assert!(indices.iter().all(|&i| mask[i]));
+        assert!(!indices.is_empty());
+    }
+
     #[test]
     fn test_aabb_translated() {
         let aabb = Aabb::from_min_max(Vec3::ZERO, Vec3::ONE);
@@ -124,4 +203,31 @@ mod tests {
         assert_eq!(moved.min, Vec3::new(5.0, 0.0, 0.0));
         assert_eq!(moved.max, Vec3::new(6.0, 1.0, 1.0));
     }
+
+    #[test]
+    fn test_aabb_transformed() {
+        let aabb = Aabb::from_min_max(Vec3::new(-1.0, -2.0, -3.0), Vec3::new(1.0, 2.0, 3.0));
+
+        let scaled = aabb.transformed(&Mat4::from_scale_rotation_translation(
+            Vec3::splat(2.0),
+            glam::Quat::IDENTITY,
+            Vec3::new(10.0, 0.0, 0.0),
+        ));
+        assert!((scaled.min - Vec3::new(8.0, -4.0, -6.0)).length() < 1e-5);
+        assert!((scaled.max - Vec3::new(12.0, 4.0, 6.0)).length() < 1e-5);
+
+        // 绕 Z 轴旋转 90°：x/y 范围互换
+        let rotated = aabb.transformed(&Mat4::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        assert!((rotated.min - Vec3::new(-2.0, -1.0, -3.0)).length() < 1e-5);
+        assert!((rotated.max - Vec3::new(2.0, 1.0, 3.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_aabb_union() {
+        let a = Aabb::from_min_max(Vec3::ZERO, Vec3::ONE);
+        let b = Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(0.5));
+        let u = a.union(&b);
+        assert_eq!(u.min, Vec3::splat(-1.0));
+        assert_eq!(u.max, Vec3::ONE);
+    }
 }
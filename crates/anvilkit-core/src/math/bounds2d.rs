@@ -0,0 +1,136 @@
+//! 2D 轴对齐包围矩形
+
+use glam::Vec2;
+
+use super::aabb::Aabb;
+
+/// 2D 轴对齐包围矩形
+///
+/// 2D 空间索引（[`SpatialHash2D`](crate::spatial::SpatialHash2D)、
+/// [`Quadtree`](crate::spatial::Quadtree)）使用的包围体。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::Bounds2D;
+/// use glam::Vec2;
+///
+/// let a = Bounds2D::from_center_half_extents(Vec2::ZERO, Vec2::ONE);
+/// let b = Bounds2D::from_min_max(Vec2::splat(0.5), Vec2::splat(2.0));
+/// assert!(a.intersects(&b));
+/// assert_eq!(a.union(&b).max, Vec2::splat(2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds2D {
+    /// 最小角
+    pub min: Vec2,
+    /// 最大角
+    pub max: Vec2,
+}
+
+impl Bounds2D {
+    /// 从最小/最大点创建
+    pub fn from_min_max(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// 从中心与半尺寸创建
+    pub fn from_center_half_extents(center: Vec2, half_extents: Vec2) -> Self {
+        Self { min: center - half_extents, max: center + half_extents }
+    }
+
+    /// 取 3D 包围盒在 XY 平面上的投影
+    pub fn from_aabb_xy(aabb: &Aabb) -> Self {
+        Self { min: aabb.min.truncate(), max: aabb.max.truncate() }
+    }
+
+    /// 中心点
+    pub fn center(&self) -> Vec2 {
+        (self.min + self.max) * 0.5
+    }
+
+    /// 半尺寸
+    pub fn half_extents(&self) -> Vec2 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// 尺寸
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    /// 是否包含点（含边界）
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.cmpge(self.min).all() && point.cmple(self.max).all()
+    }
+
+    /// 是否完全包含另一矩形
+    pub fn contains(&self, other: &Bounds2D) -> bool {
+        other.min.cmpge(self.min).all() && other.max.cmple(self.max).all()
+    }
+
+    /// 是否与另一矩形相交（边界接触视为相交）
+    pub fn intersects(&self, other: &Bounds2D) -> bool {
+        self.min.cmple(other.max).all() && self.max.cmpge(other.min).all()
+    }
+
+    /// 同时包含两者的最小矩形
+    pub fn union(&self, other: &Bounds2D) -> Bounds2D {
+        Bounds2D { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    /// 射线相交测试（slab 法）
+    ///
+    /// 返回最近交点的参数值（起点在矩形内时为 0），未命中或矩形在射线背后时返回 `None`。
+    pub fn ray_intersection(&self, origin: Vec2, direction: Vec2) -> Option<f32> {
+        let inv = direction.recip();
+        let t1 = (self.min - origin) * inv;
+        let t2 = (self.max - origin) * inv;
+        let t_near = t1.min(t2).max_element();
+        let t_far = t1.max(t2).min_element();
+
+        if t_near > t_far || t_far < 0.0 {
+            return None;
+        }
+        Some(t_near.max(0.0))
+    }
+}
+
+impl Default for Bounds2D {
+    fn default() -> Self {
+        Self { min: Vec2::splat(-0.5), max: Vec2::splat(0.5) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    #[test]
+    fn test_contains_and_intersects() {
+        let a = Bounds2D::from_min_max(Vec2::ZERO, Vec2::splat(4.0));
+        assert!(a.contains_point(Vec2::new(4.0, 0.0)));
+        assert!(!a.contains_point(Vec2::new(4.1, 0.0)));
+        assert!(a.contains(&Bounds2D::from_min_max(Vec2::ONE, Vec2::splat(2.0))));
+        assert!(!a.contains(&Bounds2D::from_min_max(Vec2::ONE, Vec2::splat(5.0))));
+        assert!(a.intersects(&Bounds2D::from_min_max(Vec2::splat(4.0), Vec2::splat(5.0))));
+        assert!(!a.intersects(&Bounds2D::from_min_max(Vec2::splat(4.5), Vec2::splat(5.0))));
+    }
+
+    #[test]
+    fn test_ray_intersection() {
+        let b = Bounds2D::from_min_max(Vec2::new(2.0, -1.0), Vec2::new(3.0, 1.0));
+        assert_eq!(b.ray_intersection(Vec2::ZERO, Vec2::X), Some(2.0));
+        assert_eq!(b.ray_intersection(Vec2::ZERO, Vec2::NEG_X), None);
+        assert_eq!(b.ray_intersection(Vec2::new(2.5, 0.0), Vec2::X), Some(0.0));
+    }
+
+    #[test]
+    fn test_from_aabb_xy() {
+        let aabb = Aabb::from_min_max(Vec3::new(-1.0, -2.0, -3.0), Vec3::new(1.0, 2.0, 3.0));
+        let b = Bounds2D::from_aabb_xy(&aabb);
+        assert_eq!(b.min, Vec2::new(-1.0, -2.0));
+        assert_eq!(b.half_extents(), Vec2::new(1.0, 2.0));
+    }
+}
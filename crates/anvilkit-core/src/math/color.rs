@@ -0,0 +1,243 @@
+//! 颜色类型
+//!
+//! [`Color`] 以线性 RGBA 存储（着色器和混合运算使用的空间），
+//! 并提供 sRGB、十六进制、HSV、HSL 构造与转换。
+
+use glam::Vec4;
+use super::lerp::Lerp;
+
+/// 线性空间 RGBA 颜色
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::Color;
+///
+/// let orange = Color::hex("#ff8800").unwrap();
+/// assert_eq!(orange.to_srgba_u8(), [255, 136, 0, 255]);
+///
+/// let red = Color::hsv(0.0, 1.0, 1.0);
+/// assert_eq!(red, Color::RED);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    /// 红（线性）
+    pub r: f32,
+    /// 绿（线性）
+    pub g: f32,
+    /// 蓝（线性）
+    pub b: f32,
+    /// 不透明度
+    pub a: f32,
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::WHITE
+    }
+}
+
+impl Color {
+    /// 白色
+    pub const WHITE: Self = Self::rgba(1.0, 1.0, 1.0, 1.0);
+    /// 黑色
+    pub const BLACK: Self = Self::rgba(0.0, 0.0, 0.0, 1.0);
+    /// 红色
+    pub const RED: Self = Self::rgba(1.0, 0.0, 0.0, 1.0);
+    /// 绿色
+    pub const GREEN: Self = Self::rgba(0.0, 1.0, 0.0, 1.0);
+    /// 蓝色
+    pub const BLUE: Self = Self::rgba(0.0, 0.0, 1.0, 1.0);
+    /// 全透明
+    pub const TRANSPARENT: Self = Self::rgba(0.0, 0.0, 0.0, 0.0);
+
+    /// 由线性 RGBA 创建
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// 由线性 RGB 创建（不透明）
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    /// 由 sRGB 分量（0..1）创建，Alpha 保持线性
+    pub fn srgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self::rgba(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a)
+    }
+
+    /// 由 sRGB 分量（0..1）创建（不透明）
+    pub fn srgb(r: f32, g: f32, b: f32) -> Self {
+        Self::srgba(r, g, b, 1.0)
+    }
+
+    /// 由 8 位 sRGB 分量创建
+    pub fn srgba_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::srgba(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+
+    /// 解析 sRGB 十六进制字符串：`#rgb`、`#rgba`、`#rrggbb`、`#rrggbbaa`（`#` 可省略）
+    pub fn hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.is_ascii() {
+            return None;
+        }
+        let nibble = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|v| v * 17);
+        let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        let [r, g, b, a] = match hex.len() {
+            3 => [nibble(0)?, nibble(1)?, nibble(2)?, 255],
+            4 => [nibble(0)?, nibble(1)?, nibble(2)?, nibble(3)?],
+            6 => [byte(0)?, byte(2)?, byte(4)?, 255],
+            8 => [byte(0)?, byte(2)?, byte(4)?, byte(6)?],
+            _ => return None,
+        };
+        Some(Self::srgba_u8(r, g, b, a))
+    }
+
+    /// 由 HSV 创建（色相为角度，饱和度/明度 0..1，结果为 sRGB 颜色）
+    pub fn hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let c = value * saturation;
+        let (r, g, b) = hue_to_rgb(hue, c);
+        let m = value - c;
+        Self::srgb(r + m, g + m, b + m)
+    }
+
+    /// 由 HSL 创建（色相为角度，饱和度/亮度 0..1，结果为 sRGB 颜色）
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let c = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let (r, g, b) = hue_to_rgb(hue, c);
+        let m = lightness - c / 2.0;
+        Self::srgb(r + m, g + m, b + m)
+    }
+
+    /// 设置 Alpha
+    pub fn with_alpha(mut self, a: f32) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// 转换为 sRGB 分量 `[r, g, b, a]`（0..1）
+    pub fn to_srgba(&self) -> [f32; 4] {
+        [linear_to_srgb(self.r), linear_to_srgb(self.g), linear_to_srgb(self.b), self.a]
+    }
+
+    /// 转换为 8 位 sRGB 分量
+    pub fn to_srgba_u8(&self) -> [u8; 4] {
+        self.to_srgba().map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// 线性 RGBA 数组
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+}
+
+/// sRGB 分量 → 线性
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// 线性分量 → sRGB
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// 色相扇区分解（HSV/HSL 共用），返回未加偏移的 RGB
+fn hue_to_rgb(hue: f32, chroma: f32) -> (f32, f32, f32) {
+    let h = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    }
+}
+
+impl Lerp for Color {
+    /// 在线性空间插值
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self::from(Vec4::from(*self).lerp(Vec4::from(*other), t))
+    }
+}
+
+impl From<Color> for Vec4 {
+    fn from(c: Color) -> Self {
+        Vec4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl From<Vec4> for Color {
+    fn from(v: Vec4) -> Self {
+        Self::rgba(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(c: Color) -> Self {
+        c.to_array()
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(a: [f32; 4]) -> Self {
+        Self::rgba(a[0], a[1], a[2], a[3])
+    }
+}
+
+#[cfg(feature = "wgpu")]
+impl From<Color> for wgpu::Color {
+    fn from(c: Color) -> Self {
+        wgpu::Color { r: c.r as f64, g: c.g as f64, b: c.b as f64, a: c.a as f64 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_srgb_roundtrip() {
+        for v in [0.0, 0.02, 0.2, 0.5, 0.8, 1.0] {
+            assert_relative_eq!(linear_to_srgb(srgb_to_linear(v)), v, epsilon = 1e-5);
+        }
+        assert_relative_eq!(srgb_to_linear(0.5), 0.214_041, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_hex_parsing() {
+        assert_eq!(Color::hex("#ff8800").unwrap().to_srgba_u8(), [255, 136, 0, 255]);
+        assert_eq!(Color::hex("f80").unwrap().to_srgba_u8(), [255, 136, 0, 255]);
+        assert_eq!(Color::hex("#00000080").unwrap().to_srgba_u8(), [0, 0, 0, 128]);
+        assert!(Color::hex("#12345").is_none());
+        assert!(Color::hex("#gg0000").is_none());
+    }
+
+    #[test]
+    fn test_hsv_hsl() {
+        assert_eq!(Color::hsv(120.0, 1.0, 1.0), Color::GREEN);
+        assert_eq!(Color::hsl(240.0, 1.0, 0.5), Color::BLUE);
+        assert_eq!(Color::hsl(0.0, 0.0, 1.0), Color::WHITE);
+        assert_eq!(Color::hsv(-120.0, 1.0, 1.0), Color::BLUE);
+    }
+
+    #[test]
+    fn test_lerp_and_conversions() {
+        let mid = Color::BLACK.lerp(&Color::WHITE, 0.5);
+        assert_eq!(mid, Color::rgb(0.5, 0.5, 0.5));
+        assert_eq!(Vec4::from(Color::RED), Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(Color::from([0.1, 0.2, 0.3, 0.4]).to_array(), [0.1, 0.2, 0.3, 0.4]);
+    }
+}
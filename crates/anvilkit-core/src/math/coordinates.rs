@@ -0,0 +1,233 @@
+//! # 坐标系约定
+//!
+//! AnvilKit 使用**左手、Y 轴向上**的坐标系：`+X` 右、`+Y` 上、`+Z` 前
+//! （相机与模型都朝 `+Z` 看，透视矩阵为 `perspective_lh`）。
+//!
+//! 各 DCC 工具与交换格式的约定不同：
+//!
+//! | 约定 | 手性 | 上 | 模型正面 |
+//! |------|------|----|---------|
+//! | AnvilKit / Unity | 左手 | `+Y` | `+Z` |
+//! | glTF / Maya / USD（默认） | 右手 | `+Y` | `+Z` |
+//! | Blender / 3ds Max / USD（`upAxis = "Z"`） | 右手 | `+Z` | `-Y` |
+//! | Unreal | 左手 | `+Z` | `+X` |
+//!
+//! [`CoordinateSystem`] 用「上方 + 正面 + 手性」描述一个约定，
+//! [`CoordinateSystem::conversion_to`] 给出把向量从一个约定映射到另一个约定的正交矩阵：
+//! 上方映射到上方、正面映射到正面，右方按手性推出。手性不同时矩阵行列式为 `-1`，
+//! 此时网格的三角形环绕方向需要翻转（见 [`CoordinateSystem::flips_winding`]）。
+//!
+//! ```rust
+//! use anvilkit_core::math::{CoordinateSystem, Transform};
+//! use glam::Vec3;
+//!
+//! // Blender 中沿 -Y（正面）移动 2 个单位、向上 1 个单位
+//! let blender = Transform::from_translation(Vec3::new(0.0, -2.0, 1.0));
+//! let engine = CoordinateSystem::BLENDER.convert_transform(&blender, CoordinateSystem::ANVILKIT);
+//! assert!((engine.translation - Vec3::new(0.0, 1.0, 2.0)).length() < 1e-6);
+//! ```
+
+use glam::{Mat3, Mat4, Quat, Vec3};
+
+use super::transform::Transform;
+
+/// 坐标系手性
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Handedness {
+    /// 左手系：`right = up × forward`
+    Left,
+    /// 右手系：`right = forward × up`
+    Right,
+}
+
+/// 带符号的坐标轴
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    /// `+X`
+    PosX,
+    /// `-X`
+    NegX,
+    /// `+Y`
+    PosY,
+    /// `-Y`
+    NegY,
+    /// `+Z`
+    PosZ,
+    /// `-Z`
+    NegZ,
+}
+
+impl Axis {
+    /// 单位向量
+    pub fn to_vec3(self) -> Vec3 {
+        match self {
+            Self::PosX => Vec3::X,
+            Self::NegX => Vec3::NEG_X,
+            Self::PosY => Vec3::Y,
+            Self::NegY => Vec3::NEG_Y,
+            Self::PosZ => Vec3::Z,
+            Self::NegZ => Vec3::NEG_Z,
+        }
+    }
+}
+
+/// 坐标系约定：上方、模型正面与手性
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoordinateSystem {
+    /// 上方轴
+    pub up: Axis,
+    /// 模型正面朝向的轴
+    pub forward: Axis,
+    /// 手性
+    pub handedness: Handedness,
+}
+
+impl CoordinateSystem {
+    /// AnvilKit 引擎坐标系（左手，Y 上，+Z 前）
+    pub const ANVILKIT: Self = Self { up: Axis::PosY, forward: Axis::PosZ, handedness: Handedness::Left };
+    /// glTF 2.0（右手，Y 上，模型正面 +Z）
+    pub const GLTF: Self = Self { up: Axis::PosY, forward: Axis::PosZ, handedness: Handedness::Right };
+    /// USD 默认 `upAxis = "Y"`（与 glTF 相同）
+    pub const USD_Y_UP: Self = Self::GLTF;
+    /// USD `upAxis = "Z"`（与 Blender 相同）
+    pub const USD_Z_UP: Self = Self::BLENDER;
+    /// Blender（右手，Z 上，模型正面 -Y）
+    pub const BLENDER: Self = Self { up: Axis::PosZ, forward: Axis::NegY, handedness: Handedness::Right };
+    /// 3ds Max（右手，Z 上，模型正面 -Y）
+    pub const MAX_3DS: Self = Self::BLENDER;
+    /// Maya（右手，Y 上，模型正面 +Z）
+    pub const MAYA: Self = Self::GLTF;
+    /// Unity（左手，Y 上，+Z 前）
+    pub const UNITY: Self = Self::ANVILKIT;
+    /// Unreal（左手，Z 上，+X 前）
+    pub const UNREAL: Self = Self { up: Axis::PosZ, forward: Axis::PosX, handedness: Handedness::Left };
+
+    /// 右方轴（由上方、正面与手性推出）
+    pub fn right(&self) -> Vec3 {
+        let (up, forward) = (self.up.to_vec3(), self.forward.to_vec3());
+        match self.handedness {
+            Handedness::Left => up.cross(forward),
+            Handedness::Right => forward.cross(up),
+        }
+    }
+
+    /// 以 `(right, up, forward)` 为列的基矩阵
+    ///
+    /// 上方与正面共线时矩阵退化。
+    pub fn basis(&self) -> Mat3 {
+        Mat3::from_cols(self.right(), self.up.to_vec3(), self.forward.to_vec3())
+    }
+
+    /// 把本约定下的向量映射到 `target` 约定的正交矩阵
+    pub fn conversion_to(&self, target: CoordinateSystem) -> Mat3 {
+        target.basis() * self.basis().transpose()
+    }
+
+    /// 转换到 `target` 时三角形环绕方向是否需要翻转（手性不同）
+    pub fn flips_winding(&self, target: CoordinateSystem) -> bool {
+        self.handedness != target.handedness
+    }
+
+    /// 转换点或方向向量
+    pub fn convert_vec3(&self, v: Vec3, target: CoordinateSystem) -> Vec3 {
+        self.conversion_to(target) * v
+    }
+
+    /// 转换旋转：`M · R · Mᵀ`，跨手性时仍是合法旋转
+    pub fn convert_rotation(&self, rotation: Quat, target: CoordinateSystem) -> Quat {
+        let m = self.conversion_to(target);
+        Quat::from_mat3(&(m * Mat3::from_quat(rotation) * m.transpose())).normalize()
+    }
+
+    /// 转换缩放（轴置换，符号保持不变）
+    pub fn convert_scale(&self, scale: Vec3, target: CoordinateSystem) -> Vec3 {
+        let m = self.conversion_to(target);
+        Mat3::from_cols(m.x_axis.abs(), m.y_axis.abs(), m.z_axis.abs()) * scale
+    }
+
+    /// 转换变换组件
+    pub fn convert_transform(&self, transform: &Transform, target: CoordinateSystem) -> Transform {
+        Transform::new(
+            self.convert_vec3(transform.translation, target),
+            self.convert_rotation(transform.rotation, target),
+            self.convert_scale(transform.scale, target),
+        )
+    }
+
+    /// 转换 4x4 仿射矩阵：`M · A · M⁻¹`
+    pub fn convert_matrix(&self, matrix: Mat4, target: CoordinateSystem) -> Mat4 {
+        let m = Mat4::from_mat3(self.conversion_to(target));
+        m * matrix * m.transpose()
+    }
+}
+
+impl Default for CoordinateSystem {
+    fn default() -> Self {
+        Self::ANVILKIT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [CoordinateSystem; 4] = [
+        CoordinateSystem::ANVILKIT,
+        CoordinateSystem::GLTF,
+        CoordinateSystem::BLENDER,
+        CoordinateSystem::UNREAL,
+    ];
+
+    #[test]
+    fn test_basis_handedness() {
+        for system in ALL {
+            let det = system.basis().determinant();
+            let expected = if system.handedness == Handedness::Left { 1.0 } else { -1.0 };
+            assert!((det - expected).abs() < 1e-6, "{system:?}");
+        }
+        assert_eq!(CoordinateSystem::GLTF.right(), Vec3::NEG_X);
+        assert_eq!(CoordinateSystem::UNREAL.right(), Vec3::Y);
+    }
+
+    #[test]
+    fn test_conversion_maps_up_and_forward() {
+        for from in ALL {
+            for to in ALL {
+                let m = from.conversion_to(to);
+                assert!((m * from.up.to_vec3() - to.up.to_vec3()).length() < 1e-6);
+                assert!((m * from.forward.to_vec3() - to.forward.to_vec3()).length() < 1e-6);
+                assert!((m * from.right() - to.right()).length() < 1e-6);
+                assert_eq!(from.flips_winding(to), m.determinant() < 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_transform_roundtrip_matches_matrix() {
+        let transform = Transform::new(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::from_euler(glam::EulerRot::XYZ, 0.3, -0.7, 1.1),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        let from = CoordinateSystem::BLENDER;
+        let to = CoordinateSystem::ANVILKIT;
+        let converted = from.convert_transform(&transform, to);
+        let expected = from.convert_matrix(transform.compute_matrix(), to);
+        assert!(converted.compute_matrix().abs_diff_eq(expected, 1e-5));
+
+        let back = to.convert_transform(&converted, from);
+        assert!(back.compute_matrix().abs_diff_eq(transform.compute_matrix(), 1e-5));
+    }
+
+    #[test]
+    fn test_gltf_to_engine_keeps_facing() {
+        // glTF 模型正面 +Z，在引擎中仍朝 +Z；模型右侧 (-X) 变为引擎右方 (+X)
+        let to = CoordinateSystem::ANVILKIT;
+        assert_eq!(CoordinateSystem::GLTF.convert_vec3(Vec3::Z, to), Vec3::Z);
+        assert_eq!(CoordinateSystem::GLTF.convert_vec3(Vec3::NEG_X, to), Vec3::X);
+        assert!(CoordinateSystem::GLTF.flips_winding(to));
+    }
+}
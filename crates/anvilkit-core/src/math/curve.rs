@@ -0,0 +1,168 @@
+//! # 关键帧曲线
+//!
+//! [`Curve<T>`] 是按时间排序的关键帧序列，对任意实现 [`Lerp`] 的值类型采样。
+//! 粒子生命周期等时间驱动的参数曲线共用这一数据结构。
+//!
+//! ```rust
+//! use anvilkit_core::math::curve::{Curve, CurveInterpolation};
+//! use glam::Vec3;
+//!
+//! let curve = Curve::new(CurveInterpolation::Linear)
+//!     .with_key(0.0, Vec3::ZERO)
+//!     .with_key(2.0, Vec3::new(4.0, 0.0, 0.0));
+//! assert_eq!(curve.duration(), 2.0);
+//! assert_eq!(curve.sample(0.5), Some(Vec3::new(1.0, 0.0, 0.0)));
+//! ```
+
+use super::lerp::Lerp;
+
+/// 关键帧之间的插值方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CurveInterpolation {
+    /// 保持前一关键帧的值直到下一关键帧
+    Step,
+    /// 线性插值（四元数为球面插值）
+    #[default]
+    Linear,
+    /// 零切线 Hermite（关键帧处速度为 0，缓入缓出）
+    Smooth,
+}
+
+/// 单个关键帧
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CurveKey<T> {
+    /// 时间（秒）
+    pub time: f32,
+    /// 值
+    pub value: T,
+}
+
+/// 关键帧曲线
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Curve<T> {
+    keys: Vec<CurveKey<T>>,
+    /// 插值方式
+    pub interpolation: CurveInterpolation,
+}
+
+impl<T> Default for Curve<T> {
+    fn default() -> Self {
+        Self { keys: Vec::new(), interpolation: CurveInterpolation::default() }
+    }
+}
+
+impl<T: Lerp + Clone> Curve<T> {
+    /// 创建空曲线
+    pub fn new(interpolation: CurveInterpolation) -> Self {
+        Self { keys: Vec::new(), interpolation }
+    }
+
+    /// 从 `(time, value)` 列表创建，自动按时间排序
+    pub fn from_keys(interpolation: CurveInterpolation, keys: impl IntoIterator<Item = (f32, T)>) -> Self {
+        let mut curve = Self::new(interpolation);
+        for (time, value) in keys {
+            curve.insert(time, value);
+        }
+        curve
+    }
+
+    /// 添加关键帧（链式调用）
+    pub fn with_key(mut self, time: f32, value: T) -> Self {
+        self.insert(time, value);
+        self
+    }
+
+    /// 插入关键帧，保持时间有序；同一时间的关键帧被替换
+    pub fn insert(&mut self, time: f32, value: T) {
+        match self.keys.binary_search_by(|k| k.time.total_cmp(&time)) {
+            Ok(index) => self.keys[index].value = value,
+            Err(index) => self.keys.insert(index, CurveKey { time, value }),
+        }
+    }
+
+    /// 所有关键帧（按时间排序）
+    pub fn keys(&self) -> &[CurveKey<T>] {
+        &self.keys
+    }
+
+    /// 是否没有关键帧
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// 最后一个关键帧的时间
+    pub fn duration(&self) -> f32 {
+        self.keys.last().map_or(0.0, |k| k.time)
+    }
+
+    /// 在 `time` 处采样；超出范围时钳制到首尾关键帧，无关键帧时返回 `None`
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let first = self.keys.first()?;
+        let last = self.keys.last()?;
+        if time <= first.time {
+            return Some(first.value.clone());
+        }
+        if time >= last.time {
+            return Some(last.value.clone());
+        }
+
+        // 第一个时间大于 time 的关键帧，前一个即区间起点
+        let next = self.keys.partition_point(|k| k.time <= time);
+        let a = &self.keys[next - 1];
+        let b = &self.keys[next];
+        let t = (time - a.time) / (b.time - a.time);
+        Some(match self.interpolation {
+            CurveInterpolation::Step => a.value.clone(),
+            CurveInterpolation::Linear => a.value.lerp(&b.value, t),
+            CurveInterpolation::Smooth => a.value.lerp(&b.value, t * t * (3.0 - 2.0 * t)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Quat;
+
+    #[test]
+    fn test_curve_insert_keeps_order() {
+        let curve = Curve::new(CurveInterpolation::Linear)
+            .with_key(2.0, 20.0_f32)
+            .with_key(0.0, 0.0)
+            .with_key(1.0, 10.0)
+            .with_key(1.0, 5.0);
+        let times: Vec<f32> = curve.keys().iter().map(|k| k.time).collect();
+        assert_eq!(times, vec![0.0, 1.0, 2.0]);
+        assert_eq!(curve.sample(1.0), Some(5.0));
+    }
+
+    #[test]
+    fn test_curve_sample_modes() {
+        let keys = [(0.0, 0.0_f32), (1.0, 1.0)];
+        let linear = Curve::from_keys(CurveInterpolation::Linear, keys);
+        let step = Curve::from_keys(CurveInterpolation::Step, keys);
+        let smooth = Curve::from_keys(CurveInterpolation::Smooth, keys);
+
+        assert_eq!(linear.sample(0.25), Some(0.25));
+        assert_eq!(step.sample(0.75), Some(0.0));
+        assert_eq!(smooth.sample(0.5), Some(0.5));
+        assert!(smooth.sample(0.25).unwrap() < 0.25);
+
+        // 超出范围钳制
+        assert_eq!(linear.sample(-1.0), Some(0.0));
+        assert_eq!(linear.sample(5.0), Some(1.0));
+        assert_eq!(Curve::<f32>::default().sample(0.0), None);
+    }
+
+    #[test]
+    fn test_curve_quat_slerp() {
+        let curve = Curve::new(CurveInterpolation::Linear)
+            .with_key(0.0, Quat::IDENTITY)
+            .with_key(1.0, Quat::from_rotation_y(std::f32::consts::FRAC_PI_2));
+        let mid = curve.sample(0.5).unwrap();
+        assert!(mid.angle_between(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)) < 1e-5);
+    }
+}
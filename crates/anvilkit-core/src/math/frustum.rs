@@ -2,6 +2,8 @@
 
 use glam::{Mat4, Vec3, Vec4};
 
+use super::aabb::Aabb;
+
 /// 视锥体 (6 个裁剪平面)
 ///
 /// 从 view-projection 矩阵提取，用于快速剔除不可见物体。
@@ -64,6 +66,25 @@ impl Frustum {
         }
         true
     }
+
+    /// 测试 [`Aabb`] 是否与视锥体相交（AABB 与视锥体处于同一空间）
+    pub fn intersects_bounds(&self, aabb: &Aabb) -> bool {
+        self.intersects_aabb(aabb.center(), aabb.half_extents())
+    }
+
+    /// 测试包围球是否与视锥体相交
+    ///
+    /// 球心到任一平面的有符号距离小于 `-radius` 时完全在外侧。
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes.iter().all(|plane| {
+            Vec3::new(plane.x, plane.y, plane.z).dot(center) + plane.w >= -radius
+        })
+    }
+
+    /// 测试点是否位于视锥体内部（含边界）
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.intersects_sphere(point, 0.0)
+    }
 }
 
 #[cfg(test)]
@@ -79,4 +100,22 @@ mod tests {
         assert!(frustum.intersects_aabb(Vec3::ZERO, Vec3::splat(0.5)));
         assert!(!frustum.intersects_aabb(Vec3::new(0.0, 0.0, -100.0), Vec3::splat(0.5)));
     }
+
+    #[test]
+    fn test_frustum_sphere_and_bounds() {
+        let view = Mat4::look_at_lh(Vec3::new(0.0, 0.0, -5.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 100.0);
+        let frustum = Frustum::from_view_proj(&(proj * view));
+
+        assert!(frustum.contains_point(Vec3::ZERO));
+        assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -6.0)));
+        assert!(frustum.intersects_sphere(Vec3::new(0.0, 0.0, -6.0), 2.0));
+        // 视锥体右侧远处的球体
+        assert!(!frustum.intersects_sphere(Vec3::new(50.0, 0.0, 0.0), 1.0));
+        assert!(frustum.intersects_sphere(Vec3::new(50.0, 0.0, 0.0), 100.0));
+
+        let inside = Aabb::from_min_max(Vec3::splat(-1.0), Vec3::splat(1.0));
+        assert!(frustum.intersects_bounds(&inside));
+        assert!(!frustum.intersects_bounds(&inside.translated(Vec3::new(0.0, 200.0, 0.0))));
+    }
 }
@@ -0,0 +1,550 @@
+//! # 几何图元
+//!
+//! 碰撞检测与空间查询使用的基础图元：[`Ray`]、[`Plane`]、[`Sphere`]、[`LineSegment`]、
+//! [`Capsule`] 与 [`OrientedBox`]，提供包含、相交与距离计算。
+//!
+//! 所有图元都实现 [`Transformable`]，可由 [`GlobalTransform`]（或局部 [`Transform`]）
+//! 从局部空间变换到世界空间。球体与胶囊体的半径按最大缩放分量放大，非均匀缩放会得到保守的包围体。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_core::math::geometry::{Capsule, Ray, Sphere, Transformable};
+//! use anvilkit_core::math::Transform;
+//! use glam::Vec3;
+//!
+//! let ball = Sphere::new(Vec3::ZERO, 1.0).transformed_by_local(&Transform::from_xyz(0.0, 0.0, 5.0));
+//! let ray = Ray::new(Vec3::ZERO, Vec3::Z);
+//! assert_eq!(ray.intersect_sphere(&ball), Some(4.0));
+//!
+//! let player = Capsule::new(Vec3::ZERO, Vec3::new(0.0, 2.0, 0.0), 0.5);
+//! assert!(player.intersects_sphere(&Sphere::new(Vec3::new(0.9, 1.0, 0.0), 0.5)));
+//! ```
+
+use glam::{Mat3, Quat, Vec3};
+
+use super::aabb::Aabb;
+use super::raycast::{ray_aabb_intersection, ray_sphere_intersection};
+use super::transform::{GlobalTransform, Transform};
+
+/// 可被变换到另一空间的几何图元
+pub trait Transformable: Sized {
+    /// 以世界变换矩阵变换
+    fn transformed(&self, transform: &GlobalTransform) -> Self;
+
+    /// 以局部 [`Transform`] 变换
+    fn transformed_by_local(&self, transform: &Transform) -> Self {
+        self.transformed(&GlobalTransform::from_transform(transform))
+    }
+}
+
+/// 最大缩放分量（用于半径）
+fn max_scale(transform: &GlobalTransform) -> f32 {
+    transform.scale().abs().max_element()
+}
+
+// ---------------------------------------------------------------------------
+//  Ray
+// ---------------------------------------------------------------------------
+
+/// 射线
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    /// 起点
+    pub origin: Vec3,
+    /// 归一化方向
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// 创建射线（方向会被归一化）
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction: direction.normalize_or_zero() }
+    }
+
+    /// 参数 `t` 处的点
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// 射线上距 `point` 最近的点
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        self.at((point - self.origin).dot(self.direction).max(0.0))
+    }
+
+    /// 与平面求交，返回参数 `t`
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denom = plane.normal.dot(self.direction);
+        if denom.abs() < 1e-7 {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// 与球体求交，返回最近交点参数 `t`
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        ray_sphere_intersection(self.origin, self.direction, sphere.center, sphere.radius)
+    }
+
+    /// 与 AABB 求交，返回最近交点参数 `t`
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        ray_aabb_intersection(self.origin, self.direction, aabb)
+    }
+
+    /// 与有向包围盒求交，返回最近交点参数 `t`
+    pub fn intersect_obb(&self, obb: &OrientedBox) -> Option<f32> {
+        // 变换到盒子局部空间后按 AABB 测试（旋转不改变 t）
+        let inv = obb.rotation.inverse();
+        let local = Ray { origin: inv * (self.origin - obb.center), direction: inv * self.direction };
+        local.intersect_aabb(&Aabb::from_min_max(-obb.half_extents, obb.half_extents))
+    }
+}
+
+impl Transformable for Ray {
+    fn transformed(&self, transform: &GlobalTransform) -> Self {
+        Ray::new(transform.transform_point(self.origin), transform.transform_vector(self.direction))
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  Plane
+// ---------------------------------------------------------------------------
+
+/// 平面：`normal · p + d = 0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane {
+    /// 归一化法线
+    pub normal: Vec3,
+    /// 原点到平面的有符号距离的相反数
+    pub d: f32,
+}
+
+impl Plane {
+    /// 由法线与平面上一点创建
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        let normal = normal.normalize_or_zero();
+        Self { normal, d: -normal.dot(point) }
+    }
+
+    /// 由三点创建（逆时针方向的一侧为正面）
+    ///
+    /// 三点共线时返回 `None`。
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Option<Self> {
+        let normal = (b - a).cross(c - a);
+        (normal.length_squared() > 1e-12).then(|| Self::from_point_normal(a, normal))
+    }
+
+    /// 点到平面的有符号距离（法线一侧为正）
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// 点在平面上的投影
+    pub fn project_point(&self, point: Vec3) -> Vec3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// 球体是否与平面相交
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.signed_distance(sphere.center).abs() <= sphere.radius
+    }
+}
+
+impl Transformable for Plane {
+    fn transformed(&self, transform: &GlobalTransform) -> Self {
+        let point = transform.transform_point(-self.normal * self.d);
+        // 法线按逆转置矩阵变换，保证非均匀缩放下仍垂直于平面
+        let normal_matrix = Mat3::from_mat4(transform.matrix()).inverse().transpose();
+        Self::from_point_normal(point, normal_matrix * self.normal)
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  Sphere
+// ---------------------------------------------------------------------------
+
+/// 球体
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere {
+    /// 球心
+    pub center: Vec3,
+    /// 半径
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// 创建球体
+    pub fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// 包含 AABB 的最小外接球
+    pub fn from_aabb(aabb: &Aabb) -> Self {
+        Self { center: aabb.center(), radius: aabb.half_extents().length() }
+    }
+
+    /// 点是否在球内（含边界）
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// 点到球面的距离（球内为 0）
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        (self.center.distance(point) - self.radius).max(0.0)
+    }
+
+    /// 与另一个球体是否相交
+    pub fn intersects_sphere(&self, other: &Sphere) -> bool {
+        let r = self.radius + other.radius;
+        self.center.distance_squared(other.center) <= r * r
+    }
+
+    /// 与 AABB 是否相交
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.contains_point(self.center.clamp(aabb.min, aabb.max))
+    }
+
+    /// 外接 AABB
+    pub fn aabb(&self) -> Aabb {
+        Aabb::from_min_max(self.center - Vec3::splat(self.radius), self.center + Vec3::splat(self.radius))
+    }
+}
+
+impl Transformable for Sphere {
+    fn transformed(&self, transform: &GlobalTransform) -> Self {
+        Self::new(transform.transform_point(self.center), self.radius * max_scale(transform))
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  LineSegment
+// ---------------------------------------------------------------------------
+
+/// 线段
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineSegment {
+    /// 起点
+    pub start: Vec3,
+    /// 终点
+    pub end: Vec3,
+}
+
+impl LineSegment {
+    /// 创建线段
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Self { start, end }
+    }
+
+    /// 长度
+    pub fn length(&self) -> f32 {
+        self.start.distance(self.end)
+    }
+
+    /// 线段上距 `point` 最近的点
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let ab = self.end - self.start;
+        let len_sq = ab.length_squared();
+        if len_sq < 1e-12 {
+            return self.start;
+        }
+        let t = ((point - self.start).dot(ab) / len_sq).clamp(0.0, 1.0);
+        self.start + ab * t
+    }
+
+    /// 点到线段的距离
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// 两条线段之间最近的一对点 `(self 上的点, other 上的点)`
+    pub fn closest_points(&self, other: &LineSegment) -> (Vec3, Vec3) {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let r = self.start - other.start;
+        let a = d1.length_squared();
+        let e = d2.length_squared();
+        let f = d2.dot(r);
+
+        let (s, t) = if a < 1e-12 && e < 1e-12 {
+            (0.0, 0.0)
+        } else if a < 1e-12 {
+            (0.0, (f / e).clamp(0.0, 1.0))
+        } else {
+            let c = d1.dot(r);
+            if e < 1e-12 {
+                ((-c / a).clamp(0.0, 1.0), 0.0)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                let s = if denom > 1e-12 { ((b * f - c * e) / denom).clamp(0.0, 1.0) } else { 0.0 };
+                let t = (b * s + f) / e;
+                if t < 0.0 {
+                    ((-c / a).clamp(0.0, 1.0), 0.0)
+                } else if t > 1.0 {
+                    (((b - c) / a).clamp(0.0, 1.0), 1.0)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.start + d1 * s, other.start + d2 * t)
+    }
+
+    /// 两条线段之间的最短距离
+    pub fn distance_to_segment(&self, other: &LineSegment) -> f32 {
+        let (p, q) = self.closest_points(other);
+        p.distance(q)
+    }
+}
+
+impl Transformable for LineSegment {
+    fn transformed(&self, transform: &GlobalTransform) -> Self {
+        Self::new(transform.transform_point(self.start), transform.transform_point(self.end))
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  Capsule
+// ---------------------------------------------------------------------------
+
+/// 胶囊体：线段沿半径膨胀
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule {
+    /// 中轴线段
+    pub segment: LineSegment,
+    /// 半径
+    pub radius: f32,
+}
+
+impl Capsule {
+    /// 由中轴两端点与半径创建
+    pub fn new(a: Vec3, b: Vec3, radius: f32) -> Self {
+        Self { segment: LineSegment::new(a, b), radius }
+    }
+
+    /// 点是否在胶囊体内（含边界）
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.segment.closest_point(point).distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// 点到胶囊体表面的距离（内部为 0）
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        (self.segment.distance_to_point(point) - self.radius).max(0.0)
+    }
+
+    /// 与球体是否相交
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        let r = self.radius + sphere.radius;
+        self.segment.closest_point(sphere.center).distance_squared(sphere.center) <= r * r
+    }
+
+    /// 与另一个胶囊体是否相交
+    pub fn intersects_capsule(&self, other: &Capsule) -> bool {
+        self.segment.distance_to_segment(&other.segment) <= self.radius + other.radius
+    }
+
+    /// 外接 AABB
+    pub fn aabb(&self) -> Aabb {
+        let r = Vec3::splat(self.radius);
+        Aabb::from_min_max(
+            self.segment.start.min(self.segment.end) - r,
+            self.segment.start.max(self.segment.end) + r,
+        )
+    }
+}
+
+impl Transformable for Capsule {
+    fn transformed(&self, transform: &GlobalTransform) -> Self {
+        Self { segment: self.segment.transformed(transform), radius: self.radius * max_scale(transform) }
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  OrientedBox
+// ---------------------------------------------------------------------------
+
+/// 有向包围盒 (OBB)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientedBox {
+    /// 中心
+    pub center: Vec3,
+    /// 局部轴向半尺寸
+    pub half_extents: Vec3,
+    /// 朝向
+    pub rotation: Quat,
+}
+
+impl OrientedBox {
+    /// 创建有向包围盒
+    pub fn new(center: Vec3, half_extents: Vec3, rotation: Quat) -> Self {
+        Self { center, half_extents, rotation }
+    }
+
+    /// 由局部 AABB 与世界变换创建（保留旋转，比 [`Aabb::transformed`] 更紧）
+    pub fn from_aabb(aabb: &Aabb, transform: &GlobalTransform) -> Self {
+        Self {
+            center: transform.transform_point(aabb.center()),
+            half_extents: aabb.half_extents() * transform.scale().abs(),
+            rotation: transform.rotation(),
+        }
+    }
+
+    /// 三条局部轴（世界空间单位向量）
+    pub fn axes(&self) -> [Vec3; 3] {
+        [self.rotation * Vec3::X, self.rotation * Vec3::Y, self.rotation * Vec3::Z]
+    }
+
+    /// 盒内（含边界）距 `point` 最近的点
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        let local = self.rotation.inverse() * (point - self.center);
+        self.center + self.rotation * local.clamp(-self.half_extents, self.half_extents)
+    }
+
+    /// 点是否在盒内（含边界）
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let local = self.rotation.inverse() * (point - self.center);
+        local.abs().cmple(self.half_extents + Vec3::splat(1e-6)).all()
+    }
+
+    /// 点到盒子的距离（内部为 0）
+    pub fn distance_to_point(&self, point: Vec3) -> f32 {
+        self.closest_point(point).distance(point)
+    }
+
+    /// 与球体是否相交
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        sphere.contains_point(self.closest_point(sphere.center))
+    }
+
+    /// 与另一个 OBB 是否相交（分离轴定理，15 条候选轴）
+    pub fn intersects_obb(&self, other: &OrientedBox) -> bool {
+        let a = self.axes();
+        let b = other.axes();
+        let offset = other.center - self.center;
+
+        let separated = |axis: Vec3| {
+            if axis.length_squared() < 1e-10 {
+                // 平行边的叉积退化，不能作为分离轴
+                return false;
+            }
+            let project = |axes: &[Vec3; 3], half: Vec3| {
+                half.x * axes[0].dot(axis).abs() + half.y * axes[1].dot(axis).abs() + half.z * axes[2].dot(axis).abs()
+            };
+            offset.dot(axis).abs() > project(&a, self.half_extents) + project(&b, other.half_extents)
+        };
+
+        if a.iter().chain(b.iter()).any(|&axis| separated(axis)) {
+            return false;
+        }
+        !a.iter().any(|&ai| b.iter().any(|&bj| separated(ai.cross(bj))))
+    }
+
+    /// 外接 AABB
+    pub fn aabb(&self) -> Aabb {
+        let local = Aabb::from_min_max(-self.half_extents, self.half_extents);
+        local.transformed(&glam::Mat4::from_rotation_translation(self.rotation, self.center))
+    }
+}
+
+impl Transformable for OrientedBox {
+    fn transformed(&self, transform: &GlobalTransform) -> Self {
+        Self {
+            center: transform.transform_point(self.center),
+            half_extents: self.half_extents * transform.scale().abs(),
+            rotation: (transform.rotation() * self.rotation).normalize(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f32 = 1e-4;
+
+    #[test]
+    fn test_ray_plane_and_obb() {
+        let ground = Plane::from_point_normal(Vec3::ZERO, Vec3::Y);
+        let ray = Ray::new(Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Y);
+        assert!((ray.intersect_plane(&ground).unwrap() - 10.0).abs() < EPS);
+        assert!(Ray::new(Vec3::Y, Vec3::Y).intersect_plane(&ground).is_none());
+
+        // 绕 Y 旋转 45° 的单位盒：沿 X 轴的射线在 √2/2 处命中角
+        let obb = OrientedBox::new(Vec3::ZERO, Vec3::splat(0.5), Quat::from_rotation_y(std::f32::consts::FRAC_PI_4));
+        let t = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::X).intersect_obb(&obb).unwrap();
+        assert!((t - (5.0 - std::f32::consts::FRAC_1_SQRT_2)).abs() < EPS);
+    }
+
+    #[test]
+    fn test_plane_from_points_and_projection() {
+        let plane = Plane::from_points(Vec3::ZERO, Vec3::Z, Vec3::X).unwrap();
+        assert!((plane.normal - Vec3::Y).length() < EPS);
+        assert!((plane.signed_distance(Vec3::new(3.0, 2.0, 1.0)) - 2.0).abs() < EPS);
+        assert!((plane.project_point(Vec3::new(3.0, 2.0, 1.0)) - Vec3::new(3.0, 0.0, 1.0)).length() < EPS);
+        assert!(Plane::from_points(Vec3::ZERO, Vec3::X, Vec3::X * 2.0).is_none());
+    }
+
+    #[test]
+    fn test_sphere_queries() {
+        let s = Sphere::new(Vec3::ZERO, 1.0);
+        assert!(s.contains_point(Vec3::new(0.5, 0.5, 0.0)));
+        assert!((s.distance_to_point(Vec3::new(3.0, 0.0, 0.0)) - 2.0).abs() < EPS);
+        assert!(s.intersects_sphere(&Sphere::new(Vec3::new(1.9, 0.0, 0.0), 1.0)));
+        assert!(!s.intersects_aabb(&Aabb::from_min_max(Vec3::splat(0.8), Vec3::splat(2.0))));
+        assert!(s.intersects_aabb(&Aabb::from_min_max(Vec3::new(0.5, -1.0, -1.0), Vec3::splat(2.0))));
+    }
+
+    #[test]
+    fn test_segment_closest_points() {
+        let a = LineSegment::new(Vec3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        let b = LineSegment::new(Vec3::new(0.0, 1.0, -1.0), Vec3::new(0.0, 1.0, 1.0));
+        let (p, q) = a.closest_points(&b);
+        assert!(p.length() < EPS);
+        assert!((q - Vec3::Y).length() < EPS);
+        assert!((a.distance_to_segment(&b) - 1.0).abs() < EPS);
+
+        // 平行线段
+        let c = LineSegment::new(Vec3::new(2.0, 2.0, 0.0), Vec3::new(4.0, 2.0, 0.0));
+        assert!((a.distance_to_segment(&c) - 5.0_f32.sqrt()).abs() < EPS);
+    }
+
+    #[test]
+    fn test_capsule_queries() {
+        let c = Capsule::new(Vec3::ZERO, Vec3::new(0.0, 2.0, 0.0), 0.5);
+        assert!(c.contains_point(Vec3::new(0.4, 1.0, 0.0)));
+        assert!(!c.contains_point(Vec3::new(0.0, 2.6, 0.0)));
+        assert!((c.distance_to_point(Vec3::new(2.0, 1.0, 0.0)) - 1.5).abs() < EPS);
+        let other = Capsule::new(Vec3::new(0.9, 0.0, 0.0), Vec3::new(0.9, 2.0, 0.0), 0.5);
+        assert!(c.intersects_capsule(&other));
+        assert_eq!(c.aabb().max, Vec3::new(0.5, 2.5, 0.5));
+    }
+
+    #[test]
+    fn test_obb_sat() {
+        let a = OrientedBox::new(Vec3::ZERO, Vec3::splat(1.0), Quat::IDENTITY);
+        let rotated = Quat::from_rotation_z(std::f32::consts::FRAC_PI_4);
+        // 旋转 45° 的盒子角点伸出 √2，在 x=2.3 处仍与 a 相交
+        assert!(a.intersects_obb(&OrientedBox::new(Vec3::new(2.3, 0.0, 0.0), Vec3::splat(1.0), rotated)));
+        assert!(!a.intersects_obb(&OrientedBox::new(Vec3::new(2.5, 0.0, 0.0), Vec3::splat(1.0), rotated)));
+        assert!(a.contains_point(Vec3::splat(0.99)));
+        assert!(a.intersects_sphere(&Sphere::new(Vec3::new(1.5, 0.0, 0.0), 0.6)));
+        assert!((a.distance_to_point(Vec3::new(3.0, 0.0, 0.0)) - 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let t = Transform::new(Vec3::new(1.0, 2.0, 3.0), Quat::from_rotation_y(std::f32::consts::FRAC_PI_2), Vec3::splat(2.0));
+        let s = Sphere::new(Vec3::X, 1.0).transformed_by_local(&t);
+        assert!((s.center - Vec3::new(1.0, 2.0, 1.0)).length() < EPS);
+        assert!((s.radius - 2.0).abs() < EPS);
+
+        let plane = Plane::from_point_normal(Vec3::ZERO, Vec3::X).transformed_by_local(&t);
+        assert!((plane.normal - Vec3::NEG_Z).length() < EPS);
+        assert!(plane.signed_distance(Vec3::new(1.0, 2.0, 3.0)).abs() < EPS);
+
+        let obb = OrientedBox::new(Vec3::ZERO, Vec3::ONE, Quat::IDENTITY).transformed_by_local(&t);
+        assert!((obb.half_extents - Vec3::splat(2.0)).length() < EPS);
+        assert!(obb.contains_point(Vec3::new(2.9, 2.0, 3.0)));
+    }
+}
@@ -0,0 +1,236 @@
+//! # 缓动函数
+//!
+//! [`EaseFunction`] 把归一化进度 `t ∈ [0, 1]` 映射为插值权重，配合 [`Lerp`](super::Lerp)
+//! 实现补间、UI 过渡与相机运动。曲线定义与 easings.net 一致：`In` 起步慢、`Out` 收尾慢、
+//! `InOut` 两端都慢；`Back` / `Elastic` 的权重会短暂超出 `[0, 1]`。
+//!
+//! ```rust
+//! use anvilkit_core::math::interpolation::EaseFunction;
+//! use anvilkit_core::math::Lerp;
+//!
+//! let t = EaseFunction::QuadraticIn.ease(0.5);
+//! assert_eq!(t, 0.25);
+//! assert_eq!(0.0_f32.lerp(&100.0, t), 25.0);
+//! ```
+
+use std::f32::consts::{PI, TAU};
+
+/// 缓动函数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EaseFunction {
+    /// 匀速
+    #[default]
+    Linear,
+    /// `t²`
+    QuadraticIn,
+    /// `1 - (1 - t)²`
+    QuadraticOut,
+    /// 二次缓入缓出
+    QuadraticInOut,
+    /// `t³`
+    CubicIn,
+    /// `1 - (1 - t)³`
+    CubicOut,
+    /// 三次缓入缓出
+    CubicInOut,
+    /// `t⁴`
+    QuarticIn,
+    /// `1 - (1 - t)⁴`
+    QuarticOut,
+    /// 四次缓入缓出
+    QuarticInOut,
+    /// `t⁵`
+    QuinticIn,
+    /// `1 - (1 - t)⁵`
+    QuinticOut,
+    /// 五次缓入缓出
+    QuinticInOut,
+    /// 正弦缓入
+    SineIn,
+    /// 正弦缓出
+    SineOut,
+    /// 正弦缓入缓出
+    SineInOut,
+    /// 指数缓入
+    ExponentialIn,
+    /// 指数缓出
+    ExponentialOut,
+    /// 指数缓入缓出
+    ExponentialInOut,
+    /// 圆弧缓入
+    CircularIn,
+    /// 圆弧缓出
+    CircularOut,
+    /// 圆弧缓入缓出
+    CircularInOut,
+    /// 起步先回拉
+    BackIn,
+    /// 收尾越过终点再回弹
+    BackOut,
+    /// 两端回拉
+    BackInOut,
+    /// 弹簧振荡缓入
+    ElasticIn,
+    /// 弹簧振荡缓出
+    ElasticOut,
+    /// 弹簧振荡缓入缓出
+    ElasticInOut,
+    /// 弹跳缓入
+    BounceIn,
+    /// 落地弹跳缓出
+    BounceOut,
+    /// 弹跳缓入缓出
+    BounceInOut,
+    /// Hermite `3t² - 2t³`
+    SmoothStep,
+}
+
+const BACK_C1: f32 = 1.70158;
+const BACK_C2: f32 = BACK_C1 * 1.525;
+const BACK_C3: f32 = BACK_C1 + 1.0;
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+/// 由 `In` 曲线组合出 `InOut`：前半段加速、后半段镜像减速
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(2.0 * t) * 0.5
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) * 0.5
+    }
+}
+
+impl EaseFunction {
+    /// 计算 `t` 处的插值权重；`t` 先钳制到 `[0, 1]`，端点精确返回 0 与 1
+    pub fn ease(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+        let out = |f: fn(f32) -> f32| 1.0 - f(1.0 - t);
+        match self {
+            Self::Linear => t,
+            Self::QuadraticIn => t * t,
+            Self::QuadraticOut => out(|t| t * t),
+            Self::QuadraticInOut => in_out(t, |t| t * t),
+            Self::CubicIn => t.powi(3),
+            Self::CubicOut => out(|t| t.powi(3)),
+            Self::CubicInOut => in_out(t, |t| t.powi(3)),
+            Self::QuarticIn => t.powi(4),
+            Self::QuarticOut => out(|t| t.powi(4)),
+            Self::QuarticInOut => in_out(t, |t| t.powi(4)),
+            Self::QuinticIn => t.powi(5),
+            Self::QuinticOut => out(|t| t.powi(5)),
+            Self::QuinticInOut => in_out(t, |t| t.powi(5)),
+            Self::SineIn => 1.0 - (t * PI * 0.5).cos(),
+            Self::SineOut => (t * PI * 0.5).sin(),
+            Self::SineInOut => -((PI * t).cos() - 1.0) * 0.5,
+            Self::ExponentialIn => 2.0_f32.powf(10.0 * t - 10.0),
+            Self::ExponentialOut => 1.0 - 2.0_f32.powf(-10.0 * t),
+            Self::ExponentialInOut => in_out(t, |t| 2.0_f32.powf(10.0 * t - 10.0)),
+            Self::CircularIn => 1.0 - (1.0 - t * t).sqrt(),
+            Self::CircularOut => (1.0 - (t - 1.0).powi(2)).sqrt(),
+            Self::CircularInOut => in_out(t, |t| 1.0 - (1.0 - t * t).sqrt()),
+            Self::BackIn => BACK_C3 * t.powi(3) - BACK_C1 * t * t,
+            Self::BackOut => out(|t| BACK_C3 * t.powi(3) - BACK_C1 * t * t),
+            Self::BackInOut => in_out(t, |t| (BACK_C2 + 1.0) * t.powi(3) - BACK_C2 * t * t),
+            Self::ElasticIn => -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * TAU / 3.0).sin(),
+            Self::ElasticOut => 2.0_f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * TAU / 3.0).sin() + 1.0,
+            Self::ElasticInOut => in_out(t, |t| -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 11.125) * TAU / 4.5).sin()),
+            Self::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Self::BounceOut => bounce_out(t),
+            Self::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+            Self::SmoothStep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [EaseFunction; 32] = [
+        EaseFunction::Linear,
+        EaseFunction::QuadraticIn,
+        EaseFunction::QuadraticOut,
+        EaseFunction::QuadraticInOut,
+        EaseFunction::CubicIn,
+        EaseFunction::CubicOut,
+        EaseFunction::CubicInOut,
+        EaseFunction::QuarticIn,
+        EaseFunction::QuarticOut,
+        EaseFunction::QuarticInOut,
+        EaseFunction::QuinticIn,
+        EaseFunction::QuinticOut,
+        EaseFunction::QuinticInOut,
+        EaseFunction::SineIn,
+        EaseFunction::SineOut,
+        EaseFunction::SineInOut,
+        EaseFunction::ExponentialIn,
+        EaseFunction::ExponentialOut,
+        EaseFunction::ExponentialInOut,
+        EaseFunction::CircularIn,
+        EaseFunction::CircularOut,
+        EaseFunction::CircularInOut,
+        EaseFunction::BackIn,
+        EaseFunction::BackOut,
+        EaseFunction::BackInOut,
+        EaseFunction::ElasticIn,
+        EaseFunction::ElasticOut,
+        EaseFunction::ElasticInOut,
+        EaseFunction::BounceIn,
+        EaseFunction::BounceOut,
+        EaseFunction::BounceInOut,
+        EaseFunction::SmoothStep,
+    ];
+
+    #[test]
+    fn test_endpoints_and_clamping() {
+        for ease in ALL {
+            assert_eq!(ease.ease(0.0), 0.0, "{ease:?}");
+            assert_eq!(ease.ease(1.0), 1.0, "{ease:?}");
+            assert_eq!(ease.ease(-1.0), 0.0, "{ease:?}");
+            assert_eq!(ease.ease(2.0), 1.0, "{ease:?}");
+            // 开区间内部连续，接近端点时趋近端点值
+            assert!(ease.ease(1e-6).abs() < 0.01, "{ease:?}");
+            assert!((ease.ease(1.0 - 1e-6) - 1.0).abs() < 0.01, "{ease:?}");
+        }
+    }
+
+    #[test]
+    fn test_in_out_symmetry() {
+        for ease in ALL.iter().filter(|e| format!("{e:?}").ends_with("InOut")) {
+            assert!((ease.ease(0.5) - 0.5).abs() < 1e-5, "{ease:?}");
+            for t in [0.1, 0.25, 0.4] {
+                assert!((ease.ease(t) + ease.ease(1.0 - t) - 1.0).abs() < 1e-5, "{ease:?} at {t}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve_shapes() {
+        assert_eq!(EaseFunction::QuadraticIn.ease(0.5), 0.25);
+        assert_eq!(EaseFunction::QuadraticOut.ease(0.5), 0.75);
+        assert!(EaseFunction::CubicIn.ease(0.5) < EaseFunction::QuadraticIn.ease(0.5));
+        assert!(EaseFunction::BackIn.ease(0.2) < 0.0);
+        assert!(EaseFunction::BackOut.ease(0.8) > 1.0);
+        assert!((EaseFunction::BounceOut.ease(1.0 / 2.75) - 1.0).abs() < 1e-5);
+        assert_eq!(EaseFunction::SmoothStep.ease(0.5), 0.5);
+    }
+}
@@ -0,0 +1,66 @@
+//! 补间 lens：组件中可插值的字段
+//!
+//! [`TweenLens`] 描述补间或脚本动作驱动的字段（组件类型 + 可 [`Lerp`] 的值类型）。
+//! 渲染层的 `Tween<L>` 与玩法层的 `Action::MoveTo` 共用这些 lens。
+//!
+//! ```rust
+//! use anvilkit_core::math::lens::{TranslationLens, TweenLens};
+//! use anvilkit_core::math::Transform;
+//! use glam::Vec3;
+//!
+//! let mut transform = Transform::default();
+//! *TranslationLens::field(&mut transform) = Vec3::X;
+//! assert_eq!(transform.translation, Vec3::X);
+//! ```
+
+use bevy_ecs::component::Component;
+use glam::{Quat, Vec3};
+
+use super::{Lerp, Transform};
+
+/// 补间驱动的组件字段
+pub trait TweenLens: Send + Sync + 'static {
+    /// 字段所在的组件
+    type Component: Component;
+    /// 字段值类型
+    type Value: Lerp + Clone + Send + Sync + 'static;
+
+    /// 取字段的可变引用
+    fn field(component: &mut Self::Component) -> &mut Self::Value;
+}
+
+/// `Transform::translation`
+#[derive(Debug, Clone, Copy)]
+pub struct TranslationLens;
+
+impl TweenLens for TranslationLens {
+    type Component = Transform;
+    type Value = Vec3;
+    fn field(transform: &mut Transform) -> &mut Vec3 {
+        &mut transform.translation
+    }
+}
+
+/// `Transform::rotation`（球面插值）
+#[derive(Debug, Clone, Copy)]
+pub struct RotationLens;
+
+impl TweenLens for RotationLens {
+    type Component = Transform;
+    type Value = Quat;
+    fn field(transform: &mut Transform) -> &mut Quat {
+        &mut transform.rotation
+    }
+}
+
+/// `Transform::scale`
+#[derive(Debug, Clone, Copy)]
+pub struct ScaleLens;
+
+impl TweenLens for ScaleLens {
+    type Component = Transform;
+    type Value = Vec3;
+    fn field(transform: &mut Transform) -> &mut Vec3 {
+        &mut transform.scale
+    }
+}
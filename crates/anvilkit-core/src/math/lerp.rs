@@ -0,0 +1,59 @@
+//! 线性插值 trait
+
+use glam::{Quat, Vec2, Vec3, Vec4};
+
+/// 线性插值
+///
+/// `t = 0` 返回 `self`，`t = 1` 返回 `other`；`t` 不做钳制。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::Lerp;
+/// use glam::Vec3;
+///
+/// assert_eq!(0.0_f32.lerp(&10.0, 0.25), 2.5);
+/// assert_eq!(Lerp::lerp(&Vec3::ZERO, &Vec3::ONE, 0.5), Vec3::splat(0.5));
+/// ```
+pub trait Lerp {
+    /// 在 `self` 与 `other` 之间按 `t` 插值
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for Vec2 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec2::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec3 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec3::lerp(*self, *other, t)
+    }
+}
+
+impl Lerp for Vec4 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Vec4::lerp(*self, *other, t)
+    }
+}
+
+/// 逐分量插值（如 `[f32; 4]` 形式的 RGBA 颜色）
+impl<const N: usize> Lerp for [f32; N] {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        std::array::from_fn(|i| self[i].lerp(&other[i], t))
+    }
+}
+
+/// 四元数使用球面插值
+impl Lerp for Quat {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
@@ -5,19 +5,53 @@
 //! ## 模块组织
 //!
 //! - [`transform`]: 3D 变换和层次结构
+//! - [`transform2d`]: 无四元数的 2D 变换
 //! - [`aabb`]: Axis-aligned bounding boxes
+//! - [`bounds2d`]: 2D 轴对齐包围矩形
 //! - [`frustum`]: View frustum for culling
 //! - [`raycast`]: Ray casting
+//! - [`color`]: 线性 RGBA 颜色与 sRGB/HSV/HSL 转换
+//! - [`lerp`]: 线性插值 trait
+//! - [`interpolation`]: 缓动函数
+//! - [`curve`]: 关键帧曲线
+//! - `lens`: 补间 lens（组件中可插值的字段，需要 `bevy_ecs` 特性）
+//! - [`coordinates`]: 坐标系约定（手性 / 上方轴）与转换
+//! - [`morton`]: Morton (Z-order) encoding and spatial sorting
+//! - [`rect_packer`]: MaxRects 矩形装箱（图集分配）
+//! - [`geometry`]: 射线、平面、球体、胶囊体与 OBB 等碰撞图元
 
 pub mod transform;
+pub mod transform2d;
 pub mod aabb;
+pub mod bounds2d;
 pub mod frustum;
 pub mod raycast;
+pub mod color;
+pub mod lerp;
+pub mod interpolation;
+pub mod curve;
+#[cfg(feature = "bevy_ecs")]
+pub mod lens;
+pub mod coordinates;
+pub mod morton;
+pub mod rect_packer;
+pub mod geometry;
 
 // 重新导出主要类型
 pub use transform::{Transform, GlobalTransform};
+pub use transform2d::Transform2D;
 pub use aabb::Aabb;
+pub use bounds2d::Bounds2D;
 pub use frustum::Frustum;
+pub use color::Color;
+pub use lerp::Lerp;
+pub use interpolation::EaseFunction;
+pub use curve::{Curve, CurveInterpolation};
+pub use coordinates::{Axis, CoordinateSystem, Handedness};
+pub use geometry::{Ray, Plane, Sphere, LineSegment, Capsule, OrientedBox, Transformable};
+
+/// 地球标准重力加速度 (m/s²)
+pub const GRAVITY_EARTH: f32 = 9.80665;
 
 /// 速度组件 — linear + angular velocity
 #[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::prelude::Component))]
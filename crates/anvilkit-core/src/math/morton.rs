@@ -0,0 +1,206 @@
+//! Morton（Z-order）编码
+//!
+//! 将 2D/3D 整数坐标交错编码为单个整数，相邻的编码在空间上也相邻。
+//! 按 Morton 码排序实体数组可改善分块流式加载、剔除与 BVH 构建时的缓存局部性。
+
+use glam::{Vec2, Vec3};
+use super::aabb::Aabb;
+
+/// 3D 编码每轴的位数（3 × 21 = 63 位）
+pub const MORTON_3D_BITS: u32 = 21;
+
+/// 将 32 位整数的每一位之间插入一个 0 位
+fn spread_2d(v: u32) -> u64 {
+    let mut x = v as u64;
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    x = (x | (x << 1)) & 0x5555_5555_5555_5555;
+    x
+}
+
+/// [`spread_2d`] 的逆运算
+fn compact_2d(v: u64) -> u32 {
+    let mut x = v & 0x5555_5555_5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    x = (x | (x >> 2)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x >> 4)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x >> 8)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x >> 16)) & 0x0000_0000_FFFF_FFFF;
+    x as u32
+}
+
+/// 将 21 位整数的每一位之间插入两个 0 位
+fn spread_3d(v: u32) -> u64 {
+    let mut x = (v & 0x1F_FFFF) as u64;
+    x = (x | (x << 32)) & 0x001F_0000_0000_FFFF;
+    x = (x | (x << 16)) & 0x001F_0000_FF00_00FF;
+    x = (x | (x << 8)) & 0x100F_00F0_0F00_F00F;
+    x = (x | (x << 4)) & 0x10C3_0C30_C30C_30C3;
+    x = (x | (x << 2)) & 0x1249_2492_4924_9249;
+    x
+}
+
+/// [`spread_3d`] 的逆运算
+fn compact_3d(v: u64) -> u32 {
+    let mut x = v & 0x1249_2492_4924_9249;
+    x = (x | (x >> 2)) & 0x10C3_0C30_C30C_30C3;
+    x = (x | (x >> 4)) & 0x100F_00F0_0F00_F00F;
+    x = (x | (x >> 8)) & 0x001F_0000_FF00_00FF;
+    x = (x | (x >> 16)) & 0x001F_0000_0000_FFFF;
+    x = (x | (x >> 32)) & 0x0000_0000_001F_FFFF;
+    x as u32
+}
+
+/// 2D Morton 编码（x 占偶数位，y 占奇数位）
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::morton::{morton_encode_2d, morton_decode_2d};
+///
+/// assert_eq!(morton_encode_2d(1, 0), 0b01);
+/// assert_eq!(morton_encode_2d(0, 1), 0b10);
+/// assert_eq!(morton_decode_2d(morton_encode_2d(123, 456)), (123, 456));
+/// ```
+pub fn morton_encode_2d(x: u32, y: u32) -> u64 {
+    spread_2d(x) | (spread_2d(y) << 1)
+}
+
+/// 2D Morton 解码
+pub fn morton_decode_2d(code: u64) -> (u32, u32) {
+    (compact_2d(code), compact_2d(code >> 1))
+}
+
+/// 3D Morton 编码（每轴取低 21 位）
+pub fn morton_encode_3d(x: u32, y: u32, z: u32) -> u64 {
+    spread_3d(x) | (spread_3d(y) << 1) | (spread_3d(z) << 2)
+}
+
+/// 3D Morton 解码
+pub fn morton_decode_3d(code: u64) -> (u32, u32, u32) {
+    (compact_3d(code), compact_3d(code >> 1), compact_3d(code >> 2))
+}
+
+/// 将 `[0, 1]` 内的值量化到 `bits` 位整数
+fn quantize(t: f32, bits: u32) -> u32 {
+    let max = ((1u64 << bits) - 1) as f32;
+    (t.clamp(0.0, 1.0) * max) as u32
+}
+
+/// 将点按 `bounds` 归一化后计算 3D Morton 码（超出范围的点被钳制到边界）
+pub fn morton_code_3d(position: Vec3, bounds: &Aabb) -> u64 {
+    let extent = (bounds.max - bounds.min).max(Vec3::splat(f32::EPSILON));
+    let t = (position - bounds.min) / extent;
+    morton_encode_3d(
+        quantize(t.x, MORTON_3D_BITS),
+        quantize(t.y, MORTON_3D_BITS),
+        quantize(t.z, MORTON_3D_BITS),
+    )
+}
+
+/// 将点按 `[min, max]` 归一化后计算 2D Morton 码
+pub fn morton_code_2d(position: Vec2, min: Vec2, max: Vec2) -> u64 {
+    let extent = (max - min).max(Vec2::splat(f32::EPSILON));
+    let t = (position - min) / extent;
+    morton_encode_2d(quantize(t.x, 32), quantize(t.y, 32))
+}
+
+/// 按位置的 3D Morton 码对数组排序（稳定排序）
+///
+/// `bounds` 为 `None` 时使用所有位置的包围盒。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::morton::sort_by_morton_3d;
+/// use glam::Vec3;
+///
+/// let mut items = vec![
+///     ("far", Vec3::new(10.0, 10.0, 10.0)),
+///     ("origin", Vec3::ZERO),
+///     ("near", Vec3::new(1.0, 0.0, 0.0)),
+/// ];
+/// sort_by_morton_3d(&mut items, None, |(_, p)| *p);
+/// assert_eq!(items[0].0, "origin");
+/// assert_eq!(items[2].0, "far");
+/// ```
+pub fn sort_by_morton_3d<T>(items: &mut [T], bounds: Option<Aabb>, position: impl Fn(&T) -> Vec3) {
+    let bounds = match bounds {
+        Some(b) => b,
+        None => {
+            let points: Vec<Vec3> = items.iter().map(&position).collect();
+            match Aabb::from_points(&points) {
+                Some(b) => b,
+                None => return,
+            }
+        }
+    };
+    items.sort_by_cached_key(|item| morton_code_3d(position(item), &bounds));
+}
+
+/// 按位置的 2D Morton 码对数组排序（稳定排序），`bounds` 为 `(min, max)`
+pub fn sort_by_morton_2d<T>(items: &mut [T], bounds: Option<(Vec2, Vec2)>, position: impl Fn(&T) -> Vec2) {
+    let (min, max) = match bounds {
+        Some(b) => b,
+        None => {
+            let Some(first) = items.first().map(&position) else { return };
+            items.iter().map(&position).fold((first, first), |(lo, hi), p| (lo.min(p), hi.max(p)))
+        }
+    };
+    items.sort_by_cached_key(|item| morton_code_2d(position(item), min, max));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_morton_2d_roundtrip() {
+        assert_eq!(morton_encode_2d(0b11, 0b00), 0b0101);
+        assert_eq!(morton_encode_2d(0b00, 0b11), 0b1010);
+        for &(x, y) in &[(0, 0), (1, 2), (u32::MAX, 0), (0xDEAD, 0xBEEF), (u32::MAX, u32::MAX)] {
+            assert_eq!(morton_decode_2d(morton_encode_2d(x, y)), (x, y));
+        }
+    }
+
+    #[test]
+    fn test_morton_3d_roundtrip() {
+        assert_eq!(morton_encode_3d(1, 0, 0), 0b001);
+        assert_eq!(morton_encode_3d(0, 1, 0), 0b010);
+        assert_eq!(morton_encode_3d(0, 0, 1), 0b100);
+        let max = (1 << MORTON_3D_BITS) - 1;
+        for &(x, y, z) in &[(0, 0, 0), (5, 9, 17), (max, 0, max), (max, max, max), (12345, 67890, 1)] {
+            assert_eq!(morton_decode_3d(morton_encode_3d(x, y, z)), (x, y, z));
+        }
+    }
+
+    #[test]
+    fn test_morton_z_order() {
+        // Z 形遍历：(0,0) (1,0) (0,1) (1,1)
+        let codes: Vec<u64> = [(0, 0), (1, 0), (0, 1), (1, 1)]
+            .iter()
+            .map(|&(x, y)| morton_encode_2d(x, y))
+            .collect();
+        assert_eq!(codes, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_sort_groups_nearby_points() {
+        let mut points = vec![
+            Vec3::new(9.0, 9.0, 9.0),
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(8.5, 9.0, 9.0),
+            Vec3::new(0.5, 0.0, 0.0),
+        ];
+        sort_by_morton_3d(&mut points, None, |p| *p);
+        assert_eq!(points[0], Vec3::ZERO);
+        assert_eq!(points[1], Vec3::new(0.5, 0.0, 0.0));
+        assert!(points[2].x > 8.0 && points[3].x > 8.0);
+
+        let mut points2 = vec![Vec2::new(5.0, 5.0), Vec2::ZERO];
+        sort_by_morton_2d(&mut points2, None, |p| *p);
+        assert_eq!(points2[0], Vec2::ZERO);
+    }
+}
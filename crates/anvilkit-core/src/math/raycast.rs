@@ -7,9 +7,12 @@
 //! - [`screen_to_ray`] — 将屏幕坐标转换为世界空间射线
 //! - [`ray_plane_intersection`] — 射线与水平平面相交测试
 //! - [`ray_sphere_intersection`] — 射线与球体相交测试
+//! - [`ray_aabb_intersection`] — 射线与轴对齐包围盒相交测试
 
 use glam::{Mat4, Vec2, Vec3};
 
+use super::aabb::Aabb;
+
 /// 将屏幕坐标转换为世界空间射线
 ///
 /// 通过反投影变换将 2D 屏幕坐标映射为 3D 世界空间的射线原点和方向。
@@ -106,6 +109,24 @@ pub fn ray_sphere_intersection(
     None
 }
 
+/// 射线与轴对齐包围盒相交测试（slab 法）
+///
+/// # 返回
+///
+/// `Some(t)` — 最近交点的参数值（起点在包围盒内时为 0），`None` — 未命中或包围盒在射线背后
+pub fn ray_aabb_intersection(origin: Vec3, direction: Vec3, aabb: &Aabb) -> Option<f32> {
+    let inv = direction.recip();
+    let t1 = (aabb.min - origin) * inv;
+    let t2 = (aabb.max - origin) * inv;
+    let t_near = t1.min(t2).max_element();
+    let t_far = t1.max(t2).min_element();
+
+    if t_near > t_far || t_far < 0.0 {
+        return None;
+    }
+    Some(t_near.max(0.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,4 +258,15 @@ mod tests {
         let hit = ray_plane_intersection(origin, dir, 0.0);
         assert!(hit.is_some(), "Expected ray to hit y=0 plane");
     }
+
+    #[test]
+    fn test_ray_aabb() {
+        let aabb = Aabb::from_min_max(Vec3::splat(-1.0), Vec3::ONE);
+        let t = ray_aabb_intersection(Vec3::new(0.0, 0.0, -5.0), Vec3::Z, &aabb);
+        assert!((t.unwrap() - 4.0).abs() < 1e-5);
+        // 轴平行射线（方向分量为 0）
+        assert!(ray_aabb_intersection(Vec3::new(0.0, 2.0, -5.0), Vec3::Z, &aabb).is_none());
+        assert!(ray_aabb_intersection(Vec3::new(0.0, 0.0, 5.0), Vec3::Z, &aabb).is_none());
+        assert_eq!(ray_aabb_intersection(Vec3::ZERO, Vec3::X, &aabb), Some(0.0));
+    }
 }
@@ -0,0 +1,326 @@
+//! 矩形装箱
+//!
+//! [`RectPacker`] 使用 MaxRects 算法（最短边最优匹配）在固定尺寸的二维区域内分配矩形，
+//! 适用于字形图集、运行时精灵图集与阴影图集的分配。支持增量插入与按 ID 释放。
+
+use std::collections::BTreeMap;
+
+/// 图集中的矩形区域（像素，左上角为原点）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedRect {
+    /// 左上角 X
+    pub x: u32,
+    /// 左上角 Y
+    pub y: u32,
+    /// 宽度
+    pub width: u32,
+    /// 高度
+    pub height: u32,
+}
+
+impl PackedRect {
+    /// 创建矩形
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// 右边界（不含）
+    pub fn right(&self) -> u32 {
+        self.x + self.width
+    }
+
+    /// 下边界（不含）
+    pub fn bottom(&self) -> u32 {
+        self.y + self.height
+    }
+
+    /// 面积
+    pub fn area(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    /// 是否与另一个矩形重叠
+    pub fn intersects(&self, other: &PackedRect) -> bool {
+        self.x < other.right() && other.x < self.right()
+            && self.y < other.bottom() && other.y < self.bottom()
+    }
+
+    /// 是否完全包含另一个矩形
+    pub fn contains(&self, other: &PackedRect) -> bool {
+        other.x >= self.x && other.y >= self.y
+            && other.right() <= self.right() && other.bottom() <= self.bottom()
+    }
+}
+
+/// [`RectPacker`] 分配句柄
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RectId(u32);
+
+/// MaxRects 矩形装箱器
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::rect_packer::RectPacker;
+///
+/// let mut packer = RectPacker::new(256, 256);
+/// let (id, rect) = packer.insert(64, 32).unwrap();
+/// assert_eq!((rect.width, rect.height), (64, 32));
+///
+/// // 释放后空间可被复用
+/// packer.remove(id);
+/// assert!(packer.insert(256, 256).is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RectPacker {
+    width: u32,
+    height: u32,
+    padding: u32,
+    free: Vec<PackedRect>,
+    /// 按 ID（即插入顺序）排列，重建空闲矩形时结果确定
+    used: BTreeMap<RectId, PackedRect>,
+    next_id: u32,
+}
+
+impl RectPacker {
+    /// 创建指定尺寸的装箱器
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            padding: 0,
+            free: vec![PackedRect::new(0, 0, width, height)],
+            used: BTreeMap::new(),
+            next_id: 0,
+        }
+    }
+
+    /// 设置矩形之间的间隔像素（防止纹理过滤时相邻条目渗色）
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// 装箱区域宽度
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// 装箱区域高度
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// 已分配数量
+    pub fn len(&self) -> usize {
+        self.used.len()
+    }
+
+    /// 是否没有任何分配
+    pub fn is_empty(&self) -> bool {
+        self.used.is_empty()
+    }
+
+    /// 查询分配的矩形（不含间隔）
+    pub fn get(&self, id: RectId) -> Option<PackedRect> {
+        self.used.get(&id).map(|r| self.strip_padding(r))
+    }
+
+    /// 已分配面积占总面积的比例（含间隔）
+    pub fn occupancy(&self) -> f32 {
+        let total = self.width as u64 * self.height as u64;
+        if total == 0 {
+            return 0.0;
+        }
+        self.used.values().map(PackedRect::area).sum::<u64>() as f32 / total as f32
+    }
+
+    /// 分配 `width × height` 的矩形；空间不足时返回 `None`
+    pub fn insert(&mut self, width: u32, height: u32) -> Option<(RectId, PackedRect)> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let (w, h) = (width + self.padding, height + self.padding);
+
+        // 最短边最优：剩余短边最小者优先，其次剩余长边最小
+        let best = self
+            .free
+            .iter()
+            .filter(|f| f.width >= w && f.height >= h)
+            .min_by_key(|f| {
+                let (dw, dh) = (f.width - w, f.height - h);
+                (dw.min(dh), dw.max(dh))
+            })?;
+        let placed = PackedRect::new(best.x, best.y, w, h);
+
+        Self::split(&mut self.free, &placed);
+        Self::prune(&mut self.free);
+
+        let id = RectId(self.next_id);
+        self.next_id += 1;
+        self.used.insert(id, placed);
+        Some((id, self.strip_padding(&placed)))
+    }
+
+    /// 释放分配，空间可被后续插入复用
+    ///
+    /// 空闲矩形按剩余分配的插入顺序重建，释放的区域与相邻空闲空间完整合并，
+    /// 相同的插入 / 释放序列总是得到相同的布局。
+    pub fn remove(&mut self, id: RectId) -> Option<PackedRect> {
+        let rect = self.used.remove(&id)?;
+        self.free = vec![PackedRect::new(0, 0, self.width, self.height)];
+        for placed in self.used.values() {
+            Self::split(&mut self.free, placed);
+            Self::prune(&mut self.free);
+        }
+        Some(self.strip_padding(&rect))
+    }
+
+    /// 清空所有分配
+    pub fn clear(&mut self) {
+        self.used.clear();
+        self.free = vec![PackedRect::new(0, 0, self.width, self.height)];
+    }
+
+    /// 遍历所有分配
+    pub fn iter(&self) -> impl Iterator<Item = (RectId, PackedRect)> + '_ {
+        self.used.iter().map(|(&id, r)| (id, self.strip_padding(r)))
+    }
+
+    fn strip_padding(&self, r: &PackedRect) -> PackedRect {
+        PackedRect::new(r.x, r.y, r.width - self.padding, r.height - self.padding)
+    }
+
+    /// 用已放置矩形切分所有与之相交的空闲矩形
+    fn split(free: &mut Vec<PackedRect>, placed: &PackedRect) {
+        let mut result = Vec::with_capacity(free.len() + 4);
+        for f in free.drain(..) {
+            if !f.intersects(placed) {
+                result.push(f);
+                continue;
+            }
+            if placed.x > f.x {
+                result.push(PackedRect::new(f.x, f.y, placed.x - f.x, f.height));
+            }
+            if placed.right() < f.right() {
+                result.push(PackedRect::new(placed.right(), f.y, f.right() - placed.right(), f.height));
+            }
+            if placed.y > f.y {
+                result.push(PackedRect::new(f.x, f.y, f.width, placed.y - f.y));
+            }
+            if placed.bottom() < f.bottom() {
+                result.push(PackedRect::new(f.x, placed.bottom(), f.width, f.bottom() - placed.bottom()));
+            }
+        }
+        *free = result;
+    }
+
+    /// 移除被其他空闲矩形完全包含的空闲矩形
+    fn prune(free: &mut Vec<PackedRect>) {
+        let mut i = 0;
+        while i < free.len() {
+            let fi = free[i];
+            let contained = free
+                .iter()
+                .enumerate()
+                .any(|(j, fj)| j != i && fj.contains(&fi) && (fi != *fj || j < i));
+            if contained {
+                free.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_no_overlap(packer: &RectPacker) {
+        let rects: Vec<_> = packer.used.values().copied().collect();
+        for (i, a) in rects.iter().enumerate() {
+            assert!(a.right() <= packer.width() && a.bottom() <= packer.height());
+            for b in &rects[i + 1..] {
+                assert!(!a.intersects(b), "{:?} overlaps {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_tiles_fill_completely() {
+        let mut packer = RectPacker::new(128, 128);
+        for _ in 0..64 {
+            assert!(packer.insert(16, 16).is_some());
+        }
+        assert!(packer.insert(1, 1).is_none());
+        assert_eq!(packer.occupancy(), 1.0);
+        assert_no_overlap(&packer);
+    }
+
+    #[test]
+    fn test_mixed_sizes_efficiency() {
+        let mut packer = RectPacker::new(256, 256);
+        let mut placed = 0u64;
+        // 确定性伪随机尺寸
+        let mut seed = 12345u32;
+        for _ in 0..400 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let w = 4 + (seed >> 16) % 29;
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let h = 4 + (seed >> 16) % 29;
+            if packer.insert(w, h).is_some() {
+                placed += (w * h) as u64;
+            }
+        }
+        assert_no_overlap(&packer);
+        assert!(packer.occupancy() > 0.8, "occupancy {}", packer.occupancy());
+        assert_eq!(placed as f32 / (256.0 * 256.0), packer.occupancy());
+    }
+
+    #[test]
+    fn test_eviction_reuses_space() {
+        let mut packer = RectPacker::new(64, 64);
+        let ids: Vec<_> = (0..4).map(|_| packer.insert(32, 32).unwrap().0).collect();
+        assert!(packer.insert(32, 32).is_none());
+
+        let freed = packer.remove(ids[1]).unwrap();
+        let (_, rect) = packer.insert(32, 32).unwrap();
+        assert_eq!(rect, freed);
+        assert!(packer.remove(ids[1]).is_none());
+
+        // 释放相邻两块后可放入合并后的区域
+        packer.remove(ids[2]);
+        packer.remove(ids[3]);
+        packer.remove(ids[0]);
+        assert_eq!(packer.len(), 1);
+        assert!(packer.insert(64, 32).is_some());
+        assert_no_overlap(&packer);
+    }
+
+    #[test]
+    fn test_remove_is_deterministic() {
+        let build = || {
+            let mut packer = RectPacker::new(128, 128);
+            let ids: Vec<_> = (1..24).map(|i| packer.insert(4 + i % 7 * 3, 4 + i % 5 * 4).unwrap().0).collect();
+            for id in ids.iter().step_by(3) {
+                packer.remove(*id);
+            }
+            packer
+        };
+        let (a, b) = (build(), build());
+        assert_eq!(a.free, b.free);
+        assert_eq!(a.iter().collect::<Vec<_>>(), b.iter().collect::<Vec<_>>());
+        assert_no_overlap(&a);
+    }
+
+    #[test]
+    fn test_padding() {
+        let mut packer = RectPacker::new(34, 17).with_padding(1);
+        let (_, a) = packer.insert(16, 16).unwrap();
+        let (_, b) = packer.insert(16, 16).unwrap();
+        assert_eq!((a.width, a.height), (16, 16));
+        assert!(a.x.abs_diff(b.x) >= 17);
+        assert!(packer.insert(16, 16).is_none());
+    }
+}
@@ -6,6 +6,9 @@
 //! 
 //! - [`Transform`]: 本地变换，相对于父对象的变换
 //! - [`GlobalTransform`]: 全局变换，世界空间中的最终变换
+//!
+//! 方向约定与渲染器的左手坐标系一致：本地 +Z 为前方、+X 为右方、+Y 为上方
+//! （见 [`Transform::forward`]）。其他工具的约定与转换见 [`coordinates`](super::coordinates)。
 //! 
 //! ## 使用示例
 //! 
@@ -188,6 +191,23 @@ impl Transform {
         Self::from_translation(Vec3::new(x, y, 0.0))
     }
 
+    /// 从欧拉角（弧度）创建旋转变换
+    ///
+    /// 依次绕 Y（偏航）、X（俯仰）、Z（翻滚）旋转，与相机控制器的 yaw / pitch 一致。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_core::math::Transform;
+    /// use glam::Vec3;
+    ///
+    /// let transform = Transform::from_euler(std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+    /// assert!((transform.forward() - Vec3::X).length() < 1e-5);
+    /// ```
+    pub fn from_euler(yaw: f32, pitch: f32, roll: f32) -> Self {
+        Self::from_rotation(Quat::from_euler(glam::EulerRot::YXZ, yaw, pitch, roll))
+    }
+
     /// 设置位置（链式调用）
     /// 
     /// # 示例
@@ -217,6 +237,9 @@ impl Transform {
     }
 
     /// 创建朝向目标的变换
+    ///
+    /// 按右手约定构造，本地 -Z 指向目标；与 [`Transform::forward`]（+Z）配合时
+    /// 请使用 [`Transform::looking_to`]。
     /// 
     /// # 参数
     /// 
@@ -270,6 +293,104 @@ impl Transform {
         Ok(Self::new(eye, rotation, Vec3::ONE))
     }
 
+    /// 保留位置与缩放，旋转为以 `direction` 为前方（+Z）
+    ///
+    /// # 错误
+    ///
+    /// `direction` 为零向量或与 `up` 平行时返回错误。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_core::math::Transform;
+    /// use glam::Vec3;
+    ///
+    /// let transform = Transform::from_xyz(0.0, 2.0, 0.0).looking_to(Vec3::X, Vec3::Y).unwrap();
+    /// assert!((transform.forward() - Vec3::X).length() < 1e-5);
+    /// assert_eq!(transform.translation, Vec3::new(0.0, 2.0, 0.0));
+    /// ```
+    pub fn looking_to(mut self, direction: Vec3, up: Vec3) -> Result<Self> {
+        let forward = direction.normalize_or_zero();
+        if forward == Vec3::ZERO {
+            return Err(AnvilKitError::generic("无效的朝向向量：方向为零或无效"));
+        }
+        let right = up.cross(forward).normalize_or_zero();
+        if right == Vec3::ZERO {
+            return Err(AnvilKitError::generic("无效的上方向向量：与前向向量平行"));
+        }
+        let up = forward.cross(right);
+        self.rotation = Quat::from_mat3(&glam::Mat3::from_cols(right, up, forward));
+        Ok(self)
+    }
+
+    /// 前方（本地 +Z）在父空间中的方向
+    pub fn forward(&self) -> Vec3 {
+        self.rotation * Vec3::Z
+    }
+
+    /// 右方（本地 +X）在父空间中的方向
+    pub fn right(&self) -> Vec3 {
+        self.rotation * Vec3::X
+    }
+
+    /// 上方（本地 +Y）在父空间中的方向
+    pub fn up(&self) -> Vec3 {
+        self.rotation * Vec3::Y
+    }
+
+    /// 在现有旋转之上叠加旋转（父空间坐标轴）
+    pub fn rotate(&mut self, rotation: Quat) {
+        self.rotation = rotation * self.rotation;
+    }
+
+    /// 绕父空间 X 轴旋转 `angle` 弧度
+    pub fn rotate_x(&mut self, angle: f32) {
+        self.rotate(Quat::from_rotation_x(angle));
+    }
+
+    /// 绕父空间 Y 轴旋转 `angle` 弧度
+    pub fn rotate_y(&mut self, angle: f32) {
+        self.rotate(Quat::from_rotation_y(angle));
+    }
+
+    /// 绕父空间 Z 轴旋转 `angle` 弧度
+    pub fn rotate_z(&mut self, angle: f32) {
+        self.rotate(Quat::from_rotation_z(angle));
+    }
+
+    /// 绕 `point` 旋转：位置绕该点公转，朝向同步旋转
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_core::math::Transform;
+    /// use glam::{Quat, Vec3};
+    ///
+    /// let mut transform = Transform::from_xyz(2.0, 0.0, 0.0);
+    /// transform.rotate_around(Vec3::new(1.0, 0.0, 0.0), Quat::from_rotation_y(std::f32::consts::PI));
+    /// assert!((transform.translation - Vec3::ZERO).length() < 1e-5);
+    /// ```
+    pub fn rotate_around(&mut self, point: Vec3, rotation: Quat) {
+        self.translation = point + rotation * (self.translation - point);
+        self.rotate(rotation);
+    }
+
+    /// 按自身朝向平移：`offset` 的 X / Y / Z 分别沿右方、上方、前方
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_core::math::Transform;
+    /// use glam::Vec3;
+    ///
+    /// let mut transform = Transform::from_euler(std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+    /// transform.translate_local(Vec3::new(0.0, 0.0, 3.0));
+    /// assert!((transform.translation - Vec3::new(3.0, 0.0, 0.0)).length() < 1e-5);
+    /// ```
+    pub fn translate_local(&mut self, offset: Vec3) {
+        self.translation += self.rotation * offset;
+    }
+
     /// 将变换转换为 4x4 变换矩阵
     /// 
     /// 矩阵的计算顺序为：缩放 → 旋转 → 平移
@@ -345,6 +466,26 @@ impl Transform {
         Transform::from_matrix(matrix)
     }
 
+    /// 在两个变换之间插值：平移与缩放线性插值，旋转球面插值
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_core::math::Transform;
+    /// use glam::Vec3;
+    ///
+    /// let a = Transform::from_xyz(0.0, 0.0, 0.0);
+    /// let b = Transform::from_xyz(2.0, 0.0, 0.0);
+    /// assert_eq!(a.lerp(&b, 0.5).translation, Vec3::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn lerp(&self, other: &Transform, t: f32) -> Transform {
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale.lerp(other.scale, t),
+        }
+    }
+
     /// 从变换矩阵创建变换
     /// 
     /// # 注意
@@ -630,6 +771,67 @@ mod tests {
         assert!(Transform::looking_at(Vec3::ZERO, Vec3::Y, Vec3::Y).is_err());
     }
 
+    #[test]
+    fn test_transform_lerp() {
+        let a = Transform::from_xyz(0.0, 0.0, 0.0);
+        let b = Transform::new(Vec3::new(4.0, 0.0, 0.0), Quat::from_rotation_y(std::f32::consts::FRAC_PI_2), Vec3::splat(3.0));
+        assert_eq!(a.lerp(&b, 0.0), a);
+        let mid = a.lerp(&b, 0.5);
+        assert!((mid.translation - Vec3::new(2.0, 0.0, 0.0)).length() < 1e-5);
+        assert!((mid.scale - Vec3::splat(2.0)).length() < 1e-5);
+        assert!(mid.rotation.angle_between(Quat::from_rotation_y(std::f32::consts::FRAC_PI_4)) < 1e-4);
+    }
+
+    #[test]
+    fn test_transform_direction_accessors() {
+        let transform = Transform::from_euler(std::f32::consts::FRAC_PI_2, 0.0, 0.0);
+        assert!(vec3_approx_eq(transform.forward(), Vec3::X, 1e-5));
+        assert!(vec3_approx_eq(transform.right(), -Vec3::Z, 1e-5));
+        assert!(vec3_approx_eq(transform.up(), Vec3::Y, 1e-5));
+
+        let pitched = Transform::from_euler(0.0, -std::f32::consts::FRAC_PI_2, 0.0);
+        assert!(vec3_approx_eq(pitched.forward(), Vec3::Y, 1e-5));
+    }
+
+    #[test]
+    fn test_transform_rotate_axes() {
+        let mut transform = Transform::IDENTITY;
+        transform.rotate_y(std::f32::consts::FRAC_PI_2);
+        assert!(quat_approx_eq(transform.rotation, Quat::from_rotation_y(std::f32::consts::FRAC_PI_2), 1e-5));
+        transform.rotate_x(0.3);
+        transform.rotate_z(-0.2);
+        let expected = Quat::from_rotation_z(-0.2) * Quat::from_rotation_x(0.3) * Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        assert!(quat_approx_eq(transform.rotation, expected, 1e-5));
+    }
+
+    #[test]
+    fn test_transform_rotate_around() {
+        let mut transform = Transform::from_xyz(1.0, 0.0, 0.0);
+        transform.rotate_around(Vec3::ZERO, Quat::from_rotation_y(std::f32::consts::FRAC_PI_2));
+        assert!(vec3_approx_eq(transform.translation, Vec3::new(0.0, 0.0, -1.0), 1e-5));
+        assert!(vec3_approx_eq(transform.forward(), Vec3::X, 1e-5));
+    }
+
+    #[test]
+    fn test_transform_looking_to() {
+        let direction = Vec3::new(1.0, 0.0, 1.0);
+        let transform = Transform::from_xyz(3.0, 1.0, 0.0).with_scale(Vec3::splat(2.0)).looking_to(direction, Vec3::Y).unwrap();
+        assert!(vec3_approx_eq(transform.forward(), direction.normalize(), 1e-5));
+        assert!(vec3_approx_eq(transform.up(), Vec3::Y, 1e-5));
+        assert_eq!(transform.translation, Vec3::new(3.0, 1.0, 0.0));
+        assert_eq!(transform.scale, Vec3::splat(2.0));
+
+        assert!(Transform::IDENTITY.looking_to(Vec3::ZERO, Vec3::Y).is_err());
+        assert!(Transform::IDENTITY.looking_to(Vec3::Y, Vec3::Y).is_err());
+    }
+
+    #[test]
+    fn test_transform_translate_local() {
+        let mut transform = Transform::from_xyz(1.0, 0.0, 0.0).with_rotation(Quat::from_rotation_y(std::f32::consts::PI));
+        transform.translate_local(Vec3::new(1.0, 2.0, 3.0));
+        assert!(vec3_approx_eq(transform.translation, Vec3::new(0.0, 2.0, -3.0), 1e-5));
+    }
+
     #[test]
     fn test_global_transform() {
         let transform = Transform::from_xyz(1.0, 2.0, 3.0);
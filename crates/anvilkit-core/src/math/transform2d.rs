@@ -0,0 +1,227 @@
+//! # 2D 变换
+//!
+//! [`Transform2D`] 以 `Vec2` 位置、弧度旋转、`Vec2` 缩放与 z 序描述 2D 对象，
+//! 无需接触四元数。渲染与层次传播仍基于 [`Transform`]：
+//! 变换系统在 `Transform2D` 变化时写入 `Transform`，`Transform` 被外部（如 2D 物理）
+//! 修改时再同步回 `Transform2D`。
+//!
+//! ```rust
+//! use anvilkit_core::math::{Transform, Transform2D};
+//! use glam::{Vec2, Vec3};
+//!
+//! let transform = Transform2D::from_xy(3.0, 4.0)
+//!     .with_rotation(std::f32::consts::FRAC_PI_2)
+//!     .with_z_index(2.0);
+//! let t3: Transform = transform.into();
+//! assert_eq!(t3.translation, Vec3::new(3.0, 4.0, 2.0));
+//! assert!((transform.transform_point(Vec2::X) - Vec2::new(3.0, 5.0)).length() < 1e-5);
+//! ```
+
+use glam::{Mat4, Quat, Vec2, Vec3};
+use anvilkit_describe::Describe;
+
+use super::transform::{GlobalTransform, Transform};
+
+/// 2D 变换组件：平面位置、绕 Z 轴旋转、平面缩放与 z 序
+#[derive(Debug, Clone, Copy, PartialEq, Describe)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::component::Component))]
+#[cfg_attr(feature = "bevy_ecs", require(Transform, GlobalTransform))]
+/// 2D transform: position, rotation in radians, scale, and z-index.
+pub struct Transform2D {
+    /// 平面位置
+    #[describe(hint = "Position in the XY plane", default = "(0, 0)")]
+    pub translation: Vec2,
+    /// 逆时针旋转角（弧度）
+    #[describe(hint = "Counter-clockwise rotation in radians", default = "0.0")]
+    pub rotation: f32,
+    /// 各轴缩放
+    #[describe(hint = "Scale per axis", default = "(1, 1)")]
+    pub scale: Vec2,
+    /// 绘制顺序，映射为 `Transform.translation.z`
+    #[describe(hint = "Draw order; maps to translation.z", default = "0.0")]
+    pub z_index: f32,
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Transform2D {
+    /// 单位变换
+    pub const IDENTITY: Self = Self { translation: Vec2::ZERO, rotation: 0.0, scale: Vec2::ONE, z_index: 0.0 };
+
+    /// 从位置创建
+    pub fn from_translation(translation: Vec2) -> Self {
+        Self { translation, ..Self::IDENTITY }
+    }
+
+    /// 从坐标创建
+    pub fn from_xy(x: f32, y: f32) -> Self {
+        Self::from_translation(Vec2::new(x, y))
+    }
+
+    /// 从旋转角（弧度）创建
+    pub fn from_rotation(rotation: f32) -> Self {
+        Self { rotation, ..Self::IDENTITY }
+    }
+
+    /// 从缩放创建
+    pub fn from_scale(scale: Vec2) -> Self {
+        Self { scale, ..Self::IDENTITY }
+    }
+
+    /// 设置位置（链式调用）
+    pub fn with_translation(mut self, translation: Vec2) -> Self {
+        self.translation = translation;
+        self
+    }
+
+    /// 设置旋转（链式调用）
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// 设置缩放（链式调用）
+    pub fn with_scale(mut self, scale: Vec2) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// 设置 z 序（链式调用）
+    pub fn with_z_index(mut self, z_index: f32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// 右方（本地 +X）
+    pub fn right(&self) -> Vec2 {
+        Vec2::from_angle(self.rotation)
+    }
+
+    /// 上方（本地 +Y）
+    pub fn up(&self) -> Vec2 {
+        self.right().perp()
+    }
+
+    /// 逆时针旋转 `angle` 弧度
+    pub fn rotate(&mut self, angle: f32) {
+        self.rotation += angle;
+    }
+
+    /// 按自身朝向平移：`offset.x` 沿右方、`offset.y` 沿上方
+    pub fn translate_local(&mut self, offset: Vec2) {
+        self.translation += self.right() * offset.x + self.up() * offset.y;
+    }
+
+    /// 应用变换到点
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.translation + Vec2::from_angle(self.rotation).rotate(point * self.scale)
+    }
+
+    /// 在两个变换之间插值（旋转取最短路径）
+    pub fn lerp(&self, other: &Transform2D, t: f32) -> Transform2D {
+        let delta = (other.rotation - self.rotation + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU)
+            - std::f32::consts::PI;
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation + delta * t,
+            scale: self.scale.lerp(other.scale, t),
+            z_index: self.z_index + (other.z_index - self.z_index) * t,
+        }
+    }
+
+    /// 转为 3D 变换
+    pub fn to_transform(&self) -> Transform {
+        Transform::new(
+            self.translation.extend(self.z_index),
+            Quat::from_rotation_z(self.rotation),
+            self.scale.extend(1.0),
+        )
+    }
+
+    /// 从 3D 变换提取（忽略绕 X / Y 的旋转与 Z 缩放）
+    pub fn from_transform(transform: &Transform) -> Self {
+        let right = transform.rotation * Vec3::X;
+        Self {
+            translation: transform.translation.truncate(),
+            rotation: right.y.atan2(right.x),
+            scale: transform.scale.truncate(),
+            z_index: transform.translation.z,
+        }
+    }
+
+    /// 计算 4x4 变换矩阵
+    pub fn compute_matrix(&self) -> Mat4 {
+        self.to_transform().compute_matrix()
+    }
+}
+
+impl From<Transform2D> for Transform {
+    fn from(transform: Transform2D) -> Self {
+        transform.to_transform()
+    }
+}
+
+impl From<Transform2D> for GlobalTransform {
+    fn from(transform: Transform2D) -> Self {
+        GlobalTransform::from_matrix(transform.compute_matrix())
+    }
+}
+
+impl From<Transform> for Transform2D {
+    fn from(transform: Transform) -> Self {
+        Self::from_transform(&transform)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_transform2d_roundtrip() {
+        let t2 = Transform2D::from_xy(1.0, -2.0).with_rotation(0.75).with_scale(Vec2::new(2.0, 3.0)).with_z_index(5.0);
+        let t3 = Transform::from(t2);
+        assert_eq!(t3.translation, Vec3::new(1.0, -2.0, 5.0));
+        assert_eq!(t3.scale, Vec3::new(2.0, 3.0, 1.0));
+
+        let back = Transform2D::from(t3);
+        assert!((back.translation - t2.translation).length() < 1e-6);
+        assert!((back.rotation - 0.75).abs() < 1e-5);
+        assert_eq!(back.scale, t2.scale);
+        assert_eq!(back.z_index, 5.0);
+    }
+
+    #[test]
+    fn test_transform2d_matches_3d_matrix() {
+        let t2 = Transform2D::from_xy(4.0, 1.0).with_rotation(FRAC_PI_2).with_scale(Vec2::splat(2.0));
+        let point = Vec2::new(1.0, 0.5);
+        let via_3d = GlobalTransform::from(t2).transform_point(point.extend(0.0)).truncate();
+        assert!((t2.transform_point(point) - via_3d).length() < 1e-5);
+        assert!((t2.transform_point(point) - Vec2::new(3.0, 3.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_transform2d_directions_and_local_move() {
+        let mut t2 = Transform2D::from_rotation(FRAC_PI_2);
+        assert!((t2.right() - Vec2::Y).length() < 1e-6);
+        assert!((t2.up() + Vec2::X).length() < 1e-6);
+        t2.translate_local(Vec2::new(2.0, 0.0));
+        assert!((t2.translation - Vec2::new(0.0, 2.0)).length() < 1e-5);
+        t2.rotate(FRAC_PI_2);
+        assert!((t2.rotation - PI).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_transform2d_lerp_shortest_arc() {
+        let a = Transform2D::from_rotation(PI - 0.1);
+        let b = Transform2D::from_rotation(-PI + 0.1);
+        let mid = a.lerp(&b, 0.5);
+        assert!((mid.rotation - PI).abs() < 1e-5);
+    }
+}
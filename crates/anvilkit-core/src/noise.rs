@@ -0,0 +1,514 @@
+//! # 程序化噪声
+//!
+//! 可设种子的梯度噪声，用于地形高度、云层密度、材质扰动与着色器参数：
+//!
+//! - [`Noise`]: 1D / 2D / 3D Perlin（改进版）与 Simplex 噪声，输出约在 `[-1, 1]`
+//! - Perlin 的 `*_tiled` 变体在给定整数周期上无缝平铺，适合生成可重复的纹理
+//! - [`Fbm`]: 分形布朗运动，叠加多个倍频（octave），可调频率倍增（lacunarity）与振幅衰减（gain）
+//!
+//! 同一种子在所有平台上产生相同结果；置换表由 [`Rng`] 洗牌得到。
+//!
+//! ```rust
+//! use anvilkit_core::noise::{Fbm, Noise, NoiseKind};
+//! use glam::Vec2;
+//!
+//! let noise = Noise::new(42);
+//! let h = noise.perlin2(Vec2::new(3.7, 1.2));
+//! assert!((-1.0..=1.0).contains(&h));
+//!
+//! // 4 个倍频的地形高度
+//! let terrain = Fbm::new(NoiseKind::Simplex).with_octaves(4).with_frequency(0.01);
+//! let height = terrain.sample2(&noise, Vec2::new(120.0, -48.0)) * 30.0;
+//! assert!(height.abs() <= 30.0);
+//!
+//! // 周期为 8 的无缝平铺
+//! let a = noise.perlin2_tiled(Vec2::new(0.3, 0.6), 8, 8);
+//! let b = noise.perlin2_tiled(Vec2::new(8.3, 0.6), 8, 8);
+//! assert!((a - b).abs() < 1e-6);
+//! ```
+
+use glam::{Vec2, Vec3};
+
+use crate::rng::Rng;
+
+/// 改进 Perlin 的五次平滑曲线 6t⁵ - 15t⁴ + 10t³
+#[inline]
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+#[inline]
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
+
+#[inline]
+fn grad1(hash: u8, x: f32) -> f32 {
+    // 梯度取 ±1..±8 的 1/8，避免所有晶格处斜率相同
+    let g = 1.0 + (hash & 7) as f32;
+    if hash & 8 == 0 { g * x / 8.0 } else { -g * x / 8.0 }
+}
+
+#[inline]
+fn grad2(hash: u8, x: f32, y: f32) -> f32 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+#[inline]
+fn grad3(hash: u8, x: f32, y: f32, z: f32) -> f32 {
+    // Perlin 2002：立方体 12 条棱方向（其中 4 个重复以凑满 16 项）
+    match hash & 15 {
+        0 | 12 => x + y,
+        1 | 14 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x + z,
+        5 => -x + z,
+        6 => x - z,
+        7 => -x - z,
+        8 => y + z,
+        9 | 13 => -y + z,
+        10 => y - z,
+        _ => -y - z,
+    }
+}
+
+/// 噪声种类（[`Fbm`] 的基础噪声）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NoiseKind {
+    /// 改进 Perlin 噪声（支持平铺）
+    #[default]
+    Perlin,
+    /// Simplex 噪声（更少方向性伪影，3D 时更快）
+    Simplex,
+}
+
+/// 梯度噪声生成器
+///
+/// 只持有 512 字节的置换表，可廉价克隆；所有采样方法都是只读的。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Noise {
+    seed: u64,
+    perm: [u8; 512],
+}
+
+impl Default for Noise {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Noise {
+    /// 以种子创建噪声
+    pub fn new(seed: u64) -> Self {
+        let mut table: [u8; 256] = std::array::from_fn(|i| i as u8);
+        Rng::seed_from_u64(seed).shuffle(&mut table);
+        let perm = std::array::from_fn(|i| table[i & 255]);
+        Self { seed, perm }
+    }
+
+    /// 创建时使用的种子
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    #[inline]
+    fn hash1(&self, x: i32) -> u8 {
+        self.perm[(x & 255) as usize]
+    }
+
+    #[inline]
+    fn hash2(&self, x: i32, y: i32) -> u8 {
+        self.perm[self.hash1(x) as usize + (y & 255) as usize]
+    }
+
+    #[inline]
+    fn hash3(&self, x: i32, y: i32, z: i32) -> u8 {
+        self.perm[self.hash2(x, y) as usize + (z & 255) as usize]
+    }
+
+    // -----------------------------------------------------------------------
+    //  Perlin
+    // -----------------------------------------------------------------------
+
+    /// 1D Perlin 噪声，整数点处为 0
+    pub fn perlin1(&self, x: f32) -> f32 {
+        self.perlin1_impl(x, None)
+    }
+
+    /// 1D Perlin 噪声，以 `period` 为周期平铺
+    pub fn perlin1_tiled(&self, x: f32, period: u32) -> f32 {
+        self.perlin1_impl(x, Some(period))
+    }
+
+    fn perlin1_impl(&self, x: f32, period: Option<u32>) -> f32 {
+        let x0 = x.floor();
+        let fx = x - x0;
+        let [i0, i1] = wrap([x0 as i32, x0 as i32 + 1], period);
+        let u = fade(fx);
+        // 插值结果最大约 0.5，放大到约 [-1, 1]
+        (2.0 * lerp(grad1(self.hash1(i0), fx), grad1(self.hash1(i1), fx - 1.0), u)).clamp(-1.0, 1.0)
+    }
+
+    /// 2D Perlin 噪声，整数点处为 0
+    pub fn perlin2(&self, p: Vec2) -> f32 {
+        self.perlin2_impl(p, None)
+    }
+
+    /// 2D Perlin 噪声，在 x / y 方向分别以 `period_x` / `period_y` 为周期平铺
+    pub fn perlin2_tiled(&self, p: Vec2, period_x: u32, period_y: u32) -> f32 {
+        self.perlin2_impl(p, Some((period_x, period_y)))
+    }
+
+    fn perlin2_impl(&self, p: Vec2, period: Option<(u32, u32)>) -> f32 {
+        let base = p.floor();
+        let f = p - base;
+        let [x0, x1] = wrap([base.x as i32, base.x as i32 + 1], period.map(|p| p.0));
+        let [y0, y1] = wrap([base.y as i32, base.y as i32 + 1], period.map(|p| p.1));
+        let (u, v) = (fade(f.x), fade(f.y));
+
+        let a = lerp(grad2(self.hash2(x0, y0), f.x, f.y), grad2(self.hash2(x1, y0), f.x - 1.0, f.y), u);
+        let b = lerp(grad2(self.hash2(x0, y1), f.x, f.y - 1.0), grad2(self.hash2(x1, y1), f.x - 1.0, f.y - 1.0), u);
+        lerp(a, b, v).clamp(-1.0, 1.0)
+    }
+
+    /// 3D Perlin 噪声，整数点处为 0
+    pub fn perlin3(&self, p: Vec3) -> f32 {
+        self.perlin3_impl(p, None)
+    }
+
+    /// 3D Perlin 噪声，三个轴均以 `period` 为周期平铺（可用于无缝循环的动画噪声）
+    pub fn perlin3_tiled(&self, p: Vec3, period: u32) -> f32 {
+        self.perlin3_impl(p, Some(period))
+    }
+
+    fn perlin3_impl(&self, p: Vec3, period: Option<u32>) -> f32 {
+        let base = p.floor();
+        let f = p - base;
+        let [x0, x1] = wrap([base.x as i32, base.x as i32 + 1], period);
+        let [y0, y1] = wrap([base.y as i32, base.y as i32 + 1], period);
+        let [z0, z1] = wrap([base.z as i32, base.z as i32 + 1], period);
+        let (u, v, w) = (fade(f.x), fade(f.y), fade(f.z));
+        let g = |x: i32, y: i32, z: i32, dx: f32, dy: f32, dz: f32| {
+            grad3(self.hash3(x, y, z), f.x - dx, f.y - dy, f.z - dz)
+        };
+
+        let near = lerp(
+            lerp(g(x0, y0, z0, 0.0, 0.0, 0.0), g(x1, y0, z0, 1.0, 0.0, 0.0), u),
+            lerp(g(x0, y1, z0, 0.0, 1.0, 0.0), g(x1, y1, z0, 1.0, 1.0, 0.0), u),
+            v,
+        );
+        let far = lerp(
+            lerp(g(x0, y0, z1, 0.0, 0.0, 1.0), g(x1, y0, z1, 1.0, 0.0, 1.0), u),
+            lerp(g(x0, y1, z1, 0.0, 1.0, 1.0), g(x1, y1, z1, 1.0, 1.0, 1.0), u),
+            v,
+        );
+        lerp(near, far, w).clamp(-1.0, 1.0)
+    }
+
+    // -----------------------------------------------------------------------
+    //  Simplex
+    // -----------------------------------------------------------------------
+
+    /// 1D Simplex 噪声
+    pub fn simplex1(&self, x: f32) -> f32 {
+        let i0 = x.floor();
+        let x0 = x - i0;
+        let x1 = x0 - 1.0;
+        let corner = |i: i32, d: f32| {
+            let t = (1.0 - d * d).max(0.0);
+            let t2 = t * t;
+            t2 * t2 * grad1(self.hash1(i), d)
+        };
+        // Gustavson 的 0.395 归一化系数；grad1 已除以 8，故再乘 8
+        (3.16 * (corner(i0 as i32, x0) + corner(i0 as i32 + 1, x1))).clamp(-1.0, 1.0)
+    }
+
+    /// 2D Simplex 噪声
+    pub fn simplex2(&self, p: Vec2) -> f32 {
+        const F2: f32 = 0.366_025_42; // (√3 - 1) / 2
+        const G2: f32 = 0.211_324_87; // (3 - √3) / 6
+
+        let s = (p.x + p.y) * F2;
+        let (i, j) = ((p.x + s).floor(), (p.y + s).floor());
+        let t = (i + j) * G2;
+        let d0 = Vec2::new(p.x - (i - t), p.y - (j - t));
+        let (i1, j1) = if d0.x > d0.y { (1, 0) } else { (0, 1) };
+        let d1 = d0 - Vec2::new(i1 as f32, j1 as f32) + Vec2::splat(G2);
+        let d2 = d0 - Vec2::ONE + Vec2::splat(2.0 * G2);
+        let (i, j) = (i as i32, j as i32);
+
+        let corner = |d: Vec2, hash: u8| {
+            let t = 0.5 - d.length_squared();
+            if t < 0.0 { 0.0 } else { t * t * t * t * grad2(hash, d.x, d.y) }
+        };
+        let n = corner(d0, self.hash2(i, j))
+            + corner(d1, self.hash2(i + i1, j + j1))
+            + corner(d2, self.hash2(i + 1, j + 1));
+        (70.0 * n).clamp(-1.0, 1.0)
+    }
+
+    /// 3D Simplex 噪声
+    pub fn simplex3(&self, p: Vec3) -> f32 {
+        const F3: f32 = 1.0 / 3.0;
+        const G3: f32 = 1.0 / 6.0;
+
+        let s = (p.x + p.y + p.z) * F3;
+        let cell = (p + Vec3::splat(s)).floor();
+        let t = (cell.x + cell.y + cell.z) * G3;
+        let d0 = p - (cell - Vec3::splat(t));
+
+        // 确定所在的四面体（按坐标大小排序）
+        let (o1, o2) = if d0.x >= d0.y {
+            if d0.y >= d0.z {
+                ([1, 0, 0], [1, 1, 0])
+            } else if d0.x >= d0.z {
+                ([1, 0, 0], [1, 0, 1])
+            } else {
+                ([0, 0, 1], [1, 0, 1])
+            }
+        } else if d0.y < d0.z {
+            ([0, 0, 1], [0, 1, 1])
+        } else if d0.x < d0.z {
+            ([0, 1, 0], [0, 1, 1])
+        } else {
+            ([0, 1, 0], [1, 1, 0])
+        };
+        let offset = |o: [i32; 3]| Vec3::new(o[0] as f32, o[1] as f32, o[2] as f32);
+        let d1 = d0 - offset(o1) + Vec3::splat(G3);
+        let d2 = d0 - offset(o2) + Vec3::splat(2.0 * G3);
+        let d3 = d0 - Vec3::ONE + Vec3::splat(3.0 * G3);
+        let (i, j, k) = (cell.x as i32, cell.y as i32, cell.z as i32);
+
+        let corner = |d: Vec3, hash: u8| {
+            let t = 0.6 - d.length_squared();
+            if t < 0.0 { 0.0 } else { t * t * t * t * grad3(hash, d.x, d.y, d.z) }
+        };
+        let n = corner(d0, self.hash3(i, j, k))
+            + corner(d1, self.hash3(i + o1[0], j + o1[1], k + o1[2]))
+            + corner(d2, self.hash3(i + o2[0], j + o2[1], k + o2[2]))
+            + corner(d3, self.hash3(i + 1, j + 1, k + 1));
+        (32.0 * n).clamp(-1.0, 1.0)
+    }
+}
+
+/// 按周期回绕晶格坐标（`None` 表示不平铺）
+#[inline]
+fn wrap(coords: [i32; 2], period: Option<u32>) -> [i32; 2] {
+    match period {
+        Some(p) if p > 0 => coords.map(|c| c.rem_euclid(p as i32)),
+        _ => coords,
+    }
+}
+
+/// 分形布朗运动
+///
+/// 第 `i` 个倍频以 `frequency · lacunarityⁱ` 采样、振幅为 `gainⁱ`，结果按振幅总和归一化，
+/// 因此输出范围与基础噪声相同（约 `[-1, 1]`）。
+///
+/// 平铺采样（`sample*_tiled`）只支持 [`NoiseKind::Perlin`]：每个倍频的周期为
+/// `period · frequency · lacunarityⁱ`，`frequency` 与 `lacunarity` 取整数（如 2.0）时才无缝。
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fbm {
+    /// 基础噪声
+    pub kind: NoiseKind,
+    /// 倍频数
+    pub octaves: u32,
+    /// 基础频率
+    pub frequency: f32,
+    /// 相邻倍频的频率倍数
+    pub lacunarity: f32,
+    /// 相邻倍频的振幅倍数（persistence）
+    pub gain: f32,
+}
+
+impl Default for Fbm {
+    fn default() -> Self {
+        Self { kind: NoiseKind::Perlin, octaves: 5, frequency: 1.0, lacunarity: 2.0, gain: 0.5 }
+    }
+}
+
+impl Fbm {
+    /// 以默认参数（5 个倍频、lacunarity 2、gain 0.5）创建
+    pub fn new(kind: NoiseKind) -> Self {
+        Self { kind, ..Default::default() }
+    }
+
+    /// 设置倍频数（至少 1）
+    pub fn with_octaves(mut self, octaves: u32) -> Self {
+        self.octaves = octaves.max(1);
+        self
+    }
+
+    /// 设置基础频率
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// 设置频率倍数
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    /// 设置振幅倍数
+    pub fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// 逐倍频累加 `sample(octave_index, frequency)`，按振幅总和归一化
+    fn accumulate(&self, mut sample: impl FnMut(u32, f32) -> f32) -> f32 {
+        let (mut sum, mut norm) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (self.frequency, 1.0);
+        for octave in 0..self.octaves.max(1) {
+            sum += sample(octave, frequency) * amplitude;
+            norm += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+        if norm > 0.0 { sum / norm } else { 0.0 }
+    }
+
+    /// 1D 采样
+    pub fn sample1(&self, noise: &Noise, x: f32) -> f32 {
+        self.accumulate(|octave, f| {
+            // 每个倍频错开采样位置，避免原点附近各倍频同时为零
+            let x = x * f + octave as f32 * 17.31;
+            match self.kind {
+                NoiseKind::Perlin => noise.perlin1(x),
+                NoiseKind::Simplex => noise.simplex1(x),
+            }
+        })
+    }
+
+    /// 2D 采样
+    pub fn sample2(&self, noise: &Noise, p: Vec2) -> f32 {
+        self.accumulate(|octave, f| {
+            let p = p * f + Vec2::splat(octave as f32 * 17.31);
+            match self.kind {
+                NoiseKind::Perlin => noise.perlin2(p),
+                NoiseKind::Simplex => noise.simplex2(p),
+            }
+        })
+    }
+
+    /// 3D 采样
+    pub fn sample3(&self, noise: &Noise, p: Vec3) -> f32 {
+        self.accumulate(|octave, f| {
+            let p = p * f + Vec3::splat(octave as f32 * 17.31);
+            match self.kind {
+                NoiseKind::Perlin => noise.perlin3(p),
+                NoiseKind::Simplex => noise.simplex3(p),
+            }
+        })
+    }
+
+    /// 2D 平铺采样：`p` 以 `period` 为周期无缝重复（始终使用 Perlin）
+    pub fn sample2_tiled(&self, noise: &Noise, p: Vec2, period: f32) -> f32 {
+        self.accumulate(|octave, f| {
+            let lattice = (period * f).round().max(1.0) as u32;
+            // 平铺时偏移取整数，保持周期对齐
+            noise.perlin2_tiled(p * f + Vec2::splat(octave as f32 * 17.0), lattice, lattice)
+        })
+    }
+
+    /// 3D 平铺采样：`p` 的每个轴以 `period` 为周期无缝重复（始终使用 Perlin）
+    pub fn sample3_tiled(&self, noise: &Noise, p: Vec3, period: f32) -> f32 {
+        self.accumulate(|octave, f| {
+            let lattice = (period * f).round().max(1.0) as u32;
+            noise.perlin3_tiled(p * f + Vec3::splat(octave as f32 * 17.0), lattice)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid2() -> impl Iterator<Item = Vec2> {
+        (0..40).flat_map(|y| (0..40).map(move |x| Vec2::new(x as f32 * 0.173 - 3.1, y as f32 * 0.191 - 2.7)))
+    }
+
+    #[test]
+    fn test_perlin_lattice_zero_and_range() {
+        let noise = Noise::new(7);
+        assert_eq!(noise.perlin1(3.0), 0.0);
+        assert_eq!(noise.perlin2(Vec2::new(-2.0, 5.0)), 0.0);
+        assert_eq!(noise.perlin3(Vec3::new(1.0, 2.0, -4.0)), 0.0);
+
+        let values: Vec<f32> = grid2().map(|p| noise.perlin2(p)).collect();
+        assert!(values.iter().all(|v| (-1.0..=1.0).contains(v)));
+        // 不是常数
+        let spread = values.iter().copied().fold(f32::MIN, f32::max) - values.iter().copied().fold(f32::MAX, f32::min);
+        assert!(spread > 0.5);
+        for x in 0..200 {
+            let x = x as f32 * 0.137 - 9.0;
+            assert!((-1.0..=1.0).contains(&noise.perlin1(x)));
+            assert!((-1.0..=1.0).contains(&noise.simplex1(x)));
+            assert!((-1.0..=1.0).contains(&noise.perlin3(Vec3::new(x, x * 0.7, -x * 0.3))));
+        }
+    }
+
+    #[test]
+    fn test_simplex_continuity_and_seed() {
+        let a = Noise::new(1);
+        let b = Noise::new(2);
+        assert_eq!(a, Noise::new(1));
+        let mut differs = false;
+        for p in grid2() {
+            let v = a.simplex2(p);
+            assert!((-1.0..=1.0).contains(&v));
+            // 连续：相邻微小偏移的变化很小
+            assert!((v - a.simplex2(p + Vec2::splat(1e-3))).abs() < 0.05);
+            let q = p.extend(p.x * 0.5);
+            assert!((a.simplex3(q) - a.simplex3(q + Vec3::splat(1e-3))).abs() < 0.05);
+            differs |= (v - b.simplex2(p)).abs() > 1e-3;
+        }
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_tiled_variants_repeat() {
+        let noise = Noise::new(99);
+        for p in grid2() {
+            assert!((noise.perlin2_tiled(p, 4, 6) - noise.perlin2_tiled(p + Vec2::new(4.0, 6.0), 4, 6)).abs() < 1e-5);
+            let q = p.extend(p.y);
+            assert!((noise.perlin3_tiled(q, 5) - noise.perlin3_tiled(q + Vec3::new(5.0, 0.0, -5.0), 5)).abs() < 1e-5);
+        }
+        assert!((noise.perlin1_tiled(0.4, 3) - noise.perlin1_tiled(3.4, 3)).abs() < 1e-6);
+
+        let fbm = Fbm::default().with_octaves(3).with_frequency(2.0);
+        let p = Vec2::new(0.23, 0.71);
+        assert!((fbm.sample2_tiled(&noise, p, 1.0) - fbm.sample2_tiled(&noise, p + Vec2::X, 1.0)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_fbm_normalized() {
+        let noise = Noise::new(3);
+        for kind in [NoiseKind::Perlin, NoiseKind::Simplex] {
+            let fbm = Fbm::new(kind).with_octaves(6).with_gain(0.6).with_lacunarity(2.1);
+            for p in grid2() {
+                assert!((-1.0..=1.0).contains(&fbm.sample2(&noise, p)));
+                assert!((-1.0..=1.0).contains(&fbm.sample3(&noise, p.extend(0.5))));
+                assert!((-1.0..=1.0).contains(&fbm.sample1(&noise, p.x)));
+            }
+        }
+        // 单倍频即基础噪声
+        let single = Fbm::new(NoiseKind::Perlin).with_octaves(1);
+        let p = Vec2::new(1.3, 2.9);
+        assert_eq!(single.sample2(&noise, p), noise.perlin2(p));
+    }
+}
@@ -0,0 +1,377 @@
+//! # 随机数
+//!
+//! 可设定种子、结果可复现的随机数工具，用于回放、网络同步与程序化生成。
+//!
+//! - [`Rng`]: PCG32（XSH-RR）生成器，64 位状态 + 可选流编号，种子经 SplitMix64 扩散
+//! - [`GlobalRng`]: ECS 资源（`bevy_ecs` 特性），保存根种子并按名称派生独立流
+//!
+//! 同一种子、同一调用序列在所有平台上产生相同结果。按名称派生的流
+//! （[`GlobalRng::stream`]）只取决于根种子与名称，与其它系统消耗了多少随机数无关，
+//! 因此新增或重排系统不会扰乱既有系统的随机序列。
+//!
+//! ```rust
+//! use anvilkit_core::rng::Rng;
+//!
+//! let mut a = Rng::seed_from_u64(1234);
+//! let mut b = Rng::seed_from_u64(1234);
+//! assert_eq!(a.next_u32(), b.next_u32());
+//!
+//! let damage = a.range(10.0..20.0);
+//! assert!((10.0..20.0).contains(&damage));
+//!
+//! let loot = ["common", "rare", "epic"];
+//! let pick = a.weighted_pick(&[70.0, 25.0, 5.0]).unwrap();
+//! assert!(pick < loot.len());
+//! ```
+
+use std::f32::consts::TAU;
+use std::ops::Range;
+
+use glam::{Vec2, Vec3};
+
+const PCG_MULTIPLIER: u64 = 6364136223846793005;
+
+/// 默认种子（[`GlobalRng::default`] 使用，保证未显式设种时也可复现）
+pub const DEFAULT_SEED: u64 = 0x5EED_A4B1_1C17;
+
+/// SplitMix64 单步：把任意 64 位输入扩散为统计质量良好的输出
+pub fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// 名称哈希（FNV-1a），跨平台与跨版本稳定
+fn name_hash(name: &str) -> u64 {
+    name.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// PCG32 随机数生成器
+///
+/// 状态只有 16 字节，可廉价复制与序列化（回放时保存 / 恢复）。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rng {
+    state: u64,
+    increment: u64,
+}
+
+impl Rng {
+    /// 按 PCG 参考实现（`pcg32_srandom`）以种子与流编号初始化
+    ///
+    /// 不同流编号产生互不相关的序列。
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self { state: 0, increment: (stream << 1) | 1 };
+        rng.step();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.step();
+        rng
+    }
+
+    /// 由单个 64 位种子初始化；种子经 SplitMix64 扩散后同时决定状态与流
+    pub fn seed_from_u64(seed: u64) -> Self {
+        let mixed = splitmix64(seed);
+        Self::with_stream(mixed, splitmix64(mixed))
+    }
+
+    /// 从本机熵源（进程随机哈希键）初始化，结果不可复现
+    pub fn from_entropy() -> Self {
+        use std::hash::{BuildHasher, Hasher};
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u64(DEFAULT_SEED);
+        Self::seed_from_u64(hasher.finish())
+    }
+
+    /// 派生一个新的独立生成器（消耗本生成器的两个输出）
+    pub fn fork(&mut self) -> Self {
+        let seed = self.next_u64();
+        Self::with_stream(seed, self.next_u64())
+    }
+
+    fn step(&mut self) {
+        self.state = self.state.wrapping_mul(PCG_MULTIPLIER).wrapping_add(self.increment);
+    }
+
+    /// 下一个均匀分布的 `u32`
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.step();
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        xorshifted.rotate_right((old >> 59) as u32)
+    }
+
+    /// 下一个均匀分布的 `u64`
+    pub fn next_u64(&mut self) -> u64 {
+        let high = self.next_u32() as u64;
+        (high << 32) | self.next_u32() as u64
+    }
+
+    /// `[0, 1)` 内均匀分布的 `f32`（24 位精度）
+    pub fn f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+    }
+
+    /// `[0, 1)` 内均匀分布的 `f64`（53 位精度）
+    pub fn f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// 以概率 `p` 返回 `true`
+    pub fn chance(&mut self, p: f32) -> bool {
+        self.f32() < p
+    }
+
+    /// `[0, len)` 内无偏的随机下标（Lemire 乘法拒绝法）
+    ///
+    /// # Panics
+    ///
+    /// `len` 为 0 或超过 `u32::MAX` 时 panic。
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "Rng::index: len 必须大于 0");
+        let n = u32::try_from(len).expect("Rng::index: len 超出 u32 范围");
+        let mut m = self.next_u32() as u64 * n as u64;
+        if (m as u32) < n {
+            let threshold = n.wrapping_neg() % n;
+            while (m as u32) < threshold {
+                m = self.next_u32() as u64 * n as u64;
+            }
+        }
+        (m >> 32) as usize
+    }
+
+    /// `[start, end)` 内均匀分布的 `f32`；空区间返回 `start`
+    pub fn range(&mut self, range: Range<f32>) -> f32 {
+        if range.end <= range.start {
+            return range.start;
+        }
+        let x = range.start + (range.end - range.start) * self.f32();
+        // 舍入可能恰好落到上界
+        if x < range.end { x } else { range.start }
+    }
+
+    /// `[start, end)` 内均匀分布的 `i32`；空区间返回 `start`
+    pub fn range_i32(&mut self, range: Range<i32>) -> i32 {
+        if range.end <= range.start {
+            return range.start;
+        }
+        let span = range.end.wrapping_sub(range.start) as u32;
+        range.start.wrapping_add(self.index(span as usize) as i32)
+    }
+
+    /// 单位圆上均匀分布的方向
+    pub fn unit_vector2(&mut self) -> Vec2 {
+        let (sin, cos) = (self.f32() * TAU).sin_cos();
+        Vec2::new(cos, sin)
+    }
+
+    /// 单位球面上均匀分布的方向
+    pub fn unit_vector3(&mut self) -> Vec3 {
+        let z = self.f32() * 2.0 - 1.0;
+        let (sin, cos) = (self.f32() * TAU).sin_cos();
+        let r = (1.0 - z * z).max(0.0).sqrt();
+        Vec3::new(r * cos, r * sin, z)
+    }
+
+    /// 半径 `radius` 的圆盘内均匀分布的点
+    pub fn inside_circle(&mut self, radius: f32) -> Vec2 {
+        self.unit_vector2() * radius * self.f32().sqrt()
+    }
+
+    /// 半径 `radius` 的球体内均匀分布的点
+    pub fn inside_sphere(&mut self, radius: f32) -> Vec3 {
+        self.unit_vector3() * radius * self.f32().cbrt()
+    }
+
+    /// 按权重随机选取下标；非正数与非有限权重视为 0，全部为 0 时返回 `None`
+    pub fn weighted_pick(&mut self, weights: &[f32]) -> Option<usize> {
+        let weight = |w: f32| if w.is_finite() && w > 0.0 { w } else { 0.0 };
+        let total: f32 = weights.iter().map(|&w| weight(w)).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut target = self.f32() * total;
+        let mut last = None;
+        for (i, &w) in weights.iter().enumerate() {
+            let w = weight(w);
+            if w == 0.0 {
+                continue;
+            }
+            if target < w {
+                return Some(i);
+            }
+            target -= w;
+            last = Some(i);
+        }
+        // 浮点累加误差落到末尾时取最后一个有效项
+        last
+    }
+
+    /// 随机选取切片中的一个元素
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(&items[self.index(items.len())])
+        }
+    }
+
+    /// Fisher–Yates 原地洗牌
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::seed_from_u64(DEFAULT_SEED)
+    }
+}
+
+/// 全局随机数资源
+///
+/// 保存根种子以便回放时重建；直接解引用为 [`Rng`] 使用共享流，
+/// 或用 [`stream`](Self::stream) 为每个系统派生独立、稳定的流：
+///
+/// ```rust
+/// use anvilkit_core::rng::GlobalRng;
+///
+/// let global = GlobalRng::new(7);
+/// let mut spawner = global.stream("spawner");
+/// let mut loot = global.stream("loot");
+/// assert_ne!(spawner.next_u32(), loot.next_u32());
+/// // 同名流总是从同一位置开始
+/// assert_eq!(global.stream("loot"), GlobalRng::new(7).stream("loot"));
+/// ```
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::system::Resource))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalRng {
+    seed: u64,
+    rng: Rng,
+}
+
+impl GlobalRng {
+    /// 以根种子创建
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: Rng::seed_from_u64(seed) }
+    }
+
+    /// 根种子
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// 重新设定根种子并重置共享流（回放开始时调用）
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// 由根种子与名称派生的独立流，不受共享流消耗的影响
+    pub fn stream(&self, name: &str) -> Rng {
+        let key = splitmix64(self.seed ^ name_hash(name));
+        Rng::with_stream(key, splitmix64(key))
+    }
+
+    /// 从共享流派生一个新生成器（结果依赖调用顺序）
+    pub fn fork(&mut self) -> Rng {
+        self.rng.fork()
+    }
+}
+
+impl Default for GlobalRng {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl std::ops::Deref for GlobalRng {
+    type Target = Rng;
+
+    fn deref(&self) -> &Rng {
+        &self.rng
+    }
+}
+
+impl std::ops::DerefMut for GlobalRng {
+    fn deref_mut(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcg32_reference_output() {
+        // pcg32-demo: pcg32_srandom(42, 54)
+        let mut rng = Rng::with_stream(42, 54);
+        assert_eq!(rng.next_u32(), 0xa15c02b7);
+        assert_eq!(rng.next_u32(), 0x7b47f409);
+        assert_eq!(rng.next_u32(), 0xba1d3330);
+    }
+
+    #[test]
+    fn test_determinism_and_streams() {
+        let seq = |rng: &mut Rng| (0..8).map(|_| rng.next_u32()).collect::<Vec<_>>();
+        assert_eq!(seq(&mut Rng::seed_from_u64(9)), seq(&mut Rng::seed_from_u64(9)));
+        assert_ne!(seq(&mut Rng::seed_from_u64(9)), seq(&mut Rng::seed_from_u64(10)));
+
+        let mut global = GlobalRng::new(3);
+        let before = global.stream("ai");
+        global.next_u64();
+        let _ = global.fork();
+        assert_eq!(global.stream("ai"), before);
+        assert_ne!(global.stream("ai"), global.stream("audio"));
+
+        global.reseed(3);
+        assert_eq!(global, GlobalRng::new(3));
+    }
+
+    #[test]
+    fn test_ranges_and_geometry() {
+        let mut rng = Rng::seed_from_u64(1);
+        let mut hits = [0u32; 5];
+        for _ in 0..5000 {
+            let x = rng.range(-2.0..3.0);
+            assert!((-2.0..3.0).contains(&x));
+            let i = rng.range_i32(-2..3);
+            assert!((-2..3).contains(&i));
+            hits[(i + 2) as usize] += 1;
+            assert!((rng.unit_vector2().length() - 1.0).abs() < 1e-5);
+            assert!((rng.unit_vector3().length() - 1.0).abs() < 1e-5);
+            assert!(rng.inside_circle(2.0).length() <= 2.0 + 1e-5);
+            assert!(rng.inside_sphere(0.5).length() <= 0.5 + 1e-5);
+        }
+        assert!(hits.iter().all(|&h| h > 800), "{hits:?}");
+        assert_eq!(rng.range(1.0..1.0), 1.0);
+        assert_eq!(rng.range_i32(i32::MIN..i32::MIN), i32::MIN);
+        let full = rng.range_i32(i32::MIN..i32::MAX);
+        assert!(full < i32::MAX);
+    }
+
+    #[test]
+    fn test_weighted_pick_and_shuffle() {
+        let mut rng = Rng::seed_from_u64(5);
+        let mut counts = [0u32; 4];
+        for _ in 0..10_000 {
+            counts[rng.weighted_pick(&[1.0, 0.0, 3.0, f32::NAN]).unwrap()] += 1;
+        }
+        assert_eq!(counts[1] + counts[3], 0);
+        let ratio = counts[2] as f32 / counts[0] as f32;
+        assert!((2.6..3.4).contains(&ratio), "{counts:?}");
+        assert_eq!(rng.weighted_pick(&[0.0, -1.0]), None);
+        assert_eq!(rng.pick::<u8>(&[]), None);
+
+        let mut items: Vec<u32> = (0..32).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..32).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..32).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,311 @@
+//! 层次包围盒（BVH）
+//!
+//! [`Bvh`] 是以 [`Aabb`] 为节点包围体的二叉树，自顶向下按质心最长轴的中位数划分构建。
+//! 对象移动后可调用 [`Bvh::refit`] 原地更新叶子并沿父链重新计算包围盒，无需重建；
+//! 拓扑不变，因此大量对象长距离移动后查询效率会下降，此时应重新 [`Bvh::build`]。
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use glam::Vec3;
+
+use crate::math::raycast::ray_aabb_intersection;
+use crate::math::{Aabb, Frustum};
+
+/// 无父节点标记
+const NO_PARENT: usize = usize::MAX;
+
+#[derive(Debug, Clone, Copy)]
+enum NodeKind<K> {
+    Leaf(K),
+    /// 左、右子节点索引
+    Internal(usize, usize),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BvhNode<K> {
+    bounds: Aabb,
+    parent: usize,
+    kind: NodeKind<K>,
+}
+
+/// 层次包围盒
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::Aabb;
+/// use anvilkit_core::spatial::Bvh;
+/// use glam::Vec3;
+///
+/// let unit = Aabb::default();
+/// let mut bvh = Bvh::build((0..10u32).map(|i| (i, unit.translated(Vec3::X * i as f32 * 3.0))));
+///
+/// let hit = bvh.ray_cast(Vec3::new(-5.0, 0.0, 0.0), Vec3::X, 100.0);
+/// assert_eq!(hit, Some((0, 4.5)));
+///
+/// bvh.refit(0, unit.translated(Vec3::Y * 10.0));
+/// assert_eq!(bvh.ray_cast(Vec3::new(-5.0, 0.0, 0.0), Vec3::X, 100.0), Some((1, 7.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bvh<K: Copy + Eq + Hash> {
+    nodes: Vec<BvhNode<K>>,
+    root: Option<usize>,
+    /// 键 → 叶子节点索引
+    leaves: HashMap<K, usize>,
+}
+
+impl<K: Copy + Eq + Hash> Default for Bvh<K> {
+    fn default() -> Self {
+        Self { nodes: Vec::new(), root: None, leaves: HashMap::new() }
+    }
+}
+
+impl<K: Copy + Eq + Hash> Bvh<K> {
+    /// 从 (键, 包围盒) 列表构建；重复的键只保留最后一个
+    pub fn build(items: impl IntoIterator<Item = (K, Aabb)>) -> Self {
+        let mut unique: HashMap<K, Aabb> = HashMap::new();
+        unique.extend(items);
+        let mut items: Vec<(K, Aabb)> = unique.into_iter().collect();
+
+        let mut bvh = Self {
+            nodes: Vec::with_capacity(items.len() * 2),
+            root: None,
+            leaves: HashMap::with_capacity(items.len()),
+        };
+        if !items.is_empty() {
+            bvh.root = Some(bvh.build_node(&mut items, NO_PARENT));
+        }
+        bvh
+    }
+
+    fn build_node(&mut self, items: &mut [(K, Aabb)], parent: usize) -> usize {
+        let index = self.nodes.len();
+        if let [(key, bounds)] = items {
+            self.nodes.push(BvhNode { bounds: *bounds, parent, kind: NodeKind::Leaf(*key) });
+            self.leaves.insert(*key, index);
+            return index;
+        }
+
+        // 占位，子树构建完成后回填
+        self.nodes.push(BvhNode { bounds: items[0].1, parent, kind: NodeKind::Internal(0, 0) });
+
+        let (lo, hi) = items.iter().fold(
+            (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+            |(lo, hi), (_, b)| (lo.min(b.center()), hi.max(b.center())),
+        );
+        let spread = hi - lo;
+        let axis = if spread.x >= spread.y && spread.x >= spread.z {
+            0
+        } else if spread.y >= spread.z {
+            1
+        } else {
+            2
+        };
+        let mid = items.len() / 2;
+        items.select_nth_unstable_by(mid, |a, b| a.1.center()[axis].total_cmp(&b.1.center()[axis]));
+
+        let (left_items, right_items) = items.split_at_mut(mid);
+        let left = self.build_node(left_items, index);
+        let right = self.build_node(right_items, index);
+        self.nodes[index].bounds = self.nodes[left].bounds.union(&self.nodes[right].bounds);
+        self.nodes[index].kind = NodeKind::Internal(left, right);
+        index
+    }
+
+    /// 存储的键数量
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// 是否包含键
+    pub fn contains(&self, key: K) -> bool {
+        self.leaves.contains_key(&key)
+    }
+
+    /// 键当前的包围盒
+    pub fn get(&self, key: K) -> Option<Aabb> {
+        self.leaves.get(&key).map(|&i| self.nodes[i].bounds)
+    }
+
+    /// 整棵树的包围盒
+    pub fn bounds(&self) -> Option<Aabb> {
+        self.root.map(|r| self.nodes[r].bounds)
+    }
+
+    /// 更新键的包围盒并重新计算其所有祖先节点；键不存在时返回 false
+    pub fn refit(&mut self, key: K, bounds: Aabb) -> bool {
+        let Some(&leaf) = self.leaves.get(&key) else { return false };
+        self.nodes[leaf].bounds = bounds;
+
+        let mut node = self.nodes[leaf].parent;
+        while node != NO_PARENT {
+            if let NodeKind::Internal(left, right) = self.nodes[node].kind {
+                self.nodes[node].bounds = self.nodes[left].bounds.union(&self.nodes[right].bounds);
+            }
+            node = self.nodes[node].parent;
+        }
+        true
+    }
+
+    /// 深度优先遍历 `visit_node` 返回 true 的节点，对其中的叶子调用 `visit_leaf`
+    fn traverse(&self, mut visit_node: impl FnMut(&Aabb) -> bool, mut visit_leaf: impl FnMut(K, &Aabb)) {
+        let Some(root) = self.root else { return };
+        let mut stack = vec![root];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !visit_node(&node.bounds) {
+                continue;
+            }
+            match node.kind {
+                NodeKind::Leaf(key) => visit_leaf(key, &node.bounds),
+                NodeKind::Internal(left, right) => stack.extend([left, right]),
+            }
+        }
+    }
+
+    /// 将包围盒与 `region` 相交的键追加到 `out`
+    pub fn overlap_aabb(&self, region: &Aabb, out: &mut Vec<K>) {
+        self.traverse(|bounds| bounds.intersects(region), |key, _| out.push(key));
+    }
+
+    /// 将包围盒与视锥体相交的键追加到 `out`
+    pub fn query_frustum(&self, frustum: &Frustum, out: &mut Vec<K>) {
+        self.traverse(|bounds| frustum.intersects_bounds(bounds), |key, _| out.push(key));
+    }
+
+    /// 射线最近命中的键及命中参数 `t`（`t <= max_distance`）
+    ///
+    /// 近的子节点优先访问，并跳过比当前最近命中更远的子树。
+    pub fn ray_cast(&self, origin: Vec3, direction: Vec3, max_distance: f32) -> Option<(K, f32)> {
+        let root = self.root?;
+        if direction == Vec3::ZERO {
+            return None;
+        }
+
+        let mut best: Option<(K, f32)> = None;
+        let mut stack = vec![(root, ray_aabb_intersection(origin, direction, &self.nodes[root].bounds)?)];
+        while let Some((index, t_enter)) = stack.pop() {
+            let limit = best.map_or(max_distance, |(_, t)| t);
+            if t_enter > limit {
+                continue;
+            }
+            match self.nodes[index].kind {
+                NodeKind::Leaf(key) => best = Some((key, t_enter)),
+                NodeKind::Internal(left, right) => {
+                    let hit = |i: usize| ray_aabb_intersection(origin, direction, &self.nodes[i].bounds).map(|t| (i, t));
+                    let (near, far) = match (hit(left), hit(right)) {
+                        (Some(a), Some(b)) if a.1 <= b.1 => (Some(a), Some(b)),
+                        (Some(a), Some(b)) => (Some(b), Some(a)),
+                        (a, b) => (a.or(b), None),
+                    };
+                    // 栈为后进先出：远的先压栈
+                    stack.extend(far);
+                    stack.extend(near);
+                }
+            }
+        }
+        best
+    }
+
+    /// 所有包围盒相交的键对（宽相位碰撞候选），每对只报告一次
+    pub fn overlapping_pairs(&self, out: &mut Vec<(K, K)>) {
+        for (&key, &leaf) in &self.leaves {
+            let bounds = self.nodes[leaf].bounds;
+            let mut stack = self.root.into_iter().collect::<Vec<_>>();
+            while let Some(index) = stack.pop() {
+                let node = &self.nodes[index];
+                if !node.bounds.intersects(&bounds) {
+                    continue;
+                }
+                match node.kind {
+                    // 以叶子索引定序，避免 (a, b) 与 (b, a) 重复
+                    NodeKind::Leaf(other) if index > leaf => out.push((key, other)),
+                    NodeKind::Leaf(_) => {}
+                    NodeKind::Internal(left, right) => stack.extend([left, right]),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Mat4;
+
+    fn cube(center: Vec3, half: f32) -> Aabb {
+        Aabb::from_min_max(center - Vec3::splat(half), center + Vec3::splat(half))
+    }
+
+    fn grid() -> Bvh<u32> {
+        Bvh::build((0..64u32).map(|i| {
+            let p = Vec3::new((i % 4) as f32, ((i / 4) % 4) as f32, (i / 16) as f32) * 4.0;
+            (i, cube(p, 0.5))
+        }))
+    }
+
+    #[test]
+    fn test_build_and_overlap() {
+        let bvh = grid();
+        assert_eq!(bvh.len(), 64);
+        let total = bvh.bounds().unwrap();
+        assert_eq!(total.min, Vec3::splat(-0.5));
+        assert_eq!(total.max, Vec3::splat(12.5));
+
+        let mut out = Vec::new();
+        bvh.overlap_aabb(&cube(Vec3::ZERO, 1.0), &mut out);
+        assert_eq!(out, vec![0]);
+
+        out.clear();
+        bvh.overlap_aabb(&Aabb::from_min_max(Vec3::splat(-1.0), Vec3::new(5.0, 1.0, 1.0)), &mut out);
+        out.sort();
+        assert_eq!(out, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_ray_cast_nearest() {
+        let bvh = grid();
+        assert_eq!(bvh.ray_cast(Vec3::new(-10.0, 4.0, 0.0), Vec3::X, 100.0), Some((4, 9.5)));
+        assert_eq!(bvh.ray_cast(Vec3::new(20.0, 4.0, 0.0), Vec3::NEG_X, 100.0), Some((7, 7.5)));
+        assert_eq!(bvh.ray_cast(Vec3::new(-10.0, 4.0, 0.0), Vec3::X, 5.0), None);
+        assert_eq!(bvh.ray_cast(Vec3::new(-10.0, 2.0, 0.0), Vec3::X, 100.0), None);
+    }
+
+    #[test]
+    fn test_refit_moves_leaf() {
+        let mut bvh = grid();
+        assert!(bvh.refit(5, cube(Vec3::splat(100.0), 0.5)));
+        assert!(!bvh.refit(999, cube(Vec3::ZERO, 1.0)));
+        assert_eq!(bvh.bounds().unwrap().max, Vec3::splat(100.5));
+
+        let mut out = Vec::new();
+        bvh.overlap_aabb(&cube(Vec3::splat(100.0), 1.0), &mut out);
+        assert_eq!(out, vec![5]);
+        assert_eq!(bvh.ray_cast(Vec3::new(-10.0, 4.0, 0.0), Vec3::X, 100.0), Some((4, 9.5)));
+        assert_eq!(bvh.ray_cast(Vec3::new(-10.0, 4.0, 4.0), Vec3::X, 100.0), Some((20, 9.5)));
+    }
+
+    #[test]
+    fn test_query_frustum_and_pairs() {
+        let bvh = Bvh::build([(1u32, cube(Vec3::ZERO, 1.0)), (2, cube(Vec3::X, 1.0)), (3, cube(Vec3::splat(50.0), 1.0))]);
+
+        let view = Mat4::look_at_lh(Vec3::new(0.0, 0.0, -10.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 30.0);
+        let mut visible = Vec::new();
+        bvh.query_frustum(&Frustum::from_view_proj(&(proj * view)), &mut visible);
+        visible.sort();
+        assert_eq!(visible, vec![1, 2]);
+
+        let mut pairs = Vec::new();
+        bvh.overlapping_pairs(&mut pairs);
+        assert_eq!(pairs.len(), 1);
+        let (a, b) = pairs[0];
+        assert_eq!((a.min(b), a.max(b)), (1, 2));
+    }
+}
@@ -0,0 +1,320 @@
+//! 均匀网格空间哈希
+//!
+//! [`SpatialHash2D`] 将平面划分为边长 `cell_size` 的正方形格子，每个键登记到其包围体
+//! 覆盖的所有格子中。只有被占用的格子会被存储，因此世界范围不受限制。
+//!
+//! 格子尺寸宜取对象典型尺寸的 1～4 倍：过小时大对象会登记到大量格子，
+//! 过大时每个格子的候选对象过多。
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use glam::{IVec2, Vec2};
+
+use super::{sort_hits, SpatialIndex2D};
+use crate::math::Bounds2D;
+
+/// 均匀网格空间哈希
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::Bounds2D;
+/// use anvilkit_core::spatial::{SpatialHash2D, SpatialIndex2D};
+/// use glam::Vec2;
+///
+/// let mut grid = SpatialHash2D::new(4.0);
+/// grid.insert(1u32, Bounds2D::from_center_half_extents(Vec2::new(1.0, 1.0), Vec2::splat(0.5)));
+/// grid.insert(2u32, Bounds2D::from_center_half_extents(Vec2::new(20.0, 1.0), Vec2::splat(0.5)));
+///
+/// let mut near = Vec::new();
+/// grid.query_region(&Bounds2D::from_min_max(Vec2::ZERO, Vec2::splat(5.0)), &mut near);
+/// assert_eq!(near, vec![1]);
+///
+/// let mut hits = Vec::new();
+/// grid.query_ray(Vec2::new(0.0, 1.0), Vec2::X, 100.0, &mut hits);
+/// assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), vec![1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::system::Resource))]
+pub struct SpatialHash2D<K: Copy + Eq + Hash> {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<K>>,
+    entries: HashMap<K, Bounds2D>,
+    /// 所有登记过的包围体的并集（删除时不收缩，只用于裁剪射线）
+    extent: Option<Bounds2D>,
+}
+
+impl<K: Copy + Eq + Hash> SpatialHash2D<K> {
+    /// 以格子边长创建
+    ///
+    /// # Panics
+    ///
+    /// `cell_size` 不是正有限数时 panic。
+    pub fn new(cell_size: f32) -> Self {
+        assert!(cell_size.is_finite() && cell_size > 0.0, "cell_size 必须为正数");
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: HashMap::new(),
+            extent: None,
+        }
+    }
+
+    /// 格子边长
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// 被占用的格子数量
+    pub fn occupied_cells(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// 点所在格子坐标
+    pub fn cell_of(&self, point: Vec2) -> IVec2 {
+        (point / self.cell_size).floor().as_ivec2()
+    }
+
+    /// 包围体覆盖的格子范围（含两端）
+    fn cell_range(&self, bounds: &Bounds2D) -> (IVec2, IVec2) {
+        (self.cell_of(bounds.min), self.cell_of(bounds.max))
+    }
+
+    fn link(&mut self, key: K, bounds: &Bounds2D) {
+        let (lo, hi) = self.cell_range(bounds);
+        for y in lo.y..=hi.y {
+            for x in lo.x..=hi.x {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(key);
+            }
+        }
+    }
+
+    fn unlink(&mut self, key: K, bounds: &Bounds2D) {
+        let (lo, hi) = self.cell_range(bounds);
+        for y in lo.y..=hi.y {
+            for x in lo.x..=hi.x {
+                let cell = IVec2::new(x, y);
+                if let Some(keys) = self.cells.get_mut(&cell) {
+                    if let Some(i) = keys.iter().position(|k| *k == key) {
+                        keys.swap_remove(i);
+                    }
+                    if keys.is_empty() {
+                        self.cells.remove(&cell);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 键登记在格子 `cell` 中且与查询范围 `[lo, hi]` 相交时，仅在重叠区域的第一个格子报告，
+    /// 从而不借助额外集合完成去重
+    fn is_first_cell(&self, bounds: &Bounds2D, cell: IVec2, lo: IVec2) -> bool {
+        self.cell_of(bounds.min).max(lo) == cell
+    }
+}
+
+impl<K: Copy + Eq + Hash> SpatialIndex2D<K> for SpatialHash2D<K> {
+    fn insert(&mut self, key: K, bounds: Bounds2D) {
+        if let Some(old) = self.entries.insert(key, bounds) {
+            self.unlink(key, &old);
+        }
+        self.link(key, &bounds);
+        self.extent = Some(self.extent.map_or(bounds, |e| e.union(&bounds)));
+    }
+
+    fn remove(&mut self, key: K) -> Option<Bounds2D> {
+        let bounds = self.entries.remove(&key)?;
+        self.unlink(key, &bounds);
+        Some(bounds)
+    }
+
+    fn update(&mut self, key: K, bounds: Bounds2D) {
+        // 覆盖的格子不变时只替换包围体
+        let same_cells = self.entries.get(&key)
+            .is_some_and(|old| self.cell_range(old) == self.cell_range(&bounds));
+        if same_cells {
+            self.entries.insert(key, bounds);
+            self.extent = Some(self.extent.map_or(bounds, |e| e.union(&bounds)));
+        } else {
+            self.insert(key, bounds);
+        }
+    }
+
+    fn get(&self, key: K) -> Option<Bounds2D> {
+        self.entries.get(&key).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+        self.extent = None;
+    }
+
+    fn query_region(&self, region: &Bounds2D, out: &mut Vec<K>) {
+        let (lo, hi) = self.cell_range(region);
+        let mut visit = |cell: IVec2, keys: &Vec<K>| {
+            for &key in keys {
+                let bounds = &self.entries[&key];
+                if self.is_first_cell(bounds, cell, lo) && bounds.intersects(region) {
+                    out.push(key);
+                }
+            }
+        };
+
+        // 查询范围覆盖的格子多于已占用格子时，直接遍历已占用格子
+        let span = hi - lo + IVec2::ONE;
+        if span.x as i64 * span.y as i64 > self.cells.len() as i64 {
+            for (&cell, keys) in &self.cells {
+                if cell.cmpge(lo).all() && cell.cmple(hi).all() {
+                    visit(cell, keys);
+                }
+            }
+        } else {
+            for y in lo.y..=hi.y {
+                for x in lo.x..=hi.x {
+                    let cell = IVec2::new(x, y);
+                    if let Some(keys) = self.cells.get(&cell) {
+                        visit(cell, keys);
+                    }
+                }
+            }
+        }
+    }
+
+    fn query_ray(&self, origin: Vec2, direction: Vec2, max_distance: f32, out: &mut Vec<(K, f32)>) {
+        let Some(extent) = self.extent else { return };
+        if direction == Vec2::ZERO || self.entries.is_empty() {
+            return;
+        }
+
+        // 将射线裁剪到所有对象的并集范围内，再用 DDA 逐格遍历
+        let inv = direction.recip();
+        let t1 = (extent.min - origin) * inv;
+        let t2 = (extent.max - origin) * inv;
+        let t_enter = t1.min(t2).max_element().max(0.0);
+        let t_exit = t1.max(t2).min_element().min(max_distance);
+        if t_enter > t_exit {
+            return;
+        }
+
+        let start = origin + direction * t_enter;
+        let mut cell = self.cell_of(start);
+        let end_cell = self.cell_of(origin + direction * t_exit);
+        let step = IVec2::new(direction.x.signum() as i32, direction.y.signum() as i32);
+        let next_boundary = (cell.as_vec2() + step.max(IVec2::ZERO).as_vec2()) * self.cell_size;
+        let mut t_max = Vec2::select(
+            direction.cmpne(Vec2::ZERO),
+            t_enter + (next_boundary - start) * inv,
+            Vec2::splat(f32::INFINITY),
+        );
+        let t_delta = (Vec2::splat(self.cell_size) * inv).abs();
+
+        let first = out.len();
+        let mut seen = HashSet::new();
+        loop {
+            if let Some(keys) = self.cells.get(&cell) {
+                for &key in keys {
+                    if !seen.insert(key) {
+                        continue;
+                    }
+                    if let Some(t) = self.entries[&key].ray_intersection(origin, direction) {
+                        if t <= max_distance {
+                            out.push((key, t));
+                        }
+                    }
+                }
+            }
+            if cell == end_cell || t_max.min_element() > t_exit {
+                break;
+            }
+            if t_max.x < t_max.y {
+                cell.x += step.x;
+                t_max.x += t_delta.x;
+            } else {
+                cell.y += step.y;
+                t_max.y += t_delta.y;
+            }
+        }
+        sort_hits(&mut out[first..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(center: Vec2, half: f32) -> Bounds2D {
+        Bounds2D::from_center_half_extents(center, Vec2::splat(half))
+    }
+
+    #[test]
+    fn test_insert_remove_update() {
+        let mut grid = SpatialHash2D::new(1.0);
+        grid.insert(1, square(Vec2::ZERO, 1.5));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.occupied_cells(), 16);
+
+        grid.update(1, square(Vec2::new(10.5, 10.5), 0.25));
+        assert_eq!(grid.occupied_cells(), 1);
+        assert_eq!(grid.get(1), Some(square(Vec2::new(10.5, 10.5), 0.25)));
+
+        assert!(grid.remove(1).is_some());
+        assert!(grid.is_empty());
+        assert_eq!(grid.occupied_cells(), 0);
+        assert!(grid.remove(1).is_none());
+    }
+
+    #[test]
+    fn test_query_region_reports_each_key_once() {
+        let mut grid = SpatialHash2D::new(1.0);
+        grid.insert(1, square(Vec2::ZERO, 2.0));
+        grid.insert(2, square(Vec2::new(3.0, 0.0), 0.4));
+        grid.insert(3, square(Vec2::new(-8.0, 0.0), 0.4));
+
+        let mut out = Vec::new();
+        grid.query_region(&Bounds2D::from_min_max(Vec2::splat(-1.0), Vec2::new(4.0, 1.0)), &mut out);
+        out.sort();
+        assert_eq!(out, vec![1, 2]);
+
+        // 大范围查询走已占用格子遍历路径
+        out.clear();
+        grid.query_region(&square(Vec2::ZERO, 1000.0), &mut out);
+        out.sort();
+        assert_eq!(out, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_query_ray_sorted_and_limited() {
+        let mut grid = SpatialHash2D::new(2.0);
+        grid.insert(1, square(Vec2::new(5.0, 0.0), 0.5));
+        grid.insert(2, square(Vec2::new(2.0, 0.0), 0.5));
+        grid.insert(3, square(Vec2::new(2.0, 5.0), 0.5));
+
+        let mut hits = Vec::new();
+        grid.query_ray(Vec2::ZERO, Vec2::X, 100.0, &mut hits);
+        assert_eq!(hits, vec![(2, 1.5), (1, 4.5)]);
+
+        hits.clear();
+        grid.query_ray(Vec2::ZERO, Vec2::X, 3.0, &mut hits);
+        assert_eq!(hits, vec![(2, 1.5)]);
+
+        hits.clear();
+        grid.query_ray(Vec2::new(10.0, 0.0), Vec2::NEG_X, 100.0, &mut hits);
+        assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_query_ray_diagonal_negative_cells() {
+        let mut grid = SpatialHash2D::new(1.0);
+        grid.insert(7, square(Vec2::new(-3.5, -3.5), 0.25));
+        let mut hits = Vec::new();
+        grid.query_ray(Vec2::ZERO, Vec2::new(-1.0, -1.0).normalize(), 100.0, &mut hits);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].0, 7);
+    }
+}
@@ -0,0 +1,70 @@
+//! # 空间索引
+//!
+//! 宽相位（broad-phase）查询使用的加速结构，按包围体快速找出区域或射线附近的对象，
+//! 避免对全部对象逐一做相交测试。
+//!
+//! ## 模块组织
+//!
+//! - [`hash_grid`]: 均匀网格空间哈希，适合尺寸相近、分布较均匀的对象
+//! - [`quadtree`]: 区域四叉树，适合尺寸差异大或分布稀疏的对象
+//! - [`bvh`]: 3D 层次包围盒，支持移动后原地 refit，用于射线、视锥体与重叠查询
+//!
+//! 前两者实现 [`SpatialIndex2D`]，可按键（通常为 `Entity`）增删改并执行区域与射线查询。
+
+pub mod bvh;
+pub mod hash_grid;
+pub mod quadtree;
+
+pub use bvh::Bvh;
+pub use hash_grid::SpatialHash2D;
+pub use quadtree::Quadtree;
+
+use std::hash::Hash;
+
+use glam::Vec2;
+
+use crate::math::Bounds2D;
+
+/// 2D 空间索引
+///
+/// 以键 `K` 存储 [`Bounds2D`]；同一键重复插入会替换旧包围体。
+pub trait SpatialIndex2D<K: Copy + Eq + Hash> {
+    /// 插入（或替换）键的包围体
+    fn insert(&mut self, key: K, bounds: Bounds2D);
+
+    /// 移除键，返回其包围体
+    fn remove(&mut self, key: K) -> Option<Bounds2D>;
+
+    /// 更新键的包围体；键不存在时等同于插入
+    fn update(&mut self, key: K, bounds: Bounds2D) {
+        self.insert(key, bounds);
+    }
+
+    /// 键当前的包围体
+    fn get(&self, key: K) -> Option<Bounds2D>;
+
+    /// 存储的键数量
+    fn len(&self) -> usize;
+
+    /// 是否为空
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 清空所有键
+    fn clear(&mut self);
+
+    /// 将包围体与 `region` 相交的键追加到 `out`（每个键至多一次，顺序不定）
+    fn query_region(&self, region: &Bounds2D, out: &mut Vec<K>);
+
+    /// 将被射线命中的键及命中参数 `t` 追加到 `out`，按 `t` 升序
+    ///
+    /// 只报告 `t <= max_distance` 的命中；`t` 以 `direction` 的长度为单位，
+    /// 传入单位方向时即为世界距离。
+    fn query_ray(&self, origin: Vec2, direction: Vec2, max_distance: f32, out: &mut Vec<(K, f32)>);
+}
+
+/// 按命中参数升序排列射线查询结果
+pub(crate) fn sort_hits<K>(hits: &mut [(K, f32)]) {
+    hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+}
@@ -0,0 +1,337 @@
+//! 区域四叉树
+//!
+//! [`Quadtree`] 覆盖一个固定的根区域，节点中的键超过容量时一分为四。
+//! 每个键只存放在能完整容纳其包围体的最深节点中，跨越子节点边界的键留在父节点；
+//! 超出根区域的键存放在根节点，查询结果仍然正确，只是失去加速效果。
+//!
+//! 节点在删除键后不会合并；对象分布剧烈变化时可调用 `clear` 后重新插入。
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use glam::Vec2;
+
+use super::{sort_hits, SpatialIndex2D};
+use crate::math::Bounds2D;
+
+/// 默认最大深度
+const DEFAULT_MAX_DEPTH: u32 = 8;
+/// 默认节点容量
+const DEFAULT_NODE_CAPACITY: usize = 8;
+
+#[derive(Debug, Clone)]
+struct QuadNode<K> {
+    bounds: Bounds2D,
+    depth: u32,
+    /// 第一个子节点索引（四个子节点连续存放：左下、右下、左上、右上）
+    children: Option<usize>,
+    items: Vec<K>,
+}
+
+impl<K> QuadNode<K> {
+    fn new(bounds: Bounds2D, depth: u32) -> Self {
+        Self { bounds, depth, children: None, items: Vec::new() }
+    }
+}
+
+/// 区域四叉树
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_core::math::Bounds2D;
+/// use anvilkit_core::spatial::{Quadtree, SpatialIndex2D};
+/// use glam::Vec2;
+///
+/// let world = Bounds2D::from_min_max(Vec2::ZERO, Vec2::splat(1024.0));
+/// let mut tree = Quadtree::new(world).with_node_capacity(4);
+/// for i in 0..64u32 {
+///     let center = Vec2::new((i % 8) as f32, (i / 8) as f32) * 128.0 + Vec2::splat(64.0);
+///     tree.insert(i, Bounds2D::from_center_half_extents(center, Vec2::splat(8.0)));
+/// }
+///
+/// let mut found = Vec::new();
+/// tree.query_region(&Bounds2D::from_min_max(Vec2::ZERO, Vec2::splat(200.0)), &mut found);
+/// found.sort();
+/// assert_eq!(found, vec![0, 1, 8, 9]);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::system::Resource))]
+pub struct Quadtree<K: Copy + Eq + Hash> {
+    nodes: Vec<QuadNode<K>>,
+    /// 键 → (包围体, 所在节点索引)
+    entries: HashMap<K, (Bounds2D, usize)>,
+    max_depth: u32,
+    node_capacity: usize,
+}
+
+impl<K: Copy + Eq + Hash> Quadtree<K> {
+    /// 以根区域创建
+    pub fn new(bounds: Bounds2D) -> Self {
+        Self {
+            nodes: vec![QuadNode::new(bounds, 0)],
+            entries: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            node_capacity: DEFAULT_NODE_CAPACITY,
+        }
+    }
+
+    /// 设置最大深度（根为 0）
+    pub fn with_max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// 设置节点分裂前可容纳的键数量
+    pub fn with_node_capacity(mut self, node_capacity: usize) -> Self {
+        self.node_capacity = node_capacity.max(1);
+        self
+    }
+
+    /// 根区域
+    pub fn bounds(&self) -> Bounds2D {
+        self.nodes[0].bounds
+    }
+
+    /// 节点总数
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// 能完整容纳 `bounds` 的子节点
+    fn child_containing(&self, node: usize, bounds: &Bounds2D) -> Option<usize> {
+        let first = self.nodes[node].children?;
+        (first..first + 4).find(|&c| self.nodes[c].bounds.contains(bounds))
+    }
+
+    /// 找到 `bounds` 应存放的节点，沿途按需分裂
+    fn place(&mut self, bounds: &Bounds2D) -> usize {
+        let mut node = 0;
+        loop {
+            let n = &self.nodes[node];
+            if n.children.is_none()
+                && n.items.len() >= self.node_capacity
+                && n.depth < self.max_depth
+                && n.bounds.contains(bounds)
+            {
+                self.split(node);
+            }
+            match self.child_containing(node, bounds) {
+                Some(child) => node = child,
+                None => return node,
+            }
+        }
+    }
+
+    fn split(&mut self, node: usize) {
+        let QuadNode { bounds, depth, .. } = self.nodes[node];
+        let center = bounds.center();
+        let first = self.nodes.len();
+        for quadrant in [
+            Bounds2D::from_min_max(bounds.min, center),
+            Bounds2D::from_min_max(Vec2::new(center.x, bounds.min.y), Vec2::new(bounds.max.x, center.y)),
+            Bounds2D::from_min_max(Vec2::new(bounds.min.x, center.y), Vec2::new(center.x, bounds.max.y)),
+            Bounds2D::from_min_max(center, bounds.max),
+        ] {
+            self.nodes.push(QuadNode::new(quadrant, depth + 1));
+        }
+        self.nodes[node].children = Some(first);
+
+        // 将能完整放入子节点的键下移
+        let items = std::mem::take(&mut self.nodes[node].items);
+        for key in items {
+            let entry = self.entries.get_mut(&key).expect("节点中的键必有条目");
+            let target = (first..first + 4)
+                .find(|&c| self.nodes[c].bounds.contains(&entry.0))
+                .unwrap_or(node);
+            entry.1 = target;
+            self.nodes[target].items.push(key);
+        }
+    }
+
+    fn detach(&mut self, key: K, node: usize) {
+        let items = &mut self.nodes[node].items;
+        if let Some(i) = items.iter().position(|k| *k == key) {
+            items.swap_remove(i);
+        }
+    }
+
+    /// 深度优先遍历 `visit_node` 返回 true 的节点，对其中每个键调用 `visit_item`
+    fn traverse(&self, mut visit_node: impl FnMut(&Bounds2D) -> bool, mut visit_item: impl FnMut(K, &Bounds2D)) {
+        // 根节点总是访问：超出根区域的键存放在根节点
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            let n = &self.nodes[node];
+            for &key in &n.items {
+                visit_item(key, &self.entries[&key].0);
+            }
+            if let Some(first) = n.children {
+                stack.extend((first..first + 4).filter(|&c| visit_node(&self.nodes[c].bounds)));
+            }
+        }
+    }
+}
+
+impl<K: Copy + Eq + Hash> SpatialIndex2D<K> for Quadtree<K> {
+    fn insert(&mut self, key: K, bounds: Bounds2D) {
+        if let Some((_, node)) = self.entries.remove(&key) {
+            self.detach(key, node);
+        }
+        let node = self.place(&bounds);
+        self.nodes[node].items.push(key);
+        self.entries.insert(key, (bounds, node));
+    }
+
+    fn remove(&mut self, key: K) -> Option<Bounds2D> {
+        let (bounds, node) = self.entries.remove(&key)?;
+        self.detach(key, node);
+        Some(bounds)
+    }
+
+    fn update(&mut self, key: K, bounds: Bounds2D) {
+        // 仍应存放在原节点时只替换包围体
+        if let Some(&(_, node)) = self.entries.get(&key) {
+            let fits = node == 0 || self.nodes[node].bounds.contains(&bounds);
+            if fits && self.child_containing(node, &bounds).is_none() {
+                self.entries.insert(key, (bounds, node));
+                return;
+            }
+        }
+        self.insert(key, bounds);
+    }
+
+    fn get(&self, key: K) -> Option<Bounds2D> {
+        self.entries.get(&key).map(|e| e.0)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn clear(&mut self) {
+        let bounds = self.bounds();
+        self.nodes.clear();
+        self.nodes.push(QuadNode::new(bounds, 0));
+        self.entries.clear();
+    }
+
+    fn query_region(&self, region: &Bounds2D, out: &mut Vec<K>) {
+        self.traverse(
+            |node| node.intersects(region),
+            |key, bounds| {
+                if bounds.intersects(region) {
+                    out.push(key);
+                }
+            },
+        );
+    }
+
+    fn query_ray(&self, origin: Vec2, direction: Vec2, max_distance: f32, out: &mut Vec<(K, f32)>) {
+        if direction == Vec2::ZERO {
+            return;
+        }
+        let first = out.len();
+        self.traverse(
+            |node| node.ray_intersection(origin, direction).is_some_and(|t| t <= max_distance),
+            |key, bounds| {
+                if let Some(t) = bounds.ray_intersection(origin, direction) {
+                    if t <= max_distance {
+                        out.push((key, t));
+                    }
+                }
+            },
+        );
+        sort_hits(&mut out[first..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(center: Vec2, half: f32) -> Bounds2D {
+        Bounds2D::from_center_half_extents(center, Vec2::splat(half))
+    }
+
+    fn tree() -> Quadtree<u32> {
+        Quadtree::new(Bounds2D::from_min_max(Vec2::ZERO, Vec2::splat(64.0))).with_node_capacity(2)
+    }
+
+    #[test]
+    fn test_split_and_query() {
+        let mut qt = tree();
+        for i in 0..16u32 {
+            let c = Vec2::new((i % 4) as f32, (i / 4) as f32) * 16.0 + Vec2::splat(8.0);
+            qt.insert(i, square(c, 1.0));
+        }
+        assert!(qt.node_count() > 1);
+        assert_eq!(qt.len(), 16);
+
+        let mut out = Vec::new();
+        qt.query_region(&Bounds2D::from_min_max(Vec2::ZERO, Vec2::splat(25.0)), &mut out);
+        out.sort();
+        assert_eq!(out, vec![0, 1, 4, 5]);
+    }
+
+    #[test]
+    fn test_straddling_and_outside_keys() {
+        let mut qt = tree();
+        // 跨越中心线的键留在父节点
+        qt.insert(1, square(Vec2::splat(32.0), 4.0));
+        // 超出根区域的键仍可查询
+        qt.insert(2, square(Vec2::splat(-100.0), 1.0));
+        for i in 10..20u32 {
+            qt.insert(i, square(Vec2::splat(4.0 + i as f32), 0.1));
+        }
+
+        let mut out = Vec::new();
+        qt.query_region(&square(Vec2::splat(32.0), 1.0), &mut out);
+        assert_eq!(out, vec![1]);
+
+        out.clear();
+        qt.query_region(&square(Vec2::splat(-100.0), 0.5), &mut out);
+        assert_eq!(out, vec![2]);
+    }
+
+    #[test]
+    fn test_update_and_remove() {
+        let mut qt = tree();
+        for i in 0..8u32 {
+            qt.insert(i, square(Vec2::new(4.0 + i as f32 * 7.0, 4.0), 1.0));
+        }
+        qt.update(3, square(Vec2::new(60.0, 60.0), 1.0));
+        assert_eq!(qt.get(3), Some(square(Vec2::new(60.0, 60.0), 1.0)));
+
+        let mut out = Vec::new();
+        qt.query_region(&square(Vec2::new(60.0, 60.0), 2.0), &mut out);
+        assert_eq!(out, vec![3]);
+
+        assert!(qt.remove(3).is_some());
+        out.clear();
+        qt.query_region(&square(Vec2::new(60.0, 60.0), 2.0), &mut out);
+        assert!(out.is_empty());
+        assert_eq!(qt.len(), 7);
+
+        qt.clear();
+        assert!(qt.is_empty());
+        assert_eq!(qt.node_count(), 1);
+    }
+
+    #[test]
+    fn test_query_ray() {
+        let mut qt = tree();
+        for i in 0..6u32 {
+            qt.insert(i, square(Vec2::new(5.0 + i as f32 * 10.0, 5.0), 1.0));
+        }
+        qt.insert(99, square(Vec2::new(30.0, 50.0), 1.0));
+
+        let mut hits = Vec::new();
+        qt.query_ray(Vec2::new(0.0, 5.0), Vec2::X, 100.0, &mut hits);
+        assert_eq!(hits.iter().map(|h| h.0).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(hits[0].1, 4.0);
+
+        hits.clear();
+        qt.query_ray(Vec2::new(0.0, 5.0), Vec2::X, 20.0, &mut hits);
+        assert_eq!(hits.len(), 2);
+    }
+}
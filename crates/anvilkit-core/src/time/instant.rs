@@ -0,0 +1,123 @@
+//! # 跨平台时间点
+//!
+//! `std::time::Instant::now()` 在 `wasm32-unknown-unknown` 上会 panic。
+//! 本模块在原生平台直接重导出标准库类型，在 wasm32 上提供基于
+//! `performance.now()` 的同名实现，引擎内部统一使用 [`Instant`]。
+//!
+//! ```rust
+//! use anvilkit_core::time::Instant;
+//! use std::time::Duration;
+//!
+//! let start = Instant::now();
+//! let later = start + Duration::from_millis(5);
+//! assert_eq!(later.duration_since(start), Duration::from_millis(5));
+//! assert!(later > start);
+//! ```
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+pub use web::Instant;
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::ops::{Add, AddAssign, Sub, SubAssign};
+    use std::time::Duration;
+
+    /// 基于 `performance.now()` 的单调时间点（毫秒精度的浮点数，通常为微秒级）
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Instant(f64);
+
+    impl Instant {
+        /// 当前时间点
+        pub fn now() -> Self {
+            let millis = web_sys::window()
+                .and_then(|w| w.performance())
+                .map(|p| p.now())
+                .unwrap_or_else(js_sys::Date::now);
+            Self(millis)
+        }
+
+        /// 自 `earlier` 以来经过的时间，`earlier` 更晚时返回零
+        pub fn duration_since(&self, earlier: Instant) -> Duration {
+            self.saturating_duration_since(earlier)
+        }
+
+        /// 同 [`duration_since`](Self::duration_since)
+        pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+            Duration::from_secs_f64(((self.0 - earlier.0) / 1000.0).max(0.0))
+        }
+
+        /// `earlier` 不晚于自身时返回经过的时间
+        pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+            (self.0 >= earlier.0).then(|| self.duration_since(earlier))
+        }
+
+        /// 自该时间点以来经过的时间
+        pub fn elapsed(&self) -> Duration {
+            Self::now().duration_since(*self)
+        }
+
+        /// 加上一段时间
+        pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+            Some(Self(self.0 + duration.as_secs_f64() * 1000.0))
+        }
+
+        /// 减去一段时间
+        pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+            Some(Self(self.0 - duration.as_secs_f64() * 1000.0))
+        }
+    }
+
+    // performance.now() 不会返回 NaN
+    impl Eq for Instant {}
+
+    impl PartialOrd for Instant {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Instant {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.total_cmp(&other.0)
+        }
+    }
+
+    impl Add<Duration> for Instant {
+        type Output = Instant;
+
+        fn add(self, rhs: Duration) -> Instant {
+            Self(self.0 + rhs.as_secs_f64() * 1000.0)
+        }
+    }
+
+    impl AddAssign<Duration> for Instant {
+        fn add_assign(&mut self, rhs: Duration) {
+            *self = *self + rhs;
+        }
+    }
+
+    impl Sub<Duration> for Instant {
+        type Output = Instant;
+
+        fn sub(self, rhs: Duration) -> Instant {
+            Self(self.0 - rhs.as_secs_f64() * 1000.0)
+        }
+    }
+
+    impl SubAssign<Duration> for Instant {
+        fn sub_assign(&mut self, rhs: Duration) {
+            *self = *self - rhs;
+        }
+    }
+
+    impl Sub<Instant> for Instant {
+        type Output = Duration;
+
+        fn sub(self, rhs: Instant) -> Duration {
+            self.duration_since(rhs)
+        }
+    }
+}
@@ -6,12 +6,13 @@
 //! 
 //! - [`time`]: 核心时间资源，跟踪帧时间和应用运行时间
 //! - [`timer`]: 计时器工具，用于延时和周期性事件
+//! - [`instant`]: 跨平台时间点（wasm32 上基于 `performance.now()`）
 //! - [`stopwatch`]: 秒表工具，用于性能测量和调试
 //! - [`frame_counter`]: 帧计数器，用于 FPS 计算和性能监控
 //! 
 //! ## 设计原则
 //! 
-//! 1. **高精度**: 使用 `std::time::Instant` 提供微秒级精度
+//! 1. **高精度**: 使用单调时钟（原生为 `std::time::Instant`，Web 为 `performance.now()`）提供微秒级精度
 //! 2. **零成本抽象**: 编译时优化，运行时开销最小
 //! 3. **易于使用**: 提供直观的 API 和常用的便利方法
 //! 4. **线程安全**: 所有类型都实现了 `Send` 和 `Sync`
@@ -45,10 +46,12 @@
 
 pub mod time;
 pub mod timer;
+pub mod instant;
 
 // 重新导出主要类型
 pub use time::Time;
 pub use timer::Timer;
+pub use instant::Instant;
 
 /// Frame-time resource — seconds elapsed since the previous frame.
 ///
@@ -61,6 +64,25 @@ impl Default for DeltaTime {
     fn default() -> Self { Self(1.0 / 60.0) }
 }
 
+/// Fixed-step progress resource — how far the fixed-timestep simulation has advanced this frame.
+///
+/// Written by fixed-timestep simulations (e.g. physics) after stepping. `alpha` is the
+/// fraction of a step left in the accumulator: `0.0` means the latest step just landed,
+/// values near `1.0` mean the next step is due. Renderers use it to blend the previous
+/// and current fixed-step state. The default (`alpha = 1.0`, no steps) disables blending.
+#[cfg_attr(feature = "bevy_ecs", derive(bevy_ecs::system::Resource))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedStepProgress {
+    /// Leftover time as a fraction of one fixed step, in `[0, 1]`.
+    pub alpha: f32,
+    /// Number of fixed steps run this frame.
+    pub steps: u32,
+}
+
+impl Default for FixedStepProgress {
+    fn default() -> Self { Self { alpha: 1.0, steps: 0 } }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
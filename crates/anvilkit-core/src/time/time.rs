@@ -12,7 +12,8 @@
 //! 
 //! `Time` 通常作为全局资源在 ECS 系统中使用，每帧调用 `update()` 方法更新时间信息。
 
-use std::time::{Duration, Instant};
+use std::time::Duration;
+use super::instant::Instant;
 use anvilkit_describe::Describe;
 
 /// 核心时间资源，跟踪应用的时间信息
@@ -129,8 +130,28 @@ impl Time {
     /// assert_eq!(time.frame_count(), 2);
     /// ```
     pub fn update(&mut self) {
-        let now = Instant::now();
-        
+        self.update_with_instant(Instant::now());
+    }
+
+    /// 以指定时间点更新时间信息
+    ///
+    /// 与 [`update`](Self::update) 相同，但由调用方提供当前时间点，
+    /// 用于回放或在测试中以确定的时钟驱动。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_core::time::Time;
+    /// use std::time::Duration;
+    ///
+    /// let mut time = Time::new();
+    /// let start = time.startup_time();
+    /// time.update_with_instant(start);
+    /// time.update_with_instant(start + Duration::from_millis(16));
+    /// assert_eq!(time.delta(), Duration::from_millis(16));
+    /// assert_eq!(time.elapsed(), Duration::from_millis(16));
+    /// ```
+    pub fn update_with_instant(&mut self, now: Instant) {
         if self.first_update {
             // 第一次更新时，delta time 为 0
             self.first_update = false;
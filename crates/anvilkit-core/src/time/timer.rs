@@ -20,6 +20,7 @@ use std::time::Duration;
 
 /// 计时器状态
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimerState {
     /// 运行中
     Running,
@@ -68,6 +69,7 @@ pub enum TimerState {
 /// }
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Timer {
     /// 计时器总时长
     duration: Duration,
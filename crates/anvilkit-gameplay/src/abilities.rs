@@ -0,0 +1,402 @@
+//! # Ability System
+//!
+//! Cooldown-driven abilities bound to input actions, with an optional
+//! [`Energy`] pool paying for each cast.
+//!
+//! ## Events
+//!
+//! - [`AbilityCast`] — emitted when an ability fires
+//! - [`AbilityReady`] — emitted when an ability's cooldown elapses
+//!
+//! ## Systems
+//!
+//! - [`ability_cooldown_system`] — ticks cooldown timers and emits [`AbilityReady`]
+//! - [`ability_input_system`] — reads the [`ActionMap`], pays costs, and emits [`AbilityCast`]
+//! - [`energy_regen_system`] — passive [`Energy`] regeneration
+//!
+//! ## Example
+//!
+//! ```rust
+//! use anvilkit_gameplay::abilities::{Ability, Abilities, Energy};
+//!
+//! let mut abilities = Abilities::new()
+//!     .with(Ability::new("fireball", 2.0).with_cost(30.0));
+//! let mut energy = Energy::new(50.0);
+//!
+//! assert!(abilities.try_cast("fireball", Some(&mut energy)));
+//! assert_eq!(energy.current, 20.0);
+//! // Still cooling down
+//! assert!(!abilities.try_cast("fireball", Some(&mut energy)));
+//! ```
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::time::{DeltaTime, Timer};
+use anvilkit_describe::Describe;
+use anvilkit_input::prelude::ActionMap;
+
+// ---------------------------------------------------------------------------
+// Data types
+// ---------------------------------------------------------------------------
+
+/// A single ability: the input action that triggers it, its cooldown, and its cost.
+#[derive(Debug, Clone)]
+pub struct Ability {
+    /// Input action name (as registered in [`ActionMap`]) that triggers this ability.
+    pub action: String,
+    /// Cooldown timer. The ability is ready while the timer is finished.
+    pub cooldown: Timer,
+    /// Energy consumed per cast.
+    pub cost: f32,
+}
+
+impl Ability {
+    /// Create a ready-to-cast ability with the given cooldown and zero cost.
+    pub fn new(action: impl Into<String>, cooldown_seconds: f32) -> Self {
+        let mut cooldown = Timer::from_seconds(cooldown_seconds);
+        cooldown.finish();
+        Self {
+            action: action.into(),
+            cooldown,
+            cost: 0.0,
+        }
+    }
+
+    /// Builder helper to set the energy cost.
+    pub fn with_cost(mut self, cost: f32) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    /// `true` when the cooldown has elapsed.
+    pub fn is_ready(&self) -> bool {
+        self.cooldown.finished()
+    }
+
+    /// Seconds until the ability is ready again (zero when ready).
+    pub fn remaining_seconds(&self) -> f32 {
+        if self.is_ready() { 0.0 } else { self.cooldown.remaining_seconds() }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Components
+// ---------------------------------------------------------------------------
+
+/// Set of abilities owned by an entity, keyed by input action name.
+#[derive(Debug, Clone, Default, Component, Describe)]
+/// Cooldown-driven abilities bound to input actions.
+pub struct Abilities {
+    /// Abilities in registration order.
+    #[describe(hint = "Ability list: action name, cooldown timer, energy cost")]
+    pub slots: Vec<Ability>,
+}
+
+impl Abilities {
+    /// Create an empty ability set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder helper to add an ability.
+    pub fn with(mut self, ability: Ability) -> Self {
+        self.insert(ability);
+        self
+    }
+
+    /// Add an ability, replacing any existing ability bound to the same action.
+    pub fn insert(&mut self, ability: Ability) {
+        if let Some(slot) = self.get_mut(&ability.action) {
+            *slot = ability;
+        } else {
+            self.slots.push(ability);
+        }
+    }
+
+    /// Look up the ability bound to `action`.
+    pub fn get(&self, action: &str) -> Option<&Ability> {
+        self.slots.iter().find(|a| a.action == action)
+    }
+
+    /// Look up the ability bound to `action` mutably.
+    pub fn get_mut(&mut self, action: &str) -> Option<&mut Ability> {
+        self.slots.iter_mut().find(|a| a.action == action)
+    }
+
+    /// `true` if the ability exists and its cooldown has elapsed.
+    pub fn is_ready(&self, action: &str) -> bool {
+        self.get(action).map_or(false, Ability::is_ready)
+    }
+
+    /// Attempt to cast the ability bound to `action`.
+    ///
+    /// Succeeds when the ability is off cooldown and `energy` (if any) covers its
+    /// cost. On success the cost is deducted and the cooldown restarts.
+    pub fn try_cast(&mut self, action: &str, energy: Option<&mut Energy>) -> bool {
+        let Some(ability) = self.get_mut(action) else { return false };
+        if !ability.is_ready() {
+            return false;
+        }
+        if let Some(energy) = energy {
+            if !energy.try_spend(ability.cost) {
+                return false;
+            }
+        }
+        ability.cooldown.reset();
+        true
+    }
+
+    /// Advance all cooldowns by `delta`, returning the actions that became ready.
+    pub fn tick(&mut self, delta: Duration) -> Vec<String> {
+        let mut ready = Vec::new();
+        for ability in &mut self.slots {
+            ability.cooldown.tick(delta);
+            if ability.cooldown.just_finished() {
+                ready.push(ability.action.clone());
+            }
+        }
+        ready
+    }
+}
+
+/// Energy pool (mana, stamina, ...) spent by ability casts.
+#[derive(Debug, Clone, Component, Describe)]
+/// Spendable resource pool for ability costs.
+pub struct Energy {
+    /// Current energy (clamped to `0.0..=max`).
+    #[describe(hint = "Current energy", range = "0.0..100000.0")]
+    pub current: f32,
+    /// Maximum energy.
+    #[describe(hint = "Maximum energy", range = "0.0..100000.0", default = "100.0")]
+    pub max: f32,
+    /// Energy regenerated per second.
+    #[describe(hint = "Energy regenerated per second", range = "0.0..1000.0", default = "0.0")]
+    pub regen_rate: f32,
+}
+
+impl Energy {
+    /// Create a full energy pool with zero regen.
+    pub fn new(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen_rate: 0.0,
+        }
+    }
+
+    /// Builder helper to set a regeneration rate.
+    pub fn with_regen(mut self, rate: f32) -> Self {
+        self.regen_rate = rate;
+        self
+    }
+
+    /// Deduct `amount` if enough energy is available. Returns `false` otherwise.
+    pub fn try_spend(&mut self, amount: f32) -> bool {
+        if self.current < amount {
+            return false;
+        }
+        self.current -= amount;
+        true
+    }
+
+    /// Increase current energy by `amount`, clamping at max.
+    pub fn restore(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Emitted when an entity successfully casts an ability.
+#[derive(Debug, Clone, Event)]
+pub struct AbilityCast {
+    /// Entity that cast the ability.
+    pub caster: Entity,
+    /// Action name of the cast ability.
+    pub action: String,
+}
+
+/// Emitted when an ability's cooldown elapses.
+#[derive(Debug, Clone, Event)]
+pub struct AbilityReady {
+    /// Entity owning the ability.
+    pub entity: Entity,
+    /// Action name of the ability that became ready.
+    pub action: String,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Ticks every [`Abilities`] cooldown by [`DeltaTime`] and emits [`AbilityReady`].
+pub fn ability_cooldown_system(
+    dt: Res<DeltaTime>,
+    mut query: Query<(Entity, &mut Abilities)>,
+    mut ready_events: EventWriter<AbilityReady>,
+) {
+    let delta = Duration::from_secs_f32(dt.0.max(0.0));
+    for (entity, mut abilities) in query.iter_mut() {
+        for action in abilities.tick(delta) {
+            ready_events.send(AbilityReady { entity, action });
+        }
+    }
+}
+
+/// Casts abilities whose action was just pressed in the [`ActionMap`].
+///
+/// A no-op when no `ActionMap` resource is present. Entities without an
+/// [`Energy`] component cast for free.
+pub fn ability_input_system(
+    action_map: Option<Res<ActionMap>>,
+    mut query: Query<(Entity, &mut Abilities, Option<&mut Energy>)>,
+    mut cast_events: EventWriter<AbilityCast>,
+) {
+    let Some(action_map) = action_map else { return };
+
+    for (entity, mut abilities, mut energy) in query.iter_mut() {
+        let pressed: Vec<String> = abilities
+            .slots
+            .iter()
+            .filter(|a| action_map.is_action_just_pressed(&a.action))
+            .map(|a| a.action.clone())
+            .collect();
+
+        for action in pressed {
+            if abilities.try_cast(&action, energy.as_deref_mut()) {
+                cast_events.send(AbilityCast { caster: entity, action });
+            }
+        }
+    }
+}
+
+/// Regenerates [`Energy`] by `regen_rate * DeltaTime`.
+pub fn energy_regen_system(dt: Res<DeltaTime>, mut query: Query<&mut Energy>) {
+    for mut energy in query.iter_mut() {
+        if energy.regen_rate > 0.0 && energy.current < energy.max {
+            let amount = energy.regen_rate * dt.0;
+            energy.restore(amount);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_input::prelude::{InputBinding, InputState, KeyCode};
+
+    #[test]
+    fn new_ability_starts_ready() {
+        let a = Ability::new("dash", 1.0);
+        assert!(a.is_ready());
+        assert_eq!(a.remaining_seconds(), 0.0);
+    }
+
+    #[test]
+    fn cast_starts_cooldown() {
+        let mut abilities = Abilities::new().with(Ability::new("dash", 1.0));
+        assert!(abilities.try_cast("dash", None));
+        assert!(!abilities.is_ready("dash"));
+        assert!(!abilities.try_cast("dash", None));
+    }
+
+    #[test]
+    fn cooldown_tick_reports_ready() {
+        let mut abilities = Abilities::new().with(Ability::new("dash", 1.0));
+        abilities.try_cast("dash", None);
+
+        assert!(abilities.tick(Duration::from_millis(500)).is_empty());
+        assert_eq!(abilities.tick(Duration::from_millis(500)), vec!["dash".to_string()]);
+        assert!(abilities.is_ready("dash"));
+        // Reported only once
+        assert!(abilities.tick(Duration::from_millis(16)).is_empty());
+    }
+
+    #[test]
+    fn insufficient_energy_blocks_cast() {
+        let mut abilities = Abilities::new().with(Ability::new("nova", 0.5).with_cost(40.0));
+        let mut energy = Energy::new(30.0);
+        assert!(!abilities.try_cast("nova", Some(&mut energy)));
+        assert_eq!(energy.current, 30.0);
+        assert!(abilities.is_ready("nova"));
+    }
+
+    #[test]
+    fn insert_replaces_same_action() {
+        let mut abilities = Abilities::new().with(Ability::new("dash", 1.0));
+        abilities.insert(Ability::new("dash", 3.0));
+        assert_eq!(abilities.slots.len(), 1);
+        assert_eq!(abilities.get("dash").unwrap().cooldown.duration_seconds(), 3.0);
+    }
+
+    #[test]
+    fn unknown_action_is_not_castable() {
+        let mut abilities = Abilities::new();
+        assert!(!abilities.try_cast("missing", None));
+    }
+
+    // -- ECS system integration tests ---------------------------------------
+
+    #[test]
+    fn input_system_casts_and_emits_event() {
+        let mut world = World::new();
+        world.init_resource::<Events<AbilityCast>>();
+
+        let mut map = ActionMap::new();
+        map.add_binding("fireball", InputBinding::Key(KeyCode::Q));
+        let mut input = InputState::new();
+        input.press_key(KeyCode::Q);
+        map.update(&input);
+        world.insert_resource(map);
+
+        let entity = world
+            .spawn((
+                Abilities::new().with(Ability::new("fireball", 2.0).with_cost(25.0)),
+                Energy::new(100.0),
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ability_input_system);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Energy>(entity).unwrap().current, 75.0);
+        assert!(!world.get::<Abilities>(entity).unwrap().is_ready("fireball"));
+
+        let events = world.resource::<Events<AbilityCast>>();
+        let mut reader = events.get_cursor();
+        let casts: Vec<_> = reader.read(events).collect();
+        assert_eq!(casts.len(), 1);
+        assert_eq!(casts[0].caster, entity);
+        assert_eq!(casts[0].action, "fireball");
+    }
+
+    #[test]
+    fn cooldown_system_emits_ready() {
+        let mut world = World::new();
+        world.init_resource::<Events<AbilityReady>>();
+        world.insert_resource(DeltaTime(0.5));
+
+        let mut abilities = Abilities::new().with(Ability::new("dash", 0.5));
+        abilities.try_cast("dash", None);
+        let entity = world.spawn(abilities).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ability_cooldown_system);
+        schedule.run(&mut world);
+
+        let events = world.resource::<Events<AbilityReady>>();
+        let mut reader = events.get_cursor();
+        let ready: Vec<_> = reader.read(events).collect();
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].entity, entity);
+        assert_eq!(ready[0].action, "dash");
+    }
+}
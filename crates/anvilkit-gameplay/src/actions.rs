@@ -0,0 +1,556 @@
+//! # Action Queue
+//!
+//! Command-pattern action queue for cutscenes and scripted AI. An
+//! [`ActionQueue`] component runs queued [`Action`]s one after another; each
+//! action finishes on its own completion criterion (duration elapsed, signal
+//! received, callback run). [`Action::Parallel`] groups run side by side and
+//! finish when every member has finished.
+//!
+//! ## Events
+//!
+//! - [`PlayAnimationRequested`] — emitted when a [`Action::PlayAnimation`] starts
+//! - [`ActionQueueFinished`] — emitted when a queue runs out of actions
+//!
+//! ## Systems
+//!
+//! - [`action_queue_system`] — advances every [`ActionQueue`] by [`DeltaTime`],
+//!   moving the entity's [`Transform`] and running callbacks via `Commands`.
+//!   The transform is only borrowed mutably while a move is running, so idle
+//!   actors are not flagged as changed.
+//!
+//! Actions are built on the engine's timing primitives: durations are
+//! [`Timer`]s, and [`Action::MoveTo`] drives [`TranslationLens`] with an
+//! [`EaseFunction`] the same way a translation tween does.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use anvilkit_gameplay::actions::{Action, ActionQueue};
+//! use anvilkit_core::Vec3;
+//!
+//! let queue = ActionQueue::new()
+//!     .then(Action::move_to(Vec3::new(5.0, 0.0, 0.0), 2.0))
+//!     .then(Action::parallel([
+//!         Action::play_animation("wave", 1.0),
+//!         Action::wait(1.5),
+//!     ]))
+//!     .then(Action::wait_for_signal("door_open"))
+//!     .then(Action::callback(|commands, entity| {
+//!         commands.entity(entity).despawn();
+//!     }));
+//! assert_eq!(queue.len(), 4);
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::DerefMut;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::lens::{TranslationLens, TweenLens};
+use anvilkit_core::math::{EaseFunction, Lerp, Transform};
+use anvilkit_core::time::{DeltaTime, Timer};
+use anvilkit_core::Vec3;
+
+// ---------------------------------------------------------------------------
+// Actions
+// ---------------------------------------------------------------------------
+
+/// Signature of an [`ActionCallback`].
+type CallbackFn = dyn Fn(&mut Commands, Entity) + Send + Sync;
+
+/// Callback run by [`Action::Callback`] with the queue owner's entity.
+#[derive(Clone)]
+pub struct ActionCallback(Arc<CallbackFn>);
+
+impl ActionCallback {
+    /// Wrap a closure as an action callback.
+    pub fn new(f: impl Fn(&mut Commands, Entity) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Invoke the callback.
+    pub fn call(&self, commands: &mut Commands, entity: Entity) {
+        (self.0)(commands, entity)
+    }
+}
+
+impl fmt::Debug for ActionCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ActionCallback(..)")
+    }
+}
+
+/// A single queued action.
+#[derive(Debug, Clone)]
+pub enum Action {
+    /// Move the entity's [`Transform`] to `target` over `duration` seconds.
+    MoveTo {
+        /// Destination translation.
+        target: Vec3,
+        /// Travel time in seconds.
+        duration: f32,
+        /// Easing applied to the travel progress.
+        ease: EaseFunction,
+    },
+    /// Do nothing for the given number of seconds.
+    Wait(f32),
+    /// Request an animation clip and wait for `duration` seconds.
+    PlayAnimation {
+        /// Clip name forwarded in [`PlayAnimationRequested`].
+        clip: String,
+        /// How long to wait before the action completes.
+        duration: f32,
+    },
+    /// Wait until [`ActionQueue::signal`] is called with this name.
+    WaitForSignal(String),
+    /// Run a callback with `Commands`; completes immediately.
+    Callback(ActionCallback),
+    /// Run actions one after another (useful inside [`Action::Parallel`]).
+    Sequence(Vec<Action>),
+    /// Run actions simultaneously; completes when all have completed.
+    Parallel(Vec<Action>),
+}
+
+impl Action {
+    /// Shorthand for a linear [`Action::MoveTo`].
+    pub fn move_to(target: Vec3, duration: f32) -> Self {
+        Action::MoveTo { target, duration, ease: EaseFunction::Linear }
+    }
+
+    /// Shorthand for an eased [`Action::MoveTo`].
+    pub fn move_to_eased(target: Vec3, duration: f32, ease: EaseFunction) -> Self {
+        Action::MoveTo { target, duration, ease }
+    }
+
+    /// Shorthand for [`Action::Wait`].
+    pub fn wait(seconds: f32) -> Self {
+        Action::Wait(seconds)
+    }
+
+    /// Shorthand for [`Action::PlayAnimation`].
+    pub fn play_animation(clip: impl Into<String>, duration: f32) -> Self {
+        Action::PlayAnimation { clip: clip.into(), duration }
+    }
+
+    /// Shorthand for [`Action::WaitForSignal`].
+    pub fn wait_for_signal(name: impl Into<String>) -> Self {
+        Action::WaitForSignal(name.into())
+    }
+
+    /// Shorthand for [`Action::Callback`].
+    pub fn callback(f: impl Fn(&mut Commands, Entity) + Send + Sync + 'static) -> Self {
+        Action::Callback(ActionCallback::new(f))
+    }
+
+    /// Shorthand for [`Action::Sequence`].
+    pub fn sequence(actions: impl IntoIterator<Item = Action>) -> Self {
+        Action::Sequence(actions.into_iter().collect())
+    }
+
+    /// Shorthand for [`Action::Parallel`].
+    pub fn parallel(actions: impl IntoIterator<Item = Action>) -> Self {
+        Action::Parallel(actions.into_iter().collect())
+    }
+
+    fn start(self) -> Running {
+        match self {
+            Action::MoveTo { target, duration, ease } => Running::MoveTo {
+                from: None,
+                target,
+                ease,
+                timer: seconds_timer(duration),
+            },
+            Action::Wait(seconds) => Running::Wait(seconds_timer(seconds)),
+            Action::PlayAnimation { clip, duration } => Running::PlayAnimation {
+                clip: Some(clip),
+                timer: seconds_timer(duration),
+            },
+            Action::WaitForSignal(name) => Running::WaitForSignal(name),
+            Action::Callback(callback) => Running::Callback(callback),
+            Action::Sequence(actions) => Running::Sequence { current: None, pending: actions.into() },
+            Action::Parallel(actions) => Running::Parallel(actions.into_iter().map(Action::start).collect()),
+        }
+    }
+}
+
+fn seconds_timer(seconds: f32) -> Timer {
+    Timer::new(Duration::from_secs_f32(seconds.max(0.0)))
+}
+
+/// Runtime state of a started action.
+#[derive(Debug, Clone)]
+enum Running {
+    MoveTo { from: Option<Vec3>, target: Vec3, ease: EaseFunction, timer: Timer },
+    Wait(Timer),
+    PlayAnimation { clip: Option<String>, timer: Timer },
+    WaitForSignal(String),
+    Callback(ActionCallback),
+    Sequence { current: Option<Box<Running>>, pending: VecDeque<Action> },
+    Parallel(Vec<Running>),
+}
+
+/// Side effects produced while stepping a queue.
+#[derive(Debug, Default)]
+pub struct ActionOutput {
+    /// Animation clips that started this step.
+    pub animations: Vec<String>,
+    /// Callbacks to run this step, in order.
+    pub callbacks: Vec<ActionCallback>,
+    /// `true` if the queue ran out of actions this step.
+    pub finished: bool,
+}
+
+impl Running {
+    /// Advance by `dt`; returns `true` once the action has completed.
+    ///
+    /// `transform` is only dereferenced mutably by a running move, so passing
+    /// a change-detecting `Mut<Transform>` does not flag idle actors.
+    fn step<T: DerefMut<Target = Transform>>(
+        &mut self,
+        dt: Duration,
+        transform: &mut Option<T>,
+        signals: &[String],
+        out: &mut ActionOutput,
+    ) -> bool {
+        match self {
+            Running::MoveTo { from, target, ease, timer } => {
+                let Some(transform) = transform.as_deref_mut() else {
+                    return true;
+                };
+                let translation = TranslationLens::field(transform);
+                let start = *from.get_or_insert(*translation);
+                timer.tick(dt);
+                *translation = Lerp::lerp(&start, target, ease.ease(timer.percent()));
+                timer.finished()
+            }
+            Running::Wait(timer) => {
+                timer.tick(dt);
+                timer.finished()
+            }
+            Running::PlayAnimation { clip, timer } => {
+                if let Some(clip) = clip.take() {
+                    out.animations.push(clip);
+                }
+                timer.tick(dt);
+                timer.finished()
+            }
+            Running::WaitForSignal(name) => signals.iter().any(|s| s == name),
+            Running::Callback(callback) => {
+                out.callbacks.push(callback.clone());
+                true
+            }
+            Running::Sequence { current, pending } => step_sequence(current, pending, dt, transform, signals, out),
+            Running::Parallel(children) => {
+                children.retain_mut(|child| !child.step(dt, transform, signals, out));
+                children.is_empty()
+            }
+        }
+    }
+}
+
+/// Step `current`, starting actions from `pending` as each one completes.
+///
+/// Actions that complete instantly (callbacks, zero-duration moves) chain
+/// within the same step; the remaining time is not carried over.
+fn step_sequence<T: DerefMut<Target = Transform>>(
+    current: &mut Option<Box<Running>>,
+    pending: &mut VecDeque<Action>,
+    dt: Duration,
+    transform: &mut Option<T>,
+    signals: &[String],
+    out: &mut ActionOutput,
+) -> bool {
+    let mut dt = dt;
+    loop {
+        if current.is_none() {
+            match pending.pop_front() {
+                Some(action) => *current = Some(Box::new(action.start())),
+                None => return true,
+            }
+        }
+        let running = current.as_mut().expect("current action was just started");
+        if !running.step(dt, transform, signals, out) {
+            return false;
+        }
+        *current = None;
+        dt = Duration::ZERO;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Queue of actions executed in order on the owning entity.
+#[derive(Debug, Clone, Default, Component)]
+pub struct ActionQueue {
+    current: Option<Box<Running>>,
+    pending: VecDeque<Action>,
+    signals: Vec<String>,
+    /// When `true`, the queue is not advanced.
+    pub paused: bool,
+}
+
+impl ActionQueue {
+    /// Create an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder helper to append an action.
+    pub fn then(mut self, action: Action) -> Self {
+        self.push(action);
+        self
+    }
+
+    /// Append an action to the end of the queue.
+    pub fn push(&mut self, action: Action) {
+        self.pending.push_back(action);
+    }
+
+    /// Drop the running action and everything queued after it.
+    pub fn clear(&mut self) {
+        self.current = None;
+        self.pending.clear();
+        self.signals.clear();
+    }
+
+    /// Raise a signal for [`Action::WaitForSignal`].
+    ///
+    /// Signals are consumed by the next [`update`](Self::update); a signal
+    /// nobody is waiting for at that point is discarded.
+    pub fn signal(&mut self, name: impl Into<String>) {
+        self.signals.push(name.into());
+    }
+
+    /// Number of actions still to run, including the running one.
+    pub fn len(&self) -> usize {
+        self.pending.len() + usize::from(self.current.is_some())
+    }
+
+    /// `true` when nothing is running or queued.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Advance the queue by `dt`, moving `transform` for [`Action::MoveTo`].
+    ///
+    /// Without a transform, move actions complete immediately.
+    pub fn update(&mut self, dt: Duration, transform: Option<&mut Transform>) -> ActionOutput {
+        self.update_with(dt, transform)
+    }
+
+    fn update_with<T: DerefMut<Target = Transform>>(&mut self, dt: Duration, mut transform: Option<T>) -> ActionOutput {
+        let mut out = ActionOutput::default();
+        if self.paused || self.is_empty() {
+            return out;
+        }
+        let signals = std::mem::take(&mut self.signals);
+        out.finished = step_sequence(&mut self.current, &mut self.pending, dt, &mut transform, &signals, &mut out);
+        out
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Emitted when an [`Action::PlayAnimation`] starts.
+#[derive(Debug, Clone, Event)]
+pub struct PlayAnimationRequested {
+    /// Entity that owns the queue.
+    pub entity: Entity,
+    /// Requested clip name.
+    pub clip: String,
+}
+
+/// Emitted when an [`ActionQueue`] finishes its last action.
+#[derive(Debug, Clone, Event)]
+pub struct ActionQueueFinished {
+    /// Entity that owns the queue.
+    pub entity: Entity,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Advances every [`ActionQueue`] by [`DeltaTime`], running callbacks and
+/// emitting [`PlayAnimationRequested`] / [`ActionQueueFinished`].
+pub fn action_queue_system(
+    mut commands: Commands,
+    dt: Res<DeltaTime>,
+    mut query: Query<(Entity, &mut ActionQueue, Option<&mut Transform>)>,
+    mut animation_events: EventWriter<PlayAnimationRequested>,
+    mut finished_events: EventWriter<ActionQueueFinished>,
+) {
+    let delta = Duration::from_secs_f32(dt.0.max(0.0));
+    for (entity, mut queue, transform) in query.iter_mut() {
+        if queue.paused || queue.is_empty() {
+            continue;
+        }
+        let out = queue.update_with(delta, transform);
+        for clip in out.animations {
+            animation_events.send(PlayAnimationRequested { entity, clip });
+        }
+        for callback in out.callbacks {
+            callback.call(&mut commands, entity);
+        }
+        if out.finished {
+            finished_events.send(ActionQueueFinished { entity });
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: f32) -> Duration {
+        Duration::from_secs_f32(s)
+    }
+
+    #[test]
+    fn move_to_interpolates_over_duration() {
+        let mut transform = Transform::default();
+        let mut queue = ActionQueue::new().then(Action::move_to(Vec3::new(10.0, 0.0, 0.0), 2.0));
+
+        queue.update(secs(1.0), Some(&mut transform));
+        assert!((transform.translation.x - 5.0).abs() < 1e-4);
+
+        let out = queue.update(secs(1.0), Some(&mut transform));
+        assert_eq!(transform.translation, Vec3::new(10.0, 0.0, 0.0));
+        assert!(out.finished);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn eased_move_uses_ease_function() {
+        let mut transform = Transform::default();
+        let mut queue = ActionQueue::new()
+            .then(Action::move_to_eased(Vec3::new(4.0, 0.0, 0.0), 2.0, EaseFunction::QuadraticIn));
+
+        queue.update(secs(1.0), Some(&mut transform));
+        assert!((transform.translation.x - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn actions_run_sequentially() {
+        let mut queue = ActionQueue::new()
+            .then(Action::wait(1.0))
+            .then(Action::play_animation("wave", 1.0));
+
+        let out = queue.update(secs(0.5), None);
+        assert!(out.animations.is_empty());
+
+        let out = queue.update(secs(0.5), None);
+        assert_eq!(out.animations, vec!["wave".to_string()]);
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn parallel_group_waits_for_slowest() {
+        let mut queue = ActionQueue::new().then(Action::parallel([Action::wait(1.0), Action::wait(2.0)]));
+        assert!(!queue.update(secs(1.0), None).finished);
+        assert!(queue.update(secs(1.0), None).finished);
+    }
+
+    #[test]
+    fn signal_completes_wait() {
+        let mut queue = ActionQueue::new().then(Action::wait_for_signal("go"));
+        assert!(!queue.update(secs(10.0), None).finished);
+        queue.signal("other");
+        assert!(!queue.update(secs(0.0), None).finished);
+        queue.signal("go");
+        assert!(queue.update(secs(0.0), None).finished);
+    }
+
+    #[test]
+    fn instant_actions_chain_in_one_step() {
+        let mut queue = ActionQueue::new()
+            .then(Action::callback(|_, _| {}))
+            .then(Action::sequence([Action::callback(|_, _| {}), Action::wait(1.0)]));
+
+        let out = queue.update(secs(0.1), None);
+        assert_eq!(out.callbacks.len(), 2);
+        assert!(!out.finished);
+    }
+
+    #[test]
+    fn paused_queue_does_not_advance() {
+        let mut queue = ActionQueue::new().then(Action::wait(1.0));
+        queue.paused = true;
+        assert!(!queue.update(secs(5.0), None).finished);
+        assert_eq!(queue.len(), 1);
+    }
+
+    // -- ECS system integration tests ---------------------------------------
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn system_runs_callbacks_and_emits_finished() {
+        let mut world = World::new();
+        world.insert_resource(DeltaTime(0.5));
+        world.init_resource::<Events<PlayAnimationRequested>>();
+        world.init_resource::<Events<ActionQueueFinished>>();
+
+        let entity = world
+            .spawn((
+                Transform::default(),
+                ActionQueue::new()
+                    .then(Action::move_to(Vec3::Y, 0.5))
+                    .then(Action::callback(|commands, entity| {
+                        commands.entity(entity).insert(Marker);
+                    })),
+            ))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(action_queue_system);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::Y);
+        assert!(world.get::<Marker>(entity).is_some());
+        let events = world.resource::<Events<ActionQueueFinished>>();
+        let mut reader = events.get_cursor();
+        assert_eq!(reader.read(events).count(), 1);
+    }
+
+    #[derive(Resource, Default)]
+    struct ChangedCount(usize);
+
+    fn count_changed(query: Query<(), Changed<Transform>>, mut count: ResMut<ChangedCount>) {
+        count.0 = query.iter().count();
+    }
+
+    #[test]
+    fn system_only_touches_transform_while_moving() {
+        let mut world = World::new();
+        world.insert_resource(DeltaTime(0.5));
+        world.init_resource::<ChangedCount>();
+        world.init_resource::<Events<PlayAnimationRequested>>();
+        world.init_resource::<Events<ActionQueueFinished>>();
+        world.spawn((Transform::default(), ActionQueue::new().then(Action::move_to(Vec3::Y, 0.5))));
+        world.spawn((Transform::default(), ActionQueue::new().then(Action::wait(10.0))));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((action_queue_system, count_changed).chain());
+        schedule.run(&mut world);
+        // 首帧所有实体都是新增的；之后只有正在移动的实体被标记
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ChangedCount>().0, 0);
+
+        world.spawn((Transform::default(), ActionQueue::new().then(Action::move_to(Vec3::X, 2.0))));
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ChangedCount>().0, 1);
+    }
+}
@@ -0,0 +1,323 @@
+//! # Stat / Attribute System
+//!
+//! Base stat values plus sourced additive/multiplicative modifiers, with lazy
+//! (dirty-flag) recomputation of final values.
+//!
+//! Final value per stat is `(base + flat) * (1 + percent)`, where `flat` and
+//! `percent` sum the [`Stats`] modifiers and any active [`StatusEffects`].
+//!
+//! ## Events
+//!
+//! - [`StatChanged`] — emitted when a final stat value changes
+//!
+//! ## Systems
+//!
+//! - [`stats_system`] — recomputes dirty [`Stats`] (or those whose
+//!   [`StatusEffects`] changed) and emits [`StatChanged`].
+//!
+//! ## Example
+//!
+//! ```rust
+//! use anvilkit_gameplay::attributes::Stats;
+//! use anvilkit_gameplay::status_effects::{Stat, StatModifier};
+//!
+//! let mut stats = Stats::new().with_base(Stat::Attack, 10.0);
+//! stats.add_modifier("sword", StatModifier::flat(Stat::Attack, 5.0));
+//! stats.add_modifier("rage", StatModifier::percent(Stat::Attack, 1.0));
+//! stats.recompute(None);
+//! assert_eq!(stats.get(Stat::Attack), 30.0);
+//!
+//! stats.remove_source("rage");
+//! stats.recompute(None);
+//! assert_eq!(stats.get(Stat::Attack), 15.0);
+//! ```
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use anvilkit_data::DataTable;
+use anvilkit_describe::Describe;
+
+use crate::status_effects::{ModifierTotal, Stat, StatModifier, StatusEffects};
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// A stat modifier tagged with the source that granted it (item, talent, aura...).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourcedModifier {
+    /// Source identifier, used to remove all modifiers from one source at once.
+    pub source: String,
+    /// The modifier itself.
+    pub modifier: StatModifier,
+}
+
+/// Character stats: base values, modifiers, and cached final values.
+#[derive(Debug, Clone, Default, Component, Describe)]
+/// Base stats with sourced modifiers and cached final values.
+pub struct Stats {
+    /// Base values before modifiers.
+    #[describe(hint = "Base value per stat")]
+    base: HashMap<Stat, f32>,
+    /// Active modifiers with their sources.
+    #[describe(hint = "Modifiers granted by equipment, talents, auras")]
+    modifiers: Vec<SourcedModifier>,
+    /// Final values from the last recomputation.
+    #[describe(hint = "Cached final values (read-only)")]
+    computed: HashMap<Stat, f32>,
+    /// Whether base values or modifiers changed since the last recomputation.
+    dirty: bool,
+}
+
+impl Stats {
+    /// Create an empty stat block.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a stat block from a data table of `stat name → base value`.
+    ///
+    /// Names are parsed with [`Stat::from_name`]; unknown names are skipped
+    /// with a warning.
+    pub fn from_table(table: &DataTable<String, f32>) -> Self {
+        let mut stats = Self::new();
+        for (name, &value) in table.iter() {
+            match Stat::from_name(name) {
+                Some(stat) => stats.set_base(stat, value),
+                None => log::warn!("Unknown stat '{}' in table '{}'", name, table.name()),
+            }
+        }
+        stats
+    }
+
+    /// Builder helper to set a base value.
+    pub fn with_base(mut self, stat: Stat, value: f32) -> Self {
+        self.set_base(stat, value);
+        self
+    }
+
+    /// Set a base value and mark the stats dirty.
+    pub fn set_base(&mut self, stat: Stat, value: f32) {
+        self.base.insert(stat, value);
+        self.dirty = true;
+    }
+
+    /// Base value of a stat (zero if unset).
+    pub fn base(&self, stat: Stat) -> f32 {
+        self.base.get(&stat).copied().unwrap_or(0.0)
+    }
+
+    /// Add a modifier granted by `source`.
+    pub fn add_modifier(&mut self, source: impl Into<String>, modifier: StatModifier) {
+        self.modifiers.push(SourcedModifier { source: source.into(), modifier });
+        self.dirty = true;
+    }
+
+    /// Remove every modifier granted by `source`. Returns how many were removed.
+    pub fn remove_source(&mut self, source: &str) -> usize {
+        let before = self.modifiers.len();
+        self.modifiers.retain(|m| m.source != source);
+        let removed = before - self.modifiers.len();
+        if removed > 0 {
+            self.dirty = true;
+        }
+        removed
+    }
+
+    /// Active modifiers.
+    pub fn modifiers(&self) -> &[SourcedModifier] {
+        &self.modifiers
+    }
+
+    /// Final value from the last recomputation (falls back to the base value).
+    pub fn get(&self, stat: Stat) -> f32 {
+        self.computed.get(&stat).copied().unwrap_or_else(|| self.base(stat))
+    }
+
+    /// `true` when base values or modifiers changed since the last recomputation.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Aggregate the modifiers (own and from `effects`) that apply to `stat`.
+    pub fn total_modifier(&self, stat: Stat, effects: Option<&StatusEffects>) -> ModifierTotal {
+        let mut total = ModifierTotal::default();
+        for m in self.modifiers.iter().filter(|m| m.modifier.stat == stat) {
+            total.accumulate(&m.modifier, 1.0);
+        }
+        match effects {
+            Some(effects) => total.combine(effects.total_modifier(stat)),
+            None => total,
+        }
+    }
+
+    /// Recompute all final values, clearing the dirty flag.
+    ///
+    /// Returns `(stat, old, new)` for every final value that changed.
+    pub fn recompute(&mut self, effects: Option<&StatusEffects>) -> Vec<(Stat, f32, f32)> {
+        let mut stats: Vec<Stat> = self.base.keys().copied().collect();
+        stats.extend(self.modifiers.iter().map(|m| m.modifier.stat));
+        if let Some(effects) = effects {
+            for effect in &effects.effects {
+                stats.extend(effect.modifiers.iter().map(|m| m.stat));
+            }
+        }
+
+        let mut computed = HashMap::with_capacity(stats.len());
+        for stat in stats {
+            if computed.contains_key(&stat) {
+                continue;
+            }
+            let value = self.total_modifier(stat, effects).apply(self.base(stat));
+            computed.insert(stat, value);
+        }
+
+        let mut changes = Vec::new();
+        for (&stat, &new) in &computed {
+            let old = self.get(stat);
+            if old != new {
+                changes.push((stat, old, new));
+            }
+        }
+        for (&stat, &old) in &self.computed {
+            if !computed.contains_key(&stat) && old != self.base(stat) {
+                changes.push((stat, old, self.base(stat)));
+            }
+        }
+
+        self.computed = computed;
+        self.dirty = false;
+        changes
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Emitted when a final stat value changes.
+#[derive(Debug, Clone, Event)]
+pub struct StatChanged {
+    /// Entity whose stat changed.
+    pub entity: Entity,
+    /// The stat that changed.
+    pub stat: Stat,
+    /// Previous final value.
+    pub old: f32,
+    /// New final value.
+    pub new: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Recomputes [`Stats`] that are dirty or whose [`StatusEffects`] changed,
+/// emitting [`StatChanged`] for each modified value.
+pub fn stats_system(
+    mut query: Query<(Entity, &mut Stats, Option<Ref<StatusEffects>>)>,
+    mut changed_events: EventWriter<StatChanged>,
+) {
+    for (entity, mut stats, effects) in query.iter_mut() {
+        let effects_changed = effects.as_ref().map_or(false, |e| e.is_changed());
+        if !stats.is_dirty() && !effects_changed {
+            continue;
+        }
+        for (stat, old, new) in stats.recompute(effects.as_deref()) {
+            changed_events.send(StatChanged { entity, stat, old, new });
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::status_effects::StatusEffect;
+
+    #[test]
+    fn new_stats_are_clean_until_modified() {
+        let mut stats = Stats::new();
+        assert!(!stats.is_dirty());
+        stats.set_base(Stat::Speed, 5.0);
+        assert!(stats.is_dirty());
+        stats.recompute(None);
+        assert!(!stats.is_dirty());
+        assert_eq!(stats.get(Stat::Speed), 5.0);
+    }
+
+    #[test]
+    fn modifiers_apply_flat_then_percent() {
+        let mut stats = Stats::new().with_base(Stat::Defense, 20.0);
+        stats.add_modifier("shield", StatModifier::flat(Stat::Defense, 10.0));
+        stats.add_modifier("stance", StatModifier::percent(Stat::Defense, 0.5));
+        let changes = stats.recompute(None);
+        assert_eq!(stats.get(Stat::Defense), 45.0);
+        assert_eq!(changes, vec![(Stat::Defense, 20.0, 45.0)]);
+    }
+
+    #[test]
+    fn remove_source_reverts_value() {
+        let mut stats = Stats::new().with_base(Stat::Speed, 4.0);
+        stats.add_modifier("boots", StatModifier::flat(Stat::Speed, 1.0));
+        stats.add_modifier("boots", StatModifier::percent(Stat::Speed, 0.5));
+        stats.recompute(None);
+        assert_eq!(stats.remove_source("boots"), 2);
+        assert_eq!(stats.remove_source("boots"), 0);
+        let changes = stats.recompute(None);
+        assert_eq!(stats.get(Stat::Speed), 4.0);
+        assert_eq!(changes, vec![(Stat::Speed, 7.5, 4.0)]);
+    }
+
+    #[test]
+    fn status_effects_contribute_modifiers() {
+        let mut stats = Stats::new().with_base(Stat::Speed, 10.0);
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::new("slow").with_modifier(StatModifier::percent(Stat::Speed, -0.5)));
+        stats.recompute(Some(&effects));
+        assert_eq!(stats.get(Stat::Speed), 5.0);
+    }
+
+    #[test]
+    fn from_table_parses_known_stats() {
+        let mut table = DataTable::new("warrior");
+        table.insert("attack".to_string(), 12.0);
+        table.insert("max_health".to_string(), 150.0);
+        table.insert("luck".to_string(), 3.0);
+        let stats = Stats::from_table(&table);
+        assert_eq!(stats.base(Stat::Attack), 12.0);
+        assert_eq!(stats.base(Stat::MaxHealth), 150.0);
+        assert!(stats.is_dirty());
+    }
+
+    // -- ECS system integration tests ---------------------------------------
+
+    #[test]
+    fn stats_system_emits_changes_once() {
+        let mut world = World::new();
+        world.init_resource::<Events<StatChanged>>();
+
+        let entity = world.spawn(Stats::new().with_base(Stat::Attack, 7.0)).id();
+        world
+            .get_mut::<Stats>(entity)
+            .unwrap()
+            .add_modifier("ring", StatModifier::flat(Stat::Attack, 3.0));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(stats_system);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Stats>(entity).unwrap().get(Stat::Attack), 10.0);
+        let events = world.resource::<Events<StatChanged>>();
+        let mut reader = events.get_cursor();
+        let changes: Vec<_> = reader.read(events).collect();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].old, 7.0);
+        assert_eq!(changes[0].new, 10.0);
+    }
+}
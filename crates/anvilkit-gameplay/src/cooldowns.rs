@@ -0,0 +1,254 @@
+//! # Named Cooldowns
+//!
+//! A keyed collection of one-shot [`Timer`]s so gameplay code does not have to
+//! store and tick a `Timer` field per cooldown.
+//!
+//! [`Cooldowns<K>`] works both as a global resource and as a per-entity
+//! component. Keys default to `String`; any `Eq + Hash + Clone` type (typically
+//! a fieldless enum) works too. A key that was never started counts as ready.
+//!
+//! ## Systems
+//!
+//! - [`cooldown_tick_system`] — ticks the `Cooldowns<K>` resource (if present) and
+//!   every `Cooldowns<K>` component by [`DeltaTime`]; add one instance per key type
+//!
+//! ## Example
+//!
+//! ```rust
+//! use anvilkit_gameplay::cooldowns::Cooldowns;
+//! use std::time::Duration;
+//!
+//! let mut cooldowns = Cooldowns::<String>::new();
+//! assert!(cooldowns.ready("dash"));
+//!
+//! assert!(cooldowns.try_start("dash", 1.5));
+//! assert!(!cooldowns.ready("dash"));
+//! assert!(!cooldowns.try_start("dash", 1.5));
+//!
+//! cooldowns.tick(Duration::from_secs(1));
+//! assert_eq!(cooldowns.remaining("dash"), 0.5);
+//! cooldowns.tick(Duration::from_secs(1));
+//! assert!(cooldowns.ready("dash"));
+//! assert!(cooldowns.just_ready("dash"));
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::time::{DeltaTime, Timer};
+
+// ---------------------------------------------------------------------------
+// Resource / component
+// ---------------------------------------------------------------------------
+
+/// Named cooldown timers keyed by `K`.
+///
+/// Usable as a [`Resource`] for global cooldowns or as a [`Component`] for
+/// per-entity cooldowns; [`cooldown_tick_system`] ticks both.
+#[derive(Debug, Clone, Resource, Component)]
+pub struct Cooldowns<K = String>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    timers: HashMap<K, Timer>,
+}
+
+impl<K> Default for Cooldowns<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self { timers: HashMap::new() }
+    }
+}
+
+impl<K> Cooldowns<K>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    /// Create an empty cooldown set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// (Re)start the cooldown for `key`, lasting `seconds`.
+    pub fn start(&mut self, key: impl Into<K>, seconds: f32) {
+        self.timers.insert(key.into(), Timer::from_seconds(seconds.max(0.0)));
+    }
+
+    /// Start the cooldown only if `key` is ready. Returns `true` when started.
+    ///
+    /// The usual "use it if it's off cooldown" pattern in one call.
+    pub fn try_start(&mut self, key: impl Into<K>, seconds: f32) -> bool {
+        let key = key.into();
+        if !self.ready(&key) {
+            return false;
+        }
+        self.start(key, seconds);
+        true
+    }
+
+    /// `true` when `key` was never started or its cooldown has elapsed.
+    pub fn ready<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.timers.get(key).is_none_or(Timer::finished)
+    }
+
+    /// `true` only on the tick in which the cooldown for `key` elapsed.
+    pub fn just_ready<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.timers.get(key).is_some_and(Timer::just_finished)
+    }
+
+    /// Seconds until `key` is ready (zero when ready or unknown).
+    pub fn remaining<Q>(&self, key: &Q) -> f32
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.timers.get(key).map_or(0.0, Timer::remaining_seconds)
+    }
+
+    /// Fraction of the cooldown for `key` that has elapsed, in `0.0..=1.0`
+    /// (`1.0` when ready or unknown). Handy for UI radial fills.
+    pub fn progress<Q>(&self, key: &Q) -> f32
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.timers.get(key).map_or(1.0, Timer::percent)
+    }
+
+    /// Immediately make `key` ready. Returns `true` if it was cooling down.
+    pub fn reset<Q>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.timers.remove(key).is_some_and(|timer| !timer.finished())
+    }
+
+    /// Make every cooldown ready.
+    pub fn clear(&mut self) {
+        self.timers.clear();
+    }
+
+    /// Number of tracked cooldowns (running or elapsed).
+    pub fn len(&self) -> usize {
+        self.timers.len()
+    }
+
+    /// `true` when no cooldown is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.timers.is_empty()
+    }
+
+    /// Keys whose cooldown is still running.
+    pub fn active(&self) -> impl Iterator<Item = &K> {
+        self.timers.iter().filter(|(_, timer)| !timer.finished()).map(|(key, _)| key)
+    }
+
+    /// Advance every cooldown by `delta`.
+    ///
+    /// Elapsed cooldowns stay tracked for one more tick so [`just_ready`](Self::just_ready)
+    /// can observe them, then are dropped.
+    pub fn tick(&mut self, delta: Duration) {
+        for timer in self.timers.values_mut() {
+            timer.tick(delta);
+        }
+        self.timers.retain(|_, timer| !timer.finished() || timer.just_finished());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Ticks the `Cooldowns<K>` resource (if any) and all `Cooldowns<K>` components by [`DeltaTime`].
+///
+/// Register once per key type, e.g. `cooldown_tick_system::<String>`.
+pub fn cooldown_tick_system<K>(
+    dt: Res<DeltaTime>,
+    global: Option<ResMut<Cooldowns<K>>>,
+    mut query: Query<&mut Cooldowns<K>>,
+) where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    let delta = Duration::from_secs_f32(dt.0.max(0.0));
+    if let Some(mut global) = global {
+        global.tick(delta);
+    }
+    for mut cooldowns in query.iter_mut() {
+        cooldowns.tick(delta);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Skill {
+        Dash,
+        Shield,
+    }
+
+    #[test]
+    fn enum_keys_and_lifecycle() {
+        let mut cooldowns = Cooldowns::<Skill>::new();
+        cooldowns.start(Skill::Dash, 1.0);
+        cooldowns.start(Skill::Shield, 3.0);
+        assert_eq!(cooldowns.active().count(), 2);
+
+        cooldowns.tick(Duration::from_secs(1));
+        assert!(cooldowns.ready(&Skill::Dash));
+        assert!(cooldowns.just_ready(&Skill::Dash));
+        assert!(!cooldowns.ready(&Skill::Shield));
+        assert!((cooldowns.progress(&Skill::Shield) - 1.0 / 3.0).abs() < 1e-5);
+
+        // Elapsed cooldowns are dropped after the tick that reported them
+        cooldowns.tick(Duration::from_millis(100));
+        assert!(!cooldowns.just_ready(&Skill::Dash));
+        assert_eq!(cooldowns.len(), 1);
+
+        assert!(cooldowns.reset(&Skill::Shield));
+        assert!(cooldowns.ready(&Skill::Shield));
+        assert!(!cooldowns.reset(&Skill::Shield));
+        assert!(cooldowns.is_empty());
+    }
+
+    #[test]
+    fn system_ticks_resource_and_components() {
+        let mut world = World::new();
+        world.insert_resource(DeltaTime(0.5));
+        let mut global = Cooldowns::<String>::new();
+        global.start("wave", 0.5);
+        world.insert_resource(global);
+
+        let mut local = Cooldowns::<String>::new();
+        local.start("dash", 1.0);
+        let entity = world.spawn(local).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(cooldown_tick_system::<String>);
+        schedule.run(&mut world);
+
+        assert!(world.resource::<Cooldowns>().just_ready("wave"));
+        let local = world.get::<Cooldowns>(entity).unwrap();
+        assert!(!local.ready("dash"));
+        assert_eq!(local.remaining("dash"), 0.5);
+    }
+}
@@ -6,6 +6,12 @@
 //!
 //! - `stats` — Health component and damage/heal events
 //! - `inventory` — Slot-based and stackable item inventory
+//! - `abilities` — Cooldown-driven abilities bound to input actions
+//! - `effects` — Stacking buffs/debuffs with durations and stat modifiers
+//! - `attributes` — Base stats with sourced modifiers and derived values
+//! - `actions` — Queued move/wait/animation/callback actions for cutscenes and AI
+//! - `cooldowns` — Named cooldown timers keyed by string or enum
+//! - `serde` — Serialization for status effects
 
 #[cfg(feature = "stats")]
 pub mod health;
@@ -13,6 +19,21 @@ pub mod health;
 #[cfg(feature = "inventory")]
 pub mod inventory;
 
+#[cfg(feature = "abilities")]
+pub mod abilities;
+
+#[cfg(feature = "effects")]
+pub mod status_effects;
+
+#[cfg(feature = "attributes")]
+pub mod attributes;
+
+#[cfg(feature = "actions")]
+pub mod actions;
+
+#[cfg(feature = "cooldowns")]
+pub mod cooldowns;
+
 /// Prelude for convenient imports.
 pub mod prelude {
     #[cfg(feature = "stats")]
@@ -20,4 +41,19 @@ pub mod prelude {
 
     #[cfg(feature = "inventory")]
     pub use crate::inventory::*;
+
+    #[cfg(feature = "abilities")]
+    pub use crate::abilities::*;
+
+    #[cfg(feature = "effects")]
+    pub use crate::status_effects::*;
+
+    #[cfg(feature = "attributes")]
+    pub use crate::attributes::*;
+
+    #[cfg(feature = "actions")]
+    pub use crate::actions::*;
+
+    #[cfg(feature = "cooldowns")]
+    pub use crate::cooldowns::*;
 }
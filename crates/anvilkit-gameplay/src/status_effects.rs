@@ -0,0 +1,464 @@
+//! # Status Effects
+//!
+//! Timed buffs and debuffs with stacking, periodic ticks, and stat modifier
+//! aggregation.
+//!
+//! ## Events
+//!
+//! - [`StatusEffectTick`] — emitted each time a periodic effect ticks
+//! - [`StatusEffectExpired`] — emitted when an effect's duration runs out
+//!
+//! ## Systems
+//!
+//! - [`status_effect_system`] — advances effect timers, emits tick/expiry events,
+//!   and removes expired effects.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use anvilkit_gameplay::status_effects::{Stat, StatModifier, StatusEffect, StatusEffects};
+//!
+//! let haste = StatusEffect::new("haste")
+//!     .with_duration(5.0)
+//!     .with_modifier(StatModifier::percent(Stat::Speed, 0.25))
+//!     .with_max_stacks(2);
+//!
+//! let mut effects = StatusEffects::default();
+//! effects.apply(haste.clone());
+//! effects.apply(haste);
+//! assert_eq!(effects.stacks("haste"), 2);
+//!
+//! let speed = effects.total_modifier(Stat::Speed).apply(10.0);
+//! assert!((speed - 15.0).abs() < 1e-5);
+//! ```
+
+use std::time::Duration;
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::time::{DeltaTime, Timer};
+use anvilkit_describe::Describe;
+
+// ---------------------------------------------------------------------------
+// Stats and modifiers
+// ---------------------------------------------------------------------------
+
+/// Identifier of a modifiable character stat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Stat {
+    /// Maximum hit-points.
+    MaxHealth,
+    /// Movement speed.
+    Speed,
+    /// Outgoing damage.
+    Attack,
+    /// Damage reduction.
+    Defense,
+    /// Attacks / casts per second.
+    AttackSpeed,
+    /// Game-defined stat.
+    Custom(u32),
+}
+
+impl Stat {
+    /// Parse a built-in stat name (`"Speed"`, `"max_health"`, ...).
+    ///
+    /// Returns `None` for unknown names; game-defined stats use [`Stat::Custom`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().replace('_', "").as_str() {
+            "maxhealth" => Some(Self::MaxHealth),
+            "speed" => Some(Self::Speed),
+            "attack" => Some(Self::Attack),
+            "defense" => Some(Self::Defense),
+            "attackspeed" => Some(Self::AttackSpeed),
+            _ => None,
+        }
+    }
+}
+
+/// How a [`StatModifier`] combines with the base value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifierKind {
+    /// Flat bonus added to the base value.
+    Additive,
+    /// Fractional bonus (`0.25` = +25%) applied after additive bonuses.
+    Multiplicative,
+}
+
+/// A single modification to a [`Stat`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatModifier {
+    /// Stat being modified.
+    pub stat: Stat,
+    /// Additive or multiplicative.
+    pub kind: ModifierKind,
+    /// Modifier amount (flat for additive, fraction for multiplicative).
+    pub value: f32,
+}
+
+impl StatModifier {
+    /// Flat bonus (or penalty, if negative).
+    pub fn flat(stat: Stat, value: f32) -> Self {
+        Self { stat, kind: ModifierKind::Additive, value }
+    }
+
+    /// Fractional bonus (or penalty, if negative).
+    pub fn percent(stat: Stat, value: f32) -> Self {
+        Self { stat, kind: ModifierKind::Multiplicative, value }
+    }
+}
+
+/// Aggregated modifiers for one stat.
+///
+/// Final value is `(base + flat) * (1 + percent)`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ModifierTotal {
+    /// Sum of additive modifiers.
+    pub flat: f32,
+    /// Sum of multiplicative fractions.
+    pub percent: f32,
+}
+
+impl ModifierTotal {
+    /// Fold a modifier (scaled by `count`) into the total.
+    pub fn accumulate(&mut self, modifier: &StatModifier, count: f32) {
+        match modifier.kind {
+            ModifierKind::Additive => self.flat += modifier.value * count,
+            ModifierKind::Multiplicative => self.percent += modifier.value * count,
+        }
+    }
+
+    /// Combine with another total.
+    pub fn combine(self, other: ModifierTotal) -> ModifierTotal {
+        ModifierTotal {
+            flat: self.flat + other.flat,
+            percent: self.percent + other.percent,
+        }
+    }
+
+    /// Apply the total to a base value.
+    pub fn apply(&self, base: f32) -> f32 {
+        (base + self.flat) * (1.0 + self.percent)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Effects
+// ---------------------------------------------------------------------------
+
+/// A buff or debuff instance.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusEffect {
+    /// Effect identifier. Applying an effect with the same id stacks/refreshes it.
+    pub id: String,
+    /// Stat modifiers contributed per stack.
+    pub modifiers: Vec<StatModifier>,
+    /// Remaining lifetime; `None` for permanent effects.
+    pub duration: Option<Timer>,
+    /// Repeating timer driving [`StatusEffectTick`] events (damage/heal over time).
+    pub tick: Option<Timer>,
+    /// Current stack count (>= 1).
+    pub stacks: u32,
+    /// Maximum stack count.
+    pub max_stacks: u32,
+}
+
+impl StatusEffect {
+    /// Create a permanent, single-stack effect with no modifiers.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            modifiers: Vec::new(),
+            duration: None,
+            tick: None,
+            stacks: 1,
+            max_stacks: 1,
+        }
+    }
+
+    /// Builder helper to give the effect a finite duration.
+    pub fn with_duration(mut self, seconds: f32) -> Self {
+        self.duration = Some(Timer::from_seconds(seconds));
+        self
+    }
+
+    /// Builder helper to emit a [`StatusEffectTick`] every `seconds`.
+    pub fn with_tick_interval(mut self, seconds: f32) -> Self {
+        self.tick = Some(Timer::repeating_from_seconds(seconds));
+        self
+    }
+
+    /// Builder helper to add a stat modifier.
+    pub fn with_modifier(mut self, modifier: StatModifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    /// Builder helper to set the maximum stack count.
+    pub fn with_max_stacks(mut self, max_stacks: u32) -> Self {
+        self.max_stacks = max_stacks.max(1);
+        self
+    }
+
+    /// `true` once a finite duration has elapsed.
+    pub fn is_expired(&self) -> bool {
+        self.duration.as_ref().map_or(false, Timer::finished)
+    }
+
+    /// Seconds remaining, or `None` for permanent effects.
+    pub fn remaining_seconds(&self) -> Option<f32> {
+        self.duration.as_ref().map(Timer::remaining_seconds)
+    }
+}
+
+/// Active status effects on an entity.
+#[derive(Debug, Clone, Default, Component, Describe)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Active buffs and debuffs with stacking and durations.
+pub struct StatusEffects {
+    /// Active effects in application order.
+    #[describe(hint = "Active effects: id, modifiers, remaining duration, stacks")]
+    pub effects: Vec<StatusEffect>,
+}
+
+/// Result of [`StatusEffects::tick`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusEffectUpdate {
+    /// `(effect id, stacks)` for every periodic tick that fired.
+    pub ticked: Vec<(String, u32)>,
+    /// Ids of effects that expired and were removed.
+    pub expired: Vec<String>,
+}
+
+impl StatusEffects {
+    /// Apply an effect.
+    ///
+    /// If an effect with the same id is active, its stack count increases (up to
+    /// `max_stacks`) and its duration is refreshed; otherwise the effect is added.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        if let Some(existing) = self.effects.iter_mut().find(|e| e.id == effect.id) {
+            existing.max_stacks = effect.max_stacks;
+            existing.stacks = (existing.stacks + effect.stacks).min(existing.max_stacks);
+            existing.duration = effect.duration;
+            existing.modifiers = effect.modifiers;
+        } else {
+            let mut effect = effect;
+            effect.stacks = effect.stacks.clamp(1, effect.max_stacks);
+            self.effects.push(effect);
+        }
+    }
+
+    /// Remove an effect by id. Returns `true` if it was active.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.effects.len();
+        self.effects.retain(|e| e.id != id);
+        self.effects.len() != before
+    }
+
+    /// `true` if an effect with `id` is active.
+    pub fn has(&self, id: &str) -> bool {
+        self.effects.iter().any(|e| e.id == id)
+    }
+
+    /// Stack count of an effect (zero if inactive).
+    pub fn stacks(&self, id: &str) -> u32 {
+        self.effects.iter().find(|e| e.id == id).map_or(0, |e| e.stacks)
+    }
+
+    /// Aggregate every active modifier for `stat`, scaled by stack count.
+    pub fn total_modifier(&self, stat: Stat) -> ModifierTotal {
+        let mut total = ModifierTotal::default();
+        for effect in &self.effects {
+            for modifier in effect.modifiers.iter().filter(|m| m.stat == stat) {
+                total.accumulate(modifier, effect.stacks as f32);
+            }
+        }
+        total
+    }
+
+    /// Advance all timers, removing expired effects.
+    pub fn tick(&mut self, delta: Duration) -> StatusEffectUpdate {
+        let mut update = StatusEffectUpdate::default();
+        for effect in &mut self.effects {
+            if let Some(tick) = &mut effect.tick {
+                tick.tick(delta);
+                if tick.just_finished() {
+                    update.ticked.push((effect.id.clone(), effect.stacks));
+                }
+            }
+            if let Some(duration) = &mut effect.duration {
+                duration.tick(delta);
+            }
+        }
+        self.effects.retain(|e| {
+            if e.is_expired() {
+                update.expired.push(e.id.clone());
+                false
+            } else {
+                true
+            }
+        });
+        update
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Events
+// ---------------------------------------------------------------------------
+
+/// Emitted when a periodic effect ticks.
+#[derive(Debug, Clone, Event)]
+pub struct StatusEffectTick {
+    /// Entity carrying the effect.
+    pub entity: Entity,
+    /// Effect id.
+    pub effect: String,
+    /// Stack count at the time of the tick.
+    pub stacks: u32,
+}
+
+/// Emitted when an effect expires and is removed.
+#[derive(Debug, Clone, Event)]
+pub struct StatusEffectExpired {
+    /// Entity that carried the effect.
+    pub entity: Entity,
+    /// Effect id.
+    pub effect: String,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Advances [`StatusEffects`] timers by [`DeltaTime`], emitting
+/// [`StatusEffectTick`] and [`StatusEffectExpired`].
+pub fn status_effect_system(
+    dt: Res<DeltaTime>,
+    mut query: Query<(Entity, &mut StatusEffects)>,
+    mut tick_events: EventWriter<StatusEffectTick>,
+    mut expired_events: EventWriter<StatusEffectExpired>,
+) {
+    let delta = Duration::from_secs_f32(dt.0.max(0.0));
+    for (entity, mut effects) in query.iter_mut() {
+        if effects.effects.is_empty() {
+            continue;
+        }
+        let update = effects.tick(delta);
+        for (effect, stacks) in update.ticked {
+            tick_events.send(StatusEffectTick { entity, effect, stacks });
+        }
+        for effect in update.expired {
+            expired_events.send(StatusEffectExpired { entity, effect });
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacking_is_capped() {
+        let poison = StatusEffect::new("poison").with_max_stacks(3);
+        let mut effects = StatusEffects::default();
+        for _ in 0..5 {
+            effects.apply(poison.clone());
+        }
+        assert_eq!(effects.stacks("poison"), 3);
+        assert_eq!(effects.effects.len(), 1);
+    }
+
+    #[test]
+    fn reapply_refreshes_duration() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::new("slow").with_duration(2.0));
+        effects.tick(Duration::from_millis(1500));
+        effects.apply(StatusEffect::new("slow").with_duration(2.0));
+        assert_eq!(effects.effects[0].remaining_seconds(), Some(2.0));
+    }
+
+    #[test]
+    fn total_modifier_aggregates_by_stat() {
+        let mut effects = StatusEffects::default();
+        effects.apply(
+            StatusEffect::new("might")
+                .with_modifier(StatModifier::flat(Stat::Attack, 5.0))
+                .with_modifier(StatModifier::percent(Stat::Speed, -0.1)),
+        );
+        effects.apply(StatusEffect::new("rage").with_modifier(StatModifier::percent(Stat::Attack, 0.5)));
+
+        let attack = effects.total_modifier(Stat::Attack);
+        assert_eq!(attack, ModifierTotal { flat: 5.0, percent: 0.5 });
+        assert!((attack.apply(10.0) - 22.5).abs() < 1e-5);
+        assert!((effects.total_modifier(Stat::Speed).percent + 0.1).abs() < 1e-6);
+        assert_eq!(effects.total_modifier(Stat::Defense), ModifierTotal::default());
+    }
+
+    #[test]
+    fn tick_reports_periodic_ticks_and_expiry() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::new("burn").with_duration(1.0).with_tick_interval(0.5));
+
+        let update = effects.tick(Duration::from_millis(500));
+        assert_eq!(update.ticked, vec![("burn".to_string(), 1)]);
+        assert!(update.expired.is_empty());
+
+        let update = effects.tick(Duration::from_millis(500));
+        assert_eq!(update.ticked.len(), 1);
+        assert_eq!(update.expired, vec!["burn".to_string()]);
+        assert!(!effects.has("burn"));
+    }
+
+    #[test]
+    fn permanent_effect_never_expires() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::new("aura"));
+        effects.tick(Duration::from_secs(3600));
+        assert!(effects.has("aura"));
+        assert!(effects.remove("aura"));
+        assert!(!effects.remove("aura"));
+    }
+
+    #[test]
+    fn stat_from_name() {
+        assert_eq!(Stat::from_name("Speed"), Some(Stat::Speed));
+        assert_eq!(Stat::from_name("max_health"), Some(Stat::MaxHealth));
+        assert_eq!(Stat::from_name("mana"), None);
+    }
+
+    // -- ECS system integration tests ---------------------------------------
+
+    #[test]
+    fn status_effect_system_emits_events() {
+        let mut world = World::new();
+        world.init_resource::<Events<StatusEffectTick>>();
+        world.init_resource::<Events<StatusEffectExpired>>();
+        world.insert_resource(DeltaTime(1.0));
+
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::new("regen").with_duration(1.0).with_tick_interval(1.0));
+        let entity = world.spawn(effects).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(status_effect_system);
+        schedule.run(&mut world);
+
+        let ticks = world.resource::<Events<StatusEffectTick>>();
+        let mut reader = ticks.get_cursor();
+        assert_eq!(reader.read(ticks).count(), 1);
+
+        let expired = world.resource::<Events<StatusEffectExpired>>();
+        let mut reader = expired.get_cursor();
+        let expired: Vec<_> = reader.read(expired).collect();
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].entity, entity);
+        assert!(world.get::<StatusEffects>(entity).unwrap().effects.is_empty());
+    }
+}
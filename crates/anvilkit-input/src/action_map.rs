@@ -6,7 +6,7 @@
 use std::collections::HashMap;
 use bevy_ecs::prelude::*;
 
-use crate::input_state::{InputState, KeyCode, MouseButton};
+use crate::input_state::{InputState, KeyCode, LogicalKey, MouseButton};
 
 /// 高性能动作标识符 — 避免 String 堆分配
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -15,6 +15,8 @@ pub struct ActionId(pub u32);
 /// 输入绑定源
 ///
 /// 一个逻辑动作可以绑定到键盘键或鼠标按钮。
+/// [`InputBinding::Key`] 按物理位置匹配（适合移动等），[`InputBinding::Logical`]
+/// 按当前布局产生的字符匹配（适合 “按 Q 退出” 这类助记键）。
 ///
 /// # 示例
 ///
@@ -31,6 +33,8 @@ pub enum InputBinding {
     Key(KeyCode),
     /// A mouse button binding.
     Mouse(MouseButton),
+    /// A layout-dependent logical key binding.
+    Logical(LogicalKey),
 }
 
 impl InputBinding {
@@ -173,14 +177,17 @@ impl ActionMap {
             let any_active = bindings.iter().any(|b| match b {
                 InputBinding::Key(k) => input.is_key_pressed(*k),
                 InputBinding::Mouse(m) => input.is_mouse_pressed(*m),
+                InputBinding::Logical(l) => input.is_logical_pressed(*l),
             });
             let any_just_pressed = bindings.iter().any(|b| match b {
                 InputBinding::Key(k) => input.is_key_just_pressed(*k),
                 InputBinding::Mouse(m) => input.is_mouse_just_pressed(*m),
+                InputBinding::Logical(l) => input.is_logical_just_pressed(*l),
             });
             let any_just_released = bindings.iter().any(|b| match b {
                 InputBinding::Key(k) => input.is_key_just_released(*k),
                 InputBinding::Mouse(m) => input.is_mouse_just_released(*m),
+                InputBinding::Logical(l) => input.is_logical_just_released(*l),
             });
 
             let state = if any_just_pressed {
@@ -217,6 +224,14 @@ impl ActionMap {
         self.action_state(action).is_just_released()
     }
 
+    /// 本帧刚触发的所有动作名
+    pub fn just_pressed_actions(&self) -> impl Iterator<Item = &str> {
+        self.states
+            .iter()
+            .filter(|(_, state)| state.is_just_pressed())
+            .map(|(name, _)| name.as_str())
+    }
+
     /// 获取动作的所有绑定
     pub fn get_bindings(&self, action: &str) -> Option<&[InputBinding]> {
         self.bindings.get(action).map(|v| v.as_slice())
@@ -277,6 +292,16 @@ impl ActionMap {
         self.axis_bindings.entry(action.to_string()).or_default().push(binding);
     }
 
+    /// 绑定默认移动轴：物理 WASD 位置与方向键
+    ///
+    /// 绑定的是物理键位，AZERTY 上为 ZQSD、Dvorak 上为 ,AOE，无需按布局重映射。
+    pub fn bind_movement_axes(&mut self, x_action: &str, y_action: &str) {
+        self.bind_axis(x_action, AxisBinding::KeyboardAxis { negative: KeyCode::A, positive: KeyCode::D });
+        self.bind_axis(x_action, AxisBinding::KeyboardAxis { negative: KeyCode::Left, positive: KeyCode::Right });
+        self.bind_axis(y_action, AxisBinding::KeyboardAxis { negative: KeyCode::S, positive: KeyCode::W });
+        self.bind_axis(y_action, AxisBinding::KeyboardAxis { negative: KeyCode::Down, positive: KeyCode::Up });
+    }
+
     /// 查询轴值（合并所有绑定的最大绝对值）
     pub fn axis_value(&self, action: &str, input: &InputState, gamepad: Option<&crate::gamepad::GamepadState>) -> f32 {
         let Some(bindings) = self.axis_bindings.get(action) else { return 0.0 };
@@ -378,6 +403,27 @@ mod tests {
         assert_eq!(val, 0.0); // both pressed = cancel out
     }
 
+    #[test]
+    fn test_movement_axes_and_logical_binding() {
+        let mut map = ActionMap::new();
+        map.bind_movement_axes("move_x", "move_y");
+        map.add_binding("quit", InputBinding::Logical(LogicalKey::Character('q')));
+
+        // AZERTY：物理 W 键帽为 Z，仍然向前；物理 A 键帽为 Q，触发 quit
+        let mut input = InputState::new();
+        input.press_key_as(KeyCode::W, LogicalKey::Character('z'));
+        input.press_key_as(KeyCode::A, LogicalKey::Character('q'));
+        map.update(&input);
+        assert_eq!(map.axis_value("move_y", &input, None), 1.0);
+        assert_eq!(map.axis_value("move_x", &input, None), -1.0);
+        assert!(map.is_action_just_pressed("quit"));
+
+        input.end_frame();
+        input.release_key(KeyCode::A);
+        map.update(&input);
+        assert!(map.is_action_just_released("quit"));
+    }
+
     #[test]
     fn test_action_id_register() {
         let mut map = ActionMap::new();
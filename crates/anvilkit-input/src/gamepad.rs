@@ -131,6 +131,16 @@ impl GamepadState {
         self.gamepads.get(&id).map_or(false, |gp| gp.just_pressed.contains(&button))
     }
 
+    /// 查询按钮是否刚释放
+    pub fn is_button_just_released(&self, id: u32, button: GamepadButton) -> bool {
+        self.gamepads.get(&id).is_some_and(|gp| gp.just_released.contains(&button))
+    }
+
+    /// 查询 gamepad 是否已连接
+    pub fn is_connected(&self, id: u32) -> bool {
+        self.gamepads.contains_key(&id)
+    }
+
     /// 查询轴值
     pub fn axis_value(&self, id: u32, axis: GamepadAxis) -> f32 {
         self.gamepads.get(&id).and_then(|gp| gp.axes.get(&axis)).copied().unwrap_or(0.0)
@@ -0,0 +1,547 @@
+//! # 手柄震动
+//!
+//! 声明式的手柄力反馈：
+//!
+//! - [`RumblePattern`]：由若干缓动分段组成的强 / 弱马达振幅包络
+//! - [`Haptics`]：震动资源，按手柄播放、排队、停止效果，每帧混合后交给后端
+//! - [`HapticBackend`]：后端抽象；默认 [`NoopHaptics`]，启用 `gilrs` feature 后可用 `GilrsHaptics`
+//!
+//! 手柄 ID 与 [`GamepadState`](crate::gamepad::GamepadState) 一致。每帧的推进与
+//! [`RumbleRequest`] 事件处理由 `anvilkit_app::auto_plugins::AutoInputPlugin` 驱动。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_core::math::EaseFunction;
+//! use anvilkit_input::haptics::{Haptics, Rumble, RumblePattern};
+//!
+//! let explosion = RumblePattern::new()
+//!     .ramp(0.05, Rumble::new(1.0, 0.6), EaseFunction::QuadraticOut)
+//!     .ramp(0.4, Rumble::OFF, EaseFunction::CubicIn);
+//!
+//! let mut haptics = Haptics::default();
+//! haptics.play(0, explosion);
+//! haptics.update(0.05);
+//! assert_eq!(haptics.intensity(0), Rumble::new(1.0, 0.6));
+//! haptics.update(1.0);
+//! assert!(!haptics.is_playing(0));
+//! ```
+
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::EaseFunction;
+
+/// 双马达震动强度（均为 `[0, 1]`）
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Rumble {
+    /// 低频（强）马达
+    pub strong: f32,
+    /// 高频（弱）马达
+    pub weak: f32,
+}
+
+impl Rumble {
+    /// 静止
+    pub const OFF: Rumble = Rumble { strong: 0.0, weak: 0.0 };
+
+    /// 创建震动强度（钳制到 `[0, 1]`）
+    pub fn new(strong: f32, weak: f32) -> Self {
+        Self { strong: strong.clamp(0.0, 1.0), weak: weak.clamp(0.0, 1.0) }
+    }
+
+    /// 两个马达使用相同强度
+    pub fn uniform(amplitude: f32) -> Self {
+        Self::new(amplitude, amplitude)
+    }
+
+    /// 是否静止
+    pub fn is_off(&self) -> bool {
+        self.strong <= 0.0 && self.weak <= 0.0
+    }
+
+    fn lerp(self, other: Rumble, t: f32) -> Rumble {
+        Rumble {
+            strong: self.strong + (other.strong - self.strong) * t,
+            weak: self.weak + (other.weak - self.weak) * t,
+        }
+    }
+
+    /// 逐马达取最大值（同时播放的效果混合方式）
+    fn max(self, other: Rumble) -> Rumble {
+        Rumble { strong: self.strong.max(other.strong), weak: self.weak.max(other.weak) }
+    }
+
+    fn scale(self, gain: f32) -> Rumble {
+        Rumble { strong: self.strong * gain, weak: self.weak * gain }
+    }
+}
+
+/// 包络分段：从上一段的终点缓动到 `target`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RumbleSegment {
+    /// 分段时长（秒）
+    pub duration: f32,
+    /// 分段终点强度
+    pub target: Rumble,
+    /// 缓动曲线
+    pub ease: EaseFunction,
+}
+
+/// 震动包络
+///
+/// 从 `start` 开始，依次缓动经过各分段终点；播放结束后强度为零。
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RumblePattern {
+    /// 起始强度
+    pub start: Rumble,
+    /// 包络分段
+    pub segments: Vec<RumbleSegment>,
+    /// 循环播放直到被停止
+    pub looping: bool,
+}
+
+impl RumblePattern {
+    /// 空包络（从静止开始）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 恒定强度持续 `duration` 秒
+    pub fn constant(rumble: Rumble, duration: f32) -> Self {
+        Self { start: rumble, ..Self::default() }.hold(duration)
+    }
+
+    /// 冲击：瞬间到达 `amplitude` 后在 `duration` 秒内衰减
+    pub fn impact(amplitude: f32, duration: f32) -> Self {
+        Self { start: Rumble::new(amplitude, amplitude * 0.5), ..Self::default() }
+            .ramp(duration, Rumble::OFF, EaseFunction::QuadraticOut)
+    }
+
+    /// 添加缓动分段
+    pub fn ramp(mut self, duration: f32, target: Rumble, ease: EaseFunction) -> Self {
+        self.segments.push(RumbleSegment { duration: duration.max(0.0), target, ease });
+        self
+    }
+
+    /// 保持当前强度 `duration` 秒
+    pub fn hold(self, duration: f32) -> Self {
+        let current = self.end();
+        self.ramp(duration, current, EaseFunction::Linear)
+    }
+
+    /// 设置为循环播放
+    pub fn looped(mut self) -> Self {
+        self.looping = true;
+        self
+    }
+
+    /// 单次播放的总时长（秒）
+    pub fn duration(&self) -> f32 {
+        self.segments.iter().map(|s| s.duration).sum()
+    }
+
+    fn end(&self) -> Rumble {
+        self.segments.last().map_or(self.start, |s| s.target)
+    }
+
+    /// 播放 `time` 秒时的强度；非循环包络结束后返回 `None`
+    pub fn sample(&self, time: f32) -> Option<Rumble> {
+        let duration = self.duration();
+        let mut time = time.max(0.0);
+        if self.looping && duration > 0.0 {
+            time %= duration;
+        } else if time >= duration {
+            return None;
+        }
+        let mut from = self.start;
+        for segment in &self.segments {
+            if time < segment.duration {
+                let t = segment.ease.ease(time / segment.duration);
+                return Some(from.lerp(segment.target, t));
+            }
+            time -= segment.duration;
+            from = segment.target;
+        }
+        Some(from)
+    }
+}
+
+/// 震动效果句柄
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RumbleId(pub u64);
+
+/// 震动后端
+///
+/// [`Haptics`] 每帧为强度发生变化的手柄调用一次 [`set_rumble`](Self::set_rumble)。
+pub trait HapticBackend: Send + Sync {
+    /// 设置手柄当前的马达强度（[`Rumble::OFF`] 表示停止）
+    fn set_rumble(&mut self, gamepad: u32, rumble: Rumble);
+
+    /// 后端名称
+    fn name(&self) -> &str;
+}
+
+/// 空后端：不支持力反馈的平台或未连接手柄时使用
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopHaptics;
+
+impl HapticBackend for NoopHaptics {
+    fn set_rumble(&mut self, _gamepad: u32, _rumble: Rumble) {}
+
+    fn name(&self) -> &str {
+        "noop"
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ActiveRumble {
+    id: RumbleId,
+    gamepad: u32,
+    pattern: RumblePattern,
+    elapsed: f32,
+    gain: f32,
+}
+
+/// 震动资源
+///
+/// 同一手柄上同时播放的效果逐马达取最大值；[`queue`](Self::queue) 的效果在该手柄
+/// 当前效果全部结束后依次播放。
+#[derive(Resource)]
+pub struct Haptics {
+    backend: Box<dyn HapticBackend>,
+    playing: Vec<ActiveRumble>,
+    queued: Vec<ActiveRumble>,
+    /// 手柄 → 上次发送给后端的强度
+    sent: HashMap<u32, Rumble>,
+    next_id: u64,
+    /// 全局强度倍数（设置菜单中的“震动强度”）
+    pub gain: f32,
+    /// 是否启用；关闭时所有手柄静止，但效果照常推进
+    pub enabled: bool,
+}
+
+impl Default for Haptics {
+    fn default() -> Self {
+        Self::new(NoopHaptics)
+    }
+}
+
+impl std::fmt::Debug for Haptics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Haptics")
+            .field("backend", &self.backend.name())
+            .field("playing", &self.playing.len())
+            .field("queued", &self.queued.len())
+            .field("gain", &self.gain)
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl Haptics {
+    /// 使用指定后端创建
+    pub fn new(backend: impl HapticBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+            playing: Vec::new(),
+            queued: Vec::new(),
+            sent: HashMap::new(),
+            next_id: 0,
+            gain: 1.0,
+            enabled: true,
+        }
+    }
+
+    /// 替换后端（先让旧后端上的所有手柄静止）
+    pub fn set_backend(&mut self, backend: impl HapticBackend + 'static) {
+        for (gamepad, _) in self.sent.drain() {
+            self.backend.set_rumble(gamepad, Rumble::OFF);
+        }
+        self.backend = Box::new(backend);
+    }
+
+    /// 当前后端名称
+    pub fn backend_name(&self) -> &str {
+        self.backend.name()
+    }
+
+    fn effect(&mut self, gamepad: u32, pattern: RumblePattern) -> ActiveRumble {
+        self.next_id += 1;
+        ActiveRumble { id: RumbleId(self.next_id), gamepad, pattern, elapsed: 0.0, gain: 1.0 }
+    }
+
+    /// 立即在手柄上播放效果
+    pub fn play(&mut self, gamepad: u32, pattern: RumblePattern) -> RumbleId {
+        let effect = self.effect(gamepad, pattern);
+        let id = effect.id;
+        self.playing.push(effect);
+        id
+    }
+
+    /// 在手柄当前的效果结束后播放
+    pub fn queue(&mut self, gamepad: u32, pattern: RumblePattern) -> RumbleId {
+        let effect = self.effect(gamepad, pattern);
+        let id = effect.id;
+        self.queued.push(effect);
+        id
+    }
+
+    /// 设置单个效果的强度倍数
+    pub fn set_effect_gain(&mut self, id: RumbleId, gain: f32) {
+        if let Some(effect) = self.playing.iter_mut().chain(&mut self.queued).find(|e| e.id == id) {
+            effect.gain = gain.max(0.0);
+        }
+    }
+
+    /// 停止（或取消排队的）效果
+    pub fn stop(&mut self, id: RumbleId) {
+        self.playing.retain(|e| e.id != id);
+        self.queued.retain(|e| e.id != id);
+    }
+
+    /// 停止手柄上的所有效果（包括排队的）
+    pub fn stop_gamepad(&mut self, gamepad: u32) {
+        self.playing.retain(|e| e.gamepad != gamepad);
+        self.queued.retain(|e| e.gamepad != gamepad);
+    }
+
+    /// 停止所有效果
+    pub fn stop_all(&mut self) {
+        self.playing.clear();
+        self.queued.clear();
+    }
+
+    /// 效果是否仍在播放或排队
+    pub fn is_active(&self, id: RumbleId) -> bool {
+        self.playing.iter().chain(&self.queued).any(|e| e.id == id)
+    }
+
+    /// 手柄上是否有正在播放的效果
+    pub fn is_playing(&self, gamepad: u32) -> bool {
+        self.playing.iter().any(|e| e.gamepad == gamepad)
+    }
+
+    /// 手柄当前的混合强度（未乘全局倍数）
+    pub fn intensity(&self, gamepad: u32) -> Rumble {
+        self.playing
+            .iter()
+            .filter(|e| e.gamepad == gamepad)
+            .filter_map(|e| e.pattern.sample(e.elapsed).map(|r| r.scale(e.gain)))
+            .fold(Rumble::OFF, Rumble::max)
+    }
+
+    /// 推进所有效果 `dt` 秒，并把变化的强度发送给后端
+    pub fn update(&mut self, dt: f32) {
+        for effect in &mut self.playing {
+            effect.elapsed += dt;
+        }
+        self.playing.retain(|e| e.pattern.sample(e.elapsed).is_some());
+
+        // 空闲手柄开始播放各自排队的下一个效果
+        let mut index = 0;
+        while index < self.queued.len() {
+            let gamepad = self.queued[index].gamepad;
+            if self.playing.iter().any(|e| e.gamepad == gamepad) {
+                index += 1;
+            } else {
+                let effect = self.queued.remove(index);
+                self.playing.push(effect);
+            }
+        }
+
+        // 上一帧震动过的手柄与正在播放的手柄都需要刷新（前者可能需要归零）
+        let mut gamepads: Vec<u32> = self.sent.keys().copied().collect();
+        gamepads.extend(self.playing.iter().map(|e| e.gamepad));
+        gamepads.sort_unstable();
+        gamepads.dedup();
+        for gamepad in gamepads {
+            let rumble = if self.enabled {
+                let mixed = self.intensity(gamepad).scale(self.gain);
+                Rumble::new(mixed.strong, mixed.weak)
+            } else {
+                Rumble::OFF
+            };
+            let previous = self.sent.get(&gamepad).copied().unwrap_or(Rumble::OFF);
+            if previous != rumble {
+                self.backend.set_rumble(gamepad, rumble);
+            }
+            if rumble.is_off() {
+                self.sent.remove(&gamepad);
+            } else {
+                self.sent.insert(gamepad, rumble);
+            }
+        }
+    }
+}
+
+/// 声明式震动请求事件
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct RumbleRequest {
+    /// 目标手柄
+    pub gamepad: u32,
+    /// 震动包络
+    pub pattern: RumblePattern,
+    /// 为 `true` 时排队到当前效果之后
+    pub queued: bool,
+}
+
+/// gilrs 力反馈后端
+///
+/// 每个手柄持有强 / 弱两个无限循环的满幅效果，每帧通过 gain 调制强度。
+#[cfg(feature = "gilrs")]
+pub struct GilrsHaptics {
+    state: std::sync::Mutex<GilrsState>,
+}
+
+#[cfg(feature = "gilrs")]
+struct GilrsState {
+    gilrs: gilrs::Gilrs,
+    effects: HashMap<u32, [gilrs::ff::Effect; 2]>,
+}
+
+#[cfg(feature = "gilrs")]
+impl GilrsHaptics {
+    /// 初始化 gilrs；失败时记录日志并返回 `None`（调用方可保留 [`NoopHaptics`]）
+    pub fn new() -> Option<Self> {
+        match gilrs::Gilrs::new() {
+            Ok(gilrs) => Some(Self::from_gilrs(gilrs)),
+            Err(e) => {
+                log::warn!("gilrs unavailable, force feedback disabled: {e}");
+                None
+            }
+        }
+    }
+
+    /// 使用已有的 gilrs 实例
+    pub fn from_gilrs(gilrs: gilrs::Gilrs) -> Self {
+        Self { state: std::sync::Mutex::new(GilrsState { gilrs, effects: HashMap::new() }) }
+    }
+
+    /// 访问内部的 gilrs 实例（如轮询手柄事件）
+    pub fn with_gilrs<R>(&self, f: impl FnOnce(&mut gilrs::Gilrs) -> R) -> R {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        f(&mut state.gilrs)
+    }
+}
+
+#[cfg(feature = "gilrs")]
+impl GilrsState {
+    fn effects(&mut self, gamepad: u32) -> Option<&[gilrs::ff::Effect; 2]> {
+        use gilrs::ff::{BaseEffect, BaseEffectType, EffectBuilder, Repeat, Replay, Ticks};
+
+        if !self.effects.contains_key(&gamepad) {
+            let id = self
+                .gilrs
+                .gamepads()
+                .find(|(id, pad)| usize::from(*id) as u32 == gamepad && pad.is_ff_supported())
+                .map(|(id, _)| id)?;
+            let mut build = |kind: BaseEffectType| {
+                let effect = EffectBuilder::new()
+                    .add_effect(BaseEffect {
+                        kind,
+                        scheduling: Replay { play_for: Ticks::from_ms(1000), ..Default::default() },
+                        ..Default::default()
+                    })
+                    .repeat(Repeat::Infinitely)
+                    .gain(0.0)
+                    .gamepads(&[id])
+                    .finish(&mut self.gilrs)
+                    .ok()?;
+                effect.play().ok()?;
+                Some(effect)
+            };
+            let strong = build(BaseEffectType::Strong { magnitude: u16::MAX })?;
+            let weak = build(BaseEffectType::Weak { magnitude: u16::MAX })?;
+            self.effects.insert(gamepad, [strong, weak]);
+        }
+        self.effects.get(&gamepad)
+    }
+}
+
+#[cfg(feature = "gilrs")]
+impl HapticBackend for GilrsHaptics {
+    fn set_rumble(&mut self, gamepad: u32, rumble: Rumble) {
+        let state = self.state.get_mut().unwrap_or_else(|e| e.into_inner());
+        if rumble.is_off() {
+            // 停止时释放效果，断开重连的手柄会重新创建
+            state.effects.remove(&gamepad);
+            return;
+        }
+        let Some([strong, weak]) = state.effects(gamepad) else { return };
+        if strong.set_gain(rumble.strong).and(weak.set_gain(rumble.weak)).is_err() {
+            log::warn!("gamepad {gamepad}: failed to update force feedback");
+            state.effects.remove(&gamepad);
+        }
+    }
+
+    fn name(&self) -> &str {
+        "gilrs"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<(u32, Rumble)>>>);
+
+    impl HapticBackend for Recorder {
+        fn set_rumble(&mut self, gamepad: u32, rumble: Rumble) {
+            self.0.lock().unwrap().push((gamepad, rumble));
+        }
+
+        fn name(&self) -> &str {
+            "recorder"
+        }
+    }
+
+    #[test]
+    fn test_pattern_sampling() {
+        let pattern = RumblePattern::new()
+            .ramp(1.0, Rumble::uniform(1.0), EaseFunction::Linear)
+            .hold(0.5)
+            .ramp(1.0, Rumble::OFF, EaseFunction::QuadraticIn);
+        assert_eq!(pattern.duration(), 2.5);
+        assert_eq!(pattern.sample(0.5), Some(Rumble::uniform(0.5)));
+        assert_eq!(pattern.sample(1.25), Some(Rumble::uniform(1.0)));
+        assert_eq!(pattern.sample(2.0), Some(Rumble::uniform(0.75)));
+        assert_eq!(pattern.sample(2.5), None);
+
+        let looped = RumblePattern::constant(Rumble::new(0.2, 0.4), 0.1).looped();
+        assert_eq!(looped.sample(10.05), Some(Rumble::new(0.2, 0.4)));
+    }
+
+    #[test]
+    fn test_mixing_queue_and_stop() {
+        let recorder = Recorder::default();
+        let mut haptics = Haptics::new(recorder.clone());
+        haptics.play(0, RumblePattern::constant(Rumble::new(0.5, 0.0), 1.0));
+        let long = haptics.play(0, RumblePattern::constant(Rumble::new(0.2, 0.8), 2.0));
+        let queued = haptics.queue(0, RumblePattern::constant(Rumble::uniform(0.3), 1.0));
+        haptics.play(1, RumblePattern::impact(1.0, 0.5));
+
+        haptics.update(0.0);
+        assert_eq!(haptics.intensity(0), Rumble::new(0.5, 0.8));
+        assert!(haptics.is_active(queued));
+
+        haptics.stop(long);
+        haptics.update(1.0);
+        // 两个直接播放的效果结束后开始排队的效果
+        assert_eq!(haptics.intensity(0), Rumble::uniform(0.3));
+        assert!(!haptics.is_playing(1));
+
+        haptics.stop_gamepad(0);
+        haptics.update(0.1);
+        let log = recorder.0.lock().unwrap();
+        assert_eq!(log.last(), Some(&(0, Rumble::OFF)));
+        assert!(log.contains(&(1, Rumble::OFF)));
+        drop(log);
+
+        // 静止的手柄不再重复发送
+        let sent = recorder.0.lock().unwrap().len();
+        haptics.update(0.1);
+        assert_eq!(recorder.0.lock().unwrap().len(), sent);
+    }
+}
@@ -0,0 +1,350 @@
+//! # 输入缓冲与连招
+//!
+//! 动作 / 格斗游戏的常用输入基础设施：
+//!
+//! - [`InputBuffer`]：记录带时间戳的动作，超出时间窗口自动丢弃；
+//!   可用于“提前按跳跃”的缓冲输入（[`InputBuffer::consume`]）
+//! - [`Combo`] / [`ComboMatcher`]: 按顺序匹配动作序列（如 ↓ ↘ → + 拳），触发 [`ComboTriggered`] 事件
+//!
+//! `InputBuffer` 与 `ComboMatcher` 既可作为全局资源，也可作为实体组件（每个角色独立缓冲）。
+//! 每帧的记录与匹配由 `anvilkit_app::auto_plugins::AutoInputPlugin` 驱动。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_input::input_buffer::{Combo, ComboMatcher, InputBuffer};
+//!
+//! let matcher = ComboMatcher::new()
+//!     .with_combo(Combo::new("hadouken", ["down", "down_forward", "forward", "punch"]));
+//! let mut buffer = InputBuffer::new(1.0);
+//!
+//! for action in ["down", "down_forward", "forward", "punch"] {
+//!     buffer.record(action);
+//!     buffer.advance(1.0 / 60.0);
+//! }
+//! assert_eq!(matcher.check(&mut buffer), vec!["hadouken".to_string()]);
+//! // 已触发的输入被消耗，不会重复触发
+//! assert!(matcher.check(&mut buffer).is_empty());
+//! ```
+
+use std::collections::VecDeque;
+use bevy_ecs::prelude::*;
+
+use crate::action_map::ActionMap;
+use crate::player_input::PlayerActions;
+
+/// 缓冲中的一次动作输入
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufferedAction {
+    /// 动作名
+    pub action: String,
+    /// 记录时的缓冲时钟（秒）
+    pub time: f32,
+    /// 单调递增序号
+    seq: u64,
+}
+
+/// 输入缓冲
+///
+/// 按时间顺序记录动作输入，早于 `window` 秒的记录在 [`advance`](Self::advance) 时丢弃。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_input::input_buffer::InputBuffer;
+///
+/// let mut buffer = InputBuffer::new(0.15);
+/// buffer.record("jump");
+/// buffer.advance(0.1);
+/// // 落地时仍在窗口内：执行缓冲的跳跃
+/// assert!(buffer.consume("jump"));
+/// assert!(!buffer.consume("jump"));
+/// ```
+#[derive(Component, Resource, Debug, Clone)]
+pub struct InputBuffer {
+    window: f32,
+    time: f32,
+    entries: VecDeque<BufferedAction>,
+    next_seq: u64,
+    /// 连招匹配已检查到的序号
+    checked_seq: u64,
+}
+
+impl Default for InputBuffer {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl InputBuffer {
+    /// 创建时间窗口为 `window` 秒的输入缓冲
+    pub fn new(window: f32) -> Self {
+        Self {
+            window: window.max(0.0),
+            time: 0.0,
+            entries: VecDeque::new(),
+            next_seq: 0,
+            checked_seq: 0,
+        }
+    }
+
+    /// 时间窗口（秒）
+    pub fn window(&self) -> f32 {
+        self.window
+    }
+
+    /// 设置时间窗口
+    pub fn set_window(&mut self, window: f32) {
+        self.window = window.max(0.0);
+        self.prune();
+    }
+
+    /// 缓冲时钟（秒）
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// 推进时钟并丢弃过期记录
+    pub fn advance(&mut self, dt: f32) {
+        self.time += dt;
+        self.prune();
+    }
+
+    fn prune(&mut self) {
+        while self.entries.front().is_some_and(|e| self.time - e.time > self.window) {
+            self.entries.pop_front();
+        }
+    }
+
+    /// 以当前时间记录一次动作
+    pub fn record(&mut self, action: impl Into<String>) {
+        self.next_seq += 1;
+        self.entries.push_back(BufferedAction { action: action.into(), time: self.time, seq: self.next_seq });
+    }
+
+    /// 记录动作映射表中本帧刚触发的所有动作（同帧按动作名排序）
+    pub fn record_just_pressed(&mut self, actions: &ActionMap) {
+        self.record_sorted(actions.just_pressed_actions());
+    }
+
+    /// 记录某个玩家本帧刚触发的所有动作（同帧按动作名排序）
+    pub fn record_player_just_pressed(&mut self, actions: &PlayerActions) {
+        self.record_sorted(actions.just_pressed_actions());
+    }
+
+    fn record_sorted<'a>(&mut self, actions: impl Iterator<Item = &'a str>) {
+        let mut pressed: Vec<&str> = actions.collect();
+        pressed.sort_unstable();
+        for action in pressed {
+            self.record(action);
+        }
+    }
+
+    /// 窗口内的记录（从旧到新）
+    pub fn entries(&self) -> impl Iterator<Item = &BufferedAction> {
+        self.entries.iter()
+    }
+
+    /// 窗口内是否有该动作
+    pub fn contains(&self, action: &str) -> bool {
+        self.entries.iter().any(|e| e.action == action)
+    }
+
+    /// 最近 `seconds` 秒内是否记录过该动作
+    pub fn pressed_within(&self, action: &str, seconds: f32) -> bool {
+        self.entries.iter().rev().any(|e| e.action == action && self.time - e.time <= seconds)
+    }
+
+    /// 消耗最近一次该动作的记录；窗口内没有时返回 `false`
+    pub fn consume(&mut self, action: &str) -> bool {
+        match self.entries.iter().rposition(|e| e.action == action) {
+            Some(index) => {
+                self.entries.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 清空所有记录
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.checked_seq = self.next_seq;
+    }
+}
+
+/// 连招：按顺序输入的动作序列
+///
+/// 相邻两步的间隔不超过 `max_gap` 秒，整个序列须在输入缓冲的时间窗口内完成。
+/// 步骤之间夹杂的其他输入会被忽略（摇杆划过的中间方向不影响判定）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combo {
+    /// 连招名（事件中返回）
+    pub name: String,
+    /// 动作序列，最后一步为触发键
+    pub steps: Vec<String>,
+    /// 相邻步骤的最大间隔（秒）
+    pub max_gap: f32,
+}
+
+impl Combo {
+    /// 创建连招（默认步骤间隔 0.25 秒）
+    pub fn new<S: Into<String>>(name: impl Into<String>, steps: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            name: name.into(),
+            steps: steps.into_iter().map(Into::into).collect(),
+            max_gap: 0.25,
+        }
+    }
+
+    /// 设置相邻步骤的最大间隔
+    pub fn with_max_gap(mut self, max_gap: f32) -> Self {
+        self.max_gap = max_gap;
+        self
+    }
+
+    /// 以 `entries[last]` 为最后一步匹配，返回所用记录的下标（从新到旧）
+    fn match_ending_at(&self, entries: &VecDeque<BufferedAction>, last: usize) -> Option<Vec<usize>> {
+        let (trigger, rest) = self.steps.split_last()?;
+        if entries[last].action != *trigger {
+            return None;
+        }
+        let mut used = vec![last];
+        let mut time = entries[last].time;
+        for step in rest.iter().rev() {
+            // 按时间而非下标比较：同一帧的输入视为无序
+            let index = (0..entries.len()).rev().find(|&i| {
+                let e = &entries[i];
+                !used.contains(&i) && e.action == *step && e.time <= time && time - e.time <= self.max_gap
+            })?;
+            time = entries[index].time;
+            used.push(index);
+        }
+        Some(used)
+    }
+}
+
+/// 连招匹配器
+///
+/// 更长的连招优先：同一次触发输入只产生一个连招，且匹配所用的记录被消耗。
+#[derive(Component, Resource, Debug, Clone, Default)]
+pub struct ComboMatcher {
+    combos: Vec<Combo>,
+}
+
+impl ComboMatcher {
+    /// 创建空的匹配器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加连招（构建器风格）
+    pub fn with_combo(mut self, combo: Combo) -> Self {
+        self.add(combo);
+        self
+    }
+
+    /// 添加连招
+    pub fn add(&mut self, combo: Combo) {
+        let index = self.combos.partition_point(|c| c.steps.len() >= combo.steps.len());
+        self.combos.insert(index, combo);
+    }
+
+    /// 已注册的连招（按步骤数降序）
+    pub fn combos(&self) -> &[Combo] {
+        &self.combos
+    }
+
+    /// 检查上次调用以来新记录的输入，返回触发的连招名
+    pub fn check(&self, buffer: &mut InputBuffer) -> Vec<String> {
+        let mut triggered = Vec::new();
+        let mut last = 0;
+        while last < buffer.entries.len() {
+            if buffer.entries[last].seq <= buffer.checked_seq {
+                last += 1;
+                continue;
+            }
+            let matched = self
+                .combos
+                .iter()
+                .find_map(|combo| combo.match_ending_at(&buffer.entries, last).map(|used| (combo, used)));
+            match matched {
+                Some((combo, mut used)) => {
+                    triggered.push(combo.name.clone());
+                    used.sort_unstable();
+                    for index in used.iter().rev() {
+                        buffer.entries.remove(*index);
+                    }
+                    last -= used.iter().filter(|&&i| i < last).count();
+                }
+                None => last += 1,
+            }
+        }
+        buffer.checked_seq = buffer.next_seq;
+        triggered
+    }
+}
+
+/// 连招触发事件
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ComboTriggered {
+    /// 拥有输入缓冲的实体；全局 [`InputBuffer`] 资源为 `None`
+    pub entity: Option<Entity>,
+    /// 连招名
+    pub combo: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(buffer: &mut InputBuffer, actions: &[&str], dt: f32) {
+        for action in actions {
+            buffer.record(*action);
+            buffer.advance(dt);
+        }
+    }
+
+    #[test]
+    fn test_buffer_window_and_consume() {
+        let mut buffer = InputBuffer::new(0.2);
+        buffer.record("jump");
+        buffer.advance(0.1);
+        buffer.record("attack");
+        assert!(buffer.pressed_within("attack", 0.0));
+        assert!(!buffer.pressed_within("jump", 0.05));
+
+        buffer.advance(0.15);
+        assert!(!buffer.contains("jump"));
+        assert!(buffer.contains("attack"));
+        assert!(buffer.consume("attack"));
+        assert_eq!(buffer.entries().count(), 0);
+    }
+
+    #[test]
+    fn test_combo_gap_noise_and_priority() {
+        let matcher = ComboMatcher::new()
+            .with_combo(Combo::new("punch", ["punch"]))
+            .with_combo(Combo::new("hadouken", ["down", "down_forward", "forward", "punch"]).with_max_gap(0.1));
+        assert_eq!(matcher.combos()[0].name, "hadouken");
+
+        // 夹杂的输入被忽略
+        let mut buffer = InputBuffer::new(1.0);
+        feed(&mut buffer, &["down", "back", "down_forward", "forward", "punch"], 0.05);
+        assert_eq!(matcher.check(&mut buffer), vec!["hadouken"]);
+
+        // 间隔过长只触发普通拳
+        feed(&mut buffer, &["down", "down_forward"], 0.05);
+        buffer.advance(0.2);
+        feed(&mut buffer, &["forward", "punch"], 0.05);
+        assert_eq!(matcher.check(&mut buffer), vec!["punch"]);
+
+        // 同一帧按下方向与拳也能匹配
+        buffer.clear();
+        feed(&mut buffer, &["down", "down_forward"], 0.05);
+        buffer.record("punch");
+        buffer.record("forward");
+        assert_eq!(matcher.check(&mut buffer), vec!["hadouken"]);
+        assert_eq!(buffer.entries().count(), 0);
+    }
+}
@@ -2,15 +2,21 @@
 //!
 //! 追踪键盘按键和鼠标按钮的当前帧状态和上一帧状态，
 //! 支持 pressed / just_pressed / just_released 查询。
+//!
+//! 键盘同时提供两种视角：[`KeyCode`] 是物理按键位置（扫描码，按美式 QWERTY 键帽命名），
+//! 不随键盘布局变化，适合移动等按位置的绑定；[`LogicalKey`] 是当前布局下按键产生的字符，
+//! 适合文本输入和“按 Q 退出”这类按字符的提示。两者之间的转换见
+//! [`KeyboardLayout`](crate::keyboard_layout::KeyboardLayout)。
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use bevy_ecs::prelude::*;
 use glam::Vec2;
 use anvilkit_describe::Describe;
 
 /// 键盘键码
 ///
-/// 常用键的枚举，与 winit VirtualKeyCode 对应。
+/// 物理按键位置的枚举（与 winit 物理 `KeyCode` 对应），按美式 QWERTY 键帽命名：
+/// AZERTY 键盘上左上角字母键按下时仍为 [`KeyCode::Q`]，虽然键帽印的是 “A”。
 ///
 /// # 示例
 ///
@@ -247,6 +253,68 @@ impl KeyCode {
     }
 }
 
+/// 逻辑按键：当前键盘布局下按键产生的字符或功能键
+///
+/// 字符统一为小写，Shift 不产生新的逻辑键。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_input::input_state::{KeyCode, LogicalKey};
+///
+/// assert_eq!(LogicalKey::character('Q'), LogicalKey::Character('q'));
+/// assert_eq!(LogicalKey::Named(KeyCode::Enter).as_char(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogicalKey {
+    /// A printable character (lowercase).
+    Character(char),
+    /// A non-printable key, identified by its [`KeyCode`].
+    Named(KeyCode),
+}
+
+impl LogicalKey {
+    /// 由字符创建逻辑键（转为小写）
+    pub fn character(c: char) -> Self {
+        LogicalKey::Character(c.to_lowercase().next().unwrap_or(c))
+    }
+
+    /// 字符逻辑键对应的字符
+    pub fn as_char(&self) -> Option<char> {
+        match self {
+            LogicalKey::Character(c) => Some(*c),
+            LogicalKey::Named(_) => None,
+        }
+    }
+
+    /// 将 winit 逻辑键映射到 AnvilKit LogicalKey
+    pub fn from_winit(key: &winit::keyboard::Key) -> Option<LogicalKey> {
+        use winit::keyboard::{Key, NamedKey as NK};
+        match key {
+            Key::Character(text) => text.chars().next().map(LogicalKey::character),
+            Key::Named(named) => {
+                let code = match named {
+                    NK::Space => return Some(LogicalKey::Character(' ')),
+                    NK::Enter => KeyCode::Enter, NK::Escape => KeyCode::Escape,
+                    NK::Tab => KeyCode::Tab, NK::Backspace => KeyCode::Backspace,
+                    NK::Delete => KeyCode::Delete,
+                    NK::ArrowLeft => KeyCode::Left, NK::ArrowRight => KeyCode::Right,
+                    NK::ArrowUp => KeyCode::Up, NK::ArrowDown => KeyCode::Down,
+                    NK::F1 => KeyCode::F1, NK::F2 => KeyCode::F2,
+                    NK::F3 => KeyCode::F3, NK::F4 => KeyCode::F4,
+                    NK::F5 => KeyCode::F5, NK::F6 => KeyCode::F6,
+                    NK::F7 => KeyCode::F7, NK::F8 => KeyCode::F8,
+                    NK::F9 => KeyCode::F9, NK::F10 => KeyCode::F10,
+                    NK::F11 => KeyCode::F11, NK::F12 => KeyCode::F12,
+                    _ => return None,
+                };
+                Some(LogicalKey::Named(code))
+            }
+            _ => None,
+        }
+    }
+}
+
 /// 鼠标按钮
 ///
 /// # 示例
@@ -328,6 +396,17 @@ pub struct InputState {
     mouse_delta: Vec2,
     /// 滚轮本帧滚动量（行数）
     scroll_delta: f32,
+
+    /// 当前按下的逻辑键
+    logical_pressed: HashSet<LogicalKey>,
+    /// 本帧新按下的逻辑键
+    logical_just_pressed: HashSet<LogicalKey>,
+    /// 本帧刚松开的逻辑键
+    logical_just_released: HashSet<LogicalKey>,
+    /// 物理键 → 按下时产生的逻辑键（松开时布局或修饰键可能已改变）
+    logical_by_key: HashMap<KeyCode, LogicalKey>,
+    /// 本帧输入的文本
+    text: String,
 }
 
 impl InputState {
@@ -343,6 +422,11 @@ impl InputState {
             mouse_position: Vec2::ZERO,
             mouse_delta: Vec2::ZERO,
             scroll_delta: 0.0,
+            logical_pressed: HashSet::new(),
+            logical_just_pressed: HashSet::new(),
+            logical_just_released: HashSet::new(),
+            logical_by_key: HashMap::new(),
+            text: String::new(),
         }
     }
 
@@ -355,11 +439,25 @@ impl InputState {
         }
     }
 
-    /// 记录按键松开
+    /// 记录按键按下，同时记录该键在当前布局下产生的逻辑键
+    pub fn press_key_as(&mut self, key: KeyCode, logical: LogicalKey) {
+        self.press_key(key);
+        if let Some(previous) = self.logical_by_key.insert(key, logical) {
+            if previous != logical {
+                self.release_logical(previous);
+            }
+        }
+        self.press_logical(logical);
+    }
+
+    /// 记录按键松开（同时松开该键按下时产生的逻辑键）
     pub fn release_key(&mut self, key: KeyCode) {
         if self.keys_pressed.remove(&key) {
             self.keys_just_released.insert(key);
         }
+        if let Some(logical) = self.logical_by_key.remove(&key) {
+            self.release_logical(logical);
+        }
     }
 
     /// 键是否正在按下
@@ -377,6 +475,47 @@ impl InputState {
         self.keys_just_released.contains(&key)
     }
 
+    // --- Logical keys / text ---
+
+    /// 记录逻辑键按下（无对应物理键码时使用）
+    pub fn press_logical(&mut self, key: LogicalKey) {
+        if self.logical_pressed.insert(key) {
+            self.logical_just_pressed.insert(key);
+        }
+    }
+
+    /// 记录逻辑键松开
+    pub fn release_logical(&mut self, key: LogicalKey) {
+        if self.logical_pressed.remove(&key) {
+            self.logical_just_released.insert(key);
+        }
+    }
+
+    /// 逻辑键是否正在按下
+    pub fn is_logical_pressed(&self, key: LogicalKey) -> bool {
+        self.logical_pressed.contains(&key)
+    }
+
+    /// 逻辑键是否本帧刚按下
+    pub fn is_logical_just_pressed(&self, key: LogicalKey) -> bool {
+        self.logical_just_pressed.contains(&key)
+    }
+
+    /// 逻辑键是否本帧刚松开
+    pub fn is_logical_just_released(&self, key: LogicalKey) -> bool {
+        self.logical_just_released.contains(&key)
+    }
+
+    /// 追加本帧输入的文本（已按布局、修饰键和输入法处理）
+    pub fn push_text(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// 本帧输入的文本
+    pub fn text_input(&self) -> &str {
+        &self.text
+    }
+
     // --- Mouse buttons ---
 
     /// 记录鼠标按钮按下
@@ -442,12 +581,15 @@ impl InputState {
 
     // --- Frame lifecycle ---
 
-    /// 帧结束，清除 just_pressed / just_released / delta 状态
+    /// 帧结束，清除 just_pressed / just_released / delta / 文本状态
     pub fn end_frame(&mut self) {
         self.keys_just_pressed.clear();
         self.keys_just_released.clear();
         self.mouse_just_pressed.clear();
         self.mouse_just_released.clear();
+        self.logical_just_pressed.clear();
+        self.logical_just_released.clear();
+        self.text.clear();
         self.mouse_delta = Vec2::ZERO;
         self.scroll_delta = 0.0;
     }
@@ -524,6 +666,26 @@ mod tests {
         assert_eq!(input.scroll_delta(), 0.0);
     }
 
+    #[test]
+    fn test_logical_keys_follow_physical_release() {
+        let mut input = InputState::new();
+        // AZERTY：物理 Q 位置产生字符 'a'
+        input.press_key_as(KeyCode::Q, LogicalKey::character('A'));
+        input.push_text("a");
+        assert!(input.is_key_pressed(KeyCode::Q));
+        assert!(input.is_logical_just_pressed(LogicalKey::Character('a')));
+        assert!(!input.is_key_pressed(KeyCode::A));
+        assert_eq!(input.text_input(), "a");
+
+        input.end_frame();
+        assert_eq!(input.text_input(), "");
+        assert!(input.is_logical_pressed(LogicalKey::Character('a')));
+
+        input.release_key(KeyCode::Q);
+        assert!(!input.is_logical_pressed(LogicalKey::Character('a')));
+        assert!(input.is_logical_just_released(LogicalKey::Character('a')));
+    }
+
     #[test]
     fn test_duplicate_press() {
         let mut input = InputState::new();
@@ -0,0 +1,228 @@
+//! # 键盘布局
+//!
+//! 在物理按键位置（[`KeyCode`]）与当前布局下的字符（[`LogicalKey`]）之间转换。
+//!
+//! 移动等按位置的绑定直接使用 [`KeyCode`]：`W`/`A`/`S`/`D` 在 AZERTY 上对应键帽
+//! `Z`/`Q`/`S`/`D`，在 Dvorak 上对应 `,`/`A`/`O`/`E`，手感与 QWERTY 一致。
+//! [`KeyboardLayout`] 用于反向查询——界面提示应显示哪个键帽、某个字符在哪个物理键上。
+//!
+//! 布局可以显式设置，也可以由窗口事件中观察到的 (物理键, 字符) 对自动识别。
+
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+
+use crate::input_state::{KeyCode, LogicalKey};
+
+/// 物理字母键 A..Z（QWERTY 命名）
+const LETTER_KEYS: [KeyCode; 26] = [
+    KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F, KeyCode::G,
+    KeyCode::H, KeyCode::I, KeyCode::J, KeyCode::K, KeyCode::L, KeyCode::M, KeyCode::N,
+    KeyCode::O, KeyCode::P, KeyCode::Q, KeyCode::R, KeyCode::S, KeyCode::T, KeyCode::U,
+    KeyCode::V, KeyCode::W, KeyCode::X, KeyCode::Y, KeyCode::Z,
+];
+
+/// 常见键盘布局
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_input::keyboard_layout::LayoutPreset;
+/// use anvilkit_input::input_state::KeyCode;
+///
+/// assert_eq!(LayoutPreset::Azerty.character(KeyCode::W), Some('z'));
+/// assert_eq!(LayoutPreset::Dvorak.key_for('s'), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LayoutPreset {
+    /// US QWERTY（物理键码的命名基准）
+    #[default]
+    Qwerty,
+    /// 法语 AZERTY
+    Azerty,
+    /// 德语 QWERTZ
+    Qwertz,
+    /// Dvorak
+    Dvorak,
+    /// Colemak
+    Colemak,
+}
+
+impl LayoutPreset {
+    /// 所有预设
+    pub const ALL: [LayoutPreset; 5] = [
+        LayoutPreset::Qwerty,
+        LayoutPreset::Azerty,
+        LayoutPreset::Qwertz,
+        LayoutPreset::Dvorak,
+        LayoutPreset::Colemak,
+    ];
+
+    /// 物理字母键 A..Z 上的字符
+    fn letters(self) -> &'static [u8; 26] {
+        match self {
+            LayoutPreset::Qwerty => b"abcdefghijklmnopqrstuvwxyz",
+            LayoutPreset::Azerty => b"qbcdefghijkl,noparstuvzxyw",
+            LayoutPreset::Qwertz => b"abcdefghijklmnopqrstuvwxzy",
+            LayoutPreset::Dvorak => b"axje.uidchtnmbrl'poygk,qf;",
+            LayoutPreset::Colemak => b"abcsftdhuneimky;qprglvwxjz",
+        }
+    }
+
+    /// 物理字母键在该布局下产生的字符（仅覆盖字母区）
+    pub fn character(self, key: KeyCode) -> Option<char> {
+        let index = LETTER_KEYS.iter().position(|&k| k == key)?;
+        Some(self.letters()[index] as char)
+    }
+
+    /// 该布局下产生字符 `c` 的物理字母键
+    pub fn key_for(self, c: char) -> Option<KeyCode> {
+        let c = c.to_ascii_lowercase();
+        let index = self.letters().iter().position(|&b| b as char == c)?;
+        Some(LETTER_KEYS[index])
+    }
+}
+
+/// 键盘布局资源
+///
+/// 先查询观察到的映射，再回退到预设表。启用自动识别时，每次观察后切换到与所有观察一致的唯一预设。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_input::keyboard_layout::{KeyboardLayout, LayoutPreset};
+/// use anvilkit_input::input_state::{KeyCode, LogicalKey};
+///
+/// let mut layout = KeyboardLayout::default();
+/// // 物理 W 位置产生了 'z' → AZERTY
+/// layout.observe(KeyCode::W, LogicalKey::Character('z'));
+/// assert_eq!(layout.preset(), LayoutPreset::Azerty);
+/// assert_eq!(layout.key_label(KeyCode::A), "Q");
+/// assert_eq!(layout.key_for(LogicalKey::Character('a')), Some(KeyCode::Q));
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct KeyboardLayout {
+    preset: LayoutPreset,
+    /// 由观察到的事件自动识别预设
+    pub auto_detect: bool,
+    observed: HashMap<KeyCode, char>,
+}
+
+impl Default for KeyboardLayout {
+    fn default() -> Self {
+        Self {
+            preset: LayoutPreset::Qwerty,
+            auto_detect: true,
+            observed: HashMap::new(),
+        }
+    }
+}
+
+impl KeyboardLayout {
+    /// 使用固定预设（关闭自动识别）
+    pub fn new(preset: LayoutPreset) -> Self {
+        Self { preset, auto_detect: false, observed: HashMap::new() }
+    }
+
+    /// 当前预设
+    pub fn preset(&self) -> LayoutPreset {
+        self.preset
+    }
+
+    /// 设置预设
+    pub fn set_preset(&mut self, preset: LayoutPreset) {
+        self.preset = preset;
+    }
+
+    /// 记录一次按键事件中物理键与逻辑键的对应关系
+    pub fn observe(&mut self, key: KeyCode, logical: LogicalKey) {
+        let Some(c) = logical.as_char() else { return };
+        if !LETTER_KEYS.contains(&key) {
+            return;
+        }
+        self.observed.insert(key, c);
+        if self.auto_detect {
+            if let Some(preset) = self.detect() {
+                self.preset = preset;
+            }
+        }
+    }
+
+    /// 与所有观察一致的唯一预设；没有观察、无一致或不唯一时返回 `None`
+    pub fn detect(&self) -> Option<LayoutPreset> {
+        if self.observed.is_empty() {
+            return None;
+        }
+        let mut matching = LayoutPreset::ALL.into_iter().filter(|preset| {
+            self.observed.iter().all(|(&key, &c)| preset.character(key) == Some(c))
+        });
+        let first = matching.next()?;
+        matching.next().is_none().then_some(first)
+    }
+
+    /// 物理键在当前布局下产生的逻辑键
+    pub fn logical(&self, key: KeyCode) -> LogicalKey {
+        match self.observed.get(&key).copied().or_else(|| self.preset.character(key)) {
+            Some(c) => LogicalKey::Character(c),
+            None => LogicalKey::Named(key),
+        }
+    }
+
+    /// 当前布局下产生该逻辑键的物理键
+    pub fn key_for(&self, logical: LogicalKey) -> Option<KeyCode> {
+        match logical {
+            LogicalKey::Named(key) => Some(key),
+            LogicalKey::Character(c) => self
+                .observed
+                .iter()
+                .find_map(|(&key, &seen)| (seen == c).then_some(key))
+                .or_else(|| self.preset.key_for(c)),
+        }
+    }
+
+    /// 界面提示用的键帽文字：字母区按当前布局显示字符，其余按键使用键名
+    pub fn key_label(&self, key: KeyCode) -> String {
+        match self.logical(key) {
+            LogicalKey::Character(c) => c.to_uppercase().collect(),
+            LogicalKey::Named(key) => format!("{key:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_are_permutations() {
+        for preset in LayoutPreset::ALL {
+            for key in LETTER_KEYS {
+                let c = preset.character(key).unwrap();
+                assert_eq!(preset.key_for(c), Some(key), "{preset:?} {key:?}");
+            }
+        }
+        assert_eq!(LayoutPreset::Qwertz.character(KeyCode::Y), Some('z'));
+        assert_eq!(LayoutPreset::Dvorak.key_for('e'), Some(KeyCode::D));
+    }
+
+    #[test]
+    fn test_detect_layout() {
+        let mut layout = KeyboardLayout::default();
+        assert_eq!(layout.detect(), None);
+
+        // 'a' on physical A is consistent with QWERTY, QWERTZ, Dvorak and Colemak
+        layout.observe(KeyCode::A, LogicalKey::Character('a'));
+        assert_eq!(layout.detect(), None);
+        assert_eq!(layout.preset(), LayoutPreset::Qwerty);
+
+        layout.observe(KeyCode::E, LogicalKey::Character('.'));
+        assert_eq!(layout.preset(), LayoutPreset::Dvorak);
+        assert_eq!(layout.key_label(KeyCode::W), ",");
+        assert_eq!(layout.key_label(KeyCode::Space), "Space");
+
+        // Fixed presets ignore observations
+        let mut fixed = KeyboardLayout::new(LayoutPreset::Colemak);
+        fixed.observe(KeyCode::W, LogicalKey::Character('z'));
+        assert_eq!(fixed.preset(), LayoutPreset::Colemak);
+        assert_eq!(fixed.key_for(LogicalKey::Character('z')), Some(KeyCode::W));
+    }
+}
@@ -22,10 +22,18 @@
 pub mod input_state;
 pub mod action_map;
 pub mod gamepad;
+pub mod keyboard_layout;
+pub mod input_buffer;
+pub mod haptics;
+pub mod player_input;
 
 /// Convenient re-exports for common input types.
 pub mod prelude {
-    pub use crate::input_state::{InputState, KeyCode, MouseButton};
+    pub use crate::input_state::{InputState, KeyCode, LogicalKey, MouseButton};
     pub use crate::action_map::{ActionId, ActionMap, ActionState, AxisBinding, InputBinding};
     pub use crate::gamepad::{GamepadAxis, GamepadButton, GamepadState};
+    pub use crate::keyboard_layout::{KeyboardLayout, LayoutPreset};
+    pub use crate::input_buffer::{Combo, ComboMatcher, ComboTriggered, InputBuffer};
+    pub use crate::haptics::{HapticBackend, Haptics, NoopHaptics, Rumble, RumbleId, RumblePattern, RumbleRequest};
+    pub use crate::player_input::{DeviceChange, InputDevice, KeyboardZone, PlayerActions, PlayerBindings, PlayerDeviceChanged, PlayerInputMap};
 }
@@ -0,0 +1,535 @@
+//! # 本地多人输入路由
+//!
+//! 把输入设备分配给玩家槽位，按玩家独立计算动作状态（分屏等本地多人玩法的基础）：
+//!
+//! - [`InputDevice`]：手柄（按 ID）或键盘的一个区域（[`KeyboardZone`]，两人共用一个键盘）
+//! - [`PlayerBindings`]：与设备无关的动作绑定，所有玩家共用
+//! - [`PlayerInputMap`]：槽位 → 设备分配；处理手柄热插拔并产生 [`PlayerDeviceChanged`] 事件
+//! - [`PlayerActions`]：玩家实体上的组件，保存该玩家本帧的动作状态与轴值
+//!
+//! 每帧的设备检查与动作计算由 `anvilkit_app::auto_plugins::AutoInputPlugin` 驱动。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_input::prelude::*;
+//! use anvilkit_input::player_input::{InputDevice, KeyboardZone, PlayerActions, PlayerBindings, PlayerInputMap};
+//!
+//! let mut bindings = PlayerBindings::new();
+//! bindings.bind_movement_axes("move_x", "move_y");
+//! bindings.bind_key("jump", KeyboardZone::Left, InputBinding::Key(KeyCode::Space));
+//! bindings.bind_key("jump", KeyboardZone::Right, InputBinding::Key(KeyCode::Enter));
+//! bindings.bind_button("jump", GamepadButton::South);
+//!
+//! let mut players = PlayerInputMap::new(2, bindings);
+//! players.assign(0, InputDevice::Keyboard(KeyboardZone::Left));
+//! players.assign(1, InputDevice::Keyboard(KeyboardZone::Right));
+//!
+//! let mut input = InputState::new();
+//! input.press_key(KeyCode::Enter);
+//! input.press_key(KeyCode::A);
+//!
+//! let (mut p1, mut p2) = (PlayerActions::new(0), PlayerActions::new(1));
+//! players.evaluate(&mut p1, &input, None);
+//! players.evaluate(&mut p2, &input, None);
+//! assert!(!p1.is_active("jump") && p2.is_just_pressed("jump"));
+//! assert_eq!(p1.axis("move_x"), -1.0);
+//! assert_eq!(p2.axis("move_x"), 0.0);
+//! ```
+
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+
+use crate::action_map::{ActionState, InputBinding};
+use crate::gamepad::{GamepadAxis, GamepadButton, GamepadState};
+use crate::input_state::{InputState, KeyCode};
+
+/// 键盘区域
+///
+/// 两名玩家共用一个键盘时，左侧玩家使用 [`Left`](Self::Left) 区域的绑定（WASD 一侧），
+/// 右侧玩家使用 [`Right`](Self::Right)（方向键 / 小键盘一侧）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum KeyboardZone {
+    /// 整个键盘：使用所有区域的绑定
+    #[default]
+    Full,
+    /// 左半区
+    Left,
+    /// 右半区
+    Right,
+}
+
+impl KeyboardZone {
+    /// 分配到此区域的玩家是否使用 `binding` 区域的绑定
+    pub fn covers(self, binding: KeyboardZone) -> bool {
+        self == KeyboardZone::Full || self == binding
+    }
+}
+
+/// 玩家输入设备
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputDevice {
+    /// 键盘（及鼠标）的一个区域
+    Keyboard(KeyboardZone),
+    /// 手柄（ID 与 [`GamepadState`] 一致）
+    Gamepad(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PlayerBinding {
+    Key(KeyboardZone, InputBinding),
+    Button(GamepadButton),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PlayerAxis {
+    Keys { zone: KeyboardZone, negative: KeyCode, positive: KeyCode },
+    Gamepad(GamepadAxis),
+}
+
+/// 玩家动作绑定
+///
+/// 键盘绑定属于某个 [`KeyboardZone`]；手柄绑定作用于分配给该玩家的手柄。
+#[derive(Debug, Clone, Default)]
+pub struct PlayerBindings {
+    buttons: HashMap<String, Vec<PlayerBinding>>,
+    axes: HashMap<String, Vec<PlayerAxis>>,
+}
+
+impl PlayerBindings {
+    /// 创建空的绑定表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为动作绑定键盘区域内的按键（或鼠标按钮）
+    pub fn bind_key(&mut self, action: &str, zone: KeyboardZone, binding: InputBinding) {
+        self.buttons.entry(action.to_string()).or_default().push(PlayerBinding::Key(zone, binding));
+    }
+
+    /// 为动作绑定手柄按钮
+    pub fn bind_button(&mut self, action: &str, button: GamepadButton) {
+        self.buttons.entry(action.to_string()).or_default().push(PlayerBinding::Button(button));
+    }
+
+    /// 为轴绑定键盘区域内的一对按键（负键 + 正键 → [-1, 0, 1]）
+    pub fn bind_key_axis(&mut self, action: &str, zone: KeyboardZone, negative: KeyCode, positive: KeyCode) {
+        self.axes.entry(action.to_string()).or_default().push(PlayerAxis::Keys { zone, negative, positive });
+    }
+
+    /// 为轴绑定手柄模拟轴
+    pub fn bind_gamepad_axis(&mut self, action: &str, axis: GamepadAxis) {
+        self.axes.entry(action.to_string()).or_default().push(PlayerAxis::Gamepad(axis));
+    }
+
+    /// 绑定默认移动轴：左半区 WASD、右半区方向键、手柄左摇杆
+    pub fn bind_movement_axes(&mut self, x_action: &str, y_action: &str) {
+        self.bind_key_axis(x_action, KeyboardZone::Left, KeyCode::A, KeyCode::D);
+        self.bind_key_axis(y_action, KeyboardZone::Left, KeyCode::S, KeyCode::W);
+        self.bind_key_axis(x_action, KeyboardZone::Right, KeyCode::Left, KeyCode::Right);
+        self.bind_key_axis(y_action, KeyboardZone::Right, KeyCode::Down, KeyCode::Up);
+        self.bind_gamepad_axis(x_action, GamepadAxis::LeftStickX);
+        self.bind_gamepad_axis(y_action, GamepadAxis::LeftStickY);
+    }
+
+    /// 所有已绑定的按钮动作名
+    pub fn actions(&self) -> impl Iterator<Item = &str> {
+        self.buttons.keys().map(String::as_str)
+    }
+}
+
+/// 设备分配变化
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeviceChange {
+    /// 设备被分配给槽位（手动、自动加入或断线后重新分配）
+    Assigned(InputDevice),
+    /// 设备从槽位移除（手动移除或被分配给其他槽位）
+    Unassigned(InputDevice),
+    /// 槽位的手柄断开；槽位会优先接收下一个连接的手柄
+    Disconnected(InputDevice),
+}
+
+/// 玩家设备分配变化事件
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerDeviceChanged {
+    /// 玩家槽位
+    pub slot: usize,
+    /// 变化
+    pub change: DeviceChange,
+}
+
+#[derive(Debug, Clone, Default)]
+struct PlayerSlot {
+    device: Option<InputDevice>,
+    /// 断开前使用的手柄，等待重新连接
+    lost: Option<InputDevice>,
+}
+
+/// 玩家输入映射资源
+///
+/// 固定数量的玩家槽位，每个槽位最多一个输入设备。启用 [`auto_assign`](Self::auto_assign)
+/// 时，未分配的已连接手柄依次加入：先回到断线前所在的槽位，再填补手柄断开的槽位，
+/// 最后填补空槽位。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_input::gamepad::GamepadState;
+/// use anvilkit_input::player_input::{DeviceChange, InputDevice, PlayerBindings, PlayerInputMap};
+///
+/// let mut players = PlayerInputMap::new(2, PlayerBindings::new());
+/// let mut pads = GamepadState::new();
+/// pads.connect(7);
+/// players.update_devices(&pads);
+/// assert_eq!(players.device(0), Some(InputDevice::Gamepad(7)));
+///
+/// // 拔出后重新插入（新 ID）：回到原槽位
+/// pads.disconnect(7);
+/// players.update_devices(&pads);
+/// pads.connect(8);
+/// players.update_devices(&pads);
+/// assert_eq!(players.gamepad(0), Some(8));
+/// assert_eq!(players.take_events().len(), 3);
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct PlayerInputMap {
+    bindings: PlayerBindings,
+    slots: Vec<PlayerSlot>,
+    events: Vec<PlayerDeviceChanged>,
+    /// 自动把新连接的手柄分配给空闲槽位
+    pub auto_assign: bool,
+}
+
+impl Default for PlayerInputMap {
+    fn default() -> Self {
+        Self::new(4, PlayerBindings::new())
+    }
+}
+
+impl PlayerInputMap {
+    /// 创建 `max_players` 个槽位
+    pub fn new(max_players: usize, bindings: PlayerBindings) -> Self {
+        Self {
+            bindings,
+            slots: vec![PlayerSlot::default(); max_players],
+            events: Vec::new(),
+            auto_assign: true,
+        }
+    }
+
+    /// 动作绑定
+    pub fn bindings(&self) -> &PlayerBindings {
+        &self.bindings
+    }
+
+    /// 可修改的动作绑定
+    pub fn bindings_mut(&mut self) -> &mut PlayerBindings {
+        &mut self.bindings
+    }
+
+    /// 槽位数量
+    pub fn max_players(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// 已分配设备的槽位数量
+    pub fn active_players(&self) -> usize {
+        self.slots.iter().filter(|s| s.device.is_some()).count()
+    }
+
+    /// 槽位当前的设备
+    pub fn device(&self, slot: usize) -> Option<InputDevice> {
+        self.slots.get(slot).and_then(|s| s.device)
+    }
+
+    /// 槽位当前的手柄 ID（如用于 [`Haptics`](crate::haptics::Haptics)）
+    pub fn gamepad(&self, slot: usize) -> Option<u32> {
+        match self.device(slot)? {
+            InputDevice::Gamepad(id) => Some(id),
+            InputDevice::Keyboard(_) => None,
+        }
+    }
+
+    /// 使用该设备的槽位
+    pub fn slot_of(&self, device: InputDevice) -> Option<usize> {
+        self.slots.iter().position(|s| s.device == Some(device))
+    }
+
+    /// 把设备分配给槽位
+    ///
+    /// 设备原先所在的槽位与槽位原先的设备都会被移除。越界槽位被忽略。
+    pub fn assign(&mut self, slot: usize, device: InputDevice) {
+        if slot >= self.slots.len() || self.slots[slot].device == Some(device) {
+            return;
+        }
+        if let Some(previous) = self.slot_of(device) {
+            self.unassign(previous);
+        }
+        self.unassign(slot);
+        self.slots[slot] = PlayerSlot { device: Some(device), lost: None };
+        self.events.push(PlayerDeviceChanged { slot, change: DeviceChange::Assigned(device) });
+    }
+
+    /// 移除槽位的设备
+    pub fn unassign(&mut self, slot: usize) {
+        let Some(entry) = self.slots.get_mut(slot) else { return };
+        entry.lost = None;
+        if let Some(device) = entry.device.take() {
+            self.events.push(PlayerDeviceChanged { slot, change: DeviceChange::Unassigned(device) });
+        }
+    }
+
+    /// 检查手柄热插拔：断开的手柄从槽位移除，（启用自动分配时）新手柄加入空闲槽位
+    pub fn update_devices(&mut self, gamepads: &GamepadState) {
+        for (slot, entry) in self.slots.iter_mut().enumerate() {
+            if let Some(device @ InputDevice::Gamepad(id)) = entry.device {
+                if !gamepads.is_connected(id) {
+                    entry.device = None;
+                    entry.lost = Some(device);
+                    self.events.push(PlayerDeviceChanged { slot, change: DeviceChange::Disconnected(device) });
+                }
+            }
+        }
+        if !self.auto_assign {
+            return;
+        }
+
+        let mut connected = gamepads.connected_gamepads();
+        connected.sort_unstable();
+        for id in connected {
+            let device = InputDevice::Gamepad(id);
+            if self.slot_of(device).is_some() {
+                continue;
+            }
+            let free = |s: &PlayerSlot| s.device.is_none();
+            let slot = self
+                .slots
+                .iter()
+                .position(|s| free(s) && s.lost == Some(device))
+                .or_else(|| self.slots.iter().position(|s| free(s) && s.lost.is_some()))
+                .or_else(|| self.slots.iter().position(free));
+            match slot {
+                Some(slot) => self.assign(slot, device),
+                None => break,
+            }
+        }
+    }
+
+    /// 取出自上次调用以来的设备分配变化
+    pub fn take_events(&mut self) -> Vec<PlayerDeviceChanged> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// 按 `actions` 所属槽位的设备计算该玩家的动作状态与轴值
+    ///
+    /// 设备被移除时仍按下的动作在本帧变为 `JustReleased`。
+    pub fn evaluate(&self, actions: &mut PlayerActions, input: &InputState, gamepads: Option<&GamepadState>) {
+        let device = self.device(actions.slot);
+        let key_zone = |zone: KeyboardZone| matches!(device, Some(InputDevice::Keyboard(own)) if own.covers(zone));
+        let pad = match device {
+            Some(InputDevice::Gamepad(id)) => gamepads.map(|g| (g, id)),
+            _ => None,
+        };
+
+        for (action, bindings) in &self.bindings.buttons {
+            let (mut pressed, mut just_pressed, mut just_released) = (false, false, false);
+            for binding in bindings {
+                match *binding {
+                    PlayerBinding::Key(zone, binding) if key_zone(zone) => {
+                        let (p, jp, jr) = match binding {
+                            InputBinding::Key(k) => (
+                                input.is_key_pressed(k),
+                                input.is_key_just_pressed(k),
+                                input.is_key_just_released(k),
+                            ),
+                            InputBinding::Mouse(m) => (
+                                input.is_mouse_pressed(m),
+                                input.is_mouse_just_pressed(m),
+                                input.is_mouse_just_released(m),
+                            ),
+                            InputBinding::Logical(l) => (
+                                input.is_logical_pressed(l),
+                                input.is_logical_just_pressed(l),
+                                input.is_logical_just_released(l),
+                            ),
+                        };
+                        pressed |= p;
+                        just_pressed |= jp;
+                        just_released |= jr;
+                    }
+                    PlayerBinding::Button(button) => {
+                        if let Some((g, id)) = pad {
+                            pressed |= g.is_button_pressed(id, button);
+                            just_pressed |= g.is_button_just_pressed(id, button);
+                            just_released |= g.is_button_just_released(id, button);
+                        }
+                    }
+                    PlayerBinding::Key(..) => {}
+                }
+            }
+
+            let was_active = actions.state(action).is_active();
+            let state = if pressed && (just_pressed || !was_active) {
+                ActionState::JustPressed
+            } else if pressed {
+                ActionState::Pressed
+            } else if just_released || was_active {
+                ActionState::JustReleased
+            } else {
+                ActionState::Inactive
+            };
+            actions.states.insert(action.clone(), state);
+        }
+
+        for (action, bindings) in &self.bindings.axes {
+            let mut value = 0.0f32;
+            for binding in bindings {
+                let v = match *binding {
+                    PlayerAxis::Keys { zone, negative, positive } if key_zone(zone) => {
+                        let neg = if input.is_key_pressed(negative) { -1.0 } else { 0.0 };
+                        let pos = if input.is_key_pressed(positive) { 1.0 } else { 0.0 };
+                        neg + pos
+                    }
+                    PlayerAxis::Gamepad(axis) => pad.map_or(0.0, |(g, id)| g.axis_value(id, axis)),
+                    PlayerAxis::Keys { .. } => 0.0,
+                };
+                if v.abs() > value.abs() {
+                    value = v;
+                }
+            }
+            actions.axes.insert(action.clone(), value);
+        }
+    }
+}
+
+/// 玩家动作状态组件
+///
+/// 挂在玩家实体上，由 [`PlayerInputMap::evaluate`] 每帧更新；查询接口与
+/// [`ActionMap`](crate::action_map::ActionMap) 一致。
+#[derive(Component, Debug, Clone, Default)]
+pub struct PlayerActions {
+    /// 玩家槽位
+    pub slot: usize,
+    states: HashMap<String, ActionState>,
+    axes: HashMap<String, f32>,
+}
+
+impl PlayerActions {
+    /// 创建槽位 `slot` 的玩家动作状态
+    pub fn new(slot: usize) -> Self {
+        Self { slot, ..Self::default() }
+    }
+
+    /// 查询动作状态
+    pub fn state(&self, action: &str) -> ActionState {
+        self.states.get(action).copied().unwrap_or(ActionState::Inactive)
+    }
+
+    /// 动作是否激活
+    pub fn is_active(&self, action: &str) -> bool {
+        self.state(action).is_active()
+    }
+
+    /// 动作是否本帧刚触发
+    pub fn is_just_pressed(&self, action: &str) -> bool {
+        self.state(action).is_just_pressed()
+    }
+
+    /// 动作是否本帧刚结束
+    pub fn is_just_released(&self, action: &str) -> bool {
+        self.state(action).is_just_released()
+    }
+
+    /// 本帧刚触发的所有动作名
+    pub fn just_pressed_actions(&self) -> impl Iterator<Item = &str> {
+        self.states
+            .iter()
+            .filter(|(_, state)| state.is_just_pressed())
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// 查询轴值
+    pub fn axis(&self, action: &str) -> f32 {
+        self.axes.get(action).copied().unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bindings() -> PlayerBindings {
+        let mut bindings = PlayerBindings::new();
+        bindings.bind_movement_axes("move_x", "move_y");
+        bindings.bind_key("fire", KeyboardZone::Left, InputBinding::Key(KeyCode::F));
+        bindings.bind_button("fire", GamepadButton::RightTrigger);
+        bindings
+    }
+
+    #[test]
+    fn test_hot_plug_reassignment() {
+        let mut players = PlayerInputMap::new(3, bindings());
+        players.assign(0, InputDevice::Keyboard(KeyboardZone::Full));
+        let mut pads = GamepadState::new();
+        pads.connect(3);
+        pads.connect(1);
+        players.update_devices(&pads);
+        assert_eq!(players.gamepad(1), Some(1));
+        assert_eq!(players.gamepad(2), Some(3));
+
+        // 手柄 1 断开：槽位 1 等待；新手柄 5 优先填补槽位 1，手柄 1 回来时已无空位
+        pads.disconnect(1);
+        players.update_devices(&pads);
+        assert_eq!(players.device(1), None);
+        pads.connect(5);
+        players.update_devices(&pads);
+        assert_eq!(players.gamepad(1), Some(5));
+        pads.connect(1);
+        players.update_devices(&pads);
+        assert_eq!(players.slot_of(InputDevice::Gamepad(1)), None);
+
+        // 手动移动设备：原槽位收到 Unassigned
+        players.take_events();
+        players.assign(0, InputDevice::Gamepad(3));
+        assert_eq!(players.take_events(), vec![
+            PlayerDeviceChanged { slot: 2, change: DeviceChange::Unassigned(InputDevice::Gamepad(3)) },
+            PlayerDeviceChanged { slot: 0, change: DeviceChange::Unassigned(InputDevice::Keyboard(KeyboardZone::Full)) },
+            PlayerDeviceChanged { slot: 0, change: DeviceChange::Assigned(InputDevice::Gamepad(3)) },
+        ]);
+        // 自动分配补上空出的槽位 2
+        players.update_devices(&pads);
+        assert_eq!(players.gamepad(2), Some(1));
+        assert_eq!(players.active_players(), 3);
+    }
+
+    #[test]
+    fn test_per_player_action_states() {
+        let mut players = PlayerInputMap::new(2, bindings());
+        players.auto_assign = false;
+        players.assign(0, InputDevice::Keyboard(KeyboardZone::Left));
+        players.assign(1, InputDevice::Gamepad(0));
+        let mut pads = GamepadState::new();
+        pads.connect(0);
+        pads.press_button(0, GamepadButton::RightTrigger);
+        pads.set_axis(0, GamepadAxis::LeftStickX, -0.5);
+        let mut input = InputState::new();
+        input.press_key(KeyCode::Up);
+
+        let (mut p1, mut p2) = (PlayerActions::new(0), PlayerActions::new(1));
+        players.evaluate(&mut p1, &input, Some(&pads));
+        players.evaluate(&mut p2, &input, Some(&pads));
+        assert_eq!(p1.state("fire"), ActionState::Inactive);
+        assert_eq!(p1.axis("move_y"), 0.0); // 方向键属于右半区
+        assert!(p2.is_just_pressed("fire"));
+        assert_eq!(p2.axis("move_x"), -0.5);
+
+        // 按住时拔出手柄：本帧 JustReleased，随后 Inactive
+        pads.end_frame();
+        pads.disconnect(0);
+        players.update_devices(&pads);
+        players.evaluate(&mut p2, &input, Some(&pads));
+        assert!(p2.is_just_released("fire"));
+        players.evaluate(&mut p2, &input, Some(&pads));
+        assert_eq!(p2.state("fire"), ActionState::Inactive);
+    }
+}
@@ -0,0 +1,122 @@
+//! # 窄相碰撞检测
+//!
+//! [`contact`] 计算两个 [`Shape2D`] 之间的接触法线与穿透深度。
+
+use glam::Vec2;
+
+use crate::components::Shape2D;
+
+/// 接触信息
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact2D {
+    /// 从 A 指向 B 的单位法线
+    pub normal: Vec2,
+    /// 穿透深度（≥ 0）
+    pub penetration: f32,
+}
+
+/// 两个形状的接触检测，`None` 表示未接触
+pub fn contact(a: &Shape2D, pos_a: Vec2, b: &Shape2D, pos_b: Vec2) -> Option<Contact2D> {
+    match (*a, *b) {
+        (Shape2D::Circle { radius: ra }, Shape2D::Circle { radius: rb }) => circle_circle(pos_a, ra, pos_b, rb),
+        (Shape2D::Rect { half_extents: ha }, Shape2D::Rect { half_extents: hb }) => rect_rect(pos_a, ha, pos_b, hb),
+        (Shape2D::Rect { half_extents }, Shape2D::Circle { radius }) => rect_circle(pos_a, half_extents, pos_b, radius),
+        (Shape2D::Circle { radius }, Shape2D::Rect { half_extents }) => {
+            rect_circle(pos_b, half_extents, pos_a, radius).map(|c| Contact2D { normal: -c.normal, ..c })
+        }
+    }
+}
+
+fn circle_circle(pa: Vec2, ra: f32, pb: Vec2, rb: f32) -> Option<Contact2D> {
+    let delta = pb - pa;
+    let dist_sq = delta.length_squared();
+    let r = ra + rb;
+    if dist_sq > r * r {
+        return None;
+    }
+    let dist = dist_sq.sqrt();
+    let normal = if dist > f32::EPSILON { delta / dist } else { Vec2::Y };
+    Some(Contact2D { normal, penetration: r - dist })
+}
+
+fn rect_rect(pa: Vec2, ha: Vec2, pb: Vec2, hb: Vec2) -> Option<Contact2D> {
+    let delta = pb - pa;
+    let overlap = ha + hb - delta.abs();
+    if overlap.x <= 0.0 || overlap.y <= 0.0 {
+        return None;
+    }
+    // 沿穿透最小的轴分离
+    Some(if overlap.x < overlap.y {
+        Contact2D { normal: Vec2::new(sign(delta.x), 0.0), penetration: overlap.x }
+    } else {
+        Contact2D { normal: Vec2::new(0.0, sign(delta.y)), penetration: overlap.y }
+    })
+}
+
+fn rect_circle(pr: Vec2, half: Vec2, pc: Vec2, radius: f32) -> Option<Contact2D> {
+    let local = pc - pr;
+    let clamped = local.clamp(-half, half);
+
+    if clamped != local {
+        // 圆心在矩形外：按最近点计算
+        let delta = local - clamped;
+        let dist_sq = delta.length_squared();
+        if dist_sq > radius * radius {
+            return None;
+        }
+        let dist = dist_sq.sqrt();
+        return Some(Contact2D { normal: delta / dist, penetration: radius - dist });
+    }
+
+    // 圆心在矩形内：沿最近的边推出
+    let to_edge = half - local.abs();
+    Some(if to_edge.x < to_edge.y {
+        Contact2D { normal: Vec2::new(sign(local.x), 0.0), penetration: to_edge.x + radius }
+    } else {
+        Contact2D { normal: Vec2::new(0.0, sign(local.y)), penetration: to_edge.y + radius }
+    })
+}
+
+fn sign(v: f32) -> f32 {
+    if v < 0.0 { -1.0 } else { 1.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circle_circle() {
+        let c = Shape2D::Circle { radius: 1.0 };
+        let hit = contact(&c, Vec2::ZERO, &c, Vec2::new(1.5, 0.0)).unwrap();
+        assert_eq!(hit.normal, Vec2::X);
+        assert!((hit.penetration - 0.5).abs() < 1e-5);
+        assert!(contact(&c, Vec2::ZERO, &c, Vec2::new(2.5, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_rect_rect_min_axis() {
+        let r = Shape2D::Rect { half_extents: Vec2::ONE };
+        let hit = contact(&r, Vec2::ZERO, &r, Vec2::new(0.5, 1.8)).unwrap();
+        assert_eq!(hit.normal, Vec2::Y);
+        assert!((hit.penetration - 0.2).abs() < 1e-5);
+        assert!(contact(&r, Vec2::ZERO, &r, Vec2::new(2.1, 0.0)).is_none());
+    }
+
+    #[test]
+    fn test_circle_rect_both_orders() {
+        let ground = Shape2D::Rect { half_extents: Vec2::new(5.0, 0.5) };
+        let ball = Shape2D::Circle { radius: 0.5 };
+        let hit = contact(&ground, Vec2::ZERO, &ball, Vec2::new(1.0, 0.9)).unwrap();
+        assert_eq!(hit.normal, Vec2::Y);
+        assert!((hit.penetration - 0.1).abs() < 1e-5);
+
+        let flipped = contact(&ball, Vec2::new(1.0, 0.9), &ground, Vec2::ZERO).unwrap();
+        assert_eq!(flipped.normal, Vec2::NEG_Y);
+
+        // 圆心在矩形内部
+        let inside = contact(&ground, Vec2::ZERO, &ball, Vec2::new(0.0, 0.3)).unwrap();
+        assert_eq!(inside.normal, Vec2::Y);
+        assert!((inside.penetration - 0.7).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,197 @@
+//! # 物理组件
+//!
+//! - [`RigidBody2D`] — 刚体类型（动态 / 运动学 / 静态）
+//! - [`Velocity2D`] — 线速度与角速度
+//! - [`Collider2D`] — 碰撞形状与材质
+//! - [`GravityScale2D`] — 单个刚体的重力倍率
+//! - [`Gravity2D`] / [`PhysicsSettings2D`] — 全局配置资源
+
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use anvilkit_core::math::{Transform, GRAVITY_EARTH};
+use anvilkit_describe::Describe;
+
+/// 刚体类型
+///
+/// 没有 `RigidBody2D` 但带有 [`Collider2D`] 的实体按 [`RigidBody2D::Static`] 处理。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Describe)]
+#[require(Velocity2D, Transform)]
+/// Rigid body simulation mode.
+pub enum RigidBody2D {
+    /// 受重力与碰撞影响
+    #[default]
+    Dynamic,
+    /// 按 `Velocity2D` 移动，不受力影响，可推动动态刚体
+    Kinematic,
+    /// 永不移动
+    Static,
+}
+
+impl RigidBody2D {
+    /// 是否受求解器驱动
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Dynamic)
+    }
+}
+
+/// 速度组件
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default, Describe)]
+/// Linear and angular velocity of a 2D body.
+pub struct Velocity2D {
+    /// 线速度（单位/秒）
+    pub linear: Vec2,
+    /// 角速度（弧度/秒，绕 Z 轴逆时针）
+    #[describe(hint = "Radians per second around +Z")]
+    pub angular: f32,
+}
+
+impl Velocity2D {
+    /// 仅有线速度
+    pub fn linear(linear: Vec2) -> Self {
+        Self { linear, angular: 0.0 }
+    }
+}
+
+/// 碰撞形状
+///
+/// 尺寸以世界单位表示，不受 `Transform::scale` 影响。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape2D {
+    /// 圆
+    Circle {
+        /// 半径
+        radius: f32,
+    },
+    /// 轴对齐矩形（忽略实体旋转）
+    Rect {
+        /// 半尺寸
+        half_extents: Vec2,
+    },
+}
+
+impl Shape2D {
+    /// 面积
+    pub fn area(&self) -> f32 {
+        match *self {
+            Self::Circle { radius } => std::f32::consts::PI * radius * radius,
+            Self::Rect { half_extents } => 4.0 * half_extents.x * half_extents.y,
+        }
+    }
+}
+
+/// 碰撞体组件
+#[derive(Component, Debug, Clone, Copy, PartialEq, Describe)]
+/// Collision shape and surface material of a 2D body.
+pub struct Collider2D {
+    /// 碰撞形状
+    pub shape: Shape2D,
+    /// 弹性系数（0 = 完全非弹性，1 = 完全弹性）
+    #[describe(range = "0.0..1.0", default = "0.0")]
+    pub restitution: f32,
+    /// 摩擦系数
+    #[describe(range = "0.0..2.0", default = "0.5")]
+    pub friction: f32,
+    /// 密度，动态刚体质量 = 密度 × 面积
+    #[describe(range = "0.001..1000.0", default = "1.0")]
+    pub density: f32,
+    /// 触发器：只报告 [`Collision2D`](crate::plugin::Collision2D) 事件，不产生碰撞响应
+    pub is_sensor: bool,
+}
+
+impl Collider2D {
+    /// 以默认材质创建
+    pub fn new(shape: Shape2D) -> Self {
+        Self { shape, restitution: 0.0, friction: 0.5, density: 1.0, is_sensor: false }
+    }
+
+    /// 圆形碰撞体
+    pub fn circle(radius: f32) -> Self {
+        Self::new(Shape2D::Circle { radius })
+    }
+
+    /// 矩形碰撞体（半尺寸）
+    pub fn rect(half_width: f32, half_height: f32) -> Self {
+        Self::new(Shape2D::Rect { half_extents: Vec2::new(half_width, half_height) })
+    }
+
+    /// 设置弹性系数
+    pub fn with_restitution(mut self, restitution: f32) -> Self {
+        self.restitution = restitution;
+        self
+    }
+
+    /// 设置摩擦系数
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /// 设置密度
+    pub fn with_density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// 标记为触发器
+    pub fn sensor(mut self) -> Self {
+        self.is_sensor = true;
+        self
+    }
+
+    /// 由密度与面积计算的质量
+    pub fn mass(&self) -> f32 {
+        (self.density * self.shape.area()).max(f32::EPSILON)
+    }
+}
+
+/// 单个刚体的重力倍率
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct GravityScale2D(pub f32);
+
+impl Default for GravityScale2D {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// 全局重力（默认 `(0, -GRAVITY_EARTH)`）
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Gravity2D(pub Vec2);
+
+impl Default for Gravity2D {
+    fn default() -> Self {
+        Self(Vec2::new(0.0, -GRAVITY_EARTH))
+    }
+}
+
+/// 物理步进配置
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PhysicsSettings2D {
+    /// 固定步长（秒）
+    pub timestep: f32,
+    /// 每帧最多步进次数，超出部分丢弃以避免死亡螺旋
+    pub max_steps_per_frame: u32,
+    /// 暂停模拟
+    pub paused: bool,
+}
+
+impl Default for PhysicsSettings2D {
+    fn default() -> Self {
+        Self { timestep: 1.0 / 60.0, max_steps_per_frame: 8, paused: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collider_mass_and_builders() {
+        let c = Collider2D::rect(1.0, 0.5).with_density(2.0).with_restitution(0.3);
+        assert!((c.mass() - 4.0).abs() < 1e-5);
+        assert_eq!(c.restitution, 0.3);
+        assert!(!c.is_sensor);
+        assert!(Collider2D::circle(1.0).sensor().is_sensor);
+        assert_eq!(Gravity2D::default().0.y, -GRAVITY_EARTH);
+    }
+}
@@ -0,0 +1,45 @@
+#![warn(missing_docs)]
+//! # AnvilKit Physics 2D
+//!
+//! 轻量级 2D 刚体物理：冲量求解器 + 固定步长积分，结果直接写回 `Transform`。
+//!
+//! ## Module Structure
+//!
+//! ```text
+//! anvilkit_physics_2d
+//! ├── components — RigidBody2D, Velocity2D, Collider2D, Gravity2D, PhysicsSettings2D
+//! ├── collision  — 窄相碰撞检测（圆 / 轴对齐矩形）
+//! └── plugin     — PhysicsPlugin2D 与固定步长求解系统
+//! ```
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_core::math::Transform;
+//! use anvilkit_physics_2d::prelude::*;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((AnvilKitEcsPlugin, PhysicsPlugin2D));
+//!
+//! // 地面（无 RigidBody2D 的碰撞体视为静态）
+//! app.world_mut().spawn((Transform::from_xyz(0.0, -1.0, 0.0), Collider2D::rect(10.0, 0.5)));
+//! // 下落的球
+//! app.world_mut().spawn((RigidBody2D::Dynamic, Transform::from_xyz(0.0, 5.0, 0.0), Collider2D::circle(0.5)));
+//! ```
+
+/// 刚体、碰撞体组件与物理配置资源
+pub mod components;
+/// 窄相碰撞检测
+pub mod collision;
+/// 物理插件与求解系统
+pub mod plugin;
+
+/// Prelude module re-exporting the most commonly used types.
+pub mod prelude {
+    pub use crate::components::{
+        RigidBody2D, Velocity2D, Collider2D, Shape2D, GravityScale2D, Gravity2D, PhysicsSettings2D,
+    };
+    pub use crate::collision::{Contact2D, contact};
+    pub use crate::plugin::{Collision2D, PhysicsPlugin2D, PhysicsTime2D, physics_step_2d_system};
+}
@@ -0,0 +1,328 @@
+//! # 物理插件
+//!
+//! [`PhysicsPlugin2D`] 在 `AnvilKitSchedule::FixedUpdate` 的 `Physics` 系统集中运行
+//! [`physics_step_2d_system`]。该系统把 `DeltaTime` 累积进 [`PhysicsTime2D`]，
+//! 以 [`PhysicsSettings2D::timestep`] 为步长推进 0..N 次，每步依次：
+//!
+//! 1. 对动态刚体施加重力并积分速度、位置
+//! 2. 两两检测碰撞体接触，发送 [`Collision2D`] 事件
+//! 3. 以冲量解算法向速度与摩擦，并做位置修正消除穿透
+//!
+//! 结果写回 `Transform.translation` 的 XY 与绕 Z 轴的 `rotation`。
+//! 刚体位置按局部 `Transform` 读写，应当挂在根实体上。
+//! 本帧步数与剩余不足一步的时间比例写入 `FixedStepProgress`，供渲染插值使用。
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use glam::{Quat, Vec2};
+use anvilkit_app::schedule::{AnvilKitSchedule, AnvilKitSystemSet};
+use anvilkit_core::math::Transform;
+use anvilkit_core::time::{DeltaTime, FixedStepProgress};
+
+use crate::collision::contact;
+use crate::components::{Collider2D, Gravity2D, GravityScale2D, PhysicsSettings2D, RigidBody2D, Velocity2D};
+
+/// 位置修正比例
+const CORRECTION_PERCENT: f32 = 0.8;
+/// 允许的穿透深度，避免静止接触抖动
+const PENETRATION_SLOP: f32 = 0.005;
+
+/// 两个碰撞体发生接触
+///
+/// 每对实体每帧最多发送一次；`normal` 从 `a` 指向 `b`。
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct Collision2D {
+    /// 第一个实体
+    pub a: Entity,
+    /// 第二个实体
+    pub b: Entity,
+    /// 从 `a` 指向 `b` 的接触法线
+    pub normal: Vec2,
+    /// 穿透深度
+    pub penetration: f32,
+}
+
+/// 固定步长累加器
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PhysicsTime2D {
+    accumulator: f32,
+    steps: u32,
+}
+
+impl PhysicsTime2D {
+    /// 本帧执行的步数
+    pub fn steps_this_frame(&self) -> u32 {
+        self.steps
+    }
+
+    /// 未消耗时间占一个步长的比例，可用于渲染插值
+    pub fn overstep_fraction(&self, settings: &PhysicsSettings2D) -> f32 {
+        (self.accumulator / settings.timestep).clamp(0.0, 1.0)
+    }
+}
+
+/// 求解时的刚体快照
+struct Body {
+    entity: Entity,
+    kind: RigidBody2D,
+    position: Vec2,
+    velocity: Vec2,
+    angular: f32,
+    rotation_delta: f32,
+    inv_mass: f32,
+    gravity_scale: f32,
+    collider: Option<Collider2D>,
+}
+
+/// [`physics_step_2d_system`] 查询的组件
+type BodyQuery2D = (
+    Entity,
+    &'static mut Transform,
+    Option<&'static RigidBody2D>,
+    Option<&'static mut Velocity2D>,
+    Option<&'static Collider2D>,
+    Option<&'static GravityScale2D>,
+);
+
+/// 参与模拟的实体：刚体或碰撞体
+type BodyFilter2D = Or<(With<RigidBody2D>, With<Collider2D>)>;
+
+/// 物理步进时钟：帧时间、固定步长累积器与插值进度
+#[derive(SystemParam)]
+pub struct PhysicsClock2D<'w> {
+    dt: Res<'w, DeltaTime>,
+    time: ResMut<'w, PhysicsTime2D>,
+    progress: ResMut<'w, FixedStepProgress>,
+}
+
+/// 物理步进系统 (FixedUpdate)
+pub fn physics_step_2d_system(
+    clock: PhysicsClock2D,
+    settings: Res<PhysicsSettings2D>,
+    gravity: Res<Gravity2D>,
+    mut query: Query<BodyQuery2D, BodyFilter2D>,
+    mut collisions: EventWriter<Collision2D>,
+    mut reported: Local<Vec<(Entity, Entity)>>,
+) {
+    let PhysicsClock2D { dt, mut time, mut progress } = clock;
+    time.steps = 0;
+    progress.steps = 0;
+    if settings.paused || settings.timestep <= 0.0 {
+        return;
+    }
+
+    time.accumulator += dt.0.max(0.0);
+    let mut steps = (time.accumulator / settings.timestep) as u32;
+    time.accumulator -= steps as f32 * settings.timestep;
+    if steps > settings.max_steps_per_frame {
+        log::debug!("2D 物理落后 {} 步，丢弃多余时间", steps - settings.max_steps_per_frame);
+        steps = settings.max_steps_per_frame;
+    }
+    time.steps = steps;
+    progress.steps = steps;
+    progress.alpha = time.overstep_fraction(&settings);
+    if steps == 0 {
+        return;
+    }
+
+    let mut bodies = Vec::with_capacity(query.iter().len());
+    for (entity, transform, kind, velocity, collider, gravity_scale) in query.iter() {
+        let kind = kind.copied().unwrap_or(RigidBody2D::Static);
+        let inv_mass = if kind.is_dynamic() { 1.0 / collider.map_or(1.0, Collider2D::mass) } else { 0.0 };
+        let velocity = velocity.copied().unwrap_or_default();
+        bodies.push(Body {
+            entity,
+            kind,
+            position: transform.translation.truncate(),
+            velocity: if kind == RigidBody2D::Static { Vec2::ZERO } else { velocity.linear },
+            angular: if kind == RigidBody2D::Static { 0.0 } else { velocity.angular },
+            rotation_delta: 0.0,
+            inv_mass,
+            gravity_scale: gravity_scale.map_or(1.0, |g| g.0),
+            collider: collider.copied(),
+        });
+    }
+
+    reported.clear();
+    let h = settings.timestep;
+    for _ in 0..steps {
+        for body in bodies.iter_mut() {
+            if body.kind == RigidBody2D::Static {
+                continue;
+            }
+            if body.kind.is_dynamic() {
+                body.velocity += gravity.0 * body.gravity_scale * h;
+            }
+            body.position += body.velocity * h;
+            body.rotation_delta += body.angular * h;
+        }
+
+        for i in 0..bodies.len() {
+            for j in (i + 1)..bodies.len() {
+                let (left, right) = bodies.split_at_mut(j);
+                let (a, b) = (&mut left[i], &mut right[0]);
+                let (Some(ca), Some(cb)) = (a.collider, b.collider) else { continue };
+                if !a.kind.is_dynamic() && !b.kind.is_dynamic() && !ca.is_sensor && !cb.is_sensor {
+                    continue;
+                }
+                let Some(hit) = contact(&ca.shape, a.position, &cb.shape, b.position) else { continue };
+
+                if !reported.contains(&(a.entity, b.entity)) {
+                    reported.push((a.entity, b.entity));
+                    collisions.send(Collision2D { a: a.entity, b: b.entity, normal: hit.normal, penetration: hit.penetration });
+                }
+                if ca.is_sensor || cb.is_sensor {
+                    continue;
+                }
+                resolve(a, b, &ca, &cb, hit.normal, hit.penetration);
+            }
+        }
+    }
+
+    // 查询迭代顺序在两次遍历之间不变
+    for ((entity, mut transform, _, velocity, ..), body) in query.iter_mut().zip(bodies.iter()) {
+        debug_assert_eq!(entity, body.entity);
+        if body.kind == RigidBody2D::Static {
+            continue;
+        }
+        transform.translation.x = body.position.x;
+        transform.translation.y = body.position.y;
+        if body.rotation_delta != 0.0 {
+            transform.rotation = Quat::from_rotation_z(body.rotation_delta) * transform.rotation;
+        }
+        if let Some(mut velocity) = velocity {
+            velocity.linear = body.velocity;
+        }
+    }
+}
+
+/// 冲量解算单个接触
+fn resolve(a: &mut Body, b: &mut Body, ca: &Collider2D, cb: &Collider2D, normal: Vec2, penetration: f32) {
+    let inv_sum = a.inv_mass + b.inv_mass;
+    if inv_sum <= 0.0 {
+        return;
+    }
+
+    let relative = b.velocity - a.velocity;
+    let normal_speed = relative.dot(normal);
+    if normal_speed < 0.0 {
+        let restitution = ca.restitution.min(cb.restitution);
+        let j = -(1.0 + restitution) * normal_speed / inv_sum;
+        a.velocity -= normal * j * a.inv_mass;
+        b.velocity += normal * j * b.inv_mass;
+
+        // 库仑摩擦
+        let relative = b.velocity - a.velocity;
+        let tangent = (relative - normal * relative.dot(normal)).normalize_or_zero();
+        if tangent != Vec2::ZERO {
+            let mu = (ca.friction * cb.friction).sqrt();
+            let jt = (-relative.dot(tangent) / inv_sum).clamp(-j * mu, j * mu);
+            a.velocity -= tangent * jt * a.inv_mass;
+            b.velocity += tangent * jt * b.inv_mass;
+        }
+    }
+
+    let correction = normal * ((penetration - PENETRATION_SLOP).max(0.0) / inv_sum * CORRECTION_PERCENT);
+    a.position -= correction * a.inv_mass;
+    b.position += correction * b.inv_mass;
+}
+
+/// 2D 物理插件
+///
+/// 需要 `AnvilKitEcsPlugin` 提供 `FixedUpdate` 调度。
+pub struct PhysicsPlugin2D;
+
+impl Plugin for PhysicsPlugin2D {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Gravity2D>()
+            .init_resource::<PhysicsSettings2D>()
+            .init_resource::<PhysicsTime2D>()
+            .init_resource::<FixedStepProgress>()
+            .init_resource::<DeltaTime>()
+            .add_event::<Collision2D>()
+            .add_systems(AnvilKitSchedule::FixedUpdate, physics_step_2d_system.in_set(AnvilKitSystemSet::Physics));
+    }
+
+    fn name(&self) -> &str {
+        "PhysicsPlugin2D"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_app::ecs_plugin::AnvilKitEcsPlugin;
+    use anvilkit_core::math::GRAVITY_EARTH;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((AnvilKitEcsPlugin, PhysicsPlugin2D));
+        app.insert_resource(DeltaTime(1.0 / 60.0));
+        app
+    }
+
+    #[test]
+    fn test_free_fall_uses_earth_gravity() {
+        let mut app = test_app();
+        let body = app.world_mut().spawn((RigidBody2D::Dynamic, Transform::from_xyz(0.0, 10.0, 0.0))).id();
+        for _ in 0..60 {
+            app.update();
+        }
+        let v = app.world().get::<Velocity2D>(body).unwrap().linear;
+        assert!((v.y + GRAVITY_EARTH).abs() < 0.05, "v = {v:?}");
+        assert!(app.world().get::<Transform>(body).unwrap().translation.y < 10.0 - 4.0);
+    }
+
+    #[test]
+    fn test_body_rests_on_static_ground() {
+        let mut app = test_app();
+        app.world_mut().spawn((Transform::from_xyz(0.0, 0.0, 0.0), Collider2D::rect(10.0, 0.5)));
+        let ball = app
+            .world_mut()
+            .spawn((RigidBody2D::Dynamic, Transform::from_xyz(0.0, 3.0, 0.0), Collider2D::circle(0.5)))
+            .id();
+
+        let mut hits = 0;
+        for _ in 0..180 {
+            app.update();
+            hits += app.world_mut().resource_mut::<Events<Collision2D>>().drain().count();
+        }
+        let y = app.world().get::<Transform>(ball).unwrap().translation.y;
+        assert!((y - 1.0).abs() < 0.05, "y = {y}");
+        assert!(app.world().get::<Velocity2D>(ball).unwrap().linear.y.abs() < 0.5);
+        assert!(hits > 0);
+    }
+
+    #[test]
+    fn test_accumulator_steps_and_pause() {
+        let mut app = test_app();
+        app.world_mut().resource_mut::<PhysicsSettings2D>().timestep = 0.125;
+        app.insert_resource(DeltaTime(0.3125));
+        app.update();
+        assert_eq!(app.world().resource::<PhysicsTime2D>().steps_this_frame(), 2);
+        app.update();
+        assert_eq!(app.world().resource::<PhysicsTime2D>().steps_this_frame(), 3);
+
+        app.world_mut().resource_mut::<PhysicsSettings2D>().paused = true;
+        app.update();
+        assert_eq!(app.world().resource::<PhysicsTime2D>().steps_this_frame(), 0);
+    }
+
+    #[test]
+    fn test_sensor_reports_without_response() {
+        let mut app = test_app();
+        app.world_mut().insert_resource(Gravity2D(Vec2::ZERO));
+        let trigger = app.world_mut().spawn((Transform::default(), Collider2D::rect(1.0, 1.0).sensor())).id();
+        let body = app
+            .world_mut()
+            .spawn((RigidBody2D::Dynamic, Velocity2D::linear(Vec2::X), Transform::from_xyz(-1.2, 0.0, 0.0), Collider2D::circle(0.5)))
+            .id();
+        app.update();
+        let events: Vec<_> = app.world_mut().resource_mut::<Events<Collision2D>>().drain().collect();
+        assert_eq!(events.len(), 1);
+        let pair = (events[0].a, events[0].b);
+        assert!(pair == (trigger, body) || pair == (body, trigger));
+        assert_eq!(app.world().get::<Velocity2D>(body).unwrap().linear, Vec2::X);
+    }
+}
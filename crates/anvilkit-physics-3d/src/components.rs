@@ -0,0 +1,156 @@
+//! # 物理组件
+//!
+//! - [`RigidBody`] — 刚体类型（动态 / 运动学 / 静态）
+//! - [`Velocity`] — 线速度与角速度（复用 `anvilkit_core::math::Velocity`）
+//! - [`Collider`] — 碰撞形状与材质
+//! - [`GravityScale`] — 单个刚体的重力倍率
+//! - [`Gravity`] / [`PhysicsSettings`] — 全局配置资源
+
+use bevy_ecs::prelude::*;
+use glam::Vec3;
+use anvilkit_core::math::{Transform, GRAVITY_EARTH};
+use anvilkit_describe::Describe;
+
+pub use anvilkit_core::math::Velocity;
+
+/// 刚体类型
+///
+/// 没有 `RigidBody` 但带有 [`Collider`] 的实体按 [`RigidBody::Static`] 处理。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Describe)]
+#[require(Velocity, Transform)]
+/// Rigid body simulation mode.
+pub enum RigidBody {
+    /// 受重力、力与碰撞影响
+    #[default]
+    Dynamic,
+    /// 按 `Velocity` 移动，不受力影响，可推动动态刚体
+    Kinematic,
+    /// 永不移动
+    Static,
+}
+
+/// 碰撞形状
+///
+/// 尺寸以世界单位表示，不受 `Transform::scale` 影响。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColliderShape {
+    /// 球
+    Ball {
+        /// 半径
+        radius: f32,
+    },
+    /// 长方体
+    Cuboid {
+        /// 半尺寸
+        half_extents: Vec3,
+    },
+    /// 沿 Y 轴的胶囊体
+    Capsule {
+        /// 中轴半长（不含半球）
+        half_height: f32,
+        /// 半径
+        radius: f32,
+    },
+}
+
+/// 碰撞体组件
+#[derive(Component, Debug, Clone, Copy, PartialEq, Describe)]
+/// Collision shape and surface material of a 3D body.
+pub struct Collider {
+    /// 碰撞形状
+    pub shape: ColliderShape,
+    /// 弹性系数（0 = 完全非弹性，1 = 完全弹性）
+    #[describe(range = "0.0..1.0", default = "0.0")]
+    pub restitution: f32,
+    /// 摩擦系数
+    #[describe(range = "0.0..2.0", default = "0.5")]
+    pub friction: f32,
+    /// 密度，动态刚体质量由密度与体积计算
+    #[describe(range = "0.001..1000.0", default = "1.0")]
+    pub density: f32,
+    /// 触发器：只发送碰撞事件，不产生碰撞响应
+    pub is_sensor: bool,
+}
+
+impl Collider {
+    /// 以默认材质创建
+    pub fn new(shape: ColliderShape) -> Self {
+        Self { shape, restitution: 0.0, friction: 0.5, density: 1.0, is_sensor: false }
+    }
+
+    /// 球形碰撞体
+    pub fn ball(radius: f32) -> Self {
+        Self::new(ColliderShape::Ball { radius })
+    }
+
+    /// 长方体碰撞体（半尺寸）
+    pub fn cuboid(half_extents: Vec3) -> Self {
+        Self::new(ColliderShape::Cuboid { half_extents })
+    }
+
+    /// Y 轴胶囊体碰撞体
+    pub fn capsule(half_height: f32, radius: f32) -> Self {
+        Self::new(ColliderShape::Capsule { half_height, radius })
+    }
+
+    /// 设置弹性系数
+    pub fn with_restitution(mut self, restitution: f32) -> Self {
+        self.restitution = restitution;
+        self
+    }
+
+    /// 设置摩擦系数
+    pub fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    /// 设置密度
+    pub fn with_density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// 标记为触发器
+    pub fn sensor(mut self) -> Self {
+        self.is_sensor = true;
+        self
+    }
+}
+
+/// 单个刚体的重力倍率
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct GravityScale(pub f32);
+
+impl Default for GravityScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// 全局重力（默认 `(0, -GRAVITY_EARTH, 0)`）
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Gravity(pub Vec3);
+
+impl Default for Gravity {
+    fn default() -> Self {
+        Self(Vec3::new(0.0, -GRAVITY_EARTH, 0.0))
+    }
+}
+
+/// 物理步进配置
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PhysicsSettings {
+    /// 固定步长（秒）
+    pub timestep: f32,
+    /// 每帧最多步进次数，超出部分丢弃以避免死亡螺旋
+    pub max_steps_per_frame: u32,
+    /// 暂停模拟
+    pub paused: bool,
+}
+
+impl Default for PhysicsSettings {
+    fn default() -> Self {
+        Self { timestep: 1.0 / 60.0, max_steps_per_frame: 8, paused: false }
+    }
+}
@@ -0,0 +1,111 @@
+//! # 碰撞体调试绘制
+//!
+//! [`PhysicsDebugRenderPlugin`] 每帧把碰撞体形状写入渲染器的 [`DebugDraw`] 资源，
+//! 由调试线段 pass 绘制。触发器使用单独的颜色。
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::{Quat, Vec3};
+use anvilkit_core::math::Transform;
+use anvilkit_render::renderer::debug::DebugDraw;
+
+use crate::components::{Collider, ColliderShape};
+
+/// 碰撞体调试绘制配置
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct PhysicsDebugRender {
+    /// 是否绘制
+    pub enabled: bool,
+    /// 实体碰撞体颜色
+    pub color: [f32; 4],
+    /// 触发器颜色
+    pub sensor_color: [f32; 4],
+}
+
+impl Default for PhysicsDebugRender {
+    fn default() -> Self {
+        Self { enabled: true, color: [0.2, 1.0, 0.4, 1.0], sensor_color: [1.0, 0.8, 0.2, 1.0] }
+    }
+}
+
+/// 绘制长方体的 12 条棱
+fn draw_cuboid(draw: &mut DebugDraw, center: Vec3, rotation: Quat, half: Vec3, color: [f32; 4]) {
+    let corner = |sx: f32, sy: f32, sz: f32| center + rotation * (half * Vec3::new(sx, sy, sz));
+    let signs = [-1.0, 1.0];
+    for &a in &signs {
+        for &b in &signs {
+            draw.line(corner(-1.0, a, b), corner(1.0, a, b), color);
+            draw.line(corner(a, -1.0, b), corner(a, 1.0, b), color);
+            draw.line(corner(a, b, -1.0), corner(a, b, 1.0), color);
+        }
+    }
+}
+
+/// 绘制胶囊体：两端球体 + 四条侧线
+fn draw_capsule(draw: &mut DebugDraw, center: Vec3, rotation: Quat, half_height: f32, radius: f32, color: [f32; 4]) {
+    let axis = rotation * Vec3::Y * half_height;
+    let (top, bottom) = (center + axis, center - axis);
+    draw.sphere(top, radius, color);
+    draw.sphere(bottom, radius, color);
+    for side in [Vec3::X, Vec3::NEG_X, Vec3::Z, Vec3::NEG_Z] {
+        let offset = rotation * side * radius;
+        draw.line(bottom + offset, top + offset, color);
+    }
+}
+
+/// 碰撞体调试绘制系统 (PostUpdate)
+pub fn physics_debug_render_system(
+    settings: Res<PhysicsDebugRender>,
+    mut draw: ResMut<DebugDraw>,
+    query: Query<(&Collider, &Transform)>,
+) {
+    if !settings.enabled {
+        return;
+    }
+    for (collider, transform) in query.iter() {
+        let color = if collider.is_sensor { settings.sensor_color } else { settings.color };
+        let (center, rotation) = (transform.translation, transform.rotation);
+        match collider.shape {
+            ColliderShape::Ball { radius } => draw.sphere(center, radius, color),
+            ColliderShape::Cuboid { half_extents } => draw_cuboid(&mut draw, center, rotation, half_extents, color),
+            ColliderShape::Capsule { half_height, radius } => {
+                draw_capsule(&mut draw, center, rotation, half_height, radius, color)
+            }
+        }
+    }
+}
+
+/// 碰撞体调试绘制插件
+pub struct PhysicsDebugRenderPlugin;
+
+impl Plugin for PhysicsDebugRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsDebugRender>()
+            .init_resource::<DebugDraw>()
+            .add_systems(bevy_app::PostUpdate, physics_debug_render_system);
+    }
+
+    fn name(&self) -> &str {
+        "PhysicsDebugRenderPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_render_emits_commands() {
+        let mut app = App::new();
+        app.add_plugins(PhysicsDebugRenderPlugin);
+        app.world_mut().spawn((Transform::default(), Collider::cuboid(Vec3::ONE)));
+        app.world_mut().spawn((Transform::default(), Collider::ball(1.0)));
+        app.update();
+        assert_eq!(app.world().resource::<DebugDraw>().len(), 13);
+
+        app.world_mut().resource_mut::<PhysicsDebugRender>().enabled = false;
+        app.world_mut().resource_mut::<DebugDraw>().clear();
+        app.update();
+        assert!(app.world().resource::<DebugDraw>().is_empty());
+    }
+}
@@ -0,0 +1,56 @@
+#![warn(missing_docs)]
+//! # AnvilKit Physics 3D
+//!
+//! 基于 rapier3d 的 3D 刚体物理，与 `anvilkit-physics-2d` 保持相同的使用方式：
+//! 组件声明刚体与碰撞体，固定步长在 `FixedUpdate` 中推进，结果写回 `Transform`。
+//!
+//! ## Module Structure
+//!
+//! ```text
+//! anvilkit_physics_3d
+//! ├── components — RigidBody, Collider, ColliderShape, GravityScale, Gravity, PhysicsSettings
+//! ├── world      — PhysicsWorld 资源（rapier 状态 + 射线 / 形状投射查询）
+//! ├── plugin     — PhysicsPlugin3D、同步系统与 CollisionStarted / CollisionEnded 事件
+//! └── debug      — 碰撞体线框调试绘制（`debug-render` feature）
+//! ```
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_app::prelude::*;
+//! use anvilkit_core::math::Transform;
+//! use anvilkit_physics_3d::prelude::*;
+//! use glam::Vec3;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((AnvilKitEcsPlugin, PhysicsPlugin3D));
+//!
+//! app.world_mut().spawn((Transform::from_xyz(0.0, -0.5, 0.0), Collider::cuboid(Vec3::new(10.0, 0.5, 10.0))));
+//! app.world_mut().spawn((RigidBody::Dynamic, Transform::from_xyz(0.0, 5.0, 0.0), Collider::ball(0.5)));
+//! app.update();
+//!
+//! let world = app.world().resource::<PhysicsWorld>();
+//! let hit = world.cast_ray(Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Y, 100.0, None);
+//! assert!(hit.is_some());
+//! ```
+
+/// 刚体、碰撞体组件与物理配置资源
+pub mod components;
+/// rapier 物理世界与场景查询
+pub mod world;
+/// 物理插件、同步系统与碰撞事件
+pub mod plugin;
+/// 碰撞体调试绘制
+#[cfg(feature = "debug-render")]
+pub mod debug;
+
+/// Prelude module re-exporting the most commonly used types.
+pub mod prelude {
+    pub use crate::components::{
+        RigidBody, Collider, ColliderShape, GravityScale, Gravity, PhysicsSettings, Velocity,
+    };
+    pub use crate::world::{PhysicsWorld, RayHit, ShapeHit};
+    pub use crate::plugin::{CollisionStarted, CollisionEnded, PhysicsPlugin3D, PhysicsSet3D, PhysicsTime};
+    #[cfg(feature = "debug-render")]
+    pub use crate::debug::{PhysicsDebugRender, PhysicsDebugRenderPlugin};
+}
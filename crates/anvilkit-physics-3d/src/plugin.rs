@@ -0,0 +1,353 @@
+//! # 物理插件
+//!
+//! [`PhysicsPlugin3D`] 在 `AnvilKitSchedule::FixedUpdate` 的 `Physics` 系统集中依次运行：
+//!
+//! 1. [`PhysicsSet3D::Sync`] — 创建 / 更新 / 移除 rapier 刚体与碰撞体，推送用户修改的 `Transform` 与 `Velocity`
+//! 2. [`PhysicsSet3D::Step`] — 累积 `DeltaTime`，按固定步长推进 0..N 次并发送碰撞事件，步数与剩余比例写入 `FixedStepProgress`
+//! 3. [`PhysicsSet3D::Writeback`] — 把动态与运动学刚体的位姿、速度写回组件
+//!
+//! 刚体位置按局部 `Transform` 读写，应当挂在根实体上。
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::Vec3;
+use anvilkit_app::schedule::{AnvilKitSchedule, AnvilKitSystemSet};
+use anvilkit_core::math::Transform;
+use anvilkit_core::time::{DeltaTime, FixedStepProgress};
+
+use crate::components::{Collider, Gravity, GravityScale, PhysicsSettings, RigidBody, Velocity};
+use crate::world::{from_isometry, from_vector, to_isometry, to_vector, PhysicsWorld};
+
+/// 推送 Transform 时的位置容差
+const SYNC_EPSILON: f32 = 1e-4;
+
+/// 两个碰撞体开始接触（或触发器开始重叠）
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionStarted {
+    /// 第一个实体
+    pub a: Entity,
+    /// 第二个实体
+    pub b: Entity,
+}
+
+/// 两个碰撞体结束接触；任一碰撞体被移除时同样发送
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionEnded {
+    /// 第一个实体
+    pub a: Entity,
+    /// 第二个实体
+    pub b: Entity,
+}
+
+/// 物理系统集（在 `FixedUpdate` 中按顺序运行）
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhysicsSet3D {
+    /// ECS → rapier
+    Sync,
+    /// 固定步长推进
+    Step,
+    /// rapier → ECS
+    Writeback,
+}
+
+/// 固定步长累加器
+#[derive(Resource, Debug, Default, Clone)]
+pub struct PhysicsTime {
+    accumulator: f32,
+    steps: u32,
+}
+
+impl PhysicsTime {
+    /// 本帧执行的步数
+    pub fn steps_this_frame(&self) -> u32 {
+        self.steps
+    }
+
+    /// 未消耗时间占一个步长的比例，可用于渲染插值
+    pub fn overstep_fraction(&self, settings: &PhysicsSettings) -> f32 {
+        (self.accumulator / settings.timestep).clamp(0.0, 1.0)
+    }
+}
+
+/// [`physics_sync_system`] 中需要重建 rapier 刚体 / 碰撞体的实体
+type ChangedBodyQuery = (
+    Entity,
+    &'static Transform,
+    Option<&'static RigidBody>,
+    Option<&'static Velocity>,
+    Option<Ref<'static, Collider>>,
+    Option<&'static GravityScale>,
+);
+
+/// 刚体、碰撞体或重力缩放发生变化
+type ChangedBodyFilter = Or<(Changed<RigidBody>, Changed<Collider>, Changed<GravityScale>)>;
+
+/// [`physics_sync_system`] 中同步变换与速度的实体
+type SyncedBodyQuery = (Entity, Ref<'static, Transform>, Option<&'static RigidBody>, Option<&'static Velocity>);
+
+/// 参与模拟的实体：刚体或碰撞体
+type SyncedBodyFilter = Or<(With<RigidBody>, With<Collider>)>;
+
+/// ECS → rapier 同步系统
+pub fn physics_sync_system(
+    mut world: ResMut<PhysicsWorld>,
+    mut removed_bodies: RemovedComponents<RigidBody>,
+    mut removed_colliders: RemovedComponents<Collider>,
+    present: Query<(Has<RigidBody>, Has<Collider>)>,
+    changed: Query<ChangedBodyQuery, ChangedBodyFilter>,
+    bodies: Query<SyncedBodyQuery, SyncedBodyFilter>,
+) {
+    for entity in removed_colliders.read() {
+        match present.get(entity) {
+            Ok((_, true)) => {}
+            Ok((true, false)) => world.remove_collider(entity),
+            _ => world.remove_entity(entity),
+        }
+    }
+    for entity in removed_bodies.read() {
+        match present.get(entity) {
+            Ok((true, _)) => {}
+            // 只剩碰撞体：退化为静态刚体
+            Ok((false, true)) => {
+                if let Ok((_, transform, ..)) = bodies.get(entity) {
+                    let iso = to_isometry(transform.translation, transform.rotation);
+                    world.upsert_body(entity, RigidBody::Static, iso, Vec3::ZERO, Vec3::ZERO, 1.0);
+                }
+            }
+            _ => world.remove_entity(entity),
+        }
+    }
+
+    for (entity, transform, kind, velocity, collider, gravity_scale) in changed.iter() {
+        let kind = kind.copied().unwrap_or(RigidBody::Static);
+        let velocity = velocity.copied().unwrap_or_default();
+        world.upsert_body(
+            entity,
+            kind,
+            to_isometry(transform.translation, transform.rotation),
+            velocity.linear,
+            velocity.angular,
+            gravity_scale.map_or(1.0, |g| g.0),
+        );
+        if let Some(collider) = collider {
+            if collider.is_changed() || !world.has_collider(entity) {
+                world.upsert_collider(entity, &collider);
+            }
+        }
+    }
+
+    // 推送用户对 Transform / Velocity 的修改（写回后两者与 rapier 一致，不会重复唤醒）
+    let world = &mut *world;
+    for (entity, transform, kind, velocity) in bodies.iter() {
+        let Some(body) = world.entity_bodies.get(&entity).and_then(|h| world.bodies.get_mut(*h)) else { continue };
+        let kind = kind.copied().unwrap_or(RigidBody::Static);
+        let (position, rotation) = from_isometry(body.position());
+        let moved = position.distance_squared(transform.translation) > SYNC_EPSILON * SYNC_EPSILON
+            || rotation.dot(transform.rotation).abs() < 1.0 - SYNC_EPSILON;
+        if moved && (kind != RigidBody::Static || transform.is_changed()) {
+            body.set_position(to_isometry(transform.translation, transform.rotation), true);
+        }
+        if kind == RigidBody::Static {
+            continue;
+        }
+        let velocity = velocity.copied().unwrap_or_default();
+        if from_vector(body.linvel()).distance_squared(velocity.linear) > SYNC_EPSILON * SYNC_EPSILON {
+            body.set_linvel(to_vector(velocity.linear), true);
+        }
+        if from_vector(body.angvel()).distance_squared(velocity.angular) > SYNC_EPSILON * SYNC_EPSILON {
+            body.set_angvel(to_vector(velocity.angular), true);
+        }
+    }
+}
+
+/// 固定步长推进系统
+pub fn physics_step_system(
+    dt: Res<DeltaTime>,
+    settings: Res<PhysicsSettings>,
+    gravity: Res<Gravity>,
+    mut time: ResMut<PhysicsTime>,
+    mut progress: ResMut<FixedStepProgress>,
+    mut world: ResMut<PhysicsWorld>,
+    mut started: EventWriter<CollisionStarted>,
+    mut ended: EventWriter<CollisionEnded>,
+) {
+    time.steps = 0;
+    progress.steps = 0;
+    if settings.paused || settings.timestep <= 0.0 {
+        return;
+    }
+
+    time.accumulator += dt.0.max(0.0);
+    let mut steps = (time.accumulator / settings.timestep) as u32;
+    time.accumulator -= steps as f32 * settings.timestep;
+    if steps > settings.max_steps_per_frame {
+        log::debug!("3D 物理落后 {} 步，丢弃多余时间", steps - settings.max_steps_per_frame);
+        steps = settings.max_steps_per_frame;
+    }
+    time.steps = steps;
+    progress.steps = steps;
+    progress.alpha = time.overstep_fraction(&settings);
+
+    for _ in 0..steps {
+        for (a, b, is_start) in world.step(settings.timestep, gravity.0) {
+            if is_start {
+                started.send(CollisionStarted { a, b });
+            } else {
+                ended.send(CollisionEnded { a, b });
+            }
+        }
+    }
+}
+
+/// rapier → ECS 写回系统
+pub fn physics_writeback_system(
+    time: Res<PhysicsTime>,
+    world: Res<PhysicsWorld>,
+    mut query: Query<(Entity, &mut Transform, &RigidBody, &mut Velocity)>,
+) {
+    if time.steps == 0 {
+        return;
+    }
+    for (entity, mut transform, kind, mut velocity) in query.iter_mut() {
+        if *kind == RigidBody::Static {
+            continue;
+        }
+        let Some(body) = world.body_handle(entity).and_then(|h| world.bodies.get(h)) else { continue };
+        if body.is_sleeping() {
+            continue;
+        }
+        let (position, rotation) = from_isometry(body.position());
+        transform.translation = position;
+        transform.rotation = rotation;
+        velocity.linear = from_vector(body.linvel());
+        velocity.angular = from_vector(body.angvel());
+    }
+}
+
+/// 3D 物理插件
+///
+/// 需要 `AnvilKitEcsPlugin` 提供 `FixedUpdate` 调度。
+pub struct PhysicsPlugin3D;
+
+impl Plugin for PhysicsPlugin3D {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Gravity>()
+            .init_resource::<PhysicsSettings>()
+            .init_resource::<PhysicsTime>()
+            .init_resource::<FixedStepProgress>()
+            .init_resource::<PhysicsWorld>()
+            .init_resource::<DeltaTime>()
+            .add_event::<CollisionStarted>()
+            .add_event::<CollisionEnded>()
+            .configure_sets(
+                AnvilKitSchedule::FixedUpdate,
+                (PhysicsSet3D::Sync, PhysicsSet3D::Step, PhysicsSet3D::Writeback)
+                    .chain()
+                    .in_set(AnvilKitSystemSet::Physics),
+            )
+            .add_systems(
+                AnvilKitSchedule::FixedUpdate,
+                (
+                    physics_sync_system.in_set(PhysicsSet3D::Sync),
+                    physics_step_system.in_set(PhysicsSet3D::Step),
+                    physics_writeback_system.in_set(PhysicsSet3D::Writeback),
+                ),
+            );
+    }
+
+    fn name(&self) -> &str {
+        "PhysicsPlugin3D"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_app::ecs_plugin::AnvilKitEcsPlugin;
+    use crate::components::ColliderShape;
+    use glam::Quat;
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((AnvilKitEcsPlugin, PhysicsPlugin3D));
+        app.insert_resource(DeltaTime(1.0 / 60.0));
+        app
+    }
+
+    fn spawn_ground(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((Transform::from_xyz(0.0, -0.5, 0.0), Collider::cuboid(Vec3::new(10.0, 0.5, 10.0))))
+            .id()
+    }
+
+    #[test]
+    fn test_ball_falls_and_rests_on_ground() {
+        let mut app = test_app();
+        spawn_ground(&mut app);
+        let ball = app
+            .world_mut()
+            .spawn((RigidBody::Dynamic, Transform::from_xyz(0.0, 3.0, 0.0), Collider::ball(0.5)))
+            .id();
+
+        let mut started = 0;
+        for _ in 0..240 {
+            app.update();
+            started += app.world_mut().resource_mut::<Events<CollisionStarted>>().drain().count();
+        }
+        let y = app.world().get::<Transform>(ball).unwrap().translation.y;
+        assert!((y - 0.5).abs() < 0.05, "y = {y}");
+        assert!(started >= 1);
+        assert!(app.world().resource::<PhysicsWorld>().has_contacts(ball));
+    }
+
+    #[test]
+    fn test_collision_ended_on_teleport_and_despawn() {
+        let mut app = test_app();
+        spawn_ground(&mut app);
+        let crate_box = app
+            .world_mut()
+            .spawn((RigidBody::Dynamic, Transform::from_xyz(0.0, 0.45, 0.0), Collider::cuboid(Vec3::splat(0.5))))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+        assert!(app.world_mut().resource_mut::<Events<CollisionStarted>>().drain().count() >= 1);
+
+        // 用户直接修改 Transform 视为瞬移
+        app.world_mut().get_mut::<Transform>(crate_box).unwrap().translation.y = 20.0;
+        app.update();
+        assert!(app.world_mut().resource_mut::<Events<CollisionEnded>>().drain().count() >= 1);
+
+        app.world_mut().despawn(crate_box);
+        app.update();
+        let world = app.world().resource::<PhysicsWorld>();
+        assert_eq!(world.body_count(), 1);
+        assert_eq!(world.collider_count(), 1);
+    }
+
+    #[test]
+    fn test_ray_and_shape_cast() {
+        let mut app = test_app();
+        let ground = spawn_ground(&mut app);
+        let probe = app
+            .world_mut()
+            .spawn((RigidBody::Kinematic, Transform::from_xyz(0.0, 5.0, 0.0), Collider::ball(0.5)))
+            .id();
+        app.update();
+
+        let world = app.world().resource::<PhysicsWorld>();
+        let hit = world.cast_ray(Vec3::new(0.0, 5.0, 0.0), Vec3::NEG_Y, 100.0, Some(probe)).unwrap();
+        assert_eq!(hit.entity, ground);
+        assert!((hit.distance - 5.0).abs() < 1e-3);
+        assert!((hit.normal - Vec3::Y).length() < 1e-3);
+
+        // 不排除自身时先命中探针
+        assert_eq!(world.cast_ray(Vec3::new(0.0, 10.0, 0.0), Vec3::NEG_Y, 100.0, None).unwrap().entity, probe);
+
+        let shape = ColliderShape::Ball { radius: 1.0 };
+        let sweep = world.cast_shape(&shape, Vec3::new(3.0, 5.0, 0.0), Quat::IDENTITY, Vec3::NEG_Y, 100.0, None).unwrap();
+        assert_eq!(sweep.entity, ground);
+        assert!((sweep.position.y - 1.0).abs() < 1e-3);
+        assert!(world.cast_ray(Vec3::new(0.0, 5.0, 0.0), Vec3::Y, 100.0, Some(probe)).is_none());
+    }
+}
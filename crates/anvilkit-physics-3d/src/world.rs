@@ -0,0 +1,346 @@
+//! # 物理世界
+//!
+//! [`PhysicsWorld`] 持有 rapier 的全部模拟状态与实体 ↔ 句柄映射，并提供场景查询：
+//!
+//! - [`PhysicsWorld::cast_ray`] — 射线投射，返回最近命中的实体、距离、命中点与法线
+//! - [`PhysicsWorld::cast_shape`] — 形状投射（扫掠），用于角色控制器、子弹等
+//!
+//! 查询使用最近一次物理步进后的碰撞体位置。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use bevy_ecs::prelude::*;
+use glam::{Quat, Vec3};
+use rapier3d::na::{Quaternion, Translation3, UnitQuaternion};
+use rapier3d::prelude::{
+    ActiveEvents, BroadPhase, CCDSolver, ColliderBuilder, ColliderHandle, ColliderSet, CollisionEvent,
+    ContactPair, EventHandler, ImpulseJointSet, IntegrationParameters, IslandManager, Isometry,
+    MultibodyJointSet, NarrowPhase, PhysicsPipeline, QueryFilter, QueryPipeline, Ray, Real,
+    RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType, SharedShape, Vector,
+};
+
+use crate::components::{Collider, ColliderShape, RigidBody};
+
+pub(crate) fn to_vector(v: Vec3) -> Vector<Real> {
+    Vector::new(v.x, v.y, v.z)
+}
+
+pub(crate) fn from_vector(v: &Vector<Real>) -> Vec3 {
+    Vec3::new(v.x, v.y, v.z)
+}
+
+pub(crate) fn to_isometry(translation: Vec3, rotation: Quat) -> Isometry<Real> {
+    Isometry::from_parts(
+        Translation3::new(translation.x, translation.y, translation.z),
+        UnitQuaternion::new_normalize(Quaternion::new(rotation.w, rotation.x, rotation.y, rotation.z)),
+    )
+}
+
+pub(crate) fn from_isometry(iso: &Isometry<Real>) -> (Vec3, Quat) {
+    let q = iso.rotation;
+    (from_vector(&iso.translation.vector), Quat::from_xyzw(q.i, q.j, q.k, q.w))
+}
+
+fn shared_shape(shape: &ColliderShape) -> SharedShape {
+    match *shape {
+        ColliderShape::Ball { radius } => SharedShape::ball(radius),
+        ColliderShape::Cuboid { half_extents } => SharedShape::cuboid(half_extents.x, half_extents.y, half_extents.z),
+        ColliderShape::Capsule { half_height, radius } => SharedShape::capsule_y(half_height, radius),
+    }
+}
+
+fn body_type(kind: RigidBody) -> RigidBodyType {
+    match kind {
+        RigidBody::Dynamic => RigidBodyType::Dynamic,
+        RigidBody::Kinematic => RigidBodyType::KinematicVelocityBased,
+        RigidBody::Static => RigidBodyType::Fixed,
+    }
+}
+
+/// 射线命中结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// 命中的实体
+    pub entity: Entity,
+    /// 起点到命中点的距离
+    pub distance: f32,
+    /// 世界空间命中点
+    pub point: Vec3,
+    /// 命中表面的世界空间法线
+    pub normal: Vec3,
+}
+
+/// 形状投射结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeHit {
+    /// 命中的实体
+    pub entity: Entity,
+    /// 沿投射方向移动的距离
+    pub distance: f32,
+    /// 接触时投射形状的中心位置
+    pub position: Vec3,
+}
+
+/// 收集 rapier 碰撞事件
+#[derive(Default)]
+struct EventCollector(Mutex<Vec<CollisionEvent>>);
+
+impl EventHandler for EventCollector {
+    fn handle_collision_event(
+        &self,
+        _bodies: &RigidBodySet,
+        _colliders: &ColliderSet,
+        event: CollisionEvent,
+        _contact_pair: Option<&ContactPair>,
+    ) {
+        if let Ok(mut events) = self.0.lock() {
+            events.push(event);
+        }
+    }
+
+    fn handle_contact_force_event(
+        &self,
+        _dt: Real,
+        _bodies: &RigidBodySet,
+        _colliders: &ColliderSet,
+        _contact_pair: &ContactPair,
+        _total_force_magnitude: Real,
+    ) {
+    }
+}
+
+/// rapier 物理世界资源
+#[derive(Resource)]
+pub struct PhysicsWorld {
+    pub(crate) bodies: RigidBodySet,
+    pub(crate) colliders: ColliderSet,
+    integration_parameters: IntegrationParameters,
+    pipeline: PhysicsPipeline,
+    islands: IslandManager,
+    broad_phase: BroadPhase,
+    narrow_phase: NarrowPhase,
+    impulse_joints: ImpulseJointSet,
+    multibody_joints: MultibodyJointSet,
+    ccd_solver: CCDSolver,
+    query_pipeline: QueryPipeline,
+    events: EventCollector,
+    pub(crate) entity_bodies: HashMap<Entity, RigidBodyHandle>,
+    entity_colliders: HashMap<Entity, ColliderHandle>,
+    collider_entities: HashMap<ColliderHandle, Entity>,
+}
+
+impl Default for PhysicsWorld {
+    fn default() -> Self {
+        Self {
+            bodies: RigidBodySet::new(),
+            colliders: ColliderSet::new(),
+            integration_parameters: IntegrationParameters::default(),
+            pipeline: PhysicsPipeline::new(),
+            islands: IslandManager::new(),
+            broad_phase: BroadPhase::new(),
+            narrow_phase: NarrowPhase::new(),
+            impulse_joints: ImpulseJointSet::new(),
+            multibody_joints: MultibodyJointSet::new(),
+            ccd_solver: CCDSolver::new(),
+            query_pipeline: QueryPipeline::new(),
+            events: EventCollector::default(),
+            entity_bodies: HashMap::new(),
+            entity_colliders: HashMap::new(),
+            collider_entities: HashMap::new(),
+        }
+    }
+}
+
+impl PhysicsWorld {
+    /// 刚体数量
+    pub fn body_count(&self) -> usize {
+        self.bodies.len()
+    }
+
+    /// 碰撞体数量
+    pub fn collider_count(&self) -> usize {
+        self.colliders.len()
+    }
+
+    /// 实体对应的 rapier 刚体句柄
+    pub fn body_handle(&self, entity: Entity) -> Option<RigidBodyHandle> {
+        self.entity_bodies.get(&entity).copied()
+    }
+
+    pub(crate) fn has_collider(&self, entity: Entity) -> bool {
+        self.entity_colliders.contains_key(&entity)
+    }
+
+    /// 碰撞体句柄对应的实体
+    pub fn collider_entity(&self, handle: ColliderHandle) -> Option<Entity> {
+        self.collider_entities.get(&handle).copied()
+    }
+
+    /// 实体的碰撞体是否与任意其他碰撞体处于接触或相交状态
+    pub fn has_contacts(&self, entity: Entity) -> bool {
+        let Some(&handle) = self.entity_colliders.get(&entity) else { return false };
+        self.narrow_phase.contact_pairs_with(handle).any(|pair| pair.has_any_active_contact)
+            || self.narrow_phase.intersection_pairs_with(handle).any(|(_, _, intersecting)| intersecting)
+    }
+
+    /// 创建或更新实体的刚体
+    pub(crate) fn upsert_body(&mut self, entity: Entity, kind: RigidBody, position: Isometry<Real>, linvel: Vec3, angvel: Vec3, gravity_scale: f32) {
+        if let Some(body) = self.entity_bodies.get(&entity).and_then(|h| self.bodies.get_mut(*h)) {
+            if body.body_type() != body_type(kind) {
+                body.set_body_type(body_type(kind), true);
+            }
+            body.set_gravity_scale(gravity_scale, true);
+            return;
+        }
+        let body = RigidBodyBuilder::new(body_type(kind))
+            .position(position)
+            .linvel(to_vector(linvel))
+            .angvel(to_vector(angvel))
+            .gravity_scale(gravity_scale)
+            .user_data(entity.to_bits() as u128)
+            .build();
+        let handle = self.bodies.insert(body);
+        self.entity_bodies.insert(entity, handle);
+    }
+
+    /// 为已有刚体附加（或替换）碰撞体
+    pub(crate) fn upsert_collider(&mut self, entity: Entity, collider: &Collider) {
+        self.remove_collider(entity);
+        let Some(&parent) = self.entity_bodies.get(&entity) else { return };
+        let built = ColliderBuilder::new(shared_shape(&collider.shape))
+            .restitution(collider.restitution)
+            .friction(collider.friction)
+            .density(collider.density)
+            .sensor(collider.is_sensor)
+            .active_events(ActiveEvents::COLLISION_EVENTS)
+            .user_data(entity.to_bits() as u128)
+            .build();
+        let handle = self.colliders.insert_with_parent(built, parent, &mut self.bodies);
+        self.entity_colliders.insert(entity, handle);
+        self.collider_entities.insert(handle, entity);
+        self.query_pipeline.update(&self.colliders);
+    }
+
+    /// 移除实体的碰撞体（保留刚体）
+    pub(crate) fn remove_collider(&mut self, entity: Entity) {
+        if let Some(handle) = self.entity_colliders.remove(&entity) {
+            self.colliders.remove(handle, &mut self.islands, &mut self.bodies, true);
+            // 保留 collider_entities 映射，使本步的 Stopped 事件仍能解析到实体
+        }
+    }
+
+    /// 移除实体的刚体及其碰撞体
+    pub(crate) fn remove_entity(&mut self, entity: Entity) {
+        self.remove_collider(entity);
+        if let Some(handle) = self.entity_bodies.remove(&entity) {
+            self.bodies.remove(
+                handle,
+                &mut self.islands,
+                &mut self.colliders,
+                &mut self.impulse_joints,
+                &mut self.multibody_joints,
+                true,
+            );
+        }
+    }
+
+    /// 推进一个固定步长，返回本步产生的碰撞事件 `(实体 A, 实体 B, 是否开始)`
+    pub(crate) fn step(&mut self, dt: f32, gravity: Vec3) -> Vec<(Entity, Entity, bool)> {
+        self.integration_parameters.dt = dt;
+        self.pipeline.step(
+            &to_vector(gravity),
+            &self.integration_parameters,
+            &mut self.islands,
+            &mut self.broad_phase,
+            &mut self.narrow_phase,
+            &mut self.bodies,
+            &mut self.colliders,
+            &mut self.impulse_joints,
+            &mut self.multibody_joints,
+            &mut self.ccd_solver,
+            Some(&mut self.query_pipeline),
+            &(),
+            &self.events,
+        );
+
+        let raw = std::mem::take(&mut *self.events.0.lock().unwrap_or_else(|e| e.into_inner()));
+        let resolved = raw
+            .iter()
+            .filter_map(|event| {
+                let a = self.collider_entities.get(&event.collider1())?;
+                let b = self.collider_entities.get(&event.collider2())?;
+                Some((*a, *b, event.started()))
+            })
+            .collect();
+
+        // 已移除碰撞体的映射在其 Stopped 事件发出后清理
+        let colliders = &self.colliders;
+        self.collider_entities.retain(|handle, _| colliders.contains(*handle));
+        resolved
+    }
+
+    /// 射线投射
+    ///
+    /// `direction` 会被归一化；`exclude` 排除指定实体（通常是发射者自身）。
+    pub fn cast_ray(&self, origin: Vec3, direction: Vec3, max_distance: f32, exclude: Option<Entity>) -> Option<RayHit> {
+        let direction = direction.normalize_or_zero();
+        if direction == Vec3::ZERO {
+            return None;
+        }
+        let ray = Ray::new(to_vector(origin).into(), to_vector(direction));
+        let (handle, hit) = self.query_pipeline.cast_ray_and_get_normal(
+            &self.bodies,
+            &self.colliders,
+            &ray,
+            max_distance,
+            true,
+            self.filter(exclude),
+        )?;
+        Some(RayHit {
+            entity: self.collider_entity(handle)?,
+            distance: hit.toi,
+            point: origin + direction * hit.toi,
+            normal: from_vector(&hit.normal),
+        })
+    }
+
+    /// 形状投射：将 `shape` 从 `position` 沿 `direction` 移动，返回第一个接触
+    pub fn cast_shape(
+        &self,
+        shape: &ColliderShape,
+        position: Vec3,
+        rotation: Quat,
+        direction: Vec3,
+        max_distance: f32,
+        exclude: Option<Entity>,
+    ) -> Option<ShapeHit> {
+        let direction = direction.normalize_or_zero();
+        if direction == Vec3::ZERO {
+            return None;
+        }
+        let shape = shared_shape(shape);
+        let (handle, toi) = self.query_pipeline.cast_shape(
+            &self.bodies,
+            &self.colliders,
+            &to_isometry(position, rotation),
+            &to_vector(direction),
+            &*shape,
+            max_distance,
+            true,
+            self.filter(exclude),
+        )?;
+        Some(ShapeHit {
+            entity: self.collider_entity(handle)?,
+            distance: toi.toi,
+            position: position + direction * toi.toi,
+        })
+    }
+
+    fn filter(&self, exclude: Option<Entity>) -> QueryFilter<'static> {
+        match exclude.and_then(|e| self.entity_bodies.get(&e)) {
+            Some(&handle) => QueryFilter::default().exclude_rigid_body(handle),
+            None => QueryFilter::default(),
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! # 关键帧动画
+//!
+//! 在 ECS 中驱动 [`anvilkit_assets::animation`] 的剪辑与播放器：
+//!
+//! - [`AnimationPlayer`]: 播放状态组件（播放 / 暂停 / 循环 / 变速 / 交叉淡入淡出），
+//!   剪辑可由 `load_gltf_animations` 导入或手工构建
+//! - [`AnimatedProperties`]: `AnimationProperty::Custom` 通道的输出，游戏逻辑按名称读取
+//!
+//! [`animation_player_system`] 每帧推进播放器并写入结果：带 [`Skeleton`] 的实体
+//! 更新 [`BoneMatrices`]，其余实体把关节 0 的姿态写入 `Transform`。
+//! 系统在 `TransformPlugin` 的层次传播之前运行。
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_assets::animation::{AnimationChannel, AnimationProperty, Interpolation, Keyframe};
+//!
+//! let mut app = App::new();
+//! app.add_plugins(AnimationPlugin);
+//!
+//! let bob = AnimationClip {
+//!     name: "bob".into(),
+//!     channels: vec![AnimationChannel {
+//!         joint_index: 0,
+//!         property: AnimationProperty::Translation,
+//!         interpolation: Interpolation::CubicSpline,
+//!         keyframes: vec![
+//!             Keyframe { time: 0.0, value: [0.0; 4] },
+//!             Keyframe { time: 0.5, value: [0.0, 1.0, 0.0, 0.0] },
+//!             Keyframe { time: 1.0, value: [0.0; 4] },
+//!         ],
+//!     }],
+//! };
+//! let mut player = AnimationPlayer::new(bob.clone());
+//! player.play(bob);
+//! app.world_mut().spawn((Transform::default(), player));
+//! ```
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+use anvilkit_assets::animation::{compute_bone_matrices, BoneMatrices, Skeleton};
+pub use anvilkit_assets::animation::{AnimationClip, AnimationPlayer};
+use anvilkit_core::math::Transform;
+use anvilkit_core::time::DeltaTime;
+
+/// 自定义属性通道的输出组件
+#[derive(Component, Debug, Clone, Default)]
+pub struct AnimatedProperties {
+    values: HashMap<&'static str, f32>,
+}
+
+impl AnimatedProperties {
+    /// 读取属性当前值
+    pub fn get(&self, name: &str) -> Option<f32> {
+        self.values.get(name).copied()
+    }
+
+    /// 遍历所有属性
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, f32)> + '_ {
+        self.values.iter().map(|(&name, &value)| (name, value))
+    }
+}
+
+/// [`animation_player_system`] 查询的组件
+type AnimationTargets<'a> = (
+    &'a mut AnimationPlayer,
+    Option<&'a Skeleton>,
+    Option<&'a mut BoneMatrices>,
+    Option<&'a mut Transform>,
+    Option<&'a mut AnimatedProperties>,
+);
+
+/// 推进所有动画播放器并写入 [`BoneMatrices`] / `Transform` / [`AnimatedProperties`]
+///
+/// 已暂停或播放结束的播放器仍会写入当前姿态，`seek` 后无需恢复播放即可生效。
+pub fn animation_player_system(
+    dt: Res<DeltaTime>,
+    mut players: Query<AnimationTargets>,
+) {
+    for (mut player, skeleton, bones, transform, properties) in &mut players {
+        player.advance(dt.0);
+        match (skeleton, bones, transform) {
+            (Some(skeleton), Some(mut bones), _) => {
+                bones.matrices = compute_bone_matrices(skeleton, &player);
+            }
+            (None, _, Some(mut transform)) => {
+                let pose = player.pose(1)[0];
+                if let Some(translation) = pose.translation {
+                    transform.translation = translation;
+                }
+                if let Some(rotation) = pose.rotation {
+                    transform.rotation = rotation;
+                }
+                if let Some(scale) = pose.scale {
+                    transform.scale = scale;
+                }
+            }
+            _ => {}
+        }
+        if let Some(mut properties) = properties {
+            properties.values.extend(player.properties());
+        }
+    }
+}
+
+/// 关键帧动画插件
+///
+/// 在 `Update` 中运行 [`animation_player_system`]。
+pub struct AnimationPlugin;
+
+impl bevy_app::Plugin for AnimationPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.init_resource::<DeltaTime>();
+        app.add_systems(bevy_app::Update, animation_player_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_assets::animation::{AnimationChannel, AnimationProperty, Interpolation, Joint, Keyframe};
+    use glam::{Mat4, Vec3};
+
+    fn slide_clip() -> AnimationClip {
+        let channel = |property| AnimationChannel {
+            joint_index: 0,
+            property,
+            interpolation: Interpolation::Linear,
+            keyframes: vec![
+                Keyframe { time: 0.0, value: [0.0; 4] },
+                Keyframe { time: 1.0, value: [1.0, 0.0, 0.0, 0.0] },
+            ],
+        };
+        AnimationClip {
+            name: "slide".into(),
+            channels: vec![channel(AnimationProperty::Translation), channel(AnimationProperty::Custom("glow"))],
+        }
+    }
+
+    fn run(app: &mut bevy_app::App, dt: f32) {
+        app.world_mut().resource_mut::<DeltaTime>().0 = dt;
+        app.update();
+    }
+
+    #[test]
+    fn test_player_drives_transform_and_properties() {
+        let mut app = bevy_app::App::new();
+        app.add_plugins(AnimationPlugin);
+        let mut player = AnimationPlayer::new(slide_clip());
+        player.playing = true;
+        let entity = app.world_mut().spawn((Transform::default(), AnimatedProperties::default(), player)).id();
+
+        run(&mut app, 0.25);
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert!((transform.translation - Vec3::new(0.25, 0.0, 0.0)).length() < 1e-6);
+        assert_eq!(app.world().get::<AnimatedProperties>(entity).unwrap().get("glow"), Some(0.25));
+
+        // 暂停后 seek 仍然生效
+        app.world_mut().get_mut::<AnimationPlayer>(entity).unwrap().pause();
+        app.world_mut().get_mut::<AnimationPlayer>(entity).unwrap().seek(0.75);
+        run(&mut app, 0.5);
+        let transform = app.world().get::<Transform>(entity).unwrap();
+        assert!((transform.translation - Vec3::new(0.75, 0.0, 0.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_player_drives_skeleton_bone_matrices() {
+        let mut app = bevy_app::App::new();
+        app.add_plugins(AnimationPlugin);
+        let skeleton = Skeleton {
+            joints: vec![Joint { name: "Root".into(), parent: None, inverse_bind_matrix: Mat4::IDENTITY }],
+        };
+        let mut player = AnimationPlayer::new(slide_clip());
+        player.playing = true;
+        let entity = app
+            .world_mut()
+            .spawn((Transform::default(), skeleton, BoneMatrices::default(), player))
+            .id();
+
+        run(&mut app, 0.5);
+        let bones = app.world().get::<BoneMatrices>(entity).unwrap();
+        assert_eq!(bones.matrices.len(), 1);
+        assert!((bones.matrices[0].col(3).x - 0.5).abs() < 1e-6);
+        // 骨骼动画不移动实体本身
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().translation, Vec3::ZERO);
+    }
+}
@@ -0,0 +1,303 @@
+//! # 2D 正交相机
+//!
+//! 提供 [`Camera2dBundle`]：正交投影相机，默认 1 世界单位 = 1 像素，
+//! 并根据 [`ScalingMode`] 在窗口尺寸变化时自动重新计算投影边界。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::camera2d::{Camera2dBundle, ScalingMode};
+//!
+//! let mut world = World::new();
+//!
+//! // 像素完美相机（1 单位 = 1 像素）
+//! world.spawn(Camera2dBundle::default());
+//!
+//! // 固定垂直可视高度为 20 个世界单位
+//! world.spawn(Camera2dBundle::new(ScalingMode::FixedVertical(20.0)));
+//! ```
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::Transform;
+use anvilkit_describe::Describe;
+use glam::{Vec2, Vec3};
+
+use crate::plugin::{CameraComponent, Projection, RenderConfig};
+use crate::renderer::state::RenderState;
+
+/// 2D 相机视口缩放模式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScalingMode {
+    /// 视口尺寸跟随窗口像素尺寸：1 世界单位 = `pixels_per_unit` 像素
+    WindowSize {
+        /// 每世界单位对应的像素数
+        pixels_per_unit: f32,
+    },
+    /// 固定可视高度（世界单位），宽度按窗口宽高比推算
+    FixedVertical(f32),
+    /// 保持宽高比，使 `width × height` 区域铺满视口（超出方向会被裁剪）
+    Fill {
+        /// 目标可视宽度（世界单位）
+        width: f32,
+        /// 目标可视高度（世界单位）
+        height: f32,
+    },
+}
+
+impl Default for ScalingMode {
+    fn default() -> Self {
+        ScalingMode::WindowSize { pixels_per_unit: 1.0 }
+    }
+}
+
+impl ScalingMode {
+    /// 计算给定视口像素尺寸下的可视区域（世界单位）
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_render::camera2d::ScalingMode;
+    ///
+    /// let mode = ScalingMode::FixedVertical(10.0);
+    /// assert_eq!(mode.visible_size(1600.0, 800.0), glam::Vec2::new(20.0, 10.0));
+    /// ```
+    pub fn visible_size(&self, viewport_width: f32, viewport_height: f32) -> Vec2 {
+        let w = viewport_width.max(1.0);
+        let h = viewport_height.max(1.0);
+        let aspect = w / h;
+        match *self {
+            ScalingMode::WindowSize { pixels_per_unit } => {
+                let ppu = pixels_per_unit.max(f32::EPSILON);
+                Vec2::new(w / ppu, h / ppu)
+            }
+            ScalingMode::FixedVertical(height) => Vec2::new(height * aspect, height),
+            ScalingMode::Fill { width, height } => {
+                if width / height.max(f32::EPSILON) > aspect {
+                    // 目标区域更宽：高度铺满，左右裁剪
+                    Vec2::new(height * aspect, height)
+                } else {
+                    Vec2::new(width, width / aspect)
+                }
+            }
+        }
+    }
+}
+
+/// 正交相机缩放组件
+///
+/// 与 [`CameraComponent`] 配合使用，由 `camera2d_projection_system` 在视口尺寸
+/// 或本组件变化时重写 `Projection::Orthographic` 边界。
+#[derive(Debug, Clone, Component, Describe)]
+/// Orthographic 2D camera scaling mode and zoom.
+pub struct OrthographicScaling {
+    /// 缩放模式
+    #[describe(hint = "How world units map to viewport pixels")]
+    pub mode: ScalingMode,
+    /// 额外缩放（>1 拉远，<1 拉近）
+    #[describe(hint = "Zoom factor; >1 shows more of the world", range = "0.01..100.0", default = "1.0")]
+    pub scale: f32,
+}
+
+impl Default for OrthographicScaling {
+    fn default() -> Self {
+        Self {
+            mode: ScalingMode::default(),
+            scale: 1.0,
+        }
+    }
+}
+
+impl OrthographicScaling {
+    /// 计算正交投影边界 `(left, right, bottom, top)`，以相机为中心
+    pub fn bounds(&self, viewport_width: f32, viewport_height: f32) -> (f32, f32, f32, f32) {
+        let half = self.mode.visible_size(viewport_width, viewport_height) * self.scale * 0.5;
+        (-half.x, half.x, -half.y, half.y)
+    }
+}
+
+/// 2D 相机实体包
+///
+/// 包含正交投影的 [`CameraComponent`]、位于 z = -1000 朝 +Z 的 [`Transform`]，
+/// 以及 [`OrthographicScaling`]。z ∈ [-1000, 1000] 范围内的精灵均可见。
+#[derive(Bundle, Debug, Clone)]
+pub struct Camera2dBundle {
+    /// 相机参数（正交投影）
+    pub camera: CameraComponent,
+    /// 相机变换
+    pub transform: Transform,
+    /// 视口缩放模式
+    pub scaling: OrthographicScaling,
+}
+
+impl Default for Camera2dBundle {
+    fn default() -> Self {
+        Self::new(ScalingMode::default())
+    }
+}
+
+impl Camera2dBundle {
+    /// 相机默认 Z 坐标
+    pub const DEFAULT_Z: f32 = -1000.0;
+
+    /// 使用指定缩放模式创建 2D 相机
+    pub fn new(mode: ScalingMode) -> Self {
+        let scaling = OrthographicScaling { mode, scale: 1.0 };
+        let (w, h) = (1280.0, 720.0);
+        let (left, right, bottom, top) = scaling.bounds(w, h);
+        Self {
+            camera: CameraComponent {
+                projection: Projection::Orthographic { left, right, bottom, top },
+                near: 0.0,
+                far: 2000.0,
+                aspect_ratio: w / h,
+                ..Default::default()
+            },
+            transform: Transform::from_xyz(0.0, 0.0, Self::DEFAULT_Z),
+            scaling,
+        }
+    }
+
+    /// 设置相机在 XY 平面上的位置
+    pub fn with_position(mut self, position: Vec2) -> Self {
+        self.transform.translation = Vec3::new(position.x, position.y, Self::DEFAULT_Z);
+        self
+    }
+}
+
+/// 屏幕像素坐标（左上为原点，Y 向下）→ 世界坐标
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::camera2d::{Camera2dBundle, viewport_to_world};
+/// use glam::Vec2;
+///
+/// let cam = Camera2dBundle::default();
+/// let world = viewport_to_world(&cam.camera, &cam.transform, Vec2::new(1280.0, 720.0), Vec2::new(640.0, 360.0));
+/// assert!(world.length() < 1e-4);
+/// ```
+pub fn viewport_to_world(
+    camera: &CameraComponent,
+    transform: &Transform,
+    viewport_size: Vec2,
+    screen_pos: Vec2,
+) -> Vec2 {
+    let Projection::Orthographic { left, right, bottom, top } = camera.projection else {
+        return transform.translation.truncate();
+    };
+    let uv = screen_pos / viewport_size.max(Vec2::ONE);
+    let x = left + uv.x * (right - left);
+    let y = top - uv.y * (top - bottom);
+    transform.translation.truncate() + Vec2::new(x, y)
+}
+
+/// 世界坐标 → 屏幕像素坐标（左上为原点，Y 向下）
+pub fn world_to_viewport(
+    camera: &CameraComponent,
+    transform: &Transform,
+    viewport_size: Vec2,
+    world_pos: Vec2,
+) -> Vec2 {
+    let Projection::Orthographic { left, right, bottom, top } = camera.projection else {
+        return viewport_size * 0.5;
+    };
+    let local = world_pos - transform.translation.truncate();
+    let u = (local.x - left) / (right - left);
+    let v = (top - local.y) / (top - bottom);
+    Vec2::new(u, v) * viewport_size
+}
+
+/// 2D 投影更新系统 (PostUpdate, before camera_system)
+///
+/// 当视口尺寸（优先 `RenderState::surface_size`，否则窗口配置尺寸）或
+/// [`OrthographicScaling`] 变化时，重写正交投影边界。
+pub fn camera2d_projection_system(
+    render_state: Option<Res<RenderState>>,
+    render_config: Option<Res<RenderConfig>>,
+    mut last_size: Local<(u32, u32)>,
+    mut query: Query<(Ref<OrthographicScaling>, &mut CameraComponent)>,
+) {
+    let size = if let Some(rs) = render_state.as_ref() {
+        rs.surface_size
+    } else if let Some(cfg) = render_config.as_ref() {
+        (cfg.window_config.width, cfg.window_config.height)
+    } else {
+        return;
+    };
+    let resized = *last_size != size;
+    *last_size = size;
+
+    let (w, h) = (size.0 as f32, size.1 as f32);
+    for (scaling, mut camera) in query.iter_mut() {
+        if !resized && !scaling.is_changed() {
+            continue;
+        }
+        let (left, right, bottom, top) = scaling.bounds(w, h);
+        camera.projection = Projection::Orthographic { left, right, bottom, top };
+        camera.aspect_ratio = w / h.max(1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_size_mode_is_pixel_perfect() {
+        let scaling = OrthographicScaling::default();
+        assert_eq!(scaling.bounds(800.0, 600.0), (-400.0, 400.0, -300.0, 300.0));
+
+        let scaling = OrthographicScaling {
+            mode: ScalingMode::WindowSize { pixels_per_unit: 16.0 },
+            scale: 1.0,
+        };
+        assert_eq!(scaling.mode.visible_size(320.0, 160.0), Vec2::new(20.0, 10.0));
+    }
+
+    #[test]
+    fn test_fill_mode_covers_viewport() {
+        let mode = ScalingMode::Fill { width: 16.0, height: 9.0 };
+        // Wider-than-target viewport: width fits, height cropped
+        assert_eq!(mode.visible_size(2000.0, 1000.0), Vec2::new(16.0, 8.0));
+        // Taller-than-target viewport: height fits, width cropped
+        assert_eq!(mode.visible_size(1000.0, 1000.0), Vec2::new(9.0, 9.0));
+    }
+
+    #[test]
+    fn test_viewport_world_roundtrip() {
+        let cam = Camera2dBundle::default().with_position(Vec2::new(100.0, 50.0));
+        let size = Vec2::new(1280.0, 720.0);
+
+        let top_left = viewport_to_world(&cam.camera, &cam.transform, size, Vec2::ZERO);
+        assert_eq!(top_left, Vec2::new(100.0 - 640.0, 50.0 + 360.0));
+
+        let world = Vec2::new(123.0, -45.0);
+        let screen = world_to_viewport(&cam.camera, &cam.transform, size, world);
+        let back = viewport_to_world(&cam.camera, &cam.transform, size, screen);
+        assert!((back - world).length() < 1e-3);
+    }
+
+    #[test]
+    fn test_projection_system_recomputes_on_resize() {
+        let mut world = World::new();
+        world.insert_resource(RenderConfig::default());
+        let entity = world.spawn(Camera2dBundle::new(ScalingMode::FixedVertical(10.0))).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(camera2d_projection_system);
+        schedule.run(&mut world);
+
+        world.resource_mut::<RenderConfig>().window_config.width = 2000;
+        world.resource_mut::<RenderConfig>().window_config.height = 1000;
+        schedule.run(&mut world);
+
+        let camera = world.get::<CameraComponent>(entity).unwrap();
+        match camera.projection {
+            Projection::Orthographic { left, right, bottom, top } => {
+                assert_eq!((left, right, bottom, top), (-10.0, 10.0, -5.0, 5.0));
+            }
+            _ => panic!("Expected orthographic"),
+        }
+    }
+}
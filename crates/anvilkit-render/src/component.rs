@@ -17,6 +17,7 @@
 //! - **Tag**: 通用标签组件
 //! - **Visibility**: 可见性控制
 //! - **Layer**: 渲染层级
+//! - **RenderLayers**: 渲染层掩码（相机可见性过滤）
 //! 
 //! ## 使用示例
 //! 
@@ -41,11 +42,13 @@
 
 use bevy_ecs::prelude::*;
 use std::fmt;
+use anvilkit_core::collections::Istr;
 use anvilkit_describe::Describe;
 
 /// 实体名称组件
 /// 
 /// 为实体提供人类可读的名称标识，主要用于调试和编辑器显示。
+/// 名称以 [`Istr`] 驻留存储：同名实体共享一份字符串，比较与哈希为 O(1)。
 /// 
 /// # 特性
 /// 
@@ -74,7 +77,7 @@ use anvilkit_describe::Describe;
 #[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Name {
-    name: String,
+    name: Istr,
 }
 
 impl Name {
@@ -94,12 +97,22 @@ impl Name {
     /// ```
     pub fn new(name: impl Into<String>) -> Self {
         Self {
-            name: name.into(),
+            name: Istr::from(name.into()),
         }
     }
 
+    /// 以驻留字符串创建名称（不分配，适合大量实体共用的名称）
+    pub fn from_istr(name: Istr) -> Self {
+        Self { name }
+    }
+
     /// 获取名称字符串引用
     pub fn as_str(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// 名称的驻留句柄（O(1) 比较与哈希）
+    pub fn as_istr(&self) -> &Istr {
         &self.name
     }
 
@@ -119,7 +132,7 @@ impl Name {
     /// assert_eq!(name.as_str(), "新名称");
     /// ```
     pub fn set(&mut self, name: impl Into<String>) {
-        self.name = name.into();
+        self.name = Istr::from(name.into());
     }
 
     /// 检查名称是否为空
@@ -129,7 +142,7 @@ impl Name {
 
     /// 获取名称长度
     pub fn len(&self) -> usize {
-        self.name.len()
+        self.name.as_str().len()
     }
 }
 
@@ -147,7 +160,13 @@ impl From<String> for Name {
 
 impl From<&str> for Name {
     fn from(name: &str) -> Self {
-        Self::new(name)
+        Self::from_istr(Istr::new(name))
+    }
+}
+
+impl From<Istr> for Name {
+    fn from(name: Istr) -> Self {
+        Self::from_istr(name)
     }
 }
 
@@ -185,7 +204,7 @@ impl From<&str> for Name {
 #[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tag {
-    tag: String,
+    tag: Istr,
 }
 
 impl Tag {
@@ -205,18 +224,28 @@ impl Tag {
     /// ```
     pub fn new(tag: impl Into<String>) -> Self {
         Self {
-            tag: tag.into(),
+            tag: Istr::from(tag.into()),
         }
     }
 
+    /// 以驻留字符串创建标签（不分配）
+    pub fn from_istr(tag: Istr) -> Self {
+        Self { tag }
+    }
+
     /// 获取标签字符串引用
     pub fn as_str(&self) -> &str {
+        self.tag.as_str()
+    }
+
+    /// 标签的驻留句柄（O(1) 比较与哈希）
+    pub fn as_istr(&self) -> &Istr {
         &self.tag
     }
 
     /// 设置新的标签
     pub fn set(&mut self, tag: impl Into<String>) {
-        self.tag = tag.into();
+        self.tag = Istr::from(tag.into());
     }
 
     /// 检查是否匹配指定标签
@@ -233,6 +262,11 @@ impl Tag {
     pub fn matches(&self, other: &str) -> bool {
         self.tag == other
     }
+
+    /// 检查是否匹配指定的驻留标签（指针比较，O(1)）
+    pub fn matches_istr(&self, other: &Istr) -> bool {
+        self.tag == *other
+    }
 }
 
 impl fmt::Display for Tag {
@@ -249,7 +283,13 @@ impl From<String> for Tag {
 
 impl From<&str> for Tag {
     fn from(tag: &str) -> Self {
-        Self::new(tag)
+        Self::from_istr(Istr::new(tag))
+    }
+}
+
+impl From<Istr> for Tag {
+    fn from(tag: Istr) -> Self {
+        Self::from_istr(tag)
     }
 }
 
@@ -262,6 +302,10 @@ impl From<&str> for Tag {
 /// - **Visible**: 实体可见，正常渲染
 /// - **Hidden**: 实体隐藏，不进行渲染
 /// - **Inherited**: 继承父实体的可见性（用于层次结构）
+///
+/// 实际生效的可见性由 [`visibility_propagate_system`] 沿 `Parent` 层次解析后写入
+/// [`ComputedVisibility`]：`Visible` / `Hidden` 不受祖先影响，`Inherited`
+/// （以及没有 `Visibility` 组件的实体）沿用父实体的结果，根实体视为可见。
 /// 
 /// # 示例
 /// 
@@ -284,6 +328,7 @@ impl From<&str> for Tag {
 /// ```
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Describe)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[require(ComputedVisibility)]
 /// Entity visibility state.
 pub enum Visibility {
     /// 实体可见
@@ -348,6 +393,76 @@ impl Visibility {
     }
 }
 
+/// 解析层次后的实际可见性
+///
+/// 由 [`visibility_propagate_system`] 每帧写入（仅在变化时写入）；
+/// 视锥体剔除把它与剔除结果合并为 `ViewVisibility`，渲染提取与拾取因此跳过被隐藏的子树。
+/// 带 `Visibility` 或 `ViewVisibility` 的实体会自动附带该组件。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComputedVisibility(bool);
+
+impl ComputedVisibility {
+    /// 可见
+    pub const VISIBLE: Self = Self(true);
+    /// 隐藏
+    pub const HIDDEN: Self = Self(false);
+
+    /// 是否可见
+    pub fn get(&self) -> bool {
+        self.0
+    }
+}
+
+impl Default for ComputedVisibility {
+    fn default() -> Self {
+        Self::VISIBLE
+    }
+}
+
+/// 由自身可见性与父实体的实际可见性求出实际可见性
+pub fn resolve_visibility(visibility: Option<&Visibility>, parent_visible: bool) -> bool {
+    match visibility {
+        Some(Visibility::Visible) => true,
+        Some(Visibility::Hidden) => false,
+        Some(Visibility::Inherited) | None => parent_visible,
+    }
+}
+
+/// 可见性传播的根：无父节点，且自身参与可见性或有子节点
+type VisibilityRootFilter = (
+    Without<crate::transform::Parent>,
+    Or<(With<ComputedVisibility>, With<crate::transform::Children>)>,
+);
+
+/// [`visibility_propagate_system`] 遍历节点时查询的组件
+type VisibilityNodeQuery = (
+    Option<&'static Visibility>,
+    Option<&'static mut ComputedVisibility>,
+    Option<&'static crate::transform::Children>,
+);
+
+/// 可见性传播系统 (PostUpdate, before frustum_culling_system)
+///
+/// 从根实体出发深度优先遍历 `Children`，为沿途带 [`ComputedVisibility`] 的实体写入结果；
+/// 不带该组件的中间节点照常向下传递。栈保存在 `Local` 中跨帧复用。
+pub fn visibility_propagate_system(
+    roots: Query<Entity, VisibilityRootFilter>,
+    mut nodes: Query<VisibilityNodeQuery>,
+    mut stack: Local<Vec<(Entity, bool)>>,
+) {
+    stack.extend(roots.iter().map(|root| (root, true)));
+    while let Some((entity, parent_visible)) = stack.pop() {
+        let Ok((visibility, computed, children)) = nodes.get_mut(entity) else { continue };
+        let visible = resolve_visibility(visibility, parent_visible);
+        if let Some(mut computed) = computed {
+            computed.set_if_neq(ComputedVisibility(visible));
+        }
+        if let Some(children) = children {
+            stack.extend(children.iter().map(|&child| (child, visible)));
+        }
+    }
+}
+
 /// 渲染层级组件
 /// 
 /// 控制实体的渲染顺序，数值越大越靠前渲染。
@@ -439,6 +554,101 @@ impl From<i32> for Layer {
     }
 }
 
+/// 显式绘制顺序
+///
+/// 在同一 [`Layer`] 内覆盖计算出的排序键（3D 的材质/网格批处理顺序、精灵的 z 值），
+/// 数值越大越晚绘制；没有该组件的实体视为 `RenderOrder(0)`。
+/// 适用于武器视图模型等需要强制最后绘制、又不应修改 z 值的对象。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::prelude::*;
+///
+/// let mut world = World::new();
+/// world.spawn((Name::new("武器视图模型"), Layer(0), RenderOrder(100)));
+/// assert!(RenderOrder(100) > RenderOrder::default());
+/// ```
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Describe)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Explicit draw order within a layer.
+pub struct RenderOrder(pub i64);
+
+/// 绘制排序键：(层级, 显式顺序)
+///
+/// 渲染提取阶段按该键排序后，再应用各自的计算键（批处理或 z 值）。
+pub fn draw_sort_key(layer: Option<&Layer>, order: Option<&RenderOrder>) -> (i32, i64) {
+    (layer.map_or(0, Layer::value), order.map_or(0, |o| o.0))
+}
+
+/// 渲染层掩码
+///
+/// 最多 32 个渲染层，每一位代表一层。挂在可渲染实体上表示它属于哪些层，
+/// 挂在相机上表示相机能看到哪些层；两者有交集时实体才会被该相机绘制。
+/// 没有该组件的实体与相机都视为只属于第 0 层。
+///
+/// 与 [`Layer`] 不同：`Layer` 决定绘制先后，`RenderLayers` 决定是否绘制。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::prelude::*;
+///
+/// let minimap_only = RenderLayers::layer(3);
+/// let minimap_camera = RenderLayers::layer(0).with(3);
+/// assert!(minimap_camera.intersects(&minimap_only));
+/// assert!(!RenderLayers::default().intersects(&minimap_only));
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Describe)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Bitmask of render layers an entity belongs to or a camera can see.
+pub struct RenderLayers(pub u32);
+
+impl RenderLayers {
+    /// 渲染层数量
+    pub const COUNT: u8 = 32;
+    /// 所有层
+    pub const ALL: Self = Self(u32::MAX);
+    /// 不属于任何层（不会被任何相机绘制）
+    pub const NONE: Self = Self(0);
+
+    /// 只包含第 `n` 层
+    ///
+    /// # Panics
+    ///
+    /// `n >= 32` 时 panic。
+    pub const fn layer(n: u8) -> Self {
+        assert!(n < Self::COUNT, "渲染层编号必须小于 32");
+        Self(1 << n)
+    }
+
+    /// 加入第 `n` 层
+    pub const fn with(self, n: u8) -> Self {
+        Self(self.0 | Self::layer(n).0)
+    }
+
+    /// 移出第 `n` 层
+    pub const fn without(self, n: u8) -> Self {
+        Self(self.0 & !Self::layer(n).0)
+    }
+
+    /// 是否包含第 `n` 层
+    pub const fn contains(&self, n: u8) -> bool {
+        n < Self::COUNT && self.0 & (1 << n) != 0
+    }
+
+    /// 是否与另一掩码有公共层
+    pub const fn intersects(&self, other: &Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl Default for RenderLayers {
+    fn default() -> Self {
+        Self::layer(0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -558,12 +768,61 @@ mod tests {
         assert!(tag.matches("enemy"));
     }
 
+    #[test]
+    fn test_interned_name_and_tag() {
+        let a = Name::new("interned");
+        let b = Name::from_istr(Istr::new("interned"));
+        assert_eq!(a, b);
+        assert_eq!(a.as_istr(), b.as_istr());
+        assert_eq!(Name::from(Istr::new("interned")), a);
+
+        let tag = Tag::new("enemy");
+        assert!(tag.matches_istr(&Istr::new("enemy")));
+        assert!(!tag.matches_istr(&Istr::new("player")));
+        assert_eq!(Tag::from(tag.as_istr().clone()), tag);
+    }
+
     #[test]
     fn test_visibility_default() {
         let vis = Visibility::default();
         assert!(vis.is_visible());
     }
 
+    #[test]
+    fn test_resolve_visibility() {
+        assert!(resolve_visibility(Some(&Visibility::Visible), false));
+        assert!(!resolve_visibility(Some(&Visibility::Hidden), true));
+        assert!(!resolve_visibility(Some(&Visibility::Inherited), false));
+        assert!(resolve_visibility(None, true));
+    }
+
+    #[test]
+    fn test_visibility_propagates_through_hierarchy() {
+        use crate::transform::{Children, Parent};
+
+        let mut world = World::new();
+        let root = world.spawn(Visibility::Hidden).id();
+        // 无 Visibility 的中间节点照常传递
+        let middle = world.spawn(Parent(root)).id();
+        let inherited = world.spawn((Parent(middle), Visibility::Inherited)).id();
+        let forced = world.spawn((Parent(middle), Visibility::Visible)).id();
+        world.entity_mut(root).insert(Children::new(vec![middle]));
+        world.entity_mut(middle).insert(Children::new(vec![inherited, forced]));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(visibility_propagate_system);
+        schedule.run(&mut world);
+
+        assert!(!world.get::<ComputedVisibility>(root).unwrap().get());
+        assert!(world.get::<ComputedVisibility>(middle).is_none());
+        assert!(!world.get::<ComputedVisibility>(inherited).unwrap().get());
+        assert!(world.get::<ComputedVisibility>(forced).unwrap().get());
+
+        *world.get_mut::<Visibility>(root).unwrap() = Visibility::Visible;
+        schedule.run(&mut world);
+        assert!(world.get::<ComputedVisibility>(inherited).unwrap().get());
+    }
+
     #[test]
     fn test_visibility_toggle_twice() {
         let mut vis = Visibility::Visible;
@@ -599,4 +858,22 @@ mod tests {
         assert!(b > a);
         assert_eq!(a, Layer::new(1));
     }
+
+    #[test]
+    fn test_draw_sort_key() {
+        assert_eq!(draw_sort_key(None, None), (0, 0));
+        assert_eq!(draw_sort_key(Some(&Layer(2)), Some(&RenderOrder(-5))), (2, -5));
+        // 层级优先于显式顺序
+        assert!(draw_sort_key(Some(&Layer(1)), None) > draw_sort_key(None, Some(&RenderOrder(i64::MAX))));
+    }
+
+    #[test]
+    fn test_render_layers() {
+        let layers = RenderLayers::layer(2).with(5);
+        assert!(layers.contains(2) && layers.contains(5) && !layers.contains(0));
+        assert!(!layers.without(2).without(5).intersects(&RenderLayers::ALL));
+        assert!(RenderLayers::default().contains(0));
+        assert!(!RenderLayers::NONE.intersects(&RenderLayers::ALL));
+        assert!(!layers.contains(40));
+    }
 }
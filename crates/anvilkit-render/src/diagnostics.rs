@@ -0,0 +1,187 @@
+//! # 诊断插件
+//!
+//! [`DiagnosticsPlugin`] 初始化 [`Diagnostics`] 指标表并注册引擎内置指标：
+//! 帧时间、FPS、实体数，以及来自 [`RenderStats`] 的绘制调用、三角形数与 GPU 内存估计。
+//! 其他模块或游戏通过 [`DiagnosticsAppExt::register_diagnostic`] 添加自己的指标，
+//! 所有来源在 `Last` 阶段统一采样。
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::diagnostics::{DiagnosticsAppExt, DiagnosticsPlugin};
+//! use anvilkit_core::diagnostics::Diagnostics;
+//!
+//! #[derive(Resource)]
+//! struct Score(u32);
+//!
+//! let mut app = App::new();
+//! app.add_plugins(DiagnosticsPlugin::default())
+//!     .insert_resource(Score(7))
+//!     .register_diagnostic("score", "", |world| world.get_resource::<Score>().map(|s| s.0 as f64));
+//! app.update();
+//! assert_eq!(app.world().resource::<Diagnostics>().value("score"), Some(7.0));
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::diagnostics::{
+    sample_diagnostics, DiagnosticSources, Diagnostics, DRAW_CALLS, ENTITY_COUNT, FPS, FRAME_TIME, GPU_MEMORY, TRIANGLES,
+};
+use anvilkit_core::time::DeltaTime;
+
+use crate::renderer::debug::RenderStats;
+
+/// 诊断插件
+///
+/// 其他插件与 [`DiagnosticsAppExt::register_diagnostic`] 会在缺少时以默认配置自动添加本插件，
+/// 因此允许重复添加：之后显式添加的实例不再重复注册系统，只应用自己的配置
+/// （`max_history` 仅影响此后注册的指标）。
+pub struct DiagnosticsPlugin {
+    /// 每个指标保留的历史帧数
+    pub max_history: usize,
+    /// 周期性输出 [`Diagnostics::summary`] 到日志的间隔（秒），`None` 不输出
+    pub log_interval: Option<f32>,
+}
+
+impl Default for DiagnosticsPlugin {
+    fn default() -> Self {
+        Self { max_history: 120, log_interval: None }
+    }
+}
+
+/// 日志输出计时 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct DiagnosticsLog {
+    /// 输出间隔（秒）
+    pub interval: f32,
+    elapsed: f32,
+}
+
+impl Plugin for DiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        if app.world().contains_resource::<DiagnosticSources>() {
+            app.world_mut().resource_mut::<Diagnostics>().max_history = self.max_history;
+            self.add_log(app);
+            return;
+        }
+
+        app.insert_resource(Diagnostics::with_max_history(self.max_history))
+            .init_resource::<DiagnosticSources>()
+            .init_resource::<RenderStats>()
+            .add_systems(bevy_app::Last, sample_diagnostics);
+
+        app.register_diagnostic(FRAME_TIME, "ms", |world| {
+            world.get_resource::<DeltaTime>().map(|dt| dt.0 as f64 * 1000.0)
+        })
+        .register_diagnostic(FPS, "", |world| {
+            world.get_resource::<DeltaTime>().filter(|dt| dt.0 > 0.0).map(|dt| 1.0 / dt.0 as f64)
+        })
+        .register_diagnostic(ENTITY_COUNT, "", |world| Some(world.entities().len() as f64))
+        .register_diagnostic(DRAW_CALLS, "", |world| {
+            world.get_resource::<RenderStats>().map(|s| s.draw_calls as f64)
+        })
+        .register_diagnostic(TRIANGLES, "", |world| {
+            world.get_resource::<RenderStats>().map(|s| s.triangles as f64)
+        })
+        .register_diagnostic(GPU_MEMORY, "MiB", |world| {
+            world.get_resource::<RenderStats>().map(|s| s.gpu_memory_bytes as f64 / (1024.0 * 1024.0))
+        });
+
+        self.add_log(app);
+    }
+
+    fn name(&self) -> &str {
+        "DiagnosticsPlugin"
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+impl DiagnosticsPlugin {
+    fn add_log(&self, app: &mut App) {
+        let Some(interval) = self.log_interval else { return };
+        if let Some(mut log) = app.world_mut().get_resource_mut::<DiagnosticsLog>() {
+            log.interval = interval;
+            return;
+        }
+        app.insert_resource(DiagnosticsLog { interval, elapsed: 0.0 })
+            .add_systems(bevy_app::Last, diagnostics_log_system.after(sample_diagnostics));
+    }
+}
+
+/// 周期性输出指标摘要 (Last)
+pub fn diagnostics_log_system(
+    mut log: ResMut<DiagnosticsLog>,
+    diagnostics: Res<Diagnostics>,
+    dt: Option<Res<DeltaTime>>,
+) {
+    log.elapsed += dt.map_or(1.0 / 60.0, |d| d.0);
+    if log.elapsed >= log.interval {
+        log.elapsed = 0.0;
+        log::info!("诊断指标:\n{}", diagnostics.summary());
+    }
+}
+
+/// 注册指标来源的 App 扩展
+pub trait DiagnosticsAppExt {
+    /// 注册指标及其每帧采样函数；未添加 [`DiagnosticsPlugin`] 时以默认配置添加
+    fn register_diagnostic(
+        &mut self,
+        name: &str,
+        unit: &str,
+        source: impl Fn(&World) -> Option<f64> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl DiagnosticsAppExt for App {
+    fn register_diagnostic(
+        &mut self,
+        name: &str,
+        unit: &str,
+        source: impl Fn(&World) -> Option<f64> + Send + Sync + 'static,
+    ) -> &mut Self {
+        if !self.is_plugin_added::<DiagnosticsPlugin>() && !self.world().contains_resource::<DiagnosticSources>() {
+            self.add_plugins(DiagnosticsPlugin::default());
+        }
+        self.world_mut().resource_mut::<Diagnostics>().register(name, unit);
+        self.world_mut().resource_mut::<DiagnosticSources>().add(name, source);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_metrics_sampled() {
+        let mut app = App::new();
+        app.add_plugins(DiagnosticsPlugin::default());
+        app.insert_resource(DeltaTime(0.02));
+        app.world_mut().spawn_empty();
+        app.world_mut().resource_mut::<RenderStats>().draw_calls = 12;
+        app.update();
+        app.update();
+
+        let diagnostics = app.world().resource::<Diagnostics>();
+        assert_eq!(diagnostics.get(FRAME_TIME).unwrap().history().len(), 2);
+        assert!((diagnostics.value(FRAME_TIME).unwrap() - 20.0).abs() < 1e-3);
+        assert!((diagnostics.value(FPS).unwrap() - 50.0).abs() < 1e-3);
+        assert_eq!(diagnostics.value(ENTITY_COUNT), Some(1.0));
+        assert_eq!(diagnostics.value(DRAW_CALLS), Some(12.0));
+    }
+
+    #[test]
+    fn test_explicit_add_after_auto_add() {
+        let mut app = App::new();
+        app.register_diagnostic("custom", "", |_| Some(1.0));
+        app.add_plugins(DiagnosticsPlugin { max_history: 30, log_interval: Some(5.0) });
+        app.update();
+
+        let diagnostics = app.world().resource::<Diagnostics>();
+        assert_eq!(diagnostics.max_history, 30);
+        assert_eq!(diagnostics.value("custom"), Some(1.0));
+        assert_eq!(app.world().resource::<DiagnosticsLog>().interval, 5.0);
+    }
+}
@@ -18,6 +18,26 @@
 //! - **渲染层**: 渲染管线、资源和绘制命令
 //! - **集成层**: ECS 插件和组件系统
 //! 
+//! ## 特性矩阵
+//!
+//! 默认启用 `render-2d` + `render-3d`。纯 2D 游戏可使用
+//! `default-features = false, features = ["render-2d"]` 跳过 3D 扩展模块的编译。
+//!
+//! | 模块 | 无特性 | `render-2d` | `render-3d` | 其他 |
+//! |------|:------:|:-----------:|:-----------:|------|
+//! | 窗口 / 设备 / ECS 渲染循环（PBR、IBL、CSM、Vignette、FXAA）、`camera2d`、`debug`、`diagnostics`、`gpu_profiler`、`report`、`profiling`、`watchdog`、`quality`、`animation`、`tween` | ✓ | ✓ | ✓ | |
+//! | `sprite`、`text`、`font`、`text2d` | | ✓ | ✓ | |
+//! | `canvas2d`、`diagnostics_overlay`、`ui`、`ui_anchor`、`ui_binding`、`ui_notify`、`ui_scroll`、`world_ui`、`light2d`、`renderer::id_picking`、`renderer::vector`、`renderer::chart` | | ✓ | | |
+//! | `canvas3d`、`particle`、`scene_spawn`、`raycast`、`shadow`、`stress_test`、`renderer::portal`、`scene_renderer`、`bloom`、`skybox`、`light_shafts`、`lightmap`、`imposter`、`crowd`、`billboard`、`day_night` | | | ✓ | |
+//! | `ssao`、`dof`、`motion_blur`、`color_grading` | | | | `advanced-render` |
+//! | `capture`、`frame_stream` | | | | `capture` |
+//! | `ui_theme` | | ✓ | | `ui-theme` |
+//! | 调度 / 系统 span；chrome-tracing 输出（`ProfilingPlugin`） | | | | `trace`；`trace-chrome` |
+//!
+//! 每一列的组合都由 `tests/feature_matrix.rs` 检查：
+//! `cargo test -p anvilkit-render --test feature_matrix` 会逐一执行
+//! `cargo check --no-default-features --features <组合>`，CI 中随测试一起运行。
+//!
 //! ## 使用示例
 //! 
 //! ```rust,no_run
@@ -37,25 +57,69 @@ pub mod renderer;
 pub mod plugin;
 pub mod demo_app;
 pub mod transform;
+pub mod animation;
+pub mod tween;
 pub mod component;
+pub mod camera2d;
+pub mod picking;
+pub mod spatial;
+pub mod diagnostics;
+pub mod profiling;
+pub mod watchdog;
+pub mod quality;
+#[cfg(feature = "render-3d")]
+pub mod stress_test;
 
 /// 预导入模块
 ///
 /// 包含最常用的类型和 trait，方便用户导入。
 pub mod prelude {
-    pub use crate::window::{RenderApp, WindowConfig};
-    pub use crate::renderer::{RenderDevice, RenderSurface, PbrVertex};
-    pub use crate::plugin::{RenderPlugin, CameraComponent};
+    pub use crate::window::{RenderApp, WindowConfig, WindowCommands, CursorGrab, WinitRunnerPlugin};
+    pub use crate::window::{WindowResized, WindowScaleFactorChanged, WindowFocused, WindowCloseRequested};
+    pub use crate::renderer::{RenderDevice, RenderDeviceRestored, RenderSettings, RenderSurface, PbrVertex};
+    pub use crate::plugin::{RenderPlugin, CameraComponent, ClearColor};
+    pub use crate::camera2d::{Camera2dBundle, OrthographicScaling, ScalingMode};
     pub use crate::demo_app::DemoApp;
+    pub use crate::diagnostics::{DiagnosticsAppExt, DiagnosticsPlugin};
+    pub use crate::renderer::gpu_profiler::GpuProfilingPlugin;
+    pub use crate::renderer::report::{DiagnosticReport, GpuReportPlugin};
+    pub use crate::profiling::{CpuFrameProfile, CpuProfilerPlugin};
+    pub use crate::watchdog::{FrameHitch, FrameWatchdogPlugin};
+    pub use crate::quality::{AdaptiveQuality, AdaptiveQualityPlugin, QualityScale};
+    pub use crate::animation::{AnimatedProperties, AnimationClip, AnimationPlayer, AnimationPlugin};
+    pub use crate::tween::{Tween, TweenAppExt, TweenCompleted, TweenLens, TweenPlugin, TweenRepeat};
+    pub use crate::picking::{Pickable, PickingMode, PickingPlugin, PickingSettings, PickingState, PointerOver, PointerOut, Clicked};
+    pub use crate::spatial::{SceneBvh, SceneBvhPlugin, SpatialIndexPlugin};
 
     // ECS 渲染资源
-    pub use crate::renderer::assets::{MeshHandle, MaterialHandle, PipelineHandle, RenderAssets};
-    pub use crate::renderer::draw::{ActiveCamera, Aabb, DrawCommandList, Frustum, InstanceData, SceneLights, DirectionalLight, PointLight, SpotLight, MaterialParams};
+    pub use crate::renderer::assets::{Handle, MeshHandle, MaterialHandle, PipelineHandle, RenderAssets};
+    pub use crate::renderer::material::{Material, Materials};
+    pub use crate::renderer::draw::{ActiveCamera, Aabb, DrawCommandList, Frustum, InstanceData, ViewVisibility, SceneLights, AmbientLight, DirectionalLight, ShadowSettings, PointLight, SpotLight, MaterialParams};
     pub use crate::renderer::state::{RenderState, PbrSceneUniform, GpuLight, MAX_LIGHTS};
+    pub use crate::renderer::debug::DebugDraw;
+    pub use crate::renderer::render_scale::DynamicResolution;
+
+    // 3D 扩展
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::imposter::{Imposter, ImposterSettings, Imposters};
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::billboard::{Billboard, BillboardPlugin};
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::day_night::{DayNightCycle, DayNightPlugin, TimeOfDay, TimeOfDayEvent};
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::crowd::{CrowdAnimations, CrowdInstances, CrowdMember};
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::lightmap::{Lightmap, LightmapMode};
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::light_shafts::LightShaftSettings;
+    #[cfg(feature = "render-3d")]
+    pub use crate::renderer::skybox::{CubemapData, Skybox};
 
     // 帧捕获
     #[cfg(feature = "capture")]
     pub use crate::renderer::capture::{CaptureState, CaptureResources, save_png};
+    #[cfg(feature = "capture")]
+    pub use crate::renderer::frame_stream::FrameStream;
 
     // 重新导出核心依赖的常用类型
     pub use wgpu::{
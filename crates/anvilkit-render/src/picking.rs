@@ -0,0 +1,421 @@
+//! # 鼠标拾取
+//!
+//! [`PickingPlugin`] 每帧从光标位置经活动相机投射一条射线，与带 [`Pickable`] 和 [`Aabb`]
+//! 的实体求交，取最近的命中实体作为悬停目标，并发送事件：
+//!
+//! - [`PointerOver`] — 光标进入实体
+//! - [`PointerOut`] — 光标离开实体
+//! - [`Clicked`] — 在同一实体上按下并释放鼠标按键
+//!
+//! 透视与正交（2D）相机使用同一套射线计算。网格实体的 `Aabb` 会自动生成，
+//! 精灵等其他实体需要手动附加 `Aabb`。
+//!
+//! 拾取在 `PreUpdate` 运行，使用上一帧的相机与 `GlobalTransform`，
+//! 因此 `Update` 中的游戏逻辑在同一帧即可读取事件。
+//!
+//! 密集的 2D 美术与 alpha 镂空精灵可改用 [`PickingMode::GpuId`]：
+//! [`GpuPickingPlugin`](crate::renderer::id_picking::GpuPickingPlugin) 把光标下的精灵实体 ID
+//! 渲染进整数纹理并异步回读，结果写入 [`GpuPickResult`]，拾取系统以它代替射线求交，
+//! 命中精确到像素（结果比光标晚一到两帧）。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::picking::{Clicked, Pickable, PickingPlugin};
+//!
+//! fn on_click(mut clicks: EventReader<Clicked>) {
+//!     for click in clicks.read() {
+//!         println!("点击了 {:?}", click.entity);
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(PickingPlugin)
+//!     .add_systems(bevy_app::Update, on_click);
+//! app.world_mut().spawn((Pickable::default(), GlobalTransform::default(), Aabb::default()));
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use anvilkit_core::math::raycast::{ray_aabb_intersection, screen_to_ray};
+use anvilkit_core::math::GlobalTransform;
+use anvilkit_input::input_state::{InputState, MouseButton};
+use glam::{Vec2, Vec3};
+use std::sync::Mutex;
+
+use crate::renderer::draw::{ActiveCamera, Aabb, ViewVisibility};
+use crate::renderer::state::RenderState;
+
+/// 可拾取标记
+///
+/// `layers` 为拾取层掩码，与 [`PickingSettings::layers`] 按位与非零时参与拾取。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pickable {
+    /// 所属拾取层（位掩码）
+    pub layers: u32,
+}
+
+impl Pickable {
+    /// 所有层
+    pub const ALL: u32 = u32::MAX;
+
+    /// 仅属于指定层
+    pub fn layer(layer: u32) -> Self {
+        Self { layers: 1 << layer }
+    }
+
+    /// 是否与掩码有交集
+    pub fn matches(&self, mask: u32) -> bool {
+        self.layers & mask != 0
+    }
+}
+
+impl Default for Pickable {
+    fn default() -> Self {
+        Self { layers: Self::ALL }
+    }
+}
+
+/// 拾取方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PickingMode {
+    /// 光标射线与实体 `Aabb` 求交
+    #[default]
+    Ray,
+    /// 读取 GPU 渲染的实体 ID（需要 `GpuPickingPlugin`）
+    GpuId,
+}
+
+/// 拾取配置
+#[derive(Resource, Debug, Clone)]
+pub struct PickingSettings {
+    /// 是否启用拾取
+    pub enabled: bool,
+    /// 拾取方式
+    pub mode: PickingMode,
+    /// 当前参与拾取的层掩码
+    pub layers: u32,
+    /// 视口像素尺寸；`None` 时使用 `RenderState` 的 surface 尺寸
+    pub viewport_size: Option<Vec2>,
+}
+
+impl Default for PickingSettings {
+    fn default() -> Self {
+        Self { enabled: true, mode: PickingMode::Ray, layers: Pickable::ALL, viewport_size: None }
+    }
+}
+
+/// 拾取命中信息
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PickHit {
+    /// 命中的实体
+    pub entity: Entity,
+    /// 世界空间命中点（GPU 模式下为光标像素坐标，z 为 0）
+    pub position: Vec3,
+    /// 射线起点到命中点的距离
+    pub distance: f32,
+}
+
+/// 当前拾取状态
+#[derive(Resource, Debug, Default)]
+pub struct PickingState {
+    hovered: Option<PickHit>,
+    pressed: Vec<(MouseButton, Entity)>,
+}
+
+impl PickingState {
+    /// 光标下的实体
+    pub fn hovered(&self) -> Option<PickHit> {
+        self.hovered
+    }
+
+    /// 实体是否处于悬停状态
+    pub fn is_hovered(&self, entity: Entity) -> bool {
+        self.hovered.is_some_and(|h| h.entity == entity)
+    }
+}
+
+/// 一次 GPU ID 回读的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpuPick {
+    /// 发起回读时光标所在的像素
+    pub pixel: (u32, u32),
+    /// 该像素最上层的实体
+    pub entity: Option<Entity>,
+}
+
+/// 最近一次完成的 GPU ID 回读 (Resource)
+///
+/// 由渲染阶段在回读完成时写入（渲染期间只能拿到 `&World`，因此内部加锁）。
+#[derive(Resource, Debug, Default)]
+pub struct GpuPickResult {
+    latest: Mutex<Option<GpuPick>>,
+}
+
+impl GpuPickResult {
+    /// 最近一次完成的回读
+    pub fn latest(&self) -> Option<GpuPick> {
+        self.latest.lock().ok().and_then(|latest| *latest)
+    }
+
+    /// 记录完成的回读
+    pub fn set(&self, pick: GpuPick) {
+        if let Ok(mut latest) = self.latest.lock() {
+            *latest = Some(pick);
+        }
+    }
+}
+
+/// 光标进入实体
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PointerOver {
+    /// 目标实体
+    pub entity: Entity,
+    /// 世界空间命中点
+    pub position: Vec3,
+}
+
+/// 光标离开实体
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointerOut {
+    /// 目标实体
+    pub entity: Entity,
+}
+
+/// 在同一实体上按下并释放鼠标按键
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct Clicked {
+    /// 目标实体
+    pub entity: Entity,
+    /// 按键
+    pub button: MouseButton,
+    /// 释放时的世界空间命中点
+    pub position: Vec3,
+}
+
+/// 沿射线查找最近的命中
+///
+/// `targets` 为 (实体, 局部 Aabb, 世界变换)，Aabb 按变换转换到世界空间后求交。
+pub fn pick_nearest<'a>(
+    origin: Vec3,
+    direction: Vec3,
+    targets: impl IntoIterator<Item = (Entity, &'a Aabb, &'a GlobalTransform)>,
+) -> Option<PickHit> {
+    targets
+        .into_iter()
+        .filter_map(|(entity, aabb, transform)| {
+            let world = aabb.transformed(&transform.0);
+            ray_aabb_intersection(origin, direction, &world).map(|distance| PickHit {
+                entity,
+                position: origin + direction * distance,
+                distance,
+            })
+        })
+        .min_by(|a, b| a.distance.total_cmp(&b.distance))
+}
+
+const BUTTONS: [MouseButton; 3] = [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
+
+/// [`picking_system`] 读取的输入：光标、相机、视口尺寸与 GPU 拾取结果
+#[derive(SystemParam)]
+pub struct PickingInputs<'w> {
+    input: Option<Res<'w, InputState>>,
+    camera: Res<'w, ActiveCamera>,
+    render_state: Option<Res<'w, RenderState>>,
+    settings: Res<'w, PickingSettings>,
+    gpu_result: Res<'w, GpuPickResult>,
+}
+
+/// 拾取系统 (PreUpdate)
+pub fn picking_system(
+    inputs: PickingInputs,
+    mut state: ResMut<PickingState>,
+    query: Query<(Entity, &Pickable, &Aabb, &GlobalTransform, Option<&ViewVisibility>)>,
+    mut over: EventWriter<PointerOver>,
+    mut out: EventWriter<PointerOut>,
+    mut clicked: EventWriter<Clicked>,
+) {
+    let PickingInputs { input, camera, render_state, settings, gpu_result } = inputs;
+    let Some(input) = input else { return };
+    let viewport = settings
+        .viewport_size
+        .or_else(|| render_state.map(|rs| Vec2::new(rs.surface_size.0 as f32, rs.surface_size.1 as f32)));
+
+    let hit = match viewport {
+        _ if settings.enabled && settings.mode == PickingMode::GpuId => gpu_result
+            .latest()
+            .and_then(|pick| pick.entity)
+            .and_then(|entity| query.get(entity).ok())
+            .filter(|(_, pickable, ..)| pickable.matches(settings.layers))
+            .map(|(entity, ..)| PickHit {
+                entity,
+                position: input.mouse_position().extend(0.0),
+                distance: 0.0,
+            }),
+        Some(viewport) if settings.enabled && viewport.x > 0.0 && viewport.y > 0.0 => {
+            let (origin, direction) = screen_to_ray(input.mouse_position(), viewport, &camera.view_proj);
+            let targets = query
+                .iter()
+                .filter(|(_, pickable, ..)| pickable.matches(settings.layers))
+                .filter(|(.., view_visibility)| view_visibility.is_none_or(|v| v.get()))
+                .map(|(entity, _, aabb, transform, _)| (entity, aabb, transform));
+            pick_nearest(origin, direction, targets)
+        }
+        _ => None,
+    };
+
+    let previous = state.hovered.map(|h| h.entity);
+    let current = hit.map(|h| h.entity);
+    if previous != current {
+        if let Some(entity) = previous {
+            out.send(PointerOut { entity });
+        }
+        if let Some(hit) = hit {
+            over.send(PointerOver { entity: hit.entity, position: hit.position });
+        }
+    }
+    state.hovered = hit;
+
+    for button in BUTTONS {
+        if input.is_mouse_just_pressed(button) {
+            state.pressed.retain(|(b, _)| *b != button);
+            if let Some(entity) = current {
+                state.pressed.push((button, entity));
+            }
+        }
+        if input.is_mouse_just_released(button) {
+            let pressed = state.pressed.iter().position(|(b, _)| *b == button).map(|i| state.pressed.swap_remove(i));
+            if let (Some((_, entity)), Some(hit)) = (pressed, hit) {
+                if entity == hit.entity {
+                    clicked.send(Clicked { entity, button, position: hit.position });
+                }
+            }
+        }
+    }
+}
+
+/// 鼠标拾取插件
+pub struct PickingPlugin;
+
+impl Plugin for PickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PickingSettings>()
+            .init_resource::<PickingState>()
+            .init_resource::<GpuPickResult>()
+            .init_resource::<ActiveCamera>()
+            .add_event::<PointerOver>()
+            .add_event::<PointerOut>()
+            .add_event::<Clicked>()
+            .add_systems(bevy_app::PreUpdate, picking_system);
+    }
+
+    fn name(&self) -> &str {
+        "PickingPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Mat4;
+
+    fn test_app() -> App {
+        let view = Mat4::look_at_lh(Vec3::new(0.0, 0.0, -10.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 100.0);
+        let mut app = App::new();
+        app.add_plugins(PickingPlugin);
+        app.insert_resource(ActiveCamera { view_proj: proj * view, ..Default::default() });
+        app.insert_resource(PickingSettings { viewport_size: Some(Vec2::new(100.0, 100.0)), ..Default::default() });
+        app.insert_resource(InputState::new());
+        app
+    }
+
+    fn set_cursor(app: &mut App, position: Vec2) {
+        let mut input = app.world_mut().resource_mut::<InputState>();
+        input.end_frame();
+        input.set_mouse_position(position);
+    }
+
+    #[test]
+    fn test_pick_nearest_prefers_closest() {
+        let aabb = Aabb::default();
+        let near = GlobalTransform(Mat4::from_translation(Vec3::new(0.0, 0.0, 2.0)));
+        let far = GlobalTransform(Mat4::from_translation(Vec3::new(0.0, 0.0, 5.0)));
+        let (e1, e2) = (Entity::from_raw(1), Entity::from_raw(2));
+        let hit = pick_nearest(Vec3::ZERO, Vec3::Z, [(e2, &aabb, &far), (e1, &aabb, &near)]).unwrap();
+        assert_eq!(hit.entity, e1);
+        assert!((hit.distance - 1.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_hover_and_click_events() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn((Pickable::default(), GlobalTransform::default(), Aabb::default())).id();
+
+        // 光标位于屏幕中心：命中
+        set_cursor(&mut app, Vec2::new(50.0, 50.0));
+        app.update();
+        assert!(app.world().resource::<PickingState>().is_hovered(target));
+        let overs: Vec<_> = app.world_mut().resource_mut::<Events<PointerOver>>().drain().collect();
+        assert_eq!(overs.len(), 1);
+        assert_eq!(overs[0].entity, target);
+
+        // 按下并释放
+        set_cursor(&mut app, Vec2::new(50.0, 50.0));
+        app.world_mut().resource_mut::<InputState>().press_mouse(MouseButton::Left);
+        app.update();
+        set_cursor(&mut app, Vec2::new(50.0, 50.0));
+        app.world_mut().resource_mut::<InputState>().release_mouse(MouseButton::Left);
+        app.update();
+        let clicks: Vec<_> = app.world_mut().resource_mut::<Events<Clicked>>().drain().collect();
+        assert_eq!(clicks.len(), 1);
+        assert_eq!(clicks[0].button, MouseButton::Left);
+
+        // 移到角落：离开
+        set_cursor(&mut app, Vec2::new(1.0, 1.0));
+        app.update();
+        let outs: Vec<_> = app.world_mut().resource_mut::<Events<PointerOut>>().drain().collect();
+        assert_eq!(outs, vec![PointerOut { entity: target }]);
+        assert!(app.world().resource::<PickingState>().hovered().is_none());
+    }
+
+    #[test]
+    fn test_gpu_mode_uses_readback() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn((Pickable::default(), GlobalTransform::default(), Aabb::default())).id();
+        app.world_mut().resource_mut::<PickingSettings>().mode = PickingMode::GpuId;
+
+        // 射线会命中，但 GPU 模式只认回读结果
+        set_cursor(&mut app, Vec2::new(50.0, 50.0));
+        app.update();
+        assert!(app.world().resource::<PickingState>().hovered().is_none());
+
+        app.world().resource::<GpuPickResult>().set(GpuPick { pixel: (50, 50), entity: Some(target) });
+        app.update();
+        let hit = app.world().resource::<PickingState>().hovered().unwrap();
+        assert_eq!(hit.entity, target);
+        assert_eq!(hit.position, Vec3::new(50.0, 50.0, 0.0));
+
+        // 回读到已销毁的实体：忽略
+        app.world_mut().despawn(target);
+        app.update();
+        assert!(app.world().resource::<PickingState>().hovered().is_none());
+    }
+
+    #[test]
+    fn test_layer_mask_filters() {
+        let mut app = test_app();
+        app.world_mut().spawn((Pickable::layer(3), GlobalTransform::default(), Aabb::default()));
+        app.world_mut().resource_mut::<PickingSettings>().layers = 1 << 1;
+
+        set_cursor(&mut app, Vec2::new(50.0, 50.0));
+        app.update();
+        assert!(app.world().resource::<PickingState>().hovered().is_none());
+
+        app.world_mut().resource_mut::<PickingSettings>().layers = 1 << 3;
+        app.update();
+        assert!(app.world().resource::<PickingState>().hovered().is_some());
+    }
+}
@@ -2,16 +2,31 @@
 //!
 //! 提供与 AnvilKit ECS 系统的集成，实现渲染功能的插件化。
 
+use std::collections::HashSet;
+
 use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
 use bevy_app::{App, Plugin};
-use anvilkit_core::math::{Transform, GlobalTransform};
+use anvilkit_core::math::{Color, Transform, GlobalTransform};
 use anvilkit_describe::Describe;
+use glam::Vec4;
 use log::info;
 
 use crate::window::WindowConfig;
-use crate::renderer::assets::{MeshHandle, MaterialHandle, RenderAssets};
-use crate::renderer::draw::{ActiveCamera, Aabb, DrawCommand, DrawCommandList, Frustum, SceneLights, MaterialParams};
-use crate::renderer::state::RenderState;
+use crate::renderer::device::RenderSettings;
+use crate::renderer::assets::{Handle, MeshHandle, MaterialHandle, RenderAssets};
+use crate::renderer::material::{Material, Materials};
+use crate::renderer::standard_material::StandardMaterial;
+use crate::renderer::draw::{ActiveCamera, Aabb, DirectionalLight, DrawCommand, DrawCommandList, Frustum, PointLight, SceneLights, SpotLight, MaterialParams, ViewVisibility};
+#[cfg(feature = "render-3d")]
+use crate::renderer::imposter::{billboard_matrix, imposter_lod_system, Imposter, Imposters};
+#[cfg(feature = "render-3d")]
+use crate::renderer::crowd::{crowd_extract_system, CrowdAnimations, CrowdInstances};
+#[cfg(feature = "render-3d")]
+use crate::renderer::lightmap::lightmap_material_system;
+use crate::renderer::state::{RenderState, MAX_LIGHTS};
+use crate::component::{draw_sort_key, visibility_propagate_system, ComputedVisibility, Layer, RenderLayers, RenderOrder};
+use crate::spatial::SceneBvh;
 
 /// 渲染插件
 ///
@@ -33,12 +48,15 @@ use crate::renderer::state::RenderState;
 pub struct RenderPlugin {
     /// 窗口配置
     window_config: WindowConfig,
+    /// 后端与适配器选择
+    render_settings: RenderSettings,
 }
 
 impl Default for RenderPlugin {
     fn default() -> Self {
         Self {
             window_config: WindowConfig::default(),
+            render_settings: RenderSettings::default(),
         }
     }
 }
@@ -89,6 +107,31 @@ impl RenderPlugin {
     pub fn window_config(&self) -> &WindowConfig {
         &self.window_config
     }
+
+    /// 设置图形后端与适配器选择
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_render::prelude::*;
+    /// use anvilkit_render::renderer::RenderSettings;
+    ///
+    /// let plugin = RenderPlugin::new().with_render_settings(
+    ///     RenderSettings::new()
+    ///         .with_backends(wgpu::Backends::VULKAN | wgpu::Backends::METAL)
+    ///         .with_power_preference(wgpu::PowerPreference::LowPower),
+    /// );
+    /// assert_eq!(plugin.render_settings().power_preference, wgpu::PowerPreference::LowPower);
+    /// ```
+    pub fn with_render_settings(mut self, settings: RenderSettings) -> Self {
+        self.render_settings = settings;
+        self
+    }
+
+    /// 获取后端与适配器选择
+    pub fn render_settings(&self) -> &RenderSettings {
+        &self.render_settings
+    }
 }
 
 impl Plugin for RenderPlugin {
@@ -96,16 +139,24 @@ impl Plugin for RenderPlugin {
         info!("构建渲染插件");
 
         // 添加渲染配置资源
-        app.insert_resource(RenderConfig {
+        let config = RenderConfig {
             window_config: self.window_config.clone(),
             ..Default::default()
-        });
+        };
+        app.insert_resource(ClearColor(Vec4::from(config.clear_color)));
+        app.insert_resource(config);
+        app.insert_resource(self.render_settings.clone());
 
         // 注册 ECS 资源
         app.init_resource::<ActiveCamera>();
         app.init_resource::<DrawCommandList>();
         app.init_resource::<RenderAssets>();
+        app.init_resource::<Materials>();
         app.init_resource::<SceneLights>();
+        app.init_resource::<crate::renderer::debug::DebugDraw>();
+        app.init_resource::<crate::window::WindowCommands>();
+        crate::window::runner::add_window_events(app);
+        app.add_event::<crate::renderer::RenderDeviceRestored>();
         // Note: InputState and DeltaTime are initialized by AnvilKitApp/AutoPlugins,
         // not by RenderPlugin. Games using RenderPlugin directly must init them manually.
 
@@ -115,15 +166,40 @@ impl Plugin for RenderPlugin {
             app.init_resource::<crate::renderer::capture::CaptureState>();
         }
 
+        // 即时模式调试绘制：每帧开始时清空上一帧的命令
+        app.add_systems(bevy_app::First, crate::renderer::debug::debug_draw_clear_system);
+
         // 添加真实 ECS 渲染系统到 PostUpdate 阶段
         app.add_systems(
             bevy_app::PostUpdate,
             (
+                crate::camera2d::camera2d_projection_system.before(camera_system),
                 camera_system,
-                render_extract_system.after(camera_system),
+                mesh_aabb_system.before(frustum_culling_system),
+                visibility_propagate_system.before(frustum_culling_system),
+                frustum_culling_system.after(camera_system),
+                directional_light_system.after(crate::transform::propagate_transforms),
+                local_lights_system.after(crate::transform::propagate_transforms).after(camera_system),
+                render_extract_system.after(frustum_culling_system),
             ),
         );
 
+        // 3D 扩展：光照贴图材质、远景替身 LOD、GPU 实例化人群
+        #[cfg(feature = "render-3d")]
+        {
+            app.init_resource::<Imposters>();
+            app.init_resource::<CrowdAnimations>();
+            app.init_resource::<CrowdInstances>();
+            app.add_systems(
+                bevy_app::PostUpdate,
+                (
+                    lightmap_material_system.before(render_extract_system),
+                    imposter_lod_system.after(frustum_culling_system).before(render_extract_system),
+                    crowd_extract_system.after(frustum_culling_system),
+                ),
+            );
+        }
+
         info!("渲染插件构建完成");
     }
 }
@@ -149,7 +225,7 @@ pub struct RenderConfig {
     /// MSAA 采样数（默认 4，设为 1 禁用）
     #[describe(hint = "Anti-aliasing sample count; 1 disables MSAA", range = "1..8", default = "4")]
     pub msaa_samples: u32,
-    /// 场景清除颜色 (linear RGBA)
+    /// 场景清除颜色 (linear RGBA)；运行时请修改 [`ClearColor`] 资源
     #[describe(hint = "Background clear color in linear RGBA", default = "[0.15, 0.3, 0.6, 1.0]")]
     pub clear_color: [f32; 4],
     /// 默认背面剔除模式
@@ -168,6 +244,48 @@ impl Default for RenderConfig {
     }
 }
 
+/// 场景背景清除颜色资源 (linear RGBA)
+///
+/// 由 [`RenderPlugin`] 以 `RenderConfig::clear_color` 初始化，渲染循环每帧读取，
+/// 游戏系统可在运行时修改。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::plugin::ClearColor;
+/// use glam::Vec4;
+///
+/// let mut clear = ClearColor::default();
+/// clear.0 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+/// assert_eq!(clear.to_wgpu(), wgpu::Color::BLACK);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct ClearColor(pub Vec4);
+
+impl Default for ClearColor {
+    fn default() -> Self {
+        Self(Vec4::from(RenderConfig::default().clear_color))
+    }
+}
+
+impl ClearColor {
+    /// 由不透明 RGB 创建
+    pub fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self(Vec4::new(r, g, b, 1.0))
+    }
+
+    /// 转换为 `wgpu::Color`
+    pub fn to_wgpu(&self) -> wgpu::Color {
+        Color::from(self.0).into()
+    }
+}
+
+impl From<Color> for ClearColor {
+    fn from(color: Color) -> Self {
+        Self(color.into())
+    }
+}
+
 /// 相机组件
 ///
 /// 定义渲染视角和投影参数的组件。
@@ -261,13 +379,13 @@ impl Default for CameraComponent {
 
 /// 相机系统 (PostUpdate)
 ///
-/// 查询 (CameraComponent, Transform) → 计算 view_proj → 写入 ActiveCamera
-fn camera_system(
-    camera_query: Query<(&CameraComponent, &Transform)>,
+/// 查询 (CameraComponent, Transform, RenderLayers) → 计算 view_proj → 写入 ActiveCamera
+pub fn camera_system(
+    camera_query: Query<(&CameraComponent, &Transform, Option<&RenderLayers>)>,
     render_state: Option<Res<RenderState>>,
     mut active_camera: ResMut<ActiveCamera>,
 ) {
-    let Some((camera, transform)) = camera_query.iter().find(|(c, _)| c.is_active) else {
+    let Some((camera, transform, render_layers)) = camera_query.iter().find(|(c, _, _)| c.is_active) else {
         return;
     };
 
@@ -284,7 +402,9 @@ fn camera_system(
     let forward = transform.rotation * glam::Vec3::Z;
     let target = eye + forward;
 
-    let view = glam::Mat4::look_at_lh(eye, target, glam::Vec3::Y);
+    // 使用相机自身的上方向，以支持 roll（无 roll 时与世界 Y 等效）
+    let up = transform.rotation * glam::Vec3::Y;
+    let view = glam::Mat4::look_at_lh(eye, target, up);
     let proj = match &camera.projection {
         Projection::Perspective { fov } => {
             glam::Mat4::perspective_lh(fov.to_radians(), aspect, camera.near, camera.far)
@@ -300,39 +420,279 @@ fn camera_system(
         Projection::Perspective { fov } => fov.to_radians(),
         Projection::Orthographic { .. } => std::f32::consts::FRAC_PI_4, // default for ortho
     };
+    active_camera.render_layers = render_layers.copied().unwrap_or_default();
+    active_camera.priority = camera.priority;
 }
 
-/// 渲染提取系统 (PostUpdate, after camera_system)
+/// 视锥体剔除：无 `Aabb` 的实体总是可见
+fn is_visible(frustum: &Frustum, global_transform: &GlobalTransform, aabb: Option<&Aabb>) -> bool {
+    let Some(aabb) = aabb else { return true };
+    frustum.intersects_bounds(&aabb.transformed(&global_transform.0))
+}
+
+/// [`mesh_aabb_system`] 的过滤条件：网格被替换或缺少包围盒
+type MeshAabbFilter = Or<(Changed<MeshHandle>, Without<Aabb>)>;
+
+/// 网格包围盒系统 (PostUpdate, before frustum_culling_system)
 ///
-/// 查询 (MeshHandle, MaterialHandle, GlobalTransform, Option<MaterialParams>, Option<Aabb>)
-/// → 视锥体剔除 → 填充 DrawCommandList
+/// 为缺少 `Aabb` 的网格实体插入由顶点位置计算的局部包围盒；
+/// `MeshHandle` 被替换时重新计算。生成时显式附带的 `Aabb` 不会被覆盖。
+/// 网格尚未上传（`RenderAssets` 中没有包围盒）的实体下一帧重试。
+pub fn mesh_aabb_system(
+    mut commands: Commands,
+    render_assets: Res<RenderAssets>,
+    query: Query<(Entity, Ref<MeshHandle>, Has<Aabb>), MeshAabbFilter>,
+) {
+    for (entity, mesh, has_aabb) in query.iter() {
+        if has_aabb && mesh.is_added() {
+            continue;
+        }
+        if let Some(bounds) = render_assets.mesh_bounds(&mesh) {
+            commands.entity(entity).insert(bounds);
+        }
+    }
+}
+
+/// 排序键与渲染层组件 (Layer, RenderOrder, RenderLayers)
+type SortKeyQuery = (Option<&'static Layer>, Option<&'static RenderOrder>, Option<&'static RenderLayers>);
+
+/// 实体的渲染层是否对活动相机可见（缺省为第 0 层）
+fn in_camera_layers(active_camera: &ActiveCamera, layers: Option<&RenderLayers>) -> bool {
+    active_camera.render_layers.intersects(&layers.copied().unwrap_or_default())
+}
+
+/// 提取阶段的可见性判断：优先使用剔除系统写入的 `ViewVisibility`
+fn extract_visible(
+    frustum: &Frustum,
+    global_transform: &GlobalTransform,
+    aabb: Option<&Aabb>,
+    view_visibility: Option<&ViewVisibility>,
+) -> bool {
+    match view_visibility {
+        Some(v) => v.get(),
+        None => is_visible(frustum, global_transform, aabb),
+    }
+}
+
+/// [`frustum_culling_system`] 查询的组件
+type CullQuery = (
+    Entity,
+    &'static GlobalTransform,
+    Option<&'static Aabb>,
+    Option<&'static ComputedVisibility>,
+    &'static mut ViewVisibility,
+);
+
+/// 视锥体剔除系统 (PostUpdate, after camera_system)
+///
+/// 从 ActiveCamera 的 view_proj 提取视锥体，测试每个实体的世界空间 Aabb，
+/// 结果写入 `ViewVisibility`（仅在变化时写入，不触发多余的变更检测）。
+/// 存在 [`SceneBvh`] 时先经 BVH 批量求出视锥体内的实体，未登记的实体仍逐个测试。
+/// `ComputedVisibility` 为隐藏的实体（含被隐藏祖先的子树）直接写入不可见。
+pub fn frustum_culling_system(
+    active_camera: Res<ActiveCamera>,
+    scene_bvh: Option<Res<SceneBvh>>,
+    mut in_frustum: Local<(Vec<Entity>, HashSet<Entity>)>,
+    mut query: Query<CullQuery>,
+) {
+    let frustum = Frustum::from_view_proj(&active_camera.view_proj);
+    let (candidates, visible_set) = &mut *in_frustum;
+    visible_set.clear();
+    if let Some(bvh) = &scene_bvh {
+        candidates.clear();
+        bvh.query_frustum(&frustum, candidates);
+        visible_set.extend(candidates.drain(..));
+    }
+
+    for (entity, global_transform, aabb, computed, mut view_visibility) in query.iter_mut() {
+        let visible = computed.is_none_or(|c| c.get())
+            && match &scene_bvh {
+                Some(bvh) if aabb.is_some() && bvh.contains(entity) => visible_set.contains(&entity),
+                _ => is_visible(&frustum, global_transform, aabb),
+            };
+        view_visibility.set_if_neq(ViewVisibility::from(visible));
+    }
+}
+
+/// 方向光同步系统 (PostUpdate)
+///
+/// 把第一个 [`DirectionalLight`] 实体写入 [`SceneLights::directional`]；
+/// 有 `GlobalTransform` 时光照方向随实体旋转。没有方向光实体时保留资源原值，
+/// 直接修改 `SceneLights` 的旧用法不受影响。
+pub fn directional_light_system(
+    query: Query<(&DirectionalLight, Option<&GlobalTransform>)>,
+    mut scene_lights: ResMut<SceneLights>,
+) {
+    let Some((light, global_transform)) = query.iter().next() else {
+        return;
+    };
+
+    let mut synced = light.clone();
+    if let Some(gt) = global_transform {
+        let direction = gt.0.transform_vector3(light.direction).normalize_or_zero();
+        if direction != glam::Vec3::ZERO {
+            synced.direction = direction;
+        }
+    }
+    scene_lights.directional = synced;
+}
+
+/// [`local_lights_system`] 查询的光源组件
+type LocalLightQuery<L> = (&'static L, Option<&'static GlobalTransform>, Option<&'static ComputedVisibility>);
+
+/// 点光/聚光收集系统 (PostUpdate, after camera_system)
+///
+/// 把 [`PointLight`] / [`SpotLight`] 组件变换到世界空间，重建 [`SceneLights`] 的点光与聚光列表。
+/// GPU 光源数组只有 `MAX_LIGHTS - 1` 个空位（slot 0 属于方向光），超出时优先保留
+/// 照射范围离相机最近的光源。被隐藏（`ComputedVisibility`）的光源不参与收集。
+/// 场景中从未出现过光源组件时不修改资源，直接填写 `SceneLights` 的旧用法不受影响。
+pub fn local_lights_system(
+    point_query: Query<LocalLightQuery<PointLight>>,
+    spot_query: Query<LocalLightQuery<SpotLight>>,
+    active_camera: Res<ActiveCamera>,
+    mut had_components: Local<bool>,
+    mut scene_lights: ResMut<SceneLights>,
+) {
+    if point_query.is_empty() && spot_query.is_empty() {
+        // 最后一个光源组件被移除后清空一次，之后交还给手动填写
+        if std::mem::take(&mut *had_components) {
+            scene_lights.point_lights.clear();
+            scene_lights.spot_lights.clear();
+        }
+        return;
+    }
+    *had_components = true;
+
+    let visible = |computed: Option<&ComputedVisibility>| computed.is_none_or(|c| c.get());
+    let mut point_lights: Vec<PointLight> = point_query
+        .iter()
+        .filter(|(_, _, computed)| visible(*computed))
+        .map(|(light, gt, _)| {
+            let mut world = light.clone();
+            if let Some(gt) = gt {
+                world.position = gt.0.transform_point3(light.position);
+            }
+            world
+        })
+        .collect();
+    let mut spot_lights: Vec<SpotLight> = spot_query
+        .iter()
+        .filter(|(_, _, computed)| visible(*computed))
+        .map(|(light, gt, _)| {
+            let mut world = light.clone();
+            if let Some(gt) = gt {
+                world.position = gt.0.transform_point3(light.position);
+                let direction = gt.0.transform_vector3(light.direction).normalize_or_zero();
+                if direction != glam::Vec3::ZERO {
+                    world.direction = direction;
+                }
+            }
+            world
+        })
+        .collect();
+
+    // 超出预算时按「相机到光照球面的距离」裁剪，点光与聚光共享预算
+    let budget = MAX_LIGHTS - 1;
+    if point_lights.len() + spot_lights.len() > budget {
+        let eye = active_camera.camera_pos;
+        let score = |position: glam::Vec3, range: f32| (position.distance(eye) - range).max(0.0);
+        let mut ranked: Vec<(f32, bool, usize)> = point_lights
+            .iter()
+            .enumerate()
+            .map(|(i, l)| (score(l.position, l.range), false, i))
+            .chain(spot_lights.iter().enumerate().map(|(i, l)| (score(l.position, l.range), true, i)))
+            .collect();
+        ranked.sort_by(|a, b| a.0.total_cmp(&b.0));
+        ranked.truncate(budget);
+
+        let mut keep_point = vec![false; point_lights.len()];
+        let mut keep_spot = vec![false; spot_lights.len()];
+        for (_, is_spot, i) in ranked {
+            if is_spot { keep_spot[i] = true } else { keep_point[i] = true }
+        }
+        let mut keep = keep_point.into_iter();
+        point_lights.retain(|_| keep.next().unwrap_or(false));
+        let mut keep = keep_spot.into_iter();
+        spot_lights.retain(|_| keep.next().unwrap_or(false));
+    }
+
+    scene_lights.point_lights = point_lights;
+    scene_lights.spot_lights = spot_lights;
+}
+
+/// 直接引用 GPU 材质句柄的网格实体
+type MaterialHandleDraw = (
+    &'static MeshHandle,
+    &'static MaterialHandle,
+    &'static GlobalTransform,
+    Option<&'static MaterialParams>,
+    Option<&'static Aabb>,
+    Option<&'static ViewVisibility>,
+    SortKeyQuery,
+);
+
+/// 使用 [`StandardMaterial`]（默认 PBR 管线）的网格实体
+type StandardMaterialDraw = (
+    &'static MeshHandle,
+    &'static StandardMaterial,
+    &'static GlobalTransform,
+    Option<&'static Aabb>,
+    Option<&'static ViewVisibility>,
+    SortKeyQuery,
+);
+
+/// 引用 [`Material`] 资产的网格实体
+type AssetMaterialDraw = (
+    &'static MeshHandle,
+    &'static Handle<Material>,
+    &'static GlobalTransform,
+    Option<&'static Aabb>,
+    Option<&'static ViewVisibility>,
+    SortKeyQuery,
+);
+
+/// 提取阶段读取的材质：默认 PBR 材质与材质资产
+#[derive(SystemParam)]
+struct ExtractMaterials<'w> {
+    default_material: Option<Res<'w, crate::renderer::standard_material::DefaultMaterialHandle>>,
+    materials: Option<Res<'w, Materials>>,
+}
+
+/// 提取阶段的远景替身实体与替身资产
+#[cfg(feature = "render-3d")]
+#[derive(SystemParam)]
+struct ImposterExtract<'w, 's> {
+    query: Query<'w, 's, (&'static Imposter, &'static GlobalTransform, SortKeyQuery)>,
+    imposters: Option<Res<'w, Imposters>>,
+}
+
+/// 渲染提取系统 (PostUpdate, after frustum_culling_system)
+///
+/// 查询 (MeshHandle, MaterialHandle | StandardMaterial | Handle<Material>, GlobalTransform, Option<Aabb>)
+/// → 跳过被剔除或不在相机 `RenderLayers` 中的实体 → 填充 DrawCommandList；切换到远景替身的实体提交替身四边形
 ///
 /// Uses `GlobalTransform` (world-space) rather than local `Transform`,
 /// so entities in a parent-child hierarchy render at their correct world position.
 fn render_extract_system(
-    query: Query<(&MeshHandle, &MaterialHandle, &GlobalTransform, Option<&MaterialParams>, Option<&Aabb>)>,
-    std_mat_query: Query<(&MeshHandle, &crate::renderer::standard_material::StandardMaterial, &GlobalTransform, Option<&Aabb>), Without<MaterialHandle>>,
+    query: Query<MaterialHandleDraw>,
+    std_mat_query: Query<StandardMaterialDraw, Without<MaterialHandle>>,
+    asset_mat_query: Query<AssetMaterialDraw, (Without<MaterialHandle>, Without<StandardMaterial>)>,
+    #[cfg(feature = "render-3d")] imposters: ImposterExtract,
     active_camera: Res<ActiveCamera>,
-    default_material: Option<Res<crate::renderer::standard_material::DefaultMaterialHandle>>,
+    materials: ExtractMaterials,
     mut draw_list: ResMut<DrawCommandList>,
 ) {
+    let ExtractMaterials { default_material, materials } = materials;
+    #[cfg(feature = "render-3d")]
+    let ImposterExtract { query: imposter_query, imposters } = imposters;
     draw_list.clear();
 
     let frustum = Frustum::from_view_proj(&active_camera.view_proj);
 
     // Path 1: 传统 MaterialHandle 实体
-    for (mesh, material, global_transform, mat_params, aabb) in query.iter() {
-        let model = global_transform.0;
-
-        if let Some(aabb) = aabb {
-            let local_center = aabb.center();
-            let world_center = model.transform_point3(local_center);
-            let scale = global_transform.scale();
-            let world_half = aabb.half_extents() * scale;
-
-            if !frustum.intersects_aabb(world_center, world_half) {
-                continue;
-            }
+    for (mesh, material, global_transform, mat_params, aabb, view_visibility, (layer, order, layers)) in query.iter() {
+        if !in_camera_layers(&active_camera, layers) || !extract_visible(&frustum, global_transform, aabb, view_visibility) {
+            continue;
         }
 
         let default_params = MaterialParams::default();
@@ -341,43 +701,97 @@ fn render_extract_system(
         draw_list.push(DrawCommand {
             mesh: *mesh,
             material: *material,
-            model_matrix: model,
+            model_matrix: global_transform.0,
             metallic: p.metallic,
             roughness: p.roughness,
             normal_scale: p.normal_scale,
             emissive_factor: p.emissive_factor,
+            base_color: [1.0; 4],
+            camera: active_camera.priority,
+            sort_key: draw_sort_key(layer, order),
         });
     }
 
     // Path 2: StandardMaterial 实体（使用默认 PBR 管线）
     if let Some(default_mat) = default_material {
-        for (mesh, std_mat, global_transform, aabb) in std_mat_query.iter() {
-            let model = global_transform.0;
-
-            if let Some(aabb) = aabb {
-                let local_center = aabb.center();
-                let world_center = model.transform_point3(local_center);
-                let scale = global_transform.scale();
-                let world_half = aabb.half_extents() * scale;
-
-                if !frustum.intersects_aabb(world_center, world_half) {
-                    continue;
-                }
+        for (mesh, std_mat, global_transform, aabb, view_visibility, (layer, order, layers)) in std_mat_query.iter() {
+            if !in_camera_layers(&active_camera, layers) || !extract_visible(&frustum, global_transform, aabb, view_visibility) {
+                continue;
             }
 
             draw_list.push(DrawCommand {
                 mesh: *mesh,
                 material: default_mat.0,
-                model_matrix: model,
+                model_matrix: global_transform.0,
                 metallic: std_mat.metallic,
                 roughness: std_mat.roughness,
                 normal_scale: std_mat.normal_scale,
                 emissive_factor: std_mat.emissive_factor,
+                base_color: std_mat.base_color,
+                camera: active_camera.priority,
+                sort_key: draw_sort_key(layer, order),
             });
         }
     }
 
-    // Sort for batching: group by material → mesh to minimize state changes
+    // Path 3: Handle<Material> 实体（GPU 资源尚未创建的材质本帧跳过）
+    if let Some(materials) = materials {
+        for (mesh, handle, global_transform, aabb, view_visibility, (layer, order, layers)) in asset_mat_query.iter() {
+            let (Some(mat), Some(gpu)) = (materials.get(handle), materials.gpu_handle(handle)) else {
+                continue;
+            };
+            if !in_camera_layers(&active_camera, layers) || !extract_visible(&frustum, global_transform, aabb, view_visibility) {
+                continue;
+            }
+
+            draw_list.push(DrawCommand {
+                mesh: *mesh,
+                material: gpu,
+                model_matrix: global_transform.0,
+                metallic: mat.metallic,
+                roughness: mat.roughness,
+                normal_scale: mat.normal_scale,
+                emissive_factor: mat.emissive_factor,
+                base_color: mat.base_color,
+                camera: active_camera.priority,
+                sort_key: draw_sort_key(layer, order),
+            });
+        }
+
+        // Path 4: 远景替身（完整网格已由 imposter_lod_system 隐藏）
+        #[cfg(feature = "render-3d")]
+        if let Some(imposters) = imposters {
+            for (imposter, global_transform, (layer, order, layers)) in imposter_query.iter() {
+                if !in_camera_layers(&active_camera, layers) {
+                    continue;
+                }
+                let Some(view) = imposter.view() else { continue };
+                let Some(asset) = imposters.get(&imposter.asset) else { continue };
+                let (Some(&mesh), Some(mat), Some(gpu)) = (
+                    asset.meshes.get(view as usize),
+                    materials.get(&asset.material),
+                    materials.gpu_handle(&asset.material),
+                ) else {
+                    continue;
+                };
+
+                draw_list.push(DrawCommand {
+                    mesh,
+                    material: gpu,
+                    model_matrix: billboard_matrix(global_transform, asset, active_camera.camera_pos),
+                    metallic: mat.metallic,
+                    roughness: mat.roughness,
+                    normal_scale: mat.normal_scale,
+                    emissive_factor: mat.emissive_factor,
+                    base_color: mat.base_color,
+                    camera: active_camera.priority,
+                    sort_key: draw_sort_key(layer, order),
+                });
+            }
+        }
+    }
+
+    // Sort by camera, then (Layer, RenderOrder), then group by material → mesh to minimize state changes
     draw_list.sort_for_batching();
 }
 
@@ -470,6 +884,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_clear_color_resource() {
+        let mut app = App::new();
+        app.add_plugins(RenderPlugin::default());
+        let clear = *app.world().resource::<ClearColor>();
+        assert_eq!(clear.0.to_array(), RenderConfig::default().clear_color);
+        assert_eq!(ClearColor::rgb(1.0, 0.0, 0.0).to_wgpu(), wgpu::Color::RED);
+    }
+
     #[test]
     fn test_render_config_msaa() {
         let config = RenderConfig::default();
@@ -524,4 +947,194 @@ mod tests {
         assert_eq!(cameras[1].priority, 5);
         assert_eq!(cameras[2].priority, 10);
     }
+
+    #[test]
+    fn test_extract_filters_by_camera_render_layers() {
+        let mut world = World::new();
+        world.init_resource::<ActiveCamera>();
+        world.init_resource::<DrawCommandList>();
+        world.spawn((
+            CameraComponent { priority: 3, ..Default::default() },
+            Transform::from_xyz(0.0, 0.0, -5.0),
+            RenderLayers::layer(0).with(2),
+        ));
+        world.spawn((MeshHandle(0), MaterialHandle(1), GlobalTransform::default()));
+        world.spawn((MeshHandle(0), MaterialHandle(2), GlobalTransform::default(), RenderLayers::layer(2)));
+        world.spawn((MeshHandle(0), MaterialHandle(3), GlobalTransform::default(), RenderLayers::layer(5)));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((camera_system, render_extract_system).chain());
+        schedule.run(&mut world);
+
+        let draw_list = world.resource::<DrawCommandList>();
+        let mut materials: Vec<u64> = draw_list.commands.iter().map(|c| c.material.index()).collect();
+        materials.sort();
+        assert_eq!(materials, [1, 2]);
+        assert!(draw_list.commands.iter().all(|c| c.camera == 3));
+    }
+
+    #[test]
+    fn test_frustum_culling_system_writes_view_visibility() {
+        let view = glam::Mat4::look_at_lh(glam::Vec3::new(0.0, 0.0, -5.0), glam::Vec3::ZERO, glam::Vec3::Y);
+        let proj = glam::Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 100.0);
+
+        let mut world = World::new();
+        world.insert_resource(ActiveCamera { view_proj: proj * view, ..Default::default() });
+        let aabb = Aabb::from_min_max(glam::Vec3::splat(-0.5), glam::Vec3::splat(0.5));
+        let on_screen = world.spawn((MeshHandle(0), GlobalTransform::default(), aabb)).id();
+        let off_screen = world
+            .spawn((MeshHandle(0), GlobalTransform(glam::Mat4::from_translation(glam::Vec3::new(0.0, 200.0, 0.0))), aabb))
+            .id();
+        let unbounded = world
+            .spawn((MeshHandle(0), GlobalTransform(glam::Mat4::from_translation(glam::Vec3::new(0.0, 200.0, 0.0)))))
+            .id();
+        let hidden = world.spawn((MeshHandle(0), GlobalTransform::default(), aabb, ComputedVisibility::HIDDEN)).id();
+
+        // MeshHandle 自动附带 ViewVisibility，剔除前默认可见
+        assert_eq!(world.get::<ViewVisibility>(off_screen), Some(&ViewVisibility::VISIBLE));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(frustum_culling_system);
+        schedule.run(&mut world);
+
+        assert!(world.get::<ViewVisibility>(on_screen).unwrap().get());
+        assert!(!world.get::<ViewVisibility>(off_screen).unwrap().get());
+        // 无 Aabb 的实体不参与剔除
+        assert!(world.get::<ViewVisibility>(unbounded).unwrap().get());
+        // 层次解析为隐藏的实体即使在视锥体内也不可见
+        assert!(!world.get::<ViewVisibility>(hidden).unwrap().get());
+    }
+
+    #[test]
+    fn test_frustum_culling_system_uses_scene_bvh() {
+        let view = glam::Mat4::look_at_lh(glam::Vec3::new(0.0, 0.0, -5.0), glam::Vec3::ZERO, glam::Vec3::Y);
+        let proj = glam::Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 100.0);
+
+        let mut world = World::new();
+        world.insert_resource(ActiveCamera { view_proj: proj * view, ..Default::default() });
+        world.init_resource::<SceneBvh>();
+        let aabb = Aabb::default();
+        let on_screen = world.spawn((MeshHandle(0), GlobalTransform::default(), aabb)).id();
+        let off_screen = world
+            .spawn((MeshHandle(0), GlobalTransform(glam::Mat4::from_translation(glam::Vec3::new(0.0, 200.0, 0.0))), aabb))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((crate::spatial::scene_bvh_system, frustum_culling_system).chain());
+        schedule.run(&mut world);
+
+        assert_eq!(world.resource::<SceneBvh>().len(), 2);
+        assert!(world.get::<ViewVisibility>(on_screen).unwrap().get());
+        assert!(!world.get::<ViewVisibility>(off_screen).unwrap().get());
+
+        // 移入视野后经 refit 变为可见
+        world.entity_mut(off_screen).insert(GlobalTransform::default());
+        schedule.run(&mut world);
+        assert!(world.get::<ViewVisibility>(off_screen).unwrap().get());
+    }
+
+    #[test]
+    fn test_mesh_aabb_system_tracks_mesh_changes() {
+        let small = MeshHandle(9_001);
+        let large = MeshHandle(9_002);
+        let mut assets = RenderAssets::default();
+        assets.set_mesh_bounds(small, Aabb::from_min_max(glam::Vec3::splat(-1.0), glam::Vec3::ONE));
+        assets.set_mesh_bounds(large, Aabb::from_min_max(glam::Vec3::splat(-5.0), glam::Vec3::splat(5.0)));
+
+        let mut world = World::new();
+        world.insert_resource(assets);
+        let auto = world.spawn(small).id();
+        let explicit = Aabb::from_min_max(glam::Vec3::ZERO, glam::Vec3::splat(0.25));
+        let manual = world.spawn((small, explicit)).id();
+        let pending = world.spawn(MeshHandle(9_003)).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(mesh_aabb_system);
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<Aabb>(auto).unwrap().max, glam::Vec3::ONE);
+        assert_eq!(world.get::<Aabb>(manual).unwrap().max, explicit.max);
+        assert!(world.get::<Aabb>(pending).is_none());
+
+        // 替换网格后重新计算
+        *world.get_mut::<MeshHandle>(auto).unwrap() = large;
+        schedule.run(&mut world);
+        assert_eq!(world.get::<Aabb>(auto).unwrap().max, glam::Vec3::splat(5.0));
+    }
+
+    #[test]
+    fn test_directional_light_system_syncs_component() {
+        let mut world = World::new();
+        world.init_resource::<SceneLights>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(directional_light_system);
+
+        // 无方向光实体时保留资源原值
+        world.resource_mut::<SceneLights>().directional.intensity = 2.0;
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<SceneLights>().directional.intensity, 2.0);
+
+        let mut light = DirectionalLight { direction: glam::Vec3::Z, intensity: 7.0, ..Default::default() };
+        light.shadows.cascade_count = 2;
+        let rotation = glam::Mat4::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        world.spawn((light, GlobalTransform(rotation)));
+        schedule.run(&mut world);
+
+        let synced = &world.resource::<SceneLights>().directional;
+        assert_eq!(synced.intensity, 7.0);
+        assert_eq!(synced.shadows.cascade_count, 2);
+        assert!((synced.direction - glam::Vec3::X).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_local_lights_system_collects_and_budgets() {
+        let mut world = World::new();
+        world.init_resource::<SceneLights>();
+        world.init_resource::<ActiveCamera>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(local_lights_system);
+
+        // 没有光源组件时保留手动填写的列表
+        world.resource_mut::<SceneLights>().point_lights.push(PointLight::default());
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<SceneLights>().point_lights.len(), 1);
+
+        let spot = SpotLight { position: glam::Vec3::ZERO, direction: glam::Vec3::Z, ..Default::default() };
+        let rotation = glam::Mat4::from_translation(glam::Vec3::new(0.0, 2.0, 0.0))
+            * glam::Mat4::from_rotation_y(std::f32::consts::FRAC_PI_2);
+        world.spawn((spot, GlobalTransform(rotation)));
+        world.spawn((PointLight { position: glam::Vec3::ZERO, ..Default::default() }, ComputedVisibility::HIDDEN));
+        schedule.run(&mut world);
+        {
+            let lights = world.resource::<SceneLights>();
+            assert!(lights.point_lights.is_empty());
+            assert_eq!(lights.spot_lights.len(), 1);
+            assert!((lights.spot_lights[0].position - glam::Vec3::new(0.0, 2.0, 0.0)).length() < 1e-5);
+            assert!((lights.spot_lights[0].direction - glam::Vec3::X).length() < 1e-5);
+        }
+
+        // 超出预算时保留离相机最近的光源
+        for i in 0..MAX_LIGHTS {
+            let position = glam::Vec3::new(100.0 + i as f32 * 10.0, 0.0, 0.0);
+            world.spawn((PointLight { position, range: 1.0, ..Default::default() }, GlobalTransform::default()));
+        }
+        schedule.run(&mut world);
+        {
+            let lights = world.resource::<SceneLights>();
+            assert_eq!(lights.point_lights.len() + lights.spot_lights.len(), MAX_LIGHTS - 1);
+            assert_eq!(lights.spot_lights.len(), 1);
+            assert!(lights.point_lights.iter().all(|l| l.position.x < 100.0 + 6.0 * 10.0));
+        }
+
+        // 移除全部光源组件后清空
+        let entities: Vec<Entity> = world
+            .query_filtered::<Entity, Or<(With<PointLight>, With<SpotLight>)>>()
+            .iter(&world)
+            .collect();
+        for entity in entities {
+            world.despawn(entity);
+        }
+        schedule.run(&mut world);
+        assert!(world.resource::<SceneLights>().spot_lights.is_empty());
+    }
 }
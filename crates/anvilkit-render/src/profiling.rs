@@ -0,0 +1,285 @@
+//! # 性能追踪
+//!
+//! 引擎在以下位置生成 [`tracing`] span：
+//!
+//! - 每次调度运行与每个系统执行（`trace` 特性，开启 `bevy_ecs/trace` 与 `bevy_app/trace`）；
+//! - 渲染帧 `render_frame` 以及其中的每个渲染 pass `render_pass` 和自定义阶段 `render_phase`。
+//!
+//! 没有订阅者时 span 几乎没有开销。开启 `trace-chrome` 特性并添加 [`ProfilingPlugin`] 后，
+//! 每次运行会在输出目录写入一个 `trace-<unix 秒>.json`，可直接拖入 Perfetto（ui.perfetto.dev）
+//! 或 `chrome://tracing` 查看，找出耗时的系统与渲染 pass。
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::profiling::ProfilingPlugin;
+//!
+//! let mut app = App::new();
+//! app.add_plugins(ProfilingPlugin::new("traces"));
+//! ```
+//!
+//! 不依赖 tracing 的轻量 CPU 计时由 [`CpuProfilerPlugin`] 提供：它替换 `Main` 调度的运行器，
+//! 每帧记录各调度的耗时（`RenderApp` 另记录 `render` 段），结果保存在 [`CpuFrameProfile`]。
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bevy_app::{App, Main, MainScheduleOrder, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::{ExecutorKind, InternedScheduleLabel};
+use anvilkit_core::time::Instant;
+
+/// 追踪插件：启用 `trace-chrome` 特性时把本次运行的 span 写为 chrome-tracing JSON
+pub struct ProfilingPlugin {
+    /// 追踪文件输出目录
+    pub output_dir: PathBuf,
+}
+
+impl Default for ProfilingPlugin {
+    fn default() -> Self {
+        Self::new("traces")
+    }
+}
+
+impl ProfilingPlugin {
+    /// 输出到指定目录
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        Self { output_dir: output_dir.into() }
+    }
+}
+
+/// 本次运行的追踪文件路径：`<dir>/trace-<unix 秒>.json`
+pub fn session_file(dir: &Path, unix_secs: u64) -> PathBuf {
+    dir.join(format!("trace-{}.json", unix_secs))
+}
+
+/// 正在写入的 chrome-tracing 会话 (NonSend Resource)
+///
+/// 持有期间持续写入；App 销毁时刷新并关闭文件。
+#[cfg(feature = "trace-chrome")]
+pub struct ChromeTraceSession {
+    path: PathBuf,
+    guard: tracing_chrome::FlushGuard,
+}
+
+#[cfg(feature = "trace-chrome")]
+impl ChromeTraceSession {
+    /// 追踪文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 立即把已记录的事件写入文件
+    pub fn flush(&self) {
+        self.guard.flush();
+    }
+}
+
+impl Plugin for ProfilingPlugin {
+    #[cfg(feature = "trace-chrome")]
+    fn build(&self, app: &mut App) {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        if let Err(e) = std::fs::create_dir_all(&self.output_dir) {
+            log::error!("创建追踪目录失败 {}: {}", self.output_dir.display(), e);
+            return;
+        }
+        let unix_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let path = session_file(&self.output_dir, unix_secs);
+
+        let (layer, guard) = tracing_chrome::ChromeLayerBuilder::new()
+            .file(&path)
+            .include_args(true)
+            .build();
+        if let Err(e) = tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layer)) {
+            log::warn!("已存在全局 tracing 订阅者，跳过 chrome-tracing 输出: {}", e);
+            return;
+        }
+        log::info!("chrome-tracing 输出到 {}", path.display());
+        app.insert_non_send_resource(ChromeTraceSession { path, guard });
+    }
+
+    #[cfg(not(feature = "trace-chrome"))]
+    fn build(&self, _app: &mut App) {
+        log::warn!(
+            "ProfilingPlugin: 未启用 trace-chrome 特性，不会写入 {}",
+            self.output_dir.display()
+        );
+    }
+
+    fn name(&self) -> &str {
+        "ProfilingPlugin"
+    }
+}
+
+/// 一帧内的一段 CPU 耗时
+#[derive(Debug, Clone, PartialEq)]
+pub struct CpuSection {
+    /// 段名称（调度名或 `render`）
+    pub name: String,
+    /// 相对帧开始的起始时间（毫秒）
+    pub start_ms: f64,
+    /// 耗时（毫秒）
+    pub milliseconds: f64,
+}
+
+/// 已结束的一帧 CPU 计时
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CpuFrame {
+    /// 帧序号
+    pub index: u64,
+    /// 整帧墙钟时间（毫秒，含等待垂直同步）
+    pub milliseconds: f64,
+    /// 各段耗时，按开始时间排序
+    pub sections: Vec<CpuSection>,
+}
+
+impl CpuFrame {
+    /// 未被任何段覆盖的时间（事件处理、呈现等待等）
+    pub fn untracked_ms(&self) -> f64 {
+        (self.milliseconds - self.sections.iter().map(|s| s.milliseconds).sum::<f64>()).max(0.0)
+    }
+
+    /// 按耗时降序列出各段（每行 `名称  毫秒  占比`）
+    pub fn breakdown(&self) -> String {
+        let mut sections: Vec<(&str, f64)> = self.sections.iter().map(|s| (s.name.as_str(), s.milliseconds)).collect();
+        sections.push(("(untracked)", self.untracked_ms()));
+        sections.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let total = self.milliseconds.max(f64::EPSILON);
+        let mut text = String::new();
+        for (name, ms) in sections {
+            let _ = writeln!(text, "  {:<24} {:>8.2} ms {:>5.1}%", name, ms, ms / total * 100.0);
+        }
+        text
+    }
+}
+
+/// 逐帧 CPU 计时 (Resource)
+#[derive(Resource, Debug, Default)]
+pub struct CpuFrameProfile {
+    frame_start: Option<Instant>,
+    index: u64,
+    current: Vec<CpuSection>,
+    last: Option<CpuFrame>,
+}
+
+impl CpuFrameProfile {
+    /// 结束上一帧并开始新的一帧
+    pub fn begin_frame(&mut self, now: Instant) {
+        if let Some(start) = self.frame_start {
+            self.last = Some(CpuFrame {
+                index: self.index,
+                milliseconds: now.saturating_duration_since(start).as_secs_f64() * 1000.0,
+                sections: std::mem::take(&mut self.current),
+            });
+            self.index += 1;
+        }
+        self.current.clear();
+        self.frame_start = Some(now);
+    }
+
+    /// 记录当前帧的一段耗时
+    pub fn record(&mut self, name: impl Into<String>, start: Instant, duration: Duration) {
+        let start_ms = self
+            .frame_start
+            .map_or(0.0, |frame_start| start.saturating_duration_since(frame_start).as_secs_f64() * 1000.0);
+        self.current.push(CpuSection { name: name.into(), start_ms, milliseconds: duration.as_secs_f64() * 1000.0 });
+    }
+
+    /// 最近一个完整帧
+    pub fn last_frame(&self) -> Option<&CpuFrame> {
+        self.last.as_ref()
+    }
+}
+
+/// 带计时的 `Main` 调度运行器，行为与 `Main::run_main` 相同
+fn run_main_profiled(world: &mut World, mut run_at_least_once: Local<bool>) {
+    if let Some(mut profile) = world.get_resource_mut::<CpuFrameProfile>() {
+        profile.begin_frame(Instant::now());
+    }
+    if !*run_at_least_once {
+        world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
+            for &label in &order.startup_labels {
+                let _ = world.try_run_schedule(label);
+            }
+        });
+        *run_at_least_once = true;
+    }
+
+    let mut timings: Vec<(InternedScheduleLabel, Instant, Duration)> = Vec::new();
+    world.resource_scope(|world, order: Mut<MainScheduleOrder>| {
+        for &label in &order.labels {
+            let start = Instant::now();
+            let _ = world.try_run_schedule(label);
+            timings.push((label, start, start.elapsed()));
+        }
+    });
+    if let Some(mut profile) = world.get_resource_mut::<CpuFrameProfile>() {
+        for (label, start, duration) in timings {
+            profile.record(format!("{:?}", label), start, duration);
+        }
+    }
+}
+
+/// CPU 计时插件：插入 [`CpuFrameProfile`] 并以带计时的运行器替换 `Main` 调度
+pub struct CpuProfilerPlugin;
+
+impl Plugin for CpuProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        let mut main = Schedule::new(Main);
+        main.set_executor_kind(ExecutorKind::SingleThreaded);
+        main.add_systems(run_main_profiled);
+        app.init_resource::<CpuFrameProfile>().add_schedule(main);
+    }
+
+    fn name(&self) -> &str {
+        "CpuProfilerPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_file() {
+        assert_eq!(
+            session_file(Path::new("traces"), 1_700_000_000),
+            Path::new("traces").join("trace-1700000000.json")
+        );
+    }
+
+    #[test]
+    fn test_cpu_frame_profile() {
+        let mut profile = CpuFrameProfile::default();
+        let start = Instant::now();
+        profile.begin_frame(start);
+        profile.record("Update", start + Duration::from_millis(2), Duration::from_millis(30));
+        profile.record("render", start + Duration::from_millis(32), Duration::from_millis(8));
+        assert!(profile.last_frame().is_none());
+
+        profile.begin_frame(start + Duration::from_millis(50));
+        let frame = profile.last_frame().unwrap();
+        assert_eq!(frame.index, 0);
+        assert!((frame.milliseconds - 50.0).abs() < 1e-6);
+        assert!((frame.sections[0].start_ms - 2.0).abs() < 1e-6);
+        assert!((frame.untracked_ms() - 12.0).abs() < 1e-6);
+        assert!(frame.breakdown().lines().next().unwrap().contains("Update"));
+    }
+
+    #[test]
+    fn test_profiled_main_schedule() {
+        let mut app = App::new();
+        app.add_plugins(CpuProfilerPlugin);
+        app.update();
+        app.update();
+
+        let frame = app.world().resource::<CpuFrameProfile>().last_frame().cloned().unwrap();
+        assert!(frame.sections.iter().any(|s| s.name == "Update"));
+        assert!(frame.sections.iter().any(|s| s.name == "Last"));
+    }
+}
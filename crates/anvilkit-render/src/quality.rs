@@ -0,0 +1,350 @@
+//! # 自适应画质
+//!
+//! [`AdaptiveQualityPlugin`] 监测帧时间与供电状态，在预设的画质档位 [`QualityTier`] 间切换，
+//! 把结果写入 [`QualityScale`]：
+//!
+//! - `render_scale` — 主渲染目标的分辨率缩放（由动态分辨率渲染读取）
+//! - `shadow_map_size` — CSM 阴影贴图分辨率（渲染循环检测到变化时重建阴影贴图）
+//! - `particle_scale` — 粒子发射率倍数
+//!
+//! 平均帧时间超过目标的 `1 + hysteresis` 倍时立即降一档；低于 `1 - hysteresis` 倍
+//! 并持续 `upgrade_delay` 秒才升一档，避免在阈值附近来回切换。
+//! 使用电池供电时画质不高于 `battery_tier_floor` 档以节省电量。
+//! 设置 [`AdaptiveQuality::override_tier`] 可锁定档位（例如设置菜单中的手动画质）。
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::quality::{AdaptiveQualityPlugin, QualityScale};
+//!
+//! let mut app = App::new();
+//! app.add_plugins(AdaptiveQualityPlugin::new(60.0));
+//! app.update();
+//! assert_eq!(app.world().resource::<QualityScale>().render_scale, 1.0);
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::time::DeltaTime;
+
+use crate::renderer::buffer::SHADOW_MAP_SIZE;
+
+/// 供电状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PowerSource {
+    /// 无法检测（台式机或不支持的平台）
+    #[default]
+    Unknown,
+    /// 外接电源
+    Mains,
+    /// 电池
+    Battery,
+}
+
+impl PowerSource {
+    /// 检测当前供电状态
+    ///
+    /// Linux 读取 `/sys/class/power_supply`；其他平台返回 [`PowerSource::Unknown`]，
+    /// 可由游戏通过 [`AdaptiveQuality::power_override`] 自行提供。
+    pub fn detect() -> Self {
+        #[cfg(target_os = "linux")]
+        {
+            let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else { return Self::Unknown };
+            let mut has_battery = false;
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let kind = std::fs::read_to_string(path.join("type")).unwrap_or_default();
+                match kind.trim() {
+                    "Mains" | "USB" if std::fs::read_to_string(path.join("online")).is_ok_and(|v| v.trim() == "1") => {
+                        return Self::Mains;
+                    }
+                    "Battery" => has_battery = true,
+                    _ => {}
+                }
+            }
+            if has_battery { Self::Battery } else { Self::Unknown }
+        }
+        #[cfg(not(target_os = "linux"))]
+        {
+            Self::Unknown
+        }
+    }
+}
+
+/// 一个画质档位
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QualityTier {
+    /// 主渲染目标分辨率缩放
+    pub render_scale: f32,
+    /// 阴影贴图分辨率
+    pub shadow_map_size: u32,
+    /// 粒子发射率倍数
+    pub particle_scale: f32,
+}
+
+/// 默认档位：从高到低
+pub const DEFAULT_TIERS: [QualityTier; 4] = [
+    QualityTier { render_scale: 1.0, shadow_map_size: SHADOW_MAP_SIZE, particle_scale: 1.0 },
+    QualityTier { render_scale: 0.85, shadow_map_size: SHADOW_MAP_SIZE, particle_scale: 0.75 },
+    QualityTier { render_scale: 0.7, shadow_map_size: SHADOW_MAP_SIZE / 2, particle_scale: 0.5 },
+    QualityTier { render_scale: 0.5, shadow_map_size: SHADOW_MAP_SIZE / 4, particle_scale: 0.25 },
+];
+
+/// 当前生效的画质参数 (Resource)
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct QualityScale {
+    /// 主渲染目标分辨率缩放
+    pub render_scale: f32,
+    /// 阴影贴图分辨率
+    pub shadow_map_size: u32,
+    /// 粒子发射率倍数
+    pub particle_scale: f32,
+}
+
+impl Default for QualityScale {
+    fn default() -> Self {
+        DEFAULT_TIERS[0].into()
+    }
+}
+
+impl From<QualityTier> for QualityScale {
+    fn from(tier: QualityTier) -> Self {
+        Self { render_scale: tier.render_scale, shadow_map_size: tier.shadow_map_size, particle_scale: tier.particle_scale }
+    }
+}
+
+/// 自适应画质控制器 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct AdaptiveQuality {
+    /// 是否自动调整
+    pub enabled: bool,
+    /// 目标帧率
+    pub target_fps: f32,
+    /// 迟滞比例（0.15 表示 ±15%）
+    pub hysteresis: f32,
+    /// 评估间隔（秒）
+    pub evaluation_interval: f32,
+    /// 持续有余量多久后升档（秒）
+    pub upgrade_delay: f32,
+    /// 电池供电时允许的最高画质档位（索引越大画质越低）
+    pub battery_tier_floor: usize,
+    /// 用户锁定的档位
+    pub override_tier: Option<usize>,
+    /// 用户指定的供电状态，`None` 时自动检测
+    pub power_override: Option<PowerSource>,
+    /// 档位表（从高到低）
+    pub tiers: Vec<QualityTier>,
+    tier: usize,
+    power: PowerSource,
+    frame_time_sum: f32,
+    frame_count: u32,
+    elapsed: f32,
+    headroom_time: f32,
+    power_poll_elapsed: f32,
+}
+
+/// 自动检测供电状态的间隔（秒）
+const POWER_POLL_INTERVAL: f32 = 10.0;
+
+impl AdaptiveQuality {
+    /// 以目标帧率创建
+    pub fn new(target_fps: f32) -> Self {
+        Self {
+            enabled: true,
+            target_fps,
+            hysteresis: 0.15,
+            evaluation_interval: 1.0,
+            upgrade_delay: 3.0,
+            battery_tier_floor: 1,
+            override_tier: None,
+            power_override: None,
+            tiers: DEFAULT_TIERS.to_vec(),
+            tier: 0,
+            power: PowerSource::Unknown,
+            frame_time_sum: 0.0,
+            frame_count: 0,
+            elapsed: 0.0,
+            headroom_time: 0.0,
+            // 首帧即检测一次
+            power_poll_elapsed: POWER_POLL_INTERVAL,
+        }
+    }
+
+    /// 当前档位索引（0 为最高画质）
+    pub fn tier(&self) -> usize {
+        self.tier
+    }
+
+    /// 当前档位参数
+    pub fn current(&self) -> QualityTier {
+        self.tiers.get(self.tier).copied().unwrap_or(DEFAULT_TIERS[0])
+    }
+
+    /// 当前供电状态
+    pub fn power_source(&self) -> PowerSource {
+        self.power_override.unwrap_or(self.power)
+    }
+
+    fn max_tier(&self) -> usize {
+        self.tiers.len().saturating_sub(1)
+    }
+
+    fn min_tier(&self) -> usize {
+        if self.power_source() == PowerSource::Battery {
+            self.battery_tier_floor.min(self.max_tier())
+        } else {
+            0
+        }
+    }
+
+    /// 记录一帧并在评估点调整档位；档位变化时返回新档位
+    pub fn observe(&mut self, dt: f32) -> Option<usize> {
+        let previous = self.tier;
+        if let Some(tier) = self.override_tier {
+            self.tier = tier.min(self.max_tier());
+            return (self.tier != previous).then_some(self.tier);
+        }
+        // 供电状态变化后立即应用上限
+        self.tier = self.tier.max(self.min_tier());
+        if !self.enabled || self.target_fps <= 0.0 {
+            return (self.tier != previous).then_some(self.tier);
+        }
+
+        self.frame_time_sum += dt;
+        self.frame_count += 1;
+        self.elapsed += dt;
+        if self.elapsed >= self.evaluation_interval {
+            let average = self.frame_time_sum / self.frame_count as f32;
+            let target = 1.0 / self.target_fps;
+            if average > target * (1.0 + self.hysteresis) {
+                self.tier = (self.tier + 1).min(self.max_tier());
+                self.headroom_time = 0.0;
+            } else if average < target * (1.0 - self.hysteresis) {
+                self.headroom_time += self.elapsed;
+                if self.headroom_time >= self.upgrade_delay {
+                    self.tier = self.tier.saturating_sub(1).max(self.min_tier());
+                    self.headroom_time = 0.0;
+                }
+            } else {
+                self.headroom_time = 0.0;
+            }
+            self.frame_time_sum = 0.0;
+            self.frame_count = 0;
+            self.elapsed = 0.0;
+        }
+        (self.tier != previous).then_some(self.tier)
+    }
+}
+
+/// 自适应画质系统 (Last)
+pub fn adaptive_quality_system(
+    dt: Res<DeltaTime>,
+    mut quality: ResMut<AdaptiveQuality>,
+    mut scale: ResMut<QualityScale>,
+) {
+    if quality.power_override.is_none() {
+        quality.power_poll_elapsed += dt.0;
+        if quality.power_poll_elapsed >= POWER_POLL_INTERVAL {
+            quality.power_poll_elapsed = 0.0;
+            let power = PowerSource::detect();
+            if power != quality.power {
+                log::info!("供电状态: {:?}", power);
+                quality.power = power;
+            }
+        }
+    }
+
+    if let Some(tier) = quality.observe(dt.0) {
+        let current = quality.current();
+        log::info!(
+            "画质档位 -> {} (render_scale {:.2}, shadow {}, particles {:.2})",
+            tier, current.render_scale, current.shadow_map_size, current.particle_scale
+        );
+        *scale = current.into();
+    }
+}
+
+/// 自适应画质插件
+pub struct AdaptiveQualityPlugin {
+    /// 目标帧率
+    pub target_fps: f32,
+}
+
+impl Default for AdaptiveQualityPlugin {
+    fn default() -> Self {
+        Self::new(60.0)
+    }
+}
+
+impl AdaptiveQualityPlugin {
+    /// 以目标帧率创建
+    pub fn new(target_fps: f32) -> Self {
+        Self { target_fps }
+    }
+}
+
+impl Plugin for AdaptiveQualityPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(AdaptiveQuality::new(self.target_fps))
+            .init_resource::<QualityScale>()
+            .init_resource::<DeltaTime>()
+            .add_systems(bevy_app::Last, adaptive_quality_system);
+    }
+
+    fn name(&self) -> &str {
+        "AdaptiveQualityPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(quality: &mut AdaptiveQuality, dt: f32, seconds: f32) {
+        for _ in 0..(seconds / dt).round() as u32 {
+            quality.observe(dt);
+        }
+    }
+
+    #[test]
+    fn test_downgrade_and_hysteresis_upgrade() {
+        let mut quality = AdaptiveQuality::new(60.0);
+        quality.power_override = Some(PowerSource::Mains);
+
+        // 32 FPS：每秒降一档直到最低
+        run(&mut quality, 1.0 / 32.0, 2.0);
+        assert_eq!(quality.tier(), 2);
+        run(&mut quality, 1.0 / 32.0, 5.0);
+        assert_eq!(quality.tier(), 3);
+
+        // 接近目标（迟滞区间内）不升档
+        run(&mut quality, 1.0 / 64.0, 10.0);
+        assert_eq!(quality.tier(), 3);
+
+        // 明显有余量：持续 upgrade_delay 后才升一档
+        run(&mut quality, 1.0 / 128.0, 2.0);
+        assert_eq!(quality.tier(), 3);
+        run(&mut quality, 1.0 / 128.0, 1.0);
+        assert_eq!(quality.tier(), 2);
+    }
+
+    #[test]
+    fn test_battery_floor_and_override() {
+        let mut quality = AdaptiveQuality::new(60.0);
+        quality.power_override = Some(PowerSource::Battery);
+        assert_eq!(quality.observe(1.0 / 120.0), Some(1));
+
+        quality.override_tier = Some(0);
+        assert_eq!(quality.observe(1.0 / 10.0), Some(0));
+        run(&mut quality, 1.0 / 8.0, 5.0);
+        assert_eq!(quality.tier(), 0);
+    }
+
+    #[test]
+    fn test_quality_scale_system() {
+        let mut app = App::new();
+        app.add_plugins(AdaptiveQualityPlugin::new(60.0));
+        app.world_mut().resource_mut::<AdaptiveQuality>().override_tier = Some(3);
+        app.update();
+        assert_eq!(*app.world().resource::<QualityScale>(), QualityScale::from(DEFAULT_TIERS[3]));
+    }
+}
@@ -2,24 +2,31 @@
 //!
 //! 管理 GPU 端的网格和材质资源，提供 Handle-based 的资产引用系统。
 //! 支持管线共享：多个材质可引用同一渲染管线，避免重复创建。
+//!
+//! 网格、材质与管线句柄均为分代键（[`SlotKey`] 打包为 `u64`），资源移除或设备重建后
+//! 旧句柄失效，不会指向复用同一槽位的新资源。
 
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::marker::PhantomData;
 
 use bevy_ecs::prelude::*;
 use wgpu::{Buffer, RenderPipeline, BindGroup, IndexFormat};
 
-use crate::renderer::RenderDevice;
-use crate::renderer::buffer::{Vertex, create_vertex_buffer, create_index_buffer, create_index_buffer_u32};
-
-static NEXT_HANDLE_ID: AtomicU64 = AtomicU64::new(1);
+use anvilkit_core::collections::{Key, SlotKey, SlotMap};
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_core::math::Aabb;
+use glam::Vec3;
 
-fn next_id() -> u64 {
-    NEXT_HANDLE_ID.fetch_add(1, Ordering::Relaxed)
-}
+use crate::renderer::RenderDevice;
+use crate::renderer::buffer::{
+    Vertex, AttributeVertex, VertexAttributes, create_vertex_buffer, create_index_buffer, create_index_buffer_u32,
+};
 
 /// 网格 GPU 句柄
+///
+/// 自动附带 [`ViewVisibility`](crate::renderer::draw::ViewVisibility)，由视锥体剔除系统每帧更新。
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
+#[require(crate::renderer::draw::ViewVisibility)]
 pub struct MeshHandle(pub u64);
 
 impl MeshHandle {
@@ -27,6 +34,11 @@ impl MeshHandle {
     pub fn index(&self) -> u64 { self.0 }
 }
 
+impl Key for MeshHandle {
+    fn from_slot_key(key: SlotKey) -> Self { Self(key.to_bits()) }
+    fn slot_key(&self) -> SlotKey { SlotKey::from_bits(self.0) }
+}
+
 /// 材质 GPU 句柄
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component)]
 pub struct MaterialHandle(pub u64);
@@ -36,12 +48,64 @@ impl MaterialHandle {
     pub fn index(&self) -> u64 { self.0 }
 }
 
+impl Key for MaterialHandle {
+    fn from_slot_key(key: SlotKey) -> Self { Self(key.to_bits()) }
+    fn slot_key(&self) -> SlotKey { SlotKey::from_bits(self.0) }
+}
+
+/// 类型化 CPU 资产句柄
+///
+/// 作为组件附加到实体上引用 CPU 端资产（如 `Handle<Material>`），
+/// GPU 资源由对应的资产存储按需创建。
+#[derive(Component)]
+pub struct Handle<T: Send + Sync + 'static> {
+    id: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Send + Sync + 'static> Handle<T> {
+    /// 以原始 ID 创建句柄
+    pub fn from_id(id: u64) -> Self {
+        Self { id, _marker: PhantomData }
+    }
+
+    /// 获取内部 ID
+    pub fn id(&self) -> u64 { self.id }
+}
+
+impl<T: Send + Sync + 'static> Clone for Handle<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T: Send + Sync + 'static> Copy for Handle<T> {}
+
+impl<T: Send + Sync + 'static> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool { self.id == other.id }
+}
+
+impl<T: Send + Sync + 'static> Eq for Handle<T> {}
+
+impl<T: Send + Sync + 'static> std::hash::Hash for Handle<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.id.hash(state) }
+}
+
+impl<T: Send + Sync + 'static> std::fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Handle<{}>({})", std::any::type_name::<T>().rsplit("::").next().unwrap_or(""), self.id)
+    }
+}
+
 /// 渲染管线句柄
 ///
 /// 多个材质可引用同一管线，减少 GPU 管线对象的数量。
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PipelineHandle(pub u64);
 
+impl Key for PipelineHandle {
+    fn from_slot_key(key: SlotKey) -> Self { Self(key.to_bits()) }
+    fn slot_key(&self) -> SlotKey { SlotKey::from_bits(self.0) }
+}
+
 /// GPU 端网格数据
 pub struct GpuMesh {
     /// GPU vertex buffer containing mesh vertex data.
@@ -52,6 +116,10 @@ pub struct GpuMesh {
     pub index_count: u32,
     /// Index element format (Uint16 or Uint32).
     pub index_format: IndexFormat,
+    /// 可选属性顶点流（slot 1，[`AttributeVertex`]），仅带可选属性上传的网格持有
+    pub attribute_buffer: Option<Buffer>,
+    /// 网格实际提供的可选属性
+    pub attributes: VertexAttributes,
 }
 
 /// GPU 端材质数据
@@ -62,6 +130,8 @@ pub struct GpuMaterial {
     pub pipeline_handle: PipelineHandle,
     /// Material-specific bind group (textures, uniforms).
     pub bind_group: BindGroup,
+    /// 管线读取的可选顶点属性；非空时绘制需在 slot 1 绑定网格的属性顶点流
+    pub vertex_attributes: VertexAttributes,
 }
 
 /// Pipeline 缓存 key
@@ -78,20 +148,48 @@ pub struct PipelineKey {
 }
 
 /// 混合模式
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum BlendMode {
     /// 不透明
+    #[default]
     Opaque,
     /// Alpha 混合
     AlphaBlend,
     /// 加法混合
     Additive,
+    /// 预乘 Alpha 混合（颜色已乘以 alpha）
+    Premultiplied,
+}
+
+impl BlendMode {
+    /// 对应的 wgpu 混合状态
+    pub fn blend_state(self) -> wgpu::BlendState {
+        match self {
+            BlendMode::Opaque => wgpu::BlendState::REPLACE,
+            BlendMode::AlphaBlend => wgpu::BlendState::ALPHA_BLENDING,
+            BlendMode::Additive => {
+                let add = wgpu::BlendComponent {
+                    src_factor: wgpu::BlendFactor::One,
+                    dst_factor: wgpu::BlendFactor::One,
+                    operation: wgpu::BlendOperation::Add,
+                };
+                wgpu::BlendState { color: add, alpha: add }
+            }
+            BlendMode::Premultiplied => wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING,
+        }
+    }
+
+    /// 是否写入深度（只有不透明写入，透明物体只做深度测试）
+    pub fn writes_depth(self) -> bool {
+        self == BlendMode::Opaque
+    }
 }
 
 /// 剔除模式
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum CullMode {
     /// 无剔除
+    #[default]
     None,
     /// 背面剔除
     Back,
@@ -99,6 +197,17 @@ pub enum CullMode {
     Front,
 }
 
+impl CullMode {
+    /// 对应的 wgpu 剔除面
+    pub fn face(self) -> Option<wgpu::Face> {
+        match self {
+            CullMode::None => None,
+            CullMode::Back => Some(wgpu::Face::Back),
+            CullMode::Front => Some(wgpu::Face::Front),
+        }
+    }
+}
+
 /// Pipeline 缓存
 ///
 /// 缓存已创建的渲染管线，避免重复创建。
@@ -207,14 +316,57 @@ impl Default for BindGroupCache {
     }
 }
 
+/// 管线构建函数，设备丢失重建后用于重新创建同一句柄下的管线
+pub type PipelineFactory = Box<dyn Fn(&RenderDevice) -> Result<RenderPipeline> + Send + Sync>;
+
+struct PipelineEntry {
+    /// 设备重建失败时为 `None`
+    pipeline: Option<RenderPipeline>,
+    /// 通过 [`RenderAssets::register_pipeline_with`] 注册的管线构建函数
+    factory: Option<PipelineFactory>,
+}
+
 /// GPU 资产存储
 ///
 /// 管理所有已上传到 GPU 的网格、材质和渲染管线资源。
 #[derive(Resource, Default)]
 pub struct RenderAssets {
-    meshes: HashMap<MeshHandle, GpuMesh>,
-    materials: HashMap<MaterialHandle, GpuMaterial>,
-    pipelines: HashMap<PipelineHandle, RenderPipeline>,
+    meshes: SlotMap<MeshHandle, GpuMesh>,
+    materials: SlotMap<MaterialHandle, GpuMaterial>,
+    pipelines: SlotMap<PipelineHandle, PipelineEntry>,
+    /// 网格局部空间包围盒（上传时由顶点位置计算）
+    mesh_bounds: HashMap<MeshHandle, Aabb>,
+}
+
+/// 从顶点缓冲区布局中 `shader_location = 0` 的 Float32x2/Float32x3 属性读取位置并计算包围盒
+///
+/// 顶点类型没有该属性或顶点为空时返回 `None`。
+pub(crate) fn vertex_bounds<V: Vertex>(vertices: &[V]) -> Option<Aabb> {
+    if vertices.is_empty() {
+        return None;
+    }
+    let layout = V::layout();
+    let attr = layout.attributes.iter().find(|a| a.shader_location == 0)?;
+    let components = match attr.format {
+        wgpu::VertexFormat::Float32x2 => 2,
+        wgpu::VertexFormat::Float32x3 => 3,
+        _ => return None,
+    };
+    let stride = std::mem::size_of::<V>();
+    let bytes: &[u8] = bytemuck::cast_slice(vertices);
+    let offset = attr.offset as usize;
+    let read = |base: usize, i: usize| {
+        let at = base + offset + i * 4;
+        f32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+    };
+    let (mut min, mut max) = (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN));
+    for base in (0..bytes.len()).step_by(stride) {
+        let z = if components == 3 { read(base, 2) } else { 0.0 };
+        let p = Vec3::new(read(base, 0), read(base, 1), z);
+        min = min.min(p);
+        max = max.max(p);
+    }
+    Some(Aabb::from_min_max(min, max))
 }
 
 impl RenderAssets {
@@ -228,13 +380,17 @@ impl RenderAssets {
     ) -> MeshHandle {
         let vertex_buffer = create_vertex_buffer(device, &format!("{} VB", label), vertices);
         let index_buffer = create_index_buffer(device, &format!("{} IB", label), indices);
-        let handle = MeshHandle(next_id());
-        self.meshes.insert(handle, GpuMesh {
+        let handle = self.meshes.insert(GpuMesh {
             vertex_buffer,
             index_buffer,
             index_count: indices.len() as u32,
             index_format: IndexFormat::Uint16,
+            attribute_buffer: None,
+            attributes: VertexAttributes::NONE,
         });
+        if let Some(bounds) = vertex_bounds(vertices) {
+            self.mesh_bounds.insert(handle, bounds);
+        }
         handle
     }
 
@@ -248,23 +404,102 @@ impl RenderAssets {
     ) -> MeshHandle {
         let vertex_buffer = create_vertex_buffer(device, &format!("{} VB", label), vertices);
         let index_buffer = create_index_buffer_u32(device, &format!("{} IB", label), indices);
-        let handle = MeshHandle(next_id());
-        self.meshes.insert(handle, GpuMesh {
+        let handle = self.meshes.insert(GpuMesh {
             vertex_buffer,
             index_buffer,
             index_count: indices.len() as u32,
             index_format: IndexFormat::Uint32,
+            attribute_buffer: None,
+            attributes: VertexAttributes::NONE,
         });
+        if let Some(bounds) = vertex_bounds(vertices) {
+            self.mesh_bounds.insert(handle, bounds);
+        }
         handle
     }
 
+    /// 上传带可选属性顶点流的网格（u32 索引）并返回句柄
+    ///
+    /// `attributes` 与 `vertices` 一一对应，作为 slot 1 顶点流上传；`provided`
+    /// 标记其中哪些属性来自源数据（其余为默认值：白色 / 零 UV）。
+    pub fn upload_mesh_with_attributes<V: Vertex>(
+        &mut self,
+        device: &RenderDevice,
+        vertices: &[V],
+        attributes: &[AttributeVertex],
+        provided: VertexAttributes,
+        indices: &[u32],
+        label: &str,
+    ) -> Result<MeshHandle> {
+        if attributes.len() != vertices.len() {
+            return Err(AnvilKitError::render(format!(
+                "{}: 属性顶点数 {} 与顶点数 {} 不一致", label, attributes.len(), vertices.len()
+            )));
+        }
+        let handle = self.upload_mesh_u32(device, vertices, indices, label);
+        let attribute_buffer = create_vertex_buffer(device, &format!("{} Attributes VB", label), attributes);
+        if let Some(mesh) = self.meshes.get_mut(handle) {
+            mesh.attribute_buffer = Some(attribute_buffer);
+            mesh.attributes = provided;
+        }
+        Ok(handle)
+    }
+
     /// 注册渲染管线并返回句柄
     ///
     /// 注册后的管线可被多个材质共享引用。
     pub fn register_pipeline(&mut self, pipeline: RenderPipeline) -> PipelineHandle {
-        let handle = PipelineHandle(next_id());
-        self.pipelines.insert(handle, pipeline);
-        handle
+        self.pipelines.insert(PipelineEntry { pipeline: Some(pipeline), factory: None })
+    }
+
+    /// 通过构建函数注册渲染管线
+    ///
+    /// 构建函数会被保留：GPU 设备丢失并重建后，管线以同一句柄自动重新创建
+    /// （见 [`RenderDeviceRestored`](crate::renderer::RenderDeviceRestored)）。
+    ///
+    /// ```rust,ignore
+    /// let handle = assets.register_pipeline_with(device, |device| {
+    ///     Ok(RenderPipelineBuilder::new()
+    ///         .with_vertex_shader(SHADER)
+    ///         .with_fragment_shader(SHADER)
+    ///         .with_format(format)
+    ///         .build(device)?
+    ///         .into_pipeline())
+    /// })?;
+    /// ```
+    pub fn register_pipeline_with(
+        &mut self,
+        device: &RenderDevice,
+        factory: impl Fn(&RenderDevice) -> Result<RenderPipeline> + Send + Sync + 'static,
+    ) -> Result<PipelineHandle> {
+        let pipeline = factory(device)?;
+        Ok(self.pipelines.insert(PipelineEntry { pipeline: Some(pipeline), factory: Some(Box::new(factory)) }))
+    }
+
+    /// 设备丢失后重建：释放旧设备上的全部网格、材质与管线，
+    /// 用保留的构建函数在新设备上重新创建管线，返回重建成功的数量
+    ///
+    /// 网格、材质与无构建函数的管线句柄随之失效；有构建函数的管线保留原句柄。
+    pub(crate) fn rebuild_for_device(&mut self, device: &RenderDevice) -> usize {
+        self.meshes.clear();
+        self.mesh_bounds.clear();
+        self.materials.clear();
+        self.pipelines.retain(|_, entry| entry.factory.is_some());
+        let mut rebuilt = 0;
+        for (handle, entry) in self.pipelines.iter_mut() {
+            let Some(factory) = &entry.factory else { continue };
+            entry.pipeline = match factory(device) {
+                Ok(pipeline) => {
+                    rebuilt += 1;
+                    Some(pipeline)
+                }
+                Err(e) => {
+                    log::error!("重建管线 {:?} 失败: {}", handle, e);
+                    None
+                }
+            };
+        }
+        rebuilt
     }
 
     /// 创建引用共享管线的材质
@@ -278,12 +513,23 @@ impl RenderAssets {
         pipeline_handle: PipelineHandle,
         bind_group: BindGroup,
     ) -> MaterialHandle {
-        let handle = MaterialHandle(next_id());
-        self.materials.insert(handle, GpuMaterial {
+        self.create_material_with_attributes(pipeline_handle, bind_group, VertexAttributes::NONE)
+    }
+
+    /// 创建引用共享管线、并读取可选顶点属性的材质
+    ///
+    /// `vertex_attributes` 须与管线的 slot 1 顶点布局一致。
+    pub fn create_material_with_attributes(
+        &mut self,
+        pipeline_handle: PipelineHandle,
+        bind_group: BindGroup,
+        vertex_attributes: VertexAttributes,
+    ) -> MaterialHandle {
+        self.materials.insert(GpuMaterial {
             pipeline_handle,
             bind_group,
-        });
-        handle
+            vertex_attributes,
+        })
     }
 
     /// 创建材质并返回句柄（向后兼容 API）
@@ -300,27 +546,38 @@ impl RenderAssets {
 
     /// 获取 GPU 网格
     pub fn get_mesh(&self, handle: &MeshHandle) -> Option<&GpuMesh> {
-        self.meshes.get(handle)
+        self.meshes.get(*handle)
+    }
+
+    /// 获取网格的局部空间包围盒
+    pub fn mesh_bounds(&self, handle: &MeshHandle) -> Option<Aabb> {
+        self.mesh_bounds.get(handle).copied()
+    }
+
+    /// 覆盖网格的局部空间包围盒（例如顶点着色器会偏移顶点时手动放大）
+    pub fn set_mesh_bounds(&mut self, handle: MeshHandle, bounds: Aabb) {
+        self.mesh_bounds.insert(handle, bounds);
     }
 
     /// 获取 GPU 材质
     pub fn get_material(&self, handle: &MaterialHandle) -> Option<&GpuMaterial> {
-        self.materials.get(handle)
+        self.materials.get(*handle)
     }
 
     /// 获取渲染管线
     pub fn get_pipeline(&self, handle: &PipelineHandle) -> Option<&RenderPipeline> {
-        self.pipelines.get(handle)
+        self.pipelines.get(*handle).and_then(|entry| entry.pipeline.as_ref())
     }
 
     /// 移除 GPU 网格资源，释放顶点和索引缓冲区
     pub fn remove_mesh(&mut self, handle: &MeshHandle) -> bool {
-        self.meshes.remove(handle).is_some()
+        self.mesh_bounds.remove(handle);
+        self.meshes.remove(*handle).is_some()
     }
 
     /// 移除 GPU 材质资源，释放绑定组
     pub fn remove_material(&mut self, handle: &MaterialHandle) -> bool {
-        self.materials.remove(handle).is_some()
+        self.materials.remove(*handle).is_some()
     }
 
     /// 移除渲染管线
@@ -328,7 +585,7 @@ impl RenderAssets {
     /// 注意：如果仍有材质引用此管线，那些材质的渲染将失败。
     /// 调用者应确保先移除所有引用此管线的材质。
     pub fn remove_pipeline(&mut self, handle: &PipelineHandle) -> bool {
-        self.pipelines.remove(handle).is_some()
+        self.pipelines.remove(*handle).is_some()
     }
 
     /// 已注册的网格数量
@@ -343,7 +600,7 @@ impl RenderAssets {
 
     /// 已注册的管线数量
     pub fn pipeline_count(&self) -> usize {
-        self.pipelines.len()
+        self.pipelines.values().filter(|entry| entry.pipeline.is_some()).count()
     }
 }
 
@@ -396,4 +653,36 @@ mod tests {
         assert_eq!(cache.get(1), Some(11));
         assert_eq!(cache.len(), 1);
     }
+
+    #[test]
+    fn test_gpu_handles_are_generational() {
+        let mut meshes: SlotMap<MeshHandle, u32> = SlotMap::with_key();
+        let old = meshes.insert(1);
+        assert_eq!(MeshHandle::from_slot_key(old.slot_key()), old);
+        meshes.remove(old);
+        let new = meshes.insert(2);
+        // 槽位复用，但旧句柄不会指向新网格
+        assert_ne!(old, new);
+        assert_eq!(meshes.get(old), None);
+        assert_eq!(meshes.get(new), Some(&2));
+        assert_ne!(old.index(), 0);
+    }
+
+    #[test]
+    fn test_vertex_bounds_from_layout() {
+        use crate::renderer::buffer::{ColorVertex, MeshVertex};
+
+        let vertices = [
+            MeshVertex { position: [-1.0, 0.0, 2.0], normal: [0.0, 1.0, 0.0], texcoord: [9.0, 9.0] },
+            MeshVertex { position: [3.0, -4.0, 0.5], normal: [0.0, 1.0, 0.0], texcoord: [-9.0, -9.0] },
+        ];
+        let bounds = vertex_bounds(&vertices).unwrap();
+        assert_eq!(bounds.min, Vec3::new(-1.0, -4.0, 0.5));
+        assert_eq!(bounds.max, Vec3::new(3.0, 0.0, 2.0));
+
+        let colored = [ColorVertex { position: [0.5, 0.5, 0.5], color: [1.0, 0.0, 0.0] }];
+        let bounds = vertex_bounds(&colored).unwrap();
+        assert_eq!(bounds.min, bounds.max);
+        assert!(vertex_bounds::<MeshVertex>(&[]).is_none());
+    }
 }
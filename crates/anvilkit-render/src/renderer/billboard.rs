@@ -0,0 +1,134 @@
+//! # 公告板
+//!
+//! [`Billboard`] 使实体始终朝向活动相机：[`billboard_system`] 在变换传播之后改写
+//! `GlobalTransform` 的旋转，保留平移与缩放。与替身四边形一致，实体局部 -Z 面朝向相机。
+//!
+//! 可锁定旋转轴（例如锁定 Y 轴的树木、血条），此时只绕该轴转向相机。
+//!
+//! 注意：改写发生在传播之后，公告板实体的子节点不会跟随这一旋转。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::billboard::billboard_rotation;
+//! use glam::{Quat, Vec3};
+//!
+//! // 相机在 -Z 方向：无需旋转
+//! let rotation = billboard_rotation(Vec3::ZERO, Vec3::new(0.0, 0.0, -5.0), None).unwrap();
+//! assert!(rotation.abs_diff_eq(Quat::IDENTITY, 1e-6));
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::GlobalTransform;
+use glam::{Mat3, Mat4, Quat, Vec3};
+
+use super::draw::ActiveCamera;
+
+/// 公告板组件
+#[derive(Debug, Clone, Copy, PartialEq, Default, Component)]
+pub struct Billboard {
+    /// 锁定的旋转轴（世界空间）；`None` 时完全朝向相机
+    pub axis_lock: Option<Vec3>,
+}
+
+impl Billboard {
+    /// 完全朝向相机
+    pub const FACE_CAMERA: Self = Self { axis_lock: None };
+
+    /// 只绕世界 Y 轴转向相机
+    pub const UPRIGHT: Self = Self { axis_lock: Some(Vec3::Y) };
+
+    /// 只绕指定轴转向相机
+    pub fn locked(axis: Vec3) -> Self {
+        Self { axis_lock: Some(axis) }
+    }
+}
+
+/// 位于 `position` 的公告板朝向 `camera_pos` 所需的世界旋转
+///
+/// 相机与公告板重合，或锁定轴与视线平行时返回 `None`。
+pub fn billboard_rotation(position: Vec3, camera_pos: Vec3, axis_lock: Option<Vec3>) -> Option<Quat> {
+    let forward = (position - camera_pos).try_normalize()?;
+    let (right, up, forward) = match axis_lock {
+        None => {
+            let hint = if forward.y.abs() > 0.999 { Vec3::Z } else { Vec3::Y };
+            let right = hint.cross(forward).normalize();
+            (right, forward.cross(right), forward)
+        }
+        Some(axis) => {
+            let axis = axis.try_normalize()?;
+            let forward = (forward - axis * forward.dot(axis)).try_normalize()?;
+            (axis.cross(forward), axis, forward)
+        }
+    };
+    Some(Quat::from_mat3(&Mat3::from_cols(right, up, forward)))
+}
+
+/// 公告板系统 (PostUpdate，变换传播与相机更新之后)
+pub fn billboard_system(active_camera: Res<ActiveCamera>, mut query: Query<(&Billboard, &mut GlobalTransform)>) {
+    for (billboard, mut global_transform) in query.iter_mut() {
+        let (scale, _, translation) = global_transform.0.to_scale_rotation_translation();
+        if let Some(rotation) = billboard_rotation(translation, active_camera.camera_pos, billboard.axis_lock) {
+            global_transform.0 = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+        }
+    }
+}
+
+/// 公告板插件
+pub struct BillboardPlugin;
+
+impl Plugin for BillboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveCamera>().add_systems(
+            bevy_app::PostUpdate,
+            billboard_system
+                .after(crate::transform::propagate_transforms)
+                .after(crate::plugin::camera_system)
+                .before(crate::spatial::scene_bvh_system)
+                .before(crate::plugin::frustum_culling_system),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "BillboardPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_face_camera_points_minus_z_at_camera() {
+        let camera = Vec3::new(3.0, 4.0, -2.0);
+        let rotation = billboard_rotation(Vec3::ZERO, camera, None).unwrap();
+        let front = rotation * Vec3::NEG_Z;
+        assert!(front.abs_diff_eq(camera.normalize(), 1e-5));
+        // 相机正上方时仍能得到合法旋转
+        assert!(billboard_rotation(Vec3::ZERO, Vec3::Y * 5.0, None).unwrap().is_normalized());
+    }
+
+    #[test]
+    fn test_axis_lock_keeps_up() {
+        let camera = Vec3::new(5.0, 10.0, 0.0);
+        let rotation = billboard_rotation(Vec3::ZERO, camera, Some(Vec3::Y)).unwrap();
+        assert!((rotation * Vec3::Y).abs_diff_eq(Vec3::Y, 1e-5));
+        assert!((rotation * Vec3::NEG_Z).abs_diff_eq(Vec3::X, 1e-5));
+        // 视线与锁定轴平行
+        assert!(billboard_rotation(Vec3::ZERO, Vec3::Y, Some(Vec3::Y)).is_none());
+    }
+
+    #[test]
+    fn test_system_preserves_scale_and_translation() {
+        let mut app = App::new();
+        app.add_plugins(BillboardPlugin);
+        app.insert_resource(ActiveCamera { camera_pos: Vec3::new(10.0, 0.0, 0.0), ..Default::default() });
+        let transform = Mat4::from_scale_rotation_translation(Vec3::splat(2.0), Quat::IDENTITY, Vec3::new(0.0, 1.0, 0.0));
+        let e = app.world_mut().spawn((Billboard::UPRIGHT, GlobalTransform(transform))).id();
+        app.update();
+
+        let (scale, rotation, translation) = app.world().get::<GlobalTransform>(e).unwrap().0.to_scale_rotation_translation();
+        assert!(scale.abs_diff_eq(Vec3::splat(2.0), 1e-5));
+        assert!(translation.abs_diff_eq(Vec3::new(0.0, 1.0, 0.0), 1e-5));
+        assert!((rotation * Vec3::NEG_Z).abs_diff_eq(Vec3::X, 1e-5));
+    }
+}
@@ -4,12 +4,14 @@
 
 use wgpu::{Buffer, BufferUsages, VertexBufferLayout, VertexStepMode, VertexAttribute, VertexFormat};
 use bytemuck::{Pod, Zeroable};
+use anvilkit_core::collections::Istr;
 
 use crate::renderer::RenderDevice;
 
 /// 顶点数据 trait
 ///
 /// 实现此 trait 的类型可以安全地用作 GPU 顶点缓冲区数据。
+/// 通常用 [`impl_vertex!`](crate::impl_vertex) 实现：按声明顺序紧密排列属性并自动计算偏移。
 ///
 /// # 示例
 ///
@@ -18,10 +20,81 @@ use crate::renderer::RenderDevice;
 ///
 /// let layout = ColorVertex::layout();
 /// assert_eq!(layout.array_stride, std::mem::size_of::<ColorVertex>() as u64);
+/// assert_eq!(ColorVertex::ATTRIBUTES[1].offset, 12);
 /// ```
 pub trait Vertex: Pod + Zeroable {
+    /// 顶点属性（偏移、着色器 location、格式）
+    const ATTRIBUTES: &'static [VertexAttribute];
+
+    /// 步进模式：逐顶点或逐实例
+    const STEP_MODE: VertexStepMode = VertexStepMode::Vertex;
+
     /// 返回此顶点类型的缓冲区布局描述
-    fn layout() -> VertexBufferLayout<'static>;
+    fn layout() -> VertexBufferLayout<'static> {
+        VertexBufferLayout {
+            array_stride: std::mem::size_of::<Self>() as u64,
+            step_mode: Self::STEP_MODE,
+            attributes: Self::ATTRIBUTES,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub use wgpu::{vertex_attr_array as __vertex_attr_array, VertexAttribute as __VertexAttribute, VertexStepMode as __VertexStepMode};
+
+/// 为紧密排列的 `#[repr(C)]` 顶点类型实现 [`Vertex`]
+///
+/// 属性按声明顺序排列，偏移由格式大小自动累加；编译期检查属性总大小
+/// 与结构体大小一致（字段与格式不匹配时编译失败）。第二个参数可选
+/// `Instance` 表示逐实例数据。含填充字段的类型请手动实现 [`Vertex`]。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::impl_vertex;
+/// use anvilkit_render::renderer::buffer::Vertex;
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct GrassVertex {
+///     position: [f32; 3],
+///     sway: f32,
+///     color: [u8; 4],
+/// }
+/// impl_vertex!(GrassVertex, [0 => Float32x3, 1 => Float32, 2 => Unorm8x4]);
+///
+/// #[repr(C)]
+/// #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+/// struct GrassInstance {
+///     offset: [f32; 4],
+/// }
+/// impl_vertex!(GrassInstance, Instance, [3 => Float32x4]);
+///
+/// assert_eq!(GrassVertex::ATTRIBUTES[2].offset, 16);
+/// assert_eq!(GrassInstance::layout().step_mode, wgpu::VertexStepMode::Instance);
+/// ```
+#[macro_export]
+macro_rules! impl_vertex {
+    ($ty:ty, [$($location:literal => $format:ident),+ $(,)?]) => {
+        $crate::impl_vertex!($ty, Vertex, [$($location => $format),+]);
+    };
+    ($ty:ty, $step:ident, [$($location:literal => $format:ident),+ $(,)?]) => {
+        impl $crate::renderer::buffer::Vertex for $ty {
+            const ATTRIBUTES: &'static [$crate::renderer::buffer::__VertexAttribute] =
+                &$crate::renderer::buffer::__vertex_attr_array![$($location => $format),+];
+            const STEP_MODE: $crate::renderer::buffer::__VertexStepMode =
+                $crate::renderer::buffer::__VertexStepMode::$step;
+        }
+
+        const _: () = {
+            let attributes = <$ty as $crate::renderer::buffer::Vertex>::ATTRIBUTES;
+            let last = &attributes[attributes.len() - 1];
+            assert!(
+                last.offset + last.format.size() == ::std::mem::size_of::<$ty>() as u64,
+                concat!("impl_vertex!: attribute sizes do not add up to size_of::<", stringify!($ty), ">()"),
+            );
+        };
+    };
 }
 
 /// 带颜色的顶点
@@ -55,30 +128,7 @@ pub struct ColorVertex {
     pub color: [f32; 3],
 }
 
-impl Vertex for ColorVertex {
-    fn layout() -> VertexBufferLayout<'static> {
-        const ATTRIBUTES: &[VertexAttribute] = &[
-            // position: location 0
-            VertexAttribute {
-                offset: 0,
-                shader_location: 0,
-                format: VertexFormat::Float32x3,
-            },
-            // color: location 1
-            VertexAttribute {
-                offset: std::mem::size_of::<[f32; 3]>() as u64,
-                shader_location: 1,
-                format: VertexFormat::Float32x3,
-            },
-        ];
-
-        VertexBufferLayout {
-            array_stride: std::mem::size_of::<ColorVertex>() as u64,
-            step_mode: VertexStepMode::Vertex,
-            attributes: ATTRIBUTES,
-        }
-    }
-}
+crate::impl_vertex!(ColorVertex, [0 => Float32x3, 1 => Float32x3]);
 
 /// 网格顶点（位置 + 法线 + 纹理坐标）
 ///
@@ -115,33 +165,7 @@ pub struct MeshVertex {
     pub texcoord: [f32; 2],
 }
 
-impl Vertex for MeshVertex {
-    fn layout() -> VertexBufferLayout<'static> {
-        const ATTRIBUTES: &[VertexAttribute] = &[
-            VertexAttribute {
-                offset: 0,
-                shader_location: 0,
-                format: VertexFormat::Float32x3,
-            },
-            VertexAttribute {
-                offset: 12,
-                shader_location: 1,
-                format: VertexFormat::Float32x3,
-            },
-            VertexAttribute {
-                offset: 24,
-                shader_location: 2,
-                format: VertexFormat::Float32x2,
-            },
-        ];
-
-        VertexBufferLayout {
-            array_stride: std::mem::size_of::<MeshVertex>() as u64,
-            step_mode: VertexStepMode::Vertex,
-            attributes: ATTRIBUTES,
-        }
-    }
-}
+crate::impl_vertex!(MeshVertex, [0 => Float32x3, 1 => Float32x3, 2 => Float32x2]);
 
 /// PBR 顶点（位置 + 法线 + 纹理坐标 + 切线）
 ///
@@ -182,39 +206,124 @@ pub struct PbrVertex {
     pub tangent: [f32; 4],
 }
 
-impl Vertex for PbrVertex {
-    fn layout() -> VertexBufferLayout<'static> {
-        const ATTRIBUTES: &[VertexAttribute] = &[
-            VertexAttribute {
-                offset: 0,
-                shader_location: 0,
-                format: VertexFormat::Float32x3,
-            },
-            VertexAttribute {
-                offset: 12,
-                shader_location: 1,
-                format: VertexFormat::Float32x3,
-            },
-            VertexAttribute {
-                offset: 24,
-                shader_location: 2,
-                format: VertexFormat::Float32x2,
-            },
-            VertexAttribute {
-                offset: 32,
-                shader_location: 3,
-                format: VertexFormat::Float32x4,
-            },
-        ];
+crate::impl_vertex!(PbrVertex, [0 => Float32x3, 1 => Float32x3, 2 => Float32x2, 3 => Float32x4]);
 
-        VertexBufferLayout {
-            array_stride: std::mem::size_of::<PbrVertex>() as u64,
-            step_mode: VertexStepMode::Vertex,
-            attributes: ATTRIBUTES,
+/// 网格可选顶点属性集合
+///
+/// 决定 PBR 管线是否读取第二个顶点流（slot 1，[`AttributeVertex`]）中的
+/// 顶点颜色 / UV1，并参与管线变体 key。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::renderer::buffer::VertexAttributes;
+///
+/// let attrs = VertexAttributes::COLORS | VertexAttributes::UV1;
+/// assert!(attrs.colors && attrs.uv1);
+/// assert_eq!(attrs.bits(), 0b11);
+/// assert!(VertexAttributes::NONE.is_empty());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VertexAttributes {
+    /// 顶点颜色（location 4，与基础色相乘）
+    pub colors: bool,
+    /// 第二套纹理坐标（location 5，光照贴图等）
+    pub uv1: bool,
+}
+
+impl VertexAttributes {
+    /// 无可选属性（仅 slot 0 的 [`PbrVertex`]）
+    pub const NONE: Self = Self { colors: false, uv1: false };
+    /// 仅顶点颜色
+    pub const COLORS: Self = Self { colors: true, uv1: false };
+    /// 仅 UV1
+    pub const UV1: Self = Self { colors: false, uv1: true };
+
+    /// 是否未启用任何可选属性
+    pub fn is_empty(self) -> bool {
+        !self.colors && !self.uv1
+    }
+
+    /// 紧凑位表示（bit 0 = colors, bit 1 = uv1），用于管线 key
+    pub fn bits(self) -> u64 {
+        self.colors as u64 | (self.uv1 as u64) << 1
+    }
+
+    /// 启用的着色器定义（见 [`shader_defs`](crate::renderer::shader_defs)）
+    pub fn shader_defs(self) -> Vec<Istr> {
+        let mut defs = Vec::new();
+        if self.colors {
+            defs.push(Istr::new("VERTEX_COLOR"));
         }
+        if self.uv1 {
+            defs.push(Istr::new("VERTEX_UV1"));
+        }
+        defs
+    }
+
+    /// slot 1 顶点流布局，只包含启用的属性（stride 不变）；为空时返回 `None`
+    pub fn layout(self) -> Option<VertexBufferLayout<'static>> {
+        const COLOR: VertexAttribute = VertexAttribute {
+            offset: 0,
+            shader_location: 4,
+            format: VertexFormat::Float32x4,
+        };
+        const UV1: VertexAttribute = VertexAttribute {
+            offset: 16,
+            shader_location: 5,
+            format: VertexFormat::Float32x2,
+        };
+        let attributes: &'static [VertexAttribute] = match (self.colors, self.uv1) {
+            (false, false) => return None,
+            (true, false) => &[COLOR],
+            (false, true) => &[UV1],
+            (true, true) => &[COLOR, UV1],
+        };
+        Some(VertexBufferLayout {
+            array_stride: std::mem::size_of::<AttributeVertex>() as u64,
+            step_mode: VertexStepMode::Vertex,
+            attributes,
+        })
+    }
+}
+
+impl std::ops::BitOr for VertexAttributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self { colors: self.colors || rhs.colors, uv1: self.uv1 || rhs.uv1 }
+    }
+}
+
+/// 可选属性顶点（第二个顶点流）
+///
+/// 与 [`PbrVertex`] 一一对应，单独上传到 slot 1，使不带可选属性的
+/// 管线（以及阴影 pass）继续只绑定 slot 0。
+///
+/// # 内存布局
+///
+/// | 偏移 | 属性 | 格式 |
+/// |------|------|------|
+/// | 0 | color | Float32x4 |
+/// | 16 | uv1 | Float32x2 |
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct AttributeVertex {
+    /// 顶点颜色 (linear RGBA)
+    pub color: [f32; 4],
+    /// 第二套纹理坐标 (u, v)
+    pub uv1: [f32; 2],
+}
+
+impl Default for AttributeVertex {
+    fn default() -> Self {
+        Self { color: [1.0; 4], uv1: [0.0; 2] }
     }
 }
 
+// 完整布局（颜色 + UV1）；管线按需使用 [`VertexAttributes::layout`] 裁剪
+crate::impl_vertex!(AttributeVertex, [4 => Float32x4, 5 => Float32x2]);
+
 /// 带骨骼蒙皮的 PBR 顶点
 ///
 /// 在 PbrVertex 基础上增加 joint_indices 和 joint_weights，
@@ -247,24 +356,14 @@ pub struct SkinnedVertex {
     pub joint_weights: [f32; 4],
 }
 
-impl Vertex for SkinnedVertex {
-    fn layout() -> VertexBufferLayout<'static> {
-        const ATTRIBUTES: &[VertexAttribute] = &[
-            VertexAttribute { offset: 0, shader_location: 0, format: VertexFormat::Float32x3 },
-            VertexAttribute { offset: 12, shader_location: 1, format: VertexFormat::Float32x3 },
-            VertexAttribute { offset: 24, shader_location: 2, format: VertexFormat::Float32x2 },
-            VertexAttribute { offset: 32, shader_location: 3, format: VertexFormat::Float32x4 },
-            VertexAttribute { offset: 48, shader_location: 4, format: VertexFormat::Uint16x4 },
-            VertexAttribute { offset: 56, shader_location: 5, format: VertexFormat::Float32x4 },
-        ];
-
-        VertexBufferLayout {
-            array_stride: std::mem::size_of::<SkinnedVertex>() as u64,
-            step_mode: VertexStepMode::Vertex,
-            attributes: ATTRIBUTES,
-        }
-    }
-}
+crate::impl_vertex!(SkinnedVertex, [
+    0 => Float32x3,
+    1 => Float32x3,
+    2 => Float32x2,
+    3 => Float32x4,
+    4 => Uint16x4,
+    5 => Float32x4,
+]);
 
 /// 创建顶点缓冲区
 ///
@@ -948,6 +1047,46 @@ mod tests {
         assert_eq!(layout.attributes[1].shader_location, 1);
     }
 
+    #[test]
+    fn test_vertex_attributes_layout() {
+        assert_eq!(std::mem::size_of::<AttributeVertex>(), 24);
+        assert!(VertexAttributes::NONE.layout().is_none());
+        let uv1 = VertexAttributes::UV1.layout().unwrap();
+        assert_eq!(uv1.array_stride, 24);
+        assert_eq!(uv1.attributes.len(), 1);
+        assert_eq!(uv1.attributes[0].shader_location, 5);
+        assert_eq!(uv1.attributes[0].offset, 16);
+        let both = (VertexAttributes::COLORS | VertexAttributes::UV1).layout().unwrap();
+        assert_eq!(both.attributes.len(), 2);
+        assert_eq!(VertexAttributes::COLORS.shader_defs(), vec![Istr::new("VERTEX_COLOR")]);
+    }
+
+    #[test]
+    fn test_impl_vertex_offsets_and_step_mode() {
+        let skinned = SkinnedVertex::layout();
+        let offsets: Vec<u64> = skinned.attributes.iter().map(|a| a.offset).collect();
+        assert_eq!(offsets, vec![0, 12, 24, 32, 48, 56]);
+        assert_eq!(skinned.attributes[4].format, VertexFormat::Uint16x4);
+        assert_eq!(skinned.step_mode, VertexStepMode::Vertex);
+        assert_eq!(
+            AttributeVertex::layout().attributes,
+            (VertexAttributes::COLORS | VertexAttributes::UV1).layout().unwrap().attributes,
+        );
+
+        #[repr(C)]
+        #[derive(Copy, Clone, Pod, Zeroable)]
+        struct Instance {
+            offset: [f32; 3],
+            scale: f32,
+        }
+        crate::impl_vertex!(Instance, Instance, [7 => Float32x3, 8 => Float32]);
+        let layout = Instance::layout();
+        assert_eq!(layout.step_mode, VertexStepMode::Instance);
+        assert_eq!(layout.array_stride, 16);
+        assert_eq!(layout.attributes[1].offset, 12);
+        assert_eq!(layout.attributes[1].shader_location, 8);
+    }
+
     #[test]
     fn test_color_vertex_creation() {
         let v = ColorVertex {
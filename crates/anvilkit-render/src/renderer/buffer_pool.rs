@@ -22,7 +22,7 @@ use wgpu::{Buffer, BufferUsages, Device};
 /// A vertex buffer will never be returned for an index buffer request.
 pub struct BufferPool {
     /// 可用缓冲区池 (buffer, capacity_bytes, usage, last_used)
-    available: Vec<(Buffer, u64, BufferUsages, std::time::Instant)>,
+    available: Vec<(Buffer, u64, BufferUsages, anvilkit_core::time::Instant)>,
     /// 本帧使用中的缓冲区数量（用于统计）
     in_use_count: usize,
     /// 池上限
@@ -85,7 +85,7 @@ impl BufferPool {
             }
         }
 
-        self.available.push((buffer, capacity, usage, std::time::Instant::now()));
+        self.available.push((buffer, capacity, usage, anvilkit_core::time::Instant::now()));
     }
 
     /// 当前池中可用缓冲区数量
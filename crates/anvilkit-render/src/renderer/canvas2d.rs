@@ -216,9 +216,9 @@ impl<'a> Canvas2D<'a> {
         render_app: &'a mut crate::window::events::RenderApp,
         renderer: &'a mut Canvas2DRenderer,
     ) -> Option<Self> {
+        let frame = render_app.get_current_frame()?;
         let device = render_app.render_device()?;
         let (w, h) = render_app.window_state().size();
-        let frame = render_app.get_current_frame()?;
         let swapchain_view = frame.texture.create_view(&Default::default());
 
         Some(Canvas2D {
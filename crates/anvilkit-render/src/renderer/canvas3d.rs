@@ -245,10 +245,10 @@ impl<'a> Canvas3D<'a> {
         render_app: &'a mut crate::window::events::RenderApp,
         renderer: &'a mut Canvas3DRenderer,
     ) -> Option<Self> {
+        let frame = render_app.get_current_frame()?;
         let device = render_app.render_device()?;
         let (w, h) = render_app.window_state().size();
         renderer.resize(device, w, h);
-        let frame = render_app.get_current_frame()?;
         let swapchain_view = frame.texture.create_view(&Default::default());
 
         Some(Canvas3D {
@@ -8,6 +8,8 @@ use bevy_ecs::prelude::Resource;
 use anvilkit_describe::Describe;
 use log::info;
 
+use crate::renderer::render_scale::MAX_RENDER_SCALE;
+
 /// 帧捕获状态（ECS Resource）
 ///
 /// 控制截图和录制行为。插入到 ECS World 后由渲染循环自动检测。
@@ -40,6 +42,9 @@ pub struct CaptureState {
     pub auto_exit: bool,
     /// 内部标志：请求退出
     pub exit_requested: bool,
+    /// 截图超采样倍数：以 `窗口尺寸 × scale` 渲染场景后缩小到窗口分辨率输出
+    /// （1 = 不超采样，上限 [`MAX_RENDER_SCALE`]；截图完成后重置为 1）
+    pub scale: u32,
 }
 
 impl Default for CaptureState {
@@ -52,6 +57,7 @@ impl Default for CaptureState {
             frame_count: 0,
             auto_exit: false,
             exit_requested: false,
+            scale: 1,
         }
     }
 }
@@ -82,11 +88,21 @@ impl CaptureState {
             || (self.recording && (self.max_frames == 0 || self.frame_count < self.max_frames))
     }
 
+    /// 本帧截图的超采样渲染缩放；无待处理截图或 `scale <= 1` 时为 `None`
+    ///
+    /// 只作用于单帧截图，录帧与 [`FrameStream`](crate::renderer::frame_stream::FrameStream)
+    /// 保持原始渲染缩放。
+    pub fn supersample_scale(&self) -> Option<f32> {
+        (self.screenshot_path.is_some() && self.scale > 1)
+            .then(|| (self.scale as f32).min(MAX_RENDER_SCALE))
+    }
+
     /// 处理一帧捕获完成后的状态更新
     pub fn on_frame_captured(&mut self) {
-        // 单帧截图：清除请求
+        // 单帧截图：清除请求并恢复超采样倍数
         if self.screenshot_path.is_some() {
             self.screenshot_path = None;
+            self.scale = 1;
         }
 
         // 录帧模式：递增计数
@@ -351,6 +367,23 @@ mod tests {
         assert!(!state.recording);
     }
 
+    #[test]
+    fn test_capture_state_supersample_resets() {
+        let mut state = CaptureState::recording("/tmp/frames", 0);
+        state.scale = 2;
+        assert_eq!(state.supersample_scale(), None);
+
+        state.screenshot_path = Some("photo.png".into());
+        assert_eq!(state.supersample_scale(), Some(2.0));
+        state.scale = 8;
+        assert_eq!(state.supersample_scale(), Some(MAX_RENDER_SCALE));
+
+        state.on_frame_captured();
+        assert_eq!(state.scale, 1);
+        assert_eq!(state.supersample_scale(), None);
+        assert!(state.recording);
+    }
+
     #[test]
     fn test_capture_state_recording() {
         let mut state = CaptureState::recording("/tmp/frames", 3);
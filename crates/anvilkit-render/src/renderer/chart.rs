@@ -0,0 +1,591 @@
+//! # 图表控件
+//!
+//! 基于矢量渲染器（[`crate::renderer::vector`]）的绘图控件，可用于调试叠加层与游戏内界面（如统计画面）：
+//!
+//! - [`LinePlot`]：滚动折线图，新数据从右侧进入，可选填充折线下方区域
+//! - [`Histogram`]：样本分布直方图（如帧时间分布）
+//! - [`BarChart`]：逐条着色的柱状图，支持负值（以 0 为基线）
+//!
+//! 图表在局部空间中以左上角为原点、y 向下绘制，位置由实体的 `Transform` 决定。
+//! 挂上 [`PlotSource`] 后，折线图与直方图每帧从 [`Diagnostics`] 同步对应指标的历史数据。
+//! 图表组件变化时由 [`chart_mesh_system`] 重新生成 [`VectorMesh`]；
+//! 自定义图表实现 [`Chart`] 并注册 `chart_mesh_system::<T>` 即可。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::chart::{Chart, LinePlot, PlotRange};
+//! use glam::Vec2;
+//!
+//! let mut plot = LinePlot::new(Vec2::new(200.0, 50.0), 120).with_range(PlotRange::Fixed { min: 0.0, max: 33.3 });
+//! for ms in [16.6, 17.0, 15.9, 33.0] {
+//!     plot.push(ms);
+//! }
+//! let mesh = plot.mesh();
+//! assert!(!mesh.is_empty());
+//! ```
+
+use std::collections::VecDeque;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::diagnostics::Diagnostics;
+use anvilkit_core::math::Color;
+use glam::Vec2;
+
+use super::vector::{vector_collect_system, Fill, LineJoin, Path, PathCommand, Stroke, VectorMesh, VectorPlugin};
+
+/// 数值轴范围
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PlotRange {
+    /// 从 0 到数据最大值（负数据时延伸到最小值）
+    #[default]
+    AutoZero,
+    /// 数据最小值到最大值
+    Auto,
+    /// 固定范围，超出的数据被截断到边缘
+    Fixed {
+        /// 下限
+        min: f32,
+        /// 上限
+        max: f32,
+    },
+}
+
+impl PlotRange {
+    /// 按数据求出实际范围，保证 `max > min`
+    pub fn resolve(&self, values: impl IntoIterator<Item = f32>) -> (f32, f32) {
+        let (min, max) = match *self {
+            PlotRange::Fixed { min, max } => (min, max),
+            auto => {
+                let (lo, hi) = values
+                    .into_iter()
+                    .filter(|v| v.is_finite())
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)));
+                if lo > hi {
+                    (0.0, 1.0)
+                } else if auto == PlotRange::AutoZero {
+                    (lo.min(0.0), hi.max(0.0))
+                } else {
+                    (lo, hi)
+                }
+            }
+        };
+        if max - min > f32::EPSILON {
+            (min, max)
+        } else if min == 0.0 {
+            (0.0, 1.0)
+        } else {
+            (min - 0.5, min + 0.5)
+        }
+    }
+}
+
+/// 数值映射到局部 y（顶部为最大值）
+fn value_y(value: f32, (min, max): (f32, f32), height: f32) -> f32 {
+    height - ((value - min) / (max - min)).clamp(0.0, 1.0) * height
+}
+
+/// 图表背景与网格样式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartStyle {
+    /// 背景色，`None` 表示透明
+    pub background: Option<Color>,
+    /// 水平网格线颜色
+    pub grid_color: Color,
+    /// 纵向等分数，`n` 等分绘制 `n - 1` 条网格线
+    pub grid_divisions: u32,
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        Self {
+            background: Some(Color::rgba(0.02, 0.02, 0.03, 0.85)),
+            grid_color: Color::rgba(0.4, 0.4, 0.4, 0.35),
+            grid_divisions: 4,
+        }
+    }
+}
+
+impl ChartStyle {
+    /// 无背景、无网格（叠加在已有面板上时使用）
+    pub fn bare() -> Self {
+        Self { background: None, grid_divisions: 0, ..Default::default() }
+    }
+
+    /// 背景与网格
+    fn draw(&self, size: Vec2, mesh: &mut VectorMesh) {
+        if let Some(background) = self.background {
+            mesh.add_fill(&Path::rect(Vec2::ZERO, size), &Fill::color(background));
+        }
+        let mut grid = Vec::new();
+        for i in 1..self.grid_divisions {
+            let y = (size.y * i as f32 / self.grid_divisions as f32).round();
+            grid.extend(Path::rect(Vec2::new(0.0, y - 0.5), Vec2::new(size.x, 1.0)).commands);
+        }
+        mesh.add_fill(&Path::from_commands(grid), &Fill::color(self.grid_color));
+    }
+}
+
+/// 由若干矩形组成的路径
+fn rects_path(rects: impl IntoIterator<Item = (Vec2, Vec2)>) -> Path {
+    Path::from_commands(
+        rects.into_iter().flat_map(|(origin, size)| Path::rect(origin, size).commands).collect::<Vec<_>>(),
+    )
+}
+
+/// 柱状几何：`(左上, 尺寸)`，以 0 为基线（0 不在范围内时取最近的边缘）
+///
+/// `gap` 为柱间空隙占每格宽度的比例。
+pub fn bar_rects(values: &[f32], range: (f32, f32), size: Vec2, gap: f32) -> Vec<(Vec2, Vec2)> {
+    let slot = size.x / values.len().max(1) as f32;
+    let inset = slot * gap.clamp(0.0, 0.95) * 0.5;
+    let baseline = value_y(0.0, range, size.y);
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| {
+            let y = value_y(value, range, size.y);
+            let (top, bottom) = (y.min(baseline), y.max(baseline));
+            (Vec2::new(i as f32 * slot + inset, top), Vec2::new(slot - 2.0 * inset, bottom - top))
+        })
+        .collect()
+}
+
+/// 可由 [`chart_mesh_system`] 生成矢量网格的图表组件
+pub trait Chart: Component {
+    /// 生成局部空间网格（左上角为原点，y 向下）
+    fn mesh(&self) -> VectorMesh;
+}
+
+/// 滚动折线图组件
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct LinePlot {
+    /// 数据，最旧的在前
+    pub values: VecDeque<f32>,
+    /// 保留的最大数据点数（横轴满格）
+    pub capacity: usize,
+    /// 尺寸（像素）
+    pub size: Vec2,
+    /// 纵轴范围
+    pub range: PlotRange,
+    /// 折线颜色
+    pub color: Color,
+    /// 折线宽度
+    pub line_width: f32,
+    /// 折线下方区域的填充色
+    pub area: Option<Color>,
+    /// 背景与网格
+    pub style: ChartStyle,
+}
+
+impl LinePlot {
+    /// 指定尺寸与容量的空折线图
+    pub fn new(size: Vec2, capacity: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(2),
+            size,
+            range: PlotRange::default(),
+            color: Color::rgb(0.3, 0.75, 1.0),
+            line_width: 1.5,
+            area: None,
+            style: ChartStyle::default(),
+        }
+    }
+
+    /// 设置折线颜色
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// 填充折线下方区域
+    pub fn with_area(mut self, color: Color) -> Self {
+        self.area = Some(color);
+        self
+    }
+
+    /// 设置纵轴范围
+    pub fn with_range(mut self, range: PlotRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// 设置背景与网格
+    pub fn with_style(mut self, style: ChartStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 追加一个数据点，超出容量时丢弃最旧的
+    pub fn push(&mut self, value: f32) {
+        while self.values.len() >= self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// 折线顶点（局部空间），最新的数据点位于右边缘
+    pub fn points(&self) -> Vec<Vec2> {
+        let range = self.range.resolve(self.values.iter().copied());
+        let step = self.size.x / (self.capacity.max(2) - 1) as f32;
+        let newest = self.values.len().saturating_sub(1);
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| Vec2::new(self.size.x - (newest - i) as f32 * step, value_y(v, range, self.size.y)))
+            .collect()
+    }
+}
+
+impl Chart for LinePlot {
+    fn mesh(&self) -> VectorMesh {
+        let mut mesh = VectorMesh::default();
+        self.style.draw(self.size, &mut mesh);
+        let points = self.points();
+        let (Some(&first), Some(&last)) = (points.first(), points.last()) else { return mesh };
+        if let Some(area) = self.area {
+            let mut commands = vec![PathCommand::MoveTo(Vec2::new(first.x, self.size.y))];
+            commands.extend(points.iter().map(|&p| PathCommand::LineTo(p)));
+            commands.extend([PathCommand::LineTo(Vec2::new(last.x, self.size.y)), PathCommand::Close]);
+            mesh.add_fill(&Path::from_commands(commands), &Fill::color(area));
+        }
+        let mut commands = vec![PathCommand::MoveTo(first)];
+        commands.extend(points[1..].iter().map(|&p| PathCommand::LineTo(p)));
+        mesh.add_stroke(
+            &Path::from_commands(commands),
+            &Stroke::new(self.color, self.line_width).with_join(LineJoin::Bevel),
+        );
+        mesh
+    }
+}
+
+/// 统计样本落入各区间的数量，超出范围的样本计入两端的区间
+pub fn histogram_counts(samples: impl IntoIterator<Item = f32>, bins: usize, (min, max): (f32, f32)) -> Vec<u32> {
+    let mut counts = vec![0u32; bins];
+    if bins == 0 {
+        return counts;
+    }
+    for sample in samples.into_iter().filter(|v| v.is_finite()) {
+        let t = ((sample - min) / (max - min)).clamp(0.0, 1.0);
+        counts[((t * bins as f32) as usize).min(bins - 1)] += 1;
+    }
+    counts
+}
+
+/// 直方图组件
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct Histogram {
+    /// 样本，最旧的在前
+    pub samples: VecDeque<f32>,
+    /// 保留的最大样本数
+    pub capacity: usize,
+    /// 区间数
+    pub bins: usize,
+    /// 横轴（样本值）范围
+    pub range: PlotRange,
+    /// 尺寸（像素）
+    pub size: Vec2,
+    /// 柱子颜色
+    pub color: Color,
+    /// 背景与网格
+    pub style: ChartStyle,
+}
+
+impl Histogram {
+    /// 指定尺寸与区间数的空直方图（保留最近 240 个样本）
+    pub fn new(size: Vec2, bins: usize) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            capacity: 240,
+            bins: bins.max(1),
+            range: PlotRange::default(),
+            size,
+            color: Color::rgb(0.95, 0.8, 0.2),
+            style: ChartStyle::default(),
+        }
+    }
+
+    /// 设置样本范围
+    pub fn with_range(mut self, range: PlotRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// 设置柱子颜色
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// 设置背景与网格
+    pub fn with_style(mut self, style: ChartStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// 追加一个样本，超出容量时丢弃最旧的
+    pub fn push(&mut self, sample: f32) {
+        while self.samples.len() >= self.capacity.max(1) {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// 各区间的样本数
+    pub fn counts(&self) -> Vec<u32> {
+        let range = self.range.resolve(self.samples.iter().copied());
+        histogram_counts(self.samples.iter().copied(), self.bins, range)
+    }
+}
+
+impl Chart for Histogram {
+    fn mesh(&self) -> VectorMesh {
+        let mut mesh = VectorMesh::default();
+        self.style.draw(self.size, &mut mesh);
+        let counts: Vec<f32> = self.counts().into_iter().map(|c| c as f32).collect();
+        let peak = counts.iter().copied().fold(0.0, f32::max);
+        let rects = bar_rects(&counts, (0.0, peak.max(1.0)), self.size, 0.1);
+        mesh.add_fill(&rects_path(rects), &Fill::color(self.color));
+        mesh
+    }
+}
+
+/// 柱状图中的一根柱子
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    /// 数值
+    pub value: f32,
+    /// 颜色
+    pub color: Color,
+}
+
+/// 柱状图组件
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct BarChart {
+    /// 柱子，从左到右
+    pub bars: Vec<Bar>,
+    /// 纵轴范围
+    pub range: PlotRange,
+    /// 尺寸（像素）
+    pub size: Vec2,
+    /// 柱间空隙占每格宽度的比例
+    pub gap: f32,
+    /// 背景与网格
+    pub style: ChartStyle,
+}
+
+impl BarChart {
+    /// 指定尺寸的空柱状图
+    pub fn new(size: Vec2) -> Self {
+        Self { bars: Vec::new(), range: PlotRange::default(), size, gap: 0.2, style: ChartStyle::default() }
+    }
+
+    /// 追加一根柱子
+    pub fn with_bar(mut self, value: f32, color: Color) -> Self {
+        self.bars.push(Bar { value, color });
+        self
+    }
+
+    /// 设置纵轴范围
+    pub fn with_range(mut self, range: PlotRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// 设置背景与网格
+    pub fn with_style(mut self, style: ChartStyle) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Chart for BarChart {
+    fn mesh(&self) -> VectorMesh {
+        let mut mesh = VectorMesh::default();
+        self.style.draw(self.size, &mut mesh);
+        let values: Vec<f32> = self.bars.iter().map(|bar| bar.value).collect();
+        let range = self.range.resolve(values.iter().copied());
+        for (bar, rect) in self.bars.iter().zip(bar_rects(&values, range, self.size, self.gap)) {
+            mesh.add_fill(&rects_path([rect]), &Fill::color(bar.color));
+        }
+        mesh
+    }
+}
+
+/// 把图表绑定到诊断指标（[`LinePlot`] 与 [`Histogram`] 有效）
+#[derive(Debug, Clone, PartialEq, Eq, Component)]
+pub struct PlotSource {
+    /// 指标名，如 [`anvilkit_core::diagnostics::FRAME_TIME`]
+    pub metric: String,
+}
+
+impl PlotSource {
+    /// 绑定指定指标
+    pub fn new(metric: impl Into<String>) -> Self {
+        Self { metric: metric.into() }
+    }
+}
+
+/// 指标历史中最近的 `capacity` 个值
+fn history_tail(history: &VecDeque<f64>, capacity: usize) -> VecDeque<f32> {
+    history.iter().skip(history.len().saturating_sub(capacity)).map(|&v| v as f32).collect()
+}
+
+/// 诊断同步系统 (PostUpdate)
+///
+/// 用指标历史替换绑定图表的数据；数据未变时不触发重建。
+pub fn chart_source_system(
+    diagnostics: Option<Res<Diagnostics>>,
+    mut charts: Query<(&PlotSource, Option<&mut LinePlot>, Option<&mut Histogram>)>,
+) {
+    let Some(diagnostics) = diagnostics else { return };
+    for (source, plot, histogram) in &mut charts {
+        let Some(diagnostic) = diagnostics.get(&source.metric) else { continue };
+        if let Some(mut plot) = plot {
+            let values = history_tail(diagnostic.history(), plot.capacity);
+            if plot.values != values {
+                plot.values = values;
+            }
+        }
+        if let Some(mut histogram) = histogram {
+            let samples = history_tail(diagnostic.history(), histogram.capacity);
+            if histogram.samples != samples {
+                histogram.samples = samples;
+            }
+        }
+    }
+}
+
+/// 图表网格系统 (PostUpdate)
+///
+/// 新图表插入 [`VectorMesh`]；图表组件变化时重新生成。
+pub fn chart_mesh_system<C: Chart>(mut commands: Commands, mut charts: Query<(Entity, Ref<C>, Option<&mut VectorMesh>)>) {
+    for (entity, chart, mesh) in &mut charts {
+        match mesh {
+            Some(mut mesh) if chart.is_changed() => *mesh = chart.mesh(),
+            Some(_) => {}
+            None => {
+                commands.entity(entity).insert(chart.mesh());
+            }
+        }
+    }
+}
+
+/// 图表插件
+///
+/// 注册指标同步与内置图表的网格系统（在矢量收集之前运行），未添加 [`VectorPlugin`] 时自动添加。
+pub struct ChartPlugin;
+
+impl Plugin for ChartPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<VectorPlugin>() {
+            app.add_plugins(VectorPlugin);
+        }
+        app.add_systems(
+            bevy_app::PostUpdate,
+            (
+                chart_source_system,
+                (chart_mesh_system::<LinePlot>, chart_mesh_system::<Histogram>, chart_mesh_system::<BarChart>),
+            )
+                .chain()
+                .before(vector_collect_system),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "ChartPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_core::diagnostics::FRAME_TIME;
+    use anvilkit_core::math::Transform;
+
+    use crate::renderer::vector::VectorCollected;
+
+    #[test]
+    fn test_plot_range_resolve() {
+        assert_eq!(PlotRange::AutoZero.resolve([3.0, 5.0]), (0.0, 5.0));
+        assert_eq!(PlotRange::Auto.resolve([3.0, 5.0, f32::NAN]), (3.0, 5.0));
+        assert_eq!(PlotRange::AutoZero.resolve([]), (0.0, 1.0));
+        assert_eq!(PlotRange::Auto.resolve([2.0, 2.0]), (1.5, 2.5));
+        assert_eq!(PlotRange::Fixed { min: -1.0, max: 1.0 }.resolve([9.0]), (-1.0, 1.0));
+    }
+
+    #[test]
+    fn test_line_plot_scrolls_from_right() {
+        let mut plot = LinePlot::new(Vec2::new(100.0, 10.0), 3).with_range(PlotRange::Fixed { min: 0.0, max: 10.0 });
+        for v in [1.0, 2.0, 5.0, 10.0] {
+            plot.push(v);
+        }
+        assert_eq!(plot.values, VecDeque::from([2.0, 5.0, 10.0]));
+        assert_eq!(plot.points(), vec![Vec2::new(0.0, 8.0), Vec2::new(50.0, 5.0), Vec2::new(100.0, 0.0)]);
+
+        plot.values = VecDeque::from([7.0]);
+        assert_eq!(plot.points(), vec![Vec2::new(100.0, 3.0)]);
+        // 背景、网格与折线各一部分；裸样式只剩区域填充与折线
+        plot.push(3.0);
+        assert_eq!(plot.mesh().parts.len(), 3);
+        let bare = plot.with_area(Color::WHITE).with_style(ChartStyle::bare()).mesh();
+        assert_eq!(bare.parts.len(), 2);
+        assert_eq!(bare.parts[0].color, Color::WHITE);
+    }
+
+    #[test]
+    fn test_histogram_and_bars() {
+        assert_eq!(histogram_counts([0.0, 0.1, 0.5, 0.99, 1.0, 7.0, -3.0], 4, (0.0, 1.0)), vec![3, 0, 1, 3]);
+
+        let rects = bar_rects(&[2.0, -1.0], (-2.0, 2.0), Vec2::new(20.0, 40.0), 0.0);
+        // 基线在 y = 20
+        assert_eq!(rects[0], (Vec2::new(0.0, 0.0), Vec2::new(10.0, 20.0)));
+        assert_eq!(rects[1], (Vec2::new(10.0, 20.0), Vec2::new(10.0, 10.0)));
+
+        let chart = BarChart::new(Vec2::new(60.0, 30.0))
+            .with_style(ChartStyle::bare())
+            .with_bar(1.0, Color::RED)
+            .with_bar(2.0, Color::BLUE)
+            .with_bar(0.0, Color::WHITE);
+        let colors: Vec<Color> = chart.mesh().parts.iter().map(|part| part.color).collect();
+        // 零高度的柱子不产生三角形
+        assert_eq!(colors, vec![Color::RED, Color::BLUE]);
+    }
+
+    #[test]
+    fn test_plot_source_and_mesh_systems() {
+        let mut world = World::new();
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.register(FRAME_TIME, "ms");
+        for ms in [16.0, 18.0, 40.0] {
+            diagnostics.add_measurement(FRAME_TIME, ms);
+        }
+        world.insert_resource(diagnostics);
+        world.init_resource::<VectorCollected>();
+
+        let plot = world
+            .spawn((LinePlot::new(Vec2::new(100.0, 20.0), 2), PlotSource::new(FRAME_TIME), Transform::default()))
+            .id();
+        let histogram = world
+            .spawn((Histogram::new(Vec2::new(100.0, 20.0), 4), PlotSource::new(FRAME_TIME), Transform::default()))
+            .id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            (chart_source_system, chart_mesh_system::<LinePlot>, chart_mesh_system::<Histogram>, vector_collect_system)
+                .chain(),
+        );
+        schedule.run(&mut world);
+
+        assert_eq!(world.get::<LinePlot>(plot).unwrap().values, VecDeque::from([18.0, 40.0]));
+        assert_eq!(world.get::<Histogram>(histogram).unwrap().counts(), vec![0, 2, 0, 1]);
+        assert!(world.get::<VectorMesh>(plot).is_some());
+        assert!(!world.resource::<VectorCollected>().vertices.is_empty());
+
+        // 新的测量值使折线图重建
+        let before = world.get::<VectorMesh>(plot).unwrap().clone();
+        world.resource_mut::<Diagnostics>().add_measurement(FRAME_TIME, 5.0);
+        schedule.run(&mut world);
+        assert_eq!(world.get::<LinePlot>(plot).unwrap().values, VecDeque::from([40.0, 5.0]));
+        assert_ne!(world.get::<VectorMesh>(plot).unwrap(), &before);
+    }
+}
@@ -0,0 +1,789 @@
+//! # 群体蒙皮角色渲染
+//!
+//! 大量动画角色（人群、兵团、鱼群）逐实体运行 `AnimationPlayer` + 骨骼矩阵上传代价过高。
+//! 本模块把骨骼动画预先烘焙为纹理，在顶点着色器中按实例采样：
+//!
+//! - [`bake_animation`]: 按固定采样率把 [`AnimationClip`] 的骨骼矩阵烘焙为 [`BakedAnimation`]
+//! - [`CrowdAnimations`]: 把多个烘焙剪辑纵向拼接成一张 `Rgba32Float` 骨骼纹理（资源）
+//! - [`CrowdMember`]: 实例组件，只记录剪辑索引、时间偏移、播放速度与染色
+//! - [`crowd_extract_system`]: 每帧把 `CrowdMember` + `GlobalTransform` 收集为 [`CrowdInstances`]
+//! - [`CrowdRenderer`]: 单次实例化 draw call 绘制同一网格的全部实例
+//!
+//! 纹理每行是一帧，每个关节占 3 个纹素，依次存放 3x4 仿射骨骼矩阵的三行；
+//! 着色器在相邻两帧之间线性混合，CPU 端 [`CrowdClip::frame_at`] 与之一致。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::crowd::{bake_animation, CrowdAnimations, CrowdMember};
+//! use anvilkit_assets::animation::{AnimationClip, Joint, Skeleton};
+//! use glam::Mat4;
+//!
+//! let skeleton = Skeleton {
+//!     joints: vec![Joint { name: "Root".into(), parent: None, inverse_bind_matrix: Mat4::IDENTITY }],
+//! };
+//! let clip = AnimationClip { name: "Idle".into(), channels: vec![] };
+//!
+//! let mut animations = CrowdAnimations::default();
+//! let idle = animations.add(bake_animation(&skeleton, &clip, 30.0)).unwrap();
+//! // 同一剪辑的不同实例错开相位，避免动作整齐划一
+//! let member = CrowdMember::new(idle).with_time_offset(0.37).with_speed(1.1);
+//! assert_eq!(member.clip, idle);
+//! ```
+
+use bevy_ecs::prelude::*;
+use bytemuck::{Pod, Zeroable};
+use glam::{Mat4, Vec3, Vec4};
+use wgpu::util::DeviceExt;
+
+use anvilkit_assets::animation::{compute_bone_matrices, AnimationClip, AnimationPlayer, Skeleton};
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_core::math::GlobalTransform;
+use anvilkit_core::time::DeltaTime;
+
+use super::buffer::{SkinnedVertex, Vertex};
+use super::draw::ViewVisibility;
+use super::RenderDevice;
+
+const CROWD_SHADER: &str = include_str!("../shaders/crowd.wgsl");
+
+/// 每个关节在纹理中占用的纹素数（3x4 仿射矩阵的三行）
+pub const TEXELS_PER_JOINT: u32 = 3;
+
+/// 烘焙后的骨骼动画
+///
+/// `texels` 按行存放：第 `frame` 行、第 `joint` 个关节的三行矩阵位于
+/// `frame * joint_count * 3 + joint * 3 ..`。
+#[derive(Debug, Clone)]
+pub struct BakedAnimation {
+    /// 剪辑名称
+    pub name: String,
+    /// 关节数
+    pub joint_count: u32,
+    /// 帧数（首尾帧都包含，至少 1）
+    pub frame_count: u32,
+    /// 实际采样率（帧/秒），保证最后一帧恰好落在剪辑末尾
+    pub sample_rate: f32,
+    /// 是否循环播放
+    pub looping: bool,
+    /// 骨骼矩阵纹素
+    pub texels: Vec<[f32; 4]>,
+}
+
+impl BakedAnimation {
+    /// 设置是否循环
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// 剪辑时长（秒）
+    pub fn duration(&self) -> f32 {
+        if self.frame_count > 1 {
+            (self.frame_count - 1) as f32 / self.sample_rate
+        } else {
+            0.0
+        }
+    }
+
+    /// 还原第 `frame` 帧、第 `joint` 个关节的骨骼矩阵
+    pub fn bone_matrix(&self, frame: u32, joint: u32) -> Mat4 {
+        let base = ((frame * self.joint_count + joint) * TEXELS_PER_JOINT) as usize;
+        decode_bone(&self.texels[base..base + 3])
+    }
+}
+
+fn encode_bone(matrix: &Mat4) -> [[f32; 4]; 3] {
+    [matrix.row(0).into(), matrix.row(1).into(), matrix.row(2).into()]
+}
+
+fn decode_bone(rows: &[[f32; 4]]) -> Mat4 {
+    Mat4::from_cols(
+        Vec4::new(rows[0][0], rows[1][0], rows[2][0], 0.0),
+        Vec4::new(rows[0][1], rows[1][1], rows[2][1], 0.0),
+        Vec4::new(rows[0][2], rows[1][2], rows[2][2], 0.0),
+        Vec4::new(rows[0][3], rows[1][3], rows[2][3], 1.0),
+    )
+}
+
+/// 以 `sample_rate` 帧/秒烘焙剪辑的骨骼矩阵
+///
+/// 帧数向上取整，并反推实际采样率使首尾帧恰好覆盖 `[0, duration]`；
+/// 默认循环播放，非循环剪辑用 [`BakedAnimation::with_looping`] 关闭。
+pub fn bake_animation(skeleton: &Skeleton, clip: &AnimationClip, sample_rate: f32) -> BakedAnimation {
+    let duration = clip.duration();
+    let joint_count = skeleton.joint_count() as u32;
+    let frame_count = if duration > 0.0 {
+        (duration * sample_rate.max(1.0)).ceil() as u32 + 1
+    } else {
+        1
+    };
+    let sample_rate = if frame_count > 1 { (frame_count - 1) as f32 / duration } else { sample_rate.max(1.0) };
+
+    let mut player = AnimationPlayer::new(clip.clone());
+    let mut texels = Vec::with_capacity((frame_count * joint_count * TEXELS_PER_JOINT) as usize);
+    for frame in 0..frame_count {
+        player.current_time = (frame as f32 / sample_rate).min(duration);
+        for bone in compute_bone_matrices(skeleton, &player) {
+            texels.extend_from_slice(&encode_bone(&bone));
+        }
+    }
+
+    BakedAnimation {
+        name: clip.name.clone(),
+        joint_count,
+        frame_count,
+        sample_rate,
+        looping: true,
+        texels,
+    }
+}
+
+/// 骨骼纹理中的一个剪辑
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrowdClip {
+    /// 首帧所在行
+    pub first_row: u32,
+    /// 帧数
+    pub frame_count: u32,
+    /// 采样率（帧/秒）
+    pub sample_rate: f32,
+    /// 是否循环
+    pub looping: bool,
+}
+
+impl CrowdClip {
+    /// 计算 `time` 处的两帧索引（相对首帧）与混合权重，与 crowd.wgsl 一致
+    pub fn frame_at(&self, time: f32) -> (u32, u32, f32) {
+        let last = self.frame_count.max(1) as f32 - 1.0;
+        let mut frame = time * self.sample_rate;
+        if self.looping && last > 0.0 {
+            frame = frame.rem_euclid(last);
+        } else {
+            frame = frame.clamp(0.0, last);
+        }
+        let f0 = frame.floor();
+        let f1 = (f0 + 1.0).min(last);
+        (f0 as u32, f1 as u32, frame - f0)
+    }
+}
+
+/// 群体动画骨骼纹理（资源）
+///
+/// 所有剪辑必须来自同一骨骼（关节数一致）；纹理宽度 = `joint_count * 3`，
+/// 高度 = 所有剪辑帧数之和。[`CrowdAnimations::version`] 在添加剪辑后递增，
+/// [`CrowdRenderer::upload_animations`] 据此判断是否需要重新上传。
+#[derive(Resource, Debug, Default)]
+pub struct CrowdAnimations {
+    joint_count: u32,
+    clips: Vec<CrowdClip>,
+    texels: Vec<[f32; 4]>,
+    version: u64,
+}
+
+impl CrowdAnimations {
+    /// 追加烘焙剪辑，返回剪辑索引
+    ///
+    /// 关节数与已有剪辑不一致时返回错误。
+    pub fn add(&mut self, baked: BakedAnimation) -> Result<usize> {
+        if self.clips.is_empty() {
+            self.joint_count = baked.joint_count;
+        } else if baked.joint_count != self.joint_count {
+            return Err(AnvilKitError::render(format!(
+                "群体动画 '{}' 关节数 {} 与骨骼纹理的 {} 不一致",
+                baked.name, baked.joint_count, self.joint_count
+            )));
+        }
+        self.clips.push(CrowdClip {
+            first_row: self.rows(),
+            frame_count: baked.frame_count,
+            sample_rate: baked.sample_rate,
+            looping: baked.looping,
+        });
+        self.texels.extend_from_slice(&baked.texels);
+        self.version += 1;
+        Ok(self.clips.len() - 1)
+    }
+
+    /// 获取剪辑
+    pub fn clip(&self, index: usize) -> Option<&CrowdClip> {
+        self.clips.get(index)
+    }
+
+    /// 剪辑数量
+    pub fn len(&self) -> usize {
+        self.clips.len()
+    }
+
+    /// 是否没有剪辑
+    pub fn is_empty(&self) -> bool {
+        self.clips.is_empty()
+    }
+
+    /// 关节数
+    pub fn joint_count(&self) -> u32 {
+        self.joint_count
+    }
+
+    /// 纹理宽度（纹素）
+    pub fn width(&self) -> u32 {
+        self.joint_count * TEXELS_PER_JOINT
+    }
+
+    /// 纹理高度（总帧数）
+    pub fn rows(&self) -> u32 {
+        self.clips.iter().map(|c| c.frame_count).sum()
+    }
+
+    /// 内容版本号，每次添加剪辑后递增
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// 按 [`CrowdClip::frame_at`] 在 CPU 端采样骨骼矩阵（用于挂点、射线检测等）
+    pub fn sample_bone(&self, clip: usize, time: f32, joint: u32) -> Option<Mat4> {
+        let clip = self.clips.get(clip)?;
+        if joint >= self.joint_count {
+            return None;
+        }
+        let (f0, f1, blend) = clip.frame_at(time);
+        let at = |frame: u32| {
+            let base = (((clip.first_row + frame) * self.joint_count + joint) * TEXELS_PER_JOINT) as usize;
+            decode_bone(&self.texels[base..base + 3])
+        };
+        Some(at(f0) * (1.0 - blend) + at(f1) * blend)
+    }
+}
+
+/// 群体实例组件
+///
+/// 与 `Transform` 一起挂在实体上即可参与群体渲染；不需要 `AnimationPlayer`。
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CrowdMember {
+    /// [`CrowdAnimations`] 中的剪辑索引
+    pub clip: usize,
+    /// 时间偏移（秒），错开同一剪辑不同实例的相位
+    pub time_offset: f32,
+    /// 播放速度倍数
+    pub speed: f32,
+    /// 线性空间 RGBA 染色
+    pub tint: [f32; 4],
+}
+
+impl CrowdMember {
+    /// 以默认速度、无偏移、白色播放指定剪辑
+    pub fn new(clip: usize) -> Self {
+        Self { clip, time_offset: 0.0, speed: 1.0, tint: [1.0; 4] }
+    }
+
+    /// 设置时间偏移
+    pub fn with_time_offset(mut self, offset: f32) -> Self {
+        self.time_offset = offset;
+        self
+    }
+
+    /// 设置播放速度
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// 设置染色
+    pub fn with_tint(mut self, tint: [f32; 4]) -> Self {
+        self.tint = tint;
+        self
+    }
+}
+
+/// GPU 群体实例数据（per-instance 顶点缓冲，112 字节）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct CrowdInstanceData {
+    /// Object-to-world model matrix.
+    pub model: [[f32; 4]; 4],
+    /// `[first_row, frame_count, sample_rate, looping]`.
+    pub clip: [f32; 4],
+    /// `[time_offset, speed, 0, 0]`.
+    pub playback: [f32; 4],
+    /// Linear RGBA tint.
+    pub tint: [f32; 4],
+}
+
+impl CrowdInstanceData {
+    /// 由实例组件、剪辑与世界矩阵构造
+    pub fn new(member: &CrowdMember, clip: &CrowdClip, model: Mat4) -> Self {
+        Self {
+            model: model.to_cols_array_2d(),
+            clip: [
+                clip.first_row as f32,
+                clip.frame_count as f32,
+                clip.sample_rate,
+                if clip.looping { 1.0 } else { 0.0 },
+            ],
+            playback: [member.time_offset, member.speed, 0.0, 0.0],
+            tint: member.tint,
+        }
+    }
+}
+
+// 逐实例数据（locations 6..=12）
+crate::impl_vertex!(CrowdInstanceData, Instance, [
+    6 => Float32x4,
+    7 => Float32x4,
+    8 => Float32x4,
+    9 => Float32x4,
+    10 => Float32x4,
+    11 => Float32x4,
+    12 => Float32x4,
+]);
+
+/// 每帧收集的群体实例（资源）
+#[derive(Resource, Debug, Default)]
+pub struct CrowdInstances {
+    /// 本帧实例
+    pub instances: Vec<CrowdInstanceData>,
+    /// 群体动画时钟（秒），所有实例共享，相位差由 `time_offset` 提供
+    pub elapsed: f32,
+}
+
+/// 收集群体实例：推进共享时钟，跳过被剔除或剪辑无效的实例
+pub fn crowd_extract_system(
+    dt: Option<Res<DeltaTime>>,
+    animations: Res<CrowdAnimations>,
+    mut crowd: ResMut<CrowdInstances>,
+    query: Query<(&CrowdMember, &GlobalTransform, Option<&ViewVisibility>)>,
+) {
+    crowd.elapsed += dt.map(|dt| dt.0).unwrap_or(0.0);
+    crowd.instances.clear();
+    for (member, global, visibility) in query.iter() {
+        if visibility.is_some_and(|v| !v.get()) {
+            continue;
+        }
+        let Some(clip) = animations.clip(member.clip) else { continue };
+        crowd.instances.push(CrowdInstanceData::new(member, clip, global.0));
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct CrowdUniform {
+    view_proj: [[f32; 4]; 4],
+    light_dir: [f32; 4],
+    params: [f32; 4],
+}
+
+/// 群体网格（蒙皮顶点 + u32 索引）
+pub struct CrowdMesh {
+    /// Skinned vertex buffer.
+    pub vertex_buffer: wgpu::Buffer,
+    /// Index buffer (u32).
+    pub index_buffer: wgpu::Buffer,
+    /// Number of indices.
+    pub index_count: u32,
+}
+
+impl CrowdMesh {
+    /// 上传蒙皮网格
+    pub fn new(device: &RenderDevice, vertices: &[SkinnedVertex], indices: &[u32], label: &str) -> Self {
+        let vertex_buffer = device.device().create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("{label} Crowd VB")),
+            contents: bytemuck::cast_slice(vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let index_buffer = device.device().create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("{label} Crowd IB")),
+            contents: bytemuck::cast_slice(indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+        Self { vertex_buffer, index_buffer, index_count: indices.len() as u32 }
+    }
+}
+
+/// 群体绘制的视图：颜色/深度目标、相机与光照
+pub struct CrowdView<'a> {
+    /// 颜色目标（保留已有内容）
+    pub target: &'a wgpu::TextureView,
+    /// 深度缓冲（与场景共享）
+    pub depth_view: &'a wgpu::TextureView,
+    /// 相机 view-projection 矩阵
+    pub view_proj: Mat4,
+    /// 世界空间光照方向（光线前进方向）
+    pub light_dir: Vec3,
+    /// 环境光比例 `[0, 1]`
+    pub ambient: f32,
+}
+
+/// GPU 群体渲染器
+///
+/// 一次实例化 draw call 绘制 [`CrowdInstances`] 中的全部实例；
+/// 光照为单方向光 + 环境项的简化漫反射，适合远中景人群。
+pub struct CrowdRenderer {
+    /// The wgpu render pipeline for baked-animation crowds.
+    pub pipeline: wgpu::RenderPipeline,
+    /// Uniform buffer holding view-projection, light and clock.
+    pub scene_buffer: wgpu::Buffer,
+    /// Bind group for the scene uniform buffer.
+    pub scene_bind_group: wgpu::BindGroup,
+    bone_layout: wgpu::BindGroupLayout,
+    bone_bind_group: Option<wgpu::BindGroup>,
+    uploaded_version: Option<u64>,
+    cached_instance_buf: super::shared::CachedBuffer,
+}
+
+impl CrowdRenderer {
+    /// Creates the crowd render pipeline, uniform buffer, and bind groups.
+    pub fn new(device: &RenderDevice, format: wgpu::TextureFormat) -> Self {
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Crowd Shader"),
+            source: wgpu::ShaderSource::Wgsl(CROWD_SHADER.into()),
+        });
+
+        let scene_bgl = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Crowd Scene BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bone_layout = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Crowd Bone Texture BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let pipeline_layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Crowd Pipeline Layout"),
+            bind_group_layouts: &[&scene_bgl, &bone_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Crowd Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[SkinnedVertex::layout(), CrowdInstanceData::layout()],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: Some(wgpu::Face::Back),
+                ..Default::default()
+            },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: wgpu::TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let initial = CrowdUniform {
+            view_proj: Mat4::IDENTITY.to_cols_array_2d(),
+            light_dir: [0.0, -1.0, 0.0, 0.3],
+            params: [0.0; 4],
+        };
+        let scene_buffer = device.device().create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Crowd Scene UB"),
+            contents: bytemuck::bytes_of(&initial),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let scene_bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Crowd Scene BG"),
+            layout: &scene_bgl,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: scene_buffer.as_entire_binding(),
+            }],
+        });
+
+        Self {
+            pipeline,
+            scene_buffer,
+            scene_bind_group,
+            bone_layout,
+            bone_bind_group: None,
+            uploaded_version: None,
+            cached_instance_buf: super::shared::CachedBuffer::vertex("Crowd Instance (cached)"),
+        }
+    }
+
+    /// 上传骨骼纹理；内容版本未变化时跳过
+    ///
+    /// 纹理尺寸超出设备上限时返回错误。
+    pub fn upload_animations(&mut self, device: &RenderDevice, animations: &CrowdAnimations) -> Result<()> {
+        if animations.is_empty() || self.uploaded_version == Some(animations.version()) {
+            return Ok(());
+        }
+        let (width, height) = (animations.width(), animations.rows());
+        let max = device.device().limits().max_texture_dimension_2d;
+        if width > max || height > max {
+            return Err(AnvilKitError::render(format!(
+                "群体骨骼纹理 {width}x{height} 超出设备上限 {max}"
+            )));
+        }
+
+        let size = wgpu::Extent3d { width, height, depth_or_array_layers: 1 };
+        let texture = device.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("Crowd Bone Texture"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba32Float,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        device.queue().write_texture(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytemuck::cast_slice(&animations.texels),
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(16 * width),
+                rows_per_image: Some(height),
+            },
+            size,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        self.bone_bind_group = Some(device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Crowd Bone Texture BG"),
+            layout: &self.bone_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        }));
+        self.uploaded_version = Some(animations.version());
+        Ok(())
+    }
+
+    /// 以单次实例化 draw call 绘制群体
+    ///
+    /// 尚未调用 [`upload_animations`](Self::upload_animations) 或没有实例时不绘制。
+    pub fn render(
+        &mut self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &CrowdView,
+        mesh: &CrowdMesh,
+        crowd: &CrowdInstances,
+    ) {
+        let Some(bone_bind_group) = &self.bone_bind_group else { return };
+        if crowd.instances.is_empty() || mesh.index_count == 0 {
+            return;
+        }
+
+        let uniform = CrowdUniform {
+            view_proj: view.view_proj.to_cols_array_2d(),
+            light_dir: view.light_dir.normalize_or_zero().extend(view.ambient.clamp(0.0, 1.0)).into(),
+            params: [crowd.elapsed, 0.0, 0.0, 0.0],
+        };
+        device.queue().write_buffer(&self.scene_buffer, 0, bytemuck::bytes_of(&uniform));
+
+        let instance_buffer = self.cached_instance_buf.ensure_and_write(
+            device.device(),
+            device.queue(),
+            bytemuck::cast_slice(&crowd.instances),
+        );
+
+        let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Crowd Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: view.target,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: view.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        rp.set_pipeline(&self.pipeline);
+        rp.set_bind_group(0, &self.scene_bind_group, &[]);
+        rp.set_bind_group(1, bone_bind_group, &[]);
+        rp.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+        rp.set_vertex_buffer(1, instance_buffer.slice(..));
+        rp.set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+        rp.draw_indexed(0..mesh.index_count, 0, 0..crowd.instances.len() as u32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_assets::animation::{AnimationChannel, AnimationProperty, Interpolation, Joint, Keyframe};
+    use glam::Quat;
+
+    fn skeleton() -> Skeleton {
+        Skeleton {
+            joints: vec![
+                Joint { name: "Root".into(), parent: None, inverse_bind_matrix: Mat4::IDENTITY },
+                Joint {
+                    name: "Arm".into(),
+                    parent: Some(0),
+                    inverse_bind_matrix: Mat4::from_translation(Vec3::new(0.0, -1.0, 0.0)),
+                },
+            ],
+        }
+    }
+
+    fn wave_clip() -> AnimationClip {
+        let quarter = Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
+        AnimationClip {
+            name: "Wave".into(),
+            channels: vec![
+                AnimationChannel {
+                    joint_index: 0,
+                    property: AnimationProperty::Translation,
+                    interpolation: Interpolation::Linear,
+                    keyframes: vec![
+                        Keyframe { time: 0.0, value: [0.0; 4] },
+                        Keyframe { time: 1.0, value: [2.0, 0.0, 0.0, 0.0] },
+                    ],
+                },
+                AnimationChannel {
+                    joint_index: 1,
+                    property: AnimationProperty::Rotation,
+                    interpolation: Interpolation::Linear,
+                    keyframes: vec![
+                        Keyframe { time: 0.0, value: [0.0, 0.0, 0.0, 1.0] },
+                        Keyframe { time: 1.0, value: quarter.to_array() },
+                    ],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_bake_matches_runtime_bone_matrices() {
+        let (skeleton, clip) = (skeleton(), wave_clip());
+        let baked = bake_animation(&skeleton, &clip, 10.0);
+        assert_eq!(baked.joint_count, 2);
+        assert_eq!(baked.frame_count, 11);
+        assert!((baked.duration() - 1.0).abs() < 1e-6);
+        assert_eq!(baked.texels.len(), 11 * 2 * 3);
+
+        let mut player = AnimationPlayer::new(clip);
+        for frame in [0, 4, 10] {
+            player.current_time = frame as f32 / 10.0;
+            let expected = compute_bone_matrices(&skeleton, &player);
+            for joint in 0..2 {
+                let bone = baked.bone_matrix(frame, joint);
+                assert!(bone.abs_diff_eq(expected[joint as usize], 1e-5), "frame {frame} joint {joint}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_frame_at_loops_and_clamps() {
+        let clip = CrowdClip { first_row: 0, frame_count: 11, sample_rate: 10.0, looping: true };
+        let (f0, f1, blend) = clip.frame_at(0.25);
+        assert_eq!((f0, f1), (2, 3));
+        assert!((blend - 0.5).abs() < 1e-5);
+        // 循环：1.25 秒回到 0.25 秒处，负时间同样回绕
+        assert_eq!(clip.frame_at(1.25).0, 2);
+        assert_eq!(clip.frame_at(-0.75).0, 2);
+
+        let once = CrowdClip { looping: false, ..clip };
+        assert_eq!(once.frame_at(5.0), (10, 10, 0.0));
+        assert_eq!(once.frame_at(-1.0), (0, 1, 0.0));
+
+        let still = CrowdClip { frame_count: 1, ..clip };
+        assert_eq!(still.frame_at(3.0), (0, 0, 0.0));
+    }
+
+    #[test]
+    fn test_animations_stack_clips_and_sample() {
+        let skeleton = skeleton();
+        let mut animations = CrowdAnimations::default();
+        let wave = animations.add(bake_animation(&skeleton, &wave_clip(), 10.0)).unwrap();
+        let idle_clip = AnimationClip { name: "Idle".into(), channels: vec![] };
+        let idle = animations.add(bake_animation(&skeleton, &idle_clip, 30.0).with_looping(false)).unwrap();
+
+        assert_eq!((wave, idle), (0, 1));
+        assert_eq!(animations.width(), 6);
+        assert_eq!(animations.rows(), 12);
+        assert_eq!(animations.clip(idle).unwrap().first_row, 11);
+        assert!(!animations.clip(idle).unwrap().looping);
+        assert_eq!(animations.version(), 2);
+
+        // 半帧处混合相邻两帧的根骨骼平移
+        let root = animations.sample_bone(wave, 0.05, 0).unwrap();
+        assert!((root.w_axis.x - 0.1).abs() < 1e-5);
+        assert!(animations.sample_bone(wave, 0.0, 2).is_none());
+        assert!(animations.sample_bone(5, 0.0, 0).is_none());
+
+        let other = Skeleton { joints: skeleton.joints[..1].to_vec() };
+        assert!(animations.add(bake_animation(&other, &idle_clip, 30.0)).is_err());
+    }
+
+    #[test]
+    fn test_extract_collects_visible_members() {
+        let mut world = World::new();
+        let mut animations = CrowdAnimations::default();
+        animations.add(bake_animation(&skeleton(), &wave_clip(), 10.0)).unwrap();
+        world.insert_resource(animations);
+        world.insert_resource(DeltaTime(0.5));
+        world.init_resource::<CrowdInstances>();
+
+        let model = GlobalTransform(Mat4::from_translation(Vec3::new(3.0, 0.0, 0.0)));
+        world.spawn((CrowdMember::new(0).with_time_offset(0.2).with_speed(2.0), model));
+        world.spawn((CrowdMember::new(0), model, ViewVisibility::HIDDEN));
+        world.spawn((CrowdMember::new(7), model));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(crowd_extract_system);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+
+        let crowd = world.resource::<CrowdInstances>();
+        assert_eq!(crowd.elapsed, 1.0);
+        assert_eq!(crowd.instances.len(), 1);
+        let instance = crowd.instances[0];
+        assert_eq!(instance.clip, [0.0, 11.0, 10.0, 1.0]);
+        assert_eq!(instance.playback, [0.2, 2.0, 0.0, 0.0]);
+        assert_eq!(instance.model[3][0], 3.0);
+        assert_eq!(std::mem::size_of::<CrowdInstanceData>(), 112);
+    }
+}
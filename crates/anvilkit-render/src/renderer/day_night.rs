@@ -0,0 +1,389 @@
+//! # 昼夜循环
+//!
+//! [`DayNightCycle`] 资源以可配置的一天时长推进时刻（0～24 小时），
+//! [`day_night_system`] 每帧据此改写 [`SceneLights`] 的方向光（方向、颜色、强度）与环境光，
+//! 并可选地驱动 [`ClearColor`] 作为天空颜色。
+//!
+//! 太阳在日出到日落之间沿东（+X）→ 天顶 → 西（-X）的弧线运动，正午仰角为 `noon_elevation`，
+//! 整条轨迹可绕 Y 轴旋转 `azimuth`。夜间方向光切换为从太阳对侧照来的月光。
+//!
+//! 时刻跨过日出、正午、日落、午夜时发送 [`TimeOfDayEvent`]，单帧跨过多个时刻时按顺序全部发送。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::day_night::{DayNightCycle, TimeOfDay};
+//!
+//! let mut cycle = DayNightCycle { time_of_day: 5.0, ..Default::default() };
+//! let mut events = Vec::new();
+//! cycle.advance_hours(2.0, &mut events);
+//! assert_eq!(events[0].kind, TimeOfDay::Dawn);
+//! assert!(cycle.sample().daylight > 0.0);
+//! ```
+
+use std::f32::consts::PI;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::time::DeltaTime;
+use anvilkit_describe::Describe;
+use glam::{Quat, Vec3};
+
+use super::draw::{AmbientLight, SceneLights};
+use crate::plugin::ClearColor;
+
+/// 一天中的关键时刻
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeOfDay {
+    /// 日出（`sunrise`）
+    Dawn,
+    /// 正午（日出与日落的中点）
+    Noon,
+    /// 日落（`sunset`）
+    Dusk,
+    /// 午夜（0 点，同时开始新的一天）
+    Midnight,
+}
+
+/// 时刻事件
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeOfDayEvent {
+    /// 跨过的时刻
+    pub kind: TimeOfDay,
+    /// 事件发生时的天数（午夜事件为新的一天）
+    pub day: u32,
+}
+
+/// 某一时刻的光照与天空参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayNightSample {
+    /// 方向光方向（从光源指向场景）；白天为太阳，夜间为月亮
+    pub light_direction: Vec3,
+    /// 方向光颜色 (linear RGB)
+    pub light_color: Vec3,
+    /// 方向光强度
+    pub light_intensity: f32,
+    /// 白天程度（0 = 夜晚，1 = 太阳高于地平线）
+    pub daylight: f32,
+    /// 环境光
+    pub ambient: AmbientLight,
+    /// 天空颜色 (linear RGB)
+    pub sky_color: Vec3,
+}
+
+/// 昼夜循环配置与状态
+#[derive(Debug, Clone, Resource, Describe)]
+/// Day/night cycle driving the sun, ambient light and sky color.
+pub struct DayNightCycle {
+    /// 现实中一整天的时长（秒）
+    #[describe(hint = "Real-time seconds per in-game day", range = "1.0..86400.0", default = "600.0")]
+    pub day_length: f32,
+    /// 当前时刻（小时，[0, 24)）
+    #[describe(hint = "Current time of day in hours", range = "0.0..24.0", default = "8.0")]
+    pub time_of_day: f32,
+    /// 已经过的天数
+    #[describe(hint = "Elapsed in-game days", default = "0")]
+    pub day: u32,
+    /// 是否暂停推进（仍会应用光照）
+    #[describe(hint = "Freeze time while still applying lighting", default = "false")]
+    pub paused: bool,
+    /// 日出时刻（小时）
+    #[describe(hint = "Sunrise hour", range = "0.0..24.0", default = "6.0")]
+    pub sunrise: f32,
+    /// 日落时刻（小时）
+    #[describe(hint = "Sunset hour", range = "0.0..24.0", default = "18.0")]
+    pub sunset: f32,
+    /// 正午太阳仰角（弧度）
+    #[describe(hint = "Sun elevation at noon in radians", range = "0.0..1.5708", default = "1.0472")]
+    pub noon_elevation: f32,
+    /// 太阳轨迹绕 Y 轴的旋转（弧度）
+    #[describe(hint = "Rotation of the sun path around Y in radians", default = "0.0")]
+    pub azimuth: f32,
+    /// 高空太阳颜色
+    #[describe(hint = "Sun color high in the sky", default = "(1.0, 0.95, 0.9)")]
+    pub sun_color: Vec3,
+    /// 地平线附近太阳颜色
+    #[describe(hint = "Sun color near the horizon", default = "(1.0, 0.5, 0.25)")]
+    pub horizon_color: Vec3,
+    /// 太阳最大强度
+    #[describe(hint = "Sun intensity", range = "0.0..20.0", default = "5.0")]
+    pub sun_intensity: f32,
+    /// 月光颜色
+    #[describe(hint = "Moonlight color", default = "(0.6, 0.7, 1.0)")]
+    pub moon_color: Vec3,
+    /// 月光最大强度
+    #[describe(hint = "Moonlight intensity", range = "0.0..5.0", default = "0.3")]
+    pub moon_intensity: f32,
+    /// 白天天空颜色
+    #[describe(hint = "Sky color during the day", default = "(0.15, 0.3, 0.6)")]
+    pub sky_day: Vec3,
+    /// 日出日落时天空颜色
+    #[describe(hint = "Sky color at sunrise / sunset", default = "(0.8, 0.45, 0.3)")]
+    pub sky_horizon: Vec3,
+    /// 夜晚天空颜色
+    #[describe(hint = "Sky color at night", default = "(0.01, 0.01, 0.03)")]
+    pub sky_night: Vec3,
+    /// 白天环境光强度
+    #[describe(hint = "Ambient intensity during the day", range = "0.0..4.0", default = "1.0")]
+    pub ambient_day: f32,
+    /// 夜晚环境光强度
+    #[describe(hint = "Ambient intensity at night", range = "0.0..4.0", default = "0.1")]
+    pub ambient_night: f32,
+    /// 是否用天空颜色改写 `ClearColor`
+    #[describe(hint = "Write the sky color into ClearColor", default = "true")]
+    pub drive_clear_color: bool,
+}
+
+impl Default for DayNightCycle {
+    fn default() -> Self {
+        Self {
+            day_length: 600.0,
+            time_of_day: 8.0,
+            day: 0,
+            paused: false,
+            sunrise: 6.0,
+            sunset: 18.0,
+            noon_elevation: 60f32.to_radians(),
+            azimuth: 0.0,
+            sun_color: Vec3::new(1.0, 0.95, 0.9),
+            horizon_color: Vec3::new(1.0, 0.5, 0.25),
+            sun_intensity: 5.0,
+            moon_color: Vec3::new(0.6, 0.7, 1.0),
+            moon_intensity: 0.3,
+            sky_day: Vec3::new(0.15, 0.3, 0.6),
+            sky_horizon: Vec3::new(0.8, 0.45, 0.3),
+            sky_night: Vec3::new(0.01, 0.01, 0.03),
+            ambient_day: 1.0,
+            ambient_night: 0.1,
+            drive_clear_color: true,
+        }
+    }
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+impl DayNightCycle {
+    /// 正午时刻（日出与日落的中点）
+    pub fn noon(&self) -> f32 {
+        (self.sunrise + self.sunset) * 0.5
+    }
+
+    /// 当前是否处于日出与日落之间
+    pub fn is_day(&self) -> bool {
+        (self.sunrise..self.sunset).contains(&self.time_of_day)
+    }
+
+    /// 跳转到指定时刻，不发送事件
+    pub fn set_time(&mut self, hours: f32) {
+        self.time_of_day = hours.rem_euclid(24.0);
+    }
+
+    /// 推进 `hours` 小时，按顺序把跨过的时刻追加到 `events`
+    pub fn advance_hours(&mut self, hours: f32, events: &mut Vec<TimeOfDayEvent>) {
+        if hours <= 0.0 || !hours.is_finite() {
+            return;
+        }
+        let marks = [
+            (self.sunrise, TimeOfDay::Dawn),
+            (self.noon(), TimeOfDay::Noon),
+            (self.sunset, TimeOfDay::Dusk),
+            (24.0, TimeOfDay::Midnight),
+        ];
+        let mut remaining = hours;
+        loop {
+            let t = self.time_of_day;
+            // 严格晚于当前时刻的下一个关键时刻；午夜总是存在
+            let (mark, kind) = marks
+                .iter()
+                .copied()
+                .filter(|&(m, _)| m > t && m <= 24.0)
+                .fold((24.0, TimeOfDay::Midnight), |best, m| if m.0 < best.0 { m } else { best });
+            let gap = mark - t;
+            if gap > remaining {
+                self.time_of_day = t + remaining;
+                return;
+            }
+            remaining -= gap;
+            if kind == TimeOfDay::Midnight {
+                self.time_of_day = 0.0;
+                self.day += 1;
+            } else {
+                self.time_of_day = mark;
+            }
+            events.push(TimeOfDayEvent { kind, day: self.day });
+        }
+    }
+
+    /// 推进 `seconds` 秒现实时间
+    pub fn advance(&mut self, seconds: f32, events: &mut Vec<TimeOfDayEvent>) {
+        self.advance_hours(seconds * 24.0 / self.day_length.max(f32::EPSILON), events);
+    }
+
+    /// 指向太阳的单位向量（夜间位于地平线以下）
+    pub fn sun_position(&self) -> Vec3 {
+        let t = self.time_of_day;
+        let day_span = (self.sunset - self.sunrise).clamp(0.01, 23.99);
+        let phase = if (self.sunrise..=self.sunset).contains(&t) {
+            PI * (t - self.sunrise) / day_span
+        } else {
+            PI + PI * (t - self.sunset).rem_euclid(24.0) / (24.0 - day_span)
+        };
+        let (sin_e, cos_e) = self.noon_elevation.sin_cos();
+        let to_sun = Vec3::new(phase.cos(), phase.sin() * sin_e, -phase.sin() * cos_e);
+        Quat::from_rotation_y(self.azimuth) * to_sun
+    }
+
+    /// 计算当前时刻的光照与天空参数
+    pub fn sample(&self) -> DayNightSample {
+        let to_sun = self.sun_position();
+        let elevation = to_sun.y;
+        let daylight = smoothstep(-0.05, 0.2, elevation);
+        let moonlight = smoothstep(-0.05, 0.2, -elevation);
+
+        let (light_direction, light_color, light_intensity) = if elevation >= 0.0 {
+            let color = self.horizon_color.lerp(self.sun_color, smoothstep(0.0, 0.5, elevation));
+            (-to_sun, color, self.sun_intensity * daylight)
+        } else {
+            (to_sun, self.moon_color, self.moon_intensity * moonlight)
+        };
+
+        let sky_color = if elevation >= 0.0 {
+            self.sky_horizon.lerp(self.sky_day, smoothstep(0.0, 0.3, elevation))
+        } else {
+            self.sky_night.lerp(self.sky_horizon, smoothstep(-0.3, 0.0, elevation))
+        };
+
+        DayNightSample {
+            light_direction,
+            light_color,
+            light_intensity,
+            daylight,
+            ambient: AmbientLight {
+                color: self.moon_color.lerp(Vec3::ONE, daylight),
+                intensity: self.ambient_night + (self.ambient_day - self.ambient_night) * daylight,
+            },
+            sky_color,
+        }
+    }
+}
+
+/// 昼夜循环系统 (Update)
+///
+/// 推进时刻、发送 [`TimeOfDayEvent`]，并把当前光照写入 [`SceneLights`] 与 [`ClearColor`]。
+pub fn day_night_system(
+    mut cycle: ResMut<DayNightCycle>,
+    dt: Option<Res<DeltaTime>>,
+    mut lights: ResMut<SceneLights>,
+    clear_color: Option<ResMut<ClearColor>>,
+    mut writer: EventWriter<TimeOfDayEvent>,
+    mut pending: Local<Vec<TimeOfDayEvent>>,
+) {
+    if !cycle.paused {
+        cycle.advance(dt.map_or(1.0 / 60.0, |d| d.0), &mut pending);
+        writer.send_batch(pending.drain(..));
+    }
+
+    let sample = cycle.sample();
+    let directional = &mut lights.directional;
+    directional.direction = sample.light_direction;
+    directional.color = sample.light_color;
+    directional.intensity = sample.light_intensity;
+    lights.ambient = sample.ambient;
+
+    if cycle.drive_clear_color {
+        if let Some(mut clear_color) = clear_color {
+            clear_color.0 = sample.sky_color.extend(clear_color.0.w);
+        }
+    }
+}
+
+/// 昼夜循环插件
+///
+/// 以给定的初始配置插入 [`DayNightCycle`]，注册 [`TimeOfDayEvent`] 与 [`day_night_system`]。
+#[derive(Default)]
+pub struct DayNightPlugin {
+    /// 初始配置
+    pub cycle: DayNightCycle,
+}
+
+impl Plugin for DayNightPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.cycle.clone())
+            .init_resource::<SceneLights>()
+            .add_event::<TimeOfDayEvent>()
+            .add_systems(bevy_app::Update, day_night_system);
+    }
+
+    fn name(&self) -> &str {
+        "DayNightPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sun_path() {
+        let mut cycle = DayNightCycle::default();
+        cycle.set_time(12.0);
+        let noon = cycle.sun_position();
+        assert!((noon.y - cycle.noon_elevation.sin()).abs() < 1e-5);
+        let sample = cycle.sample();
+        assert!(sample.light_direction.abs_diff_eq(-noon, 1e-6));
+        assert_eq!(sample.daylight, 1.0);
+        assert!((sample.light_intensity - cycle.sun_intensity).abs() < 1e-5);
+
+        cycle.set_time(6.0);
+        assert!(cycle.sun_position().abs_diff_eq(Vec3::X, 1e-5));
+
+        // 午夜：太阳在正下方，月光从上方照下
+        cycle.set_time(0.0);
+        let sample = cycle.sample();
+        assert!(cycle.sun_position().y < -0.8);
+        assert_eq!(sample.daylight, 0.0);
+        assert!(sample.light_direction.y < 0.0);
+        assert_eq!(sample.light_color, cycle.moon_color);
+        assert!((sample.ambient.intensity - cycle.ambient_night).abs() < 1e-6);
+        assert_eq!(sample.sky_color, cycle.sky_night);
+    }
+
+    #[test]
+    fn test_events_in_order_across_midnight() {
+        let mut cycle = DayNightCycle { time_of_day: 17.0, ..Default::default() };
+        let mut events = Vec::new();
+        cycle.advance_hours(20.0, &mut events);
+        let kinds: Vec<_> = events.iter().map(|e| (e.kind, e.day)).collect();
+        assert_eq!(kinds, vec![(TimeOfDay::Dusk, 0), (TimeOfDay::Midnight, 1), (TimeOfDay::Dawn, 1), (TimeOfDay::Noon, 1)]);
+        assert!((cycle.time_of_day - 13.0).abs() < 1e-4);
+
+        // 停在关键时刻上不会重复发送
+        events.clear();
+        cycle.set_time(6.0);
+        cycle.advance_hours(1.0, &mut events);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_system_drives_lights_and_events() {
+        let mut app = App::new();
+        app.add_plugins(DayNightPlugin {
+            cycle: DayNightCycle { day_length: 24.0, time_of_day: 5.5, ..Default::default() },
+        });
+        app.insert_resource(ClearColor::default());
+        app.insert_resource(DeltaTime(1.0));
+        app.update();
+
+        let cycle = app.world().resource::<DayNightCycle>();
+        assert!((cycle.time_of_day - 6.5).abs() < 1e-4);
+        let expected = cycle.sample();
+        let lights = app.world().resource::<SceneLights>();
+        assert_eq!(lights.directional.direction, expected.light_direction);
+        assert_eq!(lights.ambient, expected.ambient);
+        assert_eq!(app.world().resource::<ClearColor>().0.truncate(), expected.sky_color);
+
+        let events: Vec<_> = app.world_mut().resource_mut::<Events<TimeOfDayEvent>>().drain().collect();
+        assert_eq!(events, vec![TimeOfDayEvent { kind: TimeOfDay::Dawn, day: 0 }]);
+    }
+}
@@ -2,12 +2,14 @@
 //!
 //! 统一的调试模块，提供：
 //! - [`DebugMode`] / [`RenderStats`] / [`DebugOverlay`]: 调试状态和统计
+//! - [`DebugDraw`]: 即时模式调试绘制资源（线段、射线、包围盒、球体、坐标轴）
 //! - [`DebugRenderer`]: 3D 调试图元渲染（线段、包围盒、球体、点）
 //! - [`OverlayLineRenderer`]: 2D/3D 叠加线段渲染（十字准星、瞄准线等）
 
 use bevy_ecs::prelude::*;
 use anvilkit_describe::Describe;
-use glam::{Vec3, Mat4};
+use glam::{Vec3, Mat4, Quat};
+use anvilkit_core::math::Aabb;
 use crate::renderer::RenderDevice;
 use crate::renderer::buffer::{ColorVertex, Vertex, create_uniform_buffer};
 use crate::renderer::pipeline::RenderPipelineBuilder;
@@ -264,6 +266,213 @@ pub enum DebugDrawCommand {
         /// Size (half-length of cross arms).
         size: f32,
     },
+    /// Draw an axis gizmo (X red, Y green, Z blue).
+    Axes {
+        /// Origin.
+        position: Vec3,
+        /// Orientation of the axes.
+        rotation: Quat,
+        /// Length of each axis.
+        size: f32,
+    },
+}
+
+/// 将单条调试绘制命令展开为线段列表顶点
+fn push_command_vertices(cmd: &DebugDrawCommand, out: &mut Vec<DebugVertex>) {
+    match cmd {
+        DebugDrawCommand::Line { start, end, color } => {
+            out.push(DebugVertex { position: start.to_array(), color: *color });
+            out.push(DebugVertex { position: end.to_array(), color: *color });
+        }
+        DebugDrawCommand::Box { center, half_extents, color } => {
+            let c = *center;
+            let h = *half_extents;
+            let corners = [
+                c + Vec3::new(-h.x, -h.y, -h.z),
+                c + Vec3::new( h.x, -h.y, -h.z),
+                c + Vec3::new( h.x,  h.y, -h.z),
+                c + Vec3::new(-h.x,  h.y, -h.z),
+                c + Vec3::new(-h.x, -h.y,  h.z),
+                c + Vec3::new( h.x, -h.y,  h.z),
+                c + Vec3::new( h.x,  h.y,  h.z),
+                c + Vec3::new(-h.x,  h.y,  h.z),
+            ];
+            let edges: [(usize, usize); 12] = [
+                (0,1),(1,2),(2,3),(3,0),
+                (4,5),(5,6),(6,7),(7,4),
+                (0,4),(1,5),(2,6),(3,7),
+            ];
+            for (a, b) in edges {
+                out.push(DebugVertex { position: corners[a].to_array(), color: *color });
+                out.push(DebugVertex { position: corners[b].to_array(), color: *color });
+            }
+        }
+        DebugDrawCommand::Sphere { center, radius, color, segments } => {
+            let segs = (*segments).max(8) as usize;
+            for axis in 0..3 {
+                for i in 0..segs {
+                    let a0 = std::f32::consts::TAU * (i as f32) / (segs as f32);
+                    let a1 = std::f32::consts::TAU * ((i + 1) as f32) / (segs as f32);
+                    let (p0, p1) = match axis {
+                        0 => (
+                            *center + Vec3::new(0.0, a0.cos() * radius, a0.sin() * radius),
+                            *center + Vec3::new(0.0, a1.cos() * radius, a1.sin() * radius),
+                        ),
+                        1 => (
+                            *center + Vec3::new(a0.cos() * radius, 0.0, a0.sin() * radius),
+                            *center + Vec3::new(a1.cos() * radius, 0.0, a1.sin() * radius),
+                        ),
+                        _ => (
+                            *center + Vec3::new(a0.cos() * radius, a0.sin() * radius, 0.0),
+                            *center + Vec3::new(a1.cos() * radius, a1.sin() * radius, 0.0),
+                        ),
+                    };
+                    out.push(DebugVertex { position: p0.to_array(), color: *color });
+                    out.push(DebugVertex { position: p1.to_array(), color: *color });
+                }
+            }
+        }
+        DebugDrawCommand::Point { position, color, size } => {
+            let s = *size;
+            let p = *position;
+            for axis in 0..3 {
+                let mut offset = Vec3::ZERO;
+                match axis {
+                    0 => offset.x = s,
+                    1 => offset.y = s,
+                    _ => offset.z = s,
+                }
+                out.push(DebugVertex { position: (p - offset).to_array(), color: *color });
+                out.push(DebugVertex { position: (p + offset).to_array(), color: *color });
+            }
+        }
+        DebugDrawCommand::Axes { position, rotation, size } => {
+            let axes = [
+                (Vec3::X, [1.0, 0.0, 0.0, 1.0]),
+                (Vec3::Y, [0.0, 1.0, 0.0, 1.0]),
+                (Vec3::Z, [0.0, 0.0, 1.0, 1.0]),
+            ];
+            for (axis, color) in axes {
+                let end = *position + *rotation * axis * *size;
+                out.push(DebugVertex { position: position.to_array(), color });
+                out.push(DebugVertex { position: end.to_array(), color });
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  DebugDraw — immediate-mode ECS resource
+// ---------------------------------------------------------------------------
+
+/// 即时模式调试绘制资源
+///
+/// 游戏系统每帧调用绘制方法累积图元，渲染循环在主场景 pass 之后以
+/// line-list 管线绘制（带深度测试）。命令在每帧 `First` 阶段清空。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::renderer::debug::DebugDraw;
+/// use glam::{Quat, Vec3};
+///
+/// let mut draw = DebugDraw::default();
+/// draw.line(Vec3::ZERO, Vec3::X, [1.0, 0.0, 0.0, 1.0]);
+/// draw.ray(Vec3::ZERO, Vec3::Y * 2.0, [0.0, 1.0, 0.0, 1.0]);
+/// draw.axes(Vec3::ZERO, Quat::IDENTITY, 1.0);
+/// assert_eq!(draw.len(), 3);
+/// ```
+#[derive(Debug, Clone, Resource)]
+pub struct DebugDraw {
+    /// 是否绘制（关闭时绘制调用被忽略）
+    pub enabled: bool,
+    commands: Vec<DebugDrawCommand>,
+}
+
+impl Default for DebugDraw {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl DebugDraw {
+    /// 线段
+    pub fn line(&mut self, start: Vec3, end: Vec3, color: [f32; 4]) {
+        self.push(DebugDrawCommand::Line { start, end, color });
+    }
+
+    /// 射线：从 `origin` 沿 `direction`（含长度）
+    pub fn ray(&mut self, origin: Vec3, direction: Vec3, color: [f32; 4]) {
+        self.line(origin, origin + direction, color);
+    }
+
+    /// 轴对齐包围盒线框
+    pub fn aabb(&mut self, aabb: &Aabb, color: [f32; 4]) {
+        self.push(DebugDrawCommand::Box {
+            center: aabb.center(),
+            half_extents: aabb.half_extents(),
+            color,
+        });
+    }
+
+    /// 球体线框（三个轴向圆环，每环 24 段）
+    pub fn sphere(&mut self, center: Vec3, radius: f32, color: [f32; 4]) {
+        self.push(DebugDrawCommand::Sphere { center, radius, color, segments: 24 });
+    }
+
+    /// 点（小十字）
+    pub fn point(&mut self, position: Vec3, size: f32, color: [f32; 4]) {
+        self.push(DebugDrawCommand::Point { position, color, size });
+    }
+
+    /// 坐标轴 gizmo（X 红、Y 绿、Z 蓝）
+    pub fn axes(&mut self, position: Vec3, rotation: Quat, size: f32) {
+        self.push(DebugDrawCommand::Axes { position, rotation, size });
+    }
+
+    /// 本帧累积的命令
+    pub fn commands(&self) -> &[DebugDrawCommand] {
+        &self.commands
+    }
+
+    /// 命令数量
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    /// 是否没有命令
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// 清空所有命令
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    /// 展开为线段列表顶点（不超过 [`MAX_DEBUG_VERTICES`]）
+    pub fn build_vertices(&self) -> Vec<DebugVertex> {
+        let mut out = Vec::new();
+        for cmd in &self.commands {
+            push_command_vertices(cmd, &mut out);
+        }
+        out.truncate(MAX_DEBUG_VERTICES);
+        out
+    }
+
+    fn push(&mut self, cmd: DebugDrawCommand) {
+        if self.enabled {
+            self.commands.push(cmd);
+        }
+    }
+}
+
+/// 每帧开始时清空 [`DebugDraw`] (First)
+pub fn debug_draw_clear_system(mut draw: ResMut<DebugDraw>) {
+    draw.clear();
 }
 
 /// Debug 渲染器 — 3D scene debug primitives
@@ -285,6 +494,14 @@ pub struct DebugRenderer {
 impl DebugRenderer {
     /// Create a new debug renderer.
     pub fn new(device: &RenderDevice) -> Self {
+        Self::with_sample_count(device, 1)
+    }
+
+    /// Create a debug renderer whose pipeline matches an MSAA target.
+    ///
+    /// Use with [`render_resolved`](Self::render_resolved) to draw into the
+    /// multisampled HDR scene target after the main pass.
+    pub fn with_sample_count(device: &RenderDevice, sample_count: u32) -> Self {
         let vertex_buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
             label: Some("Debug Vertex Buffer"),
             size: (MAX_DEBUG_VERTICES * std::mem::size_of::<DebugVertex>()) as u64,
@@ -343,7 +560,10 @@ impl DebugRenderer {
                 stencil: wgpu::StencilState::default(),
                 bias: wgpu::DepthBiasState::default(),
             }),
-            multisample: wgpu::MultisampleState::default(),
+            multisample: wgpu::MultisampleState {
+                count: sample_count.max(1),
+                ..Default::default()
+            },
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
                 entry_point: "fs_main",
@@ -386,79 +606,17 @@ impl DebugRenderer {
         self.commands.push(DebugDrawCommand::Point { position, color, size });
     }
 
+    /// Queue every command accumulated in a [`DebugDraw`] resource.
+    pub fn queue(&mut self, draw: &DebugDraw) {
+        self.commands.extend_from_slice(draw.commands());
+    }
+
     /// Convert commands to line vertices.
     pub fn prepare(&mut self) {
         self.line_vertices.clear();
 
         for cmd in &self.commands {
-            match cmd {
-                DebugDrawCommand::Line { start, end, color } => {
-                    self.line_vertices.push(DebugVertex { position: start.to_array(), color: *color });
-                    self.line_vertices.push(DebugVertex { position: end.to_array(), color: *color });
-                }
-                DebugDrawCommand::Box { center, half_extents, color } => {
-                    let c = *center;
-                    let h = *half_extents;
-                    let corners = [
-                        c + Vec3::new(-h.x, -h.y, -h.z),
-                        c + Vec3::new( h.x, -h.y, -h.z),
-                        c + Vec3::new( h.x,  h.y, -h.z),
-                        c + Vec3::new(-h.x,  h.y, -h.z),
-                        c + Vec3::new(-h.x, -h.y,  h.z),
-                        c + Vec3::new( h.x, -h.y,  h.z),
-                        c + Vec3::new( h.x,  h.y,  h.z),
-                        c + Vec3::new(-h.x,  h.y,  h.z),
-                    ];
-                    let edges: [(usize, usize); 12] = [
-                        (0,1),(1,2),(2,3),(3,0),
-                        (4,5),(5,6),(6,7),(7,4),
-                        (0,4),(1,5),(2,6),(3,7),
-                    ];
-                    for (a, b) in edges {
-                        self.line_vertices.push(DebugVertex { position: corners[a].to_array(), color: *color });
-                        self.line_vertices.push(DebugVertex { position: corners[b].to_array(), color: *color });
-                    }
-                }
-                DebugDrawCommand::Sphere { center, radius, color, segments } => {
-                    let segs = (*segments).max(8) as usize;
-                    for axis in 0..3 {
-                        for i in 0..segs {
-                            let a0 = std::f32::consts::TAU * (i as f32) / (segs as f32);
-                            let a1 = std::f32::consts::TAU * ((i + 1) as f32) / (segs as f32);
-                            let (p0, p1) = match axis {
-                                0 => (
-                                    *center + Vec3::new(0.0, a0.cos() * radius, a0.sin() * radius),
-                                    *center + Vec3::new(0.0, a1.cos() * radius, a1.sin() * radius),
-                                ),
-                                1 => (
-                                    *center + Vec3::new(a0.cos() * radius, 0.0, a0.sin() * radius),
-                                    *center + Vec3::new(a1.cos() * radius, 0.0, a1.sin() * radius),
-                                ),
-                                _ => (
-                                    *center + Vec3::new(a0.cos() * radius, a0.sin() * radius, 0.0),
-                                    *center + Vec3::new(a1.cos() * radius, a1.sin() * radius, 0.0),
-                                ),
-                            };
-                            self.line_vertices.push(DebugVertex { position: p0.to_array(), color: *color });
-                            self.line_vertices.push(DebugVertex { position: p1.to_array(), color: *color });
-                        }
-                    }
-                }
-                DebugDrawCommand::Point { position, color, size } => {
-                    let s = *size;
-                    let p = *position;
-                    for axis in 0..3 {
-                        let mut offset = Vec3::ZERO;
-                        match axis {
-                            0 => offset.x = s,
-                            1 => offset.y = s,
-                            _ => offset.z = s,
-                        }
-                        self.line_vertices.push(DebugVertex { position: (p - offset).to_array(), color: *color });
-                        self.line_vertices.push(DebugVertex { position: (p + offset).to_array(), color: *color });
-                    }
-                }
-            }
+            push_command_vertices(cmd, &mut self.line_vertices);
         }
 
         if self.line_vertices.len() > MAX_DEBUG_VERTICES {
@@ -474,6 +632,34 @@ impl DebugRenderer {
         target_view: &wgpu::TextureView,
         depth_view: &wgpu::TextureView,
         view_proj: &[[f32; 4]; 4],
+    ) {
+        self.render_pass(device, encoder, target_view, None, depth_view, view_proj);
+    }
+
+    /// Render debug lines into a multisampled target, resolving into `resolve_view`.
+    ///
+    /// Requires a renderer created with [`with_sample_count`](Self::with_sample_count)
+    /// matching the target's sample count.
+    pub fn render_resolved(
+        &self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        msaa_view: &wgpu::TextureView,
+        resolve_view: &wgpu::TextureView,
+        depth_view: &wgpu::TextureView,
+        view_proj: &[[f32; 4]; 4],
+    ) {
+        self.render_pass(device, encoder, msaa_view, Some(resolve_view), depth_view, view_proj);
+    }
+
+    fn render_pass(
+        &self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        target_view: &wgpu::TextureView,
+        resolve_target: Option<&wgpu::TextureView>,
+        depth_view: &wgpu::TextureView,
+        view_proj: &[[f32; 4]; 4],
     ) {
         if self.line_vertices.is_empty() {
             return;
@@ -504,7 +690,7 @@ impl DebugRenderer {
             label: Some("Debug Lines Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                 view: target_view,
-                resolve_target: None,
+                resolve_target,
                 ops: wgpu::Operations {
                     load: wgpu::LoadOp::Load,
                     store: wgpu::StoreOp::Store,
@@ -750,6 +936,33 @@ mod tests {
         assert_eq!(commands.len(), 4);
     }
 
+    #[test]
+    fn test_debug_draw_resource() {
+        let mut draw = DebugDraw::default();
+        draw.line(Vec3::ZERO, Vec3::X, [1.0; 4]);
+        draw.aabb(&Aabb::from_min_max(Vec3::splat(-1.0), Vec3::ONE), [1.0; 4]);
+        draw.axes(Vec3::ZERO, Quat::IDENTITY, 2.0);
+        // 1 line + 12 box edges + 3 axes, 2 vertices each
+        assert_eq!(draw.build_vertices().len(), 2 * (1 + 12 + 3));
+
+        draw.enabled = false;
+        draw.sphere(Vec3::ZERO, 1.0, [1.0; 4]);
+        assert_eq!(draw.len(), 3);
+
+        draw.clear();
+        assert!(draw.is_empty());
+    }
+
+    #[test]
+    fn test_axes_follow_rotation() {
+        let mut draw = DebugDraw::default();
+        draw.axes(Vec3::ZERO, Quat::from_rotation_z(std::f32::consts::FRAC_PI_2), 1.0);
+        let verts = draw.build_vertices();
+        // X axis end rotated onto +Y
+        let x_end = Vec3::from_array(verts[1].position);
+        assert!((x_end - Vec3::Y).length() < 1e-5);
+    }
+
     #[test]
     fn test_debug_vertex_pod() {
         let vertices = vec![
@@ -2,7 +2,9 @@
 //! 
 //! 提供 wgpu 设备、适配器和实例的创建和管理功能。
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use bevy_ecs::prelude::{Event, Resource};
 use wgpu::{
     Instance, Adapter, Device, Queue, Surface,
     DeviceDescriptor, Features, Limits, PowerPreference, RequestAdapterOptions,
@@ -13,6 +15,171 @@ use log::{info, debug};
 
 use anvilkit_core::error::{AnvilKitError, Result};
 
+/// GPU 设备丢失后已重建 (Event)
+///
+/// 渲染循环检测到设备丢失（驱动重置、笔记本切换显卡等）后，重新请求适配器与设备，
+/// 重建引擎内部资源、通过 [`RenderAssets::register_pipeline_with`] 注册的管线以及
+/// [`Materials`] 中的材质，然后发送此事件。直接上传到 [`RenderAssets`] 的网格和
+/// 用户自行创建的 GPU 资源已失效，需要在收到事件后重新上传。
+///
+/// [`RenderAssets`]: crate::renderer::assets::RenderAssets
+/// [`RenderAssets::register_pipeline_with`]: crate::renderer::assets::RenderAssets::register_pipeline_with
+/// [`Materials`]: crate::renderer::material::Materials
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct RenderDeviceRestored {
+    /// 新适配器名称
+    pub adapter_name: String,
+    /// 重建的已注册管线数量
+    pub pipelines_rebuilt: usize,
+}
+
+/// 设备丢失原因是否属于意外丢失（主动销毁或释放设备不算）
+pub fn is_unexpected_device_loss(reason: wgpu::DeviceLostReason) -> bool {
+    matches!(reason, wgpu::DeviceLostReason::Unknown)
+}
+
+/// GPU 后端与适配器选择 (Resource)
+///
+/// 由 [`RenderPlugin::with_render_settings`](crate::plugin::RenderPlugin::with_render_settings)
+/// 插入，渲染设备初始化时读取。非法组合在创建设备前以 `AnvilKitError::Render` 报告。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::renderer::device::RenderSettings;
+/// use wgpu::{Backends, PowerPreference};
+///
+/// let settings = RenderSettings::new()
+///     .with_backends(Backends::VULKAN)
+///     .with_power_preference(PowerPreference::LowPower)
+///     .with_adapter_name("nvidia");
+/// assert!(settings.validate().is_ok());
+/// assert!(settings.adapter_matches("NVIDIA GeForce RTX 4070"));
+/// assert!(!settings.adapter_matches("Intel(R) UHD Graphics"));
+/// ```
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct RenderSettings {
+    /// 允许使用的图形后端（Vulkan / Metal / DX12 / GL / WebGPU）
+    pub backends: Backends,
+    /// 适配器功耗偏好
+    pub power_preference: PowerPreference,
+    /// 强制使用软件回退适配器（如 WARP / llvmpipe）
+    pub force_fallback_adapter: bool,
+    /// 必需特性；适配器不支持时初始化失败
+    pub required_features: Features,
+    /// 必需限制；`None` 时使用 `Limits::default()`（GL 后端使用 WebGL2 下级限制）
+    pub required_limits: Option<Limits>,
+    /// 适配器名称过滤（不区分大小写的子串匹配）
+    pub adapter_name: Option<String>,
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        // Web 平台优先 WebGPU，浏览器不支持时回退到 WebGL2
+        #[cfg(target_arch = "wasm32")]
+        let backends = Backends::BROWSER_WEBGPU | Backends::GL;
+        #[cfg(not(target_arch = "wasm32"))]
+        let backends = Backends::all();
+
+        Self {
+            backends,
+            power_preference: PowerPreference::HighPerformance,
+            force_fallback_adapter: false,
+            required_features: Features::empty(),
+            required_limits: None,
+            adapter_name: None,
+        }
+    }
+}
+
+impl RenderSettings {
+    /// 默认设置：所有后端、高性能适配器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 限定图形后端
+    pub fn with_backends(mut self, backends: Backends) -> Self {
+        self.backends = backends;
+        self
+    }
+
+    /// 设置功耗偏好
+    pub fn with_power_preference(mut self, power_preference: PowerPreference) -> Self {
+        self.power_preference = power_preference;
+        self
+    }
+
+    /// 强制使用软件回退适配器
+    pub fn with_fallback_adapter(mut self, force: bool) -> Self {
+        self.force_fallback_adapter = force;
+        self
+    }
+
+    /// 追加必需特性
+    pub fn with_required_features(mut self, features: Features) -> Self {
+        self.required_features |= features;
+        self
+    }
+
+    /// 设置必需限制
+    pub fn with_required_limits(mut self, limits: Limits) -> Self {
+        self.required_limits = Some(limits);
+        self
+    }
+
+    /// 只选择名称包含 `name` 的适配器
+    pub fn with_adapter_name(mut self, name: impl Into<String>) -> Self {
+        self.adapter_name = Some(name.into());
+        self
+    }
+
+    /// 检查设置本身是否自洽（不涉及具体适配器）
+    pub fn validate(&self) -> Result<()> {
+        if self.backends.is_empty() {
+            return Err(AnvilKitError::render("RenderSettings: 未选择任何图形后端".to_string()));
+        }
+        if self.adapter_name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(AnvilKitError::render("RenderSettings: 适配器名称过滤为空".to_string()));
+        }
+        if self.force_fallback_adapter && self.adapter_name.is_some() {
+            return Err(AnvilKitError::render(
+                "RenderSettings: 强制回退适配器时不能再按名称过滤适配器".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// 适配器名称是否满足过滤条件（未设置过滤时总是满足）
+    pub fn adapter_matches(&self, adapter_name: &str) -> bool {
+        match &self.adapter_name {
+            Some(filter) => adapter_name.to_lowercase().contains(&filter.trim().to_lowercase()),
+            None => true,
+        }
+    }
+
+    /// 检查适配器能否满足必需的特性与限制
+    pub fn check_adapter(&self, features: Features, limits: &Limits) -> Result<()> {
+        let missing = self.required_features - features;
+        if !missing.is_empty() {
+            return Err(AnvilKitError::render(format!("适配器不支持必需特性: {:?}", missing)));
+        }
+        if let Some(required) = &self.required_limits {
+            let mut exceeded = Vec::new();
+            required.check_limits_with_fail_fn(limits, false, |name, requested, allowed| {
+                exceeded.push(format!("{} (需要 {}, 适配器 {})", name, requested, allowed));
+            });
+            if !exceeded.is_empty() {
+                return Err(AnvilKitError::render(format!(
+                    "适配器不满足必需限制: {}",
+                    exceeded.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// GPU 渲染设备
 /// 
 /// 封装 wgpu 的实例、适配器、设备和队列，提供统一的 GPU 资源管理。
@@ -57,6 +224,8 @@ pub struct RenderDevice {
     features: Features,
     /// 设备限制
     limits: Limits,
+    /// 设备丢失回调置位的标记
+    lost: Arc<AtomicBool>,
 }
 
 impl RenderDevice {
@@ -84,19 +253,37 @@ impl RenderDevice {
     /// # }
     /// ```
     pub async fn new(window: &Arc<Window>) -> Result<Self> {
+        Self::new_with_settings(window, &RenderSettings::default()).await
+    }
+
+    /// 按 [`RenderSettings`] 选择后端与适配器并创建渲染设备
+    pub async fn new_with_settings(window: &Arc<Window>, settings: &RenderSettings) -> Result<Self> {
         info!("初始化 GPU 渲染设备");
+        settings.validate()?;
         
         // 创建 wgpu 实例
-        let instance = Self::create_instance()?;
+        let instance = Self::create_instance(settings.backends)?;
         
         // 创建表面
         let surface = Self::create_surface(&instance, window)?;
         
         // 请求适配器
-        let adapter = Self::request_adapter(&instance, &surface).await?;
+        let adapter = Self::request_adapter(&instance, &surface, settings).await?;
+        settings.check_adapter(adapter.features(), &adapter.limits())?;
         
         // 请求设备和队列
-        let (device, queue) = Self::request_device(&adapter).await?;
+        let (device, queue) = Self::request_device(&adapter, settings).await?;
+
+        let lost = Arc::new(AtomicBool::new(false));
+        {
+            let lost = lost.clone();
+            device.set_device_lost_callback(move |reason, message| {
+                if is_unexpected_device_loss(reason) {
+                    log::error!("GPU 设备丢失 ({:?}): {}", reason, message);
+                    lost.store(true, Ordering::SeqCst);
+                }
+            });
+        }
         
         let features = adapter.features();
         let limits = adapter.limits();
@@ -112,19 +299,24 @@ impl RenderDevice {
             queue,
             features,
             limits,
+            lost,
         })
     }
     
     /// 创建 wgpu 实例
     /// 
+    /// # 参数
+    /// 
+    /// - `backends`: 启用的后端（来自 [`RenderSettings::backends`]）
+    /// 
     /// # 返回
     /// 
     /// 成功时返回 Instance，失败时返回错误
-    fn create_instance() -> Result<Instance> {
-        debug!("创建 wgpu 实例");
-        
+    fn create_instance(backends: Backends) -> Result<Instance> {
+        debug!("创建 wgpu 实例: {:?}", backends);
+
         let instance = Instance::new(InstanceDescriptor {
-            backends: Backends::all(),
+            backends,
             ..Default::default()
         });
         
@@ -156,19 +348,53 @@ impl RenderDevice {
     /// 
     /// - `instance`: wgpu 实例
     /// - `surface`: 窗口表面
+    /// - `settings`: 后端与适配器选择
     /// 
     /// # 返回
     /// 
     /// 成功时返回 Adapter，失败时返回错误
-    async fn request_adapter(instance: &Instance, surface: &Surface<'_>) -> Result<Adapter> {
+    async fn request_adapter(
+        instance: &Instance,
+        surface: &Surface<'_>,
+        settings: &RenderSettings,
+    ) -> Result<Adapter> {
         debug!("请求 GPU 适配器");
+
+        // 按名称过滤时遍历所有适配器（Web 平台无法枚举，退回默认请求后再校验）
+        #[cfg(not(target_arch = "wasm32"))]
+        if settings.adapter_name.is_some() {
+            let adapters = instance.enumerate_adapters(settings.backends);
+            let names: Vec<String> = adapters.iter().map(|a| a.get_info().name).collect();
+            let adapter = adapters
+                .into_iter()
+                .find(|a| settings.adapter_matches(&a.get_info().name) && a.is_surface_supported(surface))
+                .ok_or_else(|| AnvilKitError::render(format!(
+                    "没有名称匹配 \"{}\" 的兼容适配器，可用适配器: [{}]",
+                    settings.adapter_name.as_deref().unwrap_or_default(),
+                    names.join(", ")
+                )))?;
+            let info = adapter.get_info();
+            info!("选择的 GPU 适配器: {} ({:?})", info.name, info.backend);
+            return Ok(adapter);
+        }
         
         let adapter = instance.request_adapter(&RequestAdapterOptions {
-            power_preference: PowerPreference::HighPerformance,
+            power_preference: settings.power_preference,
             compatible_surface: Some(surface),
-            force_fallback_adapter: false,
+            force_fallback_adapter: settings.force_fallback_adapter,
         }).await
-        .ok_or_else(|| AnvilKitError::render("未找到兼容的 GPU 适配器".to_string()))?;
+        .ok_or_else(|| AnvilKitError::render(format!(
+            "未找到兼容的 GPU 适配器 (后端 {:?}, 回退适配器 {})",
+            settings.backends, settings.force_fallback_adapter
+        )))?;
+
+        if !settings.adapter_matches(&adapter.get_info().name) {
+            return Err(AnvilKitError::render(format!(
+                "适配器 \"{}\" 不匹配名称过滤 \"{}\"",
+                adapter.get_info().name,
+                settings.adapter_name.as_deref().unwrap_or_default()
+            )));
+        }
         
         let info = adapter.get_info();
         info!("选择的 GPU 适配器: {} ({:?})", info.name, info.backend);
@@ -181,18 +407,32 @@ impl RenderDevice {
     /// # 参数
     /// 
     /// - `adapter`: GPU 适配器
+    /// - `settings`: 必需特性与限制
     /// 
     /// # 返回
     /// 
     /// 成功时返回 (Device, Queue)，失败时返回错误
-    async fn request_device(adapter: &Adapter) -> Result<(Device, Queue)> {
+    async fn request_device(adapter: &Adapter, settings: &RenderSettings) -> Result<(Device, Queue)> {
         debug!("请求 GPU 设备和队列");
         
+        // 时间戳查询仅在适配器支持时开启，供 GPU 计时使用
+        let optional_features = adapter.features() & Features::TIMESTAMP_QUERY;
+        let required_features = settings.required_features | optional_features;
+
+        // WebGL2 后端达不到默认限制，按下级限制请求
+        let required_limits = match &settings.required_limits {
+            Some(limits) => limits.clone(),
+            None if adapter.get_info().backend == wgpu::Backend::Gl => {
+                Limits::downlevel_webgl2_defaults().using_resolution(adapter.limits())
+            }
+            None => Limits::default(),
+        };
+
         let (device, queue) = adapter.request_device(
             &DeviceDescriptor {
                 label: Some("AnvilKit Render Device"),
-                required_features: Features::empty(),
-                required_limits: Limits::default(),
+                required_features,
+                required_limits,
             },
             None, // 不使用跟踪路径
         ).await
@@ -319,6 +559,16 @@ impl RenderDevice {
     pub fn limits(&self) -> &Limits {
         &self.limits
     }
+
+    /// 设备是否已丢失（渲染循环会在下一帧重建设备）
+    pub fn is_lost(&self) -> bool {
+        self.lost.load(Ordering::SeqCst)
+    }
+
+    /// 手动标记设备丢失，触发重建（如检测到不可恢复的表面错误）
+    pub fn mark_lost(&self) {
+        self.lost.store(true, Ordering::SeqCst);
+    }
     
     /// 检查是否支持指定特性
     /// 
@@ -378,7 +628,7 @@ mod tests {
     #[test]
     fn test_instance_creation() {
         // 测试实例创建
-        let instance = RenderDevice::create_instance();
+        let instance = RenderDevice::create_instance(RenderSettings::default().backends);
         assert!(instance.is_ok());
     }
     
@@ -402,6 +652,41 @@ mod tests {
         let _ = instance;
     }
 
+    #[test]
+    fn test_unexpected_device_loss() {
+        assert!(is_unexpected_device_loss(wgpu::DeviceLostReason::Unknown));
+        assert!(!is_unexpected_device_loss(wgpu::DeviceLostReason::Destroyed));
+        assert!(!is_unexpected_device_loss(wgpu::DeviceLostReason::Dropped));
+    }
+
+    #[test]
+    fn test_render_settings_validation() {
+        assert!(RenderSettings::default().validate().is_ok());
+        assert!(RenderSettings::new().with_backends(Backends::empty()).validate().is_err());
+        assert!(RenderSettings::new().with_adapter_name("  ").validate().is_err());
+        assert!(RenderSettings::new()
+            .with_fallback_adapter(true)
+            .with_adapter_name("llvmpipe")
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_render_settings_check_adapter() {
+        let settings = RenderSettings::new()
+            .with_required_features(Features::DEPTH_CLIP_CONTROL)
+            .with_required_limits(Limits { max_bind_groups: 8, ..Limits::downlevel_defaults() });
+
+        let err = settings.check_adapter(Features::empty(), &Limits::default()).unwrap_err();
+        assert!(err.to_string().contains("DEPTH_CLIP_CONTROL"));
+
+        let err = settings.check_adapter(Features::DEPTH_CLIP_CONTROL, &Limits::default()).unwrap_err();
+        assert!(err.to_string().contains("max_bind_groups"));
+
+        let limits = Limits { max_bind_groups: 8, ..Limits::default() };
+        assert!(settings.check_adapter(Features::DEPTH_CLIP_CONTROL, &limits).is_ok());
+    }
+
     #[test]
     fn test_device_limits_defaults() {
         let limits = Limits::default();
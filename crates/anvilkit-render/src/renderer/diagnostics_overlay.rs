@@ -0,0 +1,387 @@
+//! # 诊断叠加层
+//!
+//! [`DiagnosticsOverlayPlugin`] 在屏幕左上角绘制一个调试面板：FPS、帧时间曲线、
+//! 实体数与绘制调用数。面板通过 `AfterTonemap` 渲染阶段叠加在交换链上，
+//! 文字使用内置位图字体（[`TextRenderer`]），背景与帧时间柱状图使用精灵管线。
+//! [`DiagnosticsOverlay::plots`] 中的指标与可选的帧时间直方图以图表控件（[`crate::renderer::chart`]）
+//! 绘制在帧时间曲线下方。数据来自 [`Diagnostics`]，未添加 [`DiagnosticsPlugin`] 时自动添加。
+//!
+//! 默认按 `F3` 切换显示，键位可在 [`DiagnosticsOverlay`] 中配置。
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::diagnostics_overlay::DiagnosticsOverlayPlugin;
+//!
+//! let mut app = App::new();
+//! app.add_plugins(DiagnosticsOverlayPlugin);
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::{Vec2, Vec3};
+use anvilkit_core::diagnostics::{
+    Diagnostic, DiagnosticSources, Diagnostics, DRAW_CALLS, ENTITY_COUNT, FPS, FRAME_TIME, TRIANGLES,
+};
+use anvilkit_input::input_state::{InputState, KeyCode};
+
+use crate::diagnostics::DiagnosticsPlugin;
+use anvilkit_core::math::{Color, Transform};
+use super::buffer::{create_sampler, create_texture};
+use super::chart::{Chart, ChartStyle, Histogram, LinePlot, PlotRange};
+use super::phase::{PhaseItem, PhaseRenderContext, PhaseSlot, RenderPhase, RenderPhaseAppExt, RenderPhaseDescriptor};
+use super::sprite::{AtlasRect, SpriteBatch, SpriteRenderer};
+use super::state::RenderState;
+use super::text::TextRenderer;
+use super::vector::{VectorCollected, VectorMesh, VectorRenderer};
+
+/// 叠加层配置 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct DiagnosticsOverlay {
+    /// 是否显示
+    pub visible: bool,
+    /// 切换显示的按键
+    pub toggle_key: KeyCode,
+    /// 面板左上角（像素）
+    pub position: Vec2,
+    /// 文字大小（像素）
+    pub font_size: f32,
+    /// 帧时间曲线尺寸（像素）
+    pub graph_size: Vec2,
+    /// 曲线参考帧时间（毫秒），超过 1 倍标黄、2 倍标红
+    pub target_frame_ms: f32,
+    /// 额外绘制滚动折线图的指标名（尺寸同帧时间曲线）
+    pub plots: Vec<String>,
+    /// 是否绘制帧时间直方图（`0 ..= 2 × target_frame_ms`）
+    pub frame_time_histogram: bool,
+}
+
+impl Default for DiagnosticsOverlay {
+    fn default() -> Self {
+        Self {
+            visible: false,
+            toggle_key: KeyCode::F3,
+            position: Vec2::new(8.0, 8.0),
+            font_size: 16.0,
+            graph_size: Vec2::new(240.0, 48.0),
+            target_frame_ms: 1000.0 / 60.0,
+            plots: Vec::new(),
+            frame_time_histogram: false,
+        }
+    }
+}
+
+/// 面板文字行：FPS、帧时间、实体数、绘制调用与三角形数
+pub fn overlay_lines(diagnostics: &Diagnostics) -> [String; 4] {
+    let avg = |name| diagnostics.get(name).and_then(Diagnostic::average).unwrap_or(0.0);
+    let max = diagnostics.get(FRAME_TIME).and_then(Diagnostic::max).unwrap_or(0.0);
+    let value = |name| diagnostics.value(name).unwrap_or(0.0);
+    [
+        format!("FPS {:.0}", avg(FPS)),
+        format!("Frame {:.2} ms (max {:.2})", avg(FRAME_TIME), max),
+        format!("Entities {}", value(ENTITY_COUNT)),
+        format!("Draws {}  Tris {}", value(DRAW_CALLS), value(TRIANGLES)),
+    ]
+}
+
+/// 帧时间柱状图：每帧一根柱子，返回 `(左上, 右下, 颜色)`
+///
+/// 柱高以 `2 × target_ms` 为满格；超过目标帧时间为黄色，超过两倍为红色。
+pub fn frame_time_bars(frame_times_ms: &VecDeque<f64>, origin: Vec2, size: Vec2, target_ms: f32) -> Vec<(Vec2, Vec2, [f32; 3])> {
+    let capacity = frame_times_ms.len().max(1) as f32;
+    let width = size.x / capacity;
+    let full = (target_ms * 2.0).max(f32::EPSILON);
+    frame_times_ms
+        .iter()
+        .enumerate()
+        .map(|(i, &ms)| {
+            let ms = ms as f32;
+            let height = (ms / full).min(1.0) * size.y;
+            let x = origin.x + i as f32 * width;
+            let color = if ms <= target_ms {
+                [0.2, 0.9, 0.3]
+            } else if ms <= target_ms * 2.0 {
+                [0.95, 0.8, 0.2]
+            } else {
+                [0.95, 0.25, 0.2]
+            };
+            (Vec2::new(x, origin.y + size.y - height), Vec2::new(x + width, origin.y + size.y), color)
+        })
+        .collect()
+}
+
+/// 帧时间曲线下方的附加图表：`(标签, 标签位置, 网格, 图表左上角)`，自上而下排列
+///
+/// 未注册的指标被跳过。
+pub fn overlay_charts(
+    overlay: &DiagnosticsOverlay,
+    diagnostics: &Diagnostics,
+    origin: Vec2,
+    line_height: f32,
+    spacing: f32,
+) -> Vec<(String, Vec2, VectorMesh, Vec2)> {
+    let style = ChartStyle { background: None, ..Default::default() };
+    let mut cursor = origin;
+    let mut charts = Vec::new();
+    let mut push = |label: String, mesh: VectorMesh| {
+        let chart_origin = cursor + Vec2::new(0.0, line_height);
+        charts.push((label, cursor, mesh, chart_origin));
+        cursor.y = chart_origin.y + overlay.graph_size.y + spacing;
+    };
+    for name in &overlay.plots {
+        let Some(diagnostic) = diagnostics.get(name) else { continue };
+        let mut plot = LinePlot::new(overlay.graph_size, diagnostic.max_history())
+            .with_style(style)
+            .with_area(Color::rgba(0.3, 0.75, 1.0, 0.25));
+        plot.values = diagnostic.history().iter().map(|&v| v as f32).collect();
+        let label = format!("{} {:.2} {}", name, diagnostic.value().unwrap_or(0.0), diagnostic.unit());
+        push(label.trim_end().to_string(), plot.mesh());
+    }
+    if overlay.frame_time_histogram {
+        if let Some(frame_time) = diagnostics.get(FRAME_TIME) {
+            let mut histogram = Histogram::new(overlay.graph_size, 24)
+                .with_style(style)
+                .with_range(PlotRange::Fixed { min: 0.0, max: overlay.target_frame_ms * 2.0 });
+            histogram.capacity = frame_time.max_history();
+            histogram.samples = frame_time.history().iter().map(|&v| v as f32).collect();
+            push(format!("Frame time 0-{:.0} ms", overlay.target_frame_ms * 2.0), histogram.mesh());
+        }
+    }
+    charts
+}
+
+/// 叠加层阶段项（每帧可见时入队一个）
+pub struct DiagnosticsOverlayItem;
+
+impl PhaseItem for DiagnosticsOverlayItem {
+    type SortKey = ();
+    fn sort_key(&self) {}
+}
+
+/// 叠加层切换系统 (Update)
+///
+/// 处理显示切换，并在可见时把叠加层加入渲染阶段。
+pub fn diagnostics_overlay_system(
+    mut overlay: ResMut<DiagnosticsOverlay>,
+    mut phase: ResMut<RenderPhase<DiagnosticsOverlayItem>>,
+    input: Option<Res<InputState>>,
+) {
+    if input.is_some_and(|input| input.is_key_just_pressed(overlay.toggle_key)) {
+        overlay.visible = !overlay.visible;
+    }
+    if overlay.visible {
+        phase.add(DiagnosticsOverlayItem);
+    }
+}
+
+/// 叠加层 GPU 资源（首次绘制时按交换链格式创建）
+struct OverlayGpu {
+    format: wgpu::TextureFormat,
+    text: TextRenderer,
+    sprites: SpriteRenderer,
+    white: wgpu::BindGroup,
+    batch: SpriteBatch,
+    vectors: VectorRenderer,
+    vector_frame: VectorCollected,
+}
+
+impl OverlayGpu {
+    fn new(ctx: &PhaseRenderContext) -> Self {
+        let sprites = SpriteRenderer::new(ctx.device, ctx.color_format);
+        let (_texture, view) = create_texture(ctx.device, 1, 1, &[255; 4], "Diagnostics White");
+        let sampler = create_sampler(ctx.device, "Diagnostics Sampler");
+        let white = ctx.device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Diagnostics White BG"),
+            layout: &sprites.texture_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+            ],
+        });
+        Self {
+            format: ctx.color_format,
+            text: TextRenderer::new(ctx.device, ctx.color_format),
+            sprites,
+            white,
+            batch: SpriteBatch::new(),
+            vectors: VectorRenderer::new(ctx.device, ctx.color_format),
+            vector_frame: VectorCollected::default(),
+        }
+    }
+}
+
+fn draw_overlay(world: &World, gpu: &mut Option<OverlayGpu>, ctx: &mut PhaseRenderContext) {
+    let (Some(overlay), Some(diagnostics)) = (world.get_resource::<DiagnosticsOverlay>(), world.get_resource::<Diagnostics>()) else {
+        return;
+    };
+    let Some((width, height)) = world.get_resource::<RenderState>().map(|rs| rs.surface_size) else { return };
+    let (sw, sh) = (width as f32, height as f32);
+
+    if gpu.as_ref().is_none_or(|g| g.format != ctx.color_format) {
+        *gpu = Some(OverlayGpu::new(ctx));
+    }
+    let gpu = gpu.as_mut().unwrap();
+
+    let lines = overlay_lines(diagnostics);
+    let line_height = overlay.font_size * 1.25;
+    let padding = 6.0;
+    let text_origin = overlay.position + Vec2::splat(padding);
+    let graph_origin = text_origin + Vec2::new(0.0, line_height * lines.len() as f32 + padding);
+    let charts_origin = graph_origin + Vec2::new(0.0, overlay.graph_size.y + padding);
+    let charts = overlay_charts(overlay, diagnostics, charts_origin, line_height, padding);
+    let panel_bottom = charts.last().map_or(graph_origin.y + overlay.graph_size.y, |(_, _, _, origin)| {
+        origin.y + overlay.graph_size.y
+    });
+    let panel_max = Vec2::new(graph_origin.x + overlay.graph_size.x, panel_bottom) + Vec2::splat(padding);
+
+    // 背景 + 帧时间柱状图
+    gpu.batch.clear();
+    let white = AtlasRect::full();
+    gpu.batch.add_quad(overlay.position, panel_max, 0.0, &white, [0.04, 0.04, 0.05]);
+    let target_y = graph_origin.y + overlay.graph_size.y * 0.5;
+    gpu.batch.add_quad(
+        Vec2::new(graph_origin.x, target_y),
+        Vec2::new(graph_origin.x + overlay.graph_size.x, target_y + 1.0),
+        0.0,
+        &white,
+        [0.4, 0.4, 0.4],
+    );
+    if let Some(frame_time) = diagnostics.get(FRAME_TIME) {
+        for (min, max, color) in frame_time_bars(frame_time.history(), graph_origin, overlay.graph_size, overlay.target_frame_ms) {
+            gpu.batch.add_quad(min, max, 0.0, &white, color);
+        }
+    }
+    gpu.sprites.render(ctx.device, ctx.encoder, ctx.color_target, &gpu.batch, &gpu.white, sw, sh);
+
+    let transforms: Vec<Transform> =
+        charts.iter().map(|(_, _, _, origin)| Transform::from_xyz(origin.x, origin.y, 0.0)).collect();
+    let meshes: Vec<_> = charts.iter().zip(&transforms).map(|((_, _, mesh, _), transform)| (mesh, transform)).collect();
+    gpu.vector_frame.rebuild(&meshes);
+    gpu.vectors.render(ctx.device, ctx.encoder, ctx.color_target, &gpu.vector_frame, width, height);
+
+    let labels = charts.iter().map(|(label, position, ..)| (label.as_str(), *position));
+    let rows = lines.iter().enumerate().map(|(i, line)| (line.as_str(), text_origin + Vec2::new(0.0, i as f32 * line_height)));
+    for (line, position) in rows.chain(labels) {
+        gpu.text.draw_text(
+            ctx.device,
+            ctx.encoder,
+            ctx.color_target,
+            line,
+            position.x,
+            position.y,
+            overlay.font_size,
+            Vec3::ONE,
+            sw,
+            sh,
+        );
+    }
+}
+
+/// 诊断叠加层插件
+pub struct DiagnosticsOverlayPlugin;
+
+impl Plugin for DiagnosticsOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        let gpu: Mutex<Option<OverlayGpu>> = Mutex::new(None);
+        if !app.world().contains_resource::<DiagnosticSources>() {
+            app.add_plugins(DiagnosticsPlugin::default());
+        }
+        app.init_resource::<DiagnosticsOverlay>()
+            .add_render_phase::<DiagnosticsOverlayItem>(
+                RenderPhaseDescriptor::new("DiagnosticsOverlay", PhaseSlot::AfterTonemap).with_priority(i32::MAX),
+                move |world, _items, ctx| {
+                    let Ok(mut gpu) = gpu.lock() else { return };
+                    draw_overlay(world, &mut gpu, ctx);
+                },
+            )
+            .add_systems(bevy_app::Update, diagnostics_overlay_system);
+    }
+
+    fn name(&self) -> &str {
+        "DiagnosticsOverlayPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_lines() {
+        let mut diagnostics = Diagnostics::default();
+        for name in [FPS, FRAME_TIME, ENTITY_COUNT, DRAW_CALLS, TRIANGLES] {
+            diagnostics.register(name, "");
+        }
+        for (fps, ms) in [(25.0, 40.0), (50.0, 20.0)] {
+            diagnostics.add_measurement(FPS, fps);
+            diagnostics.add_measurement(FRAME_TIME, ms);
+        }
+        diagnostics.add_measurement(ENTITY_COUNT, 42.0);
+        diagnostics.add_measurement(DRAW_CALLS, 7.0);
+
+        let lines = overlay_lines(&diagnostics);
+        assert_eq!(lines[0], "FPS 38");
+        assert_eq!(lines[1], "Frame 30.00 ms (max 40.00)");
+        assert_eq!(lines[2], "Entities 42");
+        assert_eq!(lines[3], "Draws 7  Tris 0");
+    }
+
+    #[test]
+    fn test_frame_time_bars() {
+        let times: VecDeque<f64> = [10.0, 20.0, 50.0].into_iter().collect();
+        let bars = frame_time_bars(&times, Vec2::ZERO, Vec2::new(30.0, 40.0), 16.0);
+        assert_eq!(bars.len(), 3);
+        // 满格为 32ms
+        assert_eq!(bars[0].0, Vec2::new(0.0, 40.0 - 12.5));
+        assert_eq!(bars[1].1, Vec2::new(20.0, 40.0));
+        assert_eq!(bars[0].2, [0.2, 0.9, 0.3]);
+        assert_eq!(bars[1].2, [0.95, 0.8, 0.2]);
+        assert_eq!(bars[2].0.y, 0.0);
+        assert_eq!(bars[2].2, [0.95, 0.25, 0.2]);
+    }
+
+    #[test]
+    fn test_overlay_charts_layout() {
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.register(FRAME_TIME, "ms");
+        diagnostics.register(DRAW_CALLS, "");
+        diagnostics.add_measurement(FRAME_TIME, 20.0);
+        diagnostics.add_measurement(DRAW_CALLS, 12.0);
+        let overlay = DiagnosticsOverlay {
+            plots: vec![DRAW_CALLS.to_string(), "missing".to_string()],
+            frame_time_histogram: true,
+            ..Default::default()
+        };
+        assert!(overlay_charts(&DiagnosticsOverlay::default(), &diagnostics, Vec2::ZERO, 20.0, 6.0).is_empty());
+
+        let charts = overlay_charts(&overlay, &diagnostics, Vec2::new(10.0, 100.0), 20.0, 6.0);
+        assert_eq!(charts.len(), 2);
+        assert_eq!(charts[0].0, "draw_calls 12.00");
+        assert_eq!(charts[0].1, Vec2::new(10.0, 100.0));
+        assert_eq!(charts[0].3, Vec2::new(10.0, 120.0));
+        assert!(!charts[0].2.is_empty());
+        // 标签 + 曲线高度 48 + 间距
+        assert_eq!(charts[1].1, Vec2::new(10.0, 120.0 + 48.0 + 6.0));
+        assert!(charts[1].0.starts_with("Frame time"));
+    }
+
+    #[test]
+    fn test_toggle_and_queue() {
+        let mut app = App::new();
+        app.add_plugins(DiagnosticsOverlayPlugin);
+        app.insert_resource(InputState::new());
+
+        app.update();
+        assert!(!app.world().resource::<DiagnosticsOverlay>().visible);
+        assert!(app.world().resource::<RenderPhase<DiagnosticsOverlayItem>>().is_empty());
+
+        app.world_mut().resource_mut::<InputState>().press_key(KeyCode::F3);
+        app.update();
+        assert!(app.world().resource::<DiagnosticsOverlay>().visible);
+        // First 清空、Update 入队、Last 排序：帧末队列中有一项
+        assert_eq!(app.world().resource::<RenderPhase<DiagnosticsOverlayItem>>().len(), 1);
+        assert!(app.world().resource::<Diagnostics>().contains(FRAME_TIME));
+    }
+}
@@ -39,6 +39,7 @@ impl Default for MaterialParams {
 }
 
 /// 单个绘制命令
+#[derive(Debug, Clone)]
 pub struct DrawCommand {
     /// Handle to the GPU mesh to draw.
     pub mesh: MeshHandle,
@@ -54,13 +55,19 @@ pub struct DrawCommand {
     pub normal_scale: f32,
     /// Emissive color factor [R, G, B] for this draw.
     pub emissive_factor: [f32; 3],
+    /// Base color factor (linear RGBA) for this draw.
+    pub base_color: [f32; 4],
+    /// 所属相机的渲染优先级（高优先级先绘制）
+    pub camera: i32,
+    /// 排序键 (Layer, RenderOrder)，见 [`draw_sort_key`](crate::component::draw_sort_key)
+    pub sort_key: (i32, i64),
 }
 
 /// 每帧的绘制命令列表
 ///
 /// 由 render_extract_system 填充，由 RenderApp::render_ecs() 消费。
 /// 支持按 mesh+material 排序分组以减少管线状态切换。
-#[derive(Resource, Default)]
+#[derive(Resource, Debug, Clone, Default)]
 pub struct DrawCommandList {
     /// Collected draw commands for the current frame.
     pub commands: Vec<DrawCommand>,
@@ -77,14 +84,66 @@ impl DrawCommandList {
         self.commands.push(cmd);
     }
 
-    /// 按 (material, mesh) 排序以实现批处理
+    /// 按 (相机, sort_key, material, mesh) 排序以实现批处理
     ///
-    /// 相同 material 的命令排在一起，减少管线状态切换。
+    /// 先按相机优先级分组（高优先级在前），再按层级和显式 `RenderOrder` 排序，同一排序键内：
+    /// 相同 material 的命令排在一起，减少管线状态切换；
     /// 相同 mesh 的命令排在一起，减少顶点缓冲区切换。
     pub fn sort_for_batching(&mut self) {
         self.commands.sort_by(|a, b| {
-            a.material.index().cmp(&b.material.index())
+            b.camera.cmp(&a.camera)
+                .then(a.sort_key.cmp(&b.sort_key))
+                .then(a.material.index().cmp(&b.material.index()))
                 .then(a.mesh.index().cmp(&b.mesh.index()))
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(material: u64, sort_key: (i32, i64)) -> DrawCommand {
+        command_for(0, material, sort_key)
+    }
+
+    fn command_for(camera: i32, material: u64, sort_key: (i32, i64)) -> DrawCommand {
+        DrawCommand {
+            mesh: MeshHandle(1),
+            material: MaterialHandle(material),
+            model_matrix: Mat4::IDENTITY,
+            metallic: 0.0,
+            roughness: 0.5,
+            normal_scale: 1.0,
+            emissive_factor: [0.0; 3],
+            base_color: [1.0; 4],
+            camera,
+            sort_key,
+        }
+    }
+
+    #[test]
+    fn test_render_order_overrides_batching() {
+        let mut list = DrawCommandList::default();
+        list.push(command(1, (0, 10)));
+        list.push(command(3, (0, 0)));
+        list.push(command(2, (0, 0)));
+        list.push(command(9, (-1, 50)));
+        list.sort_for_batching();
+
+        let materials: Vec<u64> = list.commands.iter().map(|c| c.material.index()).collect();
+        assert_eq!(materials, [9, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_camera_sorts_before_layer() {
+        let mut list = DrawCommandList::default();
+        list.push(command_for(0, 1, (-5, 0)));
+        list.push(command_for(10, 2, (3, 0)));
+        list.push(command_for(10, 3, (0, 0)));
+        list.sort_for_batching();
+
+        let materials: Vec<u64> = list.commands.iter().map(|c| c.material.index()).collect();
+        assert_eq!(materials, [3, 2, 1]);
+    }
+}
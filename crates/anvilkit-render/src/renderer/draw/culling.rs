@@ -1,4 +1,44 @@
 //! AABB 和视锥体剔除 — re-exported from anvilkit-core
 
+use bevy_ecs::prelude::*;
+
 pub use anvilkit_core::math::aabb::Aabb;
 pub use anvilkit_core::math::frustum::Frustum;
+
+/// 视锥体剔除结果
+///
+/// 由 `frustum_culling_system` 每帧根据活动相机与 `ComputedVisibility` 写入，
+/// 渲染提取阶段跳过不可见的实体。没有该组件的实体在提取时即时剔除。
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(crate::component::ComputedVisibility)]
+pub struct ViewVisibility(bool);
+
+impl ViewVisibility {
+    /// 可见
+    pub const VISIBLE: Self = Self(true);
+    /// 被剔除
+    pub const HIDDEN: Self = Self(false);
+
+    /// 本帧是否在相机视锥体内
+    pub fn get(&self) -> bool {
+        self.0
+    }
+
+    /// 设置剔除结果
+    pub fn set(&mut self, visible: bool) {
+        self.0 = visible;
+    }
+}
+
+impl Default for ViewVisibility {
+    /// 首帧剔除前默认可见，避免新实体闪烁
+    fn default() -> Self {
+        Self::VISIBLE
+    }
+}
+
+impl From<bool> for ViewVisibility {
+    fn from(visible: bool) -> Self {
+        Self(visible)
+    }
+}
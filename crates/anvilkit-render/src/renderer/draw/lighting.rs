@@ -2,6 +2,9 @@
 
 use bevy_ecs::prelude::*;
 use glam::{Mat4, Vec3};
+use crate::component::RenderLayers;
+#[cfg(feature = "render-3d")]
+use crate::renderer::light_shafts::LightShaftSettings;
 
 /// 活动相机资源
 ///
@@ -14,6 +17,10 @@ pub struct ActiveCamera {
     pub camera_pos: Vec3,
     /// Vertical field of view in radians (used by CSM shadow mapping).
     pub fov_radians: f32,
+    /// Render layers visible to the active camera.
+    pub render_layers: RenderLayers,
+    /// Render priority of the active camera (higher = drawn first).
+    pub priority: i32,
 }
 
 impl Default for ActiveCamera {
@@ -22,12 +29,73 @@ impl Default for ActiveCamera {
             view_proj: Mat4::IDENTITY,
             camera_pos: Vec3::ZERO,
             fov_radians: std::f32::consts::FRAC_PI_4,
+            render_layers: RenderLayers::default(),
+            priority: 0,
         }
     }
 }
 
+/// 方向光阴影设置
+///
+/// 主方向光以级联阴影贴图（CSM）投射阴影：相机视锥体按 `cascade_splits` 切分，
+/// 每级从光源的正交视锥体渲染深度，主 pass 以 PCF 比较采样。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShadowSettings {
+    /// 是否投射阴影
+    pub enabled: bool,
+    /// 深度偏移（阴影贴图纹素），消除自阴影条纹
+    pub depth_bias: f32,
+    /// 法线偏移（世界单位），采样前沿几何法线外推，减少掠射角的阴影痤疮
+    pub normal_bias: f32,
+    /// 级联数 (1..=[`CSM_CASCADE_COUNT`](crate::renderer::state::CSM_CASCADE_COUNT))
+    pub cascade_count: u32,
+    /// 各级远端占 `max_distance` 的比例；最后一个启用的级联总是延伸到 `max_distance`
+    pub cascade_splits: [f32; 3],
+    /// 阴影覆盖的最远视距
+    pub max_distance: f32,
+    /// PCF 核半径（纹素）：0 为硬阴影，1 为 3×3，2 为 5×5
+    pub pcf_radius: u32,
+}
+
+impl Default for ShadowSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            depth_bias: 5.0,
+            normal_bias: 0.02,
+            cascade_count: 3,
+            cascade_splits: [0.1, 0.3, 1.0],
+            max_distance: 200.0,
+            pcf_radius: 1,
+        }
+    }
+}
+
+impl ShadowSettings {
+    /// 实际使用的级联数；禁用时为 0
+    pub fn active_cascades(&self) -> usize {
+        if self.enabled { (self.cascade_count as usize).clamp(1, 3) } else { 0 }
+    }
+
+    /// 各级远端比例：单调递增，最后一个启用的级联为 1，未启用的级联也为 1
+    pub fn split_ratios(&self) -> [f32; 3] {
+        let count = self.active_cascades().max(1);
+        let mut ratios = [1.0; 3];
+        let mut prev = 0.0f32;
+        for (i, ratio) in ratios.iter_mut().enumerate().take(count - 1) {
+            *ratio = self.cascade_splits[i].clamp(prev, 1.0);
+            prev = *ratio;
+        }
+        ratios
+    }
+}
+
 /// 方向光
-#[derive(Debug, Clone)]
+///
+/// 场景的主光源存放在 [`SceneLights::directional`]；也可以作为组件挂在实体上，
+/// 由 [`directional_light_system`](crate::plugin::directional_light_system) 每帧同步到资源，
+/// 此时 `direction` 为实体局部空间方向，随 `GlobalTransform` 旋转。
+#[derive(Debug, Clone, Component)]
 pub struct DirectionalLight {
     /// 光照方向（从光源指向场景）
     pub direction: Vec3,
@@ -35,6 +103,11 @@ pub struct DirectionalLight {
     pub color: Vec3,
     /// 光照强度
     pub intensity: f32,
+    /// 屏幕空间光柱（体积光近似）。`None` 禁用。
+    #[cfg(feature = "render-3d")]
+    pub light_shafts: Option<LightShaftSettings>,
+    /// 级联阴影设置
+    pub shadows: ShadowSettings,
 }
 
 impl Default for DirectionalLight {
@@ -43,14 +116,21 @@ impl Default for DirectionalLight {
             direction: Vec3::new(-0.5, -0.8, 0.3).normalize(),
             color: Vec3::new(1.0, 0.95, 0.9),
             intensity: 5.0,
+            #[cfg(feature = "render-3d")]
+            light_shafts: None,
+            shadows: ShadowSettings::default(),
         }
     }
 }
 
 /// 点光源
-#[derive(Debug, Clone)]
+///
+/// 可直接放入 [`SceneLights::point_lights`]，也可以作为组件挂在实体上，
+/// 由 [`local_lights_system`](crate::plugin::local_lights_system) 每帧收集；
+/// 作为组件时 `position` 是相对实体 `GlobalTransform` 的局部偏移。
+#[derive(Debug, Clone, PartialEq, Component)]
 pub struct PointLight {
-    /// 世界空间位置
+    /// 世界空间位置（组件形式下为局部偏移）
     pub position: Vec3,
     /// 光照颜色 (linear RGB)
     pub color: Vec3,
@@ -72,11 +152,14 @@ impl Default for PointLight {
 }
 
 /// 聚光灯
-#[derive(Debug, Clone)]
+///
+/// 组件形式与 [`PointLight`] 相同：`position` 与 `direction` 都在实体局部空间，
+/// 收集时经 `GlobalTransform` 变换到世界空间。
+#[derive(Debug, Clone, Component)]
 pub struct SpotLight {
-    /// 世界空间位置
+    /// 世界空间位置（组件形式下为局部偏移）
     pub position: Vec3,
-    /// 光照方向（从光源指向场景）
+    /// 光照方向（从光源指向场景，组件形式下为局部方向）
     pub direction: Vec3,
     /// 光照颜色 (linear RGB)
     pub color: Vec3,
@@ -104,6 +187,23 @@ impl Default for SpotLight {
     }
 }
 
+/// 环境光
+///
+/// 缩放着色器中的半球环境光（天空 / 地面渐变）；默认值保持原有亮度。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmbientLight {
+    /// 环境光色调 (linear RGB)
+    pub color: Vec3,
+    /// 环境光强度倍数
+    pub intensity: f32,
+}
+
+impl Default for AmbientLight {
+    fn default() -> Self {
+        Self { color: Vec3::ONE, intensity: 1.0 }
+    }
+}
+
 /// 最大阴影投射光源数量
 ///
 /// 限制同时投射阴影的光源数量以控制 GPU 内存和性能。
@@ -113,6 +213,8 @@ pub const MAX_SHADOW_LIGHTS: usize = 4;
 /// 场景灯光资源
 ///
 /// 持有场景中所有灯光信息，最多 8 盏（1 方向光 + 点光/聚光组合）。
+/// 存在点光/聚光组件时，`point_lights` 与 `spot_lights` 由
+/// [`local_lights_system`](crate::plugin::local_lights_system) 每帧重建。
 /// 其中最多 [`MAX_SHADOW_LIGHTS`] 个光源可同时投射阴影。
 #[derive(Resource)]
 pub struct SceneLights {
@@ -122,6 +224,8 @@ pub struct SceneLights {
     pub point_lights: Vec<PointLight>,
     /// All active spot lights in the scene.
     pub spot_lights: Vec<SpotLight>,
+    /// Hemisphere ambient light tint and intensity.
+    pub ambient: AmbientLight,
 }
 
 impl Default for SceneLights {
@@ -130,6 +234,7 @@ impl Default for SceneLights {
             directional: DirectionalLight::default(),
             point_lights: Vec::new(),
             spot_lights: Vec::new(),
+            ambient: AmbientLight::default(),
         }
     }
 }
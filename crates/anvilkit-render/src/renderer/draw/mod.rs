@@ -7,8 +7,8 @@ mod lighting;
 mod commands;
 mod gpu;
 
-pub use culling::{Aabb, Frustum};
-pub use lighting::{ActiveCamera, DirectionalLight, PointLight, SpotLight, SceneLights, MAX_SHADOW_LIGHTS};
+pub use culling::{Aabb, Frustum, ViewVisibility};
+pub use lighting::{ActiveCamera, AmbientLight, DirectionalLight, PointLight, ShadowSettings, SpotLight, SceneLights, MAX_SHADOW_LIGHTS};
 pub use commands::{MaterialParams, DrawCommand, DrawCommandList};
 pub use gpu::{UniformBatchBuffer, RenderTarget, InstanceData};
 
@@ -24,6 +24,22 @@ mod tests {
         assert!(light.intensity > 0.0);
     }
 
+    #[test]
+    fn test_shadow_split_ratios() {
+        let settings = ShadowSettings::default();
+        assert_eq!(settings.active_cascades(), 3);
+        assert_eq!(settings.split_ratios(), [0.1, 0.3, 1.0]);
+
+        // 单级联覆盖整个阴影距离；比例被钳制为单调递增
+        let single = ShadowSettings { cascade_count: 1, ..Default::default() };
+        assert_eq!(single.split_ratios(), [1.0; 3]);
+        let unordered = ShadowSettings { cascade_splits: [0.5, 0.2, 0.9], ..Default::default() };
+        assert_eq!(unordered.split_ratios(), [0.5, 0.5, 1.0]);
+
+        let disabled = ShadowSettings { enabled: false, ..Default::default() };
+        assert_eq!(disabled.active_cascades(), 0);
+    }
+
     #[test]
     fn test_scene_lights_default() {
         let lights = SceneLights::default();
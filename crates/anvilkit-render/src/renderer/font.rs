@@ -0,0 +1,432 @@
+//! # TTF 字体与字形图集
+//!
+//! 内置的 [`TextRenderer`](super::text::TextRenderer) 只覆盖 8×16 位图 ASCII。本模块通过
+//! fontdue 加载 TTF / OTF 字体，按需光栅化字形并打包进 [`GlyphAtlas`] 纹理，
+//! 供 [`Text2d`](super::text2d::Text2d) 与 UI 通过精灵管线绘制任意 Unicode 文本。
+//!
+//! ## 使用示例
+//!
+//! ```rust,no_run
+//! use anvilkit_render::renderer::font::{Fonts, GlyphAtlas, TextAlign, layout_text};
+//!
+//! let mut fonts = Fonts::default();
+//! let font = fonts.load_file("assets/fonts/NotoSans-Regular.ttf").unwrap();
+//!
+//! let mut atlas = GlyphAtlas::default();
+//! let layout = layout_text(&fonts, &mut atlas, "HP: 100", font, 24.0, TextAlign::Left);
+//! assert!(layout.size.x > 0.0);
+//! ```
+
+use std::collections::HashMap;
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use anvilkit_core::error::{AnvilKitError, Result};
+
+use super::sprite::AtlasRect;
+use super::RenderDevice;
+
+/// 字体句柄（[`Fonts`] 中的索引）
+///
+/// 默认句柄指向第一个加载的字体。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FontHandle(pub u32);
+
+/// 已加载字体表
+#[derive(Resource, Default)]
+pub struct Fonts {
+    fonts: Vec<fontdue::Font>,
+    families: HashMap<String, FontHandle>,
+}
+
+impl Fonts {
+    /// 从内存中的 TTF / OTF 数据加载字体
+    pub fn load_bytes(&mut self, bytes: &[u8]) -> Result<FontHandle> {
+        let font = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default())
+            .map_err(|e| AnvilKitError::asset(format!("解析字体失败: {}", e)))?;
+        self.fonts.push(font);
+        Ok(FontHandle(self.fonts.len() as u32 - 1))
+    }
+
+    /// 从文件加载字体
+    pub fn load_file(&mut self, path: &str) -> Result<FontHandle> {
+        let bytes = std::fs::read(path)
+            .map_err(|e| AnvilKitError::asset_with_path(format!("读取字体失败: {}", e), path))?;
+        self.load_bytes(&bytes)
+    }
+
+    /// 为字体登记族名（对应 `UiText::font_family`）
+    pub fn register_family(&mut self, family: impl Into<String>, handle: FontHandle) {
+        self.families.insert(family.into(), handle);
+    }
+
+    /// 按族名查找字体，未登记时返回默认句柄
+    pub fn family(&self, family: &str) -> FontHandle {
+        self.families.get(family).copied().unwrap_or_default()
+    }
+
+    /// 获取 fontdue 字体
+    pub fn get(&self, handle: FontHandle) -> Option<&fontdue::Font> {
+        self.fonts.get(handle.0 as usize)
+    }
+
+    /// 已加载字体数量
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// 是否没有字体
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+}
+
+/// 文本水平对齐
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    /// 左对齐（锚点为左边缘）
+    #[default]
+    Left,
+    /// 居中（锚点为中线）
+    Center,
+    /// 右对齐（锚点为右边缘）
+    Right,
+}
+
+impl TextAlign {
+    /// 宽度为 `line_width` 的行相对锚点的水平偏移
+    pub fn offset(self, line_width: f32) -> f32 {
+        match self {
+            Self::Left => 0.0,
+            Self::Center => -line_width * 0.5,
+            Self::Right => -line_width,
+        }
+    }
+}
+
+/// 图集中的字形
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasGlyph {
+    /// 图集 UV
+    pub uv: AtlasRect,
+    /// 位图尺寸（像素）
+    pub size: Vec2,
+    /// 位图左下角相对笔位 / 基线的偏移（fontdue `xmin` / `ymin`，y 向上）
+    pub offset: Vec2,
+    /// 水平前进量
+    pub advance: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GlyphKey {
+    font: FontHandle,
+    ch: char,
+    /// 字号 × 4，四分之一像素精度
+    size: u32,
+}
+
+/// 字形图集 (Resource)
+///
+/// CPU 端维护 RGBA 图集（白色 + 覆盖率 alpha，与精灵着色器相乘即得文字颜色），
+/// 使用行式（shelf）打包。新字形写入后标记为脏，[`GlyphAtlas::upload`] 再上传到 GPU。
+/// 图集写满时清空并重建——下一帧布局会重新光栅化仍在使用的字形。
+#[derive(Resource)]
+pub struct GlyphAtlas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    glyphs: HashMap<GlyphKey, AtlasGlyph>,
+    cursor: (u32, u32),
+    shelf_height: u32,
+    dirty: bool,
+    generation: u32,
+    gpu: Option<(wgpu::Texture, wgpu::BindGroup)>,
+}
+
+impl Default for GlyphAtlas {
+    fn default() -> Self {
+        Self::new(1024, 1024)
+    }
+}
+
+/// 字形之间的留白（像素），避免线性采样串色
+const GLYPH_PADDING: u32 = 1;
+
+impl GlyphAtlas {
+    /// 创建指定尺寸的空图集
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; (width * height * 4) as usize],
+            glyphs: HashMap::new(),
+            cursor: (GLYPH_PADDING, GLYPH_PADDING),
+            shelf_height: 0,
+            dirty: true,
+            generation: 0,
+            gpu: None,
+        }
+    }
+
+    /// 图集尺寸
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// 已缓存字形数量
+    pub fn glyph_count(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// 图集被清空重建的次数（布局缓存可据此失效）
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+
+    /// 是否有尚未上传的修改
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// 清空所有字形
+    pub fn clear(&mut self) {
+        self.pixels.fill(0);
+        self.glyphs.clear();
+        self.cursor = (GLYPH_PADDING, GLYPH_PADDING);
+        self.shelf_height = 0;
+        self.dirty = true;
+        self.generation += 1;
+    }
+
+    /// 在图集中分配 `w × h` 区域，空间不足返回 `None`
+    fn allocate(&mut self, w: u32, h: u32) -> Option<(u32, u32)> {
+        if w + 2 * GLYPH_PADDING > self.width || h + 2 * GLYPH_PADDING > self.height {
+            return None;
+        }
+        if self.cursor.0 + w + GLYPH_PADDING > self.width {
+            // 换到新的一行
+            self.cursor = (GLYPH_PADDING, self.cursor.1 + self.shelf_height + GLYPH_PADDING);
+            self.shelf_height = 0;
+        }
+        if self.cursor.1 + h + GLYPH_PADDING > self.height {
+            return None;
+        }
+        let pos = self.cursor;
+        self.cursor.0 += w + GLYPH_PADDING;
+        self.shelf_height = self.shelf_height.max(h);
+        Some(pos)
+    }
+
+    /// 写入覆盖率位图并登记字形
+    fn insert(&mut self, key: GlyphKey, width: u32, height: u32, coverage: &[u8], offset: Vec2, advance: f32) -> Option<AtlasGlyph> {
+        let (x, y) = self.allocate(width, height)?;
+        for row in 0..height {
+            for col in 0..width {
+                let src = coverage[(row * width + col) as usize];
+                let dst = (((y + row) * self.width + x + col) * 4) as usize;
+                self.pixels[dst..dst + 4].copy_from_slice(&[255, 255, 255, src]);
+            }
+        }
+        let (aw, ah) = (self.width as f32, self.height as f32);
+        let glyph = AtlasGlyph {
+            uv: AtlasRect::new(x as f32 / aw, y as f32 / ah, (x + width) as f32 / aw, (y + height) as f32 / ah),
+            size: Vec2::new(width as f32, height as f32),
+            offset,
+            advance,
+        };
+        self.glyphs.insert(key, glyph);
+        self.dirty = true;
+        Some(glyph)
+    }
+
+    /// 获取字形，不在图集中时光栅化并打包
+    pub fn glyph(&mut self, fonts: &Fonts, font: FontHandle, ch: char, px: f32) -> Option<AtlasGlyph> {
+        let key = GlyphKey { font, ch, size: (px * 4.0).round() as u32 };
+        if let Some(glyph) = self.glyphs.get(&key) {
+            return Some(*glyph);
+        }
+        let face = fonts.get(font)?;
+        let (metrics, coverage) = face.rasterize(ch, key.size as f32 / 4.0);
+        let offset = Vec2::new(metrics.xmin as f32, metrics.ymin as f32);
+        let (w, h) = (metrics.width as u32, metrics.height as u32);
+
+        if let Some(glyph) = self.insert(key, w, h, &coverage, offset, metrics.advance_width) {
+            return Some(glyph);
+        }
+        // 图集已满：清空后重试一次
+        log::warn!("字形图集已满（{} 个字形），清空重建", self.glyphs.len());
+        self.clear();
+        self.insert(key, w, h, &coverage, offset, metrics.advance_width)
+    }
+
+    /// 上传图集到 GPU（仅在有修改时写入）
+    ///
+    /// `layout` 为精灵渲染器的纹理绑定组布局（`SpriteRenderer::texture_bind_group_layout`）。
+    pub fn upload(&mut self, device: &RenderDevice, layout: &wgpu::BindGroupLayout) {
+        let size = wgpu::Extent3d { width: self.width, height: self.height, depth_or_array_layers: 1 };
+        if self.gpu.is_none() {
+            let texture = device.device().create_texture(&wgpu::TextureDescriptor {
+                label: Some("Glyph Atlas"),
+                size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let sampler = device.device().create_sampler(&wgpu::SamplerDescriptor {
+                label: Some("Glyph Atlas Sampler"),
+                mag_filter: wgpu::FilterMode::Linear,
+                min_filter: wgpu::FilterMode::Linear,
+                ..Default::default()
+            });
+            let bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Glyph Atlas BG"),
+                layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+                ],
+            });
+            self.gpu = Some((texture, bind_group));
+            self.dirty = true;
+        }
+        if !self.dirty {
+            return;
+        }
+        let (texture, _) = self.gpu.as_ref().unwrap();
+        device.queue().write_texture(
+            wgpu::ImageCopyTexture {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            &self.pixels,
+            wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * self.width),
+                rows_per_image: Some(self.height),
+            },
+            size,
+        );
+        self.dirty = false;
+    }
+
+    /// 图集纹理的绑定组（首次 `upload` 之后可用）
+    pub fn bind_group(&self) -> Option<&wgpu::BindGroup> {
+        self.gpu.as_ref().map(|(_, bg)| bg)
+    }
+}
+
+/// 已定位的字形四边形
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionedGlyph {
+    /// 左上角（相对锚点，像素，y 向下）
+    pub min: Vec2,
+    /// 右下角
+    pub max: Vec2,
+    /// 图集 UV
+    pub uv: AtlasRect,
+}
+
+/// 文本布局结果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextLayout {
+    /// 字形四边形
+    pub glyphs: Vec<PositionedGlyph>,
+    /// 文本包围盒尺寸
+    pub size: Vec2,
+}
+
+/// 排版文本：按 `\n` 分行，应用字距调整与对齐
+///
+/// 坐标原点为锚点，y 向下；第一行顶端位于 y = 0。字体不存在时返回空布局。
+pub fn layout_text(fonts: &Fonts, atlas: &mut GlyphAtlas, text: &str, font: FontHandle, px: f32, align: TextAlign) -> TextLayout {
+    let Some(face) = fonts.get(font) else { return TextLayout::default() };
+    let (ascent, line_height) = face
+        .horizontal_line_metrics(px)
+        .map(|m| (m.ascent, m.new_line_size))
+        .unwrap_or((px * 0.8, px * 1.2));
+
+    let mut layout = TextLayout::default();
+    for (line_index, line) in text.split('\n').enumerate() {
+        let baseline = ascent + line_index as f32 * line_height;
+        let first = layout.glyphs.len();
+        let mut pen = 0.0;
+        let mut prev = None;
+
+        for ch in line.chars() {
+            if let Some(prev) = prev {
+                pen += face.horizontal_kern(prev, ch, px).unwrap_or(0.0);
+            }
+            prev = Some(ch);
+            let Some(glyph) = atlas.glyph(fonts, font, ch, px) else { continue };
+            if glyph.size.x > 0.0 && glyph.size.y > 0.0 {
+                let min = Vec2::new(pen + glyph.offset.x, baseline - glyph.offset.y - glyph.size.y);
+                layout.glyphs.push(PositionedGlyph { min, max: min + glyph.size, uv: glyph.uv });
+            }
+            pen += glyph.advance;
+        }
+
+        let shift = align.offset(pen);
+        for glyph in &mut layout.glyphs[first..] {
+            glyph.min.x += shift;
+            glyph.max.x += shift;
+        }
+        layout.size.x = layout.size.x.max(pen);
+        layout.size.y = (line_index + 1) as f32 * line_height;
+    }
+    layout
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_offsets() {
+        assert_eq!(TextAlign::Left.offset(100.0), 0.0);
+        assert_eq!(TextAlign::Center.offset(100.0), -50.0);
+        assert_eq!(TextAlign::Right.offset(100.0), -100.0);
+    }
+
+    #[test]
+    fn test_shelf_packing_and_overflow() {
+        let mut atlas = GlyphAtlas::new(32, 32);
+        let key = |ch| GlyphKey { font: FontHandle(0), ch, size: 64 };
+        let coverage = [128u8; 100];
+
+        let a = atlas.insert(key('a'), 10, 10, &coverage, Vec2::ZERO, 10.0).unwrap();
+        let b = atlas.insert(key('b'), 10, 10, &coverage, Vec2::ZERO, 10.0).unwrap();
+        // 同一行相邻放置，中间留 1 像素
+        assert_eq!(a.uv.u_min * 32.0, 1.0);
+        assert_eq!(b.uv.u_min * 32.0, 12.0);
+        // 第三个放不下，换行
+        let c = atlas.insert(key('c'), 10, 10, &coverage, Vec2::ZERO, 10.0).unwrap();
+        assert_eq!(c.uv.v_min * 32.0, 12.0);
+        // 覆盖率写入 alpha 通道
+        let px = ((32 + 1) * 4) as usize;
+        assert_eq!(&atlas.pixels[px..px + 4], &[255, 255, 255, 128]);
+
+        assert!(atlas.insert(key('d'), 10, 10, &coverage, Vec2::ZERO, 10.0).is_some());
+        assert!(atlas.insert(key('e'), 10, 10, &coverage, Vec2::ZERO, 10.0).is_none());
+
+        atlas.clear();
+        assert_eq!(atlas.glyph_count(), 0);
+        assert_eq!(atlas.generation(), 1);
+    }
+
+    #[test]
+    fn test_invalid_font_and_missing_handle() {
+        let mut fonts = Fonts::default();
+        assert!(fonts.load_bytes(b"not a font").is_err());
+        assert!(fonts.is_empty());
+        assert_eq!(fonts.family("missing"), FontHandle(0));
+
+        let mut atlas = GlyphAtlas::default();
+        let layout = layout_text(&fonts, &mut atlas, "abc", FontHandle(0), 16.0, TextAlign::Left);
+        assert!(layout.glyphs.is_empty());
+    }
+}
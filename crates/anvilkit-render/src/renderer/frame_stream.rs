@@ -0,0 +1,323 @@
+//! # 帧流输出
+//!
+//! 将每一帧呈现的画面以固定格式输出到管道或共享内存环形缓冲，
+//! 供外部录制 / 推流工具或测试工具直接消费引擎输出，无需屏幕捕获。
+//! 通过 `capture` feature 启用，复用 [`CaptureResources`](super::capture::CaptureResources) 的像素回读。
+//!
+//! ## 帧格式（所有整数均为小端序）
+//!
+//! 每帧由 32 字节帧头 + 像素数据组成：
+//!
+//! | 偏移 | 类型    | 含义                                   |
+//! |------|---------|----------------------------------------|
+//! | 0    | `[u8;4]`| 魔数 `b"AKFS"`                         |
+//! | 4    | `u32`   | 格式版本（当前为 1）                   |
+//! | 8    | `u32`   | 宽度（像素）                           |
+//! | 12   | `u32`   | 高度（像素）                           |
+//! | 16   | `u32`   | 像素格式（1 = RGBA8 sRGB，行优先、无 padding）|
+//! | 20   | `u32`   | 每行字节数（`width * 4`）              |
+//! | 24   | `u64`   | 帧序号（从 0 开始）                    |
+//!
+//! 随后是 `height * stride` 字节像素数据。管道模式下帧依次首尾相接写出。
+//!
+//! ## 环形缓冲文件布局
+//!
+//! 文件开头为 64 字节缓冲头，之后是 `slot_count` 个大小为 `slot_size` 的槽位，
+//! 每个槽位存放一帧（帧头 + 像素）：
+//!
+//! | 偏移 | 类型    | 含义                                   |
+//! |------|---------|----------------------------------------|
+//! | 0    | `[u8;4]`| 魔数 `b"AKRB"`                         |
+//! | 4    | `u32`   | 格式版本（当前为 1）                   |
+//! | 8    | `u32`   | 槽位数量                               |
+//! | 12   | `u32`   | 槽位大小（字节）                       |
+//! | 16   | `u64`   | 已写入帧总数 `write_seq`               |
+//! | 24   | —       | 保留（置 0）                           |
+//!
+//! 最新一帧位于槽位 `(write_seq - 1) % slot_count`。写入方先写完槽位再更新
+//! `write_seq`；读取方应在读槽前后各读一次 `write_seq`，若期间前进了
+//! `slot_count` 帧以上则该槽位已被覆盖。将路径放在 `/dev/shm` 下即可
+//! 作为共享内存使用（消费者 `mmap` 该文件）。分辨率变化时文件会按新尺寸重建。
+
+use std::fs::File;
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use bevy_ecs::prelude::Resource;
+
+/// 帧头魔数
+pub const FRAME_MAGIC: [u8; 4] = *b"AKFS";
+/// 环形缓冲头魔数
+pub const RING_MAGIC: [u8; 4] = *b"AKRB";
+/// 格式版本
+pub const FRAME_STREAM_VERSION: u32 = 1;
+/// 帧头大小（字节）
+pub const FRAME_HEADER_SIZE: usize = 32;
+/// 环形缓冲头大小（字节）
+pub const RING_HEADER_SIZE: usize = 64;
+/// 像素格式：RGBA8 sRGB
+pub const PIXEL_FORMAT_RGBA8: u32 = 1;
+
+/// 编码帧头
+pub fn encode_frame_header(width: u32, height: u32, frame_index: u64) -> [u8; FRAME_HEADER_SIZE] {
+    let mut h = [0u8; FRAME_HEADER_SIZE];
+    h[0..4].copy_from_slice(&FRAME_MAGIC);
+    h[4..8].copy_from_slice(&FRAME_STREAM_VERSION.to_le_bytes());
+    h[8..12].copy_from_slice(&width.to_le_bytes());
+    h[12..16].copy_from_slice(&height.to_le_bytes());
+    h[16..20].copy_from_slice(&PIXEL_FORMAT_RGBA8.to_le_bytes());
+    h[20..24].copy_from_slice(&(width * 4).to_le_bytes());
+    h[24..32].copy_from_slice(&frame_index.to_le_bytes());
+    h
+}
+
+/// 解析帧头，返回 `(width, height, frame_index)`
+pub fn decode_frame_header(bytes: &[u8]) -> Option<(u32, u32, u64)> {
+    if bytes.len() < FRAME_HEADER_SIZE || bytes[0..4] != FRAME_MAGIC {
+        return None;
+    }
+    let u32_at = |o: usize| u32::from_le_bytes(bytes[o..o + 4].try_into().unwrap());
+    if u32_at(4) != FRAME_STREAM_VERSION || u32_at(16) != PIXEL_FORMAT_RGBA8 {
+        return None;
+    }
+    let index = u64::from_le_bytes(bytes[24..32].try_into().unwrap());
+    Some((u32_at(8), u32_at(12), index))
+}
+
+/// 帧流输出目标
+enum FrameSink {
+    /// 顺序写出（stdout / 管道 / 任意 Writer）
+    Pipe(Box<dyn Write + Send + Sync>),
+    /// 文件映射的环形缓冲
+    Ring(RingBuffer),
+}
+
+/// 环形缓冲文件写入器
+struct RingBuffer {
+    path: PathBuf,
+    slot_count: u32,
+    file: Option<File>,
+    slot_size: u32,
+    write_seq: u64,
+}
+
+impl RingBuffer {
+    /// 按帧尺寸（重新）创建文件
+    fn ensure(&mut self, frame_bytes: usize) -> io::Result<()> {
+        let slot_size = (FRAME_HEADER_SIZE + frame_bytes) as u32;
+        if self.file.is_none() || self.slot_size != slot_size {
+            if let Some(parent) = self.path.parent() {
+                std::fs::create_dir_all(parent).ok();
+            }
+            let file = File::options().read(true).write(true).create(true).truncate(true).open(&self.path)?;
+            file.set_len(RING_HEADER_SIZE as u64 + slot_size as u64 * self.slot_count as u64)?;
+            self.slot_size = slot_size;
+            self.write_seq = 0;
+            self.file = Some(file);
+            self.write_header()?;
+        }
+        Ok(())
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        let header = encode_ring_header(self.slot_count, self.slot_size, self.write_seq);
+        let file = self.file.as_mut().unwrap();
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(&header)
+    }
+
+    fn push(&mut self, header: &[u8], pixels: &[u8]) -> io::Result<()> {
+        self.ensure(pixels.len())?;
+        let slot = self.write_seq % self.slot_count as u64;
+        let offset = RING_HEADER_SIZE as u64 + slot * self.slot_size as u64;
+        let file = self.file.as_mut().unwrap();
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(header)?;
+        file.write_all(pixels)?;
+        self.write_seq += 1;
+        self.write_header()
+    }
+}
+
+/// 编码环形缓冲头
+pub fn encode_ring_header(slot_count: u32, slot_size: u32, write_seq: u64) -> [u8; RING_HEADER_SIZE] {
+    let mut h = [0u8; RING_HEADER_SIZE];
+    h[0..4].copy_from_slice(&RING_MAGIC);
+    h[4..8].copy_from_slice(&FRAME_STREAM_VERSION.to_le_bytes());
+    h[8..12].copy_from_slice(&slot_count.to_le_bytes());
+    h[12..16].copy_from_slice(&slot_size.to_le_bytes());
+    h[16..24].copy_from_slice(&write_seq.to_le_bytes());
+    h
+}
+
+/// 帧流输出资源（ECS Resource）
+///
+/// 插入 World 后，渲染循环每帧回读呈现画面并按[模块文档](self)描述的格式写出。
+/// 写出失败时记录错误并停用该输出。
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use anvilkit_render::renderer::frame_stream::FrameStream;
+///
+/// // 输出到 stdout，例如 `game | ffmpeg ...`（需自行解析帧头）
+/// let stream = FrameStream::stdout();
+///
+/// // 输出到共享内存环形缓冲（4 个槽位）
+/// let stream = FrameStream::ring_buffer("/dev/shm/anvilkit_frames", 4);
+/// ```
+#[derive(Resource)]
+pub struct FrameStream {
+    sink: FrameSink,
+    /// 是否启用输出
+    pub enabled: bool,
+    frame_index: u64,
+}
+
+impl FrameStream {
+    /// 输出到任意 Writer（管道、Socket、内存缓冲等）
+    pub fn pipe(writer: impl Write + Send + Sync + 'static) -> Self {
+        Self { sink: FrameSink::Pipe(Box::new(writer)), enabled: true, frame_index: 0 }
+    }
+
+    /// 输出到标准输出
+    ///
+    /// 注意此时日志不应再写入 stdout。
+    pub fn stdout() -> Self {
+        Self::pipe(io::stdout())
+    }
+
+    /// 输出到环形缓冲文件（放在 `/dev/shm` 下即为共享内存）
+    pub fn ring_buffer(path: impl Into<PathBuf>, slot_count: u32) -> Self {
+        Self {
+            sink: FrameSink::Ring(RingBuffer {
+                path: path.into(),
+                slot_count: slot_count.max(1),
+                file: None,
+                slot_size: 0,
+                write_seq: 0,
+            }),
+            enabled: true,
+            frame_index: 0,
+        }
+    }
+
+    /// 解析命令行参数：`--stream-stdout` 或 `--stream-shm <path> [--stream-slots <n>]`
+    pub fn from_args() -> Option<Self> {
+        let args: Vec<String> = std::env::args().collect();
+        let value = |flag: &str| {
+            args.iter().position(|a| a == flag).and_then(|i| args.get(i + 1)).cloned()
+        };
+        if args.iter().any(|a| a == "--stream-stdout") {
+            return Some(Self::stdout());
+        }
+        let path = value("--stream-shm")?;
+        let slots = value("--stream-slots").and_then(|s| s.parse().ok()).unwrap_or(3);
+        Some(Self::ring_buffer(path, slots))
+    }
+
+    /// 已写出的帧数
+    pub fn frame_index(&self) -> u64 {
+        self.frame_index
+    }
+
+    /// 环形缓冲文件路径（管道模式返回 `None`）
+    pub fn ring_path(&self) -> Option<&Path> {
+        match &self.sink {
+            FrameSink::Ring(r) => Some(&r.path),
+            FrameSink::Pipe(_) => None,
+        }
+    }
+
+    /// 写出一帧 RGBA8 像素
+    pub fn write_frame(&mut self, pixels: &[u8], width: u32, height: u32) -> io::Result<()> {
+        debug_assert_eq!(pixels.len(), (width * height * 4) as usize);
+        let header = encode_frame_header(width, height, self.frame_index);
+        match &mut self.sink {
+            FrameSink::Pipe(w) => {
+                w.write_all(&header)?;
+                w.write_all(pixels)?;
+                w.flush()?;
+            }
+            FrameSink::Ring(ring) => ring.push(&header, pixels)?,
+        }
+        self.frame_index += 1;
+        Ok(())
+    }
+
+    /// 写出一帧；失败时记录错误并停用
+    pub fn submit(&mut self, pixels: &[u8], width: u32, height: u32) {
+        if !self.enabled {
+            return;
+        }
+        if let Err(e) = self.write_frame(pixels, width, height) {
+            log::error!("帧流输出失败，已停用: {}", e);
+            self.enabled = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_frame_header_roundtrip() {
+        let h = encode_frame_header(640, 360, 42);
+        assert_eq!(&h[0..4], b"AKFS");
+        assert_eq!(u32::from_le_bytes(h[20..24].try_into().unwrap()), 640 * 4);
+        assert_eq!(decode_frame_header(&h), Some((640, 360, 42)));
+        assert_eq!(decode_frame_header(&h[..16]), None);
+    }
+
+    #[test]
+    fn test_pipe_writes_consecutive_frames() {
+        let buf = SharedBuf::default();
+        let mut stream = FrameStream::pipe(buf.clone());
+        let pixels = vec![7u8; 2 * 2 * 4];
+        stream.submit(&pixels, 2, 2);
+        stream.submit(&pixels, 2, 2);
+
+        let data = buf.0.lock().unwrap();
+        let frame_len = FRAME_HEADER_SIZE + pixels.len();
+        assert_eq!(data.len(), frame_len * 2);
+        assert_eq!(decode_frame_header(&data[frame_len..]), Some((2, 2, 1)));
+        assert_eq!(stream.frame_index(), 2);
+    }
+
+    #[test]
+    fn test_ring_buffer_layout() {
+        let path = std::env::temp_dir().join(format!("anvilkit_ring_{}.bin", std::process::id()));
+        let mut stream = FrameStream::ring_buffer(&path, 2);
+        for i in 0..3u8 {
+            stream.submit(&[i; 4], 1, 1);
+        }
+        assert!(stream.enabled);
+
+        let data = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let slot_size = FRAME_HEADER_SIZE + 4;
+        assert_eq!(data.len(), RING_HEADER_SIZE + slot_size * 2);
+        assert_eq!(data[..RING_HEADER_SIZE], encode_ring_header(2, slot_size as u32, 3));
+
+        // 第 3 帧（序号 2）覆盖槽位 0
+        let slot0 = &data[RING_HEADER_SIZE..];
+        assert_eq!(decode_frame_header(slot0), Some((1, 1, 2)));
+        assert_eq!(&slot0[FRAME_HEADER_SIZE..FRAME_HEADER_SIZE + 4], &[2; 4]);
+        let slot1 = &data[RING_HEADER_SIZE + slot_size..];
+        assert_eq!(decode_frame_header(slot1), Some((1, 1, 1)));
+    }
+}
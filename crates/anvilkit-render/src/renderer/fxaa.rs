@@ -0,0 +1,279 @@
+//! # FXAA 抗锯齿后处理
+//!
+//! 快速近似抗锯齿：在 tonemap 之后的 LDR 图像上做一次全屏 pass，
+//! 按亮度对比度检测边缘，沿边缘搜索端点后在法线方向混合相邻像素。
+//!
+//! 通过 [`PostProcessSettings::fxaa`](crate::renderer::post_process::PostProcessSettings::fxaa)
+//! 启用。启用后 tonemap pass 先写入与交换链同格式的中间纹理，FXAA pass 再输出到交换链，
+//! 因此它总是以交换链分辨率运行，位于后处理栈的最后。
+
+use anvilkit_describe::Describe;
+use crate::renderer::RenderDevice;
+
+const FXAA_SHADER: &str = include_str!("../shaders/fxaa.wgsl");
+
+/// FXAA 配置
+#[derive(Debug, Clone, PartialEq, Describe)]
+/// Fast approximate anti-aliasing settings.
+pub struct FxaaSettings {
+    /// Minimum local contrast, relative to the brightest neighbour, treated as an edge.
+    #[describe(hint = "Relative edge threshold (lower = more edges)", range = "0.063..0.333", default = "0.125")]
+    pub edge_threshold: f32,
+    /// Absolute contrast below which dark pixels are skipped.
+    #[describe(hint = "Skip edges darker than this", range = "0.0..0.1", default = "0.0312")]
+    pub edge_threshold_min: f32,
+    /// Amount of sub-pixel aliasing removal.
+    #[describe(hint = "Sub-pixel smoothing (0 = off, 1 = softest)", range = "0.0..1.0", default = "0.75")]
+    pub subpixel: f32,
+}
+
+impl Default for FxaaSettings {
+    fn default() -> Self {
+        Self {
+            edge_threshold: 0.125,
+            edge_threshold_min: 0.0312,
+            subpixel: 0.75,
+        }
+    }
+}
+
+/// FXAA GPU uniform (32 字节)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct FxaaUniform {
+    /// 1 / output size in pixels.
+    pub inv_size: [f32; 2],
+    /// Relative edge threshold.
+    pub edge_threshold: f32,
+    /// Absolute edge threshold.
+    pub edge_threshold_min: f32,
+    /// Sub-pixel blend amount.
+    pub subpixel: f32,
+    /// Alignment padding.
+    pub _padding: [f32; 3],
+}
+
+impl FxaaUniform {
+    /// 由设置与输出尺寸生成，参数被钳制到有效范围
+    pub fn new(settings: &FxaaSettings, width: u32, height: u32) -> Self {
+        Self {
+            inv_size: [1.0 / width.max(1) as f32, 1.0 / height.max(1) as f32],
+            edge_threshold: settings.edge_threshold.clamp(0.0, 1.0),
+            edge_threshold_min: settings.edge_threshold_min.clamp(0.0, 1.0),
+            subpixel: settings.subpixel.clamp(0.0, 1.0),
+            _padding: [0.0; 3],
+        }
+    }
+}
+
+/// FXAA GPU 资源
+pub struct FxaaResources {
+    /// Tonemapped LDR input (swapchain format, swapchain size).
+    pub input_texture: wgpu::Texture,
+    /// Input texture view — the tonemap pass renders here while FXAA is active.
+    pub input_view: wgpu::TextureView,
+    /// FXAA pipeline, writing to the swapchain format.
+    pub pipeline: wgpu::RenderPipeline,
+    /// Bind group layout (texture + sampler + uniform).
+    pub bind_group_layout: wgpu::BindGroupLayout,
+    /// Bind group over the current input view.
+    pub bind_group: wgpu::BindGroup,
+    /// Linear sampler.
+    pub sampler: wgpu::Sampler,
+    /// Uniform buffer.
+    pub uniform_buffer: wgpu::Buffer,
+    /// Input / output texture format.
+    pub format: wgpu::TextureFormat,
+    /// Output size the resources were created for.
+    pub size: (u32, u32),
+}
+
+impl FxaaResources {
+    /// Create FXAA GPU resources for a `width × height` target of `format`.
+    pub fn new(device: &RenderDevice, width: u32, height: u32, format: wgpu::TextureFormat) -> Self {
+        let (input_texture, input_view) = Self::create_input_texture(device, width, height, format);
+
+        let sampler = device.device().create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("FXAA Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let uniform_buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("FXAA Uniform"),
+            size: std::mem::size_of::<FxaaUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("FXAA Shader"),
+            source: wgpu::ShaderSource::Wgsl(FXAA_SHADER.into()),
+        });
+
+        let bind_group_layout = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("FXAA BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+
+        let layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("FXAA Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("FXAA Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState { module: &shader, entry_point: "vs_main", buffers: &[] },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        });
+
+        let bind_group = Self::create_bind_group(device, &bind_group_layout, &input_view, &sampler, &uniform_buffer);
+
+        Self {
+            input_texture,
+            input_view,
+            pipeline,
+            bind_group_layout,
+            bind_group,
+            sampler,
+            uniform_buffer,
+            format,
+            size: (width, height),
+        }
+    }
+
+    fn create_input_texture(device: &RenderDevice, w: u32, h: u32, format: wgpu::TextureFormat) -> (wgpu::Texture, wgpu::TextureView) {
+        let tex = device.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("FXAA Input"),
+            size: wgpu::Extent3d { width: w.max(1), height: h.max(1), depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = tex.create_view(&wgpu::TextureViewDescriptor::default());
+        (tex, view)
+    }
+
+    fn create_bind_group(
+        device: &RenderDevice,
+        layout: &wgpu::BindGroupLayout,
+        input_view: &wgpu::TextureView,
+        sampler: &wgpu::Sampler,
+        uniform_buffer: &wgpu::Buffer,
+    ) -> wgpu::BindGroup {
+        device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("FXAA BG"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(input_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: uniform_buffer.as_entire_binding() },
+            ],
+        })
+    }
+
+    /// Rebuild the input texture on resize; no-op when the size is unchanged.
+    pub fn resize(&mut self, device: &RenderDevice, width: u32, height: u32) {
+        if self.size == (width, height) {
+            return;
+        }
+        let (tex, view) = Self::create_input_texture(device, width, height, self.format);
+        self.bind_group = Self::create_bind_group(device, &self.bind_group_layout, &view, &self.sampler, &self.uniform_buffer);
+        self.input_texture = tex;
+        self.input_view = view;
+        self.size = (width, height);
+    }
+
+    /// Run FXAA from [`input_view`](Self::input_view) into `target`.
+    pub fn execute(
+        &self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        settings: &FxaaSettings,
+    ) {
+        let uniform = FxaaUniform::new(settings, self.size.0, self.size.1);
+        device.queue().write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniform));
+
+        let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("FXAA Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rp.set_pipeline(&self.pipeline);
+        rp.set_bind_group(0, &self.bind_group, &[]);
+        rp.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_layout_and_clamping() {
+        assert_eq!(std::mem::size_of::<FxaaUniform>(), 32);
+
+        let uniform = FxaaUniform::new(&FxaaSettings::default(), 1920, 1080);
+        assert_eq!(uniform.inv_size, [1.0 / 1920.0, 1.0 / 1080.0]);
+        assert_eq!(uniform.subpixel, 0.75);
+
+        let wild = FxaaSettings { edge_threshold: -1.0, edge_threshold_min: 0.5, subpixel: 3.0 };
+        let uniform = FxaaUniform::new(&wild, 0, 0);
+        assert_eq!(uniform.inv_size, [1.0, 1.0]);
+        assert_eq!(uniform.edge_threshold, 0.0);
+        assert_eq!(uniform.subpixel, 1.0);
+    }
+}
@@ -0,0 +1,289 @@
+//! # GPU 计时
+//!
+//! 在渲染循环的每个 pass 前后于编码器上写入时间戳查询（`Features::TIMESTAMP_QUERY`），帧末解析到回读缓冲并异步映射，
+//! 若干帧后读出每个 pass 的 GPU 毫秒数写入 [`Diagnostics`]（`gpu/<pass>` 与 [`GPU_FRAME_TIME`]）。
+//!
+//! 添加 [`GpuProfilingPlugin`] 即可启用；适配器不支持时间戳时会输出警告并自动关闭。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::gpu_profiler::scope_durations_ms;
+//!
+//! // 时间戳周期 1ns：两个 pass 分别耗时 2ms 与 0.5ms
+//! let timestamps = [0, 2_000_000, 2_000_000, 2_500_000];
+//! assert_eq!(scope_durations_ms(&timestamps, 1.0), vec![2.0, 0.5]);
+//! ```
+//!
+//! [`Diagnostics`]: anvilkit_core::diagnostics::Diagnostics
+//! [`GPU_FRAME_TIME`]: anvilkit_core::diagnostics::GPU_FRAME_TIME
+
+use std::sync::mpsc::{channel, Receiver};
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::diagnostics::{Diagnostics, GPU_FRAME_TIME, GPU_PASS_PREFIX};
+
+use super::RenderDevice;
+
+/// 每帧最多计时的 pass 数
+pub const MAX_GPU_SCOPES: u32 = 32;
+
+/// 回读缓冲数量（允许结果延迟的帧数）
+const READBACK_FRAMES: usize = 3;
+
+/// GPU 计时开关 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct GpuTimingSettings {
+    /// 为 `false` 时渲染循环不写时间戳
+    pub enabled: bool,
+}
+
+impl Default for GpuTimingSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// 单个 pass 的 GPU 耗时
+#[derive(Debug, Clone, PartialEq)]
+pub struct GpuPassTiming {
+    /// pass 名称
+    pub name: &'static str,
+    /// GPU 耗时（毫秒）
+    pub milliseconds: f64,
+}
+
+/// 把成对的 `[begin, end, begin, end, ...]` 时间戳换算为毫秒
+///
+/// `period_ns` 为每个时间戳刻度的纳秒数（`Queue::get_timestamp_period`）；
+/// 结束早于开始（计数器回绕或驱动异常）的 pass 记为 0。
+pub fn scope_durations_ms(timestamps: &[u64], period_ns: f32) -> Vec<f64> {
+    timestamps
+        .chunks_exact(2)
+        .map(|pair| pair[1].saturating_sub(pair[0]) as f64 * period_ns as f64 / 1_000_000.0)
+        .collect()
+}
+
+struct Readback {
+    buffer: wgpu::Buffer,
+    scopes: Vec<&'static str>,
+    mapping: Option<Receiver<Result<(), wgpu::BufferAsyncError>>>,
+    in_flight: bool,
+}
+
+/// 时间戳查询的录制、解析与回读
+///
+/// 由渲染循环持有，调用顺序：`begin_frame` → 若干对 `begin_scope` / `end_scope`
+/// → `resolve`（提交前）→ `after_submit`（提交后）→ `collect`。
+pub struct GpuProfiler {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    readbacks: Vec<Readback>,
+    /// 本帧写入的回读缓冲；没有空闲缓冲时本帧不计时
+    current: Option<usize>,
+    scopes: Vec<&'static str>,
+    open: bool,
+    period_ns: f32,
+}
+
+impl GpuProfiler {
+    /// 设备是否支持在编码器中写时间戳
+    pub fn is_supported(device: &RenderDevice) -> bool {
+        device.device().features().contains(wgpu::Features::TIMESTAMP_QUERY)
+    }
+
+    /// 创建查询集与缓冲（调用前检查 [`is_supported`](Self::is_supported)）
+    pub fn new(device: &RenderDevice) -> Self {
+        let count = MAX_GPU_SCOPES * 2;
+        let size = count as u64 * wgpu::QUERY_SIZE as u64;
+        let query_set = device.device().create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("GPU Profiler Queries"),
+            ty: wgpu::QueryType::Timestamp,
+            count,
+        });
+        let resolve_buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("GPU Profiler Resolve"),
+            size,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readbacks = (0..READBACK_FRAMES)
+            .map(|_| Readback {
+                buffer: device.device().create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("GPU Profiler Readback"),
+                    size,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                scopes: Vec::new(),
+                mapping: None,
+                in_flight: false,
+            })
+            .collect();
+
+        Self {
+            query_set,
+            resolve_buffer,
+            readbacks,
+            current: None,
+            scopes: Vec::new(),
+            open: false,
+            period_ns: device.queue().get_timestamp_period(),
+        }
+    }
+
+    /// 开始新的一帧，选取空闲的回读缓冲
+    pub fn begin_frame(&mut self) {
+        self.scopes.clear();
+        self.open = false;
+        self.current = self.readbacks.iter().position(|r| !r.in_flight);
+    }
+
+    /// 在 pass 开始前写时间戳；上一个 scope 未结束时先结束它
+    pub fn begin_scope(&mut self, encoder: &mut wgpu::CommandEncoder, name: &'static str) {
+        if self.current.is_none() || self.scopes.len() as u32 >= MAX_GPU_SCOPES {
+            return;
+        }
+        if self.open {
+            self.end_scope(encoder);
+        }
+        encoder.write_timestamp(&self.query_set, self.scopes.len() as u32 * 2);
+        self.scopes.push(name);
+        self.open = true;
+    }
+
+    /// 在 pass 结束后写时间戳
+    pub fn end_scope(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        if !self.open {
+            return;
+        }
+        encoder.write_timestamp(&self.query_set, self.scopes.len() as u32 * 2 - 1);
+        self.open = false;
+    }
+
+    /// 解析本帧查询并复制到回读缓冲（提交前调用）
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        self.end_scope(encoder);
+        let Some(index) = self.current else { return };
+        if self.scopes.is_empty() {
+            self.current = None;
+            return;
+        }
+        let count = self.scopes.len() as u32 * 2;
+        encoder.resolve_query_set(&self.query_set, 0..count, &self.resolve_buffer, 0);
+        let readback = &mut self.readbacks[index];
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &readback.buffer,
+            0,
+            count as u64 * wgpu::QUERY_SIZE as u64,
+        );
+        readback.scopes = std::mem::take(&mut self.scopes);
+        readback.in_flight = true;
+    }
+
+    /// 请求异步映射本帧的回读缓冲（提交后调用）
+    pub fn after_submit(&mut self) {
+        let Some(index) = self.current.take() else { return };
+        let readback = &mut self.readbacks[index];
+        let size = readback.scopes.len() as u64 * 2 * wgpu::QUERY_SIZE as u64;
+        let (sender, receiver) = channel();
+        readback.buffer.slice(..size).map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        readback.mapping = Some(receiver);
+    }
+
+    /// 非阻塞地取回已完成映射的结果（最新一帧）
+    pub fn collect(&mut self, device: &RenderDevice) -> Option<Vec<GpuPassTiming>> {
+        device.device().poll(wgpu::Maintain::Poll);
+        let mut latest = None;
+        for readback in &mut self.readbacks {
+            let Some(receiver) = &readback.mapping else { continue };
+            let result = match receiver.try_recv() {
+                Ok(result) => result,
+                Err(std::sync::mpsc::TryRecvError::Empty) => continue,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => Err(wgpu::BufferAsyncError),
+            };
+            readback.mapping = None;
+            readback.in_flight = false;
+            if let Err(e) = result {
+                log::warn!("GPU 计时回读失败: {:?}", e);
+                continue;
+            }
+
+            let size = readback.scopes.len() as u64 * 2 * wgpu::QUERY_SIZE as u64;
+            let timestamps: Vec<u64> = {
+                let data = readback.buffer.slice(..size).get_mapped_range();
+                bytemuck::cast_slice(&data).to_vec()
+            };
+            readback.buffer.unmap();
+
+            let timings = scope_durations_ms(&timestamps, self.period_ns)
+                .into_iter()
+                .zip(readback.scopes.iter())
+                .map(|(milliseconds, name)| GpuPassTiming { name, milliseconds })
+                .collect();
+            latest = Some(timings);
+        }
+        latest
+    }
+}
+
+/// 把一帧的 pass 耗时写入 [`Diagnostics`]（首次出现的 pass 自动注册指标）
+pub fn record_gpu_timings(diagnostics: &mut Diagnostics, timings: &[GpuPassTiming]) {
+    let mut total = 0.0;
+    for timing in timings {
+        let name = format!("{}{}", GPU_PASS_PREFIX, timing.name);
+        diagnostics.register(&name, "ms");
+        diagnostics.add_measurement(&name, timing.milliseconds);
+        total += timing.milliseconds;
+    }
+    diagnostics.register(GPU_FRAME_TIME, "ms");
+    diagnostics.add_measurement(GPU_FRAME_TIME, total);
+}
+
+/// GPU 计时插件
+///
+/// 插入 [`GpuTimingSettings`]；未添加 [`DiagnosticsPlugin`](crate::diagnostics::DiagnosticsPlugin) 时一并添加。
+pub struct GpuProfilingPlugin;
+
+impl Plugin for GpuProfilingPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Diagnostics>() {
+            app.add_plugins(crate::diagnostics::DiagnosticsPlugin::default());
+        }
+        app.init_resource::<GpuTimingSettings>();
+    }
+
+    fn name(&self) -> &str {
+        "GpuProfilingPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_durations() {
+        assert_eq!(scope_durations_ms(&[100, 1_100, 5, 1], 2.0), vec![0.002, 0.0]);
+        // 不成对的尾部被忽略
+        assert_eq!(scope_durations_ms(&[0, 1_000_000, 7], 1.0), vec![1.0]);
+    }
+
+    #[test]
+    fn test_record_gpu_timings() {
+        let mut diagnostics = Diagnostics::default();
+        record_gpu_timings(
+            &mut diagnostics,
+            &[
+                GpuPassTiming { name: "scene", milliseconds: 3.0 },
+                GpuPassTiming { name: "tonemap", milliseconds: 0.5 },
+            ],
+        );
+        assert_eq!(diagnostics.value("gpu/scene"), Some(3.0));
+        assert_eq!(diagnostics.get("gpu/tonemap").unwrap().unit(), "ms");
+        assert_eq!(diagnostics.value(GPU_FRAME_TIME), Some(3.5));
+    }
+}
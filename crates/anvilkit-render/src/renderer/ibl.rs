@@ -1,6 +1,6 @@
 //! # IBL (Image-Based Lighting) 工具
 //!
-//! 提供 BRDF 积分 LUT 的 CPU 生成。
+//! 提供 BRDF 积分 LUT 的 CPU 生成，以及无天空盒时使用的纯色环境立方体。
 //! BRDF LUT 用于 PBR 渲染中的 split-sum 近似，
 //! 存储 (F0_scale, F0_bias) 以实现能量守恒的环境光镜面反射。
 
@@ -187,6 +187,44 @@ pub fn get_or_generate_brdf_lut(cache_path: &str, size: u32) -> Vec<u8> {
     data
 }
 
+/// Create a 1x1 solid-color environment cube.
+///
+/// Bound to the IBL group when no skybox is present (the format matches the
+/// skybox environment cube, so the same bind group layout accepts both).
+pub fn create_solid_environment_cube(
+    device: &crate::renderer::RenderDevice,
+    color: [u8; 4],
+    label: &str,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let texture = device.device().create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 6 },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    device.queue().write_texture(
+        wgpu::ImageCopyTexture {
+            texture: &texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &color.repeat(6),
+        wgpu::ImageDataLayout { offset: 0, bytes_per_row: Some(4), rows_per_image: Some(1) },
+        wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 6 },
+    );
+    let view = texture.create_view(&wgpu::TextureViewDescriptor {
+        label: Some(label),
+        dimension: Some(wgpu::TextureViewDimension::Cube),
+        ..Default::default()
+    });
+    (texture, view)
+}
+
 /// Smith GGX 几何函数 (IBL 版本，k = roughness² / 2)
 fn geometry_smith_ibl(n_dot_v: f32, n_dot_l: f32, roughness: f32) -> f32 {
     let a = roughness;
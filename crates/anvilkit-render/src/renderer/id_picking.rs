@@ -0,0 +1,662 @@
+//! # GPU 实体 ID 拾取
+//!
+//! 射线拾取只能对包围盒求交，密集的 2D 美术和 alpha 镂空精灵需要像素级精度。
+//! [`GpuPickingPlugin`] 在 [`PickingMode::GpuId`] 下每帧：
+//!
+//! 1. [`id_pick_collect_system`] 收集覆盖光标像素的可拾取精灵（与精灵相同的层级 / z 顺序）
+//! 2. [`IdPickRenderer`] 用只覆盖该像素的正交投影把它们画进 1×1 的 `Rg32Uint` 目标，
+//!    片元写入实体 ID，带 [`PickAlphaMask`] 的精灵按遮罩 alpha 镂空，最后绘制的（最上层）胜出
+//! 3. 像素复制到暂存缓冲，提交后 `map_async` 异步映射，完成后写入 [`GpuPickResult`]
+//!
+//! 暂存缓冲轮流使用，渲染从不等待 GPU；结果比光标晚一到两帧，拾取系统读取最近完成的一次。
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::picking::{PickingMode, PickingPlugin, PickingSettings};
+//! use anvilkit_render::renderer::id_picking::{GpuPickingPlugin, PickAlphaMask};
+//! use anvilkit_render::renderer::sprite::Sprite;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((PickingPlugin, GpuPickingPlugin))
+//!     .insert_resource(PickingSettings { mode: PickingMode::GpuId, ..Default::default() });
+//!
+//! // 精灵纹理的 alpha 通道：透明像素不可点击
+//! let rgba = vec![255u8; 16 * 16 * 4];
+//! app.world_mut().spawn((
+//!     Sprite::default(),
+//!     Transform::from_xyz(100.0, 100.0, 0.0),
+//!     Pickable::default(),
+//!     PickAlphaMask::from_rgba(16, 16, &rgba),
+//! ));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Receiver};
+use std::sync::{Arc, Mutex};
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::Transform;
+use anvilkit_input::input_state::InputState;
+use bytemuck::{Pod, Zeroable};
+use glam::Vec2;
+use wgpu::util::DeviceExt;
+
+use super::buffer::Vertex;
+use super::draw::ViewVisibility;
+use super::phase::{PhaseItem, PhaseSlot, RenderPhase, RenderPhaseAppExt, RenderPhaseDescriptor};
+use super::shared::{CachedBuffer, MatrixUniform};
+use super::sprite::{Sprite, SpriteSortKey};
+use super::RenderDevice;
+use crate::component::{draw_sort_key, Layer, RenderOrder};
+use crate::picking::{GpuPick, GpuPickResult, Pickable, PickingMode, PickingSettings};
+
+const ID_PICKING_SHADER: &str = include_str!("../shaders/id_picking.wgsl");
+
+/// ID 目标格式（实体 bits 的低 / 高 32 位）
+const ID_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rg32Uint;
+/// 单个像素的字节数
+const ID_PIXEL_BYTES: u64 = 8;
+/// 暂存缓冲数量（同时在途的回读数）
+const READBACK_SLOTS: usize = 3;
+
+/// 拾取 alpha 遮罩组件
+///
+/// 精灵纹理的 alpha 通道（与 [`Sprite::atlas_rect`] 使用同一套 UV），
+/// 低于 `cutoff` 的像素在 GPU 拾取中视为透明。没有遮罩的精灵整个四边形都可点击。
+#[derive(Debug, Clone, Component)]
+pub struct PickAlphaMask {
+    /// 遮罩宽度（像素）
+    pub width: u32,
+    /// 遮罩高度（像素）
+    pub height: u32,
+    /// 逐像素 alpha，行优先
+    pub alpha: Arc<[u8]>,
+    /// 可点击的最小 alpha (0..1)
+    pub cutoff: f32,
+}
+
+impl PickAlphaMask {
+    /// 由逐像素 alpha 创建
+    pub fn new(width: u32, height: u32, alpha: impl Into<Arc<[u8]>>) -> Self {
+        let alpha = alpha.into();
+        debug_assert_eq!(alpha.len(), (width * height) as usize, "alpha mask size mismatch");
+        Self { width, height, alpha, cutoff: 0.5 }
+    }
+
+    /// 从 RGBA8 像素中提取 alpha 通道
+    pub fn from_rgba(width: u32, height: u32, rgba: &[u8]) -> Self {
+        Self::new(width, height, rgba.chunks_exact(4).map(|p| p[3]).collect::<Vec<_>>())
+    }
+
+    /// 修改镂空阈值
+    pub fn with_cutoff(mut self, cutoff: f32) -> Self {
+        self.cutoff = cutoff;
+        self
+    }
+}
+
+/// 实体 ID 编码为 `Rg32Uint` 像素；0 表示没有实体
+pub fn encode_entity(entity: Entity) -> [u32; 2] {
+    let bits = entity.to_bits();
+    [bits as u32, (bits >> 32) as u32]
+}
+
+/// 从 `Rg32Uint` 像素解码实体
+pub fn decode_entity(id: [u32; 2]) -> Option<Entity> {
+    Entity::try_from_bits(u64::from(id[0]) | (u64::from(id[1]) << 32)).ok()
+}
+
+/// ID 拾取顶点 (28 字节)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+pub struct IdPickVertex {
+    /// Screen-space position in pixels.
+    pub position: [f32; 2],
+    /// Alpha mask UV coordinates.
+    pub texcoord: [f32; 2],
+    /// Encoded entity id.
+    pub id: [u32; 2],
+    /// Minimum mask alpha that counts as a hit.
+    pub cutoff: f32,
+}
+
+crate::impl_vertex!(IdPickVertex, [0 => Float32x2, 1 => Float32x2, 2 => Uint32x2, 3 => Float32]);
+
+/// 单个精灵在本帧几何中的范围
+#[derive(Debug, Clone)]
+pub struct IdPickDraw {
+    /// 顶点范围
+    pub vertices: std::ops::Range<u32>,
+    /// alpha 遮罩
+    pub mask: Option<PickAlphaMask>,
+}
+
+/// ECS 资源：每帧收集的光标下精灵
+#[derive(Resource, Default)]
+pub struct IdPickCollected {
+    /// 本帧要回读的光标像素；光标不在窗口内或未启用时为 `None`
+    pub cursor: Option<(u32, u32)>,
+    /// 覆盖光标像素的精灵四边形，按绘制顺序
+    pub vertices: Vec<IdPickVertex>,
+    /// 逐精灵绘制范围
+    pub draws: Vec<IdPickDraw>,
+}
+
+impl IdPickCollected {
+    /// 清空本帧数据
+    pub fn clear(&mut self) {
+        self.cursor = None;
+        self.vertices.clear();
+        self.draws.clear();
+    }
+}
+
+/// [`id_pick_collect_system`] 查询的组件
+type IdPickQuery = (
+    Entity,
+    &'static Sprite,
+    &'static Transform,
+    &'static Pickable,
+    Option<&'static PickAlphaMask>,
+    Option<&'static ViewVisibility>,
+    Option<&'static Layer>,
+    Option<&'static RenderOrder>,
+);
+
+/// ID 拾取收集系统 (PostUpdate)
+///
+/// 只保留四边形覆盖光标像素中心的可拾取精灵，按 (`Layer`, `RenderOrder`, z_order) 排序，
+/// 与精灵绘制顺序一致。
+pub fn id_pick_collect_system(
+    settings: Res<PickingSettings>,
+    input: Option<Res<InputState>>,
+    query: Query<IdPickQuery>,
+    mut collected: ResMut<IdPickCollected>,
+    mut order: Local<Vec<SpriteSortKey>>,
+) {
+    collected.clear();
+    let Some(input) = input else { return };
+    let mouse = input.mouse_position();
+    if !settings.enabled || settings.mode != PickingMode::GpuId || mouse.x < 0.0 || mouse.y < 0.0 {
+        return;
+    }
+    let pixel = (mouse.x as u32, mouse.y as u32);
+    collected.cursor = Some(pixel);
+    let center = Vec2::new(pixel.0 as f32 + 0.5, pixel.1 as f32 + 0.5);
+
+    order.clear();
+    order.extend(
+        query
+            .iter()
+            .filter(|(_, _, _, pickable, _, visibility, ..)| {
+                pickable.matches(settings.layers) && visibility.is_none_or(|v| v.get())
+            })
+            .filter(|(_, sprite, transform, ..)| {
+                (center - transform.translation.truncate()).abs().cmple(sprite.size * 0.5).all()
+            })
+            .map(|(entity, sprite, _, _, _, _, layer, render_order)| {
+                (draw_sort_key(layer, render_order), sprite.z_order, entity)
+            }),
+    );
+    order.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    for &(_, _, entity) in order.iter() {
+        let Ok((_, sprite, transform, _, mask, ..)) = query.get(entity) else { continue };
+        let start = collected.vertices.len() as u32;
+        let p = transform.translation;
+        let half = sprite.size * 0.5;
+        let r = &sprite.atlas_rect;
+        let (u_min, u_max) = if sprite.flip_x { (r.u_max, r.u_min) } else { (r.u_min, r.u_max) };
+        let (v_min, v_max) = if sprite.flip_y { (r.v_max, r.v_min) } else { (r.v_min, r.v_max) };
+        let id = encode_entity(entity);
+        let cutoff = mask.map_or(0.0, |m| m.cutoff);
+        let vertex = |x: f32, y: f32, u: f32, v: f32| IdPickVertex { position: [x, y], texcoord: [u, v], id, cutoff };
+
+        // 与 SpriteBatch::add_sprite 相同的角点与 UV
+        let tl = vertex(p.x - half.x, p.y + half.y, u_min, v_min);
+        let bl = vertex(p.x - half.x, p.y - half.y, u_min, v_max);
+        let br = vertex(p.x + half.x, p.y - half.y, u_max, v_max);
+        let tr = vertex(p.x + half.x, p.y + half.y, u_max, v_min);
+        collected.vertices.extend([tl, bl, br, tl, br, tr]);
+        let end = collected.vertices.len() as u32;
+        collected.draws.push(IdPickDraw { vertices: start..end, mask: mask.cloned() });
+    }
+}
+
+/// 暂存缓冲状态
+enum ReadbackState {
+    /// 空闲
+    Idle,
+    /// 复制命令已编码，等待提交
+    Copied { pixel: (u32, u32) },
+    /// 已请求映射
+    Mapping { pixel: (u32, u32), done: Receiver<Result<(), wgpu::BufferAsyncError>> },
+}
+
+struct ReadbackSlot {
+    buffer: wgpu::Buffer,
+    state: ReadbackState,
+}
+
+/// GPU 实体 ID 渲染与异步回读
+pub struct IdPickRenderer {
+    pipeline: wgpu::RenderPipeline,
+    ortho_buffer: wgpu::Buffer,
+    ortho_bind_group: wgpu::BindGroup,
+    mask_bind_group_layout: wgpu::BindGroupLayout,
+    mask_sampler: wgpu::Sampler,
+    /// 无遮罩精灵使用的 1×1 白色遮罩
+    opaque_mask: wgpu::BindGroup,
+    /// 按 alpha 数据地址缓存的遮罩（保留 `Arc` 防止地址被复用）
+    masks: HashMap<usize, (Arc<[u8]>, wgpu::BindGroup)>,
+    target: wgpu::Texture,
+    target_view: wgpu::TextureView,
+    cached_vb: CachedBuffer,
+    slots: Vec<ReadbackSlot>,
+}
+
+impl IdPickRenderer {
+    /// 创建 ID 拾取渲染器
+    pub fn new(device: &RenderDevice) -> Self {
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("IdPicking Shader"),
+            source: wgpu::ShaderSource::Wgsl(ID_PICKING_SHADER.into()),
+        });
+
+        let ortho_bgl = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("IdPicking Ortho BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let mask_bgl = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("IdPicking Mask BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("IdPicking Pipeline Layout"),
+            bind_group_layouts: &[&ortho_bgl, &mask_bgl],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("IdPicking Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[IdPickVertex::layout()],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                // 整数目标不支持混合：后绘制的直接覆盖
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: ID_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let ortho_buffer = device.device().create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("IdPicking Ortho UB"),
+            contents: bytemuck::bytes_of(&MatrixUniform::identity()),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let ortho_bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("IdPicking Ortho BG"),
+            layout: &ortho_bgl,
+            entries: &[wgpu::BindGroupEntry { binding: 0, resource: ortho_buffer.as_entire_binding() }],
+        });
+
+        // 遮罩按纹素取值，保证边缘像素级精确
+        let mask_sampler = device.device().create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("IdPicking Mask Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+        let opaque_mask = Self::mask_bind_group(device, &mask_bgl, &mask_sampler, 1, 1, &[255]);
+
+        let target = device.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some("IdPicking Target"),
+            size: wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: ID_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let slots = (0..READBACK_SLOTS)
+            .map(|_| ReadbackSlot {
+                buffer: device.device().create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("IdPicking Readback"),
+                    size: ID_PIXEL_BYTES,
+                    usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                    mapped_at_creation: false,
+                }),
+                state: ReadbackState::Idle,
+            })
+            .collect();
+
+        Self {
+            pipeline,
+            ortho_buffer,
+            ortho_bind_group,
+            mask_bind_group_layout: mask_bgl,
+            mask_sampler,
+            opaque_mask,
+            masks: HashMap::new(),
+            target,
+            target_view,
+            cached_vb: CachedBuffer::vertex("IdPicking VB (cached)"),
+            slots,
+        }
+    }
+
+    fn mask_bind_group(
+        device: &RenderDevice,
+        layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        width: u32,
+        height: u32,
+        alpha: &[u8],
+    ) -> wgpu::BindGroup {
+        let texture = device.device().create_texture_with_data(
+            device.queue(),
+            &wgpu::TextureDescriptor {
+                label: Some("IdPicking Mask"),
+                size: wgpu::Extent3d { width: width.max(1), height: height.max(1), depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::R8Unorm,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            wgpu::util::TextureDataOrder::LayerMajor,
+            alpha,
+        );
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("IdPicking Mask BG"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+            ],
+        })
+    }
+
+    /// 收取已完成的回读，并为上一帧提交的复制请求映射
+    ///
+    /// 返回本次完成的回读（按完成顺序）。每帧在编码新的拾取之前调用。
+    pub fn poll(&mut self, device: &RenderDevice) -> Vec<GpuPick> {
+        device.device().poll(wgpu::Maintain::Poll);
+        let mut finished = Vec::new();
+        for slot in &mut self.slots {
+            match std::mem::replace(&mut slot.state, ReadbackState::Idle) {
+                ReadbackState::Idle => {}
+                ReadbackState::Copied { pixel } => {
+                    let (sender, receiver) = channel();
+                    slot.buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+                        let _ = sender.send(result);
+                    });
+                    slot.state = ReadbackState::Mapping { pixel, done: receiver };
+                }
+                ReadbackState::Mapping { pixel, done } => match done.try_recv() {
+                    Ok(Ok(())) => {
+                        let id: [u32; 2] = {
+                            let data = slot.buffer.slice(..).get_mapped_range();
+                            bytemuck::pod_read_unaligned(&data[..ID_PIXEL_BYTES as usize])
+                        };
+                        slot.buffer.unmap();
+                        finished.push(GpuPick { pixel, entity: decode_entity(id) });
+                    }
+                    Ok(Err(e)) => log::warn!("拾取回读映射失败: {:?}", e),
+                    Err(_) => slot.state = ReadbackState::Mapping { pixel, done },
+                },
+            }
+        }
+        finished
+    }
+
+    /// 把光标像素下的精灵 ID 画进目标并编码复制到空闲的暂存缓冲
+    ///
+    /// 所有暂存缓冲都在途时跳过本帧。复制在 `encoder` 提交后由下一次 [`poll`](Self::poll) 映射。
+    pub fn render(&mut self, device: &RenderDevice, encoder: &mut wgpu::CommandEncoder, frame: &IdPickCollected) {
+        let Some(pixel) = frame.cursor else { return };
+        let Some(slot) = self.slots.iter().position(|s| matches!(s.state, ReadbackState::Idle)) else { return };
+
+        // 只覆盖光标像素的正交投影
+        let (x, y) = (pixel.0 as f32, pixel.1 as f32);
+        let ortho = glam::Mat4::orthographic_lh(x, x + 1.0, y + 1.0, y, -1.0, 1.0);
+        device.queue().write_buffer(&self.ortho_buffer, 0, bytemuck::bytes_of(&MatrixUniform::from_mat4(&ortho)));
+
+        // 上传本帧遮罩，丢弃不再使用的
+        let mut used = Vec::with_capacity(frame.draws.len());
+        for mask in frame.draws.iter().filter_map(|d| d.mask.as_ref()) {
+            let key = mask.alpha.as_ptr() as usize;
+            used.push(key);
+            if !self.masks.contains_key(&key) {
+                let bind_group = Self::mask_bind_group(
+                    device, &self.mask_bind_group_layout, &self.mask_sampler, mask.width, mask.height, &mask.alpha,
+                );
+                self.masks.insert(key, (mask.alpha.clone(), bind_group));
+            }
+        }
+        self.masks.retain(|key, _| used.contains(key));
+
+        let vb = (!frame.vertices.is_empty())
+            .then(|| self.cached_vb.ensure_and_write(device.device(), device.queue(), bytemuck::cast_slice(&frame.vertices)));
+        {
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("IdPicking Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.target_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            if let Some(vb) = vb {
+                rp.set_pipeline(&self.pipeline);
+                rp.set_bind_group(0, &self.ortho_bind_group, &[]);
+                rp.set_vertex_buffer(0, vb.slice(..));
+                for draw in &frame.draws {
+                    let mask = draw
+                        .mask
+                        .as_ref()
+                        .and_then(|m| self.masks.get(&(m.alpha.as_ptr() as usize)))
+                        .map_or(&self.opaque_mask, |(_, bind_group)| bind_group);
+                    rp.set_bind_group(1, mask, &[]);
+                    rp.draw(draw.vertices.clone(), 0..1);
+                }
+            }
+        }
+
+        let slot = &mut self.slots[slot];
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &self.target,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &slot.buffer,
+                layout: wgpu::ImageDataLayout { offset: 0, bytes_per_row: None, rows_per_image: None },
+            },
+            wgpu::Extent3d { width: 1, height: 1, depth_or_array_layers: 1 },
+        );
+        slot.state = ReadbackState::Copied { pixel };
+    }
+}
+
+/// ID 拾取阶段项（GPU 模式下每帧入队一个）
+pub struct IdPickItem;
+
+impl PhaseItem for IdPickItem {
+    type SortKey = ();
+    fn sort_key(&self) {}
+}
+
+/// ID 拾取入队系统 (PostUpdate)
+///
+/// 光标离开窗口后仍入队，以便收取在途的回读。
+pub fn id_pick_queue_system(settings: Res<PickingSettings>, mut phase: ResMut<RenderPhase<IdPickItem>>) {
+    if settings.enabled && settings.mode == PickingMode::GpuId {
+        phase.add(IdPickItem);
+    }
+}
+
+/// GPU 实体 ID 拾取插件
+///
+/// 需要与 [`PickingPlugin`](crate::picking::PickingPlugin) 一起使用，
+/// 并把 [`PickingSettings::mode`] 设为 [`PickingMode::GpuId`]。
+pub struct GpuPickingPlugin;
+
+impl Plugin for GpuPickingPlugin {
+    fn build(&self, app: &mut App) {
+        let gpu: Mutex<Option<IdPickRenderer>> = Mutex::new(None);
+        app.init_resource::<PickingSettings>()
+            .init_resource::<GpuPickResult>()
+            .init_resource::<IdPickCollected>()
+            .add_render_phase::<IdPickItem>(
+                RenderPhaseDescriptor::new("IdPicking", PhaseSlot::AfterTonemap),
+                move |world, _items, ctx| {
+                    let (Some(frame), Some(result)) =
+                        (world.get_resource::<IdPickCollected>(), world.get_resource::<GpuPickResult>())
+                    else {
+                        return;
+                    };
+                    let Ok(mut gpu) = gpu.lock() else { return };
+                    let renderer = gpu.get_or_insert_with(|| IdPickRenderer::new(ctx.device));
+                    for pick in renderer.poll(ctx.device) {
+                        result.set(pick);
+                    }
+                    renderer.render(ctx.device, ctx.encoder, frame);
+                },
+            )
+            .add_systems(bevy_app::PostUpdate, (id_pick_collect_system, id_pick_queue_system));
+    }
+
+    fn name(&self) -> &str {
+        "GpuPickingPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(world: &mut World) {
+        let mut schedule = Schedule::default();
+        schedule.add_systems(id_pick_collect_system);
+        schedule.run(world);
+    }
+
+    fn gpu_world(cursor: Vec2) -> World {
+        let mut world = World::new();
+        world.init_resource::<IdPickCollected>();
+        world.insert_resource(PickingSettings { mode: PickingMode::GpuId, ..Default::default() });
+        let mut input = InputState::new();
+        input.set_mouse_position(cursor);
+        world.insert_resource(input);
+        world
+    }
+
+    #[test]
+    fn test_entity_id_roundtrip() {
+        let entity = Entity::from_raw(42);
+        assert_eq!(decode_entity(encode_entity(entity)), Some(entity));
+        // 清屏值不对应任何实体
+        assert_eq!(decode_entity([0, 0]), None);
+    }
+
+    #[test]
+    fn test_collect_keeps_sprites_under_cursor_in_draw_order() {
+        let mut world = gpu_world(Vec2::new(100.4, 100.6));
+        let sprite = Sprite { size: Vec2::splat(20.0), ..Default::default() };
+        let top = world
+            .spawn((Sprite { z_order: 1.0, ..sprite.clone() }, Transform::from_xyz(105.0, 100.0, 0.0), Pickable::default()))
+            .id();
+        let bottom = world
+            .spawn((sprite.clone(), Transform::from_xyz(95.0, 100.0, 0.0), Pickable::default(), PickAlphaMask::new(1, 1, vec![255])))
+            .id();
+        // 不覆盖光标、不可拾取、层不匹配
+        world.spawn((sprite.clone(), Transform::from_xyz(300.0, 100.0, 0.0), Pickable::default()));
+        world.spawn((sprite.clone(), Transform::from_xyz(100.0, 100.0, 0.0)));
+        world.spawn((sprite, Transform::from_xyz(100.0, 100.0, 0.0), Pickable { layers: 0 }));
+        collect(&mut world);
+
+        let collected = world.resource::<IdPickCollected>();
+        assert_eq!(collected.cursor, Some((100, 100)));
+        assert_eq!(collected.draws.len(), 2);
+        assert_eq!(collected.vertices.len(), 12);
+        // 最上层最后绘制
+        assert_eq!(collected.vertices[0].id, encode_entity(bottom));
+        assert_eq!(collected.vertices[0].cutoff, 0.5);
+        assert!(collected.draws[0].mask.is_some());
+        assert_eq!(collected.vertices[6].id, encode_entity(top));
+        assert_eq!(collected.vertices[6].cutoff, 0.0);
+        assert_eq!(collected.draws[1].vertices, 6..12);
+    }
+
+    #[test]
+    fn test_collect_disabled_in_ray_mode() {
+        let mut world = gpu_world(Vec2::new(10.0, 10.0));
+        world.resource_mut::<PickingSettings>().mode = PickingMode::Ray;
+        world.spawn((Sprite::default(), Transform::from_xyz(10.0, 10.0, 0.0), Pickable::default()));
+        collect(&mut world);
+
+        let collected = world.resource::<IdPickCollected>();
+        assert!(collected.cursor.is_none());
+        assert!(collected.draws.is_empty());
+    }
+
+    #[test]
+    fn test_alpha_mask_from_rgba() {
+        let rgba = [10, 20, 30, 0, 40, 50, 60, 255];
+        let mask = PickAlphaMask::from_rgba(2, 1, &rgba).with_cutoff(0.25);
+        assert_eq!(&*mask.alpha, &[0, 255]);
+        assert_eq!(mask.cutoff, 0.25);
+    }
+}
@@ -0,0 +1,547 @@
+//! # 远景替身（Imposter）
+//!
+//! 密集的树林、人群在远处只占几十个像素，却要付出完整网格的顶点与绘制开销。
+//! 替身在加载时把网格从 N 个水平角度烘焙到一张图集中，远处的实例改为绘制朝向相机的四边形：
+//!
+//! - [`bake_imposter`] 在 CPU 上光栅化网格，输出基础色图集（sRGB，背景透明）与
+//!   切线空间法线图集，运行时替身仍然参与 PBR 光照
+//! - [`create_imposter`] 烘焙并上传每个视角的四边形网格与替身材质，返回 [`Handle<ImposterAsset>`]
+//! - [`Imposter`] 组件挂在完整网格实体上：相机距离超过 `distance` 时，
+//!   `imposter_lod_system` 隐藏完整网格并选择最接近视线方向的视角，
+//!   提取阶段改为提交绕 Y 轴朝向相机的替身四边形；回到 `distance * (1 - hysteresis)` 以内时恢复
+//!
+//! 视角只在水平方向采样，适用于直立的树木、角色等；实例的俯仰 / 翻滚旋转不会反映在替身上。
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::imposter::{create_imposter, Imposter, ImposterSettings};
+//! use anvilkit_assets::material::MaterialData;
+//! use anvilkit_assets::procedural::generate_box;
+//!
+//! # fn setup(world: &mut World, device: &RenderDevice, tree: MeshHandle, bark: Handle<Material>) {
+//! let mesh = generate_box([0.5, 2.0, 0.5]);
+//! let imposter = create_imposter(world, device, &mesh, &MaterialData::default(), &ImposterSettings::default());
+//! for i in 0..1000 {
+//!     let position = glam::Vec3::new((i % 40) as f32 * 3.0, 0.0, (i / 40) as f32 * 3.0);
+//!     world.spawn((tree, bark, Transform::from_translation(position), Imposter::new(imposter, 40.0)));
+//! }
+//! # }
+//! ```
+
+use bevy_ecs::prelude::*;
+use glam::{Mat4, Quat, Vec2, Vec3};
+use anvilkit_core::collections::{SlotKey, SlotMap};
+use anvilkit_core::math::color::{linear_to_srgb, srgb_to_linear};
+use anvilkit_core::math::GlobalTransform;
+use anvilkit_assets::material::{MaterialData, TextureData};
+use anvilkit_assets::mesh::MeshData;
+
+use crate::renderer::RenderDevice;
+use crate::renderer::assets::{BlendMode, Handle, MeshHandle, RenderAssets};
+use crate::renderer::buffer::PbrVertex;
+use crate::renderer::draw::{ActiveCamera, ViewVisibility};
+use crate::renderer::material::{Material, Materials};
+
+/// 烘焙参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImposterSettings {
+    /// 水平视角数（均匀分布在 360° 上）
+    pub views: u32,
+    /// 每个视角的图集单元边长（像素）
+    pub cell_size: u32,
+}
+
+impl Default for ImposterSettings {
+    fn default() -> Self {
+        Self { views: 8, cell_size: 128 }
+    }
+}
+
+/// CPU 端烘焙结果
+#[derive(Debug, Clone)]
+pub struct ImposterBake {
+    /// 基础色图集（sRGB RGBA8，网格外 alpha 为 0）
+    pub albedo: TextureData,
+    /// 切线空间法线图集（线性，`n * 0.5 + 0.5`）
+    pub normal: TextureData,
+    /// 视角数
+    pub views: u32,
+    /// 图集列数
+    pub columns: u32,
+    /// 单元边长（像素）
+    pub cell_size: u32,
+    /// 旋转轴在网格局部空间 XZ 平面上的位置（包围盒中心）
+    pub axis: Vec2,
+    /// 四边形半宽（网格绕旋转轴的最大水平半径）
+    pub half_width: f32,
+    /// 四边形底边高度（局部 Y）
+    pub min_y: f32,
+    /// 四边形顶边高度（局部 Y）
+    pub max_y: f32,
+}
+
+/// 视角 `view` 的水平方位角
+fn view_angle(view: u32, views: u32) -> f32 {
+    view as f32 * std::f32::consts::TAU / views.max(1) as f32
+}
+
+/// 从视角 `view` 观察时指向观察者的方向（局部空间）
+///
+/// 视角 0 从 `-Z` 一侧观察（与相机沿 `+Z` 看向模型一致），视角序号沿 Y 轴正方向旋转递增。
+pub fn view_direction(view: u32, views: u32) -> Vec3 {
+    Quat::from_rotation_y(view_angle(view, views)) * Vec3::NEG_Z
+}
+
+/// 与指向观察者的局部方向最接近的视角
+pub fn view_for_direction(direction: Vec3, views: u32) -> u32 {
+    let views = views.max(1);
+    let angle = f32::atan2(-direction.x, -direction.z);
+    let step = std::f32::consts::TAU / views as f32;
+    (angle / step).round().rem_euclid(views as f32) as u32 % views
+}
+
+impl ImposterBake {
+    /// 视角 `view` 的四边形网格
+    ///
+    /// 四边形位于局部 XY 平面、法线朝 `-Z`，纹理坐标指向图集中对应的单元（内缩半个像素避免串色）。
+    pub fn cell_quad(&self, view: u32) -> MeshData {
+        let (width, height) = (self.albedo.width as f32, self.albedo.height as f32);
+        let cell = self.cell_size as f32;
+        let origin = Vec2::new((view % self.columns) as f32, (view / self.columns) as f32) * cell;
+        let uv_min = (origin + 0.5) / Vec2::new(width, height);
+        let uv_max = (origin + cell - 0.5) / Vec2::new(width, height);
+        let hw = self.half_width;
+
+        MeshData {
+            positions: vec![
+                Vec3::new(-hw, self.min_y, 0.0),
+                Vec3::new(hw, self.min_y, 0.0),
+                Vec3::new(hw, self.max_y, 0.0),
+                Vec3::new(-hw, self.max_y, 0.0),
+            ],
+            normals: vec![Vec3::NEG_Z; 4],
+            texcoords: vec![
+                Vec2::new(uv_min.x, uv_max.y),
+                Vec2::new(uv_max.x, uv_max.y),
+                Vec2::new(uv_max.x, uv_min.y),
+                Vec2::new(uv_min.x, uv_min.y),
+            ],
+            // 左手系下 cross(N, T) = -up，w = -1 使副切线指向上方（法线图集 G 通道）
+            tangents: vec![[1.0, 0.0, 0.0, -1.0]; 4],
+            indices: vec![0, 2, 1, 0, 3, 2],
+            ..Default::default()
+        }
+    }
+}
+
+/// 基础色纹理最近邻采样（线性空间）
+fn sample_albedo(texture: &TextureData, uv: Vec2) -> [f32; 4] {
+    if texture.width == 0 || texture.height == 0 {
+        return [1.0; 4];
+    }
+    let x = ((uv.x.rem_euclid(1.0) * texture.width as f32) as u32).min(texture.width - 1);
+    let y = ((uv.y.rem_euclid(1.0) * texture.height as f32) as u32).min(texture.height - 1);
+    let i = ((y * texture.width + x) * 4) as usize;
+    let Some(px) = texture.data.get(i..i + 4) else { return [1.0; 4] };
+    [
+        srgb_to_linear(px[0] as f32 / 255.0),
+        srgb_to_linear(px[1] as f32 / 255.0),
+        srgb_to_linear(px[2] as f32 / 255.0),
+        px[3] as f32 / 255.0,
+    ]
+}
+
+/// 在 CPU 上把网格从 `settings.views` 个水平角度正交光栅化到图集
+pub fn bake_imposter(mesh: &MeshData, material: &MaterialData, settings: &ImposterSettings) -> ImposterBake {
+    let views = settings.views.max(1);
+    let cell = settings.cell_size.max(1);
+    let columns = (views as f32).sqrt().ceil() as u32;
+    let rows = views.div_ceil(columns);
+    let (width, height) = (columns * cell, rows * cell);
+
+    // 以包围盒中心为旋转轴，四边形需覆盖所有视角下的投影
+    let (min, max) = mesh.positions.iter().fold(
+        (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)),
+        |(min, max), p| (min.min(*p), max.max(*p)),
+    );
+    let (min, max) = if mesh.positions.is_empty() { (Vec3::ZERO, Vec3::ZERO) } else { (min, max) };
+    let axis = Vec2::new(min.x + max.x, min.z + max.z) * 0.5;
+    let half_width = mesh.positions.iter()
+        .map(|p| Vec2::new(p.x, p.z).distance(axis))
+        .fold(1e-4, f32::max);
+    let (min_y, max_y) = (min.y, max.y.max(min.y + 1e-4));
+
+    let mut albedo = vec![0u8; (width * height * 4) as usize];
+    let mut normal = [128u8, 128, 255, 255].repeat((width * height) as usize);
+    let factor = material.base_color_factor;
+
+    for view in 0..views {
+        let eye = view_direction(view, views);
+        let rotation = Quat::from_rotation_y(view_angle(view, views));
+        let right = rotation * Vec3::X;
+        let forward = -eye;
+        let origin = Vec2::new((view % columns * cell) as f32, (view / columns * cell) as f32);
+        let mut depth = vec![f32::INFINITY; (cell * cell) as usize];
+
+        // 顶点投影到单元像素坐标
+        let project = |p: Vec3| -> Vec3 {
+            let local = p - Vec3::new(axis.x, 0.0, axis.y);
+            let u = (local.dot(right) + half_width) / (2.0 * half_width) * cell as f32;
+            let v = (max_y - p.y) / (max_y - min_y) * cell as f32;
+            Vec3::new(u, v, local.dot(forward))
+        };
+
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i as usize);
+            if a.max(b).max(c) >= mesh.positions.len() {
+                continue;
+            }
+            let (pa, pb, pc) = (project(mesh.positions[a]), project(mesh.positions[b]), project(mesh.positions[c]));
+            let area = (pb.x - pa.x) * (pc.y - pa.y) - (pb.y - pa.y) * (pc.x - pa.x);
+            if area.abs() < 1e-8 {
+                continue;
+            }
+            let x0 = pa.x.min(pb.x).min(pc.x).floor().max(0.0) as u32;
+            let y0 = pa.y.min(pb.y).min(pc.y).floor().max(0.0) as u32;
+            let x1 = (pa.x.max(pb.x).max(pc.x).ceil() as u32).min(cell);
+            let y1 = (pa.y.max(pb.y).max(pc.y).ceil() as u32).min(cell);
+
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let p = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                    let edge = |s: Vec3, e: Vec3| ((e.x - s.x) * (p.y - s.y) - (e.y - s.y) * (p.x - s.x)) / area;
+                    let (wa, wb, wc) = (edge(pb, pc), edge(pc, pa), edge(pa, pb));
+                    if wa < 0.0 || wb < 0.0 || wc < 0.0 {
+                        continue;
+                    }
+                    let z = pa.z * wa + pb.z * wb + pc.z * wc;
+                    let slot = (y * cell + x) as usize;
+                    if z >= depth[slot] {
+                        continue;
+                    }
+                    depth[slot] = z;
+
+                    let lerp3 = |va: Vec3, vb: Vec3, vc: Vec3| va * wa + vb * wb + vc * wc;
+                    let n = match (mesh.normals.get(a), mesh.normals.get(b), mesh.normals.get(c)) {
+                        (Some(na), Some(nb), Some(nc)) => lerp3(*na, *nb, *nc).normalize_or_zero(),
+                        _ => eye,
+                    };
+                    let uv = match (mesh.texcoords.get(a), mesh.texcoords.get(b), mesh.texcoords.get(c)) {
+                        (Some(ta), Some(tb), Some(tc)) => *ta * wa + *tb * wb + *tc * wc,
+                        _ => Vec2::ZERO,
+                    };
+                    let texel = material.base_color_texture.as_ref().map_or([1.0; 4], |t| sample_albedo(t, uv));
+
+                    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+                    let pixel = (((origin.y as u32 + y) * width + origin.x as u32 + x) * 4) as usize;
+                    albedo[pixel..pixel + 4].copy_from_slice(&[
+                        to_u8(linear_to_srgb(factor[0] * texel[0])),
+                        to_u8(linear_to_srgb(factor[1] * texel[1])),
+                        to_u8(linear_to_srgb(factor[2] * texel[2])),
+                        to_u8(factor[3] * texel[3]),
+                    ]);
+                    let tangent_space = Vec3::new(n.dot(right), n.y, n.dot(eye)) * 0.5 + 0.5;
+                    normal[pixel..pixel + 3].copy_from_slice(&tangent_space.to_array().map(to_u8));
+                }
+            }
+        }
+    }
+
+    dilate_edges(&mut albedo, width, height);
+
+    ImposterBake {
+        albedo: TextureData { width, height, data: albedo },
+        normal: TextureData { width, height, data: normal },
+        views,
+        columns,
+        cell_size: cell,
+        axis,
+        half_width,
+        min_y,
+        max_y,
+    }
+}
+
+/// 把透明像素的颜色扩展为相邻不透明像素的颜色（alpha 不变），避免双线性过滤时轮廓发黑
+fn dilate_edges(pixels: &mut [u8], width: u32, height: u32) {
+    let source = pixels.to_vec();
+    let (w, h) = (width as i64, height as i64);
+    for y in 0..h {
+        for x in 0..w {
+            let i = ((y * w + x) * 4) as usize;
+            if source[i + 3] != 0 {
+                continue;
+            }
+            let neighbour = [(1, 0), (-1, 0), (0, 1), (0, -1)].into_iter()
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .filter(|&(nx, ny)| nx >= 0 && ny >= 0 && nx < w && ny < h)
+                .map(|(nx, ny)| ((ny * w + nx) * 4) as usize)
+                .find(|&n| source[n + 3] != 0);
+            if let Some(n) = neighbour {
+                pixels[i..i + 3].copy_from_slice(&source[n..n + 3]);
+            }
+        }
+    }
+}
+
+/// 上传到 GPU 的替身资产
+#[derive(Debug, Clone)]
+pub struct ImposterAsset {
+    /// 每个视角的四边形网格
+    pub meshes: Vec<MeshHandle>,
+    /// 替身材质（基础色 + 法线图集，Alpha 混合）
+    pub material: Handle<Material>,
+    /// 旋转轴在网格局部空间中的位置（Y 为 0）
+    pub axis: Vec3,
+}
+
+impl ImposterAsset {
+    /// 视角数
+    pub fn views(&self) -> u32 {
+        self.meshes.len() as u32
+    }
+}
+
+/// 替身资产存储
+///
+/// 句柄为分代键，移除后旧句柄失效。
+#[derive(Resource, Default)]
+pub struct Imposters {
+    entries: SlotMap<SlotKey, ImposterAsset>,
+}
+
+impl Imposters {
+    /// 添加替身资产并返回句柄
+    pub fn add(&mut self, asset: ImposterAsset) -> Handle<ImposterAsset> {
+        Handle::from_id(self.entries.insert(asset).to_bits())
+    }
+
+    /// 获取替身资产
+    pub fn get(&self, handle: &Handle<ImposterAsset>) -> Option<&ImposterAsset> {
+        self.entries.get(SlotKey::from_bits(handle.id()))
+    }
+
+    /// 移除替身资产（网格与材质仍由 `RenderAssets` / `Materials` 持有）
+    pub fn remove(&mut self, handle: &Handle<ImposterAsset>) -> Option<ImposterAsset> {
+        self.entries.remove(SlotKey::from_bits(handle.id()))
+    }
+
+    /// 资产数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// 烘焙网格替身，上传每个视角的四边形与替身材质
+pub fn create_imposter(
+    world: &mut World,
+    device: &RenderDevice,
+    mesh: &MeshData,
+    material: &MaterialData,
+    settings: &ImposterSettings,
+) -> Handle<ImposterAsset> {
+    let bake = bake_imposter(mesh, material, settings);
+    let meshes = {
+        let mut assets = world.get_resource_or_insert_with(RenderAssets::default);
+        (0..bake.views)
+            .map(|view| {
+                let quad = bake.cell_quad(view);
+                let vertices: Vec<PbrVertex> = quad.to_pbr_vertices()
+                    .into_iter()
+                    .map(|v| PbrVertex { position: v.position, normal: v.normal, texcoord: v.texcoord, tangent: v.tangent })
+                    .collect();
+                assets.upload_mesh_u32(device, &vertices, &quad.indices, &format!("Imposter View {}", view))
+            })
+            .collect()
+    };
+    let material = world.get_resource_or_insert_with(Materials::default).add(
+        Material::new()
+            .with_roughness(material.roughness_factor)
+            .with_base_color_texture(bake.albedo)
+            .with_normal_texture(bake.normal)
+            .with_blend_mode(BlendMode::AlphaBlend),
+    );
+    let axis = Vec3::new(bake.axis.x, 0.0, bake.axis.y);
+    world.get_resource_or_insert_with(Imposters::default).add(ImposterAsset { meshes, material, axis })
+}
+
+/// 远景替身组件
+///
+/// 与完整网格的 `MeshHandle` + 材质挂在同一实体上，由 `imposter_lod_system` 每帧切换。
+#[derive(Component, Debug, Clone)]
+pub struct Imposter {
+    /// 替身资产
+    pub asset: Handle<ImposterAsset>,
+    /// 切换到替身的相机距离
+    pub distance: f32,
+    /// 切回完整网格的距离比例回差（`distance * (1 - hysteresis)`）
+    pub hysteresis: f32,
+    active: bool,
+    view: Option<u32>,
+}
+
+impl Imposter {
+    /// 超过 `distance` 时切换到替身，默认回差 10%
+    pub fn new(asset: Handle<ImposterAsset>, distance: f32) -> Self {
+        Self { asset, distance, hysteresis: 0.1, active: false, view: None }
+    }
+
+    /// 设置回差比例（链式调用）
+    pub fn with_hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// 当前是否以替身显示（不考虑剔除）
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// 本帧提交的替身视角（替身可见时）
+    pub fn view(&self) -> Option<u32> {
+        self.view
+    }
+}
+
+/// 替身四边形的模型矩阵：绕 Y 轴朝向相机，按实例缩放
+pub fn billboard_matrix(global_transform: &GlobalTransform, asset: &ImposterAsset, camera_pos: Vec3) -> Mat4 {
+    let (scale, _, _) = global_transform.0.to_scale_rotation_translation();
+    let center = global_transform.0.transform_point3(asset.axis);
+    let to_camera = camera_pos - center;
+    let yaw = f32::atan2(-to_camera.x, -to_camera.z);
+    Mat4::from_scale_rotation_translation(Vec3::new(scale.x, scale.y, scale.x), Quat::from_rotation_y(yaw), center)
+}
+
+/// 替身 LOD 系统 (PostUpdate, after frustum_culling_system, before render_extract_system)
+///
+/// 远处的实例隐藏完整网格（`ViewVisibility` 置为不可见），并记录替身应提交的视角；
+/// 替身的可见性沿用完整网格本帧的剔除结果。
+pub fn imposter_lod_system(
+    active_camera: Res<ActiveCamera>,
+    imposters: Option<Res<Imposters>>,
+    mut query: Query<(&mut Imposter, &GlobalTransform, &mut ViewVisibility)>,
+) {
+    let Some(imposters) = imposters else { return };
+    let camera_pos = active_camera.camera_pos;
+    for (mut imposter, global_transform, mut view_visibility) in query.iter_mut() {
+        let Some(asset) = imposters.get(&imposter.asset).filter(|a| a.views() > 0) else {
+            imposter.view = None;
+            continue;
+        };
+        let center = global_transform.0.transform_point3(asset.axis);
+        let distance = center.distance(camera_pos);
+        let threshold = if imposter.active { imposter.distance * (1.0 - imposter.hysteresis) } else { imposter.distance };
+        imposter.active = distance > threshold;
+
+        imposter.view = if imposter.active && view_visibility.get() {
+            let (_, rotation, _) = global_transform.0.to_scale_rotation_translation();
+            Some(view_for_direction(rotation.inverse() * (camera_pos - center), asset.views()))
+        } else {
+            None
+        };
+        if imposter.active {
+            view_visibility.set_if_neq(ViewVisibility::HIDDEN);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_assets::procedural::generate_box;
+
+    fn pixel(texture: &TextureData, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * texture.width + x) * 4) as usize;
+        texture.data[i..i + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn test_bake_box_atlas() {
+        let material = MaterialData { base_color_factor: [1.0, 0.0, 0.0, 1.0], ..Default::default() };
+        let bake = bake_imposter(&generate_box([1.0, 2.0, 1.0]), &material, &ImposterSettings { views: 4, cell_size: 16 });
+        assert_eq!((bake.albedo.width, bake.albedo.height), (32, 32));
+        assert_eq!(bake.columns, 2);
+        assert!((bake.half_width - std::f32::consts::SQRT_2).abs() < 1e-5);
+        assert_eq!((bake.min_y, bake.max_y), (-2.0, 2.0));
+
+        // 视角 0 单元中心为正面：红色、不透明，法线朝向观察者
+        assert_eq!(pixel(&bake.albedo, 8, 8), [255, 0, 0, 255]);
+        assert!(pixel(&bake.normal, 8, 8)[2] > 250);
+        // 四边形覆盖对角线半径，正视时左右两侧留空
+        assert_eq!(pixel(&bake.albedo, 0, 8)[3], 0);
+        // 视角 3 位于第二行第二列
+        assert_eq!(pixel(&bake.albedo, 24, 24), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn test_view_selection_roundtrip() {
+        for views in [1, 4, 8, 12] {
+            for view in 0..views {
+                let direction = view_direction(view, views) * 5.0 + Vec3::Y;
+                assert_eq!(view_for_direction(direction, views), view);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell_quad_uvs() {
+        let bake = bake_imposter(&generate_box([1.0, 1.0, 1.0]), &MaterialData::default(), &ImposterSettings { views: 3, cell_size: 8 });
+        let quad = bake.cell_quad(2);
+        // 视角 2 位于第二行第一列（2 列 x 2 行）
+        for uv in &quad.texcoords {
+            assert!(uv.x > 0.0 && uv.x < 0.5);
+            assert!(uv.y > 0.5 && uv.y < 1.0);
+        }
+        assert_eq!(quad.positions[2], Vec3::new(bake.half_width, bake.max_y, 0.0));
+    }
+
+    #[test]
+    fn test_lod_switch_with_hysteresis() {
+        let mut world = World::new();
+        world.insert_resource(ActiveCamera::default());
+        let mut imposters = Imposters::default();
+        let asset = imposters.add(ImposterAsset {
+            meshes: (0..4).map(MeshHandle).collect(),
+            material: Handle::from_id(0),
+            axis: Vec3::ZERO,
+        });
+        world.insert_resource(imposters);
+        let camera = world.resource::<ActiveCamera>().camera_pos;
+
+        let spawn = |world: &mut World, offset: Vec3| {
+            let transform = GlobalTransform(Mat4::from_translation(camera + offset));
+            world.spawn((Imposter::new(asset, 20.0), transform, ViewVisibility::VISIBLE)).id()
+        };
+        let near = spawn(&mut world, Vec3::new(0.0, 0.0, 5.0));
+        let far = spawn(&mut world, Vec3::new(0.0, 0.0, 50.0));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(imposter_lod_system);
+        schedule.run(&mut world);
+
+        assert!(!world.get::<Imposter>(near).unwrap().is_active());
+        assert!(world.get::<ViewVisibility>(near).unwrap().get());
+        let imposter = world.get::<Imposter>(far).unwrap();
+        assert!(imposter.is_active());
+        // 相机在实例 -Z 一侧 → 视角 0
+        assert_eq!(imposter.view(), Some(0));
+        assert!(!world.get::<ViewVisibility>(far).unwrap().get());
+
+        // 回差范围内保持替身，低于回差后切回
+        world.entity_mut(far).insert(GlobalTransform(Mat4::from_translation(camera + Vec3::Z * 19.0)));
+        *world.get_mut::<ViewVisibility>(far).unwrap() = ViewVisibility::VISIBLE;
+        schedule.run(&mut world);
+        assert!(world.get::<Imposter>(far).unwrap().is_active());
+
+        world.entity_mut(far).insert(GlobalTransform(Mat4::from_translation(camera + Vec3::Z * 17.0)));
+        *world.get_mut::<ViewVisibility>(far).unwrap() = ViewVisibility::VISIBLE;
+        schedule.run(&mut world);
+        assert!(!world.get::<Imposter>(far).unwrap().is_active());
+        assert_eq!(world.get::<Imposter>(far).unwrap().view(), None);
+    }
+}
@@ -0,0 +1,705 @@
+//! # 2D 光照与软阴影
+//!
+//! 面向俯视角 / 横版游戏的动态 2D 光照，坐标系与精灵一致（屏幕像素，y 向下）：
+//!
+//! - [`PointLight2D`]：径向衰减的点光源
+//! - [`Occluder2D`]：遮挡多边形，背光边沿光线方向挤出为阴影几何
+//! - 阴影轮廓顶点处附加半影扇形（fin），着色从本影边缘渐变到 0，模拟面光源的软边
+//!
+//! 每帧由 [`light2d_collect_system`] 在 CPU 上生成阴影与光源几何，[`Light2DRenderer`] 逐光源
+//! 先把阴影写入遮罩，再把光源叠加进以环境光清空的光照图，最后将光照图乘到目标上。
+//!
+//! [`Light2DPlugin`] 把合成注册为 `AfterTonemap` 渲染阶段；自行绘制精灵的游戏
+//! 也可以在精灵之后直接调用 [`Light2DRenderer::render`]。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::light2d::{shadow_geometry, Occluder2D, PointLight2D};
+//! use glam::Vec2;
+//!
+//! let light = PointLight2D { radius: 200.0, ..Default::default() };
+//! let wall = Occluder2D::rect(Vec2::new(10.0, 10.0));
+//! let mut vertices = Vec::new();
+//! shadow_geometry(Vec2::ZERO, &light, &wall.translated(Vec2::new(50.0, 0.0)), &mut vertices);
+//! // 三条背光边各一个四边形 + 两个轮廓顶点的半影扇形
+//! assert_eq!(vertices.len(), 3 * 6 + 2 * 3);
+//! ```
+
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::Transform;
+use anvilkit_describe::Describe;
+use bytemuck::{Pod, Zeroable};
+use glam::{Vec2, Vec3};
+use wgpu::util::DeviceExt;
+use wgpu::VertexBufferLayout;
+
+use super::buffer::Vertex;
+use super::phase::{PhaseItem, PhaseSlot, RenderPhase, RenderPhaseAppExt, RenderPhaseDescriptor};
+use super::shared::{CachedBuffer, MatrixUniform};
+use super::state::RenderState;
+use super::RenderDevice;
+
+const LIGHT2D_SHADER: &str = include_str!("../shaders/light2d.wgsl");
+
+/// 阴影遮罩格式
+const SHADOW_MASK_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
+/// 光照图格式（允许超过 1 的过曝叠加）
+const LIGHT_MAP_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+/// 2D 点光源组件
+#[derive(Debug, Clone, Component, Describe)]
+/// Radial 2D point light in screen-pixel space.
+pub struct PointLight2D {
+    /// 光照颜色 (linear RGB)
+    #[describe(hint = "Light color (linear RGB)", default = "(1.0, 1.0, 1.0)")]
+    pub color: Vec3,
+    /// 强度
+    #[describe(hint = "Brightness multiplier", range = "0.0..10.0", default = "1.0")]
+    pub intensity: f32,
+    /// 照明半径（像素）
+    #[describe(hint = "Light reach in pixels", range = "1.0..4096.0", default = "256.0")]
+    pub radius: f32,
+    /// 衰减指数，越大边缘越快变暗
+    #[describe(hint = "Falloff exponent", range = "0.1..8.0", default = "2.0")]
+    pub falloff: f32,
+    /// 光源自身半径（像素），决定半影宽度；0 为硬阴影
+    #[describe(hint = "Source size in pixels; larger = softer shadows", range = "0.0..128.0", default = "8.0")]
+    pub source_radius: f32,
+    /// 是否投射阴影
+    #[describe(hint = "Whether occluders block this light", default = "true")]
+    pub cast_shadows: bool,
+}
+
+impl Default for PointLight2D {
+    fn default() -> Self {
+        Self {
+            color: Vec3::ONE,
+            intensity: 1.0,
+            radius: 256.0,
+            falloff: 2.0,
+            source_radius: 8.0,
+            cast_shadows: true,
+        }
+    }
+}
+
+/// 2D 遮挡体组件
+///
+/// 局部空间的闭合多边形（任意绕序），随实体的 `Transform` 平移、旋转、缩放。
+#[derive(Debug, Clone, PartialEq, Component, Describe)]
+/// Closed polygon that casts 2D shadows.
+pub struct Occluder2D {
+    /// 多边形顶点（局部空间）
+    #[describe(hint = "Polygon vertices in local space")]
+    pub points: Vec<Vec2>,
+}
+
+impl Occluder2D {
+    /// 由多边形顶点创建
+    pub fn polygon(points: impl Into<Vec<Vec2>>) -> Self {
+        Self { points: points.into() }
+    }
+
+    /// 以原点为中心的矩形
+    pub fn rect(half_extents: Vec2) -> Self {
+        let Vec2 { x, y } = half_extents;
+        Self::polygon(vec![Vec2::new(-x, -y), Vec2::new(x, -y), Vec2::new(x, y), Vec2::new(-x, y)])
+    }
+
+    /// 平移后的副本
+    pub fn translated(&self, offset: Vec2) -> Vec<Vec2> {
+        self.points.iter().map(|p| *p + offset).collect()
+    }
+}
+
+/// 2D 光照全局设置
+#[derive(Debug, Clone, Resource, Describe)]
+/// Global 2D lighting settings.
+pub struct Light2DSettings {
+    /// 是否启用光照合成
+    #[describe(hint = "Toggle 2D lighting", default = "true")]
+    pub enabled: bool,
+    /// 环境光（无光源处的亮度）
+    #[describe(hint = "Unlit brightness (linear RGB)", default = "(0.1, 0.1, 0.15)")]
+    pub ambient: Vec3,
+}
+
+impl Default for Light2DSettings {
+    fn default() -> Self {
+        Self { enabled: true, ambient: Vec3::new(0.1, 0.1, 0.15) }
+    }
+}
+
+/// 阴影几何顶点 (12 字节)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+pub struct ShadowVertex2D {
+    /// Screen-space position in pixels.
+    pub position: [f32; 2],
+    /// Shadow strength (1 = umbra, 0 = fully lit).
+    pub shade: f32,
+}
+
+crate::impl_vertex!(ShadowVertex2D, [0 => Float32x2, 1 => Float32]);
+
+/// 光源四边形顶点 (36 字节)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+pub struct LightVertex2D {
+    /// Screen-space position in pixels.
+    pub position: [f32; 2],
+    /// Light center in pixels.
+    pub center: [f32; 2],
+    /// Light color premultiplied by intensity.
+    pub color: [f32; 3],
+    /// Light radius in pixels.
+    pub radius: f32,
+    /// Falloff exponent.
+    pub falloff: f32,
+}
+
+crate::impl_vertex!(LightVertex2D, [0 => Float32x2, 1 => Float32x2, 2 => Float32x3, 3 => Float32, 4 => Float32]);
+
+/// 生成单个遮挡多边形对点光源的阴影几何，追加到 `out`（三角形列表）
+///
+/// 背光边挤出为本影四边形（着色 1）；位于轮廓上的顶点再附加一个向外张开的半影扇形，
+/// 着色从本影边缘的 1 渐变到外缘的 0，张角由 `source_radius` 决定。
+/// 挤出长度取光源半径的两倍，保证超出照明范围。
+pub fn shadow_geometry(light_pos: Vec2, light: &PointLight2D, polygon: &[Vec2], out: &mut Vec<ShadowVertex2D>) {
+    let n = polygon.len();
+    if n < 2 {
+        return;
+    }
+    let extrude = light.radius * 2.0;
+    let vertex = |p: Vec2, shade: f32| ShadowVertex2D { position: p.to_array(), shade };
+    let away = |p: Vec2| (p - light_pos).normalize_or_zero();
+
+    // 有向面积符号决定外法线方向
+    let area: f32 = (0..n).map(|i| polygon[i].perp_dot(polygon[(i + 1) % n])).sum();
+    let outward = |a: Vec2, b: Vec2| {
+        let e = b - a;
+        if area >= 0.0 { Vec2::new(e.y, -e.x) } else { Vec2::new(-e.y, e.x) }
+    };
+    let back_facing = |i: usize| {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        outward(a, b).dot(a - light_pos) > 0.0
+    };
+
+    for i in 0..n {
+        if !back_facing(i) {
+            continue;
+        }
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        let (a_far, b_far) = (a + away(a) * extrude, b + away(b) * extrude);
+        out.extend([vertex(a, 1.0), vertex(b, 1.0), vertex(b_far, 1.0)]);
+        out.extend([vertex(a, 1.0), vertex(b_far, 1.0), vertex(a_far, 1.0)]);
+    }
+
+    if light.source_radius <= 0.0 {
+        return;
+    }
+    for i in 0..n {
+        // 顶点 i 两侧的边一背一正时位于轮廓上
+        let prev = (i + n - 1) % n;
+        let (prev_back, next_back) = (back_facing(prev), back_facing(i));
+        if prev_back == next_back {
+            continue;
+        }
+        let v = polygon[i];
+        let inner = if prev_back { polygon[prev] } else { polygon[(i + 1) % n] };
+        let to_v = v - light_pos;
+        let mut side = to_v.perp().normalize_or_zero();
+        if side.dot(inner - v) > 0.0 {
+            side = -side;
+        }
+        // 从光源边缘经过 v 的射线构成半影外缘
+        let outer = (to_v + side * light.source_radius).normalize_or_zero();
+        out.extend([vertex(v, 1.0), vertex(v + away(v) * extrude, 1.0), vertex(v + outer * extrude, 0.0)]);
+    }
+}
+
+/// 单个光源在本帧几何中的范围
+#[derive(Debug, Clone, PartialEq)]
+pub struct Light2DDraw {
+    /// 阴影顶点范围
+    pub shadow_vertices: std::ops::Range<u32>,
+    /// 光源四边形顶点范围
+    pub light_vertices: std::ops::Range<u32>,
+}
+
+/// ECS 资源：每帧收集的 2D 光照几何
+#[derive(Resource, Default)]
+pub struct Light2DCollected {
+    /// 环境光
+    pub ambient: Vec3,
+    /// 所有光源的阴影顶点
+    pub shadow_vertices: Vec<ShadowVertex2D>,
+    /// 所有光源的四边形顶点
+    pub light_vertices: Vec<LightVertex2D>,
+    /// 逐光源绘制范围
+    pub lights: Vec<Light2DDraw>,
+}
+
+impl Light2DCollected {
+    /// 清空本帧数据
+    pub fn clear(&mut self) {
+        self.shadow_vertices.clear();
+        self.light_vertices.clear();
+        self.lights.clear();
+    }
+}
+
+/// 2D 光照收集系统 (PostUpdate)
+///
+/// 把遮挡体变换到屏幕空间，为每个光源生成照明范围内遮挡体的阴影几何与光源四边形。
+pub fn light2d_collect_system(
+    settings: Option<Res<Light2DSettings>>,
+    lights: Query<(&PointLight2D, &Transform)>,
+    occluders: Query<(&Occluder2D, &Transform)>,
+    mut collected: ResMut<Light2DCollected>,
+    mut polygons: Local<Vec<(Vec2, f32, Vec<Vec2>)>>,
+) {
+    collected.clear();
+    collected.ambient = settings.map_or(Light2DSettings::default().ambient, |s| s.ambient);
+
+    // (中心, 外接圆半径, 世界顶点)
+    polygons.clear();
+    for (occluder, transform) in &occluders {
+        let points: Vec<Vec2> =
+            occluder.points.iter().map(|p| transform.transform_point(p.extend(0.0)).truncate()).collect();
+        let center = points.iter().copied().sum::<Vec2>() / points.len().max(1) as f32;
+        let reach = points.iter().map(|p| p.distance(center)).fold(0.0, f32::max);
+        polygons.push((center, reach, points));
+    }
+
+    for (light, transform) in &lights {
+        let center = transform.translation.truncate();
+        let shadow_start = collected.shadow_vertices.len() as u32;
+        if light.cast_shadows {
+            for (occluder_center, reach, points) in polygons.iter() {
+                if occluder_center.distance(center) - reach < light.radius {
+                    shadow_geometry(center, light, points, &mut collected.shadow_vertices);
+                }
+            }
+        }
+
+        let light_start = collected.light_vertices.len() as u32;
+        let color = (light.color * light.intensity).to_array();
+        let corner = |x: f32, y: f32| LightVertex2D {
+            position: (center + Vec2::new(x, y) * light.radius).to_array(),
+            center: center.to_array(),
+            color,
+            radius: light.radius,
+            falloff: light.falloff,
+        };
+        collected.light_vertices.extend([
+            corner(-1.0, -1.0), corner(1.0, -1.0), corner(1.0, 1.0),
+            corner(-1.0, -1.0), corner(1.0, 1.0), corner(-1.0, 1.0),
+        ]);
+
+        let draw = Light2DDraw {
+            shadow_vertices: shadow_start..collected.shadow_vertices.len() as u32,
+            light_vertices: light_start..collected.light_vertices.len() as u32,
+        };
+        collected.lights.push(draw);
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  Light2DRenderer — shadow mask, light map and composite passes
+// ---------------------------------------------------------------------------
+
+struct Light2DTargets {
+    size: (u32, u32),
+    shadow_mask: wgpu::TextureView,
+    light_map: wgpu::TextureView,
+    mask_bind_group: wgpu::BindGroup,
+    light_map_bind_group: wgpu::BindGroup,
+}
+
+/// GPU 2D 光照渲染器
+pub struct Light2DRenderer {
+    shadow_pipeline: wgpu::RenderPipeline,
+    light_pipeline: wgpu::RenderPipeline,
+    composite_pipeline: wgpu::RenderPipeline,
+    ortho_buffer: wgpu::Buffer,
+    ortho_bind_group: wgpu::BindGroup,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    targets: Option<Light2DTargets>,
+    shadow_vb: CachedBuffer,
+    light_vb: CachedBuffer,
+}
+
+impl Light2DRenderer {
+    /// 创建 2D 光照渲染器，`format` 为合成目标格式
+    pub fn new(device: &RenderDevice, format: wgpu::TextureFormat) -> Self {
+        let d = device.device();
+        let shader = d.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Light2D Shader"),
+            source: wgpu::ShaderSource::Wgsl(LIGHT2D_SHADER.into()),
+        });
+
+        let ortho_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Light2D Ortho BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let texture_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Light2D Texture BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+        let layout = d.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Light2D Pipeline Layout"),
+            bind_group_layouts: &[&ortho_bgl, &texture_bgl],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = |label: &str,
+                        vs: &str,
+                        fs: &str,
+                        buffers: &[VertexBufferLayout],
+                        format: wgpu::TextureFormat,
+                        blend: wgpu::BlendState| {
+            d.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState { module: &shader, entry_point: vs, buffers },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: fs,
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(blend),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+            })
+        };
+        use wgpu::{BlendFactor as F, BlendOperation as Op};
+        let component = |src_factor, dst_factor, operation| wgpu::BlendComponent { src_factor, dst_factor, operation };
+
+        // 阴影取最大值，重叠区域不会过暗
+        let max = component(F::One, F::One, Op::Max);
+        let shadow_pipeline = pipeline(
+            "Light2D Shadow Pipeline", "vs_shadow", "fs_shadow",
+            &[ShadowVertex2D::layout()], SHADOW_MASK_FORMAT,
+            wgpu::BlendState { color: max, alpha: max },
+        );
+        let add = component(F::One, F::One, Op::Add);
+        let light_pipeline = pipeline(
+            "Light2D Light Pipeline", "vs_light", "fs_light",
+            &[LightVertex2D::layout()], LIGHT_MAP_FORMAT,
+            wgpu::BlendState { color: add, alpha: add },
+        );
+        // 目标 = 目标 × 光照
+        let composite_pipeline = pipeline(
+            "Light2D Composite Pipeline", "vs_composite", "fs_composite",
+            &[], format,
+            wgpu::BlendState {
+                color: component(F::Dst, F::Zero, Op::Add),
+                alpha: component(F::Zero, F::One, Op::Add),
+            },
+        );
+
+        let ortho_buffer = d.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Light2D Ortho UB"),
+            contents: bytemuck::bytes_of(&MatrixUniform::identity()),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let ortho_bind_group = d.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Light2D Ortho BG"),
+            layout: &ortho_bgl,
+            entries: &[wgpu::BindGroupEntry { binding: 0, resource: ortho_buffer.as_entire_binding() }],
+        });
+
+        Self {
+            shadow_pipeline,
+            light_pipeline,
+            composite_pipeline,
+            ortho_buffer,
+            ortho_bind_group,
+            texture_bind_group_layout: texture_bgl,
+            targets: None,
+            shadow_vb: CachedBuffer::vertex("Light2D Shadow VB (cached)"),
+            light_vb: CachedBuffer::vertex("Light2D Light VB (cached)"),
+        }
+    }
+
+    fn ensure_targets(&mut self, device: &RenderDevice, size: (u32, u32)) {
+        if self.targets.as_ref().is_some_and(|t| t.size == size) {
+            return;
+        }
+        let d = device.device();
+        let texture = |label: &str, format| {
+            d.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d { width: size.0, height: size.1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+        let shadow_mask = texture("Light2D Shadow Mask", SHADOW_MASK_FORMAT);
+        let light_map = texture("Light2D Light Map", LIGHT_MAP_FORMAT);
+        let bind = |label: &str, view: &wgpu::TextureView| {
+            d.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(label),
+                layout: &self.texture_bind_group_layout,
+                entries: &[wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(view) }],
+            })
+        };
+        let mask_bind_group = bind("Light2D Shadow Mask BG", &shadow_mask);
+        let light_map_bind_group = bind("Light2D Light Map BG", &light_map);
+        self.targets = Some(Light2DTargets { size, shadow_mask, light_map, mask_bind_group, light_map_bind_group });
+    }
+
+    /// 渲染光照图并乘到 `target` 上
+    ///
+    /// `target` 的尺寸需为 `screen_width × screen_height`，格式与创建时一致。
+    pub fn render(
+        &mut self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        frame: &Light2DCollected,
+        screen_width: u32,
+        screen_height: u32,
+    ) {
+        if screen_width == 0 || screen_height == 0 {
+            return;
+        }
+        self.ensure_targets(device, (screen_width, screen_height));
+
+        let ortho = glam::Mat4::orthographic_lh(0.0, screen_width as f32, screen_height as f32, 0.0, -1.0, 1.0);
+        device.queue().write_buffer(&self.ortho_buffer, 0, bytemuck::bytes_of(&MatrixUniform::from_mat4(&ortho)));
+        let shadow_vb = (!frame.shadow_vertices.is_empty()).then(|| {
+            self.shadow_vb.ensure_and_write(device.device(), device.queue(), bytemuck::cast_slice(&frame.shadow_vertices))
+        });
+        let light_vb = (!frame.light_vertices.is_empty()).then(|| {
+            self.light_vb.ensure_and_write(device.device(), device.queue(), bytemuck::cast_slice(&frame.light_vertices))
+        });
+        let targets = self.targets.as_ref().expect("targets created above");
+
+        let attachment = |view, load| {
+            Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations { load, store: wgpu::StoreOp::Store },
+            })
+        };
+        let ambient = frame.ambient.as_dvec3();
+        let ambient = wgpu::Color { r: ambient.x, g: ambient.y, b: ambient.z, a: 1.0 };
+        let mut load_light_map = wgpu::LoadOp::Clear(ambient);
+
+        for light in &frame.lights {
+            {
+                let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Light2D Shadow Pass"),
+                    color_attachments: &[attachment(&targets.shadow_mask, wgpu::LoadOp::Clear(wgpu::Color::BLACK))],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                if let (Some(vb), false) = (shadow_vb, light.shadow_vertices.is_empty()) {
+                    rp.set_pipeline(&self.shadow_pipeline);
+                    rp.set_bind_group(0, &self.ortho_bind_group, &[]);
+                    // 阴影 pass 不采样纹理，但布局要求 group 1；遮罩本身是附件，只能绑定光照图
+                    rp.set_bind_group(1, &targets.light_map_bind_group, &[]);
+                    rp.set_vertex_buffer(0, vb.slice(..));
+                    rp.draw(light.shadow_vertices.clone(), 0..1);
+                }
+            }
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Light2D Light Pass"),
+                color_attachments: &[attachment(&targets.light_map, load_light_map)],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            load_light_map = wgpu::LoadOp::Load;
+            if let Some(vb) = light_vb {
+                rp.set_pipeline(&self.light_pipeline);
+                rp.set_bind_group(0, &self.ortho_bind_group, &[]);
+                rp.set_bind_group(1, &targets.mask_bind_group, &[]);
+                rp.set_vertex_buffer(0, vb.slice(..));
+                rp.draw(light.light_vertices.clone(), 0..1);
+            }
+        }
+        if let wgpu::LoadOp::Clear(_) = load_light_map {
+            // 没有光源：光照图只含环境光
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Light2D Ambient Pass"),
+                color_attachments: &[attachment(&targets.light_map, load_light_map)],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+        }
+
+        let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Light2D Composite Pass"),
+            color_attachments: &[attachment(target, wgpu::LoadOp::Load)],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rp.set_pipeline(&self.composite_pipeline);
+        rp.set_bind_group(0, &self.ortho_bind_group, &[]);
+        rp.set_bind_group(1, &targets.light_map_bind_group, &[]);
+        rp.draw(0..3, 0..1);
+    }
+}
+
+/// 2D 光照阶段项（启用时每帧入队一个）
+pub struct Light2DItem;
+
+impl PhaseItem for Light2DItem {
+    type SortKey = ();
+    fn sort_key(&self) {}
+}
+
+/// 2D 光照入队系统 (PostUpdate)
+pub fn light2d_queue_system(settings: Res<Light2DSettings>, mut phase: ResMut<RenderPhase<Light2DItem>>) {
+    if settings.enabled {
+        phase.add(Light2DItem);
+    }
+}
+
+/// 2D 光照插件
+///
+/// 注册收集系统，并在色调映射之后把光照图乘到交换链上（早于诊断叠加层等屏幕叠加）。
+pub struct Light2DPlugin;
+
+impl Plugin for Light2DPlugin {
+    fn build(&self, app: &mut App) {
+        let gpu: Mutex<Option<(wgpu::TextureFormat, Light2DRenderer)>> = Mutex::new(None);
+        app.init_resource::<Light2DSettings>()
+            .init_resource::<Light2DCollected>()
+            .add_render_phase::<Light2DItem>(
+                RenderPhaseDescriptor::new("Light2D", PhaseSlot::AfterTonemap).with_priority(i32::MIN),
+                move |world, _items, ctx| {
+                    let (Some(frame), Some(state)) =
+                        (world.get_resource::<Light2DCollected>(), world.get_resource::<RenderState>())
+                    else {
+                        return;
+                    };
+                    let Ok(mut gpu) = gpu.lock() else { return };
+                    if gpu.as_ref().is_none_or(|(format, _)| *format != ctx.color_format) {
+                        *gpu = Some((ctx.color_format, Light2DRenderer::new(ctx.device, ctx.color_format)));
+                    }
+                    let (_, renderer) = gpu.as_mut().expect("renderer created above");
+                    let (width, height) = state.surface_size;
+                    renderer.render(ctx.device, ctx.encoder, ctx.color_target, frame, width, height);
+                },
+            )
+            .add_systems(bevy_app::PostUpdate, (light2d_collect_system, light2d_queue_system));
+    }
+
+    fn name(&self) -> &str {
+        "Light2DPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn light() -> PointLight2D {
+        PointLight2D { radius: 100.0, source_radius: 0.0, ..Default::default() }
+    }
+
+    #[test]
+    fn test_shadow_extrudes_back_faces_away_from_light() {
+        let square = Occluder2D::rect(Vec2::splat(5.0)).translated(Vec2::new(20.0, 0.0));
+        let mut out = Vec::new();
+        shadow_geometry(Vec2::ZERO, &light(), &square, &mut out);
+
+        // 右、上、下三条边背光，朝向光源的左边不挤出
+        assert_eq!(out.len(), 3 * 6);
+        assert!(out.iter().all(|v| v.shade == 1.0));
+        // 所有阴影顶点都在遮挡体近光边之后
+        assert!(out.iter().all(|v| v.position[0] >= 15.0 - 1e-4));
+        // 挤出到光源半径之外
+        assert!(out.iter().any(|v| Vec2::from(v.position).length() > 100.0));
+
+        // 绕序不影响结果
+        let mut reversed: Vec<Vec2> = square.clone();
+        reversed.reverse();
+        let mut out_reversed = Vec::new();
+        shadow_geometry(Vec2::ZERO, &light(), &reversed, &mut out_reversed);
+        assert_eq!(out.len(), out_reversed.len());
+    }
+
+    #[test]
+    fn test_penumbra_fins_fade_outward() {
+        let soft = PointLight2D { source_radius: 10.0, ..light() };
+        let wall = Occluder2D::rect(Vec2::new(1.0, 10.0)).translated(Vec2::new(50.0, 0.0));
+        let mut out = Vec::new();
+        shadow_geometry(Vec2::ZERO, &soft, &wall, &mut out);
+
+        let fins: Vec<_> = out.iter().filter(|v| v.shade == 0.0).collect();
+        assert_eq!(fins.len(), 2);
+        // 半影外缘比本影更远离中轴
+        for fin in fins {
+            let p = Vec2::from(fin.position);
+            let umbra_slope = 10.0 / 49.0;
+            assert!(p.y.abs() / p.x > umbra_slope);
+        }
+    }
+
+    #[test]
+    fn test_collect_system_culls_distant_occluders() {
+        let mut world = World::new();
+        world.init_resource::<Light2DCollected>();
+        world.spawn((light(), Transform::from_xyz(0.0, 0.0, 0.0)));
+        world.spawn((PointLight2D { cast_shadows: false, ..light() }, Transform::from_xyz(0.0, 0.0, 0.0)));
+        world.spawn((Occluder2D::rect(Vec2::splat(5.0)), Transform::from_xyz(30.0, 0.0, 0.0)));
+        world.spawn((Occluder2D::rect(Vec2::splat(5.0)), Transform::from_xyz(500.0, 0.0, 0.0)));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(light2d_collect_system);
+        schedule.run(&mut world);
+
+        let collected = world.resource::<Light2DCollected>();
+        assert_eq!(collected.lights.len(), 2);
+        assert_eq!(collected.light_vertices.len(), 12);
+        assert_eq!(collected.ambient, Light2DSettings::default().ambient);
+
+        let mut expected = Vec::new();
+        let near = Occluder2D::rect(Vec2::splat(5.0)).translated(Vec2::new(30.0, 0.0));
+        shadow_geometry(Vec2::ZERO, &light(), &near, &mut expected);
+        assert_eq!(collected.lights[0].shadow_vertices, 0..expected.len() as u32);
+        assert!(collected.lights[1].shadow_vertices.is_empty());
+        assert_eq!(collected.lights[1].light_vertices, 6..12);
+    }
+}
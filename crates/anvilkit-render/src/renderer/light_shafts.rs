@@ -0,0 +1,472 @@
+//! # Light Shafts（体积光近似）后处理
+//!
+//! 屏幕空间光柱：以方向光在屏幕上的投影点为中心做径向模糊，
+//! 深度缓冲决定遮挡（仅天空像素透光）。三 pass 流程：
+//! 遮挡遮罩（半分辨率）→ 径向模糊（半分辨率）→ 叠加合成回 HDR。
+//!
+//! 通过 [`DirectionalLight::light_shafts`](crate::renderer::draw::DirectionalLight::light_shafts)
+//! 启用，在后处理栈中位于 Motion Blur 之后、Bloom 之前。
+
+use anvilkit_describe::Describe;
+use glam::{Mat4, Vec2, Vec3};
+use crate::renderer::RenderDevice;
+use crate::renderer::buffer::HDR_FORMAT;
+use crate::renderer::draw::DirectionalLight;
+
+const LIGHT_SHAFTS_SHADER: &str = include_str!("../shaders/light_shafts.wgsl");
+
+/// 遮罩/模糊纹理格式（单通道强度）
+const MASK_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R16Float;
+
+/// 光柱配置
+#[derive(Debug, Clone, PartialEq, Describe)]
+/// Screen-space light shaft (god ray) settings for a directional light.
+pub struct LightShaftSettings {
+    /// Shaft brightness multiplier, applied on top of the light's color × intensity.
+    #[describe(hint = "Shaft brightness", range = "0.0..2.0", default = "0.3")]
+    pub intensity: f32,
+    /// Fraction of the pixel-to-sun distance covered by the blur.
+    #[describe(hint = "Ray length toward the sun", range = "0.0..1.0", default = "0.9")]
+    pub density: f32,
+    /// Per-sample contribution.
+    #[describe(hint = "Per-sample weight", range = "0.0..0.2", default = "0.04")]
+    pub weight: f32,
+    /// Per-sample falloff along the ray.
+    #[describe(hint = "Falloff per sample", range = "0.8..1.0", default = "0.96")]
+    pub decay: f32,
+    /// Number of radial blur samples.
+    #[describe(hint = "Radial blur sample count", range = "8..128", default = "64")]
+    pub samples: u32,
+    /// Radius of the sun glow in screen height units.
+    #[describe(hint = "Sun glow radius (screen heights)", range = "0.01..1.0", default = "0.35")]
+    pub sun_radius: f32,
+}
+
+impl Default for LightShaftSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 0.3,
+            density: 0.9,
+            weight: 0.04,
+            decay: 0.96,
+            samples: 64,
+            sun_radius: 0.35,
+        }
+    }
+}
+
+/// Light shaft GPU uniform
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LightShaftUniform {
+    /// Light color × intensity (rgb) and on-screen visibility fade (a).
+    pub color: [f32; 4],
+    /// Sun position in UV space.
+    pub sun_uv: [f32; 2],
+    /// Density.
+    pub density: f32,
+    /// Weight.
+    pub weight: f32,
+    /// Decay.
+    pub decay: f32,
+    /// Samples as f32.
+    pub samples: f32,
+    /// Sun glow radius.
+    pub sun_radius: f32,
+    /// Full-resolution aspect ratio (width / height).
+    pub aspect: f32,
+}
+
+/// 计算方向光（太阳）在屏幕上的 UV 坐标
+///
+/// 将光源反方向作为无穷远点投影。太阳位于相机背后时返回 `None`。
+/// 结果可能超出 `[0, 1]`（太阳在屏幕外但仍在相机前方）。
+pub fn sun_screen_uv(view_proj: Mat4, light_direction: Vec3) -> Option<Vec2> {
+    let to_sun = -light_direction.normalize_or_zero();
+    let clip = view_proj * to_sun.extend(0.0);
+    if clip.w <= 1e-4 {
+        return None;
+    }
+    let ndc = clip.truncate().truncate() / clip.w;
+    Some(Vec2::new(ndc.x * 0.5 + 0.5, 0.5 - ndc.y * 0.5))
+}
+
+/// 太阳离开屏幕时的淡出系数
+///
+/// 屏幕内为 1.0，超出屏幕边缘 `margin`（UV 单位）后降为 0.0。
+pub fn screen_edge_fade(sun_uv: Vec2, margin: f32) -> f32 {
+    let outside = (-sun_uv).max(sun_uv - Vec2::ONE).max(Vec2::ZERO);
+    let dist = outside.x.max(outside.y);
+    (1.0 - dist / margin.max(1e-4)).clamp(0.0, 1.0)
+}
+
+/// Light Shafts GPU 资源
+pub struct LightShaftResources {
+    /// Occlusion mask (R16Float, half-res).
+    pub mask_texture: wgpu::Texture,
+    /// Mask texture view.
+    pub mask_view: wgpu::TextureView,
+    /// Radially blurred shafts (R16Float, half-res).
+    pub shaft_texture: wgpu::Texture,
+    /// Shaft texture view.
+    pub shaft_view: wgpu::TextureView,
+    /// Occlusion pass pipeline.
+    pub occlusion_pipeline: wgpu::RenderPipeline,
+    /// Radial blur pass pipeline.
+    pub blur_pipeline: wgpu::RenderPipeline,
+    /// Additive composite pipeline.
+    pub composite_pipeline: wgpu::RenderPipeline,
+    /// Uniform buffer.
+    pub uniform_buffer: wgpu::Buffer,
+    /// Linear sampler.
+    pub sampler: wgpu::Sampler,
+    /// Occlusion pass BGL (uniform + depth).
+    pub occlusion_bgl: wgpu::BindGroupLayout,
+    /// Blur / composite pass BGL (texture + sampler + uniform).
+    pub sample_bgl: wgpu::BindGroupLayout,
+    /// Full-resolution size the resources were created for.
+    pub size: (u32, u32),
+}
+
+impl LightShaftResources {
+    /// Create light shaft GPU resources.
+    pub fn new(device: &RenderDevice, width: u32, height: u32) -> Self {
+        let (mask_texture, mask_view) = Self::create_half_res_texture(device, width, height, "LightShafts Mask");
+        let (shaft_texture, shaft_view) = Self::create_half_res_texture(device, width, height, "LightShafts Blur");
+
+        let sampler = device.device().create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("LightShafts Sampler"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let uniform_buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("LightShafts Uniform"),
+            size: std::mem::size_of::<LightShaftUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("LightShafts Shader"),
+            source: wgpu::ShaderSource::Wgsl(LIGHT_SHAFTS_SHADER.into()),
+        });
+
+        // Occlusion BGL: uniform, depth
+        let occlusion_bgl = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("LightShafts Occlusion BGL"),
+            entries: &[
+                bgl_uniform(2),
+                bgl_depth_texture(3),
+            ],
+        });
+
+        // Blur / Composite BGL: src, sampler, uniform
+        let sample_bgl = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("LightShafts Sample BGL"),
+            entries: &[
+                bgl_texture_2d(0),
+                bgl_sampler_filtering(1),
+                bgl_uniform(2),
+            ],
+        });
+
+        let additive = wgpu::BlendState {
+            color: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+            alpha: wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Zero,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+        };
+
+        let occlusion_pipeline = Self::build_pipeline(device, &shader, "occlusion_fs", &occlusion_bgl, MASK_FORMAT, "LightShafts Occlusion", None);
+        let blur_pipeline = Self::build_pipeline(device, &shader, "blur_fs", &sample_bgl, MASK_FORMAT, "LightShafts Blur", None);
+        let composite_pipeline = Self::build_pipeline(device, &shader, "composite_fs", &sample_bgl, HDR_FORMAT, "LightShafts Composite", Some(additive));
+
+        Self {
+            mask_texture,
+            mask_view,
+            shaft_texture,
+            shaft_view,
+            occlusion_pipeline,
+            blur_pipeline,
+            composite_pipeline,
+            uniform_buffer,
+            sampler,
+            occlusion_bgl,
+            sample_bgl,
+            size: (width, height),
+        }
+    }
+
+    fn build_pipeline(
+        device: &RenderDevice,
+        shader: &wgpu::ShaderModule,
+        fs_entry: &str,
+        bgl: &wgpu::BindGroupLayout,
+        format: wgpu::TextureFormat,
+        label: &str,
+        blend: Option<wgpu::BlendState>,
+    ) -> wgpu::RenderPipeline {
+        let pl = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some(label),
+            bind_group_layouts: &[bgl],
+            push_constant_ranges: &[],
+        });
+        device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(label),
+            layout: Some(&pl),
+            vertex: wgpu::VertexState { module: shader, entry_point: "vs_main", buffers: &[] },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                module: shader,
+                entry_point: fs_entry,
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        })
+    }
+
+    fn create_half_res_texture(device: &RenderDevice, w: u32, h: u32, label: &str) -> (wgpu::Texture, wgpu::TextureView) {
+        let tex = device.device().create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d { width: (w / 2).max(1), height: (h / 2).max(1), depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: MASK_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = tex.create_view(&wgpu::TextureViewDescriptor::default());
+        (tex, view)
+    }
+
+    /// Rebuild textures on resize.
+    pub fn resize(&mut self, device: &RenderDevice, width: u32, height: u32) {
+        let (mt, mv) = Self::create_half_res_texture(device, width, height, "LightShafts Mask");
+        self.mask_texture = mt;
+        self.mask_view = mv;
+        let (st, sv) = Self::create_half_res_texture(device, width, height, "LightShafts Blur");
+        self.shaft_texture = st;
+        self.shaft_view = sv;
+        self.size = (width, height);
+    }
+
+    /// Execute light shaft passes, adding the result into `hdr_view`.
+    ///
+    /// Uses `light.light_shafts`; skipped when it is `None`, or when the sun
+    /// is behind the camera or far off-screen.
+    pub fn execute(
+        &self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        hdr_view: &wgpu::TextureView,
+        depth_view: &wgpu::TextureView,
+        light: &DirectionalLight,
+        view_proj: Mat4,
+    ) {
+        let Some(settings) = &light.light_shafts else { return };
+        let Some(sun_uv) = sun_screen_uv(view_proj, light.direction) else { return };
+        let fade = screen_edge_fade(sun_uv, settings.sun_radius);
+        if fade <= 0.0 || settings.intensity <= 0.0 {
+            return;
+        }
+
+        let color = light.color * light.intensity * settings.intensity;
+        let uniform = LightShaftUniform {
+            color: [color.x, color.y, color.z, fade],
+            sun_uv: sun_uv.to_array(),
+            density: settings.density,
+            weight: settings.weight,
+            decay: settings.decay,
+            samples: settings.samples.max(1) as f32,
+            sun_radius: settings.sun_radius,
+            aspect: self.size.0 as f32 / self.size.1.max(1) as f32,
+        };
+        device.queue().write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniform));
+
+        // Pass 1: Occlusion mask (half-res)
+        {
+            let bg = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("LightShafts Occlusion BG"),
+                layout: &self.occlusion_bgl,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 2, resource: self.uniform_buffer.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(depth_view) },
+                ],
+            });
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("LightShafts Occlusion Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.mask_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rp.set_pipeline(&self.occlusion_pipeline);
+            rp.set_bind_group(0, &bg, &[]);
+            rp.draw(0..3, 0..1);
+        }
+
+        // Pass 2: Radial blur toward the sun (half-res)
+        {
+            let bg = self.sample_bind_group(device, &self.mask_view, "LightShafts Blur BG");
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("LightShafts Blur Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &self.shaft_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rp.set_pipeline(&self.blur_pipeline);
+            rp.set_bind_group(0, &bg, &[]);
+            rp.draw(0..3, 0..1);
+        }
+
+        // Pass 3: Additive composite back into HDR
+        {
+            let bg = self.sample_bind_group(device, &self.shaft_view, "LightShafts Composite BG");
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("LightShafts Composite Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: hdr_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rp.set_pipeline(&self.composite_pipeline);
+            rp.set_bind_group(0, &bg, &[]);
+            rp.draw(0..3, 0..1);
+        }
+    }
+
+    fn sample_bind_group(&self, device: &RenderDevice, src: &wgpu::TextureView, label: &str) -> wgpu::BindGroup {
+        device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(label),
+            layout: &self.sample_bgl,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(src) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&self.sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: self.uniform_buffer.as_entire_binding() },
+            ],
+        })
+    }
+}
+
+// --- BGL helpers ---
+
+fn bgl_texture_2d(binding: u32) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        },
+        count: None,
+    }
+}
+
+fn bgl_depth_texture(binding: u32) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Texture {
+            sample_type: wgpu::TextureSampleType::Depth,
+            view_dimension: wgpu::TextureViewDimension::D2,
+            multisampled: false,
+        },
+        count: None,
+    }
+}
+
+fn bgl_sampler_filtering(binding: u32) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+        count: None,
+    }
+}
+
+fn bgl_uniform(binding: u32) -> wgpu::BindGroupLayoutEntry {
+    wgpu::BindGroupLayoutEntry {
+        binding,
+        visibility: wgpu::ShaderStages::FRAGMENT,
+        ty: wgpu::BindingType::Buffer {
+            ty: wgpu::BufferBindingType::Uniform,
+            has_dynamic_offset: false,
+            min_binding_size: None,
+        },
+        count: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera_looking_down_z() -> Mat4 {
+        let view = Mat4::look_at_lh(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        let proj = Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 100.0);
+        proj * view
+    }
+
+    #[test]
+    fn test_sun_straight_ahead_is_screen_center() {
+        // Light travels toward -Z, so the sun sits at +Z, straight ahead.
+        let uv = sun_screen_uv(camera_looking_down_z(), Vec3::NEG_Z).unwrap();
+        assert!((uv - Vec2::splat(0.5)).length() < 1e-4);
+    }
+
+    #[test]
+    fn test_sun_above_maps_to_upper_half() {
+        let dir = Vec3::new(0.0, -0.3, -1.0);
+        let uv = sun_screen_uv(camera_looking_down_z(), dir).unwrap();
+        assert!(uv.y < 0.5);
+    }
+
+    #[test]
+    fn test_sun_behind_camera_is_none() {
+        assert!(sun_screen_uv(camera_looking_down_z(), Vec3::Z).is_none());
+    }
+
+    #[test]
+    fn test_screen_edge_fade() {
+        assert_eq!(screen_edge_fade(Vec2::splat(0.5), 0.3), 1.0);
+        assert_eq!(screen_edge_fade(Vec2::new(1.0, 0.0), 0.3), 1.0);
+        assert!((screen_edge_fade(Vec2::new(1.15, 0.5), 0.3) - 0.5).abs() < 1e-4);
+        assert_eq!(screen_edge_fade(Vec2::new(-0.5, 0.5), 0.3), 0.0);
+    }
+
+    #[test]
+    fn test_uniform_size_is_16_byte_aligned() {
+        assert_eq!(std::mem::size_of::<LightShaftUniform>() % 16, 0);
+    }
+}
@@ -0,0 +1,453 @@
+//! # 光照贴图
+//!
+//! 静态几何的间接光（以及可选的直接光）预先烘焙到纹理中，运行时通过网格的第二套
+//! 纹理坐标（UV1）采样，以一次纹理读取换取廉价的静态全局光照：
+//!
+//! - [`Lightmap`] 组件挂在带 `Handle<Material>` 的实体上，引用烘焙好的纹理（外部工具烘焙，
+//!   或由 [`LightmapBaker`] 生成）；`lightmap_material_system` 为其派生一个光照贴图材质变体，
+//!   原材质保存在 [`LightmapSource`] 中，移除 `Lightmap` 后自动还原
+//! - 光照贴图材质使用专门化的 PBR 着色器（`LIGHTMAP` + `VERTEX_UV1`），网格须带 UV1 属性流上传
+//!   （[`RenderAssets::upload_mesh_with_attributes`](crate::renderer::assets::RenderAssets::upload_mesh_with_attributes)，
+//!   glTF 的 `TEXCOORD_1` 在 `spawn_scene` 中自动上传）
+//! - [`LightmapBaker`] 是一个简单的 CPU 烘焙器：在 UV1 空间光栅化三角形，逐纹素计算
+//!   方向光（可选阴影）与环境光（可选环境光遮蔽），并向外扩展边缘以避免双线性采样接缝
+//!
+//! 贴图中存储的是与反照率相乘的漫反射光照：[`LightmapMode::Indirect`] 替换环境光漫反射项，
+//! 实时光源仍然生效；[`LightmapMode::Full`] 表示贴图已包含直接光，实时光源对该材质不再生效。
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::lightmap::{LightmapBakeSettings, LightmapBaker, LightmapMode};
+//! use anvilkit_assets::mesh::MeshData;
+//!
+//! # fn bake(world: &mut World, entity: Entity, floor: &MeshData, transform: glam::Mat4) {
+//! let settings = LightmapBakeSettings { resolution: 256, ..Default::default() };
+//! let baked = LightmapBaker::new(settings).bake(floor, transform).expect("网格缺少 UV1");
+//! world.entity_mut(entity).insert(baked.to_lightmap(LightmapMode::Full));
+//! # }
+//! ```
+
+use bevy_ecs::prelude::*;
+use glam::{Mat3, Mat4, Vec2, Vec3};
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_core::rng::Rng;
+use anvilkit_assets::material::TextureData;
+use anvilkit_assets::mesh::MeshData;
+
+use crate::renderer::assets::Handle;
+use crate::renderer::material::{Material, Materials};
+
+// ---------------------------------------------------------------------------
+//  组件
+// ---------------------------------------------------------------------------
+
+/// 光照贴图的使用方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LightmapMode {
+    /// 贴图只包含间接 / 环境光，替换环境光漫反射项，实时光源照常叠加
+    #[default]
+    Indirect,
+    /// 贴图包含全部漫反射光照（含直接光），实时光源不再照亮该材质
+    Full,
+}
+
+/// 光照贴图组件
+///
+/// 纹理为线性 RGBA8，RGB 乘以 `intensity` 后作为漫反射光照与反照率相乘。
+/// 多个实体可共享一张图集，通过 `uv_rect` 选择各自的区域。
+#[derive(Component, Debug, Clone)]
+pub struct Lightmap {
+    /// 烘焙纹理（线性）
+    pub texture: TextureData,
+    /// UV1 到贴图坐标的变换：`uv * rect.xy + rect.zw`
+    pub uv_rect: [f32; 4],
+    /// 亮度倍数（RGBA8 编码的最大值）
+    pub intensity: f32,
+    /// 使用方式
+    pub mode: LightmapMode,
+}
+
+impl Lightmap {
+    /// 以整张纹理创建光照贴图
+    pub fn new(texture: TextureData) -> Self {
+        Self { texture, uv_rect: [1.0, 1.0, 0.0, 0.0], intensity: 1.0, mode: LightmapMode::Indirect }
+    }
+
+    /// 使用图集中的子区域（UV 单位的偏移与尺寸）
+    pub fn with_atlas_rect(mut self, offset: Vec2, size: Vec2) -> Self {
+        self.uv_rect = [size.x, size.y, offset.x, offset.y];
+        self
+    }
+
+    /// 设置亮度倍数
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// 设置使用方式
+    pub fn with_mode(mut self, mode: LightmapMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// 派生光照贴图材质之前实体引用的原材质
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LightmapSource(pub Handle<Material>);
+
+type ChangedLightmapQuery = (Entity, &'static Lightmap, &'static Handle<Material>, Option<&'static LightmapSource>);
+
+/// 为新增 / 修改了 [`Lightmap`] 的实体派生光照贴图材质，并为移除了 `Lightmap` 的实体还原原材质
+///
+/// 派生材质归该实体所有，替换或还原时会从 [`Materials`] 中移除。
+pub fn lightmap_material_system(
+    mut commands: Commands,
+    materials: Option<ResMut<Materials>>,
+    changed: Query<ChangedLightmapQuery, Changed<Lightmap>>,
+    removed: Query<(Entity, &Handle<Material>, &LightmapSource), Without<Lightmap>>,
+) {
+    let Some(mut materials) = materials else { return };
+
+    for (entity, lightmap, current, source) in changed.iter() {
+        let base = source.map_or(*current, |s| s.0);
+        let Some(material) = materials.get(&base).cloned() else { continue };
+        let derived = materials.add(material.with_lightmap(lightmap.clone()));
+        if *current != base {
+            materials.remove(current);
+        }
+        commands.entity(entity).insert((derived, LightmapSource(base)));
+    }
+
+    for (entity, current, source) in removed.iter() {
+        if *current != source.0 {
+            materials.remove(current);
+        }
+        commands.entity(entity).insert(source.0).remove::<LightmapSource>();
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  CPU 烘焙
+// ---------------------------------------------------------------------------
+
+/// 烘焙参数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LightmapBakeSettings {
+    /// 贴图边长（纹素）
+    pub resolution: u32,
+    /// 方向光照射方向（世界空间，从光源指向场景）
+    pub light_direction: Vec3,
+    /// 方向光颜色 × 强度（与 `DirectionalLight` 一致）
+    pub light_color: Vec3,
+    /// 是否追踪方向光阴影
+    pub shadows: bool,
+    /// 环境光（直接加到漫反射光照上）
+    pub ambient: Vec3,
+    /// 环境光遮蔽采样数（0 = 关闭）
+    pub ao_samples: u32,
+    /// 环境光遮蔽的最大距离
+    pub ao_distance: f32,
+    /// 边缘扩展的纹素圈数
+    pub padding: u32,
+    /// 射线起点沿法线的偏移，避免自相交
+    pub bias: f32,
+}
+
+impl Default for LightmapBakeSettings {
+    fn default() -> Self {
+        Self {
+            resolution: 128,
+            light_direction: Vec3::new(-0.3, -1.0, -0.5),
+            light_color: Vec3::splat(3.0),
+            shadows: true,
+            ambient: Vec3::splat(0.15),
+            ao_samples: 16,
+            ao_distance: 2.0,
+            padding: 2,
+            bias: 1e-3,
+        }
+    }
+}
+
+/// CPU 端烘焙结果（线性 HDR 漫反射光照）
+#[derive(Debug, Clone)]
+pub struct BakedLightmap {
+    /// 宽度（纹素）
+    pub width: u32,
+    /// 高度（纹素）
+    pub height: u32,
+    /// 逐纹素光照，行主序
+    pub texels: Vec<Vec3>,
+}
+
+impl BakedLightmap {
+    /// 读取纹素 (x, y)
+    pub fn get(&self, x: u32, y: u32) -> Vec3 {
+        self.texels[(y * self.width + x) as usize]
+    }
+
+    /// 最亮分量
+    pub fn max_value(&self) -> f32 {
+        self.texels.iter().map(|t| t.max_element()).fold(0.0, f32::max)
+    }
+
+    /// 编码为 RGBA8 线性纹理，按最亮分量归一化，返回 (纹理, 亮度倍数)
+    pub fn encode(&self) -> (TextureData, f32) {
+        let intensity = self.max_value().max(1e-6);
+        let data = self.texels.iter()
+            .flat_map(|t| {
+                let c = (*t / intensity).clamp(Vec3::ZERO, Vec3::ONE) * 255.0 + Vec3::splat(0.5);
+                [c.x as u8, c.y as u8, c.z as u8, 255]
+            })
+            .collect();
+        (TextureData { width: self.width, height: self.height, data }, intensity)
+    }
+
+    /// 转换为 [`Lightmap`] 组件（整张纹理）
+    pub fn to_lightmap(&self, mode: LightmapMode) -> Lightmap {
+        let (texture, intensity) = self.encode();
+        Lightmap::new(texture).with_intensity(intensity).with_mode(mode)
+    }
+}
+
+/// 简单的 CPU 光照贴图烘焙器（方向光 + 环境光）
+///
+/// 阴影与环境光遮蔽对所有遮挡三角形做暴力求交，适合加载时或编辑器中烘焙少量静态网格。
+pub struct LightmapBaker {
+    settings: LightmapBakeSettings,
+    occluders: Vec<[Vec3; 3]>,
+}
+
+impl LightmapBaker {
+    /// 创建烘焙器
+    pub fn new(settings: LightmapBakeSettings) -> Self {
+        Self { settings, occluders: Vec::new() }
+    }
+
+    /// 添加遮挡网格（世界变换 `transform`）；被烘焙的网格本身总是参与遮挡
+    pub fn with_occluder(mut self, mesh: &MeshData, transform: Mat4) -> Self {
+        self.occluders.extend(world_triangles(mesh, transform));
+        self
+    }
+
+    /// 烘焙 `mesh`（世界变换 `transform`）的光照贴图
+    ///
+    /// 网格缺少 UV1 时返回错误。
+    pub fn bake(&self, mesh: &MeshData, transform: Mat4) -> Result<BakedLightmap> {
+        if !mesh.has_texcoords1() {
+            return Err(AnvilKitError::render("光照贴图烘焙需要网格带 UV1 (texcoords1)".to_string()));
+        }
+        mesh.validate().map_err(AnvilKitError::render)?;
+
+        let s = &self.settings;
+        let size = s.resolution.max(1);
+        let normal_matrix = Mat3::from_mat4(transform).inverse().transpose();
+        let mut occluders = world_triangles(mesh, transform);
+        occluders.extend_from_slice(&self.occluders);
+
+        let mut texels = vec![Vec3::ZERO; (size * size) as usize];
+        let mut covered = vec![false; texels.len()];
+        let to_light = -s.light_direction.normalize_or_zero();
+        let mut rng = Rng::seed_from_u64(0x11_6487);
+
+        for tri in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i as usize);
+            let uv = [a, b, c].map(|i| mesh.texcoords1[i] * size as f32);
+            let area = edge(uv[0], uv[1], uv[2]);
+            if area.abs() < 1e-12 {
+                continue;
+            }
+            let min = uv[0].min(uv[1]).min(uv[2]).floor().max(Vec2::ZERO);
+            let max = uv[0].max(uv[1]).max(uv[2]).ceil().min(Vec2::splat(size as f32));
+            for y in min.y as u32..max.y as u32 {
+                for x in min.x as u32..max.x as u32 {
+                    let p = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                    let w = [edge(uv[1], uv[2], p), edge(uv[2], uv[0], p), edge(uv[0], uv[1], p)].map(|e| e / area);
+                    if w.iter().any(|&w| w < 0.0) {
+                        continue;
+                    }
+                    let local = mesh.positions[a] * w[0] + mesh.positions[b] * w[1] + mesh.positions[c] * w[2];
+                    let normal = mesh.normals[a] * w[0] + mesh.normals[b] * w[1] + mesh.normals[c] * w[2];
+                    let position = transform.transform_point3(local);
+                    let normal = (normal_matrix * normal).normalize_or_zero();
+                    let origin = position + normal * s.bias;
+
+                    let n_dot_l = normal.dot(to_light).max(0.0);
+                    let lit = n_dot_l > 0.0 && !(s.shadows && occluded(&occluders, origin, to_light, f32::MAX));
+                    let direct = if lit { s.light_color * n_dot_l / std::f32::consts::PI } else { Vec3::ZERO };
+
+                    let mut visibility = 1.0;
+                    if s.ao_samples > 0 {
+                        let open = (0..s.ao_samples)
+                            .filter(|_| {
+                                // 余弦加权半球采样
+                                let dir = (normal + rng.unit_vector3()).try_normalize().unwrap_or(normal);
+                                !occluded(&occluders, origin, dir, s.ao_distance)
+                            })
+                            .count();
+                        visibility = open as f32 / s.ao_samples as f32;
+                    }
+
+                    let index = (y * size + x) as usize;
+                    texels[index] = direct + s.ambient * visibility;
+                    covered[index] = true;
+                }
+            }
+        }
+
+        dilate(&mut texels, &mut covered, size, s.padding);
+        Ok(BakedLightmap { width: size, height: size, texels })
+    }
+}
+
+fn world_triangles(mesh: &MeshData, transform: Mat4) -> Vec<[Vec3; 3]> {
+    mesh.indices
+        .chunks_exact(3)
+        .filter(|tri| tri.iter().all(|&i| (i as usize) < mesh.positions.len()))
+        .map(|tri| [0, 1, 2].map(|k| transform.transform_point3(mesh.positions[tri[k] as usize])))
+        .collect()
+}
+
+/// 2D 有向面积（×2）
+fn edge(a: Vec2, b: Vec2, p: Vec2) -> f32 {
+    (b - a).perp_dot(p - a)
+}
+
+/// Möller–Trumbore 射线 / 三角形求交，返回是否在 `(0, max_t)` 内被遮挡
+fn occluded(triangles: &[[Vec3; 3]], origin: Vec3, dir: Vec3, max_t: f32) -> bool {
+    triangles.iter().any(|[a, b, c]| {
+        let e1 = *b - *a;
+        let e2 = *c - *a;
+        let p = dir.cross(e2);
+        let det = e1.dot(p);
+        if det.abs() < 1e-8 {
+            return false;
+        }
+        let inv = 1.0 / det;
+        let s = origin - *a;
+        let u = s.dot(p) * inv;
+        if !(0.0..=1.0).contains(&u) {
+            return false;
+        }
+        let q = s.cross(e1);
+        let v = dir.dot(q) * inv;
+        if v < 0.0 || u + v > 1.0 {
+            return false;
+        }
+        let t = e2.dot(q) * inv;
+        t > 1e-4 && t < max_t
+    })
+}
+
+/// 用已覆盖的邻居平均值向外扩展 `rounds` 圈，避免双线性采样在图表边缘混入未覆盖纹素
+fn dilate(texels: &mut [Vec3], covered: &mut [bool], size: u32, rounds: u32) {
+    let size = size as i32;
+    for _ in 0..rounds {
+        let mut grown = Vec::new();
+        for y in 0..size {
+            for x in 0..size {
+                if covered[(y * size + x) as usize] {
+                    continue;
+                }
+                let (mut sum, mut count) = (Vec3::ZERO, 0);
+                for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (1, -1), (-1, 1), (1, 1)] {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if nx >= 0 && ny >= 0 && nx < size && ny < size && covered[(ny * size + nx) as usize] {
+                        sum += texels[(ny * size + nx) as usize];
+                        count += 1;
+                    }
+                }
+                if count > 0 {
+                    grown.push(((y * size + x) as usize, sum / count as f32));
+                }
+            }
+        }
+        if grown.is_empty() {
+            break;
+        }
+        for (index, value) in grown {
+            texels[index] = value;
+            covered[index] = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_assets::procedural::generate_plane;
+
+    fn lightmapped_plane(size: f32) -> MeshData {
+        let mut plane = generate_plane(size, size);
+        plane.texcoords1 = plane.texcoords.clone();
+        plane
+    }
+
+    #[test]
+    fn test_bake_directional_and_shadow() {
+        let settings = LightmapBakeSettings {
+            resolution: 16,
+            light_direction: Vec3::NEG_Y,
+            light_color: Vec3::splat(std::f32::consts::PI),
+            ambient: Vec3::splat(0.1),
+            ao_samples: 0,
+            ..Default::default()
+        };
+        let floor = lightmapped_plane(4.0);
+        assert!(LightmapBaker::new(settings).bake(&generate_plane(1.0, 1.0), Mat4::IDENTITY).is_err());
+
+        let lit = LightmapBaker::new(settings).bake(&floor, Mat4::IDENTITY).unwrap();
+        assert!((lit.get(8, 8) - Vec3::splat(1.1)).length() < 1e-4);
+
+        // 上方一块小板遮挡地面中心
+        let blocker = generate_plane(1.0, 1.0);
+        let shadowed = LightmapBaker::new(settings)
+            .with_occluder(&blocker, Mat4::from_translation(Vec3::Y))
+            .bake(&floor, Mat4::IDENTITY)
+            .unwrap();
+        assert!((shadowed.get(8, 8) - Vec3::splat(0.1)).length() < 1e-4);
+        assert!((shadowed.get(0, 0) - Vec3::splat(1.1)).length() < 1e-4);
+
+        let (texture, intensity) = shadowed.encode();
+        assert_eq!(texture.data.len(), 16 * 16 * 4);
+        assert!((intensity - 1.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_dilate_fills_neighbours() {
+        let mut texels = vec![Vec3::ZERO; 9];
+        let mut covered = vec![false; 9];
+        texels[4] = Vec3::ONE;
+        covered[4] = true;
+        dilate(&mut texels, &mut covered, 3, 1);
+        assert!(covered.iter().all(|&c| c));
+        assert_eq!(texels[0], Vec3::ONE);
+    }
+
+    #[test]
+    fn test_lightmap_material_system_derives_and_restores() {
+        let mut world = World::new();
+        let mut materials = Materials::default();
+        let base = materials.add(Material::new());
+        world.insert_resource(materials);
+        let entity = world.spawn((base, Lightmap::new(TextureData { width: 1, height: 1, data: vec![255; 4] }))).id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(lightmap_material_system);
+        schedule.run(&mut world);
+
+        let derived = *world.get::<Handle<Material>>(entity).unwrap();
+        assert_ne!(derived, base);
+        assert_eq!(world.get::<LightmapSource>(entity), Some(&LightmapSource(base)));
+        let material = world.resource::<Materials>().get(&derived).unwrap();
+        assert!(material.lightmap.is_some());
+        assert!(material.vertex_attributes.uv1);
+
+        world.entity_mut(entity).remove::<Lightmap>();
+        schedule.run(&mut world);
+        assert_eq!(*world.get::<Handle<Material>>(entity).unwrap(), base);
+        assert!(world.get::<LightmapSource>(entity).is_none());
+        assert!(world.resource::<Materials>().get(&derived).is_none());
+    }
+}
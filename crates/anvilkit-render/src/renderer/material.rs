@@ -0,0 +1,699 @@
+//! # 材质资产
+//!
+//! CPU 端 [`Material`] 资产描述 PBR 因子（基础色、金属度、粗糙度、自发光）、
+//! 可选纹理与混合 / 剔除模式。实体通过 [`Handle<Material>`] 引用材质，
+//! GPU 资源在每帧 `app.update()` 之前由 [`prepare_materials`] 按需创建：
+//!
+//! - 每种 [`PipelineKey`]（顶点格式 + 混合 + 剔除）编译一个管线变体并缓存复用
+//! - 读取可选顶点属性（顶点颜色 / UV1）的材质使用专门化的着色器与 slot 1 顶点流
+//! - 带 `Lightmap` 的材质（`render-3d`）额外专门化 `LIGHTMAP`，经 UV1 采样烘焙光照
+//! - 每个材质生成一个绑定组，未提供的纹理槽使用 1x1 fallback 纹理
+//! - PBR 因子在提取阶段写入绘制命令，随 per-draw 场景 Uniform 上传
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_render::renderer::material::{Material, Materials};
+//!
+//! let mut materials = Materials::default();
+//! let gold = materials.add(
+//!     Material::new()
+//!         .with_base_color([1.0, 0.78, 0.34, 1.0])
+//!         .with_metallic(1.0)
+//!         .with_roughness(0.3),
+//! );
+//! assert_eq!(materials.get(&gold).unwrap().metallic, 1.0);
+//! // world.spawn((mesh_handle, gold, Transform::default()));
+//! ```
+
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+use log::warn;
+use anvilkit_core::collections::{Istr, SlotKey, SlotMap};
+use anvilkit_assets::material::{MaterialData, TextureData};
+
+use crate::renderer::RenderDevice;
+use crate::renderer::assets::{
+    BlendMode, CullMode, Handle, MaterialHandle, PipelineCache, PipelineKey, RenderAssets,
+};
+use crate::renderer::buffer::{
+    create_sampler, create_texture, create_texture_linear, create_uniform_buffer, Vertex, PbrVertex, VertexAttributes,
+    DEPTH_FORMAT, HDR_FORMAT, MSAA_SAMPLE_COUNT,
+};
+#[cfg(feature = "render-3d")]
+use crate::renderer::lightmap::{Lightmap, LightmapMode};
+use crate::renderer::shader_defs::specialize;
+
+const PBR_SHADER: &str = include_str!("../shaders/pbr.wgsl");
+
+/// [`PbrVertex`] 在 [`PipelineKey::vertex_format`] 中的标识
+///
+/// 可选顶点属性的位（[`VertexAttributes::bits`]）左移 8 位后并入该标识，
+/// 光照贴图变体再置位 [`LIGHTMAP_VARIANT_BIT`]。
+pub const PBR_VERTEX_FORMAT: u64 = 1;
+
+/// 光照贴图变体在 [`PipelineKey::vertex_format`] 中的标志位
+pub const LIGHTMAP_VARIANT_BIT: u64 = 1 << 16;
+
+/// PBR 材质资产
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::renderer::material::Material;
+/// use anvilkit_render::renderer::assets::BlendMode;
+///
+/// let glass = Material::new()
+///     .with_base_color([0.8, 0.9, 1.0, 0.3])
+///     .with_roughness(0.05)
+///     .with_blend_mode(BlendMode::AlphaBlend);
+/// assert_eq!(glass.pipeline_key().blend_mode, BlendMode::AlphaBlend);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Material {
+    /// 基础颜色因子 (linear RGBA)，与基础色纹理相乘
+    pub base_color: [f32; 4],
+    /// 金属度因子 [0.0 = 电介质, 1.0 = 金属]
+    pub metallic: f32,
+    /// 粗糙度因子 [0.0 = 镜面, 1.0 = 粗糙]
+    pub roughness: f32,
+    /// 法线贴图强度
+    pub normal_scale: f32,
+    /// 自发光因子 (linear RGB)
+    pub emissive_factor: [f32; 3],
+    /// 基础色纹理（sRGB）
+    pub base_color_texture: Option<TextureData>,
+    /// 法线贴图（tangent-space，线性）
+    pub normal_texture: Option<TextureData>,
+    /// 金属度/粗糙度纹理（G = roughness, B = metallic，线性）
+    pub metallic_roughness_texture: Option<TextureData>,
+    /// 环境光遮蔽纹理（R 通道，线性）
+    pub occlusion_texture: Option<TextureData>,
+    /// 自发光纹理（sRGB）
+    pub emissive_texture: Option<TextureData>,
+    /// 混合模式
+    pub blend_mode: BlendMode,
+    /// 剔除模式
+    pub cull_mode: CullMode,
+    /// 读取的可选顶点属性（网格须以 [`RenderAssets::upload_mesh_with_attributes`] 上传）
+    pub vertex_attributes: VertexAttributes,
+    /// 烘焙光照贴图（经 UV1 采样）
+    #[cfg(feature = "render-3d")]
+    pub lightmap: Option<Lightmap>,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            base_color: [1.0, 1.0, 1.0, 1.0],
+            metallic: 0.0,
+            roughness: 0.5,
+            normal_scale: 1.0,
+            emissive_factor: [0.0, 0.0, 0.0],
+            base_color_texture: None,
+            normal_texture: None,
+            metallic_roughness_texture: None,
+            occlusion_texture: None,
+            emissive_texture: None,
+            blend_mode: BlendMode::Opaque,
+            // 与默认 PBR 管线一致：不剔除（兼容 glTF 绕序）
+            cull_mode: CullMode::None,
+            vertex_attributes: VertexAttributes::NONE,
+            #[cfg(feature = "render-3d")]
+            lightmap: None,
+        }
+    }
+}
+
+impl Material {
+    /// 创建默认白色不透明材质
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置基础颜色 (linear RGBA)
+    pub fn with_base_color(mut self, color: [f32; 4]) -> Self {
+        self.base_color = color;
+        self
+    }
+
+    /// 设置金属度
+    pub fn with_metallic(mut self, metallic: f32) -> Self {
+        self.metallic = metallic;
+        self
+    }
+
+    /// 设置粗糙度
+    pub fn with_roughness(mut self, roughness: f32) -> Self {
+        self.roughness = roughness;
+        self
+    }
+
+    /// 设置法线贴图强度
+    pub fn with_normal_scale(mut self, scale: f32) -> Self {
+        self.normal_scale = scale;
+        self
+    }
+
+    /// 设置自发光因子
+    pub fn with_emissive(mut self, emissive: [f32; 3]) -> Self {
+        self.emissive_factor = emissive;
+        self
+    }
+
+    /// 设置基础色纹理
+    pub fn with_base_color_texture(mut self, texture: TextureData) -> Self {
+        self.base_color_texture = Some(texture);
+        self
+    }
+
+    /// 设置法线贴图
+    pub fn with_normal_texture(mut self, texture: TextureData) -> Self {
+        self.normal_texture = Some(texture);
+        self
+    }
+
+    /// 设置金属度/粗糙度纹理
+    pub fn with_metallic_roughness_texture(mut self, texture: TextureData) -> Self {
+        self.metallic_roughness_texture = Some(texture);
+        self
+    }
+
+    /// 设置环境光遮蔽纹理
+    pub fn with_occlusion_texture(mut self, texture: TextureData) -> Self {
+        self.occlusion_texture = Some(texture);
+        self
+    }
+
+    /// 设置自发光纹理
+    pub fn with_emissive_texture(mut self, texture: TextureData) -> Self {
+        self.emissive_texture = Some(texture);
+        self
+    }
+
+    /// 设置混合模式
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.blend_mode = blend_mode;
+        self
+    }
+
+    /// 设置剔除模式
+    pub fn with_cull_mode(mut self, cull_mode: CullMode) -> Self {
+        self.cull_mode = cull_mode;
+        self
+    }
+
+    /// 设置读取的可选顶点属性
+    pub fn with_vertex_attributes(mut self, attributes: VertexAttributes) -> Self {
+        self.vertex_attributes = attributes;
+        self
+    }
+
+    /// 顶点颜色与基础色相乘（网格需提供颜色属性流）
+    pub fn with_vertex_colors(mut self) -> Self {
+        self.vertex_attributes.colors = true;
+        self
+    }
+
+    /// 设置光照贴图（同时启用 UV1 属性）
+    ///
+    /// 通常由 [`lightmap_material_system`](crate::renderer::lightmap::lightmap_material_system)
+    /// 根据实体的 [`Lightmap`] 组件调用。
+    #[cfg(feature = "render-3d")]
+    pub fn with_lightmap(mut self, lightmap: Lightmap) -> Self {
+        self.vertex_attributes.uv1 = true;
+        self.lightmap = Some(lightmap);
+        self
+    }
+
+    /// 材质对应的管线变体 key
+    pub fn pipeline_key(&self) -> PipelineKey {
+        #[cfg(feature = "render-3d")]
+        let lightmap = if self.lightmap.is_some() { LIGHTMAP_VARIANT_BIT } else { 0 };
+        #[cfg(not(feature = "render-3d"))]
+        let lightmap = 0;
+        PipelineKey {
+            vertex_format: PBR_VERTEX_FORMAT | self.vertex_attributes.bits() << 8 | lightmap,
+            blend_mode: self.blend_mode,
+            cull_mode: self.cull_mode,
+        }
+    }
+}
+
+impl From<MaterialData> for Material {
+    fn from(data: MaterialData) -> Self {
+        Self {
+            base_color: data.base_color_factor,
+            metallic: data.metallic_factor,
+            roughness: data.roughness_factor,
+            normal_scale: data.normal_scale,
+            emissive_factor: data.emissive_factor,
+            base_color_texture: data.base_color_texture,
+            normal_texture: data.normal_texture,
+            metallic_roughness_texture: data.metallic_roughness_texture,
+            occlusion_texture: data.occlusion_texture,
+            emissive_texture: data.emissive_texture,
+            blend_mode: if data.base_color_factor[3] < 1.0 { BlendMode::AlphaBlend } else { BlendMode::Opaque },
+            cull_mode: CullMode::None,
+            vertex_attributes: VertexAttributes::NONE,
+            #[cfg(feature = "render-3d")]
+            lightmap: None,
+        }
+    }
+}
+
+struct MaterialEntry {
+    material: Material,
+    gpu: Option<MaterialHandle>,
+    dirty: bool,
+}
+
+/// 材质资产存储
+///
+/// 通过 [`get_mut`](Self::get_mut) 修改的材质会在下一帧重建 GPU 资源
+/// （重新上传纹理）。仅修改因子时也会重建，频繁变化的颜色应拆分为独立材质。
+///
+/// 句柄为分代键，材质移除后旧句柄失效，不会指向复用同一槽位的新材质。
+#[derive(Resource, Default)]
+pub struct Materials {
+    entries: SlotMap<SlotKey, MaterialEntry>,
+    removed: Vec<MaterialHandle>,
+}
+
+impl Materials {
+    /// 添加材质并返回句柄
+    pub fn add(&mut self, material: Material) -> Handle<Material> {
+        let key = self.entries.insert(MaterialEntry { material, gpu: None, dirty: true });
+        Handle::from_id(key.to_bits())
+    }
+
+    /// 获取材质
+    pub fn get(&self, handle: &Handle<Material>) -> Option<&Material> {
+        self.entries.get(SlotKey::from_bits(handle.id())).map(|e| &e.material)
+    }
+
+    /// 获取可变材质，并标记为需要重建 GPU 资源
+    pub fn get_mut(&mut self, handle: &Handle<Material>) -> Option<&mut Material> {
+        self.entries.get_mut(SlotKey::from_bits(handle.id())).map(|e| {
+            e.dirty = true;
+            &mut e.material
+        })
+    }
+
+    /// 移除材质，GPU 资源在下一帧释放
+    pub fn remove(&mut self, handle: &Handle<Material>) -> Option<Material> {
+        let entry = self.entries.remove(SlotKey::from_bits(handle.id()))?;
+        self.removed.extend(entry.gpu);
+        Some(entry.material)
+    }
+
+    /// 材质的 GPU 句柄（GPU 资源尚未创建时为 `None`）
+    pub fn gpu_handle(&self, handle: &Handle<Material>) -> Option<MaterialHandle> {
+        self.entries.get(SlotKey::from_bits(handle.id())).and_then(|e| e.gpu)
+    }
+
+    /// 材质数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 设备重建后丢弃旧 GPU 句柄，下一帧重新创建全部材质
+    pub(crate) fn invalidate_gpu(&mut self) {
+        self.removed.clear();
+        for entry in self.entries.values_mut() {
+            entry.gpu = None;
+            entry.dirty = true;
+        }
+    }
+
+    fn has_pending(&self) -> bool {
+        !self.removed.is_empty() || self.entries.values().any(|e| e.dirty)
+    }
+}
+
+/// 材质 GPU 管线资源
+///
+/// 持有 PBR 着色器（按可选顶点属性专门化的各个变体）、材质绑定组布局、
+/// fallback 纹理与管线变体缓存。GPU 初始化时插入 World。
+#[derive(Resource)]
+pub struct MaterialPipelines {
+    /// 按着色器定义集合（可选顶点属性与光照贴图）专门化的着色器
+    shaders: HashMap<Vec<Istr>, wgpu::ShaderModule>,
+    pipeline_layout: wgpu::PipelineLayout,
+    material_bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    /// base_color, normal, metallic_roughness, ao, emissive, lightmap
+    fallback_views: [wgpu::TextureView; 6],
+    /// 未使用光照贴图的材质绑定的占位参数
+    fallback_lightmap_params: wgpu::Buffer,
+    cache: PipelineCache,
+}
+
+impl MaterialPipelines {
+    /// 创建材质管线资源
+    ///
+    /// `scene_layout` 与 `ibl_shadow_layout` 分别为 PBR 着色器 group 0 / group 2 的布局。
+    pub fn new(
+        device: &RenderDevice,
+        scene_layout: &wgpu::BindGroupLayout,
+        ibl_shadow_layout: &wgpu::BindGroupLayout,
+    ) -> Self {
+        let tex_layout_entry = |binding: u32| -> wgpu::BindGroupLayoutEntry {
+            wgpu::BindGroupLayoutEntry {
+                binding, visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                }, count: None,
+            }
+        };
+
+        // Material BGL: 5 textures + 1 sampler + lightmap texture + lightmap params
+        let material_bind_group_layout = device.device().create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("PBR Material BGL"),
+                entries: &[
+                    tex_layout_entry(0), // base_color
+                    tex_layout_entry(1), // normal_map
+                    tex_layout_entry(2), // metallic_roughness
+                    tex_layout_entry(3), // ao
+                    tex_layout_entry(4), // emissive
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5, visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    tex_layout_entry(6), // lightmap
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 7, visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            },
+        );
+
+        let pipeline_layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("PBR Material PL"),
+            bind_group_layouts: &[scene_layout, &material_bind_group_layout, ibl_shadow_layout],
+            push_constant_ranges: &[],
+        });
+
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("PBR Material Shader"),
+            source: wgpu::ShaderSource::Wgsl(PBR_SHADER.into()),
+        });
+        let shaders = HashMap::from([(Vec::new(), shader)]);
+
+        let white_pixel = [255u8, 255, 255, 255];
+        let normal_pixel = [128u8, 128, 255, 255]; // 默认法线 (0,0,1) in tangent space
+        let fallback_views = [
+            create_texture(device, 1, 1, &white_pixel, "Default Base Color").1,
+            create_texture_linear(device, 1, 1, &normal_pixel, "Default Normal Map").1,
+            create_texture_linear(device, 1, 1, &white_pixel, "Default MR").1,
+            create_texture_linear(device, 1, 1, &white_pixel, "Default AO").1,
+            create_texture(device, 1, 1, &white_pixel, "Default Emissive").1,
+            create_texture_linear(device, 1, 1, &white_pixel, "Default Lightmap").1,
+        ];
+        let fallback_lightmap_params = create_uniform_buffer(
+            device, "Default Lightmap Params", bytemuck::cast_slice(&NO_LIGHTMAP_PARAMS),
+        );
+
+        Self {
+            shaders,
+            pipeline_layout,
+            material_bind_group_layout,
+            sampler: create_sampler(device, "Default Material Sampler"),
+            fallback_views,
+            fallback_lightmap_params,
+            cache: PipelineCache::new(),
+        }
+    }
+
+    /// 材质绑定组布局（PBR 着色器 group 1）
+    pub fn material_bind_group_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.material_bind_group_layout
+    }
+
+    /// 已编译的管线变体数量
+    pub fn pipeline_count(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// 为材质创建 GPU 资源（复用同 key 的管线变体）并注册到 `RenderAssets`
+    pub fn create_material(
+        &mut self,
+        device: &RenderDevice,
+        assets: &mut RenderAssets,
+        material: &Material,
+    ) -> MaterialHandle {
+        let attributes = material.vertex_attributes;
+        #[allow(unused_mut)]
+        let mut defs = attributes.shader_defs();
+        #[cfg(feature = "render-3d")]
+        if material.lightmap.is_some() {
+            defs.push(Istr::new("LIGHTMAP"));
+        }
+        let shader = self.shaders.entry(defs).or_insert_with_key(|defs| {
+            device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(&format!("PBR Material Shader {:?}", defs)),
+                source: wgpu::ShaderSource::Wgsl(specialize(PBR_SHADER, defs).into()),
+            })
+        });
+        let pipeline_handle = self.cache.get_or_create(material.pipeline_key(), |key| {
+            assets.register_pipeline(build_pipeline(device, shader, &self.pipeline_layout, key, attributes))
+        });
+
+        #[cfg(feature = "render-3d")]
+        let lightmap_texture = material.lightmap.as_ref().map(|l| &l.texture);
+        #[cfg(not(feature = "render-3d"))]
+        let lightmap_texture = None;
+        let slots = [
+            (material.base_color_texture.as_ref(), true, "Material Base Color"),
+            (material.normal_texture.as_ref(), false, "Material Normal Map"),
+            (material.metallic_roughness_texture.as_ref(), false, "Material MR"),
+            (material.occlusion_texture.as_ref(), false, "Material AO"),
+            (material.emissive_texture.as_ref(), true, "Material Emissive"),
+            (lightmap_texture, false, "Material Lightmap"),
+        ];
+        let uploaded: Vec<Option<wgpu::TextureView>> = slots
+            .iter()
+            .map(|(tex, srgb, label)| upload_texture(device, (*tex)?, *srgb, label))
+            .collect();
+        let view = |i: usize| uploaded[i].as_ref().unwrap_or(&self.fallback_views[i]);
+        #[cfg(feature = "render-3d")]
+        let lightmap_buffer = material.lightmap.as_ref().map(|lightmap| {
+            create_uniform_buffer(device, "Material Lightmap Params", bytemuck::cast_slice(&lightmap_params(lightmap)))
+        });
+        #[cfg(not(feature = "render-3d"))]
+        let lightmap_buffer: Option<wgpu::Buffer> = None;
+        let lightmap_buffer = lightmap_buffer.as_ref().unwrap_or(&self.fallback_lightmap_params);
+
+        let bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("PBR Material BG"),
+            layout: &self.material_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(view(0)) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(view(1)) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(view(2)) },
+                wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(view(3)) },
+                wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::TextureView(view(4)) },
+                wgpu::BindGroupEntry { binding: 5, resource: wgpu::BindingResource::Sampler(&self.sampler) },
+                wgpu::BindGroupEntry { binding: 6, resource: wgpu::BindingResource::TextureView(view(5)) },
+                wgpu::BindGroupEntry { binding: 7, resource: lightmap_buffer.as_entire_binding() },
+            ],
+        });
+
+        assets.create_material_with_attributes(pipeline_handle, bind_group, attributes)
+    }
+}
+
+/// 着色器 `LightmapParams`：[uv_rect, (intensity, full, 0, 0)]
+#[cfg(feature = "render-3d")]
+fn lightmap_params(l: &Lightmap) -> [[f32; 4]; 2] {
+    [l.uv_rect, [l.intensity, (l.mode == LightmapMode::Full) as u8 as f32, 0.0, 0.0]]
+}
+
+/// 无光照贴图时的 `LightmapParams`（fallback 绑定）
+const NO_LIGHTMAP_PARAMS: [[f32; 4]; 2] = [[1.0, 1.0, 0.0, 0.0], [0.0; 4]];
+
+fn upload_texture(device: &RenderDevice, tex: &TextureData, srgb: bool, label: &str) -> Option<wgpu::TextureView> {
+    if tex.width == 0 || tex.height == 0 || tex.data.len() != tex.width as usize * tex.height as usize * 4 {
+        warn!("{}: 纹理数据尺寸不匹配 ({}x{}, {} 字节)，使用 fallback", label, tex.width, tex.height, tex.data.len());
+        return None;
+    }
+    let (_, view) = if srgb {
+        create_texture(device, tex.width, tex.height, &tex.data, label)
+    } else {
+        create_texture_linear(device, tex.width, tex.height, &tex.data, label)
+    };
+    Some(view)
+}
+
+/// 混合模式对应的 wgpu 混合状态与是否写入深度
+fn blend_state(mode: BlendMode) -> (wgpu::BlendState, bool) {
+    (mode.blend_state(), mode.writes_depth())
+}
+
+fn build_pipeline(
+    device: &RenderDevice,
+    shader: &wgpu::ShaderModule,
+    layout: &wgpu::PipelineLayout,
+    key: &PipelineKey,
+    attributes: VertexAttributes,
+) -> wgpu::RenderPipeline {
+    let (blend, depth_write_enabled) = blend_state(key.blend_mode);
+    let buffers: Vec<wgpu::VertexBufferLayout> =
+        std::iter::once(PbrVertex::layout()).chain(attributes.layout()).collect();
+    device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some(&format!("PBR Material Pipeline {:?}/{:?}", key.blend_mode, key.cull_mode)),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module: shader, entry_point: "vs_main",
+            buffers: &buffers,
+        },
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            cull_mode: key.cull_mode.face(),
+            ..Default::default()
+        },
+        depth_stencil: Some(wgpu::DepthStencilState {
+            format: DEPTH_FORMAT,
+            depth_write_enabled,
+            depth_compare: wgpu::CompareFunction::Less,
+            stencil: wgpu::StencilState::default(),
+            bias: wgpu::DepthBiasState::default(),
+        }),
+        multisample: wgpu::MultisampleState {
+            count: MSAA_SAMPLE_COUNT,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        fragment: Some(wgpu::FragmentState {
+            module: shader, entry_point: "fs_main",
+            targets: &[Some(wgpu::ColorTargetState {
+                format: HDR_FORMAT,
+                blend: Some(blend),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+        }),
+        multiview: None,
+    })
+}
+
+/// 为新增 / 修改的材质创建 GPU 资源，并释放已移除材质的资源
+///
+/// 由 `RenderApp::tick` 在 `app.update()` 之前调用；自定义渲染循环需自行调用。
+pub fn prepare_materials(device: &RenderDevice, world: &mut World) {
+    let pending = world.get_resource::<Materials>().is_some_and(Materials::has_pending);
+    if !pending || !world.contains_resource::<MaterialPipelines>() || !world.contains_resource::<RenderAssets>() {
+        return;
+    }
+    world.resource_scope(|world, mut materials: Mut<Materials>| {
+        world.resource_scope(|world, mut pipelines: Mut<MaterialPipelines>| {
+            let mut assets = world.resource_mut::<RenderAssets>();
+            let materials = &mut *materials;
+            for handle in materials.removed.drain(..) {
+                assets.remove_material(&handle);
+            }
+            for entry in materials.entries.values_mut().filter(|e| e.dirty) {
+                if let Some(old) = entry.gpu.take() {
+                    assets.remove_material(&old);
+                }
+                entry.gpu = Some(pipelines.create_material(device, &mut assets, &entry.material));
+                entry.dirty = false;
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_materials_storage() {
+        let mut materials = Materials::default();
+        let a = materials.add(Material::new().with_metallic(1.0));
+        let b = materials.add(Material::new());
+        assert_ne!(a, b);
+        assert_eq!(materials.len(), 2);
+        assert!(materials.gpu_handle(&a).is_none());
+        assert!(materials.has_pending());
+
+        materials.get_mut(&b).unwrap().roughness = 0.9;
+        assert_eq!(materials.get(&b).unwrap().roughness, 0.9);
+
+        assert!(materials.remove(&a).is_some());
+        assert!(materials.get(&a).is_none());
+        assert!(materials.remove(&a).is_none());
+
+        // 复用槽位后旧句柄仍然无效
+        let c = materials.add(Material::new());
+        assert_ne!(a, c);
+        assert!(materials.get(&a).is_none());
+        assert!(materials.get(&c).is_some());
+    }
+
+    #[test]
+    fn test_materials_invalidate_gpu() {
+        let mut materials = Materials::default();
+        let a = materials.add(Material::new());
+        for entry in materials.entries.values_mut() {
+            entry.gpu = Some(MaterialHandle(7));
+            entry.dirty = false;
+        }
+        assert!(!materials.has_pending());
+
+        materials.invalidate_gpu();
+        assert!(materials.gpu_handle(&a).is_none());
+        assert!(materials.has_pending());
+    }
+
+    #[test]
+    fn test_pipeline_key_variants() {
+        let opaque = Material::new();
+        let blended = Material::new().with_blend_mode(BlendMode::AlphaBlend);
+        let culled = Material::new().with_cull_mode(CullMode::Back);
+        assert_eq!(opaque.pipeline_key(), Material::new().with_roughness(0.1).pipeline_key());
+        assert_ne!(opaque.pipeline_key(), blended.pipeline_key());
+        assert_ne!(opaque.pipeline_key(), culled.pipeline_key());
+        let painted = Material::new().with_vertex_colors();
+        assert_ne!(opaque.pipeline_key(), painted.pipeline_key());
+        assert_eq!(painted.pipeline_key().vertex_format & 0xff, PBR_VERTEX_FORMAT);
+        #[cfg(feature = "render-3d")]
+        {
+            let lightmapped = Material::new().with_lightmap(Lightmap::new(TextureData { width: 1, height: 1, data: vec![255; 4] }));
+            assert!(lightmapped.vertex_attributes.uv1);
+            assert_ne!(lightmapped.pipeline_key(), Material::new().with_vertex_attributes(VertexAttributes::UV1).pipeline_key());
+            assert_eq!(lightmap_params(&Lightmap::new(TextureData { width: 1, height: 1, data: vec![255; 4] }).with_mode(LightmapMode::Full))[1][1], 1.0);
+        }
+        assert!(!blend_state(BlendMode::AlphaBlend).1);
+        assert!(blend_state(BlendMode::Opaque).1);
+    }
+
+    #[test]
+    fn test_from_material_data() {
+        let data = MaterialData {
+            base_color_factor: [1.0, 0.0, 0.0, 0.5],
+            emissive_factor: [0.2, 0.2, 0.2],
+            ..Default::default()
+        };
+        let mat = Material::from(data);
+        assert_eq!(mat.base_color, [1.0, 0.0, 0.0, 0.5]);
+        assert_eq!(mat.metallic, 1.0);
+        assert_eq!(mat.emissive_factor, [0.2, 0.2, 0.2]);
+        assert_eq!(mat.blend_mode, BlendMode::AlphaBlend);
+    }
+}
@@ -21,17 +21,62 @@ pub mod pipeline;
 pub mod buffer;
 pub mod assets;
 pub mod draw;
+pub mod phase;
+pub mod gpu_profiler;
+pub mod report;
 pub mod state;
 pub mod ibl;
 pub mod shared;
+pub mod shader_defs;
+#[cfg(any(feature = "render-2d", feature = "render-3d"))]
 pub mod sprite;
+#[cfg(feature = "render-2d")]
 pub mod ui;
+#[cfg(feature = "render-2d")]
+pub mod diagnostics_overlay;
+#[cfg(feature = "render-2d")]
+pub mod ui_anchor;
+#[cfg(feature = "render-2d")]
+pub mod ui_binding;
+#[cfg(feature = "render-2d")]
+pub mod ui_notify;
+#[cfg(feature = "render-2d")]
+pub mod ui_scroll;
+#[cfg(feature = "render-2d")]
+pub mod world_ui;
+#[cfg(feature = "render-2d")]
+pub mod light2d;
+#[cfg(feature = "render-2d")]
+pub mod vector;
+#[cfg(feature = "render-2d")]
+pub mod chart;
+#[cfg(feature = "render-2d")]
+pub mod id_picking;
+#[cfg(feature = "ui-theme")]
+pub mod ui_theme;
+#[cfg(feature = "render-3d")]
 pub mod particle;
 pub mod debug;
+#[cfg(feature = "render-3d")]
+pub mod billboard;
+#[cfg(feature = "render-3d")]
+pub mod day_night;
+#[cfg(feature = "render-3d")]
 pub mod raycast;
+#[cfg(feature = "render-3d")]
+pub mod portal;
+#[cfg(feature = "render-3d")]
+pub mod skybox;
+#[cfg(any(feature = "render-2d", feature = "render-3d"))]
 pub mod text;
+#[cfg(any(feature = "render-2d", feature = "render-3d"))]
+pub mod font;
+#[cfg(any(feature = "render-2d", feature = "render-3d"))]
+pub mod text2d;
 pub mod buffer_pool;
+#[cfg(feature = "render-3d")]
 pub mod bloom;
+pub mod fxaa;
 #[cfg(feature = "advanced-render")]
 pub mod ssao;
 #[cfg(feature = "advanced-render")]
@@ -40,21 +85,39 @@ pub mod dof;
 pub mod motion_blur;
 #[cfg(feature = "advanced-render")]
 pub mod color_grading;
+#[cfg(feature = "render-3d")]
+pub mod light_shafts;
 pub mod post_process;
+#[cfg(feature = "render-3d")]
 pub mod shadow;
 pub mod standard_material;
+pub mod material;
+#[cfg(feature = "render-3d")]
+pub mod lightmap;
+#[cfg(feature = "render-3d")]
 pub mod scene_renderer;
+pub mod render_scale;
+#[cfg(feature = "render-3d")]
+pub mod imposter;
+#[cfg(feature = "render-3d")]
+pub mod crowd;
+#[cfg(feature = "render-3d")]
+pub mod scene_spawn;
+#[cfg(feature = "render-2d")]
 pub mod canvas2d;
+#[cfg(feature = "render-3d")]
 pub mod canvas3d;
 #[cfg(feature = "capture")]
 pub mod capture;
+#[cfg(feature = "capture")]
+pub mod frame_stream;
 
 // 重新导出主要类型
-pub use device::RenderDevice;
+pub use device::{RenderDevice, RenderDeviceRestored, RenderSettings};
 pub use surface::RenderSurface;
-pub use pipeline::{RenderPipelineBuilder, BasicRenderPipeline};
+pub use pipeline::{RenderPipelineBuilder, BasicRenderPipeline, RasterState};
 pub use buffer::{
-    Vertex, ColorVertex, MeshVertex, PbrVertex, SkinnedVertex,
+    Vertex, ColorVertex, MeshVertex, PbrVertex, SkinnedVertex, AttributeVertex, VertexAttributes,
     create_vertex_buffer, create_index_buffer, create_index_buffer_u32,
     create_uniform_buffer, create_depth_texture, create_hdr_render_target,
     DEPTH_FORMAT, HDR_FORMAT,
@@ -1,19 +1,38 @@
 //! # 粒子系统
 //!
-//! 提供 CPU 端粒子发射器、粒子生命周期管理和力场支持。
+//! 提供粒子发射器、粒子生命周期管理与实例化公告板渲染。
 //!
 //! ## 核心类型
 //!
-//! - [`ParticleEmitter`]: 粒子发射器组件
+//! - [`ParticleEmitter`]: 粒子发射器组件（发射率、寿命、发射形状，以及速度 / 大小 / 颜色的生命周期曲线）
 //! - [`Particle`]: 单个粒子运行时状态
 //! - [`ParticleSystem`]: 粒子池管理和更新逻辑
+//! - [`ParticleRenderer`]: 实例化公告板渲染，支持 Alpha 与叠加混合
+//! - [`ParticlePlugin`]: 注册发射 / 更新系统与 `AfterOpaque` 渲染阶段
+//!
+//! ## 模拟方式
+//!
+//! [`ParticleSimulation::Cpu`] 在 CPU 上逐粒子积分，Alpha 混合时按相机距离排序；
+//! [`ParticleSimulation::Gpu`] 仍在 CPU 上生成新粒子（写入环形缓冲槽位），运动积分与曲线求值
+//! 由计算着色器完成，适合大量粒子，但不排序，建议配合 [`ParticleBlendMode::Additive`]。
+//! 生命周期曲线在 GPU 上以 [`CURVE_LUT_SIZE`] 个采样的查找表近似。
 
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin};
 use bevy_ecs::prelude::*;
+use anvilkit_core::math::curve::Curve;
+use anvilkit_core::math::{Color, Transform};
+use anvilkit_core::rng::Rng;
 use anvilkit_describe::Describe;
-use glam::Vec3;
+use glam::{Mat4, Vec3, Vec4};
 use bytemuck::{Pod, Zeroable};
 use wgpu::util::DeviceExt;
 
+use super::buffer::DEPTH_FORMAT;
+use super::phase::{PhaseItem, PhaseSlot, RenderPhase, RenderPhaseAppExt, RenderPhaseDescriptor};
+
 /// 单个粒子的运行时状态
 ///
 /// # 示例
@@ -36,6 +55,8 @@ pub struct Particle {
     pub color: [f32; 4],
     /// Visual size of the particle in world units.
     pub size: f32,
+    /// Size at spawn; lifetime size curves scale this value.
+    pub start_size: f32,
     /// Elapsed time since the particle was spawned (seconds).
     pub age: f32,
     /// Total lifespan of the particle (seconds).
@@ -50,6 +71,7 @@ impl Particle {
             velocity,
             color: [1.0, 1.0, 1.0, 1.0],
             size: 0.1,
+            start_size: 0.1,
             age: 0.0,
             lifetime,
         }
@@ -73,6 +95,17 @@ impl Particle {
         // 淡出：alpha 随年龄线性衰减
         self.color[3] = 1.0 - self.normalized_age();
     }
+
+    /// 按发射器的重力与生命周期曲线更新（与 GPU 模拟一致）
+    pub fn simulate(&mut self, dt: f32, emitter: &ParticleEmitter) {
+        let speed = emitter.speed_multiplier_at(self.normalized_age());
+        self.velocity += emitter.gravity * dt;
+        self.position += self.velocity * speed * dt;
+        self.age += dt;
+        let t = self.normalized_age();
+        self.size = self.start_size * emitter.size_multiplier_at(t);
+        self.color = emitter.color_at(t);
+    }
 }
 
 /// 发射形状
@@ -85,21 +118,21 @@ impl Particle {
 /// ```
 #[derive(Debug, Clone)]
 pub enum EmitShape {
-    /// 从一个点发射
+    /// 从一个点沿 +Y 发射
     Point,
-    /// 从球体表面发射
+    /// 从球体表面沿法线发射
     Sphere {
         /// Sphere radius in world units.
         radius: f32,
     },
-    /// 从圆锥体发射（角度弧度）
+    /// 从圆锥体发射（角度弧度），轴向为 +Y
     Cone {
         /// Half-angle of the cone in radians.
         angle: f32,
         /// Base radius of the cone.
         radius: f32,
     },
-    /// 从长方体区域发射
+    /// 从长方体区域内沿 +Y 发射
     Box {
         /// Half-size of the box along each axis.
         half_extents: Vec3,
@@ -110,12 +143,60 @@ impl Default for EmitShape {
     fn default() -> Self { EmitShape::Point }
 }
 
+impl EmitShape {
+    /// 采样局部空间的 `(发射位置, 发射方向)`
+    pub fn sample(&self, rng: &mut Rng) -> (Vec3, Vec3) {
+        match *self {
+            EmitShape::Point => (Vec3::ZERO, Vec3::Y),
+            EmitShape::Sphere { radius } => {
+                let normal = rng.unit_vector3();
+                (normal * radius, normal)
+            }
+            EmitShape::Cone { angle, radius } => {
+                let disk = rng.inside_circle(radius);
+                // 在球冠上均匀采样方向
+                let cos_theta = 1.0 - rng.f32() * (1.0 - angle.clamp(0.0, std::f32::consts::PI).cos());
+                let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+                let around = rng.unit_vector2();
+                (Vec3::new(disk.x, 0.0, disk.y), Vec3::new(around.x * sin_theta, cos_theta, around.y * sin_theta))
+            }
+            EmitShape::Box { half_extents } => {
+                let mut axis = |h: f32| rng.range(-h..h);
+                (Vec3::new(axis(half_extents.x), axis(half_extents.y), axis(half_extents.z)), Vec3::Y)
+            }
+        }
+    }
+}
+
+/// 粒子混合模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ParticleBlendMode {
+    /// 标准 Alpha 混合（烟雾、灰尘），CPU 模拟时按距离排序
+    #[default]
+    Alpha,
+    /// 叠加混合（火焰、火花、魔法），与绘制顺序无关
+    Additive,
+}
+
+/// 粒子模拟方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParticleSimulation {
+    /// CPU 逐粒子积分
+    #[default]
+    Cpu,
+    /// 计算着色器积分（CPU 只负责生成）
+    Gpu,
+}
+
 /// 粒子发射器组件
 ///
+/// 生命周期曲线的时间轴为归一化年龄 `[0, 1]`。
+///
 /// # 示例
 ///
 /// ```rust
-/// use anvilkit_render::renderer::particle::{ParticleEmitter, EmitShape};
+/// use anvilkit_core::math::curve::{Curve, CurveInterpolation};
+/// use anvilkit_render::renderer::particle::{EmitShape, ParticleBlendMode, ParticleEmitter};
 /// use glam::Vec3;
 ///
 /// let emitter = ParticleEmitter {
@@ -125,12 +206,15 @@ impl Default for EmitShape {
 ///     gravity: Vec3::new(0.0, -9.8, 0.0),
 ///     shape: EmitShape::Cone { angle: 0.3, radius: 0.1 },
 ///     max_particles: 500,
+///     size_over_life: Some(Curve::new(CurveInterpolation::Linear).with_key(0.0, 1.0).with_key(1.0, 3.0)),
+///     blend: ParticleBlendMode::Additive,
 ///     ..Default::default()
 /// };
 /// assert!(emitter.enabled);
+/// assert_eq!(emitter.size_multiplier_at(0.5), 2.0);
 /// ```
 #[derive(Debug, Clone, Component, Describe)]
-/// CPU-side particle emitter component.
+/// Particle emitter component.
 pub struct ParticleEmitter {
     /// 每秒发射粒子数
     #[describe(hint = "Particles spawned per second", range = "0.0..1000.0", default = "20.0")]
@@ -171,6 +255,21 @@ pub struct ParticleEmitter {
     /// 发射累积器（内部使用）
     #[describe(hint = "Internal fractional emit counter; do not set manually", default = "0.0")]
     pub emit_accumulator: f32,
+    /// 速度倍数随年龄变化（`None` 为恒定 1）
+    #[describe(hint = "Speed multiplier over normalized age", default = "None")]
+    pub speed_over_life: Option<Curve<f32>>,
+    /// 大小倍数随年龄变化（`None` 为恒定 1）
+    #[describe(hint = "Size multiplier over normalized age", default = "None")]
+    pub size_over_life: Option<Curve<f32>>,
+    /// 颜色随年龄变化（`None` 时在起始 / 结束颜色间线性插值）
+    #[describe(hint = "Linear RGBA color over normalized age; overrides start/end color", default = "None")]
+    pub color_over_life: Option<Curve<Color>>,
+    /// 混合模式
+    #[describe(hint = "Alpha or Additive", default = "Alpha")]
+    pub blend: ParticleBlendMode,
+    /// 模拟方式
+    #[describe(hint = "Cpu or Gpu (compute shader)", default = "Cpu")]
+    pub simulation: ParticleSimulation,
 }
 
 impl Default for ParticleEmitter {
@@ -189,10 +288,146 @@ impl Default for ParticleEmitter {
             max_particles: 200,
             enabled: true,
             emit_accumulator: 0.0,
+            speed_over_life: None,
+            size_over_life: None,
+            color_over_life: None,
+            blend: ParticleBlendMode::Alpha,
+            simulation: ParticleSimulation::Cpu,
         }
     }
 }
 
+impl ParticleEmitter {
+    /// 归一化年龄 `t` 处的颜色
+    pub fn color_at(&self, t: f32) -> [f32; 4] {
+        match self.color_over_life.as_ref().and_then(|curve| curve.sample(t)) {
+            Some(color) => color.to_array(),
+            None => Vec4::from(self.start_color).lerp(Vec4::from(self.end_color), t).to_array(),
+        }
+    }
+
+    /// 归一化年龄 `t` 处的大小倍数
+    pub fn size_multiplier_at(&self, t: f32) -> f32 {
+        self.size_over_life.as_ref().and_then(|curve| curve.sample(t)).unwrap_or(1.0)
+    }
+
+    /// 归一化年龄 `t` 处的速度倍数
+    pub fn speed_multiplier_at(&self, t: f32) -> f32 {
+        self.speed_over_life.as_ref().and_then(|curve| curve.sample(t)).unwrap_or(1.0)
+    }
+
+    /// 在发射器局部形状中生成一个粒子并变换到世界空间
+    pub fn spawn(&self, transform: &Transform, rng: &mut Rng) -> Particle {
+        let (offset, direction) = self.shape.sample(rng);
+        let speed = self.initial_speed + rng.range(-self.speed_variance..self.speed_variance);
+        let size = (self.initial_size + rng.range(-self.size_variance..self.size_variance)).max(0.0);
+        let position = transform.translation + transform.rotation * (offset * transform.scale);
+        let mut particle = Particle::new(position, transform.rotation * direction * speed, self.lifetime);
+        particle.start_size = size;
+        particle.size = size * self.size_multiplier_at(0.0);
+        particle.color = self.color_at(0.0);
+        particle
+    }
+
+    /// 烘焙 GPU 模拟参数（曲线采样为查找表）
+    pub fn sim_params(&self, dt: f32, count: u32) -> ParticleSimParams {
+        let mut params = ParticleSimParams::zeroed();
+        params.gravity = self.gravity.into();
+        params.dt = dt;
+        params.count = count;
+        for i in 0..CURVE_LUT_SIZE {
+            let t = i as f32 / (CURVE_LUT_SIZE - 1) as f32;
+            params.color_lut[i] = self.color_at(t);
+            params.scalar_lut[i] = [self.size_multiplier_at(t), self.speed_multiplier_at(t), 0.0, 0.0];
+        }
+        params
+    }
+}
+
+/// GPU 模拟中生命周期曲线的采样数
+pub const CURVE_LUT_SIZE: usize = 32;
+
+/// GPU 模拟参数 (uniform, 1056 bytes)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct ParticleSimParams {
+    /// Gravity acceleration (m/s^2).
+    pub gravity: [f32; 3],
+    /// Simulation step in seconds.
+    pub dt: f32,
+    /// Number of pool slots to simulate.
+    pub count: u32,
+    /// Padding to 16-byte alignment.
+    pub _pad: [u32; 3],
+    /// Linear RGBA color over normalized age.
+    pub color_lut: [[f32; 4]; CURVE_LUT_SIZE],
+    /// Size (x) and speed (y) multipliers over normalized age.
+    pub scalar_lut: [[f32; 4]; CURVE_LUT_SIZE],
+}
+
+/// GPU 粒子池中的单个粒子 (storage, 64 bytes)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct GpuParticle {
+    /// World-space position.
+    pub position: [f32; 3],
+    /// Elapsed time since spawn (seconds).
+    pub age: f32,
+    /// Velocity (m/s).
+    pub velocity: [f32; 3],
+    /// Total lifespan (seconds); slots with `age >= lifetime` are dead.
+    pub lifetime: f32,
+    /// Current color [R, G, B, A].
+    pub color: [f32; 4],
+    /// Size at spawn.
+    pub start_size: f32,
+    /// Current size (0 for dead slots).
+    pub size: f32,
+    /// Padding to 16-byte alignment.
+    pub _pad: [f32; 2],
+}
+
+impl From<&Particle> for GpuParticle {
+    fn from(p: &Particle) -> Self {
+        Self {
+            position: p.position.into(),
+            age: p.age,
+            velocity: p.velocity.into(),
+            lifetime: p.lifetime,
+            color: p.color,
+            start_size: p.start_size,
+            size: p.size,
+            _pad: [0.0; 2],
+        }
+    }
+}
+
+impl GpuParticle {
+    /// GPU 粒子池作为实例顶点缓冲时的布局（与 [`ParticleVertex`] 使用相同的 location）
+    pub fn instance_layout() -> wgpu::VertexBufferLayout<'static> {
+        const ATTRIBUTES: &[wgpu::VertexAttribute] = &[
+            wgpu::VertexAttribute { offset: 0, shader_location: 0, format: wgpu::VertexFormat::Float32x3 },
+            wgpu::VertexAttribute { offset: 32, shader_location: 1, format: wgpu::VertexFormat::Float32x4 },
+            wgpu::VertexAttribute { offset: 52, shader_location: 2, format: wgpu::VertexFormat::Float32 },
+        ];
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<GpuParticle>() as u64,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: ATTRIBUTES,
+        }
+    }
+}
+
+/// GPU 模拟池的 CPU 端簿记：本帧新生成的粒子与各槽位的到期时间
+#[derive(Debug, Clone)]
+struct GpuPoolState {
+    spawns: Vec<(u32, Particle)>,
+    cursor: u32,
+    expiry: Vec<f32>,
+    clock: f32,
+    params: ParticleSimParams,
+}
+
 /// 粒子系统（粒子池 + 更新逻辑）
 ///
 /// # 示例
@@ -207,6 +442,10 @@ impl Default for ParticleEmitter {
 pub struct ParticleSystem {
     particles: Vec<Particle>,
     capacity: usize,
+    /// 混合模式（由发射系统从发射器同步）
+    pub blend: ParticleBlendMode,
+    rng: Rng,
+    gpu: Option<GpuPoolState>,
 }
 
 impl ParticleSystem {
@@ -215,12 +454,45 @@ impl ParticleSystem {
         Self {
             particles: Vec::with_capacity(capacity),
             capacity,
+            blend: ParticleBlendMode::default(),
+            rng: Rng::seed_from_u64(capacity as u64),
+            gpu: None,
+        }
+    }
+
+    /// 创建 GPU 模拟的粒子池：粒子写入环形缓冲槽位，由计算着色器积分
+    pub fn new_gpu(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            gpu: Some(GpuPoolState {
+                spawns: Vec::new(),
+                cursor: 0,
+                expiry: vec![0.0; capacity],
+                clock: 0.0,
+                params: ParticleSimParams::zeroed(),
+            }),
+            particles: Vec::new(),
+            ..Self::new(capacity)
         }
     }
 
-    /// 存活粒子数
+    /// 设置随机种子（发射形状与随机偏差）
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Rng::seed_from_u64(seed);
+        self
+    }
+
+    /// 模拟方式
+    pub fn simulation(&self) -> ParticleSimulation {
+        if self.gpu.is_some() { ParticleSimulation::Gpu } else { ParticleSimulation::Cpu }
+    }
+
+    /// 存活粒子数（GPU 模式按各槽位的到期时间估算）
     pub fn alive_count(&self) -> usize {
-        self.particles.iter().filter(|p| p.is_alive()).count()
+        match &self.gpu {
+            Some(gpu) => gpu.expiry.iter().filter(|&&expiry| expiry > gpu.clock).count(),
+            None => self.particles.iter().filter(|p| p.is_alive()).count(),
+        }
     }
 
     /// 最大容量
@@ -229,7 +501,16 @@ impl ParticleSystem {
     }
 
     /// 发射一个粒子
+    ///
+    /// CPU 模式池满时复用已死亡粒子的槽位；GPU 模式按环形顺序覆盖最旧的槽位。
     pub fn emit(&mut self, particle: Particle) {
+        if let Some(gpu) = &mut self.gpu {
+            let slot = gpu.cursor;
+            gpu.cursor = (gpu.cursor + 1) % self.capacity as u32;
+            gpu.expiry[slot as usize] = gpu.clock + particle.lifetime;
+            gpu.spawns.push((slot, particle));
+            return;
+        }
         if self.particles.len() < self.capacity {
             self.particles.push(particle);
         } else {
@@ -240,6 +521,14 @@ impl ParticleSystem {
         }
     }
 
+    /// 按发射器生成 `count` 个粒子
+    pub fn spawn(&mut self, emitter: &ParticleEmitter, transform: &Transform, count: usize) {
+        for _ in 0..count {
+            let particle = emitter.spawn(transform, &mut self.rng);
+            self.emit(particle);
+        }
+    }
+
     /// 更新所有粒子
     pub fn update(&mut self, dt: f32, gravity: Vec3) {
         for p in &mut self.particles {
@@ -249,7 +538,40 @@ impl ParticleSystem {
         }
     }
 
-    /// 获取存活粒子的迭代器
+    /// 按发射器的重力与生命周期曲线更新
+    ///
+    /// GPU 模式只推进时钟并烘焙模拟参数，积分在渲染时由计算着色器完成。
+    pub fn update_with(&mut self, dt: f32, emitter: &ParticleEmitter) {
+        if let Some(gpu) = &mut self.gpu {
+            gpu.clock += dt;
+            gpu.params = emitter.sim_params(dt, self.capacity as u32);
+            return;
+        }
+        for p in &mut self.particles {
+            if p.is_alive() {
+                p.simulate(dt, emitter);
+            }
+        }
+    }
+
+    /// 开始新的一帧：清空上一帧已上传的 GPU 生成记录
+    pub fn begin_frame(&mut self) {
+        if let Some(gpu) = &mut self.gpu {
+            gpu.spawns.clear();
+        }
+    }
+
+    /// 本帧新生成、待写入 GPU 池的 `(槽位, 粒子)`
+    pub fn gpu_spawns(&self) -> &[(u32, Particle)] {
+        self.gpu.as_ref().map_or(&[], |gpu| &gpu.spawns)
+    }
+
+    /// 本帧的 GPU 模拟参数（CPU 模式为 `None`）
+    pub fn gpu_params(&self) -> Option<&ParticleSimParams> {
+        self.gpu.as_ref().map(|gpu| &gpu.params)
+    }
+
+    /// 获取存活粒子的迭代器（GPU 模式粒子只存在于显存中，迭代器为空）
     pub fn alive_particles(&self) -> impl Iterator<Item = &Particle> {
         self.particles.iter().filter(|p| p.is_alive())
     }
@@ -257,14 +579,22 @@ impl ParticleSystem {
     /// 清除所有粒子
     pub fn clear(&mut self) {
         self.particles.clear();
+        if let Some(gpu) = &mut self.gpu {
+            gpu.spawns.clear();
+            gpu.expiry.fill(gpu.clock);
+        }
     }
 }
 
 // ---------------------------------------------------------------------------
-//  ParticleRenderer — GPU pipeline for particle point-sprite rendering
+//  ParticleRenderer — instanced billboards + compute simulation
 // ---------------------------------------------------------------------------
 
 const PARTICLE_SHADER: &str = include_str!("../shaders/particle.wgsl");
+const PARTICLE_SIM_SHADER: &str = include_str!("../shaders/particle_sim.wgsl");
+
+/// 计算着色器工作组大小（与 `particle_sim.wgsl` 一致）
+const SIM_WORKGROUP_SIZE: u32 = 64;
 
 /// 粒子 GPU 顶点 (32 bytes)
 #[repr(C)]
@@ -307,27 +637,87 @@ impl ParticleVertex {
     }
 }
 
+/// 粒子场景 uniform (96 bytes)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct ParticleSceneUniform {
+    view_proj: [[f32; 4]; 4],
+    camera_right: [f32; 4],
+    camera_up: [f32; 4],
+}
+
+impl ParticleSceneUniform {
+    /// 相机的世界空间右 / 上方向取自 view-projection 的前两行（对称视锥下与视图矩阵同向）
+    fn new(view_proj: &Mat4) -> Self {
+        let axis = |row: Vec4| row.truncate().normalize_or_zero().extend(0.0).to_array();
+        Self {
+            view_proj: view_proj.to_cols_array_2d(),
+            camera_right: axis(view_proj.row(0)),
+            camera_up: axis(view_proj.row(1)),
+        }
+    }
+}
+
+/// 粒子绘制视图：目标与相机
+pub struct ParticleView<'a> {
+    /// 颜色目标
+    pub color: &'a wgpu::TextureView,
+    /// MSAA 解析目标
+    pub resolve: Option<&'a wgpu::TextureView>,
+    /// 深度缓冲（提供时启用只读深度测试）
+    pub depth: Option<&'a wgpu::TextureView>,
+    /// 相机 view-projection 矩阵
+    pub view_proj: Mat4,
+    /// 相机位置（提供时按相机距离排序：远→近，正确 alpha 混合）
+    pub camera_pos: Option<Vec3>,
+}
+
+/// 单个 GPU 模拟池的显存资源
+struct GpuParticlePool {
+    capacity: usize,
+    particles: wgpu::Buffer,
+    params: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+/// 管线缓存键：(混合模式, 是否 GPU 池布局, 是否深度测试)
+type PipelineKey = (ParticleBlendMode, bool, bool);
+
 /// GPU 粒子渲染器
 pub struct ParticleRenderer {
-    /// The wgpu render pipeline for particle point-sprites.
-    pub pipeline: wgpu::RenderPipeline,
-    /// Uniform buffer holding the scene view-projection matrix.
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    shader: wgpu::ShaderModule,
+    pipeline_layout: wgpu::PipelineLayout,
+    pipelines: HashMap<PipelineKey, wgpu::RenderPipeline>,
+    /// Uniform buffer holding the view-projection matrix and camera axes.
     pub scene_buffer: wgpu::Buffer,
     /// Bind group for the scene uniform buffer.
     pub scene_bind_group: wgpu::BindGroup,
+    sim_pipeline: wgpu::ComputePipeline,
+    sim_bind_group_layout: wgpu::BindGroupLayout,
+    gpu_pools: HashMap<Entity, GpuParticlePool>,
     /// Cached instance buffer for per-frame reuse.
     cached_instance_buf: super::shared::CachedBuffer,
+    /// CPU-side instance scratch buffer, reused across frames.
+    scratch_vertices: Vec<ParticleVertex>,
 }
 
 impl ParticleRenderer {
-    /// Creates the particle render pipeline, uniform buffer, and bind group.
+    /// Creates the particle renderer for a single-sampled target.
     pub fn new(device: &super::RenderDevice, format: wgpu::TextureFormat) -> Self {
-        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+        Self::with_sample_count(device, format, 1)
+    }
+
+    /// Creates the particle renderer for a target with the given MSAA sample count.
+    pub fn with_sample_count(device: &super::RenderDevice, format: wgpu::TextureFormat, sample_count: u32) -> Self {
+        let d = device.device();
+        let shader = d.create_shader_module(wgpu::ShaderModuleDescriptor {
             label: Some("Particle Shader"),
             source: wgpu::ShaderSource::Wgsl(PARTICLE_SHADER.into()),
         });
 
-        let scene_bgl = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        let scene_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("Particle Scene BGL"),
             entries: &[wgpu::BindGroupLayoutEntry {
                 binding: 0,
@@ -341,52 +731,19 @@ impl ParticleRenderer {
             }],
         });
 
-        let pipeline_layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        let pipeline_layout = d.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
             label: Some("Particle Pipeline Layout"),
             bind_group_layouts: &[&scene_bgl],
             push_constant_ranges: &[],
         });
 
-        let pipeline = device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Particle Pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: "vs_main",
-                buffers: &[ParticleVertex::layout()],
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: "fs_main",
-                targets: &[Some(wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                ..Default::default()
-            },
-            depth_stencil: Some(wgpu::DepthStencilState {
-                format: wgpu::TextureFormat::Depth32Float,
-                depth_write_enabled: false, // read-only: particles don't write depth
-                depth_compare: wgpu::CompareFunction::Less,
-                stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
-            }),
-            multisample: wgpu::MultisampleState::default(),
-            multiview: None,
-        });
-
-        let initial = super::shared::MatrixUniform::identity();
-        let scene_buffer = device.device().create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        let scene_buffer = d.create_buffer_init(&wgpu::util::BufferInitDescriptor {
             label: Some("Particle Scene UB"),
-            contents: bytemuck::bytes_of(&initial),
+            contents: bytemuck::bytes_of(&ParticleSceneUniform::new(&Mat4::IDENTITY)),
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
         });
 
-        let scene_bg = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+        let scene_bg = d.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Particle Scene BG"),
             layout: &scene_bgl,
             entries: &[wgpu::BindGroupEntry {
@@ -395,91 +752,260 @@ impl ParticleRenderer {
             }],
         });
 
+        // 计算模拟
+        let sim_shader = d.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Particle Sim Shader"),
+            source: wgpu::ShaderSource::Wgsl(PARTICLE_SIM_SHADER.into()),
+        });
+        let sim_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Particle Sim BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: false },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let sim_layout = d.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Particle Sim Pipeline Layout"),
+            bind_group_layouts: &[&sim_bgl],
+            push_constant_ranges: &[],
+        });
+        let sim_pipeline = d.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Particle Sim Pipeline"),
+            layout: Some(&sim_layout),
+            module: &sim_shader,
+            entry_point: "cs_main",
+        });
+
         Self {
-            pipeline,
+            format,
+            sample_count,
+            shader,
+            pipeline_layout,
+            pipelines: HashMap::new(),
             scene_buffer,
             scene_bind_group: scene_bg,
+            sim_pipeline,
+            sim_bind_group_layout: sim_bgl,
+            gpu_pools: HashMap::new(),
             cached_instance_buf: super::shared::CachedBuffer::vertex("Particle Instance (cached)"),
+            scratch_vertices: Vec::new(),
         }
     }
 
+    fn pipeline(&mut self, device: &super::RenderDevice, key: PipelineKey) -> &wgpu::RenderPipeline {
+        let (blend_mode, gpu_layout, depth) = key;
+        let (format, sample_count) = (self.format, self.sample_count);
+        let (shader, layout) = (&self.shader, &self.pipeline_layout);
+        self.pipelines.entry(key).or_insert_with(|| {
+            let blend = match blend_mode {
+                ParticleBlendMode::Alpha => wgpu::BlendState::ALPHA_BLENDING,
+                ParticleBlendMode::Additive => wgpu::BlendState {
+                    color: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::SrcAlpha,
+                        dst_factor: wgpu::BlendFactor::One,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                    alpha: wgpu::BlendComponent {
+                        src_factor: wgpu::BlendFactor::Zero,
+                        dst_factor: wgpu::BlendFactor::One,
+                        operation: wgpu::BlendOperation::Add,
+                    },
+                },
+            };
+            let instance_layout = if gpu_layout { GpuParticle::instance_layout() } else { ParticleVertex::layout() };
+            device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("Particle Pipeline"),
+                layout: Some(layout),
+                vertex: wgpu::VertexState { module: shader, entry_point: "vs_main", buffers: &[instance_layout] },
+                fragment: Some(wgpu::FragmentState {
+                    module: shader,
+                    entry_point: "fs_main",
+                    targets: &[Some(wgpu::ColorTargetState { format, blend: Some(blend), write_mask: wgpu::ColorWrites::ALL })],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil: depth.then(|| wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: false, // read-only: particles don't write depth
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState { count: sample_count, ..Default::default() },
+                multiview: None,
+            })
+        })
+    }
+
     /// 从 ParticleSystem 收集存活粒子并渲染。
     ///
-    /// - `depth_view`: 如果提供，启用深度测试（read-only）。
-    /// - `camera_pos`: 如果提供，按相机距离排序（远→近，正确 alpha 混合）。
+    /// GPU 模拟的粒子池需要用 [`Self::draw`] 按实体区分显存资源。
     pub fn render(
         &mut self,
         device: &super::RenderDevice,
         encoder: &mut wgpu::CommandEncoder,
-        target: &wgpu::TextureView,
-        depth_view: Option<&wgpu::TextureView>,
+        view: &ParticleView,
         particle_system: &ParticleSystem,
-        view_proj: &glam::Mat4,
-        camera_pos: Option<Vec3>,
     ) {
-        let mut particles: Vec<&Particle> = particle_system.alive_particles().collect();
+        self.draw(device, encoder, view, Entity::PLACEHOLDER, particle_system);
+    }
 
-        if particles.is_empty() {
-            return;
-        }
+    /// 模拟（GPU 模式）并绘制一个粒子池
+    ///
+    /// `key` 标识 GPU 粒子池的显存资源，通常为发射器实体。
+    pub fn draw(
+        &mut self,
+        device: &super::RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &ParticleView,
+        key: Entity,
+        particle_system: &ParticleSystem,
+    ) {
+        let uniform = ParticleSceneUniform::new(&view.view_proj);
+        device.queue().write_buffer(&self.scene_buffer, 0, bytemuck::bytes_of(&uniform));
 
-        // Sort back-to-front for correct alpha blending
-        if let Some(cam) = camera_pos {
-            particles.sort_by(|a, b| {
-                let da = (b.position - cam).length_squared();
-                let db = (a.position - cam).length_squared();
-                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
-            });
+        if let Some(params) = particle_system.gpu_params() {
+            self.simulate(device, encoder, key, particle_system, params);
         }
+        let pipeline_key = (particle_system.blend, particle_system.gpu.is_some(), view.depth.is_some());
+        self.pipeline(device, pipeline_key);
+        let pipeline = &self.pipelines[&pipeline_key];
 
-        let vertices: Vec<ParticleVertex> = particles.iter()
-            .map(|p| ParticleVertex {
+        let (instance_buffer, instance_count) = if particle_system.gpu.is_some() {
+            let pool = &self.gpu_pools[&key];
+            (&pool.particles, pool.capacity as u32)
+        } else {
+            // 顶点暂存缓冲跨帧复用，避免每帧分配
+            self.scratch_vertices.clear();
+            self.scratch_vertices.extend(particle_system.alive_particles().map(|p| ParticleVertex {
                 position: p.position.into(),
                 color: p.color,
                 size: p.size,
-            })
-            .collect();
+            }));
+            if self.scratch_vertices.is_empty() {
+                return;
+            }
 
-        // Update view-projection
-        let uniform = super::shared::MatrixUniform::from_mat4(view_proj);
-        device.queue().write_buffer(&self.scene_buffer, 0, bytemuck::bytes_of(&uniform));
+            // Sort back-to-front for correct alpha blending
+            if let (Some(cam), ParticleBlendMode::Alpha) = (view.camera_pos, particle_system.blend) {
+                self.scratch_vertices.sort_by(|a, b| {
+                    let da = (Vec3::from(b.position) - cam).length_squared();
+                    let db = (Vec3::from(a.position) - cam).length_squared();
+                    da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+                });
+            }
 
-        // Reuse cached instance buffer if large enough
-        let data: &[u8] = bytemuck::cast_slice(&vertices);
-        let instance_buffer = self.cached_instance_buf.ensure_and_write(
-            device.device(),
-            device.queue(),
-            data,
-        );
-
-        {
-            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Particle Pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: target,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: depth_view.map(|dv| wgpu::RenderPassDepthStencilAttachment {
-                    view: dv,
-                    depth_ops: Some(wgpu::Operations {
-                        load: wgpu::LoadOp::Load,
-                        store: wgpu::StoreOp::Store, // read-only but StoreOp required
-                    }),
-                    stencil_ops: None,
+            // Reuse cached instance buffer if large enough
+            let data: &[u8] = bytemuck::cast_slice(&self.scratch_vertices);
+            let buffer = self.cached_instance_buf.ensure_and_write(device.device(), device.queue(), data);
+            (buffer, self.scratch_vertices.len() as u32)
+        };
+
+        let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Particle Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: view.color,
+                resolve_target: view.resolve,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: view.depth.map(|dv| wgpu::RenderPassDepthStencilAttachment {
+                view: dv,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Load,
+                    store: wgpu::StoreOp::Store, // read-only but StoreOp required
                 }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        rp.set_pipeline(pipeline);
+        rp.set_bind_group(0, &self.scene_bind_group, &[]);
+        rp.set_vertex_buffer(0, instance_buffer.slice(..));
+        rp.draw(0..6, 0..instance_count);
+    }
 
-            rp.set_pipeline(&self.pipeline);
-            rp.set_bind_group(0, &self.scene_bind_group, &[]);
-            rp.set_vertex_buffer(0, instance_buffer.slice(..));
-            rp.draw(0..6, 0..vertices.len() as u32);
+    /// 写入本帧新粒子并调度计算模拟
+    fn simulate(
+        &mut self,
+        device: &super::RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        key: Entity,
+        particle_system: &ParticleSystem,
+        params: &ParticleSimParams,
+    ) {
+        let capacity = particle_system.capacity();
+        if self.gpu_pools.get(&key).is_none_or(|pool| pool.capacity != capacity) {
+            let d = device.device();
+            let particles = d.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Particle Pool"),
+                contents: bytemuck::cast_slice(&vec![GpuParticle::zeroed(); capacity]),
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            });
+            let params_buffer = d.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Particle Sim Params"),
+                size: std::mem::size_of::<ParticleSimParams>() as u64,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            let bind_group = d.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Particle Sim BG"),
+                layout: &self.sim_bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry { binding: 0, resource: particles.as_entire_binding() },
+                    wgpu::BindGroupEntry { binding: 1, resource: params_buffer.as_entire_binding() },
+                ],
+            });
+            self.gpu_pools.insert(key, GpuParticlePool { capacity, particles, params: params_buffer, bind_group });
         }
+        let pool = &self.gpu_pools[&key];
+
+        let stride = std::mem::size_of::<GpuParticle>() as u64;
+        for (slot, particle) in particle_system.gpu_spawns() {
+            device.queue().write_buffer(
+                &pool.particles,
+                *slot as u64 * stride,
+                bytemuck::bytes_of(&GpuParticle::from(particle)),
+            );
+        }
+        device.queue().write_buffer(&pool.params, 0, bytemuck::bytes_of(params));
+
+        let mut cp = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Particle Sim Pass"),
+            timestamp_writes: None,
+        });
+        cp.set_pipeline(&self.sim_pipeline);
+        cp.set_bind_group(0, &pool.bind_group, &[]);
+        cp.dispatch_workgroups((capacity as u32).div_ceil(SIM_WORKGROUP_SIZE), 1, 1);
+    }
+
+    /// 释放不再存在的 GPU 粒子池
+    pub fn retain_gpu_pools(&mut self, mut keep: impl FnMut(Entity) -> bool) {
+        self.gpu_pools.retain(|entity, _| keep(*entity));
     }
 }
 
@@ -498,54 +1024,142 @@ pub struct ParticleSystems {
 ///
 /// 需要 `DeltaTime` 资源（来自 `anvilkit_core::time::DeltaTime`）和
 /// `Transform`（来自 `anvilkit_core::math::Transform`）。
+/// 模拟方式或容量变化时重建该实体的粒子池。
+///
+/// 存在 [`QualityScale`](crate::quality::QualityScale) 时发射率乘以其 `particle_scale`。
 pub fn particle_emit_system(
-    dt: Res<anvilkit_core::time::DeltaTime>,
-    mut emitters: Query<(Entity, &mut ParticleEmitter, &anvilkit_core::math::Transform)>,
+    dt: Option<Res<anvilkit_core::time::DeltaTime>>,
+    quality: Option<Res<crate::quality::QualityScale>>,
+    mut emitters: Query<(Entity, &mut ParticleEmitter, &Transform)>,
     mut pool: ResMut<ParticleSystems>,
 ) {
+    let Some(dt) = dt else { return };
+    let rate_scale = quality.map_or(1.0, |q| q.particle_scale.max(0.0));
     for (entity, mut emitter, transform) in &mut emitters {
-        if !emitter.enabled {
-            continue;
-        }
-
         let sys = pool.systems
             .entry(entity)
-            .or_insert_with(|| ParticleSystem::new(emitter.max_particles));
+            .or_insert_with(|| new_pool(entity, &emitter));
+        if sys.simulation() != emitter.simulation || sys.capacity() != emitter.max_particles.max(1) {
+            *sys = new_pool(entity, &emitter);
+        }
+        sys.blend = emitter.blend;
+        sys.begin_frame();
 
-        emitter.emit_accumulator += emitter.emit_rate * dt.0;
+        if !emitter.enabled {
+            continue;
+        }
+        emitter.emit_accumulator += emitter.emit_rate * rate_scale * dt.0;
         let emit_count = emitter.emit_accumulator as usize;
         emitter.emit_accumulator -= emit_count as f32;
-
-        for _ in 0..emit_count {
-            let velocity = Vec3::Y * emitter.initial_speed;
-            let mut p = Particle::new(transform.translation, velocity, emitter.lifetime);
-            p.size = emitter.initial_size;
-            p.color = emitter.start_color;
-            sys.emit(p);
-        }
+        sys.spawn(&emitter, transform, emit_count);
     }
 }
 
-/// 更新系统：推进所有粒子生命周期。
+fn new_pool(entity: Entity, emitter: &ParticleEmitter) -> ParticleSystem {
+    let capacity = emitter.max_particles.max(1);
+    let pool = match emitter.simulation {
+        ParticleSimulation::Cpu => ParticleSystem::new(capacity),
+        ParticleSimulation::Gpu => ParticleSystem::new_gpu(capacity),
+    };
+    pool.with_seed(entity.to_bits())
+}
+
+/// 更新系统：推进所有粒子生命周期，并移除已删除发射器的粒子池。
 pub fn particle_update_system(
-    dt: Res<anvilkit_core::time::DeltaTime>,
+    dt: Option<Res<anvilkit_core::time::DeltaTime>>,
     emitters: Query<(Entity, &ParticleEmitter)>,
     mut pool: ResMut<ParticleSystems>,
 ) {
+    pool.systems.retain(|entity, _| emitters.contains(*entity));
+    let Some(dt) = dt else { return };
     for (entity, emitter) in &emitters {
         if let Some(sys) = pool.systems.get_mut(&entity) {
-            sys.update(dt.0, emitter.gravity);
+            sys.update_with(dt.0, emitter);
+        }
+    }
+}
+
+/// 粒子阶段项：一个需要绘制的粒子池
+pub struct ParticleItem {
+    /// 发射器实体
+    pub entity: Entity,
+}
+
+impl PhaseItem for ParticleItem {
+    type SortKey = ();
+    fn sort_key(&self) {}
+}
+
+/// 粒子入队系统 (PostUpdate)：存活粒子或 GPU 池各入队一项
+pub fn particle_queue_system(pool: Res<ParticleSystems>, mut phase: ResMut<RenderPhase<ParticleItem>>) {
+    for (&entity, sys) in &pool.systems {
+        if sys.simulation() == ParticleSimulation::Gpu || sys.alive_particles().next().is_some() {
+            phase.add(ParticleItem { entity });
         }
     }
 }
 
+/// 粒子插件
+///
+/// 注册 [`ParticleSystems`]、发射与更新系统 (Update)，以及 `AfterOpaque` 插入点上的 `Particles` 阶段
+/// （优先级 100，位于其他场景内阶段之后）。发射器按与相机的距离从远到近绘制。
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        let gpu: Mutex<Option<ParticleRenderer>> = Mutex::new(None);
+        app.init_resource::<ParticleSystems>()
+            .add_render_phase::<ParticleItem>(
+                RenderPhaseDescriptor::new("Particles", PhaseSlot::AfterOpaque).with_priority(100),
+                move |world, items, ctx| {
+                    let Some(pool) = world.get_resource::<ParticleSystems>() else { return };
+                    let Ok(mut gpu) = gpu.lock() else { return };
+                    if gpu.as_ref().is_none_or(|r| r.format != ctx.color_format || r.sample_count != ctx.sample_count) {
+                        *gpu = Some(ParticleRenderer::with_sample_count(ctx.device, ctx.color_format, ctx.sample_count));
+                    }
+                    let renderer = gpu.as_mut().expect("renderer created above");
+                    renderer.retain_gpu_pools(|entity| pool.systems.contains_key(&entity));
+
+                    // 近平面中心作为相机位置的近似，用于远近排序
+                    let camera = ctx.view_proj.inverse().project_point3(Vec3::ZERO);
+                    let distance = |entity: Entity| {
+                        world.get::<Transform>(entity).map_or(0.0, |t| t.translation.distance_squared(camera))
+                    };
+                    let mut order: Vec<(f32, Entity)> = items.iter().map(|item| (distance(item.entity), item.entity)).collect();
+                    order.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+                    let view = ParticleView {
+                        color: ctx.color_target,
+                        resolve: ctx.resolve_target,
+                        depth: ctx.depth_target,
+                        view_proj: ctx.view_proj,
+                        camera_pos: Some(camera),
+                    };
+                    for (_, entity) in order {
+                        let Some(sys) = pool.systems.get(&entity) else { continue };
+                        renderer.draw(ctx.device, ctx.encoder, &view, entity, sys);
+                    }
+                },
+            )
+            .add_systems(bevy_app::Update, (particle_emit_system, particle_update_system).chain())
+            .add_systems(bevy_app::PostUpdate, particle_queue_system);
+    }
+
+    fn name(&self) -> &str {
+        "ParticlePlugin"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anvilkit_core::math::curve::CurveInterpolation;
 
     #[test]
     fn test_particle_vertex_size() {
         assert_eq!(std::mem::size_of::<ParticleVertex>(), 32);
+        assert_eq!(std::mem::size_of::<GpuParticle>(), 64);
+        assert_eq!(std::mem::size_of::<ParticleSimParams>(), 32 + 2 * 16 * CURVE_LUT_SIZE);
     }
 
     #[test]
@@ -621,4 +1235,99 @@ mod tests {
         sys.emit(Particle::new(Vec3::ONE, Vec3::ZERO, 1.0));
         assert_eq!(sys.alive_count(), 1);
     }
+
+    #[test]
+    fn test_lifetime_curves() {
+        let emitter = ParticleEmitter {
+            gravity: Vec3::ZERO,
+            start_color: [1.0, 0.0, 0.0, 1.0],
+            end_color: [0.0, 0.0, 1.0, 0.0],
+            size_over_life: Some(Curve::new(CurveInterpolation::Linear).with_key(0.0, 1.0).with_key(1.0, 0.0)),
+            speed_over_life: Some(Curve::new(CurveInterpolation::Step).with_key(0.0, 2.0).with_key(0.5, 0.0)),
+            ..Default::default()
+        };
+        assert_eq!(emitter.color_at(0.5), [0.5, 0.0, 0.5, 0.5]);
+
+        let mut p = Particle::new(Vec3::ZERO, Vec3::X, 1.0);
+        p.start_size = 0.4;
+        p.simulate(0.25, &emitter);
+        // 前半生速度翻倍，大小线性缩小
+        assert!((p.position.x - 0.5).abs() < 1e-6);
+        assert!((p.size - 0.3).abs() < 1e-6);
+        p.simulate(0.5, &emitter);
+        p.simulate(0.1, &emitter);
+        assert!((p.position.x - 1.5).abs() < 1e-6);
+
+        let colors = Curve::new(CurveInterpolation::Linear).with_key(0.0, Color::WHITE).with_key(1.0, Color::BLACK);
+        let emitter = ParticleEmitter { color_over_life: Some(colors), ..emitter };
+        let params = emitter.sim_params(0.1, 8);
+        assert_eq!(params.color_lut[0], [1.0; 4]);
+        assert_eq!(params.color_lut[CURVE_LUT_SIZE - 1], [0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(params.scalar_lut[0], [1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(params.scalar_lut[CURVE_LUT_SIZE - 1], [0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_emit_shapes() {
+        let mut rng = Rng::seed_from_u64(7);
+        for _ in 0..64 {
+            let (position, direction) = EmitShape::Sphere { radius: 2.0 }.sample(&mut rng);
+            assert!((position.length() - 2.0).abs() < 1e-4);
+            assert!(direction.dot(position) > 0.0);
+
+            let (position, direction) = EmitShape::Cone { angle: 0.3, radius: 0.5 }.sample(&mut rng);
+            assert!(position.y == 0.0 && position.length() <= 0.5);
+            assert!(direction.y >= 0.3f32.cos() - 1e-5);
+
+            let (position, _) = EmitShape::Box { half_extents: Vec3::new(1.0, 2.0, 3.0) }.sample(&mut rng);
+            assert!(position.abs().cmple(Vec3::new(1.0, 2.0, 3.0)).all());
+        }
+
+        let emitter = ParticleEmitter { speed_variance: 0.0, size_variance: 0.0, ..Default::default() };
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0).with_rotation(glam::Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        let particle = emitter.spawn(&transform, &mut rng);
+        assert_eq!(particle.position, Vec3::new(1.0, 2.0, 3.0));
+        // +Y 绕 Z 旋转 90° 后指向 -X
+        assert!(particle.velocity.abs_diff_eq(Vec3::new(-2.0, 0.0, 0.0), 1e-5));
+        assert_eq!(particle.start_size, 0.05);
+    }
+
+    #[test]
+    fn test_gpu_pool_ring_and_rebuild() {
+        let mut sys = ParticleSystem::new_gpu(3);
+        assert_eq!(sys.simulation(), ParticleSimulation::Gpu);
+        for i in 0..4 {
+            sys.emit(Particle::new(Vec3::splat(i as f32), Vec3::ZERO, 1.0));
+        }
+        let slots: Vec<u32> = sys.gpu_spawns().iter().map(|(slot, _)| *slot).collect();
+        assert_eq!(slots, vec![0, 1, 2, 0]);
+        assert_eq!(sys.alive_count(), 3);
+        assert_eq!(sys.alive_particles().count(), 0);
+
+        sys.update_with(1.5, &ParticleEmitter::default());
+        assert_eq!(sys.alive_count(), 0);
+        assert_eq!(sys.gpu_params().unwrap().count, 3);
+        sys.begin_frame();
+        assert!(sys.gpu_spawns().is_empty());
+
+        // 切换模拟方式时重建粒子池，删除发射器时移除粒子池
+        let mut world = World::new();
+        world.insert_resource(anvilkit_core::time::DeltaTime(0.5));
+        world.init_resource::<ParticleSystems>();
+        let emitter = world.spawn((ParticleEmitter { emit_rate: 4.0, ..Default::default() }, Transform::default())).id();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((particle_emit_system, particle_update_system).chain());
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ParticleSystems>().systems[&emitter].simulation(), ParticleSimulation::Cpu);
+
+        world.get_mut::<ParticleEmitter>(emitter).unwrap().simulation = ParticleSimulation::Gpu;
+        schedule.run(&mut world);
+        let pool = &world.resource::<ParticleSystems>().systems[&emitter];
+        assert_eq!(pool.simulation(), ParticleSimulation::Gpu);
+        assert_eq!(pool.gpu_spawns().len(), 2);
+
+        world.despawn(emitter);
+        schedule.run(&mut world);
+        assert!(world.resource::<ParticleSystems>().systems.is_empty());
+    }
 }
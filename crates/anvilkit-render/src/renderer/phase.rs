@@ -0,0 +1,345 @@
+//! # 自定义渲染阶段
+//!
+//! 内置的阴影、场景、调试线、后处理与色调映射 pass 顺序固定；插件可以通过
+//! [`RenderPhaseAppExt::add_render_phase`] 在固定的插入点（[`PhaseSlot`]）注册自己的阶段，
+//! 例如描边（OutlinePhase）或热扭曲（HeatDistortion）。
+//!
+//! 每个阶段由三部分组成：
+//!
+//! - 阶段项类型 `I: PhaseItem`，自带排序键类型 [`PhaseItem::SortKey`]
+//! - 队列资源 [`RenderPhase<I>`]：系统在每帧通过 `ResMut<RenderPhase<I>>` 加入阶段项，
+//!   帧开始时（`First`）清空，帧末（`Last`）按排序键排序
+//! - 绘制函数：渲染循环到达对应插入点时以 [`PhaseRenderContext`] 调用
+//!
+//! 同一插入点的多个阶段按 `priority` 升序执行，相同优先级按注册顺序执行。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::phase::*;
+//!
+//! struct OutlineItem {
+//!     entity: Entity,
+//!     depth: u32,
+//! }
+//!
+//! impl PhaseItem for OutlineItem {
+//!     type SortKey = u32;
+//!     fn sort_key(&self) -> u32 {
+//!         self.depth
+//!     }
+//! }
+//!
+//! fn queue_outlines(mut phase: ResMut<RenderPhase<OutlineItem>>, query: Query<Entity, With<Name>>) {
+//!     for entity in &query {
+//!         phase.add(OutlineItem { entity, depth: 0 });
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_render_phase::<OutlineItem>(
+//!     RenderPhaseDescriptor::new("OutlinePhase", PhaseSlot::AfterOpaque),
+//!     |_world, items, _ctx| {
+//!         // 使用 ctx.encoder / ctx.color_target 绘制 items
+//!         let _ = items.len();
+//!     },
+//! );
+//! app.add_systems(bevy_app::Update, queue_outlines);
+//! ```
+
+use std::marker::PhantomData;
+
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+use glam::Mat4;
+
+use crate::renderer::RenderDevice;
+
+/// 渲染阶段项
+pub trait PhaseItem: Send + Sync + 'static {
+    /// 排序键类型
+    type SortKey: Ord;
+
+    /// 该项的排序键（升序绘制）
+    fn sort_key(&self) -> Self::SortKey;
+}
+
+/// 阶段在帧内的插入点
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PhaseSlot {
+    /// 主场景 pass 之后：HDR MSAA 目标 + 深度缓冲，可深度测试
+    AfterOpaque,
+    /// 调试线之后、后处理之前：目标同 `AfterOpaque`
+    BeforePostProcess,
+    /// 色调映射之后：交换链（LDR），无深度缓冲，适合屏幕空间叠加层
+    AfterTonemap,
+}
+
+/// 阶段注册参数
+#[derive(Debug, Clone)]
+pub struct RenderPhaseDescriptor {
+    /// 阶段名称（调试与查询用）
+    pub name: &'static str,
+    /// 插入点
+    pub slot: PhaseSlot,
+    /// 同一插入点内的执行顺序（升序）
+    pub priority: i32,
+}
+
+impl RenderPhaseDescriptor {
+    /// 以默认优先级 0 创建
+    pub fn new(name: &'static str, slot: PhaseSlot) -> Self {
+        Self { name, slot, priority: 0 }
+    }
+
+    /// 设置优先级
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// 阶段项队列
+#[derive(Resource)]
+pub struct RenderPhase<I: PhaseItem> {
+    items: Vec<I>,
+}
+
+impl<I: PhaseItem> Default for RenderPhase<I> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<I: PhaseItem> RenderPhase<I> {
+    /// 加入一个阶段项
+    pub fn add(&mut self, item: I) {
+        self.items.push(item);
+    }
+
+    /// 按排序键稳定排序
+    pub fn sort(&mut self) {
+        self.items.sort_by_cached_key(|item| item.sort_key());
+    }
+
+    /// 清空队列
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// 当前阶段项
+    pub fn items(&self) -> &[I] {
+        &self.items
+    }
+
+    /// 阶段项数量
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// 队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// 阶段绘制上下文
+pub struct PhaseRenderContext<'a> {
+    /// 渲染设备
+    pub device: &'a RenderDevice,
+    /// 本帧共享的命令编码器
+    pub encoder: &'a mut wgpu::CommandEncoder,
+    /// 颜色目标
+    pub color_target: &'a wgpu::TextureView,
+    /// MSAA 解析目标（颜色目标为多重采样时）
+    pub resolve_target: Option<&'a wgpu::TextureView>,
+    /// 深度缓冲（`AfterTonemap` 为 `None`）
+    pub depth_target: Option<&'a wgpu::TextureView>,
+    /// 颜色目标格式（管线创建需匹配）
+    pub color_format: wgpu::TextureFormat,
+    /// 颜色目标采样数（管线创建需匹配）
+    pub sample_count: u32,
+    /// 活动相机的 view-projection 矩阵
+    pub view_proj: Mat4,
+}
+
+/// 阶段绘制函数
+pub type PhaseDrawFn<I> = dyn Fn(&World, &[I], &mut PhaseRenderContext) + Send + Sync;
+
+trait ErasedPhase: Send + Sync {
+    fn render(&self, world: &World, ctx: &mut PhaseRenderContext);
+    fn item_count(&self, world: &World) -> usize;
+}
+
+struct TypedPhase<I: PhaseItem> {
+    draw: Box<PhaseDrawFn<I>>,
+    _marker: PhantomData<fn() -> I>,
+}
+
+impl<I: PhaseItem> ErasedPhase for TypedPhase<I> {
+    fn render(&self, world: &World, ctx: &mut PhaseRenderContext) {
+        if let Some(phase) = world.get_resource::<RenderPhase<I>>() {
+            if !phase.is_empty() {
+                (self.draw)(world, phase.items(), ctx);
+            }
+        }
+    }
+
+    fn item_count(&self, world: &World) -> usize {
+        world.get_resource::<RenderPhase<I>>().map_or(0, RenderPhase::len)
+    }
+}
+
+struct PhaseEntry {
+    descriptor: RenderPhaseDescriptor,
+    phase: Box<dyn ErasedPhase>,
+}
+
+/// 已注册的自定义渲染阶段
+#[derive(Resource, Default)]
+pub struct RenderPhases {
+    entries: Vec<PhaseEntry>,
+}
+
+impl RenderPhases {
+    fn insert(&mut self, descriptor: RenderPhaseDescriptor, phase: Box<dyn ErasedPhase>) {
+        // 按 (slot, priority) 插入到同键阶段之后，保持注册顺序稳定
+        let key = (descriptor.slot, descriptor.priority);
+        let index = self
+            .entries
+            .partition_point(|e| (e.descriptor.slot, e.descriptor.priority) <= key);
+        self.entries.insert(index, PhaseEntry { descriptor, phase });
+    }
+
+    /// 插入点上的阶段描述（按执行顺序）
+    pub fn descriptors(&self, slot: PhaseSlot) -> impl Iterator<Item = &RenderPhaseDescriptor> {
+        self.entries.iter().map(|e| &e.descriptor).filter(move |d| d.slot == slot)
+    }
+
+    /// 插入点上是否有待绘制的阶段项
+    pub fn has_work(&self, world: &World, slot: PhaseSlot) -> bool {
+        self.entries
+            .iter()
+            .any(|e| e.descriptor.slot == slot && e.phase.item_count(world) > 0)
+    }
+
+    /// 执行插入点上的全部阶段（由渲染循环调用）
+    pub fn render_slot(&self, world: &World, slot: PhaseSlot, ctx: &mut PhaseRenderContext) {
+        for entry in self.entries.iter().filter(|e| e.descriptor.slot == slot) {
+            let _span = tracing::info_span!("render_phase", name = entry.descriptor.name).entered();
+            entry.phase.render(world, ctx);
+        }
+    }
+
+    /// 已注册阶段数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否没有注册任何阶段
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// 帧开始时清空阶段队列
+pub fn clear_render_phase<I: PhaseItem>(mut phase: ResMut<RenderPhase<I>>) {
+    phase.clear();
+}
+
+/// 帧末（`Last`）排序阶段队列
+pub fn sort_render_phase<I: PhaseItem>(mut phase: ResMut<RenderPhase<I>>) {
+    phase.sort();
+}
+
+/// 自定义渲染阶段注册扩展
+pub trait RenderPhaseAppExt {
+    /// 注册渲染阶段
+    ///
+    /// 初始化 [`RenderPhase<I>`] 队列资源，添加清空（`First`）与排序（`Last`）系统，
+    /// 并在 [`RenderPhases`] 中登记绘制函数。
+    fn add_render_phase<I: PhaseItem>(
+        &mut self,
+        descriptor: RenderPhaseDescriptor,
+        draw: impl Fn(&World, &[I], &mut PhaseRenderContext) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl RenderPhaseAppExt for App {
+    fn add_render_phase<I: PhaseItem>(
+        &mut self,
+        descriptor: RenderPhaseDescriptor,
+        draw: impl Fn(&World, &[I], &mut PhaseRenderContext) + Send + Sync + 'static,
+    ) -> &mut Self {
+        // 同一阶段项类型可被多个阶段共享，队列与维护系统只注册一次
+        if !self.world().contains_resource::<RenderPhase<I>>() {
+            self.init_resource::<RenderPhase<I>>();
+            self.add_systems(bevy_app::First, clear_render_phase::<I>);
+            self.add_systems(bevy_app::Last, sort_render_phase::<I>);
+        }
+        self.init_resource::<RenderPhases>();
+        self.world_mut().resource_mut::<RenderPhases>().insert(
+            descriptor,
+            Box::new(TypedPhase::<I> { draw: Box::new(draw), _marker: PhantomData }),
+        );
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Item(i32);
+
+    impl PhaseItem for Item {
+        type SortKey = std::cmp::Reverse<i32>;
+        fn sort_key(&self) -> Self::SortKey {
+            std::cmp::Reverse(self.0)
+        }
+    }
+
+    struct Other;
+
+    impl PhaseItem for Other {
+        type SortKey = ();
+        fn sort_key(&self) {}
+    }
+
+    #[test]
+    fn test_phase_queue_cleared_and_sorted_each_frame() {
+        let mut app = App::new();
+        app.add_render_phase::<Item>(RenderPhaseDescriptor::new("Test", PhaseSlot::AfterOpaque), |_, _, _| {});
+        app.add_systems(bevy_app::Update, |mut phase: ResMut<RenderPhase<Item>>| {
+            phase.add(Item(1));
+            phase.add(Item(3));
+            phase.add(Item(2));
+        });
+
+        app.update();
+        let keys: Vec<i32> = app.world().resource::<RenderPhase<Item>>().items().iter().map(|i| i.0).collect();
+        assert_eq!(keys, [3, 2, 1]);
+
+        // 下一帧开始时清空，不累积
+        app.update();
+        assert_eq!(app.world().resource::<RenderPhase<Item>>().len(), 3);
+    }
+
+    #[test]
+    fn test_phase_order_within_slots() {
+        let mut app = App::new();
+        app.add_render_phase::<Item>(
+            RenderPhaseDescriptor::new("Late", PhaseSlot::AfterOpaque).with_priority(10),
+            |_, _, _| {},
+        );
+        app.add_render_phase::<Other>(RenderPhaseDescriptor::new("Overlay", PhaseSlot::AfterTonemap), |_, _, _| {});
+        app.add_render_phase::<Other>(RenderPhaseDescriptor::new("Early", PhaseSlot::AfterOpaque), |_, _, _| {});
+
+        let phases = app.world().resource::<RenderPhases>();
+        assert_eq!(phases.len(), 3);
+        let names: Vec<_> = phases.descriptors(PhaseSlot::AfterOpaque).map(|d| d.name).collect();
+        assert_eq!(names, ["Early", "Late"]);
+        assert!(!phases.has_work(app.world(), PhaseSlot::AfterTonemap));
+    }
+}
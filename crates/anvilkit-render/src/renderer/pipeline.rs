@@ -6,13 +6,15 @@ use wgpu::{
     RenderPipeline, RenderPipelineDescriptor, VertexState, FragmentState,
     PrimitiveState, MultisampleState, PipelineLayoutDescriptor,
     ShaderModule, ShaderModuleDescriptor, ShaderSource,
-    ColorTargetState, BlendState, ColorWrites,
+    ColorTargetState, ColorWrites,
     PrimitiveTopology, FrontFace, PolygonMode,
     TextureFormat, Device,
 };
 use log::{info, debug};
 
 use crate::renderer::RenderDevice;
+use crate::renderer::assets::{BlendMode, CullMode};
+use crate::renderer::buffer::Vertex;
 use anvilkit_core::error::{AnvilKitError, Result};
 
 /// 渲染管线构建器
@@ -60,6 +62,69 @@ pub struct RenderPipelineBuilder {
     depth_format: Option<TextureFormat>,
     /// Bind group 布局
     bind_group_layouts: Vec<wgpu::BindGroupLayout>,
+    /// 混合 / 剔除 / 填充 / 深度偏移状态
+    raster: RasterState,
+}
+
+/// 管线的光栅化与混合状态
+///
+/// 默认值与旧版固定行为一致：不透明替换、无剔除、实心填充、无深度偏移。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::renderer::pipeline::RasterState;
+/// use anvilkit_render::renderer::assets::BlendMode;
+///
+/// let state = RasterState { blend_mode: BlendMode::AlphaBlend, ..Default::default() };
+/// assert!(!state.depth_write());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RasterState {
+    /// 颜色混合模式；透明模式不写入深度
+    pub blend_mode: BlendMode,
+    /// 面剔除
+    pub cull_mode: CullMode,
+    /// 多边形填充模式（`Line` / `Point` 需要设备启用对应特性）
+    pub polygon_mode: PolygonMode,
+    /// 深度偏移（常量 + 斜率缩放），用于贴花与阴影
+    pub depth_bias: wgpu::DepthBiasState,
+}
+
+impl RasterState {
+    /// 是否写入深度
+    pub fn depth_write(&self) -> bool {
+        self.blend_mode.writes_depth()
+    }
+
+    /// 检查填充模式所需的设备特性
+    fn validate(&self, device: &RenderDevice) -> Result<()> {
+        let required = match self.polygon_mode {
+            PolygonMode::Fill => return Ok(()),
+            PolygonMode::Line => wgpu::Features::POLYGON_MODE_LINE,
+            PolygonMode::Point => wgpu::Features::POLYGON_MODE_POINT,
+        };
+        if device.features().contains(required) {
+            Ok(())
+        } else {
+            Err(AnvilKitError::render(format!(
+                "{:?} 填充模式需要设备特性 {:?}（通过 RenderSettings::with_required_features 启用）",
+                self.polygon_mode, required,
+            )))
+        }
+    }
+
+    fn primitive(&self, topology: PrimitiveTopology) -> PrimitiveState {
+        PrimitiveState {
+            topology,
+            strip_index_format: None,
+            front_face: FrontFace::Ccw,
+            cull_mode: self.cull_mode.face(),
+            unclipped_depth: false,
+            polygon_mode: self.polygon_mode,
+            conservative: false,
+        }
+    }
 }
 
 impl Default for RenderPipelineBuilder {
@@ -89,6 +154,7 @@ impl RenderPipelineBuilder {
             vertex_layouts: Vec::new(),
             depth_format: None,
             bind_group_layouts: Vec::new(),
+            raster: RasterState::default(),
         }
     }
     
@@ -227,6 +293,26 @@ impl RenderPipelineBuilder {
         self
     }
 
+    /// 追加顶点类型 `V` 的缓冲区布局
+    ///
+    /// 每次调用占用下一个顶点缓冲区槽位（第一次为 slot 0），
+    /// 绘制时按相同顺序调用 `set_vertex_buffer`。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_render::renderer::RenderPipelineBuilder;
+    /// use anvilkit_render::renderer::buffer::{PbrVertex, AttributeVertex};
+    ///
+    /// let builder = RenderPipelineBuilder::new()
+    ///     .with_vertex_layout::<PbrVertex>()
+    ///     .with_vertex_layout::<AttributeVertex>();
+    /// ```
+    pub fn with_vertex_layout<V: Vertex>(mut self) -> Self {
+        self.vertex_layouts.push(V::layout());
+        self
+    }
+
     /// 设置深度纹理格式，启用深度测试
     ///
     /// # 参数
@@ -247,6 +333,56 @@ impl RenderPipelineBuilder {
         self
     }
 
+    /// 设置颜色混合模式
+    ///
+    /// 透明模式（Alpha / 加法 / 预乘）只做深度测试、不写入深度。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_render::renderer::RenderPipelineBuilder;
+    /// use anvilkit_render::renderer::assets::BlendMode;
+    ///
+    /// let builder = RenderPipelineBuilder::new()
+    ///     .with_blend_mode(BlendMode::AlphaBlend);
+    /// ```
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        self.raster.blend_mode = blend_mode;
+        self
+    }
+
+    /// 设置面剔除模式
+    pub fn with_cull_mode(mut self, cull_mode: CullMode) -> Self {
+        self.raster.cull_mode = cull_mode;
+        self
+    }
+
+    /// 设置多边形填充模式
+    ///
+    /// `PolygonMode::Line`（线框）需要设备启用 `Features::POLYGON_MODE_LINE`，
+    /// 否则 [`build`](Self::build) 返回错误。
+    pub fn with_polygon_mode(mut self, polygon_mode: PolygonMode) -> Self {
+        self.raster.polygon_mode = polygon_mode;
+        self
+    }
+
+    /// 设置深度偏移
+    ///
+    /// # 参数
+    ///
+    /// - `constant`: 常量偏移（深度格式的最小可分辨单位）
+    /// - `slope_scale`: 按多边形深度斜率缩放的偏移
+    pub fn with_depth_bias(mut self, constant: i32, slope_scale: f32) -> Self {
+        self.raster.depth_bias = wgpu::DepthBiasState { constant, slope_scale, clamp: 0.0 };
+        self
+    }
+
+    /// 一次设置全部光栅化与混合状态
+    pub fn with_raster_state(mut self, raster: RasterState) -> Self {
+        self.raster = raster;
+        self
+    }
+
     /// 构建渲染管线
     /// 
     /// # 参数
@@ -280,6 +416,8 @@ impl RenderPipelineBuilder {
         let depth_format = self.depth_format
             .ok_or_else(|| AnvilKitError::render("深度-only 管线需要深度格式".to_string()))?;
 
+        self.raster.validate(device)?;
+
         let bind_group_layout_refs: Vec<&wgpu::BindGroupLayout> =
             self.bind_group_layouts.iter().collect();
 
@@ -303,21 +441,13 @@ impl RenderPipelineBuilder {
                 entry_point: "vs_main",
                 buffers: &self.vertex_layouts,
             },
-            primitive: PrimitiveState {
-                topology: self.topology,
-                strip_index_format: None,
-                front_face: FrontFace::Ccw,
-                cull_mode: None, // disabled for glTF compatibility
-                unclipped_depth: false,
-                polygon_mode: PolygonMode::Fill,
-                conservative: false,
-            },
+            primitive: self.raster.primitive(self.topology),
             depth_stencil: Some(wgpu::DepthStencilState {
                 format: depth_format,
                 depth_write_enabled: true,
                 depth_compare: wgpu::CompareFunction::Less,
                 stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
+                bias: self.raster.depth_bias,
             }),
             multisample: MultisampleState {
                 count: 1,
@@ -353,24 +483,40 @@ impl RenderPipelineBuilder {
         let format = self.format
             .ok_or_else(|| AnvilKitError::render("缺少渲染目标格式".to_string()))?;
         
+        self.raster.validate(device)?;
+
         let bind_group_layout_refs: Vec<&wgpu::BindGroupLayout> =
             self.bind_group_layouts.iter().collect();
 
-        BasicRenderPipeline::new(
-            device,
-            &vertex_shader,
-            &fragment_shader,
+        BasicRenderPipeline::create(device, PipelineParams {
+            vertex_source: &vertex_shader,
+            fragment_source: &fragment_shader,
             format,
-            self.topology,
-            self.multisample_count,
-            self.label.as_deref(),
-            &self.vertex_layouts,
-            self.depth_format,
-            &bind_group_layout_refs,
-        )
+            topology: self.topology,
+            multisample_count: self.multisample_count,
+            label: self.label.as_deref(),
+            vertex_layouts: &self.vertex_layouts,
+            depth_format: self.depth_format,
+            bind_group_layouts: &bind_group_layout_refs,
+            raster: &self.raster,
+        })
     }
 }
 
+/// 创建 [`BasicRenderPipeline`] 所需的参数
+struct PipelineParams<'a> {
+    vertex_source: &'a str,
+    fragment_source: &'a str,
+    format: TextureFormat,
+    topology: PrimitiveTopology,
+    multisample_count: u32,
+    label: Option<&'a str>,
+    vertex_layouts: &'a [wgpu::VertexBufferLayout<'a>],
+    depth_format: Option<TextureFormat>,
+    bind_group_layouts: &'a [&'a wgpu::BindGroupLayout],
+    raster: &'a RasterState,
+}
+
 /// 基础渲染管线
 /// 
 /// 封装 wgpu 渲染管线，提供基础的渲染功能。
@@ -434,6 +580,34 @@ impl BasicRenderPipeline {
         depth_format: Option<TextureFormat>,
         bind_group_layouts: &[&wgpu::BindGroupLayout],
     ) -> Result<Self> {
+        Self::create(device, PipelineParams {
+            vertex_source,
+            fragment_source,
+            format,
+            topology,
+            multisample_count,
+            label,
+            vertex_layouts,
+            depth_format,
+            bind_group_layouts,
+            raster: &RasterState::default(),
+        })
+    }
+
+    /// 按参数创建渲染管线（[`new`](Self::new) 与 [`RenderPipelineBuilder::build`] 共用）
+    fn create(device: &RenderDevice, params: PipelineParams<'_>) -> Result<Self> {
+        let PipelineParams {
+            vertex_source,
+            fragment_source,
+            format,
+            topology,
+            multisample_count,
+            label,
+            vertex_layouts,
+            depth_format,
+            bind_group_layouts,
+            raster,
+        } = params;
         info!("创建基础渲染管线: {:?}", label);
         
         let wgpu_device = device.device();
@@ -467,21 +641,13 @@ impl BasicRenderPipeline {
                 entry_point: "vs_main",
                 buffers: vertex_layouts,
             },
-            primitive: PrimitiveState {
-                topology,
-                strip_index_format: None,
-                front_face: FrontFace::Ccw,
-                cull_mode: None, // disabled for glTF compatibility
-                unclipped_depth: false,
-                polygon_mode: PolygonMode::Fill,
-                conservative: false,
-            },
+            primitive: raster.primitive(topology),
             depth_stencil: depth_format.map(|format| wgpu::DepthStencilState {
                 format,
-                depth_write_enabled: true,
+                depth_write_enabled: raster.depth_write(),
                 depth_compare: wgpu::CompareFunction::Less,
                 stencil: wgpu::StencilState::default(),
-                bias: wgpu::DepthBiasState::default(),
+                bias: raster.depth_bias,
             }),
             multisample: MultisampleState {
                 count: multisample_count,
@@ -493,7 +659,7 @@ impl BasicRenderPipeline {
                 entry_point: "fs_main",
                 targets: &[Some(ColorTargetState {
                     format,
-                    blend: Some(BlendState::REPLACE),
+                    blend: Some(raster.blend_mode.blend_state()),
                     write_mask: ColorWrites::ALL,
                 })],
             }),
@@ -659,6 +825,39 @@ mod tests {
         assert_eq!(builder.multisample_count, 4);
     }
 
+    #[test]
+    fn test_pipeline_builder_raster_state() {
+        let builder = RenderPipelineBuilder::new();
+        assert_eq!(builder.raster, RasterState::default());
+        assert_eq!(builder.raster.primitive(PrimitiveTopology::TriangleList).cull_mode, None);
+        assert!(builder.raster.depth_write());
+
+        let builder = builder
+            .with_blend_mode(BlendMode::Premultiplied)
+            .with_cull_mode(CullMode::Back)
+            .with_polygon_mode(PolygonMode::Line)
+            .with_depth_bias(2, 1.5);
+        let primitive = builder.raster.primitive(PrimitiveTopology::TriangleList);
+        assert_eq!(primitive.cull_mode, Some(wgpu::Face::Back));
+        assert_eq!(primitive.polygon_mode, PolygonMode::Line);
+        assert_eq!(builder.raster.depth_bias.constant, 2);
+        assert_eq!(builder.raster.depth_bias.slope_scale, 1.5);
+        assert!(!builder.raster.depth_write());
+        assert_eq!(builder.raster.blend_mode.blend_state(), wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING);
+    }
+
+    #[test]
+    fn test_pipeline_builder_vertex_layout_slots() {
+        use crate::renderer::buffer::{AttributeVertex, PbrVertex};
+
+        let builder = RenderPipelineBuilder::new()
+            .with_vertex_layout::<PbrVertex>()
+            .with_vertex_layout::<AttributeVertex>();
+        assert_eq!(builder.vertex_layouts.len(), 2);
+        assert_eq!(builder.vertex_layouts[0].array_stride, 48);
+        assert_eq!(builder.vertex_layouts[1].attributes[0].shader_location, 4);
+    }
+
     #[test]
     fn test_pipeline_builder_chaining() {
         let builder = RenderPipelineBuilder::new()
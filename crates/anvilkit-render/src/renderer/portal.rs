@@ -0,0 +1,863 @@
+//! # 传送门 / 画中画渲染
+//!
+//! [`Portal`] 组件把实体局部 XY 平面上的门面四边形变成一扇「窗」：门内显示从出口
+//! （链接的出口传送门，或 [`Portal::target_transform`]）观察到的场景，并可递归显示
+//! 门内再次看到的同一扇门，深度由 [`Portal::max_recursion`] 限制。
+//!
+//! 每层门内视图按以下步骤绘制到离屏 HDR 目标：
+//!
+//! 1. **遮罩**：深度清为 0，再把上一层看到的门面写到远平面深度。场景管线使用 `Less`
+//!    深度测试，因此只有门面覆盖的像素能通过——等价于模板遮罩，且不要求场景管线带模板状态
+//! 2. **场景**：以虚拟相机与斜近裁剪面投影（[`oblique_projection`]）重绘场景，
+//!    出口门面与虚拟相机之间的物体被裁掉
+//! 3. **合成**：把更深一层的结果贴到本层的门面上
+//!
+//! 最后把第一层合成到主 HDR 目标（`PhaseSlot::AfterOpaque`）。
+//! 门内视图不采样级联阴影；门面约定朝向局部 -Z（观察者站在 -Z 一侧）。
+//!
+//! ## 使用示例
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::portal::{Portal, PortalPlugin};
+//!
+//! let mut app = App::new();
+//! app.add_plugins(PortalPlugin);
+//!
+//! let exit = app.world_mut().spawn(Transform::from_xyz(10.0, 1.0, 0.0)).id();
+//! app.world_mut().spawn((
+//!     Transform::from_xyz(0.0, 1.0, 0.0),
+//!     Portal { linked_portal: Some(exit), ..Default::default() },
+//! ));
+//! ```
+
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemParam;
+use glam::{Mat4, Vec2, Vec3, Vec4};
+
+use anvilkit_core::math::{GlobalTransform, Transform};
+
+use crate::component::{ComputedVisibility, RenderLayers};
+use crate::plugin::{CameraComponent, ClearColor};
+use crate::renderer::assets::{Handle, MaterialHandle, MeshHandle, RenderAssets};
+use crate::renderer::buffer::{
+    create_depth_texture_msaa, create_hdr_msaa_texture, create_hdr_render_target, DEPTH_FORMAT, HDR_FORMAT,
+    MSAA_SAMPLE_COUNT,
+};
+use crate::renderer::draw::{
+    ActiveCamera, Aabb, DrawCommand, DrawCommandList, Frustum, MaterialParams, SceneLights, UniformBatchBuffer,
+};
+use crate::renderer::material::{Material, Materials};
+use crate::renderer::phase::{
+    PhaseItem, PhaseRenderContext, PhaseSlot, RenderPhase, RenderPhaseAppExt, RenderPhaseDescriptor,
+};
+use crate::renderer::standard_material::{DefaultMaterialHandle, StandardMaterial};
+use crate::renderer::skybox::{environment_params, Skybox};
+use crate::renderer::state::{PbrSceneUniform, RenderState};
+use crate::renderer::RenderDevice;
+use crate::window::pack_lights;
+
+const PORTAL_SHADER: &str = include_str!("../shaders/portal.wgsl");
+
+/// 递归层数上限（每层都要重绘一次场景）
+pub const MAX_PORTAL_RECURSION: u32 = 4;
+
+const UNIFORM_ALIGNMENT: u64 = 256;
+
+/// 传送门组件
+///
+/// 门面是实体局部 XY 平面上以原点为中心、`size` 大小的四边形，正面朝向局部 -Z。
+/// 门内看到的是出口正面（-Z 一侧）的场景，如同从出口背后向外看。
+#[derive(Component, Debug, Clone)]
+pub struct Portal {
+    /// 出口变换（未设置 `linked_portal` 时使用），画中画 / 监控屏幕可直接指定
+    pub target_transform: Transform,
+    /// 链接的出口传送门实体；设置时使用其 `GlobalTransform` 作为出口
+    pub linked_portal: Option<Entity>,
+    /// 门面尺寸（宽 × 高）
+    pub size: Vec2,
+    /// 门内递归层数（1 = 只显示一层门内视图），最大 [`MAX_PORTAL_RECURSION`]
+    pub max_recursion: u32,
+}
+
+impl Default for Portal {
+    fn default() -> Self {
+        Self {
+            target_transform: Transform::default(),
+            linked_portal: None,
+            size: Vec2::new(2.0, 3.0),
+            max_recursion: 1,
+        }
+    }
+}
+
+/// 从入口到出口的空间变换：`view_main * T^k` 即第 k 层虚拟相机的视图矩阵
+///
+/// 入口局部空间绕 Y 轴翻转 180° 后对齐出口局部空间，站在入口正面的观察者
+/// 被映射到出口背面、朝出口正面方向看。
+pub fn portal_transfer(portal: &Mat4, exit: &Mat4) -> Mat4 {
+    *portal * Mat4::from_rotation_y(std::f32::consts::PI) * exit.inverse()
+}
+
+/// 斜近裁剪面投影（Lengyel）：把投影矩阵的近平面替换为视图空间平面 `clip_plane`
+///
+/// `clip_plane = (n, d)` 中满足 `dot(n, p) + d > 0` 的一侧保留。适用于 wgpu 的 `[0, 1]`
+/// 深度范围；相机不在被裁掉的一侧（`d >= 0`）时原样返回 `proj`。
+pub fn oblique_projection(proj: &Mat4, clip_plane: Vec4) -> Mat4 {
+    if clip_plane.w >= 0.0 {
+        return *proj;
+    }
+    // 与裁剪面相对的视锥体远角
+    let corner = proj.inverse() * Vec4::new(clip_plane.x.signum(), clip_plane.y.signum(), 1.0, 1.0);
+    let denom = clip_plane.dot(corner);
+    if denom.abs() < 1e-6 {
+        return *proj;
+    }
+    let depth_row = clip_plane * (proj.row(3).dot(corner) / denom);
+    let mut rows = proj.transpose();
+    rows.z_axis = depth_row;
+    rows.transpose()
+}
+
+/// 门内的一层视图
+#[derive(Debug, Clone)]
+pub struct PortalLevel {
+    /// 虚拟相机的 view-projection（已应用斜近裁剪面）
+    pub view_proj: Mat4,
+    /// 虚拟相机的世界空间位置
+    pub camera_pos: Vec3,
+    /// 上一层（看到本层门面的那一层）的 view-projection，用于遮罩与合成
+    pub parent_view_proj: Mat4,
+    /// 本层可见的绘制命令
+    pub draws: DrawCommandList,
+}
+
+/// 一扇可见传送门的全部门内视图
+#[derive(Debug, Clone)]
+pub struct PortalView {
+    /// 传送门实体
+    pub entity: Entity,
+    /// 门面四边形的世界矩阵（单位四边形 → 世界）
+    pub quad_model: Mat4,
+    /// 由外到内的各层视图，`levels[0]` 直接合成到主画面
+    pub levels: Vec<PortalLevel>,
+}
+
+/// 本帧可见的传送门视图（由 [`portal_view_system`] 写入）
+#[derive(Resource, Debug, Default)]
+pub struct PortalViews {
+    /// Visible portals in spawn order.
+    pub views: Vec<PortalView>,
+}
+
+/// 候选绘制：世界空间包围盒（无 `Aabb` 时为 `None`，总是绘制）+ 绘制命令
+type PortalCandidate = (Option<Aabb>, DrawCommand);
+
+fn portal_draw(mesh: MeshHandle, material: MaterialHandle, model: Mat4, params: (f32, f32, f32, [f32; 3], [f32; 4])) -> DrawCommand {
+    let (metallic, roughness, normal_scale, emissive_factor, base_color) = params;
+    DrawCommand {
+        mesh,
+        material,
+        model_matrix: model,
+        metallic,
+        roughness,
+        normal_scale,
+        emissive_factor,
+        base_color,
+        camera: 0,
+        sort_key: (0, 0),
+    }
+}
+
+/// 直接引用 GPU 材质句柄的网格实体
+type PortalMeshQuery = (
+    &'static MeshHandle,
+    &'static MaterialHandle,
+    &'static GlobalTransform,
+    Option<&'static MaterialParams>,
+    Option<&'static Aabb>,
+    Option<&'static RenderLayers>,
+    Option<&'static ComputedVisibility>,
+);
+
+/// 使用 [`StandardMaterial`] 或 [`Material`] 资产（`M`）的网格实体
+type PortalMaterialMeshQuery<M> = (
+    &'static MeshHandle,
+    &'static M,
+    &'static GlobalTransform,
+    Option<&'static Aabb>,
+    Option<&'static RenderLayers>,
+    Option<&'static ComputedVisibility>,
+);
+
+/// 材质资产网格：不与 `MaterialHandle` / `StandardMaterial` 路径重复收集
+type AssetMaterialFilter = (Without<MaterialHandle>, Without<StandardMaterial>);
+
+/// 门内视图可见的场景网格与材质
+#[derive(SystemParam)]
+pub struct PortalScene<'w, 's> {
+    meshes: Query<'w, 's, PortalMeshQuery>,
+    std_meshes: Query<'w, 's, PortalMaterialMeshQuery<StandardMaterial>, Without<MaterialHandle>>,
+    asset_meshes: Query<'w, 's, PortalMaterialMeshQuery<Handle<Material>>, AssetMaterialFilter>,
+    default_material: Option<Res<'w, DefaultMaterialHandle>>,
+    materials: Option<Res<'w, Materials>>,
+}
+
+/// 传送门视图系统 (PostUpdate, after camera_system)
+///
+/// 对活动相机正面可见的每扇传送门逐层计算虚拟相机，并以各层视锥体重新收集绘制命令
+/// （主相机的剔除结果不适用于门内视图）。远景替身不出现在门内。
+pub fn portal_view_system(
+    portals: Query<(Entity, &Portal, &GlobalTransform, Option<&ComputedVisibility>)>,
+    exits: Query<&GlobalTransform>,
+    cameras: Query<(&CameraComponent, &Transform)>,
+    scene: PortalScene,
+    active_camera: Res<ActiveCamera>,
+    mut portal_views: ResMut<PortalViews>,
+) {
+    let PortalScene { meshes, std_meshes, asset_meshes, default_material, materials } = scene;
+    portal_views.views.clear();
+    if portals.is_empty() {
+        return;
+    }
+    let Some((_, cam_transform)) = cameras.iter().find(|(c, _)| c.is_active) else {
+        return;
+    };
+
+    // 与 camera_system 相同的视图矩阵；投影由 ActiveCamera 反推，保证宽高比一致
+    let eye = cam_transform.translation;
+    let view = Mat4::look_at_lh(eye, eye + cam_transform.rotation * Vec3::Z, cam_transform.rotation * Vec3::Y);
+    let proj = active_camera.view_proj * view.inverse();
+    let main_frustum = Frustum::from_view_proj(&active_camera.view_proj);
+
+    let shown = |layers: Option<&RenderLayers>, computed: Option<&ComputedVisibility>| {
+        active_camera.render_layers.intersects(&layers.copied().unwrap_or_default())
+            && computed.is_none_or(|c| c.get())
+    };
+    let world_bounds = |gt: &GlobalTransform, aabb: Option<&Aabb>| aabb.map(|a| a.transformed(&gt.0));
+
+    let mut candidates: Vec<PortalCandidate> = Vec::new();
+    for (mesh, material, gt, params, aabb, layers, computed) in meshes.iter() {
+        if !shown(layers, computed) {
+            continue;
+        }
+        let p = params.cloned().unwrap_or_default();
+        let draw = portal_draw(*mesh, *material, gt.0, (p.metallic, p.roughness, p.normal_scale, p.emissive_factor, [1.0; 4]));
+        candidates.push((world_bounds(gt, aabb), draw));
+    }
+    if let Some(default_material) = &default_material {
+        for (mesh, m, gt, aabb, layers, computed) in std_meshes.iter() {
+            if !shown(layers, computed) {
+                continue;
+            }
+            let draw = portal_draw(*mesh, default_material.0, gt.0, (m.metallic, m.roughness, m.normal_scale, m.emissive_factor, m.base_color));
+            candidates.push((world_bounds(gt, aabb), draw));
+        }
+    }
+    if let Some(materials) = &materials {
+        for (mesh, handle, gt, aabb, layers, computed) in asset_meshes.iter() {
+            let (Some(m), Some(gpu)) = (materials.get(handle), materials.gpu_handle(handle)) else {
+                continue;
+            };
+            if !shown(layers, computed) {
+                continue;
+            }
+            let draw = portal_draw(*mesh, gpu, gt.0, (m.metallic, m.roughness, m.normal_scale, m.emissive_factor, m.base_color));
+            candidates.push((world_bounds(gt, aabb), draw));
+        }
+    }
+
+    for (entity, portal, portal_gt, computed) in portals.iter() {
+        if !computed.is_none_or(|c| c.get()) {
+            continue;
+        }
+        let exit = match portal.linked_portal {
+            Some(linked) => match exits.get(linked) {
+                Ok(gt) => gt.0,
+                Err(_) => continue,
+            },
+            None => portal.target_transform.compute_matrix(),
+        };
+
+        // 相机必须在门面正面（局部 -Z 一侧），且门面在主视锥体内
+        let local_eye = portal_gt.0.inverse().transform_point3(eye);
+        if local_eye.z >= 0.0 {
+            continue;
+        }
+        let quad_model = portal_gt.0 * Mat4::from_scale(Vec3::new(portal.size.x, portal.size.y, 1.0));
+        let half = Vec3::new(0.5, 0.5, 0.0);
+        let quad_bounds = Aabb::from_min_max(-half, half).transformed(&quad_model);
+        if !main_frustum.intersects_bounds(&quad_bounds) {
+            continue;
+        }
+
+        // 出口平面：保留出口正面（-Z 一侧）
+        let exit_normal = -exit.z_axis.truncate().normalize_or_zero();
+        let exit_origin = exit.w_axis.truncate();
+        let world_plane = exit_normal.extend(-exit_normal.dot(exit_origin));
+
+        let transfer = portal_transfer(&portal_gt.0, &exit);
+        let mut level_view = view;
+        let mut parent_view_proj = active_camera.view_proj;
+        let mut levels = Vec::new();
+        for _ in 0..portal.max_recursion.clamp(1, MAX_PORTAL_RECURSION) {
+            level_view *= transfer;
+            let camera_pos = level_view.inverse().transform_point3(Vec3::ZERO);
+            let view_plane = level_view.inverse().transpose() * world_plane;
+            let view_proj = oblique_projection(&proj, view_plane) * level_view;
+
+            let frustum = Frustum::from_view_proj(&(proj * level_view));
+            let mut draws = DrawCommandList::default();
+            for (bounds, draw) in &candidates {
+                if bounds.as_ref().is_none_or(|b| frustum.intersects_bounds(b)) {
+                    draws.push(draw.clone());
+                }
+            }
+            draws.sort_for_batching();
+
+            levels.push(PortalLevel { view_proj, camera_pos, parent_view_proj, draws });
+            parent_view_proj = view_proj;
+        }
+
+        portal_views.views.push(PortalView { entity, quad_model, levels });
+    }
+}
+
+/// 传送门阶段项（每扇可见传送门一个）
+pub struct PortalItem {
+    /// Index into [`PortalViews::views`].
+    pub view: usize,
+}
+
+impl PhaseItem for PortalItem {
+    type SortKey = usize;
+    fn sort_key(&self) -> usize {
+        self.view
+    }
+}
+
+/// 传送门排队系统 (PostUpdate, after portal_view_system)
+pub fn portal_queue_system(views: Res<PortalViews>, mut phase: ResMut<RenderPhase<PortalItem>>) {
+    for view in 0..views.views.len() {
+        phase.add(PortalItem { view });
+    }
+}
+
+/// 门面四边形 uniform（128 字节）
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct QuadUniform {
+    view_proj: [[f32; 4]; 4],
+    model: [[f32; 4]; 4],
+}
+
+/// 离屏层目标：两组交替使用的 HDR 目标 + 共享 MSAA 深度
+struct PortalTargets {
+    size: (u32, u32),
+    color_msaa: [wgpu::TextureView; 2],
+    resolved: [wgpu::TextureView; 2],
+    texture_bind_groups: [wgpu::BindGroup; 2],
+    depth: wgpu::TextureView,
+}
+
+/// 传送门 GPU 资源
+pub struct PortalRenderer {
+    uniform_stride: u64,
+    scene_uniform_buffer: wgpu::Buffer,
+    scene_bind_group: wgpu::BindGroup,
+    scene_capacity: u64,
+    quad_uniform_buffer: wgpu::Buffer,
+    quad_bind_group_layout: wgpu::BindGroupLayout,
+    quad_bind_group: wgpu::BindGroup,
+    quad_capacity: u64,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    mask_pipeline: wgpu::RenderPipeline,
+    composite_pipeline: wgpu::RenderPipeline,
+    targets: Option<PortalTargets>,
+}
+
+impl PortalRenderer {
+    /// 创建管线与初始 uniform 缓冲区
+    pub fn new(device: &RenderDevice, render_state: &RenderState) -> Self {
+        let raw = std::mem::size_of::<PbrSceneUniform>() as u64;
+        let uniform_stride = raw.div_ceil(UNIFORM_ALIGNMENT) * UNIFORM_ALIGNMENT;
+        let (scene_uniform_buffer, scene_bind_group) = Self::create_scene_uniforms(device, render_state, uniform_stride, 64);
+
+        let quad_bind_group_layout = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Portal Quad BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: wgpu::BufferSize::new(std::mem::size_of::<QuadUniform>() as u64),
+                },
+                count: None,
+            }],
+        });
+        let (quad_uniform_buffer, quad_bind_group) = Self::create_quad_uniforms(device, &quad_bind_group_layout, 16);
+
+        let texture_bind_group_layout = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Portal View BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Portal Shader"),
+            source: wgpu::ShaderSource::Wgsl(PORTAL_SHADER.into()),
+        });
+        let mask_layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Portal Mask Layout"),
+            bind_group_layouts: &[&quad_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let composite_layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Portal Composite Layout"),
+            bind_group_layouts: &[&quad_bind_group_layout, &texture_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = |label, layout: &wgpu::PipelineLayout, vs, fs, write_mask, depth_compare| {
+            device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(layout),
+                vertex: wgpu::VertexState { module: &shader, entry_point: vs, buffers: &[] },
+                primitive: wgpu::PrimitiveState { cull_mode: None, ..Default::default() },
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: true,
+                    depth_compare,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState { count: MSAA_SAMPLE_COUNT, ..Default::default() },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: fs,
+                    targets: &[Some(wgpu::ColorTargetState { format: HDR_FORMAT, blend: None, write_mask })],
+                }),
+                multiview: None,
+            })
+        };
+        let mask_pipeline = pipeline(
+            "Portal Mask Pipeline", &mask_layout, "vs_mask", "fs_mask",
+            wgpu::ColorWrites::empty(), wgpu::CompareFunction::Always,
+        );
+        let composite_pipeline = pipeline(
+            "Portal Composite Pipeline", &composite_layout, "vs_composite", "fs_composite",
+            wgpu::ColorWrites::ALL, wgpu::CompareFunction::Less,
+        );
+
+        Self {
+            uniform_stride,
+            scene_uniform_buffer,
+            scene_bind_group,
+            scene_capacity: 64,
+            quad_uniform_buffer,
+            quad_bind_group_layout,
+            quad_bind_group,
+            quad_capacity: 16,
+            texture_bind_group_layout,
+            mask_pipeline,
+            composite_pipeline,
+            targets: None,
+        }
+    }
+
+    fn create_scene_uniforms(
+        device: &RenderDevice,
+        render_state: &RenderState,
+        stride: u64,
+        capacity: u64,
+    ) -> (wgpu::Buffer, wgpu::BindGroup) {
+        let buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Portal Scene Uniforms"),
+            size: stride * capacity,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Portal Scene BG"),
+            layout: &render_state.scene_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(stride),
+                }),
+            }],
+        });
+        (buffer, bind_group)
+    }
+
+    fn create_quad_uniforms(
+        device: &RenderDevice,
+        layout: &wgpu::BindGroupLayout,
+        capacity: u64,
+    ) -> (wgpu::Buffer, wgpu::BindGroup) {
+        let buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Portal Quad Uniforms"),
+            size: UNIFORM_ALIGNMENT * capacity,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Portal Quad BG"),
+            layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(std::mem::size_of::<QuadUniform>() as u64),
+                }),
+            }],
+        });
+        (buffer, bind_group)
+    }
+
+    fn ensure_targets(&mut self, device: &RenderDevice, size: (u32, u32)) {
+        if self.targets.as_ref().is_some_and(|t| t.size == size) {
+            return;
+        }
+        let (w, h) = (size.0.max(1), size.1.max(1));
+        let level = |i: usize| {
+            let (_, msaa) = create_hdr_msaa_texture(device, w, h, &format!("Portal Level {i} MSAA"));
+            let (_, resolved) = create_hdr_render_target(device, w, h, &format!("Portal Level {i}"));
+            let bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Portal View BG"),
+                layout: &self.texture_bind_group_layout,
+                entries: &[wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&resolved) }],
+            });
+            (msaa, resolved, bind_group)
+        };
+        let (msaa0, resolved0, bg0) = level(0);
+        let (msaa1, resolved1, bg1) = level(1);
+        let (_, depth) = create_depth_texture_msaa(device, w, h, "Portal Depth MSAA");
+        self.targets = Some(PortalTargets {
+            size,
+            color_msaa: [msaa0, msaa1],
+            resolved: [resolved0, resolved1],
+            texture_bind_groups: [bg0, bg1],
+            depth,
+        });
+    }
+
+    /// 绘制全部门内视图并合成到阶段的颜色目标
+    ///
+    /// 从 `world` 读取 [`PortalViews`]、[`RenderState`]、[`RenderAssets`]、[`SceneLights`]、
+    /// [`ClearColor`] 与 [`Skybox`]；阶段没有深度目标时不绘制。
+    pub fn render(&mut self, world: &World, ctx: &mut PhaseRenderContext) {
+        let (Some(views), Some(render_state), Some(render_assets), Some(depth)) = (
+            world.get_resource::<PortalViews>(),
+            world.get_resource::<RenderState>(),
+            world.get_resource::<RenderAssets>(),
+            ctx.depth_target,
+        ) else {
+            return;
+        };
+        if views.views.is_empty() {
+            return;
+        }
+        let default_lights = SceneLights::default();
+        let scene_lights = world.get_resource::<SceneLights>().unwrap_or(&default_lights);
+        let clear_color = world.get_resource::<ClearColor>().copied().unwrap_or_default().to_wgpu();
+        let env_params = environment_params(world.get_resource::<Skybox>());
+        let device = ctx.device;
+        let encoder = &mut *ctx.encoder;
+        let main_target = (ctx.color_target, ctx.resolve_target, depth);
+        self.ensure_targets(device, render_state.render_size);
+
+        // --- 上传 uniform：场景 draw 与门面四边形各一批 ---
+        let (gpu_lights, light_count) = pack_lights(scene_lights);
+        let light = &scene_lights.directional;
+        let ambient = &scene_lights.ambient;
+        let mut scene_batch = UniformBatchBuffer::new(self.uniform_stride as usize);
+        let mut quad_batch = UniformBatchBuffer::new(UNIFORM_ALIGNMENT as usize);
+        // scene_offsets[view][level] = [(offset, draw index)]；quad_offsets[view][k] 为第 k 层门面（k = 0 为主画面）
+        let mut scene_offsets: Vec<Vec<Vec<(u32, usize)>>> = Vec::with_capacity(views.views.len());
+        let mut quad_offsets: Vec<Vec<u32>> = Vec::with_capacity(views.views.len());
+        for view in &views.views {
+            let mut view_quads = Vec::with_capacity(view.levels.len());
+            let mut view_scene = Vec::with_capacity(view.levels.len());
+            for level in &view.levels {
+                let quad = QuadUniform {
+                    view_proj: level.parent_view_proj.to_cols_array_2d(),
+                    model: view.quad_model.to_cols_array_2d(),
+                };
+                view_quads.push(quad_batch.push(bytemuck::bytes_of(&quad)));
+
+                let mut level_draws = Vec::new();
+                for (i, cmd) in level.draws.commands.iter().enumerate() {
+                    if render_assets.get_mesh(&cmd.mesh).is_none() || render_assets.get_material(&cmd.material).is_none() {
+                        continue;
+                    }
+                    let uniform = PbrSceneUniform {
+                        model: cmd.model_matrix.to_cols_array_2d(),
+                        view_proj: level.view_proj.to_cols_array_2d(),
+                        normal_matrix: cmd.model_matrix.inverse().transpose().to_cols_array_2d(),
+                        camera_pos: level.camera_pos.extend(0.0).to_array(),
+                        light_dir: light.direction.extend(0.0).to_array(),
+                        light_color: light.color.extend(light.intensity).to_array(),
+                        material_params: [cmd.metallic, cmd.roughness, cmd.normal_scale, light_count as f32],
+                        lights: gpu_lights,
+                        // 级联数为 0：门内视图不采样阴影
+                        emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 0.0],
+                        base_color_factor: cmd.base_color,
+                        ambient: ambient.color.extend(ambient.intensity).to_array(),
+                        env_params,
+                        ..Default::default()
+                    };
+                    level_draws.push((scene_batch.push(bytemuck::bytes_of(&uniform)), i));
+                }
+                view_scene.push(level_draws);
+            }
+            quad_offsets.push(view_quads);
+            scene_offsets.push(view_scene);
+        }
+
+        let scene_needed = scene_batch.count().max(1) as u64;
+        if scene_needed > self.scene_capacity {
+            let capacity = scene_needed.next_power_of_two();
+            (self.scene_uniform_buffer, self.scene_bind_group) =
+                Self::create_scene_uniforms(device, render_state, self.uniform_stride, capacity);
+            self.scene_capacity = capacity;
+        }
+        let quad_needed = quad_batch.count().max(1) as u64;
+        if quad_needed > self.quad_capacity {
+            let capacity = quad_needed.next_power_of_two();
+            (self.quad_uniform_buffer, self.quad_bind_group) =
+                Self::create_quad_uniforms(device, &self.quad_bind_group_layout, capacity);
+            self.quad_capacity = capacity;
+        }
+        if !scene_batch.as_bytes().is_empty() {
+            device.queue().write_buffer(&self.scene_uniform_buffer, 0, scene_batch.as_bytes());
+        }
+        device.queue().write_buffer(&self.quad_uniform_buffer, 0, quad_batch.as_bytes());
+
+        let Some(targets) = &self.targets else { return };
+
+        for (v, view) in views.views.iter().enumerate() {
+            // 由内向外：第 k 层绘制到 targets[k % 2]，并合成第 k + 1 层
+            for k in (0..view.levels.len()).rev() {
+                let level = &view.levels[k];
+                let slot = k % 2;
+                let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Portal Level Pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &targets.color_msaa[slot],
+                        resolve_target: Some(&targets.resolved[slot]),
+                        ops: wgpu::Operations { load: wgpu::LoadOp::Clear(clear_color), store: wgpu::StoreOp::Store },
+                    })],
+                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                        view: &targets.depth,
+                        // 清为 0：门面遮罩之外的像素全部无法通过 Less 测试
+                        depth_ops: Some(wgpu::Operations { load: wgpu::LoadOp::Clear(0.0), store: wgpu::StoreOp::Discard }),
+                        stencil_ops: None,
+                    }),
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+                rp.set_pipeline(&self.mask_pipeline);
+                rp.set_bind_group(0, &self.quad_bind_group, &[quad_offsets[v][k]]);
+                rp.draw(0..6, 0..1);
+
+                for &(offset, i) in &scene_offsets[v][k] {
+                    let cmd = &level.draws.commands[i];
+                    let (Some(mesh), Some(material)) = (render_assets.get_mesh(&cmd.mesh), render_assets.get_material(&cmd.material)) else {
+                        continue;
+                    };
+                    let Some(pipeline) = render_assets.get_pipeline(&material.pipeline_handle) else { continue };
+                    if !material.vertex_attributes.is_empty() {
+                        let Some(attributes) = &mesh.attribute_buffer else { continue };
+                        rp.set_vertex_buffer(1, attributes.slice(..));
+                    }
+                    rp.set_pipeline(pipeline);
+                    rp.set_bind_group(0, &self.scene_bind_group, &[offset]);
+                    rp.set_bind_group(1, &material.bind_group, &[]);
+                    rp.set_bind_group(2, &render_state.ibl_shadow_bind_group, &[]);
+                    rp.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+                    rp.set_index_buffer(mesh.index_buffer.slice(..), mesh.index_format);
+                    rp.draw_indexed(0..mesh.index_count, 0, 0..1);
+                }
+
+                // 更深一层贴到本层门面上；最深一层的门面保持门后的场景
+                if k + 1 < view.levels.len() {
+                    rp.set_pipeline(&self.composite_pipeline);
+                    rp.set_bind_group(0, &self.quad_bind_group, &[quad_offsets[v][k + 1]]);
+                    rp.set_bind_group(1, &targets.texture_bind_groups[(k + 1) % 2], &[]);
+                    rp.draw(0..6, 0..1);
+                }
+            }
+
+            // 第一层合成到主画面，与主场景深度测试
+            let (color, resolve, depth) = main_target;
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Portal Composite Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: color,
+                    resolve_target: resolve,
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: depth,
+                    depth_ops: Some(wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rp.set_pipeline(&self.composite_pipeline);
+            rp.set_bind_group(0, &self.quad_bind_group, &[quad_offsets[v][0]]);
+            rp.set_bind_group(1, &targets.texture_bind_groups[0], &[]);
+            rp.draw(0..6, 0..1);
+        }
+    }
+}
+
+/// 传送门插件
+///
+/// 注册 [`PortalViews`]、视图与排队系统，以及 `AfterOpaque` 插入点上的 `Portals` 阶段
+/// （优先级 -100，先于其他场景内阶段执行）。
+pub struct PortalPlugin;
+
+impl Plugin for PortalPlugin {
+    fn build(&self, app: &mut App) {
+        let gpu: Mutex<Option<PortalRenderer>> = Mutex::new(None);
+        app.init_resource::<PortalViews>()
+            .add_render_phase::<PortalItem>(
+                RenderPhaseDescriptor::new("Portals", PhaseSlot::AfterOpaque).with_priority(-100),
+                move |world, _items, ctx| {
+                    let Some(render_state) = world.get_resource::<RenderState>() else { return };
+                    let Ok(mut gpu) = gpu.lock() else { return };
+                    gpu.get_or_insert_with(|| PortalRenderer::new(ctx.device, render_state)).render(world, ctx);
+                },
+            )
+            .add_systems(
+                bevy_app::PostUpdate,
+                (
+                    portal_view_system
+                        .after(crate::plugin::camera_system)
+                        .after(crate::transform::propagate_transforms),
+                    portal_queue_system.after(portal_view_system),
+                ),
+            );
+    }
+
+    fn name(&self) -> &str {
+        "PortalPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oblique_projection_clips_at_plane() {
+        let proj = Mat4::perspective_lh(1.0, 1.0, 0.1, 100.0);
+        // 视图空间平面 z = 5，保留 z > 5
+        let oblique = oblique_projection(&proj, Vec4::new(0.0, 0.0, 1.0, -5.0));
+        let ndc_z = |z: f32| {
+            let clip = oblique * Vec4::new(0.0, 0.0, z, 1.0);
+            clip.z / clip.w
+        };
+        assert!(ndc_z(5.0).abs() < 1e-5);
+        assert!(ndc_z(3.0) < 0.0);
+        assert!(ndc_z(10.0) > 0.0 && ndc_z(10.0) < 1.0);
+        assert!((ndc_z(100.0) - 1.0).abs() < 1e-4);
+
+        // 相机位于保留一侧时不修改
+        assert_eq!(oblique_projection(&proj, Vec4::new(0.0, 0.0, 1.0, 5.0)), proj);
+    }
+
+    #[test]
+    fn test_portal_transfer_maps_front_to_exit_front() {
+        let portal = Mat4::from_translation(Vec3::new(0.0, 0.0, 5.0));
+        let exit = Mat4::from_translation(Vec3::new(20.0, 0.0, 0.0));
+        let transfer = portal_transfer(&portal, &exit);
+
+        // 站在入口正面 2 米处的观察者被映射到出口背面 2 米处
+        let eye = Vec3::new(0.0, 0.0, 3.0);
+        let virtual_eye = transfer.inverse().transform_point3(eye);
+        assert!((virtual_eye - Vec3::new(20.0, 0.0, 2.0)).length() < 1e-5);
+        // 朝 +Z 看入口 → 朝 -Z 看出口正面
+        let virtual_forward = transfer.inverse().transform_vector3(Vec3::Z);
+        assert!((virtual_forward - Vec3::NEG_Z).length() < 1e-5);
+    }
+
+    fn portal_world(portal_z: f32, camera: Transform) -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<PortalViews>();
+        let view = Mat4::look_at_lh(camera.translation, camera.translation + camera.rotation * Vec3::Z, Vec3::Y);
+        let proj = Mat4::perspective_lh(60f32.to_radians(), 1.0, 0.1, 1000.0);
+        world.insert_resource(ActiveCamera { view_proj: proj * view, camera_pos: camera.translation, ..Default::default() });
+        world.spawn((CameraComponent::default(), camera));
+
+        let exit = world.spawn(GlobalTransform(Mat4::from_translation(Vec3::new(50.0, 0.0, 0.0)))).id();
+        world.spawn((
+            Portal { linked_portal: Some(exit), max_recursion: 2, ..Default::default() },
+            GlobalTransform(Mat4::from_translation(Vec3::new(0.0, 0.0, portal_z))),
+        ));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(portal_view_system);
+        (world, schedule)
+    }
+
+    #[test]
+    fn test_portal_view_system_builds_levels_and_culls_per_level() {
+        let (mut world, mut schedule) = portal_world(5.0, Transform::default());
+        let unit = Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5));
+        // 出口正面前方（-Z 一侧）的物体在门内可见；入口附近的物体对虚拟相机不可见
+        world.spawn((MeshHandle(1), MaterialHandle(1), GlobalTransform(Mat4::from_translation(Vec3::new(50.0, 0.0, -10.0))), unit));
+        world.spawn((MeshHandle(2), MaterialHandle(1), GlobalTransform(Mat4::from_translation(Vec3::new(0.0, 0.0, 10.0))), unit));
+        schedule.run(&mut world);
+
+        let views = world.resource::<PortalViews>();
+        assert_eq!(views.views.len(), 1);
+        let levels = &views.views[0].levels;
+        assert_eq!(levels.len(), 2);
+        assert!((levels[0].camera_pos - Vec3::new(50.0, 0.0, 5.0)).length() < 1e-4);
+        assert_eq!(levels[1].parent_view_proj, levels[0].view_proj);
+
+        let meshes: Vec<_> = levels[0].draws.commands.iter().map(|c| c.mesh).collect();
+        assert_eq!(meshes, [MeshHandle(1)]);
+    }
+
+    #[test]
+    fn test_portal_behind_camera_or_facing_away_is_skipped() {
+        // 相机在门面背面（局部 +Z 一侧）
+        let (mut world, mut schedule) = portal_world(-5.0, Transform::default());
+        schedule.run(&mut world);
+        assert!(world.resource::<PortalViews>().views.is_empty());
+
+        // 未链接的门使用 target_transform；出口实体不存在时跳过
+        let (mut world, mut schedule) = portal_world(5.0, Transform::default());
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+        let mut portals = world.query::<&mut Portal>();
+        portals.single_mut(&mut world).linked_portal = Some(missing);
+        schedule.run(&mut world);
+        assert!(world.resource::<PortalViews>().views.is_empty());
+
+        portals.single_mut(&mut world).linked_portal = None;
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<PortalViews>().views.len(), 1);
+    }
+}
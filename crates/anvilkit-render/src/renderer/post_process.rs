@@ -3,17 +3,24 @@
 //! 统一的后处理效果开关和参数管理。通过 `PostProcessSettings` 资源
 //! 控制各效果的启用状态和参数。
 //!
+//! 场景先渲染到离屏 HDR 目标（Rgba16Float），每个效果是一次全屏 pass；
+//! Tonemap（ACES Filmic）把 HDR 转为 LDR，Vignette 在同一 pass 中应用，
+//! FXAA 启用时在 tonemap 之后以交换链分辨率再做一次全屏 pass。
+//!
 //! ## 使用示例
 //!
 //! ```rust,no_run
 //! # #[cfg(feature = "advanced-render")] {
-//! use anvilkit_render::renderer::post_process::PostProcessSettings;
+//! use anvilkit_render::renderer::post_process::{PostProcessSettings, VignetteSettings};
 //! use anvilkit_render::renderer::ssao::SsaoSettings;
 //! use anvilkit_render::renderer::bloom::BloomSettings;
+//! use anvilkit_render::renderer::fxaa::FxaaSettings;
 //!
 //! let settings = PostProcessSettings {
 //!     ssao: Some(SsaoSettings::default()),
 //!     bloom: Some(BloomSettings::default()),
+//!     vignette: Some(VignetteSettings { intensity: 0.4, ..Default::default() }),
+//!     fxaa: Some(FxaaSettings::default()),
 //!     ..Default::default()
 //! };
 //! # }
@@ -29,7 +36,36 @@ use crate::renderer::dof::DofSettings;
 use crate::renderer::motion_blur::MotionBlurSettings;
 #[cfg(feature = "advanced-render")]
 use crate::renderer::color_grading::ColorGradingSettings;
+#[cfg(feature = "render-3d")]
 use crate::renderer::bloom::BloomSettings;
+use crate::renderer::fxaa::FxaaSettings;
+
+/// 暗角配置
+///
+/// 在 tonemap pass 中按到屏幕中心的距离压暗画面边缘。
+#[derive(Debug, Clone, PartialEq, Describe)]
+/// Vignette (edge darkening) settings, applied in the tonemap pass.
+pub struct VignetteSettings {
+    /// Darkening at the corners (0 = none, 1 = black).
+    #[describe(hint = "Corner darkening", range = "0.0..1.0", default = "0.3")]
+    pub intensity: f32,
+    /// Normalized distance from the center (1 = corner) where darkening starts.
+    #[describe(hint = "Start of the falloff (0 = center, 1 = corner)", range = "0.0..0.95", default = "0.5")]
+    pub radius: f32,
+}
+
+impl Default for VignetteSettings {
+    fn default() -> Self {
+        Self { intensity: 0.3, radius: 0.5 }
+    }
+}
+
+impl VignetteSettings {
+    /// 打包为 tonemap uniform 中的 `[intensity, radius]`，参数被钳制到有效范围
+    pub fn to_uniform(&self) -> [f32; 2] {
+        [self.intensity.clamp(0.0, 1.0), self.radius.clamp(0.0, 0.95)]
+    }
+}
 
 /// 后处理管线统一配置
 ///
@@ -37,7 +73,9 @@ use crate::renderer::bloom::BloomSettings;
 /// - `None` = 禁用该效果
 /// - `Some(settings)` = 启用并使用给定参数
 ///
-/// 效果执行顺序（固定）：SSAO → DOF → Motion Blur → Bloom → Color Grading → Tonemap
+/// 效果执行顺序（固定）：SSAO → DOF → Motion Blur → Light Shafts → Bloom → Color Grading → Tonemap + Vignette → FXAA
+///
+/// Light Shafts 在 [`DirectionalLight`](crate::renderer::draw::DirectionalLight) 上配置，不在此处。
 #[derive(Resource, Default, Clone, Debug, Describe)]
 /// Unified post-process pipeline configuration.
 pub struct PostProcessSettings {
@@ -51,10 +89,15 @@ pub struct PostProcessSettings {
     #[cfg(feature = "advanced-render")]
     pub motion_blur: Option<MotionBlurSettings>,
     /// Bloom 辉光。`None` 禁用。
+    #[cfg(feature = "render-3d")]
     pub bloom: Option<BloomSettings>,
     /// 色彩分级（LUT 调色）。`None` 禁用。
     #[cfg(feature = "advanced-render")]
     pub color_grading: Option<ColorGradingSettings>,
+    /// 暗角。`None` 禁用。
+    pub vignette: Option<VignetteSettings>,
+    /// FXAA 抗锯齿（tonemap 之后，交换链分辨率）。`None` 禁用。
+    pub fxaa: Option<FxaaSettings>,
     /// Tonemap 是否接受 AO 纹理输入
     ///
     /// 启用后，tonemap pass 的 fragment shader 会额外采样 SSAO 输出，
@@ -69,6 +112,7 @@ impl PostProcessSettings {
     }
 
     /// 创建仅启用 Bloom 的配置
+    #[cfg(feature = "render-3d")]
     pub fn bloom_only() -> Self {
         Self {
             bloom: Some(BloomSettings::default()),
@@ -79,7 +123,11 @@ impl PostProcessSettings {
     /// 是否有任何效果启用
     pub fn any_enabled(&self) -> bool {
         #[allow(unused_mut)]
-        let mut enabled = self.bloom.is_some();
+        let mut enabled = self.vignette.is_some() || self.fxaa.is_some();
+        #[cfg(feature = "render-3d")]
+        {
+            enabled = enabled || self.bloom.is_some();
+        }
         #[cfg(feature = "advanced-render")]
         {
             enabled = enabled
@@ -108,6 +156,11 @@ pub struct PostProcessResources {
     /// Color Grading GPU 资源（延迟初始化）
     #[cfg(feature = "advanced-render")]
     pub color_grading: Option<crate::renderer::color_grading::ColorGradingResources>,
+    /// Light Shafts GPU 资源（方向光首次启用光柱时初始化）
+    #[cfg(feature = "render-3d")]
+    pub light_shafts: Option<crate::renderer::light_shafts::LightShaftResources>,
+    /// FXAA GPU 资源（首次启用时以交换链尺寸与格式初始化）
+    pub fxaa: Option<crate::renderer::fxaa::FxaaResources>,
     /// 上一帧的 view-projection 矩阵，供 Motion Blur 使用。
     /// 首帧为 None，使用当前帧矩阵（运动模糊=0）。
     pub prev_view_proj: Option<[[f32; 4]; 4]>,
@@ -125,6 +178,9 @@ impl PostProcessResources {
             motion_blur: None,
             #[cfg(feature = "advanced-render")]
             color_grading: None,
+            #[cfg(feature = "render-3d")]
+            light_shafts: None,
+            fxaa: None,
             prev_view_proj: None,
         }
     }
@@ -173,10 +229,37 @@ impl PostProcessResources {
         }
     }
 
-    /// Resize 所有已创建的资源
+    /// 方向光启用光柱时延迟初始化 Light Shafts 资源
+    #[cfg(feature = "render-3d")]
+    pub fn ensure_light_shafts(&mut self, device: &crate::renderer::RenderDevice, width: u32, height: u32) {
+        if self.light_shafts.is_none() {
+            self.light_shafts = Some(crate::renderer::light_shafts::LightShaftResources::new(device, width, height));
+        }
+    }
+
+    /// FXAA 启用时延迟初始化资源；交换链尺寸或格式变化时重建
+    pub fn ensure_fxaa(
+        &mut self,
+        device: &crate::renderer::RenderDevice,
+        width: u32,
+        height: u32,
+        format: wgpu::TextureFormat,
+    ) {
+        match self.fxaa {
+            Some(ref mut fxaa) if fxaa.format == format => fxaa.resize(device, width, height),
+            _ => self.fxaa = Some(crate::renderer::fxaa::FxaaResources::new(device, width, height, format)),
+        }
+    }
+
+    /// Resize 所有已创建的资源（内部渲染分辨率；FXAA 由 [`ensure_fxaa`](Self::ensure_fxaa) 跟随交换链尺寸）
     pub fn resize(&mut self, device: &crate::renderer::RenderDevice, width: u32, height: u32) {
         let _ = (device, width, height);
 
+        #[cfg(feature = "render-3d")]
+        if let Some(ref mut shafts) = self.light_shafts {
+            shafts.resize(device, width, height);
+        }
+
         #[cfg(feature = "advanced-render")]
         {
             if let Some(ref mut ssao) = self.ssao {
@@ -205,9 +288,11 @@ mod tests {
         assert!(!settings.any_enabled());
         #[cfg(feature = "advanced-render")]
         assert!(settings.ssao.is_none());
+        #[cfg(feature = "render-3d")]
         assert!(settings.bloom.is_none());
     }
 
+    #[cfg(feature = "render-3d")]
     #[test]
     fn test_bloom_only() {
         let settings = PostProcessSettings::bloom_only();
@@ -217,7 +302,18 @@ mod tests {
         assert!(settings.ssao.is_none());
     }
 
-    #[cfg(feature = "advanced-render")]
+    #[test]
+    fn test_vignette_and_fxaa_enable() {
+        let settings = PostProcessSettings { vignette: Some(VignetteSettings::default()), ..Default::default() };
+        assert!(settings.any_enabled());
+        let settings = PostProcessSettings { fxaa: Some(FxaaSettings::default()), ..Default::default() };
+        assert!(settings.any_enabled());
+
+        let wild = VignetteSettings { intensity: 2.0, radius: 1.5 };
+        assert_eq!(wild.to_uniform(), [1.0, 0.95]);
+    }
+
+    #[cfg(all(feature = "advanced-render", feature = "render-3d"))]
     #[test]
     fn test_full_pipeline() {
         let settings = PostProcessSettings {
@@ -226,6 +322,8 @@ mod tests {
             motion_blur: Some(MotionBlurSettings::default()),
             bloom: Some(BloomSettings::default()),
             color_grading: Some(ColorGradingSettings::default()),
+            vignette: Some(VignetteSettings::default()),
+            fxaa: Some(FxaaSettings::default()),
             ao_input_enabled: false,
         };
         assert!(settings.any_enabled());
@@ -2,4 +2,4 @@
 //!
 //! 提供鼠标拾取和射线测试所需的数学工具函数。
 
-pub use anvilkit_core::math::raycast::{screen_to_ray, ray_plane_intersection, ray_sphere_intersection};
+pub use anvilkit_core::math::raycast::{screen_to_ray, ray_plane_intersection, ray_sphere_intersection, ray_aabb_intersection};
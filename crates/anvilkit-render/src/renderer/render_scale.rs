@@ -0,0 +1,106 @@
+//! # 动态分辨率
+//!
+//! 3D 场景（深度、HDR、MSAA、Bloom 与后处理目标）以 `表面尺寸 × 渲染缩放` 的内部分辨率渲染，
+//! Tonemap pass 以双线性采样放大到交换链；缩小渲染时再做一次 FidelityFX CAS 风格的
+//! 对比度自适应锐化，补偿放大带来的模糊。
+//!
+//! 渲染缩放 = [`DynamicResolution::scale`] × [`QualityScale::render_scale`]（存在时，
+//! 由自适应画质控制器写入），每帧检查，内部分辨率变化时重建尺寸相关的 GPU 资源。
+
+use bevy_ecs::prelude::*;
+
+use crate::quality::QualityScale;
+
+/// 最小渲染缩放
+pub const MIN_RENDER_SCALE: f32 = 0.25;
+/// 最大渲染缩放（大于 1 为超采样）
+pub const MAX_RENDER_SCALE: f32 = 2.0;
+
+/// 动态分辨率设置 (Resource)
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct DynamicResolution {
+    /// 基础渲染缩放
+    pub scale: f32,
+    /// 放大时的锐化强度（0 关闭，1 最强）
+    pub sharpness: f32,
+}
+
+impl Default for DynamicResolution {
+    fn default() -> Self {
+        Self { scale: 1.0, sharpness: 0.3 }
+    }
+}
+
+/// Tonemap pass 参数 Uniform (16 字节)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct TonemapParams {
+    /// 锐化强度，0 跳过锐化
+    pub sharpness: f32,
+    /// 当前渲染缩放
+    pub render_scale: f32,
+    /// 暗角 `[intensity, radius]`，intensity 为 0 时不压暗
+    pub vignette: [f32; 2],
+}
+
+impl TonemapParams {
+    /// 由设置与当前缩放生成；原生或超采样分辨率下不锐化
+    pub fn new(settings: &DynamicResolution, render_scale: f32) -> Self {
+        let sharpness = if render_scale < 1.0 { settings.sharpness.clamp(0.0, 1.0) } else { 0.0 };
+        Self { sharpness, render_scale, vignette: [0.0; 2] }
+    }
+
+    /// 附加暗角参数（见 [`VignetteSettings`](crate::renderer::post_process::VignetteSettings)）
+    pub fn with_vignette(mut self, vignette: Option<&crate::renderer::post_process::VignetteSettings>) -> Self {
+        self.vignette = vignette.map_or([0.0; 2], |v| v.to_uniform());
+        self
+    }
+}
+
+/// 读取 World 中的设置，计算本帧的渲染缩放
+pub fn effective_render_scale(world: &World) -> f32 {
+    let base = world.get_resource::<DynamicResolution>().map_or(1.0, |d| d.scale);
+    let quality = world.get_resource::<QualityScale>().map_or(1.0, |q| q.render_scale);
+    let scale = base * quality;
+    if scale.is_finite() { scale.clamp(MIN_RENDER_SCALE, MAX_RENDER_SCALE) } else { 1.0 }
+}
+
+/// 按缩放计算内部分辨率（每维至少 1 像素）
+pub fn scaled_size(size: (u32, u32), scale: f32) -> (u32, u32) {
+    let scale_dim = |v: u32| ((v as f32 * scale).round() as u32).max(1);
+    (scale_dim(size.0), scale_dim(size.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaled_size() {
+        assert_eq!(scaled_size((1920, 1080), 1.0), (1920, 1080));
+        assert_eq!(scaled_size((1920, 1080), 0.5), (960, 540));
+        assert_eq!(scaled_size((1, 1), 0.25), (1, 1));
+    }
+
+    #[test]
+    fn test_effective_render_scale() {
+        let mut world = World::new();
+        assert_eq!(effective_render_scale(&world), 1.0);
+
+        world.insert_resource(DynamicResolution { scale: 0.8, sharpness: 0.5 });
+        world.insert_resource(QualityScale { render_scale: 0.5, ..Default::default() });
+        assert!((effective_render_scale(&world) - 0.4).abs() < 1e-6);
+
+        world.insert_resource(DynamicResolution { scale: 0.1, sharpness: 0.5 });
+        assert_eq!(effective_render_scale(&world), MIN_RENDER_SCALE);
+    }
+
+    #[test]
+    fn test_tonemap_params_sharpen_only_when_upscaling() {
+        let settings = DynamicResolution { scale: 1.0, sharpness: 0.6 };
+        assert_eq!(TonemapParams::new(&settings, 1.0).sharpness, 0.0);
+        assert_eq!(TonemapParams::new(&settings, 0.75).sharpness, 0.6);
+        assert_eq!(std::mem::size_of::<TonemapParams>(), 16);
+        assert_eq!(TonemapParams::new(&settings, 1.0).with_vignette(None).vignette, [0.0; 2]);
+    }
+}
@@ -0,0 +1,212 @@
+//! # GPU / 驱动信息报告
+//!
+//! [`RenderDevice::diagnostic_report`] 汇总适配器、驱动、特性、限制、表面格式、
+//! 操作系统与引擎版本，便于在问题报告中附上完整的运行环境。
+//!
+//! 渲染设备初始化后报告作为 [`DiagnosticReport`] 资源插入 World；
+//! 添加 [`GpuReportPlugin`] 后按 F7 把报告写入文件（默认 `gpu-report.txt`，与日志放在同一目录）。
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_input::prelude::{InputState, KeyCode};
+
+use super::RenderDevice;
+
+/// 运行环境报告 (Resource)
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DiagnosticReport {
+    /// 引擎版本
+    pub engine_version: String,
+    /// 操作系统与架构（如 `linux x86_64`）
+    pub os: String,
+    /// 适配器名称
+    pub adapter_name: String,
+    /// PCI 厂商 ID
+    pub vendor_id: u32,
+    /// PCI 设备 ID
+    pub device_id: u32,
+    /// 适配器类型（独显 / 集显 / CPU ...）
+    pub device_type: String,
+    /// 图形后端
+    pub backend: String,
+    /// 驱动名称
+    pub driver: String,
+    /// 驱动版本信息
+    pub driver_info: String,
+    /// 设备已启用的特性
+    pub features: Vec<String>,
+    /// 设备限制（`名称 = 值`）
+    pub limits: Vec<(String, String)>,
+    /// 表面支持的纹理格式（首个为首选）
+    pub surface_formats: Vec<String>,
+    /// 表面支持的呈现模式
+    pub present_modes: Vec<String>,
+}
+
+impl DiagnosticReport {
+    /// 格式化为纯文本（每行 `键: 值`，可直接粘贴到问题报告）
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        let _ = writeln!(text, "AnvilKit {}", self.engine_version);
+        let _ = writeln!(text, "OS: {}", self.os);
+        let _ = writeln!(
+            text,
+            "Adapter: {} (vendor 0x{:04x}, device 0x{:04x}, {})",
+            self.adapter_name, self.vendor_id, self.device_id, self.device_type
+        );
+        let _ = writeln!(text, "Backend: {}", self.backend);
+        let _ = writeln!(text, "Driver: {} {}", self.driver, self.driver_info);
+        let _ = writeln!(text, "Surface formats: {}", self.surface_formats.join(", "));
+        let _ = writeln!(text, "Present modes: {}", self.present_modes.join(", "));
+        let _ = writeln!(text, "Features: {}", self.features.join(", "));
+        let _ = writeln!(text, "Limits:");
+        for (name, value) in &self.limits {
+            let _ = writeln!(text, "  {} = {}", name, value);
+        }
+        text
+    }
+}
+
+impl std::fmt::Display for DiagnosticReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+/// 把 `Limits` 的 Debug 输出拆成 `(名称, 值)` 列表
+fn limit_entries(limits: &wgpu::Limits) -> Vec<(String, String)> {
+    let debug = format!("{:?}", limits);
+    let body = debug
+        .split_once('{')
+        .and_then(|(_, rest)| rest.rsplit_once('}'))
+        .map_or("", |(body, _)| body);
+    body.split(',')
+        .filter_map(|entry| entry.split_once(':'))
+        .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+impl RenderDevice {
+    /// 生成运行环境报告；传入表面时包含其支持的格式与呈现模式
+    pub fn diagnostic_report(&self, surface: Option<&wgpu::Surface<'_>>) -> DiagnosticReport {
+        let info = self.adapter().get_info();
+        let (surface_formats, present_modes) = match surface {
+            Some(surface) => {
+                let caps = surface.get_capabilities(self.adapter());
+                (
+                    caps.formats.iter().map(|f| format!("{:?}", f)).collect(),
+                    caps.present_modes.iter().map(|m| format!("{:?}", m)).collect(),
+                )
+            }
+            None => (Vec::new(), Vec::new()),
+        };
+
+        DiagnosticReport {
+            engine_version: env!("CARGO_PKG_VERSION").to_string(),
+            os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            adapter_name: info.name,
+            vendor_id: info.vendor,
+            device_id: info.device,
+            device_type: format!("{:?}", info.device_type),
+            backend: format!("{:?}", info.backend),
+            driver: info.driver,
+            driver_info: info.driver_info,
+            features: self.device().features().iter_names().map(|(name, _)| name.to_string()).collect(),
+            limits: limit_entries(&self.device().limits()),
+            surface_formats,
+            present_modes,
+        }
+    }
+}
+
+/// 报告导出设置 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct GpuReportSettings {
+    /// 导出按键，`None` 禁用
+    pub key: Option<KeyCode>,
+    /// 输出文件
+    pub path: PathBuf,
+}
+
+/// 按键时把 [`DiagnosticReport`] 写入文件 (Update)
+pub fn gpu_report_export_system(
+    settings: Res<GpuReportSettings>,
+    input: Option<Res<InputState>>,
+    report: Option<Res<DiagnosticReport>>,
+) {
+    let Some(key) = settings.key else { return };
+    if !input.is_some_and(|input| input.is_key_just_pressed(key)) {
+        return;
+    }
+    let Some(report) = report else {
+        log::warn!("渲染设备尚未初始化，没有可导出的 GPU 报告");
+        return;
+    };
+    match std::fs::write(&settings.path, report.to_text()) {
+        Ok(()) => log::info!("GPU 报告已写入 {}", settings.path.display()),
+        Err(e) => log::error!("写入 GPU 报告失败 {}: {}", settings.path.display(), e),
+    }
+}
+
+/// GPU 报告导出插件
+pub struct GpuReportPlugin {
+    /// 导出按键（默认 F7）
+    pub key: Option<KeyCode>,
+    /// 输出文件（默认 `gpu-report.txt`）
+    pub path: PathBuf,
+}
+
+impl Default for GpuReportPlugin {
+    fn default() -> Self {
+        Self { key: Some(KeyCode::F7), path: PathBuf::from("gpu-report.txt") }
+    }
+}
+
+impl Plugin for GpuReportPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GpuReportSettings { key: self.key, path: self.path.clone() })
+            .add_systems(bevy_app::Update, gpu_report_export_system);
+    }
+
+    fn name(&self) -> &str {
+        "GpuReportPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_entries() {
+        let entries = limit_entries(&wgpu::Limits::default());
+        assert!(entries.iter().any(|(name, value)| name == "max_bind_groups" && value == "4"));
+        assert!(entries.iter().all(|(name, _)| !name.contains(' ')));
+    }
+
+    #[test]
+    fn test_report_text() {
+        let report = DiagnosticReport {
+            engine_version: "0.1.0".into(),
+            os: "linux x86_64".into(),
+            adapter_name: "Test GPU".into(),
+            vendor_id: 0x10de,
+            device_id: 0x2786,
+            device_type: "DiscreteGpu".into(),
+            backend: "Vulkan".into(),
+            driver: "NVIDIA".into(),
+            driver_info: "550.54".into(),
+            features: vec!["TIMESTAMP_QUERY".into()],
+            limits: vec![("max_bind_groups".into(), "8".into())],
+            surface_formats: vec!["Bgra8UnormSrgb".into()],
+            present_modes: vec!["Fifo".into()],
+        };
+        let text = report.to_text();
+        assert!(text.starts_with("AnvilKit 0.1.0\n"));
+        assert!(text.contains("Adapter: Test GPU (vendor 0x10de, device 0x2786, DiscreteGpu)"));
+        assert!(text.contains("  max_bind_groups = 8"));
+    }
+}
@@ -11,10 +11,9 @@
 
 use crate::renderer::RenderDevice;
 use crate::renderer::state::RenderState;
-use crate::renderer::buffer::{
-    create_depth_texture_msaa, create_hdr_render_target, create_hdr_msaa_texture, create_sampler,
-};
 use crate::renderer::post_process::PostProcessSettings;
+use crate::renderer::render_scale::scaled_size;
+use crate::renderer::skybox::{Skybox, SkyboxResources};
 use log::debug;
 
 /// Pipeline 创建参数（从 RenderConfig 提取）
@@ -37,7 +36,7 @@ impl SceneRenderer {
     /// 处理窗口大小变化 — 重建所有 size-dependent GPU 资源
     ///
     /// 重建：depth texture, HDR RT, MSAA color, bloom mip chain, tonemap bind group,
-    /// 以及所有后处理资源。
+    /// 以及所有后处理资源。3D 目标按 `rs.render_scale` 缩放到内部渲染分辨率。
     ///
     /// # 参数
     ///
@@ -59,55 +58,72 @@ impl SceneRenderer {
         debug!("SceneRenderer: resize {}x{}", width, height);
 
         rs.surface_size = (width, height);
+        Self::resize_bloom(device, rs, bloom_mip_count);
+        rs.rebuild_render_targets(device);
+    }
 
-        // 重建 depth texture (MSAA)
-        let (_, depth_view) = create_depth_texture_msaa(device, width, height, "ECS Depth MSAA");
-        rs.depth_texture_view = depth_view;
-
-        // 重建 HDR render target (resolve) + MSAA color
-        let (hdr_tex, hdr_view) = create_hdr_render_target(device, width, height, "ECS HDR RT");
-        let (_, hdr_msaa_view) = create_hdr_msaa_texture(device, width, height, "ECS HDR MSAA");
-        let sampler = create_sampler(device, "ECS Sampler");
+    /// 修改渲染缩放 — 内部渲染分辨率变化时重建 size-dependent GPU 资源
+    pub fn set_render_scale(
+        device: &RenderDevice,
+        rs: &mut RenderState,
+        scale: f32,
+        bloom_mip_count: u32,
+    ) {
+        rs.render_scale = scale;
+        if scaled_size(rs.surface_size, scale) == rs.render_size {
+            return;
+        }
+        debug!("SceneRenderer: render scale {:.2}", scale);
+        Self::resize_bloom(device, rs, bloom_mip_count);
+        rs.rebuild_render_targets(device);
+    }
 
-        // Resize bloom mip chain
+    /// 按 `surface_size × render_scale` 重建 bloom mip 链（需在 tonemap bind group 重建之前）
+    fn resize_bloom(device: &RenderDevice, rs: &mut RenderState, bloom_mip_count: u32) {
+        let (width, height) = scaled_size(rs.surface_size, rs.render_scale);
         if let Some(ref mut bloom) = rs.bloom {
             bloom.resize(device, width, height, bloom_mip_count);
         }
+    }
 
-        // 重建 tonemap bind group
-        let bloom_view = rs.bloom.as_ref()
-            .and_then(|b| b.mip_views.first());
-        let bloom_view_ref = bloom_view.unwrap_or(&hdr_view);
-        let new_bg = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("ECS Tonemap BG"),
-            layout: &rs.tonemap_bind_group_layout,
-            entries: &[
-                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&hdr_view) },
-                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
-                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(bloom_view_ref) },
-            ],
-        });
-
-        rs.hdr_texture = hdr_tex;
-        rs.hdr_texture_view = hdr_view;
-        rs.hdr_msaa_texture_view = hdr_msaa_view;
-        rs.tonemap_bind_group = new_bg;
-
-        // Resize 后处理资源
-        rs.post_process.resize(device, width, height);
+    /// 以新的分辨率重建 CSM 阴影贴图及引用它的 IBL+Shadow bind group
+    pub fn resize_shadow_map(device: &RenderDevice, rs: &mut RenderState, size: u32) {
+        rs.resize_shadow_map(device, size);
+    }
+
+    /// 按 [`Skybox`] 资源创建、替换或移除天空盒 GPU 资源
+    ///
+    /// 立方体贴图换成新的 `Arc` 时重新上传，并重建 IBL+Shadow bind group 使材质采样新的环境贴图；
+    /// 资源被移除时恢复默认的黑色环境立方体。
+    pub fn ensure_skybox(device: &RenderDevice, rs: &mut RenderState, skybox: Option<&Skybox>) {
+        match (skybox, &rs.skybox) {
+            (Some(sky), Some(current)) if current.is_for(&sky.cubemap) => return,
+            (None, None) => return,
+            (Some(sky), _) => {
+                debug!("SceneRenderer: skybox cubemap {}x{}", sky.cubemap.size, sky.cubemap.size);
+                rs.skybox = Some(SkyboxResources::new(device, &sky.cubemap));
+            }
+            (None, Some(_)) => rs.skybox = None,
+        }
+        rs.rebuild_ibl_shadow_bind_group(device);
     }
 
     /// 确保后处理 GPU 资源已初始化
     ///
-    /// 根据 `PostProcessSettings` 延迟创建需要的 GPU 资源。
+    /// 根据 `PostProcessSettings` 延迟创建需要的 GPU 资源；
+    /// `light_shafts` 为方向光是否启用光柱。FXAA 资源跟随交换链尺寸与格式。
     /// 应在每帧 render 之前调用。
     pub fn ensure_post_process_resources(
         device: &RenderDevice,
         rs: &mut RenderState,
         settings: &PostProcessSettings,
+        light_shafts: bool,
     ) {
-        let (w, h) = rs.surface_size;
-        rs.post_process.ensure_resources(device, w, h, settings);
+        rs.ensure_post_process_resources(device, settings);
+        if light_shafts {
+            let (w, h) = rs.render_size;
+            rs.post_process.ensure_light_shafts(device, w, h);
+        }
     }
 
     /// 从 RenderConfig 读取渲染参数
@@ -0,0 +1,161 @@
+//! # 场景实体生成
+//!
+//! 将 [`SceneHierarchy`]（glTF 节点层级，或由 OBJ 包装的单节点层级）生成为 ECS 实体树：
+//!
+//! - 每个节点一个实体，带 `Transform`（局部 TRS）、`GlobalTransform` 与 `Parent` / `Children`
+//! - 网格上传到 [`RenderAssets`]，材质加入 [`Materials`]，实体通过 `Handle<Material>` 引用
+//! - 带顶点颜色 / UV1 的 primitive 额外上传属性顶点流；带顶点颜色时材质启用顶点颜色
+//! - 单 primitive 网格直接挂在节点实体上，多 primitive 网格为每个 primitive 生成子实体
+//!
+//! ## 使用示例
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::scene_spawn::spawn_gltf;
+//!
+//! # fn setup(world: &mut World, device: &RenderDevice) {
+//! let root = spawn_gltf(world, device, "assets/model.glb").expect("加载失败");
+//! world.get_mut::<Transform>(root).unwrap().scale = glam::Vec3::splat(2.0);
+//! # }
+//! ```
+
+use std::path::Path;
+
+use bevy_ecs::prelude::*;
+use anvilkit_core::error::Result;
+use anvilkit_core::math::{Transform, GlobalTransform};
+use anvilkit_assets::gltf_loader::load_gltf_hierarchy;
+use anvilkit_assets::obj_loader::load_obj;
+use anvilkit_assets::mesh::MeshData;
+use anvilkit_assets::scene::SceneHierarchy;
+
+use crate::component::Name;
+use crate::renderer::RenderDevice;
+use crate::renderer::assets::{Handle, MeshHandle, RenderAssets};
+use crate::renderer::buffer::{AttributeVertex, PbrVertex, VertexAttributes};
+use crate::renderer::material::{Material, Materials};
+use crate::transform::{Children, Parent};
+
+fn to_pbr_vertices(mesh: &MeshData) -> Vec<PbrVertex> {
+    mesh.to_pbr_vertices()
+        .into_iter()
+        .map(|v| PbrVertex { position: v.position, normal: v.normal, texcoord: v.texcoord, tangent: v.tangent })
+        .collect()
+}
+
+fn mesh_attributes(mesh: &MeshData) -> VertexAttributes {
+    VertexAttributes { colors: mesh.has_colors(), uv1: mesh.has_texcoords1() }
+}
+
+fn upload_primitive(assets: &mut RenderAssets, device: &RenderDevice, mesh: &MeshData, label: &str) -> MeshHandle {
+    let vertices = to_pbr_vertices(mesh);
+    let provided = mesh_attributes(mesh);
+    if !provided.is_empty() {
+        let attributes: Vec<AttributeVertex> = mesh.to_attribute_vertices()
+            .into_iter()
+            .map(|v| AttributeVertex { color: v.color, uv1: v.texcoord1 })
+            .collect();
+        match assets.upload_mesh_with_attributes(device, &vertices, &attributes, provided, &mesh.indices, label) {
+            Ok(handle) => return handle,
+            Err(e) => log::warn!("{}: 可选顶点属性上传失败，忽略: {}", label, e),
+        }
+    }
+    assets.upload_mesh_u32(device, &vertices, &mesh.indices, label)
+}
+
+/// 将场景层级生成为实体树，返回根实体
+///
+/// 根实体为单位变换，场景的所有根节点挂在其下，移动 / 缩放根实体即可整体摆放模型。
+pub fn spawn_scene(world: &mut World, device: &RenderDevice, scene: &SceneHierarchy) -> Entity {
+    // 上传网格并注册材质：primitives[mesh_index][primitive_index]
+    let mesh_handles: Vec<Vec<MeshHandle>> = {
+        let mut assets = world.get_resource_or_insert_with(RenderAssets::default);
+        scene.meshes.iter().enumerate()
+            .map(|(mesh_index, mesh)| {
+                let label = mesh.name.clone().unwrap_or_else(|| format!("glTF Mesh {}", mesh_index));
+                mesh.primitives.iter()
+                    .map(|prim| upload_primitive(&mut assets, device, &prim.mesh, &label))
+                    .collect()
+            })
+            .collect()
+    };
+    let material_handles: Vec<Vec<Handle<Material>>> = {
+        let mut materials = world.get_resource_or_insert_with(Materials::default);
+        scene.meshes.iter()
+            .map(|mesh| mesh.primitives.iter().map(|prim| {
+                let material = Material::from(prim.material.clone());
+                // glTF COLOR_0 与基础色相乘；UV1 留给光照贴图等需要它的材质
+                let material = if prim.mesh.has_colors() { material.with_vertex_colors() } else { material };
+                materials.add(material)
+            }).collect())
+            .collect()
+    };
+    let primitives: Vec<Vec<(MeshHandle, Handle<Material>)>> = mesh_handles.into_iter()
+        .zip(material_handles)
+        .map(|(meshes, materials)| meshes.into_iter().zip(materials).collect())
+        .collect();
+
+    let root = world.spawn((Transform::default(), GlobalTransform::default(), Name::new("Scene"))).id();
+    let mut node_entities: Vec<Option<Entity>> = vec![None; scene.nodes.len()];
+    let mut node_children: Vec<Vec<Entity>> = vec![Vec::new(); scene.nodes.len()];
+    let mut root_children = Vec::new();
+
+    scene.visit(|index, parent| {
+        let node = &scene.nodes[index];
+        let parent_entity = parent.and_then(|p| node_entities[p]).unwrap_or(root);
+        let transform = Transform::new(node.translation, node.rotation, node.scale);
+
+        let mut entity = world.spawn((transform, GlobalTransform::from_transform(&transform), Parent(parent_entity)));
+        if let Some(name) = &node.name {
+            entity.insert(Name::new(name.clone()));
+        }
+        let entity = entity.id();
+        node_entities[index] = Some(entity);
+        match parent {
+            Some(p) => node_children[p].push(entity),
+            None => root_children.push(entity),
+        }
+
+        match node.mesh.and_then(|m| primitives.get(m)).map(Vec::as_slice) {
+            Some([(mesh, material)]) => {
+                world.entity_mut(entity).insert((*mesh, *material));
+            }
+            Some(prims) => {
+                for (mesh, material) in prims {
+                    let child = world.spawn((
+                        *mesh,
+                        *material,
+                        Transform::default(),
+                        GlobalTransform::default(),
+                        Parent(entity),
+                    )).id();
+                    node_children[index].push(child);
+                }
+            }
+            None => {}
+        }
+    });
+
+    let with_children = node_entities.into_iter().zip(node_children)
+        .filter_map(|(entity, list)| Some((entity?, list)))
+        .chain(std::iter::once((root, root_children)));
+    for (entity, list) in with_children {
+        if !list.is_empty() {
+            world.entity_mut(entity).insert(Children::new(list));
+        }
+    }
+
+    root
+}
+
+/// 加载 glTF/GLB 文件并生成实体树，返回根实体
+pub fn spawn_gltf(world: &mut World, device: &RenderDevice, path: impl AsRef<Path>) -> Result<Entity> {
+    let scene = load_gltf_hierarchy(path)?;
+    Ok(spawn_scene(world, device, &scene))
+}
+
+/// 加载 OBJ 文件（及其 MTL 材质）并生成实体树，返回根实体
+pub fn spawn_obj(world: &mut World, device: &RenderDevice, path: impl AsRef<Path>) -> Result<Entity> {
+    let scene = SceneHierarchy::from(load_obj(path)?);
+    Ok(spawn_scene(world, device, &scene))
+}
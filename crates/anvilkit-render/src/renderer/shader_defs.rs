@@ -0,0 +1,91 @@
+//! # 着色器变体预处理
+//!
+//! 在 WGSL 源码中以注释形式标注可选代码块，按定义集合生成管线变体：
+//!
+//! ```text
+//! //#ifdef VERTEX_COLOR
+//! //    @location(4) color: vec4<f32>,
+//! //#endif
+//! ```
+//!
+//! 已定义的块去掉每行行首的 `//`，未定义的块保持为注释。因此原始文件本身即是
+//! 关闭所有可选特性的合法 WGSL，可直接编译或被工具检查。支持嵌套，
+//! 外层块未启用时内层块同样不启用。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_core::collections::Istr;
+//! use anvilkit_render::renderer::shader_defs::specialize;
+//!
+//! let src = "a\n//#ifdef FOO\n//b\n//#endif\n";
+//! assert_eq!(specialize(src, &[Istr::new("FOO")]), "a\n//#ifdef FOO\nb\n//#endif\n");
+//! assert_eq!(specialize(src, &[]), src);
+//! ```
+
+use anvilkit_core::collections::Istr;
+
+const IFDEF: &str = "//#ifdef ";
+const ENDIF: &str = "//#endif";
+
+/// 按定义集合展开 `//#ifdef NAME` … `//#endif` 块
+///
+/// 指令行本身原样保留（仍是注释），保证行号与源文件一致，便于对照着色器编译错误。
+/// 多余的 `//#endif` 被忽略，未闭合的块延续到文件末尾。
+/// 定义以驻留字符串给出，源码中的名称只查表不驻留，未驻留的名称必然未定义。
+pub fn specialize(source: &str, defs: &[Istr]) -> String {
+    let mut out = String::with_capacity(source.len());
+    // 每层块是否启用（已考虑外层）
+    let mut stack: Vec<bool> = Vec::new();
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if let Some(name) = trimmed.strip_prefix(IFDEF) {
+            let parent = stack.last().copied().unwrap_or(true);
+            let defined = Istr::get(name.trim()).is_some_and(|name| defs.contains(&name));
+            stack.push(parent && defined);
+            out.push_str(line);
+        } else if trimmed.starts_with(ENDIF) {
+            stack.pop();
+            out.push_str(line);
+        } else if stack.last().copied().unwrap_or(false) {
+            let indent = line.len() - trimmed.len();
+            out.push_str(&line[..indent]);
+            out.push_str(trimmed.strip_prefix("//").unwrap_or(trimmed));
+        } else {
+            out.push_str(line);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defs(names: &[&str]) -> Vec<Istr> {
+        names.iter().map(|name| Istr::new(name)).collect()
+    }
+
+    #[test]
+    fn test_nested_blocks() {
+        let src = "x\n//#ifdef A\n//a\n  //#ifdef B\n  //b\n  //#endif\n//#endif\n//c\n";
+        let a = specialize(src, &defs(&["A"]));
+        assert!(a.contains("\na\n") && a.contains("  //b\n"));
+        let b = specialize(src, &defs(&["B"]));
+        assert!(b.contains("//a\n") && b.contains("  //b\n"));
+        let ab = specialize(src, &defs(&["A", "B"]));
+        assert!(ab.contains("\na\n") && ab.contains("\n  b\n"));
+        // 块外的注释不受影响
+        assert!(ab.ends_with("//c\n"));
+    }
+
+    #[test]
+    fn test_pbr_shader_blocks_balanced() {
+        let src = include_str!("../shaders/pbr.wgsl");
+        assert_eq!(src.matches(IFDEF).count(), src.matches(ENDIF).count());
+        let all = specialize(src, &defs(&["VERTEX_COLOR", "VERTEX_UV1"]));
+        assert!(all.contains("@location(4) color: vec4<f32>"));
+        assert!(all.contains("base_color = base_color * in.color;"));
+        assert_eq!(specialize(src, &[]), src);
+    }
+}
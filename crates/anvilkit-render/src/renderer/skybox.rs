@@ -0,0 +1,559 @@
+//! # 天空盒与环境立方体贴图
+//!
+//! [`CubemapData`] 在 CPU 侧保存六个面的 RGBA8 (sRGB) 像素及其 mip 链，可由六张面图
+//! 或一张等距柱状投影（equirectangular）全景图转换得到。插入 [`Skybox`] 资源后：
+//!
+//! - 场景 pass 结束后绘制一个位于远平面的全屏三角形，只填充没有几何体的背景像素
+//! - `use_for_lighting` 打开时，同一张立方体贴图绑定到 PBR 着色器的 IBL 组，
+//!   最粗 mip 作为漫反射环境光、按粗糙度选择 mip 作为镜面反射；关闭时回退到半球环境光
+//!
+//! 立方体面朝向遵循 wgpu/Vulkan 约定：+X, -X, +Y, -Y, +Z, -Z，面内 `u` 向右、`v` 向下。
+//!
+//! ## 使用示例
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::renderer::skybox::{CubemapData, Skybox};
+//!
+//! let cubemap = CubemapData::load_equirect("assets/sky.png", 512).expect("加载天空盒失败");
+//! let mut app = App::new();
+//! app.insert_resource(Skybox::new(cubemap).with_lighting(0.8));
+//! ```
+
+use std::path::Path;
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use glam::{Mat4, Vec3};
+
+use anvilkit_assets::material::TextureData;
+use anvilkit_assets::texture::load_texture;
+use anvilkit_core::error::{AnvilKitError, Result};
+
+use crate::renderer::buffer::{create_sampler, DEPTH_FORMAT, HDR_FORMAT, MSAA_SAMPLE_COUNT};
+use crate::renderer::state::RenderState;
+use crate::renderer::RenderDevice;
+
+const SKYBOX_SHADER: &str = include_str!("../shaders/skybox.wgsl");
+
+/// 立方体贴图的一个面，顺序即 GPU 纹理层序号
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CubeFace {
+    /// +X
+    PositiveX,
+    /// -X
+    NegativeX,
+    /// +Y（天空）
+    PositiveY,
+    /// -Y（地面）
+    NegativeY,
+    /// +Z
+    PositiveZ,
+    /// -Z（默认相机朝向）
+    NegativeZ,
+}
+
+impl CubeFace {
+    /// 按纹理层顺序排列的全部六个面
+    pub const ALL: [CubeFace; 6] = [
+        CubeFace::PositiveX,
+        CubeFace::NegativeX,
+        CubeFace::PositiveY,
+        CubeFace::NegativeY,
+        CubeFace::PositiveZ,
+        CubeFace::NegativeZ,
+    ];
+
+    /// 面内坐标 `(u, v)`（0..1）对应的方向（未归一化）
+    pub fn direction(self, u: f32, v: f32) -> Vec3 {
+        let s = 2.0 * u - 1.0;
+        let t = 2.0 * v - 1.0;
+        match self {
+            CubeFace::PositiveX => Vec3::new(1.0, -t, -s),
+            CubeFace::NegativeX => Vec3::new(-1.0, -t, s),
+            CubeFace::PositiveY => Vec3::new(s, 1.0, t),
+            CubeFace::NegativeY => Vec3::new(s, -1.0, -t),
+            CubeFace::PositiveZ => Vec3::new(s, -t, 1.0),
+            CubeFace::NegativeZ => Vec3::new(-s, -t, -1.0),
+        }
+    }
+
+    /// 方向落在哪个面以及面内坐标 `(u, v)`，与 [`direction`](Self::direction) 互逆
+    pub fn from_direction(dir: Vec3) -> (CubeFace, f32, f32) {
+        let a = dir.abs();
+        let (face, sc, tc, ma) = if a.x >= a.y && a.x >= a.z {
+            if dir.x >= 0.0 {
+                (CubeFace::PositiveX, -dir.z, -dir.y, a.x)
+            } else {
+                (CubeFace::NegativeX, dir.z, -dir.y, a.x)
+            }
+        } else if a.y >= a.z {
+            if dir.y >= 0.0 {
+                (CubeFace::PositiveY, dir.x, dir.z, a.y)
+            } else {
+                (CubeFace::NegativeY, dir.x, -dir.z, a.y)
+            }
+        } else if dir.z >= 0.0 {
+            (CubeFace::PositiveZ, dir.x, -dir.y, a.z)
+        } else {
+            (CubeFace::NegativeZ, -dir.x, -dir.y, a.z)
+        };
+        let ma = ma.max(f32::EPSILON);
+        (face, 0.5 * (sc / ma + 1.0), 0.5 * (tc / ma + 1.0))
+    }
+}
+
+/// CPU 侧立方体贴图
+///
+/// `faces[i]` 为 [`CubeFace::ALL`]`[i]` 的 mip 0，RGBA8 sRGB，边长 `size`。
+#[derive(Clone)]
+pub struct CubemapData {
+    /// 每个面的边长（像素）
+    pub size: u32,
+    /// 六个面的 RGBA8 像素，顺序同 [`CubeFace::ALL`]
+    pub faces: [Vec<u8>; 6],
+}
+
+impl std::fmt::Debug for CubemapData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CubemapData").field("size", &self.size).finish_non_exhaustive()
+    }
+}
+
+impl CubemapData {
+    /// 由六张面图构建，顺序同 [`CubeFace::ALL`]；面必须是边长相同的正方形
+    pub fn from_faces(faces: [TextureData; 6]) -> Result<Self> {
+        let size = faces[0].width;
+        if size == 0 {
+            return Err(AnvilKitError::asset("立方体贴图面尺寸为 0".to_string()));
+        }
+        for (i, face) in faces.iter().enumerate() {
+            if face.width != size || face.height != size {
+                return Err(AnvilKitError::asset(format!(
+                    "立方体贴图第 {} 个面为 {}x{}，应为 {}x{}",
+                    i, face.width, face.height, size, size
+                )));
+            }
+            if face.data.len() != (size * size * 4) as usize {
+                return Err(AnvilKitError::asset(format!("立方体贴图第 {} 个面像素数据长度不匹配", i)));
+            }
+        }
+        Ok(Self { size, faces: faces.map(|f| f.data) })
+    }
+
+    /// 从六个图像文件加载，顺序同 [`CubeFace::ALL`]
+    pub fn load_faces<P: AsRef<Path>>(paths: [P; 6]) -> Result<Self> {
+        let mut faces = Vec::with_capacity(6);
+        for path in &paths {
+            faces.push(load_texture(path)?);
+        }
+        let faces: [TextureData; 6] = faces.try_into().expect("exactly six faces");
+        Self::from_faces(faces)
+    }
+
+    /// 把等距柱状投影全景图重采样为边长 `face_size` 的立方体贴图
+    ///
+    /// 全景图水平中心对应 -Z（默认相机朝向），顶边对应 +Y，双线性采样，经度方向循环。
+    pub fn from_equirect(image: &TextureData, face_size: u32) -> Result<Self> {
+        if image.width == 0 || image.height == 0 || image.data.len() != (image.width * image.height * 4) as usize {
+            return Err(AnvilKitError::asset("全景图尺寸或像素数据无效".to_string()));
+        }
+        let size = face_size.max(1);
+        let faces = CubeFace::ALL.map(|face| {
+            let mut pixels = Vec::with_capacity((size * size * 4) as usize);
+            for y in 0..size {
+                for x in 0..size {
+                    let u = (x as f32 + 0.5) / size as f32;
+                    let v = (y as f32 + 0.5) / size as f32;
+                    let dir = face.direction(u, v).normalize();
+                    let eu = 0.5 + dir.x.atan2(-dir.z) / std::f32::consts::TAU;
+                    let ev = dir.y.clamp(-1.0, 1.0).acos() / std::f32::consts::PI;
+                    pixels.extend_from_slice(&sample_bilinear(image, eu, ev));
+                }
+            }
+            pixels
+        });
+        Ok(Self { size, faces })
+    }
+
+    /// 从等距柱状投影全景图文件加载
+    pub fn load_equirect(path: impl AsRef<Path>, face_size: u32) -> Result<Self> {
+        Self::from_equirect(&load_texture(path)?, face_size)
+    }
+
+    /// 六个面均为同一颜色的 1x1 立方体贴图
+    pub fn solid(color: [u8; 4]) -> Self {
+        Self { size: 1, faces: std::array::from_fn(|_| color.to_vec()) }
+    }
+
+    /// mip 层数（直到 1x1）
+    pub fn mip_count(&self) -> u32 {
+        32 - self.size.max(1).leading_zeros()
+    }
+
+    /// 某个面的完整 mip 链（2x2 盒式滤波），第 0 层为原图
+    pub fn face_mips(&self, face: usize) -> Vec<Vec<u8>> {
+        let mut mips = vec![self.faces[face].clone()];
+        let mut size = self.size;
+        while size > 1 {
+            let next = (size / 2).max(1);
+            let src = mips.last().expect("at least one mip");
+            let mut dst = Vec::with_capacity((next * next * 4) as usize);
+            for y in 0..next {
+                for x in 0..next {
+                    for c in 0..4 {
+                        let mut sum = 0u32;
+                        for (dx, dy) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                            let sx = (x * 2 + dx).min(size - 1);
+                            let sy = (y * 2 + dy).min(size - 1);
+                            sum += src[((sy * size + sx) * 4 + c) as usize] as u32;
+                        }
+                        dst.push(((sum + 2) / 4) as u8);
+                    }
+                }
+            }
+            mips.push(dst);
+            size = next;
+        }
+        mips
+    }
+}
+
+fn sample_bilinear(image: &TextureData, u: f32, v: f32) -> [u8; 4] {
+    let (w, h) = (image.width as i64, image.height as i64);
+    let fx = u * w as f32 - 0.5;
+    let fy = (v * h as f32 - 0.5).clamp(0.0, (h - 1) as f32);
+    let (x0, y0) = (fx.floor() as i64, fy.floor() as i64);
+    let (tx, ty) = (fx - x0 as f32, fy - y0 as f32);
+    let texel = |x: i64, y: i64| {
+        let x = x.rem_euclid(w);
+        let y = y.clamp(0, h - 1);
+        let i = ((y * w + x) * 4) as usize;
+        &image.data[i..i + 4]
+    };
+    let (a, b, c, d) = (texel(x0, y0), texel(x0 + 1, y0), texel(x0, y0 + 1), texel(x0 + 1, y0 + 1));
+    std::array::from_fn(|i| {
+        let top = a[i] as f32 * (1.0 - tx) + b[i] as f32 * tx;
+        let bottom = c[i] as f32 * (1.0 - tx) + d[i] as f32 * tx;
+        (top * (1.0 - ty) + bottom * ty).round() as u8
+    })
+}
+
+/// 天空盒资源
+///
+/// 存在时作为场景背景绘制；`use_for_lighting` 为 true 时同时作为 PBR 材质的环境光照输入。
+#[derive(Resource, Debug, Clone)]
+pub struct Skybox {
+    /// 立方体贴图；替换为新的 `Arc` 时 GPU 纹理会重新上传
+    pub cubemap: Arc<CubemapData>,
+    /// Multiplier applied to the sky color drawn in the background.
+    pub brightness: f32,
+    /// Whether materials sample the cubemap for ambient and specular lighting.
+    pub use_for_lighting: bool,
+    /// Multiplier applied to the environment lighting contribution.
+    pub lighting_intensity: f32,
+}
+
+impl Skybox {
+    /// 仅作为背景的天空盒
+    pub fn new(cubemap: CubemapData) -> Self {
+        Self {
+            cubemap: Arc::new(cubemap),
+            brightness: 1.0,
+            use_for_lighting: false,
+            lighting_intensity: 1.0,
+        }
+    }
+
+    /// 设置背景亮度
+    pub fn with_brightness(mut self, brightness: f32) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    /// 同时作为环境光照输入，`intensity` 为光照强度
+    pub fn with_lighting(mut self, intensity: f32) -> Self {
+        self.use_for_lighting = true;
+        self.lighting_intensity = intensity;
+        self
+    }
+}
+
+/// 场景 uniform 的 `env_params`：`[强度（0 = 半球环境光回退）, 最大 mip, 0, 0]`
+pub fn environment_params(skybox: Option<&Skybox>) -> [f32; 4] {
+    match skybox {
+        Some(sky) if sky.use_for_lighting => [
+            sky.lighting_intensity.max(0.0),
+            (sky.cubemap.mip_count() - 1) as f32,
+            0.0,
+            0.0,
+        ],
+        _ => [0.0; 4],
+    }
+}
+
+/// 上传立方体贴图（含完整 mip 链），返回纹理与 Cube 视图
+pub fn create_environment_cube(
+    device: &RenderDevice,
+    cubemap: &CubemapData,
+    label: &str,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let mip_count = cubemap.mip_count();
+    let texture = device.device().create_texture(&wgpu::TextureDescriptor {
+        label: Some(label),
+        size: wgpu::Extent3d { width: cubemap.size, height: cubemap.size, depth_or_array_layers: 6 },
+        mip_level_count: mip_count,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8UnormSrgb,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        view_formats: &[],
+    });
+    for face in 0..6 {
+        for (mip, data) in cubemap.face_mips(face).iter().enumerate() {
+            let size = (cubemap.size >> mip).max(1);
+            device.queue().write_texture(
+                wgpu::ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: mip as u32,
+                    origin: wgpu::Origin3d { x: 0, y: 0, z: face as u32 },
+                    aspect: wgpu::TextureAspect::All,
+                },
+                data,
+                wgpu::ImageDataLayout { offset: 0, bytes_per_row: Some(4 * size), rows_per_image: Some(size) },
+                wgpu::Extent3d { width: size, height: size, depth_or_array_layers: 1 },
+            );
+        }
+    }
+    let view = texture.create_view(&wgpu::TextureViewDescriptor {
+        label: Some(label),
+        dimension: Some(wgpu::TextureViewDimension::Cube),
+        ..Default::default()
+    });
+    (texture, view)
+}
+
+/// 天空盒 GPU uniform (80 字节)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct SkyUniform {
+    inv_view_proj: [[f32; 4]; 4],
+    camera_pos: [f32; 4],
+}
+
+/// 天空盒 GPU 资源
+///
+/// 由 [`SceneRenderer::ensure_skybox`](crate::renderer::scene_renderer::SceneRenderer::ensure_skybox)
+/// 按 [`Skybox`] 资源创建，立方体贴图替换时重建。
+pub struct SkyboxResources {
+    source: Arc<CubemapData>,
+    _texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl SkyboxResources {
+    /// 上传立方体贴图并创建天空盒管线
+    pub fn new(device: &RenderDevice, cubemap: &Arc<CubemapData>) -> Self {
+        let (texture, view) = create_environment_cube(device, cubemap, "Skybox Cubemap");
+        let sampler = create_sampler(device, "Skybox Sampler");
+        let uniform_buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Skybox Uniform"),
+            size: std::mem::size_of::<SkyUniform>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = device.device().create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Skybox BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0, visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1, visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::Cube,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2, visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+        let bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Skybox BG"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: uniform_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::Sampler(&sampler) },
+            ],
+        });
+
+        let shader = device.device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Skybox Shader"),
+            source: wgpu::ShaderSource::Wgsl(SKYBOX_SHADER.into()),
+        });
+        let layout = device.device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Skybox Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.device().create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Skybox Pipeline"),
+            layout: Some(&layout),
+            vertex: wgpu::VertexState { module: &shader, entry_point: "vs_main", buffers: &[] },
+            primitive: wgpu::PrimitiveState { cull_mode: None, ..Default::default() },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: false,
+                depth_compare: wgpu::CompareFunction::LessEqual,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState { count: MSAA_SAMPLE_COUNT, ..Default::default() },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: HDR_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        });
+
+        Self {
+            source: Arc::clone(cubemap),
+            _texture: texture,
+            view,
+            uniform_buffer,
+            bind_group,
+            pipeline,
+        }
+    }
+
+    /// 是否由同一份立方体贴图创建
+    pub fn is_for(&self, cubemap: &Arc<CubemapData>) -> bool {
+        Arc::ptr_eq(&self.source, cubemap)
+    }
+
+    /// 立方体贴图视图（供 IBL bind group 使用）
+    pub fn environment_view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// 在已完成不透明几何体的 HDR 目标上绘制背景
+    ///
+    /// 写入 `render_state` 的 MSAA HDR 目标并解析到 HDR 纹理，与场景共享深度缓冲。
+    pub fn render(
+        &self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        render_state: &RenderState,
+        view_proj: &Mat4,
+        camera_pos: Vec3,
+        brightness: f32,
+    ) {
+        let uniform = SkyUniform {
+            inv_view_proj: view_proj.inverse().to_cols_array_2d(),
+            camera_pos: camera_pos.extend(brightness).to_array(),
+        };
+        device.queue().write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniform));
+
+        let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Skybox Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &render_state.hdr_msaa_texture_view,
+                resolve_target: Some(&render_state.hdr_texture_view),
+                ops: wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store },
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &render_state.depth_texture_view,
+                depth_ops: Some(wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rp.set_pipeline(&self.pipeline);
+        rp.set_bind_group(0, &self.bind_group, &[]);
+        rp.draw(0..3, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cube_face_direction_round_trip() {
+        for face in CubeFace::ALL {
+            for (u, v) in [(0.5, 0.5), (0.1, 0.2), (0.9, 0.75), (0.3, 0.95)] {
+                let (f, fu, fv) = CubeFace::from_direction(face.direction(u, v) * 3.0);
+                assert_eq!(f, face);
+                assert!((fu - u).abs() < 1e-5 && (fv - v).abs() < 1e-5, "{:?} ({u}, {v}) -> ({fu}, {fv})", face);
+            }
+        }
+        // 面中心指向各自的坐标轴
+        assert_eq!(CubeFace::NegativeZ.direction(0.5, 0.5), Vec3::NEG_Z);
+        assert_eq!(CubeFace::PositiveY.direction(0.5, 0.5), Vec3::Y);
+    }
+
+    #[test]
+    fn test_equirect_conversion_maps_sky_and_ground() {
+        // 上半部分红色、下半部分蓝色
+        let (w, h) = (64u32, 32u32);
+        let mut data = Vec::with_capacity((w * h * 4) as usize);
+        for y in 0..h {
+            for _ in 0..w {
+                data.extend_from_slice(if y < h / 2 { &[255, 0, 0, 255] } else { &[0, 0, 255, 255] });
+            }
+        }
+        let cube = CubemapData::from_equirect(&TextureData { width: w, height: h, data }, 8).unwrap();
+        assert_eq!(cube.size, 8);
+        assert_eq!(&cube.faces[2][..4], &[255, 0, 0, 255]); // +Y
+        assert_eq!(&cube.faces[3][..4], &[0, 0, 255, 255]); // -Y
+        // 侧面：上半行为天空，下半行为地面
+        let side = &cube.faces[5];
+        assert_eq!(&side[..4], &[255, 0, 0, 255]);
+        assert_eq!(&side[side.len() - 4..], &[0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_from_faces_validates_sizes() {
+        let face = |w, h| TextureData { width: w, height: h, data: vec![0; (w * h * 4) as usize] };
+        let ok = CubemapData::from_faces(std::array::from_fn(|_| face(4, 4))).unwrap();
+        assert_eq!(ok.mip_count(), 3);
+        assert_eq!(ok.face_mips(0).last().unwrap().len(), 4);
+
+        assert!(CubemapData::from_faces(std::array::from_fn(|i| if i == 3 { face(2, 2) } else { face(4, 4) })).is_err());
+        assert!(CubemapData::from_faces(std::array::from_fn(|_| face(4, 2))).is_err());
+    }
+
+    #[test]
+    fn test_environment_params() {
+        let sky = Skybox::new(CubemapData::solid([10, 20, 30, 255]));
+        assert_eq!(environment_params(None), [0.0; 4]);
+        assert_eq!(environment_params(Some(&sky)), [0.0; 4]);
+        let lit = Skybox::new(CubemapData::from_faces(std::array::from_fn(|_| TextureData {
+            width: 16, height: 16, data: vec![0; 16 * 16 * 4],
+        })).unwrap()).with_lighting(0.5);
+        assert_eq!(environment_params(Some(&lit)), [0.5, 4.0, 0.0, 0.0]);
+    }
+}
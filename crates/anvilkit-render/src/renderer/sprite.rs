@@ -12,7 +12,6 @@
 use bevy_ecs::prelude::*;
 use glam::{Vec2, Vec3};
 use bytemuck::{Pod, Zeroable};
-use wgpu::{self, VertexBufferLayout, VertexAttribute, VertexFormat, VertexStepMode};
 use super::shared::MatrixUniform;
 use wgpu::util::DeviceExt;
 
@@ -49,33 +48,7 @@ pub struct SpriteVertex {
     pub color: [f32; 3],
 }
 
-impl Vertex for SpriteVertex {
-    fn layout() -> VertexBufferLayout<'static> {
-        const ATTRIBUTES: &[VertexAttribute] = &[
-            VertexAttribute {
-                offset: 0,
-                shader_location: 0,
-                format: VertexFormat::Float32x3,
-            },
-            VertexAttribute {
-                offset: 12,
-                shader_location: 1,
-                format: VertexFormat::Float32x2,
-            },
-            VertexAttribute {
-                offset: 20,
-                shader_location: 2,
-                format: VertexFormat::Float32x3,
-            },
-        ];
-
-        VertexBufferLayout {
-            array_stride: std::mem::size_of::<SpriteVertex>() as u64,
-            step_mode: VertexStepMode::Vertex,
-            attributes: ATTRIBUTES,
-        }
-    }
-}
+crate::impl_vertex!(SpriteVertex, [0 => Float32x3, 1 => Float32x2, 2 => Float32x3]);
 
 /// 纹理图集中的矩形区域
 ///
@@ -259,6 +232,8 @@ impl Default for Sprite {
 pub struct SpriteBatch {
     /// 精灵顶点数据（6 个顶点 = 2 三角形 / 精灵）
     pub vertices: Vec<SpriteVertex>,
+    /// 排序用的暂存缓冲（跨帧复用，避免每帧分配）
+    sort_scratch: Vec<[SpriteVertex; 6]>,
 }
 
 impl SpriteBatch {
@@ -292,6 +267,18 @@ impl SpriteBatch {
         self.vertices.extend_from_slice(&[tl, bl, br, tl, br, tr]);
     }
 
+    /// 添加一个屏幕空间四边形（`min` 为左上角，对应 `uv.v_min`）
+    ///
+    /// 用于文字字形等不以中心定位的四边形。
+    pub fn add_quad(&mut self, min: Vec2, max: Vec2, z: f32, uv: &AtlasRect, color: [f32; 3]) {
+        let tl = SpriteVertex { position: [min.x, min.y, z], texcoord: [uv.u_min, uv.v_min], color };
+        let bl = SpriteVertex { position: [min.x, max.y, z], texcoord: [uv.u_min, uv.v_max], color };
+        let br = SpriteVertex { position: [max.x, max.y, z], texcoord: [uv.u_max, uv.v_max], color };
+        let tr = SpriteVertex { position: [max.x, min.y, z], texcoord: [uv.u_max, uv.v_min], color };
+
+        self.vertices.extend_from_slice(&[tl, bl, br, tl, br, tr]);
+    }
+
     /// 精灵数量
     pub fn sprite_count(&self) -> usize {
         self.vertices.len() / 6
@@ -303,17 +290,16 @@ impl SpriteBatch {
         let sprite_count = self.sprite_count();
         if sprite_count <= 1 { return; }
 
-        let mut sprites: Vec<[SpriteVertex; 6]> = Vec::with_capacity(sprite_count);
-        for chunk in self.vertices.chunks_exact(6) {
-            sprites.push([chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5]]);
-        }
+        self.sort_scratch.clear();
+        self.sort_scratch.extend(
+            self.vertices.chunks_exact(6)
+                .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3], chunk[4], chunk[5]]),
+        );
 
-        sprites.sort_by(|a, b| a[0].position[2].partial_cmp(&b[0].position[2]).unwrap_or(std::cmp::Ordering::Equal));
+        self.sort_scratch.sort_by(|a, b| a[0].position[2].partial_cmp(&b[0].position[2]).unwrap_or(std::cmp::Ordering::Equal));
 
         self.vertices.clear();
-        for sprite in sprites {
-            self.vertices.extend_from_slice(&sprite);
-        }
+        self.vertices.extend(self.sort_scratch.iter().flatten());
     }
 }
 
@@ -504,16 +490,38 @@ pub struct SpriteCollected {
     pub batch: SpriteBatch,
 }
 
+/// [`sprite_collect_system`] 查询的组件
+type SpriteCollectQuery = (
+    Entity,
+    &'static Sprite,
+    &'static anvilkit_core::math::Transform,
+    Option<&'static crate::component::Layer>,
+    Option<&'static crate::component::RenderOrder>,
+);
+
+/// 精灵排序键：((`Layer`, `RenderOrder`), z_order, 实体)
+pub(crate) type SpriteSortKey = ((i32, i64), f32, Entity);
+
 /// 收集系统：查询所有 Sprite + Transform 实体，构建排序后的 SpriteBatch。
+///
+/// 按 (`Layer`, `RenderOrder`, z_order) 排序：同一层级内显式的 `RenderOrder` 优先于 z 值。
 pub fn sprite_collect_system(
-    query: Query<(&Sprite, &anvilkit_core::math::Transform)>,
+    query: Query<SpriteCollectQuery>,
     mut collected: ResMut<SpriteCollected>,
+    mut order: Local<Vec<SpriteSortKey>>,
 ) {
     collected.batch.clear();
-    for (sprite, transform) in &query {
-        collected.batch.add_sprite(transform.translation, sprite);
+    order.clear();
+    order.extend(query.iter().map(|(entity, sprite, _, layer, render_order)| {
+        (crate::component::draw_sort_key(layer, render_order), sprite.z_order, entity)
+    }));
+    order.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+    for &(_, _, entity) in order.iter() {
+        if let Ok((_, sprite, transform, ..)) = query.get(entity) {
+            collected.batch.add_sprite(transform.translation, sprite);
+        }
     }
-    collected.batch.sort_by_z_order();
 }
 
 #[cfg(test)]
@@ -580,6 +588,28 @@ mod tests {
         assert_eq!(collected.batch.vertices[0].position[2], 0.0);
     }
 
+    #[test]
+    fn test_sprite_collect_render_order_overrides_z() {
+        use crate::component::{Layer, RenderOrder};
+
+        let mut world = World::new();
+        world.init_resource::<SpriteCollected>();
+        let at = |x: f32| anvilkit_core::math::Transform::from_translation(Vec3::new(x, 0.0, 0.0));
+
+        // 视图模型 z 值最小，但显式顺序最大
+        world.spawn((Sprite { z_order: -5.0, ..Default::default() }, at(1.0), RenderOrder(10)));
+        world.spawn((Sprite { z_order: 3.0, ..Default::default() }, at(2.0)));
+        world.spawn((Sprite { z_order: 9.0, ..Default::default() }, at(3.0), Layer(-1)));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(sprite_collect_system);
+        schedule.run(&mut world);
+
+        let batch = &world.resource::<SpriteCollected>().batch;
+        let order: Vec<f32> = batch.vertices.chunks_exact(6).map(|q| q[0].position[2]).collect();
+        assert_eq!(order, [9.0, 3.0, -5.0]);
+    }
+
     #[test]
     fn test_sprite_batch_z_sort() {
         let mut batch = SpriteBatch::new();
@@ -4,6 +4,15 @@
 //! 供渲染系统读取表面信息和场景 Uniform。
 
 use bevy_ecs::prelude::*;
+use log::debug;
+
+use crate::renderer::RenderDevice;
+use crate::renderer::buffer::{
+    create_depth_texture_msaa, create_hdr_render_target, create_hdr_msaa_texture, create_sampler,
+    create_csm_shadow_map, create_shadow_sampler,
+};
+use crate::renderer::post_process::PostProcessSettings;
+use crate::renderer::render_scale::scaled_size;
 
 /// GPU 端单个光源数据 (64 字节)
 ///
@@ -43,7 +52,7 @@ pub const MAX_LIGHTS: usize = 8;
 /// Cascade Shadow Maps 级数
 pub const CSM_CASCADE_COUNT: usize = 3;
 
-/// PBR 场景 Uniform (992 字节)
+/// PBR 场景 Uniform (1056 字节)
 ///
 /// 包含 per-object 变换、材质参数、多光源数据和 CSM 矩阵。
 /// 前 256 字节与旧布局兼容（light_dir/light_color 保留但多光源路径不使用）。
@@ -72,6 +81,14 @@ pub struct PbrSceneUniform {
     pub cascade_splits: [f32; 4],
     /// Emissive factor rgb, w = cascade_count (16 bytes).
     pub emissive_factor: [f32; 4],
+    /// Base color factor (linear RGBA), multiplied with the base color texture (16 bytes).
+    pub base_color_factor: [f32; 4],
+    /// Ambient tint rgb, w = intensity; scales the hemisphere ambient term (16 bytes).
+    pub ambient: [f32; 4],
+    /// Directional shadow params [depth_bias (texels), normal_bias (world), pcf_radius, unused] (16 bytes).
+    pub shadow_params: [f32; 4],
+    /// Environment lighting [intensity (0 = hemisphere fallback), max_mip, unused, unused] (16 bytes).
+    pub env_params: [f32; 4],
 }
 
 impl Default for PbrSceneUniform {
@@ -88,6 +105,10 @@ impl Default for PbrSceneUniform {
             cascade_view_projs: [glam::Mat4::IDENTITY.to_cols_array_2d(); CSM_CASCADE_COUNT],
             cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
             emissive_factor: [0.0, 0.0, 0.0, CSM_CASCADE_COUNT as f32],
+            base_color_factor: [1.0; 4],
+            ambient: [1.0; 4],
+            shadow_params: [5.0, 0.0, 1.0, 0.0],
+            env_params: [0.0; 4],
         }
     }
 }
@@ -102,6 +123,10 @@ pub struct RenderState {
     pub surface_format: wgpu::TextureFormat,
     /// Current surface dimensions (width, height) in pixels.
     pub surface_size: (u32, u32),
+    /// Internal 3D render resolution (surface size × render scale).
+    pub render_size: (u32, u32),
+    /// Render scale the size-dependent targets were built with.
+    pub render_scale: f32,
     /// GPU buffer holding the PBR scene uniform data.
     pub scene_uniform_buffer: wgpu::Buffer,
     /// Bind group exposing the scene uniform buffer to shaders.
@@ -120,6 +145,8 @@ pub struct RenderState {
     pub tonemap_bind_group: wgpu::BindGroup,
     /// Layout for the tone-mapping bind group.
     pub tonemap_bind_group_layout: wgpu::BindGroupLayout,
+    /// Uniform buffer for [`TonemapParams`](crate::renderer::render_scale::TonemapParams).
+    pub tonemap_params_buffer: wgpu::Buffer,
     /// Bind group for IBL environment and shadow map sampling (group 2).
     pub ibl_shadow_bind_group: wgpu::BindGroup,
     /// Layout for the IBL and shadow bind group.
@@ -130,22 +157,147 @@ pub struct RenderState {
     pub shadow_map_view: wgpu::TextureView,
     /// Per-cascade shadow map layer views (for rendering into individual layers).
     pub shadow_cascade_views: Vec<wgpu::TextureView>,
+    /// Shadow map resolution per cascade, in texels.
+    pub shadow_map_size: u32,
+    /// BRDF lookup table view (kept to rebuild the IBL/shadow bind group).
+    pub brdf_lut_view: wgpu::TextureView,
+    /// 1x1 black environment cube bound to the IBL group when no skybox is present.
+    pub default_environment_view: wgpu::TextureView,
+    /// Skybox GPU resources, created from the `Skybox` resource on demand.
+    #[cfg(feature = "render-3d")]
+    pub skybox: Option<crate::renderer::skybox::SkyboxResources>,
     /// MSAA multi-sampled HDR color attachment texture view.
     pub hdr_msaa_texture_view: wgpu::TextureView,
     /// Bloom post-processing GPU resources (mip chain, pipelines, bind groups).
+    #[cfg(feature = "render-3d")]
     pub bloom: Option<crate::renderer::bloom::BloomResources>,
+    /// 1x1 black texture bound as the tonemap bloom input when Bloom is compiled out.
+    #[cfg(not(feature = "render-3d"))]
+    pub bloom_fallback_view: wgpu::TextureView,
     /// 后处理 GPU 资源集合（SSAO, DOF, MotionBlur, ColorGrading）
     pub post_process: crate::renderer::post_process::PostProcessResources,
 }
 
+impl RenderState {
+    /// 处理窗口大小变化 — 以新的表面尺寸重建 size-dependent GPU 资源
+    ///
+    /// 启用 `render-3d` 时应使用 [`SceneRenderer::handle_resize`](crate::renderer::scene_renderer::SceneRenderer::handle_resize)，
+    /// 它会先按新尺寸重建 Bloom mip 链。
+    pub fn resize(&mut self, device: &RenderDevice, width: u32, height: u32) {
+        if width == 0 || height == 0 {
+            return;
+        }
+        debug!("RenderState: resize {}x{}", width, height);
+        self.surface_size = (width, height);
+        self.rebuild_render_targets(device);
+    }
+
+    /// 修改渲染缩放 — 内部渲染分辨率变化时重建 size-dependent GPU 资源
+    pub fn set_render_scale(&mut self, device: &RenderDevice, scale: f32) {
+        self.render_scale = scale;
+        if scaled_size(self.surface_size, scale) == self.render_size {
+            return;
+        }
+        debug!("RenderState: render scale {:.2}", scale);
+        self.rebuild_render_targets(device);
+    }
+
+    /// 以 `surface_size × render_scale` 重建 3D 渲染目标、tonemap bind group 与后处理资源
+    pub fn rebuild_render_targets(&mut self, device: &RenderDevice) {
+        let (width, height) = scaled_size(self.surface_size, self.render_scale);
+        self.render_size = (width, height);
+
+        let (_, depth_view) = create_depth_texture_msaa(device, width, height, "ECS Depth MSAA");
+        self.depth_texture_view = depth_view;
+
+        let (hdr_tex, hdr_view) = create_hdr_render_target(device, width, height, "ECS HDR RT");
+        let (_, hdr_msaa_view) = create_hdr_msaa_texture(device, width, height, "ECS HDR MSAA");
+        let sampler = create_sampler(device, "ECS Sampler");
+
+        #[cfg(feature = "render-3d")]
+        let bloom_view = self.bloom.as_ref().and_then(|b| b.mip_views.first()).unwrap_or(&hdr_view);
+        #[cfg(not(feature = "render-3d"))]
+        let bloom_view = &self.bloom_fallback_view;
+        let tonemap_bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("ECS Tonemap BG"),
+            layout: &self.tonemap_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&hdr_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(bloom_view) },
+                wgpu::BindGroupEntry { binding: 3, resource: self.tonemap_params_buffer.as_entire_binding() },
+            ],
+        });
+
+        self.hdr_texture = hdr_tex;
+        self.hdr_texture_view = hdr_view;
+        self.hdr_msaa_texture_view = hdr_msaa_view;
+        self.tonemap_bind_group = tonemap_bind_group;
+
+        self.post_process.resize(device, width, height);
+    }
+
+    /// 以新的分辨率重建 CSM 阴影贴图及引用它的 IBL+Shadow bind group
+    pub fn resize_shadow_map(&mut self, device: &RenderDevice, size: u32) {
+        let size = size.clamp(256, device.device().limits().max_texture_dimension_2d);
+        if size == self.shadow_map_size {
+            return;
+        }
+        debug!("RenderState: shadow map {} -> {}", self.shadow_map_size, size);
+
+        let (_, shadow_map_view, shadow_cascade_views) =
+            create_csm_shadow_map(device, size, CSM_CASCADE_COUNT as u32, "ECS CSM Shadow Map");
+        self.shadow_map_view = shadow_map_view;
+        self.shadow_cascade_views = shadow_cascade_views;
+        self.shadow_map_size = size;
+        self.rebuild_ibl_shadow_bind_group(device);
+    }
+
+    /// 以当前阴影贴图与环境立方体重建 IBL+Shadow bind group (group 2)
+    pub fn rebuild_ibl_shadow_bind_group(&mut self, device: &RenderDevice) {
+        let sampler = create_sampler(device, "ECS Sampler");
+        let shadow_sampler = create_shadow_sampler(device, "ECS Shadow Sampler");
+        #[cfg(feature = "render-3d")]
+        let environment_view = self.skybox.as_ref()
+            .map_or(&self.default_environment_view, |sky| sky.environment_view());
+        #[cfg(not(feature = "render-3d"))]
+        let environment_view = &self.default_environment_view;
+        self.ibl_shadow_bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("ECS IBL+Shadow BG"),
+            layout: &self.ibl_shadow_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&self.brdf_lut_view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
+                wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(&self.shadow_map_view) },
+                wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::Sampler(&shadow_sampler) },
+                wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::TextureView(environment_view) },
+                wgpu::BindGroupEntry { binding: 5, resource: wgpu::BindingResource::Sampler(&sampler) },
+            ],
+        });
+    }
+
+    /// 根据 `PostProcessSettings` 延迟创建后处理 GPU 资源；FXAA 资源跟随交换链尺寸与格式
+    ///
+    /// 应在每帧 render 之前调用。
+    pub fn ensure_post_process_resources(&mut self, device: &RenderDevice, settings: &PostProcessSettings) {
+        let (w, h) = self.render_size;
+        self.post_process.ensure_resources(device, w, h, settings);
+        if settings.fxaa.is_some() {
+            let (sw, sh) = self.surface_size;
+            let format = self.surface_format;
+            self.post_process.ensure_fxaa(device, sw, sh, format);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_pbr_scene_uniform_size() {
-        // 768 (old fields before shadow_view_proj) + 192 (3 cascade matrices) + 16 (cascade_splits) + 16 (emissive) = 992
-        assert_eq!(std::mem::size_of::<PbrSceneUniform>(), 992);
+        // 768 (old fields before shadow_view_proj) + 192 (3 cascade matrices) + 16 (cascade_splits) + 16 (emissive) + 16 (base_color) + 16 (ambient) + 16 (shadow_params) + 16 (env_params) = 1056
+        assert_eq!(std::mem::size_of::<PbrSceneUniform>(), 1056);
     }
 
     #[test]
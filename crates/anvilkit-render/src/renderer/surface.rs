@@ -13,6 +13,17 @@ use log::{info, warn, debug};
 use crate::renderer::RenderDevice;
 use anvilkit_core::error::{AnvilKitError, Result};
 
+/// 获取帧失败时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SurfaceRecovery {
+    /// 重新配置表面后重试
+    Reconfigure,
+    /// 跳过本帧
+    Skip,
+    /// 不可恢复
+    Fatal,
+}
+
 /// 渲染表面
 /// 
 /// 管理窗口表面、交换链配置和帧缓冲，提供渲染目标管理功能。
@@ -49,6 +60,8 @@ pub struct RenderSurface {
     config: SurfaceConfiguration,
     /// 当前纹理格式
     format: TextureFormat,
+    /// 设备支持的呈现模式
+    present_modes: Vec<PresentMode>,
     /// 持有窗口引用以保证 surface 生命周期
     _window: Arc<Window>,
 }
@@ -86,7 +99,8 @@ impl RenderSurface {
     /// 创建新的渲染表面（指定 vsync 模式）
     ///
     /// - `vsync = true`: 使用 `PresentMode::Fifo`（垂直同步）
-    /// - `vsync = false`: 优先使用 `PresentMode::Mailbox`（三重缓冲，低延迟）
+    /// - `vsync = false`: 优先使用 `PresentMode::Mailbox`（三重缓冲，低延迟），
+    ///   其次 `PresentMode::Immediate`，均不支持时回退到 `Fifo`
     pub fn new_with_vsync(device: &RenderDevice, window: &Arc<Window>, vsync: bool) -> Result<Self> {
         info!("创建渲染表面 (vsync={})", vsync);
 
@@ -104,11 +118,7 @@ impl RenderSurface {
         let size = window.inner_size();
 
         // 选择呈现模式
-        let present_mode = if vsync {
-            PresentMode::Fifo
-        } else {
-            Self::choose_present_mode(&capabilities.present_modes)
-        };
+        let present_mode = Self::choose_present_mode(&capabilities.present_modes, vsync);
 
         // 创建表面配置
         let config = SurfaceConfiguration {
@@ -134,6 +144,7 @@ impl RenderSurface {
             surface,
             config,
             format,
+            present_modes: capabilities.present_modes,
             _window: window.clone(),
         })
     }
@@ -170,15 +181,24 @@ impl RenderSurface {
     /// # 参数
     /// 
     /// - `modes`: 支持的呈现模式列表
+    /// - `vsync`: 是否启用垂直同步
     /// 
     /// # 返回
     /// 
     /// 返回选择的呈现模式
-    fn choose_present_mode(modes: &[PresentMode]) -> PresentMode {
-        // 优先选择 Mailbox 模式（三重缓冲）
-        if modes.contains(&PresentMode::Mailbox) {
-            debug!("选择呈现模式: Mailbox");
-            return PresentMode::Mailbox;
+    fn choose_present_mode(modes: &[PresentMode], vsync: bool) -> PresentMode {
+        // 垂直同步：Fifo 所有平台均支持
+        if vsync {
+            debug!("选择呈现模式: Fifo (vsync)");
+            return PresentMode::Fifo;
+        }
+
+        // 优先选择 Mailbox 模式（三重缓冲），其次 Immediate（无同步）
+        for mode in [PresentMode::Mailbox, PresentMode::Immediate] {
+            if modes.contains(&mode) {
+                debug!("选择呈现模式: {:?}", mode);
+                return mode;
+            }
         }
         
         // 回退到 Fifo 模式（垂直同步）
@@ -288,26 +308,84 @@ impl RenderSurface {
 
     /// 获取当前帧，自动恢复 Lost/Outdated 错误
     ///
-    /// 如果首次获取失败（Lost 或 Outdated），自动 reconfigure 后重试一次。
+    /// 如果首次获取失败（Lost 或 Outdated），自动 reconfigure 后重试一次；
+    /// 需要跳过本帧（如超时）时返回错误。
+    #[deprecated(note = "使用 `acquire_frame`，它以 `Ok(None)` 表示跳过本帧")]
     pub fn get_current_frame_with_recovery(&self, device: &RenderDevice) -> Result<SurfaceTexture> {
-        match self.surface.get_current_texture() {
-            Ok(frame) => Ok(frame),
-            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
-                warn!("表面需要重新配置，正在恢复...");
-                self.reconfigure(device);
-                // 重试一次
-                self.surface.get_current_texture()
-                    .map_err(|e| AnvilKitError::render(format!("表面恢复后仍失败: {}", e)))
-            }
-            Err(wgpu::SurfaceError::OutOfMemory) => {
-                Err(AnvilKitError::render("GPU 内存不足".to_string()))
+        self.acquire_frame(device)?
+            .ok_or_else(|| AnvilKitError::render("获取表面纹理超时，跳过本帧".to_string()))
+    }
+
+    /// 获取当前帧，自动处理可恢复的表面错误
+    ///
+    /// - `Lost` / `Outdated`：按当前配置重新配置后重试一次
+    /// - `Timeout`：跳过本帧，返回 `Ok(None)`；重试时超时或仍过时同样跳过
+    ///
+    /// 只有 `OutOfMemory` 或重试后表面仍丢失时返回错误。窗口尺寸变化不在这里处理，
+    /// 由 `RenderApp` 在获取前同步表面与渲染目标。
+    pub fn acquire_frame(&self, device: &RenderDevice) -> Result<Option<SurfaceTexture>> {
+        let err = match self.surface.get_current_texture() {
+            Ok(frame) => return Ok(Some(frame)),
+            Err(e) => e,
+        };
+        match Self::recovery_for(&err) {
+            SurfaceRecovery::Skip => {
+                debug!("获取表面纹理超时，跳过本帧");
+                Ok(None)
             }
-            Err(wgpu::SurfaceError::Timeout) => {
-                Err(AnvilKitError::render("获取表面纹理超时".to_string()))
+            SurfaceRecovery::Fatal => Err(AnvilKitError::render(format!("获取表面纹理失败: {}", err))),
+            SurfaceRecovery::Reconfigure => {
+                warn!("表面{}，正在恢复...", if matches!(err, wgpu::SurfaceError::Lost) { "丢失" } else { "过时" });
+                self.reconfigure(device);
+                match self.surface.get_current_texture() {
+                    Ok(frame) => Ok(Some(frame)),
+                    Err(wgpu::SurfaceError::Timeout | wgpu::SurfaceError::Outdated) => Ok(None),
+                    Err(e) => Err(AnvilKitError::render(format!("表面恢复后仍失败: {}", e))),
+                }
             }
         }
     }
-    
+
+    /// 表面错误的处理方式
+    fn recovery_for(err: &wgpu::SurfaceError) -> SurfaceRecovery {
+        match err {
+            wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated => SurfaceRecovery::Reconfigure,
+            wgpu::SurfaceError::Timeout => SurfaceRecovery::Skip,
+            wgpu::SurfaceError::OutOfMemory => SurfaceRecovery::Fatal,
+        }
+    }
+
+    /// 运行时切换呈现模式
+    ///
+    /// 设备不支持 `mode` 时回退到 `Fifo`。返回实际生效的模式。
+    pub fn set_present_mode(&mut self, device: &RenderDevice, mode: PresentMode) -> PresentMode {
+        let mode = if matches!(mode, PresentMode::AutoVsync | PresentMode::AutoNoVsync)
+            || self.present_modes.contains(&mode)
+        {
+            mode
+        } else {
+            warn!("设备不支持呈现模式 {:?}，回退到 Fifo", mode);
+            PresentMode::Fifo
+        };
+        if mode != self.config.present_mode {
+            info!("切换呈现模式: {:?} -> {:?}", self.config.present_mode, mode);
+            self.config.present_mode = mode;
+            self.surface.configure(device.device(), &self.config);
+        }
+        mode
+    }
+
+    /// 运行时开关垂直同步（按与创建时相同的规则选择呈现模式）
+    pub fn set_vsync(&mut self, device: &RenderDevice, vsync: bool) -> PresentMode {
+        let mode = Self::choose_present_mode(&self.present_modes, vsync);
+        self.set_present_mode(device, mode)
+    }
+
+    /// 当前呈现模式
+    pub fn present_mode(&self) -> PresentMode {
+        self.config.present_mode
+    }
+
     /// 获取表面配置
     /// 
     /// # 返回
@@ -410,8 +488,14 @@ mod tests {
             PresentMode::Immediate,
         ];
         
-        let chosen = RenderSurface::choose_present_mode(&modes);
+        let chosen = RenderSurface::choose_present_mode(&modes, false);
         assert_eq!(chosen, PresentMode::Mailbox);
+        assert_eq!(RenderSurface::choose_present_mode(&modes, true), PresentMode::Fifo);
+
+        // 无 Mailbox 时关闭 vsync 选择 Immediate
+        let modes = vec![PresentMode::Fifo, PresentMode::Immediate];
+        assert_eq!(RenderSurface::choose_present_mode(&modes, false), PresentMode::Immediate);
+        assert_eq!(RenderSurface::choose_present_mode(&[PresentMode::Fifo], false), PresentMode::Fifo);
     }
     
     #[test]
@@ -454,4 +538,13 @@ mod tests {
         let mode = PresentMode::Immediate;
         assert_eq!(mode, PresentMode::Immediate);
     }
+
+    #[test]
+    fn test_surface_error_recovery() {
+        use wgpu::SurfaceError;
+        assert_eq!(RenderSurface::recovery_for(&SurfaceError::Lost), SurfaceRecovery::Reconfigure);
+        assert_eq!(RenderSurface::recovery_for(&SurfaceError::Outdated), SurfaceRecovery::Reconfigure);
+        assert_eq!(RenderSurface::recovery_for(&SurfaceError::Timeout), SurfaceRecovery::Skip);
+        assert_eq!(RenderSurface::recovery_for(&SurfaceError::OutOfMemory), SurfaceRecovery::Fatal);
+    }
 }
@@ -0,0 +1,211 @@
+//! # Text2d 组件
+//!
+//! 使用 TTF 字体的屏幕空间文本实体。[`Text2d`] 在 `Transform` 的位置处按对齐方式排版，
+//! 字形四边形收集到 [`Text2dCollected`] 的 [`SpriteBatch`] 中，与精灵共用同一条管线：
+//!
+//! ```rust,ignore
+//! atlas.upload(&device, &sprite_renderer.texture_bind_group_layout);
+//! if let Some(bind_group) = atlas.bind_group() {
+//!     sprite_renderer.render(&device, &mut encoder, &view, &collected.batch, bind_group, w, h);
+//! }
+//! ```
+//!
+//! ```rust
+//! use anvilkit_render::renderer::font::TextAlign;
+//! use anvilkit_render::renderer::text2d::Text2d;
+//!
+//! let text = Text2d::new("Game Over").with_size(48.0).with_align(TextAlign::Center);
+//! assert_eq!(text.size, 48.0);
+//! ```
+
+use bevy_ecs::prelude::*;
+use bevy_app::{App, Plugin};
+use glam::Vec2;
+use anvilkit_core::math::Transform;
+
+use super::font::{layout_text, FontHandle, Fonts, GlyphAtlas, TextAlign, TextLayout};
+use super::sprite::SpriteBatch;
+
+/// 2D 文本组件
+#[derive(Debug, Clone, PartialEq, Component)]
+#[require(Text2dLayout, Transform)]
+pub struct Text2d {
+    /// 文本内容（`\n` 换行）
+    pub value: String,
+    /// 字体
+    pub font: FontHandle,
+    /// 字号（像素）
+    pub size: f32,
+    /// 颜色 (linear RGB)
+    pub color: [f32; 3],
+    /// 水平对齐
+    pub align: TextAlign,
+    /// Z 排序值（越小越先绘制）
+    pub z_order: f32,
+}
+
+impl Default for Text2d {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            font: FontHandle::default(),
+            size: 16.0,
+            color: [1.0, 1.0, 1.0],
+            align: TextAlign::Left,
+            z_order: 0.0,
+        }
+    }
+}
+
+impl Text2d {
+    /// 以默认字体和样式创建文本
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into(), ..Default::default() }
+    }
+
+    /// Builder: 设置字体
+    pub fn with_font(mut self, font: FontHandle) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Builder: 设置字号
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Builder: 设置颜色
+    pub fn with_color(mut self, color: [f32; 3]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Builder: 设置对齐
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// 缓存的排版结果
+///
+/// 文本、字体表变化或字形图集重建后由 [`text2d_layout_system`] 重新计算。
+#[derive(Debug, Clone, Default, Component)]
+pub struct Text2dLayout {
+    /// 排版结果（相对锚点）
+    pub layout: TextLayout,
+    atlas_generation: Option<u32>,
+}
+
+/// 文本排版系统 (PostUpdate)
+pub fn text2d_layout_system(
+    fonts: Res<Fonts>,
+    mut atlas: ResMut<GlyphAtlas>,
+    mut query: Query<(Ref<Text2d>, &mut Text2dLayout)>,
+) {
+    for (text, mut cached) in query.iter_mut() {
+        let stale = cached.atlas_generation != Some(atlas.generation());
+        if !(stale || text.is_changed() || fonts.is_changed()) {
+            continue;
+        }
+        let generation = atlas.generation();
+        cached.layout = layout_text(&fonts, &mut atlas, &text.value, text.font, text.size, text.align);
+        // 排版过程中图集可能被清空重建，此时保持 stale，下一帧再排
+        cached.atlas_generation = (atlas.generation() == generation).then_some(generation);
+    }
+}
+
+/// ECS 资源：每帧收集的文字字形批次
+#[derive(Resource, Default)]
+pub struct Text2dCollected {
+    /// 字形四边形（纹理为 [`GlyphAtlas`]）
+    pub batch: SpriteBatch,
+}
+
+/// 文字收集系统 (PostUpdate)：按 z_order 把排版结果平移到实体位置并写入批次
+pub fn text2d_collect_system(
+    query: Query<(Entity, &Text2d, &Text2dLayout, &Transform)>,
+    mut collected: ResMut<Text2dCollected>,
+    mut order: Local<Vec<(f32, Entity)>>,
+) {
+    collected.batch.clear();
+    order.clear();
+    order.extend(query.iter().map(|(entity, text, ..)| (text.z_order, entity)));
+    order.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    for &(_, entity) in order.iter() {
+        let Ok((_, text, cached, transform)) = query.get(entity) else { continue };
+        let origin = Vec2::new(transform.translation.x, transform.translation.y);
+        for glyph in &cached.layout.glyphs {
+            collected.batch.add_quad(origin + glyph.min, origin + glyph.max, text.z_order, &glyph.uv, text.color);
+        }
+    }
+}
+
+/// Text2d 插件
+///
+/// 初始化 [`Fonts`]、[`GlyphAtlas`] 与 [`Text2dCollected`]，注册排版与收集系统。
+/// 字体需由应用通过 `Fonts::load_file` / `load_bytes` 加载。
+pub struct Text2dPlugin;
+
+impl Plugin for Text2dPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Fonts>()
+            .init_resource::<GlyphAtlas>()
+            .init_resource::<Text2dCollected>()
+            .add_systems(
+                bevy_app::PostUpdate,
+                (text2d_layout_system, text2d_collect_system).chain(),
+            );
+    }
+
+    fn name(&self) -> &str {
+        "Text2dPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::font::PositionedGlyph;
+    use super::super::sprite::AtlasRect;
+    use glam::Vec3;
+
+    #[test]
+    fn test_collect_offsets_glyphs_by_transform() {
+        let mut app = App::new();
+        app.add_plugins(Text2dPlugin);
+
+        let glyph = PositionedGlyph { min: Vec2::new(0.0, 2.0), max: Vec2::new(8.0, 12.0), uv: AtlasRect::full() };
+        let entity = app
+            .world_mut()
+            .spawn((Text2d::new("A"), Transform::from_translation(Vec3::new(100.0, 50.0, 0.0))))
+            .id();
+        app.update();
+        // 没有字体时排版为空
+        assert_eq!(app.world().resource::<Text2dCollected>().batch.sprite_count(), 0);
+
+        // 注入排版结果，同一 atlas generation 下不会被重排覆盖
+        app.world_mut().get_mut::<Text2dLayout>(entity).unwrap().layout.glyphs.push(glyph);
+        app.update();
+        let batch = &app.world().resource::<Text2dCollected>().batch;
+        assert_eq!(batch.sprite_count(), 1);
+        assert_eq!(batch.vertices[0].position, [100.0, 52.0, 0.0]);
+        assert_eq!(batch.vertices[2].position, [108.0, 62.0, 0.0]);
+    }
+
+    #[test]
+    fn test_text_change_triggers_relayout() {
+        let mut app = App::new();
+        app.add_plugins(Text2dPlugin);
+        let entity = app.world_mut().spawn(Text2d::new("A")).id();
+        app.update();
+
+        let glyph = PositionedGlyph { min: Vec2::ZERO, max: Vec2::ONE, uv: AtlasRect::full() };
+        app.world_mut().get_mut::<Text2dLayout>(entity).unwrap().layout.glyphs.push(glyph);
+        app.world_mut().get_mut::<Text2d>(entity).unwrap().value = "B".into();
+        app.update();
+        assert!(app.world().get::<Text2dLayout>(entity).unwrap().layout.glyphs.is_empty());
+    }
+}
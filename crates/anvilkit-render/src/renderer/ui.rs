@@ -310,39 +310,49 @@ impl UiRenderer {
         nodes: &[&UiNode],
         screen_width: f32,
         screen_height: f32,
+    ) {
+        let nodes: Vec<_> = nodes.iter().map(|node| (*node, None)).collect();
+        self.render_with_clips(device, encoder, target, &nodes, screen_width, screen_height);
+    }
+
+    /// 渲染 UI 矩形，每个节点可带 [`UiClip`](super::ui_scroll::UiClip) 裁剪矩形
+    ///
+    /// 按顺序将裁剪矩形相同的相邻节点合为一批，整个列表在同一个渲染通道内
+    /// 逐批设置 scissor 绘制；裁剪区域为空的节点被跳过。
+    pub fn render_with_clips(
+        &mut self,
+        device: &super::RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        nodes: &[(&UiNode, Option<&super::ui_scroll::UiClip>)],
+        screen_width: f32,
+        screen_height: f32,
     ) {
         if nodes.is_empty() {
             return;
         }
 
-        // Update ortho
-        let ortho = glam::Mat4::orthographic_lh(0.0, screen_width, screen_height, 0.0, -1.0, 1.0);
-        let uniform = MatrixUniform::from_mat4(&ortho);
-        device.queue().write_buffer(&self.ortho_buffer, 0, bytemuck::bytes_of(&uniform));
-
-        // Build vertices
+        // Build vertices, batched by scissor rect
         let mut vertices = Vec::new();
-        for node in nodes {
-            if !node.visible || node.computed_rect[2] <= 0.0 || node.computed_rect[3] <= 0.0 {
+        let mut batches: Vec<(std::ops::Range<u32>, Option<[u32; 4]>)> = Vec::new();
+        for (node, clip) in nodes {
+            let scissor = match clip {
+                Some(clip) => match scissor_rect(clip.0, screen_width, screen_height) {
+                    Some(rect) => Some(rect),
+                    // 裁剪区域为空：无需绘制
+                    None => continue,
+                },
+                None => None,
+            };
+            let start = vertices.len() as u32;
+            push_node_vertices(&mut vertices, node);
+            let end = vertices.len() as u32;
+            if start == end {
                 continue;
             }
-            let [x, y, w, h] = node.computed_rect;
-            let params = [node.corner_radius, node.border_width, 0.0, 0.0];
-
-            // 6 vertices (2 triangles)
-            let corners = [
-                [0.0f32, 0.0], [1.0, 0.0], [1.0, 1.0],
-                [0.0, 0.0], [1.0, 1.0], [0.0, 1.0],
-            ];
-            for corner in &corners {
-                vertices.push(UiVertex {
-                    position: *corner,
-                    rect_min: [x, y],
-                    rect_size: [w, h],
-                    color: node.background_color,
-                    border_color: node.border_color,
-                    params,
-                });
+            match batches.last_mut() {
+                Some((range, rect)) if *rect == scissor && range.end == start => range.end = end,
+                _ => batches.push((start..end, scissor)),
             }
         }
 
@@ -350,6 +360,11 @@ impl UiRenderer {
             return;
         }
 
+        // Update ortho
+        let ortho = glam::Mat4::orthographic_lh(0.0, screen_width, screen_height, 0.0, -1.0, 1.0);
+        let uniform = MatrixUniform::from_mat4(&ortho);
+        device.queue().write_buffer(&self.ortho_buffer, 0, bytemuck::bytes_of(&uniform));
+
         let data = bytemuck::cast_slice(&vertices);
         let vb = self.cached_vb.ensure_and_write(device.device(), device.queue(), data);
 
@@ -372,7 +387,61 @@ impl UiRenderer {
             rp.set_pipeline(&self.pipeline);
             rp.set_bind_group(0, &self.ortho_bind_group, &[]);
             rp.set_vertex_buffer(0, vb.slice(..));
-            rp.draw(0..vertices.len() as u32, 0..1);
+            // 未裁剪的批次仅在之前设置过 scissor 时恢复为整屏
+            let mut current = None;
+            for (range, scissor) in batches {
+                if scissor != current {
+                    let [x, y, w, h] = scissor.unwrap_or([0, 0, screen_width as u32, screen_height as u32]);
+                    rp.set_scissor_rect(x, y, w, h);
+                    current = scissor;
+                }
+                rp.draw(range, 0..1);
+            }
         }
     }
 }
+
+/// 为一个 UI 节点生成 6 个顶点（2 个三角形），不可见或空矩形的节点不生成顶点
+fn push_node_vertices(vertices: &mut Vec<UiVertex>, node: &UiNode) {
+    if !node.visible || node.computed_rect[2] <= 0.0 || node.computed_rect[3] <= 0.0 {
+        return;
+    }
+    let [x, y, w, h] = node.computed_rect;
+    let params = [node.corner_radius, node.border_width, 0.0, 0.0];
+
+    let corners = [
+        [0.0f32, 0.0], [1.0, 0.0], [1.0, 1.0],
+        [0.0, 0.0], [1.0, 1.0], [0.0, 1.0],
+    ];
+    for corner in &corners {
+        vertices.push(UiVertex {
+            position: *corner,
+            rect_min: [x, y],
+            rect_size: [w, h],
+            color: node.background_color,
+            border_color: node.border_color,
+            params,
+        });
+    }
+}
+
+/// 将裁剪矩形限制在屏幕内并转换为整数 scissor 矩形，空矩形返回 `None`
+fn scissor_rect(clip: [f32; 4], screen_width: f32, screen_height: f32) -> Option<[u32; 4]> {
+    let x0 = clip[0].max(0.0).floor();
+    let y0 = clip[1].max(0.0).floor();
+    let x1 = (clip[0] + clip[2]).min(screen_width).ceil();
+    let y1 = (clip[1] + clip[3]).min(screen_height).ceil();
+    (x1 > x0 && y1 > y0).then_some([x0 as u32, y0 as u32, (x1 - x0) as u32, (y1 - y0) as u32])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scissor_rect_clamps_to_screen() {
+        assert_eq!(scissor_rect([10.0, 20.0, 100.0, 50.0], 800.0, 600.0), Some([10, 20, 100, 50]));
+        assert_eq!(scissor_rect([-10.0, 580.0, 100.0, 50.0], 800.0, 600.0), Some([0, 580, 90, 20]));
+        assert_eq!(scissor_rect([900.0, 0.0, 10.0, 10.0], 800.0, 600.0), None);
+    }
+}
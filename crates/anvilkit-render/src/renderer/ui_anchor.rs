@@ -0,0 +1,283 @@
+//! # UI 锚点布局
+//!
+//! [`UiAnchor`] 将 [`UiNode`] 固定到屏幕（或安全区）的边或角，并以逻辑像素指定尺寸与边距。
+//! [`ui_anchor_system`] 在窗口尺寸、DPI 缩放或安全区变化时重新计算 `computed_rect`（物理像素），
+//! HUD 因此能适应分辨率切换和带刘海的设备。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::ui_anchor::{Anchor, AnchorSpace, Margin, UiAnchor, UiViewport, anchored_rect};
+//! use glam::Vec2;
+//!
+//! // 右上角 200x40 的血条，距边缘 16 逻辑像素，避开刘海
+//! let hud = UiAnchor::new(Anchor::TopRight, Vec2::new(200.0, 40.0))
+//!     .with_margin(Margin::all(16.0))
+//!     .in_space(AnchorSpace::SafeArea);
+//!
+//! let viewport = UiViewport {
+//!     physical_size: Vec2::new(2000.0, 1000.0),
+//!     scale_factor: 2.0,
+//!     safe_area: Margin { top: 20.0, ..Margin::ZERO },
+//! };
+//! assert_eq!(anchored_rect(&hud, &viewport), [1568.0, 72.0, 400.0, 80.0]);
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+
+use super::state::RenderState;
+use super::ui::UiNode;
+use crate::window::{WindowResized, WindowScaleFactorChanged};
+
+/// 锚点：节点对齐到容器的哪个边或角
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Anchor {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// 锚点在容器中的归一化位置（0 = 左/上，1 = 右/下）
+    pub fn factors(self) -> Vec2 {
+        match self {
+            Self::TopLeft => Vec2::new(0.0, 0.0),
+            Self::Top => Vec2::new(0.5, 0.0),
+            Self::TopRight => Vec2::new(1.0, 0.0),
+            Self::Left => Vec2::new(0.0, 0.5),
+            Self::Center => Vec2::new(0.5, 0.5),
+            Self::Right => Vec2::new(1.0, 0.5),
+            Self::BottomLeft => Vec2::new(0.0, 1.0),
+            Self::Bottom => Vec2::new(0.5, 1.0),
+            Self::BottomRight => Vec2::new(1.0, 1.0),
+        }
+    }
+}
+
+/// 四边距离（逻辑像素）
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Margin {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl Margin {
+    /// 全部为 0
+    pub const ZERO: Self = Self { left: 0.0, top: 0.0, right: 0.0, bottom: 0.0 };
+
+    /// 四边相同
+    pub fn all(value: f32) -> Self {
+        Self { left: value, top: value, right: value, bottom: value }
+    }
+
+    /// 水平 / 垂直对称
+    pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self { left: horizontal, top: vertical, right: horizontal, bottom: vertical }
+    }
+}
+
+/// 锚点参照区域
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnchorSpace {
+    /// 整个视口
+    #[default]
+    Viewport,
+    /// 扣除 [`UiViewport::safe_area`] 后的安全区
+    SafeArea,
+}
+
+/// 锚点布局组件
+///
+/// 与 [`UiNode`] 一起使用；`UiNode::computed_rect` 由 [`ui_anchor_system`] 维护。
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[require(UiNode)]
+pub struct UiAnchor {
+    /// 对齐的边或角
+    pub anchor: Anchor,
+    /// 与锚定边的距离（逻辑像素）；居中轴上左右（上下）边距之差决定偏移
+    pub margin: Margin,
+    /// 节点尺寸（逻辑像素）
+    pub size: Vec2,
+    /// 参照区域
+    pub space: AnchorSpace,
+}
+
+impl UiAnchor {
+    /// 创建锚点布局
+    pub fn new(anchor: Anchor, size: Vec2) -> Self {
+        Self { anchor, margin: Margin::ZERO, size, space: AnchorSpace::Viewport }
+    }
+
+    /// 设置边距
+    pub fn with_margin(mut self, margin: Margin) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// 设置参照区域
+    pub fn in_space(mut self, space: AnchorSpace) -> Self {
+        self.space = space;
+        self
+    }
+}
+
+/// UI 视口信息
+///
+/// 由 [`ui_anchor_system`] 根据窗口事件与 `RenderState` 维护；
+/// `safe_area` 由平台层或游戏写入（例如移动设备的刘海、圆角区域）。
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct UiViewport {
+    /// 视口尺寸（物理像素）
+    pub physical_size: Vec2,
+    /// DPI 缩放因子
+    pub scale_factor: f32,
+    /// 安全区内缩（逻辑像素）
+    pub safe_area: Margin,
+}
+
+impl Default for UiViewport {
+    fn default() -> Self {
+        Self { physical_size: Vec2::new(1280.0, 720.0), scale_factor: 1.0, safe_area: Margin::ZERO }
+    }
+}
+
+impl UiViewport {
+    /// 视口尺寸（逻辑像素）
+    pub fn logical_size(&self) -> Vec2 {
+        self.physical_size / self.scale_factor.max(f32::EPSILON)
+    }
+
+    /// 参照区域矩形 `[x, y, w, h]`（物理像素）
+    pub fn rect(&self, space: AnchorSpace) -> [f32; 4] {
+        match space {
+            AnchorSpace::Viewport => [0.0, 0.0, self.physical_size.x, self.physical_size.y],
+            AnchorSpace::SafeArea => {
+                let s = self.scale_factor;
+                let inset = &self.safe_area;
+                [
+                    inset.left * s,
+                    inset.top * s,
+                    (self.physical_size.x - (inset.left + inset.right) * s).max(0.0),
+                    (self.physical_size.y - (inset.top + inset.bottom) * s).max(0.0),
+                ]
+            }
+        }
+    }
+}
+
+/// 计算锚点节点的矩形 `[x, y, w, h]`（物理像素）
+pub fn anchored_rect(anchor: &UiAnchor, viewport: &UiViewport) -> [f32; 4] {
+    let [cx, cy, cw, ch] = viewport.rect(anchor.space);
+    let s = viewport.scale_factor;
+    let f = anchor.anchor.factors();
+    let size = anchor.size * s;
+    let m = &anchor.margin;
+
+    // 左/上锚点加前边距，右/下锚点减后边距，居中时两者各取一半
+    let x = cx + f.x * (cw - size.x) + ((1.0 - f.x) * m.left - f.x * m.right) * s;
+    let y = cy + f.y * (ch - size.y) + ((1.0 - f.y) * m.top - f.y * m.bottom) * s;
+    [x, y, size.x, size.y]
+}
+
+/// 锚点布局系统 (PostUpdate)
+///
+/// 视口变化时重算全部锚点节点，否则只处理 `UiAnchor` 变化的节点。
+pub fn ui_anchor_system(
+    mut resized: EventReader<WindowResized>,
+    mut rescaled: EventReader<WindowScaleFactorChanged>,
+    render_state: Option<Res<RenderState>>,
+    mut viewport: ResMut<UiViewport>,
+    mut query: Query<(Ref<UiAnchor>, &mut UiNode)>,
+) {
+    let mut size = viewport.physical_size;
+    if let Some(event) = resized.read().last() {
+        size = Vec2::new(event.width as f32, event.height as f32);
+    }
+    if let Some(rs) = render_state {
+        size = Vec2::new(rs.surface_size.0 as f32, rs.surface_size.1 as f32);
+    }
+    if size != viewport.physical_size && size.x > 0.0 && size.y > 0.0 {
+        viewport.physical_size = size;
+    }
+    if let Some(event) = rescaled.read().last() {
+        let scale = event.scale_factor as f32;
+        if scale != viewport.scale_factor {
+            viewport.scale_factor = scale;
+        }
+    }
+
+    let viewport_changed = viewport.is_changed();
+    for (anchor, mut node) in query.iter_mut() {
+        if viewport_changed || anchor.is_changed() {
+            node.computed_rect = anchored_rect(&anchor, &viewport);
+        }
+    }
+}
+
+/// UI 锚点布局插件
+pub struct UiAnchorPlugin;
+
+impl Plugin for UiAnchorPlugin {
+    fn build(&self, app: &mut App) {
+        crate::window::runner::add_window_events(app);
+        app.init_resource::<UiViewport>()
+            .add_systems(bevy_app::PostUpdate, ui_anchor_system);
+    }
+
+    fn name(&self) -> &str {
+        "UiAnchorPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchored_rect_corners_and_center() {
+        let viewport = UiViewport { physical_size: Vec2::new(800.0, 600.0), ..Default::default() };
+        let anchor = |a| UiAnchor::new(a, Vec2::new(100.0, 50.0)).with_margin(Margin::all(10.0));
+
+        assert_eq!(anchored_rect(&anchor(Anchor::TopLeft), &viewport), [10.0, 10.0, 100.0, 50.0]);
+        assert_eq!(anchored_rect(&anchor(Anchor::BottomRight), &viewport), [690.0, 540.0, 100.0, 50.0]);
+        assert_eq!(anchored_rect(&anchor(Anchor::Center), &viewport), [350.0, 275.0, 100.0, 50.0]);
+        assert_eq!(anchored_rect(&anchor(Anchor::Bottom), &viewport), [350.0, 540.0, 100.0, 50.0]);
+    }
+
+    #[test]
+    fn test_safe_area_and_dpi() {
+        let viewport = UiViewport {
+            physical_size: Vec2::new(1000.0, 500.0),
+            scale_factor: 2.0,
+            safe_area: Margin { left: 30.0, ..Margin::ZERO },
+        };
+        let node = UiAnchor::new(Anchor::Left, Vec2::new(50.0, 50.0)).in_space(AnchorSpace::SafeArea);
+        assert_eq!(anchored_rect(&node, &viewport), [60.0, 200.0, 100.0, 100.0]);
+        assert_eq!(viewport.logical_size(), Vec2::new(500.0, 250.0));
+    }
+
+    #[test]
+    fn test_recomputed_on_resize_and_scale() {
+        let mut app = App::new();
+        app.add_plugins(UiAnchorPlugin);
+        let e = app.world_mut().spawn(UiAnchor::new(Anchor::BottomRight, Vec2::new(10.0, 10.0))).id();
+        app.update();
+        assert_eq!(app.world().get::<UiNode>(e).unwrap().computed_rect, [1270.0, 710.0, 10.0, 10.0]);
+
+        app.world_mut().send_event(WindowResized { width: 400, height: 300 });
+        app.world_mut().send_event(WindowScaleFactorChanged { scale_factor: 2.0 });
+        app.update();
+        assert_eq!(app.world().get::<UiNode>(e).unwrap().computed_rect, [380.0, 280.0, 20.0, 20.0]);
+    }
+}
@@ -0,0 +1,246 @@
+//! # UI 数据绑定
+//!
+//! 声明式地把 ECS 组件 / 资源的值绑定到 `UiNode`，依赖变化检测自动刷新，
+//! 无需为每个 HUD 元素手写"同步 UI"系统。
+//!
+//! - [`UiBinding<C>`]：绑定某个实体上的组件 `C`
+//! - [`UiResourceBinding<R>`]：绑定资源 `R`
+//! - [`UiBindingAppExt`]：为具体类型注册同步系统（每个类型一次）
+//!
+//! 同步只在源数据变化（或绑定本身新增 / 修改）时执行。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::ui::UiNode;
+//! use anvilkit_render::renderer::ui_binding::{UiBinding, UiBindingAppExt};
+//! use bevy_app::App;
+//! use bevy_ecs::prelude::*;
+//!
+//! #[derive(Component)]
+//! struct Health(u32);
+//!
+//! let mut app = App::new();
+//! app.add_ui_binding::<Health>();
+//!
+//! let player = app.world_mut().spawn(Health(100)).id();
+//! let label = app.world_mut().spawn(UiBinding::<Health>::text(player, |h| format!("HP: {}", h.0))).id();
+//! app.update();
+//! assert_eq!(app.world().get::<UiNode>(label).unwrap().text.as_ref().unwrap().content, "HP: 100");
+//! ```
+
+use std::any::TypeId;
+use std::collections::HashSet;
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+
+use super::ui::{UiNode, UiText};
+
+type Apply<T> = Box<dyn Fn(&T, &mut UiNode) + Send + Sync>;
+type Format<T> = Box<dyn Fn(&T) -> String + Send + Sync>;
+
+/// 用格式化结果更新节点文本，内容未变时不触发 `UiNode` 的变化检测
+fn set_text(node: &mut Mut<UiNode>, content: String) {
+    if node.text.as_ref().is_some_and(|text| text.content == content) {
+        return;
+    }
+    if let Some(text) = node.text.as_mut() {
+        text.content = content;
+    } else {
+        node.text = Some(UiText::new(content));
+    }
+}
+
+/// 组件绑定
+///
+/// 附加到 UI 节点上，`source` 实体的组件 `C` 变化时调用绑定函数更新该节点。
+#[derive(Component)]
+#[require(UiNode)]
+pub struct UiBinding<C: Component> {
+    /// 数据来源实体
+    pub source: Entity,
+    apply: Apply<C>,
+    text: Option<Format<C>>,
+}
+
+impl<C: Component> UiBinding<C> {
+    /// 任意属性绑定：`apply` 直接修改节点（颜色、可见性等）
+    pub fn new(source: Entity, apply: impl Fn(&C, &mut UiNode) + Send + Sync + 'static) -> Self {
+        Self { source, apply: Box::new(apply), text: None }
+    }
+
+    /// 文本绑定：节点文本显示 `format` 的结果
+    pub fn text(source: Entity, format: impl Fn(&C) -> String + Send + Sync + 'static) -> Self {
+        Self { source, apply: Box::new(|_, _| {}), text: Some(Box::new(format)) }
+    }
+
+    fn sync(&self, value: &C, node: &mut Mut<UiNode>) {
+        match &self.text {
+            Some(format) => set_text(node, format(value)),
+            None => (self.apply)(value, node),
+        }
+    }
+}
+
+/// 资源绑定
+///
+/// 资源 `R` 变化时调用绑定函数更新该节点。
+#[derive(Component)]
+#[require(UiNode)]
+pub struct UiResourceBinding<R: Resource> {
+    apply: Apply<R>,
+    text: Option<Format<R>>,
+}
+
+impl<R: Resource> UiResourceBinding<R> {
+    /// 任意属性绑定
+    pub fn new(apply: impl Fn(&R, &mut UiNode) + Send + Sync + 'static) -> Self {
+        Self { apply: Box::new(apply), text: None }
+    }
+
+    /// 文本绑定
+    pub fn text(format: impl Fn(&R) -> String + Send + Sync + 'static) -> Self {
+        Self { apply: Box::new(|_, _| {}), text: Some(Box::new(format)) }
+    }
+
+    fn sync(&self, value: &R, node: &mut Mut<UiNode>) {
+        match &self.text {
+            Some(format) => set_text(node, format(value)),
+            None => (self.apply)(value, node),
+        }
+    }
+}
+
+/// 组件绑定同步系统 (PostUpdate)
+pub fn sync_ui_bindings<C: Component>(
+    sources: Query<Ref<C>>,
+    mut targets: Query<(Ref<UiBinding<C>>, &mut UiNode)>,
+) {
+    for (binding, mut node) in targets.iter_mut() {
+        let Ok(value) = sources.get(binding.source) else { continue };
+        if value.is_changed() || binding.is_changed() {
+            binding.sync(&value, &mut node);
+        }
+    }
+}
+
+/// 资源绑定同步系统 (PostUpdate)
+pub fn sync_ui_resource_bindings<R: Resource>(
+    resource: Option<Res<R>>,
+    mut targets: Query<(Ref<UiResourceBinding<R>>, &mut UiNode)>,
+) {
+    let Some(resource) = resource else { return };
+    let changed = resource.is_changed();
+    for (binding, mut node) in targets.iter_mut() {
+        if changed || binding.is_changed() {
+            binding.sync(&resource, &mut node);
+        }
+    }
+}
+
+/// UI 绑定同步系统集合，位于 `PostUpdate`
+///
+/// 需要读取绑定结果的布局系统可排在此集合之后。
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UiBindingSet;
+
+/// 已注册的绑定类型
+#[derive(Resource, Default)]
+struct RegisteredUiBindings(HashSet<TypeId>);
+
+/// UI 绑定注册扩展
+pub trait UiBindingAppExt {
+    /// 为组件类型 `C` 注册 [`UiBinding<C>`] 同步系统
+    fn add_ui_binding<C: Component>(&mut self) -> &mut Self;
+
+    /// 为资源类型 `R` 注册 [`UiResourceBinding<R>`] 同步系统
+    fn add_ui_resource_binding<R: Resource>(&mut self) -> &mut Self;
+}
+
+impl UiBindingAppExt for App {
+    fn add_ui_binding<C: Component>(&mut self) -> &mut Self {
+        if register::<UiBinding<C>>(self) {
+            self.add_systems(bevy_app::PostUpdate, sync_ui_bindings::<C>.in_set(UiBindingSet));
+        }
+        self
+    }
+
+    fn add_ui_resource_binding<R: Resource>(&mut self) -> &mut Self {
+        if register::<UiResourceBinding<R>>(self) {
+            self.add_systems(bevy_app::PostUpdate, sync_ui_resource_bindings::<R>.in_set(UiBindingSet));
+        }
+        self
+    }
+}
+
+/// 记录绑定类型，首次注册时返回 `true`
+fn register<T: 'static>(app: &mut App) -> bool {
+    app.world_mut()
+        .get_resource_or_insert_with(RegisteredUiBindings::default)
+        .0
+        .insert(TypeId::of::<T>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component)]
+    struct Health(u32);
+
+    #[derive(Resource)]
+    struct Score(u32);
+
+    #[test]
+    fn test_component_text_binding_follows_changes() {
+        let mut app = App::new();
+        app.add_ui_binding::<Health>().add_ui_binding::<Health>();
+
+        let player = app.world_mut().spawn(Health(100)).id();
+        let label = app.world_mut().spawn(UiBinding::<Health>::text(player, |h| format!("HP: {}", h.0))).id();
+        app.update();
+        let text = |app: &App| app.world().get::<UiNode>(label).unwrap().text.as_ref().unwrap().content.clone();
+        assert_eq!(text(&app), "HP: 100");
+
+        app.world_mut().get_mut::<Health>(player).unwrap().0 = 40;
+        app.update();
+        assert_eq!(text(&app), "HP: 40");
+
+        // 源实体销毁后保留最后的值
+        app.world_mut().despawn(player);
+        app.update();
+        assert_eq!(text(&app), "HP: 40");
+    }
+
+    #[test]
+    fn test_unchanged_source_does_not_touch_node() {
+        let mut app = App::new();
+        app.add_ui_binding::<Health>();
+        let player = app.world_mut().spawn(Health(1)).id();
+        let label = app.world_mut().spawn(UiBinding::<Health>::text(player, |h| h.0.to_string())).id();
+        app.update();
+
+        // 手动改动节点后，源未变化则不会被覆盖
+        app.world_mut().get_mut::<UiNode>(label).unwrap().text = Some(UiText::new("manual"));
+        app.update();
+        assert_eq!(app.world().get::<UiNode>(label).unwrap().text.as_ref().unwrap().content, "manual");
+    }
+
+    #[test]
+    fn test_resource_and_property_binding() {
+        let mut app = App::new();
+        app.add_ui_resource_binding::<Score>().add_ui_binding::<Health>();
+        app.insert_resource(Score(3));
+
+        let score = app.world_mut().spawn(UiResourceBinding::<Score>::text(|s| format!("Score: {}", s.0))).id();
+        let player = app.world_mut().spawn(Health(10)).id();
+        let bar = app.world_mut().spawn(UiBinding::<Health>::new(player, |h, node| node.visible = h.0 > 0)).id();
+        app.update();
+        assert_eq!(app.world().get::<UiNode>(score).unwrap().text.as_ref().unwrap().content, "Score: 3");
+        assert!(app.world().get::<UiNode>(bar).unwrap().visible);
+
+        app.world_mut().resource_mut::<Score>().0 = 7;
+        app.world_mut().get_mut::<Health>(player).unwrap().0 = 0;
+        app.update();
+        assert_eq!(app.world().get::<UiNode>(score).unwrap().text.as_ref().unwrap().content, "Score: 7");
+        assert!(!app.world().get::<UiNode>(bar).unwrap().visible);
+    }
+}
@@ -0,0 +1,646 @@
+//! # 通知与模态对话框
+//!
+//! 两个现成的 UI 辅助：
+//!
+//! - [`Toasts`]：`toasts.show("Saved!", duration)` 入队一条提示，自动堆叠、淡入淡出并在到期后移除。
+//! - [`ModalDialog`]：标题 + 正文 + 按钮的模态对话框构建器，按钮点击（或 Enter / Escape）
+//!   以 [`ModalResponse`] 事件返回，随后对话框自动关闭。
+//!
+//! 两者都生成普通的 [`UiNode`] 实体，定位基于 [`UiAnchor`]，由 [`UiNotifyPlugin`] 驱动。
+//! 对话框各部分带有 [`RenderOrder`]，收集 UI 节点绘制时按它排序即可保证遮罩在面板之下、
+//! 后打开的对话框在上。
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use anvilkit_render::renderer::ui_notify::{toast_alpha, Toasts};
+//!
+//! let mut toasts = Toasts::default();
+//! toasts.show("Saved!", Duration::from_secs(2));
+//! assert_eq!(toasts.pending_len(), 1);
+//!
+//! // 淡入中途、完全显示、淡出中途
+//! assert!(toast_alpha(0.1, 2.0, 0.25) < 1.0);
+//! assert_eq!(toast_alpha(1.0, 2.0, 0.25), 1.0);
+//! assert!(toast_alpha(1.9, 2.0, 0.25) < 1.0);
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use anvilkit_core::time::DeltaTime;
+use anvilkit_input::input_state::{InputState, KeyCode, MouseButton};
+
+use crate::component::RenderOrder;
+use super::ui::{UiNode, UiText};
+use super::ui_anchor::{ui_anchor_system, Anchor, Margin, UiAnchor, UiAnchorPlugin, UiViewport};
+
+// ---------------------------------------------------------------------------
+//  Toasts
+// ---------------------------------------------------------------------------
+
+/// 提示条外观与布局
+#[derive(Debug, Clone)]
+pub struct ToastStyle {
+    /// 堆叠起点（底部锚点向上堆叠，其余向下堆叠）
+    pub anchor: Anchor,
+    /// 与锚定边的距离（逻辑像素）
+    pub margin: Margin,
+    /// 单条尺寸（逻辑像素）
+    pub size: Vec2,
+    /// 条目间距（逻辑像素）
+    pub spacing: f32,
+    /// 淡入 / 淡出时长（秒）
+    pub fade: f32,
+    /// 堆叠位移的跟随速度（1/秒，越大越快）
+    pub slide_speed: f32,
+    /// 同时显示的最大条数，其余排队等待
+    pub max_visible: usize,
+    /// 背景色
+    pub background: [f32; 4],
+    /// 文字颜色
+    pub text_color: [f32; 4],
+    /// 字号
+    pub font_size: f32,
+}
+
+impl Default for ToastStyle {
+    fn default() -> Self {
+        Self {
+            anchor: Anchor::Bottom,
+            margin: Margin::all(24.0),
+            size: Vec2::new(320.0, 44.0),
+            spacing: 8.0,
+            fade: 0.25,
+            slide_speed: 12.0,
+            max_visible: 4,
+            background: [0.08, 0.08, 0.1, 0.9],
+            text_color: [1.0, 1.0, 1.0, 1.0],
+            font_size: 16.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ToastRequest {
+    message: String,
+    duration: f32,
+}
+
+/// 提示队列 (Resource)
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Toasts {
+    /// 外观与布局
+    pub style: ToastStyle,
+    pending: VecDeque<ToastRequest>,
+    next_seq: u64,
+}
+
+impl Toasts {
+    /// 显示一条提示，持续 `duration`（含淡入淡出）
+    pub fn show(&mut self, message: impl Into<String>, duration: Duration) {
+        self.pending.push_back(ToastRequest { message: message.into(), duration: duration.as_secs_f32() });
+    }
+
+    /// 尚未显示的提示数
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// 丢弃尚未显示的提示
+    pub fn clear_pending(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// 正在显示的提示条
+#[derive(Debug, Clone, Component)]
+#[require(UiNode)]
+pub struct Toast {
+    /// 已显示时长（秒）
+    pub elapsed: f32,
+    /// 总时长（秒）
+    pub duration: f32,
+    seq: u64,
+    offset: Option<f32>,
+}
+
+/// 提示条不透明度：开头淡入、结尾淡出，中间为 1（smoothstep 缓动）
+pub fn toast_alpha(elapsed: f32, duration: f32, fade: f32) -> f32 {
+    if fade <= 0.0 {
+        return if elapsed < duration { 1.0 } else { 0.0 };
+    }
+    let t = (elapsed / fade).min((duration - elapsed) / fade).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// 提示系统 (Update)
+///
+/// 从队列生成提示实体，推进计时、堆叠位移与透明度，并移除到期的提示。
+/// 最新的提示紧贴锚定边，较早的依次让开。
+pub fn toast_system(
+    mut commands: Commands,
+    mut toasts: ResMut<Toasts>,
+    dt: Option<Res<DeltaTime>>,
+    mut query: Query<(Entity, &mut Toast, &mut UiAnchor, &mut UiNode)>,
+) {
+    let dt = dt.map_or(1.0 / 60.0, |d| d.0);
+    let style = toasts.style.clone();
+
+    let mut active: Vec<_> = query.iter_mut().collect();
+    let mut visible = active.len();
+    while visible < style.max_visible {
+        let Some(request) = toasts.pending.pop_front() else { break };
+        let seq = toasts.next_seq;
+        toasts.next_seq += 1;
+        commands.spawn((
+            Toast { elapsed: 0.0, duration: request.duration, seq, offset: None },
+            UiAnchor::new(style.anchor, style.size).with_margin(style.margin),
+            UiNode {
+                text: Some(UiText::new(request.message).with_font_size(style.font_size).with_color([0.0; 4])),
+                background_color: [0.0; 4],
+                corner_radius: 6.0,
+                ..Default::default()
+            },
+        ));
+        visible += 1;
+    }
+
+    active.sort_by_key(|a| std::cmp::Reverse(a.1.seq));
+    let from_bottom = style.anchor.factors().y >= 1.0;
+    let follow = 1.0 - (-style.slide_speed * dt).exp();
+    let mut slot = 0.0;
+    for (entity, toast, anchor, node) in active.iter_mut() {
+        toast.elapsed += dt;
+        if toast.elapsed >= toast.duration {
+            commands.entity(*entity).despawn();
+            continue;
+        }
+
+        let offset = match toast.offset {
+            Some(current) => current + (slot - current) * follow,
+            None => slot,
+        };
+        toast.offset = Some(offset);
+        slot += style.size.y + style.spacing;
+
+        let mut margin = style.margin;
+        if from_bottom {
+            margin.bottom += offset;
+        } else {
+            margin.top += offset;
+        }
+        if anchor.margin != margin {
+            anchor.margin = margin;
+        }
+
+        let alpha = toast_alpha(toast.elapsed, toast.duration, style.fade);
+        node.background_color = [style.background[0], style.background[1], style.background[2], style.background[3] * alpha];
+        if let Some(text) = node.text.as_mut() {
+            text.color = [style.text_color[0], style.text_color[1], style.text_color[2], style.text_color[3] * alpha];
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  Modal dialogs
+// ---------------------------------------------------------------------------
+
+const BACKDROP_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.55];
+const PANEL_COLOR: [f32; 4] = [0.12, 0.12, 0.15, 1.0];
+const BUTTON_COLOR: [f32; 4] = [0.22, 0.24, 0.3, 1.0];
+const BUTTON_DEFAULT_COLOR: [f32; 4] = [0.25, 0.45, 0.8, 1.0];
+
+/// 对话框绘制顺序的起点，高于未设置 `RenderOrder` 的普通 UI
+const MODAL_RENDER_ORDER: i64 = 1 << 32;
+/// 下一个对话框的绘制顺序；每个对话框占用遮罩、面板、内容三级
+static NEXT_MODAL_ORDER: AtomicI64 = AtomicI64::new(MODAL_RENDER_ORDER);
+
+/// 对话框按钮被选择时发送
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct ModalResponse {
+    /// 对话框实体（[`ModalDialog::spawn`] 的返回值）
+    pub dialog: Entity,
+    /// 按钮序号
+    pub button: usize,
+    /// 按钮文字
+    pub label: String,
+}
+
+/// 模态对话框构建器
+///
+/// ```rust,ignore
+/// let dialog = ModalDialog::new("Quit?")
+///     .with_message("Unsaved progress will be lost.")
+///     .with_button("Quit")
+///     .with_button("Cancel")
+///     .with_cancel(1)
+///     .spawn(&mut commands);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ModalDialog {
+    /// 标题
+    pub title: String,
+    /// 正文
+    pub message: String,
+    /// 按钮文字（从左到右）
+    pub buttons: Vec<String>,
+    /// 面板尺寸（逻辑像素）
+    pub size: Vec2,
+    /// Enter 触发的按钮
+    pub default_button: Option<usize>,
+    /// Escape 触发的按钮
+    pub cancel_button: Option<usize>,
+}
+
+impl ModalDialog {
+    /// 以标题创建对话框
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            message: String::new(),
+            buttons: Vec::new(),
+            size: Vec2::new(420.0, 200.0),
+            default_button: None,
+            cancel_button: None,
+        }
+    }
+
+    /// Builder: 设置正文
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Builder: 追加按钮
+    pub fn with_button(mut self, label: impl Into<String>) -> Self {
+        self.buttons.push(label.into());
+        self
+    }
+
+    /// Builder: 设置面板尺寸
+    pub fn with_size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Builder: Enter 触发的按钮
+    pub fn with_default(mut self, button: usize) -> Self {
+        self.default_button = Some(button);
+        self
+    }
+
+    /// Builder: Escape 触发的按钮
+    pub fn with_cancel(mut self, button: usize) -> Self {
+        self.cancel_button = Some(button);
+        self
+    }
+
+    /// 生成对话框实体（面板、遮罩、标题、正文与按钮），返回面板实体
+    ///
+    /// 绘制顺序：遮罩 → 面板 → 标题 / 正文 / 按钮，并位于之前打开的对话框之上。
+    pub fn spawn(self, commands: &mut Commands) -> Entity {
+        let order = NEXT_MODAL_ORDER.fetch_add(3, Ordering::Relaxed);
+        let content = RenderOrder(order + 2);
+        let dialog = commands
+            .spawn((
+                UiAnchor::new(Anchor::Center, self.size),
+                UiNode { background_color: PANEL_COLOR, corner_radius: 8.0, ..Default::default() },
+                RenderOrder(order + 1),
+            ))
+            .id();
+
+        commands.spawn((
+            ModalPart { dialog, role: ModalRole::Backdrop },
+            UiNode { background_color: BACKDROP_COLOR, ..Default::default() },
+            RenderOrder(order),
+        ));
+        commands.spawn((
+            ModalPart { dialog, role: ModalRole::Title },
+            UiNode { text: Some(UiText::new(self.title).with_font_size(22.0)), ..Default::default() },
+            content,
+        ));
+        commands.spawn((
+            ModalPart { dialog, role: ModalRole::Message },
+            UiNode { text: Some(UiText::new(self.message)), ..Default::default() },
+            content,
+        ));
+        for (index, label) in self.buttons.iter().enumerate() {
+            let color = if self.default_button == Some(index) { BUTTON_DEFAULT_COLOR } else { BUTTON_COLOR };
+            commands.spawn((
+                ModalPart { dialog, role: ModalRole::Button(index) },
+                UiNode {
+                    text: Some(UiText::new(label.clone())),
+                    background_color: color,
+                    corner_radius: 4.0,
+                    ..Default::default()
+                },
+                content,
+            ));
+        }
+
+        commands.entity(dialog).insert(Modal {
+            buttons: self.buttons,
+            default_button: self.default_button,
+            cancel_button: self.cancel_button,
+        });
+        dialog
+    }
+}
+
+/// 模态对话框面板
+#[derive(Debug, Clone, Component)]
+#[require(UiNode, RenderOrder)]
+pub struct Modal {
+    /// 按钮文字
+    pub buttons: Vec<String>,
+    /// Enter 触发的按钮
+    pub default_button: Option<usize>,
+    /// Escape 触发的按钮
+    pub cancel_button: Option<usize>,
+}
+
+/// 对话框组成部分的角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalRole {
+    /// 全屏遮罩
+    Backdrop,
+    /// 标题
+    Title,
+    /// 正文
+    Message,
+    /// 第 n 个按钮
+    Button(usize),
+}
+
+/// 对话框组成部分，矩形由 [`modal_layout_system`] 按面板位置计算
+#[derive(Debug, Clone, Copy, Component)]
+#[require(UiNode)]
+pub struct ModalPart {
+    /// 所属对话框
+    pub dialog: Entity,
+    /// 角色
+    pub role: ModalRole,
+}
+
+/// 对话框内部布局结果（物理像素 `[x, y, w, h]`）
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalLayout {
+    /// 标题区域
+    pub title: [f32; 4],
+    /// 正文区域
+    pub message: [f32; 4],
+    /// 按钮区域（从左到右）
+    pub buttons: Vec<[f32; 4]>,
+}
+
+/// 计算面板内部布局：标题在上、按钮行在底部等分宽度、正文占据其余空间
+pub fn modal_layout(panel: [f32; 4], button_count: usize, scale: f32) -> ModalLayout {
+    let [x, y, w, h] = panel;
+    let pad = 16.0 * scale;
+    let gap = 8.0 * scale;
+    let title_h = 28.0 * scale;
+    let button_h = 36.0 * scale;
+    let inner_w = (w - 2.0 * pad).max(0.0);
+
+    let title = [x + pad, y + pad, inner_w, title_h];
+    let button_y = y + h - pad - button_h;
+    let message_y = y + pad + title_h + gap;
+    let message = [x + pad, message_y, inner_w, (button_y - gap - message_y).max(0.0)];
+
+    let buttons = if button_count == 0 {
+        Vec::new()
+    } else {
+        let button_w = ((inner_w - gap * (button_count - 1) as f32) / button_count as f32).max(0.0);
+        (0..button_count)
+            .map(|i| [x + pad + i as f32 * (button_w + gap), button_y, button_w, button_h])
+            .collect()
+    };
+    ModalLayout { title, message, buttons }
+}
+
+fn contains(rect: [f32; 4], point: Vec2) -> bool {
+    point.x >= rect[0] && point.y >= rect[1] && point.x <= rect[0] + rect[2] && point.y <= rect[1] + rect[3]
+}
+
+/// 对话框布局系统 (PostUpdate，锚点布局之后)
+///
+/// 面板已被移除的组成部分一并清理。
+pub fn modal_layout_system(
+    mut commands: Commands,
+    viewport: Res<UiViewport>,
+    dialogs: Query<(&Modal, &UiNode), Without<ModalPart>>,
+    mut parts: Query<(Entity, &ModalPart, &mut UiNode)>,
+) {
+    for (entity, part, mut node) in parts.iter_mut() {
+        let Ok((modal, panel)) = dialogs.get(part.dialog) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let layout = modal_layout(panel.computed_rect, modal.buttons.len(), viewport.scale_factor);
+        let rect = match part.role {
+            ModalRole::Backdrop => [0.0, 0.0, viewport.physical_size.x, viewport.physical_size.y],
+            ModalRole::Title => layout.title,
+            ModalRole::Message => layout.message,
+            ModalRole::Button(i) => layout.buttons.get(i).copied().unwrap_or([0.0; 4]),
+        };
+        if node.computed_rect != rect {
+            node.computed_rect = rect;
+        }
+    }
+}
+
+/// 对话框输入系统 (Update)
+///
+/// 鼠标点击按钮、Enter（默认按钮）或 Escape（取消按钮）发送 [`ModalResponse`] 并关闭对话框。
+/// 只有最上层（最后打开）的对话框响应输入，下层对话框被其遮罩挡住。
+pub fn modal_input_system(
+    mut commands: Commands,
+    input: Option<Res<InputState>>,
+    dialogs: Query<(Entity, &Modal, &RenderOrder)>,
+    parts: Query<(&ModalPart, &UiNode)>,
+    mut responses: EventWriter<ModalResponse>,
+) {
+    let Some(input) = input else { return };
+    let Some((dialog, modal, _)) = dialogs.iter().max_by_key(|(_, _, order)| **order) else { return };
+
+    let mut chosen = None;
+    if input.is_mouse_just_pressed(MouseButton::Left) {
+        let cursor = input.mouse_position();
+        chosen = parts.iter().find_map(|(part, node)| match part.role {
+            ModalRole::Button(i) if part.dialog == dialog && contains(node.computed_rect, cursor) => Some(i),
+            _ => None,
+        });
+    }
+    if chosen.is_none() && input.is_key_just_pressed(KeyCode::Enter) {
+        chosen = modal.default_button;
+    }
+    if chosen.is_none() && input.is_key_just_pressed(KeyCode::Escape) {
+        chosen = modal.cancel_button;
+    }
+
+    let Some(button) = chosen.filter(|&i| i < modal.buttons.len()) else { return };
+    responses.send(ModalResponse { dialog, button, label: modal.buttons[button].clone() });
+    // 组成部分由布局系统在面板消失后清理
+    commands.entity(dialog).despawn();
+}
+
+/// 运行条件：是否有打开的模态对话框（可用于暂停游戏输入）
+pub fn any_modal_open(dialogs: Query<(), With<Modal>>) -> bool {
+    !dialogs.is_empty()
+}
+
+/// 通知与对话框插件
+///
+/// 初始化 [`Toasts`]、注册 [`ModalResponse`] 事件与相关系统；未添加 [`UiAnchorPlugin`] 时一并添加。
+pub struct UiNotifyPlugin;
+
+impl Plugin for UiNotifyPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UiAnchorPlugin>() {
+            app.add_plugins(UiAnchorPlugin);
+        }
+        app.init_resource::<Toasts>()
+            .add_event::<ModalResponse>()
+            .add_systems(bevy_app::Update, (toast_system, modal_input_system))
+            .add_systems(bevy_app::PostUpdate, modal_layout_system.after(ui_anchor_system));
+    }
+
+    fn name(&self) -> &str {
+        "UiNotifyPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toast_alpha_fades() {
+        assert_eq!(toast_alpha(0.0, 2.0, 0.5), 0.0);
+        assert_eq!(toast_alpha(0.25, 2.0, 0.5), 0.5);
+        assert_eq!(toast_alpha(1.0, 2.0, 0.5), 1.0);
+        assert_eq!(toast_alpha(2.0, 2.0, 0.5), 0.0);
+        assert_eq!(toast_alpha(0.0, 1.0, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_toasts_stack_and_expire() {
+        let mut app = App::new();
+        app.add_plugins(UiNotifyPlugin);
+        app.insert_resource(DeltaTime(0.1));
+        {
+            let mut toasts = app.world_mut().resource_mut::<Toasts>();
+            toasts.style.max_visible = 2;
+            toasts.show("first", Duration::from_secs_f32(0.45));
+            toasts.show("second", Duration::from_secs(5));
+            toasts.show("third", Duration::from_secs(5));
+        }
+        app.update();
+        assert_eq!(app.world().resource::<Toasts>().pending_len(), 1);
+
+        app.update();
+        let mut margins: Vec<(String, f32)> = app
+            .world_mut()
+            .query::<(&Toast, &UiAnchor, &UiNode)>()
+            .iter(app.world())
+            .map(|(_, anchor, node)| (node.text.as_ref().unwrap().content.clone(), anchor.margin.bottom))
+            .collect();
+        margins.sort_by(|a, b| a.1.total_cmp(&b.1));
+        // 最新的紧贴底边
+        assert_eq!(margins[0], ("second".to_string(), 24.0));
+        assert_eq!(margins[1], ("first".to_string(), 24.0 + 44.0 + 8.0));
+
+        for _ in 0..5 {
+            app.update();
+        }
+        let remaining: Vec<String> = app
+            .world_mut()
+            .query::<(&Toast, &UiNode)>()
+            .iter(app.world())
+            .map(|(_, node)| node.text.as_ref().unwrap().content.clone())
+            .collect();
+        assert!(!remaining.contains(&"first".to_string()));
+        assert!(remaining.contains(&"third".to_string()));
+    }
+
+    #[test]
+    fn test_modal_layout() {
+        let layout = modal_layout([100.0, 100.0, 232.0, 200.0], 2, 1.0);
+        assert_eq!(layout.title, [116.0, 116.0, 200.0, 28.0]);
+        assert_eq!(layout.buttons, vec![[116.0, 248.0, 96.0, 36.0], [220.0, 248.0, 96.0, 36.0]]);
+        assert_eq!(layout.message, [116.0, 152.0, 200.0, 88.0]);
+    }
+
+    #[test]
+    fn test_modal_click_sends_response_and_closes() {
+        let mut app = App::new();
+        app.add_plugins(UiNotifyPlugin);
+        app.insert_resource(InputState::new());
+
+        let dialog = ModalDialog::new("Quit?")
+            .with_button("Yes")
+            .with_button("No")
+            .with_cancel(1)
+            .spawn(&mut app.world_mut().commands());
+        app.world_mut().flush();
+        app.update();
+
+        // 遮罩、标题、正文与两个按钮
+        assert_eq!(app.world_mut().query::<&ModalPart>().iter(app.world()).count(), 5);
+        let no_rect = app
+            .world_mut()
+            .query::<(&ModalPart, &UiNode)>()
+            .iter(app.world())
+            .find(|(part, _)| part.role == ModalRole::Button(1))
+            .map(|(_, node)| node.computed_rect)
+            .unwrap();
+
+        {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.set_mouse_position(Vec2::new(no_rect[0] + 1.0, no_rect[1] + 1.0));
+            input.press_mouse(MouseButton::Left);
+        }
+        app.update();
+
+        let sent: Vec<_> = app.world_mut().resource_mut::<Events<ModalResponse>>().drain().collect();
+        assert_eq!(sent, vec![ModalResponse { dialog, button: 1, label: "No".into() }]);
+        assert!(app.world().get_entity(dialog).is_err());
+        assert_eq!(app.world_mut().query::<&ModalPart>().iter(app.world()).count(), 0);
+    }
+
+    #[test]
+    fn test_modal_draw_order_and_topmost_input() {
+        let mut app = App::new();
+        app.add_plugins(UiNotifyPlugin);
+        app.insert_resource(InputState::new());
+
+        let lower = ModalDialog::new("Lower").with_button("Ok").with_cancel(0).spawn(&mut app.world_mut().commands());
+        let upper = ModalDialog::new("Upper").with_button("Ok").with_cancel(0).spawn(&mut app.world_mut().commands());
+        app.world_mut().flush();
+        app.update();
+
+        let order = |app: &mut App, dialog: Entity, role: ModalRole| {
+            app.world_mut()
+                .query::<(&ModalPart, &RenderOrder)>()
+                .iter(app.world())
+                .find(|(part, _)| part.dialog == dialog && part.role == role)
+                .map(|(_, order)| *order)
+                .unwrap()
+        };
+        let panel = *app.world().get::<RenderOrder>(lower).unwrap();
+        assert!(order(&mut app, lower, ModalRole::Backdrop) < panel);
+        assert!(panel < order(&mut app, lower, ModalRole::Button(0)));
+        assert!(order(&mut app, lower, ModalRole::Button(0)) < order(&mut app, upper, ModalRole::Backdrop));
+
+        // Escape 只关闭最上层对话框
+        app.world_mut().resource_mut::<InputState>().press_key(KeyCode::Escape);
+        app.update();
+        let sent: Vec<_> = app.world_mut().resource_mut::<Events<ModalResponse>>().drain().collect();
+        assert_eq!(sent, vec![ModalResponse { dialog: upper, button: 0, label: "Ok".into() }]);
+        assert!(app.world().get_entity(lower).is_ok());
+    }
+}
@@ -0,0 +1,471 @@
+//! # UI 滚动视图
+//!
+//! [`ScrollView`] 是可滚动的裁剪容器，其内容由带 [`ScrollContent`] 的节点组成：
+//!
+//! - **内容测量**：视图的 `content_size` 由所有内容节点的局部矩形自动计算
+//! - **滚动输入**：鼠标悬停时滚轮滚动，按住左键拖拽，松开后按惯性减速（kinetic easing）
+//! - **聚焦输入**：点击视图获得焦点后，方向键与手柄方向键 / 右摇杆也可滚动
+//! - **裁剪**：内容节点获得 [`UiClip`]，渲染时交给 [`UiRenderer::render_with_clips`](super::ui::UiRenderer::render_with_clips) 做 scissor 裁剪
+//! - **滚动条**：[`ScrollView::scrollbar_thumbs`] 返回滑块矩形，[`scrollbar_nodes`] 直接生成 `UiNode`
+//!
+//! ```rust
+//! use anvilkit_render::renderer::ui::UiNode;
+//! use anvilkit_render::renderer::ui_scroll::{ScrollContent, ScrollView};
+//! use bevy_ecs::prelude::*;
+//!
+//! let mut world = World::new();
+//! let view = world.spawn((ScrollView::vertical(), UiNode { computed_rect: [0.0, 0.0, 300.0, 200.0], ..Default::default() })).id();
+//! for i in 0..20 {
+//!     world.spawn(ScrollContent::new(view, [0.0, i as f32 * 40.0, 300.0, 36.0]));
+//! }
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use anvilkit_core::time::DeltaTime;
+use anvilkit_input::gamepad::{GamepadAxis, GamepadButton, GamepadState};
+use anvilkit_input::input_state::{InputState, KeyCode, MouseButton};
+
+use super::ui::UiNode;
+
+/// 滚动方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAxes {
+    /// 仅垂直
+    #[default]
+    Vertical,
+    /// 仅水平
+    Horizontal,
+    /// 双向
+    Both,
+}
+
+impl ScrollAxes {
+    fn mask(self) -> Vec2 {
+        match self {
+            Self::Vertical => Vec2::new(0.0, 1.0),
+            Self::Horizontal => Vec2::new(1.0, 0.0),
+            Self::Both => Vec2::ONE,
+        }
+    }
+}
+
+/// 滚动视图组件
+///
+/// 视图自身的 `UiNode::computed_rect` 即可视区域。
+#[derive(Debug, Clone, Component)]
+#[require(UiNode)]
+pub struct ScrollView {
+    /// 滚动方向
+    pub axes: ScrollAxes,
+    /// 当前滚动偏移（像素，0 = 内容顶端 / 左端）
+    pub offset: Vec2,
+    /// 惯性速度（像素/秒）
+    pub velocity: Vec2,
+    /// 内容尺寸（由 [`scroll_content_system`] 测量）
+    pub content_size: Vec2,
+    /// 每格滚轮滚动距离（像素）
+    pub wheel_step: f32,
+    /// 方向键 / 手柄滚动速度（像素/秒）
+    pub key_speed: f32,
+    /// 惯性衰减率（1/秒），越大停得越快
+    pub deceleration: f32,
+    /// 是否显示滚动条
+    pub show_scrollbars: bool,
+    /// 滚动条宽度（像素）
+    pub scrollbar_width: f32,
+    /// 是否拥有键盘 / 手柄焦点
+    pub focused: bool,
+    dragging: Option<Vec2>,
+}
+
+impl Default for ScrollView {
+    fn default() -> Self {
+        Self {
+            axes: ScrollAxes::Vertical,
+            offset: Vec2::ZERO,
+            velocity: Vec2::ZERO,
+            content_size: Vec2::ZERO,
+            wheel_step: 48.0,
+            key_speed: 600.0,
+            deceleration: 6.0,
+            show_scrollbars: true,
+            scrollbar_width: 6.0,
+            focused: false,
+            dragging: None,
+        }
+    }
+}
+
+impl ScrollView {
+    /// 垂直滚动视图
+    pub fn vertical() -> Self {
+        Self::default()
+    }
+
+    /// 水平滚动视图
+    pub fn horizontal() -> Self {
+        Self { axes: ScrollAxes::Horizontal, ..Default::default() }
+    }
+
+    /// 双向滚动视图
+    pub fn both() -> Self {
+        Self { axes: ScrollAxes::Both, ..Default::default() }
+    }
+
+    /// 可滚动的最大偏移
+    pub fn max_offset(&self, viewport_size: Vec2) -> Vec2 {
+        (self.content_size - viewport_size).max(Vec2::ZERO) * self.axes.mask()
+    }
+
+    /// 是否正在拖拽
+    pub fn is_dragging(&self) -> bool {
+        self.dragging.is_some()
+    }
+
+    /// 立即滚动到指定偏移（停止惯性）
+    pub fn scroll_to(&mut self, offset: Vec2, viewport_size: Vec2) {
+        self.offset = offset.clamp(Vec2::ZERO, self.max_offset(viewport_size));
+        self.velocity = Vec2::ZERO;
+    }
+
+    /// 滚动条滑块矩形 `(垂直, 水平)`，内容未溢出的方向返回 `None`
+    pub fn scrollbar_thumbs(&self, view_rect: [f32; 4]) -> (Option<[f32; 4]>, Option<[f32; 4]>) {
+        if !self.show_scrollbars {
+            return (None, None);
+        }
+        let [x, y, w, h] = view_rect;
+        let max = self.max_offset(Vec2::new(w, h));
+        let bar = self.scrollbar_width;
+
+        let vertical = (max.y > 0.0).then(|| {
+            let len = (h * h / self.content_size.y).max(bar * 2.0).min(h);
+            let pos = y + (h - len) * (self.offset.y / max.y);
+            [x + w - bar, pos, bar, len]
+        });
+        let horizontal = (max.x > 0.0).then(|| {
+            let len = (w * w / self.content_size.x).max(bar * 2.0).min(w);
+            let pos = x + (w - len) * (self.offset.x / max.x);
+            [pos, y + h - bar, len, bar]
+        });
+        (vertical, horizontal)
+    }
+
+    /// 推进惯性并限制在有效范围内
+    pub fn integrate(&mut self, dt: f32, viewport_size: Vec2) {
+        let max = self.max_offset(viewport_size);
+        if self.dragging.is_none() {
+            self.offset += self.velocity * dt;
+            self.velocity *= (-self.deceleration * dt).exp();
+            if self.velocity.length_squared() < 1.0 {
+                self.velocity = Vec2::ZERO;
+            }
+        }
+        let clamped = self.offset.clamp(Vec2::ZERO, max);
+        // 撞到边界的轴停止惯性
+        if clamped.x != self.offset.x {
+            self.velocity.x = 0.0;
+        }
+        if clamped.y != self.offset.y {
+            self.velocity.y = 0.0;
+        }
+        self.offset = clamped;
+    }
+}
+
+/// 滚动内容节点
+///
+/// `local_rect` 为相对视图内容原点的矩形 `[x, y, w, h]`（像素）。
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[require(UiNode)]
+pub struct ScrollContent {
+    /// 所属滚动视图
+    pub view: Entity,
+    /// 内容坐标系中的矩形
+    pub local_rect: [f32; 4],
+}
+
+impl ScrollContent {
+    /// 创建内容节点
+    pub fn new(view: Entity, local_rect: [f32; 4]) -> Self {
+        Self { view, local_rect }
+    }
+}
+
+/// 节点的裁剪矩形 `[x, y, w, h]`（像素）
+///
+/// `UiRenderer::render_with_clips` 按裁剪矩形分批设置 scissor。
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct UiClip(pub [f32; 4]);
+
+fn contains(rect: [f32; 4], point: Vec2) -> bool {
+    point.x >= rect[0] && point.y >= rect[1] && point.x <= rect[0] + rect[2] && point.y <= rect[1] + rect[3]
+}
+
+fn view_size(rect: [f32; 4]) -> Vec2 {
+    Vec2::new(rect[2], rect[3])
+}
+
+/// 滚动输入系统 (Update)
+///
+/// 处理焦点、滚轮、拖拽、方向键与手柄输入，并推进惯性。
+pub fn ui_scroll_input_system(
+    input: Option<Res<InputState>>,
+    gamepads: Option<Res<GamepadState>>,
+    dt: Option<Res<DeltaTime>>,
+    mut views: Query<(&mut ScrollView, &UiNode)>,
+) {
+    let dt = dt.map_or(1.0 / 60.0, |d| d.0).max(1e-4);
+
+    for (mut view, node) in views.iter_mut() {
+        let rect = node.computed_rect;
+        let size = view_size(rect);
+        let mask = view.axes.mask();
+
+        if let Some(input) = input.as_deref() {
+            let cursor = input.mouse_position();
+            let hovered = node.visible && contains(rect, cursor);
+
+            if input.is_mouse_just_pressed(MouseButton::Left) {
+                view.focused = hovered;
+                if hovered {
+                    view.dragging = Some(cursor);
+                    view.velocity = Vec2::ZERO;
+                }
+            }
+            if let Some(last) = view.dragging {
+                if input.is_mouse_pressed(MouseButton::Left) {
+                    let delta = (cursor - last) * mask;
+                    view.offset -= delta;
+                    // 跟随最近一帧的拖拽速度，松手后作为惯性初速度
+                    view.velocity = -delta / dt;
+                    view.dragging = Some(cursor);
+                } else {
+                    view.dragging = None;
+                }
+            }
+
+            let wheel = input.scroll_delta();
+            if hovered && wheel != 0.0 {
+                // 滚轮向上为正：减小偏移。转换为初速度，由惯性衰减走完约一个步长 × 格数
+                let axis = if view.axes == ScrollAxes::Horizontal { Vec2::X } else { Vec2::Y };
+                let impulse = axis * wheel * view.wheel_step * view.deceleration;
+                view.velocity -= impulse;
+            }
+
+            if view.focused {
+                let mut dir = Vec2::ZERO;
+                if input.is_key_pressed(KeyCode::Up) { dir.y -= 1.0; }
+                if input.is_key_pressed(KeyCode::Down) { dir.y += 1.0; }
+                if input.is_key_pressed(KeyCode::Left) { dir.x -= 1.0; }
+                if input.is_key_pressed(KeyCode::Right) { dir.x += 1.0; }
+                if dir != Vec2::ZERO {
+                    view.velocity = dir * mask * view.key_speed;
+                }
+            }
+        }
+
+        if view.focused {
+            if let Some(pads) = gamepads.as_deref() {
+                let mut dir = Vec2::ZERO;
+                for id in pads.connected_gamepads() {
+                    dir.x += pads.axis_value(id, GamepadAxis::RightStickX);
+                    // 摇杆向上为正，对应减小偏移
+                    dir.y -= pads.axis_value(id, GamepadAxis::RightStickY);
+                    if pads.is_button_pressed(id, GamepadButton::DPadUp) { dir.y -= 1.0; }
+                    if pads.is_button_pressed(id, GamepadButton::DPadDown) { dir.y += 1.0; }
+                    if pads.is_button_pressed(id, GamepadButton::DPadLeft) { dir.x -= 1.0; }
+                    if pads.is_button_pressed(id, GamepadButton::DPadRight) { dir.x += 1.0; }
+                }
+                if dir.length_squared() > 0.04 {
+                    view.velocity = dir.clamp(Vec2::NEG_ONE, Vec2::ONE) * mask * view.key_speed;
+                }
+            }
+        }
+
+        view.integrate(dt, size);
+    }
+}
+
+/// 内容布局系统 (PostUpdate)
+///
+/// 测量每个视图的内容尺寸，并按滚动偏移放置内容节点、写入裁剪矩形。
+pub fn scroll_content_system(
+    mut commands: Commands,
+    mut views: Query<(&mut ScrollView, &UiNode), Without<ScrollContent>>,
+    mut contents: Query<(Entity, &ScrollContent, &mut UiNode, Option<&mut UiClip>), Without<ScrollView>>,
+) {
+    for (mut view, _) in views.iter_mut() {
+        if view.content_size != Vec2::ZERO {
+            view.content_size = Vec2::ZERO;
+        }
+    }
+    for (_, content, ..) in contents.iter() {
+        if let Ok((mut view, _)) = views.get_mut(content.view) {
+            let [x, y, w, h] = content.local_rect;
+            let extent = Vec2::new(x + w, y + h);
+            if extent.cmpgt(view.content_size).any() {
+                view.content_size = view.content_size.max(extent);
+            }
+        }
+    }
+
+    for (entity, content, mut node, clip) in contents.iter_mut() {
+        let Ok((view, view_node)) = views.get(content.view) else { continue };
+        let [vx, vy, vw, vh] = view_node.computed_rect;
+        let [x, y, w, h] = content.local_rect;
+        node.computed_rect = [vx + x - view.offset.x, vy + y - view.offset.y, w, h];
+        // 完全滚出视图的节点跳过绘制
+        node.visible = view_node.visible
+            && node.computed_rect[0] < vx + vw
+            && node.computed_rect[1] < vy + vh
+            && node.computed_rect[0] + w > vx
+            && node.computed_rect[1] + h > vy;
+
+        let clip_rect = view_node.computed_rect;
+        match clip {
+            Some(mut clip) => {
+                if clip.0 != clip_rect {
+                    clip.0 = clip_rect;
+                }
+            }
+            None => {
+                commands.entity(entity).insert(UiClip(clip_rect));
+            }
+        }
+    }
+}
+
+/// 生成视图滚动条滑块的 `UiNode`
+pub fn scrollbar_nodes(view: &ScrollView, view_rect: [f32; 4], color: [f32; 4]) -> Vec<UiNode> {
+    let (vertical, horizontal) = view.scrollbar_thumbs(view_rect);
+    vertical
+        .into_iter()
+        .chain(horizontal)
+        .map(|rect| UiNode {
+            background_color: color,
+            corner_radius: view.scrollbar_width * 0.5,
+            computed_rect: rect,
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// UI 滚动视图插件
+pub struct UiScrollPlugin;
+
+impl Plugin for UiScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(bevy_app::Update, ui_scroll_input_system)
+            .add_systems(bevy_app::PostUpdate, scroll_content_system);
+    }
+
+    fn name(&self) -> &str {
+        "UiScrollPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn view_node() -> UiNode {
+        UiNode { computed_rect: [0.0, 0.0, 100.0, 100.0], ..Default::default() }
+    }
+
+    fn test_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(UiScrollPlugin);
+        app.insert_resource(InputState::new());
+        app.insert_resource(DeltaTime(1.0 / 60.0));
+        let view = app.world_mut().spawn((ScrollView::vertical(), view_node())).id();
+        for i in 0..10 {
+            app.world_mut().spawn(ScrollContent::new(view, [0.0, i as f32 * 50.0, 100.0, 40.0]));
+        }
+        app.update();
+        (app, view)
+    }
+
+    #[test]
+    fn test_content_measured_and_clipped() {
+        let (mut app, view) = test_app();
+        assert_eq!(app.world().get::<ScrollView>(view).unwrap().content_size, Vec2::new(100.0, 490.0));
+        app.update();
+
+        let mut q = app.world_mut().query::<(&ScrollContent, &UiNode, &UiClip)>();
+        let visible = q.iter(app.world()).filter(|(_, node, _)| node.visible).count();
+        assert_eq!(visible, 2);
+        assert!(q.iter(app.world()).all(|(.., clip)| clip.0 == [0.0, 0.0, 100.0, 100.0]));
+    }
+
+    #[test]
+    fn test_wheel_scroll_eases_and_clamps() {
+        let (mut app, view) = test_app();
+        {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.set_mouse_position(Vec2::new(50.0, 50.0));
+            input.add_scroll_delta(-1.0);
+        }
+        app.update();
+        let first = app.world().get::<ScrollView>(view).unwrap().offset.y;
+        assert!(first > 0.0);
+
+        app.world_mut().resource_mut::<InputState>().end_frame();
+        for _ in 0..120 {
+            app.update();
+        }
+        let sv = app.world().get::<ScrollView>(view).unwrap();
+        assert!(sv.offset.y > first && sv.offset.y <= 390.0);
+        assert_eq!(sv.velocity, Vec2::ZERO);
+
+        // 越界时被限制在最大偏移
+        app.world_mut().get_mut::<ScrollView>(view).unwrap().velocity = Vec2::new(0.0, 1e5);
+        app.update();
+        assert_eq!(app.world().get::<ScrollView>(view).unwrap().offset.y, 390.0);
+    }
+
+    #[test]
+    fn test_drag_and_keyboard_focus() {
+        let (mut app, view) = test_app();
+        {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.set_mouse_position(Vec2::new(50.0, 80.0));
+            input.press_mouse(MouseButton::Left);
+        }
+        app.update();
+        assert!(app.world().get::<ScrollView>(view).unwrap().focused);
+
+        {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.end_frame();
+            input.set_mouse_position(Vec2::new(50.0, 50.0));
+        }
+        app.update();
+        let sv = app.world().get::<ScrollView>(view).unwrap();
+        assert!(sv.is_dragging());
+        assert_eq!(sv.offset.y, 30.0);
+
+        {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.end_frame();
+            input.release_mouse(MouseButton::Left);
+            input.press_key(KeyCode::Down);
+        }
+        app.update();
+        let sv = app.world().get::<ScrollView>(view).unwrap();
+        assert!(!sv.is_dragging());
+        assert!(sv.offset.y > 30.0);
+    }
+
+    #[test]
+    fn test_scrollbar_thumb() {
+        let mut sv = ScrollView { content_size: Vec2::new(100.0, 400.0), ..ScrollView::vertical() };
+        let (v, h) = sv.scrollbar_thumbs([0.0, 0.0, 100.0, 100.0]);
+        assert_eq!(v, Some([94.0, 0.0, 6.0, 25.0]));
+        assert!(h.is_none());
+        sv.offset.y = 300.0;
+        assert_eq!(sv.scrollbar_thumbs([0.0, 0.0, 100.0, 100.0]).0.unwrap()[1], 75.0);
+        assert_eq!(scrollbar_nodes(&sv, [0.0, 0.0, 100.0, 100.0], [1.0; 4]).len(), 1);
+    }
+}
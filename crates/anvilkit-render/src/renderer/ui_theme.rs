@@ -0,0 +1,384 @@
+//! # UI 主题
+//!
+//! [`UiTheme`] 是可从 RON 加载的样式资产：调色板、字体、九宫格面板与样式类。
+//! UI 节点通过 [`UiClass`] 声明所属的类（类似 CSS class，空格分隔，后者覆盖前者），
+//! [`ui_theme_apply_system`] 在布局前把类的属性解析到 `UiNode` 上。
+//! 主题文件修改后由 [`UiThemePlugin`] 热重载（需启用 `hot-reload` feature）。
+//!
+//! ```ron
+//! (
+//!     colors: { "accent": (1.0, 0.5, 0.0, 1.0) },
+//!     fonts: { "title": (family: "bold", size: 24.0) },
+//!     panels: { "frame": (image: "ui/frame.png", border: (8.0, 8.0, 8.0, 8.0), image_size: (64.0, 64.0)) },
+//!     classes: {
+//!         "panel": (background: Some("#202020e0"), panel: Some("frame"), padding: Some((8.0, 8.0, 8.0, 8.0))),
+//!         "title": (font: Some("title"), text_color: Some("accent")),
+//!     },
+//! )
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+use anvilkit_assets::hot_reload::FileWatcher;
+use anvilkit_core::error::{AnvilKitError, Result};
+
+use super::sprite::AtlasRect;
+use super::ui::UiNode;
+
+/// 主题字体
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ThemeFont {
+    /// 字体族名（对应 `UiText::font_family`）
+    pub family: String,
+    /// 字号（像素）
+    pub size: f32,
+}
+
+/// 九宫格面板
+///
+/// 四角保持原始尺寸，边缘单向拉伸，中心双向拉伸。由主题类解析后作为组件挂在节点上。
+#[derive(Debug, Clone, PartialEq, Deserialize, Component)]
+pub struct NineSlice {
+    /// 面板贴图路径
+    pub image: String,
+    /// 边框宽度（像素）`[left, top, right, bottom]`
+    pub border: [f32; 4],
+    /// 贴图尺寸（像素）
+    pub image_size: [f32; 2],
+}
+
+impl NineSlice {
+    /// 把目标矩形切成 9 块，返回 `(屏幕矩形 [x, y, w, h], 贴图 UV)`，按行从左上到右下
+    ///
+    /// 矩形小于边框总和时按比例压缩边框。
+    pub fn slices(&self, rect: [f32; 4]) -> [([f32; 4], AtlasRect); 9] {
+        let [x, y, w, h] = rect;
+        let [l, t, r, b] = self.border;
+        let sx = if l + r > w && l + r > 0.0 { w / (l + r) } else { 1.0 };
+        let sy = if t + b > h && t + b > 0.0 { h / (t + b) } else { 1.0 };
+        let xs = [x, x + l * sx, x + w - r * sx, x + w];
+        let ys = [y, y + t * sy, y + h - b * sy, y + h];
+        let [iw, ih] = self.image_size;
+        let us = [0.0, l / iw, 1.0 - r / iw, 1.0];
+        let vs = [0.0, t / ih, 1.0 - b / ih, 1.0];
+
+        std::array::from_fn(|i| {
+            let (row, col) = (i / 3, i % 3);
+            (
+                [xs[col], ys[row], xs[col + 1] - xs[col], ys[row + 1] - ys[row]],
+                AtlasRect::new(us[col], vs[row], us[col + 1], vs[row + 1]),
+            )
+        })
+    }
+}
+
+/// 样式类：未设置的属性保持节点原值
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct StyleClass {
+    /// 背景色（调色板名或 `#rrggbb[aa]`）
+    pub background: Option<String>,
+    /// 边框色
+    pub border_color: Option<String>,
+    /// 边框宽度
+    pub border_width: Option<f32>,
+    /// 圆角半径
+    pub corner_radius: Option<f32>,
+    /// 内边距 `[top, right, bottom, left]`
+    pub padding: Option<[f32; 4]>,
+    /// 外边距
+    pub margin: Option<[f32; 4]>,
+    /// 子节点间距
+    pub gap: Option<f32>,
+    /// 字体（`fonts` 表中的名字）
+    pub font: Option<String>,
+    /// 文字颜色
+    pub text_color: Option<String>,
+    /// 九宫格面板（`panels` 表中的名字）
+    pub panel: Option<String>,
+}
+
+/// UI 主题资产 (Resource)
+#[derive(Resource, Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct UiTheme {
+    /// 调色板
+    pub colors: HashMap<String, [f32; 4]>,
+    /// 字体表
+    pub fonts: HashMap<String, ThemeFont>,
+    /// 九宫格面板表
+    pub panels: HashMap<String, NineSlice>,
+    /// 样式类
+    pub classes: HashMap<String, StyleClass>,
+}
+
+impl UiTheme {
+    /// 从 RON 文本解析主题
+    pub fn from_ron(source: &str) -> Result<Self> {
+        ron::from_str(source).map_err(|e| AnvilKitError::asset(format!("解析 UI 主题失败: {}", e)))
+    }
+
+    /// 从 RON 文件加载主题
+    pub fn load_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| {
+            AnvilKitError::asset_with_path(format!("读取 UI 主题失败: {}", e), path.display().to_string())
+        })?;
+        Self::from_ron(&source)
+    }
+
+    /// 解析颜色：先查调色板，再尝试 `#rrggbb` / `#rrggbbaa`
+    pub fn color(&self, value: &str) -> Option<[f32; 4]> {
+        if let Some(color) = self.colors.get(value) {
+            return Some(*color);
+        }
+        let hex = value.strip_prefix('#')?;
+        if !matches!(hex.len(), 6 | 8) {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i * 2..i * 2 + 2)?, 16).ok().map(|v| v as f32 / 255.0);
+        let alpha = if hex.len() == 8 { channel(3)? } else { 1.0 };
+        Some([channel(0)?, channel(1)?, channel(2)?, alpha])
+    }
+
+    /// 按顺序把类应用到节点，返回最终生效的九宫格面板
+    pub fn apply(&self, classes: &[String], node: &mut UiNode) -> Option<NineSlice> {
+        let mut panel = None;
+        for name in classes {
+            let Some(class) = self.classes.get(name) else {
+                log::debug!("UI 主题中没有样式类 '{}'", name);
+                continue;
+            };
+            if let Some(c) = class.background.as_deref().and_then(|c| self.color(c)) {
+                node.background_color = c;
+            }
+            if let Some(c) = class.border_color.as_deref().and_then(|c| self.color(c)) {
+                node.border_color = c;
+            }
+            if let Some(v) = class.border_width {
+                node.border_width = v;
+            }
+            if let Some(v) = class.corner_radius {
+                node.corner_radius = v;
+            }
+            if let Some(v) = class.padding {
+                node.style.padding = v;
+            }
+            if let Some(v) = class.margin {
+                node.style.margin = v;
+            }
+            if let Some(v) = class.gap {
+                node.style.gap = v;
+            }
+            if let Some(text) = node.text.as_mut() {
+                if let Some(font) = class.font.as_deref().and_then(|f| self.fonts.get(f)) {
+                    text.font_family = font.family.clone();
+                    text.font_size = font.size;
+                }
+                if let Some(c) = class.text_color.as_deref().and_then(|c| self.color(c)) {
+                    text.color = c;
+                }
+            }
+            if let Some(p) = class.panel.as_deref().and_then(|p| self.panels.get(p)) {
+                panel = Some(p.clone());
+            }
+        }
+        panel
+    }
+}
+
+/// 节点的样式类列表
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+#[require(UiNode)]
+pub struct UiClass(pub Vec<String>);
+
+impl UiClass {
+    /// 由空格分隔的类名创建，如 `"button primary"`
+    pub fn new(classes: &str) -> Self {
+        Self(classes.split_whitespace().map(str::to_string).collect())
+    }
+}
+
+/// 样式解析系统 (PostUpdate)
+///
+/// 主题变化时重新解析所有节点，否则只处理 `UiClass` 变化的节点。
+pub fn ui_theme_apply_system(
+    mut commands: Commands,
+    theme: Option<Res<UiTheme>>,
+    mut query: Query<(Entity, Ref<UiClass>, &mut UiNode, Option<&NineSlice>)>,
+) {
+    let Some(theme) = theme else { return };
+    let all = theme.is_changed();
+    for (entity, class, mut node, current_panel) in query.iter_mut() {
+        if !(all || class.is_changed()) {
+            continue;
+        }
+        match (theme.apply(&class.0, &mut node), current_panel) {
+            (Some(panel), current) if current != Some(&panel) => {
+                commands.entity(entity).insert(panel);
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<NineSlice>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 主题文件路径 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct UiThemeSource {
+    /// RON 文件路径
+    pub path: PathBuf,
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.ends_with(b) || b.ends_with(a),
+    }
+}
+
+/// 主题热重载系统 (Update)
+///
+/// 监视器为 non-send 资源；文件解析失败时保留旧主题。
+pub fn ui_theme_reload_system(
+    mut commands: Commands,
+    source: Option<Res<UiThemeSource>>,
+    watcher: Option<NonSendMut<FileWatcher>>,
+) {
+    let (Some(source), Some(mut watcher)) = (source, watcher) else { return };
+    if !watcher.poll_changes().iter().any(|p| same_file(p, &source.path)) {
+        return;
+    }
+    match UiTheme::load_file(&source.path) {
+        Ok(theme) => {
+            log::info!("UI 主题已重载: {}", source.path.display());
+            commands.insert_resource(theme);
+        }
+        Err(e) => log::warn!("UI 主题重载失败，保留旧主题: {}", e),
+    }
+}
+
+/// UI 主题插件
+///
+/// 设置了路径时加载主题文件并监视其所在目录；否则由应用自行插入 [`UiTheme`] 资源。
+#[derive(Default)]
+pub struct UiThemePlugin {
+    /// 主题文件路径
+    pub path: Option<PathBuf>,
+}
+
+impl UiThemePlugin {
+    /// 从 RON 文件加载主题
+    pub fn from_file(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()) }
+    }
+}
+
+impl Plugin for UiThemePlugin {
+    fn build(&self, app: &mut App) {
+        if let Some(path) = &self.path {
+            match UiTheme::load_file(path) {
+                Ok(theme) => {
+                    app.insert_resource(theme);
+                }
+                Err(e) => log::error!("{}", e),
+            }
+            app.insert_resource(UiThemeSource { path: path.clone() });
+            let dir = path.parent().filter(|d| !d.as_os_str().is_empty()).unwrap_or(Path::new("."));
+            match FileWatcher::new(dir) {
+                Ok(watcher) => {
+                    app.insert_non_send_resource(watcher);
+                }
+                Err(e) => log::warn!("UI 主题热重载不可用: {}", e),
+            }
+        }
+        app.add_systems(bevy_app::Update, ui_theme_reload_system)
+            .add_systems(bevy_app::PostUpdate, ui_theme_apply_system);
+    }
+
+    fn name(&self) -> &str {
+        "UiThemePlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ui::UiText;
+
+    const THEME: &str = r##"(
+        colors: { "accent": (1.0, 0.5, 0.0, 1.0) },
+        fonts: { "title": (family: "bold", size: 24.0) },
+        panels: { "frame": (image: "ui/frame.png", border: (8.0, 8.0, 8.0, 8.0), image_size: (32.0, 32.0)) },
+        classes: {
+            "panel": (background: Some("#ff000080"), panel: Some("frame"), padding: Some((4.0, 4.0, 4.0, 4.0))),
+            "title": (font: Some("title"), text_color: Some("accent")),
+            "flat": (background: Some("accent"), corner_radius: Some(0.0)),
+        },
+    )"##;
+
+    #[test]
+    fn test_parse_and_colors() {
+        let theme = UiTheme::from_ron(THEME).unwrap();
+        assert_eq!(theme.classes.len(), 3);
+        assert_eq!(theme.color("accent"), Some([1.0, 0.5, 0.0, 1.0]));
+        assert_eq!(theme.color("#ffffff"), Some([1.0; 4]));
+        assert_eq!(theme.color("#00000000"), Some([0.0; 4]));
+        assert_eq!(theme.color("#12"), None);
+        assert_eq!(theme.color("missing"), None);
+        assert!(UiTheme::from_ron("(classes: 3)").is_err());
+    }
+
+    #[test]
+    fn test_classes_apply_in_order() {
+        let theme = UiTheme::from_ron(THEME).unwrap();
+        let mut node = UiNode { text: Some(UiText::new("Hi")), corner_radius: 6.0, ..Default::default() };
+        let panel = theme.apply(&UiClass::new("panel title flat").0, &mut node);
+
+        assert_eq!(node.background_color, [1.0, 0.5, 0.0, 1.0]);
+        assert_eq!(node.corner_radius, 0.0);
+        assert_eq!(node.style.padding, [4.0; 4]);
+        let text = node.text.unwrap();
+        assert_eq!((text.font_family.as_str(), text.font_size), ("bold", 24.0));
+        assert_eq!(panel.unwrap().image, "ui/frame.png");
+    }
+
+    #[test]
+    fn test_system_reapplies_on_theme_change() {
+        let mut app = App::new();
+        app.add_plugins(UiThemePlugin::default());
+        app.insert_resource(UiTheme::from_ron(THEME).unwrap());
+        let e = app.world_mut().spawn(UiClass::new("panel")).id();
+        app.update();
+        assert!(app.world().get::<NineSlice>(e).is_some());
+        assert_eq!(app.world().get::<UiNode>(e).unwrap().background_color[3], 128.0 / 255.0);
+
+        // 重新换肤：panel 类不再有面板
+        let mut theme = UiTheme::from_ron(THEME).unwrap();
+        theme.classes.get_mut("panel").unwrap().panel = None;
+        theme.classes.get_mut("panel").unwrap().background = Some("accent".into());
+        app.insert_resource(theme);
+        app.update();
+        assert!(app.world().get::<NineSlice>(e).is_none());
+        assert_eq!(app.world().get::<UiNode>(e).unwrap().background_color, [1.0, 0.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_nine_slice_rects() {
+        let slice = NineSlice { image: String::new(), border: [8.0; 4], image_size: [32.0, 32.0] };
+        let parts = slice.slices([0.0, 0.0, 100.0, 50.0]);
+        assert_eq!(parts[0].0, [0.0, 0.0, 8.0, 8.0]);
+        assert_eq!(parts[4].0, [8.0, 8.0, 84.0, 34.0]);
+        assert_eq!(parts[8].0, [92.0, 42.0, 8.0, 8.0]);
+        assert_eq!(parts[4].1, AtlasRect::new(0.25, 0.25, 0.75, 0.75));
+
+        // 过小的矩形按比例压缩边框
+        let tiny = slice.slices([0.0, 0.0, 8.0, 8.0]);
+        assert_eq!(tiny[0].0, [0.0, 0.0, 4.0, 4.0]);
+    }
+}
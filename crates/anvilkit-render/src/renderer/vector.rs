@@ -0,0 +1,1099 @@
+//! # 2D 矢量路径渲染
+//!
+//! 用 [`Path`] 描述由直线与贝塞尔曲线组成的轮廓，配合 [`Fill`] / [`Stroke`] 组件绘制清晰的
+//! UI 图形、图表与矢量美术。坐标系与精灵一致（屏幕像素，y 向下），随实体的 `Transform` 变换。
+//!
+//! - [`vector_tessellate_system`] 在路径或样式变化时把曲线按容差展平并细分为三角形，
+//!   结果按颜色分部分缓存在 [`VectorMesh`] 组件中；未变化的实体每帧只做顶点变换
+//! - 填充按扫描带（slab）分解：所有顶点与边交点的 y 坐标把平面切成水平带，
+//!   带内边互不相交，按 [`FillRule`] 取出内部梯形，支持孔洞与自相交
+//! - 描边为每段生成四边形，并按 [`LineJoin`] / [`LineCap`] 补齐拐角与端点
+//! - [`VectorRenderer`] 在 4x MSAA 图层中绘制，每个填充 / 描边使用唯一深度，
+//!   同一形状内重叠的三角形只着色一次（半透明描边的拐角不会变暗），最后预乘 Alpha 合成到目标
+//!
+//! SVG 文件经 [`anvilkit_assets::svg_loader`] 解析后，可用 [`svg_shapes`] 转换为组件。
+//! [`VectorPlugin`] 把合成注册为 `AfterTonemap` 阶段，位于 2D 光照之后（矢量图形不受光照影响）。
+//!
+//! ```rust
+//! use anvilkit_core::math::Color;
+//! use anvilkit_render::renderer::vector::{flatten, tessellate_fill, Fill, Path};
+//! use glam::Vec2;
+//!
+//! let path = Path::new()
+//!     .move_to(Vec2::new(0.0, 0.0))
+//!     .line_to(Vec2::new(100.0, 0.0))
+//!     .quad_to(Vec2::new(100.0, 100.0), Vec2::new(0.0, 100.0))
+//!     .close();
+//! let fill = Fill::color(Color::rgb(0.2, 0.6, 1.0));
+//! let mut triangles = Vec::new();
+//! tessellate_fill(&flatten(&path.commands, path.tolerance), fill.rule, &mut triangles);
+//! assert_eq!(triangles.len() % 3, 0);
+//! ```
+
+use std::sync::Mutex;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_assets::svg_loader::{ellipse_path, rect_path, SvgDocument, SvgShape};
+use anvilkit_core::math::{Color, Transform};
+use anvilkit_describe::Describe;
+use bytemuck::{Pod, Zeroable};
+use glam::{Mat4, Vec2};
+use wgpu::util::DeviceExt;
+
+pub use anvilkit_assets::svg_loader::{FillRule, LineCap, LineJoin, PathCommand};
+
+use super::buffer::{Vertex, DEPTH_FORMAT};
+use super::phase::{PhaseItem, PhaseSlot, RenderPhase, RenderPhaseAppExt, RenderPhaseDescriptor};
+use super::shared::{CachedBuffer, MatrixUniform};
+use super::state::RenderState;
+use super::RenderDevice;
+
+const VECTOR_SHADER: &str = include_str!("../shaders/vector.wgsl");
+
+/// 矢量图层的 MSAA 采样数（独立于场景的 `MSAA_SAMPLE_COUNT`，保证边缘始终抗锯齿）
+const VECTOR_SAMPLE_COUNT: u32 = 4;
+
+/// 默认展平容差（局部空间单位，通常为像素）
+pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+/// 2D 矢量路径组件
+#[derive(Debug, Clone, PartialEq, Component, Describe)]
+/// 2D vector outline made of lines and Bézier curves.
+pub struct Path {
+    /// 路径命令（局部空间）
+    #[describe(hint = "Path commands in local space")]
+    pub commands: Vec<PathCommand>,
+    /// 曲线展平的最大偏差
+    #[describe(hint = "Max curve flattening error", range = "0.01..2.0", default = "0.1")]
+    pub tolerance: f32,
+}
+
+impl Default for Path {
+    fn default() -> Self {
+        Self { commands: Vec::new(), tolerance: DEFAULT_TOLERANCE }
+    }
+}
+
+impl Path {
+    /// 空路径
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 由命令列表创建
+    pub fn from_commands(commands: impl Into<Vec<PathCommand>>) -> Self {
+        Self { commands: commands.into(), ..Default::default() }
+    }
+
+    /// 以 `center` 为圆心的圆
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::from_commands(ellipse_path(center, Vec2::splat(radius)))
+    }
+
+    /// 椭圆
+    pub fn ellipse(center: Vec2, radii: Vec2) -> Self {
+        Self::from_commands(ellipse_path(center, radii))
+    }
+
+    /// 左上角为 `origin` 的矩形
+    pub fn rect(origin: Vec2, size: Vec2) -> Self {
+        Self::from_commands(rect_path(origin, size, Vec2::ZERO))
+    }
+
+    /// 圆角矩形
+    pub fn rounded_rect(origin: Vec2, size: Vec2, radius: f32) -> Self {
+        let radius = radius.clamp(0.0, size.x.min(size.y) / 2.0);
+        Self::from_commands(rect_path(origin, size, Vec2::splat(radius)))
+    }
+
+    /// 开始新的子路径
+    pub fn move_to(mut self, to: Vec2) -> Self {
+        self.commands.push(PathCommand::MoveTo(to));
+        self
+    }
+
+    /// 直线
+    pub fn line_to(mut self, to: Vec2) -> Self {
+        self.commands.push(PathCommand::LineTo(to));
+        self
+    }
+
+    /// 二次贝塞尔
+    pub fn quad_to(mut self, ctrl: Vec2, to: Vec2) -> Self {
+        self.commands.push(PathCommand::QuadTo { ctrl, to });
+        self
+    }
+
+    /// 三次贝塞尔
+    pub fn cubic_to(mut self, ctrl1: Vec2, ctrl2: Vec2, to: Vec2) -> Self {
+        self.commands.push(PathCommand::CubicTo { ctrl1, ctrl2, to });
+        self
+    }
+
+    /// 闭合当前子路径
+    pub fn close(mut self) -> Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    /// 设置展平容差
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// 填充组件
+#[derive(Debug, Clone, Copy, PartialEq, Component, Describe)]
+/// Fills the interior of the entity's path.
+pub struct Fill {
+    /// 填充颜色（线性 RGBA）
+    #[describe(hint = "Fill color", default = "WHITE")]
+    pub color: Color,
+    /// 填充规则（未闭合的子路径按隐式闭合处理）
+    #[describe(hint = "NonZero or EvenOdd", default = "NonZero")]
+    pub rule: FillRule,
+}
+
+impl Fill {
+    /// 非零规则的纯色填充
+    pub fn color(color: Color) -> Self {
+        Self { color, rule: FillRule::NonZero }
+    }
+
+    /// 设置填充规则
+    pub fn with_rule(mut self, rule: FillRule) -> Self {
+        self.rule = rule;
+        self
+    }
+}
+
+/// 描边组件
+#[derive(Debug, Clone, Copy, PartialEq, Component, Describe)]
+/// Strokes the outline of the entity's path.
+pub struct Stroke {
+    /// 描边颜色（线性 RGBA）
+    #[describe(hint = "Stroke color", default = "BLACK")]
+    pub color: Color,
+    /// 线宽（局部空间）
+    #[describe(hint = "Line width", range = "0.0..64.0", default = "1.0")]
+    pub width: f32,
+    /// 端点样式
+    #[describe(hint = "Butt, Square or Round", default = "Butt")]
+    pub cap: LineCap,
+    /// 拐角样式
+    #[describe(hint = "Miter, Round or Bevel", default = "Miter")]
+    pub join: LineJoin,
+    /// 斜接限制（斜接长度 / 线宽），超过时改为斜切
+    #[describe(hint = "Miter length limit relative to width", range = "1.0..20.0", default = "4.0")]
+    pub miter_limit: f32,
+}
+
+impl Stroke {
+    /// 纯色描边，尖角与平头
+    pub fn new(color: Color, width: f32) -> Self {
+        Self { color, width, cap: LineCap::Butt, join: LineJoin::Miter, miter_limit: 4.0 }
+    }
+
+    /// 设置端点样式
+    pub fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// 设置拐角样式
+    pub fn with_join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+}
+
+/// SVG 文档中的形状转换为 `(Path, Fill, Stroke)` 组件，按绘制顺序排列
+///
+/// 文档内的先后顺序需要由调用方通过 `RenderOrder` 或 `Transform` 的 z 保持。
+pub fn svg_shapes(doc: &SvgDocument) -> Vec<(Path, Option<Fill>, Option<Stroke>)> {
+    doc.shapes.iter().map(svg_shape).collect()
+}
+
+/// 单个 SVG 形状转换为组件
+pub fn svg_shape(shape: &SvgShape) -> (Path, Option<Fill>, Option<Stroke>) {
+    (
+        Path::from_commands(shape.commands.clone()),
+        shape.fill.map(|f| Fill { color: f.color, rule: f.rule }),
+        shape.stroke.map(|s| Stroke {
+            color: s.color,
+            width: s.width,
+            cap: s.cap,
+            join: s.join,
+            miter_limit: s.miter_limit,
+        }),
+    )
+}
+
+// ---------------------------------------------------------------------------
+//  Flattening & tessellation
+// ---------------------------------------------------------------------------
+
+/// 展平后的子路径
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    /// 顶点
+    pub points: Vec<Vec2>,
+    /// 是否以 `Close` 结束
+    pub closed: bool,
+}
+
+/// 把路径命令按容差展平为折线
+pub fn flatten(commands: &[PathCommand], tolerance: f32) -> Vec<Polyline> {
+    let tolerance = tolerance.max(1e-3);
+    let mut out = Vec::new();
+    let mut current = Polyline { points: Vec::new(), closed: false };
+    let mut last = Vec2::ZERO;
+    let mut start = Vec2::ZERO;
+
+    let finish = |current: &mut Polyline, out: &mut Vec<Polyline>| {
+        if !current.points.is_empty() {
+            out.push(std::mem::replace(current, Polyline { points: Vec::new(), closed: false }));
+        }
+    };
+    for command in commands {
+        if current.points.is_empty() && !matches!(command, PathCommand::MoveTo(_) | PathCommand::Close) {
+            current.points.push(last);
+            start = last;
+        }
+        match *command {
+            PathCommand::MoveTo(p) => {
+                finish(&mut current, &mut out);
+                current.points.push(p);
+                start = p;
+                last = p;
+            }
+            PathCommand::LineTo(p) => {
+                current.points.push(p);
+                last = p;
+            }
+            PathCommand::QuadTo { ctrl, to } => {
+                let dd = (last - 2.0 * ctrl + to).length();
+                let n = segment_count((dd / (8.0 * tolerance)).sqrt());
+                let p0 = last;
+                for i in 1..=n {
+                    let t = i as f32 / n as f32;
+                    let mt = 1.0 - t;
+                    current.points.push(mt * mt * p0 + 2.0 * mt * t * ctrl + t * t * to);
+                }
+                last = to;
+            }
+            PathCommand::CubicTo { ctrl1, ctrl2, to } => {
+                let dd = (last - 2.0 * ctrl1 + ctrl2).length().max((ctrl1 - 2.0 * ctrl2 + to).length());
+                let n = segment_count((0.75 * dd / tolerance).sqrt());
+                let p0 = last;
+                for i in 1..=n {
+                    let t = i as f32 / n as f32;
+                    let mt = 1.0 - t;
+                    current.points.push(
+                        mt * mt * mt * p0 + 3.0 * mt * mt * t * ctrl1 + 3.0 * mt * t * t * ctrl2 + t * t * t * to,
+                    );
+                }
+                last = to;
+            }
+            PathCommand::Close => {
+                if !current.points.is_empty() {
+                    current.closed = true;
+                    finish(&mut current, &mut out);
+                }
+                last = start;
+            }
+        }
+    }
+    finish(&mut current, &mut out);
+    out
+}
+
+fn segment_count(estimate: f32) -> usize {
+    (estimate.ceil() as usize).clamp(1, 256)
+}
+
+/// 扫描带之间允许的最小高度
+const SLAB_EPSILON: f32 = 1e-5;
+
+/// 细分填充区域，三角形（每 3 个顶点一个）追加到 `out`
+///
+/// 未闭合的子路径按隐式闭合处理；三角形互不重叠，绕序不固定。
+pub fn tessellate_fill(polylines: &[Polyline], rule: FillRule, out: &mut Vec<Vec2>) {
+    // (上端点, 下端点, 方向)：y 向下为 +1
+    let mut edges: Vec<(Vec2, Vec2, i32)> = Vec::new();
+    for line in polylines {
+        let n = line.points.len();
+        if n < 3 {
+            continue;
+        }
+        for i in 0..n {
+            let (a, b) = (line.points[i], line.points[(i + 1) % n]);
+            if (a.y - b.y).abs() <= SLAB_EPSILON {
+                continue;
+            }
+            edges.push(if a.y < b.y { (a, b, 1) } else { (b, a, -1) });
+        }
+    }
+    if edges.is_empty() {
+        return;
+    }
+
+    // 扫描带边界：所有端点与边交点的 y
+    let mut ys: Vec<f32> = edges.iter().flat_map(|(a, b, _)| [a.y, b.y]).collect();
+    for i in 0..edges.len() {
+        for j in i + 1..edges.len() {
+            let ((a0, a1, _), (b0, b1, _)) = (edges[i], edges[j]);
+            if a1.y <= b0.y || b1.y <= a0.y {
+                continue;
+            }
+            let (r, s) = (a1 - a0, b1 - b0);
+            let denom = r.perp_dot(s);
+            if denom.abs() < 1e-9 {
+                continue;
+            }
+            let t = (b0 - a0).perp_dot(s) / denom;
+            let u = (b0 - a0).perp_dot(r) / denom;
+            if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 {
+                ys.push(a0.y + r.y * t);
+            }
+        }
+    }
+    ys.sort_by(f32::total_cmp);
+    ys.dedup_by(|a, b| (*a - *b).abs() <= SLAB_EPSILON);
+
+    let inside = |winding: i32| match rule {
+        FillRule::NonZero => winding != 0,
+        FillRule::EvenOdd => winding % 2 != 0,
+    };
+    let x_at = |(top, bottom, _): (Vec2, Vec2, i32), y: f32| {
+        top.x + (bottom.x - top.x) * ((y - top.y) / (bottom.y - top.y))
+    };
+    let mut crossings: Vec<(f32, f32, f32, i32)> = Vec::new();
+    for slab in ys.windows(2) {
+        let (y0, y1) = (slab[0], slab[1]);
+        if y1 - y0 <= SLAB_EPSILON {
+            continue;
+        }
+        let mid = 0.5 * (y0 + y1);
+        crossings.clear();
+        crossings.extend(
+            edges
+                .iter()
+                .filter(|(top, bottom, _)| top.y <= y0 + SLAB_EPSILON && bottom.y >= y1 - SLAB_EPSILON)
+                .map(|&edge| (x_at(edge, mid), x_at(edge, y0), x_at(edge, y1), edge.2)),
+        );
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+        let mut span_start = 0;
+        for (k, crossing) in crossings.iter().enumerate() {
+            let was_inside = inside(winding);
+            winding += crossing.3;
+            match (was_inside, inside(winding)) {
+                (false, true) => span_start = k,
+                (true, false) => {
+                    let left = crossings[span_start];
+                    let (a0, b0) = (Vec2::new(left.1, y0), Vec2::new(crossing.1, y0));
+                    let (a1, b1) = (Vec2::new(left.2, y1), Vec2::new(crossing.2, y1));
+                    out.extend([a0, b0, b1, a0, b1, a1]);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn perp(v: Vec2) -> Vec2 {
+    Vec2::new(-v.y, v.x)
+}
+
+/// 以 `center` 为圆心，从半径向量 `from` 旋转 `angle` 弧度的扇形
+fn arc_fan(center: Vec2, from: Vec2, angle: f32, tolerance: f32, out: &mut Vec<Vec2>) {
+    let radius = from.length();
+    if radius <= 0.0 {
+        return;
+    }
+    let max_step = 2.0 * (1.0 - (tolerance / radius).min(1.0)).acos();
+    let segments = ((angle.abs() / max_step.max(1e-3)).ceil() as usize).clamp(1, 128);
+    let mut previous = from;
+    for i in 1..=segments {
+        let next = Vec2::from_angle(angle * i as f32 / segments as f32).rotate(from);
+        out.extend([center, center + previous, center + next]);
+        previous = next;
+    }
+}
+
+/// 细分描边，三角形追加到 `out`
+///
+/// 三角形之间可能重叠（拐角处），由渲染器的逐形状深度保证只着色一次。
+pub fn tessellate_stroke(polylines: &[Polyline], stroke: &Stroke, tolerance: f32, out: &mut Vec<Vec2>) {
+    let hw = stroke.width * 0.5;
+    if hw <= 0.0 {
+        return;
+    }
+    let tolerance = tolerance.max(1e-3);
+    for line in polylines {
+        let mut points: Vec<Vec2> = Vec::with_capacity(line.points.len());
+        for &p in &line.points {
+            if points.last().is_none_or(|last: &Vec2| last.distance_squared(p) > 1e-12) {
+                points.push(p);
+            }
+        }
+        if line.closed && points.len() > 1 && points[0].distance_squared(points[points.len() - 1]) <= 1e-12 {
+            points.pop();
+        }
+        let n = points.len();
+        if n == 1 {
+            // 零长度子路径：圆头 / 方头画成点
+            let p = points[0];
+            match stroke.cap {
+                LineCap::Round => arc_fan(p, Vec2::new(hw, 0.0), std::f32::consts::TAU, tolerance, out),
+                LineCap::Square => {
+                    let (a, b) = (p - Vec2::splat(hw), p + Vec2::splat(hw));
+                    out.extend([a, Vec2::new(b.x, a.y), b, a, b, Vec2::new(a.x, b.y)]);
+                }
+                LineCap::Butt => {}
+            }
+            continue;
+        }
+        if n == 0 {
+            continue;
+        }
+
+        let closed = line.closed && n > 2;
+        let segment_total = if closed { n } else { n - 1 };
+        for i in 0..segment_total {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            let offset = perp((b - a).normalize()) * hw;
+            out.extend([a + offset, b + offset, b - offset, a + offset, b - offset, a - offset]);
+        }
+
+        let joins = if closed { 0..n } else { 1..n - 1 };
+        for i in joins {
+            let p = points[i];
+            let d0 = (p - points[(i + n - 1) % n]).normalize();
+            let d1 = (points[(i + 1) % n] - p).normalize();
+            stroke_join(p, d0, d1, hw, stroke, tolerance, out);
+        }
+
+        if !closed {
+            stroke_cap(points[0], (points[0] - points[1]).normalize(), hw, stroke.cap, tolerance, out);
+            stroke_cap(points[n - 1], (points[n - 1] - points[n - 2]).normalize(), hw, stroke.cap, tolerance, out);
+        }
+    }
+}
+
+fn stroke_join(p: Vec2, d0: Vec2, d1: Vec2, hw: f32, stroke: &Stroke, tolerance: f32, out: &mut Vec<Vec2>) {
+    let cross = d0.perp_dot(d1);
+    if cross.abs() < 1e-6 && d0.dot(d1) > 0.0 {
+        return;
+    }
+    // 外侧：左转（cross > 0）时在右侧
+    let side = if cross > 0.0 { -1.0 } else { 1.0 };
+    let (n0, n1) = (perp(d0) * hw * side, perp(d1) * hw * side);
+    match stroke.join {
+        LineJoin::Round => {
+            let angle = (n0.dot(n1) / (hw * hw)).clamp(-1.0, 1.0).acos();
+            let angle = if n0.perp_dot(n1) < 0.0 { -angle } else { angle };
+            arc_fan(p, n0, angle, tolerance, out);
+        }
+        LineJoin::Miter | LineJoin::Bevel => {
+            out.extend([p, p + n0, p + n1]);
+            if stroke.join == LineJoin::Miter {
+                let bisector = (n0 + n1).normalize_or_zero();
+                let cos_half = bisector.dot(n0) / hw;
+                if cos_half > 1e-4 && 1.0 / cos_half <= stroke.miter_limit {
+                    let tip = p + bisector * (hw / cos_half);
+                    out.extend([p + n0, tip, p + n1]);
+                }
+            }
+        }
+    }
+}
+
+fn stroke_cap(p: Vec2, outward: Vec2, hw: f32, cap: LineCap, tolerance: f32, out: &mut Vec<Vec2>) {
+    let normal = perp(outward) * hw;
+    match cap {
+        LineCap::Butt => {}
+        LineCap::Square => {
+            let e = outward * hw;
+            out.extend([p + normal, p - normal, p - normal + e, p + normal, p - normal + e, p + normal + e]);
+        }
+        // perp(outward) 顺时针旋转 90° 即为 outward，半圆经过端点外侧
+        LineCap::Round => arc_fan(p, normal, -std::f32::consts::PI, tolerance, out),
+    }
+}
+
+/// 单一颜色的一组三角形（局部空间）
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorPart {
+    /// 三角形顶点（每 3 个一个）
+    pub triangles: Vec<Vec2>,
+    /// 颜色（线性 RGBA）
+    pub color: Color,
+}
+
+/// 缓存的细分结果，按绘制顺序排列的单色部分
+///
+/// 由 [`vector_tessellate_system`] 插入与更新，路径、填充或描边变化时重建；
+/// 图表等程序化图形也可以直接构建（见 [`crate::renderer::chart`]）。
+/// 每个部分使用独立深度，部分内重叠的三角形只着色一次。
+#[derive(Debug, Clone, Default, PartialEq, Component)]
+pub struct VectorMesh {
+    /// 单色部分，后者覆盖前者
+    pub parts: Vec<VectorPart>,
+}
+
+impl VectorMesh {
+    /// 按当前组件细分：先填充后描边
+    pub fn tessellate(path: &Path, fill: Option<&Fill>, stroke: Option<&Stroke>) -> Self {
+        let mut mesh = Self::default();
+        if let Some(fill) = fill {
+            mesh.add_fill(path, fill);
+        }
+        if let Some(stroke) = stroke {
+            mesh.add_stroke(path, stroke);
+        }
+        mesh
+    }
+
+    /// 追加路径的填充
+    pub fn add_fill(&mut self, path: &Path, fill: &Fill) {
+        let mut triangles = Vec::new();
+        tessellate_fill(&flatten(&path.commands, path.tolerance), fill.rule, &mut triangles);
+        self.push(triangles, fill.color);
+    }
+
+    /// 追加路径的描边
+    pub fn add_stroke(&mut self, path: &Path, stroke: &Stroke) {
+        let mut triangles = Vec::new();
+        tessellate_stroke(&flatten(&path.commands, path.tolerance), stroke, path.tolerance, &mut triangles);
+        self.push(triangles, stroke.color);
+    }
+
+    /// 追加一组三角形（空集合与完全透明的颜色被忽略）
+    pub fn push(&mut self, triangles: Vec<Vec2>, color: Color) {
+        if !triangles.is_empty() && color.a > 0.0 {
+            self.parts.push(VectorPart { triangles, color });
+        }
+    }
+
+    /// 是否没有任何三角形
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+}
+
+/// [`vector_tessellate_system`] 查询的组件
+type VectorShapeQuery = (
+    Entity,
+    Ref<'static, Path>,
+    Option<Ref<'static, Fill>>,
+    Option<Ref<'static, Stroke>>,
+    Option<&'static mut VectorMesh>,
+);
+
+/// 矢量细分系统 (PostUpdate)
+///
+/// 新实体插入 [`VectorMesh`]；`Path`、`Fill`、`Stroke` 任一变化（含新增）时重新细分。
+pub fn vector_tessellate_system(mut commands: Commands, mut query: Query<VectorShapeQuery>) {
+    for (entity, path, fill, stroke, mesh) in &mut query {
+        let changed = path.is_changed()
+            || fill.as_ref().is_some_and(|f| f.is_changed())
+            || stroke.as_ref().is_some_and(|s| s.is_changed());
+        match mesh {
+            Some(mut mesh) if changed => *mesh = VectorMesh::tessellate(&path, fill.as_deref(), stroke.as_deref()),
+            Some(_) => {}
+            None => {
+                commands.entity(entity).insert(VectorMesh::tessellate(&path, fill.as_deref(), stroke.as_deref()));
+            }
+        }
+    }
+}
+
+/// 矢量三角形顶点 (28 字节)
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+pub struct VectorVertex {
+    /// Screen-space position in pixels.
+    pub position: [f32; 2],
+    /// Per-shape depth; later shapes use smaller values.
+    pub depth: f32,
+    /// Linear RGBA color (straight alpha).
+    pub color: [f32; 4],
+}
+
+crate::impl_vertex!(VectorVertex, [0 => Float32x2, 1 => Float32, 2 => Float32x4]);
+
+/// ECS 资源：每帧收集的矢量三角形（屏幕空间，按绘制顺序）
+#[derive(Resource, Default)]
+pub struct VectorCollected {
+    /// 三角形顶点
+    pub vertices: Vec<VectorVertex>,
+}
+
+impl VectorCollected {
+    /// 按绘制顺序重建顶点，网格经各自的 `Transform` 变换到屏幕空间
+    ///
+    /// 每个部分分配一个递减的深度值，后绘制者覆盖先绘制者。
+    pub fn rebuild(&mut self, meshes: &[(&VectorMesh, &Transform)]) {
+        self.vertices.clear();
+        let parts: usize = meshes.iter().map(|(mesh, _)| mesh.parts.len()).sum();
+        let mut index = 0usize;
+        for (mesh, transform) in meshes {
+            let matrix = transform.compute_matrix();
+            for part in &mesh.parts {
+                index += 1;
+                let depth = 1.0 - index as f32 / (parts + 1) as f32;
+                let color = part.color.to_array();
+                self.vertices.extend(part.triangles.iter().map(|p| VectorVertex {
+                    position: matrix.transform_point3(p.extend(0.0)).truncate().to_array(),
+                    depth,
+                    color,
+                }));
+            }
+        }
+    }
+}
+
+/// 矢量收集系统 (PostUpdate)
+///
+/// 按 (`Layer`, `RenderOrder`, z) 排序后交给 [`VectorCollected::rebuild`]。
+pub fn vector_collect_system(
+    query: Query<(&VectorMesh, &Transform, Option<&crate::component::Layer>, Option<&crate::component::RenderOrder>)>,
+    mut collected: ResMut<VectorCollected>,
+) {
+    let mut order: Vec<_> = query
+        .iter()
+        .map(|(mesh, transform, layer, render_order)| {
+            (crate::component::draw_sort_key(layer, render_order), transform.translation.z, mesh, transform)
+        })
+        .collect();
+    order.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    let meshes: Vec<_> = order.into_iter().map(|(_, _, mesh, transform)| (mesh, transform)).collect();
+    collected.rebuild(&meshes);
+}
+
+// ---------------------------------------------------------------------------
+//  VectorRenderer — MSAA layer + premultiplied composite
+// ---------------------------------------------------------------------------
+
+struct VectorTargets {
+    size: (u32, u32),
+    color_msaa: wgpu::TextureView,
+    resolved: wgpu::TextureView,
+    depth: wgpu::TextureView,
+    layer_bind_group: wgpu::BindGroup,
+}
+
+/// GPU 矢量渲染器
+pub struct VectorRenderer {
+    format: wgpu::TextureFormat,
+    vector_pipeline: wgpu::RenderPipeline,
+    composite_pipeline: wgpu::RenderPipeline,
+    ortho_buffer: wgpu::Buffer,
+    ortho_bind_group: wgpu::BindGroup,
+    texture_bind_group_layout: wgpu::BindGroupLayout,
+    targets: Option<VectorTargets>,
+    vertex_buffer: CachedBuffer,
+}
+
+impl VectorRenderer {
+    /// 创建矢量渲染器，`format` 为合成目标格式
+    pub fn new(device: &RenderDevice, format: wgpu::TextureFormat) -> Self {
+        let d = device.device();
+        let shader = d.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Vector Shader"),
+            source: wgpu::ShaderSource::Wgsl(VECTOR_SHADER.into()),
+        });
+
+        let ortho_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Vector Ortho BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let texture_bgl = d.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Vector Layer BGL"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+        let vector_layout = d.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Vector Pipeline Layout"),
+            bind_group_layouts: &[&ortho_bgl],
+            push_constant_ranges: &[],
+        });
+        let composite_layout = d.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Vector Composite Layout"),
+            bind_group_layouts: &[&ortho_bgl, &texture_bgl],
+            push_constant_ranges: &[],
+        });
+
+        // 预乘 Alpha 的 "over"
+        let premultiplied = wgpu::BlendComponent {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+            operation: wgpu::BlendOperation::Add,
+        };
+        let blend = Some(wgpu::BlendState { color: premultiplied, alpha: premultiplied });
+        let vector_pipeline = d.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Vector Pipeline"),
+            layout: Some(&vector_layout),
+            vertex: wgpu::VertexState { module: &shader, entry_point: "vs_main", buffers: &[VectorVertex::layout()] },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState { format, blend, write_mask: wgpu::ColorWrites::ALL })],
+            }),
+            primitive: wgpu::PrimitiveState { topology: wgpu::PrimitiveTopology::TriangleList, ..Default::default() },
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            multisample: wgpu::MultisampleState { count: VECTOR_SAMPLE_COUNT, ..Default::default() },
+            multiview: None,
+        });
+        let composite_pipeline = d.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Vector Composite Pipeline"),
+            layout: Some(&composite_layout),
+            vertex: wgpu::VertexState { module: &shader, entry_point: "vs_composite", buffers: &[] },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_composite",
+                targets: &[Some(wgpu::ColorTargetState { format, blend, write_mask: wgpu::ColorWrites::ALL })],
+            }),
+            primitive: wgpu::PrimitiveState { topology: wgpu::PrimitiveTopology::TriangleList, ..Default::default() },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        });
+
+        let ortho_buffer = d.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Vector Ortho UB"),
+            contents: bytemuck::bytes_of(&MatrixUniform::identity()),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+        let ortho_bind_group = d.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Vector Ortho BG"),
+            layout: &ortho_bgl,
+            entries: &[wgpu::BindGroupEntry { binding: 0, resource: ortho_buffer.as_entire_binding() }],
+        });
+
+        Self {
+            format,
+            vector_pipeline,
+            composite_pipeline,
+            ortho_buffer,
+            ortho_bind_group,
+            texture_bind_group_layout: texture_bgl,
+            targets: None,
+            vertex_buffer: CachedBuffer::vertex("Vector VB (cached)"),
+        }
+    }
+
+    fn ensure_targets(&mut self, device: &RenderDevice, size: (u32, u32)) {
+        if self.targets.as_ref().is_some_and(|t| t.size == size) {
+            return;
+        }
+        let d = device.device();
+        let texture = |label: &str, format, sample_count, usage| {
+            d.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d { width: size.0, height: size.1, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage,
+                view_formats: &[],
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default())
+        };
+        let attachment = wgpu::TextureUsages::RENDER_ATTACHMENT;
+        let color_msaa = texture("Vector Layer MSAA", self.format, VECTOR_SAMPLE_COUNT, attachment);
+        let resolved = texture("Vector Layer", self.format, 1, attachment | wgpu::TextureUsages::TEXTURE_BINDING);
+        let depth = texture("Vector Depth MSAA", DEPTH_FORMAT, VECTOR_SAMPLE_COUNT, attachment);
+        let layer_bind_group = d.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Vector Layer BG"),
+            layout: &self.texture_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&resolved) }],
+        });
+        self.targets = Some(VectorTargets { size, color_msaa, resolved, depth, layer_bind_group });
+    }
+
+    /// 绘制矢量图层并合成到 `target`
+    ///
+    /// `target` 的尺寸需为 `screen_width × screen_height`，格式与创建时一致。
+    pub fn render(
+        &mut self,
+        device: &RenderDevice,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        frame: &VectorCollected,
+        screen_width: u32,
+        screen_height: u32,
+    ) {
+        if screen_width == 0 || screen_height == 0 || frame.vertices.is_empty() {
+            return;
+        }
+        self.ensure_targets(device, (screen_width, screen_height));
+
+        // 深度直接取顶点的 depth（near = 0, far = 1）
+        let ortho = Mat4::orthographic_lh(0.0, screen_width as f32, screen_height as f32, 0.0, 0.0, 1.0);
+        device.queue().write_buffer(&self.ortho_buffer, 0, bytemuck::bytes_of(&MatrixUniform::from_mat4(&ortho)));
+        let vb = self.vertex_buffer.ensure_and_write(device.device(), device.queue(), bytemuck::cast_slice(&frame.vertices));
+        let targets = self.targets.as_ref().expect("targets created above");
+
+        {
+            let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("Vector Layer Pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &targets.color_msaa,
+                    resolve_target: Some(&targets.resolved),
+                    ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT), store: wgpu::StoreOp::Discard },
+                })],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &targets.depth,
+                    depth_ops: Some(wgpu::Operations { load: wgpu::LoadOp::Clear(1.0), store: wgpu::StoreOp::Discard }),
+                    stencil_ops: None,
+                }),
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+            rp.set_pipeline(&self.vector_pipeline);
+            rp.set_bind_group(0, &self.ortho_bind_group, &[]);
+            rp.set_vertex_buffer(0, vb.slice(..));
+            rp.draw(0..frame.vertices.len() as u32, 0..1);
+        }
+
+        let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Vector Composite Pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations { load: wgpu::LoadOp::Load, store: wgpu::StoreOp::Store },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        rp.set_pipeline(&self.composite_pipeline);
+        rp.set_bind_group(0, &self.ortho_bind_group, &[]);
+        rp.set_bind_group(1, &targets.layer_bind_group, &[]);
+        rp.draw(0..3, 0..1);
+    }
+}
+
+/// 矢量阶段项（有三角形时每帧入队一个）
+pub struct VectorItem;
+
+impl PhaseItem for VectorItem {
+    type SortKey = ();
+    fn sort_key(&self) {}
+}
+
+/// 矢量入队系统 (PostUpdate)
+pub fn vector_queue_system(collected: Res<VectorCollected>, mut phase: ResMut<RenderPhase<VectorItem>>) {
+    if !collected.vertices.is_empty() {
+        phase.add(VectorItem);
+    }
+}
+
+/// 2D 矢量路径插件
+///
+/// 注册细分、收集与入队系统，并在色调映射之后（2D 光照之后、诊断叠加层之前）合成矢量图层。
+pub struct VectorPlugin;
+
+impl Plugin for VectorPlugin {
+    fn build(&self, app: &mut App) {
+        let gpu: Mutex<Option<VectorRenderer>> = Mutex::new(None);
+        app.init_resource::<VectorCollected>()
+            .add_render_phase::<VectorItem>(
+                RenderPhaseDescriptor::new("Vector2D", PhaseSlot::AfterTonemap).with_priority(-1000),
+                move |world, _items, ctx| {
+                    let (Some(frame), Some(state)) =
+                        (world.get_resource::<VectorCollected>(), world.get_resource::<RenderState>())
+                    else {
+                        return;
+                    };
+                    let Ok(mut gpu) = gpu.lock() else { return };
+                    if gpu.as_ref().is_none_or(|r| r.format != ctx.color_format) {
+                        *gpu = Some(VectorRenderer::new(ctx.device, ctx.color_format));
+                    }
+                    let renderer = gpu.as_mut().expect("renderer created above");
+                    let (width, height) = state.surface_size;
+                    renderer.render(ctx.device, ctx.encoder, ctx.color_target, frame, width, height);
+                },
+            )
+            .add_systems(
+                bevy_app::PostUpdate,
+                (vector_tessellate_system, vector_collect_system, vector_queue_system).chain(),
+            );
+    }
+
+    fn name(&self) -> &str {
+        "VectorPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(triangles: &[Vec2]) -> f32 {
+        triangles.chunks_exact(3).map(|t| 0.5 * (t[1] - t[0]).perp_dot(t[2] - t[0]).abs()).sum()
+    }
+
+    fn square(origin: Vec2, size: f32) -> Vec<PathCommand> {
+        Path::rect(origin, Vec2::splat(size)).commands
+    }
+
+    #[test]
+    fn test_flatten_curves_within_tolerance() {
+        let circle = Path::circle(Vec2::new(50.0, 50.0), 40.0);
+        let lines = flatten(&circle.commands, 0.1);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].closed);
+        assert!(lines[0].points.len() > 16);
+        for p in &lines[0].points {
+            assert!((p.distance(Vec2::new(50.0, 50.0)) - 40.0).abs() < 0.1);
+        }
+        // 更粗的容差产生更少的顶点
+        assert!(flatten(&circle.commands, 2.0)[0].points.len() < lines[0].points.len());
+    }
+
+    #[test]
+    fn test_fill_rules_and_holes() {
+        // 同向嵌套的两个正方形：非零规则填满，奇偶规则挖空内部
+        let mut commands = square(Vec2::ZERO, 10.0);
+        commands.extend(square(Vec2::splat(2.5), 5.0));
+        let lines = flatten(&commands, DEFAULT_TOLERANCE);
+
+        let mut nonzero = Vec::new();
+        tessellate_fill(&lines, FillRule::NonZero, &mut nonzero);
+        assert!((area(&nonzero) - 100.0).abs() < 1e-3);
+
+        let mut even_odd = Vec::new();
+        tessellate_fill(&lines, FillRule::EvenOdd, &mut even_odd);
+        assert!((area(&even_odd) - 75.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_fill_self_intersecting_bowtie() {
+        // 蝴蝶结：两个三角形各 25
+        let bowtie = Path::new()
+            .move_to(Vec2::ZERO)
+            .line_to(Vec2::new(10.0, 10.0))
+            .line_to(Vec2::new(10.0, 0.0))
+            .line_to(Vec2::new(0.0, 10.0))
+            .close();
+        let mut out = Vec::new();
+        tessellate_fill(&flatten(&bowtie.commands, DEFAULT_TOLERANCE), FillRule::NonZero, &mut out);
+        assert!((area(&out) - 50.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_stroke_caps_and_joins() {
+        let line = flatten(&Path::new().move_to(Vec2::ZERO).line_to(Vec2::new(10.0, 0.0)).commands, DEFAULT_TOLERANCE);
+        let stroke = Stroke::new(Color::BLACK, 2.0);
+
+        let mut butt = Vec::new();
+        tessellate_stroke(&line, &stroke, DEFAULT_TOLERANCE, &mut butt);
+        assert!((area(&butt) - 20.0).abs() < 1e-4);
+
+        let mut square_cap = Vec::new();
+        tessellate_stroke(&line, &stroke.with_cap(LineCap::Square), DEFAULT_TOLERANCE, &mut square_cap);
+        assert!((area(&square_cap) - 24.0).abs() < 1e-4);
+        assert!(square_cap.iter().any(|p| p.x < -0.5) && square_cap.iter().any(|p| p.x > 10.5));
+
+        let mut round = Vec::new();
+        tessellate_stroke(&line, &stroke.with_cap(LineCap::Round), 0.01, &mut round);
+        assert!((area(&round) - (20.0 + std::f32::consts::PI)).abs() < 0.1);
+
+        // 直角拐弯：斜接在外侧补一个角，斜切只补三角形
+        let corner = flatten(
+            &Path::new().move_to(Vec2::ZERO).line_to(Vec2::new(10.0, 0.0)).line_to(Vec2::new(10.0, 10.0)).commands,
+            DEFAULT_TOLERANCE,
+        );
+        let mut miter = Vec::new();
+        tessellate_stroke(&corner, &stroke, DEFAULT_TOLERANCE, &mut miter);
+        assert!(miter.iter().any(|p| p.distance(Vec2::new(11.0, -1.0)) < 1e-4));
+        let mut bevel = Vec::new();
+        tessellate_stroke(&corner, &stroke.with_join(LineJoin::Bevel), DEFAULT_TOLERANCE, &mut bevel);
+        assert!(!bevel.iter().any(|p| p.distance(Vec2::new(11.0, -1.0)) < 1e-4));
+        assert!(bevel.len() < miter.len());
+    }
+
+    #[test]
+    fn test_tessellate_and_collect_systems() {
+        let mut world = World::new();
+        world.init_resource::<VectorCollected>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems((vector_tessellate_system, vector_collect_system).chain());
+
+        let below = world
+            .spawn((
+                Path::rect(Vec2::ZERO, Vec2::splat(10.0)),
+                Fill::color(Color::RED),
+                Stroke::new(Color::BLACK, 1.0),
+                Transform::from_xyz(100.0, 50.0, 0.0),
+            ))
+            .id();
+        world.spawn((Path::circle(Vec2::ZERO, 5.0), Fill::color(Color::BLUE), Transform::from_xyz(0.0, 0.0, 1.0)));
+        // 没有填充与描边的路径不产生顶点
+        world.spawn((Path::circle(Vec2::ZERO, 5.0), Transform::default()));
+        schedule.run(&mut world);
+
+        let collected = world.resource::<VectorCollected>();
+        let first = collected.vertices[0];
+        assert_eq!(first.color, Color::RED.to_array());
+        assert!(first.position[0] >= 100.0 && first.position[1] >= 50.0);
+        // 三个部分的深度依次递减：红色填充、黑色描边、蓝色圆
+        let mut depths: Vec<f32> = collected.vertices.iter().map(|v| v.depth).collect();
+        depths.dedup();
+        assert_eq!(depths, vec![0.75, 0.5, 0.25]);
+
+        // 修改路径后重新细分
+        let before = world.get::<VectorMesh>(below).unwrap().parts[0].clone();
+        world.get_mut::<Path>(below).unwrap().commands = square(Vec2::ZERO, 20.0);
+        schedule.run(&mut world);
+        let after = &world.get::<VectorMesh>(below).unwrap().parts[0];
+        assert_ne!(&before, after);
+        assert_eq!(after.color, Color::RED);
+        assert!((area(&after.triangles) - 400.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_svg_shapes_convert_styles() {
+        let doc = anvilkit_assets::svg_loader::parse_svg(
+            r#"<svg width="10" height="10"><rect width="4" height="4" fill="red" stroke="blue" stroke-linecap="round"/></svg>"#,
+        )
+        .unwrap();
+        let shapes = svg_shapes(&doc);
+        assert_eq!(shapes.len(), 1);
+        let (path, fill, stroke) = &shapes[0];
+        assert_eq!(path.commands.len(), 5);
+        assert_eq!(fill.unwrap().color, Color::RED);
+        assert_eq!(stroke.unwrap().cap, LineCap::Round);
+    }
+}
@@ -0,0 +1,368 @@
+//! # 世界空间 UI 锚点
+//!
+//! [`WorldUiAnchor`] 让 [`UiNode`] 跟随 3D 实体（血条、名牌等）：[`world_ui_system`] 每帧把
+//! 目标实体的世界位置经活动相机投影到屏幕，并以投影点为中心写入 `computed_rect`。
+//!
+//! - 目标离开屏幕时按 [`OffscreenBehavior`] 隐藏节点，或将其夹紧到屏幕边缘（指示方向）
+//! - 存在 [`SceneBvh`] 时，从相机到锚点的射线被其他实体遮挡会使节点平滑淡出到
+//!   [`WorldUiAnchor::occluded_opacity`]
+//!
+//! 节点的透明度按 [`WorldUiState::opacity`] 缩放背景、边框与文字颜色的 alpha，
+//! 基准 alpha 在首次同步时记录。
+//!
+//! ```rust
+//! use anvilkit_render::renderer::world_ui::project_to_screen;
+//! use glam::{Mat4, Vec2, Vec3};
+//!
+//! let view = Mat4::look_at_lh(Vec3::new(0.0, 0.0, -10.0), Vec3::ZERO, Vec3::Y);
+//! let proj = Mat4::perspective_lh(60.0_f32.to_radians(), 1.0, 0.1, 100.0);
+//! let (screen, in_front) = project_to_screen(&(proj * view), Vec3::ZERO, Vec2::new(800.0, 800.0));
+//! assert!(in_front);
+//! assert!(screen.abs_diff_eq(Vec2::new(400.0, 400.0), 1e-3));
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::GlobalTransform;
+use anvilkit_core::time::DeltaTime;
+use glam::{Mat4, Vec2, Vec3};
+
+use super::draw::ActiveCamera;
+use super::ui::UiNode;
+use super::ui_anchor::{ui_anchor_system, UiAnchorPlugin, UiViewport};
+use crate::spatial::SceneBvh;
+
+/// 目标离开屏幕时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffscreenBehavior {
+    /// 隐藏节点
+    #[default]
+    Hide,
+    /// 夹紧到屏幕边缘
+    Clamp,
+}
+
+/// 世界空间 UI 锚点
+///
+/// 放在 UI 节点实体上，`target` 为被跟随的 3D 实体。
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+#[require(UiNode, WorldUiState)]
+pub struct WorldUiAnchor {
+    /// 被跟随的实体（需要 `GlobalTransform`）
+    pub target: Entity,
+    /// 目标局部空间中的锚点偏移（例如头顶上方）
+    pub offset: Vec3,
+    /// 节点尺寸（逻辑像素）
+    pub size: Vec2,
+    /// 投影后的屏幕偏移（逻辑像素，+Y 向下）
+    pub screen_offset: Vec2,
+    /// 离开屏幕时的处理方式
+    pub offscreen: OffscreenBehavior,
+    /// 夹紧时与屏幕边缘的距离（逻辑像素）
+    pub edge_margin: f32,
+    /// 被遮挡时的目标透明度；`None` 时不做遮挡检测
+    pub occluded_opacity: Option<f32>,
+    /// 透明度过渡速度（每秒）
+    pub fade_speed: f32,
+}
+
+impl WorldUiAnchor {
+    /// 跟随 `target`，节点尺寸为 `size`（逻辑像素）
+    pub fn new(target: Entity, size: Vec2) -> Self {
+        Self {
+            target,
+            offset: Vec3::ZERO,
+            size,
+            screen_offset: Vec2::ZERO,
+            offscreen: OffscreenBehavior::Hide,
+            edge_margin: 8.0,
+            occluded_opacity: None,
+            fade_speed: 8.0,
+        }
+    }
+
+    /// 设置锚点偏移（目标局部空间）
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// 设置屏幕偏移（逻辑像素）
+    pub fn with_screen_offset(mut self, screen_offset: Vec2) -> Self {
+        self.screen_offset = screen_offset;
+        self
+    }
+
+    /// 离开屏幕时夹紧到边缘
+    pub fn clamped(mut self, edge_margin: f32) -> Self {
+        self.offscreen = OffscreenBehavior::Clamp;
+        self.edge_margin = edge_margin;
+        self
+    }
+
+    /// 被遮挡时淡出到 `opacity`
+    pub fn with_occlusion_fade(mut self, opacity: f32) -> Self {
+        self.occluded_opacity = Some(opacity.clamp(0.0, 1.0));
+        self
+    }
+}
+
+/// 世界空间 UI 的同步结果，由 [`world_ui_system`] 写入
+#[derive(Debug, Clone, Copy, PartialEq, Component)]
+pub struct WorldUiState {
+    /// 节点中心（物理像素）
+    pub screen_position: Vec2,
+    /// 锚点是否在屏幕内且位于相机前方
+    pub on_screen: bool,
+    /// 是否被夹紧到屏幕边缘
+    pub clamped: bool,
+    /// 是否被遮挡
+    pub occluded: bool,
+    /// 当前透明度
+    pub opacity: f32,
+    /// 背景、边框、文字颜色的基准 alpha
+    base_alpha: Option<[f32; 3]>,
+}
+
+impl Default for WorldUiState {
+    fn default() -> Self {
+        Self {
+            screen_position: Vec2::ZERO,
+            on_screen: false,
+            clamped: false,
+            occluded: false,
+            opacity: 1.0,
+            base_alpha: None,
+        }
+    }
+}
+
+/// 将世界坐标投影到屏幕（物理像素，原点左上）
+///
+/// 返回屏幕坐标与点是否位于相机前方。点在相机后方时屏幕坐标按镜像方向给出，
+/// 夹紧到边缘后仍指向目标所在的一侧。
+pub fn project_to_screen(view_proj: &Mat4, world: Vec3, viewport: Vec2) -> (Vec2, bool) {
+    let clip = *view_proj * world.extend(1.0);
+    let in_front = clip.w > 1e-5;
+    // 相机后方的点除以负 w 会翻转方向；用 |w| 保持指向
+    let ndc = clip.truncate().truncate() / clip.w.abs().max(1e-5);
+    let screen = Vec2::new((ndc.x + 1.0) * 0.5 * viewport.x, (1.0 - ndc.y) * 0.5 * viewport.y);
+    (screen, in_front)
+}
+
+/// 将节点中心夹紧到屏幕内：沿屏幕中心指向 `position` 的方向收缩到边缘
+///
+/// `half_size` 为节点半尺寸，`margin` 为与边缘的距离（均为物理像素）。
+pub fn clamp_to_screen(position: Vec2, viewport: Vec2, half_size: Vec2, margin: f32) -> Vec2 {
+    let center = viewport * 0.5;
+    let limit = (center - half_size - Vec2::splat(margin)).max(Vec2::ZERO);
+    let offset = position - center;
+    let scale = [offset.x.abs(), offset.y.abs()]
+        .into_iter()
+        .zip([limit.x, limit.y])
+        .filter(|(d, _)| *d > f32::EPSILON)
+        .map(|(d, l)| l / d)
+        .fold(1.0_f32, f32::min);
+    center + offset * scale
+}
+
+/// 世界空间 UI 同步系统 (PostUpdate，相机、场景 BVH 与锚点布局之后)
+pub fn world_ui_system(
+    active_camera: Res<ActiveCamera>,
+    viewport: Res<UiViewport>,
+    scene_bvh: Option<Res<SceneBvh>>,
+    dt: Option<Res<DeltaTime>>,
+    targets: Query<&GlobalTransform>,
+    mut anchors: Query<(&WorldUiAnchor, &mut WorldUiState, &mut UiNode)>,
+) {
+    let dt = dt.map_or(1.0 / 60.0, |d| d.0);
+    let scale = viewport.scale_factor;
+    let screen = viewport.physical_size;
+
+    for (anchor, mut state, mut node) in anchors.iter_mut() {
+        let Ok(target) = targets.get(anchor.target) else {
+            node.visible = false;
+            state.on_screen = false;
+            continue;
+        };
+        let world = target.0.transform_point3(anchor.offset);
+        let (projected, in_front) = project_to_screen(&active_camera.view_proj, world, screen);
+        let position = projected + anchor.screen_offset * scale;
+        let size = anchor.size * scale;
+
+        let inside = in_front && position.cmpge(Vec2::ZERO).all() && position.cmple(screen).all();
+        let (position, clamped, shown) = match anchor.offscreen {
+            _ if inside => (position, false, true),
+            OffscreenBehavior::Hide => (position, false, false),
+            OffscreenBehavior::Clamp => {
+                (clamp_to_screen(position, screen, size * 0.5, anchor.edge_margin * scale), true, true)
+            }
+        };
+
+        // 遮挡：相机到锚点之间最近的命中不是目标本身
+        let occluded = match (&scene_bvh, anchor.occluded_opacity) {
+            (Some(bvh), Some(_)) if inside => {
+                let to_anchor = world - active_camera.camera_pos;
+                let distance = to_anchor.length();
+                distance > 1e-4
+                    && bvh
+                        .ray_cast(active_camera.camera_pos, to_anchor / distance, distance - 1e-3)
+                        .is_some_and(|(hit, _)| hit != anchor.target)
+            }
+            _ => false,
+        };
+
+        let goal = if occluded { anchor.occluded_opacity.unwrap_or(1.0) } else { 1.0 };
+        let follow = 1.0 - (-anchor.fade_speed * dt).exp();
+        let opacity = state.opacity + (goal - state.opacity) * follow;
+
+        state.screen_position = position;
+        state.on_screen = inside;
+        state.clamped = clamped;
+        state.occluded = occluded;
+        state.opacity = opacity;
+
+        let base = *state.base_alpha.get_or_insert([
+            node.background_color[3],
+            node.border_color[3],
+            node.text.as_ref().map_or(1.0, |t| t.color[3]),
+        ]);
+        node.background_color[3] = base[0] * opacity;
+        node.border_color[3] = base[1] * opacity;
+        if let Some(text) = node.text.as_mut() {
+            text.color[3] = base[2] * opacity;
+        }
+
+        node.visible = shown;
+        node.computed_rect = [position.x - size.x * 0.5, position.y - size.y * 0.5, size.x, size.y];
+    }
+}
+
+/// 世界空间 UI 插件
+///
+/// 未添加 [`UiAnchorPlugin`] 时一并添加（由其维护 [`UiViewport`]）。
+pub struct WorldUiPlugin;
+
+impl Plugin for WorldUiPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UiAnchorPlugin>() {
+            app.add_plugins(UiAnchorPlugin);
+        }
+        app.init_resource::<ActiveCamera>().add_systems(
+            bevy_app::PostUpdate,
+            world_ui_system
+                .after(ui_anchor_system)
+                .after(crate::plugin::camera_system)
+                .after(crate::spatial::scene_bvh_system),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "WorldUiPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::draw::Aabb;
+
+    fn camera() -> ActiveCamera {
+        let view = Mat4::look_at_lh(Vec3::new(0.0, 0.0, -10.0), Vec3::ZERO, Vec3::Y);
+        let proj = Mat4::perspective_lh(90.0_f32.to_radians(), 1.0, 0.1, 100.0);
+        ActiveCamera { view_proj: proj * view, camera_pos: Vec3::new(0.0, 0.0, -10.0), ..Default::default() }
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(WorldUiPlugin);
+        app.insert_resource(camera());
+        app.insert_resource(UiViewport { physical_size: Vec2::new(800.0, 800.0), ..Default::default() });
+        app.insert_resource(DeltaTime(0.1));
+        app
+    }
+
+    fn at(position: Vec3) -> GlobalTransform {
+        GlobalTransform(Mat4::from_translation(position))
+    }
+
+    #[test]
+    fn test_clamp_to_screen_keeps_direction() {
+        let viewport = Vec2::new(800.0, 600.0);
+        let clamped = clamp_to_screen(Vec2::new(1600.0, 300.0), viewport, Vec2::splat(10.0), 5.0);
+        assert_eq!(clamped, Vec2::new(785.0, 300.0));
+        let clamped = clamp_to_screen(Vec2::new(400.0 - 1000.0, 300.0 - 2000.0), viewport, Vec2::ZERO, 0.0);
+        assert!(clamped.abs_diff_eq(Vec2::new(250.0, 0.0), 1e-3));
+        // 屏幕内的点不变
+        assert_eq!(clamp_to_screen(Vec2::new(100.0, 100.0), viewport, Vec2::ZERO, 0.0), Vec2::new(100.0, 100.0));
+    }
+
+    #[test]
+    fn test_project_behind_camera_mirrors() {
+        let cam = camera();
+        let (_, in_front) = project_to_screen(&cam.view_proj, Vec3::new(5.0, 0.0, -20.0), Vec2::splat(800.0));
+        assert!(!in_front);
+        let (screen, _) = project_to_screen(&cam.view_proj, Vec3::new(5.0, 0.0, -20.0), Vec2::splat(800.0));
+        // 目标在相机右后方，镜像后仍在屏幕右侧
+        assert!(screen.x > 400.0);
+    }
+
+    #[test]
+    fn test_follow_hide_and_clamp() {
+        let mut app = test_app();
+        let target = app.world_mut().spawn(at(Vec3::ZERO)).id();
+        let label = app.world_mut().spawn(WorldUiAnchor::new(target, Vec2::new(100.0, 20.0)).with_offset(Vec3::Y)).id();
+        let marker = app.world_mut().spawn(WorldUiAnchor::new(target, Vec2::new(20.0, 20.0)).clamped(10.0)).id();
+        app.update();
+
+        let node = app.world().get::<UiNode>(label).unwrap();
+        assert!(node.visible);
+        assert!((node.computed_rect[0] - 350.0).abs() < 1e-3);
+        assert!(node.computed_rect[1] < 390.0);
+
+        // 目标移出屏幕右侧
+        app.world_mut().entity_mut(target).insert(at(Vec3::new(100.0, 0.0, 0.0)));
+        app.update();
+        assert!(!app.world().get::<UiNode>(label).unwrap().visible);
+        let state = app.world().get::<WorldUiState>(marker).unwrap();
+        assert!(state.clamped && !state.on_screen);
+        assert!((state.screen_position.x - 780.0).abs() < 1e-3);
+        assert!(app.world().get::<UiNode>(marker).unwrap().visible);
+
+        // 目标被销毁
+        app.world_mut().despawn(target);
+        app.update();
+        assert!(!app.world().get::<UiNode>(marker).unwrap().visible);
+    }
+
+    #[test]
+    fn test_occlusion_fade() {
+        let mut app = test_app();
+        app.add_plugins(crate::spatial::SceneBvhPlugin);
+        let target = app.world_mut().spawn((at(Vec3::new(0.0, 0.0, 5.0)), Aabb::default())).id();
+        let wall = app.world_mut().spawn((at(Vec3::ZERO), Aabb::default())).id();
+        let label = app
+            .world_mut()
+            .spawn((
+                WorldUiAnchor::new(target, Vec2::splat(10.0)).with_occlusion_fade(0.2),
+                UiNode { background_color: [1.0, 0.0, 0.0, 0.5], ..Default::default() },
+            ))
+            .id();
+        for _ in 0..30 {
+            app.update();
+        }
+        let state = *app.world().get::<WorldUiState>(label).unwrap();
+        assert!(state.occluded);
+        assert!((state.opacity - 0.2).abs() < 0.01);
+        assert!((app.world().get::<UiNode>(label).unwrap().background_color[3] - 0.1).abs() < 0.01);
+
+        // 移开遮挡物后恢复
+        app.world_mut().entity_mut(wall).insert(at(Vec3::new(50.0, 0.0, 0.0)));
+        for _ in 0..30 {
+            app.update();
+        }
+        let state = *app.world().get::<WorldUiState>(label).unwrap();
+        assert!(!state.occluded);
+        assert!((state.opacity - 1.0).abs() < 0.01);
+    }
+}
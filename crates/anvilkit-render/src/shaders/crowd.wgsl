@@ -0,0 +1,99 @@
+// AnvilKit Crowd Shader
+// Instanced skinned meshes driven by baked animation textures.
+// Each texture row is one sampled frame; each joint occupies 3 texels holding
+// the rows of its 3x4 affine bone matrix. Instances carry their own clip and
+// time offset, so no per-entity CPU animation work is needed.
+
+struct CrowdUniform {
+    view_proj: mat4x4<f32>,
+    light_dir: vec4<f32>,
+    // x = elapsed seconds
+    params: vec4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> scene: CrowdUniform;
+@group(1) @binding(0) var bone_texture: texture_2d<f32>;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+    @location(2) texcoord: vec2<f32>,
+    @location(3) tangent: vec4<f32>,
+    @location(4) joint_indices: vec4<u32>,
+    @location(5) joint_weights: vec4<f32>,
+};
+
+struct InstanceInput {
+    @location(6) model_0: vec4<f32>,
+    @location(7) model_1: vec4<f32>,
+    @location(8) model_2: vec4<f32>,
+    @location(9) model_3: vec4<f32>,
+    // first_row, frame_count, sample_rate, looping
+    @location(10) clip: vec4<f32>,
+    // time_offset, speed
+    @location(11) playback: vec4<f32>,
+    @location(12) tint: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_normal: vec3<f32>,
+    @location(1) tint: vec4<f32>,
+};
+
+fn bone_matrix(row: u32, joint: u32) -> mat4x4<f32> {
+    let x = joint * 3u;
+    let r0 = textureLoad(bone_texture, vec2<u32>(x, row), 0);
+    let r1 = textureLoad(bone_texture, vec2<u32>(x + 1u, row), 0);
+    let r2 = textureLoad(bone_texture, vec2<u32>(x + 2u, row), 0);
+    return mat4x4<f32>(
+        vec4<f32>(r0.x, r1.x, r2.x, 0.0),
+        vec4<f32>(r0.y, r1.y, r2.y, 0.0),
+        vec4<f32>(r0.z, r1.z, r2.z, 0.0),
+        vec4<f32>(r0.w, r1.w, r2.w, 1.0),
+    );
+}
+
+fn skin_matrix(row: u32, in: VertexInput) -> mat4x4<f32> {
+    return bone_matrix(row, in.joint_indices.x) * in.joint_weights.x
+         + bone_matrix(row, in.joint_indices.y) * in.joint_weights.y
+         + bone_matrix(row, in.joint_indices.z) * in.joint_weights.z
+         + bone_matrix(row, in.joint_indices.w) * in.joint_weights.w;
+}
+
+@vertex
+fn vs_main(in: VertexInput, instance: InstanceInput) -> VertexOutput {
+    // Mirrors CrowdClip::frame_at on the CPU side
+    let frame_count = max(instance.clip.y, 1.0);
+    let last = frame_count - 1.0;
+    var frame = (scene.params.x * instance.playback.y + instance.playback.x) * instance.clip.z;
+    if (instance.clip.w > 0.5 && last > 0.0) {
+        frame = frame - floor(frame / last) * last;
+    } else {
+        frame = clamp(frame, 0.0, last);
+    }
+    let f0 = floor(frame);
+    let f1 = min(f0 + 1.0, last);
+    let blend = frame - f0;
+    let first_row = u32(instance.clip.x);
+
+    let skin = skin_matrix(first_row + u32(f0), in) * (1.0 - blend)
+             + skin_matrix(first_row + u32(f1), in) * blend;
+    let model = mat4x4<f32>(instance.model_0, instance.model_1, instance.model_2, instance.model_3);
+
+    let world_pos = model * skin * vec4<f32>(in.position, 1.0);
+    var out: VertexOutput;
+    out.clip_position = scene.view_proj * world_pos;
+    // Uniform-scale assumption: crowd instances rarely carry shear
+    out.world_normal = normalize((model * skin * vec4<f32>(in.normal, 0.0)).xyz);
+    out.tint = instance.tint;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let L = normalize(-scene.light_dir.xyz);
+    let diffuse = max(dot(normalize(in.world_normal), L), 0.0);
+    let ambient = scene.light_dir.w;
+    return vec4<f32>(in.tint.rgb * (ambient + diffuse * (1.0 - ambient)), in.tint.a);
+}
@@ -0,0 +1,143 @@
+// AnvilKit FXAA 后处理着色器
+// 全屏三角形 + FXAA 3.11 风格边缘检测：局部对比度 → 边缘方向 → 沿边搜索端点 → 亚像素混合
+// 输入为 tonemap 后的 LDR 颜色，输出到交换链
+
+@group(0) @binding(0) var src_texture: texture_2d<f32>;
+@group(0) @binding(1) var src_sampler: sampler;
+
+struct FxaaParams {
+    inv_size: vec2<f32>,
+    edge_threshold: f32,
+    edge_threshold_min: f32,
+    subpixel: f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+};
+@group(0) @binding(2) var<uniform> params: FxaaParams;
+
+struct VertexOutput { @builtin(position) position: vec4<f32>, @location(0) texcoord: vec2<f32> };
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let x = f32(i32(vi & 1u) * 4 - 1);
+    let y = f32(i32(vi & 2u) * 2 - 1);
+    out.position = vec4<f32>(x, y, 0.0, 1.0);
+    out.texcoord = vec2<f32>((x + 1.0) * 0.5, (1.0 - y) * 0.5);
+    return out;
+}
+
+const SEARCH_STEPS: i32 = 12;
+
+fn luma(c: vec3<f32>) -> f32 {
+    return dot(c, vec3<f32>(0.299, 0.587, 0.114));
+}
+
+fn luma_at(uv: vec2<f32>) -> f32 {
+    return luma(textureSampleLevel(src_texture, src_sampler, uv, 0.0).rgb);
+}
+
+fn luma_offset(uv: vec2<f32>, x: f32, y: f32) -> f32 {
+    return luma_at(uv + vec2<f32>(x, y) * params.inv_size);
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.texcoord;
+    let color = textureSampleLevel(src_texture, src_sampler, uv, 0.0);
+
+    // 局部对比度：低于阈值的像素不处理
+    let l_c = luma(color.rgb);
+    let l_n = luma_offset(uv, 0.0, -1.0);
+    let l_s = luma_offset(uv, 0.0, 1.0);
+    let l_w = luma_offset(uv, -1.0, 0.0);
+    let l_e = luma_offset(uv, 1.0, 0.0);
+    let l_min = min(l_c, min(min(l_n, l_s), min(l_w, l_e)));
+    let l_max = max(l_c, max(max(l_n, l_s), max(l_w, l_e)));
+    let range = l_max - l_min;
+    if (range < max(params.edge_threshold_min, l_max * params.edge_threshold)) {
+        return color;
+    }
+
+    let l_nw = luma_offset(uv, -1.0, -1.0);
+    let l_ne = luma_offset(uv, 1.0, -1.0);
+    let l_sw = luma_offset(uv, -1.0, 1.0);
+    let l_se = luma_offset(uv, 1.0, 1.0);
+
+    // 亚像素混合量：3x3 低通与中心的差
+    let l_avg = (2.0 * (l_n + l_s + l_w + l_e) + l_nw + l_ne + l_sw + l_se) / 12.0;
+    let sub = clamp(abs(l_avg - l_c) / range, 0.0, 1.0);
+    let sub_smooth = smoothstep(0.0, 1.0, sub);
+    let sub_blend = sub_smooth * sub_smooth * params.subpixel;
+
+    // 边缘方向：水平边缘（沿 x 延伸）时沿 y 偏移
+    let edge_h = abs(l_nw + l_ne - 2.0 * l_n) + 2.0 * abs(l_w + l_e - 2.0 * l_c) + abs(l_sw + l_se - 2.0 * l_s);
+    let edge_v = abs(l_nw + l_sw - 2.0 * l_w) + 2.0 * abs(l_n + l_s - 2.0 * l_c) + abs(l_ne + l_se - 2.0 * l_e);
+    let horizontal = edge_h >= edge_v;
+
+    var step_len = select(params.inv_size.x, params.inv_size.y, horizontal);
+    let l_neg = select(l_w, l_n, horizontal);
+    let l_pos = select(l_e, l_s, horizontal);
+    let grad_neg = abs(l_neg - l_c);
+    let grad_pos = abs(l_pos - l_c);
+    var l_pair = l_pos;
+    if (grad_neg >= grad_pos) {
+        step_len = -step_len;
+        l_pair = l_neg;
+    }
+    let grad_scaled = 0.25 * max(grad_neg, grad_pos);
+    let l_edge = 0.5 * (l_c + l_pair);
+
+    // 边界中点与沿边搜索方向
+    var edge_uv = uv;
+    var along = vec2<f32>(params.inv_size.x, 0.0);
+    if (horizontal) {
+        edge_uv.y += step_len * 0.5;
+    } else {
+        edge_uv.x += step_len * 0.5;
+        along = vec2<f32>(0.0, params.inv_size.y);
+    }
+
+    var uv_neg = edge_uv - along;
+    var uv_pos = edge_uv + along;
+    var d_neg = luma_at(uv_neg) - l_edge;
+    var d_pos = luma_at(uv_pos) - l_edge;
+    var done_neg = abs(d_neg) >= grad_scaled;
+    var done_pos = abs(d_pos) >= grad_scaled;
+    for (var i = 0; i < SEARCH_STEPS; i++) {
+        if (done_neg && done_pos) {
+            break;
+        }
+        if (!done_neg) {
+            uv_neg -= along;
+            d_neg = luma_at(uv_neg) - l_edge;
+            done_neg = abs(d_neg) >= grad_scaled;
+        }
+        if (!done_pos) {
+            uv_pos += along;
+            d_pos = luma_at(uv_pos) - l_edge;
+            done_pos = abs(d_pos) >= grad_scaled;
+        }
+    }
+
+    // 距较近端点的比例决定沿法线的偏移量
+    let dist_neg = select(uv.x - uv_neg.x, uv.y - uv_neg.y, !horizontal);
+    let dist_pos = select(uv_pos.x - uv.x, uv_pos.y - uv.y, !horizontal);
+    let nearest_neg = dist_neg < dist_pos;
+    let dist = min(dist_neg, dist_pos);
+    let span = dist_neg + dist_pos;
+    let d_end = select(d_pos, d_neg, nearest_neg);
+    let center_below = (l_c - l_edge) < 0.0;
+    let good_span = (d_end < 0.0) != center_below;
+    let edge_blend = select(0.0, 0.5 - dist / max(span, 1e-6), good_span);
+
+    let blend = max(edge_blend, sub_blend);
+    var final_uv = uv;
+    if (horizontal) {
+        final_uv.y += blend * step_len;
+    } else {
+        final_uv.x += blend * step_len;
+    }
+    return textureSampleLevel(src_texture, src_sampler, final_uv, 0.0);
+}
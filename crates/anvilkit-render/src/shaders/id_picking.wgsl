@@ -0,0 +1,47 @@
+// 实体 ID 拾取 — 光标下的精灵四边形写入 Rg32Uint 目标，alpha 遮罩镂空
+
+struct OrthoUniform {
+    projection: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> ortho: OrthoUniform;
+
+// 精灵 alpha 遮罩（无遮罩时为 1x1 白色纹理）
+@group(1) @binding(0)
+var mask_texture: texture_2d<f32>;
+@group(1) @binding(1)
+var mask_sampler: sampler;
+
+struct VertexInput {
+    @location(0) position: vec2<f32>,
+    @location(1) texcoord: vec2<f32>,
+    @location(2) id: vec2<u32>,
+    @location(3) cutoff: f32,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) texcoord: vec2<f32>,
+    @location(1) @interpolate(flat) id: vec2<u32>,
+    @location(2) @interpolate(flat) cutoff: f32,
+};
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = ortho.projection * vec4<f32>(in.position, 0.0, 1.0);
+    out.texcoord = in.texcoord;
+    out.id = in.id;
+    out.cutoff = in.cutoff;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec2<u32> {
+    let alpha = textureSampleLevel(mask_texture, mask_sampler, in.texcoord, 0.0).r;
+    if (alpha < in.cutoff) {
+        discard;
+    }
+    return in.id;
+}
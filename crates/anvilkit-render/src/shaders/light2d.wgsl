@@ -0,0 +1,99 @@
+// 2D lighting — shadow mask, additive point lights, light map composite
+
+struct OrthoUniform {
+    projection: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> ortho: OrthoUniform;
+
+// Shadow mask (light pass) or light map (composite pass), read per pixel
+@group(1) @binding(0)
+var source_texture: texture_2d<f32>;
+
+// ---------------------------------------------------------------------------
+// Shadow pass: extruded occluder geometry, max-blended into the mask
+// ---------------------------------------------------------------------------
+
+struct ShadowInput {
+    @location(0) position: vec2<f32>,
+    @location(1) shade: f32,
+};
+
+struct ShadowOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) shade: f32,
+};
+
+@vertex
+fn vs_shadow(in: ShadowInput) -> ShadowOutput {
+    var out: ShadowOutput;
+    out.clip_position = ortho.projection * vec4<f32>(in.position, 0.0, 1.0);
+    out.shade = in.shade;
+    return out;
+}
+
+@fragment
+fn fs_shadow(in: ShadowOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(in.shade, 0.0, 0.0, 1.0);
+}
+
+// ---------------------------------------------------------------------------
+// Light pass: radial falloff attenuated by the shadow mask, added to the light map
+// ---------------------------------------------------------------------------
+
+struct LightInput {
+    @location(0) position: vec2<f32>,
+    @location(1) center: vec2<f32>,
+    @location(2) color: vec3<f32>,
+    @location(3) radius: f32,
+    @location(4) falloff: f32,
+};
+
+struct LightOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) position: vec2<f32>,
+    @location(1) center: vec2<f32>,
+    @location(2) color: vec3<f32>,
+    @location(3) radius: f32,
+    @location(4) falloff: f32,
+};
+
+@vertex
+fn vs_light(in: LightInput) -> LightOutput {
+    var out: LightOutput;
+    out.clip_position = ortho.projection * vec4<f32>(in.position, 0.0, 1.0);
+    out.position = in.position;
+    out.center = in.center;
+    out.color = in.color;
+    out.radius = in.radius;
+    out.falloff = in.falloff;
+    return out;
+}
+
+@fragment
+fn fs_light(in: LightOutput) -> @location(0) vec4<f32> {
+    let d = distance(in.position, in.center) / in.radius;
+    if (d >= 1.0) {
+        discard;
+    }
+    let attenuation = pow(1.0 - d * d, in.falloff);
+    let shadow = textureLoad(source_texture, vec2<i32>(in.clip_position.xy), 0).r;
+    return vec4<f32>(in.color * attenuation * (1.0 - shadow), 1.0);
+}
+
+// ---------------------------------------------------------------------------
+// Composite pass: fullscreen triangle, multiplied onto the target
+// ---------------------------------------------------------------------------
+
+@vertex
+fn vs_composite(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_composite(@builtin(position) frag_coord: vec4<f32>) -> @location(0) vec4<f32> {
+    let light = textureLoad(source_texture, vec2<i32>(frag_coord.xy), 0).rgb;
+    return vec4<f32>(light, 1.0);
+}
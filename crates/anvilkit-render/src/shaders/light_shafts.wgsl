@@ -0,0 +1,72 @@
+// Light shaft shader — sky occlusion mask + radial blur toward the sun + additive composite.
+
+struct LightShaftParams {
+    // rgb = light color * intensity, a = on-screen visibility fade
+    color: vec4<f32>,
+    sun_uv: vec2<f32>,
+    density: f32,
+    weight: f32,
+    decay: f32,
+    samples: f32,
+    sun_radius: f32,
+    aspect: f32,
+};
+
+@group(0) @binding(0) var src_texture: texture_2d<f32>;
+@group(0) @binding(1) var tex_sampler: sampler;
+@group(0) @binding(2) var<uniform> params: LightShaftParams;
+@group(0) @binding(3) var depth_texture: texture_depth_2d;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let uv = vec2<f32>(f32((vi << 1u) & 2u), f32(vi & 2u));
+    out.position = vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+    out.uv = vec2<f32>(uv.x, 1.0 - uv.y);
+    return out;
+}
+
+// --- Pass 1: occlusion mask (half-res). Only sky pixels (depth at far plane) let light through. ---
+@fragment
+fn occlusion_fs(in: VertexOutput) -> @location(0) f32 {
+    let dims = vec2<f32>(textureDimensions(depth_texture));
+    let coord = vec2<i32>(clamp(in.uv * dims, vec2<f32>(0.0), dims - 1.0));
+    let depth = textureLoad(depth_texture, coord, 0);
+    if (depth < 0.9999) {
+        return 0.0;
+    }
+    // Aspect-corrected radial falloff around the sun disk
+    let d = (in.uv - params.sun_uv) * vec2<f32>(params.aspect, 1.0);
+    let glow = clamp(1.0 - length(d) / max(params.sun_radius, 0.0001), 0.0, 1.0);
+    return glow * glow;
+}
+
+// --- Pass 2: radial blur from each pixel toward the sun (half-res) ---
+@fragment
+fn blur_fs(in: VertexOutput) -> @location(0) f32 {
+    let num_samples = max(i32(params.samples), 1);
+    let step = (in.uv - params.sun_uv) * (params.density / f32(num_samples));
+
+    var uv = in.uv;
+    var illumination_decay = 1.0;
+    var accum = 0.0;
+    for (var i = 0; i < num_samples; i++) {
+        uv -= step;
+        let s = textureSampleLevel(src_texture, tex_sampler, uv, 0.0).r;
+        accum += s * illumination_decay * params.weight;
+        illumination_decay *= params.decay;
+    }
+    return accum;
+}
+
+// --- Pass 3: additive composite into HDR ---
+@fragment
+fn composite_fs(in: VertexOutput) -> @location(0) vec4<f32> {
+    let shaft = textureSample(src_texture, tex_sampler, in.uv).r;
+    return vec4<f32>(params.color.rgb * shaft * params.color.a, 1.0);
+}
@@ -1,7 +1,10 @@
-// Particle point-sprite shader
+// Particle billboard shader
 
 struct SceneUniform {
     view_proj: mat4x4<f32>,
+    // World-space camera axes used to expand each particle into a facing quad
+    camera_right: vec4<f32>,
+    camera_up: vec4<f32>,
 };
 
 @group(0) @binding(0)
@@ -19,8 +22,8 @@ struct VertexOutput {
     @location(1) uv: vec2<f32>,
 };
 
-// Expand each particle into a camera-facing quad via vertex_index
-// Each particle is drawn as 6 vertices (2 triangles)
+// Expand each particle instance into a camera-facing quad via vertex_index
+// Each particle is drawn as 6 vertices (2 triangles); dead GPU particles have size 0
 fn get_corner(vid: u32) -> vec2<f32> {
     let idx = vid % 6u;
     // Triangle 1: TL, BR, TR  Triangle 2: TL, BL, BR
@@ -42,10 +45,9 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vid: u32) -> VertexOutput {
     let corner = get_corner(vid);
 
     var out: VertexOutput;
-    // Billboard: offset in clip space after projection
-    let clip = scene.view_proj * vec4<f32>(in.position, 1.0);
-    let offset = vec2<f32>(corner.x * in.size, corner.y * in.size);
-    out.clip_position = vec4<f32>(clip.xy + offset * clip.w, clip.z, clip.w);
+    // Billboard: offset along the camera axes in world units
+    let offset = (scene.camera_right.xyz * corner.x + scene.camera_up.xyz * corner.y) * in.size;
+    out.clip_position = scene.view_proj * vec4<f32>(in.position + offset, 1.0);
     out.color = in.color;
     out.uv = corner + vec2<f32>(0.5, 0.5);
     return out;
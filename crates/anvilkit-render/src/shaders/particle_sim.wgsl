@@ -0,0 +1,79 @@
+// GPU particle simulation — one invocation per pool slot
+//
+// Spawning happens on the CPU (new particles are written into ring-buffer slots);
+// this pass integrates motion and applies the emitter's baked lifetime curves.
+
+struct GpuParticle {
+    position: vec3<f32>,
+    age: f32,
+    velocity: vec3<f32>,
+    lifetime: f32,
+    color: vec4<f32>,
+    start_size: f32,
+    size: f32,
+    _pad: vec2<f32>,
+};
+
+const LUT_SIZE: u32 = 32u;
+
+struct SimParams {
+    gravity: vec3<f32>,
+    dt: f32,
+    count: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+    // Color over normalized age
+    color_lut: array<vec4<f32>, 32>,
+    // x = size multiplier, y = speed multiplier
+    scalar_lut: array<vec4<f32>, 32>,
+};
+
+@group(0) @binding(0)
+var<storage, read_write> particles: array<GpuParticle>;
+
+@group(0) @binding(1)
+var<uniform> params: SimParams;
+
+fn lut_coords(t: f32) -> vec3<f32> {
+    let x = clamp(t, 0.0, 1.0) * f32(LUT_SIZE - 1u);
+    let i0 = floor(x);
+    let i1 = min(i0 + 1.0, f32(LUT_SIZE - 1u));
+    return vec3<f32>(i0, i1, x - i0);
+}
+
+fn sample_color(t: f32) -> vec4<f32> {
+    let c = lut_coords(t);
+    return mix(params.color_lut[u32(c.x)], params.color_lut[u32(c.y)], c.z);
+}
+
+fn sample_scalar(t: f32) -> vec4<f32> {
+    let c = lut_coords(t);
+    return mix(params.scalar_lut[u32(c.x)], params.scalar_lut[u32(c.y)], c.z);
+}
+
+@compute @workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if index >= params.count {
+        return;
+    }
+    var p = particles[index];
+    if p.age >= p.lifetime {
+        if p.size != 0.0 {
+            p.size = 0.0;
+            particles[index] = p;
+        }
+        return;
+    }
+
+    let speed = sample_scalar(p.age / p.lifetime).y;
+    p.velocity += params.gravity * params.dt;
+    p.position += p.velocity * speed * params.dt;
+    p.age += params.dt;
+
+    let t = p.age / p.lifetime;
+    p.size = select(p.start_size * sample_scalar(t).x, 0.0, p.age >= p.lifetime);
+    p.color = sample_color(t);
+    particles[index] = p;
+}
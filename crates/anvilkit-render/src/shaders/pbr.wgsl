@@ -1,5 +1,7 @@
 // AnvilKit PBR 着色器
 // Cook-Torrance BRDF + TBN 法线贴图 + 多光源 + 阴影 + IBL + 完整材质
+// 可选顶点属性与光照贴图以 `#ifdef` 注释块（VERTEX_COLOR / VERTEX_UV1 / LIGHTMAP）标注，
+// 由 shader_defs::specialize 按管线变体启用（未启用时保持为注释）
 
 const PI: f32 = 3.14159265359;
 
@@ -22,6 +24,10 @@ struct SceneUniform {
     cascade_view_projs: array<mat4x4<f32>, 3>,
     cascade_splits: vec4<f32>,
     emissive_factor: vec4<f32>,
+    base_color_factor: vec4<f32>,
+    ambient: vec4<f32>,
+    shadow_params: vec4<f32>, // x = depth bias (texels), y = normal bias (world units), z = PCF radius
+    env_params: vec4<f32>, // x = environment cubemap intensity (0 = hemisphere), y = max mip level
 };
 
 @group(0) @binding(0) var<uniform> scene: SceneUniform;
@@ -31,16 +37,34 @@ struct SceneUniform {
 @group(1) @binding(3) var ao_texture: texture_2d<f32>;
 @group(1) @binding(4) var emissive_texture: texture_2d<f32>;
 @group(1) @binding(5) var material_sampler: sampler;
+//#ifdef LIGHTMAP
+//struct LightmapParams {
+//    // xy = scale, zw = offset (UV1 -> lightmap)
+//    uv_rect: vec4<f32>,
+//    // x = intensity, y = 1.0 when the lightmap includes direct light
+//    params: vec4<f32>,
+//};
+//@group(1) @binding(6) var lightmap_texture: texture_2d<f32>;
+//@group(1) @binding(7) var<uniform> lightmap: LightmapParams;
+//#endif
 @group(2) @binding(0) var brdf_lut: texture_2d<f32>;
 @group(2) @binding(1) var brdf_lut_sampler: sampler;
 @group(2) @binding(2) var shadow_map: texture_depth_2d_array;
 @group(2) @binding(3) var shadow_sampler: sampler_comparison;
+@group(2) @binding(4) var environment_map: texture_cube<f32>;
+@group(2) @binding(5) var environment_sampler: sampler;
 
 struct VertexInput {
     @location(0) position: vec3<f32>,
     @location(1) normal: vec3<f32>,
     @location(2) texcoord: vec2<f32>,
     @location(3) tangent: vec4<f32>,
+//#ifdef VERTEX_COLOR
+//    @location(4) color: vec4<f32>,
+//#endif
+//#ifdef VERTEX_UV1
+//    @location(5) uv1: vec2<f32>,
+//#endif
 };
 
 struct VertexOutput {
@@ -50,6 +74,12 @@ struct VertexOutput {
     @location(2) world_position: vec3<f32>,
     @location(3) world_tangent: vec3<f32>,
     @location(4) world_bitangent: vec3<f32>,
+//#ifdef VERTEX_COLOR
+//    @location(5) color: vec4<f32>,
+//#endif
+//#ifdef VERTEX_UV1
+//    @location(6) uv1: vec2<f32>,
+//#endif
 };
 
 @vertex
@@ -65,6 +95,12 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     out.world_tangent = T;
     out.world_bitangent = B;
     out.texcoord = in.texcoord;
+//#ifdef VERTEX_COLOR
+//    out.color = in.color;
+//#endif
+//#ifdef VERTEX_UV1
+//    out.uv1 = in.uv1;
+//#endif
     return out;
 }
 
@@ -105,7 +141,18 @@ fn hemisphere_specular(R: vec3<f32>, roughness: f32) -> vec3<f32> {
     return mix(avg, sharp, 1.0 - roughness * roughness);
 }
 
-fn calculate_shadow(world_pos: vec3<f32>) -> f32 {
+// Skybox cubemap as ambient input: coarsest mip approximates irradiance, roughness selects the specular mip
+fn environment_irradiance(N: vec3<f32>) -> vec3<f32> {
+    if (scene.env_params.x <= 0.0) { return hemisphere_irradiance(N); }
+    return textureSampleLevel(environment_map, environment_sampler, N, scene.env_params.y).rgb * scene.env_params.x;
+}
+
+fn environment_specular(R: vec3<f32>, roughness: f32) -> vec3<f32> {
+    if (scene.env_params.x <= 0.0) { return hemisphere_specular(R, roughness); }
+    return textureSampleLevel(environment_map, environment_sampler, R, roughness * scene.env_params.y).rgb * scene.env_params.x;
+}
+
+fn calculate_shadow(world_pos: vec3<f32>, geo_normal: vec3<f32>) -> f32 {
     // Compute view-space depth for cascade selection
     let view_pos = scene.view_proj * vec4<f32>(world_pos, 1.0);
     let view_z = view_pos.w; // clip-space w = -view_z (RH convention), positive for objects in front of camera
@@ -117,20 +164,24 @@ fn calculate_shadow(world_pos: vec3<f32>) -> f32 {
     if (view_z > scene.cascade_splits.y) { cascade_idx = 2u; }
     if (cascade_idx >= cascade_count) { return 1.0; }
 
+    // Normal offset: push the lookup point off the surface to fight acne on grazing angles
+    let offset_pos = world_pos + geo_normal * scene.shadow_params.y;
     let shadow_vp = scene.cascade_view_projs[cascade_idx];
-    let clip = shadow_vp * vec4<f32>(world_pos, 1.0);
+    let clip = shadow_vp * vec4<f32>(offset_pos, 1.0);
     let ndc = clip.xyz / clip.w;
     let uv = vec2<f32>(ndc.x * 0.5 + 0.5, -ndc.y * 0.5 + 0.5);
     let depth = ndc.z;
     if (uv.x < 0.0 || uv.x > 1.0 || uv.y < 0.0 || uv.y > 1.0 || depth > 1.0) { return 1.0; }
 
     let ts = scene.cascade_splits.w; // shadow texel size
-    let shadow_bias = ts * 5.0; // Scale bias by texel size
+    let shadow_bias = ts * scene.shadow_params.x; // Scale bias by texel size
+    let r = i32(scene.shadow_params.z);
     var s = 0.0;
-    for (var x = -1; x <= 1; x++) { for (var y = -1; y <= 1; y++) {
+    for (var x = -r; x <= r; x++) { for (var y = -r; y <= r; y++) {
         s += textureSampleCompare(shadow_map, shadow_sampler, uv + vec2<f32>(f32(x), f32(y)) * ts, cascade_idx, depth - shadow_bias);
     }}
-    return s / 9.0;
+    let taps = f32((2 * r + 1) * (2 * r + 1));
+    return s / taps;
 }
 
 fn fresnel_schlick_roughness(cos_theta: f32, F0: vec3<f32>, roughness: f32) -> vec3<f32> {
@@ -139,7 +190,11 @@ fn fresnel_schlick_roughness(cos_theta: f32, F0: vec3<f32>, roughness: f32) -> v
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let albedo = textureSample(base_color_texture, material_sampler, in.texcoord).rgb;
+    var base_color = textureSample(base_color_texture, material_sampler, in.texcoord) * scene.base_color_factor;
+//#ifdef VERTEX_COLOR
+//    base_color = base_color * in.color;
+//#endif
+    let albedo = base_color.rgb;
     let normal_scale = scene.material_params.z;
     let mr = textureSample(metallic_roughness_texture, material_sampler, in.texcoord);
     let metallic = mr.b * scene.material_params.x;
@@ -159,7 +214,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let NdotV = max(dot(N, V), 0.0);
     let F0 = mix(vec3<f32>(0.04), albedo, metallic);
 
-    let shadow = calculate_shadow(in.world_position);
+    let shadow = calculate_shadow(in.world_position, Ng);
     let light_count = u32(scene.material_params.w);
     var Lo = vec3<f32>(0.0);
 
@@ -192,14 +247,22 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
     let Fi = fresnel_schlick_roughness(NdotV, F0, roughness);
     let kDi = (vec3<f32>(1.0) - Fi) * (1.0 - metallic);
-    let diff_ibl = hemisphere_irradiance(N) * albedo * kDi;
+    var diff_ibl = environment_irradiance(N) * albedo * kDi;
+//#ifdef LIGHTMAP
+//    let lightmap_uv = in.uv1 * lightmap.uv_rect.xy + lightmap.uv_rect.zw;
+//    let baked = textureSample(lightmap_texture, material_sampler, lightmap_uv).rgb * lightmap.params.x;
+//    diff_ibl = baked * albedo * kDi;
+//    if (lightmap.params.y > 0.5) {
+//        Lo = vec3<f32>(0.0);
+//    }
+//#endif
     let R = reflect(-V, N);
     let brdf = textureSample(brdf_lut, brdf_lut_sampler, vec2<f32>(NdotV, roughness)).rg;
-    let spec_ibl = hemisphere_specular(R, roughness) * (F0 * brdf.x + brdf.y);
-    let ambient = (diff_ibl + spec_ibl) * ao;
+    let spec_ibl = environment_specular(R, roughness) * (F0 * brdf.x + brdf.y);
+    let ambient = (diff_ibl + spec_ibl) * ao * scene.ambient.rgb * scene.ambient.w;
 
     let emissive_tex = textureSample(emissive_texture, material_sampler, in.texcoord).rgb;
     let emissive = emissive_tex * scene.emissive_factor.xyz;
 
-    return vec4<f32>(ambient + Lo + emissive, 1.0);
+    return vec4<f32>(ambient + Lo + emissive, base_color.a);
 }
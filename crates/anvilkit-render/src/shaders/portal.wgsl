@@ -0,0 +1,45 @@
+// 传送门门面 — 遮罩 pass 把门面写到远平面深度，合成 pass 按屏幕坐标读取门内视图
+
+struct QuadUniform {
+    view_proj: mat4x4<f32>,
+    model: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> quad: QuadUniform;
+
+// 已解析的门内视图（与 HDR 目标同尺寸）
+@group(1) @binding(0)
+var portal_view: texture_2d<f32>;
+
+fn quad_clip_position(vi: u32) -> vec4<f32> {
+    // 局部 XY 平面上的单位四边形，两个三角形
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-0.5, -0.5), vec2<f32>(0.5, -0.5), vec2<f32>(0.5, 0.5),
+        vec2<f32>(-0.5, -0.5), vec2<f32>(0.5, 0.5), vec2<f32>(-0.5, 0.5),
+    );
+    let local = corners[vi];
+    return quad.view_proj * quad.model * vec4<f32>(local, 0.0, 1.0);
+}
+
+@vertex
+fn vs_mask(@builtin(vertex_index) vi: u32) -> @builtin(position) vec4<f32> {
+    // z = w：门面区域的深度为 1.0，场景只在门面内通过深度测试（模板遮罩的等价物）
+    let clip = quad_clip_position(vi);
+    return vec4<f32>(clip.xy, clip.w, clip.w);
+}
+
+@fragment
+fn fs_mask() -> @location(0) vec4<f32> {
+    return vec4<f32>(0.0);
+}
+
+@vertex
+fn vs_composite(@builtin(vertex_index) vi: u32) -> @builtin(position) vec4<f32> {
+    return quad_clip_position(vi);
+}
+
+@fragment
+fn fs_composite(@builtin(position) frag_coord: vec4<f32>) -> @location(0) vec4<f32> {
+    return textureLoad(portal_view, vec2<i32>(frag_coord.xy), 0);
+}
@@ -24,6 +24,10 @@ struct SceneUniform {
     cascade_view_projs: array<mat4x4<f32>, 3>,
     cascade_splits: vec4<f32>,
     emissive_factor: vec4<f32>,
+    base_color_factor: vec4<f32>,
+    ambient: vec4<f32>,
+    shadow_params: vec4<f32>, // x = depth bias (texels), y = normal bias (world units), z = PCF radius
+    env_params: vec4<f32>, // x = environment cubemap intensity (0 = hemisphere), y = max mip level
 };
 
 struct JointMatrices {
@@ -118,7 +122,7 @@ fn fresnel_schlick_roughness(cos_theta: f32, F0: vec3<f32>, roughness: f32) -> v
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    let base_color = textureSample(base_color_texture, material_sampler, in.texcoord);
+    let base_color = textureSample(base_color_texture, material_sampler, in.texcoord) * scene.base_color_factor;
     let albedo = base_color.rgb;
     let alpha = base_color.a;
 
@@ -158,8 +162,14 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
             let dist = length(to_light);
             L = normalize(to_light);
             let range = light.direction_range.w;
-            attenuation = max(1.0 - dist / range, 0.0);
+            let r = clamp(dist / range, 0.0, 1.0);
+            attenuation = max(1.0 - r * r, 0.0);
             attenuation = attenuation * attenuation;
+            // Spot light: smooth falloff between inner (params.x) and outer (params.y) cone cosines
+            if (light_type == 2u) {
+                let cos_angle = dot(normalize(light.direction_range.xyz), -L);
+                attenuation *= clamp((cos_angle - light.params.y) / max(light.params.x - light.params.y, 0.0001), 0.0, 1.0);
+            }
         }
 
         let H = normalize(V + L);
@@ -183,7 +193,7 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let diffuse_env = kD_env * albedo * irradiance;
     let brdf = textureSample(brdf_lut, brdf_lut_sampler, vec2<f32>(NdotV, roughness)).rg;
     let spec_env = F_env * brdf.x + brdf.y;
-    let ambient = (diffuse_env + spec_env * 0.3) * ao;
+    let ambient = (diffuse_env + spec_env * 0.3) * ao * scene.ambient.rgb * scene.ambient.w;
 
     let color = Lo + ambient + emissive;
     return vec4<f32>(color, alpha);
@@ -0,0 +1,35 @@
+// AnvilKit 天空盒
+// 全屏三角形写在远平面（深度 1.0），LessEqual 深度测试只填充没有几何体的背景像素
+
+struct SkyUniform {
+    inv_view_proj: mat4x4<f32>,
+    // xyz = camera position, w = brightness
+    camera_pos: vec4<f32>,
+};
+
+@group(0) @binding(0) var<uniform> sky: SkyUniform;
+@group(0) @binding(1) var sky_texture: texture_cube<f32>;
+@group(0) @binding(2) var sky_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) ndc: vec2<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let x = f32(i32(vi & 1u) * 4 - 1);
+    let y = f32(i32(vi & 2u) * 2 - 1);
+    out.position = vec4<f32>(x, y, 1.0, 1.0);
+    out.ndc = vec2<f32>(x, y);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let far = sky.inv_view_proj * vec4<f32>(in.ndc, 1.0, 1.0);
+    let dir = normalize(far.xyz / far.w - sky.camera_pos.xyz);
+    let color = textureSampleLevel(sky_texture, sky_sampler, dir, 0.0).rgb;
+    return vec4<f32>(color * sky.camera_pos.w, 1.0);
+}
@@ -1,10 +1,14 @@
 // AnvilKit Tone Mapping 后处理着色器
-// 全屏三角形 + Bloom 合成 + ACES Filmic + Gamma 校正
+// 全屏三角形 + Bloom 合成 + ACES Filmic + CAS 锐化 + 暗角 + Gamma 校正
+// HDR 以内部渲染分辨率输入，双线性采样放大到输出尺寸
 
 @group(0) @binding(0) var hdr_texture: texture_2d<f32>;
 @group(0) @binding(1) var hdr_sampler: sampler;
 @group(0) @binding(2) var bloom_texture: texture_2d<f32>;
 
+struct TonemapParams { sharpness: f32, render_scale: f32, vignette: vec2<f32> };
+@group(0) @binding(3) var<uniform> params: TonemapParams;
+
 struct VertexOutput { @builtin(position) position: vec4<f32>, @location(0) texcoord: vec2<f32> };
 
 @vertex
@@ -21,13 +25,33 @@ fn aces_filmic(x: vec3<f32>) -> vec3<f32> {
     return clamp((x * (2.51 * x + 0.03)) / (x * (2.43 * x + 0.59) + 0.14), vec3<f32>(0.0), vec3<f32>(1.0));
 }
 
+fn tonemapped(uv: vec2<f32>, bloom: vec3<f32>) -> vec3<f32> {
+    return aces_filmic(textureSample(hdr_texture, hdr_sampler, uv).rgb + bloom);
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    var c = textureSample(hdr_texture, hdr_sampler, in.texcoord).rgb;
     // Bloom composite: add bloom contribution
     let bloom = textureSample(bloom_texture, hdr_sampler, in.texcoord).rgb;
-    c += bloom;
-    c = aces_filmic(c);
+    var c = tonemapped(in.texcoord, bloom);
+
+    // CAS 风格锐化：十字邻域对比度决定锐化权重，低对比度区域锐化更强
+    let texel = 1.0 / vec2<f32>(textureDimensions(hdr_texture));
+    let n = tonemapped(in.texcoord - vec2<f32>(0.0, texel.y), bloom);
+    let s = tonemapped(in.texcoord + vec2<f32>(0.0, texel.y), bloom);
+    let w = tonemapped(in.texcoord - vec2<f32>(texel.x, 0.0), bloom);
+    let e = tonemapped(in.texcoord + vec2<f32>(texel.x, 0.0), bloom);
+    let mn = min(c, min(min(n, s), min(w, e)));
+    let mx = max(c, max(max(n, s), max(w, e)));
+    let amp = sqrt(clamp(min(mn, vec3<f32>(1.0) - mx) / max(mx, vec3<f32>(1e-4)), vec3<f32>(0.0), vec3<f32>(1.0)));
+    let weight = amp * (-1.0 / mix(8.0, 5.0, params.sharpness));
+    let sharpened = (c + (n + s + w + e) * weight) / (vec3<f32>(1.0) + 4.0 * weight);
+    c = select(c, clamp(sharpened, vec3<f32>(0.0), vec3<f32>(1.0)), params.sharpness > 0.0);
+
+    // 暗角：到中心的归一化距离（角落为 1）超过 radius 后逐渐压暗
+    let d = length(in.texcoord - vec2<f32>(0.5)) * 1.41421356;
+    c *= 1.0 - params.vignette.x * smoothstep(params.vignette.y, 1.0, d);
+
     c = pow(c, vec3<f32>(1.0 / 2.2));
     return vec4<f32>(c, 1.0);
 }
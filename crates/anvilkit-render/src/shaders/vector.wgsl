@@ -0,0 +1,49 @@
+// 2D vector paths — MSAA layer with per-shape depth, composited over the target
+
+struct OrthoUniform {
+    projection: mat4x4<f32>,
+};
+
+@group(0) @binding(0)
+var<uniform> ortho: OrthoUniform;
+
+// Resolved vector layer (composite pass)
+@group(1) @binding(0)
+var layer_texture: texture_2d<f32>;
+
+struct VectorInput {
+    @location(0) position: vec2<f32>,
+    @location(1) depth: f32,
+    @location(2) color: vec4<f32>,
+};
+
+struct VectorOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(in: VectorInput) -> VectorOutput {
+    var out: VectorOutput;
+    // 每个填充 / 描边使用唯一深度：同一形状内重叠的三角形只着色一次
+    out.clip_position = ortho.projection * vec4<f32>(in.position, in.depth, 1.0);
+    out.color = vec4<f32>(in.color.rgb * in.color.a, in.color.a);
+    return out;
+}
+
+@fragment
+fn fs_main(in: VectorOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
+
+@vertex
+fn vs_composite(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_composite(@builtin(position) frag_coord: vec4<f32>) -> @location(0) vec4<f32> {
+    // 预乘 Alpha
+    return textureLoad(layer_texture, vec2<i32>(frag_coord.xy), 0);
+}
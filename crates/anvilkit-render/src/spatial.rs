@@ -0,0 +1,272 @@
+//! # 空间索引同步
+//!
+//! [`SpatialIndexPlugin`] 把 `anvilkit_core::spatial` 中的 2D 空间索引作为资源插入，
+//! 并在 `PostUpdate` 变换传播之后，将带 [`Aabb`] 与 `GlobalTransform` 的实体的
+//! 世界包围盒（投影到 XY 平面）同步到索引中：
+//!
+//! - 新增或变换 / 包围盒发生变化的实体会被插入或更新
+//! - 移除 `Aabb` 或被销毁的实体会从索引中删除
+//!
+//! 同步后的索引可在下一帧的任意系统中通过 `Res<S>` 做区域与射线查询。
+//!
+//! 3D 场景使用 [`SceneBvhPlugin`]：[`SceneBvh`] 资源以实体世界包围盒构建 BVH，
+//! 变换变化时原地 refit，实体增删时重建。提供射线检测与包围盒重叠查询，
+//! 存在时视锥体剔除系统也会借助它批量剔除。
+//!
+//! ## 使用示例
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::spatial::SpatialIndexPlugin;
+//!
+//! fn nearby(grid: Res<SpatialHash2D<Entity>>) {
+//!     let mut found = Vec::new();
+//!     grid.query_region(&Bounds2D::from_center_half_extents(Vec2::ZERO, Vec2::splat(10.0)), &mut found);
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(SpatialIndexPlugin::new(SpatialHash2D::<Entity>::new(4.0)))
+//!     .add_systems(bevy_app::Update, nearby);
+//! app.world_mut().spawn((GlobalTransform::default(), Aabb::default()));
+//! app.update();
+//! ```
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::math::{Bounds2D, GlobalTransform};
+use anvilkit_core::spatial::{Bvh, SpatialIndex2D};
+use glam::Vec3;
+
+use crate::renderer::draw::{Aabb, Frustum};
+
+/// 包围盒或世界变换发生变化的实体
+type BoundsChanged = Or<(Changed<Aabb>, Changed<GlobalTransform>)>;
+
+/// 空间索引同步系统 (PostUpdate，变换传播之后)
+pub fn sync_spatial_index<S: SpatialIndex2D<Entity> + Resource>(
+    mut index: ResMut<S>,
+    changed: Query<(Entity, &Aabb, &GlobalTransform), BoundsChanged>,
+    mut removed: RemovedComponents<Aabb>,
+) {
+    for entity in removed.read() {
+        index.remove(entity);
+    }
+    for (entity, aabb, transform) in &changed {
+        index.update(entity, Bounds2D::from_aabb_xy(&aabb.transformed(&transform.0)));
+    }
+}
+
+/// 空间索引插件
+///
+/// 以给定的索引实例（如 `SpatialHash2D::new(cell_size)` 或 `Quadtree::new(world_bounds)`）
+/// 初始化资源。可为不同的索引类型分别添加一次。
+pub struct SpatialIndexPlugin<S> {
+    index: S,
+}
+
+impl<S> SpatialIndexPlugin<S> {
+    /// 以初始索引创建
+    pub fn new(index: S) -> Self {
+        Self { index }
+    }
+}
+
+impl<S: SpatialIndex2D<Entity> + Resource + Clone> Plugin for SpatialIndexPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.index.clone()).add_systems(
+            bevy_app::PostUpdate,
+            sync_spatial_index::<S>.after(crate::transform::propagate_transforms),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "SpatialIndexPlugin"
+    }
+}
+
+/// 场景 BVH
+///
+/// 存储所有带 [`Aabb`] 与 `GlobalTransform` 的实体的世界包围盒，由 [`scene_bvh_system`] 维护。
+///
+/// refit 不改变树的拓扑，大量对象长距离移动后查询效率会下降，
+/// 可调用 [`request_rebuild`](Self::request_rebuild) 让下一次同步完整重建。
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SceneBvh {
+    bvh: Bvh<Entity>,
+    rebuild_requested: bool,
+}
+
+impl SceneBvh {
+    /// 底层 BVH
+    pub fn bvh(&self) -> &Bvh<Entity> {
+        &self.bvh
+    }
+
+    /// 射线最近命中的实体及距离（`direction` 为单位向量时）
+    pub fn ray_cast(&self, origin: Vec3, direction: Vec3, max_distance: f32) -> Option<(Entity, f32)> {
+        self.bvh.ray_cast(origin, direction, max_distance)
+    }
+
+    /// 世界包围盒与 `region` 相交的实体
+    pub fn overlap_aabb(&self, region: &Aabb) -> Vec<Entity> {
+        let mut out = Vec::new();
+        self.bvh.overlap_aabb(region, &mut out);
+        out
+    }
+
+    /// 世界包围盒与视锥体相交的实体追加到 `out`
+    pub fn query_frustum(&self, frustum: &Frustum, out: &mut Vec<Entity>) {
+        self.bvh.query_frustum(frustum, out);
+    }
+
+    /// 世界包围盒相交的实体对（物理宽相位候选）
+    pub fn overlapping_pairs(&self) -> Vec<(Entity, Entity)> {
+        let mut out = Vec::new();
+        self.bvh.overlapping_pairs(&mut out);
+        out
+    }
+
+    /// 实体是否已登记
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.bvh.contains(entity)
+    }
+
+    /// 登记的实体数量
+    pub fn len(&self) -> usize {
+        self.bvh.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.bvh.is_empty()
+    }
+
+    /// 下一次同步时完整重建
+    pub fn request_rebuild(&mut self) {
+        self.rebuild_requested = true;
+    }
+}
+
+/// 场景 BVH 同步系统 (PostUpdate，变换传播之后、视锥体剔除之前)
+///
+/// 只有已登记实体的包围盒变化时逐个 refit；出现新实体或登记的实体被移除时整体重建。
+pub fn scene_bvh_system(
+    mut scene: ResMut<SceneBvh>,
+    all: Query<(Entity, &Aabb, &GlobalTransform)>,
+    changed: Query<(Entity, &Aabb, &GlobalTransform), BoundsChanged>,
+    mut removed: RemovedComponents<Aabb>,
+) {
+    let mut rebuild = scene.rebuild_requested;
+    for entity in removed.read() {
+        rebuild |= scene.bvh.contains(entity);
+    }
+    if !rebuild {
+        for (entity, aabb, transform) in &changed {
+            if !scene.bvh.refit(entity, aabb.transformed(&transform.0)) {
+                rebuild = true;
+                break;
+            }
+        }
+    }
+    if rebuild {
+        scene.bvh = Bvh::build(all.iter().map(|(entity, aabb, transform)| (entity, aabb.transformed(&transform.0))));
+        scene.rebuild_requested = false;
+    }
+}
+
+/// 场景 BVH 插件
+pub struct SceneBvhPlugin;
+
+impl Plugin for SceneBvhPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneBvh>().add_systems(
+            bevy_app::PostUpdate,
+            scene_bvh_system
+                .after(crate::transform::propagate_transforms)
+                .before(crate::plugin::frustum_culling_system),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "SceneBvhPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_core::spatial::{Quadtree, SpatialHash2D};
+    use glam::{Mat4, Vec2, Vec3};
+
+    fn at(x: f32, y: f32) -> GlobalTransform {
+        GlobalTransform(Mat4::from_translation(Vec3::new(x, y, 0.0)))
+    }
+
+    fn query<S: SpatialIndex2D<Entity> + Resource>(app: &App, center: Vec2) -> Vec<Entity> {
+        let mut out = Vec::new();
+        app.world()
+            .resource::<S>()
+            .query_region(&Bounds2D::from_center_half_extents(center, Vec2::splat(1.0)), &mut out);
+        out
+    }
+
+    #[test]
+    fn test_sync_insert_move_despawn() {
+        let mut app = App::new();
+        app.add_plugins(SpatialIndexPlugin::new(SpatialHash2D::<Entity>::new(2.0)));
+        let e = app.world_mut().spawn((Aabb::default(), at(0.0, 0.0))).id();
+        app.update();
+        assert_eq!(query::<SpatialHash2D<Entity>>(&app, Vec2::ZERO), vec![e]);
+
+        app.world_mut().entity_mut(e).insert(at(20.0, 0.0));
+        app.update();
+        assert!(query::<SpatialHash2D<Entity>>(&app, Vec2::ZERO).is_empty());
+        assert_eq!(query::<SpatialHash2D<Entity>>(&app, Vec2::new(20.0, 0.0)), vec![e]);
+
+        app.world_mut().despawn(e);
+        app.update();
+        assert!(app.world().resource::<SpatialHash2D<Entity>>().is_empty());
+    }
+
+    #[test]
+    fn test_sync_quadtree_remove_aabb() {
+        let world = Bounds2D::from_min_max(Vec2::splat(-64.0), Vec2::splat(64.0));
+        let mut app = App::new();
+        app.add_plugins(SpatialIndexPlugin::new(Quadtree::<Entity>::new(world)));
+        let e = app.world_mut().spawn((Aabb::default(), at(5.0, 5.0))).id();
+        app.update();
+        assert_eq!(query::<Quadtree<Entity>>(&app, Vec2::splat(5.0)), vec![e]);
+
+        app.world_mut().entity_mut(e).remove::<Aabb>();
+        app.update();
+        assert!(app.world().resource::<Quadtree<Entity>>().is_empty());
+    }
+
+    #[test]
+    fn test_scene_bvh_refit_and_rebuild() {
+        let mut app = App::new();
+        app.add_plugins(SceneBvhPlugin);
+        let a = app.world_mut().spawn((Aabb::default(), at(0.0, 0.0))).id();
+        let b = app.world_mut().spawn((Aabb::default(), at(5.0, 0.0))).id();
+        app.update();
+        assert_eq!(app.world().resource::<SceneBvh>().len(), 2);
+
+        let origin = Vec3::new(-10.0, 0.0, 0.0);
+        let hit = app.world().resource::<SceneBvh>().ray_cast(origin, Vec3::X, 100.0);
+        assert_eq!(hit, Some((a, 9.5)));
+
+        // 变换变化 → refit
+        app.world_mut().entity_mut(a).insert(at(0.0, 10.0));
+        app.update();
+        let scene = app.world().resource::<SceneBvh>();
+        assert_eq!(scene.ray_cast(origin, Vec3::X, 100.0), Some((b, 14.5)));
+        assert_eq!(scene.overlap_aabb(&Aabb::default().translated(Vec3::new(0.0, 10.0, 0.0))), vec![a]);
+
+        // 销毁 → 重建
+        app.world_mut().despawn(b);
+        app.update();
+        let scene = app.world().resource::<SceneBvh>();
+        assert_eq!(scene.len(), 1);
+        assert_eq!(scene.ray_cast(origin, Vec3::X, 100.0), None);
+    }
+}
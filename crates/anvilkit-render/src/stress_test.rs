@@ -0,0 +1,597 @@
+//! # 压力测试场景
+//!
+//! [`StressTestPlugin`] 按参数生成固定的负载，用于在不同机器、不同版本之间对比性能：
+//!
+//! - `sprites` 个精灵（地面上网格排列的彩色四边形网格实体，走引擎的网格绘制路径）；
+//! - `lights` 个点光源（加入 [`SceneLights`]，结束后只移除这些光源）；
+//! - `meshes` 条旋转立方体链，每条深度为 `hierarchy_depth`（父子层级逐级旋转）。
+//!
+//! 预热 `warmup_secs` 秒后逐帧采样 [`Diagnostics`]，持续 `duration_secs` 秒，
+//! 结束时写出 CSV、清理生成的实体并发送 [`StressTestFinished`]。
+//! 发送 [`RunStressTest`] 事件或按 [`StressTestPlugin::toggle_key`] 开始 / 中止测试。
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::stress_test::{RunStressTest, StressTestConfig, StressTestPlugin};
+//!
+//! let mut app = App::new();
+//! app.add_plugins(StressTestPlugin::default());
+//! app.world_mut().send_event(RunStressTest(
+//!     StressTestConfig::new().with_sprites(10_000).with_lights(8).with_meshes(200, 4),
+//! ));
+//! ```
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::{Quat, Vec2, Vec3};
+use anvilkit_core::diagnostics::{Diagnostics, DRAW_CALLS, ENTITY_COUNT, FPS, FRAME_TIME, GPU_FRAME_TIME, TRIANGLES};
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_core::math::{GlobalTransform, Transform};
+use anvilkit_core::time::DeltaTime;
+use anvilkit_assets::mesh::MeshData;
+use anvilkit_input::prelude::{InputState, KeyCode};
+
+use crate::renderer::RenderDevice;
+use crate::renderer::assets::{MeshHandle, RenderAssets};
+use crate::renderer::buffer::PbrVertex;
+use crate::renderer::draw::{PointLight, SceneLights};
+use crate::renderer::standard_material::StandardMaterial;
+use crate::transform::{Children, Parent};
+
+/// 压力测试参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct StressTestConfig {
+    /// 精灵数量（以四边形网格实体绘制）
+    pub sprites: u32,
+    /// 点光源数量
+    pub lights: u32,
+    /// 动画网格链数量
+    pub meshes: u32,
+    /// 每条网格链的层级深度（≥ 1）
+    pub hierarchy_depth: u32,
+    /// 预热时间（秒），期间不采样
+    pub warmup_secs: f32,
+    /// 采样时间（秒）
+    pub duration_secs: f32,
+    /// CSV 输出目录
+    pub output_dir: PathBuf,
+}
+
+impl Default for StressTestConfig {
+    fn default() -> Self {
+        Self {
+            sprites: 1_000,
+            lights: 4,
+            meshes: 100,
+            hierarchy_depth: 3,
+            warmup_secs: 2.0,
+            duration_secs: 10.0,
+            output_dir: PathBuf::from("benchmarks"),
+        }
+    }
+}
+
+impl StressTestConfig {
+    /// 默认参数
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置精灵数量
+    pub fn with_sprites(mut self, sprites: u32) -> Self {
+        self.sprites = sprites;
+        self
+    }
+
+    /// 设置点光源数量
+    pub fn with_lights(mut self, lights: u32) -> Self {
+        self.lights = lights;
+        self
+    }
+
+    /// 设置网格链数量与层级深度
+    pub fn with_meshes(mut self, meshes: u32, hierarchy_depth: u32) -> Self {
+        self.meshes = meshes;
+        self.hierarchy_depth = hierarchy_depth;
+        self
+    }
+
+    /// 设置预热与采样时长（秒）
+    pub fn with_duration(mut self, warmup_secs: f32, duration_secs: f32) -> Self {
+        self.warmup_secs = warmup_secs;
+        self.duration_secs = duration_secs;
+        self
+    }
+
+    /// 设置 CSV 输出目录
+    pub fn with_output_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = dir.into();
+        self
+    }
+
+    /// 检查参数
+    pub fn validate(&self) -> Result<()> {
+        if self.meshes > 0 && self.hierarchy_depth == 0 {
+            return Err(AnvilKitError::config("压力测试: hierarchy_depth 至少为 1".to_string()));
+        }
+        if self.duration_secs <= 0.0 || self.warmup_secs < 0.0 {
+            return Err(AnvilKitError::config("压力测试: 采样时长必须为正，预热时长不能为负".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 本次测试的 CSV 文件路径（文件名包含负载参数，便于对比）
+    pub fn csv_path(&self) -> PathBuf {
+        self.output_dir.join(format!(
+            "stress-{}s-{}l-{}m-d{}.csv",
+            self.sprites, self.lights, self.meshes, self.hierarchy_depth
+        ))
+    }
+}
+
+/// 开始压力测试 (Event)；测试进行中时先中止当前测试
+#[derive(Event, Debug, Clone)]
+pub struct RunStressTest(pub StressTestConfig);
+
+/// 压力测试完成 (Event)
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct StressTestFinished {
+    /// CSV 路径
+    pub path: PathBuf,
+    /// 采样帧数
+    pub frames: usize,
+    /// 平均帧时间（毫秒）
+    pub average_frame_ms: f64,
+}
+
+/// 单帧采样
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StressSample {
+    /// 自采样开始的时间（秒）
+    pub time: f64,
+    /// 帧时间（毫秒）
+    pub frame_time_ms: f64,
+    /// FPS
+    pub fps: f64,
+    /// 绘制调用数
+    pub draw_calls: f64,
+    /// 三角形数
+    pub triangles: f64,
+    /// 实体数
+    pub entities: f64,
+    /// GPU 帧时间（毫秒，未启用 GPU 计时时为空）
+    pub gpu_frame_ms: Option<f64>,
+}
+
+/// 把采样格式化为 CSV（首行为表头）
+pub fn samples_to_csv(samples: &[StressSample]) -> String {
+    let mut csv = String::from("frame,time_s,frame_time_ms,fps,draw_calls,triangles,entities,gpu_frame_ms\n");
+    for (frame, s) in samples.iter().enumerate() {
+        let gpu = s.gpu_frame_ms.map(|v| format!("{:.3}", v)).unwrap_or_default();
+        let _ = writeln!(
+            csv,
+            "{},{:.4},{:.3},{:.1},{},{},{},{}",
+            frame, s.time, s.frame_time_ms, s.fps, s.draw_calls, s.triangles, s.entities, gpu
+        );
+    }
+    csv
+}
+
+/// 压力测试生成的实体标记
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StressEntity;
+
+/// 压力测试网格：等待上传共享网格后挂上 [`MeshHandle`]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StressMesh {
+    /// 网格链中的立方体
+    Cube,
+    /// 精灵负载的四边形
+    Quad,
+}
+
+/// 绕 Y 轴匀速旋转（弧度 / 秒）
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StressSpin(pub f32);
+
+/// 当前测试状态 (Resource)
+#[derive(Resource, Debug, Default)]
+pub struct StressTest {
+    config: Option<StressTestConfig>,
+    elapsed: f32,
+    samples: Vec<StressSample>,
+    /// 本次测试加入 [`SceneLights`] 的点光源，结束后逐个移除
+    lights: Vec<PointLight>,
+    /// 共享的立方体网格（首次需要时上传）
+    cube: Option<MeshHandle>,
+    /// 共享的四边形网格（首次需要时上传）
+    quad: Option<MeshHandle>,
+}
+
+impl StressTest {
+    /// 是否正在运行
+    pub fn is_running(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// 当前配置
+    pub fn config(&self) -> Option<&StressTestConfig> {
+        self.config.as_ref()
+    }
+
+    /// 已采集的帧数
+    pub fn sample_count(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+/// 以原点为中心的正方形网格上第 `index` 个位置
+fn grid_position(index: u32, count: u32, spacing: f32) -> Vec2 {
+    let side = (count as f32).sqrt().ceil().max(1.0) as u32;
+    let offset = (side - 1) as f32 * spacing * 0.5;
+    Vec2::new((index % side) as f32 * spacing - offset, (index / side) as f32 * spacing - offset)
+}
+
+/// 生成负载实体，返回加入 [`SceneLights`] 的点光源
+fn spawn_workload(world: &mut World, config: &StressTestConfig) -> Vec<PointLight> {
+    for i in 0..config.sprites {
+        let p = grid_position(i, config.sprites, 0.5);
+        let hue = i as f32 / config.sprites.max(1) as f32;
+        let transform = Transform::from_translation(Vec3::new(p.x, -0.5, p.y));
+        world.spawn((
+            transform,
+            GlobalTransform::from_transform(&transform),
+            StandardMaterial { base_color: [hue, 1.0 - hue, 0.5, 1.0], ..Default::default() },
+            StressMesh::Quad,
+            StressEntity,
+        ));
+    }
+
+    for i in 0..config.meshes {
+        let p = grid_position(i, config.meshes, 3.0);
+        let mut parent: Option<Entity> = None;
+        for depth in 0..config.hierarchy_depth {
+            let transform = if parent.is_some() {
+                Transform::from_translation(Vec3::new(0.0, 1.2, 0.0)).with_scale(Vec3::splat(0.8))
+            } else {
+                Transform::from_translation(Vec3::new(p.x, 0.0, p.y))
+            };
+            let speed = 0.5 + depth as f32 * 0.25;
+            let mut entity = world.spawn((
+                transform,
+                GlobalTransform::from_transform(&transform),
+                StandardMaterial::default(),
+                StressSpin(if i % 2 == 0 { speed } else { -speed }),
+                StressMesh::Cube,
+                StressEntity,
+            ));
+            if let Some(parent) = parent {
+                entity.insert(Parent(parent));
+            }
+            let id = entity.id();
+            if let Some(parent) = parent {
+                world.entity_mut(parent).insert(Children::new(vec![id]));
+            }
+            parent = Some(id);
+        }
+    }
+
+    let spawned: Vec<PointLight> = (0..config.lights)
+        .map(|i| {
+            let p = grid_position(i, config.lights, 6.0);
+            let t = i as f32 / config.lights.max(1) as f32;
+            PointLight {
+                position: Vec3::new(p.x, 3.0, p.y),
+                color: Vec3::new(1.0 - t, 0.6, t),
+                intensity: 4.0,
+                range: 12.0,
+            }
+        })
+        .collect();
+    world.get_resource_or_insert_with(SceneLights::default).point_lights.extend(spawned.iter().cloned());
+    spawned
+}
+
+/// 清理生成的实体，并只移除测试加入的光源（保留期间用户新增的光源）
+fn despawn_workload(world: &mut World, spawned_lights: &[PointLight]) {
+    let entities: Vec<Entity> = world.query_filtered::<Entity, With<StressEntity>>().iter(world).collect();
+    for entity in entities {
+        world.despawn(entity);
+    }
+    if let Some(mut lights) = world.get_resource_mut::<SceneLights>() {
+        for light in spawned_lights {
+            if let Some(index) = lights.point_lights.iter().rposition(|l| l == light) {
+                lights.point_lights.remove(index);
+            }
+        }
+    }
+}
+
+fn start(world: &mut World, config: StressTestConfig) {
+    if let Err(e) = config.validate() {
+        log::error!("{}", e);
+        return;
+    }
+    log::info!(
+        "开始压力测试: {} 精灵, {} 光源, {} 网格链 × 深度 {}",
+        config.sprites, config.lights, config.meshes, config.hierarchy_depth
+    );
+    let lights = spawn_workload(world, &config);
+    let mut test = world.resource_mut::<StressTest>();
+    test.lights = lights;
+    test.elapsed = 0.0;
+    test.samples.clear();
+    test.config = Some(config);
+}
+
+/// 结束测试：写 CSV（`write` 为 `false` 时中止不写）并清理
+fn finish(world: &mut World, write: bool) {
+    let (config, samples, lights) = {
+        let mut test = world.resource_mut::<StressTest>();
+        let Some(config) = test.config.take() else { return };
+        (config, std::mem::take(&mut test.samples), std::mem::take(&mut test.lights))
+    };
+    despawn_workload(world, &lights);
+    if !write {
+        log::info!("压力测试已中止");
+        return;
+    }
+
+    let path = config.csv_path();
+    match write_csv(&path, &samples) {
+        Ok(()) => {
+            let average_frame_ms = if samples.is_empty() {
+                0.0
+            } else {
+                samples.iter().map(|s| s.frame_time_ms).sum::<f64>() / samples.len() as f64
+            };
+            log::info!("压力测试完成: {} 帧, 平均 {:.2} ms → {}", samples.len(), average_frame_ms, path.display());
+            world.send_event(StressTestFinished { path, frames: samples.len(), average_frame_ms });
+        }
+        Err(e) => log::error!("{}", e),
+    }
+}
+
+fn write_csv(path: &Path, samples: &[StressSample]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| AnvilKitError::persistence_with_path(format!("创建目录失败: {}", e), dir.display().to_string()))?;
+    }
+    std::fs::write(path, samples_to_csv(samples))
+        .map_err(|e| AnvilKitError::persistence_with_path(format!("写入压力测试结果失败: {}", e), path.display().to_string()))
+}
+
+/// 处理开始事件与切换按键 (Update)
+pub fn stress_test_control_system(world: &mut World) {
+    let requests: Vec<StressTestConfig> = world
+        .get_resource_mut::<Events<RunStressTest>>()
+        .map(|mut events| events.drain().map(|e| e.0).collect())
+        .unwrap_or_default();
+
+    let toggle_key = world.get_resource::<StressTestToggle>().and_then(|t| t.key);
+    let toggled = toggle_key.is_some_and(|key| {
+        world.get_resource::<InputState>().is_some_and(|input| input.is_key_just_pressed(key))
+    });
+
+    if toggled && requests.is_empty() {
+        if world.resource::<StressTest>().is_running() {
+            finish(world, false);
+        } else {
+            let config = world.resource::<StressTestToggle>().config.clone();
+            start(world, config);
+        }
+        return;
+    }
+    if let Some(config) = requests.into_iter().last() {
+        finish(world, false);
+        start(world, config);
+    }
+}
+
+/// 旋转网格链 (Update)
+pub fn stress_spin_system(dt: Option<Res<DeltaTime>>, mut query: Query<(&StressSpin, &mut Transform)>) {
+    let dt = dt.map_or(0.0, |d| d.0);
+    for (spin, mut transform) in &mut query {
+        transform.rotation = Quat::from_rotation_y(spin.0 * dt) * transform.rotation;
+    }
+}
+
+/// 采样诊断数据，到时后写出结果 (Last，在诊断采样之后)
+pub fn stress_test_sample_system(world: &mut World) {
+    let dt = world.get_resource::<DeltaTime>().map_or(0.0, |d| d.0);
+    let sample = world.get_resource::<Diagnostics>().map(|d| StressSample {
+        time: 0.0,
+        frame_time_ms: d.value(FRAME_TIME).unwrap_or(dt as f64 * 1000.0),
+        fps: d.value(FPS).unwrap_or(0.0),
+        draw_calls: d.value(DRAW_CALLS).unwrap_or(0.0),
+        triangles: d.value(TRIANGLES).unwrap_or(0.0),
+        entities: d.value(ENTITY_COUNT).unwrap_or(0.0),
+        gpu_frame_ms: d.value(GPU_FRAME_TIME),
+    });
+
+    let done = {
+        let mut test = world.resource_mut::<StressTest>();
+        let Some(config) = test.config.clone() else { return };
+        test.elapsed += dt;
+        let sampling = test.elapsed - config.warmup_secs;
+        if sampling >= 0.0 {
+            let mut sample = sample.unwrap_or_default();
+            sample.time = sampling as f64;
+            test.samples.push(sample);
+        }
+        sampling >= config.duration_secs
+    };
+    if done {
+        finish(world, true);
+    }
+}
+
+/// 为等待中的网格实体上传共享网格并挂上句柄（渲染循环在 `app.update()` 前调用）
+pub fn prepare_stress_meshes(device: &RenderDevice, world: &mut World) {
+    if !world.get_resource::<StressTest>().is_some_and(StressTest::is_running) {
+        return;
+    }
+    let pending: Vec<(Entity, StressMesh)> = world
+        .query_filtered::<(Entity, &StressMesh), Without<MeshHandle>>()
+        .iter(world)
+        .map(|(entity, kind)| (entity, *kind))
+        .collect();
+    if pending.is_empty() {
+        return;
+    }
+
+    for (entity, kind) in pending {
+        let handle = shared_mesh(device, world, kind);
+        world.entity_mut(entity).insert(handle);
+    }
+}
+
+/// 取共享网格，首次使用时上传
+fn shared_mesh(device: &RenderDevice, world: &mut World, kind: StressMesh) -> MeshHandle {
+    let test = world.resource::<StressTest>();
+    let cached = match kind {
+        StressMesh::Cube => test.cube,
+        StressMesh::Quad => test.quad,
+    };
+    if let Some(handle) = cached {
+        return handle;
+    }
+
+    let (mesh, label) = match kind {
+        StressMesh::Cube => (MeshData::generate_box(1.0), "Stress Test Cube"),
+        StressMesh::Quad => (MeshData::generate_plane(0.4), "Stress Test Quad"),
+    };
+    let vertices: Vec<PbrVertex> = mesh.to_pbr_vertices()
+        .into_iter()
+        .map(|v| PbrVertex { position: v.position, normal: v.normal, texcoord: v.texcoord, tangent: v.tangent })
+        .collect();
+    let handle = world.get_resource_or_insert_with(RenderAssets::default)
+        .upload_mesh_u32(device, &vertices, &mesh.indices, label);
+    let mut test = world.resource_mut::<StressTest>();
+    match kind {
+        StressMesh::Cube => test.cube = Some(handle),
+        StressMesh::Quad => test.quad = Some(handle),
+    }
+    handle
+}
+
+/// 切换按键与其使用的配置 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct StressTestToggle {
+    /// 开始 / 中止按键，`None` 禁用
+    pub key: Option<KeyCode>,
+    /// 按键开始时使用的配置
+    pub config: StressTestConfig,
+}
+
+/// 压力测试插件
+pub struct StressTestPlugin {
+    /// 按键开始时使用的配置
+    pub config: StressTestConfig,
+    /// 开始 / 中止按键（默认 F8），`None` 只能通过事件开始
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Default for StressTestPlugin {
+    fn default() -> Self {
+        Self { config: StressTestConfig::default(), toggle_key: Some(KeyCode::F8) }
+    }
+}
+
+impl StressTestPlugin {
+    /// 使用指定配置
+    pub fn new(config: StressTestConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    /// 设置切换按键
+    pub fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+}
+
+impl Plugin for StressTestPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<Diagnostics>() {
+            app.add_plugins(crate::diagnostics::DiagnosticsPlugin::default());
+        }
+        app.init_resource::<StressTest>()
+            .insert_resource(StressTestToggle { key: self.toggle_key, config: self.config.clone() })
+            .add_event::<RunStressTest>()
+            .add_event::<StressTestFinished>()
+            .add_systems(bevy_app::Update, (stress_test_control_system, stress_spin_system).chain())
+            .add_systems(
+                bevy_app::Last,
+                stress_test_sample_system.after(anvilkit_core::diagnostics::sample_diagnostics),
+            );
+    }
+
+    fn name(&self) -> &str {
+        "StressTestPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_to_csv() {
+        let csv = samples_to_csv(&[
+            StressSample { time: 0.0, frame_time_ms: 16.6667, fps: 60.0, draw_calls: 12.0, triangles: 3400.0, entities: 120.0, gpu_frame_ms: None },
+            StressSample { time: 0.016, frame_time_ms: 8.0, fps: 125.0, draw_calls: 12.0, triangles: 3400.0, entities: 120.0, gpu_frame_ms: Some(4.25) },
+        ]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "frame,time_s,frame_time_ms,fps,draw_calls,triangles,entities,gpu_frame_ms");
+        assert_eq!(lines[1], "0,0.0000,16.667,60.0,12,3400,120,");
+        assert_eq!(lines[2], "1,0.0160,8.000,125.0,12,3400,120,4.250");
+    }
+
+    #[test]
+    fn test_workload_spawn_and_cleanup() {
+        let mut app = App::new();
+        app.add_plugins(StressTestPlugin::default().with_toggle_key(None));
+        app.init_resource::<SceneLights>();
+        app.world_mut().resource_mut::<SceneLights>().point_lights.push(PointLight::default());
+
+        let config = StressTestConfig::new()
+            .with_sprites(10)
+            .with_lights(3)
+            .with_meshes(4, 3)
+            .with_duration(0.0, 1.0)
+            .with_output_dir(std::env::temp_dir().join("anvilkit_stress_test"));
+        app.world_mut().send_event(RunStressTest(config));
+        app.update();
+
+        let world = app.world_mut();
+        assert!(world.resource::<StressTest>().is_running());
+        let kinds: Vec<StressMesh> = world.query::<&StressMesh>().iter(world).copied().collect();
+        assert_eq!(kinds.iter().filter(|k| **k == StressMesh::Quad).count(), 10);
+        assert_eq!(kinds.iter().filter(|k| **k == StressMesh::Cube).count(), 12);
+        assert_eq!(world.query::<&Parent>().iter(world).count(), 8);
+        assert_eq!(world.resource::<SceneLights>().point_lights.len(), 4);
+
+        // 测试期间用户新增的光源在结束后保留
+        let user_light = PointLight { intensity: 1.0, ..Default::default() };
+        world.resource_mut::<SceneLights>().point_lights.push(user_light.clone());
+
+        finish(world, false);
+        assert_eq!(world.query::<&StressEntity>().iter(world).count(), 0);
+        assert_eq!(world.resource::<SceneLights>().point_lights, vec![PointLight::default(), user_light]);
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(StressTestConfig::default().validate().is_ok());
+        assert!(StressTestConfig::new().with_meshes(5, 0).validate().is_err());
+        assert!(StressTestConfig::new().with_duration(1.0, 0.0).validate().is_err());
+        assert_eq!(
+            StressTestConfig::new().with_output_dir("out").csv_path(),
+            Path::new("out").join("stress-1000s-4l-100m-d3.csv")
+        );
+    }
+}
@@ -15,6 +15,8 @@
 //! - **GlobalTransform**: 全局变换，世界空间中的绝对变换
 //! - **Parent**: 父实体引用
 //! - **Children**: 子实体列表
+//! - **Transform2D**: 2D 变换（位置、弧度旋转、缩放、z 序），与 Transform 双向同步
+//! - **PreviousTransform**: 固定步长插值历史，平滑低步频驱动的运动
 //! 
 //! ## 使用示例
 //! 
@@ -44,8 +46,9 @@
 use std::collections::HashSet;
 
 use bevy_ecs::prelude::*;
+use anvilkit_core::time::FixedStepProgress;
 // 重新导出 anvilkit-core 的变换类型
-pub use anvilkit_core::math::{Transform, GlobalTransform};
+pub use anvilkit_core::math::{Transform, GlobalTransform, Transform2D};
 
 /// 父实体组件
 /// 
@@ -189,6 +192,65 @@ impl From<Vec<Entity>> for Children {
     }
 }
 
+/// 固定步长插值组件
+///
+/// 挂在由固定步长（如物理）驱动的根实体上。渲染帧率高于逻辑步频时，
+/// 按 [`FixedStepProgress::alpha`] 在上一步与当前步的 `Transform` 之间混合出 `GlobalTransform`，
+/// 避免画面随步长抖动。代价是渲染结果落后逻辑状态至多一步。
+///
+/// 只对根实体（无 [`Parent`]）生效，子实体经层次传播跟随插值后的父变换。
+/// 在非固定步长的帧里修改 `Transform` 视为瞬移，不做插值。
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::prelude::*;
+///
+/// let mut world = World::new();
+/// let transform = Transform::from_xyz(0.0, 5.0, 0.0);
+/// world.spawn((transform, GlobalTransform::default(), PreviousTransform::new(transform)));
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PreviousTransform {
+    /// 上一固定步结束时的变换
+    pub previous: Transform,
+    /// 最近一个固定步结束时的变换
+    current: Transform,
+}
+
+impl PreviousTransform {
+    /// 以当前变换初始化（首帧不插值）
+    pub fn new(transform: Transform) -> Self {
+        Self { previous: transform, current: transform }
+    }
+
+    /// 瞬移到指定变换，清除插值历史
+    pub fn teleport(&mut self, transform: Transform) {
+        self.previous = transform;
+        self.current = transform;
+    }
+
+    /// 按 `alpha` 混合上一步与当前步的变换
+    pub fn interpolate(&self, alpha: f32) -> Transform {
+        self.previous.lerp(&self.current, alpha.clamp(0.0, 1.0))
+    }
+
+    fn record(&mut self, transform: Transform, stepped: bool) {
+        if stepped {
+            self.previous = self.current;
+            self.current = transform;
+        } else if transform != self.current {
+            self.teleport(transform);
+        }
+    }
+}
+
+impl From<Transform> for PreviousTransform {
+    fn from(transform: Transform) -> Self {
+        Self::new(transform)
+    }
+}
+
 /// 变换插件
 /// 
 /// 提供变换系统的完整功能，包括层次传播和变更检测。
@@ -199,6 +261,7 @@ impl From<Vec<Entity>> for Children {
 /// - 父子关系管理
 /// - 变更检测优化
 /// - 全局变换计算
+/// - 固定步长插值
 /// 
 /// # 示例
 /// 
@@ -218,7 +281,10 @@ impl bevy_app::Plugin for TransformPlugin {
         app.add_systems(
             bevy_app::PostUpdate,
             (
+                sync_transform2d,
                 sync_simple_transforms,
+                track_previous_transforms,
+                interpolate_transforms,
                 propagate_transforms,
             )
                 .chain(),
@@ -230,6 +296,24 @@ impl bevy_app::Plugin for TransformPlugin {
     }
 }
 
+/// 同步 2D 变换系统
+///
+/// `Transform2D` 变化时写入 `Transform`，之后由常规层次传播计算 `GlobalTransform`；
+/// 仅 `Transform` 被修改时（如 2D 物理写回）反向更新 `Transform2D`，
+/// 且不触发其变更检测，避免来回同步。
+pub fn sync_transform2d(mut query: Query<(&mut Transform2D, &mut Transform)>) {
+    for (mut transform2d, mut transform) in &mut query {
+        if transform2d.is_changed() {
+            let target = transform2d.to_transform();
+            if *transform != target {
+                *transform = target;
+            }
+        } else if transform.is_changed() {
+            *transform2d.bypass_change_detection() = Transform2D::from_transform(&transform);
+        }
+    }
+}
+
 /// 同步简单变换系统
 /// 
 /// 对于没有父实体的实体，直接将本地变换复制到全局变换。
@@ -246,112 +330,67 @@ pub fn sync_simple_transforms(
     }
 }
 
+/// 记录固定步长状态系统
+///
+/// 本帧执行过固定步时，把上一步的变换移入 [`PreviousTransform::previous`]；
+/// 未执行固定步但 `Transform` 被修改时按瞬移处理。
+pub fn track_previous_transforms(
+    progress: Option<Res<FixedStepProgress>>,
+    mut query: Query<(&Transform, &mut PreviousTransform)>,
+) {
+    let stepped = progress.is_some_and(|p| p.steps > 0);
+    for (transform, mut previous) in &mut query {
+        previous.record(*transform, stepped);
+    }
+}
+
+/// 变换插值系统
+///
+/// 对带 [`PreviousTransform`] 的根实体，用 [`FixedStepProgress::alpha`]
+/// 混合出渲染用的 `GlobalTransform`，覆盖 [`sync_simple_transforms`] 的结果。
+pub fn interpolate_transforms(
+    progress: Option<Res<FixedStepProgress>>,
+    mut query: Query<(&PreviousTransform, &mut GlobalTransform), Without<Parent>>,
+) {
+    let alpha = progress.map_or(1.0, |p| p.alpha);
+    for (previous, mut global_transform) in &mut query {
+        *global_transform = GlobalTransform::from(previous.interpolate(alpha));
+    }
+}
+
 /// 传播变换系统
 ///
 /// 将父实体的全局变换传播到所有子实体。
 /// 
 /// 这个系统实现了变换层次的核心逻辑，确保子实体的全局变换
 /// 正确反映其在世界空间中的位置。
+///
+/// 使用显式栈做深度优先遍历，栈保存在 `Local` 中跨帧复用，
+/// 稳定后传播过程不产生堆分配，也不受层级深度的递归栈限制。
 pub fn propagate_transforms(
-    mut root_query: Query<
-        (Entity, &Children, &GlobalTransform),
+    root_query: Query<
+        (&Children, &GlobalTransform),
         Without<Parent>,
     >,
     mut transform_query: Query<(&Transform, &mut GlobalTransform, Option<&Children>), With<Parent>>,
-    children_query: Query<&Children, (With<Parent>, Without<GlobalTransform>)>,
+    mut stack: Local<Vec<(GlobalTransform, Entity)>>,
 ) {
     // 处理根实体的变换传播（每帧对所有根实体传播，确保子实体本地变换变更也被捕获）
-    for (_entity, children, global_transform) in &mut root_query {
-        propagate_recursive(
-            global_transform,
-            children,
-            &mut transform_query,
-            &children_query,
-        );
-    }
-}
+    for (children, global_transform) in &root_query {
+        stack.extend(children.iter().map(|&child| (*global_transform, child)));
 
-/// 递归传播变换
-/// 
-/// 递归地将父变换传播到所有子实体及其后代。
-/// 
-/// # 参数
-/// 
-/// - `parent_global`: 父实体的全局变换
-/// - `children`: 子实体列表
-/// - `transform_query`: 变换查询
-/// - `children_query`: 子实体查询
-fn propagate_recursive(
-    parent_global: &GlobalTransform,
-    children: &Children,
-    transform_query: &mut Query<(&Transform, &mut GlobalTransform, Option<&Children>), With<Parent>>,
-    children_query: &Query<&Children, (With<Parent>, Without<GlobalTransform>)>,
-) {
-    // 收集需要递归处理的子实体（仅复制 Entity ID，避免 clone 整个 Children）
-    let mut to_recurse: Vec<(GlobalTransform, Vec<Entity>)> = Vec::new();
-
-    for &child_entity in children.iter() {
-        // 尝试获取子实体的变换组件
-        if let Ok((transform, mut global_transform, child_children)) =
-            transform_query.get_mut(child_entity) {
-
-            // 计算子实体的全局变换
+        while let Some((parent_global, entity)) = stack.pop() {
+            let Ok((transform, mut global_transform, grandchildren)) = transform_query.get_mut(entity) else {
+                continue;
+            };
             let new_global = parent_global.mul_transform(&GlobalTransform::from(*transform));
             *global_transform = new_global;
 
-            // 如果子实体还有自己的子实体，收集 Entity ID 稍后处理
-            if let Some(grandchildren) = child_children {
-                let child_entities: Vec<Entity> = grandchildren.iter().copied().collect();
-                to_recurse.push((new_global, child_entities));
+            if let Some(grandchildren) = grandchildren {
+                stack.extend(grandchildren.iter().map(|&child| (new_global, child)));
             }
         }
     }
-
-    // 递归处理子实体
-    for (global_transform, child_entities) in to_recurse {
-        propagate_recursive_entities(
-            &global_transform,
-            &child_entities,
-            transform_query,
-            children_query,
-        );
-    }
-}
-
-/// 递归传播变换（基于 Entity 切片）
-///
-/// 与 `propagate_recursive` 相同的逻辑，但接受 `&[Entity]` 而非 `&Children`，
-/// 避免在递归过程中 clone 整个 Children 组件。
-fn propagate_recursive_entities(
-    parent_global: &GlobalTransform,
-    child_entities: &[Entity],
-    transform_query: &mut Query<(&Transform, &mut GlobalTransform, Option<&Children>), With<Parent>>,
-    children_query: &Query<&Children, (With<Parent>, Without<GlobalTransform>)>,
-) {
-    let mut to_recurse: Vec<(GlobalTransform, Vec<Entity>)> = Vec::new();
-
-    for &child_entity in child_entities.iter() {
-        if let Ok((transform, mut global_transform, child_children)) =
-            transform_query.get_mut(child_entity) {
-
-            let new_global = parent_global.mul_transform(&GlobalTransform::from(*transform));
-            *global_transform = new_global;
-
-            if let Some(grandchildren) = child_children {
-                let entities: Vec<Entity> = grandchildren.iter().copied().collect();
-                to_recurse.push((new_global, entities));
-            }
-        }
-    }
-
-    for (global_transform, entities) in to_recurse {
-        propagate_recursive_entities(
-            &global_transform,
-            &entities,
-            transform_query,
-            children_query,
-        );
-    }
 }
 
 /// 变换层次工具
@@ -616,6 +655,55 @@ mod tests {
         assert_eq!(global_transform.translation(), Vec3::new(1.0, 2.0, 3.0));
     }
 
+    #[test]
+    fn test_interpolate_transforms() {
+        let mut world = World::new();
+        let start = Transform::from_xyz(0.0, 0.0, 0.0);
+        let entity = world.spawn((start, GlobalTransform::default(), PreviousTransform::new(start))).id();
+
+        let mut track = IntoSystem::into_system(track_previous_transforms);
+        track.initialize(&mut world);
+        let mut interpolate = IntoSystem::into_system(interpolate_transforms);
+        interpolate.initialize(&mut world);
+
+        // 执行一个固定步后移动到 x = 4，剩余四分之一步
+        world.get_mut::<Transform>(entity).unwrap().translation.x = 4.0;
+        world.insert_resource(FixedStepProgress { alpha: 0.25, steps: 1 });
+        track.run((), &mut world);
+        interpolate.run((), &mut world);
+        assert_eq!(world.get::<GlobalTransform>(entity).unwrap().translation(), Vec3::new(1.0, 0.0, 0.0));
+
+        // 未执行固定步：历史保持，只推进 alpha
+        world.insert_resource(FixedStepProgress { alpha: 0.75, steps: 0 });
+        track.run((), &mut world);
+        interpolate.run((), &mut world);
+        assert_eq!(world.get::<GlobalTransform>(entity).unwrap().translation(), Vec3::new(3.0, 0.0, 0.0));
+
+        // 固定步之外的修改视为瞬移
+        world.get_mut::<Transform>(entity).unwrap().translation.x = -10.0;
+        track.run((), &mut world);
+        interpolate.run((), &mut world);
+        assert_eq!(world.get::<GlobalTransform>(entity).unwrap().translation(), Vec3::new(-10.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_sync_transform2d() {
+        let mut world = World::new();
+        let entity = world.spawn(Transform2D::from_xy(2.0, 3.0).with_z_index(1.0)).id();
+        assert!(world.get::<GlobalTransform>(entity).is_some());
+
+        let mut system = IntoSystem::into_system(sync_transform2d);
+        system.initialize(&mut world);
+        system.run((), &mut world);
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation, Vec3::new(2.0, 3.0, 1.0));
+
+        // 外部修改 Transform（如物理写回）同步回 Transform2D
+        world.get_mut::<Transform>(entity).unwrap().translation.x = 7.0;
+        system.run((), &mut world);
+        assert_eq!(world.get::<Transform2D>(entity).unwrap().translation, glam::Vec2::new(7.0, 3.0));
+        assert_eq!(world.get::<Transform>(entity).unwrap().translation.x, 7.0);
+    }
+
     #[test]
     fn test_children_from_vec() {
         let mut world = World::new();
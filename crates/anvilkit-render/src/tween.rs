@@ -0,0 +1,422 @@
+//! # 补间
+//!
+//! [`Tween<L>`] 组件在一段时间内把组件字段从当前值插值到目标值，权重由
+//! [`EaseFunction`] 缓动函数给出：
+//!
+//! - [`TweenLens`] 描述被驱动的字段（组件类型 + 可 [`Lerp`] 的值类型），内置
+//!   [`TranslationLens`] / [`RotationLens`] / [`ScaleLens`]，以及颜色 lens `BaseColorLens`
+//!   （`StandardMaterial::base_color`，`render-3d`）与 `UiColorLens`（`UiNode::background_color`，
+//!   `render-2d`）；自定义字段实现该 trait 后用 [`TweenAppExt::register_tween`] 注册对应的系统
+//! - [`TweenRepeat`] 控制重复次数，`yoyo` 使每个周期往返交替
+//! - 补间结束时发送 [`TweenCompleted`] 事件并移除组件
+//!
+//! 起点在首次更新时从组件读取（也可用 [`Tween::with_start`] 指定），系统在 `Update` 中运行，
+//! 早于 `TransformPlugin` 的层次传播。
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::tween::{Tween, TweenAppExt, TweenLens, TweenPlugin, TweenRepeat};
+//! use anvilkit_core::math::{Color, EaseFunction};
+//!
+//! #[derive(Component)]
+//! struct Tint(Color);
+//!
+//! struct TintLens;
+//! impl TweenLens for TintLens {
+//!     type Component = Tint;
+//!     type Value = Color;
+//!     fn field(tint: &mut Tint) -> &mut Color { &mut tint.0 }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_plugins(TweenPlugin).register_tween::<TintLens>();
+//! app.world_mut().spawn((
+//!     Transform::default(),
+//!     Tween::translation(Vec3::new(0.0, 2.0, 0.0), 0.5).with_ease(EaseFunction::BackOut),
+//!     Tint(Color::WHITE),
+//!     Tween::<TintLens>::new(Color::rgb(1.0, 0.0, 0.0), 0.25).with_repeat(TweenRepeat::Forever).with_yoyo(true),
+//! ));
+//! app.update();
+//! ```
+
+use std::any::TypeId;
+use std::collections::HashSet;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use glam::{Quat, Vec3};
+
+use anvilkit_core::math::{EaseFunction, Lerp};
+pub use anvilkit_core::math::lens::{RotationLens, ScaleLens, TranslationLens, TweenLens};
+use anvilkit_core::time::DeltaTime;
+
+/// `StandardMaterial::base_color`（线性 RGBA，逐分量插值）
+#[cfg(feature = "render-3d")]
+#[derive(Debug, Clone, Copy)]
+pub struct BaseColorLens;
+
+#[cfg(feature = "render-3d")]
+impl TweenLens for BaseColorLens {
+    type Component = crate::renderer::standard_material::StandardMaterial;
+    type Value = [f32; 4];
+    fn field(material: &mut Self::Component) -> &mut [f32; 4] {
+        &mut material.base_color
+    }
+}
+
+/// `UiNode::background_color`（RGBA，逐分量插值）
+#[cfg(feature = "render-2d")]
+#[derive(Debug, Clone, Copy)]
+pub struct UiColorLens;
+
+#[cfg(feature = "render-2d")]
+impl TweenLens for UiColorLens {
+    type Component = crate::renderer::ui::UiNode;
+    type Value = [f32; 4];
+    fn field(node: &mut Self::Component) -> &mut [f32; 4] {
+        &mut node.background_color
+    }
+}
+
+/// 重复次数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TweenRepeat {
+    /// 播放一个周期
+    #[default]
+    Once,
+    /// 播放指定周期数（`Times(1)` 等同于 `Once`）
+    Times(u32),
+    /// 无限重复，不会完成
+    Forever,
+}
+
+/// 补间组件
+#[derive(Component, Debug, Clone)]
+pub struct Tween<L: TweenLens> {
+    start: Option<L::Value>,
+    /// 目标值
+    pub end: L::Value,
+    /// 单个周期时长（秒）
+    pub duration: f32,
+    /// 缓动函数
+    pub ease: EaseFunction,
+    /// 重复次数
+    pub repeat: TweenRepeat,
+    /// 往返：奇数周期从目标值返回起点
+    pub yoyo: bool,
+    /// 暂停
+    pub paused: bool,
+    /// 完成事件中携带的用户标识
+    pub id: u32,
+    elapsed: f32,
+    cycle: u32,
+}
+
+/// 平移补间
+pub type TranslationTween = Tween<TranslationLens>;
+/// 旋转补间
+pub type RotationTween = Tween<RotationLens>;
+/// 缩放补间
+pub type ScaleTween = Tween<ScaleLens>;
+
+impl<L: TweenLens> Tween<L> {
+    /// 在 `duration` 秒内从当前值补间到 `end`（线性、播放一次）
+    pub fn new(end: L::Value, duration: f32) -> Self {
+        Self {
+            start: None,
+            end,
+            duration,
+            ease: EaseFunction::Linear,
+            repeat: TweenRepeat::Once,
+            yoyo: false,
+            paused: false,
+            id: 0,
+            elapsed: 0.0,
+            cycle: 0,
+        }
+    }
+
+    /// 指定起点（默认在首次更新时读取组件的当前值）
+    pub fn with_start(mut self, start: L::Value) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// 设置缓动函数（链式调用）
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// 设置重复次数（链式调用）
+    pub fn with_repeat(mut self, repeat: TweenRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// 设置往返（链式调用）
+    pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    /// 设置完成事件中的用户标识（链式调用）
+    pub fn with_id(mut self, id: u32) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// 已完成的周期数
+    pub fn completed_cycles(&self) -> u32 {
+        self.cycle
+    }
+
+    /// 当前周期内的归一化进度 `[0, 1]`（未考虑往返方向与缓动）
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 { (self.elapsed / self.duration).clamp(0.0, 1.0) } else { 1.0 }
+    }
+
+    fn total_cycles(&self) -> Option<u32> {
+        match self.repeat {
+            TweenRepeat::Once => Some(1),
+            TweenRepeat::Times(n) => Some(n.max(1)),
+            TweenRepeat::Forever => None,
+        }
+    }
+
+    /// 推进 `dt` 秒，返回 `(缓动后的插值权重, 是否完成)`
+    fn advance(&mut self, dt: f32) -> (f32, bool) {
+        self.elapsed += dt.max(0.0);
+        let mut finished = false;
+        if self.duration <= 0.0 {
+            self.cycle = self.total_cycles().unwrap_or(1);
+            finished = self.total_cycles().is_some();
+        } else {
+            while self.elapsed >= self.duration {
+                if self.total_cycles().is_some_and(|total| self.cycle + 1 >= total) {
+                    self.elapsed = self.duration;
+                    finished = true;
+                    break;
+                }
+                self.elapsed -= self.duration;
+                self.cycle += 1;
+            }
+            if finished {
+                self.cycle += 1;
+            }
+        }
+
+        // 完成时停在最后一个周期的终点
+        let cycle = if finished { self.cycle - 1 } else { self.cycle };
+        let t = self.ease.ease(self.progress());
+        let reversed = self.yoyo && cycle % 2 == 1;
+        (if reversed { 1.0 - t } else { t }, finished)
+    }
+}
+
+impl Tween<TranslationLens> {
+    /// 平移补间
+    pub fn translation(end: Vec3, duration: f32) -> Self {
+        Self::new(end, duration)
+    }
+}
+
+impl Tween<RotationLens> {
+    /// 旋转补间
+    pub fn rotation(end: Quat, duration: f32) -> Self {
+        Self::new(end, duration)
+    }
+}
+
+impl Tween<ScaleLens> {
+    /// 缩放补间
+    pub fn scale(end: Vec3, duration: f32) -> Self {
+        Self::new(end, duration)
+    }
+}
+
+/// 补间完成事件（`TweenRepeat::Forever` 不会发送）
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TweenCompleted {
+    /// 补间所在实体
+    pub entity: Entity,
+    /// [`Tween::id`]
+    pub id: u32,
+}
+
+/// 推进 `Tween<L>` 并写入字段；完成后发送 [`TweenCompleted`] 并移除组件
+pub fn tween_system<L: TweenLens>(
+    mut commands: Commands,
+    dt: Res<DeltaTime>,
+    mut query: Query<(Entity, &mut Tween<L>, &mut L::Component)>,
+    mut completed: EventWriter<TweenCompleted>,
+) {
+    for (entity, mut tween, mut component) in &mut query {
+        if tween.paused {
+            continue;
+        }
+        let field = L::field(&mut component);
+        let start = tween.start.get_or_insert_with(|| field.clone()).clone();
+        let (t, finished) = tween.advance(dt.0);
+        *field = start.lerp(&tween.end, t);
+        if finished {
+            completed.send(TweenCompleted { entity, id: tween.id });
+            commands.entity(entity).remove::<Tween<L>>();
+        }
+    }
+}
+
+/// 已注册补间系统的 lens 类型，避免重复注册导致一帧推进两次
+#[derive(Resource, Default)]
+struct RegisteredTweens(HashSet<TypeId>);
+
+/// 注册自定义补间 lens
+pub trait TweenAppExt {
+    /// 为 `L` 添加 [`tween_system`]（重复调用无效果）；未添加 [`TweenPlugin`] 时一并添加，
+    /// 之后再显式添加 [`TweenPlugin`] 也不会冲突
+    fn register_tween<L: TweenLens>(&mut self) -> &mut Self;
+}
+
+impl TweenAppExt for App {
+    fn register_tween<L: TweenLens>(&mut self) -> &mut Self {
+        if !self.is_plugin_added::<TweenPlugin>() && !self.world().contains_resource::<RegisteredTweens>() {
+            self.add_plugins(TweenPlugin);
+        }
+        let newly_registered = self.world_mut().resource_mut::<RegisteredTweens>().0.insert(TypeId::of::<L>());
+        if newly_registered {
+            self.add_systems(bevy_app::Update, tween_system::<L>);
+        }
+        self
+    }
+}
+
+/// 补间插件
+///
+/// 注册 [`TweenCompleted`] 事件、`Transform` 平移 / 旋转 / 缩放与内置颜色补间系统。
+/// 构建是幂等的，因此允许在 [`TweenAppExt::register_tween`] 自动添加后再次显式添加。
+pub struct TweenPlugin;
+
+impl Plugin for TweenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DeltaTime>();
+        app.init_resource::<RegisteredTweens>();
+        app.add_event::<TweenCompleted>();
+        app.register_tween::<TranslationLens>()
+            .register_tween::<RotationLens>()
+            .register_tween::<ScaleLens>();
+        #[cfg(feature = "render-3d")]
+        app.register_tween::<BaseColorLens>();
+        #[cfg(feature = "render-2d")]
+        app.register_tween::<UiColorLens>();
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anvilkit_core::math::{Color, Transform};
+
+    fn run(app: &mut App, dt: f32) {
+        app.world_mut().resource_mut::<DeltaTime>().0 = dt;
+        app.update();
+    }
+
+    fn completed(app: &App) -> Vec<TweenCompleted> {
+        let events = app.world().resource::<Events<TweenCompleted>>();
+        events.get_cursor().read(events).copied().collect()
+    }
+
+    #[test]
+    fn test_advance_repeat_and_yoyo() {
+        let mut tween = Tween::<ScaleLens>::new(Vec3::ONE, 1.0).with_repeat(TweenRepeat::Times(3)).with_yoyo(true);
+        assert_eq!(tween.advance(0.25), (0.25, false));
+        // 第二个周期反向
+        let (t, finished) = tween.advance(1.0);
+        assert!((t - 0.75).abs() < 1e-6 && !finished);
+        assert_eq!(tween.completed_cycles(), 1);
+        // 跨过剩余的两个周期，停在第三个周期（正向）的终点
+        assert_eq!(tween.advance(5.0), (1.0, true));
+        assert_eq!(tween.completed_cycles(), 3);
+
+        let mut forever = Tween::<ScaleLens>::new(Vec3::ONE, 0.5).with_repeat(TweenRepeat::Forever);
+        let (t, finished) = forever.advance(10.2);
+        assert!((t - 0.4).abs() < 1e-4 && !finished);
+    }
+
+    #[test]
+    fn test_transform_tweens_complete_and_emit_events() {
+        let mut app = App::new();
+        app.add_plugins(TweenPlugin);
+        let entity = app.world_mut().spawn((
+            Transform::from_translation(Vec3::new(2.0, 0.0, 0.0)),
+            Tween::translation(Vec3::new(4.0, 0.0, 0.0), 1.0).with_id(7),
+            Tween::scale(Vec3::splat(3.0), 0.5).with_ease(EaseFunction::QuadraticIn),
+        )).id();
+
+        run(&mut app, 0.25);
+        let transform = *app.world().get::<Transform>(entity).unwrap();
+        assert!((transform.translation.x - 2.5).abs() < 1e-5);
+        assert!((transform.scale.x - 1.5).abs() < 1e-5);
+
+        run(&mut app, 0.25);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().scale, Vec3::splat(3.0));
+        assert!(app.world().get::<ScaleTween>(entity).is_none());
+        assert!(app.world().get::<TranslationTween>(entity).is_some());
+
+        run(&mut app, 0.5);
+        assert_eq!(app.world().get::<Transform>(entity).unwrap().translation, Vec3::new(4.0, 0.0, 0.0));
+        assert!(completed(&app).contains(&TweenCompleted { entity, id: 7 }));
+    }
+
+    #[derive(Component)]
+    struct Tint(Color);
+
+    struct TintLens;
+
+    impl TweenLens for TintLens {
+        type Component = Tint;
+        type Value = Color;
+        fn field(tint: &mut Tint) -> &mut Color {
+            &mut tint.0
+        }
+    }
+
+    #[test]
+    fn test_custom_lens_registered_once() {
+        let mut app = App::new();
+        app.register_tween::<TintLens>().register_tween::<TintLens>();
+        // 自动添加后再显式添加插件不会 panic
+        app.add_plugins(TweenPlugin);
+        let entity = app.world_mut().spawn((
+            Tint(Color::BLACK),
+            Tween::<TintLens>::new(Color::WHITE, 1.0).with_start(Color::rgb(0.0, 0.0, 0.0)),
+        )).id();
+
+        run(&mut app, 0.5);
+        assert!((app.world().get::<Tint>(entity).unwrap().0.r - 0.5).abs() < 1e-5);
+
+        app.world_mut().get_mut::<Tween<TintLens>>(entity).unwrap().paused = true;
+        run(&mut app, 0.5);
+        assert!((app.world().get::<Tint>(entity).unwrap().0.r - 0.5).abs() < 1e-5);
+    }
+
+    #[cfg(feature = "render-2d")]
+    #[test]
+    fn test_ui_color_lens() {
+        use crate::renderer::ui::UiNode;
+
+        let mut app = App::new();
+        app.add_plugins(TweenPlugin);
+        let entity = app.world_mut().spawn((
+            UiNode { background_color: [0.0, 0.0, 0.0, 1.0], ..Default::default() },
+            Tween::<UiColorLens>::new([1.0, 0.5, 0.0, 1.0], 1.0),
+        )).id();
+
+        run(&mut app, 0.5);
+        assert_eq!(app.world().get::<UiNode>(entity).unwrap().background_color, [0.5, 0.25, 0.0, 1.0]);
+    }
+}
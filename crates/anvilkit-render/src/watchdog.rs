@@ -0,0 +1,226 @@
+//! # 长帧看门狗
+//!
+//! [`FrameWatchdogPlugin`] 检查 [`CpuFrameProfile`] 中每个完整帧的墙钟时间，
+//! 超过阈值时记为一次卡顿：输出该帧各调度的耗时分解、累加 [`HITCHES`] 指标、
+//! 发送 [`FrameHitch`] 事件，并可把该帧写成 chrome-tracing JSON 快照供离线分析
+//! （拖入 Perfetto 或 `chrome://tracing` 查看）。
+//!
+//! ```rust
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::watchdog::FrameWatchdogPlugin;
+//!
+//! let mut app = App::new();
+//! app.add_plugins(FrameWatchdogPlugin::new(33.0).with_dump_dir("hitches"));
+//! ```
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use anvilkit_core::diagnostics::HITCHES;
+
+use crate::diagnostics::DiagnosticsAppExt;
+use crate::profiling::{CpuFrame, CpuFrameProfile, CpuProfilerPlugin};
+
+/// 检测到长帧时发送的事件
+#[derive(Event, Debug, Clone)]
+pub struct FrameHitch {
+    /// 超时帧的计时
+    pub frame: CpuFrame,
+}
+
+/// 看门狗状态 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct FrameWatchdog {
+    /// 超过该帧时间（毫秒）视为卡顿
+    pub threshold_ms: f64,
+    /// 快照输出目录，`None` 不写快照
+    pub dump_dir: Option<PathBuf>,
+    /// 最多写入的快照数，避免持续卡顿时填满磁盘
+    pub max_dumps: usize,
+    hitches: u64,
+    dumps: usize,
+    last_checked: Option<u64>,
+}
+
+impl FrameWatchdog {
+    /// 以阈值创建
+    pub fn new(threshold_ms: f64) -> Self {
+        Self { threshold_ms, dump_dir: None, max_dumps: 10, hitches: 0, dumps: 0, last_checked: None }
+    }
+
+    /// 累计卡顿次数
+    pub fn hitch_count(&self) -> u64 {
+        self.hitches
+    }
+}
+
+/// 把一帧的各段耗时转为 chrome-tracing JSON（`ph: "X"` 完整事件，时间单位微秒）
+pub fn trace_snapshot_json(frame: &CpuFrame) -> String {
+    let mut json = String::from("{\"traceEvents\":[");
+    let _ = write!(
+        json,
+        "{{\"name\":\"frame {}\",\"ph\":\"X\",\"ts\":0,\"dur\":{:.0},\"pid\":1,\"tid\":1}}",
+        frame.index,
+        frame.milliseconds * 1000.0
+    );
+    for section in &frame.sections {
+        let name = section.name.replace('\\', "\\\\").replace('"', "\\\"");
+        let _ = write!(
+            json,
+            ",{{\"name\":\"{}\",\"ph\":\"X\",\"ts\":{:.0},\"dur\":{:.0},\"pid\":1,\"tid\":1}}",
+            name,
+            section.start_ms * 1000.0,
+            section.milliseconds * 1000.0
+        );
+    }
+    json.push_str("]}");
+    json
+}
+
+/// 检查上一帧是否超时 (First)
+pub fn frame_watchdog_system(
+    profile: Res<CpuFrameProfile>,
+    mut watchdog: ResMut<FrameWatchdog>,
+    mut hitches: EventWriter<FrameHitch>,
+    #[cfg(feature = "trace-chrome")] session: Option<NonSend<crate::profiling::ChromeTraceSession>>,
+) {
+    let Some(frame) = profile.last_frame() else { return };
+    if watchdog.last_checked == Some(frame.index) {
+        return;
+    }
+    watchdog.last_checked = Some(frame.index);
+    if frame.milliseconds <= watchdog.threshold_ms {
+        return;
+    }
+
+    watchdog.hitches += 1;
+    log::warn!(
+        "长帧 #{}: {:.1} ms（阈值 {:.1} ms）\n{}",
+        frame.index,
+        frame.milliseconds,
+        watchdog.threshold_ms,
+        frame.breakdown()
+    );
+
+    #[cfg(feature = "trace-chrome")]
+    if let Some(session) = session {
+        session.flush();
+    }
+
+    if let Some(dir) = watchdog.dump_dir.clone() {
+        if watchdog.dumps < watchdog.max_dumps {
+            let path = dir.join(format!("hitch-{}.json", frame.index));
+            let written = std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&path, trace_snapshot_json(frame)));
+            match written {
+                Ok(()) => {
+                    watchdog.dumps += 1;
+                    log::info!("长帧快照已写入 {}", path.display());
+                }
+                Err(e) => log::error!("写入长帧快照失败 {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    hitches.send(FrameHitch { frame: frame.clone() });
+}
+
+/// 长帧看门狗插件
+///
+/// 未添加 [`CpuProfilerPlugin`] 时一并添加。
+pub struct FrameWatchdogPlugin {
+    /// 卡顿阈值（毫秒，默认 50）
+    pub threshold_ms: f64,
+    /// 快照输出目录（默认不写）
+    pub dump_dir: Option<PathBuf>,
+}
+
+impl Default for FrameWatchdogPlugin {
+    fn default() -> Self {
+        Self::new(50.0)
+    }
+}
+
+impl FrameWatchdogPlugin {
+    /// 以阈值创建
+    pub fn new(threshold_ms: f64) -> Self {
+        Self { threshold_ms, dump_dir: None }
+    }
+
+    /// 卡顿时把该帧写成 chrome-tracing 快照到指定目录
+    pub fn with_dump_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dump_dir = Some(dir.into());
+        self
+    }
+}
+
+impl Plugin for FrameWatchdogPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.world().contains_resource::<CpuFrameProfile>() {
+            app.add_plugins(CpuProfilerPlugin);
+        }
+        let mut watchdog = FrameWatchdog::new(self.threshold_ms);
+        watchdog.dump_dir = self.dump_dir.clone();
+        app.insert_resource(watchdog)
+            .add_event::<FrameHitch>()
+            .add_systems(bevy_app::First, frame_watchdog_system)
+            .register_diagnostic(HITCHES, "", |world| {
+                world.get_resource::<FrameWatchdog>().map(|w| w.hitch_count() as f64)
+            });
+    }
+
+    fn name(&self) -> &str {
+        "FrameWatchdogPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profiling::CpuSection;
+
+    fn frame(index: u64, milliseconds: f64) -> CpuFrame {
+        CpuFrame {
+            index,
+            milliseconds,
+            sections: vec![CpuSection { name: "Update".into(), start_ms: 1.0, milliseconds: milliseconds - 2.0 }],
+        }
+    }
+
+    #[test]
+    fn test_trace_snapshot_json() {
+        let json = trace_snapshot_json(&frame(3, 80.0));
+        assert!(json.starts_with("{\"traceEvents\":[{\"name\":\"frame 3\""));
+        assert!(json.contains("\"name\":\"Update\",\"ph\":\"X\",\"ts\":1000,\"dur\":78000"));
+        assert!(json.ends_with("]}"));
+    }
+
+    #[test]
+    fn test_watchdog_counts_hitches() {
+        let mut world = World::new();
+        world.init_resource::<Events<FrameHitch>>();
+        world.insert_resource(FrameWatchdog::new(50.0));
+        world.insert_resource(CpuFrameProfile::default());
+
+        let mut system = IntoSystem::into_system(frame_watchdog_system);
+        system.initialize(&mut world);
+
+        let mut run_frame = |world: &mut World, ms: u64| {
+            let mut profile = world.resource_mut::<CpuFrameProfile>();
+            let start = anvilkit_core::time::Instant::now();
+            profile.begin_frame(start);
+            profile.begin_frame(start + std::time::Duration::from_millis(ms));
+            system.run((), world);
+            // 同一帧不重复计数
+            system.run((), world);
+        };
+        run_frame(&mut world, 16);
+        run_frame(&mut world, 120);
+
+        assert_eq!(world.resource::<FrameWatchdog>().hitch_count(), 1);
+        let events = world.resource::<Events<FrameHitch>>();
+        let hitch = events.iter_current_update_events().next().unwrap();
+        assert!((hitch.frame.milliseconds - 120.0).abs() < 1e-6);
+    }
+}
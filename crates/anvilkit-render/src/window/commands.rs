@@ -0,0 +1,128 @@
+//! # 运行时窗口控制
+//!
+//! 游戏系统通过 [`WindowCommands`] 资源排队窗口操作（标题、全屏、光标、尺寸、垂直同步），
+//! 由 [`RenderApp`](super::RenderApp) 在每帧 `app.update()` 之后于 winit 循环中统一执行。
+
+use bevy_ecs::prelude::Resource;
+
+/// 光标捕获模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorGrab {
+    /// 不捕获
+    #[default]
+    None,
+    /// 限制在窗口内
+    Confined,
+    /// 锁定在原位（适合 FPS 视角；平台不支持时回退到 `Confined`）
+    Locked,
+}
+
+/// 窗口操作
+#[derive(Debug, Clone, PartialEq)]
+pub enum WindowCommand {
+    /// 修改标题
+    SetTitle(String),
+    /// 切换无边框全屏
+    SetFullscreen(bool),
+    /// 显示 / 隐藏光标
+    SetCursorVisible(bool),
+    /// 光标捕获模式
+    SetCursorGrab(CursorGrab),
+    /// 请求新的内部尺寸（物理像素）
+    RequestInnerSize(u32, u32),
+    /// 开关垂直同步
+    SetVsync(bool),
+}
+
+/// 窗口命令队列（ECS Resource）
+///
+/// # 示例
+///
+/// ```rust
+/// use anvilkit_render::window::{CursorGrab, WindowCommands};
+///
+/// let mut commands = WindowCommands::default();
+/// commands.set_title("Paused");
+/// commands.set_cursor_visible(false);
+/// commands.set_cursor_grab(CursorGrab::Locked);
+/// assert_eq!(commands.len(), 3);
+/// ```
+#[derive(Debug, Clone, Default, Resource)]
+pub struct WindowCommands {
+    queue: Vec<WindowCommand>,
+}
+
+impl WindowCommands {
+    /// 修改窗口标题
+    pub fn set_title(&mut self, title: impl Into<String>) {
+        self.queue.push(WindowCommand::SetTitle(title.into()));
+    }
+
+    /// 切换全屏
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        self.queue.push(WindowCommand::SetFullscreen(fullscreen));
+    }
+
+    /// 显示 / 隐藏光标
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        self.queue.push(WindowCommand::SetCursorVisible(visible));
+    }
+
+    /// 设置光标捕获模式
+    pub fn set_cursor_grab(&mut self, grab: CursorGrab) {
+        self.queue.push(WindowCommand::SetCursorGrab(grab));
+    }
+
+    /// 请求新的窗口内部尺寸（物理像素，平台可能调整或忽略）
+    pub fn request_inner_size(&mut self, width: u32, height: u32) {
+        self.queue.push(WindowCommand::RequestInnerSize(width, height));
+    }
+
+    /// 开关垂直同步
+    pub fn set_vsync(&mut self, vsync: bool) {
+        self.queue.push(WindowCommand::SetVsync(vsync));
+    }
+
+    /// 追加任意命令
+    pub fn push(&mut self, command: WindowCommand) {
+        self.queue.push(command);
+    }
+
+    /// 待执行命令数
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// 队列是否为空
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// 取出全部待执行命令（按入队顺序）
+    pub fn drain(&mut self) -> Vec<WindowCommand> {
+        std::mem::take(&mut self.queue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_drain_in_order() {
+        let mut commands = WindowCommands::default();
+        commands.set_fullscreen(true);
+        commands.request_inner_size(800, 600);
+        commands.set_vsync(false);
+
+        assert_eq!(
+            commands.drain(),
+            vec![
+                WindowCommand::SetFullscreen(true),
+                WindowCommand::RequestInnerSize(800, 600),
+                WindowCommand::SetVsync(false),
+            ]
+        );
+        assert!(commands.is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+use log::{error, info, warn};
+
+use anvilkit_core::time::Instant;
+
+use super::render_app::RenderApp;
+use crate::renderer::{RenderDevice, RenderDeviceRestored};
+use crate::renderer::assets::RenderAssets;
+use crate::renderer::material::Materials;
+use crate::renderer::state::RenderState;
+
+/// 重建失败后的重试间隔（如切换显卡期间适配器暂时不可用）
+const RECOVERY_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+
+impl RenderApp {
+    /// 检测设备丢失并尝试重建（每帧在 `about_to_wait` 开始时调用）
+    pub(super) fn handle_device_lost(&mut self) {
+        if self.render_device.as_ref().is_some_and(RenderDevice::is_lost) {
+            warn!("GPU 设备丢失，释放渲染资源并重新初始化");
+            self.release_render_resources();
+            self.device_lost = true;
+            self.last_recovery_attempt = None;
+        }
+        if !self.device_lost || self.render_device.is_some() {
+            return;
+        }
+        if self.last_recovery_attempt.is_some_and(|t| t.elapsed() < RECOVERY_RETRY_INTERVAL) {
+            return;
+        }
+        self.last_recovery_attempt = Some(Instant::now());
+
+        #[cfg(target_arch = "wasm32")]
+        if let Err(e) = self.spawn_init_render() {
+            error!("重新初始化渲染失败: {}", e);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        match pollster::block_on(self.init_render()) {
+            Ok(()) => self.finish_device_recovery(),
+            Err(e) => error!("重新初始化渲染失败，稍后重试: {}", e),
+        }
+    }
+
+    /// 释放旧设备上的表面、渲染状态与延迟创建的渲染器
+    fn release_render_resources(&mut self) {
+        self.render_surface = None;
+        self.render_device = None;
+        self.debug_renderer = None;
+        self.gpu_profiler = None;
+        #[cfg(feature = "capture")]
+        {
+            self.capture_resources = None;
+        }
+        self.gpu_initialized = false;
+
+        if let Some(app) = &mut self.app {
+            app.world_mut().remove_resource::<RenderState>();
+        }
+    }
+
+    /// 新设备就绪后重建已注册管线、引擎资源与材质，并通知游戏代码
+    pub(super) fn finish_device_recovery(&mut self) {
+        let (Some(device), Some(app)) = (&self.render_device, &mut self.app) else { return };
+
+        let pipelines_rebuilt = app.world_mut()
+            .get_resource_mut::<RenderAssets>()
+            .map(|mut assets| assets.rebuild_for_device(device))
+            .unwrap_or(0);
+        let adapter_name = device.adapter().get_info().name;
+
+        self.inject_render_state_to_ecs();
+
+        let Some(app) = &mut self.app else { return };
+        if let Some(mut materials) = app.world_mut().get_resource_mut::<Materials>() {
+            materials.invalidate_gpu();
+        }
+        if let Some(mut events) = app.world_mut().get_resource_mut::<bevy_ecs::event::Events<RenderDeviceRestored>>() {
+            events.send(RenderDeviceRestored { adapter_name: adapter_name.clone(), pipelines_rebuilt });
+        }
+
+        self.device_lost = false;
+        info!("GPU 设备已重建: {} (重建管线 {} 条)", adapter_name, pipelines_rebuilt);
+
+        if let Some(window) = &self.window {
+            window.request_redraw();
+        }
+    }
+}
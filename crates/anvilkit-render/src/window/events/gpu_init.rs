@@ -8,15 +8,16 @@ use crate::renderer::state::{RenderState, PbrSceneUniform, CSM_CASCADE_COUNT};
 use crate::renderer::buffer::{
     create_depth_texture_msaa,
     create_hdr_render_target, create_hdr_msaa_texture,
-    create_sampler, create_texture, create_texture_linear, create_shadow_sampler,
+    create_sampler, create_texture_linear, create_shadow_sampler,
     create_csm_shadow_map,
-    Vertex, PbrVertex, SHADOW_MAP_SIZE, HDR_FORMAT, MSAA_SAMPLE_COUNT,
+    Vertex, PbrVertex, SHADOW_MAP_SIZE,
 };
-use crate::renderer::ibl::get_or_generate_brdf_lut;
+use crate::renderer::ibl::{create_solid_environment_cube, get_or_generate_brdf_lut};
+#[cfg(feature = "render-3d")]
 use crate::renderer::bloom::{BloomResources, BloomSettings};
+use crate::renderer::render_scale::{effective_render_scale, scaled_size, DynamicResolution, TonemapParams};
 
 /// Shadow pass shader (depth-only, reads model + view_proj from scene uniform)
-const PBR_SHADER: &str = include_str!("../../shaders/pbr.wgsl");
 const SHADOW_SHADER: &str = include_str!("../../shaders/shadow.wgsl");
 
 /// ACES Filmic tone mapping post-process shader (fullscreen triangle)
@@ -36,8 +37,8 @@ impl RenderApp {
         let format = surface.format();
         let (w, h) = self.window_state.size();
 
-        // 创建动态 Uniform 缓冲区 — 容量 1024 draws × 1024 bytes/draw = 1 MB
-        // PbrSceneUniform 为 992 字节，对齐到 256 边界 → 每个 draw 占 1024 字节
+        // 创建动态 Uniform 缓冲区 — 容量 1024 draws × 1280 bytes/draw = 1.25 MB
+        // PbrSceneUniform 为 1056 字节，向上对齐到 256 边界 → 每个 draw 占 1280 字节
         const UNIFORM_ALIGNMENT: u64 = 256;
         let uniform_stride = {
             let raw = std::mem::size_of::<PbrSceneUniform>() as u64;
@@ -83,18 +84,32 @@ impl RenderApp {
             }],
         });
 
-        let (_, depth_texture_view) = create_depth_texture_msaa(device, w, h, "ECS Depth MSAA");
+        // 3D 目标以内部渲染分辨率创建，tonemap pass 放大到表面
+        let render_scale = effective_render_scale(app.world());
+        let (rw, rh) = scaled_size((w, h), render_scale);
+
+        let (_, depth_texture_view) = create_depth_texture_msaa(device, rw, rh, "ECS Depth MSAA");
 
         // HDR render target (resolve target, sample_count=1) + MSAA color attachment
-        let (hdr_texture, hdr_texture_view) = create_hdr_render_target(device, w, h, "ECS HDR RT");
-        let (_, hdr_msaa_texture_view) = create_hdr_msaa_texture(device, w, h, "ECS HDR MSAA");
+        let (hdr_texture, hdr_texture_view) = create_hdr_render_target(device, rw, rh, "ECS HDR RT");
+        let (_, hdr_msaa_texture_view) = create_hdr_msaa_texture(device, rw, rh, "ECS HDR MSAA");
         let sampler = create_sampler(device, "ECS Tonemap Sampler");
 
         // --- Bloom resources ---
-        let bloom_settings = BloomSettings::default();
-        let bloom = BloomResources::new(device, w, h, bloom_settings.mip_count);
+        // 设备重建时沿用游戏已修改的设置
+        #[cfg(feature = "render-3d")]
+        let bloom_settings = app.world().get_resource::<BloomSettings>().cloned().unwrap_or_default();
+        #[cfg(feature = "render-3d")]
+        let bloom = BloomResources::new(device, rw, rh, bloom_settings.mip_count);
+
+        let tonemap_params_buffer = device.device().create_buffer(&wgpu::BufferDescriptor {
+            label: Some("ECS Tonemap Params"),
+            size: std::mem::size_of::<TonemapParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
 
-        // Tonemap bind group layout + bind group (3 entries: HDR + sampler + bloom)
+        // Tonemap bind group layout + bind group (4 entries: HDR + sampler + bloom + params)
         let tonemap_bgl_entries = [
             wgpu::BindGroupLayoutEntry {
                 binding: 0, visibility: wgpu::ShaderStages::FRAGMENT,
@@ -117,6 +132,14 @@ impl RenderApp {
                     multisampled: false,
                 }, count: None,
             },
+            wgpu::BindGroupLayoutEntry {
+                binding: 3, visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                }, count: None,
+            },
         ];
 
         let tonemap_bind_group_layout = device.device().create_bind_group_layout(
@@ -126,11 +149,13 @@ impl RenderApp {
             },
         );
 
-        let bloom_view_for_tonemap = if bloom.mip_views.is_empty() {
-            &hdr_texture_view // fallback — shouldn't happen
-        } else {
-            &bloom.mip_views[0]
-        };
+        #[cfg(feature = "render-3d")]
+        let bloom_view_for_tonemap = bloom.mip_views.first().unwrap_or(&hdr_texture_view);
+        // 未编译 Bloom 时绑定黑色纹理，tonemap 的 bloom 叠加项为 0
+        #[cfg(not(feature = "render-3d"))]
+        let (_, bloom_fallback_view) = create_texture_linear(device, 1, 1, &[0, 0, 0, 255], "ECS Bloom Fallback");
+        #[cfg(not(feature = "render-3d"))]
+        let bloom_view_for_tonemap = &bloom_fallback_view;
         let tonemap_bind_group = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("ECS Tonemap BG"),
             layout: &tonemap_bind_group_layout,
@@ -138,6 +163,7 @@ impl RenderApp {
                 wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&hdr_texture_view) },
                 wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
                 wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(bloom_view_for_tonemap) },
+                wgpu::BindGroupEntry { binding: 3, resource: tonemap_params_buffer.as_entire_binding() },
             ],
         });
 
@@ -160,12 +186,16 @@ impl RenderApp {
             .expect("创建 Tonemap 管线失败")
             .into_pipeline();
 
-        // IBL + Shadow: bind group 2 (BRDF LUT + CSM shadow map array)
+        // IBL + Shadow: bind group 2 (BRDF LUT + CSM shadow map array + environment cube)
         let brdf_lut_data = get_or_generate_brdf_lut(".cache/brdf_lut_256.bin", 256);
         let (_, brdf_lut_view) = create_texture_linear(device, 256, 256, &brdf_lut_data, "ECS BRDF LUT");
+        let shadow_map_size = app.world().get_resource::<crate::quality::QualityScale>()
+            .map_or(SHADOW_MAP_SIZE, |q| q.shadow_map_size);
         let (_shadow_tex, shadow_map_view, shadow_cascade_views) =
-            create_csm_shadow_map(device, SHADOW_MAP_SIZE, CSM_CASCADE_COUNT as u32, "ECS CSM Shadow Map");
+            create_csm_shadow_map(device, shadow_map_size, CSM_CASCADE_COUNT as u32, "ECS CSM Shadow Map");
         let shadow_sampler = create_shadow_sampler(device, "ECS Shadow Sampler");
+        let (_, default_environment_view) =
+            create_solid_environment_cube(device, [0, 0, 0, 255], "ECS Default Environment");
 
         let ibl_shadow_bind_group_layout = device.device().create_bind_group_layout(
             &wgpu::BindGroupLayoutDescriptor {
@@ -197,6 +227,19 @@ impl RenderApp {
                         ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Comparison),
                         count: None,
                     },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4, visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::Cube,
+                            multisampled: false,
+                        }, count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5, visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
                 ],
             },
         );
@@ -209,6 +252,8 @@ impl RenderApp {
                 wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(&sampler) },
                 wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(&shadow_map_view) },
                 wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::Sampler(&shadow_sampler) },
+                wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::TextureView(&default_environment_view) },
+                wgpu::BindGroupEntry { binding: 5, resource: wgpu::BindingResource::Sampler(&sampler) },
             ],
         });
 
@@ -243,6 +288,8 @@ impl RenderApp {
         app.insert_resource(RenderState {
             surface_format: format,
             surface_size: (w, h),
+            render_size: (rw, rh),
+            render_scale,
             scene_uniform_buffer,
             scene_bind_group,
             scene_bind_group_layout,
@@ -252,145 +299,44 @@ impl RenderApp {
             tonemap_pipeline,
             tonemap_bind_group,
             tonemap_bind_group_layout,
+            tonemap_params_buffer,
             ibl_shadow_bind_group,
             ibl_shadow_bind_group_layout,
             shadow_pipeline,
             shadow_map_view,
             shadow_cascade_views,
+            shadow_map_size,
+            brdf_lut_view,
+            default_environment_view,
+            #[cfg(feature = "render-3d")]
+            skybox: None,
             hdr_msaa_texture_view,
+            #[cfg(feature = "render-3d")]
             bloom: Some(bloom),
+            #[cfg(not(feature = "render-3d"))]
+            bloom_fallback_view,
             post_process: crate::renderer::post_process::PostProcessResources::new(),
         });
+        #[cfg(feature = "render-3d")]
         app.insert_resource(bloom_settings);
-        app.insert_resource(crate::renderer::post_process::PostProcessSettings::default());
+        app.insert_resource(device.diagnostic_report(Some(surface.surface())));
+        app.init_resource::<crate::renderer::post_process::PostProcessSettings>();
+        app.init_resource::<DynamicResolution>();
 
-        // --- 创建默认 PBR 管线 + 默认材质（StandardMaterial 使用） ---
+        // --- 创建材质管线 + 默认材质（StandardMaterial 使用） ---
         {
+            use crate::renderer::material::{Material, MaterialPipelines};
             use crate::renderer::standard_material::DefaultMaterialHandle;
 
-            // 创建 1x1 fallback 纹理
-            let white_pixel = [255u8, 255, 255, 255];
-            let normal_pixel = [128u8, 128, 255, 255]; // 默认法线 (0,0,1) in tangent space
-            let _black_pixel = [0u8, 0, 0, 255];
-
-            let (_, default_base_view) = create_texture(device, 1, 1, &white_pixel, "Default Base Color");
-            let (_, default_normal_view) = create_texture_linear(device, 1, 1, &normal_pixel, "Default Normal Map");
-            let (_, default_mr_view) = create_texture_linear(device, 1, 1, &white_pixel, "Default MR");
-            let (_, default_ao_view) = create_texture_linear(device, 1, 1, &white_pixel, "Default AO");
-            let (_, default_emissive_view) = create_texture(device, 1, 1, &white_pixel, "Default Emissive");
-            let default_sampler = create_sampler(device, "Default Material Sampler");
-
-            // Material BGL: 5 textures + 1 sampler
-            let tex_layout_entry = |binding: u32| -> wgpu::BindGroupLayoutEntry {
-                wgpu::BindGroupLayoutEntry {
-                    binding, visibility: wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Texture {
-                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
-                        view_dimension: wgpu::TextureViewDimension::D2,
-                        multisampled: false,
-                    }, count: None,
-                }
+            let mut pipelines = {
+                let rs = app.world().resource::<RenderState>();
+                MaterialPipelines::new(device, &rs.scene_bind_group_layout, &rs.ibl_shadow_bind_group_layout)
             };
-
-            let mat_bgl = device.device().create_bind_group_layout(
-                &wgpu::BindGroupLayoutDescriptor {
-                    label: Some("Default Material BGL"),
-                    entries: &[
-                        tex_layout_entry(0), // base_color
-                        tex_layout_entry(1), // normal_map
-                        tex_layout_entry(2), // metallic_roughness
-                        tex_layout_entry(3), // ao
-                        tex_layout_entry(4), // emissive
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 5, visibility: wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
-                            count: None,
-                        },
-                    ],
-                },
-            );
-
-            let default_mat_bg = device.device().create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("Default Material BG"),
-                layout: &mat_bgl,
-                entries: &[
-                    wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&default_base_view) },
-                    wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::TextureView(&default_normal_view) },
-                    wgpu::BindGroupEntry { binding: 2, resource: wgpu::BindingResource::TextureView(&default_mr_view) },
-                    wgpu::BindGroupEntry { binding: 3, resource: wgpu::BindingResource::TextureView(&default_ao_view) },
-                    wgpu::BindGroupEntry { binding: 4, resource: wgpu::BindingResource::TextureView(&default_emissive_view) },
-                    wgpu::BindGroupEntry { binding: 5, resource: wgpu::BindingResource::Sampler(&default_sampler) },
-                ],
-            });
-
-            // Scene BGL 和 IBL+Shadow BGL 用于 PBR 管线需要重新创建（builder 取走所有权）
-            let pbr_scene_bgl = device.device().create_bind_group_layout(
-                &wgpu::BindGroupLayoutDescriptor {
-                    label: Some("PBR Scene BGL"),
-                    entries: &[wgpu::BindGroupLayoutEntry {
-                        binding: 0,
-                        visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                        ty: wgpu::BindingType::Buffer {
-                            ty: wgpu::BufferBindingType::Uniform,
-                            has_dynamic_offset: true,
-                            min_binding_size: uniform_binding_size,
-                        },
-                        count: None,
-                    }],
-                },
-            );
-            let pbr_ibl_bgl = device.device().create_bind_group_layout(
-                &wgpu::BindGroupLayoutDescriptor {
-                    label: Some("PBR IBL+Shadow BGL"),
-                    entries: &[
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 0, visibility: wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Texture {
-                                sample_type: wgpu::TextureSampleType::Float { filterable: true },
-                                view_dimension: wgpu::TextureViewDimension::D2,
-                                multisampled: false,
-                            }, count: None,
-                        },
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 1, visibility: wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
-                            count: None,
-                        },
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 2, visibility: wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Texture {
-                                sample_type: wgpu::TextureSampleType::Depth,
-                                view_dimension: wgpu::TextureViewDimension::D2Array,
-                                multisampled: false,
-                            }, count: None,
-                        },
-                        wgpu::BindGroupLayoutEntry {
-                            binding: 3, visibility: wgpu::ShaderStages::FRAGMENT,
-                            ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Comparison),
-                            count: None,
-                        },
-                    ],
-                },
-            );
-
-            let pbr_pipeline = RenderPipelineBuilder::new()
-                .with_vertex_shader(PBR_SHADER)
-                .with_fragment_shader(PBR_SHADER)
-                .with_format(HDR_FORMAT)
-                .with_vertex_layouts(vec![PbrVertex::layout()])
-                .with_depth_format(DEPTH_FORMAT)
-                .with_bind_group_layouts(vec![pbr_scene_bgl, mat_bgl, pbr_ibl_bgl])
-                .with_label("Default PBR Pipeline")
-                .with_multisample_count(MSAA_SAMPLE_COUNT)
-                .build(device)
-                .expect("创建默认 PBR 管线失败")
-                .into_pipeline();
-
-            // 注册到 RenderAssets
             let mat_handle = {
                 let mut assets = app.world_mut().get_resource_mut::<RenderAssets>().expect("RenderAssets 必须已注册");
-                assets.create_material(pbr_pipeline, default_mat_bg)
+                pipelines.create_material(device, &mut assets, &Material::default())
             };
+            app.world_mut().insert_resource(pipelines);
             app.world_mut().insert_resource(DefaultMaterialHandle(mat_handle));
             info!("默认 PBR 材质已创建: {:?}", mat_handle);
         }
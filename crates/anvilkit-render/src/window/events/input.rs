@@ -1,4 +1,4 @@
-use std::time::Instant;
+use anvilkit_core::time::Instant;
 use winit::{
     application::ApplicationHandler,
     event::{WindowEvent, DeviceEvent, DeviceId},
@@ -9,7 +9,7 @@ use log::{info, error, debug};
 
 use bevy_app::App;
 use anvilkit_core::time::DeltaTime;
-use anvilkit_input::prelude::{InputState, KeyCode, MouseButton};
+use anvilkit_input::prelude::{InputState, KeyCode, KeyboardLayout, LogicalKey, MouseButton};
 
 use super::render_app::RenderApp;
 
@@ -23,14 +23,30 @@ impl RenderApp {
     pub fn forward_input(app: &mut App, event: &WindowEvent) {
         match event {
             WindowEvent::KeyboardInput { event, .. } => {
-                if let winit::keyboard::PhysicalKey::Code(code) = event.physical_key {
-                    if let Some(mut input) = app.world_mut().get_resource_mut::<InputState>() {
-                        if let Some(key) = KeyCode::from_winit(code) {
-                            if event.state.is_pressed() {
-                                input.press_key(key);
-                            } else {
-                                input.release_key(key);
-                            }
+                // 物理键码（按位置）与逻辑键（按当前布局的字符）同时记录
+                let key = match event.physical_key {
+                    winit::keyboard::PhysicalKey::Code(code) => KeyCode::from_winit(code),
+                    _ => None,
+                };
+                let logical = LogicalKey::from_winit(&event.logical_key);
+                let pressed = event.state.is_pressed();
+                if let (true, Some(key), Some(logical)) = (pressed, key, logical) {
+                    if let Some(mut layout) = app.world_mut().get_resource_mut::<KeyboardLayout>() {
+                        layout.observe(key, logical);
+                    }
+                }
+                if let Some(mut input) = app.world_mut().get_resource_mut::<InputState>() {
+                    match (key, logical) {
+                        (Some(key), Some(logical)) if pressed => input.press_key_as(key, logical),
+                        (Some(key), _) if pressed => input.press_key(key),
+                        (Some(key), _) => input.release_key(key),
+                        (None, Some(logical)) if pressed => input.press_logical(logical),
+                        (None, Some(logical)) => input.release_logical(logical),
+                        (None, None) => {}
+                    }
+                    if pressed {
+                        if let Some(text) = &event.text {
+                            input.push_text(text);
                         }
                     }
                 }
@@ -76,7 +92,7 @@ impl RenderApp {
         }
     }
 
-    /// Run a single frame tick: update DeltaTime, run `app.update()`, clear input state.
+    /// Run a single frame tick: update DeltaTime, prepare material GPU resources, run `app.update()`, clear input state.
     ///
     /// Call this from your own [`ApplicationHandler::about_to_wait`] implementation.
     /// Handles the standard per-frame lifecycle so your game only needs to add
@@ -88,6 +104,12 @@ impl RenderApp {
         let dt = raw_dt.clamp(0.001, 0.1);
         app.world_mut().insert_resource(DeltaTime(dt));
 
+        if let Some(device) = &self.render_device {
+            crate::renderer::material::prepare_materials(device, app.world_mut());
+            #[cfg(feature = "render-3d")]
+            crate::stress_test::prepare_stress_meshes(device, app.world_mut());
+        }
+
         app.update();
 
         if let Some(mut input) = app.world_mut().get_resource_mut::<InputState>() {
@@ -111,6 +133,17 @@ impl ApplicationHandler for RenderApp {
             return;
         }
 
+        // 浏览器中无法阻塞等待适配器：异步初始化，完成后在 about_to_wait 中接管
+        #[cfg(target_arch = "wasm32")]
+        {
+            if let Err(e) = self.spawn_init_render() {
+                error!("初始化渲染失败: {}", e);
+                event_loop.exit();
+            }
+            return;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
         if let Err(e) = pollster::block_on(self.init_render()) {
             error!("初始化渲染失败: {}", e);
             event_loop.exit();
@@ -132,6 +165,10 @@ impl ApplicationHandler for RenderApp {
         _window_id: WindowId,
         event: WindowEvent,
     ) {
+        if let Some(app) = &mut self.app {
+            Self::forward_window_event(app, &event);
+        }
+
         match event {
             WindowEvent::CloseRequested => {
                 info!("收到窗口关闭请求");
@@ -187,8 +224,27 @@ impl ApplicationHandler for RenderApp {
     }
 
     /// 即将等待事件
-    #[allow(unused_variables)]
     fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        #[cfg(target_arch = "wasm32")]
+        match self.poll_pending_render() {
+            Some(Ok(())) if self.device_lost => self.finish_device_recovery(),
+            Some(Ok(())) => {
+                self.inject_render_state_to_ecs();
+                if let Some(window) = &self.window {
+                    window.request_redraw();
+                }
+            }
+            Some(Err(e)) if self.device_lost => error!("重新初始化渲染失败，稍后重试: {}", e),
+            Some(Err(e)) => {
+                error!("初始化渲染失败: {}", e);
+                event_loop.exit();
+                return;
+            }
+            None => {}
+        }
+
+        self.handle_device_lost();
+
         // 使用 tick() 统一处理：DeltaTime → app.update() → end_frame → request_redraw
         // 注意：需要临时取出 app 以满足借用检查（tick 需要 &mut self 和 &mut App）
         if let Some(mut app) = self.app.take() {
@@ -205,8 +261,23 @@ impl ApplicationHandler for RenderApp {
                 }
             }
 
+            // 系统发送了 AppExit
+            if app.should_exit().is_some() {
+                info!("收到 AppExit，退出事件循环");
+                event_loop.exit();
+            }
+
             self.app = Some(app);
         }
+
+        // 执行游戏系统排队的窗口操作（app 已放回，resize 可同步更新 RenderState）
+        let commands = self.app.as_mut()
+            .and_then(|app| app.world_mut().get_resource_mut::<crate::window::WindowCommands>())
+            .map(|mut c| c.drain())
+            .unwrap_or_default();
+        if !commands.is_empty() {
+            self.execute_window_commands(commands);
+        }
     }
 }
 
@@ -60,6 +60,22 @@ pub fn compute_cascade_matrices(
     aspect: f32,
     near: f32,
     far: f32,
+) -> ([glam::Mat4; 3], [f32; 3]) {
+    compute_cascade_matrices_with_splits(light_direction, view, fov, aspect, near, far, &CSM_SPLIT_RATIOS)
+}
+
+/// 按自定义分割比例计算 CSM 各级 cascade 的光空间矩阵
+///
+/// `split_ratios[i]` 为第 i 级远端在 `near..far` 中的比例（见
+/// [`ShadowSettings::split_ratios`](crate::renderer::draw::ShadowSettings::split_ratios)）。
+pub fn compute_cascade_matrices_with_splits(
+    light_direction: &glam::Vec3,
+    view: &glam::Mat4,
+    fov: f32,
+    aspect: f32,
+    near: f32,
+    far: f32,
+    split_ratios: &[f32; 3],
 ) -> ([glam::Mat4; 3], [f32; 3]) {
     let light_dir = light_direction.normalize();
     let _inv_view = view.inverse();
@@ -68,8 +84,9 @@ pub fn compute_cascade_matrices(
     let mut splits = [0.0f32; 3];
     let mut prev_split = near;
 
-    for (i, &ratio) in CSM_SPLIT_RATIOS.iter().enumerate() {
-        let split_far = near + (far - near) * ratio;
+    for (i, &ratio) in split_ratios.iter().enumerate() {
+        // 相同比例的相邻级联退化为极薄切片，避免近远平面重合
+        let split_far = (near + (far - near) * ratio).max(prev_split + 1e-3);
         splits[i] = split_far;
 
         // Compute frustum corners for this cascade slice
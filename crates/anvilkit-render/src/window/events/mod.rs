@@ -5,8 +5,9 @@
 mod lighting;
 mod render_app;
 mod gpu_init;
+mod device_lost;
 mod render_loop;
 mod input;
 
 pub use render_app::RenderApp;
-pub use lighting::{pack_lights, compute_cascade_matrices, compute_light_space_matrix};
+pub use lighting::{pack_lights, compute_cascade_matrices, compute_cascade_matrices_with_splits, compute_light_space_matrix};
@@ -1,12 +1,12 @@
 use std::sync::Arc;
-use std::time::Instant;
+use anvilkit_core::time::Instant;
 use winit::event_loop::ActiveEventLoop;
 use winit::window::Window;
 use log::info;
 
 use bevy_app::App;
 use crate::window::{WindowConfig, WindowState};
-use crate::renderer::{RenderDevice, RenderSurface};
+use crate::renderer::{RenderDevice, RenderSettings, RenderSurface};
 use anvilkit_core::error::{AnvilKitError, Result};
 
 /// 渲染应用
@@ -52,15 +52,33 @@ pub struct RenderApp {
     pub(super) app: Option<App>,
     /// GPU 是否已初始化并注入到 ECS World
     pub(super) gpu_initialized: bool,
+    /// 设备已丢失、等待重建
+    pub(super) device_lost: bool,
+    /// 上次尝试重建设备的时间
+    pub(super) last_recovery_attempt: Option<Instant>,
 
     /// 上一帧时间戳，用于计算真实帧时间
     pub(super) last_frame_time: Instant,
 
+    /// 调试线渲染器（首次出现 DebugDraw 命令时延迟创建）
+    pub(super) debug_renderer: Option<crate::renderer::debug::DebugRenderer>,
+
+    /// GPU 计时器（存在 GpuTimingSettings 且启用时延迟创建）
+    pub(super) gpu_profiler: Option<crate::renderer::gpu_profiler::GpuProfiler>,
+
     /// 帧捕获资源（capture feature 启用时）
     #[cfg(feature = "capture")]
     pub(super) capture_resources: Option<crate::renderer::capture::CaptureResources>,
+
+    /// Web 平台上异步初始化的渲染设备与表面（浏览器中无法阻塞等待适配器）
+    #[cfg(target_arch = "wasm32")]
+    pub(super) pending_render: PendingRender,
 }
 
+/// 异步初始化结果的共享槽位
+#[cfg(target_arch = "wasm32")]
+pub(super) type PendingRender = std::rc::Rc<std::cell::RefCell<Option<Result<(RenderDevice, RenderSurface)>>>>;
+
 impl RenderApp {
     /// 创建新的渲染应用
     ///
@@ -88,9 +106,15 @@ impl RenderApp {
             exit_requested: false,
             app: None,
             gpu_initialized: false,
+            device_lost: false,
+            last_recovery_attempt: None,
             last_frame_time: Instant::now(),
+            debug_renderer: None,
+            gpu_profiler: None,
             #[cfg(feature = "capture")]
             capture_resources: None,
+            #[cfg(target_arch = "wasm32")]
+            pending_render: Default::default(),
         }
     }
 
@@ -102,7 +126,13 @@ impl RenderApp {
     /// # 参数
     ///
     /// - `app`: 已配置好 RenderPlugin 和系统的 ECS App
-    pub fn run(app: App) {
+    ///
+    /// 窗口关闭或系统发送 `AppExit` 后返回退出码。
+    ///
+    /// Web 平台（wasm32）上事件循环交给浏览器驱动（`requestAnimationFrame`），
+    /// 本函数立即返回 `AppExit::Success`。
+    pub fn run(mut app: App) -> bevy_app::AppExit {
+        Self::finish_plugins(&mut app);
         let event_loop = winit::event_loop::EventLoop::new().unwrap();
 
         // 从 App 中读取 RenderConfig 获取 WindowConfig
@@ -113,7 +143,31 @@ impl RenderApp {
         let mut render_app = Self::new(window_config);
         render_app.app = Some(app);
 
+        #[cfg(target_arch = "wasm32")]
+        {
+            use winit::platform::web::EventLoopExtWebSys;
+            event_loop.spawn_app(render_app);
+            return bevy_app::AppExit::Success;
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
         event_loop.run_app(&mut render_app).unwrap();
+
+        render_app.app.as_mut()
+            .and_then(|app| app.should_exit())
+            .unwrap_or(bevy_app::AppExit::Success)
+    }
+
+    /// 完成插件的 `finish` / `cleanup` 阶段
+    ///
+    /// 与 bevy 自带的 runner 一致，在事件循环开始前调用；依赖其他插件资源的
+    /// 插件（如在 `finish` 中接管成就后端的 `SteamPlugin`）因此与添加顺序无关。
+    /// 自定义 runner 也应在进入主循环前调用。
+    pub fn finish_plugins(app: &mut App) {
+        if app.plugins_state() == bevy_app::PluginsState::Ready {
+            app.finish();
+            app.cleanup();
+        }
     }
 
     /// 获取窗口配置
@@ -153,8 +207,82 @@ impl RenderApp {
     }
 
     /// 获取当前帧的 SurfaceTexture（用于外部渲染）
-    pub fn get_current_frame(&self) -> Option<wgpu::SurfaceTexture> {
-        self.render_surface.as_ref().and_then(|s| s.get_current_frame().ok())
+    ///
+    /// 窗口尺寸变化时先同步表面与渲染目标；超时、窗口最小化或表面无法恢复时返回 `None`，跳过本帧即可。
+    pub fn get_current_frame(&mut self) -> Option<wgpu::SurfaceTexture> {
+        if !self.sync_surface_size() {
+            return None;
+        }
+        let (device, surface) = (self.render_device.as_ref()?, self.render_surface.as_ref()?);
+        surface.acquire_frame(device).unwrap_or_else(|e| {
+            log::error!("获取当前帧失败: {}", e);
+            None
+        })
+    }
+
+    /// 运行时开关垂直同步，返回实际生效的呈现模式（表面未初始化时返回 `None`）
+    pub fn set_vsync(&mut self, vsync: bool) -> Option<wgpu::PresentMode> {
+        self.config.vsync = vsync;
+        let (Some(device), Some(surface)) = (&self.render_device, &mut self.render_surface) else {
+            return None;
+        };
+        Some(surface.set_vsync(device, vsync))
+    }
+
+    /// 执行 ECS 中 [`WindowCommands`](crate::window::WindowCommands) 排队的窗口操作
+    ///
+    /// `RenderApp::run()` 启动时每帧自动调用；自定义 `ApplicationHandler` 时在 `tick()` 之后调用。
+    pub fn apply_window_commands(&mut self, app: &mut App) {
+        let Some(commands) = app.world_mut().get_resource_mut::<crate::window::WindowCommands>()
+            .map(|mut c| c.drain()) else { return };
+        self.execute_window_commands(commands);
+    }
+
+    pub(super) fn execute_window_commands(&mut self, commands: Vec<crate::window::WindowCommand>) {
+        use crate::window::{CursorGrab, WindowCommand};
+        use winit::window::{CursorGrabMode, Fullscreen};
+
+        for command in commands {
+            match command {
+                WindowCommand::SetVsync(vsync) => {
+                    self.set_vsync(vsync);
+                    continue;
+                }
+                WindowCommand::SetTitle(ref title) => self.config.title = title.clone(),
+                WindowCommand::SetFullscreen(fullscreen) => {
+                    self.config.fullscreen = fullscreen;
+                    self.window_state.set_fullscreen(fullscreen);
+                }
+                _ => {}
+            }
+
+            let Some(window) = &self.window else { continue };
+            match command {
+                WindowCommand::SetTitle(title) => window.set_title(&title),
+                WindowCommand::SetFullscreen(fullscreen) => {
+                    window.set_fullscreen(fullscreen.then_some(Fullscreen::Borderless(None)));
+                }
+                WindowCommand::SetCursorVisible(visible) => window.set_cursor_visible(visible),
+                WindowCommand::SetCursorGrab(grab) => {
+                    let result = match grab {
+                        CursorGrab::None => window.set_cursor_grab(CursorGrabMode::None),
+                        CursorGrab::Confined => window.set_cursor_grab(CursorGrabMode::Confined),
+                        CursorGrab::Locked => window.set_cursor_grab(CursorGrabMode::Locked)
+                            .or_else(|_| window.set_cursor_grab(CursorGrabMode::Confined)),
+                    };
+                    if let Err(e) = result {
+                        log::warn!("设置光标捕获模式失败: {}", e);
+                    }
+                }
+                WindowCommand::RequestInnerSize(width, height) => {
+                    // 若平台立即应用，返回新尺寸；否则稍后通过 Resized 事件通知
+                    if let Some(size) = window.request_inner_size(winit::dpi::PhysicalSize::new(width, height)) {
+                        self.handle_resize(size);
+                    }
+                }
+                WindowCommand::SetVsync(_) => {}
+            }
+        }
     }
 
     // --- Internal methods ---
@@ -176,6 +304,14 @@ impl RenderApp {
         self.window_state.set_size(size.width, size.height);
         self.window_state.set_scale_factor(window.scale_factor());
 
+        // HiDPI 显示器上 UI 视口在首个缩放事件之前就需要正确的缩放因子
+        #[cfg(feature = "render-2d")]
+        if let Some(mut viewport) = self.app.as_mut()
+            .and_then(|app| app.world_mut().get_resource_mut::<crate::renderer::ui_anchor::UiViewport>())
+        {
+            viewport.scale_factor = window.scale_factor() as f32;
+        }
+
         self.window = Some(Arc::new(window));
 
         info!("窗口创建成功");
@@ -188,18 +324,64 @@ impl RenderApp {
             return Ok(());
         }
 
-        let window = self.window.as_ref()
+        let window = self.window.clone()
             .ok_or_else(|| AnvilKitError::render("窗口未创建".to_string()))?;
 
-        info!("初始化渲染设备和表面");
-
-        let device = RenderDevice::new(window).await?;
-        let surface = RenderSurface::new_with_vsync(&device, window, self.config.vsync)?;
-
+        let settings = self.render_settings();
+        let (device, surface) = Self::create_render_context(window, self.config.vsync, settings).await?;
         self.render_device = Some(device);
         self.render_surface = Some(surface);
+        Ok(())
+    }
+
+    /// ECS World 中的 [`RenderSettings`]，未配置时使用默认值
+    fn render_settings(&self) -> RenderSettings {
+        self.app.as_ref()
+            .and_then(|app| app.world().get_resource::<RenderSettings>().cloned())
+            .unwrap_or_default()
+    }
+
+    /// 为窗口创建渲染设备与表面（不借用 self，可交给浏览器的异步执行器）
+    pub(super) async fn create_render_context(
+        window: Arc<Window>,
+        vsync: bool,
+        settings: RenderSettings,
+    ) -> Result<(RenderDevice, RenderSurface)> {
+        info!("初始化渲染设备和表面");
+
+        let device = RenderDevice::new_with_settings(&window, &settings).await?;
+        let surface = RenderSurface::new_with_vsync(&device, &window, vsync)?;
 
         info!("渲染设备和表面初始化成功");
+        Ok((device, surface))
+    }
+
+    /// Web 平台：发起异步渲染初始化，结果写入 `pending_render`
+    #[cfg(target_arch = "wasm32")]
+    pub(super) fn spawn_init_render(&mut self) -> Result<()> {
+        if self.render_device.is_some() {
+            return Ok(());
+        }
+        let window = self.window.clone()
+            .ok_or_else(|| AnvilKitError::render("窗口未创建".to_string()))?;
+
+        let slot = self.pending_render.clone();
+        let vsync = self.config.vsync;
+        let settings = self.render_settings();
+        wasm_bindgen_futures::spawn_local(async move {
+            let result = Self::create_render_context(window, vsync, settings).await;
+            *slot.borrow_mut() = Some(result);
+        });
         Ok(())
     }
+
+    /// Web 平台：异步初始化完成后接管设备与表面；尚未完成时返回 `None`
+    #[cfg(target_arch = "wasm32")]
+    pub(super) fn poll_pending_render(&mut self) -> Option<Result<()>> {
+        let result = self.pending_render.borrow_mut().take()?;
+        Some(result.map(|(device, surface)| {
+            self.render_device = Some(device);
+            self.render_surface = Some(surface);
+        }))
+    }
 }
@@ -2,12 +2,24 @@ use winit::dpi::PhysicalSize;
 use log::{error, debug};
 
 use super::render_app::RenderApp;
-use super::lighting::{pack_lights, compute_cascade_matrices};
+use super::lighting::{pack_lights, compute_cascade_matrices_with_splits};
 use crate::renderer::draw::{ActiveCamera, DrawCommandList, SceneLights, UniformBatchBuffer};
 use crate::renderer::assets::RenderAssets;
-use crate::renderer::state::{RenderState, PbrSceneUniform, CSM_CASCADE_COUNT};
-use crate::renderer::buffer::SHADOW_MAP_SIZE;
+use crate::renderer::state::{RenderState, PbrSceneUniform};
+use crate::renderer::buffer::MSAA_SAMPLE_COUNT;
+#[cfg(feature = "render-3d")]
 use crate::renderer::bloom::BloomSettings;
+use crate::renderer::buffer::HDR_FORMAT;
+use crate::renderer::phase::{PhaseRenderContext, PhaseSlot, RenderPhases};
+use crate::renderer::debug::RenderStats;
+use crate::renderer::gpu_profiler::{record_gpu_timings, GpuProfiler, GpuTimingSettings};
+use crate::profiling::CpuFrameProfile;
+use crate::renderer::render_scale::{effective_render_scale, DynamicResolution, TonemapParams};
+#[cfg(feature = "render-3d")]
+use crate::renderer::scene_renderer::SceneRenderer;
+#[cfg(feature = "render-3d")]
+use crate::renderer::skybox::{environment_params, Skybox};
+use anvilkit_core::diagnostics::Diagnostics;
 
 impl RenderApp {
     /// 处理窗口大小变化
@@ -22,21 +34,40 @@ impl RenderApp {
             }
         }
 
-        // 通过 SceneRenderer 重建所有 size-dependent GPU 资源
+        // 重建所有 size-dependent GPU 资源（render-3d 下经 SceneRenderer 同时重建 Bloom mip 链）
         if self.gpu_initialized && new_size.width > 0 && new_size.height > 0 {
             if let (Some(app), Some(device)) = (&mut self.app, &self.render_device) {
+                #[cfg(feature = "render-3d")]
                 let bloom_mip_count: u32 = app.world().get_resource::<BloomSettings>()
                     .map(|s| s.mip_count)
                     .unwrap_or(5u32);
                 if let Some(mut rs) = app.world_mut().get_resource_mut::<RenderState>() {
-                    crate::renderer::scene_renderer::SceneRenderer::handle_resize(
+                    #[cfg(feature = "render-3d")]
+                    SceneRenderer::handle_resize(
                         device, &mut rs, new_size.width, new_size.height, bloom_mip_count,
                     );
+                    #[cfg(not(feature = "render-3d"))]
+                    rs.resize(device, new_size.width, new_size.height);
                 }
             }
         }
     }
 
+    /// 窗口尺寸与表面配置不一致时（resize 事件尚未送达）先走 [`Self::handle_resize`]，
+    /// 同步表面、`RenderState.surface_size` 与尺寸相关的渲染目标
+    ///
+    /// 窗口最小化（尺寸为 0）时返回 `false`，本帧应跳过。
+    pub(super) fn sync_surface_size(&mut self) -> bool {
+        let Some(size) = self.window.as_ref().map(|w| w.inner_size()) else { return false };
+        if size.width == 0 || size.height == 0 {
+            return false;
+        }
+        if self.render_surface.as_ref().is_some_and(|s| s.size() != (size.width, size.height)) {
+            self.handle_resize(size);
+        }
+        true
+    }
+
     /// 处理缩放因子变化
     pub(super) fn handle_scale_factor_changed(&mut self, scale_factor: f64) {
         debug!("缩放因子变化: {}", scale_factor);
@@ -46,23 +77,80 @@ impl RenderApp {
     /// 执行 ECS 多物体 HDR PBR 渲染
     ///
     /// Pass 1: 场景渲染到 HDR RT (Rgba16Float)
-    /// Pass 2: Tone mapping HDR → Swapchain (ACES Filmic)
+    /// Pass 2: Tone mapping HDR → Swapchain (ACES Filmic + Vignette)
+    /// Pass 3: FXAA（可选）→ Swapchain
     fn render_ecs(&mut self) {
+        if !self.sync_surface_size() {
+            return;
+        }
         let (Some(device), Some(surface)) = (&self.render_device, &self.render_surface) else {
             return;
         };
 
         let Some(app) = &mut self.app else { return };
 
-        // 延迟初始化后处理 GPU 资源（通过 SceneRenderer）
+        // 延迟初始化后处理 GPU 资源（render-3d 下经 SceneRenderer 同时管理 Bloom、光柱与天空盒）
         {
             let pp_settings = app.world().get_resource::<crate::renderer::post_process::PostProcessSettings>()
                 .cloned()
                 .unwrap_or_default();
+            let shadow_map_size = app.world().get_resource::<crate::quality::QualityScale>()
+                .map(|q| q.shadow_map_size);
+            let render_scale = effective_render_scale(app.world());
+            // 超采样截图：本帧以截图倍数渲染 3D 目标，捕获 pass 再缩小到窗口分辨率
+            #[cfg(feature = "capture")]
+            let render_scale = app.world().get_resource::<crate::renderer::capture::CaptureState>()
+                .and_then(|s| s.supersample_scale())
+                .unwrap_or(render_scale);
+            let dynamic_resolution = app.world().get_resource::<DynamicResolution>().copied().unwrap_or_default();
+            #[cfg(feature = "render-3d")]
+            let bloom_mip_count = app.world().get_resource::<BloomSettings>().map_or(5, |s| s.mip_count);
+            #[cfg(feature = "render-3d")]
+            let light_shafts = app.world().get_resource::<SceneLights>()
+                .is_some_and(|l| l.directional.light_shafts.is_some());
+            #[cfg(feature = "render-3d")]
+            let skybox = app.world().get_resource::<Skybox>().cloned();
             if let Some(mut rs) = app.world_mut().get_resource_mut::<RenderState>() {
-                crate::renderer::scene_renderer::SceneRenderer::ensure_post_process_resources(
-                    device, &mut rs, &pp_settings,
-                );
+                if render_scale != rs.render_scale {
+                    #[cfg(feature = "render-3d")]
+                    SceneRenderer::set_render_scale(device, &mut rs, render_scale, bloom_mip_count);
+                    #[cfg(not(feature = "render-3d"))]
+                    rs.set_render_scale(device, render_scale);
+                }
+                let params = TonemapParams::new(&dynamic_resolution, rs.render_scale)
+                    .with_vignette(pp_settings.vignette.as_ref());
+                device.queue().write_buffer(&rs.tonemap_params_buffer, 0, bytemuck::bytes_of(&params));
+                #[cfg(feature = "render-3d")]
+                SceneRenderer::ensure_post_process_resources(device, &mut rs, &pp_settings, light_shafts);
+                #[cfg(not(feature = "render-3d"))]
+                rs.ensure_post_process_resources(device, &pp_settings);
+                if let Some(size) = shadow_map_size.filter(|&size| size != rs.shadow_map_size) {
+                    rs.resize_shadow_map(device, size);
+                }
+                #[cfg(feature = "render-3d")]
+                SceneRenderer::ensure_skybox(device, &mut rs, skybox.as_ref());
+            }
+        }
+
+        // GPU 计时：按设置延迟创建；不支持时间戳的适配器自动关闭
+        {
+            let enabled = app.world().get_resource::<GpuTimingSettings>().is_some_and(|s| s.enabled);
+            if !enabled {
+                self.gpu_profiler = None;
+            } else if self.gpu_profiler.is_none() {
+                if GpuProfiler::is_supported(device) {
+                    self.gpu_profiler = Some(GpuProfiler::new(device));
+                } else {
+                    log::warn!("适配器不支持编码器内时间戳查询，GPU 计时已关闭");
+                    app.world_mut().resource_mut::<GpuTimingSettings>().enabled = false;
+                }
+            }
+        }
+        if let Some(profiler) = self.gpu_profiler.as_mut() {
+            if let Some(timings) = profiler.collect(device) {
+                if let Some(mut diagnostics) = app.world_mut().get_resource_mut::<Diagnostics>() {
+                    record_gpu_timings(&mut diagnostics, &timings);
+                }
             }
         }
 
@@ -75,14 +163,17 @@ impl RenderApp {
             return;
         }
 
-        let frame = match surface.get_current_frame_with_recovery(device) {
-            Ok(frame) => frame,
+        let frame = match surface.acquire_frame(device) {
+            Ok(Some(frame)) => frame,
+            // 超时：跳过本帧
+            Ok(None) => return,
             Err(e) => {
                 error!("获取当前帧失败: {}", e);
                 return;
             }
         };
 
+        let _frame_span = tracing::info_span!("render_frame").entered();
         let swapchain_view = frame.texture.create_view(&Default::default());
         let view_proj = active_camera.view_proj;
         let camera_pos = active_camera.camera_pos;
@@ -93,6 +184,7 @@ impl RenderApp {
             .unwrap_or(&default_lights);
         let (gpu_lights, light_count) = pack_lights(scene_lights);
         let light = &scene_lights.directional;
+        let ambient = &scene_lights.ambient;
 
         // Compute CSM cascade matrices for shadow mapping
         let (sw, sh) = render_state.surface_size;
@@ -104,8 +196,16 @@ impl RenderApp {
             camera_pos + (active_camera.view_proj.inverse() * glam::Vec4::new(0.0, 0.0, -1.0, 0.0)).truncate().normalize(),
             glam::Vec3::Y,
         );
-        let (cascade_matrices, cascade_splits) =
-            compute_cascade_matrices(&light.direction, &cam_view_approx, cam_fov, cam_aspect, 0.1, 200.0);
+        let shadows = &light.shadows;
+        #[cfg(feature = "render-3d")]
+        let skybox = app.world().get_resource::<Skybox>();
+        #[cfg(feature = "render-3d")]
+        let env_params = environment_params(skybox);
+        #[cfg(not(feature = "render-3d"))]
+        let env_params = [0.0; 4];
+        let (cascade_matrices, cascade_splits) = compute_cascade_matrices_with_splits(
+            &light.direction, &cam_view_approx, cam_fov, cam_aspect, 0.1, shadows.max_distance.max(1.0), &shadows.split_ratios(),
+        );
 
         // === Batched rendering: single encoder, multiple passes, single submit ===
         let mut encoder = device.device().create_command_encoder(
@@ -113,14 +213,14 @@ impl RenderApp {
         );
 
         // --- Batch all uniform data into a single CPU buffer, then upload once ---
-        // Alignment: 256 bytes. PbrSceneUniform is 992 bytes -> stride = 1024 bytes.
+        // Alignment: 256 bytes. PbrSceneUniform is 1056 bytes -> stride = 1280 bytes.
         let alignment = 256usize;
         let mut batch = UniformBatchBuffer::new(alignment);
 
         // --- Pass 0: CSM Shadow passes (one render pass per cascade, batched draws) ---
         // Accumulate shadow uniforms for all cascades x objects.
         // shadow_draw_info[cascade_idx] = vec of (offset, cmd_idx) for draws in that cascade.
-        let num_cascades = render_state.shadow_cascade_views.len().min(CSM_CASCADE_COUNT);
+        let num_cascades = render_state.shadow_cascade_views.len().min(shadows.active_cascades());
         let mut shadow_draw_info: Vec<Vec<(u32, usize)>> = vec![Vec::new(); num_cascades];
 
         for cascade_idx in 0..num_cascades {
@@ -166,8 +266,12 @@ impl RenderApp {
                     cascade_matrices[1].to_cols_array_2d(),
                     cascade_matrices[2].to_cols_array_2d(),
                 ],
-                cascade_splits: [cascade_splits[0], cascade_splits[1], cascade_splits[2], 1.0 / SHADOW_MAP_SIZE as f32],
-                emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], CSM_CASCADE_COUNT as f32],
+                cascade_splits: [cascade_splits[0], cascade_splits[1], cascade_splits[2], 1.0 / render_state.shadow_map_size as f32],
+                emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], num_cascades as f32],
+                base_color_factor: cmd.base_color,
+                ambient: [ambient.color.x, ambient.color.y, ambient.color.z, ambient.intensity],
+                shadow_params: [shadows.depth_bias, shadows.normal_bias, shadows.pcf_radius.min(4) as f32, 0.0],
+                env_params,
             };
             let offset = batch.push(bytemuck::bytes_of(&uniform));
             scene_draw_info.push((offset, cmd_idx));
@@ -180,12 +284,21 @@ impl RenderApp {
             );
         }
 
+        // 本帧网格绘制统计（写入 RenderStats）
+        let mut draw_calls: u32 = 0;
+        let mut triangles: u32 = 0;
+
+        let mut profiler = self.gpu_profiler.as_mut();
+        if let Some(p) = profiler.as_mut() { p.begin_frame(); }
+
         // --- Shadow render passes: one per cascade, all draws inside ---
+        if let Some(p) = profiler.as_mut() { p.begin_scope(&mut encoder, "shadow"); }
         for cascade_idx in 0..num_cascades {
             let cascade_view = &render_state.shadow_cascade_views[cascade_idx];
             let draws = &shadow_draw_info[cascade_idx];
             if draws.is_empty() { continue; }
 
+            let _span = tracing::info_span!("render_pass", name = "CSM Shadow Pass", cascade = cascade_idx).entered();
             let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("CSM Shadow Pass"),
                 color_attachments: &[],
@@ -209,18 +322,26 @@ impl RenderApp {
                 rp.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
                 rp.set_index_buffer(gpu_mesh.index_buffer.slice(..), gpu_mesh.index_format);
                 rp.draw_indexed(0..gpu_mesh.index_count, 0, 0..1);
+                draw_calls += 1;
+                triangles += gpu_mesh.index_count / 3;
             }
         }
 
         // --- Pass 1: Scene -> HDR render target (single render pass, all draws) ---
+        if let Some(p) = profiler.as_mut() { p.begin_scope(&mut encoder, "scene"); }
+        let clear_color = app.world().get_resource::<crate::plugin::ClearColor>()
+            .copied()
+            .unwrap_or_default()
+            .to_wgpu();
         if !scene_draw_info.is_empty() {
+            let _span = tracing::info_span!("render_pass", name = "ECS HDR Scene Pass").entered();
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("ECS HDR Scene Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
                     view: &render_state.hdr_msaa_texture_view,
                     resolve_target: Some(&render_state.hdr_texture_view),
                     ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color { r: 0.15, g: 0.3, b: 0.6, a: 1.0 }),
+                        load: wgpu::LoadOp::Clear(clear_color),
                         store: wgpu::StoreOp::Store,
                     },
                 })],
@@ -249,6 +370,17 @@ impl RenderApp {
                     }
                 };
 
+                // 读取可选顶点属性的材质需要网格提供 slot 1 属性顶点流
+                if !gpu_material.vertex_attributes.is_empty() {
+                    match &gpu_mesh.attribute_buffer {
+                        Some(buffer) => render_pass.set_vertex_buffer(1, buffer.slice(..)),
+                        None => {
+                            log::warn!("材质需要可选顶点属性 {:?}，但网格未上传属性顶点流", gpu_material.vertex_attributes);
+                            continue;
+                        }
+                    }
+                }
+
                 render_pass.set_pipeline(pipeline);
                 render_pass.set_bind_group(0, &render_state.scene_bind_group, &[offset]);
                 render_pass.set_bind_group(1, &gpu_material.bind_group, &[]);
@@ -256,11 +388,69 @@ impl RenderApp {
                 render_pass.set_vertex_buffer(0, gpu_mesh.vertex_buffer.slice(..));
                 render_pass.set_index_buffer(gpu_mesh.index_buffer.slice(..), gpu_mesh.index_format);
                 render_pass.draw_indexed(0..gpu_mesh.index_count, 0, 0..1);
+                draw_calls += 1;
+                triangles += gpu_mesh.index_count / 3;
+            }
+        }
+
+        // --- Pass 1.1: Skybox -> HDR (fills pixels left at the far plane) ---
+        #[cfg(feature = "render-3d")]
+        if let (Some(sky_res), Some(skybox)) = (&render_state.skybox, skybox) {
+            if !scene_draw_info.is_empty() {
+                let _span = tracing::info_span!("render_pass", name = "Skybox Pass").entered();
+                sky_res.render(device, &mut encoder, render_state, &view_proj, camera_pos, skybox.brightness);
+            }
+        }
+
+        // --- 自定义渲染阶段: AfterOpaque ---
+        if let Some(p) = profiler.as_mut() { p.begin_scope(&mut encoder, "hdr_phases"); }
+        let render_phases = app.world().get_resource::<RenderPhases>();
+        let run_hdr_phases = |slot: PhaseSlot, encoder: &mut wgpu::CommandEncoder| {
+            let Some(phases) = render_phases else { return };
+            if !phases.has_work(app.world(), slot) { return; }
+            let mut ctx = PhaseRenderContext {
+                device,
+                encoder,
+                color_target: &render_state.hdr_msaa_texture_view,
+                resolve_target: Some(&render_state.hdr_texture_view),
+                depth_target: Some(&render_state.depth_texture_view),
+                color_format: HDR_FORMAT,
+                sample_count: MSAA_SAMPLE_COUNT,
+                view_proj,
+            };
+            phases.render_slot(app.world(), slot, &mut ctx);
+        };
+        run_hdr_phases(PhaseSlot::AfterOpaque, &mut encoder);
+
+        // --- Pass 1.5: DebugDraw lines -> HDR (depth-tested, after the main pass) ---
+        if let Some(debug_draw) = app.world().get_resource::<crate::renderer::debug::DebugDraw>() {
+            if !debug_draw.is_empty() {
+                let _span = tracing::info_span!("render_pass", name = "Debug Draw Pass").entered();
+                let debug_renderer = self.debug_renderer.get_or_insert_with(|| {
+                    crate::renderer::debug::DebugRenderer::with_sample_count(device, MSAA_SAMPLE_COUNT)
+                });
+                debug_renderer.clear();
+                debug_renderer.queue(debug_draw);
+                debug_renderer.prepare();
+                debug_renderer.render_resolved(
+                    device,
+                    &mut encoder,
+                    &render_state.hdr_msaa_texture_view,
+                    &render_state.hdr_texture_view,
+                    &render_state.depth_texture_view,
+                    &view_proj.to_cols_array_2d(),
+                );
             }
         }
 
-        // --- 后处理管线 (顺序: SSAO → DOF → MotionBlur → Bloom → ColorGrading) ---
+        // --- 自定义渲染阶段: BeforePostProcess ---
+        run_hdr_phases(PhaseSlot::BeforePostProcess, &mut encoder);
+
+        // --- 后处理管线 (顺序: SSAO → DOF → MotionBlur → LightShafts → Bloom → ColorGrading) ---
+        if let Some(p) = profiler.as_mut() { p.begin_scope(&mut encoder, "post_process"); }
         {
+            let _span = tracing::info_span!("render_pass", name = "Post Process").entered();
+            #[cfg(any(feature = "render-3d", feature = "advanced-render"))]
             let pp_settings = app.world().get_resource::<crate::renderer::post_process::PostProcessSettings>()
                 .cloned()
                 .unwrap_or_default();
@@ -288,7 +478,14 @@ impl RenderApp {
                 mb_res.execute(device, &mut encoder, &render_state.hdr_texture_view, &render_state.depth_texture_view, mb_settings, prev_vp, curr_inv_vp);
             }
 
-            // 4. Bloom
+            // 4. Light Shafts（方向光上配置）
+            #[cfg(feature = "render-3d")]
+            if let Some(ref shaft_res) = render_state.post_process.light_shafts {
+                shaft_res.execute(device, &mut encoder, &render_state.hdr_texture_view, &render_state.depth_texture_view, light, view_proj);
+            }
+
+            // 5. Bloom
+            #[cfg(feature = "render-3d")]
             if let Some(ref bloom) = render_state.bloom {
                 let bloom_settings = pp_settings.bloom.as_ref()
                     .or_else(|| app.world().get_resource::<BloomSettings>());
@@ -297,19 +494,24 @@ impl RenderApp {
                 bloom.execute(device, &mut encoder, &render_state.hdr_texture_view, settings);
             }
 
-            // 5. Color Grading（通过中间纹理避免 src == dst 读写冲突）
+            // 6. Color Grading（通过中间纹理避免 src == dst 读写冲突）
             #[cfg(feature = "advanced-render")]
             if let (Some(ref cg_settings), Some(ref cg_res)) = (&pp_settings.color_grading, &render_state.post_process.color_grading) {
                 cg_res.execute(device, &mut encoder, &render_state.hdr_texture_view, &render_state.hdr_texture, &render_state.hdr_texture_view, cg_settings);
             }
         }
 
-        // --- Pass 2: Tone mapping HDR + Bloom → Swapchain ---
+        // --- Pass 2: Tone mapping HDR + Bloom → Swapchain（FXAA 启用时先写入其输入纹理） ---
+        if let Some(p) = profiler.as_mut() { p.begin_scope(&mut encoder, "tonemap"); }
+        let fxaa = app.world().get_resource::<crate::renderer::post_process::PostProcessSettings>()
+            .and_then(|s| s.fxaa.as_ref())
+            .zip(render_state.post_process.fxaa.as_ref());
         {
+            let _span = tracing::info_span!("render_pass", name = "ECS Tonemap Pass").entered();
             let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("ECS Tonemap Pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &swapchain_view,
+                    view: fxaa.map_or(&swapchain_view, |(_, res)| &res.input_view),
                     resolve_target: None,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
@@ -326,12 +528,40 @@ impl RenderApp {
             rp.draw(0..3, 0..1); // Fullscreen triangle
         }
 
+        // --- Pass 3: FXAA → Swapchain ---
+        if let Some((fxaa_settings, fxaa_res)) = fxaa {
+            let _span = tracing::info_span!("render_pass", name = "FXAA Pass").entered();
+            fxaa_res.execute(device, &mut encoder, &swapchain_view, fxaa_settings);
+        }
+
+        // --- 自定义渲染阶段: AfterTonemap (交换链叠加层) ---
+        if let Some(p) = profiler.as_mut() { p.begin_scope(&mut encoder, "overlay"); }
+        if let Some(phases) = render_phases {
+            if phases.has_work(app.world(), PhaseSlot::AfterTonemap) {
+                let mut ctx = PhaseRenderContext {
+                    device,
+                    encoder: &mut encoder,
+                    color_target: &swapchain_view,
+                    resolve_target: None,
+                    depth_target: None,
+                    color_format: surface.format(),
+                    sample_count: 1,
+                    view_proj,
+                };
+                phases.render_slot(app.world(), PhaseSlot::AfterTonemap, &mut ctx);
+            }
+        }
+
+        if let Some(p) = profiler.as_mut() { p.end_scope(&mut encoder); }
+
         // --- Capture: 额外 tonemap pass → capture texture → staging buffer ---
         #[cfg(feature = "capture")]
         let capture_active = {
             use crate::renderer::capture::{CaptureState, CaptureResources};
 
-            let should_capture = app.world().get_resource::<CaptureState>()
+            let streaming = app.world().get_resource::<crate::renderer::frame_stream::FrameStream>()
+                .is_some_and(|s| s.enabled);
+            let should_capture = streaming || app.world().get_resource::<CaptureState>()
                 .map(|s| s.should_capture())
                 .unwrap_or(false);
 
@@ -348,7 +578,7 @@ impl RenderApp {
                 }
 
                 if let Some(ref cr) = self.capture_resources {
-                    // 额外 tonemap pass 写入 capture_view
+                    // 额外 tonemap pass 写入 capture_view（超采样时双线性采样完成缩小）
                     {
                         let mut rp = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                             label: Some("Capture Tonemap Pass"),
@@ -378,7 +608,9 @@ impl RenderApp {
         };
 
         // Single submit for all passes
+        if let Some(p) = profiler.as_mut() { p.resolve(&mut encoder); }
         device.queue().submit(std::iter::once(encoder.finish()));
+        if let Some(p) = profiler { p.after_submit(); }
 
         // --- Capture: 回读像素并保存 ---
         #[cfg(feature = "capture")]
@@ -394,6 +626,9 @@ impl RenderApp {
                         if let Some(path) = output_path {
                             save_png(&pixels, cr.width, cr.height, &path);
                         }
+                        if let Some(mut stream) = app.world_mut().get_resource_mut::<crate::renderer::frame_stream::FrameStream>() {
+                            stream.submit(&pixels, cr.width, cr.height);
+                        }
                     }
                     Err(e) => {
                         log::error!("帧捕获像素回读失败: {}", e);
@@ -408,6 +643,10 @@ impl RenderApp {
         if let Some(mut rs) = app.world_mut().get_resource_mut::<RenderState>() {
             rs.post_process.prev_view_proj = Some(view_proj.to_cols_array_2d());
         }
+        if let Some(mut stats) = app.world_mut().get_resource_mut::<RenderStats>() {
+            stats.draw_calls = draw_calls;
+            stats.triangles = triangles;
+        }
 
         // 更新 CaptureState（需要 &mut self.app）
         #[cfg(feature = "capture")]
@@ -423,7 +662,11 @@ impl RenderApp {
     /// 执行渲染（ECS 路径）
     pub(super) fn render(&mut self) {
         if self.app.is_some() && self.gpu_initialized {
+            let start = anvilkit_core::time::Instant::now();
             self.render_ecs();
+            if let Some(mut profile) = self.app.as_mut().and_then(|app| app.world_mut().get_resource_mut::<CpuFrameProfile>()) {
+                profile.record("render", start, start.elapsed());
+            }
         }
     }
 }
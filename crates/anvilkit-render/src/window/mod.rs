@@ -7,6 +7,8 @@
 //! - **RenderApp**: 实现 ApplicationHandler 的主应用结构
 //! - **WindowConfig**: 窗口配置参数
 //! - **WindowState**: 窗口状态管理
+//! - **WindowCommands**: 运行时窗口控制命令队列
+//! - **WinitRunnerPlugin**: 以 winit 事件循环作为 ECS App 的 runner
 //! 
 //! ## 设计理念
 //! 
@@ -29,9 +31,16 @@
 
 pub mod window;
 pub mod events;
+pub mod commands;
+pub mod runner;
 
 // 重新导出主要类型
 pub use window::{WindowConfig, WindowState};
+pub use commands::{CursorGrab, WindowCommand, WindowCommands};
+pub use runner::{
+    WinitRunnerPlugin, winit_runner,
+    WindowResized, WindowScaleFactorChanged, WindowFocused, WindowCloseRequested,
+};
 pub use events::{RenderApp, pack_lights, compute_light_space_matrix};
 
 #[cfg(test)]
@@ -0,0 +1,125 @@
+//! # winit 运行器
+//!
+//! 将 winit 事件循环作为 ECS `App` 的 runner：
+//!
+//! - [`WinitRunnerPlugin`]: 设置 runner 后直接 `app.run()` 即可启动窗口与主循环
+//! - 窗口事件（尺寸、缩放、焦点、关闭请求）以 ECS 事件形式写入 World
+//! - 系统发送 `AppExit` 后事件循环退出，`app.run()` 返回该退出码
+//!
+//! ```rust,no_run
+//! use anvilkit_render::prelude::*;
+//! use anvilkit_render::window::WinitRunnerPlugin;
+//!
+//! let mut app = App::new();
+//! app.add_plugins((RenderPlugin::default(), WinitRunnerPlugin));
+//! app.run();
+//! ```
+
+use bevy_app::{App, AppExit, Plugin};
+use bevy_ecs::prelude::*;
+use winit::event::WindowEvent;
+
+use super::RenderApp;
+
+/// 窗口尺寸变化（物理像素）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct WindowResized {
+    /// 新宽度
+    pub width: u32,
+    /// 新高度
+    pub height: u32,
+}
+
+/// 窗口缩放因子变化
+#[derive(Debug, Clone, Copy, PartialEq, Event)]
+pub struct WindowScaleFactorChanged {
+    /// 新缩放因子
+    pub scale_factor: f64,
+}
+
+/// 窗口焦点变化
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct WindowFocused {
+    /// 是否获得焦点
+    pub focused: bool,
+}
+
+/// 用户请求关闭窗口
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+pub struct WindowCloseRequested;
+
+/// 注册窗口 ECS 事件
+pub(crate) fn add_window_events(app: &mut App) {
+    app.add_event::<WindowResized>();
+    app.add_event::<WindowScaleFactorChanged>();
+    app.add_event::<WindowFocused>();
+    app.add_event::<WindowCloseRequested>();
+}
+
+fn send<E: Event>(app: &mut App, event: E) {
+    if let Some(mut events) = app.world_mut().get_resource_mut::<Events<E>>() {
+        events.send(event);
+    }
+}
+
+impl RenderApp {
+    /// 将窗口事件转换为 ECS 事件（尺寸、缩放、焦点、关闭请求）
+    ///
+    /// 与 [`forward_input`](Self::forward_input) 相同，自定义 `ApplicationHandler` 时调用。
+    pub fn forward_window_event(app: &mut App, event: &WindowEvent) {
+        match event {
+            WindowEvent::Resized(size) => send(app, WindowResized { width: size.width, height: size.height }),
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                send(app, WindowScaleFactorChanged { scale_factor: *scale_factor })
+            }
+            WindowEvent::Focused(focused) => send(app, WindowFocused { focused: *focused }),
+            WindowEvent::CloseRequested => send(app, WindowCloseRequested),
+            _ => {}
+        }
+    }
+}
+
+/// 以 winit 事件循环运行 `App`，返回 `AppExit`
+///
+/// 窗口关闭时返回 `AppExit::Success`。
+pub fn winit_runner(app: App) -> AppExit {
+    RenderApp::run(app)
+}
+
+/// 将 [`winit_runner`] 设为 `App` 的 runner
+pub struct WinitRunnerPlugin;
+
+impl Plugin for WinitRunnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.set_runner(winit_runner);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use winit::dpi::PhysicalSize;
+
+    #[test]
+    fn test_window_events_forwarded() {
+        let mut app = App::new();
+        add_window_events(&mut app);
+
+        RenderApp::forward_window_event(&mut app, &WindowEvent::Resized(PhysicalSize::new(800, 600)));
+        RenderApp::forward_window_event(&mut app, &WindowEvent::Focused(false));
+
+        let resized = app.world().resource::<Events<WindowResized>>();
+        let mut cursor = resized.get_cursor();
+        assert_eq!(cursor.read(resized).copied().collect::<Vec<_>>(), vec![WindowResized { width: 800, height: 600 }]);
+
+        let focused = app.world().resource::<Events<WindowFocused>>();
+        assert_eq!(focused.get_cursor().read(focused).count(), 1);
+    }
+
+    #[test]
+    fn test_forward_without_registered_events() {
+        // 未注册事件时静默忽略
+        let mut app = App::new();
+        RenderApp::forward_window_event(&mut app, &WindowEvent::CloseRequested);
+    }
+}
@@ -44,6 +44,8 @@ pub struct WindowConfig {
     pub min_size: Option<(u32, u32)>,
     /// 最大窗口大小
     pub max_size: Option<(u32, u32)>,
+    /// Web 平台上渲染到的 `<canvas>` 元素 id；`None` 时创建新 canvas 并追加到 `<body>`（原生平台忽略）
+    pub canvas_id: Option<String>,
 }
 
 impl Default for WindowConfig {
@@ -58,6 +60,7 @@ impl Default for WindowConfig {
             vsync: true,
             min_size: Some((320, 240)),
             max_size: None,
+            canvas_id: None,
         }
     }
 }
@@ -212,7 +215,22 @@ impl WindowConfig {
         self.max_size = max_size;
         self
     }
-    
+
+    /// 设置 Web 平台使用的 canvas 元素 id
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use anvilkit_render::window::WindowConfig;
+    ///
+    /// let config = WindowConfig::new().with_canvas_id("game-canvas");
+    /// assert_eq!(config.canvas_id.as_deref(), Some("game-canvas"));
+    /// ```
+    pub fn with_canvas_id(mut self, canvas_id: impl Into<String>) -> Self {
+        self.canvas_id = Some(canvas_id.into());
+        self
+    }
+
     /// 将配置转换为 winit 的 WindowAttributes
     /// 
     /// # 返回
@@ -245,6 +263,24 @@ impl WindowConfig {
         if self.fullscreen {
             attributes = attributes.with_fullscreen(Some(Fullscreen::Borderless(None)));
         }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::JsCast;
+            use winit::platform::web::WindowAttributesExtWebSys;
+
+            let canvas = self.canvas_id.as_deref().and_then(|id| {
+                web_sys::window()?
+                    .document()?
+                    .get_element_by_id(id)?
+                    .dyn_into::<web_sys::HtmlCanvasElement>()
+                    .ok()
+            });
+            attributes = match canvas {
+                Some(canvas) => attributes.with_canvas(Some(canvas)),
+                None => attributes.with_append(true),
+            };
+        }
         
         attributes
     }
@@ -0,0 +1,80 @@
+//! 特性矩阵检查
+//!
+//! 普通测试在当前启用的特性下确认对应模块可用；
+//! [`feature_matrix_compiles`] 对矩阵中的每个组合执行 `cargo check --no-default-features`，
+//! 随 `cargo test --workspace` 在 CI 中运行。嵌套的 cargo 使用独立的 target 目录，
+//! 不与外层构建争用目录锁。
+
+use std::path::Path;
+use std::process::Command;
+
+/// 需要单独编译通过的特性组合
+const FEATURE_MATRIX: &[&[&str]] = &[
+    &[],
+    &["render-2d"],
+    &["render-3d"],
+    &["render-2d", "render-3d"],
+    &["render-2d", "render-3d", "advanced-render", "capture"],
+    &["ui-theme"],
+];
+
+#[test]
+fn core_modules_always_available() {
+    use anvilkit_render::renderer::{assets::RenderAssets, draw::DrawCommandList, material::Materials};
+    let _ = RenderAssets::default();
+    let _ = DrawCommandList::default();
+    let _ = Materials::default();
+}
+
+#[cfg(any(feature = "render-2d", feature = "render-3d"))]
+#[test]
+fn shared_2d_primitives_available() {
+    use anvilkit_render::renderer::sprite::{SpriteBatch, SpriteVertex};
+    assert_eq!(std::mem::size_of::<SpriteVertex>(), 32);
+    assert_eq!(SpriteBatch::new().sprite_count(), 0);
+    let _ = std::mem::size_of::<anvilkit_render::renderer::text::TextRenderer>();
+}
+
+#[cfg(feature = "render-2d")]
+#[test]
+fn render_2d_modules_available() {
+    let _ = std::mem::size_of::<anvilkit_render::renderer::canvas2d::Canvas2DRenderer>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::ui::UiRenderer>();
+}
+
+#[cfg(feature = "render-3d")]
+#[test]
+fn render_3d_modules_available() {
+    use anvilkit_render::renderer::particle::ParticleSystems;
+    use anvilkit_render::renderer::{bloom::BloomSettings, crowd::CrowdInstances, imposter::Imposters};
+    let _ = std::mem::size_of::<anvilkit_render::renderer::canvas3d::Canvas3DRenderer>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::shadow::ShadowCasterData>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::scene_renderer::SceneRenderer>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::skybox::Skybox>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::lightmap::Lightmap>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::light_shafts::LightShaftSettings>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::billboard::Billboard>();
+    let _ = std::mem::size_of::<anvilkit_render::renderer::day_night::DayNightCycle>();
+    assert!(ParticleSystems::default().systems.is_empty());
+    assert!(BloomSettings::default().mip_count > 0);
+    let _ = CrowdInstances::default();
+    let _ = Imposters::default();
+}
+
+#[test]
+fn feature_matrix_compiles() {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("feature-matrix");
+    for features in FEATURE_MATRIX {
+        let mut cmd = Command::new(&cargo);
+        cmd.current_dir(env!("CARGO_MANIFEST_DIR"))
+            .env("CARGO_TARGET_DIR", &target_dir)
+            // 依赖已由外层构建下载
+            .args(["check", "--offline", "-p", "anvilkit-render", "--lib", "--no-default-features"]);
+        if !features.is_empty() {
+            cmd.args(["--features", &features.join(",")]);
+        }
+        let status = cmd.status().expect("无法启动 cargo");
+        assert!(status.success(), "特性组合 {:?} 编译失败", features);
+    }
+}
@@ -27,6 +27,9 @@ pub use anvilkit_mcp as mcp;
 pub mod default_plugins;
 pub use default_plugins::DefaultPlugins;
 
+#[cfg(feature = "settings")]
+pub mod settings;
+
 /// Convenient re-exports of the most commonly used types and traits.
 pub mod prelude {
     pub use anvilkit_core::prelude::*;
@@ -34,7 +37,7 @@ pub mod prelude {
     pub use anvilkit_assets::prelude::*;
     pub use anvilkit_input::prelude::*;
     pub use anvilkit_audio::AudioPlugin;
-    pub use anvilkit_audio::components::{AudioSource, AudioListener, PlaybackState, AudioBus};
+    pub use anvilkit_audio::components::{AudioSource, AudioSink, AudioListener, PlaybackState, AudioBus};
     pub use anvilkit_app::prelude::{
         AnvilKitApp, GameCallbacks, GameConfig, GameContext, WindowSize,
         CursorMode, ScreenPlugin, EguiTextures,
@@ -46,6 +49,8 @@ pub mod prelude {
     };
     pub use anvilkit_describe::{Describe, ComponentSchema, FieldSchema};
     pub use crate::DefaultPlugins;
+    #[cfg(feature = "settings")]
+    pub use crate::settings::{GraphicsPreset, SettingChanged, SettingDef, SettingValue, Settings, SettingsMenu, SettingsPlugin};
 
     // Re-export bevy_ecs prelude for games
     pub use bevy_ecs::prelude::*;
@@ -0,0 +1,1019 @@
+//! # 设置菜单
+//!
+//! 声明式的设置定义：每项 [`SettingDef`] 描述键名、标签、控件类型、默认值以及
+//! 值变化时如何作用到对应子系统（窗口、音频总线、动作映射、后处理）。
+//!
+//! - [`Settings`] 资源保存当前值，并以 RON 读写到配置文件；
+//! - [`SettingsMenu`] 打开时按定义自动生成 UI 行（标签、`<` 值 `>`），
+//!   点击或按键修改设置；
+//! - [`SettingsPlugin`] 在启动时加载并应用全部设置，之后每次修改都会应用并写回文件。
+//!
+//! ```rust
+//! use anvilkit::settings::{SettingDef, SettingValue, Settings};
+//! use anvilkit_input::prelude::KeyCode;
+//!
+//! let mut settings = Settings::default();
+//! settings.register(SettingDef::vsync());
+//! settings.register(SettingDef::slider("mouse_sensitivity", "Mouse Sensitivity", 0.1, 5.0, 0.1, 1.0));
+//! settings.register(SettingDef::key_binding("jump", "Jump", KeyCode::Space));
+//!
+//! settings.set("mouse_sensitivity", SettingValue::Float(9.0));
+//! assert_eq!(settings.get_f32("mouse_sensitivity"), Some(5.0));
+//!
+//! let ron = settings.to_ron().unwrap();
+//! let mut restored = Settings::default();
+//! restored.register(SettingDef::key_binding("jump", "Jump", KeyCode::Space));
+//! restored.register(SettingDef::slider("mouse_sensitivity", "Mouse Sensitivity", 0.1, 5.0, 0.1, 1.0));
+//! restored.load_ron(&ron).unwrap();
+//! assert_eq!(restored.get_f32("mouse_sensitivity"), Some(5.0));
+//! assert_eq!(restored.get_str("input.jump"), Some("Space"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bevy_ecs::prelude::*;
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use anvilkit_app::ecs_app::{App, Plugin};
+use anvilkit_audio::components::{AudioBus, AudioBusCategory};
+use anvilkit_core::error::{AnvilKitError, Result};
+use anvilkit_input::prelude::{ActionMap, InputBinding, InputState, KeyCode, MouseButton};
+use anvilkit_render::renderer::bloom::BloomSettings;
+use anvilkit_render::renderer::post_process::PostProcessSettings;
+use anvilkit_render::renderer::ui::{UiNode, UiText};
+use anvilkit_render::renderer::ui_anchor::{ui_anchor_system, Anchor, UiAnchor, UiAnchorPlugin, UiViewport};
+use anvilkit_render::window::WindowCommands;
+
+// ---------------------------------------------------------------------------
+//  定义
+// ---------------------------------------------------------------------------
+
+/// 设置值（RON 中的持久化形式）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SettingValue {
+    /// 开关
+    Bool(bool),
+    /// 数值（滑块）
+    Float(f32),
+    /// 文本（选项名或按键名）
+    Text(String),
+}
+
+impl SettingValue {
+    /// 作为开关读取
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// 作为数值读取
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// 作为文本读取
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// 设置项的控件类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum SettingKind {
+    /// 开关，值为 [`SettingValue::Bool`]
+    Toggle,
+    /// 滑块，值为 [`SettingValue::Float`]，按 `step` 增减并限制在 `[min, max]`
+    Slider {
+        /// 最小值
+        min: f32,
+        /// 最大值
+        max: f32,
+        /// 步长
+        step: f32,
+    },
+    /// 选项列表，值为 [`SettingValue::Text`]（选项名）
+    Choice(Vec<String>),
+    /// 按键绑定，值为 [`SettingValue::Text`]（[`KeyCode::from_name`] 可识别的按键名）
+    KeyBinding,
+}
+
+/// 设置值变化时调用的应用函数
+pub type SettingApplyFn = dyn Fn(&mut World, &SettingValue) + Send + Sync;
+
+/// 单个设置项的声明
+#[derive(Clone)]
+pub struct SettingDef {
+    /// 配置文件中的键名
+    pub key: String,
+    /// 菜单中显示的标签
+    pub label: String,
+    /// 控件类型
+    pub kind: SettingKind,
+    /// 默认值
+    pub default: SettingValue,
+    apply: Option<Arc<SettingApplyFn>>,
+}
+
+impl fmt::Debug for SettingDef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SettingDef")
+            .field("key", &self.key)
+            .field("label", &self.label)
+            .field("kind", &self.kind)
+            .field("default", &self.default)
+            .field("apply", &self.apply.is_some())
+            .finish()
+    }
+}
+
+impl SettingDef {
+    fn new(key: impl Into<String>, label: impl Into<String>, kind: SettingKind, default: SettingValue) -> Self {
+        Self { key: key.into(), label: label.into(), kind, default, apply: None }
+    }
+
+    /// 开关设置
+    pub fn toggle(key: impl Into<String>, label: impl Into<String>, default: bool) -> Self {
+        Self::new(key, label, SettingKind::Toggle, SettingValue::Bool(default))
+    }
+
+    /// 滑块设置
+    pub fn slider(key: impl Into<String>, label: impl Into<String>, min: f32, max: f32, step: f32, default: f32) -> Self {
+        Self::new(key, label, SettingKind::Slider { min, max, step }, SettingValue::Float(default.clamp(min, max)))
+    }
+
+    /// 选项设置，默认值为 `options[default]`
+    pub fn choice(key: impl Into<String>, label: impl Into<String>, options: Vec<String>, default: usize) -> Self {
+        let value = options.get(default).or(options.first()).cloned().unwrap_or_default();
+        Self::new(key, label, SettingKind::Choice(options), SettingValue::Text(value))
+    }
+
+    /// 动作的按键绑定，键名为 `input.<action>`，修改后替换 [`ActionMap`] 中该动作的全部绑定
+    pub fn key_binding(action: &str, label: impl Into<String>, default: KeyCode) -> Self {
+        let action_name = action.to_string();
+        Self::new(
+            format!("input.{}", action),
+            label,
+            SettingKind::KeyBinding,
+            SettingValue::Text(format!("{:?}", default)),
+        )
+        .with_apply(move |world, value| {
+            let (Some(mut map), Some(binding)) = (
+                world.get_resource_mut::<ActionMap>(),
+                value.as_str().and_then(InputBinding::from_key_name),
+            ) else {
+                return;
+            };
+            map.clear_bindings(&action_name);
+            map.add_binding(&action_name, binding);
+        })
+    }
+
+    /// 窗口分辨率，选项形如 `"1920x1080"`，默认第一项
+    pub fn resolution(sizes: &[(u32, u32)]) -> Self {
+        let options = sizes.iter().map(|(w, h)| format!("{}x{}", w, h)).collect();
+        Self::choice("video.resolution", "Resolution", options, 0).with_apply(|world, value| {
+            let Some((w, h)) = value.as_str().and_then(parse_resolution) else { return };
+            if let Some(mut window) = world.get_resource_mut::<WindowCommands>() {
+                window.request_inner_size(w, h);
+            }
+        })
+    }
+
+    /// 垂直同步开关
+    pub fn vsync() -> Self {
+        Self::toggle("video.vsync", "VSync", true).with_apply(|world, value| {
+            let Some(vsync) = value.as_bool() else { return };
+            if let Some(mut window) = world.get_resource_mut::<WindowCommands>() {
+                window.set_vsync(vsync);
+            }
+        })
+    }
+
+    /// 主音量
+    pub fn master_volume() -> Self {
+        Self::slider("audio.master", "Master Volume", 0.0, 1.0, 0.1, 1.0)
+            .with_apply(|world, value| set_bus_volume(world, value, |bus| &mut bus.master))
+    }
+
+    /// 分类总线音量
+    pub fn volume(category: AudioBusCategory) -> Self {
+        let (key, label) = match category {
+            AudioBusCategory::SFX => ("audio.sfx", "SFX Volume"),
+            AudioBusCategory::Music => ("audio.music", "Music Volume"),
+            AudioBusCategory::Voice => ("audio.voice", "Voice Volume"),
+        };
+        Self::slider(key, label, 0.0, 1.0, 0.1, 1.0).with_apply(move |world, value| {
+            set_bus_volume(world, value, |bus| match category {
+                AudioBusCategory::SFX => &mut bus.sfx,
+                AudioBusCategory::Music => &mut bus.music,
+                AudioBusCategory::Voice => &mut bus.voice,
+            })
+        })
+    }
+
+    /// 画质预设，见 [`GraphicsPreset`]
+    pub fn graphics_preset() -> Self {
+        let options = GraphicsPreset::ALL.iter().map(|p| p.name().to_string()).collect();
+        Self::choice("video.graphics", "Graphics", options, GraphicsPreset::High as usize).with_apply(|world, value| {
+            let Some(preset) = value.as_str().and_then(GraphicsPreset::from_name) else { return };
+            match world.get_resource_mut::<PostProcessSettings>() {
+                Some(mut post_process) => preset.apply(&mut post_process),
+                None => world.insert_resource(preset.post_process()),
+            }
+        })
+    }
+
+    /// 设置值变化（以及启动加载）时调用 `apply`
+    pub fn with_apply(mut self, apply: impl Fn(&mut World, &SettingValue) + Send + Sync + 'static) -> Self {
+        self.apply = Some(Arc::new(apply));
+        self
+    }
+
+    /// 校验值：类型不符或不在选项中时返回 `None`，滑块值按范围截断
+    pub fn normalize(&self, value: SettingValue) -> Option<SettingValue> {
+        match (&self.kind, value) {
+            (SettingKind::Toggle, value @ SettingValue::Bool(_)) => Some(value),
+            (SettingKind::Slider { min, max, .. }, SettingValue::Float(v)) if v.is_finite() => {
+                Some(SettingValue::Float(v.clamp(*min, *max)))
+            }
+            (SettingKind::Choice(options), SettingValue::Text(name)) if options.contains(&name) => {
+                Some(SettingValue::Text(name))
+            }
+            (SettingKind::KeyBinding, SettingValue::Text(name)) if InputBinding::from_key_name(&name).is_some() => {
+                Some(SettingValue::Text(name))
+            }
+            _ => None,
+        }
+    }
+
+    /// 菜单中 `<` / `>` 的调整结果：开关取反，滑块按步长增减，选项循环切换
+    pub fn step(&self, value: &SettingValue, direction: i32) -> SettingValue {
+        match (&self.kind, value) {
+            (SettingKind::Toggle, SettingValue::Bool(v)) => SettingValue::Bool(!v),
+            (SettingKind::Slider { min, max, step }, SettingValue::Float(v)) => {
+                // 按步长对齐，避免累积误差
+                let steps = ((v - min) / step).round() + direction as f32;
+                SettingValue::Float((min + steps * step).clamp(*min, *max))
+            }
+            (SettingKind::Choice(options), SettingValue::Text(name)) if !options.is_empty() => {
+                let len = options.len() as i32;
+                let index = options.iter().position(|o| o == name).unwrap_or(0) as i32;
+                SettingValue::Text(options[(index + direction).rem_euclid(len) as usize].clone())
+            }
+            _ => value.clone(),
+        }
+    }
+
+    /// 值在菜单中的显示文字
+    pub fn display(&self, value: &SettingValue) -> String {
+        match (&self.kind, value) {
+            (SettingKind::Toggle, SettingValue::Bool(v)) => if *v { "On" } else { "Off" }.to_string(),
+            (SettingKind::Slider { max, .. }, SettingValue::Float(v)) if *max <= 1.0 => {
+                format!("{:.0}%", v * 100.0)
+            }
+            (_, SettingValue::Float(v)) => format!("{:.1}", v),
+            (_, SettingValue::Bool(v)) => v.to_string(),
+            (_, SettingValue::Text(v)) => v.clone(),
+        }
+    }
+}
+
+fn parse_resolution(value: &str) -> Option<(u32, u32)> {
+    let (w, h) = value.split_once('x')?;
+    Some((w.trim().parse().ok()?, h.trim().parse().ok()?))
+}
+
+fn set_bus_volume(world: &mut World, value: &SettingValue, field: impl Fn(&mut AudioBus) -> &mut f32) {
+    let Some(volume) = value.as_f32() else { return };
+    if let Some(mut bus) = world.get_resource_mut::<AudioBus>() {
+        *field(&mut bus) = volume.clamp(0.0, 1.0);
+    }
+}
+
+/// 画质预设，映射到 [`PostProcessSettings`] 中的 Bloom 配置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsPreset {
+    /// 关闭后处理
+    Low,
+    /// 低开销 Bloom
+    Medium,
+    /// 默认 Bloom
+    High,
+    /// 更宽更亮的 Bloom
+    Ultra,
+}
+
+impl GraphicsPreset {
+    /// 全部预设（从低到高）
+    pub const ALL: [GraphicsPreset; 4] = [Self::Low, Self::Medium, Self::High, Self::Ultra];
+
+    /// 显示与持久化用的名称
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Medium => "Medium",
+            Self::High => "High",
+            Self::Ultra => "Ultra",
+        }
+    }
+
+    /// 按名称查找
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.name() == name)
+    }
+
+    /// 预设对应的 Bloom 配置
+    pub fn bloom(self) -> Option<BloomSettings> {
+        match self {
+            Self::Low => None,
+            Self::Medium => Some(BloomSettings { mip_count: 3, ..Default::default() }),
+            Self::High => Some(BloomSettings::default()),
+            Self::Ultra => Some(BloomSettings { mip_count: 7, intensity: 0.4, ..Default::default() }),
+        }
+    }
+
+    /// 只修改预设控制的字段，保留用户的其他后处理配置
+    pub fn apply(self, settings: &mut PostProcessSettings) {
+        settings.bloom = self.bloom();
+    }
+
+    /// 以默认配置为基础的预设后处理配置
+    pub fn post_process(self) -> PostProcessSettings {
+        let mut settings = PostProcessSettings::default();
+        self.apply(&mut settings);
+        settings
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  设置值与持久化
+// ---------------------------------------------------------------------------
+
+/// 设置值表 (Resource)
+///
+/// 修改通过 [`set`](Self::set) 进行，变化的键在下一帧由 [`settings_apply_system`]
+/// 应用到子系统；只有 `set` 产生的修改会（如配置了路径）写回文件，
+/// 注册与从文件加载只应用不写回。
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Settings {
+    defs: Vec<SettingDef>,
+    values: BTreeMap<String, SettingValue>,
+    changed: Vec<String>,
+    dirty: bool,
+    path: Option<PathBuf>,
+}
+
+impl Settings {
+    /// 创建持久化到 `path` 的设置表
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), ..Default::default() }
+    }
+
+    /// 配置文件路径
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// 注册设置项；已有合法值（如先加载了文件）时保留，否则使用默认值
+    pub fn register(&mut self, def: SettingDef) {
+        let value = self
+            .values
+            .get(&def.key)
+            .and_then(|v| def.normalize(v.clone()))
+            .unwrap_or_else(|| def.default.clone());
+        self.values.insert(def.key.clone(), value);
+        self.mark_changed(&def.key);
+        match self.defs.iter_mut().find(|d| d.key == def.key) {
+            Some(existing) => *existing = def,
+            None => self.defs.push(def),
+        }
+    }
+
+    /// 按注册顺序的设置定义
+    pub fn defs(&self) -> &[SettingDef] {
+        &self.defs
+    }
+
+    /// 查找设置定义
+    pub fn def(&self, key: &str) -> Option<&SettingDef> {
+        self.defs.iter().find(|d| d.key == key)
+    }
+
+    /// 当前值
+    pub fn get(&self, key: &str) -> Option<&SettingValue> {
+        self.values.get(key)
+    }
+
+    /// 开关值
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(SettingValue::as_bool)
+    }
+
+    /// 数值
+    pub fn get_f32(&self, key: &str) -> Option<f32> {
+        self.get(key).and_then(SettingValue::as_f32)
+    }
+
+    /// 文本值
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(SettingValue::as_str)
+    }
+
+    /// 修改设置；未注册或值不合法时返回 `false`
+    pub fn set(&mut self, key: &str, value: SettingValue) -> bool {
+        let Some(value) = self.def(key).and_then(|def| def.normalize(value)) else {
+            return false;
+        };
+        if self.values.get(key) != Some(&value) {
+            self.values.insert(key.to_string(), value);
+            self.mark_changed(key);
+            self.dirty = true;
+        }
+        true
+    }
+
+    /// 是否有尚未写回文件的修改
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// 恢复全部默认值
+    pub fn reset_all(&mut self) {
+        let defaults: Vec<(String, SettingValue)> =
+            self.defs.iter().map(|d| (d.key.clone(), d.default.clone())).collect();
+        for (key, value) in defaults {
+            self.set(&key, value);
+        }
+    }
+
+    /// 自上次取出以来变化的键
+    pub fn take_changed(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.changed)
+    }
+
+    fn mark_changed(&mut self, key: &str) {
+        if !self.changed.iter().any(|k| k == key) {
+            self.changed.push(key.to_string());
+        }
+    }
+
+    /// 序列化为 RON
+    pub fn to_ron(&self) -> Result<String> {
+        ron::ser::to_string_pretty(&self.values, ron::ser::PrettyConfig::default())
+            .map_err(|e| AnvilKitError::serialization(format!("序列化设置失败: {}", e)))
+    }
+
+    /// 从 RON 读取值
+    ///
+    /// 已注册的键经校验后写入；未注册的键原样保留，待对应设置注册时使用。
+    pub fn load_ron(&mut self, source: &str) -> Result<()> {
+        let loaded: BTreeMap<String, SettingValue> =
+            ron::from_str(source).map_err(|e| AnvilKitError::config(format!("解析设置失败: {}", e)))?;
+        for (key, value) in loaded {
+            if self.def(&key).is_some() {
+                self.set(&key, value);
+            } else {
+                self.values.insert(key, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// 从配置文件加载；文件不存在时保持默认值
+    pub fn load(&mut self) -> Result<()> {
+        let Some(path) = self.path.clone() else { return Ok(()) };
+        if !path.exists() {
+            return Ok(());
+        }
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| AnvilKitError::config_with_key(format!("读取设置失败: {}", e), path.display().to_string()))?;
+        self.load_ron(&source)?;
+        // 值与文件一致，无需写回
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// 写回配置文件（未配置路径时什么都不做）
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else { return Ok(()) };
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                AnvilKitError::config_with_key(format!("创建设置目录失败: {}", e), parent.display().to_string())
+            })?;
+        }
+        std::fs::write(path, self.to_ron()?)
+            .map_err(|e| AnvilKitError::config_with_key(format!("写入设置失败: {}", e), path.display().to_string()))
+    }
+}
+
+/// 设置变化事件
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct SettingChanged {
+    /// 键名
+    pub key: String,
+    /// 新值
+    pub value: SettingValue,
+}
+
+/// 将变化的设置应用到子系统并发送 [`SettingChanged`]（独占系统，Update 末尾）
+///
+/// 启动后的第一次运行会应用全部设置；仅在有 [`Settings::set`] 产生的修改时写回文件。
+pub fn settings_apply_system(world: &mut World) {
+    let Some(mut settings) = world.get_resource_mut::<Settings>() else { return };
+    let changed = settings.take_changed();
+    if changed.is_empty() {
+        return;
+    }
+    let dirty = std::mem::take(&mut settings.dirty);
+    let settings = settings.clone();
+
+    for key in &changed {
+        let (Some(def), Some(value)) = (settings.def(key), settings.get(key)) else { continue };
+        if let Some(apply) = &def.apply {
+            apply(world, value);
+        }
+        world.send_event(SettingChanged { key: key.clone(), value: value.clone() });
+    }
+    if !dirty {
+        return;
+    }
+    if let Err(e) = settings.save() {
+        log::warn!("{}", e);
+    }
+}
+
+// ---------------------------------------------------------------------------
+//  菜单
+// ---------------------------------------------------------------------------
+
+const PANEL_COLOR: [f32; 4] = [0.12, 0.12, 0.15, 0.96];
+const BUTTON_COLOR: [f32; 4] = [0.25, 0.25, 0.3, 1.0];
+const CAPTURE_COLOR: [f32; 4] = [0.3, 0.45, 0.8, 1.0];
+
+/// 设置菜单状态 (Resource)
+#[derive(Resource, Debug, Clone)]
+pub struct SettingsMenu {
+    /// 菜单是否打开
+    pub open: bool,
+    /// 切换菜单的按键（`None` 时仅由代码控制）
+    pub toggle_key: Option<KeyCode>,
+    /// 面板逻辑尺寸
+    pub size: Vec2,
+    /// 正在等待按键的绑定设置
+    pub capturing: Option<String>,
+}
+
+impl Default for SettingsMenu {
+    fn default() -> Self {
+        Self { open: false, toggle_key: Some(KeyCode::F10), size: Vec2::new(520.0, 480.0), capturing: None }
+    }
+}
+
+/// 设置菜单面板
+#[derive(Debug, Clone, Copy, Component)]
+#[require(UiNode)]
+pub struct SettingsPanel;
+
+/// 设置行中的控件角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsWidgetRole {
+    /// 面板标题
+    Title,
+    /// 设置标签
+    Label,
+    /// 减小 / 上一项
+    Decrement,
+    /// 当前值（点击：开关取反、选项下一项、按键开始捕获）
+    Value,
+    /// 增大 / 下一项
+    Increment,
+}
+
+/// 自动生成的设置控件，矩形由 [`settings_menu_layout_system`] 计算
+#[derive(Debug, Clone, Component)]
+#[require(UiNode)]
+pub struct SettingsWidget {
+    /// 所属面板
+    pub panel: Entity,
+    /// 设置键名（标题为空）
+    pub key: String,
+    /// 行号
+    pub row: usize,
+    /// 角色
+    pub role: SettingsWidgetRole,
+}
+
+/// 单行布局（物理像素 `[x, y, w, h]`）
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsRowLayout {
+    /// 标签
+    pub label: [f32; 4],
+    /// `<` 按钮
+    pub decrement: [f32; 4],
+    /// 值
+    pub value: [f32; 4],
+    /// `>` 按钮
+    pub increment: [f32; 4],
+}
+
+/// 计算第 `row` 行的布局：标签占左半，右半为 `<` 值 `>`
+pub fn settings_row_layout(panel: [f32; 4], row: usize, scale: f32) -> SettingsRowLayout {
+    let [x, y, w, _] = panel;
+    let pad = 16.0 * scale;
+    let gap = 6.0 * scale;
+    let title_h = 32.0 * scale;
+    let row_h = 30.0 * scale;
+    let inner_w = (w - 2.0 * pad).max(0.0);
+
+    let row_y = y + pad + title_h + gap + row as f32 * (row_h + gap);
+    let label_w = inner_w * 0.5;
+    let button_w = row_h;
+    let controls_x = x + pad + label_w;
+    let value_w = (inner_w - label_w - 2.0 * (button_w + gap)).max(0.0);
+
+    SettingsRowLayout {
+        label: [x + pad, row_y, label_w, row_h],
+        decrement: [controls_x, row_y, button_w, row_h],
+        value: [controls_x + button_w + gap, row_y, value_w, row_h],
+        increment: [controls_x + button_w + gap + value_w + gap, row_y, button_w, row_h],
+    }
+}
+
+fn contains(rect: [f32; 4], point: Vec2) -> bool {
+    point.x >= rect[0] && point.y >= rect[1] && point.x <= rect[0] + rect[2] && point.y <= rect[1] + rect[3]
+}
+
+fn button(text: &str) -> UiNode {
+    UiNode { text: Some(UiText::new(text)), background_color: BUTTON_COLOR, corner_radius: 4.0, ..Default::default() }
+}
+
+/// 按 [`SettingsMenu::open`] 生成或移除菜单实体 (Update)
+pub fn settings_menu_spawn_system(
+    mut commands: Commands,
+    mut menu: ResMut<SettingsMenu>,
+    settings: Res<Settings>,
+    input: Option<Res<InputState>>,
+    panels: Query<Entity, With<SettingsPanel>>,
+) {
+    if let (Some(input), Some(key)) = (&input, menu.toggle_key) {
+        if menu.capturing.is_none() && input.is_key_just_pressed(key) {
+            menu.open = !menu.open;
+        }
+    }
+
+    if !menu.open {
+        menu.capturing = None;
+        // 控件由布局系统在面板消失后清理
+        for panel in panels.iter() {
+            commands.entity(panel).despawn();
+        }
+        return;
+    }
+    if !panels.is_empty() {
+        return;
+    }
+
+    let panel = commands
+        .spawn((
+            SettingsPanel,
+            UiAnchor::new(Anchor::Center, menu.size),
+            UiNode { background_color: PANEL_COLOR, corner_radius: 8.0, ..Default::default() },
+        ))
+        .id();
+    commands.spawn((
+        SettingsWidget { panel, key: String::new(), row: 0, role: SettingsWidgetRole::Title },
+        UiNode { text: Some(UiText::new("Settings").with_font_size(22.0)), ..Default::default() },
+    ));
+    for (row, def) in settings.defs().iter().enumerate() {
+        let widget = |role| SettingsWidget { panel, key: def.key.clone(), row, role };
+        commands.spawn((
+            widget(SettingsWidgetRole::Label),
+            UiNode { text: Some(UiText::new(def.label.clone())), ..Default::default() },
+        ));
+        if !matches!(def.kind, SettingKind::KeyBinding | SettingKind::Toggle) {
+            commands.spawn((widget(SettingsWidgetRole::Decrement), button("<")));
+            commands.spawn((widget(SettingsWidgetRole::Increment), button(">")));
+        }
+        commands.spawn((widget(SettingsWidgetRole::Value), button("")));
+    }
+}
+
+/// 菜单布局与显示文字同步 (PostUpdate，锚点布局之后)
+pub fn settings_menu_layout_system(
+    mut commands: Commands,
+    viewport: Res<UiViewport>,
+    menu: Res<SettingsMenu>,
+    settings: Res<Settings>,
+    panels: Query<&UiNode, (With<SettingsPanel>, Without<SettingsWidget>)>,
+    mut widgets: Query<(Entity, &SettingsWidget, &mut UiNode)>,
+) {
+    let scale = viewport.scale_factor;
+    for (entity, widget, mut node) in widgets.iter_mut() {
+        let Ok(panel) = panels.get(widget.panel) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        let [x, y, w, _] = panel.computed_rect;
+        let layout = settings_row_layout(panel.computed_rect, widget.row, scale);
+        let rect = match widget.role {
+            SettingsWidgetRole::Title => [x + 16.0 * scale, y + 16.0 * scale, (w - 32.0 * scale).max(0.0), 32.0 * scale],
+            SettingsWidgetRole::Label => layout.label,
+            SettingsWidgetRole::Decrement => layout.decrement,
+            SettingsWidgetRole::Value => match settings.def(&widget.key).map(|d| &d.kind) {
+                // 无 `<` `>` 的控件占满右半
+                Some(SettingKind::KeyBinding | SettingKind::Toggle) => {
+                    [layout.decrement[0], layout.value[1], layout.increment[0] + layout.increment[2] - layout.decrement[0], layout.value[3]]
+                }
+                _ => layout.value,
+            },
+            SettingsWidgetRole::Increment => layout.increment,
+        };
+        if node.computed_rect != rect {
+            node.computed_rect = rect;
+        }
+
+        if widget.role != SettingsWidgetRole::Value {
+            continue;
+        }
+        let capturing = menu.capturing.as_deref() == Some(widget.key.as_str());
+        let content = if capturing {
+            "Press a key...".to_string()
+        } else {
+            match (settings.def(&widget.key), settings.get(&widget.key)) {
+                (Some(def), Some(value)) => def.display(value),
+                _ => String::new(),
+            }
+        };
+        let color = if capturing { CAPTURE_COLOR } else { BUTTON_COLOR };
+        if node.text.as_ref().map(|t| t.content.as_str()) != Some(content.as_str()) {
+            node.text = Some(UiText::new(content));
+        }
+        if node.background_color != color {
+            node.background_color = color;
+        }
+    }
+}
+
+/// 菜单输入系统 (Update)
+///
+/// 点击 `<` / `>` 调整，点击值切换开关、循环选项或开始捕获按键；
+/// 捕获中按下的第一个按键成为新绑定，Escape 取消。
+pub fn settings_menu_input_system(
+    mut menu: ResMut<SettingsMenu>,
+    mut settings: ResMut<Settings>,
+    input: Option<Res<InputState>>,
+    widgets: Query<(&SettingsWidget, &UiNode)>,
+) {
+    let Some(input) = input else { return };
+    if !menu.open {
+        return;
+    }
+
+    if let Some(key) = menu.capturing.clone() {
+        let pressed = input.pressed_keys().iter().copied().find(|k| input.is_key_just_pressed(*k));
+        match pressed {
+            Some(KeyCode::Escape) => menu.capturing = None,
+            Some(code) => {
+                settings.set(&key, SettingValue::Text(format!("{:?}", code)));
+                menu.capturing = None;
+            }
+            None => {}
+        }
+        return;
+    }
+
+    if !input.is_mouse_just_pressed(MouseButton::Left) {
+        return;
+    }
+    let cursor = input.mouse_position();
+    let Some(widget) = widgets.iter().find_map(|(w, node)| contains(node.computed_rect, cursor).then_some(w)) else {
+        return;
+    };
+    let (Some(def), Some(value)) = (settings.def(&widget.key), settings.get(&widget.key)) else { return };
+    let next = match (widget.role, &def.kind) {
+        (SettingsWidgetRole::Value, SettingKind::KeyBinding) => {
+            menu.capturing = Some(widget.key.clone());
+            return;
+        }
+        (SettingsWidgetRole::Decrement, _) => def.step(value, -1),
+        (SettingsWidgetRole::Increment | SettingsWidgetRole::Value, _) => def.step(value, 1),
+        _ => return,
+    };
+    let key = widget.key.clone();
+    settings.set(&key, next);
+}
+
+// ---------------------------------------------------------------------------
+//  插件
+// ---------------------------------------------------------------------------
+
+/// 设置插件
+///
+/// 加载配置文件、注册设置定义并生成菜单；未添加 [`UiAnchorPlugin`] 时一并添加。
+///
+/// ```rust,ignore
+/// app.add_plugins(
+///     SettingsPlugin::new("config/settings.ron")
+///         .with_defaults(&[(1280, 720), (1920, 1080)])
+///         .with(SettingDef::key_binding("jump", "Jump", KeyCode::Space)),
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SettingsPlugin {
+    path: Option<PathBuf>,
+    defs: Vec<SettingDef>,
+}
+
+impl SettingsPlugin {
+    /// 持久化到 `path` 的设置插件
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: Some(path.into()), defs: Vec::new() }
+    }
+
+    /// 添加设置定义（按添加顺序显示）
+    pub fn with(mut self, def: SettingDef) -> Self {
+        self.defs.push(def);
+        self
+    }
+
+    /// 添加内置设置：分辨率、垂直同步、画质预设与全部音量总线
+    pub fn with_defaults(self, resolutions: &[(u32, u32)]) -> Self {
+        self.with(SettingDef::resolution(resolutions))
+            .with(SettingDef::vsync())
+            .with(SettingDef::graphics_preset())
+            .with(SettingDef::master_volume())
+            .with(SettingDef::volume(AudioBusCategory::Music))
+            .with(SettingDef::volume(AudioBusCategory::SFX))
+            .with(SettingDef::volume(AudioBusCategory::Voice))
+    }
+}
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UiAnchorPlugin>() {
+            app.add_plugins(UiAnchorPlugin);
+        }
+
+        let mut settings = match &self.path {
+            Some(path) => Settings::with_path(path),
+            None => Settings::default(),
+        };
+        if let Err(e) = settings.load() {
+            log::warn!("{}", e);
+        }
+        for def in &self.defs {
+            settings.register(def.clone());
+        }
+
+        app.insert_resource(settings)
+            .init_resource::<SettingsMenu>()
+            .add_event::<SettingChanged>()
+            .add_systems(
+                bevy_app::Update,
+                (settings_menu_spawn_system, settings_menu_input_system, settings_apply_system).chain(),
+            )
+            .add_systems(bevy_app::PostUpdate, settings_menu_layout_system.after(ui_anchor_system));
+    }
+
+    fn name(&self) -> &str {
+        "SettingsPlugin"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_and_normalize() {
+        let slider = SettingDef::slider("s", "S", 0.0, 1.0, 0.1, 0.5);
+        assert_eq!(slider.step(&SettingValue::Float(0.5), 1), SettingValue::Float(0.6));
+        assert_eq!(slider.step(&SettingValue::Float(1.0), 1), SettingValue::Float(1.0));
+        assert_eq!(slider.normalize(SettingValue::Float(-3.0)), Some(SettingValue::Float(0.0)));
+        assert_eq!(slider.normalize(SettingValue::Bool(true)), None);
+
+        let choice = SettingDef::resolution(&[(1280, 720), (1920, 1080)]);
+        assert_eq!(choice.default, SettingValue::Text("1280x720".into()));
+        assert_eq!(choice.step(&choice.default, -1), SettingValue::Text("1920x1080".into()));
+        assert_eq!(choice.normalize(SettingValue::Text("640x480".into())), None);
+
+        let key = SettingDef::key_binding("jump", "Jump", KeyCode::Space);
+        assert_eq!(key.normalize(SettingValue::Text("NotAKey".into())), None);
+        assert_eq!(parse_resolution("2560x1440"), Some((2560, 1440)));
+    }
+
+    #[test]
+    fn test_apply_and_persist() {
+        let path = std::env::temp_dir().join(format!("anvilkit_settings_test_{}.ron", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut app = App::new();
+        let mut actions = ActionMap::new();
+        actions.add_binding("jump", InputBinding::Key(KeyCode::Space));
+        let vignette = anvilkit_render::renderer::post_process::VignetteSettings::default();
+        app.insert_resource(PostProcessSettings { vignette: Some(vignette), ..Default::default() })
+            .insert_resource(AudioBus::default())
+            .insert_resource(WindowCommands::default())
+            .insert_resource(actions)
+            .add_plugins(
+                SettingsPlugin::new(&path)
+                    .with_defaults(&[(1280, 720)])
+                    .with(SettingDef::key_binding("jump", "Jump", KeyCode::Space)),
+            );
+        app.update();
+        // 启动时应用全部设置
+        let bloom = app.world().resource::<PostProcessSettings>().bloom.as_ref().map(|b| b.mip_count);
+        assert_eq!(bloom, Some(BloomSettings::default().mip_count));
+        // 预设只修改 Bloom，用户的其他后处理配置保留
+        assert!(app.world().resource::<PostProcessSettings>().vignette.is_some());
+        // 启动时的应用不写回文件
+        assert!(!path.exists());
+        assert!(!app.world().resource::<WindowCommands>().is_empty());
+        let startup: Vec<_> = app.world_mut().resource_mut::<Events<SettingChanged>>().drain().collect();
+        assert_eq!(startup.len(), 8);
+
+        {
+            let mut settings = app.world_mut().resource_mut::<Settings>();
+            assert!(settings.set("audio.music", SettingValue::Float(0.3)));
+            assert!(settings.set("input.jump", SettingValue::Text("J".into())));
+            assert!(!settings.set("unknown", SettingValue::Bool(true)));
+        }
+        app.update();
+
+        assert_eq!(app.world().resource::<AudioBus>().music, 0.3);
+        let bindings = app.world().resource::<ActionMap>().get_bindings("jump").unwrap().to_vec();
+        assert_eq!(bindings, vec![InputBinding::Key(KeyCode::J)]);
+        let events: Vec<_> = app.world_mut().resource_mut::<Events<SettingChanged>>().drain().collect();
+        assert_eq!(events.len(), 2);
+
+        let mut reloaded = Settings::with_path(&path);
+        reloaded.load().unwrap();
+        reloaded.register(SettingDef::volume(AudioBusCategory::Music));
+        assert_eq!(reloaded.get_f32("audio.music"), Some(0.3));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_menu_click_and_key_capture() {
+        let mut app = App::new();
+        app.insert_resource(InputState::new())
+            .add_plugins(SettingsPlugin::default().with(SettingDef::vsync()).with(SettingDef::key_binding(
+                "jump",
+                "Jump",
+                KeyCode::Space,
+            )));
+        app.world_mut().resource_mut::<SettingsMenu>().open = true;
+        app.update();
+        app.update();
+
+        let value_rect = |app: &mut App, key: &str| {
+            app.world_mut()
+                .query::<(&SettingsWidget, &UiNode)>()
+                .iter(app.world())
+                .find(|(w, _)| w.key == key && w.role == SettingsWidgetRole::Value)
+                .map(|(_, node)| node.computed_rect)
+                .unwrap()
+        };
+        let click = |app: &mut App, rect: [f32; 4]| {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.end_frame();
+            input.release_mouse(MouseButton::Left);
+            input.set_mouse_position(Vec2::new(rect[0] + 1.0, rect[1] + 1.0));
+            input.press_mouse(MouseButton::Left);
+        };
+
+        let vsync = value_rect(&mut app, "video.vsync");
+        click(&mut app, vsync);
+        app.update();
+        assert_eq!(app.world().resource::<Settings>().get_bool("video.vsync"), Some(false));
+
+        let jump = value_rect(&mut app, "input.jump");
+        click(&mut app, jump);
+        app.update();
+        assert_eq!(app.world().resource::<SettingsMenu>().capturing.as_deref(), Some("input.jump"));
+        {
+            let mut input = app.world_mut().resource_mut::<InputState>();
+            input.end_frame();
+            input.press_key(KeyCode::K);
+        }
+        app.update();
+        assert_eq!(app.world().resource::<Settings>().get_str("input.jump"), Some("K"));
+        assert!(app.world().resource::<SettingsMenu>().capturing.is_none());
+
+        app.world_mut().resource_mut::<SettingsMenu>().open = false;
+        app.update();
+        assert_eq!(app.world_mut().query::<&SettingsWidget>().iter(app.world()).count(), 0);
+    }
+}
@@ -93,6 +93,7 @@ fn main() {
             direction: glam::Vec3::new(-0.4, -0.7, 0.5).normalize(),
             color: glam::Vec3::new(1.0, 0.95, 0.85),
             intensity: 4.0,
+            ..Default::default()
         },
         point_lights: vec![
             PointLight {
@@ -109,6 +110,7 @@ fn main() {
             },
         ],
         spot_lights: vec![],
+        ..Default::default()
     });
 
     let event_loop = EventLoop::new().unwrap();
@@ -378,6 +380,8 @@ impl ShowcaseApp {
                 cascade_view_projs: [svp.to_cols_array_2d(); 3],
                 cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
                 emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 3.0],
+                base_color_factor: [1.0; 4],
+                ..Default::default()
             };
             device.queue().write_buffer(ub, 0, bytemuck::bytes_of(&u));
 
@@ -60,6 +60,7 @@ fn main() {
             direction: glam::Vec3::new(-0.4, -0.7, 0.5).normalize(),
             color: glam::Vec3::new(1.0, 0.95, 0.85),
             intensity: 6.0,
+            ..Default::default()
         },
         point_lights: vec![
             PointLight { position: glam::Vec3::new(1.0, 0.5, -1.0), color: glam::Vec3::new(1.0, 0.9, 0.7), intensity: 25.0, range: 5.0 },
@@ -67,6 +68,7 @@ fn main() {
             PointLight { position: glam::Vec3::new(-3.0, 1.0, -1.0), color: glam::Vec3::new(0.3, 0.5, 1.0), intensity: 10.0, range: 10.0 },
         ],
         spot_lights: vec![],
+        ..Default::default()
     });
 
     let event_loop = EventLoop::new().unwrap();
@@ -338,6 +340,8 @@ impl DemoBloomApp {
                 cascade_view_projs: [svp.to_cols_array_2d(); 3],
                 cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
                 emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 3.0],
+                base_color_factor: [1.0; 4],
+                ..Default::default()
             };
             device.queue().write_buffer(ub, 0, bytemuck::bytes_of(&u));
 
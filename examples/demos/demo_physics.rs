@@ -61,11 +61,13 @@ fn main() {
             direction: glam::Vec3::new(-0.5, -0.8, 0.3).normalize(),
             color: glam::Vec3::new(1.0, 0.98, 0.90),
             intensity: 5.0,
+            ..Default::default()
         },
         point_lights: vec![
             PointLight { position: glam::Vec3::new(3.0, 3.0, -2.0), color: glam::Vec3::new(1.0, 0.8, 0.5), intensity: 10.0, range: 12.0 },
         ],
         spot_lights: vec![],
+        ..Default::default()
     });
 
     let event_loop = EventLoop::new().unwrap();
@@ -372,6 +374,8 @@ impl DemoPhysicsApp {
                 cascade_view_projs: [svp.to_cols_array_2d(); 3],
                 cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
                 emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 3.0],
+                base_color_factor: [1.0; 4],
+                ..Default::default()
             };
             device.queue().write_buffer(ub, 0, bytemuck::bytes_of(&u));
 
@@ -74,8 +74,9 @@ fn collision_detection_system(
         }
     }
 }
-use anvilkit_render::renderer::particle::{ParticleSystem, Particle, ParticleRenderer};
+use anvilkit_render::renderer::particle::{ParticleSystem, Particle, ParticleRenderer, ParticleView};
 use anvilkit_render::renderer::ui::{UiNode, UiText, UiStyle, Val, UiRenderer};
+use anvilkit_render::renderer::ui_scroll::UiClip;
 
 // ---------------------------------------------------------------------------
 //  Procedural cube mesh
@@ -328,6 +329,7 @@ fn main() {
             direction: glam::Vec3::new(-0.4, -0.7, 0.5).normalize(),
             color: glam::Vec3::new(1.0, 0.95, 0.85),
             intensity: 4.0,
+            ..Default::default()
         },
         point_lights: vec![
             PointLight {
@@ -338,6 +340,7 @@ fn main() {
             },
         ],
         spot_lights: vec![],
+        ..Default::default()
     });
 
     // Spawn player entity (MeshHandle/MaterialHandle added after GPU init)
@@ -704,6 +707,8 @@ impl GameApp {
                 cascade_view_projs: [glam::Mat4::IDENTITY.to_cols_array_2d(); 3],
                 cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
                 emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 3.0],
+                base_color_factor: [1.0; 4],
+                ..Default::default()
             };
             device.queue().write_buffer(ub, 0, bytemuck::bytes_of(&u));
 
@@ -757,7 +762,8 @@ impl GameApp {
         if let Some(ref mut pr) = self.particle_renderer {
             if let Some(particles) = self.app.world().get_resource::<GameParticles>() {
                 let mut enc = device.device().create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("Game Particle Enc") });
-                pr.render(device, &mut enc, &swapchain, None, &particles.system, &cam.view_proj, None);
+                let view = ParticleView { color: &swapchain, resolve: None, depth: None, view_proj: cam.view_proj, camera_pos: None };
+                pr.render(device, &mut enc, &view, &particles.system);
                 device.queue().submit(std::iter::once(enc.finish()));
             }
         }
@@ -766,15 +772,15 @@ impl GameApp {
         if let Some(ref mut ur) = self.ui_renderer {
             let (sw, sh) = self.render_app.window_state().size();
             // Collect UI node data (query needs &mut World, so clone the data)
-            let ui_nodes: Vec<UiNode> = self.app.world_mut().query::<&UiNode>()
+            let ui_nodes: Vec<(UiNode, Option<UiClip>)> = self.app.world_mut().query::<(&UiNode, Option<&UiClip>)>()
                 .iter(self.app.world())
-                .filter(|n| n.visible)
-                .cloned()
+                .filter(|(n, _)| n.visible)
+                .map(|(n, clip)| (n.clone(), clip.copied()))
                 .collect();
             if !ui_nodes.is_empty() {
-                let node_refs: Vec<&UiNode> = ui_nodes.iter().collect();
+                let node_refs: Vec<_> = ui_nodes.iter().map(|(n, clip)| (n, clip.as_ref())).collect();
                 let mut enc = device.device().create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some("Game UI Enc") });
-                ur.render(device, &mut enc, &swapchain, &node_refs, sw as f32, sh as f32);
+                ur.render_with_clips(device, &mut enc, &swapchain, &node_refs, sw as f32, sh as f32);
                 device.queue().submit(std::iter::once(enc.finish()));
             }
         }
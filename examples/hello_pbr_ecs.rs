@@ -73,6 +73,7 @@ fn main() {
             direction: glam::Vec3::new(-0.5, -0.8, 0.3).normalize(),
             color: glam::Vec3::new(1.0, 0.95, 0.9),
             intensity: 3.0,
+            ..Default::default()
         },
         point_lights: vec![
             PointLight {
@@ -89,6 +90,7 @@ fn main() {
             },
         ],
         spot_lights: vec![],
+        ..Default::default()
     });
 
     let event_loop = EventLoop::new().unwrap();
@@ -433,6 +435,8 @@ impl PbrEcsApp {
                 cascade_view_projs: [shadow_view_proj.to_cols_array_2d(); 3],
                 cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
                 emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 3.0],
+                base_color_factor: [1.0; 4],
+                ..Default::default()
             };
             device.queue().write_buffer(ub, 0, bytemuck::bytes_of(&uniform));
 
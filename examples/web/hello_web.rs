@@ -0,0 +1,67 @@
+//! # Web (WebGPU / WebGL2) 示例
+//!
+//! 渲染到页面中 id 为 `anvilkit-canvas` 的 `<canvas>`，背景色随时间渐变，
+//! 并用调试线绘制一个旋转的正方形。浏览器支持 WebGPU 时使用 WebGPU，否则回退到 WebGL2。
+//!
+//! 原生运行: `cargo run -p anvilkit-render --example hello_web`
+//!
+//! 构建 Web 版本（需要 `wasm-bindgen-cli`）:
+//!
+//! ```text
+//! cargo build -p anvilkit-render --example hello_web --target wasm32-unknown-unknown --release
+//! wasm-bindgen --target web --out-dir examples/web/pkg \
+//!     target/wasm32-unknown-unknown/release/examples/hello_web.wasm
+//! python3 -m http.server -d examples/web
+//! ```
+//!
+//! 然后在浏览器中打开 `http://localhost:8000`。
+
+use anvilkit_render::prelude::*;
+
+/// 累计运行时间（秒）
+#[derive(Resource, Default)]
+struct Elapsed(f32);
+
+fn animate(
+    mut elapsed: ResMut<Elapsed>,
+    dt: Res<DeltaTime>,
+    mut clear: ResMut<ClearColor>,
+    mut draw: ResMut<DebugDraw>,
+) {
+    elapsed.0 += dt.0;
+    let t = elapsed.0;
+    *clear = ClearColor::rgb(0.1 + 0.1 * t.sin().abs(), 0.15, 0.3 + 0.2 * (t * 0.5).cos().abs());
+
+    let corners: Vec<Vec3> = (0..4)
+        .map(|i| {
+            let angle = t + i as f32 * std::f32::consts::FRAC_PI_2;
+            Vec3::new(angle.cos(), angle.sin(), 0.0)
+        })
+        .collect();
+    for i in 0..4 {
+        draw.line(corners[i], corners[(i + 1) % 4], [1.0, 0.8, 0.2, 1.0]);
+    }
+}
+
+fn main() {
+    #[cfg(not(target_arch = "wasm32"))]
+    env_logger::init();
+
+    let mut app = App::new();
+    app.add_plugins(RenderPlugin::new().with_window_config(
+        WindowConfig::new()
+            .with_title("AnvilKit - Web")
+            .with_size(800, 600)
+            .with_canvas_id("anvilkit-canvas"),
+    ));
+    app.init_resource::<Elapsed>();
+    app.add_systems(bevy_app::Update, animate);
+    app.add_systems(bevy_app::Startup, |mut commands: Commands| {
+        commands.spawn((
+            CameraComponent { aspect_ratio: 800.0 / 600.0, ..Default::default() },
+            Transform::from_xyz(0.0, 0.0, 4.0),
+        ));
+    });
+
+    RenderApp::run(app);
+}
@@ -111,6 +111,7 @@ fn main() {
             direction: glam::Vec3::new(-0.3, -0.8, 0.4).normalize(),
             color: glam::Vec3::new(1.0, 0.97, 0.92),
             intensity: 3.5,
+            ..Default::default()
         },
         point_lights: vec![
             PointLight {
@@ -121,6 +122,7 @@ fn main() {
             },
         ],
         spot_lights: vec![],
+        ..Default::default()
     });
 
     // Spawn cue ball — head end of table (negative Z, near camera)
@@ -314,6 +316,8 @@ impl BilliardGame {
                 cascade_view_projs: [glam::Mat4::IDENTITY.to_cols_array_2d(); 3],
                 cascade_splits: [10.0, 30.0, 100.0, 1.0 / 2048.0],
                 emissive_factor: [cmd.emissive_factor[0], cmd.emissive_factor[1], cmd.emissive_factor[2], 3.0],
+                base_color_factor: [1.0; 4],
+                ..Default::default()
             };
             device.queue().write_buffer(&gpu.scene_ub, 0, bytemuck::bytes_of(&u));
 
@@ -1,6 +1,4 @@
 use bytemuck::{Pod, Zeroable};
-use wgpu::{VertexBufferLayout, VertexStepMode, VertexAttribute, VertexFormat};
-use anvilkit_render::renderer::buffer::Vertex;
 
 /// Block vertex: position + UV + normal + AO + light.
 ///
@@ -22,52 +20,19 @@ pub struct BlockVertex {
     pub light: f32,
 }
 
-impl Vertex for BlockVertex {
-    fn layout() -> VertexBufferLayout<'static> {
-        const ATTRIBUTES: &[VertexAttribute] = &[
-            // position: location 0
-            VertexAttribute {
-                offset: 0,
-                shader_location: 0,
-                format: VertexFormat::Float32x3,
-            },
-            // uv: location 1
-            VertexAttribute {
-                offset: 12,
-                shader_location: 1,
-                format: VertexFormat::Float32x2,
-            },
-            // normal: location 2
-            VertexAttribute {
-                offset: 20,
-                shader_location: 2,
-                format: VertexFormat::Float32x3,
-            },
-            // ao: location 3
-            VertexAttribute {
-                offset: 32,
-                shader_location: 3,
-                format: VertexFormat::Float32,
-            },
-            // light: location 4 (packed sky*16 + block)
-            VertexAttribute {
-                offset: 36,
-                shader_location: 4,
-                format: VertexFormat::Float32,
-            },
-        ];
-
-        VertexBufferLayout {
-            array_stride: std::mem::size_of::<BlockVertex>() as u64,
-            step_mode: VertexStepMode::Vertex,
-            attributes: ATTRIBUTES,
-        }
-    }
-}
+// position, uv, normal, ao, light (packed sky*16 + block)
+anvilkit_render::impl_vertex!(BlockVertex, [
+    0 => Float32x3,
+    1 => Float32x2,
+    2 => Float32x3,
+    3 => Float32,
+    4 => Float32,
+]);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use anvilkit_render::renderer::buffer::Vertex;
 
     #[test]
     fn vertex_size() {